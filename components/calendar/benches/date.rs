@@ -8,17 +8,16 @@ use crate::fixtures::structs::DateFixture;
 use criterion::{
     black_box, criterion_group, criterion_main, measurement::WallTime, BenchmarkGroup, Criterion,
 };
-use icu_calendar::{AsCalendar, Calendar, Date, DateDuration};
+use icu_calendar::{types::Overflow, AsCalendar, Calendar, Date, DateDuration};
 
 fn bench_date<A: AsCalendar>(date: &mut Date<A>) {
     // black_box used to avoid compiler optimization.
     // Arithmetic
-    date.add(DateDuration::new(
-        black_box(1),
-        black_box(2),
-        black_box(3),
-        black_box(4),
-    ));
+    date.add(
+        DateDuration::new(black_box(1), black_box(2), black_box(3), black_box(4)),
+        Overflow::Constrain,
+    )
+    .expect("Failed to add duration.");
 
     // Retrieving vals
     let _ = black_box(date.year().number);
@@ -8,17 +8,19 @@ use crate::fixtures::structs::DateFixture;
 use criterion::{
     black_box, criterion_group, criterion_main, measurement::WallTime, BenchmarkGroup, Criterion,
 };
-use icu_calendar::{types::Time, AsCalendar, Calendar, DateDuration, DateTime};
+use icu_calendar::types::{Overflow, Time};
+use icu_calendar::{AsCalendar, Calendar, DateDuration, DateTime};
 
 fn bench_datetime<A: AsCalendar>(datetime: &mut DateTime<A>) {
     // black_box used to avoid compiler optimization.
     // Arithmetic.
-    datetime.date.add(DateDuration::new(
-        black_box(1),
-        black_box(2),
-        black_box(3),
-        black_box(4),
-    ));
+    datetime
+        .date
+        .add(
+            DateDuration::new(black_box(1), black_box(2), black_box(3), black_box(4)),
+            Overflow::Constrain,
+        )
+        .expect("Failed to add duration.");
     datetime.time = Time::try_new(black_box(14), black_box(30), black_box(0), black_box(0))
         .expect("Failed to initialize Time instance.");
 
@@ -5,12 +5,18 @@
 //! Module for working with multiple calendars at once
 
 use crate::buddhist::Buddhist;
+use crate::chinese::Chinese;
 use crate::coptic::Coptic;
+use crate::dangi::Dangi;
 use crate::ethiopian::{Ethiopian, EthiopianEraStyle};
 use crate::gregorian::Gregorian;
+use crate::hebrew::Hebrew;
 use crate::indian::Indian;
+use crate::islamic::{IslamicCivil, IslamicTabular, IslamicUmmAlQura};
 use crate::iso::Iso;
 use crate::japanese::{Japanese, JapaneseExtended};
+use crate::persian::Persian;
+use crate::roc::Roc;
 use crate::{
     types, AsCalendar, Calendar, Date, DateDuration, DateDurationUnit, DateTime, DateTimeError, Ref,
 };
@@ -18,7 +24,7 @@ use alloc::string::ToString;
 
 use icu_locid::{
     extensions::unicode::Value, extensions_unicode_key as key, extensions_unicode_value as value,
-    subtags_language as language, Locale,
+    subtags::Region, subtags_language as language, Locale,
 };
 use icu_provider::prelude::*;
 use tinystr::tinystr;
@@ -91,6 +97,22 @@ pub enum AnyCalendar {
     Indian(Indian),
     /// A [`Coptic`] calendar
     Coptic(Coptic),
+    /// A [`Chinese`] calendar
+    Chinese(Chinese),
+    /// A [`Dangi`] calendar
+    Dangi(Dangi),
+    /// A [`Hebrew`] calendar
+    Hebrew(Hebrew),
+    /// A [`Persian`] calendar
+    Persian(Persian),
+    /// A [`Roc`] calendar
+    Roc(Roc),
+    /// An [`IslamicCivil`] calendar
+    IslamicCivil(IslamicCivil),
+    /// An [`IslamicTabular`] calendar
+    IslamicTabular(IslamicTabular),
+    /// An [`IslamicUmmAlQura`] calendar
+    IslamicUmmAlQura(IslamicUmmAlQura),
     /// An [`Iso`] calendar
     Iso(Iso),
 }
@@ -113,6 +135,22 @@ pub enum AnyDateInner {
     Indian(<Indian as Calendar>::DateInner),
     /// A date for a [`Coptic`] calendar
     Coptic(<Coptic as Calendar>::DateInner),
+    /// A date for a [`Chinese`] calendar
+    Chinese(<Chinese as Calendar>::DateInner),
+    /// A date for a [`Dangi`] calendar
+    Dangi(<Dangi as Calendar>::DateInner),
+    /// A date for a [`Hebrew`] calendar
+    Hebrew(<Hebrew as Calendar>::DateInner),
+    /// A date for a [`Persian`] calendar
+    Persian(<Persian as Calendar>::DateInner),
+    /// A date for a [`Roc`] calendar
+    Roc(<Roc as Calendar>::DateInner),
+    /// A date for an [`IslamicCivil`] calendar
+    IslamicCivil(<IslamicCivil as Calendar>::DateInner),
+    /// A date for an [`IslamicTabular`] calendar
+    IslamicTabular(<IslamicTabular as Calendar>::DateInner),
+    /// A date for an [`IslamicUmmAlQura`] calendar
+    IslamicUmmAlQura(<IslamicUmmAlQura as Calendar>::DateInner),
     /// A date for an [`Iso`] calendar
     Iso(<Iso as Calendar>::DateInner),
 }
@@ -130,6 +168,23 @@ macro_rules! match_cal_and_date {
             (&Self::Ethiopian(ref $cal_matched), &AnyDateInner::Ethiopian(ref $date_matched)) => $e,
             (&Self::Indian(ref $cal_matched), &AnyDateInner::Indian(ref $date_matched)) => $e,
             (&Self::Coptic(ref $cal_matched), &AnyDateInner::Coptic(ref $date_matched)) => $e,
+            (&Self::Chinese(ref $cal_matched), &AnyDateInner::Chinese(ref $date_matched)) => $e,
+            (&Self::Dangi(ref $cal_matched), &AnyDateInner::Dangi(ref $date_matched)) => $e,
+            (&Self::Hebrew(ref $cal_matched), &AnyDateInner::Hebrew(ref $date_matched)) => $e,
+            (&Self::Persian(ref $cal_matched), &AnyDateInner::Persian(ref $date_matched)) => $e,
+            (&Self::Roc(ref $cal_matched), &AnyDateInner::Roc(ref $date_matched)) => $e,
+            (
+                &Self::IslamicCivil(ref $cal_matched),
+                &AnyDateInner::IslamicCivil(ref $date_matched),
+            ) => $e,
+            (
+                &Self::IslamicTabular(ref $cal_matched),
+                &AnyDateInner::IslamicTabular(ref $date_matched),
+            ) => $e,
+            (
+                &Self::IslamicUmmAlQura(ref $cal_matched),
+                &AnyDateInner::IslamicUmmAlQura(ref $date_matched),
+            ) => $e,
             (&Self::Iso(ref $cal_matched), &AnyDateInner::Iso(ref $date_matched)) => $e,
             _ => panic!(
                 "Found AnyCalendar with mixed calendar type {} and date type {}!",
@@ -148,30 +203,57 @@ impl Calendar for AnyCalendar {
         year: i32,
         month_code: types::MonthCode,
         day: u8,
+        overflow: types::Overflow,
     ) -> Result<Self::DateInner, DateTimeError> {
         let ret = match *self {
             Self::Gregorian(ref c) => {
-                AnyDateInner::Gregorian(c.date_from_codes(era, year, month_code, day)?)
+                AnyDateInner::Gregorian(c.date_from_codes(era, year, month_code, day, overflow)?)
             }
             Self::Buddhist(ref c) => {
-                AnyDateInner::Buddhist(c.date_from_codes(era, year, month_code, day)?)
+                AnyDateInner::Buddhist(c.date_from_codes(era, year, month_code, day, overflow)?)
             }
             Self::Japanese(ref c) => {
-                AnyDateInner::Japanese(c.date_from_codes(era, year, month_code, day)?)
-            }
-            Self::JapaneseExtended(ref c) => {
-                AnyDateInner::JapaneseExtended(c.date_from_codes(era, year, month_code, day)?)
+                AnyDateInner::Japanese(c.date_from_codes(era, year, month_code, day, overflow)?)
             }
+            Self::JapaneseExtended(ref c) => AnyDateInner::JapaneseExtended(
+                c.date_from_codes(era, year, month_code, day, overflow)?,
+            ),
             Self::Ethiopian(ref c) => {
-                AnyDateInner::Ethiopian(c.date_from_codes(era, year, month_code, day)?)
+                AnyDateInner::Ethiopian(c.date_from_codes(era, year, month_code, day, overflow)?)
             }
             Self::Indian(ref c) => {
-                AnyDateInner::Indian(c.date_from_codes(era, year, month_code, day)?)
+                AnyDateInner::Indian(c.date_from_codes(era, year, month_code, day, overflow)?)
             }
             Self::Coptic(ref c) => {
-                AnyDateInner::Coptic(c.date_from_codes(era, year, month_code, day)?)
+                AnyDateInner::Coptic(c.date_from_codes(era, year, month_code, day, overflow)?)
+            }
+            Self::Chinese(ref c) => {
+                AnyDateInner::Chinese(c.date_from_codes(era, year, month_code, day, overflow)?)
+            }
+            Self::Dangi(ref c) => {
+                AnyDateInner::Dangi(c.date_from_codes(era, year, month_code, day, overflow)?)
+            }
+            Self::Hebrew(ref c) => {
+                AnyDateInner::Hebrew(c.date_from_codes(era, year, month_code, day, overflow)?)
+            }
+            Self::Persian(ref c) => {
+                AnyDateInner::Persian(c.date_from_codes(era, year, month_code, day, overflow)?)
+            }
+            Self::Roc(ref c) => {
+                AnyDateInner::Roc(c.date_from_codes(era, year, month_code, day, overflow)?)
+            }
+            Self::IslamicCivil(ref c) => {
+                AnyDateInner::IslamicCivil(c.date_from_codes(era, year, month_code, day, overflow)?)
+            }
+            Self::IslamicTabular(ref c) => AnyDateInner::IslamicTabular(
+                c.date_from_codes(era, year, month_code, day, overflow)?,
+            ),
+            Self::IslamicUmmAlQura(ref c) => AnyDateInner::IslamicUmmAlQura(
+                c.date_from_codes(era, year, month_code, day, overflow)?,
+            ),
+            Self::Iso(ref c) => {
+                AnyDateInner::Iso(c.date_from_codes(era, year, month_code, day, overflow)?)
             }
-            Self::Iso(ref c) => AnyDateInner::Iso(c.date_from_codes(era, year, month_code, day)?),
         };
         Ok(ret)
     }
@@ -184,6 +266,14 @@ impl Calendar for AnyCalendar {
             Self::Ethiopian(ref c) => AnyDateInner::Ethiopian(c.date_from_iso(iso)),
             Self::Indian(ref c) => AnyDateInner::Indian(c.date_from_iso(iso)),
             Self::Coptic(ref c) => AnyDateInner::Coptic(c.date_from_iso(iso)),
+            Self::Chinese(ref c) => AnyDateInner::Chinese(c.date_from_iso(iso)),
+            Self::Dangi(ref c) => AnyDateInner::Dangi(c.date_from_iso(iso)),
+            Self::Hebrew(ref c) => AnyDateInner::Hebrew(c.date_from_iso(iso)),
+            Self::Persian(ref c) => AnyDateInner::Persian(c.date_from_iso(iso)),
+            Self::Roc(ref c) => AnyDateInner::Roc(c.date_from_iso(iso)),
+            Self::IslamicCivil(ref c) => AnyDateInner::IslamicCivil(c.date_from_iso(iso)),
+            Self::IslamicTabular(ref c) => AnyDateInner::IslamicTabular(c.date_from_iso(iso)),
+            Self::IslamicUmmAlQura(ref c) => AnyDateInner::IslamicUmmAlQura(c.date_from_iso(iso)),
             Self::Iso(ref c) => AnyDateInner::Iso(c.date_from_iso(iso)),
         }
     }
@@ -204,31 +294,60 @@ impl Calendar for AnyCalendar {
         match_cal_and_date!(match (self, date): (c, d) => c.days_in_month(d))
     }
 
-    fn offset_date(&self, date: &mut Self::DateInner, offset: DateDuration<Self>) {
+    fn offset_date(
+        &self,
+        date: &mut Self::DateInner,
+        offset: DateDuration<Self>,
+        overflow: types::Overflow,
+    ) -> Result<(), DateTimeError> {
         match (self, date) {
             (&Self::Gregorian(ref c), &mut AnyDateInner::Gregorian(ref mut d)) => {
-                c.offset_date(d, offset.cast_unit())
+                c.offset_date(d, offset.cast_unit(), overflow)
             }
             (&Self::Buddhist(ref c), &mut AnyDateInner::Buddhist(ref mut d)) => {
-                c.offset_date(d, offset.cast_unit())
+                c.offset_date(d, offset.cast_unit(), overflow)
             }
             (&Self::Japanese(ref c), &mut AnyDateInner::Japanese(ref mut d)) => {
-                c.offset_date(d, offset.cast_unit())
+                c.offset_date(d, offset.cast_unit(), overflow)
             }
             (&Self::JapaneseExtended(ref c), &mut AnyDateInner::JapaneseExtended(ref mut d)) => {
-                c.offset_date(d, offset.cast_unit())
+                c.offset_date(d, offset.cast_unit(), overflow)
             }
             (&Self::Ethiopian(ref c), &mut AnyDateInner::Ethiopian(ref mut d)) => {
-                c.offset_date(d, offset.cast_unit())
+                c.offset_date(d, offset.cast_unit(), overflow)
             }
             (&Self::Indian(ref c), &mut AnyDateInner::Indian(ref mut d)) => {
-                c.offset_date(d, offset.cast_unit())
+                c.offset_date(d, offset.cast_unit(), overflow)
             }
             (&Self::Coptic(ref c), &mut AnyDateInner::Coptic(ref mut d)) => {
-                c.offset_date(d, offset.cast_unit())
+                c.offset_date(d, offset.cast_unit(), overflow)
+            }
+            (&Self::Chinese(ref c), &mut AnyDateInner::Chinese(ref mut d)) => {
+                c.offset_date(d, offset.cast_unit(), overflow)
+            }
+            (&Self::Dangi(ref c), &mut AnyDateInner::Dangi(ref mut d)) => {
+                c.offset_date(d, offset.cast_unit(), overflow)
+            }
+            (&Self::Hebrew(ref c), &mut AnyDateInner::Hebrew(ref mut d)) => {
+                c.offset_date(d, offset.cast_unit(), overflow)
+            }
+            (&Self::Persian(ref c), &mut AnyDateInner::Persian(ref mut d)) => {
+                c.offset_date(d, offset.cast_unit(), overflow)
+            }
+            (&Self::Roc(ref c), &mut AnyDateInner::Roc(ref mut d)) => {
+                c.offset_date(d, offset.cast_unit(), overflow)
+            }
+            (&Self::IslamicCivil(ref c), &mut AnyDateInner::IslamicCivil(ref mut d)) => {
+                c.offset_date(d, offset.cast_unit(), overflow)
+            }
+            (&Self::IslamicTabular(ref c), &mut AnyDateInner::IslamicTabular(ref mut d)) => {
+                c.offset_date(d, offset.cast_unit(), overflow)
+            }
+            (&Self::IslamicUmmAlQura(ref c), &mut AnyDateInner::IslamicUmmAlQura(ref mut d)) => {
+                c.offset_date(d, offset.cast_unit(), overflow)
             }
             (&Self::Iso(ref c), &mut AnyDateInner::Iso(ref mut d)) => {
-                c.offset_date(d, offset.cast_unit())
+                c.offset_date(d, offset.cast_unit(), overflow)
             }
             // This is only reached from misuse of from_raw, a semi-internal api
             #[allow(clippy::panic)]
@@ -305,6 +424,70 @@ impl Calendar for AnyCalendar {
             ) => c1
                 .until(d1, d2, c2, largest_unit, smallest_unit)
                 .cast_unit(),
+            (
+                &Self::Chinese(ref c1),
+                &Self::Chinese(ref c2),
+                &AnyDateInner::Chinese(ref d1),
+                &AnyDateInner::Chinese(ref d2),
+            ) => c1
+                .until(d1, d2, c2, largest_unit, smallest_unit)
+                .cast_unit(),
+            (
+                &Self::Dangi(ref c1),
+                &Self::Dangi(ref c2),
+                &AnyDateInner::Dangi(ref d1),
+                &AnyDateInner::Dangi(ref d2),
+            ) => c1
+                .until(d1, d2, c2, largest_unit, smallest_unit)
+                .cast_unit(),
+            (
+                &Self::Hebrew(ref c1),
+                &Self::Hebrew(ref c2),
+                &AnyDateInner::Hebrew(ref d1),
+                &AnyDateInner::Hebrew(ref d2),
+            ) => c1
+                .until(d1, d2, c2, largest_unit, smallest_unit)
+                .cast_unit(),
+            (
+                &Self::Persian(ref c1),
+                &Self::Persian(ref c2),
+                &AnyDateInner::Persian(ref d1),
+                &AnyDateInner::Persian(ref d2),
+            ) => c1
+                .until(d1, d2, c2, largest_unit, smallest_unit)
+                .cast_unit(),
+            (
+                &Self::Roc(ref c1),
+                &Self::Roc(ref c2),
+                &AnyDateInner::Roc(ref d1),
+                &AnyDateInner::Roc(ref d2),
+            ) => c1
+                .until(d1, d2, c2, largest_unit, smallest_unit)
+                .cast_unit(),
+            (
+                &Self::IslamicCivil(ref c1),
+                &Self::IslamicCivil(ref c2),
+                &AnyDateInner::IslamicCivil(ref d1),
+                &AnyDateInner::IslamicCivil(ref d2),
+            ) => c1
+                .until(d1, d2, c2, largest_unit, smallest_unit)
+                .cast_unit(),
+            (
+                &Self::IslamicTabular(ref c1),
+                &Self::IslamicTabular(ref c2),
+                &AnyDateInner::IslamicTabular(ref d1),
+                &AnyDateInner::IslamicTabular(ref d2),
+            ) => c1
+                .until(d1, d2, c2, largest_unit, smallest_unit)
+                .cast_unit(),
+            (
+                &Self::IslamicUmmAlQura(ref c1),
+                &Self::IslamicUmmAlQura(ref c2),
+                &AnyDateInner::IslamicUmmAlQura(ref d1),
+                &AnyDateInner::IslamicUmmAlQura(ref d2),
+            ) => c1
+                .until(d1, d2, c2, largest_unit, smallest_unit)
+                .cast_unit(),
             (
                 &Self::Iso(ref c1),
                 &Self::Iso(ref c2),
@@ -357,6 +540,14 @@ impl Calendar for AnyCalendar {
             Self::Ethiopian(_) => "AnyCalendar (Ethiopian)",
             Self::Indian(_) => "AnyCalendar (Indian)",
             Self::Coptic(_) => "AnyCalendar (Coptic)",
+            Self::Chinese(_) => "AnyCalendar (Chinese)",
+            Self::Dangi(_) => "AnyCalendar (Dangi)",
+            Self::Hebrew(_) => "AnyCalendar (Hebrew)",
+            Self::Persian(_) => "AnyCalendar (Persian)",
+            Self::Roc(_) => "AnyCalendar (ROC)",
+            Self::IslamicCivil(_) => "AnyCalendar (Islamic (civil))",
+            Self::IslamicTabular(_) => "AnyCalendar (Islamic (tabular))",
+            Self::IslamicUmmAlQura(_) => "AnyCalendar (Islamic (Umm al-Qura))",
             Self::Iso(_) => "AnyCalendar (Iso)",
         }
     }
@@ -393,6 +584,14 @@ impl AnyCalendar {
             ),
             AnyCalendarKind::Indian => AnyCalendar::Indian(Indian),
             AnyCalendarKind::Coptic => AnyCalendar::Coptic(Coptic),
+            AnyCalendarKind::Chinese => AnyCalendar::Chinese(Chinese),
+            AnyCalendarKind::Dangi => AnyCalendar::Dangi(Dangi),
+            AnyCalendarKind::Hebrew => AnyCalendar::Hebrew(Hebrew),
+            AnyCalendarKind::Persian => AnyCalendar::Persian(Persian),
+            AnyCalendarKind::Roc => AnyCalendar::Roc(Roc),
+            AnyCalendarKind::IslamicCivil => AnyCalendar::IslamicCivil(IslamicCivil),
+            AnyCalendarKind::IslamicTabular => AnyCalendar::IslamicTabular(IslamicTabular),
+            AnyCalendarKind::IslamicUmmAlQura => AnyCalendar::IslamicUmmAlQura(IslamicUmmAlQura),
             AnyCalendarKind::Iso => AnyCalendar::Iso(Iso),
             AnyCalendarKind::Ethiopian => AnyCalendar::Ethiopian(Ethiopian::new_with_era_style(
                 EthiopianEraStyle::AmeteMihret,
@@ -432,6 +631,14 @@ impl AnyCalendar {
             ),
             AnyCalendarKind::Indian => AnyCalendar::Indian(Indian),
             AnyCalendarKind::Coptic => AnyCalendar::Coptic(Coptic),
+            AnyCalendarKind::Chinese => AnyCalendar::Chinese(Chinese),
+            AnyCalendarKind::Dangi => AnyCalendar::Dangi(Dangi),
+            AnyCalendarKind::Hebrew => AnyCalendar::Hebrew(Hebrew),
+            AnyCalendarKind::Persian => AnyCalendar::Persian(Persian),
+            AnyCalendarKind::Roc => AnyCalendar::Roc(Roc),
+            AnyCalendarKind::IslamicCivil => AnyCalendar::IslamicCivil(IslamicCivil),
+            AnyCalendarKind::IslamicTabular => AnyCalendar::IslamicTabular(IslamicTabular),
+            AnyCalendarKind::IslamicUmmAlQura => AnyCalendar::IslamicUmmAlQura(IslamicUmmAlQura),
             AnyCalendarKind::Iso => AnyCalendar::Iso(Iso),
             AnyCalendarKind::Ethiopian => AnyCalendar::Ethiopian(Ethiopian::new_with_era_style(
                 EthiopianEraStyle::AmeteMihret,
@@ -467,6 +674,14 @@ impl AnyCalendar {
             }
             AnyCalendarKind::Indian => AnyCalendar::Indian(Indian),
             AnyCalendarKind::Coptic => AnyCalendar::Coptic(Coptic),
+            AnyCalendarKind::Chinese => AnyCalendar::Chinese(Chinese),
+            AnyCalendarKind::Dangi => AnyCalendar::Dangi(Dangi),
+            AnyCalendarKind::Hebrew => AnyCalendar::Hebrew(Hebrew),
+            AnyCalendarKind::Persian => AnyCalendar::Persian(Persian),
+            AnyCalendarKind::Roc => AnyCalendar::Roc(Roc),
+            AnyCalendarKind::IslamicCivil => AnyCalendar::IslamicCivil(IslamicCivil),
+            AnyCalendarKind::IslamicTabular => AnyCalendar::IslamicTabular(IslamicTabular),
+            AnyCalendarKind::IslamicUmmAlQura => AnyCalendar::IslamicUmmAlQura(IslamicUmmAlQura),
             AnyCalendarKind::Iso => AnyCalendar::Iso(Iso),
             AnyCalendarKind::Ethiopian => AnyCalendar::Ethiopian(Ethiopian::new_with_era_style(
                 EthiopianEraStyle::AmeteMihret,
@@ -493,7 +708,8 @@ impl AnyCalendar {
     /// **This method is unstable; the bounds on `P` might expand over time as more calendars are added**
     ///
     /// In case the locale's calendar is unknown or unspecified, it will attempt to load the default
-    /// calendar for the locale, falling back to gregorian.
+    /// calendar for the locale, falling back to gregorian. To use the preferred calendar of the
+    /// locale's region instead, see [`Self::try_new_for_locale_with_preferences_unstable()`].
     ///
     /// For calendars that need data, will attempt to load the appropriate data from the source
     pub fn try_new_for_locale_unstable<P>(
@@ -505,7 +721,64 @@ impl AnyCalendar {
             + DataProvider<crate::provider::JapaneseExtendedErasV1Marker>
             + ?Sized,
     {
-        let kind = AnyCalendarKind::from_data_locale_with_fallback(locale);
+        let kind = AnyCalendarKind::from_data_locale(locale)
+            .unwrap_or_else(|_| AnyCalendarKind::from_data_locale_without_preferences(locale));
+        Self::try_new_unstable(provider, kind)
+    }
+
+    icu_provider::gen_any_buffer_constructors!(
+        locale: include,
+        options: skip,
+        error: DataError,
+        functions: [
+            Self::try_new_for_locale_with_preferences_unstable,
+            try_new_for_locale_with_preferences_with_any_provider,
+            try_new_for_locale_with_preferences_with_buffer_provider
+        ]
+    );
+
+    /// Constructs an AnyCalendar for a given locale and data source, using the calendar
+    /// preferences of the locale's region.
+    ///
+    /// **This method is unstable; the bounds on `P` might expand over time as more calendars are added**
+    ///
+    /// In case the locale's calendar is unknown or unspecified, it will use the first supported
+    /// calendar among the preferred calendars of the locale's region, from the
+    /// `calendar/preferences@1` data key, falling back to gregorian. The region of a `-u-rg`
+    /// keyword takes precedence over the region of the locale. If the provider has no calendar
+    /// preference data, this picks the same calendar as [`Self::try_new_for_locale_unstable()`].
+    ///
+    /// For calendars that need data, will attempt to load the appropriate data from the source
+    ///
+    /// ```
+    /// use icu::calendar::{AnyCalendar, AnyCalendarKind};
+    /// use icu::locid::locale;
+    ///
+    /// let provider = icu_testdata::get_provider();
+    /// let kind = |locale: icu::locid::Locale| {
+    ///     AnyCalendar::try_new_for_locale_with_preferences_with_buffer_provider(
+    ///         &provider,
+    ///         &locale.into(),
+    ///     )
+    ///     .expect("constructing AnyCalendar failed")
+    ///     .kind()
+    /// };
+    ///
+    /// assert_eq!(kind(locale!("fa-IR")), AnyCalendarKind::Persian);
+    /// assert_eq!(kind(locale!("en-u-rg-thzzzz")), AnyCalendarKind::Buddhist);
+    /// assert_eq!(kind(locale!("fa-IR-u-ca-gregory")), AnyCalendarKind::Gregorian);
+    /// ```
+    pub fn try_new_for_locale_with_preferences_unstable<P>(
+        provider: &P,
+        locale: &DataLocale,
+    ) -> Result<Self, DataError>
+    where
+        P: DataProvider<crate::provider::JapaneseErasV1Marker>
+            + DataProvider<crate::provider::JapaneseExtendedErasV1Marker>
+            + DataProvider<crate::provider::CalendarPreferencesV1Marker>
+            + ?Sized,
+    {
+        let kind = AnyCalendarKind::try_from_data_locale_with_preferences(provider, locale)?;
         Self::try_new_unstable(provider, kind)
     }
 
@@ -518,6 +791,14 @@ impl AnyCalendar {
             Self::Ethiopian(_) => "Ethiopian",
             Self::Indian(_) => "Indian",
             Self::Coptic(_) => "Coptic",
+            Self::Chinese(_) => "Chinese",
+            Self::Dangi(_) => "Dangi",
+            Self::Hebrew(_) => "Hebrew",
+            Self::Persian(_) => "Persian",
+            Self::Roc(_) => "ROC",
+            Self::IslamicCivil(_) => "Islamic (civil)",
+            Self::IslamicTabular(_) => "Islamic (tabular)",
+            Self::IslamicUmmAlQura(_) => "Islamic (Umm al-Qura)",
             Self::Iso(_) => "Iso",
         }
     }
@@ -535,6 +816,14 @@ impl AnyCalendar {
                 .expect("Ethiopian calendar known to have an AnyCalendarKind"),
             Self::Indian(_) => AnyCalendarKind::Indian,
             Self::Coptic(_) => AnyCalendarKind::Coptic,
+            Self::Chinese(_) => AnyCalendarKind::Chinese,
+            Self::Dangi(_) => AnyCalendarKind::Dangi,
+            Self::Hebrew(_) => AnyCalendarKind::Hebrew,
+            Self::Persian(_) => AnyCalendarKind::Persian,
+            Self::Roc(_) => AnyCalendarKind::Roc,
+            Self::IslamicCivil(_) => AnyCalendarKind::IslamicCivil,
+            Self::IslamicTabular(_) => AnyCalendarKind::IslamicTabular,
+            Self::IslamicUmmAlQura(_) => AnyCalendarKind::IslamicUmmAlQura,
             Self::Iso(_) => AnyCalendarKind::Iso,
         }
     }
@@ -578,6 +867,14 @@ impl AnyDateInner {
             AnyDateInner::Ethiopian(_) => "Ethiopian",
             AnyDateInner::Indian(_) => "Indian",
             AnyDateInner::Coptic(_) => "Coptic",
+            AnyDateInner::Chinese(_) => "Chinese",
+            AnyDateInner::Dangi(_) => "Dangi",
+            AnyDateInner::Hebrew(_) => "Hebrew",
+            AnyDateInner::Persian(_) => "Persian",
+            AnyDateInner::Roc(_) => "ROC",
+            AnyDateInner::IslamicCivil(_) => "Islamic (civil)",
+            AnyDateInner::IslamicTabular(_) => "Islamic (tabular)",
+            AnyDateInner::IslamicUmmAlQura(_) => "Islamic (Umm al-Qura)",
             AnyDateInner::Iso(_) => "Iso",
         }
     }
@@ -603,6 +900,22 @@ pub enum AnyCalendarKind {
     Indian,
     /// The kind of a [`Coptic`] calendar
     Coptic,
+    /// The kind of a [`Chinese`] calendar
+    Chinese,
+    /// The kind of a [`Dangi`] calendar
+    Dangi,
+    /// The kind of a [`Hebrew`] calendar
+    Hebrew,
+    /// The kind of a [`Persian`] calendar
+    Persian,
+    /// The kind of a [`Roc`] calendar
+    Roc,
+    /// The kind of an [`IslamicCivil`] calendar
+    IslamicCivil,
+    /// The kind of an [`IslamicTabular`] calendar
+    IslamicTabular,
+    /// The kind of an [`IslamicUmmAlQura`] calendar
+    IslamicUmmAlQura,
     /// The kind of an [`Iso`] calendar
     Iso,
 }
@@ -619,6 +932,14 @@ impl AnyCalendarKind {
             "japanext" => AnyCalendarKind::JapaneseExtended,
             "indian" => AnyCalendarKind::Indian,
             "coptic" => AnyCalendarKind::Coptic,
+            "chinese" => AnyCalendarKind::Chinese,
+            "dangi" => AnyCalendarKind::Dangi,
+            "hebrew" => AnyCalendarKind::Hebrew,
+            "persian" => AnyCalendarKind::Persian,
+            "roc" => AnyCalendarKind::Roc,
+            "islamic-civil" | "islamicc" => AnyCalendarKind::IslamicCivil,
+            "islamic-tbla" => AnyCalendarKind::IslamicTabular,
+            "islamic-umalqura" => AnyCalendarKind::IslamicUmmAlQura,
             "iso" => AnyCalendarKind::Iso,
             "ethiopic" => AnyCalendarKind::Ethiopian,
             "ethioaa" => AnyCalendarKind::EthiopianAmeteAlem,
@@ -642,6 +963,24 @@ impl AnyCalendarKind {
             AnyCalendarKind::Indian
         } else if *x == value!("coptic") {
             AnyCalendarKind::Coptic
+        } else if *x == value!("chinese") {
+            AnyCalendarKind::Chinese
+        } else if *x == value!("dangi") {
+            AnyCalendarKind::Dangi
+        } else if *x == value!("hebrew") {
+            AnyCalendarKind::Hebrew
+        } else if *x == value!("persian") {
+            AnyCalendarKind::Persian
+        } else if *x == value!("roc") {
+            AnyCalendarKind::Roc
+        } else if x.as_tinystr_slice() == [tinystr!(8, "islamic"), tinystr!(8, "civil")]
+            || *x == value!("islamicc")
+        {
+            AnyCalendarKind::IslamicCivil
+        } else if x.as_tinystr_slice() == [tinystr!(8, "islamic"), tinystr!(8, "tbla")] {
+            AnyCalendarKind::IslamicTabular
+        } else if x.as_tinystr_slice() == [tinystr!(8, "islamic"), tinystr!(8, "umalqura")] {
+            AnyCalendarKind::IslamicUmmAlQura
         } else if *x == value!("iso") {
             AnyCalendarKind::Iso
         } else if *x == value!("ethiopic") {
@@ -665,6 +1004,14 @@ impl AnyCalendarKind {
             AnyCalendarKind::JapaneseExtended => "japanext",
             AnyCalendarKind::Indian => "indian",
             AnyCalendarKind::Coptic => "coptic",
+            AnyCalendarKind::Chinese => "chinese",
+            AnyCalendarKind::Dangi => "dangi",
+            AnyCalendarKind::Hebrew => "hebrew",
+            AnyCalendarKind::Persian => "persian",
+            AnyCalendarKind::Roc => "roc",
+            AnyCalendarKind::IslamicCivil => "islamic-civil",
+            AnyCalendarKind::IslamicTabular => "islamic-tbla",
+            AnyCalendarKind::IslamicUmmAlQura => "islamic-umalqura",
             AnyCalendarKind::Iso => "iso",
             AnyCalendarKind::Ethiopian => "ethiopic",
             AnyCalendarKind::EthiopianAmeteAlem => "ethioaa",
@@ -680,6 +1027,19 @@ impl AnyCalendarKind {
             AnyCalendarKind::JapaneseExtended => value!("japanext"),
             AnyCalendarKind::Indian => value!("indian"),
             AnyCalendarKind::Coptic => value!("coptic"),
+            AnyCalendarKind::Chinese => value!("chinese"),
+            AnyCalendarKind::Dangi => value!("dangi"),
+            AnyCalendarKind::Hebrew => value!("hebrew"),
+            AnyCalendarKind::Persian => value!("persian"),
+            AnyCalendarKind::Roc => value!("roc"),
+            // `value!` only supports values with a single subtag
+            AnyCalendarKind::IslamicCivil
+            | AnyCalendarKind::IslamicTabular
+            | AnyCalendarKind::IslamicUmmAlQura => {
+                #[allow(clippy::expect_used)] // the BCP-47 strings are valid values
+                Value::from_bytes(self.as_bcp47_string().as_bytes())
+                    .expect("Islamic calendar identifiers are valid values")
+            }
             AnyCalendarKind::Iso => value!("iso"),
             AnyCalendarKind::Ethiopian => value!("ethiopic"),
             AnyCalendarKind::EthiopianAmeteAlem => value!("ethioaa"),
@@ -715,23 +1075,65 @@ impl AnyCalendarKind {
             .and_then(|v| Self::from_bcp47(&v))
     }
 
-    // Do not make public, this will eventually need fallback
-    // data from the provider
-    fn from_data_locale_with_fallback(l: &DataLocale) -> Self {
+    /// Resolve the calendar kind of a [`DataLocale`], using the first supported calendar among the
+    /// preferred calendars of its region if it does not specify a known one
+    ///
+    /// The region of a `-u-rg` keyword takes precedence over the region of the locale.
+    fn try_from_data_locale_with_preferences<P>(
+        provider: &P,
+        l: &DataLocale,
+    ) -> Result<Self, DataError>
+    where
+        P: DataProvider<crate::provider::CalendarPreferencesV1Marker> + ?Sized,
+    {
         if let Ok(kind) = Self::from_data_locale(l) {
-            kind
+            return Ok(kind);
+        }
+        let mut locale = DataLocale::from(l.get_langid());
+        if let Some(region) = Self::region_override(l) {
+            locale.set_region(Some(region));
+        }
+        let response = provider.load(DataRequest {
+            locale: &locale,
+            metadata: Default::default(),
+        });
+        match response.and_then(DataResponse::take_payload) {
+            Ok(preferences) => Ok(preferences
+                .get()
+                .calendars
+                .iter()
+                .find_map(|calendar| Self::from_bcp47_string(&calendar))
+                .unwrap_or(Self::Gregorian)),
+            // Providers without calendar preference data get the previous, language-based defaults
+            Err(DataError {
+                kind: DataErrorKind::MissingDataKey | DataErrorKind::MissingLocale,
+                ..
+            }) => Ok(Self::from_data_locale_without_preferences(l)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Extract the region of the `-u-rg` keyword of a [`DataLocale`], such as `SA` from `sazzzz`
+    fn region_override(l: &DataLocale) -> Option<Region> {
+        let rg = l.get_unicode_ext(&key!("rg"))?;
+        let subdivision = match rg.as_tinystr_slice() {
+            [subdivision] => subdivision.as_str(),
+            _ => return None,
+        };
+        // The region is either two letters or three digits, followed by the subdivision code
+        let len = if subdivision.starts_with(|c: char| c.is_ascii_digit()) {
+            3
         } else {
-            let lang = l.language();
-            if lang == language!("th") {
-                Self::Buddhist
-            // Other known fallback routes for currently-unsupported calendars
-            // } else if lang == langugage!("sa") {
-            //     Self::IslamicUmalqura
-            // } else if lang == language!("af") || lang == langugage!("ir") {
-            //     Self::Persian
-            } else {
-                Self::Gregorian
-            }
+            2
+        };
+        Region::from_bytes(subdivision.as_bytes().get(..len)?).ok()
+    }
+
+    fn from_data_locale_without_preferences(l: &DataLocale) -> Self {
+        if l.language() == language!("th") {
+            Self::Buddhist
+        } else {
+            Self::Gregorian
         }
     }
 }
@@ -850,6 +1252,102 @@ impl IntoAnyCalendar for Coptic {
     }
 }
 
+impl IntoAnyCalendar for Chinese {
+    fn to_any(self) -> AnyCalendar {
+        AnyCalendar::Chinese(Chinese)
+    }
+    fn to_any_cloned(&self) -> AnyCalendar {
+        AnyCalendar::Chinese(Chinese)
+    }
+    fn date_to_any(&self, d: &Self::DateInner) -> AnyDateInner {
+        AnyDateInner::Chinese(*d)
+    }
+}
+
+impl IntoAnyCalendar for Dangi {
+    fn to_any(self) -> AnyCalendar {
+        AnyCalendar::Dangi(Dangi)
+    }
+    fn to_any_cloned(&self) -> AnyCalendar {
+        AnyCalendar::Dangi(Dangi)
+    }
+    fn date_to_any(&self, d: &Self::DateInner) -> AnyDateInner {
+        AnyDateInner::Dangi(*d)
+    }
+}
+
+impl IntoAnyCalendar for Hebrew {
+    fn to_any(self) -> AnyCalendar {
+        AnyCalendar::Hebrew(Hebrew)
+    }
+    fn to_any_cloned(&self) -> AnyCalendar {
+        AnyCalendar::Hebrew(Hebrew)
+    }
+    fn date_to_any(&self, d: &Self::DateInner) -> AnyDateInner {
+        AnyDateInner::Hebrew(*d)
+    }
+}
+
+impl IntoAnyCalendar for Persian {
+    fn to_any(self) -> AnyCalendar {
+        AnyCalendar::Persian(Persian)
+    }
+    fn to_any_cloned(&self) -> AnyCalendar {
+        AnyCalendar::Persian(Persian)
+    }
+    fn date_to_any(&self, d: &Self::DateInner) -> AnyDateInner {
+        AnyDateInner::Persian(*d)
+    }
+}
+
+impl IntoAnyCalendar for Roc {
+    fn to_any(self) -> AnyCalendar {
+        AnyCalendar::Roc(Roc)
+    }
+    fn to_any_cloned(&self) -> AnyCalendar {
+        AnyCalendar::Roc(Roc)
+    }
+    fn date_to_any(&self, d: &Self::DateInner) -> AnyDateInner {
+        AnyDateInner::Roc(*d)
+    }
+}
+
+impl IntoAnyCalendar for IslamicCivil {
+    fn to_any(self) -> AnyCalendar {
+        AnyCalendar::IslamicCivil(IslamicCivil)
+    }
+    fn to_any_cloned(&self) -> AnyCalendar {
+        AnyCalendar::IslamicCivil(IslamicCivil)
+    }
+    fn date_to_any(&self, d: &Self::DateInner) -> AnyDateInner {
+        AnyDateInner::IslamicCivil(*d)
+    }
+}
+
+impl IntoAnyCalendar for IslamicTabular {
+    fn to_any(self) -> AnyCalendar {
+        AnyCalendar::IslamicTabular(IslamicTabular)
+    }
+    fn to_any_cloned(&self) -> AnyCalendar {
+        AnyCalendar::IslamicTabular(IslamicTabular)
+    }
+    fn date_to_any(&self, d: &Self::DateInner) -> AnyDateInner {
+        AnyDateInner::IslamicTabular(*d)
+    }
+}
+
+impl IntoAnyCalendar for IslamicUmmAlQura {
+    fn to_any(self) -> AnyCalendar {
+        AnyCalendar::IslamicUmmAlQura(IslamicUmmAlQura)
+    }
+    fn to_any_cloned(&self) -> AnyCalendar {
+        AnyCalendar::IslamicUmmAlQura(IslamicUmmAlQura)
+    }
+    fn date_to_any(&self, d: &Self::DateInner) -> AnyDateInner {
+        AnyDateInner::IslamicUmmAlQura(*d)
+    }
+}
+
 impl IntoAnyCalendar for Iso {
     fn to_any(self) -> AnyCalendar {
         AnyCalendar::Iso(Iso)
@@ -942,6 +1440,11 @@ mod tests {
         let buddhist =
             AnyCalendar::try_new_with_buffer_provider(&provider, AnyCalendarKind::Buddhist)
                 .expect("Calendar construction must succeed");
+        let chinese =
+            AnyCalendar::try_new_with_buffer_provider(&provider, AnyCalendarKind::Chinese)
+                .expect("Calendar construction must succeed");
+        let dangi = AnyCalendar::try_new_with_buffer_provider(&provider, AnyCalendarKind::Dangi)
+            .expect("Calendar construction must succeed");
         let coptic = AnyCalendar::try_new_with_buffer_provider(&provider, AnyCalendarKind::Coptic)
             .expect("Calendar construction must succeed");
         let ethiopian =
@@ -955,22 +1458,46 @@ mod tests {
         let gregorian =
             AnyCalendar::try_new_with_buffer_provider(&provider, AnyCalendarKind::Gregorian)
                 .expect("Calendar construction must succeed");
+        let hebrew = AnyCalendar::try_new_with_buffer_provider(&provider, AnyCalendarKind::Hebrew)
+            .expect("Calendar construction must succeed");
         let indian = AnyCalendar::try_new_with_buffer_provider(&provider, AnyCalendarKind::Indian)
             .expect("Calendar construction must succeed");
+        let islamic_civil =
+            AnyCalendar::try_new_with_buffer_provider(&provider, AnyCalendarKind::IslamicCivil)
+                .expect("Calendar construction must succeed");
+        let islamic_tabular =
+            AnyCalendar::try_new_with_buffer_provider(&provider, AnyCalendarKind::IslamicTabular)
+                .expect("Calendar construction must succeed");
+        let islamic_umm_al_qura =
+            AnyCalendar::try_new_with_buffer_provider(&provider, AnyCalendarKind::IslamicUmmAlQura)
+                .expect("Calendar construction must succeed");
         let japanese =
             AnyCalendar::try_new_with_buffer_provider(&provider, AnyCalendarKind::Japanese)
                 .expect("Calendar construction must succeed");
         let japanext =
             AnyCalendar::try_new_with_buffer_provider(&provider, AnyCalendarKind::JapaneseExtended)
                 .expect("Calendar construction must succeed");
+        let persian =
+            AnyCalendar::try_new_with_buffer_provider(&provider, AnyCalendarKind::Persian)
+                .expect("Calendar construction must succeed");
+        let roc = AnyCalendar::try_new_with_buffer_provider(&provider, AnyCalendarKind::Roc)
+            .expect("Calendar construction must succeed");
         let buddhist = Ref(&buddhist);
+        let chinese = Ref(&chinese);
         let coptic = Ref(&coptic);
+        let dangi = Ref(&dangi);
         let ethiopian = Ref(&ethiopian);
         let ethioaa = Ref(&ethioaa);
         let gregorian = Ref(&gregorian);
+        let hebrew = Ref(&hebrew);
         let indian = Ref(&indian);
+        let islamic_civil = Ref(&islamic_civil);
+        let islamic_tabular = Ref(&islamic_tabular);
+        let islamic_umm_al_qura = Ref(&islamic_umm_al_qura);
         let japanese = Ref(&japanese);
         let japanext = Ref(&japanext);
+        let persian = Ref(&persian);
+        let roc = Ref(&roc);
 
         single_test_roundtrip(buddhist, "be", 100, "M03", 1);
         single_test_roundtrip(buddhist, "be", 2000, "M03", 1);
@@ -1051,6 +1578,66 @@ mod tests {
             DateTimeError::UnknownMonthCode("M13".parse().unwrap(), "Gregorian"),
         );
 
+        single_test_roundtrip(chinese, "chinese", 4660, "M02", 30);
+        single_test_roundtrip(chinese, "chinese", 4660, "M02L", 1);
+        single_test_roundtrip(chinese, "chinese", 4661, "M12", 1);
+        single_test_error(
+            chinese,
+            "chinese",
+            4661,
+            "M02L",
+            1,
+            DateTimeError::UnknownMonthCode("M02L".parse().unwrap(), "Chinese"),
+        );
+        single_test_error(
+            chinese,
+            "chinese",
+            4661,
+            "M01",
+            30,
+            DateTimeError::OutOfRange,
+        );
+
+        single_test_roundtrip(dangi, "dangi", 4356, "M02L", 1);
+        single_test_roundtrip(dangi, "dangi", 4357, "M01", 1);
+        single_test_error(
+            dangi,
+            "chinese",
+            4356,
+            "M01",
+            1,
+            DateTimeError::UnknownEra("chinese".parse().unwrap(), "Dangi"),
+        );
+
+        single_test_roundtrip(hebrew, "am", 5783, "M06", 1);
+        single_test_roundtrip(hebrew, "am", 5784, "M05L", 1);
+        single_test_roundtrip(hebrew, "am", 5784, "M06", 29);
+        single_test_error(
+            hebrew,
+            "am",
+            5783,
+            "M05L",
+            1,
+            DateTimeError::UnknownMonthCode("M05L".parse().unwrap(), "Hebrew"),
+        );
+        single_test_error(hebrew, "am", 5784, "M06", 30, DateTimeError::OutOfRange);
+        single_test_error(
+            hebrew,
+            "am",
+            5784,
+            "M06L",
+            1,
+            DateTimeError::UnknownMonthCode("M06L".parse().unwrap(), "Hebrew"),
+        );
+        single_test_error(
+            hebrew,
+            "ce",
+            5784,
+            "M01",
+            1,
+            DateTimeError::UnknownEra("ce".parse().unwrap(), "Hebrew"),
+        );
+
         single_test_roundtrip(indian, "saka", 100, "M03", 1);
         single_test_roundtrip(indian, "saka", 2000, "M12", 1);
         single_test_roundtrip(indian, "saka", -100, "M03", 1);
@@ -1071,6 +1658,62 @@ mod tests {
         single_test_error(japanese, "ce", 0, "M03", 1, DateTimeError::OutOfRange);
         single_test_error(japanese, "bce", 0, "M03", 1, DateTimeError::OutOfRange);
 
+        single_test_roundtrip(islamic_civil, "ah", 1444, "M09", 1);
+        single_test_roundtrip(islamic_civil, "ah", -100, "M12", 29);
+        single_test_roundtrip(islamic_tabular, "ah", 1444, "M09", 1);
+        single_test_roundtrip(islamic_umm_al_qura, "ah", 1444, "M09", 1);
+        single_test_roundtrip(islamic_umm_al_qura, "ah", 1700, "M12", 29);
+        single_test_error(
+            islamic_umm_al_qura,
+            "ah",
+            1444,
+            "M13",
+            1,
+            DateTimeError::UnknownMonthCode("M13".parse().unwrap(), "Islamic (Umm al-Qura)"),
+        );
+        single_test_error(
+            islamic_umm_al_qura,
+            "ah",
+            1444,
+            "M09",
+            30,
+            DateTimeError::OutOfRange,
+        );
+        single_test_error(
+            islamic_civil,
+            "ce",
+            1444,
+            "M01",
+            1,
+            DateTimeError::UnknownEra("ce".parse().unwrap(), "Islamic (civil)"),
+        );
+
+        single_test_roundtrip(persian, "ap", 1403, "M12", 30);
+        single_test_roundtrip(persian, "ap", -100, "M01", 1);
+        single_test_error(persian, "ap", 1404, "M12", 30, DateTimeError::OutOfRange);
+        single_test_error(
+            persian,
+            "ah",
+            1403,
+            "M01",
+            1,
+            DateTimeError::UnknownEra("ah".parse().unwrap(), "Persian"),
+        );
+
+        single_test_roundtrip(roc, "roc", 113, "M03", 1);
+        single_test_roundtrip(roc, "roc", 1, "M01", 1);
+        single_test_roundtrip(roc, "roc-inverse", 1, "M12", 31);
+        single_test_roundtrip(roc, "roc-inverse", 100, "M03", 1);
+        single_test_error(roc, "roc", 0, "M03", 1, DateTimeError::OutOfRange);
+        single_test_error(
+            roc,
+            "roc",
+            113,
+            "M13",
+            1,
+            DateTimeError::UnknownMonthCode("M13".parse().unwrap(), "ROC"),
+        );
+
         single_test_error(
             japanese,
             "reiwa",
@@ -1101,4 +1744,66 @@ mod tests {
             ),
         );
     }
+
+    #[test]
+    fn test_calendar_preferences() {
+        use crate::provider::{CalendarPreferencesV1, CalendarPreferencesV1Marker};
+        use icu_locid::locale;
+        use zerovec::ZeroVec;
+
+        // Provides the preferences of IR and SA, without any fallback
+        struct PreferencesProvider;
+        impl DataProvider<CalendarPreferencesV1Marker> for PreferencesProvider {
+            fn load(
+                &self,
+                req: DataRequest,
+            ) -> Result<DataResponse<CalendarPreferencesV1Marker>, DataError> {
+                let calendars: &[_] = match req.locale.region().as_ref().map(|r| r.as_str()) {
+                    Some("IR") => &[tinystr!(16, "persian"), tinystr!(16, "gregory")],
+                    // islamic is not supported and is skipped
+                    Some("SA") => &[tinystr!(16, "islamic"), tinystr!(16, "islamic-umalqura")],
+                    _ => {
+                        return Err(DataErrorKind::MissingLocale
+                            .with_req(CalendarPreferencesV1Marker::KEY, req))
+                    }
+                };
+                Ok(DataResponse {
+                    metadata: Default::default(),
+                    payload: Some(DataPayload::from_owned(CalendarPreferencesV1 {
+                        calendars: ZeroVec::alloc_from_slice(calendars),
+                    })),
+                })
+            }
+        }
+
+        let kind = |locale: Locale| {
+            AnyCalendarKind::try_from_data_locale_with_preferences(
+                &PreferencesProvider,
+                &locale.into(),
+            )
+            .unwrap()
+        };
+
+        assert_eq!(kind(locale!("fa-IR")), AnyCalendarKind::Persian);
+        assert_eq!(kind(locale!("ar-SA")), AnyCalendarKind::IslamicUmmAlQura);
+        assert_eq!(kind(locale!("fa-IR-u-ca-roc")), AnyCalendarKind::Roc);
+        // Unknown calendars in the locale are ignored
+        assert_eq!(
+            kind(locale!("fa-IR-u-ca-unknown")),
+            AnyCalendarKind::Persian
+        );
+        // The region override takes precedence over the region of the locale
+        assert_eq!(kind(locale!("en-US-u-rg-irzzzz")), AnyCalendarKind::Persian);
+        assert_eq!(
+            kind(locale!("en-u-rg-sazzzz")),
+            AnyCalendarKind::IslamicUmmAlQura
+        );
+        assert_eq!(
+            kind(locale!("fa-IR-u-rg-uszzzz")),
+            AnyCalendarKind::Gregorian
+        );
+        // Without data, the language-based defaults are used
+        assert_eq!(kind(locale!("th")), AnyCalendarKind::Buddhist);
+        assert_eq!(kind(locale!("en-US")), AnyCalendarKind::Gregorian);
+    }
 }
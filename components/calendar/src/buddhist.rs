@@ -69,13 +69,14 @@ impl Calendar for Buddhist {
         year: i32,
         month_code: types::MonthCode,
         day: u8,
+        overflow: types::Overflow,
     ) -> Result<Self::DateInner, DateTimeError> {
         if era.0 != tinystr!(16, "be") {
             return Err(DateTimeError::UnknownEra(era.0, self.debug_name()));
         }
         let year = year - BUDDHIST_ERA_OFFSET;
 
-        ArithmeticDate::new_from_solar(self, year, month_code, day).map(IsoDateInner)
+        ArithmeticDate::new_from_solar(self, year, month_code, day, overflow).map(IsoDateInner)
     }
     fn date_from_iso(&self, iso: Date<Iso>) -> IsoDateInner {
         *iso.inner()
@@ -97,11 +98,15 @@ impl Calendar for Buddhist {
         Iso.days_in_month(date)
     }
 
-    fn offset_date(&self, date: &mut Self::DateInner, offset: DateDuration<Self>) {
-        Iso.offset_date(date, offset.cast_unit())
+    fn offset_date(
+        &self,
+        date: &mut Self::DateInner,
+        offset: DateDuration<Self>,
+        overflow: types::Overflow,
+    ) -> Result<(), DateTimeError> {
+        Iso.offset_date(date, offset.cast_unit(), overflow)
     }
 
-    #[allow(clippy::field_reassign_with_default)] // it's more clear this way
     fn until(
         &self,
         date1: &Self::DateInner,
@@ -215,6 +220,7 @@ fn iso_year_as_buddhist(year: i32) -> types::FormattableYear {
     types::FormattableYear {
         era: types::Era(tinystr!(16, "be")),
         number: buddhist_year,
+        cyclic: None,
         related_iso: None,
     }
 }
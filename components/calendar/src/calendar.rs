@@ -20,12 +20,16 @@ pub trait Calendar {
     /// The internal type used to represent dates
     type DateInner: PartialEq + Eq + Clone + fmt::Debug;
     /// Construct a date from era/month codes and fields
+    ///
+    /// A day past the end of the month, or a leap month code for a year that doesn't have
+    /// that leap month, is constrained or rejected according to `overflow`.
     fn date_from_codes(
         &self,
         era: types::Era,
         year: i32,
         month_code: types::MonthCode,
         day: u8,
+        overflow: types::Overflow,
     ) -> Result<Self::DateInner, DateTimeError>;
     /// Construct the date from an ISO date
     fn date_from_iso(&self, iso: Date<Iso>) -> Self::DateInner;
@@ -52,10 +56,18 @@ pub trait Calendar {
 
     #[doc(hidden)] // unstable
     /// Add `offset` to `date`
-    fn offset_date(&self, date: &mut Self::DateInner, offset: DateDuration<Self>);
+    ///
+    /// If the date after adding the years and months of `offset` doesn't exist, it is
+    /// constrained, or an error is returned and `date` is left unchanged, according to
+    /// `overflow`.
+    fn offset_date(
+        &self,
+        date: &mut Self::DateInner,
+        offset: DateDuration<Self>,
+        overflow: types::Overflow,
+    ) -> Result<(), DateTimeError>;
 
-    #[doc(hidden)] // unstable
-    /// Calculate `date2 - date` as a duration
+    /// Calculate `date2 - date1` as a duration, balanced as described in [`Date::until`]
     ///
     /// `calendar2` is the calendar object associated with `date2`. In case the specific calendar objects
     /// differ on data, the data for the first calendar is used, and `date2` may be converted if necessary.
@@ -2,7 +2,9 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
-use crate::{types, Calendar, DateDuration, DateDurationUnit, DateTimeError};
+use crate::types::{self, Overflow};
+use crate::{Calendar, DateDuration, DateDurationUnit, DateTimeError};
+use core::cmp::Ordering;
 use core::convert::TryInto;
 use core::marker::PhantomData;
 use tinystr::tinystr;
@@ -22,6 +24,21 @@ pub trait CalendarArithmetic: Calendar {
     fn month_days(year: i32, month: u8) -> u8;
     fn months_for_every_year(year: i32) -> u8;
     fn is_leap_year(year: i32) -> bool;
+
+    /// The ordinal month of `year` with the same month code as the ordinal month `month` of
+    /// `from_year`, used when adding years to a date.
+    ///
+    /// If `year` doesn't have that month, this returns `Err` with the month it is constrained
+    /// to, as in Temporal. Calendars with leap months need to override this.
+    fn month_with_same_code(from_year: i32, month: u8, year: i32) -> Result<u8, u8> {
+        let _ = from_year;
+        let months = Self::months_for_every_year(year);
+        if month <= months {
+            Ok(month)
+        } else {
+            Err(months)
+        }
+    }
 }
 
 impl<C: CalendarArithmetic> ArithmeticDate<C> {
@@ -53,47 +70,77 @@ impl<C: CalendarArithmetic> ArithmeticDate<C> {
     }
 
     #[inline]
-    fn offset_months(&mut self, mut month_offset: i32) {
-        while month_offset != 0 {
-            let year_months = C::months_for_every_year(self.year);
-            if self.month as i32 + month_offset > year_months as i32 {
-                self.year += 1;
-                month_offset -= year_months as i32;
-            } else if self.month as i32 + month_offset < 1 {
-                self.year -= 1;
-                month_offset += C::months_for_every_year(self.year) as i32;
-            } else {
-                self.month = (self.month as i32 + month_offset) as u8;
-                month_offset = 0
-            }
+    fn offset_months(&mut self, month_offset: i32) {
+        // Count from the first month of the year, since years can have different numbers of months
+        let mut month_offset = month_offset + self.month as i32 - 1;
+        while month_offset >= C::months_for_every_year(self.year) as i32 {
+            month_offset -= C::months_for_every_year(self.year) as i32;
+            self.year += 1;
+        }
+        while month_offset < 0 {
+            self.year -= 1;
+            month_offset += C::months_for_every_year(self.year) as i32;
         }
+        self.month = month_offset as u8 + 1;
     }
 
+    /// Adds `years` and then `months` to this date, keeping the month code when adding
+    /// years and constraining the day to the end of the resulting month.
+    ///
+    /// Also returns whether the month code or the day had to be constrained.
     #[inline]
-    pub fn offset_date(&mut self, offset: DateDuration<C>) {
-        // For offset_date to work with lunar calendars, need to handle an edge case where the original month is not valid in the future year.
-        self.year += offset.years;
+    fn constrained_years_months(&self, years: i32, months: i32) -> (Self, bool) {
+        let mut date = Self::new(self.year, self.month, self.day);
+        let mut constrained = false;
+        if years != 0 {
+            date.year += years;
+            date.month = match C::month_with_same_code(self.year, self.month, date.year) {
+                Ok(month) => month,
+                Err(month) => {
+                    constrained = true;
+                    month
+                }
+            };
+        }
+        date.offset_months(months);
+        let month_days = C::month_days(date.year, date.month);
+        if date.day > month_days {
+            date.day = month_days;
+            constrained = true;
+        }
+        (date, constrained)
+    }
 
-        self.offset_months(offset.months);
+    /// Adds `offset` to this date, with the years and months added first, as in Temporal.
+    ///
+    /// With [`Overflow::Reject`], this returns an error and leaves the date unchanged if the
+    /// intermediate date after adding years and months doesn't exist.
+    #[inline]
+    pub fn offset_date(
+        &mut self,
+        offset: DateDuration<C>,
+        overflow: Overflow,
+    ) -> Result<(), DateTimeError> {
+        let (date, constrained) = self.constrained_years_months(offset.years, offset.months);
+        if constrained && overflow == Overflow::Reject {
+            return Err(DateTimeError::OutOfRange);
+        }
+        *self = date;
 
         let day_offset = offset.days + offset.weeks * 7 + self.day as i32 - 1;
         self.day = 1;
         self.offset_days(day_offset);
+        Ok(())
     }
 
     #[inline]
     pub fn until(
         &self,
-        date2: ArithmeticDate<C>,
-        _largest_unit: DateDurationUnit,
-        _smaller_unti: DateDurationUnit,
+        other: ArithmeticDate<C>,
+        largest_unit: DateDurationUnit,
+        smallest_unit: DateDurationUnit,
     ) -> DateDuration<C> {
-        DateDuration::new(
-            self.year - date2.year,
-            self.month as i32 - date2.month as i32,
-            0,
-            self.day as i32 - date2.day as i32,
-        )
+        until(self, &other, largest_unit, smallest_unit)
     }
 
     #[inline]
@@ -187,7 +234,7 @@ impl<C: CalendarArithmetic> ArithmeticDate<C> {
     }
 
     /// Construct a new arithmetic date from a year, month code, and day, bounds checking
-    /// the month
+    /// the month, and constraining or rejecting the day according to `overflow`
     pub fn new_from_solar<C2: Calendar>(
         // Separate type since the debug_name() impl may differ when DateInner types
         // are nested (e.g. in GregorianDateInner)
@@ -195,6 +242,7 @@ impl<C: CalendarArithmetic> ArithmeticDate<C> {
         year: i32,
         month_code: types::MonthCode,
         day: u8,
+        overflow: Overflow,
     ) -> Result<Self, DateTimeError> {
         let month = if let Some(ordinal) = ordinal_solar_month_from_code(month_code) {
             ordinal
@@ -212,11 +260,148 @@ impl<C: CalendarArithmetic> ArithmeticDate<C> {
             ));
         }
 
-        if day > C::month_days(year, month) {
-            return Err(DateTimeError::OutOfRange);
+        Ok(Self::new(
+            year,
+            month,
+            constrain_day(day, C::month_days(year, month), overflow)?,
+        ))
+    }
+}
+
+impl<C: CalendarArithmetic> UntilDate for ArithmeticDate<C> {
+    fn compare(&self, other: &Self) -> Ordering {
+        (self.year, self.month, self.day).cmp(&(other.year, other.month, other.day))
+    }
+
+    fn year(&self) -> i32 {
+        self.year
+    }
+
+    fn months_until(&self, other: &Self) -> i32 {
+        let (start, end, sign) = if self.year <= other.year {
+            (self.year, other.year, 1)
+        } else {
+            (other.year, self.year, -1)
+        };
+        (start..end)
+            .map(|year| C::months_for_every_year(year) as i32)
+            .sum::<i32>()
+            * sign
+            + other.month as i32
+            - self.month as i32
+    }
+
+    fn added_years_months(&self, years: i32, months: i32) -> Self {
+        self.constrained_years_months(years, months).0
+    }
+
+    fn days_until(&self, other: &Self) -> i32 {
+        let mut days = other.day_of_year() as i32 - self.day_of_year() as i32;
+        for year in other.year..self.year {
+            days -= Self::new(year, 1, 1).days_in_year() as i32;
+        }
+        for year in self.year..other.year {
+            days += Self::new(year, 1, 1).days_in_year() as i32;
         }
+        days
+    }
+}
+
+/// A date that [`until()`] can compute durations between
+pub(crate) trait UntilDate: Sized {
+    /// The chronological ordering of `self` and `other`
+    fn compare(&self, other: &Self) -> Ordering;
+    /// The extended year of this date
+    fn year(&self) -> i32;
+    /// The number of months between the start of the months of `self` and `other`
+    fn months_until(&self, other: &Self) -> i32;
+    /// Adds `years` and then `months` to this date, keeping the month code when adding years
+    /// and constraining the day to the resulting month, as in Temporal
+    fn added_years_months(&self, years: i32, months: i32) -> Self;
+    /// The number of days from `self` to `other`
+    fn days_until(&self, other: &Self) -> i32;
+}
+
+/// The duration from `from` to `to`, in units from `largest_unit` down to `smallest_unit`, as
+/// in Temporal's `CalendarDateUntil`. The units smaller than `smallest_unit` are truncated.
+pub(crate) fn until<D: UntilDate, C: Calendar>(
+    from: &D,
+    to: &D,
+    largest_unit: DateDurationUnit,
+    smallest_unit: DateDurationUnit,
+) -> DateDuration<C> {
+    let sign = match to.compare(from) {
+        Ordering::Greater => 1,
+        Ordering::Less => -1,
+        Ordering::Equal => return DateDuration::default(),
+    };
+    // Whether `date` is past `to` in the direction of `sign`
+    let surpasses = |date: &D| date.compare(to) == to.compare(from);
+    // A `smallest_unit` larger than `largest_unit` extends `largest_unit`
+    let largest = unit_size(largest_unit).max(unit_size(smallest_unit));
+    let smallest = unit_size(smallest_unit);
+
+    let mut duration = DateDuration::default();
+    if largest >= unit_size(DateDurationUnit::Months) {
+        if largest == unit_size(DateDurationUnit::Years) {
+            duration.years = to.year() - from.year();
+            if surpasses(&from.added_years_months(duration.years, 0)) {
+                duration.years -= sign;
+            }
+        }
+        duration.months = from.added_years_months(duration.years, 0).months_until(to);
+        if surpasses(&from.added_years_months(duration.years, duration.months)) {
+            duration.months -= sign;
+        }
+    }
 
-        Ok(Self::new(year, month, day))
+    duration.days = from
+        .added_years_months(duration.years, duration.months)
+        .days_until(to);
+    // Weeks are only used if they are the largest or the smallest unit, like in Temporal
+    if largest == unit_size(DateDurationUnit::Weeks)
+        || smallest == unit_size(DateDurationUnit::Weeks)
+    {
+        duration.weeks = duration.days / 7;
+        duration.days %= 7;
+    }
+
+    // Truncate the units smaller than `smallest_unit`
+    if smallest >= unit_size(DateDurationUnit::Weeks) {
+        duration.days = 0;
+    }
+    if smallest >= unit_size(DateDurationUnit::Months) {
+        duration.weeks = 0;
+    }
+    if smallest >= unit_size(DateDurationUnit::Years) {
+        duration.months = 0;
+    }
+    duration
+}
+
+/// Check that `day` is at most `month_days`, constraining it to `month_days` or returning an
+/// error according to `overflow`
+pub(crate) fn constrain_day(
+    day: u8,
+    month_days: u8,
+    overflow: Overflow,
+) -> Result<u8, DateTimeError> {
+    if day <= month_days {
+        Ok(day)
+    } else if overflow == Overflow::Constrain {
+        Ok(month_days)
+    } else {
+        Err(DateTimeError::OutOfRange)
+    }
+}
+
+/// The relative size of a [`DateDurationUnit`], for comparing units
+fn unit_size(unit: DateDurationUnit) -> u8 {
+    match unit {
+        DateDurationUnit::Days => 0,
+        DateDurationUnit::Weeks => 1,
+        DateDurationUnit::Months => 2,
+        DateDurationUnit::Years => 3,
     }
 }
 
@@ -235,8 +420,26 @@ pub fn ordinal_solar_month_from_code(code: types::MonthCode) -> Option<u8> {
         if bytes[2] >= b'1' && bytes[2] <= b'9' {
             return Some(bytes[2] - b'0');
         }
-    } else if bytes[1] == b'1' && bytes[2] >= b'1' && bytes[2] <= b'3' {
+    } else if bytes[1] == b'1' && bytes[2] >= b'0' && bytes[2] <= b'3' {
         return Some(10 + bytes[2] - b'0');
     }
     None
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tinystr::tinystr;
+
+    #[test]
+    fn test_ordinal_solar_month_from_code() {
+        let ordinal = |code| ordinal_solar_month_from_code(types::MonthCode(code));
+        assert_eq!(ordinal(tinystr!(4, "M01")), Some(1));
+        assert_eq!(ordinal(tinystr!(4, "M09")), Some(9));
+        assert_eq!(ordinal(tinystr!(4, "M10")), Some(10));
+        assert_eq!(ordinal(tinystr!(4, "M13")), Some(13));
+        assert_eq!(ordinal(tinystr!(4, "M00")), None);
+        assert_eq!(ordinal(tinystr!(4, "M14")), None);
+        assert_eq!(ordinal(tinystr!(4, "M05L")), None);
+    }
+}
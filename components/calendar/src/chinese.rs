@@ -0,0 +1,526 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! This module contains types and implementations for the Chinese calendar.
+//!
+//! ```rust
+//! use icu::calendar::{chinese::Chinese, Date, DateTime};
+//!
+//! // `Date` type
+//! let date_iso = Date::new_iso_date(1970, 1, 2)
+//!     .expect("Failed to initialize ISO Date instance.");
+//! let date_chinese = Date::new_from_iso(date_iso, Chinese);
+//!
+//! // `DateTime` type
+//! let datetime_iso = DateTime::new_iso_datetime(1970, 1, 2, 13, 1, 0)
+//!     .expect("Failed to initialize ISO DateTime instance.");
+//! let datetime_chinese = DateTime::new_from_iso(datetime_iso, Chinese);
+//!
+//! // `Date` checks
+//! assert_eq!(date_chinese.year().number, 4606);
+//! assert_eq!(date_chinese.year().related_iso, Some(1969));
+//! assert_eq!(date_chinese.year().cyclic.map(|c| c.get()), Some(46));
+//! assert_eq!(date_chinese.month().ordinal, 11);
+//! assert_eq!(date_chinese.day_of_month().0, 25);
+//!
+//! // `DateTime` type
+//! assert_eq!(datetime_chinese.date.year().number, 4606);
+//! assert_eq!(datetime_chinese.date.month().ordinal, 11);
+//! assert_eq!(datetime_chinese.date.day_of_month().0, 25);
+//! assert_eq!(datetime_chinese.time.hour.number(), 13);
+//! assert_eq!(datetime_chinese.time.minute.number(), 1);
+//! assert_eq!(datetime_chinese.time.second.number(), 0);
+//! ```
+
+use crate::any_calendar::AnyCalendarKind;
+use crate::calendar_arithmetic::{constrain_day, ArithmeticDate, CalendarArithmetic};
+use crate::chinese_based::{self, ChineseBased, ChineseBasedYearInfo};
+use crate::iso::Iso;
+use crate::{types, Calendar, Date, DateDuration, DateDurationUnit, DateTime, DateTimeError};
+use tinystr::tinystr;
+
+/// The [Chinese Calendar]
+///
+/// The [Chinese calendar] is a lunisolar calendar used in China and by Chinese communities
+/// worldwide, for example to determine the dates of traditional holidays like the Lunar New
+/// Year. Months begin on the day of a new moon in the time zone of Beijing. Years have 12
+/// months, or 13 months in leap years, and begin with the second new moon after the winter
+/// solstice, in late January or February.
+///
+/// This type can be used with [`Date`] or [`DateTime`] to represent dates in this calendar.
+///
+/// The new moons and solar terms of the years 1900 to 2099 are computed astronomically. Other
+/// years are approximated with the mean new moons and solar terms, and can differ from the
+/// astronomical calendar by a day or, when the leap month differs, by a month.
+///
+/// [Chinese calendar]: https://en.wikipedia.org/wiki/Chinese_calendar
+///
+/// # Year numbers
+///
+/// Years are numbered from 2637 B.C.E. in the single era `"chinese"`, so that the year
+/// beginning in 2023 is 4660. The [`FormattableYear`](types::FormattableYear) of a date also
+/// contains the related ISO year, in which the year begins, and the year in the 60-year cycle
+/// (the sexagenary cycle), which is how years are usually named.
+///
+/// # Month codes
+///
+/// The twelve regular months have the month codes `"M01"` to `"M12"`. The leap month repeats
+/// the month before it, and has that month's code followed by `"L"`, such as `"M02L"` for the
+/// leap month that follows the second month.
+#[derive(Copy, Clone, Debug, Hash, Default, Eq, PartialEq)]
+#[allow(clippy::exhaustive_structs)] // this type is stable
+pub struct Chinese;
+
+/// The inner date type used for representing [`Date`]s of [`Chinese`]. See [`Date`] and [`Chinese`] for more details.
+///
+/// Months are stored as ordinals, so that the leap month and the months after it have an ordinal
+/// one greater than their number.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct ChineseDateInner(pub(crate) ArithmeticDate<Chinese>);
+
+impl CalendarArithmetic for Chinese {
+    fn month_days(year: i32, month: u8) -> u8 {
+        ChineseBasedYearInfo::for_year::<Self>(year).days_in_month(month)
+    }
+
+    fn months_for_every_year(year: i32) -> u8 {
+        ChineseBasedYearInfo::for_year::<Self>(year).months_in_year()
+    }
+
+    fn is_leap_year(year: i32) -> bool {
+        ChineseBasedYearInfo::for_year::<Self>(year).leap_month != 0
+    }
+
+    fn month_with_same_code(from_year: i32, month: u8, year: i32) -> Result<u8, u8> {
+        ChineseBasedYearInfo::for_year::<Self>(year)
+            .month_with_same_code(&ChineseBasedYearInfo::for_year::<Self>(from_year), month)
+    }
+}
+
+impl ChineseBased for Chinese {
+    const YEAR_DATA: &'static [u32] = CHINESE_YEAR_DATA;
+
+    const RELATED_ISO_OFFSET: i32 = 2637;
+
+    fn utc_offset(fixed: i32) -> i64 {
+        // China has used the standard time of 120°E since 1929, and the local mean time of
+        // Beijing, 7:45:40, before
+        if fixed < FIXED_1929 {
+            27_940_000
+        } else {
+            28_800_000
+        }
+    }
+}
+
+/// The fixed date of January 1, 1929.
+const FIXED_1929: i32 = 704_188;
+
+impl Calendar for Chinese {
+    type DateInner = ChineseDateInner;
+    fn date_from_codes(
+        &self,
+        era: types::Era,
+        year: i32,
+        month_code: types::MonthCode,
+        day: u8,
+        overflow: types::Overflow,
+    ) -> Result<Self::DateInner, DateTimeError> {
+        if era.0 != tinystr!(16, "chinese") {
+            return Err(DateTimeError::UnknownEra(era.0, self.debug_name()));
+        }
+
+        let year_info = ChineseBasedYearInfo::for_year::<Self>(year);
+        let month = if let Some(month) = year_info.ordinal_from_code(month_code, overflow) {
+            month
+        } else {
+            return Err(DateTimeError::UnknownMonthCode(
+                month_code.0,
+                self.debug_name(),
+            ));
+        };
+
+        if day == 0 {
+            return Err(DateTimeError::OutOfRange);
+        }
+        let day = constrain_day(day, year_info.days_in_month(month), overflow)?;
+
+        Ok(ChineseDateInner(ArithmeticDate::new(year, month, day)))
+    }
+
+    fn date_from_iso(&self, iso: Date<Iso>) -> ChineseDateInner {
+        let fixed_iso = Iso::fixed_from_iso(*iso.inner());
+        ChineseDateInner(chinese_based::chinese_based_from_fixed(fixed_iso))
+    }
+
+    fn date_to_iso(&self, date: &Self::DateInner) -> Date<Iso> {
+        let fixed_chinese = chinese_based::fixed_from_chinese_based(date.0);
+        Iso::iso_from_fixed(fixed_chinese)
+    }
+
+    fn months_in_year(&self, date: &Self::DateInner) -> u8 {
+        date.0.months_in_year()
+    }
+
+    fn days_in_year(&self, date: &Self::DateInner) -> u32 {
+        ChineseBasedYearInfo::for_year::<Self>(date.0.year).days_in_year()
+    }
+
+    fn days_in_month(&self, date: &Self::DateInner) -> u8 {
+        date.0.days_in_month()
+    }
+
+    fn day_of_week(&self, date: &Self::DateInner) -> types::IsoWeekday {
+        Iso.day_of_week(Chinese.date_to_iso(date).inner())
+    }
+
+    fn offset_date(
+        &self,
+        date: &mut Self::DateInner,
+        offset: DateDuration<Self>,
+        overflow: types::Overflow,
+    ) -> Result<(), DateTimeError> {
+        date.0.offset_date(offset, overflow)
+    }
+
+    fn until(
+        &self,
+        date1: &Self::DateInner,
+        date2: &Self::DateInner,
+        _calendar2: &Self,
+        largest_unit: DateDurationUnit,
+        smallest_unit: DateDurationUnit,
+    ) -> DateDuration<Self> {
+        date1.0.until(date2.0, largest_unit, smallest_unit)
+    }
+
+    fn year(&self, date: &Self::DateInner) -> types::FormattableYear {
+        year_as_chinese(date.0.year)
+    }
+
+    fn month(&self, date: &Self::DateInner) -> types::FormattableMonth {
+        types::FormattableMonth {
+            ordinal: date.0.month as u32,
+            code: ChineseBasedYearInfo::for_year::<Self>(date.0.year)
+                .code_from_ordinal(date.0.month),
+        }
+    }
+
+    fn day_of_month(&self, date: &Self::DateInner) -> types::DayOfMonth {
+        date.0.day_of_month()
+    }
+
+    fn day_of_year_info(&self, date: &Self::DateInner) -> types::DayOfYearInfo {
+        let prev_year = date.0.year - 1;
+        let next_year = date.0.year + 1;
+        types::DayOfYearInfo {
+            day_of_year: date.0.day_of_year(),
+            days_in_year: ChineseBasedYearInfo::for_year::<Self>(date.0.year).days_in_year(),
+            prev_year: year_as_chinese(prev_year),
+            days_in_prev_year: ChineseBasedYearInfo::for_year::<Self>(prev_year).days_in_year(),
+            next_year: year_as_chinese(next_year),
+        }
+    }
+
+    fn debug_name(&self) -> &'static str {
+        "Chinese"
+    }
+
+    fn any_calendar_kind(&self) -> Option<AnyCalendarKind> {
+        Some(AnyCalendarKind::Chinese)
+    }
+}
+
+impl Date<Chinese> {
+    /// Construct new Chinese Date.
+    ///
+    /// The year is counted from 2637 B.C.E., and the month is the ordinal of the month in the
+    /// year, so that the leap month and the months after it are one greater than their number.
+    /// Use [`Date::new_from_codes`] to construct a date from a month code instead.
+    ///
+    /// ```rust
+    /// use icu::calendar::Date;
+    ///
+    /// // The leap month following the second month of 2023
+    /// let date_chinese =
+    ///     Date::new_chinese_date(4660, 3, 1).expect("Failed to initialize Chinese Date instance.");
+    ///
+    /// assert_eq!(date_chinese.year().number, 4660);
+    /// assert_eq!(date_chinese.year().related_iso, Some(2023));
+    /// assert_eq!(date_chinese.month().ordinal, 3);
+    /// assert_eq!(date_chinese.month().code.0, "M02L");
+    /// assert_eq!(date_chinese.day_of_month().0, 1);
+    /// ```
+    pub fn new_chinese_date(year: i32, month: u8, day: u8) -> Result<Date<Chinese>, DateTimeError> {
+        let inner = ArithmeticDate::new(year, month, day);
+
+        let bound = inner.days_in_month();
+        if day == 0 || day > bound {
+            return Err(DateTimeError::OutOfRange);
+        }
+
+        Ok(Date::from_raw(ChineseDateInner(inner), Chinese))
+    }
+}
+
+impl DateTime<Chinese> {
+    /// Construct a new Chinese datetime from integers.
+    ///
+    /// See [`Date::new_chinese_date`] for how years and months are numbered.
+    ///
+    /// ```rust
+    /// use icu::calendar::DateTime;
+    ///
+    /// let datetime_chinese = DateTime::new_chinese_datetime(4660, 3, 1, 13, 1, 0)
+    ///     .expect("Failed to initialize Chinese DateTime instance.");
+    ///
+    /// assert_eq!(datetime_chinese.date.year().number, 4660);
+    /// assert_eq!(datetime_chinese.date.month().ordinal, 3);
+    /// assert_eq!(datetime_chinese.date.day_of_month().0, 1);
+    /// assert_eq!(datetime_chinese.time.hour.number(), 13);
+    /// assert_eq!(datetime_chinese.time.minute.number(), 1);
+    /// assert_eq!(datetime_chinese.time.second.number(), 0);
+    /// ```
+    pub fn new_chinese_datetime(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> Result<DateTime<Chinese>, DateTimeError> {
+        Ok(DateTime {
+            date: Date::new_chinese_date(year, month, day)?,
+            time: types::Time::try_new(hour, minute, second, 0)?,
+        })
+    }
+}
+
+fn year_as_chinese(year: i32) -> types::FormattableYear {
+    chinese_based::year_as_chinese_based::<Chinese>(types::Era(tinystr!(16, "chinese")), year)
+}
+
+/// The precomputed year data for the related ISO years 1900 to 2099, see [`ChineseBased::YEAR_DATA`].
+#[rustfmt::skip]
+const CHINESE_YEAR_DATA: &[u32] = &[
+    0x3d36d2, 0x620752, 0x4c0ea5, 0x38d64a, 0x5c064b, 0x440a9b, 0x30b55a, 0x56056a,
+    0x400b59, 0x2a7752, 0x500752, 0x3afb25, 0x600b25, 0x480a4b, 0x32d4ab, 0x5802ad,
+    0x42056b, 0x2c6b69, 0x520da9, 0x3f1d92, 0x640e92, 0x4c0d25, 0x36da4d, 0x5c0a56,
+    0x4602b6, 0x2eb5b5, 0x5606d4, 0x400ea9, 0x2c7e92, 0x500e92, 0x3aed26, 0x5e052b,
+    0x480a57, 0x32d2b6, 0x580b5a, 0x4406d4, 0x2e8ec9, 0x520749, 0x3d1693, 0x620a93,
+    0x4c052b, 0x34ea5b, 0x5a0aad, 0x46056a, 0x30bb55, 0x560ba4, 0x400b49, 0x2a7a93,
+    0x500a95, 0x39152d, 0x5e0536, 0x480aad, 0x34d5aa, 0x5805b2, 0x420da5, 0x2e9d4a,
+    0x540d4a, 0x3d2a95, 0x600a97, 0x4c0556, 0x36eab5, 0x5a0ad5, 0x4606d2, 0x30aea5,
+    0x560ea5, 0x40064a, 0x288c97, 0x4e0a9b, 0x3b155a, 0x5e056a, 0x480b69, 0x34d752,
+    0x5a0b52, 0x420b25, 0x2cb64b, 0x520a4b, 0x3d34ab, 0x6002ad, 0x4a056d, 0x36eb69,
+    0x5c0da9, 0x460d92, 0x30bd25, 0x560d25, 0x417a4d, 0x640a56, 0x4e02b6, 0x38e5b5,
+    0x5e06d5, 0x480ea9, 0x34de92, 0x5a0e92, 0x440d26, 0x2c8a56, 0x500a57, 0x3d34d6,
+    0x62035a, 0x4a06d5, 0x36d6c9, 0x5c0749, 0x460693, 0x2eb52b, 0x54052b, 0x3e0a5b,
+    0x2a755a, 0x4e056a, 0x391b55, 0x600ba4, 0x4a0b49, 0x32da93, 0x580a95, 0x42052d,
+    0x2caaad, 0x500ab5, 0x3d55aa, 0x6205d2, 0x4c0da5, 0x36fd4a, 0x5c0d4a, 0x460c95,
+    0x30b52e, 0x540556, 0x3e0ab5, 0x2a75b2, 0x5006d2, 0x38eea5, 0x5e0725, 0x48064b,
+    0x32cc97, 0x560cab, 0x42055a, 0x2c8ad6, 0x520b69, 0x3d9752, 0x620b52, 0x4c0b25,
+    0x36fa4b, 0x5a0a4b, 0x4404ab, 0x2ec55b, 0x5405ad, 0x3e0b6a, 0x2a7b52, 0x500d92,
+    0x3b1d25, 0x5e0d25, 0x480a55, 0x32d4ad, 0x5804b6, 0x4005b5, 0x2c8daa, 0x520ec9,
+    0x3f3e92, 0x620e92, 0x4c0d26, 0x36ea56, 0x5a0a57, 0x440556, 0x2ea6d5, 0x540755,
+    0x400749, 0x288e93, 0x4e0693, 0x39152b, 0x5e052b, 0x460a5b, 0x32d55a, 0x58056a,
+    0x420b65, 0x2cb74a, 0x520b4a, 0x3d3a95, 0x620a95, 0x4a052d, 0x34eaad, 0x5a0ab5,
+    0x4605aa, 0x2eaba5, 0x540da5, 0x400d4a, 0x2a9c95, 0x4e0c96, 0x39194e, 0x5e0556,
+    0x480ab5, 0x32d5b2, 0x5806d2, 0x420ea5, 0x2eae4a, 0x50068b, 0x3b2c97, 0x6004ab,
+    0x4a055b, 0x34ead6, 0x5a0b6a, 0x460752, 0x30b725, 0x540b45, 0x3e0a8b, 0x28749b,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RoundingMode;
+
+    #[test]
+    fn test_new_year() {
+        // (related ISO year, ISO date of the Lunar New Year, leap month code)
+        let cases = [
+            (1900, (1900, 1, 31), Some("M08L")),
+            (1985, (1985, 2, 20), None),
+            (1997, (1997, 2, 7), None),
+            (2001, (2001, 1, 24), Some("M04L")),
+            (2017, (2017, 1, 28), Some("M06L")),
+            (2020, (2020, 1, 25), Some("M04L")),
+            (2023, (2023, 1, 22), Some("M02L")),
+            (2024, (2024, 2, 10), None),
+            (2025, (2025, 1, 29), Some("M06L")),
+            (2026, (2026, 2, 17), None),
+            (2033, (2033, 1, 31), Some("M11L")),
+        ];
+        for (related_iso, (iso_year, iso_month, iso_day), leap_month) in cases {
+            let year = related_iso + Chinese::RELATED_ISO_OFFSET;
+            let iso = Date::new_iso_date(iso_year, iso_month, iso_day).unwrap();
+            let chinese = Date::new_chinese_date(year, 1, 1).unwrap();
+            assert_eq!(chinese.to_iso(), iso, "{related_iso}");
+            assert_eq!(iso.to_calendar(Chinese), chinese, "{related_iso}");
+            assert_eq!(chinese.year().related_iso, Some(related_iso));
+            assert_eq!(Chinese::is_leap_year(year), leap_month.is_some());
+
+            let year_info = ChineseBasedYearInfo::for_year::<Chinese>(year);
+            let leap_code = (1..=year_info.months_in_year())
+                .map(|month| year_info.code_from_ordinal(month))
+                .find(|code| code.0.len() == 4);
+            assert_eq!(
+                leap_code.map(|code| code.0),
+                leap_month.map(|code| code.parse().unwrap())
+            );
+        }
+    }
+
+    #[test]
+    fn test_leap_months() {
+        let iso = |y, m, d| Date::new_iso_date(y, m, d).unwrap();
+        let cases = [
+            (iso(2023, 3, 21), 4660, 2, "M02", 30),
+            (iso(2023, 3, 22), 4660, 3, "M02L", 1),
+            (iso(2023, 4, 20), 4660, 4, "M03", 1),
+            (iso(2024, 2, 9), 4660, 13, "M12", 30),
+            (iso(2024, 6, 6), 4661, 5, "M05", 1),
+        ];
+        for (iso, year, ordinal, code, day) in cases {
+            let chinese = iso.to_calendar(Chinese);
+            assert_eq!(chinese.year().number, year, "{iso:?}");
+            assert_eq!(chinese.month().ordinal, ordinal, "{iso:?}");
+            assert_eq!(chinese.month().code.0, code, "{iso:?}");
+            assert_eq!(chinese.day_of_month().0, day as u32, "{iso:?}");
+
+            let from_codes = Date::new_from_codes(
+                types::Era(tinystr!(16, "chinese")),
+                year,
+                types::MonthCode(code.parse().unwrap()),
+                day,
+                Chinese,
+            )
+            .unwrap();
+            assert_eq!(from_codes, chinese, "{iso:?}");
+        }
+
+        // 2024 has no leap month
+        assert!(Date::new_from_codes(
+            types::Era(tinystr!(16, "chinese")),
+            4661,
+            types::MonthCode(tinystr!(4, "M02L")),
+            1,
+            Chinese,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_cyclic_year() {
+        // 1984 and 2044 are the first years of a cycle
+        for (related_iso, cyclic) in [(1983, 60), (1984, 1), (2023, 40), (2043, 60), (2044, 1)] {
+            let year = Date::new_chinese_date(related_iso + 2637, 1, 1)
+                .unwrap()
+                .year();
+            assert_eq!(year.cyclic.map(|c| c.get()), Some(cyclic), "{related_iso}");
+        }
+    }
+
+    #[test]
+    fn test_until() {
+        let iso = |y, m, d| Date::new_iso_date(y, m, d).unwrap();
+        // 4660 has a leap month after its second month
+        let dates: Vec<_> = (0..900)
+            .step_by(11)
+            .map(|offset| {
+                iso(2023, 1, 1)
+                    .added(
+                        DateDuration::new(0, 0, 0, offset),
+                        types::Overflow::Constrain,
+                    )
+                    .unwrap()
+            })
+            .map(|date| date.to_calendar(Chinese))
+            .collect();
+        for date in &dates {
+            for other in &dates {
+                for unit in [DateDurationUnit::Years, DateDurationUnit::Months] {
+                    let duration =
+                        date.until(other, unit, DateDurationUnit::Days, RoundingMode::Trunc);
+                    assert_eq!(
+                        &date
+                            .clone()
+                            .added(duration, types::Overflow::Constrain)
+                            .unwrap(),
+                        other,
+                        "{date:?} until {other:?}"
+                    );
+                }
+            }
+        }
+
+        // The leap month M02L becomes M02 when adding a year
+        let leap_month = Date::new_chinese_date(4660, 3, 1).unwrap();
+        assert_eq!(leap_month.month().code.0, "M02L");
+        let next_year = leap_month
+            .clone()
+            .added(DateDuration::new(1, 0, 0, 0), types::Overflow::Constrain)
+            .unwrap();
+        assert_eq!(next_year.month().code.0, "M02");
+        assert_eq!(next_year.year().number, 4661);
+        assert_eq!(
+            leap_month.added(DateDuration::new(1, 0, 0, 0), types::Overflow::Reject),
+            Err(DateTimeError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_from_codes_overflow() {
+        let from_codes = |month: &str, overflow| {
+            Date::new_from_codes_with_overflow(
+                types::Era(tinystr!(16, "chinese")),
+                4661,
+                types::MonthCode(month.parse().unwrap()),
+                1,
+                overflow,
+                Chinese,
+            )
+        };
+
+        // 4661 has no leap month, so M02L is constrained to M02
+        let date = from_codes("M02L", types::Overflow::Constrain).unwrap();
+        assert_eq!(date, from_codes("M02", types::Overflow::Reject).unwrap());
+        assert!(matches!(
+            from_codes("M02L", types::Overflow::Reject),
+            Err(DateTimeError::UnknownMonthCode(..))
+        ));
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        // Covers the approximated years before and after the precomputed data
+        let start = Iso::fixed_from_iso(*Date::new_iso_date(1850, 1, 1).unwrap().inner());
+        let end = Iso::fixed_from_iso(*Date::new_iso_date(2150, 1, 1).unwrap().inner());
+        let mut prev: Option<Date<Chinese>> = None;
+        for fixed in start..end {
+            let iso = Iso::iso_from_fixed(fixed);
+            let chinese = iso.to_calendar(Chinese);
+            assert_eq!(chinese.to_iso(), iso);
+            if let Some(prev) = prev {
+                let prev = prev.inner().0;
+                let next = chinese.inner().0;
+                if next.day == 1 {
+                    assert!(matches!(prev.day, 29 | 30), "{:?}", iso);
+                    if next.month == 1 {
+                        assert_eq!(next.year, prev.year + 1, "{iso:?}");
+                        assert_eq!(prev.month, Chinese::months_for_every_year(prev.year));
+                    } else {
+                        assert_eq!((next.year, next.month), (prev.year, prev.month + 1));
+                    }
+                } else {
+                    assert_eq!(
+                        (next.year, next.month, next.day),
+                        (prev.year, prev.month, prev.day + 1)
+                    );
+                }
+            }
+            prev = Some(chinese);
+        }
+    }
+}
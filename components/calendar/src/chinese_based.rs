@@ -0,0 +1,481 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! This module contains the logic shared by the lunisolar calendars that follow the rules of the
+//! Chinese calendar, namely the [`Chinese`](crate::chinese::Chinese) and
+//! [`Dangi`](crate::dangi::Dangi) calendars.
+//!
+//! Months begin on the day of a new moon, and the year begins with the second new moon after
+//! the winter solstice, or the third one if a leap month comes in between. A year that has
+//! thirteen months repeats the first month in it that contains no major solar term, which is
+//! the leap month of the year. Both calendars follow these rules, but determine the days of
+//! the new moons and solar terms in the local time of Beijing and Seoul respectively.
+//!
+//! The years 1900 to 2099 use precomputed data, which was derived from the astronomical
+//! algorithms for the solar longitude and the new moons in
+//! Dershowitz, Nachum, and Edward M. Reingold. _Calendrical calculations_. Cambridge University Press, 2008.
+//!
+//! Other years are approximated with the same rules applied to the *mean* new moons and solar
+//! terms, which can place new moons and leap months a day or a month away from the
+//! astronomical ones.
+
+use crate::calendar_arithmetic::{ArithmeticDate, CalendarArithmetic};
+use crate::iso::{Iso, IsoDateInner};
+use crate::types::{self, Overflow};
+use core::convert::TryFrom;
+use core::num::NonZeroU8;
+use tinystr::{tinystr, TinyStr4};
+
+/// The first related ISO year of the precomputed year data.
+const FIRST_DATA_YEAR: i32 = 1900;
+
+const MS_PER_DAY: i64 = 86_400_000;
+
+/// The mean length of a lunar month, 29.530588853 days, in milliseconds.
+const MEAN_SYNODIC_MONTH: i64 = 2_551_442_878;
+
+/// The mean length of a tropical year, 365.242189 days, in milliseconds.
+const MEAN_TROPICAL_YEAR: i64 = 31_556_925_130;
+
+/// The time between two major solar terms, a twelfth of a tropical year.
+const MEAN_SOLAR_TERM: i64 = MEAN_TROPICAL_YEAR / 12;
+
+/// The moment of the mean new moon of January 6, 2000 (14:20 UT), in milliseconds since the
+/// start of fixed day 0.
+const MEAN_NEW_MOON_EPOCH: i64 = 730125 * MS_PER_DAY + 51_573_824;
+
+/// The moment of the mean winter solstice of December 22, 1900 (08:10 UT), in milliseconds
+/// since the start of fixed day 0, fitted to the winter solstices of 1900 to 2100.
+const MEAN_WINTER_SOLSTICE_EPOCH: i64 = 693951 * MS_PER_DAY + 29_458_790;
+
+/// A calendar that follows the rules of the Chinese calendar.
+pub(crate) trait ChineseBased: CalendarArithmetic {
+    /// The precomputed data of the years starting with the related ISO year 1900.
+    ///
+    /// For every year, bits 0 to 12 are set if the corresponding month has 30 days instead of
+    /// 29, bits 13 to 16 contain the ordinal of the leap month, or 0 if there is none, and bits
+    /// 17 to 22 contain the number of days from January 1 of the related ISO year to the first
+    /// day of the year.
+    const YEAR_DATA: &'static [u32];
+
+    /// The difference between the year number of the calendar and the related ISO year.
+    const RELATED_ISO_OFFSET: i32;
+
+    /// The offset of the local standard time from universal time on the given fixed date, in
+    /// milliseconds.
+    fn utc_offset(fixed: i32) -> i64;
+}
+
+/// The months of a year of a [`ChineseBased`] calendar.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct ChineseBasedYearInfo {
+    /// The fixed date of the first day of the year.
+    pub(crate) new_year: i32,
+    /// Bit `n` is set if month `n + 1` has 30 days.
+    month_lengths: u16,
+    /// The ordinal of the leap month, or 0 if the year has twelve months.
+    pub(crate) leap_month: u8,
+}
+
+impl ChineseBasedYearInfo {
+    /// The year info of the year with the given year number of the calendar `C`.
+    pub(crate) fn for_year<C: ChineseBased>(year: i32) -> Self {
+        let related_iso = year - C::RELATED_ISO_OFFSET;
+        match data_for_related_iso::<C>(related_iso) {
+            Some(data) => Self {
+                new_year: Iso::fixed_from_iso(IsoDateInner::jan_1(related_iso))
+                    + (data >> 17) as i32,
+                month_lengths: (data & 0x1fff) as u16,
+                leap_month: ((data >> 13) & 0xf) as u8,
+            },
+            None => mean_year_info::<C>(related_iso),
+        }
+    }
+
+    pub(crate) fn months_in_year(&self) -> u8 {
+        if self.leap_month == 0 {
+            12
+        } else {
+            13
+        }
+    }
+
+    pub(crate) fn days_in_month(&self, month: u8) -> u8 {
+        if month == 0 || month > self.months_in_year() {
+            0
+        } else if self.month_lengths & (1 << (month - 1)) != 0 {
+            30
+        } else {
+            29
+        }
+    }
+
+    pub(crate) fn days_in_year(&self) -> u32 {
+        (1..=self.months_in_year())
+            .map(|month| self.days_in_month(month) as u32)
+            .sum()
+    }
+
+    /// The fixed date of the given date of this year.
+    pub(crate) fn fixed_from_month_day(&self, month: u8, day: u8) -> i32 {
+        let days_before_month: u32 = (1..month)
+            .map(|month| self.days_in_month(month) as u32)
+            .sum();
+        self.new_year + days_before_month as i32 + day as i32 - 1
+    }
+
+    /// The ordinal month of a month code, such as `"M04L"` for the leap month following the
+    /// fourth month.
+    ///
+    /// With [`Overflow::Constrain`], a leap month that this year doesn't have is constrained
+    /// to the month it would repeat.
+    pub(crate) fn ordinal_from_code(
+        &self,
+        code: types::MonthCode,
+        overflow: Overflow,
+    ) -> Option<u8> {
+        let code = code.0.as_str().strip_prefix('M')?;
+        let (number, is_leap) = match code.strip_suffix('L') {
+            Some(number) => (number, true),
+            None => (code, false),
+        };
+        if number.len() != 2 {
+            return None;
+        }
+        let number: u8 = number.parse().ok()?;
+        if !(1..=12).contains(&number) {
+            return None;
+        }
+        if is_leap {
+            // The leap month repeats the month before it
+            if self.leap_month == number + 1 {
+                return Some(number + 1);
+            } else if overflow == Overflow::Reject {
+                return None;
+            }
+        }
+        if self.leap_month != 0 && number >= self.leap_month {
+            Some(number + 1)
+        } else {
+            Some(number)
+        }
+    }
+
+    /// The number of an ordinal month, and whether it is the leap month repeating it.
+    fn month_number(&self, month: u8) -> (u8, bool) {
+        if self.leap_month == 0 || month < self.leap_month {
+            (month, false)
+        } else {
+            (month - 1, month == self.leap_month)
+        }
+    }
+
+    /// The ordinal month of this year with the same month code as the ordinal month `month` of
+    /// the year `other`.
+    ///
+    /// If this year doesn't have that leap month, the result is `Err` with the month it
+    /// repeats, like Temporal's constraining of month codes.
+    pub(crate) fn month_with_same_code(&self, other: &Self, month: u8) -> Result<u8, u8> {
+        let (number, is_leap) = other.month_number(month);
+        // The leap month, or a month after this year's leap month
+        let ordinal = if (is_leap && self.leap_month == number + 1)
+            || (self.leap_month != 0 && number >= self.leap_month)
+        {
+            number + 1
+        } else {
+            number
+        };
+        if is_leap && self.leap_month != number + 1 {
+            Err(ordinal)
+        } else {
+            Ok(ordinal)
+        }
+    }
+
+    /// The month code of an ordinal month.
+    pub(crate) fn code_from_ordinal(&self, month: u8) -> types::MonthCode {
+        let (number, is_leap) = self.month_number(month);
+        let codes = if is_leap {
+            LEAP_MONTH_CODES
+        } else {
+            MONTH_CODES
+        };
+        types::MonthCode(
+            codes
+                .get(number.wrapping_sub(1) as usize)
+                .copied()
+                .unwrap_or(tinystr!(4, "und")),
+        )
+    }
+}
+
+const MONTH_CODES: &[TinyStr4] = &[
+    tinystr!(4, "M01"),
+    tinystr!(4, "M02"),
+    tinystr!(4, "M03"),
+    tinystr!(4, "M04"),
+    tinystr!(4, "M05"),
+    tinystr!(4, "M06"),
+    tinystr!(4, "M07"),
+    tinystr!(4, "M08"),
+    tinystr!(4, "M09"),
+    tinystr!(4, "M10"),
+    tinystr!(4, "M11"),
+    tinystr!(4, "M12"),
+];
+
+const LEAP_MONTH_CODES: &[TinyStr4] = &[
+    tinystr!(4, "M01L"),
+    tinystr!(4, "M02L"),
+    tinystr!(4, "M03L"),
+    tinystr!(4, "M04L"),
+    tinystr!(4, "M05L"),
+    tinystr!(4, "M06L"),
+    tinystr!(4, "M07L"),
+    tinystr!(4, "M08L"),
+    tinystr!(4, "M09L"),
+    tinystr!(4, "M10L"),
+    tinystr!(4, "M11L"),
+    tinystr!(4, "M12L"),
+];
+
+fn data_for_related_iso<C: ChineseBased>(related_iso: i32) -> Option<u32> {
+    usize::try_from(related_iso - FIRST_DATA_YEAR)
+        .ok()
+        .and_then(|index| C::YEAR_DATA.get(index))
+        .copied()
+}
+
+/// The fixed date of the date with the given year number, ordinal month and day of `C`.
+pub(crate) fn fixed_from_chinese_based<C: ChineseBased>(date: ArithmeticDate<C>) -> i32 {
+    ChineseBasedYearInfo::for_year::<C>(date.year).fixed_from_month_day(date.month, date.day)
+}
+
+/// The date of `C` on the given fixed date.
+pub(crate) fn chinese_based_from_fixed<C: ChineseBased>(fixed: i32) -> ArithmeticDate<C> {
+    let mut year = Iso::iso_year_from_fixed(fixed) + C::RELATED_ISO_OFFSET;
+    let mut year_info = ChineseBasedYearInfo::for_year::<C>(year);
+    if fixed < year_info.new_year {
+        year -= 1;
+        year_info = ChineseBasedYearInfo::for_year::<C>(year);
+    }
+
+    let mut day = fixed - year_info.new_year + 1;
+    let mut month = 1;
+    while month < year_info.months_in_year() && day > year_info.days_in_month(month) as i32 {
+        day -= year_info.days_in_month(month) as i32;
+        month += 1;
+    }
+    // day <= 30 by the loop above
+    ArithmeticDate::new(year, month, day as u8)
+}
+
+/// The [`types::FormattableYear`] of the given year number of `C`.
+pub(crate) fn year_as_chinese_based<C: ChineseBased>(
+    era: types::Era,
+    year: i32,
+) -> types::FormattableYear {
+    let related_iso = year - C::RELATED_ISO_OFFSET;
+    types::FormattableYear {
+        era,
+        number: year,
+        // The ISO year 4 was the first year of a cycle
+        cyclic: NonZeroU8::new((related_iso - 4).rem_euclid(60) as u8 + 1),
+        related_iso: Some(related_iso),
+    }
+}
+
+// The approximation of the years outside of the precomputed data, using Reingold's algorithms
+// with mean new moons and solar terms.
+//
+// Lisp code reference: https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l#L5205
+
+/// The fixed date of the first day of the year with the given related ISO year.
+fn new_year_for_related_iso<C: ChineseBased>(related_iso: i32) -> i32 {
+    match data_for_related_iso::<C>(related_iso) {
+        Some(data) => Iso::fixed_from_iso(IsoDateInner::jan_1(related_iso)) + (data >> 17) as i32,
+        // July 1, as every year starts between January 21 and February 21
+        None => mean_new_year_on_or_before::<C>(
+            Iso::fixed_from_iso(IsoDateInner::jan_1(related_iso)) + 181,
+        ),
+    }
+}
+
+fn mean_year_info<C: ChineseBased>(related_iso: i32) -> ChineseBasedYearInfo {
+    let new_year = new_year_for_related_iso::<C>(related_iso);
+    let next_new_year = new_year_for_related_iso::<C>(related_iso + 1);
+
+    let mut month_lengths = 0;
+    let mut first_month_without_major_term = 0;
+    let mut month_start = new_year;
+    let mut month = 0;
+    while month_start < next_new_year && month < 13 {
+        let next_month_start = mean_new_moon_on_or_after::<C>(month_start + 1);
+        if next_month_start - month_start == 30 {
+            month_lengths |= 1 << month;
+        }
+        month += 1;
+        if first_month_without_major_term == 0
+            && mean_major_solar_term::<C>(month_start)
+                == mean_major_solar_term::<C>(next_month_start)
+        {
+            first_month_without_major_term = month;
+        }
+        month_start = next_month_start;
+    }
+
+    ChineseBasedYearInfo {
+        new_year,
+        month_lengths,
+        // With mean solar terms, which are longer than any month, a year has a month without a
+        // major solar term exactly if it has thirteen months
+        leap_month: if month == 13 {
+            first_month_without_major_term
+        } else {
+            0
+        },
+    }
+}
+
+/// The moment of the local midnight that starts the given fixed date.
+fn midnight<C: ChineseBased>(fixed: i32) -> i64 {
+    fixed as i64 * MS_PER_DAY - C::utc_offset(fixed)
+}
+
+/// The local fixed date of the given moment.
+fn local_date<C: ChineseBased>(moment: i64) -> i32 {
+    let fixed = moment.div_euclid(MS_PER_DAY) as i32;
+    (moment + C::utc_offset(fixed)).div_euclid(MS_PER_DAY) as i32
+}
+
+fn mean_new_moon_on_or_after<C: ChineseBased>(fixed: i32) -> i32 {
+    let n = (midnight::<C>(fixed) - MEAN_NEW_MOON_EPOCH + MEAN_SYNODIC_MONTH - 1)
+        .div_euclid(MEAN_SYNODIC_MONTH);
+    local_date::<C>(MEAN_NEW_MOON_EPOCH + n * MEAN_SYNODIC_MONTH)
+}
+
+fn mean_new_moon_before<C: ChineseBased>(fixed: i32) -> i32 {
+    let n = (midnight::<C>(fixed) - MEAN_NEW_MOON_EPOCH - 1).div_euclid(MEAN_SYNODIC_MONTH);
+    local_date::<C>(MEAN_NEW_MOON_EPOCH + n * MEAN_SYNODIC_MONTH)
+}
+
+fn mean_winter_solstice_on_or_before<C: ChineseBased>(fixed: i32) -> i32 {
+    let n =
+        (midnight::<C>(fixed + 1) - MEAN_WINTER_SOLSTICE_EPOCH - 1).div_euclid(MEAN_TROPICAL_YEAR);
+    local_date::<C>(MEAN_WINTER_SOLSTICE_EPOCH + n * MEAN_TROPICAL_YEAR)
+}
+
+/// The number of major solar terms from the epoch to the start of the given fixed date.
+fn mean_major_solar_term<C: ChineseBased>(fixed: i32) -> i64 {
+    (midnight::<C>(fixed) - MEAN_WINTER_SOLSTICE_EPOCH).div_euclid(MEAN_SOLAR_TERM)
+}
+
+fn mean_no_major_solar_term<C: ChineseBased>(fixed: i32) -> bool {
+    mean_major_solar_term::<C>(fixed)
+        == mean_major_solar_term::<C>(mean_new_moon_on_or_after::<C>(fixed + 1))
+}
+
+/// The first day of the year that begins in the winter solstice year ("sui") containing
+/// `fixed`.
+fn mean_new_year_in_sui<C: ChineseBased>(fixed: i32) -> i32 {
+    let s1 = mean_winter_solstice_on_or_before::<C>(fixed);
+    let s2 = mean_winter_solstice_on_or_before::<C>(s1 + 370);
+    let m12 = mean_new_moon_on_or_after::<C>(s1 + 1);
+    let m13 = mean_new_moon_on_or_after::<C>(m12 + 1);
+    let next_m11 = mean_new_moon_before::<C>(s2 + 1);
+    // Twelve months take about 354 days, eleven months about 325 days
+    if next_m11 - m12 > 340
+        && (mean_no_major_solar_term::<C>(m12) || mean_no_major_solar_term::<C>(m13))
+    {
+        mean_new_moon_on_or_after::<C>(m13 + 1)
+    } else {
+        m13
+    }
+}
+
+fn mean_new_year_on_or_before<C: ChineseBased>(fixed: i32) -> i32 {
+    let new_year = mean_new_year_in_sui::<C>(fixed);
+    if fixed >= new_year {
+        new_year
+    } else {
+        mean_new_year_in_sui::<C>(fixed - 180)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chinese::Chinese;
+    use crate::dangi::Dangi;
+
+    fn check_year_lengths<C: ChineseBased>() {
+        // The precomputed data and the approximation must agree where they meet
+        for related_iso in FIRST_DATA_YEAR - 50..FIRST_DATA_YEAR + 250 {
+            let year = related_iso + C::RELATED_ISO_OFFSET;
+            let year_info = ChineseBasedYearInfo::for_year::<C>(year);
+            let next_year_info = ChineseBasedYearInfo::for_year::<C>(year + 1);
+            assert_eq!(
+                year_info.new_year + year_info.days_in_year() as i32,
+                next_year_info.new_year,
+                "{related_iso}"
+            );
+            assert!(matches!(year_info.days_in_year(), 353..=355 | 383..=385));
+        }
+    }
+
+    #[test]
+    fn test_year_lengths() {
+        check_year_lengths::<Chinese>();
+        check_year_lengths::<Dangi>();
+    }
+
+    #[test]
+    fn test_month_codes() {
+        let year_info = ChineseBasedYearInfo {
+            new_year: 0,
+            month_lengths: 0,
+            leap_month: 3,
+        };
+        let codes = ["M01", "M02", "M02L", "M03", "M04"];
+        for (ordinal, code) in (1..).zip(codes) {
+            let code = types::MonthCode(code.parse().unwrap());
+            assert_eq!(year_info.code_from_ordinal(ordinal), code);
+            assert_eq!(
+                year_info.ordinal_from_code(code, Overflow::Reject),
+                Some(ordinal)
+            );
+        }
+        for code in ["M01L", "M03L", "M13", "M1", "M00"] {
+            let code = types::MonthCode(code.parse().unwrap());
+            assert_eq!(year_info.ordinal_from_code(code, Overflow::Reject), None);
+        }
+        // Missing leap months are constrained to the month they would repeat
+        for (code, ordinal) in [("M01L", Some(1)), ("M03L", Some(4)), ("M13L", None)] {
+            let code = types::MonthCode(code.parse().unwrap());
+            assert_eq!(
+                year_info.ordinal_from_code(code, Overflow::Constrain),
+                ordinal
+            );
+        }
+
+        let common_year = ChineseBasedYearInfo {
+            leap_month: 0,
+            ..year_info
+        };
+        let other_leap_year = ChineseBasedYearInfo {
+            leap_month: 5,
+            ..year_info
+        };
+        // M02L becomes M02 in years without it
+        assert_eq!(common_year.month_with_same_code(&year_info, 3), Err(2));
+        assert_eq!(other_leap_year.month_with_same_code(&year_info, 3), Err(2));
+        assert_eq!(year_info.month_with_same_code(&year_info, 3), Ok(3));
+        // M04 follows the leap month in the first year
+        assert_eq!(common_year.month_with_same_code(&year_info, 5), Ok(4));
+        assert_eq!(year_info.month_with_same_code(&common_year, 4), Ok(5));
+        // M05 follows the leap month M04L
+        assert_eq!(other_leap_year.month_with_same_code(&common_year, 5), Ok(6));
+        assert_eq!(other_leap_year.month_with_same_code(&common_year, 4), Ok(4));
+    }
+}
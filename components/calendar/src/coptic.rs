@@ -92,6 +92,7 @@ impl Calendar for Coptic {
         year: i32,
         month_code: types::MonthCode,
         day: u8,
+        overflow: types::Overflow,
     ) -> Result<Self::DateInner, DateTimeError> {
         let year = if era.0 == tinystr!(16, "ad") {
             if year <= 0 {
@@ -107,7 +108,7 @@ impl Calendar for Coptic {
             return Err(DateTimeError::UnknownEra(era.0, self.debug_name()));
         };
 
-        ArithmeticDate::new_from_solar(self, year, month_code, day).map(CopticDateInner)
+        ArithmeticDate::new_from_solar(self, year, month_code, day, overflow).map(CopticDateInner)
     }
     fn date_from_iso(&self, iso: Date<Iso>) -> CopticDateInner {
         let fixed_iso = Iso::fixed_from_iso(*iso.inner());
@@ -135,20 +136,24 @@ impl Calendar for Coptic {
         Iso.day_of_week(Coptic.date_to_iso(date).inner())
     }
 
-    fn offset_date(&self, date: &mut Self::DateInner, offset: DateDuration<Self>) {
-        date.0.offset_date(offset);
+    fn offset_date(
+        &self,
+        date: &mut Self::DateInner,
+        offset: DateDuration<Self>,
+        overflow: types::Overflow,
+    ) -> Result<(), DateTimeError> {
+        date.0.offset_date(offset, overflow)
     }
 
-    #[allow(clippy::field_reassign_with_default)]
     fn until(
         &self,
         date1: &Self::DateInner,
         date2: &Self::DateInner,
         _calendar2: &Self,
-        _largest_unit: DateDurationUnit,
-        _smallest_unit: DateDurationUnit,
+        largest_unit: DateDurationUnit,
+        smallest_unit: DateDurationUnit,
     ) -> DateDuration<Self> {
-        date1.0.until(date2.0, _largest_unit, _smallest_unit)
+        date1.0.until(date2.0, largest_unit, smallest_unit)
     }
 
     fn year(&self, date: &Self::DateInner) -> types::FormattableYear {
@@ -298,12 +303,14 @@ fn year_as_coptic(year: i32) -> types::FormattableYear {
         types::FormattableYear {
             era: types::Era(tinystr!(16, "ad")),
             number: year,
+            cyclic: None,
             related_iso: None,
         }
     } else {
         types::FormattableYear {
             era: types::Era(tinystr!(16, "bd")),
             number: 1 - year,
+            cyclic: None,
             related_iso: None,
         }
     }
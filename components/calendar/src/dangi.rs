@@ -0,0 +1,377 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! This module contains types and implementations for the Dangi (Korean) calendar.
+//!
+//! ```rust
+//! use icu::calendar::{dangi::Dangi, Date, DateTime};
+//!
+//! // `Date` type
+//! let date_iso = Date::new_iso_date(1970, 1, 2)
+//!     .expect("Failed to initialize ISO Date instance.");
+//! let date_dangi = Date::new_from_iso(date_iso, Dangi);
+//!
+//! // `DateTime` type
+//! let datetime_iso = DateTime::new_iso_datetime(1970, 1, 2, 13, 1, 0)
+//!     .expect("Failed to initialize ISO DateTime instance.");
+//! let datetime_dangi = DateTime::new_from_iso(datetime_iso, Dangi);
+//!
+//! // `Date` checks
+//! assert_eq!(date_dangi.year().number, 4302);
+//! assert_eq!(date_dangi.year().related_iso, Some(1969));
+//! assert_eq!(date_dangi.year().cyclic.map(|c| c.get()), Some(46));
+//! assert_eq!(date_dangi.month().ordinal, 11);
+//! assert_eq!(date_dangi.day_of_month().0, 25);
+//!
+//! // `DateTime` type
+//! assert_eq!(datetime_dangi.date.year().number, 4302);
+//! assert_eq!(datetime_dangi.date.month().ordinal, 11);
+//! assert_eq!(datetime_dangi.date.day_of_month().0, 25);
+//! assert_eq!(datetime_dangi.time.hour.number(), 13);
+//! assert_eq!(datetime_dangi.time.minute.number(), 1);
+//! assert_eq!(datetime_dangi.time.second.number(), 0);
+//! ```
+
+use crate::any_calendar::AnyCalendarKind;
+use crate::calendar_arithmetic::{constrain_day, ArithmeticDate, CalendarArithmetic};
+use crate::chinese_based::{self, ChineseBased, ChineseBasedYearInfo};
+use crate::iso::Iso;
+use crate::{types, Calendar, Date, DateDuration, DateDurationUnit, DateTime, DateTimeError};
+use tinystr::tinystr;
+
+/// The [Dangi Calendar]
+///
+/// The [Dangi calendar] is the traditional Korean lunisolar calendar, which is used to determine
+/// the dates of traditional holidays like Seollal, the Korean New Year. It follows the rules of
+/// the [`Chinese`](crate::chinese::Chinese) calendar, but months begin on the day of a new moon in
+/// the time zone of Seoul, so that some months, and sometimes the new year, begin a day later
+/// than in the Chinese calendar.
+///
+/// This type can be used with [`Date`] or [`DateTime`] to represent dates in this calendar.
+///
+/// The new moons and solar terms of the years 1900 to 2099 are computed astronomically. Other
+/// years are approximated with the mean new moons and solar terms, and can differ from the
+/// astronomical calendar by a day or, when the leap month differs, by a month.
+///
+/// [Dangi calendar]: https://en.wikipedia.org/wiki/Korean_calendar
+///
+/// # Year numbers
+///
+/// Years are numbered from 2333 B.C.E., the traditional founding of Gojoseon, in the single
+/// era `"dangi"`, so that the year beginning in 2023 is 4356. The [`FormattableYear`](types::FormattableYear) of a date also
+/// contains the related ISO year, in which the year begins, and the year in the 60-year cycle
+/// (the sexagenary cycle), which is how years are usually named.
+///
+/// # Month codes
+///
+/// The twelve regular months have the month codes `"M01"` to `"M12"`. The leap month repeats
+/// the month before it, and has that month's code followed by `"L"`, such as `"M02L"` for the
+/// leap month that follows the second month.
+#[derive(Copy, Clone, Debug, Hash, Default, Eq, PartialEq)]
+#[allow(clippy::exhaustive_structs)] // this type is stable
+pub struct Dangi;
+
+/// The inner date type used for representing [`Date`]s of [`Dangi`]. See [`Date`] and [`Dangi`] for more details.
+///
+/// Months are stored as ordinals, so that the leap month and the months after it have an ordinal
+/// one greater than their number.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct DangiDateInner(pub(crate) ArithmeticDate<Dangi>);
+
+impl CalendarArithmetic for Dangi {
+    fn month_days(year: i32, month: u8) -> u8 {
+        ChineseBasedYearInfo::for_year::<Self>(year).days_in_month(month)
+    }
+
+    fn months_for_every_year(year: i32) -> u8 {
+        ChineseBasedYearInfo::for_year::<Self>(year).months_in_year()
+    }
+
+    fn is_leap_year(year: i32) -> bool {
+        ChineseBasedYearInfo::for_year::<Self>(year).leap_month != 0
+    }
+
+    fn month_with_same_code(from_year: i32, month: u8, year: i32) -> Result<u8, u8> {
+        ChineseBasedYearInfo::for_year::<Self>(year)
+            .month_with_same_code(&ChineseBasedYearInfo::for_year::<Self>(from_year), month)
+    }
+}
+
+impl ChineseBased for Dangi {
+    const YEAR_DATA: &'static [u32] = DANGI_YEAR_DATA;
+
+    const RELATED_ISO_OFFSET: i32 = 2333;
+
+    fn utc_offset(fixed: i32) -> i64 {
+        // Korea used the local mean time of Seoul, 8:27:52, until April 1908, and alternated
+        // between the standard times of 127.5°E and 135°E afterwards
+        if fixed < FIXED_1908_04_01 {
+            30_472_000
+        } else if fixed < FIXED_1912_01_01 {
+            30_600_000
+        } else if fixed < FIXED_1954_03_21 {
+            32_400_000
+        } else if fixed < FIXED_1961_08_10 {
+            30_600_000
+        } else {
+            32_400_000
+        }
+    }
+}
+
+const FIXED_1908_04_01: i32 = 696_608;
+const FIXED_1912_01_01: i32 = 697_978;
+const FIXED_1954_03_21: i32 = 713_398;
+const FIXED_1961_08_10: i32 = 716_097;
+
+impl Calendar for Dangi {
+    type DateInner = DangiDateInner;
+    fn date_from_codes(
+        &self,
+        era: types::Era,
+        year: i32,
+        month_code: types::MonthCode,
+        day: u8,
+        overflow: types::Overflow,
+    ) -> Result<Self::DateInner, DateTimeError> {
+        if era.0 != tinystr!(16, "dangi") {
+            return Err(DateTimeError::UnknownEra(era.0, self.debug_name()));
+        }
+
+        let year_info = ChineseBasedYearInfo::for_year::<Self>(year);
+        let month = if let Some(month) = year_info.ordinal_from_code(month_code, overflow) {
+            month
+        } else {
+            return Err(DateTimeError::UnknownMonthCode(
+                month_code.0,
+                self.debug_name(),
+            ));
+        };
+
+        if day == 0 {
+            return Err(DateTimeError::OutOfRange);
+        }
+        let day = constrain_day(day, year_info.days_in_month(month), overflow)?;
+
+        Ok(DangiDateInner(ArithmeticDate::new(year, month, day)))
+    }
+
+    fn date_from_iso(&self, iso: Date<Iso>) -> DangiDateInner {
+        let fixed_iso = Iso::fixed_from_iso(*iso.inner());
+        DangiDateInner(chinese_based::chinese_based_from_fixed(fixed_iso))
+    }
+
+    fn date_to_iso(&self, date: &Self::DateInner) -> Date<Iso> {
+        let fixed_dangi = chinese_based::fixed_from_chinese_based(date.0);
+        Iso::iso_from_fixed(fixed_dangi)
+    }
+
+    fn months_in_year(&self, date: &Self::DateInner) -> u8 {
+        date.0.months_in_year()
+    }
+
+    fn days_in_year(&self, date: &Self::DateInner) -> u32 {
+        ChineseBasedYearInfo::for_year::<Self>(date.0.year).days_in_year()
+    }
+
+    fn days_in_month(&self, date: &Self::DateInner) -> u8 {
+        date.0.days_in_month()
+    }
+
+    fn day_of_week(&self, date: &Self::DateInner) -> types::IsoWeekday {
+        Iso.day_of_week(Dangi.date_to_iso(date).inner())
+    }
+
+    fn offset_date(
+        &self,
+        date: &mut Self::DateInner,
+        offset: DateDuration<Self>,
+        overflow: types::Overflow,
+    ) -> Result<(), DateTimeError> {
+        date.0.offset_date(offset, overflow)
+    }
+
+    fn until(
+        &self,
+        date1: &Self::DateInner,
+        date2: &Self::DateInner,
+        _calendar2: &Self,
+        largest_unit: DateDurationUnit,
+        smallest_unit: DateDurationUnit,
+    ) -> DateDuration<Self> {
+        date1.0.until(date2.0, largest_unit, smallest_unit)
+    }
+
+    fn year(&self, date: &Self::DateInner) -> types::FormattableYear {
+        year_as_dangi(date.0.year)
+    }
+
+    fn month(&self, date: &Self::DateInner) -> types::FormattableMonth {
+        types::FormattableMonth {
+            ordinal: date.0.month as u32,
+            code: ChineseBasedYearInfo::for_year::<Self>(date.0.year)
+                .code_from_ordinal(date.0.month),
+        }
+    }
+
+    fn day_of_month(&self, date: &Self::DateInner) -> types::DayOfMonth {
+        date.0.day_of_month()
+    }
+
+    fn day_of_year_info(&self, date: &Self::DateInner) -> types::DayOfYearInfo {
+        let prev_year = date.0.year - 1;
+        let next_year = date.0.year + 1;
+        types::DayOfYearInfo {
+            day_of_year: date.0.day_of_year(),
+            days_in_year: ChineseBasedYearInfo::for_year::<Self>(date.0.year).days_in_year(),
+            prev_year: year_as_dangi(prev_year),
+            days_in_prev_year: ChineseBasedYearInfo::for_year::<Self>(prev_year).days_in_year(),
+            next_year: year_as_dangi(next_year),
+        }
+    }
+
+    fn debug_name(&self) -> &'static str {
+        "Dangi"
+    }
+
+    fn any_calendar_kind(&self) -> Option<AnyCalendarKind> {
+        Some(AnyCalendarKind::Dangi)
+    }
+}
+
+impl Date<Dangi> {
+    /// Construct new Dangi Date.
+    ///
+    /// The year is counted from 2333 B.C.E., and the month is the ordinal of the month in the
+    /// year, so that the leap month and the months after it are one greater than their number.
+    /// Use [`Date::new_from_codes`] to construct a date from a month code instead.
+    ///
+    /// ```rust
+    /// use icu::calendar::Date;
+    ///
+    /// // The leap month following the second month of 2023
+    /// let date_dangi =
+    ///     Date::new_dangi_date(4356, 3, 1).expect("Failed to initialize Dangi Date instance.");
+    ///
+    /// assert_eq!(date_dangi.year().number, 4356);
+    /// assert_eq!(date_dangi.year().related_iso, Some(2023));
+    /// assert_eq!(date_dangi.month().ordinal, 3);
+    /// assert_eq!(date_dangi.month().code.0, "M02L");
+    /// assert_eq!(date_dangi.day_of_month().0, 1);
+    /// ```
+    pub fn new_dangi_date(year: i32, month: u8, day: u8) -> Result<Date<Dangi>, DateTimeError> {
+        let inner = ArithmeticDate::new(year, month, day);
+
+        let bound = inner.days_in_month();
+        if day == 0 || day > bound {
+            return Err(DateTimeError::OutOfRange);
+        }
+
+        Ok(Date::from_raw(DangiDateInner(inner), Dangi))
+    }
+}
+
+impl DateTime<Dangi> {
+    /// Construct a new Dangi datetime from integers.
+    ///
+    /// See [`Date::new_dangi_date`] for how years and months are numbered.
+    ///
+    /// ```rust
+    /// use icu::calendar::DateTime;
+    ///
+    /// let datetime_dangi = DateTime::new_dangi_datetime(4356, 3, 1, 13, 1, 0)
+    ///     .expect("Failed to initialize Dangi DateTime instance.");
+    ///
+    /// assert_eq!(datetime_dangi.date.year().number, 4356);
+    /// assert_eq!(datetime_dangi.date.month().ordinal, 3);
+    /// assert_eq!(datetime_dangi.date.day_of_month().0, 1);
+    /// assert_eq!(datetime_dangi.time.hour.number(), 13);
+    /// assert_eq!(datetime_dangi.time.minute.number(), 1);
+    /// assert_eq!(datetime_dangi.time.second.number(), 0);
+    /// ```
+    pub fn new_dangi_datetime(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> Result<DateTime<Dangi>, DateTimeError> {
+        Ok(DateTime {
+            date: Date::new_dangi_date(year, month, day)?,
+            time: types::Time::try_new(hour, minute, second, 0)?,
+        })
+    }
+}
+
+fn year_as_dangi(year: i32) -> types::FormattableYear {
+    chinese_based::year_as_chinese_based::<Dangi>(types::Era(tinystr!(16, "dangi")), year)
+}
+
+/// The precomputed year data for the related ISO years 1900 to 2099, see [`ChineseBased::YEAR_DATA`].
+#[rustfmt::skip]
+const DANGI_YEAR_DATA: &[u32] = &[
+    0x3d36d2, 0x620752, 0x4c0ea5, 0x38ce4a, 0x5c054b, 0x440a97, 0x30b556, 0x56056a,
+    0x400b55, 0x2a7752, 0x500752, 0x3af725, 0x600b25, 0x480a4b, 0x32d29b, 0x580aad,
+    0x44056a, 0x2c6b69, 0x520ba9, 0x3f1b52, 0x640d92, 0x4c0d25, 0x36da4d, 0x5c0956,
+    0x4602b5, 0x2eb5ad, 0x5606d4, 0x400da9, 0x2c7d92, 0x500e92, 0x3aed26, 0x5e0527,
+    0x480a57, 0x32d2b6, 0x580ada, 0x4406d4, 0x2e8ea9, 0x520749, 0x3d1693, 0x620a93,
+    0x4c052b, 0x34ea5b, 0x5a096d, 0x460b6a, 0x32bb54, 0x560ba4, 0x400b49, 0x2a7a93,
+    0x500a95, 0x39152b, 0x5e052d, 0x480aad, 0x34d56a, 0x580db2, 0x440da4, 0x2e9d49,
+    0x540d4a, 0x3d3a95, 0x620a96, 0x4c0556, 0x36eab5, 0x5a0ad5, 0x4606d2, 0x30aea5,
+    0x560ea5, 0x400e4a, 0x2a8c96, 0x4e0a9b, 0x3b1556, 0x5e056a, 0x480b59, 0x34d752,
+    0x5a0752, 0x420725, 0x2cb64b, 0x520a4b, 0x3d32ab, 0x6002ad, 0x4a056b, 0x36eb69,
+    0x5c0da9, 0x460d92, 0x30bb25, 0x560d25, 0x417a4d, 0x640a56, 0x4e02b6, 0x38f5ad,
+    0x6006d4, 0x480da9, 0x34dd92, 0x5a0e92, 0x440d26, 0x2c8a56, 0x500a57, 0x3d32b6,
+    0x620b5a, 0x4c06d4, 0x36cec9, 0x5c0749, 0x460693, 0x2eb527, 0x54052b, 0x3e0a5b,
+    0x2a755a, 0x4e036a, 0x391b55, 0x600ba4, 0x4a0b49, 0x32da93, 0x580a95, 0x42052d,
+    0x2c8a5d, 0x500aad, 0x3d55aa, 0x6205d2, 0x4c0da5, 0x36dd4a, 0x5c0d4a, 0x460a95,
+    0x30b52d, 0x540556, 0x3e0ab5, 0x2a75aa, 0x5006d2, 0x38eea5, 0x5e0ea5, 0x4a0e4a,
+    0x34cc96, 0x560c9b, 0x42055a, 0x2c8ad5, 0x520b69, 0x3d9752, 0x620752, 0x4c0b25,
+    0x36f64b, 0x5a0a4b, 0x4404ab, 0x2ec55b, 0x54056d, 0x3e0b69, 0x2a7b52, 0x500d92,
+    0x3b1d25, 0x5e0d25, 0x480a4d, 0x32d4ad, 0x5802b6, 0x4005b5, 0x2c8da9, 0x520ea9,
+    0x3f3d92, 0x620e92, 0x4c0d26, 0x36ea56, 0x5a0a57, 0x4404d6, 0x2ea6b5, 0x5406d5,
+    0x400ec9, 0x2a8e92, 0x4e0693, 0x39152b, 0x5e052b, 0x460a5b, 0x32d55a, 0x58056a,
+    0x420b55, 0x2cb749, 0x520b49, 0x3d3a93, 0x620a95, 0x4a052d, 0x34eaad, 0x5a0ab5,
+    0x4605aa, 0x2eaba5, 0x540da5, 0x400d4a, 0x2a9a95, 0x4e0c95, 0x39152e, 0x5e0556,
+    0x480ab5, 0x32d5b2, 0x5806d2, 0x420ea5, 0x2ebe4a, 0x52064a, 0x3b2c97, 0x600cab,
+    0x4c055a, 0x34ead5, 0x5a0b69, 0x460752, 0x30b6a5, 0x540b25, 0x3e064b, 0x289497,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_year() {
+        // (related ISO year, ISO date of Seollal)
+        let cases = [
+            (1900, (1900, 1, 31)),
+            // Seollal was a day after the Chinese New Year in 1997, 2027 and 2028
+            (1997, (1997, 2, 8)),
+            (2023, (2023, 1, 22)),
+            (2024, (2024, 2, 10)),
+            (2027, (2027, 2, 7)),
+            (2028, (2028, 1, 27)),
+        ];
+        for (related_iso, (iso_year, iso_month, iso_day)) in cases {
+            let year = related_iso + Dangi::RELATED_ISO_OFFSET;
+            let iso = Date::new_iso_date(iso_year, iso_month, iso_day).unwrap();
+            let dangi = Date::new_dangi_date(year, 1, 1).unwrap();
+            assert_eq!(dangi.to_iso(), iso, "{related_iso}");
+            assert_eq!(iso.to_calendar(Dangi), dangi, "{related_iso}");
+            assert_eq!(dangi.year().related_iso, Some(related_iso));
+        }
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let start = Iso::fixed_from_iso(*Date::new_iso_date(1850, 1, 1).unwrap().inner());
+        let end = Iso::fixed_from_iso(*Date::new_iso_date(2150, 1, 1).unwrap().inner());
+        for fixed in start..end {
+            let iso = Iso::iso_from_fixed(fixed);
+            assert_eq!(iso.to_calendar(Dangi).to_iso(), iso);
+        }
+    }
+}
@@ -3,9 +3,13 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use crate::any_calendar::{AnyCalendar, IntoAnyCalendar};
-use crate::{types, Calendar, DateDuration, DateDurationUnit, DateTimeError, Iso};
+use crate::week_of::WeekendInfo;
+use crate::{
+    types, Calendar, DateDuration, DateDurationUnit, DateTimeError, Iso, RataDie, RoundingMode,
+};
 use alloc::rc::Rc;
 use alloc::sync::Arc;
+use core::convert::TryFrom;
 use core::fmt;
 use core::ops::Deref;
 
@@ -103,6 +107,9 @@ pub struct Date<A: AsCalendar> {
 
 impl<A: AsCalendar> Date<A> {
     /// Construct a date from from era/month codes and fields, and some calendar representation
+    ///
+    /// Days past the end of the month and leap months that don't exist in the year are
+    /// rejected; use [`Date::new_from_codes_with_overflow()`] to constrain them instead.
     #[inline]
     pub fn new_from_codes(
         era: types::Era,
@@ -110,10 +117,64 @@ impl<A: AsCalendar> Date<A> {
         month_code: types::MonthCode,
         day: u8,
         calendar: A,
+    ) -> Result<Self, DateTimeError> {
+        Self::new_from_codes_with_overflow(
+            era,
+            year,
+            month_code,
+            day,
+            types::Overflow::Reject,
+            calendar,
+        )
+    }
+
+    /// Construct a date from from era/month codes and fields, and some calendar representation,
+    /// handling days past the end of the month and leap months that don't exist in the year
+    /// according to `overflow`
+    ///
+    /// ```rust
+    /// use icu::calendar::types::{Era, MonthCode, Overflow};
+    /// use icu::calendar::{hebrew::Hebrew, Date, Iso};
+    /// use tinystr::tinystr;
+    ///
+    /// let era = Era(tinystr!(16, "default"));
+    /// let february = MonthCode(tinystr!(4, "M02"));
+    ///
+    /// let date =
+    ///     Date::new_from_codes_with_overflow(era, 2023, february, 30, Overflow::Constrain, Iso)
+    ///         .unwrap();
+    /// assert_eq!(date, Date::new_iso_date(2023, 2, 28).unwrap());
+    ///
+    /// assert!(
+    ///     Date::new_from_codes_with_overflow(era, 2023, february, 30, Overflow::Reject, Iso)
+    ///         .is_err()
+    /// );
+    ///
+    /// // Adar I becomes Adar in a common year
+    /// let date = Date::new_from_codes_with_overflow(
+    ///     Era(tinystr!(16, "am")),
+    ///     5783,
+    ///     MonthCode(tinystr!(4, "M05L")),
+    ///     30,
+    ///     Overflow::Constrain,
+    ///     Hebrew,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(date.month().code.0, "M06");
+    /// assert_eq!(date.day_of_month().0, 29);
+    /// ```
+    #[inline]
+    pub fn new_from_codes_with_overflow(
+        era: types::Era,
+        year: i32,
+        month_code: types::MonthCode,
+        day: u8,
+        overflow: types::Overflow,
+        calendar: A,
     ) -> Result<Self, DateTimeError> {
         let inner = calendar
             .as_calendar()
-            .date_from_codes(era, year, month_code, day)?;
+            .date_from_codes(era, year, month_code, day, overflow)?;
         Ok(Date { inner, calendar })
     }
 
@@ -130,6 +191,28 @@ impl<A: AsCalendar> Date<A> {
         self.calendar.as_calendar().date_to_iso(self.inner())
     }
 
+    /// The number of days since R.D. 1, i.e. 0001-01-01 in the proleptic ISO calendar.
+    ///
+    /// This is the "fixed" day number used in the Calendrical Calculations algorithms; see
+    /// [`Date::to_rata_die`] for the public equivalent.
+    #[inline]
+    pub(crate) fn to_fixed(&self) -> i32 {
+        Iso::fixed_from_iso(*self.to_iso().inner())
+    }
+
+    /// Construct a date from a [`RataDie`] and some calendar representation
+    #[inline]
+    pub fn from_rata_die(rata_die: RataDie, calendar: A) -> Self {
+        Self::new_from_iso(Iso::iso_from_fixed(rata_die.to_i32_date()), calendar)
+    }
+
+    /// The [`RataDie`] of this date, which can be used to convert it to other day numberings
+    /// such as the Julian Day Number
+    #[inline]
+    pub fn to_rata_die(&self) -> RataDie {
+        RataDie::new(self.to_fixed())
+    }
+
     /// Convert the Date to a date in a different calendar
     #[inline]
     pub fn to_calendar<A2: AsCalendar>(&self, calendar: A2) -> Date<A2> {
@@ -162,45 +245,264 @@ impl<A: AsCalendar> Date<A> {
         self.calendar.as_calendar().day_of_week(self.inner())
     }
 
+    /// Whether this date falls on the weekend described by `weekend`
+    ///
+    /// ```rust
+    /// use icu::calendar::week_of::WeekendInfo;
+    /// use icu::calendar::Date;
+    ///
+    /// // A Saturday
+    /// let date = Date::new_iso_date(2023, 7, 1).unwrap();
+    /// assert!(date.is_weekend(&WeekendInfo::default()));
+    /// ```
+    #[inline]
+    pub fn is_weekend(&self, weekend: &WeekendInfo) -> bool {
+        weekend.is_weekend(self.day_of_week())
+    }
+
+    /// Add `days` business days, i.e. days that are not part of `weekend`, to this date,
+    /// returning the new one
+    ///
+    /// Like the `WORKDAY` function of spreadsheets, this moves forwards (or backwards, if `days`
+    /// is negative) until `days` business days have been passed, not counting the date itself.
+    /// The result is therefore always a business day, unless `days` is zero, in which case the
+    /// date is returned unchanged. If `weekend` covers the whole week, the date is returned
+    /// unchanged as well. An error is returned if the result is outside the range of dates.
+    ///
+    /// ```rust
+    /// use icu::calendar::types::IsoWeekday;
+    /// use icu::calendar::week_of::WeekendInfo;
+    /// use icu::calendar::Date;
+    ///
+    /// let friday_saturday = WeekendInfo {
+    ///     weekend_start: IsoWeekday::Friday,
+    ///     weekend_end: IsoWeekday::Saturday,
+    /// };
+    ///
+    /// // A Thursday
+    /// let date = Date::new_iso_date(2023, 7, 6).unwrap();
+    ///
+    /// let next = date.clone().next_business_day(&friday_saturday).unwrap();
+    /// assert_eq!(next, Date::new_iso_date(2023, 7, 9).unwrap());
+    ///
+    /// let next = date.clone().next_business_day(&WeekendInfo::default()).unwrap();
+    /// assert_eq!(next, Date::new_iso_date(2023, 7, 7).unwrap());
+    ///
+    /// let earlier = date.added_business_days(-10, &friday_saturday).unwrap();
+    /// assert_eq!(earlier, Date::new_iso_date(2023, 6, 22).unwrap());
+    /// ```
+    pub fn added_business_days(
+        self,
+        days: i32,
+        weekend: &WeekendInfo,
+    ) -> Result<Self, DateTimeError> {
+        let business_days_per_week = weekend.business_days_per_week() as i64;
+        if days == 0 || business_days_per_week == 0 {
+            return Ok(self);
+        }
+        let step = days.signum() as i64;
+        let mut remaining = (days as i64).abs();
+        // Any 7 consecutive days contain the same number of business days, so whole weeks can
+        // be skipped at once, as long as some business days remain to end on one
+        let weeks = (remaining - 1) / business_days_per_week;
+        remaining -= weeks * business_days_per_week;
+        // Computed in i64, as skipping whole weeks can move past the range of an i32 day number
+        let mut fixed = self.to_fixed() as i64 + step * 7 * weeks;
+        let mut weekday = self.day_of_week() as i64;
+        while remaining > 0 {
+            fixed += step;
+            weekday += step;
+            if !weekend.is_weekend(types::IsoWeekday::from(weekday.rem_euclid(7) as usize)) {
+                remaining -= 1;
+            }
+        }
+        let fixed = i32::try_from(fixed).map_err(|_| DateTimeError::OutOfRange)?;
+        Ok(Self::from_rata_die(RataDie::new(fixed), self.calendar))
+    }
+
+    /// Returns the next business day after this date, i.e. the next day that is not part of
+    /// `weekend`
+    ///
+    /// See [`Date::added_business_days()`] for details.
+    #[inline]
+    pub fn next_business_day(self, weekend: &WeekendInfo) -> Result<Self, DateTimeError> {
+        self.added_business_days(1, weekend)
+    }
+
     /// Add a `duration` to this date, mutating it
     ///
-    /// Currently unstable for ICU4X 1.0
-    #[doc(hidden)]
+    /// As in Temporal, the years and months of `duration` are added first. If the resulting
+    /// day doesn't exist, such as when adding a month to January 31, or a year to a leap
+    /// month, it is constrained to the end of the month (or to the month the leap month
+    /// repeats) with [`Overflow::Constrain`](types::Overflow::Constrain). With
+    /// [`Overflow::Reject`](types::Overflow::Reject), an error is returned and the date is
+    /// left unchanged instead. Weeks and days are added afterwards.
+    ///
+    /// ```rust
+    /// use icu::calendar::types::Overflow;
+    /// use icu::calendar::{Date, DateDuration};
+    ///
+    /// let mut date = Date::new_iso_date(2023, 1, 31).unwrap();
+    ///
+    /// assert!(date
+    ///     .add(DateDuration::new(0, 1, 0, 0), Overflow::Reject)
+    ///     .is_err());
+    /// assert_eq!(date, Date::new_iso_date(2023, 1, 31).unwrap());
+    ///
+    /// date.add(DateDuration::new(0, 1, 0, 0), Overflow::Constrain)
+    ///     .unwrap();
+    /// assert_eq!(date, Date::new_iso_date(2023, 2, 28).unwrap());
+    /// ```
     #[inline]
-    pub fn add(&mut self, duration: DateDuration<A::Calendar>) {
+    pub fn add(
+        &mut self,
+        duration: DateDuration<A::Calendar>,
+        overflow: types::Overflow,
+    ) -> Result<(), DateTimeError> {
         self.calendar
             .as_calendar()
-            .offset_date(&mut self.inner, duration)
+            .offset_date(&mut self.inner, duration, overflow)
     }
 
     /// Add a `duration` to this date, returning the new one
     ///
-    /// Currently unstable for ICU4X 1.0
-    #[doc(hidden)]
+    /// See [`Date::add()`] for how `overflow` is handled.
     #[inline]
-    pub fn added(mut self, duration: DateDuration<A::Calendar>) -> Self {
-        self.add(duration);
-        self
+    pub fn added(
+        mut self,
+        duration: DateDuration<A::Calendar>,
+        overflow: types::Overflow,
+    ) -> Result<Self, DateTimeError> {
+        self.add(duration, overflow)?;
+        Ok(self)
     }
 
-    /// Calculating the duration between `other - self`
+    /// Calculate the duration from `self` to `other`, which results in `other` when it is
+    /// added to `self`
+    ///
+    /// Like Temporal's `PlainDate.prototype.until`, the duration has no units larger than
+    /// `largest_unit`. Years and months are counted first, with the day constrained to the
+    /// end of shorter months, followed by the remaining days. Weeks are only used if
+    /// `largest_unit` or `smallest_unit` is [`DateDurationUnit::Weeks`]. The fields of the
+    /// duration are negative if `other` is before `self`.
+    ///
+    /// If `other` is between two multiples of `smallest_unit` from `self`, `smallest_unit` is
+    /// rounded according to `rounding_mode`, by how far `other` is in days between these two
+    /// dates, also as in Temporal. The result is then balanced again, so that, for example,
+    /// rounding up eleven months and a half results in a year when `largest_unit` is
+    /// [`DateDurationUnit::Years`].
+    ///
+    /// ```rust
+    /// use icu::calendar::{Date, DateDurationUnit, RoundingMode};
     ///
-    /// Currently unstable for ICU4X 1.0
-    #[doc(hidden)]
+    /// let birthday = Date::new_iso_date(1990, 2, 28).unwrap();
+    /// let today = Date::new_iso_date(2024, 2, 27).unwrap();
+    ///
+    /// let age = birthday.until(
+    ///     &today,
+    ///     DateDurationUnit::Years,
+    ///     DateDurationUnit::Days,
+    ///     RoundingMode::Trunc,
+    /// );
+    /// assert_eq!((age.years, age.months, age.days), (33, 11, 30));
+    ///
+    /// let age = birthday.until(
+    ///     &today,
+    ///     DateDurationUnit::Years,
+    ///     DateDurationUnit::Years,
+    ///     RoundingMode::Trunc,
+    /// );
+    /// assert_eq!((age.years, age.months, age.days), (33, 0, 0));
+    ///
+    /// let age = birthday.until(
+    ///     &today,
+    ///     DateDurationUnit::Years,
+    ///     DateDurationUnit::Years,
+    ///     RoundingMode::HalfExpand,
+    /// );
+    /// assert_eq!((age.years, age.months, age.days), (34, 0, 0));
+    ///
+    /// let due_date = Date::new_iso_date(2024, 3, 20).unwrap();
+    /// let remaining = today.until(
+    ///     &due_date,
+    ///     DateDurationUnit::Weeks,
+    ///     DateDurationUnit::Days,
+    ///     RoundingMode::Trunc,
+    /// );
+    /// assert_eq!((remaining.weeks, remaining.days), (3, 1));
+    ///
+    /// let remaining = today.until(
+    ///     &due_date,
+    ///     DateDurationUnit::Weeks,
+    ///     DateDurationUnit::Weeks,
+    ///     RoundingMode::Ceil,
+    /// );
+    /// assert_eq!((remaining.weeks, remaining.days), (4, 0));
+    /// ```
     #[inline]
     pub fn until<B: AsCalendar<Calendar = A::Calendar>>(
         &self,
         other: &Date<B>,
         largest_unit: DateDurationUnit,
         smallest_unit: DateDurationUnit,
+        rounding_mode: RoundingMode,
     ) -> DateDuration<A::Calendar> {
-        self.calendar.as_calendar().until(
+        let calendar = self.calendar.as_calendar();
+        let duration = calendar.until(
             self.inner(),
             other.inner(),
             other.calendar.as_calendar(),
             largest_unit,
             smallest_unit,
-        )
+        );
+        let added = |duration: &DateDuration<A::Calendar>| {
+            let mut date = Date::from_raw(self.inner.clone(), Ref(calendar));
+            let duration = DateDuration::new(
+                duration.years,
+                duration.months,
+                duration.weeks,
+                duration.days,
+            );
+            // Adding with `Overflow::Constrain` can't fail
+            let _ = date.add(duration, types::Overflow::Constrain);
+            date
+        };
+
+        // `duration` is truncated, so `other` is at or past the date it results in
+        let truncated = added(&duration).to_fixed();
+        let remainder = other.to_fixed() - truncated;
+        if remainder == 0 {
+            return duration;
+        }
+        let sign = remainder.signum();
+        let mut expanded = DateDuration::new(
+            duration.years,
+            duration.months,
+            duration.weeks,
+            duration.days,
+        );
+        let unit = match smallest_unit {
+            DateDurationUnit::Years => &mut expanded.years,
+            DateDurationUnit::Months => &mut expanded.months,
+            DateDurationUnit::Weeks => &mut expanded.weeks,
+            DateDurationUnit::Days => &mut expanded.days,
+        };
+        let odd = *unit % 2 != 0;
+        *unit += sign;
+        let end = added(&expanded);
+        let unit_days = (end.to_fixed() - truncated).abs();
+        if rounding_mode.rounds_away_from_zero(sign < 0, (2 * remainder.abs()).cmp(&unit_days), odd)
+        {
+            calendar.until(
+                self.inner(),
+                end.inner(),
+                calendar,
+                largest_unit,
+                smallest_unit,
+            )
+        } else {
+            duration
+        }
     }
 
     /// The calendar-specific year represented by `self`
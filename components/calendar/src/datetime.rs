@@ -3,8 +3,17 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use crate::any_calendar::{AnyCalendar, IntoAnyCalendar};
-use crate::types::{self, Time};
-use crate::{AsCalendar, Calendar, Date, DateTimeError, Iso};
+use crate::rata_die::UNIX_EPOCH;
+use crate::types::{self, Overflow, Time, NANOSECONDS_PER_DAY, NANOSECONDS_PER_SECOND};
+use crate::{
+    AsCalendar, Calendar, Date, DateDuration, DateDurationUnit, DateTimeError, Iso, RataDie, Ref,
+    RoundingMode, TimeDuration,
+};
+use core::convert::TryFrom;
+
+// Like Temporal, datetimes built from a time since the Unix epoch are limited to 10^8 days on
+// either side of it, well within the range that calendar conversions handle without overflow
+const MAX_DAYS_FROM_UNIX_EPOCH: i128 = 100_000_000;
 
 /// A date+time for a given calendar.
 ///
@@ -83,6 +92,235 @@ impl<A: AsCalendar> DateTime<A> {
             time: self.time,
         }
     }
+
+    /// Construct a datetime from the number of seconds since the local Unix epoch, 1970-01-01
+    /// 00:00:00, and some calendar representation
+    ///
+    /// Like all [`DateTime`]s, the result is in local time, without a time zone. Returns an
+    /// error if the date is more than 100,000,000 days away from the epoch, which is the range
+    /// supported by Temporal.
+    ///
+    /// ```rust
+    /// use icu::calendar::{DateTime, Iso};
+    ///
+    /// let datetime = DateTime::from_seconds_since_local_unix_epoch(1_000_000_000, Iso).unwrap();
+    /// assert_eq!(
+    ///     datetime,
+    ///     DateTime::new_iso_datetime(2001, 9, 9, 1, 46, 40).unwrap()
+    /// );
+    /// assert_eq!(datetime.seconds_since_local_unix_epoch(), 1_000_000_000);
+    /// ```
+    pub fn from_seconds_since_local_unix_epoch(
+        seconds: i64,
+        calendar: A,
+    ) -> Result<Self, DateTimeError> {
+        Self::from_nanoseconds_since_local_unix_epoch(
+            seconds as i128 * NANOSECONDS_PER_SECOND as i128,
+            calendar,
+        )
+    }
+
+    /// Construct a datetime from the number of milliseconds since the local Unix epoch,
+    /// 1970-01-01 00:00:00, and some calendar representation
+    ///
+    /// See [`DateTime::from_seconds_since_local_unix_epoch()`] for details.
+    ///
+    /// ```rust
+    /// use icu::calendar::{DateTime, Iso};
+    ///
+    /// let datetime = DateTime::from_milliseconds_since_local_unix_epoch(-1, Iso).unwrap();
+    /// assert_eq!(datetime.date, DateTime::new_iso_datetime(1969, 12, 31, 0, 0, 0).unwrap().date);
+    /// assert_eq!(datetime.time.nanosecond.number(), 999_000_000);
+    /// assert_eq!(datetime.milliseconds_since_local_unix_epoch(), -1);
+    /// ```
+    pub fn from_milliseconds_since_local_unix_epoch(
+        milliseconds: i64,
+        calendar: A,
+    ) -> Result<Self, DateTimeError> {
+        Self::from_nanoseconds_since_local_unix_epoch(milliseconds as i128 * 1_000_000, calendar)
+    }
+
+    /// Construct a datetime from the number of nanoseconds since the local Unix epoch,
+    /// 1970-01-01 00:00:00, and some calendar representation
+    ///
+    /// See [`DateTime::from_seconds_since_local_unix_epoch()`] for details.
+    pub fn from_nanoseconds_since_local_unix_epoch(
+        nanoseconds: i128,
+        calendar: A,
+    ) -> Result<Self, DateTimeError> {
+        let (time, days) = Time::from_nanoseconds_since_midnight(nanoseconds);
+        if days.abs() > MAX_DAYS_FROM_UNIX_EPOCH {
+            return Err(DateTimeError::OutOfRange);
+        }
+        let fixed = UNIX_EPOCH.to_i32_date() + days as i32;
+        Ok(DateTime {
+            date: Date::from_rata_die(RataDie::new(fixed), calendar),
+            time,
+        })
+    }
+
+    /// The number of seconds since the local Unix epoch, 1970-01-01 00:00:00, rounded down
+    ///
+    /// A leap second (a `second` of 60) is counted as the first second of the next minute.
+    pub fn seconds_since_local_unix_epoch(&self) -> i64 {
+        // Within i64, since the date is within the range of an i32 day number
+        self.nanoseconds_since_local_unix_epoch()
+            .div_euclid(NANOSECONDS_PER_SECOND as i128) as i64
+    }
+
+    /// The number of milliseconds since the local Unix epoch, 1970-01-01 00:00:00, rounded
+    /// down
+    ///
+    /// A leap second (a `second` of 60) is counted as the first second of the next minute.
+    pub fn milliseconds_since_local_unix_epoch(&self) -> i64 {
+        // Within i64, since the date is within the range of an i32 day number
+        self.nanoseconds_since_local_unix_epoch()
+            .div_euclid(1_000_000) as i64
+    }
+
+    /// The number of nanoseconds since the local Unix epoch, 1970-01-01 00:00:00
+    ///
+    /// A leap second (a `second` of 60) is counted as the first second of the next minute.
+    ///
+    /// ```rust
+    /// use icu::calendar::types::Time;
+    /// use icu::calendar::{DateTime, Iso};
+    ///
+    /// let datetime = DateTime::new(
+    ///     DateTime::new_iso_datetime(1970, 1, 2, 0, 0, 0).unwrap().date,
+    ///     Time::try_new(0, 0, 1, 500).unwrap(),
+    /// );
+    /// let nanoseconds = datetime.nanoseconds_since_local_unix_epoch();
+    /// assert_eq!(nanoseconds, 86_401_000_000_500);
+    /// assert_eq!(
+    ///     DateTime::from_nanoseconds_since_local_unix_epoch(nanoseconds, Iso),
+    ///     Ok(datetime)
+    /// );
+    /// ```
+    pub fn nanoseconds_since_local_unix_epoch(&self) -> i128 {
+        let days = self.date.to_rata_die().to_i32_date() - UNIX_EPOCH.to_i32_date();
+        days as i128 * NANOSECONDS_PER_DAY as i128 + self.time.nanoseconds_since_midnight() as i128
+    }
+
+    /// Add a date and a time duration to this datetime, mutating it
+    ///
+    /// The `date_duration` is added first, as in [`Date::add`], after which the `time_duration`
+    /// is added to the time, with any overflow of the time fields rolling into days. The
+    /// `overflow` option only applies to adding `date_duration`. An error is returned if the
+    /// `time_duration` spans more days than a [`DateDuration`] can hold.
+    ///
+    /// ```rust
+    /// use icu::calendar::types::Overflow;
+    /// use icu::calendar::{DateDuration, DateTime, TimeDuration};
+    ///
+    /// let mut datetime = DateTime::new_iso_datetime(2020, 1, 31, 18, 0, 0).unwrap();
+    ///
+    /// datetime
+    ///     .add(
+    ///         DateDuration::new(0, 1, 0, 0),
+    ///         TimeDuration::new(6, 0, 0, 0),
+    ///         Overflow::Constrain,
+    ///     )
+    ///     .unwrap();
+    /// assert_eq!(datetime.date.month().ordinal, 3);
+    /// assert_eq!(datetime.date.day_of_month().0, 1);
+    /// assert_eq!(datetime.time.hour.number(), 0);
+    ///
+    /// datetime
+    ///     .add(
+    ///         DateDuration::default(),
+    ///         TimeDuration::new(0, -1, 0, 0),
+    ///         Overflow::Reject,
+    ///     )
+    ///     .unwrap();
+    /// assert_eq!(datetime.date.month().ordinal, 2);
+    /// assert_eq!(datetime.date.day_of_month().0, 29);
+    /// assert_eq!(datetime.time.hour.number(), 23);
+    /// assert_eq!(datetime.time.minute.number(), 59);
+    /// ```
+    pub fn add(
+        &mut self,
+        date_duration: DateDuration<A::Calendar>,
+        time_duration: TimeDuration,
+        overflow: Overflow,
+    ) -> Result<(), DateTimeError> {
+        let (time, days) = Time::from_nanoseconds_since_midnight(
+            self.time.nanoseconds_since_midnight() as i128 + time_duration.total_nanoseconds(),
+        );
+        let days = i32::try_from(days).map_err(|_| DateTimeError::OutOfRange)?;
+        self.date.add(date_duration, overflow)?;
+        if days != 0 {
+            self.date
+                .add(DateDuration::new(0, 0, 0, days), Overflow::Constrain)?;
+        }
+        self.time = time;
+        Ok(())
+    }
+
+    /// Add a date and a time duration to this datetime, returning the new one
+    ///
+    /// See [`DateTime::add`] for details.
+    pub fn added(
+        mut self,
+        date_duration: DateDuration<A::Calendar>,
+        time_duration: TimeDuration,
+        overflow: Overflow,
+    ) -> Result<Self, DateTimeError> {
+        self.add(date_duration, time_duration, overflow)?;
+        Ok(self)
+    }
+
+    /// Calculate the duration from `self` to `other`, which results in `other` when it is
+    /// added to `self`
+    ///
+    /// The date part of the duration is computed as in [`Date::until`], with no units larger
+    /// than `largest_unit`, after the time difference has been balanced so that both parts
+    /// have the same sign. The time part is always less than a day, with no unit but
+    /// `hours` overflowing into the next larger one.
+    ///
+    /// ```rust
+    /// use icu::calendar::{DateDurationUnit, DateTime, TimeDuration};
+    ///
+    /// let start = DateTime::new_iso_datetime(2024, 1, 30, 22, 15, 0).unwrap();
+    /// let end = DateTime::new_iso_datetime(2024, 3, 1, 9, 0, 30).unwrap();
+    ///
+    /// let (date, time) = start.until(&end, DateDurationUnit::Months);
+    /// assert_eq!((date.months, date.days), (1, 0));
+    /// assert_eq!(time, TimeDuration::new(10, 45, 30, 0));
+    ///
+    /// let (date, time) = end.until(&start, DateDurationUnit::Days);
+    /// assert_eq!(date.days, -30);
+    /// assert_eq!(time, TimeDuration::new(-10, -45, -30, 0));
+    /// ```
+    pub fn until<B: AsCalendar<Calendar = A::Calendar>>(
+        &self,
+        other: &DateTime<B>,
+        largest_unit: DateDurationUnit,
+    ) -> (DateDuration<A::Calendar>, TimeDuration) {
+        let mut time_difference =
+            other.time.nanoseconds_since_midnight() - self.time.nanoseconds_since_midnight();
+        let mut other_date = Date::from_raw(other.date.inner().clone(), Ref(other.date.calendar()));
+        let time_sign = time_difference.signum();
+        let date_sign = (other.date.to_fixed() - self.date.to_fixed()).signum() as i64;
+        if time_sign != 0 && time_sign == -date_sign {
+            // Borrow a day from the date part so that both parts have the same sign
+            // Adding days never needs to be constrained, so this can't fail
+            let _ = other_date.add(
+                DateDuration::new(0, 0, 0, time_sign as i32),
+                Overflow::Constrain,
+            );
+            time_difference -= time_sign * NANOSECONDS_PER_DAY;
+        }
+        (
+            self.date.until(
+                &other_date,
+                largest_unit,
+                DateDurationUnit::Days,
+                RoundingMode::Trunc,
+            ),
+            TimeDuration::from_nanoseconds(time_difference),
+        )
+    }
 }
 
 impl<C: IntoAnyCalendar, A: AsCalendar<Calendar = C>> DateTime<A> {
@@ -117,3 +355,183 @@ impl<A: AsCalendar + Clone> Clone for DateTime<A> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hebrew::Hebrew;
+
+    #[test]
+    fn test_add_overflows_into_days() {
+        let datetime = DateTime::new_iso_datetime(2021, 12, 31, 23, 0, 0).unwrap();
+
+        // (hours, minutes, seconds, nanoseconds, expected date, expected time)
+        let cases = [
+            (1, 0, 0, 0, (2022, 1, 1), (0, 0, 0, 0)),
+            (0, 0, 0, -1, (2021, 12, 31), (22, 59, 59, 999_999_999)),
+            (0, 0, 3600, 1, (2022, 1, 1), (0, 0, 0, 1)),
+            (49, 30, 0, 0, (2022, 1, 3), (0, 30, 0, 0)),
+            (-47, 0, 0, 0, (2021, 12, 30), (0, 0, 0, 0)),
+            (0, -24 * 60 * 365, 0, 0, (2020, 12, 31), (23, 0, 0, 0)),
+        ];
+        for (hours, minutes, seconds, nanoseconds, date, time) in cases {
+            let duration = TimeDuration::new(hours, minutes, seconds, nanoseconds);
+            let result = datetime
+                .clone()
+                .added(DateDuration::default(), duration, Overflow::Reject)
+                .unwrap();
+            let expected = DateTime::new(
+                Date::new_iso_date(date.0, date.1, date.2).unwrap(),
+                Time::try_new(time.0, time.1, time.2, time.3).unwrap(),
+            );
+            assert_eq!(result, expected, "{duration:?}");
+        }
+    }
+
+    #[test]
+    fn test_add_out_of_range() {
+        let mut datetime = DateTime::new_iso_datetime(2021, 12, 31, 23, 0, 0).unwrap();
+        let result = datetime.add(
+            DateDuration::default(),
+            TimeDuration::new(i64::MAX, 0, 0, 0),
+            Overflow::Constrain,
+        );
+        assert_eq!(result, Err(DateTimeError::OutOfRange));
+        assert_eq!(
+            datetime,
+            DateTime::new_iso_datetime(2021, 12, 31, 23, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_add_leap_second() {
+        let datetime = DateTime::new(
+            Date::new_iso_date(2016, 12, 31).unwrap(),
+            Time::try_new(23, 59, 60, 0).unwrap(),
+        );
+        let result = datetime
+            .added(
+                DateDuration::default(),
+                TimeDuration::default(),
+                Overflow::Reject,
+            )
+            .unwrap();
+        assert_eq!(
+            result,
+            DateTime::new_iso_datetime(2017, 1, 1, 0, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_unix_epoch_roundtrip() {
+        let epoch = DateTime::new_iso_datetime(1970, 1, 1, 0, 0, 0).unwrap();
+        for milliseconds in [
+            0,
+            1,
+            -1,
+            86_399_999,
+            -86_400_000,
+            1_700_000_000_123,
+            -62_135_596_800_000, // 0001-01-01
+            253_402_300_799_999, // 9999-12-31 23:59:59.999
+        ] {
+            let datetime =
+                DateTime::from_milliseconds_since_local_unix_epoch(milliseconds, Hebrew).unwrap();
+            assert_eq!(datetime.milliseconds_since_local_unix_epoch(), milliseconds);
+            assert_eq!(
+                datetime.seconds_since_local_unix_epoch(),
+                milliseconds.div_euclid(1000)
+            );
+            let duration = TimeDuration::new(
+                0,
+                0,
+                milliseconds.div_euclid(1000),
+                milliseconds.rem_euclid(1000) * 1_000_000,
+            );
+            assert_eq!(
+                datetime.to_iso(),
+                epoch
+                    .clone()
+                    .added(DateDuration::default(), duration, Overflow::Reject)
+                    .unwrap(),
+                "{milliseconds}"
+            );
+        }
+
+        let nanoseconds = -1_234_567_890_123_456_789;
+        let datetime = DateTime::from_nanoseconds_since_local_unix_epoch(nanoseconds, Iso).unwrap();
+        assert_eq!(datetime.nanoseconds_since_local_unix_epoch(), nanoseconds);
+
+        assert_eq!(
+            DateTime::from_seconds_since_local_unix_epoch(i64::MAX, Iso),
+            Err(DateTimeError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_unix_epoch_range() {
+        const SECONDS_PER_DAY: i64 = 86_400;
+        let last = DateTime::from_seconds_since_local_unix_epoch(
+            100_000_000 * SECONDS_PER_DAY + SECONDS_PER_DAY - 1,
+            Iso,
+        )
+        .unwrap();
+        assert_eq!(last.date, Date::new_iso_date(275760, 9, 13).unwrap());
+        let first =
+            DateTime::from_seconds_since_local_unix_epoch(-100_000_000 * SECONDS_PER_DAY, Iso)
+                .unwrap();
+        assert_eq!(first.date, Date::new_iso_date(-271821, 4, 20).unwrap());
+
+        for seconds in [
+            100_000_001 * SECONDS_PER_DAY,
+            -100_000_000 * SECONDS_PER_DAY - 1,
+            i64::MAX,
+            i64::MIN,
+        ] {
+            assert_eq!(
+                DateTime::from_seconds_since_local_unix_epoch(seconds, Iso),
+                Err(DateTimeError::OutOfRange)
+            );
+        }
+    }
+
+    #[test]
+    fn test_until_roundtrip() {
+        let datetimes = [
+            DateTime::new_iso_datetime(2020, 2, 29, 0, 0, 0).unwrap(),
+            DateTime::new_iso_datetime(2020, 2, 29, 23, 59, 59).unwrap(),
+            DateTime::new_iso_datetime(2021, 1, 31, 12, 30, 0).unwrap(),
+            DateTime::new_iso_datetime(2021, 3, 1, 6, 0, 0).unwrap(),
+            DateTime::new_iso_datetime(2024, 12, 31, 18, 45, 15).unwrap(),
+        ];
+        for a in &datetimes {
+            for b in &datetimes {
+                for largest_unit in [
+                    DateDurationUnit::Years,
+                    DateDurationUnit::Months,
+                    DateDurationUnit::Weeks,
+                    DateDurationUnit::Days,
+                ] {
+                    let (date, time) = a.until(b, largest_unit);
+                    for field in [date.years, date.months, date.weeks, date.days] {
+                        assert!(
+                            field == 0
+                                || time.total_nanoseconds() == 0
+                                || (field > 0) == (time.total_nanoseconds() > 0),
+                            "{:?} {:?} {:?}",
+                            a,
+                            b,
+                            largest_unit
+                        );
+                    }
+                    assert!(time.hours.abs() < 24, "{:?} {:?} {:?}", a, b, largest_unit);
+                    assert_eq!(
+                        &a.clone().added(date, time, Overflow::Constrain).unwrap(),
+                        b,
+                        "{a:?} {b:?} {largest_unit:?}"
+                    );
+                }
+            }
+        }
+    }
+}
@@ -2,7 +2,9 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
+use crate::types::NANOSECONDS_PER_SECOND;
 use crate::Calendar;
+use core::cmp::Ordering;
 use core::fmt;
 use core::marker::PhantomData;
 
@@ -13,7 +15,8 @@ use core::marker::PhantomData;
 /// # Example
 ///
 /// ```rust
-/// use icu_calendar::{types::IsoWeekday, Date, DateDuration, DateDurationUnit};
+/// use icu_calendar::types::{IsoWeekday, Overflow};
+/// use icu_calendar::{Date, DateDuration, DateDurationUnit, RoundingMode};
 ///
 /// // Creating ISO date: 1992-09-02.
 /// let mut date_iso = Date::new_iso_date(1992, 9, 2)
@@ -29,13 +32,13 @@ use core::marker::PhantomData;
 /// assert_eq!(date_iso.days_in_month(), 30);
 ///
 /// // Advancing date in-place by 1 year, 2 months, 3 weeks, 4 days.
-/// date_iso.add(DateDuration::new(1, 2, 3, 4));
+/// date_iso.add(DateDuration::new(1, 2, 3, 4), Overflow::Constrain).unwrap();
 /// assert_eq!(date_iso.year().number, 1993);
 /// assert_eq!(date_iso.month().ordinal, 11);
 /// assert_eq!(date_iso.day_of_month().0, 27);
 ///
 /// // Reverse date advancement.
-/// date_iso.add(DateDuration::new(-1, -2, -3, -4));
+/// date_iso.add(DateDuration::new(-1, -2, -3, -4), Overflow::Constrain).unwrap();
 /// assert_eq!(date_iso.year().number, 1992);
 /// assert_eq!(date_iso.month().ordinal, 9);
 /// assert_eq!(date_iso.day_of_month().0, 2);
@@ -44,14 +47,21 @@ use core::marker::PhantomData;
 /// let newer_date_iso = Date::new_iso_date(2022, 1, 30)
 ///     .expect("Failed to initialize ISO Date instance.");
 ///
-/// // Comparing dates: 2022-01-30 and 1992-09-02.
-/// let duration = newer_date_iso.until(&date_iso, DateDurationUnit::Years, DateDurationUnit::Days);
-/// assert_eq!(duration.years, 30);
-/// assert_eq!(duration.months, -8);
+/// // Comparing dates: 1992-09-02 and 2022-01-30.
+/// let duration = date_iso.until(
+///     &newer_date_iso,
+///     DateDurationUnit::Years,
+///     DateDurationUnit::Days,
+///     RoundingMode::Trunc,
+/// );
+/// assert_eq!(duration.years, 29);
+/// assert_eq!(duration.months, 4);
 /// assert_eq!(duration.days, 28);
 ///
 /// // Create new date with date advancement. Reassign to new variable.
-/// let mutated_date_iso = date_iso.added(DateDuration::new(1, 2, 3, 4));
+/// let mutated_date_iso = date_iso
+///     .added(DateDuration::new(1, 2, 3, 4), Overflow::Constrain)
+///     .unwrap();
 /// assert_eq!(mutated_date_iso.year().number, 1993);
 /// assert_eq!(mutated_date_iso.month().ordinal, 11);
 /// assert_eq!(mutated_date_iso.day_of_month().0, 27);
@@ -86,6 +96,58 @@ pub enum DateDurationUnit {
     Days,
 }
 
+/// How to round the smallest unit of a duration, like the `roundingMode` option of Temporal
+///
+/// The modes are named like the rounding methods of `FixedDecimal`.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum RoundingMode {
+    /// Round towards positive infinity
+    Ceil,
+    /// Round away from zero
+    Expand,
+    /// Round towards negative infinity
+    Floor,
+    /// Round towards zero
+    Trunc,
+    /// Round to the nearest value, with ties towards positive infinity
+    HalfCeil,
+    /// Round to the nearest value, with ties away from zero
+    HalfExpand,
+    /// Round to the nearest value, with ties towards negative infinity
+    HalfFloor,
+    /// Round to the nearest value, with ties towards zero
+    HalfTrunc,
+    /// Round to the nearest value, with ties towards the even value
+    HalfEven,
+}
+
+impl RoundingMode {
+    /// Whether a value that lies strictly between two integers is rounded away from zero
+    ///
+    /// `fraction` is how the fractional part of the magnitude compares to one half, and `odd`
+    /// is whether the value truncated towards zero is odd.
+    pub(crate) fn rounds_away_from_zero(
+        self,
+        negative: bool,
+        fraction: Ordering,
+        odd: bool,
+    ) -> bool {
+        match self {
+            Self::Ceil => !negative,
+            Self::Expand => true,
+            Self::Floor => negative,
+            Self::Trunc => false,
+            _ if fraction != Ordering::Equal => fraction == Ordering::Greater,
+            Self::HalfCeil => !negative,
+            Self::HalfExpand => true,
+            Self::HalfFloor => negative,
+            Self::HalfTrunc => false,
+            Self::HalfEven => odd,
+        }
+    }
+}
+
 impl<C: Calendar + ?Sized> Default for DateDuration<C> {
     fn default() -> Self {
         Self {
@@ -138,3 +200,79 @@ impl<C: Calendar> fmt::Debug for DateDuration<C> {
             .finish()
     }
 }
+
+/// A duration of hours, minutes, seconds, and nanoseconds
+///
+/// Can be used together with a [`DateDuration`] to perform arithmetic on
+/// [`DateTime`](crate::DateTime)s. The fields do not need to be balanced: a duration of
+/// 36 hours or of -90 minutes is valid, and when added to a time overflows into days.
+///
+/// ```rust
+/// use icu_calendar::types::Overflow;
+/// use icu_calendar::{DateDuration, DateTime, TimeDuration};
+///
+/// let mut datetime = DateTime::new_iso_datetime(2022, 12, 31, 22, 30, 0)
+///     .expect("Failed to initialize ISO DateTime instance.");
+///
+/// datetime
+///     .add(
+///         DateDuration::default(),
+///         TimeDuration::new(1, 45, 0, 0),
+///         Overflow::Reject,
+///     )
+///     .expect("Failed to add duration.");
+/// assert_eq!(datetime.date.year().number, 2023);
+/// assert_eq!(datetime.date.month().ordinal, 1);
+/// assert_eq!(datetime.date.day_of_month().0, 1);
+/// assert_eq!(datetime.time.hour.number(), 0);
+/// assert_eq!(datetime.time.minute.number(), 15);
+/// ```
+#[derive(Copy, Clone, Default, Debug, Eq, PartialEq)]
+#[allow(clippy::exhaustive_structs)] // this type should be stable (and is intended to be constructed manually)
+pub struct TimeDuration {
+    /// The number of hours
+    pub hours: i64,
+    /// The number of minutes
+    pub minutes: i64,
+    /// The number of seconds
+    pub seconds: i64,
+    /// The number of nanoseconds
+    pub nanoseconds: i64,
+}
+
+impl TimeDuration {
+    /// Construct a TimeDuration
+    ///
+    /// ```rust
+    /// # use icu_calendar::*;
+    /// // one hour and thirty minutes
+    /// let duration = TimeDuration::new(1, 30, 0, 0);
+    /// ```
+    pub fn new(hours: i64, minutes: i64, seconds: i64, nanoseconds: i64) -> Self {
+        TimeDuration {
+            hours,
+            minutes,
+            seconds,
+            nanoseconds,
+        }
+    }
+
+    /// The total length of this duration in nanoseconds
+    pub(crate) fn total_nanoseconds(&self) -> i128 {
+        ((self.hours as i128 * 60 + self.minutes as i128) * 60 + self.seconds as i128)
+            * NANOSECONDS_PER_SECOND as i128
+            + self.nanoseconds as i128
+    }
+
+    /// Construct a balanced TimeDuration from a number of nanoseconds, in which all fields
+    /// have the same sign and no field but `hours` overflows into the next larger one
+    pub(crate) fn from_nanoseconds(nanoseconds: i64) -> Self {
+        let seconds = nanoseconds / NANOSECONDS_PER_SECOND;
+        TimeDuration {
+            hours: seconds / 3600,
+            minutes: seconds / 60 % 60,
+            seconds: seconds % 60,
+            nanoseconds: nanoseconds % NANOSECONDS_PER_SECOND,
+        }
+    }
+}
@@ -112,6 +112,7 @@ impl Calendar for Ethiopian {
         year: i32,
         month_code: types::MonthCode,
         day: u8,
+        overflow: types::Overflow,
     ) -> Result<Self::DateInner, DateTimeError> {
         let year = if era.0 == tinystr!(16, "incar") {
             if year <= 0 {
@@ -129,7 +130,8 @@ impl Calendar for Ethiopian {
             return Err(DateTimeError::UnknownEra(era.0, self.debug_name()));
         };
 
-        ArithmeticDate::new_from_solar(self, year, month_code, day).map(EthiopianDateInner)
+        ArithmeticDate::new_from_solar(self, year, month_code, day, overflow)
+            .map(EthiopianDateInner)
     }
     fn date_from_iso(&self, iso: Date<Iso>) -> EthiopianDateInner {
         let fixed_iso = Iso::fixed_from_iso(*iso.inner());
@@ -157,20 +159,24 @@ impl Calendar for Ethiopian {
         Iso.day_of_week(self.date_to_iso(date).inner())
     }
 
-    fn offset_date(&self, date: &mut Self::DateInner, offset: DateDuration<Self>) {
-        date.0.offset_date(offset);
+    fn offset_date(
+        &self,
+        date: &mut Self::DateInner,
+        offset: DateDuration<Self>,
+        overflow: types::Overflow,
+    ) -> Result<(), DateTimeError> {
+        date.0.offset_date(offset, overflow)
     }
 
-    #[allow(clippy::field_reassign_with_default)]
     fn until(
         &self,
         date1: &Self::DateInner,
         date2: &Self::DateInner,
         _calendar2: &Self,
-        _largest_unit: DateDurationUnit,
-        _smallest_unit: DateDurationUnit,
+        largest_unit: DateDurationUnit,
+        smallest_unit: DateDurationUnit,
     ) -> DateDuration<Self> {
-        date1.0.until(date2.0, _largest_unit, _smallest_unit)
+        date1.0.until(date2.0, largest_unit, smallest_unit)
     }
 
     fn year(&self, date: &Self::DateInner) -> types::FormattableYear {
@@ -274,18 +280,21 @@ impl Ethiopian {
             types::FormattableYear {
                 era: types::Era(tinystr!(16, "mundi")),
                 number: year + AMETE_ALEM_OFFSET,
+                cyclic: None,
                 related_iso: None,
             }
         } else if year > 0 {
             types::FormattableYear {
                 era: types::Era(tinystr!(16, "incar")),
                 number: year,
+                cyclic: None,
                 related_iso: None,
             }
         } else {
             types::FormattableYear {
                 era: types::Era(tinystr!(16, "pre-incar")),
                 number: 1 - year,
+                cyclic: None,
                 related_iso: None,
             }
         }
@@ -64,6 +64,7 @@ impl Calendar for Gregorian {
         year: i32,
         month_code: types::MonthCode,
         day: u8,
+        overflow: types::Overflow,
     ) -> Result<Self::DateInner, DateTimeError> {
         let year = if era.0 == tinystr!(16, "ce") {
             if year <= 0 {
@@ -79,7 +80,7 @@ impl Calendar for Gregorian {
             return Err(DateTimeError::UnknownEra(era.0, self.debug_name()));
         };
 
-        ArithmeticDate::new_from_solar(self, year, month_code, day)
+        ArithmeticDate::new_from_solar(self, year, month_code, day, overflow)
             .map(IsoDateInner)
             .map(GregorianDateInner)
     }
@@ -104,11 +105,15 @@ impl Calendar for Gregorian {
         Iso.days_in_month(&date.0)
     }
 
-    fn offset_date(&self, date: &mut Self::DateInner, offset: DateDuration<Self>) {
-        Iso.offset_date(&mut date.0, offset.cast_unit())
+    fn offset_date(
+        &self,
+        date: &mut Self::DateInner,
+        offset: DateDuration<Self>,
+        overflow: types::Overflow,
+    ) -> Result<(), DateTimeError> {
+        Iso.offset_date(&mut date.0, offset.cast_unit(), overflow)
     }
 
-    #[allow(clippy::field_reassign_with_default)] // it's more clear this way
     fn until(
         &self,
         date1: &Self::DateInner,
@@ -222,12 +227,14 @@ pub(crate) fn year_as_gregorian(year: i32) -> types::FormattableYear {
         types::FormattableYear {
             era: types::Era(tinystr!(16, "ce")),
             number: year,
+            cyclic: None,
             related_iso: None,
         }
     } else {
         types::FormattableYear {
             era: types::Era(tinystr!(16, "bce")),
             number: 1 - year,
+            cyclic: None,
             related_iso: None,
         }
     }
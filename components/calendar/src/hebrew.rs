@@ -0,0 +1,639 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! This module contains types and implementations for the Hebrew calendar.
+//!
+//! ```rust
+//! use icu::calendar::{hebrew::Hebrew, Date, DateTime};
+//!
+//! // `Date` type
+//! let date_iso = Date::new_iso_date(1970, 1, 2)
+//!     .expect("Failed to initialize ISO Date instance.");
+//! let date_hebrew = Date::new_from_iso(date_iso, Hebrew);
+//!
+//! // `DateTime` type
+//! let datetime_iso = DateTime::new_iso_datetime(1970, 1, 2, 13, 1, 0)
+//!     .expect("Failed to initialize ISO DateTime instance.");
+//! let datetime_hebrew = DateTime::new_from_iso(datetime_iso, Hebrew);
+//!
+//! // `Date` checks
+//! assert_eq!(date_hebrew.year().number, 5730);
+//! assert_eq!(date_hebrew.month().ordinal, 4);
+//! assert_eq!(date_hebrew.day_of_month().0, 24);
+//!
+//! // `DateTime` type
+//! assert_eq!(datetime_hebrew.date.year().number, 5730);
+//! assert_eq!(datetime_hebrew.date.month().ordinal, 4);
+//! assert_eq!(datetime_hebrew.date.day_of_month().0, 24);
+//! assert_eq!(datetime_hebrew.time.hour.number(), 13);
+//! assert_eq!(datetime_hebrew.time.minute.number(), 1);
+//! assert_eq!(datetime_hebrew.time.second.number(), 0);
+//! ```
+
+use crate::any_calendar::AnyCalendarKind;
+use crate::calendar_arithmetic::{constrain_day, ArithmeticDate, CalendarArithmetic};
+use crate::iso::Iso;
+use crate::{types, Calendar, Date, DateDuration, DateDurationUnit, DateTime, DateTimeError};
+use core::marker::PhantomData;
+use tinystr::tinystr;
+
+/// The [Hebrew Calendar]
+///
+/// The [Hebrew calendar] is a lunisolar calendar used as the Jewish religious calendar and as
+/// an official calendar in Israel. Years have 12 months, or 13 months in leap years, and begin
+/// with the month of Tishri. The start of each year is computed from the *molad*, the mean
+/// conjunction of the moon, of Tishri, together with the rules that postpone the new year.
+///
+/// This type can be used with [`Date`] or [`DateTime`] to represent dates in this calendar.
+///
+/// [Hebrew calendar]: https://en.wikipedia.org/wiki/Hebrew_calendar
+///
+/// # Era codes
+///
+/// This calendar supports a single era code, `"am"`, corresponding to the Anno Mundi era.
+/// 1 A.M. is equivalent to 3761 B.C.E.
+///
+/// # Month codes
+///
+/// The months Tishri through Shevat have the month codes `"M01"` to `"M05"`, and the months
+/// Nisan through Elul have the month codes `"M07"` to `"M12"`. In common years, Adar has the
+/// month code `"M06"`. In leap years, the inserted month Adar I has the month code `"M05L"`,
+/// and Adar II has the month code `"M06"` of the month it replaces, so that it keeps the month
+/// code when a date in Adar II moves to a common year.
+#[derive(Copy, Clone, Debug, Hash, Default, Eq, PartialEq)]
+#[allow(clippy::exhaustive_structs)] // this type is stable
+pub struct Hebrew;
+
+/// The inner date type used for representing [`Date`]s of [`Hebrew`]. See [`Date`] and [`Hebrew`] for more details.
+///
+/// Months are stored as ordinals starting from Tishri.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct HebrewDateInner(pub(crate) ArithmeticDate<Hebrew>);
+
+impl CalendarArithmetic for Hebrew {
+    fn month_days(year: i32, month: u8) -> u8 {
+        if month == 0 || month > Self::months_for_every_year(year) {
+            return 0;
+        }
+        match Self::biblical_month(year, month) {
+            // Iyyar, Tammuz, Elul, Tevet and Adar II have 29 days
+            2 | 4 | 6 | 10 | 13 => 29,
+            // Adar has 29 days in common years, Adar I has 30 days
+            12 if !Self::is_leap_year(year) => 29,
+            // Heshvan has 30 days in "complete" years
+            8 if !Self::is_long_heshvan(year) => 29,
+            // Kislev has 29 days in "deficient" years
+            9 if Self::is_short_kislev(year) => 29,
+            _ => 30,
+        }
+    }
+
+    fn months_for_every_year(year: i32) -> u8 {
+        if Self::is_leap_year(year) {
+            13
+        } else {
+            12
+        }
+    }
+
+    fn is_leap_year(year: i32) -> bool {
+        (7 * year + 1).rem_euclid(19) < 7
+    }
+
+    fn month_with_same_code(from_year: i32, month: u8, year: i32) -> Result<u8, u8> {
+        let code = Self::code_from_ordinal(from_year, month);
+        // Adar I becomes Adar in common years, and Adar II keeps its month code
+        Self::ordinal_from_code(year, code).ok_or(6)
+    }
+}
+
+impl Calendar for Hebrew {
+    type DateInner = HebrewDateInner;
+    fn date_from_codes(
+        &self,
+        era: types::Era,
+        year: i32,
+        month_code: types::MonthCode,
+        day: u8,
+        overflow: types::Overflow,
+    ) -> Result<Self::DateInner, DateTimeError> {
+        if era.0 != tinystr!(16, "am") {
+            return Err(DateTimeError::UnknownEra(era.0, self.debug_name()));
+        }
+
+        let month = match Self::ordinal_from_code(year, month_code) {
+            Some(month) => month,
+            // Adar I is constrained to Adar in common years
+            None if overflow == types::Overflow::Constrain
+                && month_code.0 == tinystr!(4, "M05L") =>
+            {
+                6
+            }
+            None => {
+                return Err(DateTimeError::UnknownMonthCode(
+                    month_code.0,
+                    self.debug_name(),
+                ))
+            }
+        };
+
+        if day == 0 {
+            return Err(DateTimeError::OutOfRange);
+        }
+        let day = constrain_day(day, Self::month_days(year, month), overflow)?;
+
+        Ok(HebrewDateInner(ArithmeticDate::new(year, month, day)))
+    }
+
+    fn date_from_iso(&self, iso: Date<Iso>) -> HebrewDateInner {
+        let fixed_iso = Iso::fixed_from_iso(*iso.inner());
+        Self::hebrew_from_fixed(fixed_iso)
+    }
+
+    fn date_to_iso(&self, date: &Self::DateInner) -> Date<Iso> {
+        let fixed_hebrew = Self::fixed_from_hebrew(date.0);
+        Iso::iso_from_fixed(fixed_hebrew)
+    }
+
+    fn months_in_year(&self, date: &Self::DateInner) -> u8 {
+        date.0.months_in_year()
+    }
+
+    fn days_in_year(&self, date: &Self::DateInner) -> u32 {
+        Self::days_in_year_direct(date.0.year)
+    }
+
+    fn days_in_month(&self, date: &Self::DateInner) -> u8 {
+        date.0.days_in_month()
+    }
+
+    fn day_of_week(&self, date: &Self::DateInner) -> types::IsoWeekday {
+        Iso.day_of_week(Hebrew.date_to_iso(date).inner())
+    }
+
+    fn offset_date(
+        &self,
+        date: &mut Self::DateInner,
+        offset: DateDuration<Self>,
+        overflow: types::Overflow,
+    ) -> Result<(), DateTimeError> {
+        date.0.offset_date(offset, overflow)
+    }
+
+    fn until(
+        &self,
+        date1: &Self::DateInner,
+        date2: &Self::DateInner,
+        _calendar2: &Self,
+        largest_unit: DateDurationUnit,
+        smallest_unit: DateDurationUnit,
+    ) -> DateDuration<Self> {
+        date1.0.until(date2.0, largest_unit, smallest_unit)
+    }
+
+    fn year(&self, date: &Self::DateInner) -> types::FormattableYear {
+        year_as_hebrew(date.0.year)
+    }
+
+    fn month(&self, date: &Self::DateInner) -> types::FormattableMonth {
+        types::FormattableMonth {
+            ordinal: date.0.month as u32,
+            code: Self::code_from_ordinal(date.0.year, date.0.month),
+        }
+    }
+
+    fn day_of_month(&self, date: &Self::DateInner) -> types::DayOfMonth {
+        date.0.day_of_month()
+    }
+
+    fn day_of_year_info(&self, date: &Self::DateInner) -> types::DayOfYearInfo {
+        let prev_year = date.0.year - 1;
+        let next_year = date.0.year + 1;
+        types::DayOfYearInfo {
+            day_of_year: date.0.day_of_year(),
+            days_in_year: Self::days_in_year_direct(date.0.year),
+            prev_year: year_as_hebrew(prev_year),
+            days_in_prev_year: Self::days_in_year_direct(prev_year),
+            next_year: year_as_hebrew(next_year),
+        }
+    }
+
+    fn debug_name(&self) -> &'static str {
+        "Hebrew"
+    }
+
+    fn any_calendar_kind(&self) -> Option<AnyCalendarKind> {
+        Some(AnyCalendarKind::Hebrew)
+    }
+}
+
+/// The fixed date of 1 Tishri 1 A.M., which is October 7, 3761 B.C.E. in the Julian calendar.
+pub(crate) const HEBREW_EPOCH: i32 = -1373427;
+
+impl Hebrew {
+    // "Fixed" is a day count representation of calendars staring from Jan 1st of year 1 of the Georgian Calendar.
+    // The fixed date algorithms are from
+    // Dershowitz, Nachum, and Edward M. Reingold. _Calendrical calculations_. Cambridge University Press, 2008.
+    //
+    // Lisp code reference: https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l#L2206
+
+    /// The number of days from the epoch to the day of the molad of Tishri of `year`, postponed
+    /// by a day if the molad falls on a Sunday, Wednesday or Friday.
+    fn calendar_elapsed_days(year: i32) -> i32 {
+        let months_elapsed = (235 * year as i64 - 234).div_euclid(19);
+        // A day has 25920 "parts", and the molad of Tishri 1 A.M. was 5 hours and 204 parts
+        // after noon on the day before the epoch, i.e. 12084 parts into the epoch's day.
+        let parts_elapsed = 12084 + 13753 * months_elapsed;
+        let days = 29 * months_elapsed + parts_elapsed.div_euclid(25920);
+        if (3 * (days + 1)).rem_euclid(7) < 3 {
+            days as i32 + 1
+        } else {
+            days as i32
+        }
+    }
+
+    /// The additional postponement of the new year, which keeps year lengths within the
+    /// permitted 353-355 days for common years and 383-385 days for leap years.
+    fn year_length_correction(year: i32) -> i32 {
+        let ny0 = Self::calendar_elapsed_days(year - 1);
+        let ny1 = Self::calendar_elapsed_days(year);
+        let ny2 = Self::calendar_elapsed_days(year + 1);
+        if ny2 - ny1 == 356 {
+            2
+        } else if ny1 - ny0 == 382 {
+            1
+        } else {
+            0
+        }
+    }
+
+    /// The fixed date of 1 Tishri of `year`.
+    fn new_year(year: i32) -> i32 {
+        HEBREW_EPOCH + Self::calendar_elapsed_days(year) + Self::year_length_correction(year)
+    }
+
+    fn days_in_year_direct(year: i32) -> u32 {
+        (Self::new_year(year + 1) - Self::new_year(year)) as u32
+    }
+
+    fn is_long_heshvan(year: i32) -> bool {
+        matches!(Self::days_in_year_direct(year), 355 | 385)
+    }
+
+    fn is_short_kislev(year: i32) -> bool {
+        matches!(Self::days_in_year_direct(year), 353 | 383)
+    }
+
+    /// Converts a month ordinal counted from Tishri into the month number counted from Nisan,
+    /// where the leap month Adar II is month 13.
+    fn biblical_month(year: i32, month: u8) -> u8 {
+        let months = Self::months_for_every_year(year);
+        if month + 6 > months {
+            month + 6 - months
+        } else {
+            month + 6
+        }
+    }
+
+    fn ordinal_from_code(year: i32, code: types::MonthCode) -> Option<u8> {
+        let is_leap_year = Self::is_leap_year(year);
+        let ordinal = match code.0.as_str() {
+            "M01" => 1,
+            "M02" => 2,
+            "M03" => 3,
+            "M04" => 4,
+            "M05" => 5,
+            "M05L" if is_leap_year => 6,
+            "M06" => 6,
+            "M07" => 7,
+            "M08" => 8,
+            "M09" => 9,
+            "M10" => 10,
+            "M11" => 11,
+            "M12" => 12,
+            _ => return None,
+        };
+        // Adar II and the months after it come one month later in leap years
+        if is_leap_year && ordinal >= 6 && code.0 != tinystr!(4, "M05L") {
+            Some(ordinal + 1)
+        } else {
+            Some(ordinal)
+        }
+    }
+
+    fn code_from_ordinal(year: i32, month: u8) -> types::MonthCode {
+        let code = match (Self::is_leap_year(year), month) {
+            (_, 1) => tinystr!(4, "M01"),
+            (_, 2) => tinystr!(4, "M02"),
+            (_, 3) => tinystr!(4, "M03"),
+            (_, 4) => tinystr!(4, "M04"),
+            (_, 5) => tinystr!(4, "M05"),
+            (false, 6) | (true, 7) => tinystr!(4, "M06"),
+            (false, 7) | (true, 8) => tinystr!(4, "M07"),
+            (false, 8) | (true, 9) => tinystr!(4, "M08"),
+            (false, 9) | (true, 10) => tinystr!(4, "M09"),
+            (false, 10) | (true, 11) => tinystr!(4, "M10"),
+            (false, 11) | (true, 12) => tinystr!(4, "M11"),
+            (false, 12) | (true, 13) => tinystr!(4, "M12"),
+            (true, 6) => tinystr!(4, "M05L"),
+            _ => tinystr!(4, "und"),
+        };
+        types::MonthCode(code)
+    }
+
+    fn fixed_from_hebrew(date: ArithmeticDate<Hebrew>) -> i32 {
+        Self::new_year(date.year) + date.day_of_year() as i32 - 1
+    }
+
+    // Lisp code reference: https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l#L2352
+    fn hebrew_from_fixed(date: i32) -> HebrewDateInner {
+        // The average length of a year is 35975351/98496 days
+        let approx = ((date - HEBREW_EPOCH) as i64 * 98496).div_euclid(35975351) as i32 + 1;
+        let mut year = approx - 1;
+        while Self::new_year(year + 1) <= date {
+            year += 1;
+        }
+        let day_of_year = (date - Self::new_year(year) + 1) as u32;
+        HebrewDateInner(ArithmeticDate::date_from_year_day(year, day_of_year))
+    }
+}
+
+impl Date<Hebrew> {
+    /// Construct new Hebrew Date.
+    ///
+    /// The month is the ordinal of the month in the year, counted from Tishri, so that Nisan is
+    /// month 7 in common years and month 8 in leap years. Use [`Date::new_from_codes`] to
+    /// construct a date from a month code instead.
+    ///
+    /// ```rust
+    /// use icu::calendar::Date;
+    ///
+    /// let date_hebrew =
+    ///     Date::new_hebrew_date(5784, 7, 1).expect("Failed to initialize Hebrew Date instance.");
+    ///
+    /// assert_eq!(date_hebrew.year().number, 5784);
+    /// assert_eq!(date_hebrew.month().ordinal, 7);
+    /// assert_eq!(date_hebrew.month().code.0, "M06");
+    /// assert_eq!(date_hebrew.day_of_month().0, 1);
+    /// ```
+    pub fn new_hebrew_date(year: i32, month: u8, day: u8) -> Result<Date<Hebrew>, DateTimeError> {
+        let inner = ArithmeticDate {
+            year,
+            month,
+            day,
+            marker: PhantomData,
+        };
+
+        let bound = inner.days_in_month();
+        if day == 0 || day > bound {
+            return Err(DateTimeError::OutOfRange);
+        }
+
+        Ok(Date::from_raw(HebrewDateInner(inner), Hebrew))
+    }
+}
+
+impl DateTime<Hebrew> {
+    /// Construct a new Hebrew datetime from integers.
+    ///
+    /// See [`Date::new_hebrew_date`] for how months are numbered.
+    ///
+    /// ```rust
+    /// use icu::calendar::DateTime;
+    ///
+    /// let datetime_hebrew = DateTime::new_hebrew_datetime(5784, 7, 1, 13, 1, 0)
+    ///     .expect("Failed to initialize Hebrew DateTime instance.");
+    ///
+    /// assert_eq!(datetime_hebrew.date.year().number, 5784);
+    /// assert_eq!(datetime_hebrew.date.month().ordinal, 7);
+    /// assert_eq!(datetime_hebrew.date.day_of_month().0, 1);
+    /// assert_eq!(datetime_hebrew.time.hour.number(), 13);
+    /// assert_eq!(datetime_hebrew.time.minute.number(), 1);
+    /// assert_eq!(datetime_hebrew.time.second.number(), 0);
+    /// ```
+    pub fn new_hebrew_datetime(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> Result<DateTime<Hebrew>, DateTimeError> {
+        Ok(DateTime {
+            date: Date::new_hebrew_date(year, month, day)?,
+            time: types::Time::try_new(hour, minute, second, 0)?,
+        })
+    }
+}
+
+fn year_as_hebrew(year: i32) -> types::FormattableYear {
+    types::FormattableYear {
+        era: types::Era(tinystr!(16, "am")),
+        number: year,
+        cyclic: None,
+        related_iso: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RoundingMode;
+
+    #[test]
+    fn test_new_year() {
+        // (Hebrew year, ISO date of 1 Tishri, days in year)
+        let cases = [
+            (5730, (1969, 9, 13), 383),
+            (5783, (2022, 9, 26), 355),
+            (5784, (2023, 9, 16), 383),
+            (5785, (2024, 10, 3), 355),
+            (5786, (2025, 9, 23), 354),
+        ];
+        for (year, (iso_year, iso_month, iso_day), days) in cases {
+            let iso = Date::new_iso_date(iso_year, iso_month, iso_day).unwrap();
+            let hebrew = Date::new_hebrew_date(year, 1, 1).unwrap();
+            assert_eq!(hebrew.to_iso(), iso, "{year}");
+            assert_eq!(iso.to_calendar(Hebrew), hebrew, "{year}");
+            assert_eq!(hebrew.days_in_year(), days, "{year}");
+            assert_eq!(Hebrew::is_leap_year(year), days > 380, "{year}");
+        }
+    }
+
+    #[test]
+    fn test_leap_months() {
+        let iso = |y, m, d| Date::new_iso_date(y, m, d).unwrap();
+        let cases = [
+            // 5784 is a leap year
+            (iso(2024, 2, 10), 5784, 6, "M05L", 1),
+            (iso(2024, 3, 11), 5784, 7, "M06", 1),
+            (iso(2024, 3, 24), 5784, 7, "M06", 14),
+            (iso(2024, 4, 9), 5784, 8, "M07", 1),
+            // 5783 is a common year
+            (iso(2023, 3, 7), 5783, 6, "M06", 14),
+            (iso(2023, 3, 23), 5783, 7, "M07", 1),
+        ];
+        for (iso, year, ordinal, code, day) in cases {
+            let hebrew = iso.to_calendar(Hebrew);
+            assert_eq!(hebrew.year().number, year, "{iso:?}");
+            assert_eq!(hebrew.month().ordinal, ordinal, "{iso:?}");
+            assert_eq!(hebrew.month().code.0, code, "{iso:?}");
+            assert_eq!(hebrew.day_of_month().0, day, "{iso:?}");
+
+            let from_codes = Date::new_from_codes(
+                types::Era(tinystr!(16, "am")),
+                year,
+                types::MonthCode(code.parse().unwrap()),
+                day as u8,
+                Hebrew,
+            )
+            .unwrap();
+            assert_eq!(from_codes, hebrew, "{iso:?}");
+        }
+
+        // M06L is not a month code of the Hebrew calendar
+        assert!(Date::new_from_codes(
+            types::Era(tinystr!(16, "am")),
+            5784,
+            types::MonthCode(tinystr!(4, "M06L")),
+            14,
+            Hebrew,
+        )
+        .is_err());
+
+        // Adar I does not exist in common years
+        assert!(Date::new_from_codes(
+            types::Era(tinystr!(16, "am")),
+            5783,
+            types::MonthCode(tinystr!(4, "M05L")),
+            1,
+            Hebrew,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        for fixed in Hebrew::new_year(5700)..Hebrew::new_year(5800) {
+            let iso = Iso::iso_from_fixed(fixed);
+            let hebrew = iso.to_calendar(Hebrew);
+            assert_eq!(Hebrew::fixed_from_hebrew(hebrew.inner().0), fixed);
+            assert_eq!(hebrew.to_iso(), iso);
+        }
+    }
+
+    #[test]
+    fn test_offset_keeps_month_code() {
+        // 1 Nisan 5783 plus a year is 1 Nisan 5784, which is a month later in the leap year
+        let mut date = Date::new_hebrew_date(5783, 7, 1).unwrap();
+        date.add(DateDuration::new(1, 0, 0, 0), types::Overflow::Constrain)
+            .unwrap();
+        assert_eq!(date.month().code.0, "M07");
+        assert_eq!(date.to_iso(), Date::new_iso_date(2024, 4, 9).unwrap());
+
+        // Adar I and Adar II become Adar in common years
+        for month in [6, 7] {
+            let mut date = Date::new_hebrew_date(5784, month, 1).unwrap();
+            date.add(DateDuration::new(1, 0, 0, 0), types::Overflow::Constrain)
+                .unwrap();
+            assert_eq!(date.month().code.0, "M06");
+            assert_eq!(date, Date::new_hebrew_date(5785, 6, 1).unwrap());
+        }
+
+        // Adar II keeps its month code, but Adar I does not exist in common years
+        let mut date = Date::new_hebrew_date(5784, 7, 1).unwrap();
+        date.add(DateDuration::new(1, 0, 0, 0), types::Overflow::Reject)
+            .unwrap();
+        assert_eq!(date, Date::new_hebrew_date(5785, 6, 1).unwrap());
+        let mut date = Date::new_hebrew_date(5784, 6, 1).unwrap();
+        assert_eq!(
+            date.add(DateDuration::new(1, 0, 0, 0), types::Overflow::Reject),
+            Err(DateTimeError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_from_codes_overflow() {
+        let from_codes = |year, month: &str, day, overflow| {
+            Date::new_from_codes_with_overflow(
+                types::Era(tinystr!(16, "am")),
+                year,
+                types::MonthCode(month.parse().unwrap()),
+                day,
+                overflow,
+                Hebrew,
+            )
+        };
+
+        // 5783 is a common year, in which Adar has 29 days
+        let date = from_codes(5783, "M05L", 30, types::Overflow::Constrain).unwrap();
+        assert_eq!(date.month().code.0, "M06");
+        assert_eq!(date.day_of_month().0, 29);
+        assert!(matches!(
+            from_codes(5783, "M05L", 1, types::Overflow::Reject),
+            Err(DateTimeError::UnknownMonthCode(..))
+        ));
+        assert_eq!(
+            from_codes(5783, "M06", 30, types::Overflow::Reject),
+            Err(DateTimeError::OutOfRange)
+        );
+
+        // 5784 is a leap year, in which Adar I has 30 days
+        let date = from_codes(5784, "M05L", 30, types::Overflow::Reject).unwrap();
+        assert_eq!(date, Date::new_hebrew_date(5784, 6, 30).unwrap());
+    }
+
+    #[test]
+    fn test_until() {
+        let start = Hebrew::new_year(5783) - 40;
+        let dates: Vec<_> = (0..800)
+            .step_by(9)
+            .map(|offset| Iso::iso_from_fixed(start + offset).to_calendar(Hebrew))
+            .collect();
+        for date in &dates {
+            for other in &dates {
+                for unit in [DateDurationUnit::Years, DateDurationUnit::Months] {
+                    let duration =
+                        date.until(other, unit, DateDurationUnit::Days, RoundingMode::Trunc);
+                    assert_eq!(
+                        &date
+                            .clone()
+                            .added(duration, types::Overflow::Constrain)
+                            .unwrap(),
+                        other,
+                        "{date:?} until {other:?}"
+                    );
+                }
+            }
+        }
+
+        // From 1 Nisan 5783 to 1 Nisan 5784 is a year, or 13 months
+        let nisan_5783 = Date::new_hebrew_date(5783, 7, 1).unwrap();
+        let nisan_5784 = Date::new_hebrew_date(5784, 8, 1).unwrap();
+        let duration = nisan_5783.until(
+            &nisan_5784,
+            DateDurationUnit::Years,
+            DateDurationUnit::Days,
+            RoundingMode::Trunc,
+        );
+        assert_eq!((duration.years, duration.months, duration.days), (1, 0, 0));
+        let duration = nisan_5783.until(
+            &nisan_5784,
+            DateDurationUnit::Months,
+            DateDurationUnit::Days,
+            RoundingMode::Trunc,
+        );
+        assert_eq!((duration.years, duration.months, duration.days), (0, 13, 0));
+    }
+
+    #[test]
+    fn test_offset_across_leap_year() {
+        // 1 Shevat 5784 plus two months is 1 Adar II
+        let mut date = Date::new_hebrew_date(5784, 5, 1).unwrap();
+        date.add(DateDuration::new(0, 2, 0, 0), types::Overflow::Constrain)
+            .unwrap();
+        assert_eq!(date.month().code.0, "M06");
+        assert_eq!(date.to_iso(), Date::new_iso_date(2024, 3, 11).unwrap());
+    }
+}
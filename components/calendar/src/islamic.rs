@@ -0,0 +1,870 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! This module contains types and implementations for the Islamic calendars.
+//!
+//! ```rust
+//! use icu::calendar::islamic::{IslamicCivil, IslamicUmmAlQura};
+//! use icu::calendar::{Date, DateTime};
+//!
+//! // `Date` type
+//! let date_iso = Date::new_iso_date(1970, 1, 2)
+//!     .expect("Failed to initialize ISO Date instance.");
+//! let date_civil = Date::new_from_iso(date_iso.clone(), IslamicCivil);
+//! let date_umm_al_qura = Date::new_from_iso(date_iso, IslamicUmmAlQura);
+//!
+//! // `DateTime` type
+//! let datetime_iso = DateTime::new_iso_datetime(1970, 1, 2, 13, 1, 0)
+//!     .expect("Failed to initialize ISO DateTime instance.");
+//! let datetime_civil = DateTime::new_from_iso(datetime_iso, IslamicCivil);
+//!
+//! // `Date` checks
+//! assert_eq!(date_civil.year().number, 1389);
+//! assert_eq!(date_civil.month().ordinal, 10);
+//! assert_eq!(date_civil.day_of_month().0, 23);
+//!
+//! assert_eq!(date_umm_al_qura.year().number, 1389);
+//! assert_eq!(date_umm_al_qura.month().ordinal, 10);
+//! assert_eq!(date_umm_al_qura.day_of_month().0, 23);
+//!
+//! // `DateTime` type
+//! assert_eq!(datetime_civil.date.year().number, 1389);
+//! assert_eq!(datetime_civil.date.month().ordinal, 10);
+//! assert_eq!(datetime_civil.date.day_of_month().0, 23);
+//! assert_eq!(datetime_civil.time.hour.number(), 13);
+//! assert_eq!(datetime_civil.time.minute.number(), 1);
+//! assert_eq!(datetime_civil.time.second.number(), 0);
+//! ```
+
+use crate::any_calendar::AnyCalendarKind;
+use crate::calendar_arithmetic::{ArithmeticDate, CalendarArithmetic};
+use crate::iso::Iso;
+use crate::julian::Julian;
+use crate::{types, Calendar, Date, DateDuration, DateDurationUnit, DateTime, DateTimeError};
+use core::convert::TryFrom;
+use core::marker::PhantomData;
+use tinystr::tinystr;
+
+/// The civil [Islamic Calendar] (`islamic-civil`)
+///
+/// The [Islamic calendar] is a lunar calendar used to determine Islamic holidays and, in some
+/// countries, for civil purposes. Years have twelve months of alternately 30 and 29 days, and
+/// the last month has 30 days in eleven out of every 30 years.
+///
+/// This is the tabular variant of the calendar that starts on Friday, July 16, 622 C.E. (Julian),
+/// and that is used in civil contexts.
+///
+/// This type can be used with [`Date`] or [`DateTime`] to represent dates in this calendar.
+///
+/// [Islamic calendar]: https://en.wikipedia.org/wiki/Islamic_calendar
+///
+/// # Era codes
+///
+/// This calendar supports a single era code, `"ah"`, corresponding to the Anno Hegirae era.
+#[derive(Copy, Clone, Debug, Hash, Default, Eq, PartialEq)]
+#[allow(clippy::exhaustive_structs)] // this type is stable
+pub struct IslamicCivil;
+
+/// The tabular [Islamic Calendar] with an astronomical epoch (`islamic-tbla`)
+///
+/// This calendar has the same arithmetic rules as [`IslamicCivil`], but starts one day earlier,
+/// on Thursday, July 15, 622 C.E. (Julian), the day of the astronomical new moon.
+///
+/// This type can be used with [`Date`] or [`DateTime`] to represent dates in this calendar.
+///
+/// [Islamic calendar]: https://en.wikipedia.org/wiki/Islamic_calendar
+///
+/// # Era codes
+///
+/// This calendar supports a single era code, `"ah"`, corresponding to the Anno Hegirae era.
+#[derive(Copy, Clone, Debug, Hash, Default, Eq, PartialEq)]
+#[allow(clippy::exhaustive_structs)] // this type is stable
+pub struct IslamicTabular;
+
+/// The Umm al-Qura [Islamic Calendar] (`islamic-umalqura`)
+///
+/// The [Umm al-Qura calendar] is the Islamic calendar used by Saudi Arabia. Its months begin
+/// according to astronomical criteria for the visibility of the moon at Mecca, which are
+/// published as a table of month lengths.
+///
+/// This type uses the published month lengths for the years 1300 to 1600 A.H.
+/// (1882 to 2174 C.E.). Outside of this range, it falls back to the rules of [`IslamicCivil`].
+///
+/// This type can be used with [`Date`] or [`DateTime`] to represent dates in this calendar.
+///
+/// [Islamic calendar]: https://en.wikipedia.org/wiki/Islamic_calendar
+/// [Umm al-Qura calendar]: https://en.wikipedia.org/wiki/Islamic_calendar#Saudi_Arabia's_Umm_al-Qura_calendar
+///
+/// # Era codes
+///
+/// This calendar supports a single era code, `"ah"`, corresponding to the Anno Hegirae era.
+#[derive(Copy, Clone, Debug, Hash, Default, Eq, PartialEq)]
+#[allow(clippy::exhaustive_structs)] // this type is stable
+pub struct IslamicUmmAlQura;
+
+/// The inner date type used for representing [`Date`]s of [`IslamicCivil`]. See [`Date`] and [`IslamicCivil`] for more details.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct IslamicCivilDateInner(pub(crate) ArithmeticDate<IslamicCivil>);
+
+/// The inner date type used for representing [`Date`]s of [`IslamicTabular`]. See [`Date`] and [`IslamicTabular`] for more details.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct IslamicTabularDateInner(pub(crate) ArithmeticDate<IslamicTabular>);
+
+/// The inner date type used for representing [`Date`]s of [`IslamicUmmAlQura`]. See [`Date`] and [`IslamicUmmAlQura`] for more details.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct IslamicUmmAlQuraDateInner(pub(crate) ArithmeticDate<IslamicUmmAlQura>);
+
+// Friday, July 16, 622 C.E. (Julian)
+const ISLAMIC_CIVIL_EPOCH: i32 = Julian::fixed_from_julian_integers(622, 7, 16);
+// Thursday, July 15, 622 C.E. (Julian)
+const ISLAMIC_TABULAR_EPOCH: i32 = ISLAMIC_CIVIL_EPOCH - 1;
+
+// The fixed date algorithms for the tabular calendars are from
+// Dershowitz, Nachum, and Edward M. Reingold. _Calendrical calculations_. Cambridge University Press, 2008.
+//
+// Lisp code reference: https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l#L2066
+
+fn tabular_is_leap_year(year: i32) -> bool {
+    (14 + 11 * year).rem_euclid(30) < 11
+}
+
+fn tabular_month_days(year: i32, month: u8) -> u8 {
+    match month {
+        1 | 3 | 5 | 7 | 9 | 11 => 30,
+        2 | 4 | 6 | 8 | 10 => 29,
+        12 if tabular_is_leap_year(year) => 30,
+        12 => 29,
+        _ => 0,
+    }
+}
+
+fn tabular_days_in_year(year: i32) -> u32 {
+    if tabular_is_leap_year(year) {
+        355
+    } else {
+        354
+    }
+}
+
+fn fixed_from_tabular(epoch: i32, year: i32, month: u8, day: u8) -> i32 {
+    epoch - 1
+        + (year - 1) * 354
+        + (3 + 11 * year).div_euclid(30)
+        + 29 * (month as i32 - 1)
+        + month as i32 / 2
+        + day as i32
+}
+
+fn tabular_from_fixed(epoch: i32, date: i32) -> (i32, u8, u8) {
+    let year = (30 * (date - epoch) + 10646).div_euclid(10631);
+    let prior_days = date - fixed_from_tabular(epoch, year, 1, 1);
+    let month = ((11 * prior_days + 330) / 325) as u8; // in 1..=12 < u8::MAX
+    let day = (date - fixed_from_tabular(epoch, year, month, 1) + 1) as u8; // <= days_in_month < u8::MAX
+    (year, month, day)
+}
+
+fn year_as_islamic(year: i32) -> types::FormattableYear {
+    types::FormattableYear {
+        era: types::Era(tinystr!(16, "ah")),
+        number: year,
+        cyclic: None,
+        related_iso: None,
+    }
+}
+
+fn check_era(era: types::Era, debug_name: &'static str) -> Result<(), DateTimeError> {
+    if era.0 == tinystr!(16, "ah") {
+        Ok(())
+    } else {
+        Err(DateTimeError::UnknownEra(era.0, debug_name))
+    }
+}
+
+impl CalendarArithmetic for IslamicCivil {
+    fn month_days(year: i32, month: u8) -> u8 {
+        tabular_month_days(year, month)
+    }
+
+    fn months_for_every_year(_: i32) -> u8 {
+        12
+    }
+
+    fn is_leap_year(year: i32) -> bool {
+        tabular_is_leap_year(year)
+    }
+}
+
+impl Calendar for IslamicCivil {
+    type DateInner = IslamicCivilDateInner;
+    fn date_from_codes(
+        &self,
+        era: types::Era,
+        year: i32,
+        month_code: types::MonthCode,
+        day: u8,
+        overflow: types::Overflow,
+    ) -> Result<Self::DateInner, DateTimeError> {
+        check_era(era, self.debug_name())?;
+        ArithmeticDate::new_from_solar(self, year, month_code, day, overflow)
+            .map(IslamicCivilDateInner)
+    }
+
+    fn date_from_iso(&self, iso: Date<Iso>) -> IslamicCivilDateInner {
+        let fixed_iso = Iso::fixed_from_iso(*iso.inner());
+        let (year, month, day) = tabular_from_fixed(ISLAMIC_CIVIL_EPOCH, fixed_iso);
+        IslamicCivilDateInner(ArithmeticDate::new(year, month, day))
+    }
+
+    fn date_to_iso(&self, date: &Self::DateInner) -> Date<Iso> {
+        let fixed = fixed_from_tabular(ISLAMIC_CIVIL_EPOCH, date.0.year, date.0.month, date.0.day);
+        Iso::iso_from_fixed(fixed)
+    }
+
+    fn months_in_year(&self, date: &Self::DateInner) -> u8 {
+        date.0.months_in_year()
+    }
+
+    fn days_in_year(&self, date: &Self::DateInner) -> u32 {
+        date.0.days_in_year()
+    }
+
+    fn days_in_month(&self, date: &Self::DateInner) -> u8 {
+        date.0.days_in_month()
+    }
+
+    fn day_of_week(&self, date: &Self::DateInner) -> types::IsoWeekday {
+        Iso.day_of_week(self.date_to_iso(date).inner())
+    }
+
+    fn offset_date(
+        &self,
+        date: &mut Self::DateInner,
+        offset: DateDuration<Self>,
+        overflow: types::Overflow,
+    ) -> Result<(), DateTimeError> {
+        date.0.offset_date(offset, overflow)
+    }
+
+    fn until(
+        &self,
+        date1: &Self::DateInner,
+        date2: &Self::DateInner,
+        _calendar2: &Self,
+        largest_unit: DateDurationUnit,
+        smallest_unit: DateDurationUnit,
+    ) -> DateDuration<Self> {
+        date1.0.until(date2.0, largest_unit, smallest_unit)
+    }
+
+    fn year(&self, date: &Self::DateInner) -> types::FormattableYear {
+        year_as_islamic(date.0.year)
+    }
+
+    fn month(&self, date: &Self::DateInner) -> types::FormattableMonth {
+        date.0.solar_month()
+    }
+
+    fn day_of_month(&self, date: &Self::DateInner) -> types::DayOfMonth {
+        date.0.day_of_month()
+    }
+
+    fn day_of_year_info(&self, date: &Self::DateInner) -> types::DayOfYearInfo {
+        let prev_year = date.0.year - 1;
+        let next_year = date.0.year + 1;
+        types::DayOfYearInfo {
+            day_of_year: date.0.day_of_year(),
+            days_in_year: date.0.days_in_year(),
+            prev_year: year_as_islamic(prev_year),
+            days_in_prev_year: tabular_days_in_year(prev_year),
+            next_year: year_as_islamic(next_year),
+        }
+    }
+
+    fn debug_name(&self) -> &'static str {
+        "Islamic (civil)"
+    }
+
+    fn any_calendar_kind(&self) -> Option<AnyCalendarKind> {
+        Some(AnyCalendarKind::IslamicCivil)
+    }
+}
+
+impl CalendarArithmetic for IslamicTabular {
+    fn month_days(year: i32, month: u8) -> u8 {
+        tabular_month_days(year, month)
+    }
+
+    fn months_for_every_year(_: i32) -> u8 {
+        12
+    }
+
+    fn is_leap_year(year: i32) -> bool {
+        tabular_is_leap_year(year)
+    }
+}
+
+impl Calendar for IslamicTabular {
+    type DateInner = IslamicTabularDateInner;
+    fn date_from_codes(
+        &self,
+        era: types::Era,
+        year: i32,
+        month_code: types::MonthCode,
+        day: u8,
+        overflow: types::Overflow,
+    ) -> Result<Self::DateInner, DateTimeError> {
+        check_era(era, self.debug_name())?;
+        ArithmeticDate::new_from_solar(self, year, month_code, day, overflow)
+            .map(IslamicTabularDateInner)
+    }
+
+    fn date_from_iso(&self, iso: Date<Iso>) -> IslamicTabularDateInner {
+        let fixed_iso = Iso::fixed_from_iso(*iso.inner());
+        let (year, month, day) = tabular_from_fixed(ISLAMIC_TABULAR_EPOCH, fixed_iso);
+        IslamicTabularDateInner(ArithmeticDate::new(year, month, day))
+    }
+
+    fn date_to_iso(&self, date: &Self::DateInner) -> Date<Iso> {
+        let fixed =
+            fixed_from_tabular(ISLAMIC_TABULAR_EPOCH, date.0.year, date.0.month, date.0.day);
+        Iso::iso_from_fixed(fixed)
+    }
+
+    fn months_in_year(&self, date: &Self::DateInner) -> u8 {
+        date.0.months_in_year()
+    }
+
+    fn days_in_year(&self, date: &Self::DateInner) -> u32 {
+        date.0.days_in_year()
+    }
+
+    fn days_in_month(&self, date: &Self::DateInner) -> u8 {
+        date.0.days_in_month()
+    }
+
+    fn day_of_week(&self, date: &Self::DateInner) -> types::IsoWeekday {
+        Iso.day_of_week(self.date_to_iso(date).inner())
+    }
+
+    fn offset_date(
+        &self,
+        date: &mut Self::DateInner,
+        offset: DateDuration<Self>,
+        overflow: types::Overflow,
+    ) -> Result<(), DateTimeError> {
+        date.0.offset_date(offset, overflow)
+    }
+
+    fn until(
+        &self,
+        date1: &Self::DateInner,
+        date2: &Self::DateInner,
+        _calendar2: &Self,
+        largest_unit: DateDurationUnit,
+        smallest_unit: DateDurationUnit,
+    ) -> DateDuration<Self> {
+        date1.0.until(date2.0, largest_unit, smallest_unit)
+    }
+
+    fn year(&self, date: &Self::DateInner) -> types::FormattableYear {
+        year_as_islamic(date.0.year)
+    }
+
+    fn month(&self, date: &Self::DateInner) -> types::FormattableMonth {
+        date.0.solar_month()
+    }
+
+    fn day_of_month(&self, date: &Self::DateInner) -> types::DayOfMonth {
+        date.0.day_of_month()
+    }
+
+    fn day_of_year_info(&self, date: &Self::DateInner) -> types::DayOfYearInfo {
+        let prev_year = date.0.year - 1;
+        let next_year = date.0.year + 1;
+        types::DayOfYearInfo {
+            day_of_year: date.0.day_of_year(),
+            days_in_year: date.0.days_in_year(),
+            prev_year: year_as_islamic(prev_year),
+            days_in_prev_year: tabular_days_in_year(prev_year),
+            next_year: year_as_islamic(next_year),
+        }
+    }
+
+    fn debug_name(&self) -> &'static str {
+        "Islamic (tabular)"
+    }
+
+    fn any_calendar_kind(&self) -> Option<AnyCalendarKind> {
+        Some(AnyCalendarKind::IslamicTabular)
+    }
+}
+
+const UMM_AL_QURA_START_YEAR: i32 = 1300;
+
+/// The Umm al-Qura calendar for the years 1300 to 1600 A.H.
+///
+/// Bit `m - 1` of each entry is set if month `m` of the year has 30 days rather than 29.
+/// Bits 12 and 13 hold the difference between the start of the year and the start of the
+/// same year in [`IslamicCivil`], plus one.
+///
+/// The month lengths are taken from the `UMALQURA_MONTHLENGTH` table in ICU4C's
+/// `i18n/islamcal.cpp`, which stores month 1 in bit 11 instead, and the year starts from the
+/// JDK's `hijrah-config-Hijrah-umalqura_islamic-umalqura.properties`.
+static UMM_AL_QURA_DATA: [u16; 301] = [
+    0x1555, 0x02AB, 0x0937, 0x12B6, 0x0576, 0x136C, 0x1B55, 0x1AAA, // 1300..=1307
+    0x1956, 0x049E, 0x095D, 0x12BA, 0x05B5, 0x13AA, 0x1B4B, 0x1A96, // 1308..=1315
+    0x152E, 0x02AD, 0x056D, 0x1B5A, 0x1752, 0x1F25, 0x2E8A, 0x1D16, // 1316..=1323
+    0x1A56, 0x1AB5, 0x16B4, 0x1DA9, 0x1B92, 0x1B25, 0x164B, 0x0A9B, // 1324..=1331
+    0x135A, 0x16D9, 0x15D4, 0x1DA5, 0x2D4A, 0x1A95, 0x1536, 0x0975, // 1332..=1339
+    0x12F4, 0x16E9, 0x16D4, 0x16A9, 0x1535, 0x025D, 0x04BD, 0x09BA, // 1340..=1347
+    0x13B4, 0x1B69, 0x1B2A, 0x1A55, 0x14AD, 0x0A5D, 0x12DA, 0x16D9, // 1348..=1355
+    0x1EAA, 0x2E94, 0x1D2A, 0x1C56, 0x14AE, 0x0A6D, 0x156A, 0x1D55, // 1356..=1363
+    0x1D4A, 0x1A93, 0x152B, 0x0A5B, 0x153A, 0x06B5, 0x1EA9, 0x2D52, // 1364..=1371
+    0x1D29, 0x1A55, 0x14AD, 0x056D, 0x1AEA, 0x16E4, 0x1ED1, 0x2DA2, // 1372..=1379
+    0x1AAA, 0x195A, 0x12DA, 0x05B9, 0x1BB2, 0x2764, 0x16C9, 0x1555, // 1380..=1387
+    0x02AB, 0x04DB, 0x1ABA, 0x15B4, 0x1DA9, 0x2D52, 0x1AA5, 0x192D, // 1388..=1395
+    0x126D, 0x08ED, 0x12DA, 0x0AD5, 0x1AA5, 0x1A4B, 0x0497, 0x0937, // 1396..=1403
+    0x12B6, 0x0975, 0x1D69, 0x1D52, 0x1C95, 0x192B, 0x025B, 0x04DB, // 1404..=1411
+    0x19D5, 0x15D2, 0x1DA5, 0x2D4A, 0x1A95, 0x154D, 0x0AAD, 0x13AA, // 1412..=1419
+    0x1BD2, 0x1BC4, 0x1B89, 0x1A95, 0x052D, 0x05AD, 0x1B6A, 0x16D4, // 1420..=1427
+    0x1DC9, 0x1D92, 0x1AA6, 0x1956, 0x02AE, 0x056D, 0x136A, 0x0B55, // 1428..=1435
+    0x1AAA, 0x094D, 0x049D, 0x095D, 0x02BA, 0x05B5, 0x15AA, 0x0D55, // 1436..=1443
+    0x1A9A, 0x192E, 0x026E, 0x055D, 0x0ADA, 0x16D4, 0x16A5, 0x0B27, // 1444..=1451
+    0x1A4D, 0x14AD, 0x056D, 0x1B5A, 0x2754, 0x1F49, 0x2E92, 0x1D26, // 1452..=1459
+    0x1A56, 0x1356, 0x06B5, 0x1BAA, 0x2B92, 0x1B25, 0x168B, 0x0A9B, // 1460..=1467
+    0x155A, 0x1ADA, 0x15B4, 0x1DA9, 0x2B52, 0x1A9A, 0x1536, 0x1276, // 1468..=1475
+    0x0575, 0x1AF2, 0x16D4, 0x16A9, 0x1555, 0x02AD, 0x04BD, 0x19BA, // 1476..=1483
+    0x1574, 0x1B69, 0x2B52, 0x1A95, 0x152D, 0x0A5D, 0x14DA, 0x1AD9, // 1484..=1491
+    0x16B2, 0x1E95, 0x2E2A, 0x1C96, 0x192E, 0x0AAD, 0x156A, 0x1D65, // 1492..=1499
+    0x1D4A, 0x1D15, 0x162B, 0x0C5B, 0x153A, 0x16B5, 0x1DB2, 0x2D64, // 1500..=1507
+    0x1D29, 0x1A55, 0x14AD, 0x096D, 0x1AEA, 0x26E8, 0x1ED1, 0x2DA4, // 1508..=1515
+    0x2D4A, 0x1A6A, 0x12DA, 0x05B9, 0x1B72, 0x2B68, 0x16D1, 0x1655, // 1516..=1523
+    0x14AB, 0x095B, 0x12BA, 0x05B5, 0x1DA9, 0x2D52, 0x1CA6, 0x194E, // 1524..=1531
+    0x146E, 0x095D, 0x14DA, 0x1AD5, 0x1AAA, 0x1A4D, 0x049B, 0x0937, // 1532..=1539
+    0x14B6, 0x0975, 0x1D6A, 0x2D52, 0x1AA5, 0x194B, 0x12AB, 0x055B, // 1540..=1547
+    0x1AD9, 0x15D2, 0x1DC5, 0x2D92, 0x1B25, 0x1555, 0x1AB5, 0x15B4, // 1548..=1555
+    0x1BA9, 0x17A2, 0x1745, 0x1593, 0x0AAB, 0x14D6, 0x19D6, 0x15D2, // 1556..=1563
+    0x1BA5, 0x2B4A, 0x1A95, 0x14AD, 0x015D, 0x02DD, 0x19DA, 0x15B4, // 1564..=1571
+    0x15A9, 0x152D, 0x025B, 0x08B7, 0x1176, 0x056D, 0x1B6A, 0x1ACA, // 1572..=1579
+    0x1A96, 0x152B, 0x015B, 0x02BB, 0x15B6, 0x1DAA, 0x2B94, 0x1D46, // 1580..=1587
+    0x1A8D, 0x152D, 0x0A9D, 0x155A, 0x1755, 0x1749, 0x1F13, 0x2E4A, // 1588..=1595
+    0x1A96, 0x1556, 0x06B5, 0x1BAA, 0x2B94, // 1596..=1600
+];
+
+impl IslamicUmmAlQura {
+    fn year_data(year: i32) -> Option<u16> {
+        usize::try_from(year - UMM_AL_QURA_START_YEAR)
+            .ok()
+            .and_then(|index| UMM_AL_QURA_DATA.get(index))
+            .copied()
+    }
+
+    /// The fixed date of the first day of `year`
+    fn new_year(year: i32) -> i32 {
+        let offset = match Self::year_data(year) {
+            Some(data) => (data >> 12) as i32 - 1,
+            None => 0,
+        };
+        fixed_from_tabular(ISLAMIC_CIVIL_EPOCH, year, 1, 1) + offset
+    }
+
+    fn days_in_year_direct(year: i32) -> u32 {
+        (Self::new_year(year + 1) - Self::new_year(year)) as u32
+    }
+
+    fn fixed_from_umm_al_qura(date: ArithmeticDate<IslamicUmmAlQura>) -> i32 {
+        Self::new_year(date.year) + date.day_of_year() as i32 - 1
+    }
+
+    fn umm_al_qura_from_fixed(date: i32) -> IslamicUmmAlQuraDateInner {
+        // Years start at most a day apart from the civil calendar
+        let (mut year, _, _) = tabular_from_fixed(ISLAMIC_CIVIL_EPOCH, date);
+        if date < Self::new_year(year) {
+            year -= 1;
+        } else if date >= Self::new_year(year + 1) {
+            year += 1;
+        }
+        let day_of_year = (date - Self::new_year(year) + 1) as u32;
+        IslamicUmmAlQuraDateInner(ArithmeticDate::date_from_year_day(year, day_of_year))
+    }
+}
+
+impl CalendarArithmetic for IslamicUmmAlQura {
+    fn month_days(year: i32, month: u8) -> u8 {
+        match Self::year_data(year) {
+            Some(data) if (1..=12).contains(&month) => {
+                if data & (1 << (month - 1)) != 0 {
+                    30
+                } else {
+                    29
+                }
+            }
+            Some(_) => 0,
+            None => tabular_month_days(year, month),
+        }
+    }
+
+    fn months_for_every_year(_: i32) -> u8 {
+        12
+    }
+
+    fn is_leap_year(year: i32) -> bool {
+        Self::days_in_year_direct(year) > 354
+    }
+}
+
+impl Calendar for IslamicUmmAlQura {
+    type DateInner = IslamicUmmAlQuraDateInner;
+    fn date_from_codes(
+        &self,
+        era: types::Era,
+        year: i32,
+        month_code: types::MonthCode,
+        day: u8,
+        overflow: types::Overflow,
+    ) -> Result<Self::DateInner, DateTimeError> {
+        check_era(era, self.debug_name())?;
+        ArithmeticDate::new_from_solar(self, year, month_code, day, overflow)
+            .map(IslamicUmmAlQuraDateInner)
+    }
+
+    fn date_from_iso(&self, iso: Date<Iso>) -> IslamicUmmAlQuraDateInner {
+        let fixed_iso = Iso::fixed_from_iso(*iso.inner());
+        Self::umm_al_qura_from_fixed(fixed_iso)
+    }
+
+    fn date_to_iso(&self, date: &Self::DateInner) -> Date<Iso> {
+        let fixed = Self::fixed_from_umm_al_qura(date.0);
+        Iso::iso_from_fixed(fixed)
+    }
+
+    fn months_in_year(&self, date: &Self::DateInner) -> u8 {
+        date.0.months_in_year()
+    }
+
+    fn days_in_year(&self, date: &Self::DateInner) -> u32 {
+        date.0.days_in_year()
+    }
+
+    fn days_in_month(&self, date: &Self::DateInner) -> u8 {
+        date.0.days_in_month()
+    }
+
+    fn day_of_week(&self, date: &Self::DateInner) -> types::IsoWeekday {
+        Iso.day_of_week(self.date_to_iso(date).inner())
+    }
+
+    fn offset_date(
+        &self,
+        date: &mut Self::DateInner,
+        offset: DateDuration<Self>,
+        overflow: types::Overflow,
+    ) -> Result<(), DateTimeError> {
+        date.0.offset_date(offset, overflow)
+    }
+
+    fn until(
+        &self,
+        date1: &Self::DateInner,
+        date2: &Self::DateInner,
+        _calendar2: &Self,
+        largest_unit: DateDurationUnit,
+        smallest_unit: DateDurationUnit,
+    ) -> DateDuration<Self> {
+        date1.0.until(date2.0, largest_unit, smallest_unit)
+    }
+
+    fn year(&self, date: &Self::DateInner) -> types::FormattableYear {
+        year_as_islamic(date.0.year)
+    }
+
+    fn month(&self, date: &Self::DateInner) -> types::FormattableMonth {
+        date.0.solar_month()
+    }
+
+    fn day_of_month(&self, date: &Self::DateInner) -> types::DayOfMonth {
+        date.0.day_of_month()
+    }
+
+    fn day_of_year_info(&self, date: &Self::DateInner) -> types::DayOfYearInfo {
+        let prev_year = date.0.year - 1;
+        let next_year = date.0.year + 1;
+        types::DayOfYearInfo {
+            day_of_year: date.0.day_of_year(),
+            days_in_year: date.0.days_in_year(),
+            prev_year: year_as_islamic(prev_year),
+            days_in_prev_year: Self::days_in_year_direct(prev_year),
+            next_year: year_as_islamic(next_year),
+        }
+    }
+
+    fn debug_name(&self) -> &'static str {
+        "Islamic (Umm al-Qura)"
+    }
+
+    fn any_calendar_kind(&self) -> Option<AnyCalendarKind> {
+        Some(AnyCalendarKind::IslamicUmmAlQura)
+    }
+}
+
+fn new_arithmetic_date<C: CalendarArithmetic>(
+    year: i32,
+    month: u8,
+    day: u8,
+) -> Result<ArithmeticDate<C>, DateTimeError> {
+    let inner = ArithmeticDate {
+        year,
+        month,
+        day,
+        marker: PhantomData,
+    };
+
+    let bound = inner.days_in_month();
+    if day == 0 || day > bound {
+        return Err(DateTimeError::OutOfRange);
+    }
+
+    Ok(inner)
+}
+
+impl Date<IslamicCivil> {
+    /// Construct new civil Islamic Date.
+    ///
+    /// ```rust
+    /// use icu::calendar::Date;
+    ///
+    /// let date_islamic = Date::new_islamic_civil_date(1392, 4, 25)
+    ///     .expect("Failed to initialize Islamic Date instance.");
+    ///
+    /// assert_eq!(date_islamic.year().number, 1392);
+    /// assert_eq!(date_islamic.month().ordinal, 4);
+    /// assert_eq!(date_islamic.day_of_month().0, 25);
+    /// ```
+    pub fn new_islamic_civil_date(
+        year: i32,
+        month: u8,
+        day: u8,
+    ) -> Result<Date<IslamicCivil>, DateTimeError> {
+        new_arithmetic_date(year, month, day)
+            .map(|inner| Date::from_raw(IslamicCivilDateInner(inner), IslamicCivil))
+    }
+}
+
+impl Date<IslamicTabular> {
+    /// Construct new tabular Islamic Date.
+    ///
+    /// ```rust
+    /// use icu::calendar::Date;
+    ///
+    /// let date_islamic = Date::new_islamic_tabular_date(1392, 4, 25)
+    ///     .expect("Failed to initialize Islamic Date instance.");
+    ///
+    /// assert_eq!(date_islamic.year().number, 1392);
+    /// assert_eq!(date_islamic.month().ordinal, 4);
+    /// assert_eq!(date_islamic.day_of_month().0, 25);
+    /// ```
+    pub fn new_islamic_tabular_date(
+        year: i32,
+        month: u8,
+        day: u8,
+    ) -> Result<Date<IslamicTabular>, DateTimeError> {
+        new_arithmetic_date(year, month, day)
+            .map(|inner| Date::from_raw(IslamicTabularDateInner(inner), IslamicTabular))
+    }
+}
+
+impl Date<IslamicUmmAlQura> {
+    /// Construct new Umm al-Qura Islamic Date.
+    ///
+    /// ```rust
+    /// use icu::calendar::Date;
+    ///
+    /// let date_islamic = Date::new_islamic_umm_al_qura_date(1392, 4, 25)
+    ///     .expect("Failed to initialize Islamic Date instance.");
+    ///
+    /// assert_eq!(date_islamic.year().number, 1392);
+    /// assert_eq!(date_islamic.month().ordinal, 4);
+    /// assert_eq!(date_islamic.day_of_month().0, 25);
+    /// ```
+    pub fn new_islamic_umm_al_qura_date(
+        year: i32,
+        month: u8,
+        day: u8,
+    ) -> Result<Date<IslamicUmmAlQura>, DateTimeError> {
+        new_arithmetic_date(year, month, day)
+            .map(|inner| Date::from_raw(IslamicUmmAlQuraDateInner(inner), IslamicUmmAlQura))
+    }
+}
+
+impl DateTime<IslamicCivil> {
+    /// Construct a new civil Islamic datetime from integers.
+    ///
+    /// ```rust
+    /// use icu::calendar::DateTime;
+    ///
+    /// let datetime_islamic = DateTime::new_islamic_civil_datetime(474, 10, 11, 13, 1, 0)
+    ///     .expect("Failed to initialize Islamic DateTime instance.");
+    ///
+    /// assert_eq!(datetime_islamic.date.year().number, 474);
+    /// assert_eq!(datetime_islamic.date.month().ordinal, 10);
+    /// assert_eq!(datetime_islamic.date.day_of_month().0, 11);
+    /// assert_eq!(datetime_islamic.time.hour.number(), 13);
+    /// assert_eq!(datetime_islamic.time.minute.number(), 1);
+    /// assert_eq!(datetime_islamic.time.second.number(), 0);
+    /// ```
+    pub fn new_islamic_civil_datetime(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> Result<DateTime<IslamicCivil>, DateTimeError> {
+        Ok(DateTime {
+            date: Date::new_islamic_civil_date(year, month, day)?,
+            time: types::Time::try_new(hour, minute, second, 0)?,
+        })
+    }
+}
+
+impl DateTime<IslamicTabular> {
+    /// Construct a new tabular Islamic datetime from integers.
+    ///
+    /// ```rust
+    /// use icu::calendar::DateTime;
+    ///
+    /// let datetime_islamic = DateTime::new_islamic_tabular_datetime(474, 10, 11, 13, 1, 0)
+    ///     .expect("Failed to initialize Islamic DateTime instance.");
+    ///
+    /// assert_eq!(datetime_islamic.date.year().number, 474);
+    /// assert_eq!(datetime_islamic.date.month().ordinal, 10);
+    /// assert_eq!(datetime_islamic.date.day_of_month().0, 11);
+    /// assert_eq!(datetime_islamic.time.hour.number(), 13);
+    /// assert_eq!(datetime_islamic.time.minute.number(), 1);
+    /// assert_eq!(datetime_islamic.time.second.number(), 0);
+    /// ```
+    pub fn new_islamic_tabular_datetime(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> Result<DateTime<IslamicTabular>, DateTimeError> {
+        Ok(DateTime {
+            date: Date::new_islamic_tabular_date(year, month, day)?,
+            time: types::Time::try_new(hour, minute, second, 0)?,
+        })
+    }
+}
+
+impl DateTime<IslamicUmmAlQura> {
+    /// Construct a new Umm al-Qura Islamic datetime from integers.
+    ///
+    /// ```rust
+    /// use icu::calendar::DateTime;
+    ///
+    /// let datetime_islamic = DateTime::new_islamic_umm_al_qura_datetime(1444, 9, 1, 13, 1, 0)
+    ///     .expect("Failed to initialize Islamic DateTime instance.");
+    ///
+    /// assert_eq!(datetime_islamic.date.year().number, 1444);
+    /// assert_eq!(datetime_islamic.date.month().ordinal, 9);
+    /// assert_eq!(datetime_islamic.date.day_of_month().0, 1);
+    /// assert_eq!(datetime_islamic.time.hour.number(), 13);
+    /// assert_eq!(datetime_islamic.time.minute.number(), 1);
+    /// assert_eq!(datetime_islamic.time.second.number(), 0);
+    /// ```
+    pub fn new_islamic_umm_al_qura_datetime(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> Result<DateTime<IslamicUmmAlQura>, DateTimeError> {
+        Ok(DateTime {
+            date: Date::new_islamic_umm_al_qura_date(year, month, day)?,
+            time: types::Time::try_new(hour, minute, second, 0)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tabular() {
+        // From the sample data of Calendrical Calculations
+        let iso = Date::new_iso_date(1945, 11, 12).unwrap();
+        let civil = iso.to_calendar(IslamicCivil);
+        assert_eq!(civil, Date::new_islamic_civil_date(1364, 12, 6).unwrap());
+        assert_eq!(civil.to_iso(), iso);
+
+        // The tabular calendar starts a day earlier
+        let tabular = iso.to_calendar(IslamicTabular);
+        assert_eq!(
+            tabular,
+            Date::new_islamic_tabular_date(1364, 12, 7).unwrap()
+        );
+        assert_eq!(tabular.to_iso(), iso);
+
+        // 1364 is a common year, 1366 is a leap year
+        assert_eq!(civil.days_in_year(), 354);
+        assert_eq!(civil.days_in_month(), 29);
+        assert!(Date::new_islamic_civil_date(1364, 12, 30).is_err());
+        assert!(Date::new_islamic_civil_date(1366, 12, 30).is_ok());
+    }
+
+    #[test]
+    fn test_umm_al_qura() {
+        let iso = |y, m, d| Date::new_iso_date(y, m, d).unwrap();
+        let cases = [
+            (iso(1882, 11, 12), (1300, 1, 1)),
+            (iso(1945, 11, 12), (1364, 12, 7)),
+            (iso(2000, 1, 1), (1420, 9, 24)),
+            (iso(2023, 3, 23), (1444, 9, 1)),
+            (iso(2023, 7, 19), (1445, 1, 1)),
+            (iso(2024, 4, 9), (1445, 9, 30)),
+            (iso(2173, 12, 7), (1600, 1, 1)),
+        ];
+        for (iso, (year, month, day)) in cases {
+            let date = Date::new_islamic_umm_al_qura_date(year, month, day).unwrap();
+            assert_eq!(iso.to_calendar(IslamicUmmAlQura), date, "{iso:?}");
+            assert_eq!(date.to_iso(), iso, "{iso:?}");
+        }
+
+        // Ramadan 1444 had 29 days, Ramadan 1445 had 30 days
+        assert!(Date::new_islamic_umm_al_qura_date(1444, 9, 30).is_err());
+        assert!(Date::new_islamic_umm_al_qura_date(1445, 9, 30).is_ok());
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        // Covers the years before, in and after the Umm al-Qura table
+        let start = Iso::fixed_from_iso(*Date::new_iso_date(1850, 1, 1).unwrap().inner());
+        let end = Iso::fixed_from_iso(*Date::new_iso_date(2200, 1, 1).unwrap().inner());
+        for fixed in start..end {
+            let iso = Iso::iso_from_fixed(fixed);
+            assert_eq!(iso.to_calendar(IslamicCivil).to_iso(), iso);
+            assert_eq!(iso.to_calendar(IslamicTabular).to_iso(), iso);
+            let umm_al_qura = iso.to_calendar(IslamicUmmAlQura);
+            assert_eq!(umm_al_qura.to_iso(), iso);
+            assert!(umm_al_qura.day_of_month().0 <= umm_al_qura.days_in_month() as u32);
+        }
+    }
+
+    #[test]
+    fn test_umm_al_qura_year_lengths() {
+        for year in 1290..1610 {
+            let first = Date::new_islamic_umm_al_qura_date(year, 1, 1).unwrap();
+            let next = Date::new_islamic_umm_al_qura_date(year + 1, 1, 1).unwrap();
+            assert_eq!(
+                (next.to_fixed() - first.to_fixed()) as u32,
+                first.days_in_year(),
+                "{year}"
+            );
+        }
+    }
+}
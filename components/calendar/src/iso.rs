@@ -88,12 +88,13 @@ impl Calendar for Iso {
         year: i32,
         month_code: types::MonthCode,
         day: u8,
+        overflow: types::Overflow,
     ) -> Result<Self::DateInner, DateTimeError> {
         if era.0 != tinystr!(16, "default") {
             return Err(DateTimeError::UnknownEra(era.0, self.debug_name()));
         }
 
-        ArithmeticDate::new_from_solar(self, year, month_code, day).map(IsoDateInner)
+        ArithmeticDate::new_from_solar(self, year, month_code, day, overflow).map(IsoDateInner)
     }
 
     fn date_from_iso(&self, iso: Date<Iso>) -> IsoDateInner {
@@ -161,20 +162,24 @@ impl Calendar for Iso {
         types::IsoWeekday::from((day_offset + 1) as usize)
     }
 
-    fn offset_date(&self, date: &mut Self::DateInner, offset: DateDuration<Self>) {
-        date.0.offset_date(offset);
+    fn offset_date(
+        &self,
+        date: &mut Self::DateInner,
+        offset: DateDuration<Self>,
+        overflow: types::Overflow,
+    ) -> Result<(), DateTimeError> {
+        date.0.offset_date(offset, overflow)
     }
 
-    #[allow(clippy::field_reassign_with_default)]
     fn until(
         &self,
         date1: &Self::DateInner,
         date2: &Self::DateInner,
         _calendar2: &Self,
-        _largest_unit: DateDurationUnit,
-        _smallest_unit: DateDurationUnit,
+        largest_unit: DateDurationUnit,
+        smallest_unit: DateDurationUnit,
     ) -> DateDuration<Self> {
-        date1.0.until(date2.0, _largest_unit, _smallest_unit)
+        date1.0.until(date2.0, largest_unit, smallest_unit)
     }
 
     /// The calendar-specific year represented by `date`
@@ -377,7 +382,8 @@ impl Iso {
         // Calculate days per year
         let mut fixed: i32 = EPOCH - 1 + 365 * (date.0.year - 1);
         // Adjust for leap year logic
-        fixed += ((date.0.year - 1) / 4) - ((date.0.year - 1) / 100) + ((date.0.year - 1) / 400);
+        fixed += (date.0.year - 1).div_euclid(4) - (date.0.year - 1).div_euclid(100)
+            + (date.0.year - 1).div_euclid(400);
         // Days of current year
         fixed += (367 * (date.0.month as i32) - 362) / 12;
         // Leap year adjustment for the current year
@@ -419,10 +425,12 @@ impl Iso {
     }
 
     // Lisp code reference: https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l#L1191-L1217
-    fn iso_year_from_fixed(date: i32) -> i32 {
+    pub(crate) fn iso_year_from_fixed(date: i32) -> i32 {
+        let date = date - EPOCH;
+
         // 400 year cycles have 146097 days
-        let n_400 = date / 146097;
-        let date = date % 146097;
+        let n_400 = date.div_euclid(146097);
+        let date = date.rem_euclid(146097);
 
         // 100 year cycles have 36524 days
         let n_100 = date / 36524;
@@ -487,6 +495,7 @@ impl Iso {
         types::FormattableYear {
             era: types::Era(tinystr!(16, "default")),
             number: year,
+            cyclic: None,
             related_iso: None,
         }
     }
@@ -515,6 +524,7 @@ impl From<&'_ IsoDateInner> for crate::provider::EraStartDate {
 mod test {
     use super::*;
     use crate::types::IsoWeekday;
+    use crate::RoundingMode;
 
     #[test]
     fn test_day_of_week() {
@@ -578,20 +588,37 @@ mod test {
     fn test_offset() {
         let today = Date::new_iso_date(2021, 6, 23).unwrap();
         let today_plus_5000 = Date::new_iso_date(2035, 3, 2).unwrap();
-        let offset = today.clone().added(DateDuration::new(0, 0, 0, 5000));
+        let offset = today
+            .clone()
+            .added(DateDuration::new(0, 0, 0, 5000), types::Overflow::Constrain)
+            .unwrap();
         assert_eq!(offset, today_plus_5000);
         let offset = today
             .clone()
-            .added(simple_subtract(&today_plus_5000, &today));
+            .added(
+                simple_subtract(&today_plus_5000, &today),
+                types::Overflow::Constrain,
+            )
+            .unwrap();
         assert_eq!(offset, today_plus_5000);
 
         let today = Date::new_iso_date(2021, 6, 23).unwrap();
         let today_minus_5000 = Date::new_iso_date(2007, 10, 15).unwrap();
-        let offset = today.clone().added(DateDuration::new(0, 0, 0, -5000));
+        let offset = today
+            .clone()
+            .added(
+                DateDuration::new(0, 0, 0, -5000),
+                types::Overflow::Constrain,
+            )
+            .unwrap();
         assert_eq!(offset, today_minus_5000);
         let offset = today
             .clone()
-            .added(simple_subtract(&today_minus_5000, &today));
+            .added(
+                simple_subtract(&today_minus_5000, &today),
+                types::Overflow::Constrain,
+            )
+            .unwrap();
         assert_eq!(offset, today_minus_5000);
     }
 
@@ -599,32 +626,44 @@ mod test {
     fn test_offset_at_month_boundary() {
         let today = Date::new_iso_date(2020, 2, 28).unwrap();
         let today_plus_2 = Date::new_iso_date(2020, 3, 1).unwrap();
-        let offset = today.added(DateDuration::new(0, 0, 0, 2));
+        let offset = today
+            .added(DateDuration::new(0, 0, 0, 2), types::Overflow::Constrain)
+            .unwrap();
         assert_eq!(offset, today_plus_2);
 
         let today = Date::new_iso_date(2020, 2, 28).unwrap();
         let today_plus_3 = Date::new_iso_date(2020, 3, 2).unwrap();
-        let offset = today.added(DateDuration::new(0, 0, 0, 3));
+        let offset = today
+            .added(DateDuration::new(0, 0, 0, 3), types::Overflow::Constrain)
+            .unwrap();
         assert_eq!(offset, today_plus_3);
 
         let today = Date::new_iso_date(2020, 2, 28).unwrap();
         let today_plus_1 = Date::new_iso_date(2020, 2, 29).unwrap();
-        let offset = today.added(DateDuration::new(0, 0, 0, 1));
+        let offset = today
+            .added(DateDuration::new(0, 0, 0, 1), types::Overflow::Constrain)
+            .unwrap();
         assert_eq!(offset, today_plus_1);
 
         let today = Date::new_iso_date(2019, 2, 28).unwrap();
         let today_plus_2 = Date::new_iso_date(2019, 3, 2).unwrap();
-        let offset = today.added(DateDuration::new(0, 0, 0, 2));
+        let offset = today
+            .added(DateDuration::new(0, 0, 0, 2), types::Overflow::Constrain)
+            .unwrap();
         assert_eq!(offset, today_plus_2);
 
         let today = Date::new_iso_date(2019, 2, 28).unwrap();
         let today_plus_1 = Date::new_iso_date(2019, 3, 1).unwrap();
-        let offset = today.added(DateDuration::new(0, 0, 0, 1));
+        let offset = today
+            .added(DateDuration::new(0, 0, 0, 1), types::Overflow::Constrain)
+            .unwrap();
         assert_eq!(offset, today_plus_1);
 
         let today = Date::new_iso_date(2020, 3, 1).unwrap();
         let today_minus_1 = Date::new_iso_date(2020, 2, 29).unwrap();
-        let offset = today.added(DateDuration::new(0, 0, 0, -1));
+        let offset = today
+            .added(DateDuration::new(0, 0, 0, -1), types::Overflow::Constrain)
+            .unwrap();
         assert_eq!(offset, today_minus_1);
     }
 
@@ -632,37 +671,349 @@ mod test {
     fn test_offset_handles_negative_month_offset() {
         let today = Date::new_iso_date(2020, 3, 1).unwrap();
         let today_minus_2_months = Date::new_iso_date(2020, 1, 1).unwrap();
-        let offset = today.added(DateDuration::new(0, -2, 0, 0));
+        let offset = today
+            .added(DateDuration::new(0, -2, 0, 0), types::Overflow::Constrain)
+            .unwrap();
         assert_eq!(offset, today_minus_2_months);
 
         let today = Date::new_iso_date(2020, 3, 1).unwrap();
         let today_minus_4_months = Date::new_iso_date(2019, 11, 1).unwrap();
-        let offset = today.added(DateDuration::new(0, -4, 0, 0));
+        let offset = today
+            .added(DateDuration::new(0, -4, 0, 0), types::Overflow::Constrain)
+            .unwrap();
         assert_eq!(offset, today_minus_4_months);
 
         let today = Date::new_iso_date(2020, 3, 1).unwrap();
         let today_minus_24_months = Date::new_iso_date(2018, 3, 1).unwrap();
-        let offset = today.added(DateDuration::new(0, -24, 0, 0));
+        let offset = today
+            .added(DateDuration::new(0, -24, 0, 0), types::Overflow::Constrain)
+            .unwrap();
         assert_eq!(offset, today_minus_24_months);
 
         let today = Date::new_iso_date(2020, 3, 1).unwrap();
         let today_minus_27_months = Date::new_iso_date(2017, 12, 1).unwrap();
-        let offset = today.added(DateDuration::new(0, -27, 0, 0));
+        let offset = today
+            .added(DateDuration::new(0, -27, 0, 0), types::Overflow::Constrain)
+            .unwrap();
         assert_eq!(offset, today_minus_27_months);
     }
 
     #[test]
     fn test_offset_handles_out_of_bound_month_offset() {
         let today = Date::new_iso_date(2021, 1, 31).unwrap();
-        // since 2021/02/31 isn't a valid date, `offset_date` auto-adjusts by adding 3 days to 2021/02/28
-        let today_plus_1_month = Date::new_iso_date(2021, 3, 3).unwrap();
-        let offset = today.added(DateDuration::new(0, 1, 0, 0));
+        // since 2021/02/31 isn't a valid date, `offset_date` constrains it to 2021/02/28
+        let today_plus_1_month = Date::new_iso_date(2021, 2, 28).unwrap();
+        let offset = today
+            .added(DateDuration::new(0, 1, 0, 0), types::Overflow::Constrain)
+            .unwrap();
         assert_eq!(offset, today_plus_1_month);
 
         let today = Date::new_iso_date(2021, 1, 31).unwrap();
-        // since 2021/02/31 isn't a valid date, `offset_date` auto-adjusts by adding 3 days to 2021/02/28
-        let today_plus_1_month_1_day = Date::new_iso_date(2021, 3, 4).unwrap();
-        let offset = today.added(DateDuration::new(0, 1, 0, 1));
+        // days are added after constraining the day to 2021/02/28
+        let today_plus_1_month_1_day = Date::new_iso_date(2021, 3, 1).unwrap();
+        let offset = today
+            .added(DateDuration::new(0, 1, 0, 1), types::Overflow::Constrain)
+            .unwrap();
         assert_eq!(offset, today_plus_1_month_1_day);
+
+        let today = Date::new_iso_date(2020, 2, 29).unwrap();
+        let today_plus_1_year = Date::new_iso_date(2021, 2, 28).unwrap();
+        let offset = today
+            .added(DateDuration::new(1, 0, 0, 0), types::Overflow::Constrain)
+            .unwrap();
+        assert_eq!(offset, today_plus_1_year);
+    }
+
+    #[test]
+    fn test_offset_overflow_reject() {
+        let mut date = Date::new_iso_date(2021, 1, 31).unwrap();
+        assert_eq!(
+            date.add(DateDuration::new(0, 1, 0, 0), types::Overflow::Reject),
+            Err(DateTimeError::OutOfRange)
+        );
+        assert_eq!(date, Date::new_iso_date(2021, 1, 31).unwrap());
+        assert_eq!(
+            date.add(DateDuration::new(0, 1, 0, 1), types::Overflow::Reject),
+            Err(DateTimeError::OutOfRange)
+        );
+
+        // Adding days never overflows
+        date.add(DateDuration::new(0, 0, 0, 29), types::Overflow::Reject)
+            .unwrap();
+        assert_eq!(date, Date::new_iso_date(2021, 3, 1).unwrap());
+        date.add(DateDuration::new(-1, 2, 0, 0), types::Overflow::Reject)
+            .unwrap();
+        assert_eq!(date, Date::new_iso_date(2020, 5, 1).unwrap());
+
+        let leap_day = Date::new_iso_date(2020, 2, 29).unwrap();
+        assert!(leap_day
+            .clone()
+            .added(DateDuration::new(1, 0, 0, 0), types::Overflow::Reject)
+            .is_err());
+        assert_eq!(
+            leap_day.added(DateDuration::new(4, 0, 0, 0), types::Overflow::Reject),
+            Date::new_iso_date(2024, 2, 29)
+        );
+    }
+
+    #[test]
+    fn test_from_codes_overflow() {
+        let era = types::Era(tinystr!(16, "default"));
+        let from_codes = |month: &str, day, overflow| {
+            Date::new_from_codes_with_overflow(
+                era,
+                2021,
+                types::MonthCode(month.parse().unwrap()),
+                day,
+                overflow,
+                Iso,
+            )
+        };
+
+        assert_eq!(
+            from_codes("M02", 31, types::Overflow::Constrain),
+            Date::new_iso_date(2021, 2, 28)
+        );
+        assert_eq!(
+            from_codes("M04", 31, types::Overflow::Constrain),
+            Date::new_iso_date(2021, 4, 30)
+        );
+        assert_eq!(
+            from_codes("M02", 31, types::Overflow::Reject),
+            Err(DateTimeError::OutOfRange)
+        );
+        assert_eq!(
+            from_codes("M02", 28, types::Overflow::Reject),
+            Date::new_iso_date(2021, 2, 28)
+        );
+        // Unknown month codes are never constrained
+        for overflow in [types::Overflow::Constrain, types::Overflow::Reject] {
+            assert!(matches!(
+                from_codes("M13", 1, overflow),
+                Err(DateTimeError::UnknownMonthCode(..))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_until() {
+        let iso = |y, m, d| Date::new_iso_date(y, m, d).unwrap();
+        // (date, other, largest unit, smallest unit, (years, months, weeks, days))
+        let cases = [
+            (
+                iso(1990, 2, 28),
+                iso(2024, 2, 27),
+                DateDurationUnit::Years,
+                DateDurationUnit::Days,
+                (33, 11, 0, 30),
+            ),
+            (
+                iso(2024, 2, 27),
+                iso(1990, 2, 28),
+                DateDurationUnit::Years,
+                DateDurationUnit::Days,
+                (-33, -11, 0, -27),
+            ),
+            (
+                iso(1990, 2, 28),
+                iso(2024, 2, 27),
+                DateDurationUnit::Months,
+                DateDurationUnit::Days,
+                (0, 407, 0, 30),
+            ),
+            (
+                iso(1990, 2, 28),
+                iso(2024, 2, 27),
+                DateDurationUnit::Years,
+                DateDurationUnit::Months,
+                (33, 11, 0, 0),
+            ),
+            (
+                iso(1990, 2, 28),
+                iso(2024, 2, 27),
+                DateDurationUnit::Years,
+                DateDurationUnit::Years,
+                (33, 0, 0, 0),
+            ),
+            (
+                iso(2024, 1, 31),
+                iso(2024, 2, 29),
+                DateDurationUnit::Months,
+                DateDurationUnit::Days,
+                (0, 1, 0, 0),
+            ),
+            (
+                iso(2024, 2, 29),
+                iso(2024, 1, 31),
+                DateDurationUnit::Months,
+                DateDurationUnit::Days,
+                (0, 0, 0, -29),
+            ),
+            (
+                iso(2024, 3, 31),
+                iso(2024, 2, 29),
+                DateDurationUnit::Months,
+                DateDurationUnit::Days,
+                (0, -1, 0, 0),
+            ),
+            (
+                iso(2020, 2, 29),
+                iso(2021, 2, 28),
+                DateDurationUnit::Years,
+                DateDurationUnit::Days,
+                (1, 0, 0, 0),
+            ),
+            (
+                iso(2024, 1, 1),
+                iso(2024, 3, 1),
+                DateDurationUnit::Weeks,
+                DateDurationUnit::Days,
+                (0, 0, 8, 4),
+            ),
+            (
+                iso(2024, 1, 1),
+                iso(2024, 3, 1),
+                DateDurationUnit::Days,
+                DateDurationUnit::Days,
+                (0, 0, 0, 60),
+            ),
+            (
+                iso(2024, 3, 1),
+                iso(2024, 1, 1),
+                DateDurationUnit::Weeks,
+                DateDurationUnit::Weeks,
+                (0, 0, -8, 0),
+            ),
+            (
+                iso(2024, 1, 1),
+                iso(2024, 3, 20),
+                DateDurationUnit::Months,
+                DateDurationUnit::Weeks,
+                (0, 2, 2, 0),
+            ),
+            (
+                iso(2021, 6, 23),
+                iso(2035, 3, 2),
+                DateDurationUnit::Days,
+                DateDurationUnit::Days,
+                (0, 0, 0, 5000),
+            ),
+            // A smallest unit larger than the largest unit extends the largest unit
+            (
+                iso(2024, 1, 1),
+                iso(2025, 3, 1),
+                DateDurationUnit::Days,
+                DateDurationUnit::Months,
+                (0, 14, 0, 0),
+            ),
+            (
+                iso(2024, 1, 1),
+                iso(2024, 1, 1),
+                DateDurationUnit::Years,
+                DateDurationUnit::Days,
+                (0, 0, 0, 0),
+            ),
+        ];
+        for (date, other, largest, smallest, (years, months, weeks, days)) in cases {
+            let duration = date.until(&other, largest, smallest, RoundingMode::Trunc);
+            assert_eq!(
+                (
+                    duration.years,
+                    duration.months,
+                    duration.weeks,
+                    duration.days
+                ),
+                (years, months, weeks, days),
+                "{date:?} until {other:?} in {largest:?} to {smallest:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_until_rounding() {
+        let iso = |y, m, d| Date::new_iso_date(y, m, d).unwrap();
+        let months = |date: &Date<Iso>, other: &Date<Iso>, rounding_mode| {
+            date.until(
+                other,
+                DateDurationUnit::Months,
+                DateDurationUnit::Months,
+                rounding_mode,
+            )
+            .months
+        };
+
+        // 19 of the 29 days of February 2024 past a month
+        let (date, other) = (iso(2024, 1, 1), iso(2024, 2, 20));
+        assert_eq!(months(&date, &other, RoundingMode::Trunc), 1);
+        assert_eq!(months(&date, &other, RoundingMode::Floor), 1);
+        assert_eq!(months(&date, &other, RoundingMode::Ceil), 2);
+        assert_eq!(months(&date, &other, RoundingMode::Expand), 2);
+        assert_eq!(months(&date, &other, RoundingMode::HalfTrunc), 2);
+        assert_eq!(months(&date, &other, RoundingMode::HalfExpand), 2);
+        assert_eq!(months(&other, &date, RoundingMode::Trunc), -1);
+        assert_eq!(months(&other, &date, RoundingMode::Floor), -2);
+        assert_eq!(months(&other, &date, RoundingMode::Ceil), -1);
+        assert_eq!(months(&other, &date, RoundingMode::HalfExpand), -2);
+
+        // Half of February 2023, from the start of an even and an odd number of months
+        let (date, other) = (iso(2023, 2, 1), iso(2023, 2, 15));
+        assert_eq!(months(&date, &other, RoundingMode::HalfTrunc), 0);
+        assert_eq!(months(&date, &other, RoundingMode::HalfExpand), 1);
+        assert_eq!(months(&date, &other, RoundingMode::HalfCeil), 1);
+        assert_eq!(months(&date, &other, RoundingMode::HalfFloor), 0);
+        assert_eq!(months(&date, &other, RoundingMode::HalfEven), 0);
+        assert_eq!(months(&iso(2023, 1, 1), &other, RoundingMode::HalfEven), 2);
+        let (date, other) = (iso(2023, 3, 1), iso(2023, 2, 15));
+        assert_eq!(months(&date, &other, RoundingMode::HalfExpand), -1);
+        assert_eq!(months(&date, &other, RoundingMode::HalfCeil), 0);
+        assert_eq!(months(&date, &other, RoundingMode::HalfFloor), -1);
+        assert_eq!(months(&date, &other, RoundingMode::HalfEven), 0);
+
+        // Rounding up balances the duration again
+        let duration = iso(2023, 1, 1).until(
+            &iso(2023, 12, 20),
+            DateDurationUnit::Years,
+            DateDurationUnit::Months,
+            RoundingMode::HalfExpand,
+        );
+        assert_eq!((duration.years, duration.months), (1, 0));
+        let duration = iso(2024, 1, 1).until(
+            &iso(2024, 1, 26),
+            DateDurationUnit::Weeks,
+            DateDurationUnit::Weeks,
+            RoundingMode::HalfExpand,
+        );
+        assert_eq!((duration.weeks, duration.days), (4, 0));
+    }
+
+    #[test]
+    fn test_until_roundtrip() {
+        let units = [
+            DateDurationUnit::Years,
+            DateDurationUnit::Months,
+            DateDurationUnit::Weeks,
+            DateDurationUnit::Days,
+        ];
+        let start = Iso::fixed_from_iso(*Date::new_iso_date(2019, 12, 25).unwrap().inner());
+        let dates: Vec<_> = (0..500)
+            .step_by(3)
+            .map(|offset| Iso::iso_from_fixed(start + offset))
+            .collect();
+        for date in &dates {
+            for other in &dates {
+                for unit in units {
+                    let duration =
+                        date.until(other, unit, DateDurationUnit::Days, RoundingMode::Trunc);
+                    assert_eq!(
+                        &date
+                            .clone()
+                            .added(duration, types::Overflow::Constrain)
+                            .unwrap(),
+                        other,
+                        "{date:?} until {other:?} in {unit:?}"
+                    );
+                }
+            }
+        }
     }
 }
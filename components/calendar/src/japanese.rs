@@ -44,6 +44,7 @@
 //! ```
 
 use crate::any_calendar::AnyCalendarKind;
+use crate::calendar_arithmetic::CalendarArithmetic;
 use crate::iso::{Iso, IsoDateInner};
 use crate::provider::{EraStartDate, JapaneseErasV1Marker, JapaneseExtendedErasV1Marker};
 use crate::{
@@ -133,6 +134,7 @@ impl Japanese {
         year: i32,
         month_code: types::MonthCode,
         day: u8,
+        overflow: types::Overflow,
         debug_name: &'static str,
     ) -> Result<JapaneseDateInner, DateTimeError> {
         let month = crate::calendar_arithmetic::ordinal_solar_month_from_code(month_code);
@@ -146,7 +148,7 @@ impl Japanese {
             return Err(DateTimeError::UnknownMonthCode(month_code.0, debug_name));
         }
 
-        self.new_japanese_date_inner(era, year, month, day)
+        self.new_japanese_date_inner(era, year, month, day, overflow)
     }
 }
 
@@ -176,8 +178,9 @@ impl Calendar for Japanese {
         year: i32,
         month_code: types::MonthCode,
         day: u8,
+        overflow: types::Overflow,
     ) -> Result<Self::DateInner, DateTimeError> {
-        self.japanese_date_from_codes(era, year, month_code, day, self.debug_name())
+        self.japanese_date_from_codes(era, year, month_code, day, overflow, self.debug_name())
     }
 
     fn date_from_iso(&self, iso: Date<Iso>) -> JapaneseDateInner {
@@ -205,11 +208,17 @@ impl Calendar for Japanese {
         Iso.days_in_month(&date.inner)
     }
 
-    fn offset_date(&self, date: &mut Self::DateInner, offset: DateDuration<Self>) {
-        Iso.offset_date(&mut date.inner, offset.cast_unit());
+    fn offset_date(
+        &self,
+        date: &mut Self::DateInner,
+        offset: DateDuration<Self>,
+        overflow: types::Overflow,
+    ) -> Result<(), DateTimeError> {
+        Iso.offset_date(&mut date.inner, offset.cast_unit(), overflow)?;
         let (adjusted_year, era) = self.adjusted_year_for(&date.inner);
         date.adjusted_year = adjusted_year;
-        date.era = era
+        date.era = era;
+        Ok(())
     }
 
     fn until(
@@ -235,6 +244,7 @@ impl Calendar for Japanese {
         types::FormattableYear {
             era: types::Era(date.era),
             number: date.adjusted_year,
+            cyclic: None,
             related_iso: None,
         }
     }
@@ -283,9 +293,10 @@ impl Calendar for JapaneseExtended {
         year: i32,
         month_code: types::MonthCode,
         day: u8,
+        overflow: types::Overflow,
     ) -> Result<Self::DateInner, DateTimeError> {
         self.0
-            .japanese_date_from_codes(era, year, month_code, day, self.debug_name())
+            .japanese_date_from_codes(era, year, month_code, day, overflow, self.debug_name())
     }
 
     fn date_from_iso(&self, iso: Date<Iso>) -> JapaneseDateInner {
@@ -308,8 +319,13 @@ impl Calendar for JapaneseExtended {
         Japanese::days_in_month(&self.0, date)
     }
 
-    fn offset_date(&self, date: &mut Self::DateInner, offset: DateDuration<Self>) {
-        Japanese::offset_date(&self.0, date, offset.cast_unit())
+    fn offset_date(
+        &self,
+        date: &mut Self::DateInner,
+        offset: DateDuration<Self>,
+        overflow: types::Overflow,
+    ) -> Result<(), DateTimeError> {
+        Japanese::offset_date(&self.0, date, offset.cast_unit(), overflow)
     }
 
     fn until(
@@ -407,9 +423,13 @@ impl Date<Japanese> {
         day: u8,
         japanese_calendar: A,
     ) -> Result<Date<A>, DateTimeError> {
-        let inner = japanese_calendar
-            .as_calendar()
-            .new_japanese_date_inner(era, year, month, day)?;
+        let inner = japanese_calendar.as_calendar().new_japanese_date_inner(
+            era,
+            year,
+            month,
+            day,
+            types::Overflow::Reject,
+        )?;
         Ok(Date::from_raw(inner, japanese_calendar))
     }
 }
@@ -451,10 +471,13 @@ impl Date<JapaneseExtended> {
         day: u8,
         japanext_calendar: A,
     ) -> Result<Date<A>, DateTimeError> {
-        let inner = japanext_calendar
-            .as_calendar()
-            .0
-            .new_japanese_date_inner(era, year, month, day)?;
+        let inner = japanext_calendar.as_calendar().0.new_japanese_date_inner(
+            era,
+            year,
+            month,
+            day,
+            types::Overflow::Reject,
+        )?;
         Ok(Date::from_raw(inner, japanext_calendar))
     }
 
@@ -701,20 +724,27 @@ impl Japanese {
         year: i32,
         month: u8,
         day: u8,
+        overflow: types::Overflow,
     ) -> Result<JapaneseDateInner, DateTimeError> {
+        let constrain = |iso_year| match overflow {
+            types::Overflow::Constrain => day.min(Iso::month_days(iso_year, month)),
+            types::Overflow::Reject => day,
+        };
         let cal = Ref(self);
         if era.0 == tinystr!(16, "bce") {
             if year <= 0 {
                 return Err(DateTimeError::OutOfRange);
             }
-            return Ok(Date::new_iso_date(1 - year, month, day)?
+            return Ok(Date::new_iso_date(1 - year, month, constrain(1 - year))?
                 .to_calendar(cal)
                 .inner);
         } else if era.0 == tinystr!(16, "ce") {
             if year <= 0 {
                 return Err(DateTimeError::OutOfRange);
             }
-            return Ok(Date::new_iso_date(year, month, day)?.to_calendar(cal).inner);
+            return Ok(Date::new_iso_date(year, month, constrain(year))?
+                .to_calendar(cal)
+                .inner);
         }
 
         let (era_start, next_era_start) = self.japanese_era_range_for(era.0)?;
@@ -722,7 +752,7 @@ impl Japanese {
         let date_in_iso = EraStartDate {
             year: era_start.year + year - 1,
             month,
-            day,
+            day: constrain(era_start.year + year - 1),
         };
 
         if date_in_iso < era_start {
@@ -926,4 +956,27 @@ mod tests {
             DateTimeError::OutOfRange,
         );
     }
+
+    #[test]
+    fn test_from_codes_overflow() {
+        let provider = icu_testdata::get_provider();
+        let calendar = Japanese::try_new_unstable(&provider).expect("Cannot load japanese data");
+        let from_codes = |era: &str, year, overflow| {
+            Date::new_from_codes_with_overflow(
+                types::Era(era.parse().unwrap()),
+                year,
+                types::MonthCode(tinystr!(4, "M02")),
+                30,
+                overflow,
+                Ref(&calendar),
+            )
+        };
+
+        // Reiwa 2 (2020) is a leap year
+        let date = from_codes("reiwa", 2, types::Overflow::Constrain).unwrap();
+        assert_eq!(date.to_iso(), Date::new_iso_date(2020, 2, 29).unwrap());
+        let date = from_codes("ce", 2021, types::Overflow::Constrain).unwrap();
+        assert_eq!(date.to_iso(), Date::new_iso_date(2021, 2, 28).unwrap());
+        assert!(from_codes("reiwa", 2, types::Overflow::Reject).is_err());
+    }
 }
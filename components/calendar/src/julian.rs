@@ -90,6 +90,7 @@ impl Calendar for Julian {
         year: i32,
         month_code: types::MonthCode,
         day: u8,
+        overflow: types::Overflow,
     ) -> Result<Self::DateInner, DateTimeError> {
         let year = if era.0 == tinystr!(16, "ad") {
             if year <= 0 {
@@ -105,7 +106,7 @@ impl Calendar for Julian {
             return Err(DateTimeError::UnknownEra(era.0, self.debug_name()));
         };
 
-        ArithmeticDate::new_from_solar(self, year, month_code, day).map(JulianDateInner)
+        ArithmeticDate::new_from_solar(self, year, month_code, day, overflow).map(JulianDateInner)
     }
     fn date_from_iso(&self, iso: Date<Iso>) -> JulianDateInner {
         let fixed_iso = Iso::fixed_from_iso(*iso.inner());
@@ -133,20 +134,24 @@ impl Calendar for Julian {
         Iso.day_of_week(Julian.date_to_iso(date).inner())
     }
 
-    fn offset_date(&self, date: &mut Self::DateInner, offset: DateDuration<Self>) {
-        date.0.offset_date(offset);
+    fn offset_date(
+        &self,
+        date: &mut Self::DateInner,
+        offset: DateDuration<Self>,
+        overflow: types::Overflow,
+    ) -> Result<(), DateTimeError> {
+        date.0.offset_date(offset, overflow)
     }
 
-    #[allow(clippy::field_reassign_with_default)]
     fn until(
         &self,
         date1: &Self::DateInner,
         date2: &Self::DateInner,
         _calendar2: &Self,
-        _largest_unit: DateDurationUnit,
-        _smallest_unit: DateDurationUnit,
+        largest_unit: DateDurationUnit,
+        smallest_unit: DateDurationUnit,
     ) -> DateDuration<Self> {
-        date1.0.until(date2.0, _largest_unit, _smallest_unit)
+        date1.0.until(date2.0, largest_unit, smallest_unit)
     }
 
     /// The calendar-specific year represented by `date`
@@ -194,7 +199,7 @@ impl Julian {
 
     #[inline(always)]
     const fn is_leap_year_const(year: i32) -> bool {
-        year % 4 == 0
+        year.rem_euclid(4) == 0
     }
 
     // "Fixed" is a day count representation of calendars staring from Jan 1st of year 1 of the Georgian Calendar.
@@ -202,13 +207,11 @@ impl Julian {
     // Dershowitz, Nachum, and Edward M. Reingold. _Calendrical calculations_. Cambridge University Press, 2008.
     //
     // Lisp code reference: https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l#L1689-L1709
+    //
+    // Unlike the reference code, which has no year zero, years here are counted with year 0 = 1 BC.
     pub(crate) const fn fixed_from_julian(date: ArithmeticDate<Julian>) -> i32 {
-        let year = if date.year < 0 {
-            date.year + 1
-        } else {
-            date.year
-        };
-        let mut fixed: i32 = JULIAN_EPOCH - 1 + 365 * (year - 1) + (year - 1) / 4;
+        let year = date.year;
+        let mut fixed: i32 = JULIAN_EPOCH - 1 + 365 * (year - 1) + (year - 1).div_euclid(4);
         fixed += (367 * (date.month as i32) - 362) / 12;
         fixed += if date.month <= 2 {
             0
@@ -241,9 +244,8 @@ impl Julian {
     }
 
     // Lisp code reference: https://github.com/EdReingold/calendar-code2/blob/1ee51ecfaae6f856b0d7de3e36e9042100b4f424/calendar.l#L1711-L1738
-    fn julian_from_fixed(date: i32) -> JulianDateInner {
-        let approx = ((4 * date) + 1464) / 1461;
-        let year = if approx <= 0 { approx - 1 } else { approx };
+    pub(crate) fn julian_from_fixed(date: i32) -> JulianDateInner {
+        let year = (4 * (date - JULIAN_EPOCH) + 1464).div_euclid(1461);
         let prior_days = date - Self::fixed_from_julian_integers(year, 1, 1);
         let correction = if date < Self::fixed_from_julian_integers(year, 3, 1) {
             0
@@ -394,4 +396,43 @@ mod test {
         let iso_expected_date = Date::new_iso_date(2022, 3, 1).unwrap();
         assert_eq!(iso_date, iso_expected_date);
     }
+
+    #[test]
+    fn test_julian_bce() {
+        // Julian Day Number 0 is January 1, 4713 BC
+        let date = Date::from_rata_die(crate::RataDie::from_julian_day_number(0), Julian);
+        assert_eq!(
+            *date.inner(),
+            *Date::new_julian_date(-4712, 1, 1).unwrap().inner()
+        );
+        assert_eq!(date.year().era.0, tinystr!(16, "bce"));
+        assert_eq!(date.year().number, 4713);
+
+        // Every day from 4713 BC to 10 AD roundtrips, and follows the previous one
+        let mut previous = Date::new_julian_date(-4713, 12, 31).unwrap();
+        for fixed in date.to_fixed()..Date::new_julian_date(10, 1, 1).unwrap().to_fixed() {
+            let date = Date::from_rata_die(crate::RataDie::new(fixed), Julian);
+            assert_eq!(date.to_fixed(), fixed);
+            let expected = if previous.day_of_month().0 < previous.days_in_month() as u32 {
+                (
+                    previous.inner().0.year,
+                    previous.inner().0.month,
+                    previous.inner().0.day + 1,
+                )
+            } else if previous.inner().0.month < 12 {
+                (previous.inner().0.year, previous.inner().0.month + 1, 1)
+            } else {
+                (previous.inner().0.year + 1, 1, 1)
+            };
+            assert_eq!(
+                (
+                    date.inner().0.year,
+                    date.inner().0.month,
+                    date.inner().0.day
+                ),
+                expected
+            );
+            previous = date;
+        }
+    }
 }
@@ -0,0 +1,730 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! This module contains types and implementations for the hybrid Julian/Gregorian calendar.
+//!
+//! ```rust
+//! use icu::calendar::{julian_gregorian::JulianGregorian, Date, DateTime};
+//!
+//! // `Date` type
+//! let date_iso = Date::new_iso_date(1582, 10, 14)
+//!     .expect("Failed to initialize ISO Date instance.");
+//! let date_julian_gregorian = Date::new_from_iso(date_iso, JulianGregorian::new());
+//!
+//! // `DateTime` type
+//! let datetime_iso = DateTime::new_iso_datetime(1582, 10, 14, 13, 1, 0)
+//!     .expect("Failed to initialize ISO DateTime instance.");
+//! let datetime_julian_gregorian =
+//!     DateTime::new_from_iso(datetime_iso, JulianGregorian::new());
+//!
+//! // `Date` checks: the day before the cutover is in the Julian calendar
+//! assert_eq!(date_julian_gregorian.year().number, 1582);
+//! assert_eq!(date_julian_gregorian.month().ordinal, 10);
+//! assert_eq!(date_julian_gregorian.day_of_month().0, 4);
+//!
+//! // `DateTime` type
+//! assert_eq!(datetime_julian_gregorian.date.year().number, 1582);
+//! assert_eq!(datetime_julian_gregorian.date.month().ordinal, 10);
+//! assert_eq!(datetime_julian_gregorian.date.day_of_month().0, 4);
+//! assert_eq!(datetime_julian_gregorian.time.hour.number(), 13);
+//! assert_eq!(datetime_julian_gregorian.time.minute.number(), 1);
+//! assert_eq!(datetime_julian_gregorian.time.second.number(), 0);
+//! ```
+
+use crate::any_calendar::AnyCalendarKind;
+use crate::calendar_arithmetic::{
+    self, constrain_day, ordinal_solar_month_from_code, ArithmeticDate, CalendarArithmetic,
+    UntilDate,
+};
+use crate::iso::{Iso, IsoDateInner};
+use crate::julian::Julian;
+use crate::{
+    types, AsCalendar, Calendar, Date, DateDuration, DateDurationUnit, DateTime, DateTimeError,
+    Gregorian, RataDie,
+};
+use core::cmp::Ordering;
+use tinystr::tinystr;
+
+/// The fixed date of October 15, 1582, the first day of the Gregorian calendar
+const GREGORIAN_REFORM: i32 = 577_736;
+
+/// The hybrid Julian/Gregorian calendar
+///
+/// This calendar uses the [`Julian`] calendar before a cutover date, and the [`Gregorian`]
+/// calendar from it on. By default, the cutover is October 15, 1582, when the Gregorian
+/// calendar was first adopted, so that October 4, 1582 is followed by October 15, 1582. This
+/// matches `java.util.GregorianCalendar`, and is how historical sources record dates in
+/// countries that adopted the Gregorian calendar at that time.
+///
+/// The days skipped at the cutover do not exist in this calendar: they are rejected or moved to
+/// the cutover date, according to the [`Overflow`](types::Overflow) option, and the months and
+/// years containing the cutover are shorter.
+///
+/// This type can be used with [`Date`] or [`DateTime`] to represent dates in this calendar.
+/// Since the cutover is configurable and there is no BCP-47 identifier for this calendar, it
+/// can only be used directly, and not through [`AnyCalendar`](crate::any_calendar::AnyCalendar).
+///
+/// # Era codes
+///
+/// This calendar supports two era codes: `"bce"`, and `"ce"`, corresponding to the BCE and CE
+/// eras, as in the [`Gregorian`] calendar.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct JulianGregorian {
+    /// The fixed date of the first day of the Gregorian calendar
+    cutover: i32,
+}
+
+/// The inner date type used for representing [`Date`]s of [`JulianGregorian`]. See [`Date`] and
+/// [`JulianGregorian`] for more details.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct JulianGregorianDateInner {
+    pub(crate) year: i32,
+    pub(crate) month: u8,
+    pub(crate) day: u8,
+    /// Whether the fields are in the Gregorian calendar rather than the Julian calendar
+    pub(crate) gregorian: bool,
+}
+
+impl Default for JulianGregorian {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Calendar for JulianGregorian {
+    type DateInner = JulianGregorianDateInner;
+    fn date_from_codes(
+        &self,
+        era: types::Era,
+        year: i32,
+        month_code: types::MonthCode,
+        day: u8,
+        overflow: types::Overflow,
+    ) -> Result<Self::DateInner, DateTimeError> {
+        let year = if era.0 == tinystr!(16, "ce") {
+            if year <= 0 {
+                return Err(DateTimeError::OutOfRange);
+            }
+            year
+        } else if era.0 == tinystr!(16, "bce") {
+            if year <= 0 {
+                return Err(DateTimeError::OutOfRange);
+            }
+            1 - year
+        } else {
+            return Err(DateTimeError::UnknownEra(era.0, self.debug_name()));
+        };
+
+        let month = ordinal_solar_month_from_code(month_code)
+            .filter(|month| *month <= 12)
+            .ok_or(DateTimeError::UnknownMonthCode(
+                month_code.0,
+                self.debug_name(),
+            ))?;
+        let day = constrain_day(day, self.last_day_of_month(year, month), overflow)?;
+        match self.date_from_fields(year, month, day) {
+            Ok(date) => Ok(date),
+            Err(cutover) if overflow == types::Overflow::Constrain => Ok(cutover),
+            Err(_) => Err(DateTimeError::OutOfRange),
+        }
+    }
+
+    fn date_from_iso(&self, iso: Date<Iso>) -> JulianGregorianDateInner {
+        self.date_from_fixed(Iso::fixed_from_iso(*iso.inner()))
+    }
+
+    fn date_to_iso(&self, date: &Self::DateInner) -> Date<Iso> {
+        Iso::iso_from_fixed(Self::fixed_from_date(date))
+    }
+
+    fn months_in_year(&self, _date: &Self::DateInner) -> u8 {
+        12
+    }
+
+    fn days_in_year(&self, date: &Self::DateInner) -> u32 {
+        self.days_in_year_direct(date.year)
+    }
+
+    fn days_in_month(&self, date: &Self::DateInner) -> u8 {
+        let (start, end) = self.month_bounds(date.year, date.month);
+        (end - start) as u8 // a month has fewer than u8::MAX days
+    }
+
+    fn offset_date(
+        &self,
+        date: &mut Self::DateInner,
+        offset: DateDuration<Self>,
+        overflow: types::Overflow,
+    ) -> Result<(), DateTimeError> {
+        let (intermediate, constrained) =
+            self.added_years_months(date, offset.years, offset.months);
+        if constrained && overflow == types::Overflow::Reject {
+            return Err(DateTimeError::OutOfRange);
+        }
+        *date = self
+            .date_from_fixed(Self::fixed_from_date(&intermediate) + offset.weeks * 7 + offset.days);
+        Ok(())
+    }
+
+    fn until(
+        &self,
+        date1: &Self::DateInner,
+        date2: &Self::DateInner,
+        _calendar2: &Self,
+        largest_unit: DateDurationUnit,
+        smallest_unit: DateDurationUnit,
+    ) -> DateDuration<Self> {
+        // Days are counted between fixed dates, so that the days skipped at the cutover are not
+        // counted
+        calendar_arithmetic::until(
+            &CalendarDate {
+                calendar: self,
+                date: *date1,
+            },
+            &CalendarDate {
+                calendar: self,
+                date: *date2,
+            },
+            largest_unit,
+            smallest_unit,
+        )
+    }
+
+    /// The calendar-specific year represented by `date`
+    /// This calendar has the same era scheme as Gregorian
+    fn year(&self, date: &Self::DateInner) -> types::FormattableYear {
+        crate::gregorian::year_as_gregorian(date.year)
+    }
+
+    /// The calendar-specific month represented by `date`
+    fn month(&self, date: &Self::DateInner) -> types::FormattableMonth {
+        ArithmeticDate::<Iso>::new(date.year, date.month, date.day).solar_month()
+    }
+
+    /// The calendar-specific day-of-month represented by `date`
+    fn day_of_month(&self, date: &Self::DateInner) -> types::DayOfMonth {
+        types::DayOfMonth(date.day.into())
+    }
+
+    fn day_of_year_info(&self, date: &Self::DateInner) -> types::DayOfYearInfo {
+        let prev_year = date.year - 1;
+        let next_year = date.year + 1;
+        types::DayOfYearInfo {
+            day_of_year: (Self::fixed_from_date(date) - self.year_start(date.year) + 1) as u32,
+            days_in_year: self.days_in_year_direct(date.year),
+            prev_year: crate::gregorian::year_as_gregorian(prev_year),
+            days_in_prev_year: self.days_in_year_direct(prev_year),
+            next_year: crate::gregorian::year_as_gregorian(next_year),
+        }
+    }
+
+    fn debug_name(&self) -> &'static str {
+        "Julian/Gregorian"
+    }
+
+    /// This calendar has no BCP-47 identifier, and is not supported by [`AnyCalendar`]
+    ///
+    /// [`AnyCalendar`]: crate::any_calendar::AnyCalendar
+    fn any_calendar_kind(&self) -> Option<AnyCalendarKind> {
+        None
+    }
+}
+
+impl JulianGregorian {
+    /// Construct a new hybrid Julian/Gregorian calendar, with the cutover on October 15, 1582
+    pub const fn new() -> Self {
+        Self {
+            cutover: GREGORIAN_REFORM,
+        }
+    }
+
+    /// Construct a new hybrid Julian/Gregorian calendar, with the Gregorian calendar starting on
+    /// `cutover`
+    ///
+    /// For example, Great Britain and its colonies adopted the Gregorian calendar on
+    /// September 14, 1752, the day after September 2, 1752.
+    ///
+    /// ```rust
+    /// use icu::calendar::julian_gregorian::JulianGregorian;
+    /// use icu::calendar::Date;
+    ///
+    /// let british = JulianGregorian::new_with_cutover(
+    ///     Date::new_gregorian_date(1752, 9, 14).unwrap(),
+    /// );
+    ///
+    /// let date = Date::new_julian_gregorian_date(1752, 9, 2, british).unwrap();
+    /// assert_eq!(date.to_iso(), Date::new_iso_date(1752, 9, 13).unwrap());
+    /// assert_eq!(date.days_in_month(), 19);
+    ///
+    /// // September 3 to 13, 1752 were skipped
+    /// assert!(Date::new_julian_gregorian_date(1752, 9, 3, british).is_err());
+    /// ```
+    pub fn new_with_cutover(cutover: Date<Gregorian>) -> Self {
+        Self {
+            cutover: cutover.to_fixed(),
+        }
+    }
+
+    /// The first day of the Gregorian calendar
+    pub fn cutover(&self) -> Date<Gregorian> {
+        Date::from_rata_die(RataDie::new(self.cutover), Gregorian)
+    }
+
+    fn date_from_fixed(&self, fixed: i32) -> JulianGregorianDateInner {
+        if fixed >= self.cutover {
+            let date = Iso::iso_from_fixed(fixed).inner().0;
+            JulianGregorianDateInner {
+                year: date.year,
+                month: date.month,
+                day: date.day,
+                gregorian: true,
+            }
+        } else {
+            let date = Julian::julian_from_fixed(fixed).0;
+            JulianGregorianDateInner {
+                year: date.year,
+                month: date.month,
+                day: date.day,
+                gregorian: false,
+            }
+        }
+    }
+
+    fn fixed_from_date(date: &JulianGregorianDateInner) -> i32 {
+        if date.gregorian {
+            Iso::fixed_from_iso(IsoDateInner(ArithmeticDate::new(
+                date.year, date.month, date.day,
+            )))
+        } else {
+            Julian::fixed_from_julian_integers(date.year, date.month, date.day)
+        }
+    }
+
+    /// The date with the given fields, which must be within the month, or the cutover date
+    /// as an error if they fall in the days skipped at the cutover
+    fn date_from_fields(
+        &self,
+        year: i32,
+        month: u8,
+        day: u8,
+    ) -> Result<JulianGregorianDateInner, JulianGregorianDateInner> {
+        let date = JulianGregorianDateInner {
+            year,
+            month,
+            day,
+            gregorian: true,
+        };
+        if day <= Iso::month_days(year, month) && Self::fixed_from_date(&date) >= self.cutover {
+            return Ok(date);
+        }
+        let date = JulianGregorianDateInner {
+            gregorian: false,
+            ..date
+        };
+        if day <= Julian::month_days(year, month) && Self::fixed_from_date(&date) < self.cutover {
+            return Ok(date);
+        }
+        Err(self.date_from_fixed(self.cutover))
+    }
+
+    /// The fixed dates of the first day of the given month, and of the month after it
+    fn month_bounds(&self, year: i32, month: u8) -> (i32, i32) {
+        let start = |year, month| {
+            Self::fixed_from_date(&self.date_from_fields(year, month, 1).unwrap_or_else(|e| e))
+        };
+        if month == 12 {
+            (start(year, 12), start(year + 1, 1))
+        } else {
+            (start(year, month), start(year, month + 1))
+        }
+    }
+
+    /// The day number of the last day of the given month, which is not the number of days in the
+    /// month if days were skipped at the cutover
+    fn last_day_of_month(&self, year: i32, month: u8) -> u8 {
+        self.date_from_fixed(self.month_bounds(year, month).1 - 1)
+            .day
+    }
+
+    fn year_start(&self, year: i32) -> i32 {
+        self.month_bounds(year, 1).0
+    }
+
+    fn days_in_year_direct(&self, year: i32) -> u32 {
+        (self.year_start(year + 1) - self.year_start(year)) as u32
+    }
+
+    /// Adds `years` and `months` to `date`, constraining the day to the resulting month, and
+    /// moving it to the cutover date if it was skipped.
+    ///
+    /// Also returns whether the day had to be constrained.
+    fn added_years_months(
+        &self,
+        date: &JulianGregorianDateInner,
+        years: i32,
+        months: i32,
+    ) -> (JulianGregorianDateInner, bool) {
+        let month = date.month as i32 - 1 + months;
+        let year = date.year + years + month.div_euclid(12);
+        let month = month.rem_euclid(12) as u8 + 1;
+        let last_day = self.last_day_of_month(year, month);
+        match self.date_from_fields(year, month, date.day.min(last_day)) {
+            Ok(result) => (result, date.day > last_day),
+            Err(cutover) => (cutover, true),
+        }
+    }
+}
+
+/// A date of a [`JulianGregorian`] calendar, which needs the calendar for its arithmetic
+struct CalendarDate<'a> {
+    calendar: &'a JulianGregorian,
+    date: JulianGregorianDateInner,
+}
+
+impl UntilDate for CalendarDate<'_> {
+    fn compare(&self, other: &Self) -> Ordering {
+        JulianGregorian::fixed_from_date(&self.date)
+            .cmp(&JulianGregorian::fixed_from_date(&other.date))
+    }
+
+    fn year(&self) -> i32 {
+        self.date.year
+    }
+
+    fn months_until(&self, other: &Self) -> i32 {
+        12 * (other.date.year - self.date.year) + other.date.month as i32 - self.date.month as i32
+    }
+
+    fn added_years_months(&self, years: i32, months: i32) -> Self {
+        CalendarDate {
+            calendar: self.calendar,
+            date: self
+                .calendar
+                .added_years_months(&self.date, years, months)
+                .0,
+        }
+    }
+
+    fn days_until(&self, other: &Self) -> i32 {
+        JulianGregorian::fixed_from_date(&other.date) - JulianGregorian::fixed_from_date(&self.date)
+    }
+}
+
+impl Date<JulianGregorian> {
+    /// Construct new hybrid Julian/Gregorian Date.
+    ///
+    /// Zero and negative years are in BCE, with year 0 = 1 BCE
+    ///
+    /// ```rust
+    /// use icu::calendar::julian_gregorian::JulianGregorian;
+    /// use icu::calendar::Date;
+    ///
+    /// let date = Date::new_julian_gregorian_date(1582, 10, 4, JulianGregorian::new())
+    ///     .expect("Failed to initialize Julian/Gregorian Date instance.");
+    ///
+    /// assert_eq!(date.year().number, 1582);
+    /// assert_eq!(date.month().ordinal, 10);
+    /// assert_eq!(date.day_of_month().0, 4);
+    /// assert_eq!(date.to_iso(), Date::new_iso_date(1582, 10, 14).unwrap());
+    ///
+    /// // October 5 to 14, 1582 were skipped
+    /// assert!(Date::new_julian_gregorian_date(1582, 10, 10, JulianGregorian::new()).is_err());
+    /// ```
+    pub fn new_julian_gregorian_date<A: AsCalendar<Calendar = JulianGregorian>>(
+        year: i32,
+        month: u8,
+        day: u8,
+        calendar: A,
+    ) -> Result<Date<A>, DateTimeError> {
+        if !(1..=12).contains(&month)
+            || day == 0
+            || day > calendar.as_calendar().last_day_of_month(year, month)
+        {
+            return Err(DateTimeError::OutOfRange);
+        }
+        let inner = calendar
+            .as_calendar()
+            .date_from_fields(year, month, day)
+            .map_err(|_| DateTimeError::OutOfRange)?;
+        Ok(Date::from_raw(inner, calendar))
+    }
+}
+
+impl DateTime<JulianGregorian> {
+    /// Construct a new hybrid Julian/Gregorian datetime from integers.
+    ///
+    /// Zero and negative years are in BCE, with year 0 = 1 BCE
+    ///
+    /// ```rust
+    /// use icu::calendar::julian_gregorian::JulianGregorian;
+    /// use icu::calendar::DateTime;
+    ///
+    /// let datetime = DateTime::new_julian_gregorian_datetime(
+    ///     1582,
+    ///     10,
+    ///     15,
+    ///     13,
+    ///     1,
+    ///     0,
+    ///     JulianGregorian::new(),
+    /// )
+    /// .expect("Failed to initialize Julian/Gregorian DateTime instance.");
+    ///
+    /// assert_eq!(datetime.date.year().number, 1582);
+    /// assert_eq!(datetime.date.month().ordinal, 10);
+    /// assert_eq!(datetime.date.day_of_month().0, 15);
+    /// assert_eq!(datetime.time.hour.number(), 13);
+    /// assert_eq!(datetime.time.minute.number(), 1);
+    /// assert_eq!(datetime.time.second.number(), 0);
+    /// ```
+    #[allow(clippy::too_many_arguments)] // it's more convenient to have this many arguments
+                                         // if people wish to construct this by parts they can use
+                                         // Date::new_julian_gregorian_date() + DateTime::new(date, time)
+    pub fn new_julian_gregorian_datetime<A: AsCalendar<Calendar = JulianGregorian>>(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+        calendar: A,
+    ) -> Result<DateTime<A>, DateTimeError> {
+        Ok(DateTime {
+            date: Date::new_julian_gregorian_date(year, month, day, calendar)?,
+            time: types::Time::try_new(hour, minute, second, 0)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::types::Overflow;
+    use crate::RoundingMode;
+
+    fn date(year: i32, month: u8, day: u8) -> Date<JulianGregorian> {
+        Date::new_julian_gregorian_date(year, month, day, JulianGregorian::new()).unwrap()
+    }
+
+    #[test]
+    fn test_cutover() {
+        assert_eq!(
+            JulianGregorian::new().cutover(),
+            Date::new_gregorian_date(1582, 10, 15).unwrap()
+        );
+
+        // The day before the cutover is the last Julian day
+        let julian = Date::new_julian_date(1582, 10, 4).unwrap();
+        assert_eq!(date(1582, 10, 4).to_iso(), julian.to_iso());
+        assert_eq!(date(1582, 10, 4).day_of_week(), types::IsoWeekday::Thursday);
+        assert_eq!(date(1582, 10, 15).day_of_week(), types::IsoWeekday::Friday);
+        assert_eq!(
+            date(1582, 10, 15).to_fixed() - date(1582, 10, 4).to_fixed(),
+            1
+        );
+
+        // Dates before and after the cutover use the Julian and Gregorian calendars
+        let iso = Date::new_iso_date(1500, 3, 1).unwrap();
+        assert_eq!(
+            Date::new_from_iso(iso.clone(), JulianGregorian::new()).to_iso(),
+            Date::new_from_iso(iso, Julian).to_iso()
+        );
+        assert_eq!(
+            *Date::new_from_iso(
+                Date::new_iso_date(1500, 3, 1).unwrap(),
+                JulianGregorian::new()
+            )
+            .inner(),
+            *date(1500, 2, 20).inner()
+        );
+        assert_eq!(date(1500, 2, 29).days_in_month(), 29);
+        assert_eq!(date(1700, 2, 28).days_in_month(), 28);
+        assert!(Date::new_julian_gregorian_date(1700, 2, 29, JulianGregorian::new()).is_err());
+
+        // The skipped days are missing from the month and the year
+        assert_eq!(date(1582, 10, 1).days_in_month(), 21);
+        assert_eq!(date(1582, 10, 1).days_in_year(), 355);
+        assert_eq!(date(1582, 12, 31).day_of_year_info().day_of_year, 355);
+        assert_eq!(date(1583, 1, 1).day_of_year_info().days_in_prev_year, 355);
+        for day in 5..=14 {
+            assert_eq!(
+                Date::new_julian_gregorian_date(1582, 10, day, JulianGregorian::new()),
+                Err(DateTimeError::OutOfRange)
+            );
+        }
+
+        // From codes, the skipped days are constrained to the cutover
+        let code = types::MonthCode(tinystr!(4, "M10"));
+        let era = types::Era(tinystr!(16, "ce"));
+        let calendar = JulianGregorian::new();
+        assert_eq!(
+            calendar.date_from_codes(era, 1582, code, 10, Overflow::Constrain),
+            Ok(*date(1582, 10, 15).inner())
+        );
+        assert_eq!(
+            calendar.date_from_codes(era, 1582, code, 10, Overflow::Reject),
+            Err(DateTimeError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        for calendar in [
+            JulianGregorian::new(),
+            JulianGregorian::new_with_cutover(Date::new_gregorian_date(1752, 9, 14).unwrap()),
+            // Russia, where the cutover skipped the start of February
+            JulianGregorian::new_with_cutover(Date::new_gregorian_date(1918, 2, 14).unwrap()),
+        ] {
+            let cutover = calendar.cutover().to_fixed();
+            let mut previous = Date::from_rata_die(RataDie::new(cutover - 800), calendar);
+            // The number of days seen in the current month and year
+            let mut days_in_month = previous.day_of_month().0;
+            let mut days_in_year = previous.day_of_year_info().day_of_year;
+            for fixed in cutover - 799..cutover + 800 {
+                let date = Date::from_rata_die(RataDie::new(fixed), calendar);
+                assert_eq!(date.to_fixed(), fixed);
+                let (year, month, day) = (
+                    date.year().number,
+                    date.month().ordinal,
+                    date.day_of_month().0,
+                );
+                assert_eq!(
+                    Date::new_julian_gregorian_date(year, month as u8, day as u8, calendar),
+                    Ok(date.clone()),
+                    "{fixed}"
+                );
+
+                if month == previous.month().ordinal {
+                    // Days follow each other, except at the cutover
+                    if fixed != cutover {
+                        assert_eq!(day, previous.day_of_month().0 + 1, "{fixed}");
+                    }
+                    days_in_month += 1;
+                } else {
+                    assert_eq!(previous.days_in_month() as u32, days_in_month, "{fixed}");
+                    days_in_month = 1;
+                }
+                if date.year() == previous.year() {
+                    days_in_year += 1;
+                } else {
+                    assert_eq!(previous.days_in_year(), days_in_year, "{fixed}");
+                    assert_eq!(date.day_of_year_info().days_in_prev_year, days_in_year);
+                    days_in_year = 1;
+                }
+                assert_eq!(date.day_of_year_info().day_of_year, days_in_year, "{fixed}");
+                previous = date;
+            }
+        }
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let constrain = |date: Date<JulianGregorian>, years, months, weeks, days| {
+            date.added(
+                DateDuration::new(years, months, weeks, days),
+                Overflow::Constrain,
+            )
+            .unwrap()
+        };
+
+        // Adding days skips the missing days
+        assert_eq!(constrain(date(1582, 10, 4), 0, 0, 0, 1), date(1582, 10, 15));
+        assert_eq!(
+            constrain(date(1582, 10, 15), 0, 0, 0, -1),
+            date(1582, 10, 4)
+        );
+        assert_eq!(constrain(date(1582, 10, 1), 0, 0, 1, 0), date(1582, 10, 18));
+        assert_eq!(constrain(date(1582, 1, 1), 0, 0, 0, 355), date(1583, 1, 1));
+
+        // Adding months to a missing day constrains it to the cutover
+        assert_eq!(constrain(date(1582, 9, 10), 0, 1, 0, 0), date(1582, 10, 15));
+        assert_eq!(constrain(date(1582, 9, 4), 0, 1, 0, 0), date(1582, 10, 4));
+        assert_eq!(
+            constrain(date(1581, 10, 10), 1, 0, 0, 0),
+            date(1582, 10, 15)
+        );
+        assert_eq!(
+            date(1582, 9, 10).added(DateDuration::new(0, 1, 0, 0), Overflow::Reject),
+            Err(DateTimeError::OutOfRange)
+        );
+        // Leap days follow the calendar of the resulting year
+        assert_eq!(
+            constrain(date(1500, 2, 29), 200, 0, 0, 0),
+            date(1700, 2, 28)
+        );
+        assert_eq!(
+            constrain(date(1700, 2, 28), -200, 0, 0, 0),
+            date(1500, 2, 28)
+        );
+
+        // `until` counts the actual days
+        let until = |a: &Date<JulianGregorian>, b: &Date<JulianGregorian>, largest| {
+            a.until(b, largest, DateDurationUnit::Days, RoundingMode::Trunc)
+        };
+        assert_eq!(
+            until(
+                &date(1582, 10, 4),
+                &date(1582, 10, 15),
+                DateDurationUnit::Days
+            ),
+            DateDuration::new(0, 0, 0, 1)
+        );
+        assert_eq!(
+            until(
+                &date(1582, 10, 15),
+                &date(1582, 10, 4),
+                DateDurationUnit::Days
+            ),
+            DateDuration::new(0, 0, 0, -1)
+        );
+        assert_eq!(
+            until(&date(1582, 1, 1), &date(1583, 1, 1), DateDurationUnit::Days),
+            DateDuration::new(0, 0, 0, 355)
+        );
+        assert_eq!(
+            until(
+                &date(1582, 9, 20),
+                &date(1582, 10, 20),
+                DateDurationUnit::Months
+            ),
+            DateDuration::new(0, 1, 0, 0)
+        );
+        assert_eq!(
+            until(
+                &date(1582, 9, 30),
+                &date(1582, 10, 15),
+                DateDurationUnit::Months
+            ),
+            DateDuration::new(0, 0, 0, 5)
+        );
+
+        // `until` and `added` roundtrip across the cutover
+        let dates = [
+            date(1581, 10, 10),
+            date(1582, 2, 28),
+            date(1582, 9, 10),
+            date(1582, 10, 4),
+            date(1582, 10, 15),
+            date(1582, 10, 31),
+            date(1583, 3, 31),
+        ];
+        for a in &dates {
+            for b in &dates {
+                for largest in [
+                    DateDurationUnit::Years,
+                    DateDurationUnit::Months,
+                    DateDurationUnit::Weeks,
+                    DateDurationUnit::Days,
+                ] {
+                    let duration = until(a, b, largest);
+                    assert_eq!(
+                        a.clone().added(duration, Overflow::Constrain).unwrap(),
+                        *b,
+                        "{a:?} {b:?} {largest:?}"
+                    );
+                }
+            }
+        }
+    }
+}
@@ -116,18 +116,28 @@ pub mod any_calendar;
 pub mod buddhist;
 mod calendar;
 mod calendar_arithmetic;
+pub mod chinese;
+mod chinese_based;
 pub mod coptic;
+pub mod dangi;
 mod date;
 mod datetime;
 mod duration;
 mod error;
 pub mod ethiopian;
 pub mod gregorian;
+pub mod hebrew;
 pub mod indian;
+pub mod islamic;
 pub mod iso;
 pub mod japanese;
 pub mod julian;
+pub mod julian_gregorian;
+mod partial_date;
+pub mod persian;
 pub mod provider;
+mod rata_die;
+pub mod roc;
 pub mod types;
 pub mod week_of;
 
@@ -135,8 +145,9 @@ pub use any_calendar::{AnyCalendar, AnyCalendarKind};
 pub use calendar::Calendar;
 pub use date::{AsCalendar, Date, Ref};
 pub use datetime::DateTime;
-#[doc(hidden)]
-pub use duration::{DateDuration, DateDurationUnit};
+pub use duration::{DateDuration, DateDurationUnit, RoundingMode, TimeDuration};
 pub use error::DateTimeError;
 pub use gregorian::Gregorian;
 pub use iso::Iso;
+pub use partial_date::{MonthDay, YearMonth};
+pub use rata_die::RataDie;
@@ -0,0 +1,394 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Partial dates: a year and month without a day, and a month and day without a year.
+
+use crate::{types, AsCalendar, Calendar, Date, DateDuration, DateTimeError, Iso};
+use core::fmt;
+
+/// The number of years searched backwards for a reference year of a [`MonthDay`].
+const MAX_REFERENCE_YEARS: u8 = 100;
+
+/// A year and month in a given calendar, without a day, similar to Temporal's `PlainYearMonth`.
+///
+/// This is useful for things like credit card expiry dates. Internally this is represented as
+/// the first day of the month, which is also what [`YearMonth::to_iso()`] returns.
+///
+/// ```rust
+/// use icu::calendar::types::{Era, MonthCode, Overflow};
+/// use icu::calendar::{hebrew::Hebrew, Gregorian, YearMonth};
+/// use tinystr::tinystr;
+///
+/// let expiry = YearMonth::try_new_from_codes(
+///     Era(tinystr!(16, "ce")),
+///     2023,
+///     MonthCode(tinystr!(4, "M03")),
+///     Gregorian,
+/// )
+/// .unwrap();
+/// assert_eq!(expiry.days_in_month(), 31);
+///
+/// let last_day = expiry.to_date(31, Overflow::Reject).unwrap();
+/// assert_eq!(last_day.day_of_month().0, 31);
+///
+/// // Adar I only exists in Hebrew leap years
+/// let adar_i = MonthCode(tinystr!(4, "M05L"));
+/// assert!(YearMonth::try_new_from_codes(Era(tinystr!(16, "am")), 5784, adar_i, Hebrew).is_ok());
+/// assert!(YearMonth::try_new_from_codes(Era(tinystr!(16, "am")), 5783, adar_i, Hebrew).is_err());
+/// ```
+pub struct YearMonth<A: AsCalendar> {
+    first_day: Date<A>,
+}
+
+impl<A: AsCalendar> YearMonth<A> {
+    /// Construct a year and month from era/month codes and a year in the given calendar
+    ///
+    /// Leap months that don't exist in the year are rejected.
+    pub fn try_new_from_codes(
+        era: types::Era,
+        year: i32,
+        month_code: types::MonthCode,
+        calendar: A,
+    ) -> Result<Self, DateTimeError> {
+        Date::new_from_codes(era, year, month_code, 1, calendar).map(|first_day| Self { first_day })
+    }
+
+    /// Construct the year and month containing `date`
+    ///
+    /// An error is returned if the calendar can't construct a date from the era and year codes
+    /// of `date`.
+    pub fn try_from_date(date: Date<A>) -> Result<Self, DateTimeError> {
+        let year = date.year();
+        let month = date.month();
+        // The first day of the month may have been skipped by a calendar reform, in which case
+        // it is constrained to the first day that exists
+        let inner = date.calendar().date_from_codes(
+            year.era,
+            year.number,
+            month.code,
+            1,
+            types::Overflow::Constrain,
+        )?;
+        Ok(Self {
+            first_day: Date::from_raw(inner, date.calendar),
+        })
+    }
+
+    /// The calendar-specific year represented by `self`
+    #[inline]
+    pub fn year(&self) -> types::FormattableYear {
+        self.first_day.year()
+    }
+
+    /// The calendar-specific month represented by `self`
+    #[inline]
+    pub fn month(&self) -> types::FormattableMonth {
+        self.first_day.month()
+    }
+
+    /// The number of months in the year of `self`
+    #[inline]
+    pub fn months_in_year(&self) -> u8 {
+        self.first_day.months_in_year()
+    }
+
+    /// The number of days in the month represented by `self`
+    #[inline]
+    pub fn days_in_month(&self) -> u8 {
+        self.first_day.days_in_month()
+    }
+
+    /// Construct a date for the given day of this month, handling days past the end of the
+    /// month according to `overflow`
+    pub fn to_date(self, day: u8, overflow: types::Overflow) -> Result<Date<A>, DateTimeError> {
+        let year = self.year();
+        Date::new_from_codes_with_overflow(
+            year.era,
+            year.number,
+            self.month().code,
+            day,
+            overflow,
+            self.first_day.calendar,
+        )
+    }
+
+    /// The ISO date of the first day of this month
+    #[inline]
+    pub fn to_iso(&self) -> Date<Iso> {
+        self.first_day.to_iso()
+    }
+
+    /// Get a reference to the contained calendar
+    #[inline]
+    pub fn calendar(&self) -> &A::Calendar {
+        self.first_day.calendar()
+    }
+}
+
+/// A month and day in a given calendar, without a year, similar to Temporal's `PlainMonthDay`.
+///
+/// This is useful for things like birthdays and anniversaries. Whether the month and day exist
+/// is checked against the calendar, and a reference year is chosen in which they do: the latest
+/// year whose occurrence is on or before ISO 1972-12-31, as in Temporal. The date in that
+/// reference year is what [`MonthDay::to_iso()`] returns.
+///
+/// ```rust
+/// use icu::calendar::types::{Era, MonthCode, Overflow};
+/// use icu::calendar::{Gregorian, MonthDay};
+/// use tinystr::tinystr;
+///
+/// let february = MonthCode(tinystr!(4, "M02"));
+///
+/// let leap_day = MonthDay::try_new_from_codes(february, 29, Gregorian).unwrap();
+/// assert!(MonthDay::try_new_from_codes(february, 30, Gregorian).is_err());
+///
+/// // Leap day birthdays fall on February 28 in common years
+/// let birthday = leap_day
+///     .to_date(Era(tinystr!(16, "ce")), 2023, Overflow::Constrain)
+///     .unwrap();
+/// assert_eq!(birthday.day_of_month().0, 28);
+/// ```
+pub struct MonthDay<A: AsCalendar> {
+    reference: Date<A>,
+}
+
+impl<A: AsCalendar> MonthDay<A> {
+    /// Construct a month and day from a month code and day in the given calendar
+    ///
+    /// An error is returned if the month and day do not occur in any of the reference years
+    /// searched.
+    pub fn try_new_from_codes(
+        month_code: types::MonthCode,
+        day: u8,
+        calendar: A,
+    ) -> Result<Self, DateTimeError> {
+        let inner = reference_date(calendar.as_calendar(), month_code, day)?;
+        Ok(Self {
+            reference: Date::from_raw(inner, calendar),
+        })
+    }
+
+    /// Construct the month and day of `date`
+    ///
+    /// As with [`MonthDay::try_new_from_codes()`], an error is returned if the month and day do
+    /// not occur in any of the reference years searched, which is possible for rare leap months.
+    pub fn try_from_date(date: Date<A>) -> Result<Self, DateTimeError> {
+        let month_code = date.month().code;
+        let day = date.day_of_month().0 as u8;
+        let inner = reference_date(date.calendar(), month_code, day)?;
+        Ok(Self {
+            reference: Date::from_raw(inner, date.calendar),
+        })
+    }
+
+    /// The month code represented by `self`
+    #[inline]
+    pub fn month_code(&self) -> types::MonthCode {
+        self.reference.month().code
+    }
+
+    /// The calendar-specific month represented by `self`
+    ///
+    /// The ordinal month is the one in the reference year, and may differ in other years
+    /// in calendars with leap months.
+    #[inline]
+    pub fn month(&self) -> types::FormattableMonth {
+        self.reference.month()
+    }
+
+    /// The calendar-specific day-of-month represented by `self`
+    #[inline]
+    pub fn day_of_month(&self) -> types::DayOfMonth {
+        self.reference.day_of_month()
+    }
+
+    /// Construct a date for this month and day in the given year, handling days past the end
+    /// of the month and leap months that don't exist in the year according to `overflow`
+    pub fn to_date(
+        self,
+        era: types::Era,
+        year: i32,
+        overflow: types::Overflow,
+    ) -> Result<Date<A>, DateTimeError> {
+        let day = self.day_of_month().0 as u8;
+        Date::new_from_codes_with_overflow(
+            era,
+            year,
+            self.month_code(),
+            day,
+            overflow,
+            self.reference.calendar,
+        )
+    }
+
+    /// The ISO date of this month and day in the reference year
+    #[inline]
+    pub fn to_iso(&self) -> Date<Iso> {
+        self.reference.to_iso()
+    }
+
+    /// Get a reference to the contained calendar
+    #[inline]
+    pub fn calendar(&self) -> &A::Calendar {
+        self.reference.calendar()
+    }
+}
+
+/// Find the latest occurrence of `month_code` and `day` on or before ISO 1972-12-31.
+fn reference_date<C: Calendar>(
+    calendar: &C,
+    month_code: types::MonthCode,
+    day: u8,
+) -> Result<C::DateInner, DateTimeError> {
+    let end = Date::new_iso_date(1972, 12, 31)?;
+    let end_fixed = end.to_fixed();
+    let mut year_date = calendar.date_from_iso(end);
+    let mut result = Err(DateTimeError::OutOfRange);
+    for _ in 0..MAX_REFERENCE_YEARS {
+        let year = calendar.year(&year_date);
+        result = calendar.date_from_codes(
+            year.era,
+            year.number,
+            month_code,
+            day,
+            types::Overflow::Reject,
+        );
+        if let Ok(ref date) = result {
+            if calendar.date_to_iso(date).to_fixed() <= end_fixed {
+                break;
+            }
+        }
+        calendar.offset_date(
+            &mut year_date,
+            DateDuration::new(-1, 0, 0, 0),
+            types::Overflow::Constrain,
+        )?;
+    }
+    result
+}
+
+impl<A: AsCalendar + Clone> Clone for YearMonth<A> {
+    fn clone(&self) -> Self {
+        Self {
+            first_day: self.first_day.clone(),
+        }
+    }
+}
+
+impl<A: AsCalendar + Clone> Clone for MonthDay<A> {
+    fn clone(&self) -> Self {
+        Self {
+            reference: self.reference.clone(),
+        }
+    }
+}
+
+impl<C, A, B> PartialEq<YearMonth<B>> for YearMonth<A>
+where
+    C: Calendar,
+    A: AsCalendar<Calendar = C>,
+    B: AsCalendar<Calendar = C>,
+{
+    fn eq(&self, other: &YearMonth<B>) -> bool {
+        self.first_day.eq(&other.first_day)
+    }
+}
+
+impl<A: AsCalendar> Eq for YearMonth<A> {}
+
+impl<C, A, B> PartialEq<MonthDay<B>> for MonthDay<A>
+where
+    C: Calendar,
+    A: AsCalendar<Calendar = C>,
+    B: AsCalendar<Calendar = C>,
+{
+    fn eq(&self, other: &MonthDay<B>) -> bool {
+        self.reference.eq(&other.reference)
+    }
+}
+
+impl<A: AsCalendar> Eq for MonthDay<A> {}
+
+impl<A: AsCalendar> fmt::Debug for YearMonth<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(
+            f,
+            "YearMonth({:?}, for calendar {})",
+            self.first_day.inner,
+            self.calendar().debug_name()
+        )
+    }
+}
+
+impl<A: AsCalendar> fmt::Debug for MonthDay<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(
+            f,
+            "MonthDay({:?}, for calendar {})",
+            self.reference.inner,
+            self.calendar().debug_name()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hebrew::Hebrew;
+    use crate::julian_gregorian::JulianGregorian;
+    use crate::Gregorian;
+    use tinystr::tinystr;
+
+    #[test]
+    fn test_year_month() {
+        let gregorian = types::Era(tinystr!(16, "ce"));
+        let year_month = YearMonth::try_new_from_codes(
+            gregorian,
+            2024,
+            types::MonthCode(tinystr!(4, "M02")),
+            Gregorian,
+        )
+        .unwrap();
+        assert_eq!(year_month.days_in_month(), 29);
+        assert_eq!(year_month.to_iso(), Date::new_iso_date(2024, 2, 1).unwrap());
+
+        let date = Date::new_gregorian_date(2024, 2, 17).unwrap();
+        assert_eq!(YearMonth::try_from_date(date), Ok(year_month));
+
+        assert!(YearMonth::try_new_from_codes(
+            gregorian,
+            2024,
+            types::MonthCode(tinystr!(4, "M13")),
+            Gregorian
+        )
+        .is_err());
+
+        // The first days of February 1918 were skipped when Russia adopted the Gregorian calendar
+        let russian =
+            JulianGregorian::new_with_cutover(Date::new_gregorian_date(1918, 2, 14).unwrap());
+        let date = Date::new_julian_gregorian_date(1918, 2, 20, russian).unwrap();
+        assert_eq!(
+            YearMonth::try_from_date(date).unwrap().to_iso(),
+            Date::new_iso_date(1918, 2, 14).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_month_day_reference_year() {
+        let february = types::MonthCode(tinystr!(4, "M02"));
+        let leap_day = MonthDay::try_new_from_codes(february, 29, Gregorian).unwrap();
+        assert_eq!(leap_day.to_iso(), Date::new_iso_date(1972, 2, 29).unwrap());
+        assert!(MonthDay::try_new_from_codes(february, 30, Gregorian).is_err());
+
+        // Adar I 5733 falls after 1972-12-31, so the reference year is 5730
+        let adar_i = types::MonthCode(tinystr!(4, "M05L"));
+        let month_day = MonthDay::try_new_from_codes(adar_i, 30, Hebrew).unwrap();
+        assert_eq!(month_day.month_code(), adar_i);
+        assert_eq!(month_day.to_iso().year().number, 1970);
+
+        let date = Date::new_hebrew_date(5784, 6, 30).unwrap();
+        assert_eq!(date.month().code, adar_i);
+        assert_eq!(MonthDay::try_from_date(date), Ok(month_day));
+    }
+}
@@ -0,0 +1,349 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! This module contains types and implementations for the Persian calendar.
+//!
+//! ```rust
+//! use icu::calendar::{persian::Persian, Date, DateTime};
+//!
+//! // `Date` type
+//! let date_iso = Date::new_iso_date(1970, 1, 2)
+//!     .expect("Failed to initialize ISO Date instance.");
+//! let date_persian = Date::new_from_iso(date_iso, Persian);
+//!
+//! // `DateTime` type
+//! let datetime_iso = DateTime::new_iso_datetime(1970, 1, 2, 13, 1, 0)
+//!     .expect("Failed to initialize ISO DateTime instance.");
+//! let datetime_persian = DateTime::new_from_iso(datetime_iso, Persian);
+//!
+//! // `Date` checks
+//! assert_eq!(date_persian.year().number, 1348);
+//! assert_eq!(date_persian.month().ordinal, 10);
+//! assert_eq!(date_persian.day_of_month().0, 12);
+//!
+//! // `DateTime` type
+//! assert_eq!(datetime_persian.date.year().number, 1348);
+//! assert_eq!(datetime_persian.date.month().ordinal, 10);
+//! assert_eq!(datetime_persian.date.day_of_month().0, 12);
+//! assert_eq!(datetime_persian.time.hour.number(), 13);
+//! assert_eq!(datetime_persian.time.minute.number(), 1);
+//! assert_eq!(datetime_persian.time.second.number(), 0);
+//! ```
+
+use crate::any_calendar::AnyCalendarKind;
+use crate::calendar_arithmetic::{ArithmeticDate, CalendarArithmetic};
+use crate::iso::Iso;
+use crate::julian::Julian;
+use crate::{types, Calendar, Date, DateDuration, DateDurationUnit, DateTime, DateTimeError};
+use core::marker::PhantomData;
+use tinystr::tinystr;
+
+/// The Persian Calendar
+///
+/// The [Persian calendar] (also known as the Solar Hijri calendar) is a solar calendar used
+/// officially in Iran and Afghanistan, with twelve months. The year begins with the month of
+/// Farvardin at the March equinox. The first six months have 31 days, the next five have 30 days,
+/// and the last month, Esfand, has 29 days, or 30 days in leap years.
+///
+/// The official calendar is astronomical; this type uses the arithmetic 33-year cycle of
+/// leap years, which matches the astronomical calendar for the years 1178 to 1634 A.P.
+///
+/// This type can be used with [`Date`] or [`DateTime`] to represent dates in this calendar.
+///
+/// [Persian calendar]: https://en.wikipedia.org/wiki/Solar_Hijri_calendar
+///
+/// # Era codes
+///
+/// This calendar has a single era: `"ap"`, corresponding to Anno Persico. 1 A.P. began in
+/// March 622 CE. Dates before this era use negative years.
+#[derive(Copy, Clone, Debug, Hash, Default, Eq, PartialEq)]
+#[allow(clippy::exhaustive_structs)] // this type is stable
+pub struct Persian;
+
+/// The inner date type used for representing [`Date`]s of [`Persian`]. See [`Date`] and [`Persian`] for more details.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+pub struct PersianDateInner(ArithmeticDate<Persian>);
+
+/// The fixed date of the Persian epoch, March 19, 622 CE in the Julian calendar.
+///
+/// In the 33-year cycle, 1 Farvardin 1 A.P. falls on the day before the epoch.
+const PERSIAN_EPOCH: i32 = Julian::fixed_from_julian_integers(622, 3, 19);
+
+impl CalendarArithmetic for Persian {
+    fn month_days(year: i32, month: u8) -> u8 {
+        match month {
+            1..=6 => 31,
+            7..=11 => 30,
+            12 if Self::is_leap_year(year) => 30,
+            12 => 29,
+            _ => 0,
+        }
+    }
+
+    fn months_for_every_year(_: i32) -> u8 {
+        12
+    }
+
+    fn is_leap_year(year: i32) -> bool {
+        (25 * year as i64 + 11).rem_euclid(33) < 8
+    }
+}
+
+impl Calendar for Persian {
+    type DateInner = PersianDateInner;
+    fn date_from_codes(
+        &self,
+        era: types::Era,
+        year: i32,
+        month_code: types::MonthCode,
+        day: u8,
+        overflow: types::Overflow,
+    ) -> Result<Self::DateInner, DateTimeError> {
+        if era.0 != tinystr!(16, "ap") {
+            return Err(DateTimeError::UnknownEra(era.0, self.debug_name()));
+        }
+
+        ArithmeticDate::new_from_solar(self, year, month_code, day, overflow).map(PersianDateInner)
+    }
+
+    fn date_from_iso(&self, iso: Date<Iso>) -> PersianDateInner {
+        let fixed_iso = Iso::fixed_from_iso(*iso.inner());
+        Self::persian_from_fixed(fixed_iso)
+    }
+
+    fn date_to_iso(&self, date: &Self::DateInner) -> Date<Iso> {
+        let fixed_persian = Self::fixed_from_persian(date.0);
+        Iso::iso_from_fixed(fixed_persian)
+    }
+
+    fn months_in_year(&self, date: &Self::DateInner) -> u8 {
+        date.0.months_in_year()
+    }
+
+    fn days_in_year(&self, date: &Self::DateInner) -> u32 {
+        date.0.days_in_year()
+    }
+
+    fn days_in_month(&self, date: &Self::DateInner) -> u8 {
+        date.0.days_in_month()
+    }
+
+    fn day_of_week(&self, date: &Self::DateInner) -> types::IsoWeekday {
+        Iso.day_of_week(Persian.date_to_iso(date).inner())
+    }
+
+    fn offset_date(
+        &self,
+        date: &mut Self::DateInner,
+        offset: DateDuration<Self>,
+        overflow: types::Overflow,
+    ) -> Result<(), DateTimeError> {
+        date.0.offset_date(offset, overflow)
+    }
+
+    fn until(
+        &self,
+        date1: &Self::DateInner,
+        date2: &Self::DateInner,
+        _calendar2: &Self,
+        largest_unit: DateDurationUnit,
+        smallest_unit: DateDurationUnit,
+    ) -> DateDuration<Self> {
+        date1.0.until(date2.0, largest_unit, smallest_unit)
+    }
+
+    fn year(&self, date: &Self::DateInner) -> types::FormattableYear {
+        year_as_persian(date.0.year)
+    }
+
+    fn month(&self, date: &Self::DateInner) -> types::FormattableMonth {
+        date.0.solar_month()
+    }
+
+    fn day_of_month(&self, date: &Self::DateInner) -> types::DayOfMonth {
+        date.0.day_of_month()
+    }
+
+    fn day_of_year_info(&self, date: &Self::DateInner) -> types::DayOfYearInfo {
+        let prev_year = date.0.year - 1;
+        let next_year = date.0.year + 1;
+        types::DayOfYearInfo {
+            day_of_year: date.0.day_of_year(),
+            days_in_year: date.0.days_in_year(),
+            prev_year: year_as_persian(prev_year),
+            days_in_prev_year: Persian::days_in_year_direct(prev_year),
+            next_year: year_as_persian(next_year),
+        }
+    }
+
+    fn debug_name(&self) -> &'static str {
+        "Persian"
+    }
+
+    fn any_calendar_kind(&self) -> Option<AnyCalendarKind> {
+        Some(AnyCalendarKind::Persian)
+    }
+}
+
+impl Persian {
+    /// The fixed date of 1 Farvardin of `year`.
+    fn new_year(year: i32) -> i32 {
+        let year = year as i64;
+        (PERSIAN_EPOCH as i64 - 1 + 365 * (year - 1) + (8 * year + 21).div_euclid(33)) as i32
+    }
+
+    fn days_in_year_direct(year: i32) -> u32 {
+        if Self::is_leap_year(year) {
+            366
+        } else {
+            365
+        }
+    }
+
+    fn fixed_from_persian(date: ArithmeticDate<Persian>) -> i32 {
+        Self::new_year(date.year) + date.day_of_year() as i32 - 1
+    }
+
+    fn persian_from_fixed(date: i32) -> PersianDateInner {
+        // The 33-year cycle has 12053 days
+        let days_since_epoch = (date - PERSIAN_EPOCH) as i64 + 1;
+        let year = (1 + (33 * days_since_epoch + 3).div_euclid(12053)) as i32;
+        let day_of_year = (date - Self::new_year(year) + 1) as u32;
+        PersianDateInner(ArithmeticDate::date_from_year_day(year, day_of_year))
+    }
+}
+
+impl Date<Persian> {
+    /// Construct new Persian Date.
+    ///
+    /// ```rust
+    /// use icu::calendar::Date;
+    ///
+    /// let date_persian =
+    ///     Date::new_persian_date(1403, 1, 1).expect("Failed to initialize Persian Date instance.");
+    ///
+    /// assert_eq!(date_persian.year().number, 1403);
+    /// assert_eq!(date_persian.month().ordinal, 1);
+    /// assert_eq!(date_persian.day_of_month().0, 1);
+    /// ```
+    pub fn new_persian_date(year: i32, month: u8, day: u8) -> Result<Date<Persian>, DateTimeError> {
+        let inner = ArithmeticDate {
+            year,
+            month,
+            day,
+            marker: PhantomData,
+        };
+
+        let bound = inner.days_in_month();
+        if day == 0 || day > bound {
+            return Err(DateTimeError::OutOfRange);
+        }
+
+        Ok(Date::from_raw(PersianDateInner(inner), Persian))
+    }
+}
+
+impl DateTime<Persian> {
+    /// Construct a new Persian datetime from integers.
+    ///
+    /// ```rust
+    /// use icu::calendar::DateTime;
+    ///
+    /// let datetime_persian = DateTime::new_persian_datetime(1403, 1, 1, 13, 1, 0)
+    ///     .expect("Failed to initialize Persian DateTime instance.");
+    ///
+    /// assert_eq!(datetime_persian.date.year().number, 1403);
+    /// assert_eq!(datetime_persian.date.month().ordinal, 1);
+    /// assert_eq!(datetime_persian.date.day_of_month().0, 1);
+    /// assert_eq!(datetime_persian.time.hour.number(), 13);
+    /// assert_eq!(datetime_persian.time.minute.number(), 1);
+    /// assert_eq!(datetime_persian.time.second.number(), 0);
+    /// ```
+    pub fn new_persian_datetime(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> Result<DateTime<Persian>, DateTimeError> {
+        Ok(DateTime {
+            date: Date::new_persian_date(year, month, day)?,
+            time: types::Time::try_new(hour, minute, second, 0)?,
+        })
+    }
+}
+
+fn year_as_persian(year: i32) -> types::FormattableYear {
+    types::FormattableYear {
+        era: types::Era(tinystr!(16, "ap")),
+        number: year,
+        cyclic: None,
+        related_iso: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_year() {
+        // (Persian year, ISO date of 1 Farvardin, days in year)
+        let cases = [
+            (1354, (1975, 3, 21), 366),
+            (1375, (1996, 3, 20), 366),
+            (1399, (2020, 3, 20), 366),
+            (1400, (2021, 3, 21), 365),
+            (1403, (2024, 3, 20), 366),
+            (1404, (2025, 3, 21), 365),
+            (1408, (2029, 3, 20), 366),
+        ];
+        for (year, (iso_year, iso_month, iso_day), days) in cases {
+            let iso = Date::new_iso_date(iso_year, iso_month, iso_day).unwrap();
+            let persian = Date::new_persian_date(year, 1, 1).unwrap();
+            assert_eq!(persian.to_iso(), iso, "{year}");
+            assert_eq!(iso.to_calendar(Persian), persian, "{year}");
+            assert_eq!(persian.days_in_year(), days, "{year}");
+            assert_eq!(
+                Persian::new_year(year + 1) - Persian::new_year(year),
+                days as i32
+            );
+        }
+    }
+
+    #[test]
+    fn test_month_lengths() {
+        let iso = |y, m, d| Date::new_iso_date(y, m, d).unwrap();
+        let cases = [
+            (iso(1970, 1, 2), 1348, 10, 12),
+            (iso(2000, 1, 1), 1378, 10, 11),
+            (iso(2024, 9, 21), 1403, 6, 31),
+            (iso(2024, 9, 22), 1403, 7, 1),
+            (iso(2025, 3, 19), 1403, 12, 29),
+            (iso(2025, 3, 20), 1403, 12, 30),
+            (iso(2026, 3, 20), 1404, 12, 29),
+        ];
+        for (iso, year, month, day) in cases {
+            let persian = iso.to_calendar(Persian);
+            assert_eq!(persian.year().number, year, "{iso:?}");
+            assert_eq!(persian.month().ordinal, month, "{iso:?}");
+            assert_eq!(persian.day_of_month().0, day, "{iso:?}");
+            assert_eq!(persian.to_iso(), iso);
+        }
+
+        // Esfand has 29 days in common years
+        assert!(Date::new_persian_date(1404, 12, 30).is_err());
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        for fixed in Persian::new_year(-100)..Persian::new_year(1700) {
+            let iso = Iso::iso_from_fixed(fixed);
+            let persian = iso.to_calendar(Persian);
+            assert_eq!(Persian::fixed_from_persian(persian.inner().0), fixed);
+            assert_eq!(persian.to_iso(), iso);
+        }
+    }
+}
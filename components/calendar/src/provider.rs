@@ -73,3 +73,26 @@ impl FromStr for EraStartDate {
         Ok(EraStartDate { year, month, day })
     }
 }
+
+/// The calendars preferred in a region, from CLDR's calendarPreferenceData, used to pick the
+/// default calendar of a locale that does not specify one
+#[icu_provider::data_struct(marker(
+    CalendarPreferencesV1Marker,
+    "calendar/preferences@1",
+    fallback_by = "region"
+))]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_calendar::provider),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct CalendarPreferencesV1<'data> {
+    /// The BCP-47 identifiers of the preferred calendars, most preferred first
+    ///
+    /// This may contain calendars that are not supported by
+    /// [`AnyCalendarKind`](crate::AnyCalendarKind).
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub calendars: ZeroVec<'data, TinyStr16>,
+}
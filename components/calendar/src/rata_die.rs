@@ -0,0 +1,98 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use core::fmt;
+
+/// The number of days between the start of the Julian Period (noon on January 1, 4713 BCE in
+/// the proleptic Julian calendar) and R.D. 0, rounded to the day starting at noon
+const JULIAN_DAY_NUMBER_OFFSET: i32 = 1_721_425;
+
+/// January 1, 1970, the day of the Unix epoch
+pub(crate) const UNIX_EPOCH: RataDie = RataDie(719_163);
+
+/// A day number counted from R.D. 1, which is January 1, 1 CE in the proleptic ISO calendar
+///
+/// [Rata Die] numbering is the "fixed" day numbering used by the algorithms of
+/// _Calendrical Calculations_, and is independent of any calendar. Every [`Date`](crate::Date)
+/// can be converted to and from a [`RataDie`] without loss, which makes it a convenient
+/// interchange format.
+///
+/// [Rata Die]: https://en.wikipedia.org/wiki/Rata_Die
+///
+/// ```rust
+/// use icu::calendar::{hebrew::Hebrew, Date, Iso, RataDie};
+///
+/// let date = Date::new_iso_date(2000, 1, 1).unwrap();
+/// assert_eq!(date.to_rata_die(), RataDie::new(730120));
+///
+/// let hebrew = Date::from_rata_die(RataDie::new(730120), Hebrew);
+/// assert_eq!(hebrew.year().number, 5760);
+/// assert_eq!(hebrew.to_rata_die().to_i32_date(), 730120);
+///
+/// // The Julian Day Number of a day is the Julian Day at its noon
+/// assert_eq!(date.to_rata_die().to_julian_day_number(), 2451545);
+/// assert_eq!(
+///     Date::from_rata_die(RataDie::from_julian_day_number(2451545), Iso),
+///     date
+/// );
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[allow(clippy::exhaustive_structs)] // this is a newtype
+pub struct RataDie(i32);
+
+impl RataDie {
+    /// Construct a [`RataDie`] from a day number
+    pub const fn new(fixed: i32) -> Self {
+        Self(fixed)
+    }
+
+    /// The day number of this [`RataDie`]
+    pub const fn to_i32_date(self) -> i32 {
+        self.0
+    }
+
+    /// Construct a [`RataDie`] from a Julian Day Number, the number of days since
+    /// January 1, 4713 BCE in the proleptic Julian calendar, with days starting at noon
+    pub const fn from_julian_day_number(julian_day_number: i32) -> Self {
+        Self(julian_day_number - JULIAN_DAY_NUMBER_OFFSET)
+    }
+
+    /// The Julian Day Number of this day, which is the Julian Day at its noon
+    pub const fn to_julian_day_number(self) -> i32 {
+        self.0 + JULIAN_DAY_NUMBER_OFFSET
+    }
+}
+
+impl fmt::Debug for RataDie {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "RD {}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Date, Iso};
+
+    #[test]
+    fn test_julian_day_number() {
+        // (ISO date, Julian Day Number)
+        let cases = [
+            ((-4713, 11, 24), 0),
+            ((1, 1, 1), 1_721_426),
+            ((1858, 11, 17), 2_400_001),
+            ((1970, 1, 1), 2_440_588),
+            ((2000, 1, 1), 2_451_545),
+        ];
+        for ((year, month, day), julian_day_number) in cases {
+            let date = Date::new_iso_date(year, month, day).unwrap();
+            let rata_die = date.to_rata_die();
+            assert_eq!(rata_die.to_julian_day_number(), julian_day_number);
+            assert_eq!(RataDie::from_julian_day_number(julian_day_number), rata_die);
+            assert_eq!(Date::from_rata_die(rata_die, Iso), date);
+        }
+
+        assert_eq!(UNIX_EPOCH.to_julian_day_number(), 2_440_588);
+    }
+}
@@ -8,6 +8,7 @@ use crate::error::DateTimeError;
 use core::convert::TryFrom;
 use core::convert::TryInto;
 use core::fmt;
+use core::num::NonZeroU8;
 use core::str::FromStr;
 use tinystr::{TinyStr16, TinyStr4};
 use zerovec::maps::ZeroMapKV;
@@ -37,7 +38,7 @@ impl FromStr for Era {
 /// Representation of a formattable year.
 ///
 /// More fields may be added in the future, for things like
-/// the cyclic or extended year
+/// the extended year
 #[derive(Copy, Clone, Debug, PartialEq)]
 #[non_exhaustive]
 pub struct FormattableYear {
@@ -47,6 +48,12 @@ pub struct FormattableYear {
     /// The year number in the current era (usually 1-based).
     pub number: i32,
 
+    /// The year in the 60-year cycle of calendars like the Chinese calendar, from 1 to 60, where
+    /// year 1 is 甲子 (jiǎzǐ).
+    ///
+    /// Is None for calendars that do not use cyclic years.
+    pub cyclic: Option<NonZeroU8>,
+
     /// The related ISO year. This is normally the ISO (proleptic Gregorian) year having the greatest
     /// overlap with the calendar year. It is used in certain date formatting patterns.
     ///
@@ -64,6 +71,7 @@ impl FormattableYear {
         Self {
             era,
             number,
+            cyclic: None,
             related_iso: None,
         }
     }
@@ -185,6 +193,21 @@ fn test_day_of_week_in_month() {
     assert_eq!(DayOfWeekInMonth::from(DayOfMonth(8)).0, 2);
 }
 
+/// How to handle fields that are out of range for a date, for example the day 31 in a
+/// 30-day month, or a leap month in a year that doesn't have it
+///
+/// This follows the `overflow` option of Temporal.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[allow(clippy::exhaustive_enums)] // this type is stable
+pub enum Overflow {
+    /// Constrain out-of-range fields to the closest valid date: the day is clamped to the
+    /// end of the month, and a leap month that doesn't exist becomes the month it repeats
+    /// (or, in the Hebrew calendar, Adar).
+    Constrain,
+    /// Return an error for out-of-range fields
+    Reject,
+}
+
 /// This macro defines a struct for 0-based date fields: hours, minutes, seconds
 /// and fractional seconds. Each unit is bounded by a range. The traits implemented
 /// here will return a Result on whether or not the unit is in range from the given
@@ -470,8 +493,34 @@ impl Time {
             nanosecond: nanosecond.try_into()?,
         })
     }
+
+    /// The number of nanoseconds since midnight represented by this time
+    pub(crate) fn nanoseconds_since_midnight(&self) -> i64 {
+        ((self.hour.0 as i64 * 60 + self.minute.0 as i64) * 60 + self.second.0 as i64)
+            * NANOSECONDS_PER_SECOND
+            + self.nanosecond.0 as i64
+    }
+
+    /// Construct a [`Time`] from a number of nanoseconds since midnight, returning it together
+    /// with the number of whole days that `nanoseconds` overflows (or underflows) into
+    pub(crate) fn from_nanoseconds_since_midnight(nanoseconds: i128) -> (Self, i128) {
+        let days = nanoseconds.div_euclid(NANOSECONDS_PER_DAY as i128);
+        // Within [0, NANOSECONDS_PER_DAY), so all the fields below are in range
+        let nanoseconds = nanoseconds.rem_euclid(NANOSECONDS_PER_DAY as i128) as i64;
+        let seconds = nanoseconds / NANOSECONDS_PER_SECOND;
+        let time = Self {
+            hour: IsoHour((seconds / 3600) as u8),
+            minute: IsoMinute((seconds / 60 % 60) as u8),
+            second: IsoSecond((seconds % 60) as u8),
+            nanosecond: NanoSecond((nanoseconds % NANOSECONDS_PER_SECOND) as u32),
+        };
+        (time, days)
+    }
 }
 
+pub(crate) const NANOSECONDS_PER_SECOND: i64 = 1_000_000_000;
+pub(crate) const NANOSECONDS_PER_DAY: i64 = 86_400 * NANOSECONDS_PER_SECOND;
+
 /// A weekday in a 7-day week, according to ISO-8601.
 ///
 /// The discriminant values correspond to ISO-8601 weekday numbers (Monday = 1, Sunday = 7).
@@ -44,6 +44,64 @@ impl Default for CalendarInfo {
     }
 }
 
+/// Information about which days of the week are the weekend in a region, as in CLDR's
+/// `weekendStart` and `weekendEnd`.
+///
+/// The weekend runs from `weekend_start` through `weekend_end`, wrapping around the end of the
+/// ISO week if needed. All other days of the week are business days.
+///
+/// ```rust
+/// use icu::calendar::types::IsoWeekday;
+/// use icu::calendar::week_of::WeekendInfo;
+///
+/// // The weekend in e.g. Saudi Arabia
+/// let weekend = WeekendInfo {
+///     weekend_start: IsoWeekday::Friday,
+///     weekend_end: IsoWeekday::Saturday,
+/// };
+///
+/// assert!(weekend.is_weekend(IsoWeekday::Friday));
+/// assert!(!weekend.is_weekend(IsoWeekday::Sunday));
+/// assert_eq!(weekend.business_days_per_week(), 5);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_calendar::week_of),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[allow(clippy::exhaustive_structs)] // this type is stable
+pub struct WeekendInfo {
+    /// The first day of the weekend.
+    pub weekend_start: IsoWeekday,
+    /// The last day of the weekend.
+    pub weekend_end: IsoWeekday,
+}
+
+impl WeekendInfo {
+    /// Returns whether `weekday` is part of the weekend.
+    pub fn is_weekend(&self, weekday: IsoWeekday) -> bool {
+        // The index of `weekday` in the weekend, and the index of the last day of the weekend
+        let index = (7 + weekday as u8 - self.weekend_start as u8) % 7;
+        index <= (7 + self.weekend_end as u8 - self.weekend_start as u8) % 7
+    }
+
+    /// Returns the number of business days, i.e. days that are not part of the weekend, in a week.
+    pub fn business_days_per_week(&self) -> u8 {
+        6 - (7 + self.weekend_end as u8 - self.weekend_start as u8) % 7
+    }
+}
+
+impl Default for WeekendInfo {
+    fn default() -> Self {
+        Self {
+            weekend_start: IsoWeekday::Saturday,
+            weekend_end: IsoWeekday::Sunday,
+        }
+    }
+}
+
 /// Returns the weekday that's `num_days` after `weekday`.
 fn add_to_weekday(weekday: IsoWeekday, num_days: i32) -> IsoWeekday {
     let new_weekday = (7 + (weekday as i32) + (num_days % 7)) % 7;
@@ -100,6 +158,9 @@ impl UnitInfo {
     }
 
     /// Returns the number of weeks in this unit according to `calendar`.
+    ///
+    /// The last week of this unit is the first week of the next unit if it has at least
+    /// `min_week_days` days in the next unit.
     fn num_weeks(&self, calendar: &CalendarInfo) -> u16 {
         let first_week_offset = self.first_week_offset(calendar);
         let num_days_including_first_week =
@@ -108,7 +169,7 @@ impl UnitInfo {
             num_days_including_first_week >= 0,
             "Unit is shorter than a week."
         );
-        ((num_days_including_first_week + 7 - (calendar.min_week_days as i32)) / 7) as u16
+        ((num_days_including_first_week + (calendar.min_week_days as i32) - 1) / 7) as u16
     }
 
     /// Returns the week number for the given day in this unit.
@@ -205,24 +266,21 @@ pub fn simple_week_of(first_weekday: IsoWeekday, day: u16, week_day: IsoWeekday)
         min_week_days: 1,
     };
 
-    #[allow(clippy::unwrap_used)] // week_of should can't fail with MIN_UNIT_DAYS
-    week_of(
-        &calendar,
-        // The duration of the current and previous unit does not influence the result if min_week_days = 1
-        // so we only need to use a valid value.
-        MIN_UNIT_DAYS,
-        MIN_UNIT_DAYS,
-        day,
-        week_day,
-    )
-    .unwrap()
-    .week
+    // With min_week_days = 1 the first week always starts in this month or year, on or before
+    // its first day, and every later week belongs to it too, however long it is.
+    let first_day = add_to_weekday(week_day, 1 - i32::from(day));
+    let first_day_index = calendar.weekday_index(first_day) as u16;
+    (day + first_day_index - 1) / 7 + 1
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{week_of, CalendarInfo, RelativeUnit, RelativeWeek, UnitInfo, WeekOf};
-    use crate::{error::DateTimeError, types::IsoWeekday, Date, DateDuration};
+    use super::{week_of, CalendarInfo, RelativeUnit, RelativeWeek, UnitInfo, WeekOf, WeekendInfo};
+    use crate::{
+        error::DateTimeError,
+        types::{IsoWeekday, Overflow},
+        Date, DateDuration,
+    };
 
     static ISO_CALENDAR: CalendarInfo = CalendarInfo {
         first_weekday: IsoWeekday::Monday,
@@ -295,10 +353,10 @@ mod tests {
             2
         );
 
-        // 1 day in first & last week.
+        // 1 day in first & last week. The last week is the first week of the next unit.
         assert_eq!(
             UnitInfo::new(IsoWeekday::Saturday, 1 + 2 * 7 + 1)?.num_weeks(&US_CALENDAR),
-            4
+            3
         );
         Ok(())
     }
@@ -321,12 +379,13 @@ mod tests {
         let mut day_week_of_units = Vec::new();
         let mut weeks_in_unit = 0;
         for (index, week) in weeks.iter().enumerate() {
-            let week_of_unit = if week.len() < usize::from(calendar.min_week_days) {
-                match index {
-                    0 => RelativeWeek::LastWeekOfPreviousUnit,
-                    x if x == weeks.len() - 1 => RelativeWeek::FirstWeekOfNextUnit,
-                    _ => panic!(),
-                }
+            let min_week_days = usize::from(calendar.min_week_days);
+            let week_of_unit = if index == 0 && week.len() < min_week_days {
+                RelativeWeek::LastWeekOfPreviousUnit
+            } else if index == weeks.len() - 1 && 7 - week.len() >= min_week_days {
+                // The rest of the week is in the next unit, and enough of it for the week to
+                // be the first week of the next unit.
+                RelativeWeek::FirstWeekOfNextUnit
             } else {
                 weeks_in_unit += 1;
                 RelativeWeek::WeekOfCurrentUnit(weeks_in_unit)
@@ -378,7 +437,10 @@ mod tests {
         let day = (yyyymmdd % 100) as u8;
 
         let date = Date::new_iso_date(year, month, day)?;
-        let previous_month = date.clone().added(DateDuration::new(0, -1, 0, 0));
+        let previous_month = date
+            .clone()
+            .added(DateDuration::new(0, -1, 0, 0), Overflow::Constrain)
+            .unwrap();
 
         week_of(
             calendar,
@@ -440,14 +502,21 @@ mod tests {
             }
         );
 
-        // US calendar always assigns the week to the current month. 2020-12-31 is a Thursday.
+        // US calendar assigns a week to the month of its last day. 2020-12-31 is a Thursday.
         assert_eq!(
-            week_of_month_from_iso_date(&US_CALENDAR, 20201231)?,
+            week_of_month_from_iso_date(&US_CALENDAR, 20201226)?,
             WeekOf {
-                week: 5,
+                week: 4,
                 unit: RelativeUnit::Current,
             }
         );
+        assert_eq!(
+            week_of_month_from_iso_date(&US_CALENDAR, 20201231)?,
+            WeekOf {
+                week: 1,
+                unit: RelativeUnit::Next,
+            }
+        );
         assert_eq!(
             week_of_month_from_iso_date(&US_CALENDAR, 20210101)?,
             WeekOf {
@@ -458,6 +527,93 @@ mod tests {
 
         Ok(())
     }
+    #[test]
+    fn test_is_weekend() {
+        use IsoWeekday::*;
+        let days = [
+            Monday, Tuesday, Wednesday, Thursday, Friday, Saturday, Sunday,
+        ];
+        // (weekend_start, weekend_end, weekend days)
+        let cases = [
+            (Saturday, Sunday, vec![Saturday, Sunday]),
+            (Friday, Saturday, vec![Friday, Saturday]),
+            (Thursday, Friday, vec![Thursday, Friday]),
+            (Sunday, Sunday, vec![Sunday]),
+            (Sunday, Monday, vec![Sunday, Monday]),
+            (Monday, Sunday, days.to_vec()),
+        ];
+        for (weekend_start, weekend_end, weekend_days) in cases {
+            let weekend = WeekendInfo {
+                weekend_start,
+                weekend_end,
+            };
+            for day in days {
+                assert_eq!(
+                    weekend.is_weekend(day),
+                    weekend_days.contains(&day),
+                    "{weekend:?} {day:?}"
+                );
+            }
+            assert_eq!(
+                weekend.business_days_per_week() as usize,
+                7 - weekend_days.len()
+            );
+        }
+    }
+
+    #[test]
+    fn test_added_business_days() {
+        use IsoWeekday::*;
+        let weekends = [(Saturday, Sunday), (Friday, Saturday), (Sunday, Sunday)];
+        for (weekend_start, weekend_end) in weekends {
+            let weekend = WeekendInfo {
+                weekend_start,
+                weekend_end,
+            };
+            for start in 0..7 {
+                let date = Date::new_iso_date(2023, 7, 1)
+                    .unwrap()
+                    .added(DateDuration::new(0, 0, 0, start), Overflow::Constrain)
+                    .unwrap();
+                for days in -30i32..30 {
+                    // Step one day at a time, counting business days
+                    let mut expected = date.clone();
+                    let mut remaining = days;
+                    while remaining != 0 {
+                        expected = expected
+                            .added(
+                                DateDuration::new(0, 0, 0, days.signum()),
+                                Overflow::Constrain,
+                            )
+                            .unwrap();
+                        if !expected.is_weekend(&weekend) {
+                            remaining -= days.signum();
+                        }
+                    }
+                    assert_eq!(
+                        date.clone().added_business_days(days, &weekend),
+                        Ok(expected),
+                        "{date:?} {days} {weekend:?}"
+                    );
+                }
+            }
+        }
+
+        let all_week = WeekendInfo {
+            weekend_start: Monday,
+            weekend_end: Sunday,
+        };
+        let date = Date::new_iso_date(2023, 7, 1).unwrap();
+        assert_eq!(date.clone().next_business_day(&all_week), Ok(date.clone()));
+
+        for days in [i32::MAX, i32::MIN] {
+            assert_eq!(
+                date.clone()
+                    .added_business_days(days, &WeekendInfo::default()),
+                Err(DateTimeError::OutOfRange)
+            );
+        }
+    }
 }
 
 #[test]
@@ -483,4 +639,19 @@ fn test_simple_week_of() {
         simple_week_of(IsoWeekday::Tuesday, 7, IsoWeekday::Tuesday),
         2
     );
+
+    // The 1st is a Friday and the week starts on Sundays: the last days of a long month
+    // are in its fifth or sixth week.
+    assert_eq!(
+        simple_week_of(IsoWeekday::Sunday, 17, IsoWeekday::Sunday),
+        4
+    );
+    assert_eq!(
+        simple_week_of(IsoWeekday::Sunday, 30, IsoWeekday::Saturday),
+        5
+    );
+    assert_eq!(
+        simple_week_of(IsoWeekday::Sunday, 31, IsoWeekday::Sunday),
+        6
+    );
 }
@@ -7,10 +7,12 @@ use alloc::string::String;
 
 use icu_provider::prelude::*;
 
-use crate::provider::{calendar::*, week_data::WeekDataV1Marker};
+use crate::provider::{calendar::*, time_data::TimeDataV1Marker, week_data::WeekDataV1Marker};
 use crate::{input::DateInput, DateTimeFormatterError, FormattedDateTime};
 use icu_calendar::any_calendar::{AnyCalendar, AnyCalendarKind};
-use icu_calendar::provider::{JapaneseErasV1Marker, JapaneseExtendedErasV1Marker};
+use icu_calendar::provider::{
+    CalendarPreferencesV1Marker, JapaneseErasV1Marker, JapaneseExtendedErasV1Marker,
+};
 use icu_calendar::Date;
 use icu_decimal::provider::DecimalSymbolsV1Marker;
 use icu_plurals::provider::OrdinalV1Marker;
@@ -64,12 +66,14 @@ impl DateFormatter {
     /// calendar for the locale. See [`AnyCalendarKind`] for a list of supported calendars.
     ///
     /// The provider must be able to provide data for the following keys: `datetime/symbols@1`, `datetime/timelengths@1`,
-    /// `datetime/timelengths@1`, `datetime/symbols@1`, `datetime/skeletons@1`, `datetime/week_data@1`, and `plurals/ordinals@1`.
+    /// `datetime/timelengths@1`, `datetime/symbols@1`, `datetime/skeletons@1`, `datetime/week_data@1`, `datetime/time_data@1`, and `plurals/ordinals@1`.
 
     ///
     /// Furthermore, based on the type of calendar used, one of the following data keys may be necessary:
     ///
     /// - `u-ca-japanese` (Japanese calendar): `calendar/japanese@1`
+    /// - no `u-ca` keyword: `calendar/preferences@1`, see
+    ///   [`AnyCalendar::try_new_for_locale_with_preferences_unstable()`]
     #[inline]
     pub fn try_new_with_any_provider<P>(
         data_provider: &P,
@@ -90,11 +94,13 @@ impl DateFormatter {
     /// calendar for the locale. See [`AnyCalendarKind`] for a list of supported calendars.
     ///
     /// The provider must be able to provide data for the following keys: `datetime/symbols@1`, `datetime/datelengths@1`,
-    /// `datetime/timelengths@1`, `datetime/symbols@1`, `datetime/skeletons@1`, `datetime/week_data@1`, and `plurals/ordinals@1`.
+    /// `datetime/timelengths@1`, `datetime/symbols@1`, `datetime/skeletons@1`, `datetime/week_data@1`, `datetime/time_data@1`, and `plurals/ordinals@1`.
     ///
     /// Furthermore, based on the type of calendar used, one of the following data keys may be necessary:
     ///
     /// - `u-ca-japanese` (Japanese calendar): `calendar/japanese@1`
+    /// - no `u-ca` keyword: `calendar/preferences@1`, see
+    ///   [`AnyCalendar::try_new_for_locale_with_preferences_unstable()`]
     ///
     /// # Examples
     ///
@@ -177,6 +183,7 @@ impl DateFormatter {
     where
         P: DataProvider<TimeSymbolsV1Marker>
             + DataProvider<TimeLengthsV1Marker>
+            + DataProvider<TimeDataV1Marker>
             + DataProvider<OrdinalV1Marker>
             + DataProvider<WeekDataV1Marker>
             + DataProvider<DecimalSymbolsV1Marker>
@@ -185,6 +192,12 @@ impl DateFormatter {
             + DataProvider<JapaneseDateLengthsV1Marker>
             + DataProvider<JapaneseExtendedDateLengthsV1Marker>
             + DataProvider<CopticDateLengthsV1Marker>
+            + DataProvider<ChineseDateLengthsV1Marker>
+            + DataProvider<DangiDateLengthsV1Marker>
+            + DataProvider<HebrewDateLengthsV1Marker>
+            + DataProvider<PersianDateLengthsV1Marker>
+            + DataProvider<RocDateLengthsV1Marker>
+            + DataProvider<IslamicDateLengthsV1Marker>
             + DataProvider<IndianDateLengthsV1Marker>
             + DataProvider<EthiopianDateLengthsV1Marker>
             + DataProvider<GregorianDateSymbolsV1Marker>
@@ -192,13 +205,21 @@ impl DateFormatter {
             + DataProvider<JapaneseDateSymbolsV1Marker>
             + DataProvider<JapaneseExtendedDateSymbolsV1Marker>
             + DataProvider<CopticDateSymbolsV1Marker>
+            + DataProvider<ChineseDateSymbolsV1Marker>
+            + DataProvider<DangiDateSymbolsV1Marker>
+            + DataProvider<HebrewDateSymbolsV1Marker>
+            + DataProvider<PersianDateSymbolsV1Marker>
+            + DataProvider<RocDateSymbolsV1Marker>
+            + DataProvider<IslamicDateSymbolsV1Marker>
             + DataProvider<IndianDateSymbolsV1Marker>
             + DataProvider<EthiopianDateSymbolsV1Marker>
             + DataProvider<JapaneseErasV1Marker>
             + DataProvider<JapaneseExtendedErasV1Marker>
+            + DataProvider<CalendarPreferencesV1Marker>
             + ?Sized,
     {
-        let calendar = AnyCalendar::try_new_for_locale_unstable(data_provider, locale)?;
+        let calendar =
+            AnyCalendar::try_new_for_locale_with_preferences_unstable(data_provider, locale)?;
         let kind = calendar.kind();
 
         Ok(Self(
@@ -9,10 +9,15 @@ use icu_provider::prelude::*;
 
 #[cfg(feature = "experimental")]
 use crate::options::components;
-use crate::provider::{calendar::*, date_time::PatternSelector, week_data::WeekDataV1Marker};
-use crate::{input::DateTimeInput, DateTimeFormatterError, FormattedDateTime};
+use crate::provider::{
+    calendar::*, date_time::PatternSelector, time_data::TimeDataV1Marker,
+    week_data::WeekDataV1Marker,
+};
+use crate::{input::DateTimeInput, DateTimeFormatterError, FormattedDateTime, Leniency};
 use icu_calendar::any_calendar::{AnyCalendar, AnyCalendarKind};
-use icu_calendar::provider::{JapaneseErasV1Marker, JapaneseExtendedErasV1Marker};
+use icu_calendar::provider::{
+    CalendarPreferencesV1Marker, JapaneseErasV1Marker, JapaneseExtendedErasV1Marker,
+};
 use icu_calendar::{types::Time, DateTime};
 use icu_decimal::provider::DecimalSymbolsV1Marker;
 use icu_plurals::provider::OrdinalV1Marker;
@@ -113,11 +118,13 @@ impl DateTimeFormatter {
     /// calendar for the locale. See [`AnyCalendarKind`] for a list of supported calendars.
     ///
     /// The provider must be able to provide data for the following keys: `datetime/symbols@1`, `datetime/timelengths@1`,
-    /// `datetime/timelengths@1`, `datetime/symbols@1`, `datetime/skeletons@1`, `datetime/week_data@1`, and `plurals/ordinals@1`.
+    /// `datetime/timelengths@1`, `datetime/symbols@1`, `datetime/skeletons@1`, `datetime/append_items@1`, `datetime/week_data@1`, `datetime/time_data@1`, and `plurals/ordinals@1`.
     ///
     /// Furthermore, based on the type of calendar used, one of the following data keys may be necessary:
     ///
     /// - `u-ca-japanese` (Japanese calendar): `calendar/japanese@1`
+    /// - no `u-ca` keyword: `calendar/preferences@1`, see
+    ///   [`AnyCalendar::try_new_for_locale_with_preferences_unstable()`]
     #[inline]
     pub fn try_new_with_any_provider<P>(
         data_provider: &P,
@@ -138,11 +145,13 @@ impl DateTimeFormatter {
     /// calendar for the locale. See [`AnyCalendarKind`] for a list of supported calendars.
     ///
     /// The provider must be able to provide data for the following keys: `datetime/symbols@1`, `datetime/datelengths@1`,
-    /// `datetime/timelengths@1`, `datetime/symbols@1`, `datetime/skeletons@1`, `datetime/week_data@1`, and `plurals/ordinals@1`.
+    /// `datetime/timelengths@1`, `datetime/symbols@1`, `datetime/skeletons@1`, `datetime/append_items@1`, `datetime/week_data@1`, `datetime/time_data@1`, and `plurals/ordinals@1`.
     ///
     /// Furthermore, based on the type of calendar used, one of the following data keys may be necessary:
     ///
     /// - `u-ca-japanese` (Japanese calendar): `calendar/japanese@1`
+    /// - no `u-ca` keyword: `calendar/preferences@1`, see
+    ///   [`AnyCalendar::try_new_for_locale_with_preferences_unstable()`]
     ///
     /// # Examples
     ///
@@ -226,7 +235,9 @@ impl DateTimeFormatter {
     where
         P: DataProvider<TimeSymbolsV1Marker>
             + DataProvider<TimeLengthsV1Marker>
+            + DataProvider<TimeDataV1Marker>
             + DataProvider<crate::provider::calendar::DateSkeletonPatternsV1Marker>
+            + DataProvider<crate::provider::calendar::DateAppendItemsV1Marker>
             + DataProvider<OrdinalV1Marker>
             + DataProvider<WeekDataV1Marker>
             + DataProvider<DecimalSymbolsV1Marker>
@@ -235,6 +246,12 @@ impl DateTimeFormatter {
             + DataProvider<JapaneseDateLengthsV1Marker>
             + DataProvider<JapaneseExtendedDateLengthsV1Marker>
             + DataProvider<CopticDateLengthsV1Marker>
+            + DataProvider<ChineseDateLengthsV1Marker>
+            + DataProvider<DangiDateLengthsV1Marker>
+            + DataProvider<HebrewDateLengthsV1Marker>
+            + DataProvider<PersianDateLengthsV1Marker>
+            + DataProvider<RocDateLengthsV1Marker>
+            + DataProvider<IslamicDateLengthsV1Marker>
             + DataProvider<IndianDateLengthsV1Marker>
             + DataProvider<EthiopianDateLengthsV1Marker>
             + DataProvider<GregorianDateSymbolsV1Marker>
@@ -242,13 +259,21 @@ impl DateTimeFormatter {
             + DataProvider<JapaneseDateSymbolsV1Marker>
             + DataProvider<JapaneseExtendedDateSymbolsV1Marker>
             + DataProvider<CopticDateSymbolsV1Marker>
+            + DataProvider<ChineseDateSymbolsV1Marker>
+            + DataProvider<DangiDateSymbolsV1Marker>
+            + DataProvider<HebrewDateSymbolsV1Marker>
+            + DataProvider<PersianDateSymbolsV1Marker>
+            + DataProvider<RocDateSymbolsV1Marker>
+            + DataProvider<IslamicDateSymbolsV1Marker>
             + DataProvider<IndianDateSymbolsV1Marker>
             + DataProvider<EthiopianDateSymbolsV1Marker>
             + DataProvider<JapaneseErasV1Marker>
             + DataProvider<JapaneseExtendedErasV1Marker>
+            + DataProvider<CalendarPreferencesV1Marker>
             + ?Sized,
     {
-        let calendar = AnyCalendar::try_new_for_locale_unstable(data_provider, locale)?;
+        let calendar =
+            AnyCalendar::try_new_for_locale_with_preferences_unstable(data_provider, locale)?;
         let kind = calendar.kind();
 
         let patterns = PatternSelector::for_options(
@@ -281,6 +306,7 @@ impl DateTimeFormatter {
     where
         P: DataProvider<TimeSymbolsV1Marker>
             + DataProvider<TimeLengthsV1Marker>
+            + DataProvider<TimeDataV1Marker>
             + DataProvider<OrdinalV1Marker>
             + DataProvider<WeekDataV1Marker>
             + DataProvider<DecimalSymbolsV1Marker>
@@ -289,6 +315,12 @@ impl DateTimeFormatter {
             + DataProvider<JapaneseDateLengthsV1Marker>
             + DataProvider<JapaneseExtendedDateLengthsV1Marker>
             + DataProvider<CopticDateLengthsV1Marker>
+            + DataProvider<ChineseDateLengthsV1Marker>
+            + DataProvider<DangiDateLengthsV1Marker>
+            + DataProvider<HebrewDateLengthsV1Marker>
+            + DataProvider<PersianDateLengthsV1Marker>
+            + DataProvider<RocDateLengthsV1Marker>
+            + DataProvider<IslamicDateLengthsV1Marker>
             + DataProvider<IndianDateLengthsV1Marker>
             + DataProvider<EthiopianDateLengthsV1Marker>
             + DataProvider<GregorianDateSymbolsV1Marker>
@@ -296,13 +328,21 @@ impl DateTimeFormatter {
             + DataProvider<JapaneseDateSymbolsV1Marker>
             + DataProvider<JapaneseExtendedDateSymbolsV1Marker>
             + DataProvider<CopticDateSymbolsV1Marker>
+            + DataProvider<ChineseDateSymbolsV1Marker>
+            + DataProvider<DangiDateSymbolsV1Marker>
+            + DataProvider<HebrewDateSymbolsV1Marker>
+            + DataProvider<PersianDateSymbolsV1Marker>
+            + DataProvider<RocDateSymbolsV1Marker>
+            + DataProvider<IslamicDateSymbolsV1Marker>
             + DataProvider<IndianDateSymbolsV1Marker>
             + DataProvider<EthiopianDateSymbolsV1Marker>
             + DataProvider<JapaneseErasV1Marker>
             + DataProvider<JapaneseExtendedErasV1Marker>
+            + DataProvider<CalendarPreferencesV1Marker>
             + ?Sized,
     {
-        let calendar = AnyCalendar::try_new_for_locale_unstable(data_provider, locale)?;
+        let calendar =
+            AnyCalendar::try_new_for_locale_with_preferences_unstable(data_provider, locale)?;
         let kind = calendar.kind();
 
         let patterns = PatternSelector::for_options(
@@ -431,6 +471,68 @@ where {
         }
     }
 
+    /// Reads a string that follows the pattern of this [`DateTimeFormatter`] back into a
+    /// [`DateTime`] in the calendar of the formatter.
+    ///
+    /// See [`TypedDateTimeFormatter::parse()`](crate::TypedDateTimeFormatter::parse) for
+    /// what can be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::calendar::DateTime;
+    /// use icu::datetime::{options::length, DateTimeFormatter, Leniency};
+    /// use icu::locid::locale;
+    ///
+    /// let provider = icu_testdata::get_provider();
+    /// let options = length::Bag::from_date_style(length::Date::Medium);
+    /// let dtf = DateTimeFormatter::try_new_with_buffer_provider(
+    ///     &provider,
+    ///     &locale!("fr-u-ca-gregory").into(),
+    ///     options.into(),
+    /// )
+    /// .expect("Failed to create DateTimeFormatter instance.");
+    ///
+    /// let datetime = dtf
+    ///     .parse("5 janv. 2023", Leniency::Strict)
+    ///     .expect("The input follows the pattern");
+    /// let expected = DateTime::new_gregorian_datetime(2023, 1, 5, 0, 0, 0)
+    ///     .unwrap()
+    ///     .to_any();
+    ///
+    /// assert_eq!(
+    ///     dtf.format_to_string(&datetime).expect("calendars should match"),
+    ///     dtf.format_to_string(&expected).expect("calendars should match"),
+    /// );
+    /// ```
+    pub fn parse(
+        &self,
+        input: &str,
+        leniency: Leniency,
+    ) -> Result<DateTime<icu_calendar::Ref<'_, AnyCalendar>>, DateTimeFormatterError> {
+        self.0
+            .parse(input, leniency, None, icu_calendar::Ref(&self.1))
+    }
+
+    /// Like [`parse()`](Self::parse), but places two-digit years in the hundred years starting
+    /// at `two_digit_year_start` of the calendar of the formatter.
+    ///
+    /// See [`TypedDateTimeFormatter::parse_with_two_digit_year_start()`](crate::TypedDateTimeFormatter::parse_with_two_digit_year_start)
+    /// for an example.
+    pub fn parse_with_two_digit_year_start(
+        &self,
+        input: &str,
+        leniency: Leniency,
+        two_digit_year_start: i32,
+    ) -> Result<DateTime<icu_calendar::Ref<'_, AnyCalendar>>, DateTimeFormatterError> {
+        self.0.parse(
+            input,
+            leniency,
+            Some(two_digit_year_start),
+            icu_calendar::Ref(&self.1),
+        )
+    }
+
     /// Returns a [`components::Bag`] that represents the resolved components for the
     /// options that were provided to the [`DateTimeFormatter`]. The developer may request
     /// a certain set of options for a [`DateTimeFormatter`] but the locale and resolution
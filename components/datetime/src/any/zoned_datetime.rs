@@ -8,11 +8,16 @@ use alloc::string::String;
 use icu_provider::prelude::*;
 
 use crate::input::{DateTimeInput, ExtractedDateTimeInput, TimeZoneInput};
-use crate::provider::{self, calendar::*, date_time::PatternSelector, week_data::WeekDataV1Marker};
+use crate::provider::{
+    self, calendar::*, date_time::PatternSelector, time_data::TimeDataV1Marker,
+    week_data::WeekDataV1Marker,
+};
 use crate::time_zone::TimeZoneFormatterOptions;
 use crate::{DateTimeFormatterError, FormattedZonedDateTime};
 use icu_calendar::any_calendar::{AnyCalendar, AnyCalendarKind};
-use icu_calendar::provider::{JapaneseErasV1Marker, JapaneseExtendedErasV1Marker};
+use icu_calendar::provider::{
+    CalendarPreferencesV1Marker, JapaneseErasV1Marker, JapaneseExtendedErasV1Marker,
+};
 use icu_calendar::{types::Time, DateTime};
 use icu_decimal::provider::DecimalSymbolsV1Marker;
 use icu_plurals::provider::OrdinalV1Marker;
@@ -123,10 +128,13 @@ impl ZonedDateTimeFormatter {
     where
         P: DataProvider<TimeSymbolsV1Marker>
             + DataProvider<TimeLengthsV1Marker>
+            + DataProvider<TimeDataV1Marker>
             + DataProvider<crate::provider::calendar::DateSkeletonPatternsV1Marker>
+            + DataProvider<crate::provider::calendar::DateAppendItemsV1Marker>
             + DataProvider<WeekDataV1Marker>
             + DataProvider<provider::time_zones::TimeZoneFormatsV1Marker>
             + DataProvider<provider::time_zones::ExemplarCitiesV1Marker>
+            + DataProvider<provider::time_zones::TimeZoneIanaIdsV1Marker>
             + DataProvider<provider::time_zones::MetaZoneGenericNamesLongV1Marker>
             + DataProvider<provider::time_zones::MetaZoneGenericNamesShortV1Marker>
             + DataProvider<provider::time_zones::MetaZoneSpecificNamesLongV1Marker>
@@ -138,6 +146,12 @@ impl ZonedDateTimeFormatter {
             + DataProvider<JapaneseDateLengthsV1Marker>
             + DataProvider<JapaneseExtendedDateLengthsV1Marker>
             + DataProvider<CopticDateLengthsV1Marker>
+            + DataProvider<ChineseDateLengthsV1Marker>
+            + DataProvider<DangiDateLengthsV1Marker>
+            + DataProvider<HebrewDateLengthsV1Marker>
+            + DataProvider<PersianDateLengthsV1Marker>
+            + DataProvider<RocDateLengthsV1Marker>
+            + DataProvider<IslamicDateLengthsV1Marker>
             + DataProvider<IndianDateLengthsV1Marker>
             + DataProvider<EthiopianDateLengthsV1Marker>
             + DataProvider<GregorianDateSymbolsV1Marker>
@@ -145,13 +159,20 @@ impl ZonedDateTimeFormatter {
             + DataProvider<JapaneseDateSymbolsV1Marker>
             + DataProvider<JapaneseExtendedDateSymbolsV1Marker>
             + DataProvider<CopticDateSymbolsV1Marker>
+            + DataProvider<ChineseDateSymbolsV1Marker>
+            + DataProvider<DangiDateSymbolsV1Marker>
+            + DataProvider<HebrewDateSymbolsV1Marker>
+            + DataProvider<PersianDateSymbolsV1Marker>
+            + DataProvider<RocDateSymbolsV1Marker>
+            + DataProvider<IslamicDateSymbolsV1Marker>
             + DataProvider<IndianDateSymbolsV1Marker>
             + DataProvider<EthiopianDateSymbolsV1Marker>
             + DataProvider<JapaneseErasV1Marker>
             + DataProvider<JapaneseExtendedErasV1Marker>
+            + DataProvider<CalendarPreferencesV1Marker>
             + ?Sized,
     {
-        let calendar = AnyCalendar::try_new_for_locale_unstable(provider, locale)?;
+        let calendar = AnyCalendar::try_new_for_locale_with_preferences_unstable(provider, locale)?;
         let kind = calendar.kind();
 
         let patterns = PatternSelector::for_options(
@@ -187,9 +208,11 @@ impl ZonedDateTimeFormatter {
     where
         P: DataProvider<TimeSymbolsV1Marker>
             + DataProvider<TimeLengthsV1Marker>
+            + DataProvider<TimeDataV1Marker>
             + DataProvider<WeekDataV1Marker>
             + DataProvider<provider::time_zones::TimeZoneFormatsV1Marker>
             + DataProvider<provider::time_zones::ExemplarCitiesV1Marker>
+            + DataProvider<provider::time_zones::TimeZoneIanaIdsV1Marker>
             + DataProvider<provider::time_zones::MetaZoneGenericNamesLongV1Marker>
             + DataProvider<provider::time_zones::MetaZoneGenericNamesShortV1Marker>
             + DataProvider<provider::time_zones::MetaZoneSpecificNamesLongV1Marker>
@@ -201,6 +224,12 @@ impl ZonedDateTimeFormatter {
             + DataProvider<JapaneseDateLengthsV1Marker>
             + DataProvider<JapaneseExtendedDateLengthsV1Marker>
             + DataProvider<CopticDateLengthsV1Marker>
+            + DataProvider<ChineseDateLengthsV1Marker>
+            + DataProvider<DangiDateLengthsV1Marker>
+            + DataProvider<HebrewDateLengthsV1Marker>
+            + DataProvider<PersianDateLengthsV1Marker>
+            + DataProvider<RocDateLengthsV1Marker>
+            + DataProvider<IslamicDateLengthsV1Marker>
             + DataProvider<IndianDateLengthsV1Marker>
             + DataProvider<EthiopianDateLengthsV1Marker>
             + DataProvider<GregorianDateSymbolsV1Marker>
@@ -208,13 +237,20 @@ impl ZonedDateTimeFormatter {
             + DataProvider<JapaneseDateSymbolsV1Marker>
             + DataProvider<JapaneseExtendedDateSymbolsV1Marker>
             + DataProvider<CopticDateSymbolsV1Marker>
+            + DataProvider<ChineseDateSymbolsV1Marker>
+            + DataProvider<DangiDateSymbolsV1Marker>
+            + DataProvider<HebrewDateSymbolsV1Marker>
+            + DataProvider<PersianDateSymbolsV1Marker>
+            + DataProvider<RocDateSymbolsV1Marker>
+            + DataProvider<IslamicDateSymbolsV1Marker>
             + DataProvider<IndianDateSymbolsV1Marker>
             + DataProvider<EthiopianDateSymbolsV1Marker>
             + DataProvider<JapaneseErasV1Marker>
             + DataProvider<JapaneseExtendedErasV1Marker>
+            + DataProvider<CalendarPreferencesV1Marker>
             + ?Sized,
     {
-        let calendar = AnyCalendar::try_new_for_locale_unstable(provider, locale)?;
+        let calendar = AnyCalendar::try_new_for_locale_with_preferences_unstable(provider, locale)?;
         let kind = calendar.kind();
 
         let patterns = PatternSelector::for_options(
@@ -243,13 +279,15 @@ impl ZonedDateTimeFormatter {
     /// calendar for the locale. See [`AnyCalendarKind`] for a list of supported calendars.
     ///
     /// The provider must be able to provide data for the following keys: `datetime/symbols@1`, `datetime/timelengths@1`,
-    /// `datetime/timelengths@1`, `datetime/symbols@1`, `datetime/skeletons@1`, `datetime/week_data@1`, `plurals/ordinals@1`,
+    /// `datetime/timelengths@1`, `datetime/symbols@1`, `datetime/skeletons@1`, `datetime/append_items@1`, `datetime/week_data@1`, `datetime/time_data@1`, `plurals/ordinals@1`,
     /// `time_zone/formats@1`, `time_zone/exemplar_cities@1`, `time_zone/generic_long@1`, `time_zone/generic_short@1`,
     /// `time_zone/specific_long@1`, `time_zone/specific_short@1`, `time_zone/metazone_period@1`.
     ///
     /// Furthermore, based on the type of calendar used, one of the following data keys may be necessary:
     ///
     /// - `u-ca-japanese` (Japanese calendar): `calendar/japanese@1`
+    /// - no `u-ca` keyword: `calendar/preferences@1`, see
+    ///   [`AnyCalendar::try_new_for_locale_with_preferences_unstable()`]
     ///
     /// Test will currently fail due to <https://github.com/unicode-org/icu4x/issues/2188>,
     /// since these functions currently *must* be given a fallback-enabled provider and
@@ -303,13 +341,15 @@ impl ZonedDateTimeFormatter {
     /// calendar for the locale. See [`AnyCalendarKind`] for a list of supported calendars.
     ///
     /// The provider must be able to provide data for the following keys: `datetime/symbols@1`, `datetime/timelengths@1`,
-    /// `datetime/timelengths@1`, `datetime/symbols@1`, `datetime/skeletons@1`, `datetime/week_data@1`, `plurals/ordinals@1`,
+    /// `datetime/timelengths@1`, `datetime/symbols@1`, `datetime/skeletons@1`, `datetime/append_items@1`, `datetime/week_data@1`, `datetime/time_data@1`, `plurals/ordinals@1`,
     /// `time_zone/formats@1`, `time_zone/exemplar_cities@1`, `time_zone/generic_long@1`, `time_zone/generic_short@1`,
     /// `time_zone/specific_long@1`, `time_zone/specific_short@1`, `time_zone/metazone_period@1`.
     ///
     /// Furthermore, based on the type of calendar used, one of the following data keys may be necessary:
     ///
     /// - `u-ca-japanese` (Japanese calendar): `calendar/japanese@1`
+    /// - no `u-ca` keyword: `calendar/preferences@1`, see
+    ///   [`AnyCalendar::try_new_for_locale_with_preferences_unstable()`]
     ///
     /// ```rust
     /// use icu::calendar::Gregorian;
@@ -8,8 +8,20 @@ use alloc::string::ToString;
 use core::any;
 use icu_calendar::any_calendar::AnyCalendarKind;
 use icu_calendar::{
-    buddhist::Buddhist, coptic::Coptic, ethiopian::Ethiopian, indian::Indian, japanese::Japanese,
-    japanese::JapaneseExtended, Gregorian,
+    buddhist::Buddhist,
+    chinese::Chinese,
+    coptic::Coptic,
+    dangi::Dangi,
+    ethiopian::Ethiopian,
+    hebrew::Hebrew,
+    indian::Indian,
+    islamic::{IslamicCivil, IslamicTabular, IslamicUmmAlQura},
+    japanese::Japanese,
+    japanese::JapaneseExtended,
+    julian_gregorian::JulianGregorian,
+    persian::Persian,
+    roc::Roc,
+    Gregorian,
 };
 use icu_locid::extensions::unicode::Value;
 use icu_locid::extensions_unicode_key as key;
@@ -47,6 +59,13 @@ impl CldrCalendar for Gregorian {
     type DateLengthsV1Marker = GregorianDateLengthsV1Marker;
 }
 
+/// The hybrid Julian/Gregorian calendar is formatted like the Gregorian calendar
+impl CldrCalendar for JulianGregorian {
+    const DEFAULT_BCP_47_IDENTIFIER: Value = value!("gregory");
+    type DateSymbolsV1Marker = GregorianDateSymbolsV1Marker;
+    type DateLengthsV1Marker = GregorianDateLengthsV1Marker;
+}
+
 impl CldrCalendar for Buddhist {
     const DEFAULT_BCP_47_IDENTIFIER: Value = value!("buddhist");
     type DateSymbolsV1Marker = BuddhistDateSymbolsV1Marker;
@@ -71,12 +90,76 @@ impl CldrCalendar for Coptic {
     type DateLengthsV1Marker = CopticDateLengthsV1Marker;
 }
 
+impl CldrCalendar for Chinese {
+    const DEFAULT_BCP_47_IDENTIFIER: Value = value!("chinese");
+    type DateSymbolsV1Marker = ChineseDateSymbolsV1Marker;
+    type DateLengthsV1Marker = ChineseDateLengthsV1Marker;
+}
+
+impl CldrCalendar for Dangi {
+    const DEFAULT_BCP_47_IDENTIFIER: Value = value!("dangi");
+    type DateSymbolsV1Marker = DangiDateSymbolsV1Marker;
+    type DateLengthsV1Marker = DangiDateLengthsV1Marker;
+}
+
+impl CldrCalendar for Hebrew {
+    const DEFAULT_BCP_47_IDENTIFIER: Value = value!("hebrew");
+    type DateSymbolsV1Marker = HebrewDateSymbolsV1Marker;
+    type DateLengthsV1Marker = HebrewDateLengthsV1Marker;
+}
+
+impl CldrCalendar for Persian {
+    const DEFAULT_BCP_47_IDENTIFIER: Value = value!("persian");
+    type DateSymbolsV1Marker = PersianDateSymbolsV1Marker;
+    type DateLengthsV1Marker = PersianDateLengthsV1Marker;
+}
+
+impl CldrCalendar for Roc {
+    const DEFAULT_BCP_47_IDENTIFIER: Value = value!("roc");
+    type DateSymbolsV1Marker = RocDateSymbolsV1Marker;
+    type DateLengthsV1Marker = RocDateLengthsV1Marker;
+}
+
 impl CldrCalendar for Indian {
     const DEFAULT_BCP_47_IDENTIFIER: Value = value!("indian");
     type DateSymbolsV1Marker = IndianDateSymbolsV1Marker;
     type DateLengthsV1Marker = IndianDateLengthsV1Marker;
 }
 
+// The Islamic calendars share their symbols and patterns, which are stored for the
+// `islamic` identifier.
+
+impl CldrCalendar for IslamicCivil {
+    const DEFAULT_BCP_47_IDENTIFIER: Value = value!("islamic");
+    type DateSymbolsV1Marker = IslamicDateSymbolsV1Marker;
+    type DateLengthsV1Marker = IslamicDateLengthsV1Marker;
+
+    fn is_identifier_allowed_for_calendar(value: &Value) -> bool {
+        value.as_tinystr_slice() == [tinystr!(8, "islamic"), tinystr!(8, "civil")]
+            || *value == value!("islamicc")
+    }
+}
+
+impl CldrCalendar for IslamicTabular {
+    const DEFAULT_BCP_47_IDENTIFIER: Value = value!("islamic");
+    type DateSymbolsV1Marker = IslamicDateSymbolsV1Marker;
+    type DateLengthsV1Marker = IslamicDateLengthsV1Marker;
+
+    fn is_identifier_allowed_for_calendar(value: &Value) -> bool {
+        value.as_tinystr_slice() == [tinystr!(8, "islamic"), tinystr!(8, "tbla")]
+    }
+}
+
+impl CldrCalendar for IslamicUmmAlQura {
+    const DEFAULT_BCP_47_IDENTIFIER: Value = value!("islamic");
+    type DateSymbolsV1Marker = IslamicDateSymbolsV1Marker;
+    type DateLengthsV1Marker = IslamicDateLengthsV1Marker;
+
+    fn is_identifier_allowed_for_calendar(value: &Value) -> bool {
+        value.as_tinystr_slice() == [tinystr!(8, "islamic"), tinystr!(8, "umalqura")]
+    }
+}
+
 impl CldrCalendar for Ethiopian {
     const DEFAULT_BCP_47_IDENTIFIER: Value = value!("ethiopic");
     type DateSymbolsV1Marker = EthiopianDateSymbolsV1Marker;
@@ -151,6 +234,12 @@ where
         + DataProvider<JapaneseDateLengthsV1Marker>
         + DataProvider<JapaneseExtendedDateLengthsV1Marker>
         + DataProvider<CopticDateLengthsV1Marker>
+        + DataProvider<ChineseDateLengthsV1Marker>
+        + DataProvider<DangiDateLengthsV1Marker>
+        + DataProvider<HebrewDateLengthsV1Marker>
+        + DataProvider<PersianDateLengthsV1Marker>
+        + DataProvider<RocDateLengthsV1Marker>
+        + DataProvider<IslamicDateLengthsV1Marker>
         + DataProvider<IndianDateLengthsV1Marker>
         + DataProvider<EthiopianDateLengthsV1Marker>
         + ?Sized,
@@ -190,6 +279,38 @@ where
                 .take_payload()?
                 .cast()
         }
+        AnyCalendarKind::Chinese => {
+            DataProvider::<<Chinese as CldrCalendar>::DateLengthsV1Marker>::load(provider, req)?
+                .take_payload()?
+                .cast()
+        }
+        AnyCalendarKind::Dangi => {
+            DataProvider::<<Dangi as CldrCalendar>::DateLengthsV1Marker>::load(provider, req)?
+                .take_payload()?
+                .cast()
+        }
+        AnyCalendarKind::Hebrew => {
+            DataProvider::<<Hebrew as CldrCalendar>::DateLengthsV1Marker>::load(provider, req)?
+                .take_payload()?
+                .cast()
+        }
+        AnyCalendarKind::Persian => {
+            DataProvider::<<Persian as CldrCalendar>::DateLengthsV1Marker>::load(provider, req)?
+                .take_payload()?
+                .cast()
+        }
+        AnyCalendarKind::Roc => {
+            DataProvider::<<Roc as CldrCalendar>::DateLengthsV1Marker>::load(provider, req)?
+                .take_payload()?
+                .cast()
+        }
+        AnyCalendarKind::IslamicCivil
+        | AnyCalendarKind::IslamicTabular
+        | AnyCalendarKind::IslamicUmmAlQura => DataProvider::<
+            <IslamicCivil as CldrCalendar>::DateLengthsV1Marker,
+        >::load(provider, req)?
+        .take_payload()?
+        .cast(),
         AnyCalendarKind::Ethiopian => {
             DataProvider::<<Ethiopian as CldrCalendar>::DateLengthsV1Marker>::load(provider, req)?
                 .take_payload()?
@@ -221,6 +342,12 @@ where
         + DataProvider<JapaneseDateSymbolsV1Marker>
         + DataProvider<JapaneseExtendedDateSymbolsV1Marker>
         + DataProvider<CopticDateSymbolsV1Marker>
+        + DataProvider<ChineseDateSymbolsV1Marker>
+        + DataProvider<DangiDateSymbolsV1Marker>
+        + DataProvider<HebrewDateSymbolsV1Marker>
+        + DataProvider<PersianDateSymbolsV1Marker>
+        + DataProvider<RocDateSymbolsV1Marker>
+        + DataProvider<IslamicDateSymbolsV1Marker>
         + DataProvider<IndianDateSymbolsV1Marker>
         + DataProvider<EthiopianDateSymbolsV1Marker>
         + ?Sized,
@@ -260,6 +387,38 @@ where
                 .take_payload()?
                 .cast()
         }
+        AnyCalendarKind::Chinese => {
+            DataProvider::<<Chinese as CldrCalendar>::DateSymbolsV1Marker>::load(provider, req)?
+                .take_payload()?
+                .cast()
+        }
+        AnyCalendarKind::Dangi => {
+            DataProvider::<<Dangi as CldrCalendar>::DateSymbolsV1Marker>::load(provider, req)?
+                .take_payload()?
+                .cast()
+        }
+        AnyCalendarKind::Hebrew => {
+            DataProvider::<<Hebrew as CldrCalendar>::DateSymbolsV1Marker>::load(provider, req)?
+                .take_payload()?
+                .cast()
+        }
+        AnyCalendarKind::Persian => {
+            DataProvider::<<Persian as CldrCalendar>::DateSymbolsV1Marker>::load(provider, req)?
+                .take_payload()?
+                .cast()
+        }
+        AnyCalendarKind::Roc => {
+            DataProvider::<<Roc as CldrCalendar>::DateSymbolsV1Marker>::load(provider, req)?
+                .take_payload()?
+                .cast()
+        }
+        AnyCalendarKind::IslamicCivil
+        | AnyCalendarKind::IslamicTabular
+        | AnyCalendarKind::IslamicUmmAlQura => DataProvider::<
+            <IslamicCivil as CldrCalendar>::DateSymbolsV1Marker,
+        >::load(provider, req)?
+        .take_payload()?
+        .cast(),
         AnyCalendarKind::Ethiopian => {
             DataProvider::<<Ethiopian as CldrCalendar>::DateSymbolsV1Marker>::load(provider, req)?
                 .take_payload()?
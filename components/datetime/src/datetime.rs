@@ -6,21 +6,24 @@
 //! Central to this is the [`TypedDateTimeFormatter`].
 
 use crate::{
-    options::{length, preferences, DateTimeFormatterOptions},
-    provider::calendar::{TimeLengthsV1Marker, TimeSymbolsV1Marker},
-    provider::date_time::PatternSelector,
-    provider::week_data::WeekDataV1Marker,
+    options::{length, DateTimeFormatterOptions},
+    pattern::{reference, runtime, runtime::PatternPlurals, PatternItem},
+    provider::calendar::{patterns::PatternPluralsV1, TimeLengthsV1Marker, TimeSymbolsV1Marker},
+    provider::date_time::{self, PatternSelector},
+    provider::time_data::TimeDataV1Marker,
+    provider::week_data::{WeekDataV1, WeekDataV1Marker},
     raw,
 };
 use alloc::string::String;
 use core::marker::PhantomData;
+use icu_calendar::{types::IsoWeekday, week_of::CalendarInfo, AsCalendar, Calendar, DateTime};
 use icu_decimal::provider::DecimalSymbolsV1Marker;
 use icu_plurals::provider::OrdinalV1Marker;
 use icu_provider::prelude::*;
 
 use crate::{
     calendar, input::DateInput, input::DateTimeInput, input::IsoTimeInput, CldrCalendar,
-    DateTimeFormatterError, FormattedDateTime,
+    DateTimeFormatterError, FormattedDateTime, Leniency,
 };
 
 #[cfg(feature = "experimental")]
@@ -94,11 +97,12 @@ impl TimeFormatter {
     ) -> Result<Self, DateTimeFormatterError>
     where
         D: DataProvider<TimeLengthsV1Marker>
+            + DataProvider<TimeDataV1Marker>
             + DataProvider<TimeSymbolsV1Marker>
             + DataProvider<DecimalSymbolsV1Marker>
             + ?Sized,
     {
-        let preferences = Some(preferences::Bag::from_data_locale(locale));
+        let preferences = Some(date_time::preferences_for_locale(data_provider, locale)?);
 
         Ok(Self(raw::TimeFormatter::try_new(
             data_provider,
@@ -496,7 +500,9 @@ where {
             + DataProvider<<C as CldrCalendar>::DateLengthsV1Marker>
             + DataProvider<TimeSymbolsV1Marker>
             + DataProvider<TimeLengthsV1Marker>
+            + DataProvider<TimeDataV1Marker>
             + DataProvider<crate::provider::calendar::DateSkeletonPatternsV1Marker>
+            + DataProvider<crate::provider::calendar::DateAppendItemsV1Marker>
             + DataProvider<DecimalSymbolsV1Marker>
             + DataProvider<OrdinalV1Marker>
             + DataProvider<WeekDataV1Marker>
@@ -534,6 +540,7 @@ where {
             + DataProvider<<C as CldrCalendar>::DateLengthsV1Marker>
             + DataProvider<TimeSymbolsV1Marker>
             + DataProvider<TimeLengthsV1Marker>
+            + DataProvider<TimeDataV1Marker>
             + DataProvider<DecimalSymbolsV1Marker>
             + DataProvider<OrdinalV1Marker>
             + DataProvider<WeekDataV1Marker>
@@ -563,6 +570,194 @@ where {
         error: DateTimeFormatterError
     );
 
+    /// Constructor that takes a selected locale, reference to a [data provider] and a pattern
+    /// in the [CLDR pattern syntax], then collects the data needed to format date and time
+    /// values with that pattern in the given locale.
+    ///
+    /// The pattern is used as-is: it is not adjusted to the hour cycle or field order of the
+    /// locale. Only the symbol data that the fields of the pattern need is loaded.
+    ///
+    /// Returns [`DateTimeFormatterError::Pattern`] if the pattern cannot be parsed or has a
+    /// field of a length that is not defined for it, such as "ddd", and
+    /// [`DateTimeFormatterError::UnsupportedField`] if it contains a field that this formatter
+    /// cannot format, such as a time zone.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::calendar::{DateTime, Gregorian};
+    /// use icu::datetime::{DateTimeFormatterError, TypedDateTimeFormatter};
+    /// use icu::locid::locale;
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let provider = icu_testdata::get_provider();
+    ///
+    /// let dtf = TypedDateTimeFormatter::<Gregorian>::try_new_with_pattern_with_buffer_provider(
+    ///     &provider,
+    ///     &locale!("fr").into(),
+    ///     "EEE, d MMM y HH:mm",
+    /// )
+    /// .expect("Failed to create TypedDateTimeFormatter instance.");
+    ///
+    /// let datetime = DateTime::new_gregorian_datetime(2020, 9, 1, 12, 34, 28)
+    ///     .expect("Failed to construct DateTime.");
+    ///
+    /// assert_writeable_eq!(dtf.format(&datetime), "mar., 1 sept. 2020 12:34");
+    ///
+    /// assert!(matches!(
+    ///     TypedDateTimeFormatter::<Gregorian>::try_new_with_pattern_with_buffer_provider(
+    ///         &provider,
+    ///         &locale!("fr").into(),
+    ///         "HH:mm zzzz",
+    ///     ),
+    ///     Err(DateTimeFormatterError::UnsupportedField(_))
+    /// ));
+    /// ```
+    ///
+    /// [data provider]: icu_provider
+    /// [CLDR pattern syntax]: https://unicode.org/reports/tr35/tr35-dates.html#Date_Field_Symbol_Table
+    #[inline]
+    pub fn try_new_with_pattern_unstable<D>(
+        data_provider: &D,
+        locale: &DataLocale,
+        pattern: &str,
+    ) -> Result<Self, DateTimeFormatterError>
+    where
+        D: DataProvider<<C as CldrCalendar>::DateSymbolsV1Marker>
+            + DataProvider<TimeSymbolsV1Marker>
+            + DataProvider<TimeLengthsV1Marker>
+            + DataProvider<TimeDataV1Marker>
+            + DataProvider<DecimalSymbolsV1Marker>
+            + DataProvider<OrdinalV1Marker>
+            + DataProvider<WeekDataV1Marker>
+            + ?Sized,
+    {
+        calendar::check_locale::<C>(locale)?;
+        let pattern = pattern.parse::<reference::Pattern>()?;
+        for item in pattern.items() {
+            if let PatternItem::Field(field) = item {
+                crate::format::datetime::check_field(*field)?;
+            }
+        }
+        let patterns = DataPayload::from_owned(PatternPluralsV1(PatternPlurals::from(
+            runtime::Pattern::from(&pattern),
+        )));
+        Ok(Self(
+            raw::DateTimeFormatter::try_new(
+                data_provider,
+                patterns,
+                || calendar::load_symbols_for_cldr_calendar::<C, _>(data_provider, locale),
+                locale,
+            )?,
+            PhantomData,
+        ))
+    }
+
+    /// Create a new instance using an [`AnyProvider`](icu_provider::AnyProvider).
+    ///
+    /// See also: [`Self::try_new_with_pattern_unstable`]
+    pub fn try_new_with_pattern_with_any_provider(
+        provider: &(impl AnyProvider + ?Sized),
+        locale: &DataLocale,
+        pattern: &str,
+    ) -> Result<Self, DateTimeFormatterError> {
+        Self::try_new_with_pattern_unstable(&provider.as_downcasting(), locale, pattern)
+    }
+
+    /// Create a new instance using a [`BufferProvider`](icu_provider::BufferProvider). Enabled with the `"serde"` feature.
+    ///
+    /// See also: [`Self::try_new_with_pattern_unstable`]
+    #[cfg(feature = "serde")]
+    pub fn try_new_with_pattern_with_buffer_provider(
+        provider: &(impl BufferProvider + ?Sized),
+        locale: &DataLocale,
+        pattern: &str,
+    ) -> Result<Self, DateTimeFormatterError> {
+        Self::try_new_with_pattern_unstable(&provider.as_deserializing(), locale, pattern)
+    }
+
+    /// Constructor that takes a selected locale and reference to a [data provider], then
+    /// collects the data needed to format dates as [ISO 8601 week dates], such as "2023-W05-3"
+    /// for the Wednesday of the fifth week of 2023.
+    ///
+    /// The week-based year, week number and weekday number follow the ISO 8601 rules, in which
+    /// weeks start on Monday and the first week of a year is the one with at least four of its
+    /// days in that year, regardless of the week data of the locale. The numbers use the digits
+    /// of the locale.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::calendar::{DateTime, Gregorian};
+    /// use icu::datetime::TypedDateTimeFormatter;
+    /// use icu::locid::locale;
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let provider = icu_testdata::get_provider();
+    ///
+    /// let dtf = TypedDateTimeFormatter::<Gregorian>::try_new_iso_week_date_with_buffer_provider(
+    ///     &provider,
+    ///     &locale!("en").into(),
+    /// )
+    /// .expect("Failed to create TypedDateTimeFormatter instance.");
+    ///
+    /// let datetime = DateTime::new_gregorian_datetime(2023, 2, 1, 12, 34, 28)
+    ///     .expect("Failed to construct DateTime.");
+    /// assert_writeable_eq!(dtf.format(&datetime), "2023-W05-3");
+    ///
+    /// // The first days of 2021 are in the last week of 2020.
+    /// let datetime = DateTime::new_gregorian_datetime(2021, 1, 1, 12, 34, 28)
+    ///     .expect("Failed to construct DateTime.");
+    /// assert_writeable_eq!(dtf.format(&datetime), "2020-W53-5");
+    /// ```
+    ///
+    /// [data provider]: icu_provider
+    /// [ISO 8601 week dates]: https://en.wikipedia.org/wiki/ISO_week_date
+    #[inline]
+    pub fn try_new_iso_week_date_unstable<D>(
+        data_provider: &D,
+        locale: &DataLocale,
+    ) -> Result<Self, DateTimeFormatterError>
+    where
+        D: DataProvider<<C as CldrCalendar>::DateSymbolsV1Marker>
+            + DataProvider<TimeSymbolsV1Marker>
+            + DataProvider<TimeLengthsV1Marker>
+            + DataProvider<TimeDataV1Marker>
+            + DataProvider<DecimalSymbolsV1Marker>
+            + DataProvider<OrdinalV1Marker>
+            + DataProvider<WeekDataV1Marker>
+            + ?Sized,
+    {
+        let mut formatter =
+            Self::try_new_with_pattern_unstable(data_provider, locale, "YYYY-'W'ww-e")?;
+        formatter.0.week_data = Some(DataPayload::from_owned(WeekDataV1(CalendarInfo {
+            first_weekday: IsoWeekday::Monday,
+            min_week_days: 4,
+        })));
+        Ok(formatter)
+    }
+
+    /// Create a new instance using an [`AnyProvider`](icu_provider::AnyProvider).
+    ///
+    /// See also: [`Self::try_new_iso_week_date_unstable`]
+    pub fn try_new_iso_week_date_with_any_provider(
+        provider: &(impl AnyProvider + ?Sized),
+        locale: &DataLocale,
+    ) -> Result<Self, DateTimeFormatterError> {
+        Self::try_new_iso_week_date_unstable(&provider.as_downcasting(), locale)
+    }
+
+    /// Create a new instance using a [`BufferProvider`](icu_provider::BufferProvider). Enabled with the `"serde"` feature.
+    ///
+    /// See also: [`Self::try_new_iso_week_date_unstable`]
+    #[cfg(feature = "serde")]
+    pub fn try_new_iso_week_date_with_buffer_provider(
+        provider: &(impl BufferProvider + ?Sized),
+        locale: &DataLocale,
+    ) -> Result<Self, DateTimeFormatterError> {
+        Self::try_new_iso_week_date_unstable(&provider.as_deserializing(), locale)
+    }
+
     /// Takes a [`DateTimeInput`] implementer and returns an instance of a [`FormattedDateTime`]
     /// that contains all information necessary to display a formatted date and operate on it.
     ///
@@ -650,6 +845,100 @@ where {
         self.0.format_to_string(value)
     }
 
+    /// Reads a string that follows the pattern of this [`TypedDateTimeFormatter`] back into a
+    /// [`DateTime`] in `calendar`.
+    ///
+    /// Month, weekday, quarter, era and day period names are read from the same data that is
+    /// used for formatting, and so are the digits of the numbering system. Fields that are not
+    /// part of the pattern are taken from 1970-01-01T00:00, and two-digit years are placed in
+    /// the hundred years starting at 1950; use
+    /// [`parse_with_two_digit_year_start()`](Self::parse_with_two_digit_year_start) to choose
+    /// another start. See [`Leniency`] for how closely the input has to follow the pattern.
+    ///
+    /// Week, day of year and time zone fields cannot be parsed, and return
+    /// [`DateTimeFormatterError::UnsupportedField`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::calendar::{DateTime, Gregorian};
+    /// use icu::datetime::{options::length, Leniency, TypedDateTimeFormatter};
+    /// use icu::locid::locale;
+    /// let provider = icu_testdata::get_provider();
+    /// let options =
+    ///     length::Bag::from_date_time_style(length::Date::Long, length::Time::Short);
+    /// let dtf = TypedDateTimeFormatter::<Gregorian>::try_new_with_buffer_provider(
+    ///     &provider,
+    ///     &locale!("en").into(),
+    ///     options.into(),
+    /// )
+    /// .expect("Failed to create TypedDateTimeFormatter instance.");
+    ///
+    /// let datetime = dtf
+    ///     .parse("September 1, 2020 at 12:34 PM", Leniency::Strict, Gregorian)
+    ///     .expect("The input follows the pattern");
+    ///
+    /// assert_eq!(
+    ///     datetime,
+    ///     DateTime::new_gregorian_datetime(2020, 9, 1, 12, 34, 0).unwrap()
+    /// );
+    /// ```
+    ///
+    /// [`DateTime`]: icu_calendar::DateTime
+    pub fn parse<A: AsCalendar<Calendar = C>>(
+        &self,
+        input: &str,
+        leniency: Leniency,
+        calendar: A,
+    ) -> Result<DateTime<A>, DateTimeFormatterError>
+    where
+        C: Calendar,
+    {
+        self.0.parse(input, leniency, None, calendar)
+    }
+
+    /// Like [`parse()`](Self::parse), but places two-digit years in the hundred years starting
+    /// at `two_digit_year_start` of `calendar`, for example twenty years before the current year.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::calendar::{DateTime, Gregorian};
+    /// use icu::datetime::{options::length, Leniency, TypedDateTimeFormatter};
+    /// use icu::locid::locale;
+    ///
+    /// let provider = icu_testdata::get_provider();
+    /// let options = length::Bag::from_date_style(length::Date::Short);
+    /// let dtf = TypedDateTimeFormatter::<Gregorian>::try_new_with_buffer_provider(
+    ///     &provider,
+    ///     &locale!("en").into(),
+    ///     options.into(),
+    /// )
+    /// .expect("Failed to create TypedDateTimeFormatter instance.");
+    ///
+    /// let datetime = dtf
+    ///     .parse_with_two_digit_year_start("1/5/55", Leniency::Strict, 2006, Gregorian)
+    ///     .expect("The input follows the pattern");
+    ///
+    /// assert_eq!(
+    ///     datetime,
+    ///     DateTime::new_gregorian_datetime(2055, 1, 5, 0, 0, 0).unwrap()
+    /// );
+    /// ```
+    pub fn parse_with_two_digit_year_start<A: AsCalendar<Calendar = C>>(
+        &self,
+        input: &str,
+        leniency: Leniency,
+        two_digit_year_start: i32,
+        calendar: A,
+    ) -> Result<DateTime<A>, DateTimeFormatterError>
+    where
+        C: Calendar,
+    {
+        self.0
+            .parse(input, leniency, Some(two_digit_year_start), calendar)
+    }
+
     /// Returns a [`components::Bag`] that represents the resolved components for the
     /// options that were provided to the [`TypedDateTimeFormatter`]. The developer may request
     /// a certain set of options for a [`TypedDateTimeFormatter`] but the locale and resolution
@@ -54,6 +54,9 @@ pub enum DateTimeFormatterError {
     /// An error originating from a missing month symbol in the data.
     #[displaydoc("Data file missing month symbol for month code {0}")]
     MissingMonthSymbol(MonthCode),
+    /// An error originating from a missing quarter symbol in the data.
+    #[displaydoc("Data file missing quarter symbol for quarter {0}")]
+    MissingQuarterSymbol(usize),
     /// An error while attempting to format the input as a FixedDecimal
     #[displaydoc("FixedDecimal")]
     FixedDecimal,
@@ -77,6 +80,9 @@ pub enum DateTimeFormatterError {
     /// ordinal_rules must be set for PatternPlurals::MultipleVariants
     #[displaydoc("ordinal_rules must be set for PatternPlurals::MultipleVariants")]
     MissingOrdinalRules,
+    /// The input of a `parse` call does not match the pattern of the formatter
+    #[displaydoc("Input does not match the pattern at byte offset {0}")]
+    ParseMismatch(usize),
 }
 
 #[cfg(feature = "std")]
@@ -60,6 +60,7 @@ impl Field {
         match self.symbol {
             FieldSymbol::Era => TextOrNumeric::Text,
             FieldSymbol::Year(year) => year.get_length_type(self.length),
+            FieldSymbol::Quarter(quarter) => quarter.get_length_type(self.length),
             FieldSymbol::Month(month) => month.get_length_type(self.length),
             FieldSymbol::Week(week) => week.get_length_type(self.length),
             FieldSymbol::Day(day) => day.get_length_type(self.length),
@@ -58,6 +58,9 @@ pub enum FieldSymbol {
     Second(Second),
     /// Time zone as a name, a zone ID, or a ISO 8601 numerical offset.
     TimeZone(TimeZone),
+    /// Quarter number or quarter name.
+    // Added last to keep the serialized variant indices of the other symbols stable.
+    Quarter(Quarter),
 }
 
 impl FieldSymbol {
@@ -109,6 +112,7 @@ impl FieldSymbol {
             FieldSymbol::Minute => (8, 0),
             FieldSymbol::Second(second) => (9, second.idx()),
             FieldSymbol::TimeZone(tz) => (10, tz.idx()),
+            FieldSymbol::Quarter(quarter) => (11, quarter.idx()),
         };
         let result = high << 4;
         result | low
@@ -133,6 +137,7 @@ impl FieldSymbol {
             8 if low == 0 => Self::Minute,
             9 => Self::Second(Second::from_idx(low)?),
             10 => Self::TimeZone(TimeZone::from_idx(low)?),
+            11 => Self::Quarter(Quarter::from_idx(low)?),
             _ => return Err(SymbolError::InvalidIndex(idx)),
         })
     }
@@ -143,15 +148,16 @@ impl FieldSymbol {
         match self {
             FieldSymbol::Era => 0,
             FieldSymbol::Year(_) => 1,
-            FieldSymbol::Month(_) => 2,
-            FieldSymbol::Week(_) => 3,
-            FieldSymbol::Day(_) => 4,
-            FieldSymbol::Weekday(_) => 5,
-            FieldSymbol::DayPeriod(_) => 6,
-            FieldSymbol::Hour(_) => 7,
-            FieldSymbol::Minute => 8,
-            FieldSymbol::Second(_) => 9,
-            FieldSymbol::TimeZone(_) => 10,
+            FieldSymbol::Quarter(_) => 2,
+            FieldSymbol::Month(_) => 3,
+            FieldSymbol::Week(_) => 4,
+            FieldSymbol::Day(_) => 5,
+            FieldSymbol::Weekday(_) => 6,
+            FieldSymbol::DayPeriod(_) => 7,
+            FieldSymbol::Hour(_) => 8,
+            FieldSymbol::Minute => 9,
+            FieldSymbol::Second(_) => 10,
+            FieldSymbol::TimeZone(_) => 11,
         }
     }
 
@@ -233,34 +239,38 @@ impl FieldSymbol {
             Self::Era => 0,
             Self::Year(Year::Calendar) => 1,
             Self::Year(Year::WeekOf) => 2,
-            Self::Month(Month::Format) => 3,
-            Self::Month(Month::StandAlone) => 4,
-            Self::Week(Week::WeekOfYear) => 5,
-            Self::Week(Week::WeekOfMonth) => 6,
-            Self::Day(Day::DayOfMonth) => 7,
-            Self::Day(Day::DayOfYear) => 8,
-            Self::Day(Day::DayOfWeekInMonth) => 9,
-            Self::Day(Day::ModifiedJulianDay) => 10,
-            Self::Weekday(Weekday::Format) => 11,
-            Self::Weekday(Weekday::Local) => 12,
-            Self::Weekday(Weekday::StandAlone) => 13,
-            Self::DayPeriod(DayPeriod::AmPm) => 14,
-            Self::DayPeriod(DayPeriod::NoonMidnight) => 15,
-            Self::Hour(Hour::H11) => 16,
-            Self::Hour(Hour::H12) => 17,
-            Self::Hour(Hour::H23) => 18,
-            Self::Hour(Hour::H24) => 19,
-            Self::Minute => 20,
-            Self::Second(Second::Second) => 21,
-            Self::Second(Second::FractionalSecond) => 22,
-            Self::Second(Second::Millisecond) => 23,
-            Self::TimeZone(TimeZone::LowerZ) => 24,
-            Self::TimeZone(TimeZone::UpperZ) => 25,
-            Self::TimeZone(TimeZone::UpperO) => 26,
-            Self::TimeZone(TimeZone::LowerV) => 27,
-            Self::TimeZone(TimeZone::UpperV) => 28,
-            Self::TimeZone(TimeZone::LowerX) => 29,
-            Self::TimeZone(TimeZone::UpperX) => 30,
+            Self::Year(Year::Cyclic) => 3,
+            Self::Year(Year::RelatedIso) => 4,
+            Self::Quarter(Quarter::Format) => 5,
+            Self::Quarter(Quarter::StandAlone) => 6,
+            Self::Month(Month::Format) => 7,
+            Self::Month(Month::StandAlone) => 8,
+            Self::Week(Week::WeekOfYear) => 9,
+            Self::Week(Week::WeekOfMonth) => 10,
+            Self::Day(Day::DayOfMonth) => 11,
+            Self::Day(Day::DayOfYear) => 12,
+            Self::Day(Day::DayOfWeekInMonth) => 13,
+            Self::Day(Day::ModifiedJulianDay) => 14,
+            Self::Weekday(Weekday::Format) => 15,
+            Self::Weekday(Weekday::Local) => 16,
+            Self::Weekday(Weekday::StandAlone) => 17,
+            Self::DayPeriod(DayPeriod::AmPm) => 18,
+            Self::DayPeriod(DayPeriod::NoonMidnight) => 19,
+            Self::Hour(Hour::H11) => 20,
+            Self::Hour(Hour::H12) => 21,
+            Self::Hour(Hour::H23) => 22,
+            Self::Hour(Hour::H24) => 23,
+            Self::Minute => 24,
+            Self::Second(Second::Second) => 25,
+            Self::Second(Second::FractionalSecond) => 26,
+            Self::Second(Second::Millisecond) => 27,
+            Self::TimeZone(TimeZone::LowerZ) => 28,
+            Self::TimeZone(TimeZone::UpperZ) => 29,
+            Self::TimeZone(TimeZone::UpperO) => 30,
+            Self::TimeZone(TimeZone::LowerV) => 31,
+            Self::TimeZone(TimeZone::UpperV) => 32,
+            Self::TimeZone(TimeZone::LowerX) => 33,
+            Self::TimeZone(TimeZone::UpperX) => 34,
         }
     }
 }
@@ -278,6 +288,7 @@ impl TryFrom<char> for FieldSymbol {
             Err(SymbolError::Unknown(ch))
         })
         .or_else(|_| Year::try_from(ch).map(Self::Year))
+        .or_else(|_| Quarter::try_from(ch).map(Self::Quarter))
         .or_else(|_| Month::try_from(ch).map(Self::Month))
         .or_else(|_| Week::try_from(ch).map(Self::Week))
         .or_else(|_| Day::try_from(ch).map(Self::Day))
@@ -301,6 +312,7 @@ impl From<FieldSymbol> for char {
         match symbol {
             FieldSymbol::Era => 'G',
             FieldSymbol::Year(year) => year.into(),
+            FieldSymbol::Quarter(quarter) => quarter.into(),
             FieldSymbol::Month(month) => month.into(),
             FieldSymbol::Week(week) => week.into(),
             FieldSymbol::Day(day) => day.into(),
@@ -445,11 +457,41 @@ field_type! (
         ///
         /// This works for “week of year” based calendars in which the year transition occurs on a week boundary; may differ from calendar year [`Year::Calendar`] near a year transition. This numeric year designation is used in conjunction with [`Week::WeekOfYear`], but can be used in non-Gregorian based calendar systems where week date processing is desired. The field length is interpreted in the same way as for [`Year::Calendar`].
         'Y' => WeekOf = 1,
+        /// Field symbol for the year in the 60-year cycle of calendars like the Chinese calendar.
+        ///
+        /// The year is formatted with its name in the cycle, or as its number in the cycle, from 1
+        /// to 60, for locales without cyclic year names.
+        'U' => Cyclic = 2,
+        /// Field symbol for the related ISO year of calendars like the Chinese calendar.
+        ///
+        /// This is the ISO year in which the calendar year begins. The field length is
+        /// interpreted in the same way as for [`Year::Calendar`].
+        'r' => RelatedIso = 3,
     };
     Numeric;
     YearULE
 );
 
+field_type!(
+    /// An enum for the possible symbols of a quarter field in a date pattern.
+    Quarter; {
+        /// Field symbol for quarter number or name in a pattern that contains multiple fields.
+        'Q' => Format = 0,
+        /// Field symbol for a "stand-alone" quarter number or name.
+        ///
+        /// The stand-alone quarter name is used when the quarter is displayed by itself. This may differ from the standard form based on the language and context.
+        'q' => StandAlone = 1,
+}; QuarterULE);
+
+impl LengthType for Quarter {
+    fn get_length_type(&self, length: FieldLength) -> TextOrNumeric {
+        match length {
+            FieldLength::One | FieldLength::TwoDigit => TextOrNumeric::Numeric,
+            _ => TextOrNumeric::Text,
+        }
+    }
+}
+
 field_type!(
     /// An enum for the possible symbols of a month field in a date pattern.
     Month; {
@@ -568,8 +610,8 @@ impl LengthType for Weekday {
         match self {
             Self::Format => TextOrNumeric::Text,
             Self::Local | Self::StandAlone => match length {
-                FieldLength::One | FieldLength::TwoDigit => TextOrNumeric::Text,
-                _ => TextOrNumeric::Numeric,
+                FieldLength::One | FieldLength::TwoDigit => TextOrNumeric::Numeric,
+                _ => TextOrNumeric::Text,
             },
         }
     }
@@ -16,12 +16,19 @@ use crate::provider::calendar::patterns::PatternPluralsFromPatternsV1Marker;
 use crate::provider::date_time::{DateSymbols, TimeSymbols};
 use crate::provider::week_data::WeekDataV1;
 
-use core::fmt;
+use crate::parts;
+use core::fmt::{self, Write};
+use core::iter::Peekable;
 use fixed_decimal::FixedDecimal;
+use icu_calendar::types::FormattableMonth;
 use icu_decimal::FixedDecimalFormatter;
 use icu_plurals::PluralRules;
 use icu_provider::DataPayload;
-use writeable::Writeable;
+use writeable::{PartsWrite, Writeable};
+
+/// The Julian day number of R.D. 0, so that adding a fixed day number gives its Julian day number.
+/// The `g` field counts local days rather than starting them at noon UTC.
+const JULIAN_DAY_OF_RD_EPOCH: i64 = 1_721_425;
 
 /// [`FormattedDateTime`] is a intermediate structure which can be retrieved as
 /// an output from [`TypedDateTimeFormatter`](crate::TypedDateTimeFormatter).
@@ -59,7 +66,7 @@ pub struct FormattedDateTime<'l> {
 }
 
 impl<'l> Writeable for FormattedDateTime<'l> {
-    fn write_to<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+    fn write_to_parts<S: PartsWrite + ?Sized>(&self, sink: &mut S) -> fmt::Result {
         write_pattern_plurals(
             &self.patterns.get().0,
             self.date_symbols,
@@ -92,11 +99,45 @@ fn format_number<W>(
 where
     W: fmt::Write + ?Sized,
 {
+    apply_length(&mut num, length);
+    let formatted = fixed_decimal_format.format(&num);
+    formatted.write_to(result)
+}
+
+// Like `format_number`, but a two-digit year keeps only its two low-order digits.
+fn format_year<W>(
+    result: &mut W,
+    fixed_decimal_format: &FixedDecimalFormatter,
+    mut num: FixedDecimal,
+    length: FieldLength,
+) -> fmt::Result
+where
+    W: fmt::Write + ?Sized,
+{
+    if length == FieldLength::TwoDigit {
+        num.set_max_position(2);
+    }
+    format_number(result, fixed_decimal_format, num, length)
+}
+
+// A month falls in a leap year when an earlier leap month has pushed its ordinal past the
+// number in its code, e.g. Adar II (`M06`) is the seventh month of a Hebrew leap year.
+fn is_in_leap_year(month: FormattableMonth) -> bool {
+    month
+        .code
+        .0
+        .get(1..3)
+        .and_then(|number| number.parse::<u32>().ok())
+        .map(|number| month.ordinal > number)
+        .unwrap_or(false)
+}
+
+// Pad the integer digits of a number to the width of the field.
+fn apply_length(num: &mut FixedDecimal, length: FieldLength) {
     match length {
         FieldLength::One => {}
         FieldLength::TwoDigit => {
             num.pad_start(2);
-            num.set_max_position(2);
         }
         FieldLength::Abbreviated => {
             num.pad_start(3);
@@ -115,9 +156,6 @@ where
             num.set_max_position(p as i16);
         }
     }
-
-    let formatted = fixed_decimal_format.format(&num);
-    formatted.write_to(result)
 }
 
 fn write_pattern<T, W>(
@@ -130,12 +168,12 @@ fn write_pattern<T, W>(
 ) -> Result<(), Error>
 where
     T: DateTimeInput,
-    W: fmt::Write + ?Sized,
+    W: PartsWrite + ?Sized,
 {
     let mut iter = pattern.items.iter().peekable();
     loop {
         match iter.next() {
-            Some(PatternItem::Field(field)) => write_field(
+            Some(PatternItem::Field(field)) => write_field_parts(
                 pattern,
                 field,
                 iter.peek(),
@@ -145,13 +183,171 @@ where
                 fixed_decimal_format,
                 w,
             )?,
-            Some(PatternItem::Literal(ch)) => w.write_char(ch)?,
+            Some(PatternItem::Literal(ch)) => write_literals(ch, &mut iter, w)?,
             None => break,
         }
     }
     Ok(())
 }
 
+/// Writes `first` and the literals that directly follow it as a single [`parts::LITERAL`].
+pub(super) fn write_literals<W>(
+    first: char,
+    iter: &mut Peekable<impl Iterator<Item = PatternItem>>,
+    w: &mut W,
+) -> fmt::Result
+where
+    W: PartsWrite + ?Sized,
+{
+    w.with_part(parts::LITERAL, |w| {
+        w.write_char(first)?;
+        while let Some(&PatternItem::Literal(ch)) = iter.peek() {
+            w.write_char(ch)?;
+            iter.next();
+        }
+        Ok(())
+    })
+}
+
+/// The [`Part`](writeable::Part) that the output of a field is tagged with.
+pub(super) fn field_part(symbol: FieldSymbol) -> writeable::Part {
+    match symbol {
+        FieldSymbol::Era => parts::ERA,
+        FieldSymbol::Year(_) => parts::YEAR,
+        FieldSymbol::Quarter(_) => parts::QUARTER,
+        FieldSymbol::Month(_) => parts::MONTH,
+        FieldSymbol::Week(_) => parts::WEEK,
+        FieldSymbol::Day(_) => parts::DAY,
+        FieldSymbol::Weekday(_) => parts::WEEKDAY,
+        FieldSymbol::DayPeriod(_) => parts::DAY_PERIOD,
+        FieldSymbol::Hour(_) => parts::HOUR,
+        FieldSymbol::Minute => parts::MINUTE,
+        FieldSymbol::Second(Second::Second) => parts::SECOND,
+        FieldSymbol::Second(_) => parts::FRACTIONAL_SECOND,
+        FieldSymbol::TimeZone(_) => parts::TIME_ZONE_NAME,
+    }
+}
+
+/// Writes a field tagged with its [`Part`](writeable::Part).
+///
+/// Seconds that are followed by fractional seconds are written as three parts: the whole
+/// seconds, the decimal separator as a literal, and the fractional digits.
+#[allow(clippy::too_many_arguments)]
+pub(super) fn write_field_parts<T, W>(
+    pattern: &crate::pattern::runtime::Pattern,
+    field: fields::Field,
+    next_item: Option<&PatternItem>,
+    date_symbols: Option<&crate::provider::calendar::DateSymbolsV1>,
+    time_symbols: Option<&crate::provider::calendar::TimeSymbolsV1>,
+    datetime: &impl LocalizedDateTimeInput<T>,
+    fixed_decimal_format: &FixedDecimalFormatter,
+    w: &mut W,
+) -> Result<(), Error>
+where
+    T: DateTimeInput,
+    W: PartsWrite + ?Sized,
+{
+    match field.symbol {
+        FieldSymbol::Second(Second::Second) => {
+            if let Some(mut seconds) = seconds_with_fraction(datetime, next_item)? {
+                apply_length(&mut seconds, field.length);
+                let integer_digits = (*seconds.magnitude_range().end()).max(0) as usize + 1;
+                let fraction_digits = (-*seconds.magnitude_range().start()).max(0) as usize;
+                let formatted = fixed_decimal_format.format(&seconds);
+                let formatted = formatted.write_to_string();
+                let char_count = formatted.chars().count();
+                let byte_index = |char_index: usize| {
+                    formatted
+                        .char_indices()
+                        .nth(char_index)
+                        .map_or(formatted.len(), |(i, _)| i)
+                };
+                let separator_start = byte_index(integer_digits);
+                let fraction_start = byte_index(char_count.saturating_sub(fraction_digits));
+                let (whole, fraction) = formatted.split_at(fraction_start);
+                let (whole, separator) = whole.split_at(separator_start.min(whole.len()));
+                w.with_part(parts::SECOND, |w| w.write_str(whole))?;
+                w.with_part(parts::LITERAL, |w| w.write_str(separator))?;
+                w.with_part(parts::FRACTIONAL_SECOND, |w| w.write_str(fraction))?;
+                return Ok(());
+            }
+        }
+        FieldSymbol::Second(Second::FractionalSecond) => {
+            // Formatting of fractional seconds is handled when formatting seconds.
+            return Ok(());
+        }
+        _ => {}
+    }
+    let mut result = Ok(());
+    let written = w.with_part(field_part(field.symbol), |w| {
+        result = write_field(
+            pattern,
+            field,
+            next_item,
+            date_symbols,
+            time_symbols,
+            datetime,
+            fixed_decimal_format,
+            w,
+        );
+        result.map_err(|_| fmt::Error)
+    });
+    result?;
+    Ok(written?)
+}
+
+/// Returns the seconds and nanoseconds of `datetime` as a single decimal, padded to the
+/// precision of the fractional seconds field, if `next_item` is one.
+fn seconds_with_fraction<T>(
+    datetime: &impl LocalizedDateTimeInput<T>,
+    next_item: Option<&PatternItem>,
+) -> Result<Option<FixedDecimal>, Error>
+where
+    T: DateTimeInput,
+{
+    let next_field = match next_item {
+        Some(PatternItem::Field(next_field))
+            if next_field.symbol == FieldSymbol::Second(Second::FractionalSecond) =>
+        {
+            next_field
+        }
+        _ => return Ok(None),
+    };
+    let mut seconds = FixedDecimal::from(usize::from(
+        datetime
+            .datetime()
+            .second()
+            .ok_or(Error::MissingInputField(Some("second")))?,
+    ));
+    let mut fraction = FixedDecimal::from(usize::from(
+        datetime
+            .datetime()
+            .nanosecond()
+            .ok_or(Error::MissingInputField(Some("nanosecond")))?,
+    ));
+
+    // We only support fixed field length for fractional seconds.
+    let precision = match next_field.length {
+        FieldLength::Fixed(p) => p,
+        _ => {
+            return Err(Error::Pattern(
+                crate::pattern::PatternError::FieldLengthInvalid(FieldSymbol::Second(
+                    Second::FractionalSecond,
+                )),
+            ));
+        }
+    };
+
+    // We store fractional seconds as nanoseconds, convert to seconds.
+    fraction.multiply_pow10(-9);
+
+    seconds
+        .concatenate_right(fraction)
+        .map_err(|_| Error::FixedDecimal)?;
+    seconds.pad_end(-(precision as i16));
+    Ok(Some(seconds))
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn write_pattern_plurals<T, W>(
     patterns: &PatternPlurals,
@@ -165,7 +361,7 @@ pub fn write_pattern_plurals<T, W>(
 ) -> Result<(), Error>
 where
     T: DateTimeInput,
-    W: fmt::Write + ?Sized,
+    W: PartsWrite + ?Sized,
 {
     let loc_datetime = DateTimeInputWithCalendar::new(datetime, week_data.map(|d| &d.0));
     let pattern = patterns.select(&loc_datetime, ordinal_rules)?;
@@ -212,7 +408,7 @@ where
             w.write_str(symbol)?
         }
         FieldSymbol::Year(year) => match year {
-            Year::Calendar => format_number(
+            Year::Calendar => format_year(
                 w,
                 fixed_decimal_format,
                 FixedDecimal::from(
@@ -224,13 +420,66 @@ where
                 ),
                 field.length,
             )?,
-            Year::WeekOf => format_number(
+            Year::WeekOf => format_year(
                 w,
                 fixed_decimal_format,
                 FixedDecimal::from(datetime.year_week()?.number),
                 field.length,
             )?,
+            Year::Cyclic => {
+                let cyclic = datetime
+                    .datetime()
+                    .year()
+                    .and_then(|year| year.cyclic)
+                    .ok_or(Error::MissingInputField(Some("cyclic")))?;
+                // Calendars without names for their cyclic years show the number in the cycle
+                match date_symbols
+                    .and_then(|symbols| symbols.get_symbol_for_cyclic_year(field.length, cyclic))
+                {
+                    Some(symbol) => w.write_str(symbol)?,
+                    None => format_number(
+                        w,
+                        fixed_decimal_format,
+                        FixedDecimal::from(cyclic.get()),
+                        field.length,
+                    )?,
+                }
+            }
+            Year::RelatedIso => format_number(
+                w,
+                fixed_decimal_format,
+                FixedDecimal::from(
+                    datetime
+                        .datetime()
+                        .year()
+                        .and_then(|year| year.related_iso)
+                        .ok_or(Error::MissingInputField(Some("related_iso")))?,
+                ),
+                field.length,
+            )?,
         },
+        FieldSymbol::Quarter(quarter) => {
+            let month = datetime
+                .datetime()
+                .month()
+                .ok_or(Error::MissingInputField(Some("month")))?;
+            // A thirteenth month is folded into the last quarter; see `components::Quarter`.
+            let quarter_idx = core::cmp::min(month.ordinal.saturating_sub(1) / 3, 3);
+            match field.length {
+                FieldLength::One | FieldLength::TwoDigit => format_number(
+                    w,
+                    fixed_decimal_format,
+                    FixedDecimal::from(quarter_idx + 1),
+                    field.length,
+                )?,
+                length => {
+                    let symbol = date_symbols
+                        .ok_or(Error::MissingDateSymbols)?
+                        .get_symbol_for_quarter(quarter, length, quarter_idx as usize)?;
+                    w.write_str(symbol)?
+                }
+            }
+        }
         FieldSymbol::Month(month) => match field.length {
             FieldLength::One | FieldLength::TwoDigit => format_number(
                 w,
@@ -245,16 +494,17 @@ where
                 field.length,
             )?,
             length => {
+                let formattable_month = datetime
+                    .datetime()
+                    .month()
+                    .ok_or(Error::MissingInputField(Some("month")))?;
                 let symbol = date_symbols
                     .ok_or(Error::MissingDateSymbols)?
                     .get_symbol_for_month(
                         month,
                         length,
-                        datetime
-                            .datetime()
-                            .month()
-                            .ok_or(Error::MissingInputField(Some("month")))?
-                            .code,
+                        formattable_month.code,
+                        is_in_leap_year(formattable_month),
                     )?;
                 w.write_str(symbol)?
             }
@@ -273,6 +523,20 @@ where
                 field.length,
             )?,
         },
+        FieldSymbol::Weekday(weekday @ (fields::Weekday::Local | fields::Weekday::StandAlone))
+            if matches!(field.length, FieldLength::One | FieldLength::TwoDigit) =>
+        {
+            format_number(
+                w,
+                fixed_decimal_format,
+                FixedDecimal::from(datetime.local_day_of_week()?),
+                // The stand-alone form has no zero-padded variant.
+                match weekday {
+                    fields::Weekday::StandAlone => FieldLength::One,
+                    _ => field.length,
+                },
+            )?
+        }
         FieldSymbol::Weekday(weekday) => {
             let dow = datetime
                 .datetime()
@@ -283,19 +547,33 @@ where
                 .get_symbol_for_weekday(weekday, field.length, dow)?;
             w.write_str(symbol)?
         }
-        symbol @ FieldSymbol::Day(day) => format_number(
+        FieldSymbol::Day(day) => format_number(
             w,
             fixed_decimal_format,
             FixedDecimal::from(match day {
-                fields::Day::DayOfMonth => {
+                fields::Day::DayOfMonth => i64::from(
                     datetime
                         .datetime()
                         .day_of_month()
                         .ok_or(Error::MissingInputField(Some("day_of_month")))?
-                        .0
+                        .0,
+                ),
+                fields::Day::DayOfYear => i64::from(
+                    datetime
+                        .datetime()
+                        .day_of_year_info()
+                        .ok_or(Error::MissingInputField(Some("day_of_year_info")))?
+                        .day_of_year,
+                ),
+                fields::Day::DayOfWeekInMonth => i64::from(datetime.day_of_week_in_month()?.0),
+                fields::Day::ModifiedJulianDay => {
+                    i64::from(
+                        datetime
+                            .datetime()
+                            .fixed_day()
+                            .ok_or(Error::MissingInputField(Some("fixed_day")))?,
+                    ) + JULIAN_DAY_OF_RD_EPOCH
                 }
-                fields::Day::DayOfWeekInMonth => datetime.day_of_week_in_month()?.0,
-                _ => return Err(Error::UnsupportedField(symbol)),
             }),
             field.length,
         )?,
@@ -344,42 +622,15 @@ where
             field.length,
         )?,
         FieldSymbol::Second(Second::Second) => {
-            let mut seconds = FixedDecimal::from(usize::from(
-                datetime
-                    .datetime()
-                    .second()
-                    .ok_or(Error::MissingInputField(Some("second")))?,
-            ));
-            if let Some(PatternItem::Field(next_field)) = next_item {
-                if let FieldSymbol::Second(Second::FractionalSecond) = next_field.symbol {
-                    let mut fraction = FixedDecimal::from(usize::from(
-                        datetime
-                            .datetime()
-                            .nanosecond()
-                            .ok_or(Error::MissingInputField(Some("nanosecond")))?,
-                    ));
-
-                    // We only support fixed field length for fractional seconds.
-                    let precision = match next_field.length {
-                        FieldLength::Fixed(p) => p,
-                        _ => {
-                            return Err(Error::Pattern(
-                                crate::pattern::PatternError::FieldLengthInvalid(
-                                    FieldSymbol::Second(Second::FractionalSecond),
-                                ),
-                            ));
-                        }
-                    };
-
-                    // We store fractional seconds as nanoseconds, convert to seconds.
-                    fraction.multiply_pow10(-9);
-
-                    seconds
-                        .concatenate_right(fraction)
-                        .map_err(|_| Error::FixedDecimal)?;
-                    seconds.pad_end(-(precision as i16));
-                }
-            }
+            let seconds = match seconds_with_fraction(datetime, next_item)? {
+                Some(seconds) => seconds,
+                None => FixedDecimal::from(usize::from(
+                    datetime
+                        .datetime()
+                        .second()
+                        .ok_or(Error::MissingInputField(Some("second")))?,
+                )),
+            };
             format_number(w, fixed_decimal_format, seconds, field.length)?
         }
         FieldSymbol::Second(Second::FractionalSecond) => {
@@ -440,11 +691,14 @@ impl RequiredData {
         for field in fields {
             if !self.date_symbols_data {
                 self.date_symbols_data = match field.symbol {
-                    FieldSymbol::Era => true,
-                    FieldSymbol::Month(_) => {
+                    FieldSymbol::Era | FieldSymbol::Year(Year::Cyclic) => true,
+                    FieldSymbol::Quarter(_) | FieldSymbol::Month(_) => {
+                        !matches!(field.length, FieldLength::One | FieldLength::TwoDigit)
+                    }
+                    FieldSymbol::Weekday(fields::Weekday::Format) => true,
+                    FieldSymbol::Weekday(_) => {
                         !matches!(field.length, FieldLength::One | FieldLength::TwoDigit)
                     }
-                    FieldSymbol::Weekday(_) => true,
                     _ => false,
                 }
             }
@@ -453,10 +707,13 @@ impl RequiredData {
             }
 
             if !self.week_data {
-                self.week_data = matches!(
-                    field.symbol,
-                    FieldSymbol::Year(Year::WeekOf) | FieldSymbol::Week(_)
-                )
+                self.week_data = match field.symbol {
+                    FieldSymbol::Year(Year::WeekOf) | FieldSymbol::Week(_) => true,
+                    FieldSymbol::Weekday(fields::Weekday::Local | fields::Weekday::StandAlone) => {
+                        matches!(field.length, FieldLength::One | FieldLength::TwoDigit)
+                    }
+                    _ => false,
+                }
             }
 
             if supports_time_zones {
@@ -477,6 +734,38 @@ impl RequiredData {
 }
 
 // Determines what optional data needs to be loaded to format `patterns`.
+/// Checks that [`write_field`] can format `field` at its length, following the
+/// [date field symbol table](https://unicode.org/reports/tr35/tr35-dates.html#Date_Field_Symbol_Table).
+///
+/// Time zone lengths are checked by the time zone formatter.
+pub(crate) fn check_field(field: Field) -> Result<(), Error> {
+    let max_length = match field.symbol {
+        FieldSymbol::Era
+        | FieldSymbol::Year(_)
+        | FieldSymbol::Quarter(_)
+        | FieldSymbol::Month(_)
+        | FieldSymbol::DayPeriod(_) => 5,
+        FieldSymbol::Week(Week::WeekOfYear) => 2,
+        FieldSymbol::Week(Week::WeekOfMonth) => 1,
+        FieldSymbol::Weekday(_) => 6,
+        FieldSymbol::Day(fields::Day::DayOfMonth) => 2,
+        FieldSymbol::Day(fields::Day::DayOfYear) => 3,
+        FieldSymbol::Day(fields::Day::DayOfWeekInMonth) => 1,
+        FieldSymbol::Day(fields::Day::ModifiedJulianDay) => 6,
+        FieldSymbol::Hour(_) | FieldSymbol::Minute | FieldSymbol::Second(Second::Second) => 2,
+        FieldSymbol::Second(Second::FractionalSecond) | FieldSymbol::TimeZone(_) => return Ok(()),
+        symbol @ FieldSymbol::Second(Second::Millisecond) => {
+            return Err(Error::UnsupportedField(symbol))
+        }
+    };
+    if field.length.to_len() > max_length {
+        return Err(Error::Pattern(
+            crate::pattern::PatternError::FieldLengthInvalid(field.symbol),
+        ));
+    }
+    Ok(())
+}
+
 pub fn analyze_patterns(
     patterns: &PatternPlurals,
     supports_time_zones: bool,
@@ -525,6 +814,7 @@ mod tests {
     #[test]
     #[cfg(feature = "serde")]
     fn test_basic() {
+        use crate::provider::calendar::patterns::PatternPluralsV1;
         use crate::provider::calendar::{GregorianDateSymbolsV1Marker, TimeSymbolsV1Marker};
         use icu_calendar::DateTime;
         use icu_provider::prelude::*;
@@ -539,24 +829,148 @@ mod tests {
             provider.load(req).unwrap().take_payload().unwrap();
         let time_data: DataPayload<TimeSymbolsV1Marker> =
             provider.load(req).unwrap().take_payload().unwrap();
-        let pattern = "MMM".parse().unwrap();
+        let pattern: crate::pattern::runtime::Pattern = "MMM".parse().unwrap();
+        let patterns = DataPayload::<PatternPluralsFromPatternsV1Marker>::from_owned(
+            PatternPluralsV1(pattern.into()),
+        );
+        let datetime = DateTime::new_gregorian_datetime(2020, 8, 1, 12, 34, 28).unwrap();
+        let fixed_decimal_format =
+            FixedDecimalFormatter::try_new_unstable(&provider, &locale, Default::default())
+                .unwrap();
+
+        let formatted = FormattedDateTime {
+            patterns: &patterns,
+            date_symbols: Some(date_data.get()),
+            time_symbols: Some(time_data.get()),
+            datetime: ExtractedDateTimeInput::extract_from(&datetime),
+            week_data: None,
+            ordinal_rules: None,
+            fixed_decimal_format: &fixed_decimal_format,
+        };
+        println!("{}", formatted.write_to_string());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_local_weekday() {
+        use crate::provider::calendar::patterns::PatternPluralsV1;
+        use crate::provider::calendar::GregorianDateSymbolsV1Marker;
+        use icu_calendar::types::IsoWeekday;
+        use icu_calendar::{week_of::CalendarInfo, DateTime};
+        use icu_provider::prelude::*;
+
+        let provider = icu_testdata::get_provider();
+        let locale = "en-u-ca-gregory".parse::<Locale>().unwrap().into();
+        let req = DataRequest {
+            locale: &locale,
+            metadata: Default::default(),
+        };
+        let date_data: DataPayload<GregorianDateSymbolsV1Marker> =
+            provider.load(req).unwrap().take_payload().unwrap();
+        let pattern: crate::pattern::runtime::Pattern = "e ee eee c cccc".parse().unwrap();
+        let patterns = DataPayload::<PatternPluralsFromPatternsV1Marker>::from_owned(
+            PatternPluralsV1(pattern.into()),
+        );
+        // A Saturday.
         let datetime = DateTime::new_gregorian_datetime(2020, 8, 1, 12, 34, 28).unwrap();
         let fixed_decimal_format =
             FixedDecimalFormatter::try_new_unstable(&provider, &locale, Default::default())
                 .unwrap();
 
-        let mut sink = String::new();
-        let loc_datetime = DateTimeInputWithCalendar::new(&datetime, None);
-        write_pattern(
-            &pattern,
-            Some(date_data.get()),
-            Some(time_data.get()),
-            &loc_datetime,
-            &fixed_decimal_format,
-            &mut sink,
+        for (first_weekday, expected) in [
+            (IsoWeekday::Monday, "6 06 Sat 6 Saturday"),
+            (IsoWeekday::Sunday, "7 07 Sat 7 Saturday"),
+            (IsoWeekday::Saturday, "1 01 Sat 1 Saturday"),
+        ] {
+            let week_data = WeekDataV1(CalendarInfo {
+                first_weekday,
+                min_week_days: 1,
+            });
+            let formatted = FormattedDateTime {
+                patterns: &patterns,
+                date_symbols: Some(date_data.get()),
+                time_symbols: None,
+                datetime: ExtractedDateTimeInput::extract_from(&datetime),
+                week_data: Some(&week_data),
+                ordinal_rules: None,
+                fixed_decimal_format: &fixed_decimal_format,
+            };
+            assert_eq!(formatted.write_to_string(), expected);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_parts() {
+        use crate::parts;
+        use crate::pattern::runtime;
+        use crate::provider::calendar::patterns::PatternPluralsV1;
+        use crate::provider::calendar::{GregorianDateSymbolsV1Marker, TimeSymbolsV1Marker};
+        use icu_calendar::{types::Time, Date, DateTime};
+        use icu_provider::prelude::*;
+        use writeable::assert_writeable_parts_eq;
+
+        let provider = icu_testdata::get_provider();
+        let locale = "en-u-ca-gregory".parse::<Locale>().unwrap().into();
+        let req = DataRequest {
+            locale: &locale,
+            metadata: Default::default(),
+        };
+        let date_data: DataPayload<GregorianDateSymbolsV1Marker> =
+            provider.load(req).unwrap().take_payload().unwrap();
+        let time_data: DataPayload<TimeSymbolsV1Marker> =
+            provider.load(req).unwrap().take_payload().unwrap();
+        // Fractional seconds directly follow the seconds, as in patterns from skeletons.
+        let pattern: runtime::Pattern = "EEEE, d MMM y 'at' h:mm:ssSS a".parse().unwrap();
+        let patterns = DataPayload::<PatternPluralsFromPatternsV1Marker>::from_owned(
+            PatternPluralsV1(pattern.into()),
+        );
+        let datetime = DateTime::new(
+            Date::new_gregorian_date(2020, 8, 1).unwrap(),
+            Time::try_new(12, 34, 28, 250_000_000).unwrap(),
+        );
+        let mut fixed_decimal_format_options = FixedDecimalFormatterOptions::default();
+        fixed_decimal_format_options.grouping_strategy = GroupingStrategy::Never;
+        let fixed_decimal_format = FixedDecimalFormatter::try_new_unstable(
+            &provider,
+            &locale,
+            fixed_decimal_format_options,
         )
         .unwrap();
-        println!("{}", sink);
+
+        let formatted = FormattedDateTime {
+            patterns: &patterns,
+            date_symbols: Some(date_data.get()),
+            time_symbols: Some(time_data.get()),
+            datetime: ExtractedDateTimeInput::extract_from(&datetime),
+            week_data: None,
+            ordinal_rules: None,
+            fixed_decimal_format: &fixed_decimal_format,
+        };
+
+        assert_writeable_parts_eq!(
+            formatted,
+            "Saturday, 1 Aug 2020 at 12:34:28.25 PM",
+            [
+                (0, 8, parts::WEEKDAY),
+                (8, 10, parts::LITERAL),
+                (10, 11, parts::DAY),
+                (11, 12, parts::LITERAL),
+                (12, 15, parts::MONTH),
+                (15, 16, parts::LITERAL),
+                (16, 20, parts::YEAR),
+                (20, 24, parts::LITERAL),
+                (24, 26, parts::HOUR),
+                (26, 27, parts::LITERAL),
+                (27, 29, parts::MINUTE),
+                (29, 30, parts::LITERAL),
+                (30, 32, parts::SECOND),
+                (32, 33, parts::LITERAL),
+                (33, 35, parts::FRACTIONAL_SECOND),
+                (35, 36, parts::LITERAL),
+                (36, 38, parts::DAY_PERIOD),
+            ]
+        );
     }
 
     #[test]
@@ -565,7 +979,7 @@ mod tests {
         let values = &[2, 20, 201, 2017, 20173];
         let samples = &[
             (FieldLength::One, ["2", "20", "201", "2017", "20173"]),
-            (FieldLength::TwoDigit, ["02", "20", "01", "17", "73"]),
+            (FieldLength::TwoDigit, ["02", "20", "201", "2017", "20173"]),
             (
                 FieldLength::Abbreviated,
                 ["002", "020", "201", "2017", "20173"],
@@ -596,5 +1010,17 @@ mod tests {
                 assert_eq!(s, *expected);
             }
         }
+
+        for (value, expected) in values.iter().zip(["02", "20", "01", "17", "73"]) {
+            let mut s = String::new();
+            format_year(
+                &mut s,
+                &fixed_decimal_format,
+                FixedDecimal::from(*value as i32),
+                FieldLength::TwoDigit,
+            )
+            .unwrap();
+            assert_eq!(s, expected);
+        }
     }
 }
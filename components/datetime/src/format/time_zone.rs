@@ -7,10 +7,11 @@ use core::fmt;
 use crate::error::DateTimeFormatterError as Error;
 use crate::{
     input::TimeZoneInput,
+    parts,
     time_zone::{FormatTimeZone, TimeZoneFormatter, TimeZoneFormatterUnit},
     DateTimeFormatterError,
 };
-use writeable::Writeable;
+use writeable::{PartsWrite, Writeable};
 
 /// [`FormattedTimeZone`] is a intermediate structure which can be retrieved as an output from [`TimeZoneFormatter`].
 pub struct FormattedTimeZone<'l, T>
@@ -57,6 +58,11 @@ where
         }
     }
 
+    /// Format time zone with fallbacks, as a single [`parts::TIME_ZONE_NAME`].
+    fn write_to_parts<S: PartsWrite + ?Sized>(&self, sink: &mut S) -> fmt::Result {
+        sink.with_part(parts::TIME_ZONE_NAME, |w| self.write_to(w))
+    }
+
     // TODO(#489): Implement write_len
 }
 
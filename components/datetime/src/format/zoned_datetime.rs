@@ -13,7 +13,7 @@ use crate::input::{
 use crate::pattern::{runtime, PatternItem};
 use crate::{raw, FormattedTimeZone};
 use core::fmt;
-use writeable::Writeable;
+use writeable::{PartsWrite, Writeable};
 
 use super::datetime;
 
@@ -25,7 +25,7 @@ pub struct FormattedZonedDateTime<'l> {
 }
 
 impl<'l> Writeable for FormattedZonedDateTime<'l> {
-    fn write_to<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+    fn write_to_parts<S: PartsWrite + ?Sized>(&self, sink: &mut S) -> fmt::Result {
         write_pattern(
             self.zoned_datetime_format,
             &self.datetime,
@@ -40,13 +40,7 @@ impl<'l> Writeable for FormattedZonedDateTime<'l> {
 
 impl<'l> fmt::Display for FormattedZonedDateTime<'l> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write_pattern(
-            self.zoned_datetime_format,
-            &self.datetime,
-            &self.time_zone,
-            f,
-        )
-        .map_err(|_| core::fmt::Error)
+        self.write_to(f)
     }
 }
 
@@ -59,7 +53,7 @@ pub(crate) fn write_pattern<D, Z, W>(
 where
     D: DateTimeInput,
     Z: TimeZoneInput,
-    W: fmt::Write + ?Sized,
+    W: PartsWrite + ?Sized,
 {
     let patterns = &zoned_datetime_format.datetime_format.patterns;
     let loc_datetime = DateTimeInputWithCalendar::new(
@@ -88,7 +82,7 @@ where
                 time_zone,
                 w,
             )?,
-            Some(PatternItem::Literal(ch)) => w.write_char(ch)?,
+            Some(PatternItem::Literal(ch)) => datetime::write_literals(ch, &mut iter, w)?,
             None => break,
         }
     }
@@ -107,7 +101,7 @@ fn write_field<D, Z, W>(
 where
     D: DateTimeInput,
     Z: TimeZoneInput,
-    W: fmt::Write + ?Sized,
+    W: PartsWrite + ?Sized,
 {
     let date_symbols = zoned_datetime_format
         .datetime_format
@@ -126,8 +120,8 @@ where
             time_zone_format: &zoned_datetime_format.time_zone_format,
             time_zone,
         }
-        .write_to(w)?,
-        _ => datetime::write_field(
+        .write_to_parts(w)?,
+        _ => datetime::write_field_parts(
             pattern,
             field,
             next_item,
@@ -8,7 +8,7 @@
 use crate::provider::time_zones::{MetaZoneId, TimeZoneBcp47Id};
 use icu_calendar::any_calendar::AnyCalendarKind;
 use icu_calendar::Calendar;
-use icu_calendar::{week_of, AsCalendar, Date, DateTime, Iso};
+use icu_calendar::{week_of, AsCalendar, Date, DateTime, Iso, MonthDay, YearMonth};
 use icu_timezone::{CustomTimeZone, GmtOffset, ZoneVariant};
 
 // TODO (Manishearth) fix up imports to directly import from icu_calendar
@@ -43,6 +43,13 @@ pub trait DateInput {
     /// Gets information on the position of the day within the year.
     fn day_of_year_info(&self) -> Option<DayOfYearInfo>;
 
+    /// Gets the fixed day number, where R.D. 1 is 0001-01-01 in the proleptic ISO calendar.
+    ///
+    /// Defaults to `None`, in which case Julian day fields cannot be formatted.
+    fn fixed_day(&self) -> Option<i32> {
+        None
+    }
+
     /// Gets the kind of calendar this date is for, if associated with AnyCalendar
     /// In most cases you'll probably want to return AnyCalendarKind::Iso
     fn any_calendar_kind(&self) -> Option<AnyCalendarKind>;
@@ -121,6 +128,11 @@ pub trait LocalizedDateTimeInput<T: DateTimeInput> {
     /// For example, July 8, 2020 is the 2nd Wednesday of July.
     fn day_of_week_in_month(&self) -> Result<DayOfWeekInMonth, DateTimeError>;
 
+    /// The 1-based day of week relative to the locale's first day of the week.
+    ///
+    /// For example, Monday is day 1 in de, while Sunday is day 1 in en-US.
+    fn local_day_of_week(&self) -> Result<u8, DateTimeError>;
+
     /// TODO(#487): Implement flexible day periods.
     fn flexible_day_period(&self);
 }
@@ -140,6 +152,7 @@ pub(crate) struct ExtractedDateTimeInput {
     day_of_month: Option<DayOfMonth>,
     iso_weekday: Option<IsoWeekday>,
     day_of_year_info: Option<DayOfYearInfo>,
+    fixed_day: Option<i32>,
     any_calendar_kind: Option<AnyCalendarKind>,
     hour: Option<IsoHour>,
     minute: Option<IsoMinute>,
@@ -166,6 +179,7 @@ impl ExtractedDateTimeInput {
             day_of_month: input.day_of_month(),
             iso_weekday: input.iso_weekday(),
             day_of_year_info: input.day_of_year_info(),
+            fixed_day: input.fixed_day(),
             any_calendar_kind: input.any_calendar_kind(),
             hour: input.hour(),
             minute: input.minute(),
@@ -181,6 +195,7 @@ impl ExtractedDateTimeInput {
             day_of_month: input.day_of_month(),
             iso_weekday: input.iso_weekday(),
             day_of_year_info: input.day_of_year_info(),
+            fixed_day: input.fixed_day(),
             any_calendar_kind: input.any_calendar_kind(),
             ..Default::default()
         }
@@ -228,6 +243,9 @@ impl DateInput for ExtractedDateTimeInput {
     fn day_of_year_info(&self) -> Option<DayOfYearInfo> {
         self.day_of_year_info
     }
+    fn fixed_day(&self) -> Option<i32> {
+        self.fixed_day
+    }
     fn any_calendar_kind(&self) -> Option<AnyCalendarKind> {
         self.any_calendar_kind
     }
@@ -342,6 +360,16 @@ fn day_of_week_in_month<T: DateInput>(datetime: &T) -> Result<DayOfWeekInMonth,
     Ok(day_of_month.into())
 }
 
+fn local_day_of_week<T: DateInput>(
+    datetime: &T,
+    first_weekday: IsoWeekday,
+) -> Result<u8, DateTimeError> {
+    let weekday = datetime
+        .iso_weekday()
+        .ok_or(DateTimeError::MissingInput("DateTimeInput::iso_weekday"))?;
+    Ok((7 + weekday as u8 - first_weekday as u8) % 7 + 1)
+}
+
 impl<'data, T: DateTimeInput> DateTimeInputWithCalendar<'data, T> {
     pub(crate) fn new(data: &'data T, calendar: Option<&'data week_of::CalendarInfo>) -> Self {
         Self { data, calendar }
@@ -380,6 +408,15 @@ impl<'data, T: DateTimeInput> LocalizedDateTimeInput<T> for DateTimeInputWithCal
         day_of_week_in_month(self.data)
     }
 
+    fn local_day_of_week(&self) -> Result<u8, DateTimeError> {
+        local_day_of_week(
+            self.data,
+            self.calendar
+                .ok_or(DateTimeError::MissingCalendar)?
+                .first_weekday,
+        )
+    }
+
     fn flexible_day_period(&self) {
         todo!("#487")
     }
@@ -412,6 +449,11 @@ impl<C: Calendar, A: AsCalendar<Calendar = C>> DateInput for Date<A> {
         Some(self.day_of_year_info())
     }
 
+    /// Gets the fixed day number.
+    fn fixed_day(&self) -> Option<i32> {
+        Some(self.to_rata_die().to_i32_date())
+    }
+
     fn any_calendar_kind(&self) -> Option<AnyCalendarKind> {
         self.calendar().any_calendar_kind()
     }
@@ -448,6 +490,11 @@ impl<C: Calendar, A: AsCalendar<Calendar = C>> DateInput for DateTime<A> {
         Some(self.date.day_of_year_info())
     }
 
+    /// Gets the fixed day number.
+    fn fixed_day(&self) -> Option<i32> {
+        Some(self.date.to_rata_die().to_i32_date())
+    }
+
     fn any_calendar_kind(&self) -> Option<AnyCalendarKind> {
         self.date.calendar().any_calendar_kind()
     }
@@ -478,6 +525,132 @@ impl<A: AsCalendar> IsoTimeInput for DateTime<A> {
     }
 }
 
+impl<C: Calendar, A: AsCalendar<Calendar = C>> DateInput for YearMonth<A> {
+    type Calendar = C;
+    /// Gets the era and year input.
+    fn year(&self) -> Option<FormattableYear> {
+        Some(self.year())
+    }
+
+    /// Gets the month input.
+    fn month(&self) -> Option<FormattableMonth> {
+        Some(self.month())
+    }
+
+    /// Returns `None`, since a year and month has no day.
+    fn day_of_month(&self) -> Option<DayOfMonth> {
+        None
+    }
+
+    /// Returns `None`, since a year and month has no day.
+    fn iso_weekday(&self) -> Option<IsoWeekday> {
+        None
+    }
+
+    /// Returns `None`, since a year and month has no day.
+    fn day_of_year_info(&self) -> Option<DayOfYearInfo> {
+        None
+    }
+
+    /// Returns `None`, since a year and month has no day.
+    fn fixed_day(&self) -> Option<i32> {
+        None
+    }
+
+    fn any_calendar_kind(&self) -> Option<AnyCalendarKind> {
+        self.calendar().any_calendar_kind()
+    }
+
+    fn to_iso(&self) -> Date<Iso> {
+        YearMonth::to_iso(self)
+    }
+}
+
+impl<A: AsCalendar> IsoTimeInput for YearMonth<A> {
+    /// Returns `None`, since a year and month has no time.
+    fn hour(&self) -> Option<IsoHour> {
+        None
+    }
+
+    /// Returns `None`, since a year and month has no time.
+    fn minute(&self) -> Option<IsoMinute> {
+        None
+    }
+
+    /// Returns `None`, since a year and month has no time.
+    fn second(&self) -> Option<IsoSecond> {
+        None
+    }
+
+    /// Returns `None`, since a year and month has no time.
+    fn nanosecond(&self) -> Option<NanoSecond> {
+        None
+    }
+}
+
+impl<C: Calendar, A: AsCalendar<Calendar = C>> DateInput for MonthDay<A> {
+    type Calendar = C;
+    /// Returns `None`, since a month and day has no year.
+    fn year(&self) -> Option<FormattableYear> {
+        None
+    }
+
+    /// Gets the month input.
+    fn month(&self) -> Option<FormattableMonth> {
+        Some(self.month())
+    }
+
+    /// Gets the day input.
+    fn day_of_month(&self) -> Option<DayOfMonth> {
+        Some(self.day_of_month())
+    }
+
+    /// Returns `None`, since the weekday depends on the year.
+    fn iso_weekday(&self) -> Option<IsoWeekday> {
+        None
+    }
+
+    /// Returns `None`, since the position of the day within the year depends on the year.
+    fn day_of_year_info(&self) -> Option<DayOfYearInfo> {
+        None
+    }
+
+    /// Returns `None`, since the fixed day number depends on the year.
+    fn fixed_day(&self) -> Option<i32> {
+        None
+    }
+
+    fn any_calendar_kind(&self) -> Option<AnyCalendarKind> {
+        self.calendar().any_calendar_kind()
+    }
+
+    fn to_iso(&self) -> Date<Iso> {
+        MonthDay::to_iso(self)
+    }
+}
+
+impl<A: AsCalendar> IsoTimeInput for MonthDay<A> {
+    /// Returns `None`, since a month and day has no time.
+    fn hour(&self) -> Option<IsoHour> {
+        None
+    }
+
+    /// Returns `None`, since a month and day has no time.
+    fn minute(&self) -> Option<IsoMinute> {
+        None
+    }
+
+    /// Returns `None`, since a month and day has no time.
+    fn second(&self) -> Option<IsoSecond> {
+        None
+    }
+
+    /// Returns `None`, since a month and day has no time.
+    fn nanosecond(&self) -> Option<NanoSecond> {
+        None
+    }
+}
+
 impl TimeZoneInput for CustomTimeZone {
     fn gmt_offset(&self) -> Option<GmtOffset> {
         self.gmt_offset
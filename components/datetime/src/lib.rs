@@ -114,14 +114,19 @@ mod format;
 pub mod input;
 pub mod mock;
 pub mod options;
+mod parse;
+pub mod parts;
 #[doc(hidden)]
 pub mod pattern;
+#[cfg(feature = "experimental")]
+mod pattern_generator;
 pub mod provider;
 pub(crate) mod raw;
 #[doc(hidden)]
 #[allow(clippy::exhaustive_structs, clippy::exhaustive_enums)] // private-ish module
 #[cfg(feature = "experimental_skeleton_matching")]
 pub mod skeleton;
+pub mod symbols;
 #[allow(missing_docs)] // TODO(#686) - Add missing docs.
 pub mod time_zone;
 mod zoned_datetime;
@@ -136,6 +141,10 @@ pub use format::datetime::FormattedDateTime;
 pub use format::time_zone::FormattedTimeZone;
 pub use format::zoned_datetime::FormattedZonedDateTime;
 pub use options::DateTimeFormatterOptions;
+pub use parse::Leniency;
+#[cfg(feature = "experimental")]
+pub use pattern_generator::DateTimePatternGenerator;
+pub use symbols::DateTimeSymbols;
 pub use time_zone::TimeZoneFormatter;
 pub use time_zone::TimeZoneFormatterConfig;
 pub use time_zone::TimeZoneFormatterOptions;
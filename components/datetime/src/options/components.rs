@@ -14,7 +14,7 @@
 //! | Match skeleton fields according to a ranking             | Implemented |
 //! | Adjust the matched pattern to have certain widths        | Implemented |
 //! | Match date and times separately, and them combine them   | Implemented |
-//! | Use appendItems to fill in a pattern with missing fields | Not yet, and may not be fully implemented. See [issue #586](https://github.com/unicode-org/icu4x/issues/586) |
+//! | Use appendItems to fill in a pattern with missing fields | Implemented, without the field display names |
 //!
 //! # Description
 //!
@@ -74,6 +74,7 @@
 use crate::{
     fields::{self, Field, FieldLength, FieldSymbol},
     pattern::{runtime::PatternPlurals, PatternItem},
+    time_zone::{IsoFormat, IsoMinutes, IsoSeconds},
 };
 
 #[cfg(feature = "experimental")]
@@ -92,6 +93,8 @@ pub struct Bag {
     pub era: Option<Text>,
     /// Include the year, such as "1970" or "70".
     pub year: Option<Year>,
+    /// Include the quarter, such as "Q3" or "3rd quarter".
+    pub quarter: Option<Quarter>,
     /// Include the month, such as "April" or "Apr".
     pub month: Option<Month>,
     /// Include the week number, such as "51st" or "51" for week 51.
@@ -171,9 +174,24 @@ impl Bag {
             });
         }
 
-        // TODO(#501) - Unimplemented quarter fields:
-        // Q - Quarter number/name
-        // q - Stand-alone quarter
+        if let Some(quarter) = self.quarter {
+            fields.push(Field {
+                // Always choose Quarter::Format as Quarter::StandAlone is not used in skeletons.
+                symbol: FieldSymbol::Quarter(fields::Quarter::Format),
+                length: match quarter {
+                    // Q      2            Numeric: 1 digit
+                    // QQ     02           Numeric: 2 digits + zero pad
+                    // QQQ    Q2           Abbreviated
+                    // QQQQ   2nd quarter  Wide
+                    // QQQQQ  2            Narrow
+                    Quarter::Numeric => FieldLength::One,
+                    Quarter::TwoDigit => FieldLength::TwoDigit,
+                    Quarter::Long => FieldLength::Wide,
+                    Quarter::Short => FieldLength::Abbreviated,
+                    Quarter::Narrow => FieldLength::Narrow,
+                },
+            });
+        }
 
         if let Some(month) = self.month {
             fields.push(Field {
@@ -209,28 +227,31 @@ impl Bag {
         }
 
         if let Some(day) = self.day {
-            // TODO(#591) Unimplemented day fields:
-            // D - Day of year
-            // g - Modified Julian day.
             fields.push(Field {
                 symbol: FieldSymbol::Day(match day {
                     Day::NumericDayOfMonth | Day::TwoDigitDayOfMonth => fields::Day::DayOfMonth,
                     Day::DayOfWeekInMonth => fields::Day::DayOfWeekInMonth,
+                    Day::NumericDayOfYear | Day::ThreeDigitDayOfYear => fields::Day::DayOfYear,
+                    Day::ModifiedJulianDay => fields::Day::ModifiedJulianDay,
                 }),
                 length: match day {
                     // d    1 	  Numeric day of month: minimum digits
                     // dd   01 	  Numeric day of month: 2 digits, zero pad if needed
                     // F    1  	  Numeric day of week in month: minimum digits
-                    Day::NumericDayOfMonth | Day::DayOfWeekInMonth => FieldLength::One,
+                    // D    1     Numeric day of year: minimum digits
+                    // DDD  001   Numeric day of year: 3 digits, zero pad if needed
+                    // g    1     Modified Julian day: minimum digits
+                    Day::NumericDayOfMonth
+                    | Day::DayOfWeekInMonth
+                    | Day::NumericDayOfYear
+                    | Day::ModifiedJulianDay => FieldLength::One,
                     Day::TwoDigitDayOfMonth => FieldLength::TwoDigit,
+                    Day::ThreeDigitDayOfYear => FieldLength::Abbreviated,
                 },
             });
         }
 
         if let Some(weekday) = self.weekday {
-            // TODO(#593) Unimplemented fields
-            // e - Local day of week.
-            // c - Stand-alone local day of week.
             fields.push(Field {
                 symbol: FieldSymbol::Weekday(fields::Weekday::Format),
                 length: match weekday {
@@ -265,6 +286,7 @@ impl Bag {
                 symbol: FieldSymbol::Hour(match self.preferences {
                     Some(preferences::Bag {
                         hour_cycle: Some(hour_cycle),
+                        ..
                     }) => match hour_cycle {
                         // Skeletons only contain the h12, not h11. The pattern that is matched
                         // is free to use h11 or h12.
@@ -423,6 +445,31 @@ pub enum Month {
     Narrow,
 }
 
+/// Options for displaying a Quarter for the `components::`[`Bag`].
+///
+/// Quarters are counted in groups of three from the ordinal month of the calendar. In calendars
+/// with a thirteenth month, such as the Coptic and Ethiopian calendars or the Hebrew calendar in
+/// leap years, the thirteenth month is counted as part of the fourth quarter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "kebab-case")
+)]
+#[non_exhaustive]
+pub enum Quarter {
+    /// The numeric value of the quarter, such as "2".
+    Numeric,
+    /// The two-digit value of the quarter, such as "02".
+    TwoDigit,
+    /// The long value of the quarter, such as "2nd quarter".
+    Long,
+    /// The short value of the quarter, such as "Q2".
+    Short,
+    /// The narrow value of the quarter, such as "2".
+    Narrow,
+}
+
 // Each enum variant is documented with the UTS 35 field information from:
 // https://unicode.org/reports/tr35/tr35-dates.html#Date_Field_Symbol_Table
 
@@ -460,6 +507,12 @@ pub enum Day {
     TwoDigitDayOfMonth,
     /// The day of week in this month, such as the "2" in 2nd Wednesday of July.
     DayOfWeekInMonth,
+    /// The numeric value of the day of year, such as the "184" in 1984-07-02.
+    NumericDayOfYear,
+    /// The three digit value of the day of year, such as the "009" in 1984-01-09.
+    ThreeDigitDayOfYear,
+    /// The modified Julian day, such as the "2445884" for July 2 1984.
+    ModifiedJulianDay,
 }
 
 /// Options for displaying a time zone for the `components::`[`Bag`].
@@ -504,6 +557,28 @@ pub enum TimeZoneName {
     //  * falling back to long localized GMT
     /// Long generic non-location format (e.g.: Pacific Time, Nordamerikanische Westküstenzeit),
     LongGeneric,
+
+    // UTS-35 fields: VVVV
+    /// Generic location format (e.g.: Los Angeles Time, Los Angeles (Ortszeit)).
+    GenericLocation,
+
+    // UTS-35 fields: V
+    /// Short time zone identifier (e.g.: uslax).
+    ShortId,
+
+    // UTS-35 fields: VV
+    /// Long time zone identifier (e.g.: America/Los_Angeles).
+    LongId,
+
+    // UTS-35 fields: VVV
+    /// Exemplar city of the time zone (e.g.: Los Angeles).
+    ExemplarCity,
+
+    // UTS-35 fields: X..XXXXX, x..xxxxx
+    // Per UTS-35, the minutes are always displayed in the extended format and when the seconds
+    // may be displayed, so those combinations are widened to required minutes.
+    /// ISO 8601 format (e.g.: -0800, -08:00, Z).
+    Iso8601(IsoFormat, IsoMinutes, IsoSeconds),
 }
 
 impl From<TimeZoneName> for Field {
@@ -529,6 +604,52 @@ impl From<TimeZoneName> for Field {
                 symbol: FieldSymbol::TimeZone(fields::TimeZone::LowerV),
                 length: FieldLength::Wide,
             },
+            TimeZoneName::GenericLocation => Field {
+                symbol: FieldSymbol::TimeZone(fields::TimeZone::UpperV),
+                length: FieldLength::Wide,
+            },
+            TimeZoneName::ShortId => Field {
+                symbol: FieldSymbol::TimeZone(fields::TimeZone::UpperV),
+                length: FieldLength::One,
+            },
+            TimeZoneName::LongId => Field {
+                symbol: FieldSymbol::TimeZone(fields::TimeZone::UpperV),
+                length: FieldLength::TwoDigit,
+            },
+            TimeZoneName::ExemplarCity => Field {
+                symbol: FieldSymbol::TimeZone(fields::TimeZone::UpperV),
+                length: FieldLength::Abbreviated,
+            },
+            TimeZoneName::Iso8601(format, minutes, seconds) => Field {
+                symbol: FieldSymbol::TimeZone(match format {
+                    IsoFormat::Basic | IsoFormat::Extended => fields::TimeZone::LowerX,
+                    IsoFormat::UtcBasic | IsoFormat::UtcExtended => fields::TimeZone::UpperX,
+                }),
+                // X     -08, +0530, Z
+                // XX    -0800, Z
+                // XXX   -08:00, Z
+                // XXXX  -0800, -075258, Z
+                // XXXXX -08:00, -07:52:58, Z
+                length: match (format, minutes, seconds) {
+                    (
+                        IsoFormat::Basic | IsoFormat::UtcBasic,
+                        IsoMinutes::Optional,
+                        IsoSeconds::Never,
+                    ) => FieldLength::One,
+                    (IsoFormat::Basic | IsoFormat::UtcBasic, _, IsoSeconds::Never) => {
+                        FieldLength::TwoDigit
+                    }
+                    (IsoFormat::Extended | IsoFormat::UtcExtended, _, IsoSeconds::Never) => {
+                        FieldLength::Abbreviated
+                    }
+                    (IsoFormat::Basic | IsoFormat::UtcBasic, _, IsoSeconds::Optional) => {
+                        FieldLength::Wide
+                    }
+                    (IsoFormat::Extended | IsoFormat::UtcExtended, _, IsoSeconds::Optional) => {
+                        FieldLength::Narrow
+                    }
+                },
+            },
         }
     }
 }
@@ -565,7 +686,9 @@ impl<'data> From<&PatternPlurals<'data>> for Bag {
                 }
                 FieldSymbol::Year(year) => {
                     bag.year = Some(match year {
-                        fields::Year::Calendar => match field.length {
+                        fields::Year::Calendar
+                        | fields::Year::Cyclic
+                        | fields::Year::RelatedIso => match field.length {
                             FieldLength::TwoDigit => Year::TwoDigit,
                             _ => Year::Numeric,
                         },
@@ -575,6 +698,19 @@ impl<'data> From<&PatternPlurals<'data>> for Bag {
                         },
                     });
                 }
+                FieldSymbol::Quarter(_) => {
+                    // `Quarter::StandAlone` is only relevant in the pattern, so only differentiate
+                    // on the field length.
+                    bag.quarter = Some(match field.length {
+                        FieldLength::One => Quarter::Numeric,
+                        FieldLength::TwoDigit => Quarter::TwoDigit,
+                        FieldLength::Abbreviated => Quarter::Short,
+                        FieldLength::Wide => Quarter::Long,
+                        FieldLength::Narrow | FieldLength::Six | FieldLength::Fixed(_) => {
+                            Quarter::Narrow
+                        }
+                    });
+                }
                 FieldSymbol::Month(_) => {
                     // `Month::StandAlone` is only relevant in the pattern, so only differentiate
                     // on the field length.
@@ -603,16 +739,22 @@ impl<'data> From<&PatternPlurals<'data>> for Bag {
                             FieldLength::TwoDigit => Day::TwoDigitDayOfMonth,
                             _ => Day::NumericDayOfMonth,
                         },
-                        fields::Day::DayOfYear => unimplemented!("fields::Day::DayOfYear #591"),
+                        fields::Day::DayOfYear => match field.length {
+                            FieldLength::One | FieldLength::TwoDigit => Day::NumericDayOfYear,
+                            _ => Day::ThreeDigitDayOfYear,
+                        },
                         fields::Day::DayOfWeekInMonth => Day::DayOfWeekInMonth,
-                        fields::Day::ModifiedJulianDay => {
-                            unimplemented!("fields::Day::ModifiedJulianDay")
-                        }
+                        fields::Day::ModifiedJulianDay => Day::ModifiedJulianDay,
                     });
                 }
+                FieldSymbol::Weekday(fields::Weekday::Local | fields::Weekday::StandAlone)
+                    if matches!(field.length, FieldLength::One | FieldLength::TwoDigit) =>
+                {
+                    // The numeric local day of week has no equivalent in the components bag.
+                }
                 FieldSymbol::Weekday(weekday) => {
                     bag.weekday = Some(match weekday {
-                        fields::Weekday::Format => match field.length {
+                        fields::Weekday::Format | fields::Weekday::Local => match field.length {
                             FieldLength::One | FieldLength::TwoDigit | FieldLength::Abbreviated => {
                                 Text::Short
                             }
@@ -620,37 +762,12 @@ impl<'data> From<&PatternPlurals<'data>> for Bag {
                             _ => Text::Narrow,
                         },
                         fields::Weekday::StandAlone => match field.length {
-                            FieldLength::One | FieldLength::TwoDigit => {
-                                // Stand-alone fields also support a numeric 1 digit per UTS-35, but there is
-                                // no way to request it using the current system. As of 2021-12-06
-                                // no skeletons resolve to patterns containing this symbol.
-                                //
-                                // All resolved patterns from cldr-json:
-                                // https://github.com/gregtatum/cldr-json/blob/d4779f9611a4cc1e3e6a0a4597e92ead32d9f118/stand-alone-week.js
-                                //     'ccc',
-                                //     'ccc d. MMM',
-                                //     'ccc d. MMMM',
-                                //     'cccc d. MMMM y',
-                                //     'd, ccc',
-                                //     'cccနေ့',
-                                //     'ccc, d MMM',
-                                //     "ccc, d 'de' MMMM",
-                                //     "ccc, d 'de' MMMM 'de' y",
-                                //     'ccc, h:mm B',
-                                //     'ccc, h:mm:ss B',
-                                //     'ccc, d',
-                                //     "ccc, dd.MM.y 'г'.",
-                                //     'ccc, d.MM.y',
-                                //     'ccc, MMM d. y'
-                                unimplemented!("Numeric stand-alone fields are not supported.")
-                            }
-                            FieldLength::Abbreviated => Text::Short,
                             FieldLength::Wide => Text::Long,
                             FieldLength::Narrow | FieldLength::Six | FieldLength::Fixed(_) => {
                                 Text::Narrow
                             }
+                            _ => Text::Short,
                         },
-                        fields::Weekday::Local => unimplemented!("fields::Weekday::Local"),
                     });
                 }
                 FieldSymbol::DayPeriod(_) => {
@@ -661,14 +778,12 @@ impl<'data> From<&PatternPlurals<'data>> for Bag {
                         FieldLength::TwoDigit => Numeric::TwoDigit,
                         _ => Numeric::Numeric,
                     });
-                    bag.preferences = Some(preferences::Bag {
-                        hour_cycle: Some(match hour {
-                            fields::Hour::H11 => preferences::HourCycle::H11,
-                            fields::Hour::H12 => preferences::HourCycle::H12,
-                            fields::Hour::H23 => preferences::HourCycle::H23,
-                            fields::Hour::H24 => preferences::HourCycle::H24,
-                        }),
-                    });
+                    bag.preferences = Some(preferences::Bag::from_hour_cycle(match hour {
+                        fields::Hour::H11 => preferences::HourCycle::H11,
+                        fields::Hour::H12 => preferences::HourCycle::H12,
+                        fields::Hour::H23 => preferences::HourCycle::H23,
+                        fields::Hour::H24 => preferences::HourCycle::H24,
+                    }));
                 }
                 FieldSymbol::Minute => {
                     bag.minute = Some(match field.length {
@@ -707,10 +822,53 @@ impl<'data> From<&PatternPlurals<'data>> for Bag {
                             _ => TimeZoneName::LongGeneric,
                         },
                         fields::TimeZone::UpperO => TimeZoneName::GmtOffset,
-                        fields::TimeZone::UpperZ => unimplemented!("fields::TimeZone::UpperZ"),
-                        fields::TimeZone::UpperV => unimplemented!("fields::TimeZone::UpperV"),
-                        fields::TimeZone::LowerX => unimplemented!("fields::TimeZone::LowerX"),
-                        fields::TimeZone::UpperX => unimplemented!("fields::TimeZone::UpperX"),
+                        fields::TimeZone::UpperZ => match field.length {
+                            FieldLength::Wide => TimeZoneName::GmtOffset,
+                            FieldLength::Narrow => TimeZoneName::Iso8601(
+                                IsoFormat::UtcExtended,
+                                IsoMinutes::Required,
+                                IsoSeconds::Optional,
+                            ),
+                            _ => TimeZoneName::Iso8601(
+                                IsoFormat::Basic,
+                                IsoMinutes::Required,
+                                IsoSeconds::Optional,
+                            ),
+                        },
+                        fields::TimeZone::UpperV => match field.length {
+                            FieldLength::One => TimeZoneName::ShortId,
+                            FieldLength::TwoDigit => TimeZoneName::LongId,
+                            FieldLength::Abbreviated => TimeZoneName::ExemplarCity,
+                            _ => TimeZoneName::GenericLocation,
+                        },
+                        fields::TimeZone::LowerX | fields::TimeZone::UpperX => {
+                            let utc = time_zone_name == fields::TimeZone::UpperX;
+                            let (extended, minutes, seconds) = match field.length {
+                                FieldLength::One => {
+                                    (false, IsoMinutes::Optional, IsoSeconds::Never)
+                                }
+                                FieldLength::TwoDigit => {
+                                    (false, IsoMinutes::Required, IsoSeconds::Never)
+                                }
+                                FieldLength::Abbreviated => {
+                                    (true, IsoMinutes::Required, IsoSeconds::Never)
+                                }
+                                FieldLength::Wide => {
+                                    (false, IsoMinutes::Required, IsoSeconds::Optional)
+                                }
+                                _ => (true, IsoMinutes::Required, IsoSeconds::Optional),
+                            };
+                            TimeZoneName::Iso8601(
+                                match (utc, extended) {
+                                    (false, false) => IsoFormat::Basic,
+                                    (false, true) => IsoFormat::Extended,
+                                    (true, false) => IsoFormat::UtcBasic,
+                                    (true, true) => IsoFormat::UtcExtended,
+                                },
+                                minutes,
+                                seconds,
+                            )
+                        }
                     });
                 }
             }
@@ -779,4 +937,52 @@ mod test {
             ]
         );
     }
+
+    #[test]
+    fn test_component_bag_to_vec_field_quarter() {
+        let bag = Bag {
+            year: Some(Year::Numeric),
+            quarter: Some(Quarter::Short),
+            ..Default::default()
+        };
+        assert_eq!(
+            bag.to_vec_fields(),
+            vec![
+                (Symbol::Year(fields::Year::Calendar), Length::One).into(),
+                (
+                    Symbol::Quarter(fields::Quarter::Format),
+                    Length::Abbreviated
+                )
+                    .into(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_component_bag_to_vec_field_day_of_year() {
+        let bag = Bag {
+            year: Some(Year::Numeric),
+            day: Some(Day::ThreeDigitDayOfYear),
+            ..Default::default()
+        };
+        assert_eq!(
+            bag.to_vec_fields(),
+            vec![
+                (Symbol::Year(fields::Year::Calendar), Length::One).into(),
+                (Symbol::Day(fields::Day::DayOfYear), Length::Abbreviated).into(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_time_zone_name_iso8601_round_trip() {
+        for pattern in [
+            "x", "xx", "xxx", "xxxx", "xxxxx", "X", "XX", "XXX", "XXXX", "XXXXX",
+        ] {
+            let pattern: crate::pattern::runtime::Pattern = pattern.parse().unwrap();
+            let bag = Bag::from(&PatternPlurals::SinglePattern(pattern.clone()));
+            let field: Field = bag.time_zone_name.unwrap().into();
+            assert_eq!(pattern.items.get(0), Some(PatternItem::Field(field)));
+        }
+    }
 }
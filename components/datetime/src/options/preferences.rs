@@ -27,7 +27,9 @@ use crate::fields;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use icu_locid::extensions::unicode::Key;
 use icu_locid::extensions_unicode_key as key;
+use icu_locid::subtags::Region;
 use icu_provider::DataLocale;
 use tinystr::tinystr;
 use tinystr::TinyAsciiStr;
@@ -50,6 +52,12 @@ pub struct Bag {
     /// given locale.
     #[cfg_attr(feature = "serde", serde(rename = "hourCycle"))]
     pub hour_cycle: Option<HourCycle>,
+
+    /// The region of the `-u-rg` or `-u-sd` keyword when it differs from the region of the
+    /// locale. The formatters take the hour cycle of this region from data when `hour_cycle`
+    /// is not set.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) region_override: Option<Region>,
 }
 
 impl Bag {
@@ -57,10 +65,16 @@ impl Bag {
     pub fn from_hour_cycle(h: HourCycle) -> Self {
         Self {
             hour_cycle: Some(h),
+            region_override: None,
         }
     }
 
     /// Construct a [`Bag`] from a given [`DataLocale`]
+    ///
+    /// This reads the `-u-hc` keyword, and the region override of the `-u-rg` keyword, such as
+    /// `GB` in `en-u-rg-gbzzzz`, or if the locale has no region, of the `-u-sd` keyword, such as
+    /// `GB` in `en-u-sd-gbsct`. Unless `-u-hc` is present, the formatters use the hour cycle
+    /// that CLDR prefers in the region override, which requires data.
     pub fn from_data_locale(data_locale: &DataLocale) -> Self {
         const H11: TinyAsciiStr<8> = tinystr!(8, "h11");
         const H12: TinyAsciiStr<8> = tinystr!(8, "h12");
@@ -76,13 +90,39 @@ impl Bag {
             Some(H24) => Some(HourCycle::H24),
             _ => None,
         };
-        Self { hour_cycle }
+        fn region_of(data_locale: &DataLocale, keyword: Key) -> Option<Region> {
+            let value = data_locale.get_unicode_ext(&keyword)?;
+            let bytes = value.as_single_subtag()?.as_bytes();
+            // The region is a prefix of either two letters or three digits.
+            let length = if bytes.first()?.is_ascii_digit() {
+                3
+            } else {
+                2
+            };
+            Region::from_bytes(bytes.get(..length)?).ok()
+        }
+        let region_override = region_of(data_locale, key!("rg"))
+            .or_else(|| {
+                data_locale
+                    .region()
+                    .map_or_else(|| region_of(data_locale, key!("sd")), |_| None)
+            })
+            .filter(|region| Some(*region) != data_locale.region());
+        Self {
+            hour_cycle,
+            region_override,
+        }
     }
 }
 
 /// A user preference for adjusting how the hour component is displayed.
 #[derive(Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "datagen",
+    derive(databake::Bake),
+    databake(path = icu_datetime::options::preferences),
+)]
 #[allow(clippy::exhaustive_enums)] // this type is stable
 pub enum HourCycle {
     /// Hour is formatted to be in range 1-24 where midnight is 24:00.
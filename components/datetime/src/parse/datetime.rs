@@ -0,0 +1,865 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use super::Leniency;
+use crate::fields::{self, Field, FieldLength, FieldSymbol, Second, Week, Year};
+use crate::pattern::runtime::{Pattern, PatternPlurals};
+use crate::pattern::PatternItem;
+use crate::provider::calendar::{day_periods, DateSymbolsV1, TimeSymbolsV1};
+use crate::provider::date_time::DateSymbols;
+use crate::DateTimeFormatterError;
+use alloc::string::String;
+use alloc::vec::Vec;
+use fixed_decimal::FixedDecimal;
+use icu_calendar::types::{Era, IsoWeekday, MonthCode, Time};
+use icu_calendar::{AsCalendar, Date, DateTime, Ref};
+use icu_decimal::FixedDecimalFormatter;
+use tinystr::TinyStr4;
+use writeable::Writeable;
+
+/// Numbers are never read past this many digits, which keeps every value within a `u32`.
+const MAX_DIGITS: usize = 9;
+
+/// The widths that lenient parsing tries for names, in addition to the width of the field.
+const LENIENT_LENGTHS: [FieldLength; 2] = [FieldLength::Abbreviated, FieldLength::Wide];
+
+const WEEKDAYS: [IsoWeekday; 7] = [
+    IsoWeekday::Monday,
+    IsoWeekday::Tuesday,
+    IsoWeekday::Wednesday,
+    IsoWeekday::Thursday,
+    IsoWeekday::Friday,
+    IsoWeekday::Saturday,
+    IsoWeekday::Sunday,
+];
+
+/// The values read from the input, before they are resolved into a [`DateTime`].
+///
+/// Weekdays, quarters and hours remember the byte offset they were read at, so that
+/// validation errors can point at them.
+#[derive(Default)]
+struct ParsedFields {
+    era: Option<Era>,
+    year: Option<i32>,
+    two_digit_year: bool,
+    month: Option<MonthCode>,
+    day: Option<u8>,
+    weekday: Option<(IsoWeekday, usize)>,
+    quarter: Option<(u32, usize)>,
+    hour: Option<(u32, fields::Hour, usize)>,
+    pm: bool,
+    minute: Option<u8>,
+    second: Option<u8>,
+    nanosecond: Option<u32>,
+}
+
+/// Reads `input` with each pattern of `patterns` in turn, and returns the first [`DateTime`]
+/// that could be read. If none of them match, the error of the first pattern is returned.
+///
+/// Two-digit years are placed in the hundred years starting at `two_digit_year_start`, or
+/// starting twenty years before the reference year if it is `None`.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn parse_pattern_plurals<'l, A: AsCalendar>(
+    patterns: &PatternPlurals,
+    date_symbols: Option<&'l DateSymbolsV1<'l>>,
+    time_symbols: Option<&'l TimeSymbolsV1<'l>>,
+    fixed_decimal_format: &FixedDecimalFormatter,
+    input: &str,
+    leniency: Leniency,
+    two_digit_year_start: Option<i32>,
+    calendar: A,
+) -> Result<DateTime<A>, DateTimeFormatterError> {
+    let parser = Parser::new(date_symbols, time_symbols, fixed_decimal_format, leniency);
+    let mut first_error = None;
+    for pattern in patterns.patterns_iter() {
+        match parser.parse_pattern(pattern, input) {
+            Ok(parsed) => return parser.resolve(parsed, two_digit_year_start, calendar),
+            Err(e) => {
+                first_error.get_or_insert(e);
+            }
+        }
+    }
+    Err(first_error.unwrap_or(DateTimeFormatterError::ParseMismatch(0)))
+}
+
+struct Parser<'l> {
+    date_symbols: Option<&'l DateSymbolsV1<'l>>,
+    time_symbols: Option<&'l TimeSymbolsV1<'l>>,
+    /// The digits zero through nine of the numbering system of the locale.
+    digits: [char; 10],
+    /// The separator between seconds and fractional seconds.
+    decimal_separator: String,
+    leniency: Leniency,
+}
+
+impl<'l> Parser<'l> {
+    fn new(
+        date_symbols: Option<&'l DateSymbolsV1<'l>>,
+        time_symbols: Option<&'l TimeSymbolsV1<'l>>,
+        fixed_decimal_format: &FixedDecimalFormatter,
+        leniency: Leniency,
+    ) -> Self {
+        // The digits of the numbering system are read off the decimal formatter, so that
+        // parsing understands exactly what formatting writes.
+        let mut digits = ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'];
+        for (value, digit) in (0u8..).zip(digits.iter_mut()) {
+            let value = FixedDecimal::from(value);
+            let formatted = fixed_decimal_format.format(&value);
+            if let Some(ch) = formatted.write_to_string().chars().next() {
+                *digit = ch;
+            }
+        }
+        let mut half = FixedDecimal::from(5);
+        half.multiply_pow10(-1);
+        let formatted = fixed_decimal_format.format(&half);
+        let decimal_separator = formatted
+            .write_to_string()
+            .trim_start_matches(digits[0])
+            .trim_end_matches(digits[5])
+            .into();
+        Self {
+            date_symbols,
+            time_symbols,
+            digits,
+            decimal_separator,
+            leniency,
+        }
+    }
+
+    fn is_lenient(&self) -> bool {
+        self.leniency == Leniency::Lenient
+    }
+
+    fn parse_pattern(
+        &self,
+        pattern: &Pattern,
+        input: &str,
+    ) -> Result<ParsedFields, DateTimeFormatterError> {
+        let items: Vec<PatternItem> = pattern.items.iter().collect();
+        let mut parsed = ParsedFields::default();
+        let mut rest = input;
+        let offset = |rest: &str| input.len() - rest.len();
+
+        for (idx, item) in items.iter().enumerate() {
+            let next_item = items.get(idx + 1);
+            match *item {
+                PatternItem::Literal(ch) => {
+                    if !self.match_literal(&mut rest, ch) {
+                        return Err(DateTimeFormatterError::ParseMismatch(offset(rest)));
+                    }
+                }
+                PatternItem::Field(field) => {
+                    let start = offset(rest);
+                    self.parse_field(field, next_item, &mut rest, start, &mut parsed)?
+                        .ok_or(DateTimeFormatterError::ParseMismatch(start))?;
+                }
+            }
+        }
+
+        let trailing = if self.is_lenient() {
+            rest.trim_end()
+        } else {
+            rest
+        };
+        if !trailing.is_empty() {
+            return Err(DateTimeFormatterError::ParseMismatch(offset(rest)));
+        }
+        Ok(parsed)
+    }
+
+    /// Reads a single field into `parsed`. Returns `Ok(None)` if the input does not match
+    /// the field, and an error if the field cannot be parsed at all.
+    fn parse_field(
+        &self,
+        field: Field,
+        next_item: Option<&PatternItem>,
+        rest: &mut &str,
+        start: usize,
+        parsed: &mut ParsedFields,
+    ) -> Result<Option<()>, DateTimeFormatterError> {
+        // Numeric fields that abut another numeric field, like in "HHmm", can only
+        // take as many digits as their length.
+        let abuts_number = matches!(next_item, Some(PatternItem::Field(next)) if is_numeric(next));
+        let number = |rest: &mut &str| {
+            let (min, max) = match (self.leniency, field.length) {
+                (Leniency::Strict, FieldLength::TwoDigit) => (2, 2),
+                (Leniency::Strict, length) if abuts_number => (length.to_len(), length.to_len()),
+                (Leniency::Strict, length) => (length.to_len(), MAX_DIGITS),
+                (_, length) if abuts_number => (1, length.to_len()),
+                _ => (1, MAX_DIGITS),
+            };
+            self.match_number(rest, min, max)
+        };
+
+        match field.symbol {
+            FieldSymbol::Era => {
+                let era = self
+                    .match_name(rest, self.era_names(field.length))
+                    .and_then(|code| code.parse().ok().map(Era));
+                match era {
+                    Some(era) => parsed.era = Some(era),
+                    None => return Ok(None),
+                }
+            }
+            FieldSymbol::Year(Year::Calendar) => match number(rest) {
+                Some((value, digits)) => {
+                    parsed.year = Some(value as i32);
+                    parsed.two_digit_year = field.length == FieldLength::TwoDigit && digits == 2;
+                }
+                None => return Ok(None),
+            },
+            FieldSymbol::Quarter(quarter) => {
+                let value = match field.length {
+                    FieldLength::One | FieldLength::TwoDigit => number(rest)
+                        .map(|(value, _)| value)
+                        .filter(|value| (1..=4).contains(value)),
+                    length => {
+                        let symbols = self.date_symbols()?;
+                        let names = self.lengths(length).flat_map(|length| {
+                            self.quarter_contexts(quarter).flat_map(move |quarter| {
+                                (1..=4).filter_map(move |value| {
+                                    symbols
+                                        .get_symbol_for_quarter(quarter, length, value as usize - 1)
+                                        .ok()
+                                        .map(|name| (name, value))
+                                })
+                            })
+                        });
+                        self.match_name(rest, names)
+                    }
+                };
+                match value {
+                    Some(value) => parsed.quarter = Some((value, start)),
+                    None => return Ok(None),
+                }
+            }
+            FieldSymbol::Month(month) => {
+                let code = match field.length {
+                    FieldLength::One | FieldLength::TwoDigit => number(rest)
+                        .filter(|(value, _)| (1..=13).contains(value))
+                        .and_then(|(value, _)| month_code(value as u8, false)),
+                    length => {
+                        let symbols = self.date_symbols()?;
+                        let codes: Vec<MonthCode> = (1..=13)
+                            .flat_map(|n| [month_code(n, false), month_code(n, true)])
+                            .flatten()
+                            .collect();
+                        let names = self.lengths(length).flat_map(|length| {
+                            let codes = &codes;
+                            self.month_contexts(month).flat_map(move |month| {
+                                codes.iter().flat_map(move |&code| {
+                                    // A month may be named differently in leap years.
+                                    IntoIterator::into_iter([false, true]).filter_map(
+                                        move |is_leap_year| {
+                                            symbols
+                                                .get_symbol_for_month(
+                                                    month,
+                                                    length,
+                                                    code,
+                                                    is_leap_year,
+                                                )
+                                                .ok()
+                                                .map(|name| (name, code))
+                                        },
+                                    )
+                                })
+                            })
+                        });
+                        self.match_name(rest, names)
+                    }
+                };
+                match code {
+                    Some(code) => parsed.month = Some(code),
+                    None => return Ok(None),
+                }
+            }
+            FieldSymbol::Weekday(fields::Weekday::Local | fields::Weekday::StandAlone)
+                if matches!(field.length, FieldLength::One | FieldLength::TwoDigit) =>
+            {
+                // The numeric local day of week depends on week data, and does not add
+                // anything to a date that has been read, so it is only checked for shape.
+                if number(rest)
+                    .filter(|(value, _)| (1..=7).contains(value))
+                    .is_none()
+                {
+                    return Ok(None);
+                }
+            }
+            FieldSymbol::Weekday(weekday) => {
+                let symbols = self.date_symbols()?;
+                let names = self.lengths(field.length).flat_map(|length| {
+                    self.weekday_contexts(weekday).flat_map(move |weekday| {
+                        WEEKDAYS.iter().filter_map(move |&day| {
+                            symbols
+                                .get_symbol_for_weekday(weekday, length, day)
+                                .ok()
+                                .map(|name| (name, day))
+                        })
+                    })
+                });
+                match self.match_name(rest, names) {
+                    Some(day) => parsed.weekday = Some((day, start)),
+                    None => return Ok(None),
+                }
+            }
+            FieldSymbol::Day(fields::Day::DayOfMonth) => match number(rest) {
+                Some((value, _)) => parsed.day = Some(value.min(u8::MAX as u32) as u8),
+                None => return Ok(None),
+            },
+            FieldSymbol::Hour(hour) => match number(rest) {
+                Some((value, _)) => parsed.hour = Some((value, hour, start)),
+                None => return Ok(None),
+            },
+            FieldSymbol::Minute => match number(rest) {
+                Some((value, _)) => parsed.minute = Some(value.min(u8::MAX as u32) as u8),
+                None => return Ok(None),
+            },
+            FieldSymbol::Second(Second::Second) => {
+                match number(rest) {
+                    Some((value, _)) => parsed.second = Some(value.min(u8::MAX as u32) as u8),
+                    None => return Ok(None),
+                }
+                // Formatting writes the fractional seconds together with the seconds,
+                // so they are read here as well.
+                if let Some(PatternItem::Field(Field {
+                    symbol: FieldSymbol::Second(Second::FractionalSecond),
+                    length,
+                })) = next_item
+                {
+                    match self.match_fraction(rest, *length) {
+                        Some(nanosecond) => parsed.nanosecond = Some(nanosecond),
+                        None => return Ok(None),
+                    }
+                }
+            }
+            FieldSymbol::Second(Second::FractionalSecond) => {
+                // Read together with the seconds.
+            }
+            FieldSymbol::DayPeriod(_) => {
+                let symbols = &self
+                    .time_symbols
+                    .ok_or(DateTimeFormatterError::MissingTimeSymbols)?
+                    .day_periods
+                    .format;
+                let names = self.lengths(field.length).flat_map(|length| {
+                    let symbols = match length {
+                        FieldLength::Wide => &symbols.wide,
+                        FieldLength::Narrow => &symbols.narrow,
+                        _ => &symbols.abbreviated,
+                    };
+                    day_period_names(symbols)
+                });
+                match self.match_name(rest, names) {
+                    Some(pm) => parsed.pm = pm,
+                    None => return Ok(None),
+                }
+            }
+            field @ (FieldSymbol::Year(Year::WeekOf | Year::Cyclic | Year::RelatedIso)
+            | FieldSymbol::Week(Week::WeekOfYear | Week::WeekOfMonth)
+            | FieldSymbol::Day(_)
+            | FieldSymbol::Second(Second::Millisecond)
+            | FieldSymbol::TimeZone(_)) => {
+                return Err(DateTimeFormatterError::UnsupportedField(field))
+            }
+        }
+        Ok(Some(()))
+    }
+
+    /// Turns the values read from the input into a [`DateTime`] in `calendar`.
+    ///
+    /// Fields that are not part of the pattern are taken from 1970-01-01T00:00 in the
+    /// given calendar. Two-digit years are placed in the hundred years starting at
+    /// `two_digit_year_start`, which defaults to twenty years before that reference year.
+    fn resolve<A: AsCalendar>(
+        &self,
+        parsed: ParsedFields,
+        two_digit_year_start: Option<i32>,
+        calendar: A,
+    ) -> Result<DateTime<A>, DateTimeFormatterError> {
+        let reference = Date::new_iso_date(1970, 1, 1)?.to_calendar(Ref(calendar.as_calendar()));
+        let reference_year = reference.year();
+
+        let year = match parsed.year {
+            Some(year) if parsed.two_digit_year => {
+                let start = two_digit_year_start.unwrap_or(reference_year.number - 20);
+                start + (year - start.rem_euclid(100)).rem_euclid(100)
+            }
+            Some(year) => year,
+            None => reference_year.number,
+        };
+
+        let hour = match parsed.hour {
+            Some((value, cycle, offset)) => {
+                let (range, hour) = match cycle {
+                    fields::Hour::H11 => (0..=11, value % 12),
+                    fields::Hour::H12 => (1..=12, value % 12),
+                    fields::Hour::H23 => (0..=23, value),
+                    fields::Hour::H24 => (1..=24, value % 24),
+                };
+                if !range.contains(&value) {
+                    return Err(DateTimeFormatterError::ParseMismatch(offset));
+                }
+                let hour = match cycle {
+                    fields::Hour::H11 | fields::Hour::H12 if parsed.pm => hour + 12,
+                    _ => hour,
+                };
+                hour as u8
+            }
+            None => 0,
+        };
+
+        let time = Time::try_new(
+            hour,
+            parsed.minute.unwrap_or(0),
+            parsed.second.unwrap_or(0),
+            parsed.nanosecond.unwrap_or(0),
+        )?;
+        let datetime = DateTime::new_from_codes(
+            parsed.era.unwrap_or(reference_year.era),
+            year,
+            parsed.month.unwrap_or_else(|| reference.month().code),
+            parsed
+                .day
+                .unwrap_or_else(|| reference.day_of_month().0 as u8),
+            time,
+            calendar,
+        )?;
+
+        if !self.is_lenient() {
+            if let Some((weekday, offset)) = parsed.weekday {
+                if datetime.date.day_of_week() != weekday {
+                    return Err(DateTimeFormatterError::ParseMismatch(offset));
+                }
+            }
+            if let Some((quarter, offset)) = parsed.quarter {
+                // Calendars with a thirteenth month fold it into the last quarter.
+                let expected =
+                    core::cmp::min(datetime.date.month().ordinal.saturating_sub(1) / 3, 3);
+                if quarter != expected + 1 {
+                    return Err(DateTimeFormatterError::ParseMismatch(offset));
+                }
+            }
+        }
+
+        Ok(datetime)
+    }
+
+    fn date_symbols(&self) -> Result<&'l DateSymbolsV1<'l>, DateTimeFormatterError> {
+        self.date_symbols
+            .ok_or(DateTimeFormatterError::MissingDateSymbols)
+    }
+
+    /// The name widths to try for a field of the given length.
+    fn lengths(&self, length: FieldLength) -> impl Iterator<Item = FieldLength> {
+        let lenient: &[FieldLength] = if self.is_lenient() {
+            &LENIENT_LENGTHS
+        } else {
+            &[]
+        };
+        core::iter::once(length).chain(lenient.iter().copied().filter(move |&l| l != length))
+    }
+
+    fn month_contexts(&self, month: fields::Month) -> impl Iterator<Item = fields::Month> {
+        let other = match month {
+            fields::Month::Format => fields::Month::StandAlone,
+            fields::Month::StandAlone => fields::Month::Format,
+        };
+        core::iter::once(month).chain(Some(other).filter(|_| self.is_lenient()))
+    }
+
+    fn quarter_contexts(&self, quarter: fields::Quarter) -> impl Iterator<Item = fields::Quarter> {
+        let other = match quarter {
+            fields::Quarter::Format => fields::Quarter::StandAlone,
+            fields::Quarter::StandAlone => fields::Quarter::Format,
+        };
+        core::iter::once(quarter).chain(Some(other).filter(|_| self.is_lenient()))
+    }
+
+    fn weekday_contexts(&self, weekday: fields::Weekday) -> impl Iterator<Item = fields::Weekday> {
+        let other = match weekday {
+            fields::Weekday::StandAlone => fields::Weekday::Format,
+            _ => fields::Weekday::StandAlone,
+        };
+        core::iter::once(weekday).chain(Some(other).filter(|_| self.is_lenient()))
+    }
+
+    /// The era names for a field of the given length, paired with their era codes.
+    fn era_names(&self, length: FieldLength) -> impl Iterator<Item = (&'l str, &'l str)> + '_ {
+        let eras = self.date_symbols.map(|symbols| &symbols.eras);
+        self.lengths(length).flat_map(move |length| {
+            eras.into_iter().flat_map(move |eras| {
+                let names = match length {
+                    FieldLength::Wide => &eras.names,
+                    FieldLength::Narrow => &eras.narrow,
+                    _ => &eras.abbr,
+                };
+                names.iter().map(|(code, name)| (name, code))
+            })
+        })
+    }
+
+    /// Consumes a literal character of the pattern.
+    fn match_literal(&self, rest: &mut &str, literal: char) -> bool {
+        if self.is_lenient() && literal.is_whitespace() {
+            // Any run of whitespace, including none at all, stands in for a whitespace literal.
+            *rest = rest.trim_start();
+            return true;
+        }
+        if let Some(remaining) = rest.strip_prefix(literal) {
+            *rest = remaining;
+            return true;
+        }
+        if !self.is_lenient() {
+            return false;
+        }
+        match rest.chars().next() {
+            Some(ch) if chars_eq_ignore_case(ch, literal) => {
+                *rest = rest.get(ch.len_utf8()..).unwrap_or_default();
+                true
+            }
+            // Punctuation may be left out.
+            _ => !literal.is_alphanumeric(),
+        }
+    }
+
+    /// Reads between `min` and `max` digits and returns their value and count. Values that
+    /// do not fit a `u32` are not matched.
+    fn match_number(&self, rest: &mut &str, min: usize, max: usize) -> Option<(u32, usize)> {
+        let mut value = 0u32;
+        let mut count = 0;
+        let mut consumed = 0;
+        for ch in rest.chars().take(max) {
+            let digit = match self.digits.iter().position(|&d| d == ch) {
+                Some(digit) => digit as u32,
+                None if self.is_lenient() => match ch.to_digit(10) {
+                    Some(digit) => digit,
+                    None => break,
+                },
+                None => break,
+            };
+            value = value.checked_mul(10)?.checked_add(digit)?;
+            count += 1;
+            consumed += ch.len_utf8();
+        }
+        if count < min.max(1) {
+            return None;
+        }
+        *rest = rest.get(consumed..)?;
+        Some((value, count))
+    }
+
+    /// Reads a decimal separator followed by fractional seconds, and returns them as nanoseconds.
+    fn match_fraction(&self, rest: &mut &str, length: FieldLength) -> Option<u32> {
+        let separator_len = match rest.strip_prefix(self.decimal_separator.as_str()) {
+            Some(_) => self.decimal_separator.len(),
+            None if self.is_lenient() && rest.starts_with(['.', ',']) => 1,
+            None => return None,
+        };
+        *rest = rest.get(separator_len..)?;
+        let (min, max) = match (self.leniency, length) {
+            (Leniency::Strict, length) => (length.to_len(), length.to_len()),
+            _ => (1, MAX_DIGITS),
+        };
+        let (value, digits) = self.match_number(rest, min.min(MAX_DIGITS), max.min(MAX_DIGITS))?;
+        if max > MAX_DIGITS {
+            // Digits past nanoseconds are read, but do not add to the value.
+            self.match_number(rest, min - MAX_DIGITS, max - MAX_DIGITS)?;
+        }
+        Some(value * 10u32.pow((MAX_DIGITS - digits) as u32))
+    }
+
+    /// Consumes the longest of `names` that the input starts with, and returns its value.
+    fn match_name<'a, T>(
+        &self,
+        rest: &mut &str,
+        names: impl Iterator<Item = (&'a str, T)>,
+    ) -> Option<T> {
+        let mut best: Option<(usize, T)> = None;
+        for (name, value) in names {
+            if let Some(len) = self.match_prefix(rest, name) {
+                if !matches!(best, Some((best_len, _)) if best_len >= len) {
+                    best = Some((len, value));
+                }
+            }
+        }
+        let (len, value) = best?;
+        *rest = rest.get(len..)?;
+        if self.is_lenient() {
+            // Tolerate an abbreviation dot that the data does not have.
+            if let Some(remaining) = rest.strip_prefix('.') {
+                *rest = remaining;
+            }
+        }
+        Some(value)
+    }
+
+    /// Returns the number of bytes of `input` that `name` matches, if any.
+    fn match_prefix(&self, input: &str, name: &str) -> Option<usize> {
+        if name.is_empty() {
+            return None;
+        }
+        if !self.is_lenient() {
+            return if input.starts_with(name) {
+                Some(name.len())
+            } else {
+                None
+            };
+        }
+        let mut consumed = 0;
+        let mut name_chars = name.chars();
+        for ch in input.chars() {
+            match name_chars.next() {
+                Some(expected) if chars_eq_ignore_case(ch, expected) => consumed += ch.len_utf8(),
+                Some(_) => break,
+                None => return Some(consumed),
+            }
+        }
+        match name_chars.as_str() {
+            // The name was fully matched, or only its abbreviation dot is missing.
+            "" | "." if consumed > 0 => Some(consumed),
+            _ => None,
+        }
+    }
+}
+
+fn chars_eq_ignore_case(a: char, b: char) -> bool {
+    a == b || a.to_lowercase().eq(b.to_lowercase())
+}
+
+/// Whether a field is formatted as a number.
+fn is_numeric(field: &Field) -> bool {
+    match field.symbol {
+        FieldSymbol::Era | FieldSymbol::DayPeriod(_) | FieldSymbol::TimeZone(_) => false,
+        FieldSymbol::Weekday(fields::Weekday::Format) => false,
+        FieldSymbol::Month(_) | FieldSymbol::Quarter(_) | FieldSymbol::Weekday(_) => {
+            matches!(field.length, FieldLength::One | FieldLength::TwoDigit)
+        }
+        _ => true,
+    }
+}
+
+fn month_code(ordinal: u8, leap: bool) -> Option<MonthCode> {
+    let digits = [b'M', b'0' + ordinal / 10, b'0' + ordinal % 10, b'L'];
+    let bytes = if leap { &digits[..] } else { digits.get(..3)? };
+    TinyStr4::from_bytes(bytes).ok().map(MonthCode)
+}
+
+/// The day period names, paired with whether they are after noon.
+fn day_period_names<'a>(
+    symbols: &'a day_periods::SymbolsV1<'a>,
+) -> impl Iterator<Item = (&'a str, bool)> {
+    IntoIterator::into_iter([
+        (Some(&*symbols.am), false),
+        (Some(&*symbols.pm), true),
+        (symbols.midnight.as_deref(), false),
+        (symbols.noon.as_deref(), true),
+    ])
+    .filter_map(|(name, pm)| name.map(|name| (name, pm)))
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde")]
+mod tests {
+    use super::*;
+    use crate::options::length;
+    use crate::TypedDateTimeFormatter;
+    use icu_calendar::Gregorian;
+    use icu_locid::Locale;
+
+    fn formatter(locale: &str, options: length::Bag) -> TypedDateTimeFormatter<Gregorian> {
+        let locale: Locale = locale.parse().unwrap();
+        TypedDateTimeFormatter::try_new_with_buffer_provider(
+            &icu_testdata::get_provider(),
+            &locale.into(),
+            options.into(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let datetime = DateTime::new_gregorian_datetime(2023, 1, 5, 18, 7, 9).unwrap();
+        for locale in ["en", "fr", "es-AR", "ar-EG", "bn", "ja", "th"] {
+            for date in [
+                length::Date::Full,
+                length::Date::Medium,
+                length::Date::Short,
+            ] {
+                let dtf = formatter(
+                    locale,
+                    length::Bag::from_date_time_style(date, length::Time::Medium),
+                );
+                let formatted = dtf.format_to_string(&datetime);
+                let parsed = dtf.parse(&formatted, Leniency::Strict, Gregorian);
+                assert_eq!(
+                    parsed.ok(),
+                    Some(datetime.clone()),
+                    "{}: {}",
+                    locale,
+                    formatted
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_lenient() {
+        let dtf = formatter("fr", length::Bag::from_date_style(length::Date::Medium));
+        let expected = DateTime::new_gregorian_datetime(2023, 1, 5, 0, 0, 0).unwrap();
+        for input in [
+            "5 janv. 2023",
+            "5 JANV 2023",
+            "05  janvier 2023 ",
+            "5 janv.2023",
+        ] {
+            assert_eq!(
+                dtf.parse(input, Leniency::Lenient, Gregorian).ok(),
+                Some(expected.clone())
+            );
+            assert_eq!(
+                dtf.parse(input, Leniency::Strict, Gregorian).is_ok(),
+                input == "5 janv. 2023",
+                "{}",
+                input
+            );
+        }
+
+        let dtf = formatter("en", length::Bag::from_date_style(length::Date::Medium));
+        assert_eq!(
+            dtf.parse("jan 5 2023", Leniency::Lenient, Gregorian).ok(),
+            Some(expected.clone())
+        );
+        assert!(dtf
+            .parse("jan 5 2023", Leniency::Strict, Gregorian)
+            .is_err());
+
+        // Egyptian Arabic formats with Arabic-Indic digits, but ASCII digits are read leniently.
+        let dtf = formatter("ar-EG", length::Bag::from_date_style(length::Date::Short));
+        assert_eq!(
+            dtf.parse(
+                "\u{665}\u{200f}/\u{661}\u{200f}/\u{662}\u{660}\u{662}\u{663}",
+                Leniency::Strict,
+                Gregorian
+            )
+            .ok(),
+            Some(expected.clone())
+        );
+        assert_eq!(
+            dtf.parse("5\u{200f}/1\u{200f}/2023", Leniency::Lenient, Gregorian)
+                .ok(),
+            Some(expected)
+        );
+        assert!(dtf
+            .parse("5\u{200f}/1\u{200f}/2023", Leniency::Strict, Gregorian)
+            .is_err());
+    }
+
+    #[test]
+    fn test_strict_errors() {
+        let dtf = formatter(
+            "en",
+            length::Bag::from_date_time_style(length::Date::Full, length::Time::Short),
+        );
+        assert!(matches!(
+            dtf.parse(
+                "Friday, January 5, 2023 at 6:07 PM",
+                Leniency::Strict,
+                Gregorian
+            ),
+            Err(DateTimeFormatterError::ParseMismatch(0))
+        ));
+        assert!(matches!(
+            dtf.parse(
+                "Thursday, January 5, 2023 at 6:07 PM!",
+                Leniency::Strict,
+                Gregorian
+            ),
+            Err(DateTimeFormatterError::ParseMismatch(36))
+        ));
+        assert!(matches!(
+            dtf.parse(
+                "Thursday, January 5, 2023 at 13:07 PM",
+                Leniency::Strict,
+                Gregorian
+            ),
+            Err(DateTimeFormatterError::ParseMismatch(29))
+        ));
+        assert!(matches!(
+            dtf.parse(
+                "Thursday, January 32, 2023 at 6:07 PM",
+                Leniency::Strict,
+                Gregorian
+            ),
+            Err(DateTimeFormatterError::DateTimeInput(_))
+        ));
+    }
+
+    #[test]
+    fn test_two_digit_year() {
+        let dtf = formatter("en", length::Bag::from_date_style(length::Date::Short));
+        for (input, year) in [("1/5/23", 2023), ("1/5/49", 2049), ("1/5/50", 1950)] {
+            assert_eq!(
+                dtf.parse(input, Leniency::Strict, Gregorian).ok(),
+                Some(DateTime::new_gregorian_datetime(year, 1, 5, 0, 0, 0).unwrap())
+            );
+        }
+        assert_eq!(
+            dtf.parse("1/5/1923", Leniency::Lenient, Gregorian).ok(),
+            Some(DateTime::new_gregorian_datetime(1923, 1, 5, 0, 0, 0).unwrap())
+        );
+        for (input, year) in [("1/5/05", 2105), ("1/5/06", 2006), ("1/5/99", 2099)] {
+            assert_eq!(
+                dtf.parse_with_two_digit_year_start(input, Leniency::Strict, 2006, Gregorian)
+                    .ok(),
+                Some(DateTime::new_gregorian_datetime(year, 1, 5, 0, 0, 0).unwrap()),
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_long_numbers() {
+        let parse = |pattern: &str, input: &str, leniency| {
+            TypedDateTimeFormatter::<Gregorian>::try_new_with_pattern_unstable(
+                &icu_testdata::get_provider(),
+                &"en".parse::<Locale>().unwrap().into(),
+                pattern,
+            )
+            .unwrap()
+            .parse(input, leniency, Gregorian)
+        };
+
+        // Numbers are read up to nine digits, which always fit in a `u32`
+        for leniency in [Leniency::Strict, Leniency::Lenient] {
+            assert!(matches!(
+                parse("y", "99999999999999999999", leniency),
+                Err(DateTimeFormatterError::ParseMismatch(9))
+            ));
+        }
+
+        // Digits past nanoseconds are read, but do not add to the value
+        let datetime = DateTime::new_gregorian_datetime(2023, 1, 5, 18, 7, 9).unwrap();
+        for (pattern, input) in [
+            (
+                "y-MM-dd HH:mm:ssSSSSSSSSSS",
+                "2023-01-05 18:07:09.1234567890",
+            ),
+            (
+                "y-MM-dd HH:mm:ssSSSSSSSSSSSS",
+                "2023-01-05 18:07:09.123456789012",
+            ),
+        ] {
+            let parsed = parse(pattern, input, Leniency::Strict).unwrap();
+            assert_eq!(parsed.date, datetime.date, "{}", pattern);
+            assert_eq!(parsed.time.nanosecond.number(), 123_456_789, "{}", pattern);
+        }
+        assert!(parse(
+            "y-MM-dd HH:mm:ssSSSSSSSSSS",
+            "2023-01-05 18:07:09.123456789",
+            Leniency::Strict
+        )
+        .is_err());
+    }
+}
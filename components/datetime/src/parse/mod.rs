@@ -0,0 +1,51 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Reading localized date and time strings back into [`DateTime`](icu_calendar::DateTime)s.
+
+pub(crate) mod datetime;
+
+/// How closely the input of a `parse` call has to follow the pattern of the formatter.
+///
+/// # Examples
+///
+/// ```
+/// use icu::calendar::{DateTime, Gregorian};
+/// use icu::datetime::{options::length, Leniency, TypedDateTimeFormatter};
+/// use icu::locid::locale;
+///
+/// let provider = icu_testdata::get_provider();
+/// let options = length::Bag::from_date_style(length::Date::Medium).into();
+/// let dtf = TypedDateTimeFormatter::<Gregorian>::try_new_with_buffer_provider(
+///     &provider,
+///     &locale!("fr").into(),
+///     options,
+/// )
+/// .expect("Failed to create TypedDateTimeFormatter instance.");
+///
+/// // The pattern is "d MMM y".
+/// assert!(dtf.parse("5 janv. 2023", Leniency::Strict, Gregorian).is_ok());
+/// assert!(dtf.parse("5 JANV 2023", Leniency::Strict, Gregorian).is_err());
+///
+/// let datetime = dtf
+///     .parse("5 JANV 2023", Leniency::Lenient, Gregorian)
+///     .expect("Lenient parsing ignores case and the abbreviation dot");
+/// assert_eq!(
+///     datetime,
+///     DateTime::new_gregorian_datetime(2023, 1, 5, 0, 0, 0).unwrap()
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Leniency {
+    /// The input has to be exactly what the formatter would produce for some date: literals
+    /// match exactly, numbers have the padded width of their field, and names have the width
+    /// of their field. Weekdays and quarters must agree with the resulting date.
+    Strict,
+    /// Names are matched case-insensitively in any width, with or without a trailing period,
+    /// numbers may have any width, any run of whitespace matches a whitespace literal,
+    /// punctuation literals may be left out, and ASCII digits are accepted in addition to the
+    /// digits of the locale.
+    Lenient,
+}
@@ -0,0 +1,136 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! The [`Part`]s used by [`FormattedDateTime`](crate::FormattedDateTime),
+//! [`FormattedTimeZone`](crate::FormattedTimeZone) and
+//! [`FormattedZonedDateTime`](crate::FormattedZonedDateTime).
+//!
+//! Every field of the pattern is written as a single part, and every run of literal text
+//! between fields as a [`LITERAL`] part. Seconds with fractional digits are split into
+//! [`SECOND`], a [`LITERAL`] for the decimal separator, and [`FRACTIONAL_SECOND`].
+//!
+//! # Examples
+//!
+//! ```
+//! use icu::calendar::{DateTime, Gregorian};
+//! use icu::datetime::{options::length, parts, TypedDateTimeFormatter};
+//! use icu::locid::locale;
+//! use writeable::assert_writeable_parts_eq;
+//!
+//! let provider = icu_testdata::get_provider();
+//! let options = length::Bag::from_date_time_style(length::Date::Medium, length::Time::Short);
+//! let dtf = TypedDateTimeFormatter::<Gregorian>::try_new_with_buffer_provider(
+//!     &provider,
+//!     &locale!("en").into(),
+//!     options.into(),
+//! )
+//! .expect("Failed to create TypedDateTimeFormatter instance.");
+//!
+//! let datetime = DateTime::new_gregorian_datetime(2020, 9, 1, 12, 34, 28)
+//!     .expect("Failed to construct DateTime.");
+//!
+//! assert_writeable_parts_eq!(
+//!     dtf.format(&datetime),
+//!     "Sep 1, 2020, 12:34 PM",
+//!     [
+//!         (0, 3, parts::MONTH),
+//!         (3, 4, parts::LITERAL),
+//!         (4, 5, parts::DAY),
+//!         (5, 7, parts::LITERAL),
+//!         (7, 11, parts::YEAR),
+//!         (11, 13, parts::LITERAL),
+//!         (13, 15, parts::HOUR),
+//!         (15, 16, parts::LITERAL),
+//!         (16, 18, parts::MINUTE),
+//!         (18, 19, parts::LITERAL),
+//!         (19, 21, parts::DAY_PERIOD),
+//!     ]
+//! );
+//! ```
+
+use writeable::Part;
+
+/// The [`Part`] used for the era, such as "AD".
+pub const ERA: Part = Part {
+    category: "datetime",
+    value: "era",
+};
+
+/// The [`Part`] used for the year and the week-based year.
+pub const YEAR: Part = Part {
+    category: "datetime",
+    value: "year",
+};
+
+/// The [`Part`] used for the quarter.
+pub const QUARTER: Part = Part {
+    category: "datetime",
+    value: "quarter",
+};
+
+/// The [`Part`] used for the month.
+pub const MONTH: Part = Part {
+    category: "datetime",
+    value: "month",
+};
+
+/// The [`Part`] used for the week of the year or of the month.
+pub const WEEK: Part = Part {
+    category: "datetime",
+    value: "week",
+};
+
+/// The [`Part`] used for the day of the month, and for the other day counts.
+pub const DAY: Part = Part {
+    category: "datetime",
+    value: "day",
+};
+
+/// The [`Part`] used for the day of the week.
+pub const WEEKDAY: Part = Part {
+    category: "datetime",
+    value: "weekday",
+};
+
+/// The [`Part`] used for the day period, such as "PM".
+pub const DAY_PERIOD: Part = Part {
+    category: "datetime",
+    value: "dayPeriod",
+};
+
+/// The [`Part`] used for the hour.
+pub const HOUR: Part = Part {
+    category: "datetime",
+    value: "hour",
+};
+
+/// The [`Part`] used for the minute.
+pub const MINUTE: Part = Part {
+    category: "datetime",
+    value: "minute",
+};
+
+/// The [`Part`] used for the whole seconds.
+pub const SECOND: Part = Part {
+    category: "datetime",
+    value: "second",
+};
+
+/// The [`Part`] used for the digits after the decimal separator of the seconds.
+pub const FRACTIONAL_SECOND: Part = Part {
+    category: "datetime",
+    value: "fractionalSecond",
+};
+
+/// The [`Part`] used for the time zone, in any of its formats.
+pub const TIME_ZONE_NAME: Part = Part {
+    category: "datetime",
+    value: "timeZoneName",
+};
+
+/// The [`Part`] used for text between fields, such as ", " or " at ".
+pub const LITERAL: Part = Part {
+    category: "datetime",
+    value: "literal",
+};
@@ -97,12 +97,16 @@ impl CoarseHourCycle {
         match skeleton::create_best_pattern_for_fields(
             skeletons,
             date_time,
+            // Only the hour symbol changes, so there are no missing fields to append.
+            None,
             skeleton.as_slice(),
             &Default::default(),
             // Prefer using the matched pattern directly, rather than mutating it to match the
             // requested fields.
             true,
-        ) {
+        )
+        .ok()?
+        {
             skeleton::BestSkeleton::AllFieldsMatch(patterns)
             | skeleton::BestSkeleton::MissingOrExtraFields(patterns) => {
                 Some(reference::Pattern::from(&patterns.expect_pattern(
@@ -124,6 +128,7 @@ pub(crate) fn naively_apply_preferences(
     // If there is a preference overiding the hour cycle, apply it now.
     if let Some(preferences::Bag {
         hour_cycle: Some(hour_cycle),
+        ..
     }) = preferences
     {
         runtime::helpers::maybe_replace_first(pattern, |item| {
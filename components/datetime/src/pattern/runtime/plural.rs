@@ -118,6 +118,18 @@ impl<'data> PluralPattern<'data> {
         f(&mut self.other);
     }
 
+    pub fn try_for_each_mut<F, E>(&mut self, f: &F) -> Result<(), E>
+    where
+        F: Fn(&mut Pattern<'data>) -> Result<(), E>,
+    {
+        self.zero.iter_mut().try_for_each(f)?;
+        self.one.iter_mut().try_for_each(f)?;
+        self.two.iter_mut().try_for_each(f)?;
+        self.few.iter_mut().try_for_each(f)?;
+        self.many.iter_mut().try_for_each(f)?;
+        f(&mut self.other)
+    }
+
     pub fn into_owned(self) -> PluralPattern<'static> {
         PluralPattern {
             pivot_field: self.pivot_field,
@@ -198,6 +210,16 @@ impl<'data> PatternPlurals<'data> {
         }
     }
 
+    pub fn try_for_each_mut<F, E>(&mut self, f: F) -> Result<(), E>
+    where
+        F: Fn(&mut Pattern<'data>) -> Result<(), E>,
+    {
+        match self {
+            Self::SinglePattern(pattern) => f(pattern),
+            Self::MultipleVariants(variants) => variants.try_for_each_mut(&f),
+        }
+    }
+
     pub fn expect_pattern(self, msg: &str) -> Pattern<'data> {
         match self {
             Self::SinglePattern(pattern) => pattern,
@@ -0,0 +1,311 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::{
+    calendar,
+    options::{components, preferences},
+    pattern::{hour_cycle, runtime::PatternPlurals},
+    provider::{
+        calendar::{
+            BuddhistDateLengthsV1Marker, ChineseDateLengthsV1Marker, CopticDateLengthsV1Marker,
+            DangiDateLengthsV1Marker, DateAppendItemsV1Marker, DateSkeletonPatternsV1Marker,
+            ErasedDateLengthsV1Marker, EthiopianDateLengthsV1Marker, GregorianDateLengthsV1Marker,
+            HebrewDateLengthsV1Marker, IndianDateLengthsV1Marker, IslamicDateLengthsV1Marker,
+            JapaneseDateLengthsV1Marker, JapaneseExtendedDateLengthsV1Marker,
+            PersianDateLengthsV1Marker, RocDateLengthsV1Marker,
+        },
+        date_time,
+        time_data::TimeDataV1Marker,
+    },
+    skeleton::{self, reference::Skeleton},
+    DateTimeFormatterError,
+};
+use alloc::string::{String, ToString};
+use core::convert::TryFrom;
+use icu_calendar::any_calendar::AnyCalendarKind;
+use icu_provider::prelude::*;
+
+/// [`DateTimePatternGenerator`] resolves a skeleton, such as `"yMMMd"`, to the pattern that a
+/// locale uses for those fields in a given calendar, such as `"MMM d, y"` in English.
+///
+/// This is the same matching that [`DateTimeFormatter`](crate::DateTimeFormatter) performs for
+/// a [`components::Bag`], so it can be used to show which pattern a locale will use without
+/// formatting a value.
+///
+/// Enabled with the `"experimental"` feature.
+///
+/// # Examples
+///
+/// ```
+/// use icu::calendar::any_calendar::AnyCalendarKind;
+/// use icu::datetime::DateTimePatternGenerator;
+/// use icu::locid::locale;
+///
+/// let provider = icu_testdata::get_provider();
+///
+/// let generator = DateTimePatternGenerator::try_new_with_buffer_provider(
+///     &provider,
+///     &locale!("en").into(),
+///     AnyCalendarKind::Gregorian,
+/// )
+/// .expect("Failed to create DateTimePatternGenerator instance.");
+///
+/// assert_eq!(generator.get_best_pattern("yMMMd").unwrap(), "MMM d, y");
+/// assert_eq!(generator.get_best_pattern("yMMMMEEEEd").unwrap(), "EEEE, MMMM d, y");
+/// assert_eq!(generator.get_best_pattern("jm").unwrap(), "h:mm a");
+///
+/// assert!(generator.skeletons().any(|skeleton| skeleton == "yMMMd"));
+/// ```
+#[derive(Debug)]
+pub struct DateTimePatternGenerator {
+    skeletons: DataPayload<DateSkeletonPatternsV1Marker>,
+    append_items: DataPayload<DateAppendItemsV1Marker>,
+    date_lengths: DataPayload<ErasedDateLengthsV1Marker>,
+    preferences: Option<preferences::Bag>,
+    hour_cycle: preferences::HourCycle,
+    allowed_hour_cycle: preferences::HourCycle,
+}
+
+impl DateTimePatternGenerator {
+    /// Constructor that takes a selected locale, reference to a [data provider] and the kind of
+    /// calendar, then collects the skeleton data of that calendar in the given locale.
+    ///
+    /// A `-u-hc` or `-u-rg` Unicode extension in the locale overrides the hour cycle of the
+    /// locale, see [`DateTimePatternGenerator::hour_cycle`].
+    ///
+    /// [data provider]: icu_provider
+    #[inline(never)]
+    pub fn try_new_unstable<D>(
+        data_provider: &D,
+        locale: &DataLocale,
+        calendar: AnyCalendarKind,
+    ) -> Result<Self, DateTimeFormatterError>
+    where
+        D: DataProvider<DateSkeletonPatternsV1Marker>
+            + DataProvider<DateAppendItemsV1Marker>
+            + DataProvider<TimeDataV1Marker>
+            + DataProvider<GregorianDateLengthsV1Marker>
+            + DataProvider<BuddhistDateLengthsV1Marker>
+            + DataProvider<JapaneseDateLengthsV1Marker>
+            + DataProvider<JapaneseExtendedDateLengthsV1Marker>
+            + DataProvider<CopticDateLengthsV1Marker>
+            + DataProvider<ChineseDateLengthsV1Marker>
+            + DataProvider<DangiDateLengthsV1Marker>
+            + DataProvider<HebrewDateLengthsV1Marker>
+            + DataProvider<PersianDateLengthsV1Marker>
+            + DataProvider<RocDateLengthsV1Marker>
+            + DataProvider<IslamicDateLengthsV1Marker>
+            + DataProvider<IndianDateLengthsV1Marker>
+            + DataProvider<EthiopianDateLengthsV1Marker>
+            + ?Sized,
+    {
+        let cal_val = calendar.as_bcp47_value();
+        let skeletons = date_time::skeleton_data_payload(data_provider, locale, &cal_val)?;
+        let append_items = date_time::skeleton_data_payload(data_provider, locale, &cal_val)?;
+        let date_lengths =
+            calendar::load_lengths_for_any_calendar_kind(data_provider, locale, calendar)?;
+
+        let preferences = date_time::preferences_for_locale(data_provider, locale)?;
+        let time_data = *date_time::time_data_payload(data_provider, locale)?.get();
+        let hour_cycle = preferences
+            .hour_cycle
+            .unwrap_or(time_data.preferred_hour_cycle);
+        let allowed_hour_cycle = preferences::Bag::from_data_locale(locale)
+            .hour_cycle
+            .unwrap_or(time_data.allowed_hour_cycle);
+
+        Ok(Self {
+            skeletons,
+            append_items,
+            date_lengths,
+            preferences: Some(preferences).filter(|prefs| prefs.hour_cycle.is_some()),
+            hour_cycle,
+            allowed_hour_cycle,
+        })
+    }
+
+    /// Create a new instance using an [`AnyProvider`](icu_provider::AnyProvider).
+    ///
+    /// See also: [`Self::try_new_unstable`]
+    pub fn try_new_with_any_provider(
+        provider: &(impl AnyProvider + ?Sized),
+        locale: &DataLocale,
+        calendar: AnyCalendarKind,
+    ) -> Result<Self, DateTimeFormatterError> {
+        Self::try_new_unstable(&provider.as_downcasting(), locale, calendar)
+    }
+
+    /// Create a new instance using a [`BufferProvider`](icu_provider::BufferProvider). Enabled with the `"serde"` feature.
+    ///
+    /// See also: [`Self::try_new_unstable`]
+    #[cfg(feature = "serde")]
+    pub fn try_new_with_buffer_provider(
+        provider: &(impl BufferProvider + ?Sized),
+        locale: &DataLocale,
+        calendar: AnyCalendarKind,
+    ) -> Result<Self, DateTimeFormatterError> {
+        Self::try_new_unstable(&provider.as_deserializing(), locale, calendar)
+    }
+
+    /// Returns the pattern that best matches the fields of `skeleton`, in the
+    /// [CLDR pattern syntax].
+    ///
+    /// The skeleton uses the same field symbols as patterns, in any order and without literals.
+    /// The symbol `j` requests the hour in the [hour cycle](Self::hour_cycle) of the locale, with a
+    /// day period if the hour cycle needs one, and `J` requests it without a day period. The
+    /// symbol `C` requests the hour in the most common of the hour cycles allowed in the region of
+    /// the locale, which CLDR may list in a different order than the preferred one. Fields
+    /// that no pattern of the locale contains are appended to the best match, and the widths of
+    /// the matched fields are adjusted to the requested ones.
+    ///
+    /// If the best pattern depends on the plural form of a week number, the pattern of the
+    /// `other` form is returned.
+    ///
+    /// Returns [`DateTimeFormatterError::Skeleton`] if the skeleton cannot be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::calendar::any_calendar::AnyCalendarKind;
+    /// use icu::datetime::DateTimePatternGenerator;
+    /// use icu::locid::locale;
+    ///
+    /// let provider = icu_testdata::get_provider();
+    ///
+    /// let generator = DateTimePatternGenerator::try_new_with_buffer_provider(
+    ///     &provider,
+    ///     &locale!("fr").into(),
+    ///     AnyCalendarKind::Gregorian,
+    /// )
+    /// .expect("Failed to create DateTimePatternGenerator instance.");
+    ///
+    /// assert_eq!(generator.get_best_pattern("yMMMd").unwrap(), "d MMM y");
+    /// assert_eq!(generator.get_best_pattern("jms").unwrap(), "HH:mm:ss");
+    /// assert!(generator.get_best_pattern("yMMMd!").is_err());
+    /// ```
+    ///
+    /// [CLDR pattern syntax]: https://unicode.org/reports/tr35/tr35-dates.html#Date_Field_Symbol_Table
+    pub fn get_best_pattern(&self, skeleton: &str) -> Result<String, DateTimeFormatterError> {
+        // `j` and `C` request the hour of the locale with its day period, if any, and `J` requests
+        // it without a day period. The skeletons of the data only use `h` and `H`, so the hour is
+        // matched as one of those and then adjusted to the requested hour cycle.
+        let mut requested_hour_cycle = None;
+        let skeleton = skeleton
+            .chars()
+            .map(|ch| {
+                let hour_cycle = match ch {
+                    'j' | 'J' => self.hour_cycle,
+                    'C' => self.allowed_hour_cycle,
+                    ch => return ch,
+                };
+                requested_hour_cycle = Some(hour_cycle);
+                match (ch, hour_cycle) {
+                    ('J', _)
+                    | (_, preferences::HourCycle::H23)
+                    | (_, preferences::HourCycle::H24) => 'H',
+                    (_, preferences::HourCycle::H11) | (_, preferences::HourCycle::H12) => 'h',
+                }
+            })
+            .collect::<String>();
+        let skeleton = Skeleton::try_from(skeleton.as_str())?;
+
+        let components = components::Bag {
+            preferences: self.preferences,
+            ..Default::default()
+        };
+
+        match skeleton::create_best_pattern_for_fields(
+            self.skeletons.get(),
+            &self.date_lengths.get().length_combinations,
+            Some(self.append_items.get()),
+            skeleton.as_slice(),
+            &components,
+            false, // Prefer the requested fields over the matched pattern.
+        )? {
+            skeleton::BestSkeleton::AllFieldsMatch(patterns)
+            | skeleton::BestSkeleton::MissingOrExtraFields(patterns) => {
+                let mut pattern = match patterns {
+                    PatternPlurals::SinglePattern(pattern) => pattern,
+                    PatternPlurals::MultipleVariants(variants) => variants.other,
+                };
+                if let Some(hour_cycle) = requested_hour_cycle {
+                    hour_cycle::naively_apply_preferences(
+                        &mut pattern,
+                        &Some(preferences::Bag::from_hour_cycle(hour_cycle)),
+                    );
+                }
+                Ok(pattern.to_string())
+            }
+            skeleton::BestSkeleton::NoMatch => Err(DateTimeFormatterError::UnsupportedOptions),
+        }
+    }
+
+    /// Returns the skeletons for which the locale has a pattern, in their canonical field order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::calendar::any_calendar::AnyCalendarKind;
+    /// use icu::datetime::DateTimePatternGenerator;
+    /// use icu::locid::locale;
+    ///
+    /// let provider = icu_testdata::get_provider();
+    ///
+    /// let generator = DateTimePatternGenerator::try_new_with_buffer_provider(
+    ///     &provider,
+    ///     &locale!("en").into(),
+    ///     AnyCalendarKind::Gregorian,
+    /// )
+    /// .expect("Failed to create DateTimePatternGenerator instance.");
+    ///
+    /// for skeleton in generator.skeletons() {
+    ///     assert!(generator.get_best_pattern(&skeleton).is_ok());
+    /// }
+    /// ```
+    pub fn skeletons(&self) -> impl Iterator<Item = String> + '_ {
+        self.skeletons
+            .get()
+            .0
+            .iter_keys()
+            .map(|skeleton| skeleton.0.to_string())
+    }
+
+    /// Returns the hour cycle that the `j` symbol of a skeleton resolves to, and that the hours
+    /// of the resolved patterns use.
+    ///
+    /// This is the hour cycle of the `-u-hc` Unicode extension of the locale if it has one, and
+    /// the hour cycle that CLDR prefers in the region of the locale otherwise, where the region
+    /// may be overridden with a `-u-rg` extension. With a `-u-hc` or `-u-rg` extension, the hours
+    /// of all resolved patterns are adjusted to it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::calendar::any_calendar::AnyCalendarKind;
+    /// use icu::datetime::{options::preferences::HourCycle, DateTimePatternGenerator};
+    /// use icu::locid::locale;
+    ///
+    /// let provider = icu_testdata::get_provider();
+    ///
+    /// let generator = DateTimePatternGenerator::try_new_with_buffer_provider(
+    ///     &provider,
+    ///     &locale!("en").into(),
+    ///     AnyCalendarKind::Gregorian,
+    /// )
+    /// .expect("Failed to create DateTimePatternGenerator instance.");
+    /// assert_eq!(generator.hour_cycle(), HourCycle::H12);
+    ///
+    /// let generator = DateTimePatternGenerator::try_new_with_buffer_provider(
+    ///     &provider,
+    ///     &"en-u-hc-h23".parse::<icu::locid::Locale>().unwrap().into(),
+    ///     AnyCalendarKind::Gregorian,
+    /// )
+    /// .expect("Failed to create DateTimePatternGenerator instance.");
+    /// assert_eq!(generator.hour_cycle(), HourCycle::H23);
+    /// assert_eq!(generator.get_best_pattern("jm").unwrap(), "HH:mm");
+    /// ```
+    pub fn hour_cycle(&self) -> preferences::HourCycle {
+        self.hour_cycle
+    }
+}
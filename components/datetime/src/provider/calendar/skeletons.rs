@@ -5,7 +5,7 @@
 #![allow(missing_docs)] // TODO(#686) - Add missing docs.
 
 use crate::{
-    pattern::runtime::PatternPlurals,
+    pattern::runtime::{GenericPattern, PatternPlurals},
     skeleton::{reference::Skeleton, SkeletonError},
 };
 use core::convert::TryFrom;
@@ -38,6 +38,79 @@ impl icu_provider::KeyedDataMarker for DateSkeletonPatternsV1Marker {
     const KEY: icu_provider::DataKey = icu_provider::data_key!("datetime/skeletons@1[u-ca]");
 }
 
+/// The CLDR `appendItems` patterns, used to append requested fields that are missing from the
+/// best matching skeleton. In each pattern, `{0}` is the matched pattern and `{1}` is the
+/// missing field.
+///
+/// Unlike CLDR, the patterns have no `{2}` placeholder for the display name of the missing
+/// field, as field display names are not part of the data. Datagen removes it, so that the
+/// English day item `{0} ({2}: {1})` becomes `{0} ({1})`.
+// Manually implement DataMarker so that we can keep it in the proper experimental feature
+// #[icu_provider::data_struct(marker(
+//     DateAppendItemsV1Marker,
+//     "datetime/append_items@1",
+//     extension_key = "ca"
+// ))]
+#[derive(yoke::Yokeable, zerofrom::ZeroFrom, Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_datetime::provider::calendar),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct DateAppendItemsV1<'data> {
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub era: GenericPattern<'data>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub year: GenericPattern<'data>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub quarter: GenericPattern<'data>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub month: GenericPattern<'data>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub week: GenericPattern<'data>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub day: GenericPattern<'data>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub day_of_week: GenericPattern<'data>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub hour: GenericPattern<'data>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub minute: GenericPattern<'data>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub second: GenericPattern<'data>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub time_zone: GenericPattern<'data>,
+}
+
+#[cfg(feature = "experimental")]
+pub struct DateAppendItemsV1Marker;
+#[cfg(feature = "experimental")]
+impl icu_provider::DataMarker for DateAppendItemsV1Marker {
+    type Yokeable = DateAppendItemsV1<'static>;
+}
+#[cfg(feature = "experimental")]
+impl icu_provider::KeyedDataMarker for DateAppendItemsV1Marker {
+    const KEY: icu_provider::DataKey = icu_provider::data_key!("datetime/append_items@1[u-ca]");
+}
+
+#[cfg(all(feature = "datagen", feature = "experimental"))]
+impl Default for DateAppendItemsV1Marker {
+    fn default() -> Self {
+        Self
+    }
+}
+
+#[cfg(all(feature = "datagen", feature = "experimental"))]
+impl databake::Bake for DateAppendItemsV1Marker {
+    fn bake(&self, env: &databake::CrateEnv) -> databake::TokenStream {
+        env.insert("icu_datetime");
+        databake::quote! {
+            ::icu_datetime::provider::calendar::DateAppendItemsV1Marker
+        }
+    }
+}
+
 /// This struct is a public wrapper around the internal `Skeleton` struct. This allows
 /// access to the serialization and deserialization capabilities, without exposing the
 /// internals of the skeleton machinery.
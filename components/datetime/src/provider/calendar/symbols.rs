@@ -12,7 +12,7 @@ use icu_calendar::types::MonthCode;
 use icu_provider::prelude::*;
 use icu_provider::{yoke, zerofrom};
 use tinystr::{tinystr, TinyStr4};
-use zerovec::ZeroMap;
+use zerovec::{VarZeroVec, ZeroMap};
 
 #[icu_provider::data_struct(
     marker(GregorianDateSymbolsV1Marker, "datetime/gregory/datesymbols@1"),
@@ -20,6 +20,12 @@ use zerovec::ZeroMap;
     marker(JapaneseDateSymbolsV1Marker, "datetime/japanese/datesymbols@1"),
     marker(JapaneseExtendedDateSymbolsV1Marker, "datetime/japanext/datesymbols@1"),
     marker(CopticDateSymbolsV1Marker, "datetime/coptic/datesymbols@1"),
+    marker(ChineseDateSymbolsV1Marker, "datetime/chinese/datesymbols@1"),
+    marker(DangiDateSymbolsV1Marker, "datetime/dangi/datesymbols@1"),
+    marker(HebrewDateSymbolsV1Marker, "datetime/hebrew/datesymbols@1"),
+    marker(PersianDateSymbolsV1Marker, "datetime/persian/datesymbols@1"),
+    marker(RocDateSymbolsV1Marker, "datetime/roc/datesymbols@1"),
+    marker(IslamicDateSymbolsV1Marker, "datetime/islamic/datesymbols@1"),
     marker(IndianDateSymbolsV1Marker, "datetime/indian/datesymbols@1"),
     marker(EthiopianDateSymbolsV1Marker, "datetime/ethiopic/datesymbols@1")
 )]
@@ -34,10 +40,16 @@ use zerovec::ZeroMap;
 pub struct DateSymbolsV1<'data> {
     #[cfg_attr(feature = "serde", serde(borrow))]
     pub months: months::ContextsV1<'data>,
+    // Added after the first release of the keys, so data without it is still accepted.
+    #[cfg_attr(feature = "serde", serde(borrow, default))]
+    pub quarters: quarters::ContextsV1<'data>,
     #[cfg_attr(feature = "serde", serde(borrow))]
     pub weekdays: weekdays::ContextsV1<'data>,
     #[cfg_attr(feature = "serde", serde(borrow))]
     pub eras: Eras<'data>,
+    // Added after the first release of the keys, so data without it is still accepted.
+    #[cfg_attr(feature = "serde", serde(borrow, default))]
+    pub cyclic_years: CyclicYears<'data>,
 }
 
 pub(crate) struct ErasedDateSymbolsV1Marker;
@@ -77,6 +89,25 @@ pub struct Eras<'data> {
     pub narrow: ZeroMap<'data, str, str>,
 }
 
+/// The names of the years of the sexagenary cycle, in the order of the cycle, for calendars
+/// like the Chinese calendar. Empty for calendars without cyclic years.
+#[derive(Debug, PartialEq, Clone, Default, yoke::Yokeable, zerofrom::ZeroFrom)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_datetime::provider::calendar),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct CyclicYears<'data> {
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub names: VarZeroVec<'data, str>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub abbr: VarZeroVec<'data, str>,
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub narrow: VarZeroVec<'data, str>,
+}
+
 macro_rules! symbols {
     ($name: ident, $symbols: item) => {
         pub mod $name {
@@ -171,6 +202,14 @@ symbols!(
         /// A calendar with an arbitrary number of months, potentially including leap months
         #[cfg_attr(feature = "serde", serde(borrow))]
         Other(ZeroMap<'data, MonthCode, str>),
+        /// A calendar whose months keep their code in leap years but may take a different
+        /// name there, like Adar II (`M06`) in the Hebrew calendar
+        LeapYearNames {
+            #[cfg_attr(feature = "serde", serde(borrow))]
+            names: ZeroMap<'data, MonthCode, str>,
+            #[cfg_attr(feature = "serde", serde(borrow))]
+            leap_year_names: ZeroMap<'data, MonthCode, str>,
+        },
     }
 );
 
@@ -210,7 +249,18 @@ impl<'data> months::SymbolsV1<'data> {
                 };
                 arr.get(idx).map(|x| &**x)
             }
-            Self::Other(ref map) => map.get(&code),
+            Self::Other(ref map) | Self::LeapYearNames { names: ref map, .. } => map.get(&code),
+        }
+    }
+
+    /// Get the symbol for the given month code in a year with or without a leap month
+    pub fn get_for_year(&self, code: MonthCode, is_leap_year: bool) -> Option<&str> {
+        match *self {
+            Self::LeapYearNames {
+                ref leap_year_names,
+                ..
+            } if is_leap_year => leap_year_names.get(&code).or_else(|| self.get(code)),
+            _ => self.get(code),
         }
     }
 }
@@ -221,6 +271,21 @@ impl Default for months::SymbolsV1<'_> {
     }
 }
 
+symbols!(
+    quarters,
+    #[derive(Default)]
+    pub struct SymbolsV1<'data>(
+        #[cfg_attr(
+            feature = "serde",
+            serde(
+                borrow,
+                deserialize_with = "icu_provider::serde::borrow_de_utils::array_of_cow"
+            )
+        )]
+        pub [Cow<'data, str>; 4],
+    );
+);
+
 symbols!(
     weekdays,
     #[derive(Default)]
@@ -285,6 +350,13 @@ mod test {
         ];
         let months = months::SymbolsV1::Other(months.iter().copied().collect());
 
+        let quarters = quarters::SymbolsV1([
+            Cow::Owned("1st quarter".to_string()),
+            Cow::Owned("2nd quarter".to_string()),
+            Cow::Owned("3rd quarter".to_string()),
+            Cow::Owned("4th quarter".to_string()),
+        ]);
+
         let weekdays = weekdays::SymbolsV1([
             Cow::Owned("Monday".to_string()),
             Cow::Owned("Tuesday".to_string()),
@@ -310,6 +382,15 @@ mod test {
                     wide: Some(months.clone()),
                 }),
             },
+            quarters: quarters::ContextsV1 {
+                format: quarters::FormatWidthsV1 {
+                    abbreviated: quarters.clone(),
+                    narrow: quarters.clone(),
+                    short: None,
+                    wide: quarters.clone(),
+                },
+                stand_alone: None,
+            },
             weekdays: weekdays::ContextsV1 {
                 format: weekdays::FormatWidthsV1 {
                     abbreviated: weekdays.clone(),
@@ -329,6 +410,7 @@ mod test {
                 abbr: ZeroMap::new(),
                 narrow: ZeroMap::new(),
             },
+            cyclic_years: CyclicYears::default(),
         })
         .unwrap()
     }
@@ -372,6 +454,27 @@ mod test {
         ));
     }
 
+    #[test]
+    fn quarters_borrows() {
+        let bytes = serialize_date();
+        let de = bincode::deserialize::<DateSymbolsV1>(&bytes).unwrap();
+
+        assert!(matches!(de.quarters.format.wide.0[2], Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn quarters_default() {
+        let bytes = serialize_date();
+        let symbols = bincode::deserialize::<DateSymbolsV1>(&bytes).unwrap();
+        let mut json = serde_json::to_value(&symbols).unwrap();
+        json.as_object_mut().unwrap().remove("quarters");
+        let json = serde_json::to_string(&json).unwrap();
+        let de = serde_json::from_str::<DateSymbolsV1>(&json).unwrap();
+
+        assert_eq!(de.quarters, quarters::ContextsV1::default());
+        assert_eq!(de.months, symbols.months);
+    }
+
     #[test]
     fn day_periods_borrows() {
         let bytes = serialize_time();
@@ -14,11 +14,19 @@ use crate::provider::calendar::{
     ErasedDateLengthsV1Marker, TimeLengthsV1Marker,
 };
 use crate::provider::calendar::{DateLengthsV1, TimeLengthsV1};
+use crate::provider::time_data::TimeDataV1Marker;
+use crate::provider::week_data::{WeekDataV1, WeekDataV1Marker};
 #[cfg(feature = "experimental")]
-use crate::{options::components, provider::calendar::DateSkeletonPatternsV1Marker};
-use icu_calendar::types::{Era, MonthCode};
+use crate::{
+    options::components,
+    provider::calendar::{DateAppendItemsV1Marker, DateSkeletonPatternsV1Marker},
+};
+use core::num::NonZeroU8;
+use icu_calendar::types::{Era, IsoWeekday, MonthCode};
+use icu_calendar::week_of::CalendarInfo;
 #[cfg(feature = "experimental")]
 use icu_locid::extensions::unicode::Value;
+use icu_locid::extensions_unicode_key as key;
 use icu_provider::prelude::*;
 
 type Result<T> = core::result::Result<T, DateTimeFormatterError>;
@@ -32,6 +40,7 @@ fn pattern_for_time_length_inner<'data>(
     // or the preferred hour cycle for the locale.
     let time = if let Some(preferences::Bag {
         hour_cycle: Some(hour_cycle_pref),
+        ..
     }) = preferences
     {
         match hour_cycle_pref {
@@ -72,6 +81,92 @@ where
     Ok(data)
 }
 
+/// Loads the time data of a locale, which holds the hour cycles of its region.
+///
+/// The region override of the `-u-rg` or `-u-sd` keyword is used if there is one, see
+/// [`preferences::Bag::from_data_locale`].
+pub(crate) fn time_data_payload<D>(
+    data_provider: &D,
+    locale: &DataLocale,
+) -> Result<DataPayload<TimeDataV1Marker>>
+where
+    D: DataProvider<TimeDataV1Marker> + ?Sized,
+{
+    let mut time_locale = DataLocale::from(locale.get_langid());
+    if let Some(region) = preferences::Bag::from_data_locale(locale).region_override {
+        time_locale.set_region(Some(region));
+    }
+    let data = data_provider
+        .load(DataRequest {
+            locale: &time_locale,
+            metadata: Default::default(),
+        })?
+        .take_payload()?;
+    Ok(data)
+}
+
+/// Resolves the [`preferences::Bag`] of a locale.
+///
+/// Unless the `-u-hc` keyword sets the hour cycle, the time lengths data of the locale is
+/// expected to supply it, so the hour cycle is only set here when `-u-rg` or `-u-sd` select a
+/// region other than the one of the locale. It is then the preferred hour cycle of that region.
+pub(crate) fn preferences_for_locale<D>(
+    data_provider: &D,
+    locale: &DataLocale,
+) -> Result<preferences::Bag>
+where
+    D: DataProvider<TimeDataV1Marker> + ?Sized,
+{
+    let mut preferences = preferences::Bag::from_data_locale(locale);
+    if preferences.hour_cycle.is_none() && preferences.region_override.is_some() {
+        let time_data = time_data_payload(data_provider, locale)?;
+        preferences.hour_cycle = Some(time_data.get().preferred_hour_cycle);
+    }
+    Ok(preferences)
+}
+
+/// Loads the week data of a locale.
+///
+/// The week data of the region selected by `-u-rg` or `-u-sd` is used if there is one, and the
+/// `-u-fw` keyword overrides the first day of the week.
+pub(crate) fn week_data_payload<D>(
+    data_provider: &D,
+    locale: &DataLocale,
+) -> Result<DataPayload<WeekDataV1Marker>>
+where
+    D: DataProvider<WeekDataV1Marker> + ?Sized,
+{
+    let mut week_locale = locale.clone();
+    if let Some(region) = preferences::Bag::from_data_locale(locale).region_override {
+        week_locale.set_region(Some(region));
+    }
+    let data: DataPayload<WeekDataV1Marker> = data_provider
+        .load(DataRequest {
+            locale: &week_locale,
+            metadata: Default::default(),
+        })?
+        .take_payload()?;
+    let first_weekday = match locale
+        .get_unicode_ext(&key!("fw"))
+        .as_ref()
+        .and_then(|v| v.as_single_subtag())
+        .map(|v| v.as_str())
+    {
+        Some("mon") => IsoWeekday::Monday,
+        Some("tue") => IsoWeekday::Tuesday,
+        Some("wed") => IsoWeekday::Wednesday,
+        Some("thu") => IsoWeekday::Thursday,
+        Some("fri") => IsoWeekday::Friday,
+        Some("sat") => IsoWeekday::Saturday,
+        Some("sun") => IsoWeekday::Sunday,
+        _ => return Ok(data),
+    };
+    Ok(DataPayload::from_owned(WeekDataV1(CalendarInfo {
+        first_weekday,
+        ..data.get().0
+    })))
+}
+
 fn pattern_for_date_length_inner(data: DateLengthsV1, length: length::Date) -> PatternPlurals {
     let pattern = match length {
         length::Date::Full => data.date.full,
@@ -140,7 +235,7 @@ pub struct PatternSelector<'a, D: ?Sized> {
 
 impl<D> PatternSelector<'_, D>
 where
-    D: DataProvider<TimeLengthsV1Marker> + ?Sized,
+    D: DataProvider<TimeLengthsV1Marker> + DataProvider<TimeDataV1Marker> + ?Sized,
 {
     #[cfg(not(feature = "experimental"))]
     pub(crate) fn for_options<'a>(
@@ -156,7 +251,7 @@ where
         };
         match options {
             DateTimeFormatterOptions::Length(bag) => selector
-                .pattern_for_length_bag(bag, Some(preferences::Bag::from_data_locale(locale))),
+                .pattern_for_length_bag(bag, Some(preferences_for_locale(data_provider, locale)?)),
         }
     }
 
@@ -220,7 +315,11 @@ where
 #[cfg(feature = "experimental")]
 impl<D> PatternSelector<'_, D>
 where
-    D: DataProvider<TimeLengthsV1Marker> + DataProvider<DateSkeletonPatternsV1Marker> + ?Sized,
+    D: DataProvider<TimeLengthsV1Marker>
+        + DataProvider<TimeDataV1Marker>
+        + DataProvider<DateSkeletonPatternsV1Marker>
+        + DataProvider<DateAppendItemsV1Marker>
+        + ?Sized,
 {
     pub(crate) fn for_options<'a>(
         data_provider: &'a D,
@@ -237,7 +336,7 @@ where
         };
         match options {
             DateTimeFormatterOptions::Length(bag) => selector
-                .pattern_for_length_bag(bag, Some(preferences::Bag::from_data_locale(locale))),
+                .pattern_for_length_bag(bag, Some(preferences_for_locale(data_provider, locale)?)),
             DateTimeFormatterOptions::Components(bag) => selector.patterns_for_components_bag(bag),
         }
     }
@@ -248,16 +347,20 @@ where
         components: &components::Bag,
     ) -> Result<DataPayload<PatternPluralsFromPatternsV1Marker>> {
         use crate::skeleton;
-        let skeletons_data = self.skeleton_data_payload()?;
+        let skeletons_data: DataPayload<DateSkeletonPatternsV1Marker> =
+            self.skeleton_data_payload()?;
+        let append_items_data: DataPayload<DateAppendItemsV1Marker> =
+            self.skeleton_data_payload()?;
         // Not all skeletons are currently supported.
         let requested_fields = components.to_vec_fields();
         let patterns = match skeleton::create_best_pattern_for_fields(
             skeletons_data.get(),
             &self.date_patterns_data.get().length_combinations,
+            Some(append_items_data.get()),
             &requested_fields,
             components,
             false, // Prefer the requested fields over the matched pattern.
-        ) {
+        )? {
             skeleton::BestSkeleton::AllFieldsMatch(pattern)
             | skeleton::BestSkeleton::MissingOrExtraFields(pattern) => Some(pattern),
             skeleton::BestSkeleton::NoMatch => None,
@@ -269,32 +372,62 @@ where
     }
 
     #[cfg(feature = "experimental")]
-    fn skeleton_data_payload(&self) -> Result<DataPayload<DateSkeletonPatternsV1Marker>> {
-        use icu_locid::{extensions_unicode_key as key, extensions_unicode_value as value};
-        let mut locale = self.locale.clone();
-        // Skeleton data for ethioaa is stored under ethiopic
-        if self.cal_val == &value!("ethioaa") {
-            locale.set_unicode_ext(key!("ca"), value!("ethiopic"));
-        } else {
-            locale.set_unicode_ext(key!("ca"), self.cal_val.clone());
-        };
-        let data = self
-            .data_provider
-            .load(DataRequest {
-                locale: &locale,
-                metadata: Default::default(),
-            })?
-            .take_payload()?;
-        Ok(data)
+    fn skeleton_data_payload<M>(&self) -> Result<DataPayload<M>>
+    where
+        M: KeyedDataMarker,
+        D: DataProvider<M>,
+    {
+        skeleton_data_payload(self.data_provider, self.locale, self.cal_val)
     }
 }
 
+/// Load skeleton-keyed data, such as the skeletons or the append items, for the calendar
+/// with the BCP-47 value `cal_val`.
+#[cfg(feature = "experimental")]
+pub(crate) fn skeleton_data_payload<D, M>(
+    data_provider: &D,
+    locale: &DataLocale,
+    cal_val: &Value,
+) -> Result<DataPayload<M>>
+where
+    M: KeyedDataMarker,
+    D: DataProvider<M> + ?Sized,
+{
+    use icu_locid::extensions_unicode_value as value;
+    let mut locale = locale.clone();
+    // Skeleton and append items data for ethioaa is stored under ethiopic, and the data
+    // for the variants of the Islamic calendar is stored under islamic
+    if cal_val == &value!("ethioaa") {
+        locale.set_unicode_ext(key!("ca"), value!("ethiopic"));
+    } else if cal_val.as_tinystr_slice().first() == Some(&tinystr::tinystr!(8, "islamic"))
+        || cal_val == &value!("islamicc")
+    {
+        locale.set_unicode_ext(key!("ca"), value!("islamic"));
+    } else {
+        locale.set_unicode_ext(key!("ca"), cal_val.clone());
+    };
+    let data = data_provider
+        .load(DataRequest {
+            locale: &locale,
+            metadata: Default::default(),
+        })?
+        .take_payload()?;
+    Ok(data)
+}
+
 pub trait DateSymbols {
     fn get_symbol_for_month(
         &self,
         month: fields::Month,
         length: fields::FieldLength,
         code: MonthCode,
+        is_leap_year: bool,
+    ) -> Result<&str>;
+    fn get_symbol_for_quarter(
+        &self,
+        quarter: fields::Quarter,
+        length: fields::FieldLength,
+        idx: usize,
     ) -> Result<&str>;
     fn get_symbol_for_weekday(
         &self,
@@ -303,6 +436,11 @@ pub trait DateSymbols {
         day: input::IsoWeekday,
     ) -> Result<&str>;
     fn get_symbol_for_era<'a>(&'a self, length: fields::FieldLength, era_code: &'a Era) -> &str;
+    fn get_symbol_for_cyclic_year(
+        &self,
+        length: fields::FieldLength,
+        cyclic: NonZeroU8,
+    ) -> Option<&str>;
 }
 
 impl<'data> DateSymbols for provider::calendar::DateSymbolsV1<'data> {
@@ -313,7 +451,8 @@ impl<'data> DateSymbols for provider::calendar::DateSymbolsV1<'data> {
         day: input::IsoWeekday,
     ) -> Result<&str> {
         let widths = match weekday {
-            fields::Weekday::Format => &self.weekdays.format,
+            // The text forms of the local day of week are the same as the format forms.
+            fields::Weekday::Format | fields::Weekday::Local => &self.weekdays.format,
             fields::Weekday::StandAlone => {
                 if let Some(ref widths) = self.weekdays.stand_alone {
                     let symbols = match length {
@@ -338,7 +477,6 @@ impl<'data> DateSymbols for provider::calendar::DateSymbolsV1<'data> {
                     return self.get_symbol_for_weekday(fields::Weekday::Format, length, day);
                 }
             }
-            fields::Weekday::Local => unimplemented!(),
         };
         let symbols = match length {
             fields::FieldLength::Wide => &widths.wide,
@@ -359,6 +497,7 @@ impl<'data> DateSymbols for provider::calendar::DateSymbolsV1<'data> {
         month: fields::Month,
         length: fields::FieldLength,
         code: MonthCode,
+        is_leap_year: bool,
     ) -> Result<&str> {
         let widths = match month {
             fields::Month::Format => &self.months.format,
@@ -371,13 +510,23 @@ impl<'data> DateSymbols for provider::calendar::DateSymbolsV1<'data> {
                     };
                     if let Some(symbols) = symbols {
                         return symbols
-                            .get(code)
+                            .get_for_year(code, is_leap_year)
                             .ok_or(DateTimeFormatterError::MissingMonthSymbol(code));
                     } else {
-                        return self.get_symbol_for_month(fields::Month::Format, length, code);
+                        return self.get_symbol_for_month(
+                            fields::Month::Format,
+                            length,
+                            code,
+                            is_leap_year,
+                        );
                     }
                 } else {
-                    return self.get_symbol_for_month(fields::Month::Format, length, code);
+                    return self.get_symbol_for_month(
+                        fields::Month::Format,
+                        length,
+                        code,
+                        is_leap_year,
+                    );
                 }
             }
         };
@@ -387,10 +536,51 @@ impl<'data> DateSymbols for provider::calendar::DateSymbolsV1<'data> {
             _ => &widths.abbreviated,
         };
         symbols
-            .get(code)
+            .get_for_year(code, is_leap_year)
             .ok_or(DateTimeFormatterError::MissingMonthSymbol(code))
     }
 
+    fn get_symbol_for_quarter(
+        &self,
+        quarter: fields::Quarter,
+        length: fields::FieldLength,
+        idx: usize,
+    ) -> Result<&str> {
+        let widths = match quarter {
+            fields::Quarter::Format => &self.quarters.format,
+            fields::Quarter::StandAlone => {
+                if let Some(ref widths) = self.quarters.stand_alone {
+                    let symbols = match length {
+                        fields::FieldLength::Wide => widths.wide.as_ref(),
+                        fields::FieldLength::Narrow => widths.narrow.as_ref(),
+                        _ => widths.abbreviated.as_ref(),
+                    };
+                    if let Some(symbols) = symbols {
+                        return symbols
+                            .0
+                            .get(idx)
+                            .map(|x| &**x)
+                            .ok_or(DateTimeFormatterError::MissingQuarterSymbol(idx));
+                    } else {
+                        return self.get_symbol_for_quarter(fields::Quarter::Format, length, idx);
+                    }
+                } else {
+                    return self.get_symbol_for_quarter(fields::Quarter::Format, length, idx);
+                }
+            }
+        };
+        let symbols = match length {
+            fields::FieldLength::Wide => &widths.wide,
+            fields::FieldLength::Narrow => &widths.narrow,
+            _ => &widths.abbreviated,
+        };
+        symbols
+            .0
+            .get(idx)
+            .map(|x| &**x)
+            .ok_or(DateTimeFormatterError::MissingQuarterSymbol(idx))
+    }
+
     /// Get the era symbol
     ///
     /// This will fall back to the era code directly, if, for example,
@@ -403,6 +593,19 @@ impl<'data> DateSymbols for provider::calendar::DateSymbolsV1<'data> {
         };
         symbols.get(&era_code.0).unwrap_or(&era_code.0)
     }
+
+    fn get_symbol_for_cyclic_year(
+        &self,
+        length: fields::FieldLength,
+        cyclic: NonZeroU8,
+    ) -> Option<&str> {
+        let symbols = match length {
+            fields::FieldLength::Wide => &self.cyclic_years.names,
+            fields::FieldLength::Narrow => &self.cyclic_years.narrow,
+            _ => &self.cyclic_years.abbr,
+        };
+        symbols.get(usize::from(cyclic.get() - 1))
+    }
 }
 
 pub trait TimeSymbols {
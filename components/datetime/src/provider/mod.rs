@@ -12,6 +12,9 @@
 /// Data providers for calendar-specific symbols and patterns.
 pub mod calendar;
 
+/// Provider for the hour cycles of regions.
+pub mod time_data;
+
 /// Data providers for time zones.
 pub mod time_zones;
 
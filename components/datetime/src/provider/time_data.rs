@@ -0,0 +1,28 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::options::preferences::HourCycle;
+use icu_provider::prelude::*;
+
+/// An ICU4X mapping to CLDR timeData, the hour cycles used in a region.
+/// See CLDR-JSON's timeData.json for more context.
+#[icu_provider::data_struct(marker(
+    TimeDataV1Marker,
+    "datetime/time_data@1",
+    fallback_by = "region"
+))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_datetime::provider::time_data),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct TimeDataV1 {
+    /// The preferred hour cycle of the region, which the `j` skeleton symbol resolves to.
+    pub preferred_hour_cycle: HourCycle,
+    /// The hour cycle of the most common of the hour formats allowed in the region, which the
+    /// `C` skeleton symbol resolves to.
+    pub allowed_hour_cycle: HourCycle,
+}
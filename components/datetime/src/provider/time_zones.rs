@@ -65,6 +65,24 @@ pub struct ExemplarCitiesV1<'data>(
     #[cfg_attr(feature = "serde", serde(borrow))] pub ZeroMap<'data, TimeZoneBcp47Id, str>,
 );
 
+/// An ICU4X mapping from BCP-47 time zone identifiers to IANA time zone identifiers.
+/// See CLDR-JSON bcp47/timezone.json for more context.
+#[icu_provider::data_struct(TimeZoneIanaIdsV1Marker = "time_zone/iana_ids@1")]
+#[derive(PartialEq, Debug, Clone, Default)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_datetime::provider::time_zones),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct TimeZoneIanaIdsV1<'data>(
+    /// The mapping between a BCP-47 time zone id and its canonical IANA time zone id, e.g.
+    /// "uslax" to "America/Los_Angeles".
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub ZeroMap<'data, TimeZoneBcp47Id, str>,
+);
+
 /// An ICU4X mapping to the long-form generic metazone names.
 /// See CLDR-JSON timeZoneNames.json for more context.
 #[icu_provider::data_struct(MetaZoneGenericNamesLongV1Marker = "time_zone/generic_long@1")]
@@ -19,3 +19,19 @@ use icu_provider::prelude::*;
 )]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize))]
 pub struct WeekDataV1(pub icu_calendar::week_of::CalendarInfo);
+
+/// An ICU4X mapping to the weekend subset of CLDR weekData.
+/// See CLDR-JSON's weekData.json for more context.
+#[icu_provider::data_struct(marker(
+    WeekendDataV1Marker,
+    "datetime/weekend@1",
+    fallback_by = "region"
+))]
+#[derive(Clone, Copy, Default)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize, databake::Bake),
+    databake(path = icu_datetime::provider::week_data),
+)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct WeekendDataV1(pub icu_calendar::week_of::WeekendInfo);
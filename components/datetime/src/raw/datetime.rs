@@ -19,11 +19,13 @@ use crate::{
             ErasedDateLengthsV1Marker, ErasedDateSymbolsV1Marker, TimeLengthsV1Marker,
             TimeSymbolsV1Marker,
         },
+        time_data::TimeDataV1Marker,
         week_data::WeekDataV1Marker,
     },
-    DateTimeFormatterError, FormattedDateTime,
+    DateTimeFormatterError, FormattedDateTime, Leniency,
 };
 use alloc::string::String;
+use icu_calendar::{AsCalendar, DateTime};
 
 use icu_decimal::{
     options::{FixedDecimalFormatterOptions, GroupingStrategy},
@@ -32,6 +34,7 @@ use icu_decimal::{
 };
 use icu_plurals::{provider::OrdinalV1Marker, PluralRules};
 use icu_provider::prelude::*;
+use writeable::Writeable;
 
 pub(crate) struct TimeFormatter {
     pub patterns: DataPayload<PatternPluralsFromPatternsV1Marker>,
@@ -52,6 +55,7 @@ impl TimeFormatter {
     ) -> Result<Self, DateTimeFormatterError>
     where
         D: DataProvider<TimeLengthsV1Marker>
+            + DataProvider<TimeDataV1Marker>
             + DataProvider<TimeSymbolsV1Marker>
             + DataProvider<DecimalSymbolsV1Marker>
             + ?Sized,
@@ -131,18 +135,7 @@ impl TimeFormatter {
         w: &mut impl core::fmt::Write,
         value: &impl IsoTimeInput,
     ) -> core::fmt::Result {
-        let extracted = ExtractedDateTimeInput::extract_from_time(value);
-        datetime::write_pattern_plurals(
-            &self.patterns.get().0,
-            None,
-            self.symbols.as_ref().map(|s| s.get()),
-            &extracted,
-            None,
-            None,
-            &self.fixed_decimal_format,
-            w,
-        )
-        .map_err(|_| core::fmt::Error)
+        self.format(value).write_to(w)
     }
 
     /// Takes a [`IsoTimeInput`] implementer and returns it formatted as a string.
@@ -188,13 +181,11 @@ impl DateFormatter {
         let required = datetime::analyze_patterns(&patterns.get().0, false)
             .map_err(|field| DateTimeFormatterError::UnsupportedField(field.symbol))?;
 
-        let req = DataRequest {
-            locale,
-            metadata: Default::default(),
-        };
-
         let week_data = if required.week_data {
-            Some(data_provider.load(req)?.take_payload()?)
+            Some(provider::date_time::week_data_payload(
+                data_provider,
+                locale,
+            )?)
         } else {
             None
         };
@@ -265,8 +256,8 @@ impl DateFormatter {
             date_symbols: self.symbols.as_ref().map(|s| s.get()),
             time_symbols: None,
             datetime: ExtractedDateTimeInput::extract_from_date(value),
-            week_data: None,
-            ordinal_rules: None,
+            week_data: self.week_data.as_ref().map(|s| s.get()),
+            ordinal_rules: self.ordinal_rules.as_ref(),
             fixed_decimal_format: &self.fixed_decimal_format,
         }
     }
@@ -279,18 +270,7 @@ impl DateFormatter {
         w: &mut impl core::fmt::Write,
         value: &impl DateInput,
     ) -> core::fmt::Result {
-        let extracted = ExtractedDateTimeInput::extract_from_date(value);
-        datetime::write_pattern_plurals(
-            &self.patterns.get().0,
-            self.symbols.as_ref().map(|s| s.get()),
-            None,
-            &extracted,
-            None,
-            None,
-            &self.fixed_decimal_format,
-            w,
-        )
-        .map_err(|_| core::fmt::Error)
+        self.format(value).write_to(w)
     }
 
     /// Takes a [`DateInput`] implementer and returns it formatted as a string.
@@ -367,6 +347,7 @@ impl DateTimeFormatter {
     where
         D: DataProvider<TimeSymbolsV1Marker>
             + DataProvider<TimeLengthsV1Marker>
+            + DataProvider<TimeDataV1Marker>
             + DataProvider<DecimalSymbolsV1Marker>
             + DataProvider<OrdinalV1Marker>
             + DataProvider<WeekDataV1Marker>
@@ -381,7 +362,10 @@ impl DateTimeFormatter {
         };
 
         let week_data = if required.week_data {
-            Some(data_provider.load(req)?.take_payload()?)
+            Some(provider::date_time::week_data_payload(
+                data_provider,
+                locale,
+            )?)
         } else {
             None
         };
@@ -473,17 +457,7 @@ impl DateTimeFormatter {
         w: &mut impl core::fmt::Write,
         value: &impl DateTimeInput,
     ) -> core::fmt::Result {
-        datetime::write_pattern_plurals(
-            &self.patterns.get().0,
-            self.date_symbols.as_ref().map(|s| s.get()),
-            self.time_symbols.as_ref().map(|s| s.get()),
-            value,
-            self.week_data.as_ref().map(|s| s.get()),
-            self.ordinal_rules.as_ref(),
-            &self.fixed_decimal_format,
-            w,
-        )
-        .map_err(|_| core::fmt::Error)
+        self.format(value).write_to(w)
     }
 
     /// Takes a [`DateTimeInput`] implementer and returns it formatted as a string.
@@ -494,6 +468,27 @@ impl DateTimeFormatter {
         s
     }
 
+    /// Reads a string formatted with the patterns of this [`DateTimeFormatter`] back into a
+    /// [`DateTime`] in the given calendar.
+    pub fn parse<A: AsCalendar>(
+        &self,
+        input: &str,
+        leniency: Leniency,
+        two_digit_year_start: Option<i32>,
+        calendar: A,
+    ) -> Result<DateTime<A>, DateTimeFormatterError> {
+        crate::parse::datetime::parse_pattern_plurals(
+            &self.patterns.get().0,
+            self.date_symbols.as_ref().map(|s| s.get()),
+            self.time_symbols.as_ref().map(|s| s.get()),
+            &self.fixed_decimal_format,
+            input,
+            leniency,
+            two_digit_year_start,
+            calendar,
+        )
+    }
+
     /// Returns a [`components::Bag`] that represents the resolved components for the
     /// options that were provided to the [`DateTimeFormatter`].
     #[cfg(feature = "experimental")]
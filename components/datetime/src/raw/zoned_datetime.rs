@@ -10,12 +10,10 @@ use icu_decimal::{
 };
 use icu_plurals::{provider::OrdinalV1Marker, PluralRules};
 use icu_provider::prelude::*;
+use writeable::Writeable;
 
 use crate::{
-    format::{
-        datetime,
-        zoned_datetime::{self, FormattedZonedDateTime},
-    },
+    format::{datetime, zoned_datetime::FormattedZonedDateTime},
     input::{DateTimeInput, TimeZoneInput},
     input::{ExtractedDateTimeInput, ExtractedTimeZoneInput},
     pattern::runtime::PatternPlurals,
@@ -25,6 +23,7 @@ use crate::{
             patterns::PatternPluralsFromPatternsV1Marker, ErasedDateSymbolsV1Marker,
             TimeLengthsV1Marker, TimeSymbolsV1Marker,
         },
+        time_data::TimeDataV1Marker,
         week_data::WeekDataV1Marker,
     },
     raw,
@@ -56,9 +55,11 @@ impl ZonedDateTimeFormatter {
     where
         P: DataProvider<TimeSymbolsV1Marker>
             + DataProvider<TimeLengthsV1Marker>
+            + DataProvider<TimeDataV1Marker>
             + DataProvider<WeekDataV1Marker>
             + DataProvider<provider::time_zones::TimeZoneFormatsV1Marker>
             + DataProvider<provider::time_zones::ExemplarCitiesV1Marker>
+            + DataProvider<provider::time_zones::TimeZoneIanaIdsV1Marker>
             + DataProvider<provider::time_zones::MetaZoneGenericNamesLongV1Marker>
             + DataProvider<provider::time_zones::MetaZoneGenericNamesShortV1Marker>
             + DataProvider<provider::time_zones::MetaZoneSpecificNamesLongV1Marker>
@@ -76,7 +77,7 @@ impl ZonedDateTimeFormatter {
         };
 
         let week_data = if required.week_data {
-            Some(provider.load(req)?.take_payload()?)
+            Some(provider::date_time::week_data_payload(provider, locale)?)
         } else {
             None
         };
@@ -156,7 +157,7 @@ impl ZonedDateTimeFormatter {
         date: &impl DateTimeInput,
         time_zone: &impl TimeZoneInput,
     ) -> core::fmt::Result {
-        zoned_datetime::write_pattern(self, date, time_zone, w).map_err(|_| core::fmt::Error)
+        self.format(date, time_zone).write_to(w)
     }
 
     /// Takes a [`ZonedDateTimeInput`] implementer and returns it formatted as a string.
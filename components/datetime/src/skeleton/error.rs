@@ -60,10 +60,7 @@ impl From<fields::SymbolError> for SkeletonError {
                 //       https://github.com/unicode-org/icu4x/blob/main/provider/testdata/README.md
                 match ch {
                     // TODO(#487) - Flexible day periods
-                    'B'
-                    // TODO(#501) - Quarters
-                    | 'Q'
-                    => Self::SymbolUnimplemented(ch),
+                    'B' => Self::SymbolUnimplemented(ch),
                     _ => Self::SymbolUnknown(ch),
                 }
             }
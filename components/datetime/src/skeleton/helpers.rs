@@ -12,9 +12,11 @@ use crate::{
     pattern::{
         hour_cycle,
         runtime::{self, PatternPlurals},
-        PatternItem, TimeGranularity,
+        PatternError, PatternItem, TimeGranularity,
+    },
+    provider::calendar::{
+        patterns::GenericLengthPatternsV1, DateAppendItemsV1, DateSkeletonPatternsV1,
     },
-    provider::calendar::{patterns::GenericLengthPatternsV1, DateSkeletonPatternsV1},
 };
 
 // The following scalar values are for testing the suitability of a skeleton's field for the
@@ -77,9 +79,9 @@ const SKELETON_EXTRA_SYMBOL: u32 = 1000;
 const REQUESTED_SYMBOL_MISSING: u32 = 10000;
 
 /// According to the [UTS 35 skeleton matching algorithm](https://unicode.org/reports/tr35/tr35-dates.html#Matching_Skeletons)
-/// there will be a guaranteed match for a skeleton. However, with this initial implementation,
-/// there is no attempt to add on missing fields. This enum encodes the variants for the current
-/// search for a best skeleton.
+/// there will be a guaranteed match for a skeleton. Missing fields are only added on when the
+/// `appendItems` data is available. This enum encodes the variants for the current search for a
+/// best skeleton.
 #[derive(Debug, PartialEq, Clone)]
 pub enum BestSkeleton<T> {
     AllFieldsMatch(T),
@@ -119,6 +121,8 @@ fn naively_apply_time_zone_name(
 ///
 /// * `skeletons` - The skeletons that will be matched against
 /// * `length_patterns` - Contains information on how to combine date and time patterns.
+/// * `append_items` - Contains information on how to append requested fields that are missing
+///         from the matched pattern. If `None`, missing fields are left out.
 /// * `fields` - The desired fields to match against.
 /// * `prefer_matched_pattern` - This algorithm does some extra steps of trying to respect
 ///         the desired fields, even if the provider data doesn't completely match. This
@@ -127,10 +131,11 @@ fn naively_apply_time_zone_name(
 pub fn create_best_pattern_for_fields<'data>(
     skeletons: &DateSkeletonPatternsV1<'data>,
     length_patterns: &GenericLengthPatternsV1<'data>,
+    append_items: Option<&DateAppendItemsV1<'data>>,
     fields: &[Field],
     components: &components::Bag,
     prefer_matched_pattern: bool,
-) -> BestSkeleton<PatternPlurals<'data>> {
+) -> Result<BestSkeleton<PatternPlurals<'data>>, PatternError> {
     let first_pattern_match =
         get_best_available_format_pattern(skeletons, fields, prefer_matched_pattern);
 
@@ -140,35 +145,52 @@ pub fn create_best_pattern_for_fields<'data>(
             hour_cycle::naively_apply_preferences(pattern, &components.preferences);
             naively_apply_time_zone_name(pattern, &components.time_zone_name);
         });
-        return BestSkeleton::AllFieldsMatch(pattern_plurals);
+        return Ok(BestSkeleton::AllFieldsMatch(pattern_plurals));
     }
 
     let FieldsByType { date, time } = group_fields_by_type(fields);
 
-    if date.is_empty() || time.is_empty() {
-        return match first_pattern_match {
+    // A time zone on its own is not a time, so rather than combining it with the date using the
+    // date time length patterns, it gets appended to the date.
+    let time_zone_only = time
+        .iter()
+        .all(|field| matches!(field.symbol, FieldSymbol::TimeZone(_)));
+
+    if date.is_empty() || time.is_empty() || time_zone_only {
+        return Ok(match first_pattern_match {
             BestSkeleton::AllFieldsMatch(_) => {
                 unreachable!("Logic error in implementation. AllFieldsMatch handled above.")
             }
             BestSkeleton::MissingOrExtraFields(mut pattern_plurals) => {
-                if date.is_empty() {
-                    pattern_plurals.for_each_mut(|pattern| {
+                pattern_plurals.try_for_each_mut(|pattern| {
+                    if let Some(append_items) = append_items {
+                        append_missing_fields(pattern, fields, append_items)?;
+                    }
+                    if date.is_empty() {
                         hour_cycle::naively_apply_preferences(pattern, &components.preferences);
-                        naively_apply_time_zone_name(pattern, &components.time_zone_name);
                         append_fractional_seconds(pattern, &time);
-                    });
-                }
+                    }
+                    naively_apply_time_zone_name(pattern, &components.time_zone_name);
+                    Ok::<(), PatternError>(())
+                })?;
                 BestSkeleton::MissingOrExtraFields(pattern_plurals)
             }
             BestSkeleton::NoMatch => BestSkeleton::NoMatch,
-        };
+        });
     }
 
     // Match the date and time, and then simplify the combinatorial logic of the results into
     // an optional values of the results, and a boolean value.
     let (date_patterns, date_missing_or_extra): (Option<PatternPlurals<'data>>, bool) =
         match get_best_available_format_pattern(skeletons, &date, prefer_matched_pattern) {
-            BestSkeleton::MissingOrExtraFields(fields) => (Some(fields), true),
+            BestSkeleton::MissingOrExtraFields(mut fields) => {
+                if let Some(append_items) = append_items {
+                    fields.try_for_each_mut(|pattern| {
+                        append_missing_fields(pattern, &date, append_items)
+                    })?;
+                }
+                (Some(fields), true)
+            }
             BestSkeleton::AllFieldsMatch(fields) => (Some(fields), false),
             BestSkeleton::NoMatch => (None, true),
         };
@@ -179,14 +201,22 @@ pub fn create_best_pattern_for_fields<'data>(
             BestSkeleton::AllFieldsMatch(fields) => (Some(fields), false),
             BestSkeleton::NoMatch => (None, true),
         };
-    let time_pattern: Option<runtime::Pattern<'data>> = time_patterns.map(|pattern_plurals| {
-        let mut pattern =
-            pattern_plurals.expect_pattern("Only date patterns can contain plural variants");
-        hour_cycle::naively_apply_preferences(&mut pattern, &components.preferences);
-        naively_apply_time_zone_name(&mut pattern, &components.time_zone_name);
-        append_fractional_seconds(&mut pattern, &time);
-        pattern
-    });
+    let time_pattern: Option<runtime::Pattern<'data>> = match time_patterns {
+        Some(pattern_plurals) => {
+            let mut pattern =
+                pattern_plurals.expect_pattern("Only date patterns can contain plural variants");
+            if time_missing_or_extra {
+                if let Some(append_items) = append_items {
+                    append_missing_fields(&mut pattern, &time, append_items)?;
+                }
+            }
+            hour_cycle::naively_apply_preferences(&mut pattern, &components.preferences);
+            naively_apply_time_zone_name(&mut pattern, &components.time_zone_name);
+            append_fractional_seconds(&mut pattern, &time);
+            Some(pattern)
+        }
+        None => None,
+    };
 
     // Determine how to combine the date and time.
     let patterns: Option<PatternPlurals<'data>> = match (date_patterns, time_pattern) {
@@ -229,17 +259,12 @@ pub fn create_best_pattern_for_fields<'data>(
                 length::Date::Short => &length_patterns.short,
             };
 
-            date_patterns.for_each_mut(|pattern| {
+            date_patterns.try_for_each_mut(|pattern| {
                 let date = pattern.clone();
                 let time = time_pattern.clone();
-                #[allow(clippy::expect_used)]
-                // TODO(#1668) Clippy exceptions need docs or fixing.
-                let dt = dt_pattern
-                    .clone()
-                    .combined(date, time)
-                    .expect("Failed to combine date and time");
-                *pattern = dt;
-            });
+                *pattern = dt_pattern.clone().combined(date, time)?;
+                Ok::<(), PatternError>(())
+            })?;
             Some(date_patterns)
         }
         (None, Some(pattern)) => Some(pattern.into()),
@@ -247,7 +272,7 @@ pub fn create_best_pattern_for_fields<'data>(
         (None, None) => None,
     };
 
-    match patterns {
+    Ok(match patterns {
         Some(patterns) => {
             if date_missing_or_extra || time_missing_or_extra {
                 BestSkeleton::MissingOrExtraFields(patterns)
@@ -256,7 +281,7 @@ pub fn create_best_pattern_for_fields<'data>(
             }
         }
         None => BestSkeleton::NoMatch,
-    }
+    })
 }
 
 struct FieldsByType {
@@ -277,6 +302,7 @@ fn group_fields_by_type(fields: &[Field]) -> FieldsByType {
             //  - Solo example: "E"
             FieldSymbol::Era
             | FieldSymbol::Year(_)
+            | FieldSymbol::Quarter(_)
             | FieldSymbol::Month(_)
             | FieldSymbol::Week(_)
             | FieldSymbol::Day(_)
@@ -349,6 +375,51 @@ fn append_fractional_seconds(pattern: &mut runtime::Pattern, fields: &[Field]) {
     }
 }
 
+/// Alters given Pattern so that the requested fields it is missing get appended to it, following
+/// the CLDR `appendItems` patterns for each type of field.
+///
+/// For example the "MMMM d, y" pattern will be changed to "MMMM d, y zzzz" given fields
+/// ["y", "MMMM", "d", "zzzz"].
+/// (see https://unicode.org/reports/tr35/tr35-dates.html#Missing_Skeleton_Fields)
+fn append_missing_fields(
+    pattern: &mut runtime::Pattern,
+    fields: &[Field],
+    append_items: &DateAppendItemsV1,
+) -> Result<(), PatternError> {
+    for field in fields {
+        let is_present = pattern.items.iter().any(|item| match item {
+            PatternItem::Field(pattern_field) => {
+                pattern_field.symbol.discriminant_cmp(&field.symbol).is_eq()
+            }
+            PatternItem::Literal(_) => false,
+        });
+        if is_present {
+            continue;
+        }
+        let append_item = match field.symbol {
+            FieldSymbol::Era => &append_items.era,
+            FieldSymbol::Year(_) => &append_items.year,
+            FieldSymbol::Quarter(_) => &append_items.quarter,
+            FieldSymbol::Month(_) => &append_items.month,
+            FieldSymbol::Week(_) => &append_items.week,
+            FieldSymbol::Day(_) => &append_items.day,
+            FieldSymbol::Weekday(_) => &append_items.day_of_week,
+            FieldSymbol::Hour(_) => &append_items.hour,
+            FieldSymbol::Minute => &append_items.minute,
+            FieldSymbol::Second(_) => &append_items.second,
+            FieldSymbol::TimeZone(_) => &append_items.time_zone,
+            // The day period goes along with the hour, and has no append item.
+            FieldSymbol::DayPeriod(_) => continue,
+        };
+        let missing = runtime::Pattern::from(vec![PatternItem::Field(*field)]);
+        let appended = append_item.clone().combined(missing, pattern.clone())?;
+        let time_granularity = pattern.time_granularity;
+        *pattern = appended;
+        pattern.time_granularity = pattern.time_granularity.max(time_granularity);
+    }
+    Ok(())
+}
+
 /// A partial implementation of the [UTS 35 skeleton matching algorithm](https://unicode.org/reports/tr35/tr35-dates.html#Matching_Skeletons).
 ///
 /// The following is implemented:
@@ -360,11 +431,8 @@ fn append_fractional_seconds(pattern: &mut runtime::Pattern, fields: &[Field]) {
 ///      then be modified to use the requested length to produce a pattern "d MMMM y".
 ///      However, fields should not be changed from numeric to text.
 ///
-/// The following is not implemented:
-///
-///  * 2.6.2.2 Missing Skeleton Fields
-///    - TODO(#586) - Using the CLDR appendItems field. Note: There is not agreement yet on how
-///      much of this step to implement. See the issue for more information.
+/// Missing skeleton fields are not added here, see [`create_best_pattern_for_fields`], which
+/// appends them using the CLDR `appendItems`.
 pub fn get_best_available_format_pattern<'data>(
     skeletons: &DateSkeletonPatternsV1<'data>,
     fields: &[Field],
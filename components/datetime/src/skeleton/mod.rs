@@ -25,8 +25,8 @@ mod test {
         options::components,
         pattern::runtime,
         provider::calendar::{
-            DateSkeletonPatternsV1, DateSkeletonPatternsV1Marker, GregorianDateLengthsV1Marker,
-            SkeletonV1,
+            DateAppendItemsV1Marker, DateSkeletonPatternsV1, DateSkeletonPatternsV1Marker,
+            GregorianDateLengthsV1Marker, SkeletonV1,
         },
     };
     use core::convert::TryFrom;
@@ -112,18 +112,17 @@ mod test {
                         .expect_pattern("pattern should not have plural variants")
                         .to_string(),
                     // CLDR has ("yw", "MMMMW", "ccc"). The first two result in 1 missing & 1 extra symbol vs just
-                    // 1 missing symbol for "ccc".
-                    String::from("ccc")
+                    // 1 missing symbol for "ccc". The stand-alone weekday then takes the requested field, as
+                    // months do.
+                    String::from("E")
                 )
             }
             best => panic!("Unexpected {:?}", best),
         };
     }
 
-    // TODO(#586) - Append items support needs to be added.
     #[test]
-    #[should_panic]
-    fn test_missing_append_items_support() {
+    fn test_append_items() {
         let components = components::Bag {
             year: Some(components::Year::Numeric),
             month: Some(components::Month::Long),
@@ -134,21 +133,32 @@ mod test {
         };
         let requested_fields = components.to_vec_fields();
         let (patterns, skeletons) = get_data_payload();
+        let locale = "en-u-ca-gregory".parse::<Locale>().unwrap().into();
+        let append_items: DataPayload<DateAppendItemsV1Marker> = icu_testdata::get_provider()
+            .load(DataRequest {
+                locale: &locale,
+                metadata: Default::default(),
+            })
+            .expect("Failed to load payload")
+            .take_payload()
+            .expect("Failed to retrieve payload");
 
         match create_best_pattern_for_fields(
             skeletons.get(),
             &patterns.get().length_combinations,
+            Some(append_items.get()),
             &requested_fields,
-            &Default::default(),
+            &components,
             false,
-        ) {
-            BestSkeleton::AllFieldsMatch(available_format_pattern) => {
-                // TODO - Append items are needed here.
+        )
+        .expect("Failed to append the missing fields")
+        {
+            BestSkeleton::MissingOrExtraFields(available_format_pattern) => {
                 assert_eq!(
                     available_format_pattern
                         .expect_pattern("pattern should not have plural variants")
                         .to_string(),
-                    String::from("MMMM d, y vvvv")
+                    String::from("MMMM d, y zzzz")
                 )
             }
             best => panic!("Unexpected {:?}", best),
@@ -202,7 +212,7 @@ mod test {
         "MMMMdEEEE", "MMMMdE", "MMMMd",
         "MMMMdd", "MMMd", "MMMdd", "MMd", "MMdd", "Md", "Mdd", "d", "h", "hm", "hms", "mmss", "ms",
         "y", "yM", "yMdEEEE", "yMdE", "yMM", "yMMM", "yMMMdEEEE", "yMMMdE", "yMMMM", "yMMMMdEEEE",
-        "yMMMMdE", "yMMMMdcccc", "yMMMMd", "yMMMd", "yMMdd", "yMd", "yw",
+        "yMMMMdE", "yMMMMdcccc", "yMMMMd", "yMMMd", "yMMdd", "yMd", "yQ", "yQQQ", "yQQQQ", "yw",
         "Gy", "GyM", "GyMMM", "GyMMMdEEEE", "GyMMMdE", "GyMMMM", "GyMMMMdE", "GyMMMMd", "GyMMMd",
         // Timezones
         "HHmmZ", "Hmsv", "Hmsvvvv", "Hmv", "Hmvvvv", "hmsv", "hmsvvvv", "hmv", "hmvvvv",
@@ -216,8 +226,6 @@ mod test {
     const UNSUPPORTED_STRING_SKELETONS: &[&str] = &[
         // TODO(#487) - Flexible day periods
         "Bh", "Bhm", "Bhms", "EBhm", "EBhms",
-        // TODO(#501) - Quarters
-        "yQ", "yQQQ", "yQQQQ",
     ];
 
     #[test]
@@ -75,6 +75,7 @@ impl From<&Pattern> for Skeleton {
                 field.symbol = match field.symbol {
                     // Only the format varieties are used in the skeletons, the matched patterns
                     // will be more specific.
+                    FieldSymbol::Quarter(_) => FieldSymbol::Quarter(fields::Quarter::Format),
                     FieldSymbol::Month(_) => FieldSymbol::Month(fields::Month::Format),
                     FieldSymbol::Weekday(_) => FieldSymbol::Weekday(fields::Weekday::Format),
 
@@ -0,0 +1,401 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Lists of the localized names of months, weekdays, eras and day periods, as used by
+//! calendar and date picker widgets.
+//!
+//! # Examples
+//!
+//! ```
+//! use icu::calendar::any_calendar::AnyCalendarKind;
+//! use icu::datetime::symbols::{Context, DateTimeSymbols, Width};
+//! use icu::locid::locale;
+//!
+//! let provider = icu_testdata::get_provider();
+//!
+//! let symbols = DateTimeSymbols::try_new_with_buffer_provider(
+//!     &provider,
+//!     &locale!("fr").into(),
+//!     AnyCalendarKind::Gregorian,
+//! )
+//! .expect("Failed to create DateTimeSymbols instance.");
+//!
+//! let months = symbols
+//!     .month_names(Context::StandAlone, Width::Wide)
+//!     .map(|(_, name)| name)
+//!     .collect::<Vec<_>>();
+//! assert_eq!(months.len(), 12);
+//! assert_eq!(months[0], "janvier");
+//!
+//! // Weeks start on Monday in France.
+//! let weekdays = symbols
+//!     .weekday_names(Context::StandAlone, Width::Narrow)
+//!     .map(|(_, name)| name)
+//!     .collect::<Vec<_>>();
+//! assert_eq!(weekdays, ["L", "M", "M", "J", "V", "S", "D"]);
+//! ```
+
+use crate::{
+    calendar,
+    fields::{self, FieldLength},
+    provider::{
+        calendar::{
+            day_periods, months, BuddhistDateSymbolsV1Marker, ChineseDateSymbolsV1Marker,
+            CopticDateSymbolsV1Marker, DangiDateSymbolsV1Marker, ErasedDateSymbolsV1Marker,
+            EthiopianDateSymbolsV1Marker, GregorianDateSymbolsV1Marker, HebrewDateSymbolsV1Marker,
+            IndianDateSymbolsV1Marker, IslamicDateSymbolsV1Marker, JapaneseDateSymbolsV1Marker,
+            JapaneseExtendedDateSymbolsV1Marker, PersianDateSymbolsV1Marker,
+            RocDateSymbolsV1Marker, TimeSymbolsV1Marker,
+        },
+        date_time::{self, DateSymbols},
+        week_data::WeekDataV1Marker,
+    },
+    DateTimeFormatterError,
+};
+use alloc::vec::Vec;
+use icu_calendar::{
+    any_calendar::AnyCalendarKind,
+    types::{IsoWeekday, MonthCode},
+};
+use icu_provider::prelude::*;
+use tinystr::tinystr;
+use zerovec::ule::AsULE;
+
+/// Whether names are used inside of a formatted date, such as the month in "1 janvier 2023",
+/// or on their own, such as in the header of a calendar.
+///
+/// Some languages inflect names differently in the two contexts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Context {
+    /// The names used inside of a formatted date.
+    Format,
+    /// The names used on their own.
+    StandAlone,
+}
+
+/// The width of names, such as "Tuesday", "Tue", "Tu" or "T".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Width {
+    /// The full name, such as "Tuesday".
+    Wide,
+    /// The abbreviated name, such as "Tue".
+    Abbreviated,
+    /// A name between the abbreviated and narrow ones, such as "Tu". Only weekdays have these
+    /// names, the other names use the abbreviated ones instead.
+    Short,
+    /// The narrowest name, such as "T". Narrow names are not necessarily unique.
+    Narrow,
+}
+
+impl From<Width> for FieldLength {
+    fn from(width: Width) -> Self {
+        match width {
+            Width::Wide => FieldLength::Wide,
+            Width::Abbreviated => FieldLength::Abbreviated,
+            Width::Short => FieldLength::Six,
+            Width::Narrow => FieldLength::Narrow,
+        }
+    }
+}
+
+/// The names of the day periods of a locale, see [`DateTimeSymbols::day_period_names`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct DayPeriodNames<'a> {
+    /// The name of the period before noon, such as "AM".
+    pub am: &'a str,
+    /// The name of the period after noon, such as "PM".
+    pub pm: &'a str,
+    /// The name of noon, such as "noon", if the locale has one.
+    pub noon: Option<&'a str>,
+    /// The name of midnight, such as "midnight", if the locale has one.
+    pub midnight: Option<&'a str>,
+}
+
+/// [`DateTimeSymbols`] gives access to the names of the months, weekdays, eras and day periods
+/// of a locale in a given calendar.
+///
+/// These are the names that [`DateTimeFormatter`](crate::DateTimeFormatter) uses, so a
+/// stand-alone name that a locale does not have falls back to the format name of the same width.
+///
+/// See the [module-level](self) docs for an example.
+#[derive(Debug)]
+pub struct DateTimeSymbols {
+    date_symbols: DataPayload<ErasedDateSymbolsV1Marker>,
+    time_symbols: DataPayload<TimeSymbolsV1Marker>,
+    first_weekday: IsoWeekday,
+}
+
+impl DateTimeSymbols {
+    /// Constructor that takes a selected locale, reference to a [data provider] and the kind of
+    /// calendar, then collects the names of that calendar in the given locale.
+    ///
+    /// [data provider]: icu_provider
+    #[inline(never)]
+    pub fn try_new_unstable<D>(
+        data_provider: &D,
+        locale: &DataLocale,
+        calendar: AnyCalendarKind,
+    ) -> Result<Self, DateTimeFormatterError>
+    where
+        D: DataProvider<TimeSymbolsV1Marker>
+            + DataProvider<WeekDataV1Marker>
+            + DataProvider<GregorianDateSymbolsV1Marker>
+            + DataProvider<BuddhistDateSymbolsV1Marker>
+            + DataProvider<JapaneseDateSymbolsV1Marker>
+            + DataProvider<JapaneseExtendedDateSymbolsV1Marker>
+            + DataProvider<CopticDateSymbolsV1Marker>
+            + DataProvider<ChineseDateSymbolsV1Marker>
+            + DataProvider<DangiDateSymbolsV1Marker>
+            + DataProvider<HebrewDateSymbolsV1Marker>
+            + DataProvider<PersianDateSymbolsV1Marker>
+            + DataProvider<RocDateSymbolsV1Marker>
+            + DataProvider<IslamicDateSymbolsV1Marker>
+            + DataProvider<IndianDateSymbolsV1Marker>
+            + DataProvider<EthiopianDateSymbolsV1Marker>
+            + ?Sized,
+    {
+        let req = DataRequest {
+            locale,
+            metadata: Default::default(),
+        };
+        let week_data = date_time::week_data_payload(data_provider, locale)?;
+        Ok(Self {
+            date_symbols: calendar::load_symbols_for_any_calendar_kind(
+                data_provider,
+                locale,
+                calendar,
+            )?,
+            time_symbols: data_provider.load(req)?.take_payload()?,
+            first_weekday: week_data.get().0.first_weekday,
+        })
+    }
+
+    /// Create a new instance using an [`AnyProvider`](icu_provider::AnyProvider).
+    ///
+    /// See also: [`Self::try_new_unstable`]
+    pub fn try_new_with_any_provider(
+        provider: &(impl AnyProvider + ?Sized),
+        locale: &DataLocale,
+        calendar: AnyCalendarKind,
+    ) -> Result<Self, DateTimeFormatterError> {
+        Self::try_new_unstable(&provider.as_downcasting(), locale, calendar)
+    }
+
+    /// Create a new instance using a [`BufferProvider`](icu_provider::BufferProvider). Enabled with the `"serde"` feature.
+    ///
+    /// See also: [`Self::try_new_unstable`]
+    #[cfg(feature = "serde")]
+    pub fn try_new_with_buffer_provider(
+        provider: &(impl BufferProvider + ?Sized),
+        locale: &DataLocale,
+        calendar: AnyCalendarKind,
+    ) -> Result<Self, DateTimeFormatterError> {
+        Self::try_new_unstable(&provider.as_deserializing(), locale, calendar)
+    }
+
+    /// Returns the names of the months of the calendar, in the order of their month codes.
+    ///
+    /// Leap months are included for calendars that have them. Months that are named differently
+    /// in leap years, like Adar II in the Hebrew calendar, use their common-year name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::calendar::any_calendar::AnyCalendarKind;
+    /// use icu::datetime::symbols::{Context, DateTimeSymbols, Width};
+    /// use icu::locid::locale;
+    ///
+    /// let provider = icu_testdata::get_provider();
+    /// let symbols = DateTimeSymbols::try_new_with_buffer_provider(
+    ///     &provider,
+    ///     &locale!("ru").into(),
+    ///     AnyCalendarKind::Gregorian,
+    /// )
+    /// .expect("Failed to create DateTimeSymbols instance.");
+    ///
+    /// let (code, name) = symbols
+    ///     .month_names(Context::Format, Width::Wide)
+    ///     .next()
+    ///     .unwrap();
+    /// assert_eq!(code.0, "M01");
+    /// assert_eq!(name, "января");
+    ///
+    /// let (_, name) = symbols
+    ///     .month_names(Context::StandAlone, Width::Wide)
+    ///     .next()
+    ///     .unwrap();
+    /// assert_eq!(name, "январь");
+    /// ```
+    pub fn month_names(
+        &self,
+        context: Context,
+        width: Width,
+    ) -> impl Iterator<Item = (MonthCode, &str)> + '_ {
+        const SOLAR_CODES: [MonthCode; 12] = [
+            MonthCode(tinystr!(4, "M01")),
+            MonthCode(tinystr!(4, "M02")),
+            MonthCode(tinystr!(4, "M03")),
+            MonthCode(tinystr!(4, "M04")),
+            MonthCode(tinystr!(4, "M05")),
+            MonthCode(tinystr!(4, "M06")),
+            MonthCode(tinystr!(4, "M07")),
+            MonthCode(tinystr!(4, "M08")),
+            MonthCode(tinystr!(4, "M09")),
+            MonthCode(tinystr!(4, "M10")),
+            MonthCode(tinystr!(4, "M11")),
+            MonthCode(tinystr!(4, "M12")),
+        ];
+        let symbols = self.date_symbols.get();
+        let codes: Vec<MonthCode> = match symbols.months.format.wide {
+            months::SymbolsV1::SolarTwelve(_) => SOLAR_CODES.to_vec(),
+            months::SymbolsV1::Other(ref map)
+            | months::SymbolsV1::LeapYearNames { names: ref map, .. } => map
+                .iter_keys()
+                .map(|code| MonthCode::from_unaligned(*code))
+                .collect(),
+        };
+        let month = match context {
+            Context::Format => fields::Month::Format,
+            Context::StandAlone => fields::Month::StandAlone,
+        };
+        codes.into_iter().filter_map(move |code| {
+            symbols
+                .get_symbol_for_month(month, width.into(), code, false)
+                .ok()
+                .map(|name| (code, name))
+        })
+    }
+
+    /// Returns the names of the seven weekdays, starting from the first day of the week in the
+    /// locale.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::calendar::{any_calendar::AnyCalendarKind, types::IsoWeekday};
+    /// use icu::datetime::symbols::{Context, DateTimeSymbols, Width};
+    /// use icu::locid::locale;
+    ///
+    /// let provider = icu_testdata::get_provider();
+    /// let symbols = DateTimeSymbols::try_new_with_buffer_provider(
+    ///     &provider,
+    ///     &locale!("en").into(),
+    ///     AnyCalendarKind::Gregorian,
+    /// )
+    /// .expect("Failed to create DateTimeSymbols instance.");
+    ///
+    /// assert_eq!(symbols.first_weekday(), IsoWeekday::Sunday);
+    /// let weekdays = symbols
+    ///     .weekday_names(Context::StandAlone, Width::Short)
+    ///     .map(|(_, name)| name)
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(weekdays, ["Su", "Mo", "Tu", "We", "Th", "Fr", "Sa"]);
+    /// ```
+    pub fn weekday_names(
+        &self,
+        context: Context,
+        width: Width,
+    ) -> impl Iterator<Item = (IsoWeekday, &str)> + '_ {
+        let symbols = self.date_symbols.get();
+        let weekday = match context {
+            Context::Format => fields::Weekday::Format,
+            Context::StandAlone => fields::Weekday::StandAlone,
+        };
+        let first_weekday = self.first_weekday as usize;
+        (0..7).filter_map(move |offset| {
+            let day = IsoWeekday::from((first_weekday + offset) % 7);
+            symbols
+                .get_symbol_for_weekday(weekday, width.into(), day)
+                .ok()
+                .map(|name| (day, name))
+        })
+    }
+
+    /// Returns the first day of the week in the locale, which is the first weekday returned by
+    /// [`Self::weekday_names`].
+    pub fn first_weekday(&self) -> IsoWeekday {
+        self.first_weekday
+    }
+
+    /// Returns the names of the eras of the calendar, ordered by their era codes.
+    ///
+    /// There are no short era names, so [`Width::Short`] returns the abbreviated ones.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::calendar::any_calendar::AnyCalendarKind;
+    /// use icu::datetime::symbols::{DateTimeSymbols, Width};
+    /// use icu::locid::locale;
+    ///
+    /// let provider = icu_testdata::get_provider();
+    /// let symbols = DateTimeSymbols::try_new_with_buffer_provider(
+    ///     &provider,
+    ///     &locale!("en").into(),
+    ///     AnyCalendarKind::Gregorian,
+    /// )
+    /// .expect("Failed to create DateTimeSymbols instance.");
+    ///
+    /// let eras = symbols.era_names(Width::Wide).collect::<Vec<_>>();
+    /// assert_eq!(eras, [("bce", "Before Christ"), ("ce", "Anno Domini")]);
+    /// ```
+    pub fn era_names(&self, width: Width) -> impl Iterator<Item = (&str, &str)> + '_ {
+        let eras = &self.date_symbols.get().eras;
+        let names = match width {
+            Width::Wide => &eras.names,
+            Width::Narrow => &eras.narrow,
+            Width::Abbreviated | Width::Short => &eras.abbr,
+        };
+        names.iter()
+    }
+
+    /// Returns the names of the day periods.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::calendar::any_calendar::AnyCalendarKind;
+    /// use icu::datetime::symbols::{Context, DateTimeSymbols, Width};
+    /// use icu::locid::locale;
+    ///
+    /// let provider = icu_testdata::get_provider();
+    /// let symbols = DateTimeSymbols::try_new_with_buffer_provider(
+    ///     &provider,
+    ///     &locale!("en").into(),
+    ///     AnyCalendarKind::Gregorian,
+    /// )
+    /// .expect("Failed to create DateTimeSymbols instance.");
+    ///
+    /// let names = symbols.day_period_names(Context::Format, Width::Wide);
+    /// assert_eq!((names.am, names.pm), ("AM", "PM"));
+    /// assert_eq!(names.noon, Some("noon"));
+    /// ```
+    pub fn day_period_names(&self, context: Context, width: Width) -> DayPeriodNames<'_> {
+        let day_periods = &self.time_symbols.get().day_periods;
+        let format = &day_periods.format;
+        let format = match width {
+            Width::Wide => &format.wide,
+            Width::Narrow => &format.narrow,
+            Width::Abbreviated | Width::Short => &format.abbreviated,
+        };
+        let symbols: &day_periods::SymbolsV1 = match (context, &day_periods.stand_alone) {
+            (Context::StandAlone, Some(stand_alone)) => match width {
+                Width::Wide => stand_alone.wide.as_ref(),
+                Width::Narrow => stand_alone.narrow.as_ref(),
+                Width::Abbreviated | Width::Short => stand_alone.abbreviated.as_ref(),
+            }
+            .unwrap_or(format),
+            _ => format,
+        };
+        DayPeriodNames {
+            am: &symbols.am,
+            pm: &symbols.pm,
+            noon: symbols.noon.as_deref(),
+            midnight: symbols.midnight.as_deref(),
+        }
+    }
+}
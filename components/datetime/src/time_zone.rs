@@ -91,6 +91,8 @@ pub(super) struct TimeZoneDataPayloads {
     pub(super) zone_formats: DataPayload<provider::time_zones::TimeZoneFormatsV1Marker>,
     /// The exemplar cities for time zones.
     pub(super) exemplar_cities: Option<DataPayload<provider::time_zones::ExemplarCitiesV1Marker>>,
+    /// The IANA identifiers for time zones.
+    pub(super) iana_ids: Option<DataPayload<provider::time_zones::TimeZoneIanaIdsV1Marker>>,
     /// The generic long metazone names, e.g. Pacific Time
     pub(super) mz_generic_long:
         Option<DataPayload<provider::time_zones::MetaZoneGenericNamesLongV1Marker>>,
@@ -117,6 +119,7 @@ impl TimeZoneFormatter {
     where
         ZP: DataProvider<provider::time_zones::TimeZoneFormatsV1Marker>
             + DataProvider<provider::time_zones::ExemplarCitiesV1Marker>
+            + DataProvider<provider::time_zones::TimeZoneIanaIdsV1Marker>
             + DataProvider<provider::time_zones::MetaZoneGenericNamesLongV1Marker>
             + DataProvider<provider::time_zones::MetaZoneGenericNamesShortV1Marker>
             + DataProvider<provider::time_zones::MetaZoneSpecificNamesLongV1Marker>
@@ -132,6 +135,7 @@ impl TimeZoneFormatter {
                 })?
                 .take_payload()?,
             exemplar_cities: None,
+            iana_ids: None,
             mz_generic_long: None,
             mz_generic_short: None,
             mz_specific_long: None,
@@ -200,8 +204,12 @@ impl TimeZoneFormatter {
                     }
                 },
                 TimeZone::UpperV => match length {
-                    1 => (), // BCP-47 identifier, no CLDR-data necessary.
-                    2 => (), // IANA time-zone ID, no CLDR data necessary.
+                    1 => {
+                        tz_format.load_bcp47_id_format()?;
+                    }
+                    2 => {
+                        tz_format.load_iana_id_format(zone_provider)?;
+                    }
                     3 => {
                         tz_format.load_exemplar_city_format(zone_provider)?;
                     }
@@ -241,35 +249,35 @@ impl TimeZoneFormatter {
                 TimeZone::LowerX => match length {
                     1 => {
                         tz_format.load_iso_8601_format(
-                            IsoFormat::UtcBasic,
+                            IsoFormat::Basic,
                             IsoMinutes::Optional,
                             IsoSeconds::Never,
                         )?;
                     }
                     2 => {
                         tz_format.load_iso_8601_format(
-                            IsoFormat::UtcBasic,
+                            IsoFormat::Basic,
                             IsoMinutes::Required,
                             IsoSeconds::Never,
                         )?;
                     }
                     3 => {
                         tz_format.load_iso_8601_format(
-                            IsoFormat::UtcExtended,
+                            IsoFormat::Extended,
                             IsoMinutes::Required,
                             IsoSeconds::Never,
                         )?;
                     }
                     4 => {
                         tz_format.load_iso_8601_format(
-                            IsoFormat::UtcBasic,
+                            IsoFormat::Basic,
                             IsoMinutes::Required,
                             IsoSeconds::Optional,
                         )?;
                     }
                     5 => {
                         tz_format.load_iso_8601_format(
-                            IsoFormat::UtcExtended,
+                            IsoFormat::Extended,
                             IsoMinutes::Required,
                             IsoSeconds::Optional,
                         )?;
@@ -283,35 +291,35 @@ impl TimeZoneFormatter {
                 TimeZone::UpperX => match length {
                     1 => {
                         tz_format.load_iso_8601_format(
-                            IsoFormat::Basic,
+                            IsoFormat::UtcBasic,
                             IsoMinutes::Optional,
                             IsoSeconds::Never,
                         )?;
                     }
                     2 => {
                         tz_format.load_iso_8601_format(
-                            IsoFormat::Basic,
+                            IsoFormat::UtcBasic,
                             IsoMinutes::Required,
                             IsoSeconds::Never,
                         )?;
                     }
                     3 => {
                         tz_format.load_iso_8601_format(
-                            IsoFormat::Extended,
+                            IsoFormat::UtcExtended,
                             IsoMinutes::Required,
                             IsoSeconds::Never,
                         )?;
                     }
                     4 => {
                         tz_format.load_iso_8601_format(
-                            IsoFormat::Basic,
+                            IsoFormat::UtcBasic,
                             IsoMinutes::Required,
                             IsoSeconds::Optional,
                         )?;
                     }
                     5 => {
                         tz_format.load_iso_8601_format(
-                            IsoFormat::Extended,
+                            IsoFormat::UtcExtended,
                             IsoMinutes::Required,
                             IsoSeconds::Optional,
                         )?;
@@ -382,6 +390,7 @@ impl TimeZoneFormatter {
                 })?
                 .take_payload()?,
             exemplar_cities: None,
+            iana_ids: None,
             mz_generic_long: None,
             mz_generic_short: None,
             mz_specific_long: None,
@@ -563,6 +572,34 @@ impl TimeZoneFormatter {
         Ok(self)
     }
 
+    /// Load BCP-47 identifier format for timezone. For example, uslax.
+    fn load_bcp47_id_format(&mut self) -> Result<&mut TimeZoneFormatter, DateTimeFormatterError> {
+        self.format_units
+            .push(TimeZoneFormatterUnit::Bcp47Id(Bcp47IdFormat {}));
+        Ok(self)
+    }
+
+    /// Load IANA identifier format for timezone. For example, America/Los_Angeles.
+    fn load_iana_id_format<ZP>(
+        &mut self,
+        zone_provider: &ZP,
+    ) -> Result<&mut TimeZoneFormatter, DateTimeFormatterError>
+    where
+        ZP: DataProvider<provider::time_zones::TimeZoneIanaIdsV1Marker> + ?Sized,
+    {
+        if self.data_payloads.iana_ids.is_none() {
+            // The IANA identifiers are not localized.
+            load(
+                &Default::default(),
+                &mut self.data_payloads.iana_ids,
+                zone_provider,
+            )?;
+        }
+        self.format_units
+            .push(TimeZoneFormatterUnit::IanaId(IanaIdFormat {}));
+        Ok(self)
+    }
+
     /// Load localized GMT format for timezone. For example, GMT-07:00.
     pub fn load_localized_gmt_format(
         &mut self,
@@ -766,6 +803,11 @@ impl TimeZoneFormatter {
 
 /// Determines which ISO-8601 format should be used to format a [`GmtOffset`](icu_timezone::GmtOffset).
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
 #[allow(clippy::exhaustive_enums)] // this type is stable
 pub enum IsoFormat {
     /// ISO-8601 Basic Format.
@@ -791,6 +833,11 @@ pub enum IsoFormat {
 
 /// Whether the minutes field should be optional or required in ISO-8601 format.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
 #[allow(clippy::exhaustive_enums)] // this type is stable
 pub enum IsoMinutes {
     /// Minutes are always displayed.
@@ -802,6 +849,11 @@ pub enum IsoMinutes {
 
 /// Whether the seconds field should be optional or excluded in ISO-8601 format.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
 #[allow(clippy::exhaustive_enums)] // this type is stable
 pub enum IsoSeconds {
     /// Seconds are displayed only if they are non-zero.
@@ -892,6 +944,16 @@ pub(super) struct Iso8601Format {
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub(super) struct ExemplarCityFormat {}
 
+// uslax
+// It is only used for pattern in special case and not public to users.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) struct Bcp47IdFormat {}
+
+// America/Los_Angeles
+// It is only used for pattern in special case and not public to users.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) struct IanaIdFormat {}
+
 // An enum for time zone format unit.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub(super) enum TimeZoneFormatterUnit {
@@ -903,6 +965,8 @@ pub(super) enum TimeZoneFormatterUnit {
     LocalizedGmt(LocalizedGmtFormat),
     Iso8601(Iso8601Format),
     ExemplarCity(ExemplarCityFormat),
+    Bcp47Id(Bcp47IdFormat),
+    IanaId(IanaIdFormat),
 }
 
 impl Default for TimeZoneFormatterUnit {
@@ -936,6 +1000,8 @@ impl FormatTimeZone for TimeZoneFormatterUnit {
             Self::LocalizedGmt(unit) => unit.format(sink, time_zone, data_payloads),
             Self::Iso8601(unit) => unit.format(sink, time_zone, data_payloads),
             Self::ExemplarCity(unit) => unit.format(sink, time_zone, data_payloads),
+            Self::Bcp47Id(unit) => unit.format(sink, time_zone, data_payloads),
+            Self::IanaId(unit) => unit.format(sink, time_zone, data_payloads),
         }
     }
 }
@@ -1229,9 +1295,7 @@ impl FormatTimeZone for Iso8601Format {
             if gmt_offset.is_zero()
                 && matches!(self.format, IsoFormat::UtcBasic | IsoFormat::UtcExtended)
             {
-                if let Err(e) = sink.write_char('Z') {
-                    return Ok(Err(e));
-                }
+                return Ok(sink.write_char('Z'));
             }
 
             let extended_format =
@@ -1346,3 +1410,47 @@ impl FormatTimeZone for ExemplarCityFormat {
         }
     }
 }
+
+impl FormatTimeZone for Bcp47IdFormat {
+    /// Writes the short time zone identifier as defined by the UTS-35 spec.
+    /// e.g. uslax
+    /// https://unicode.org/reports/tr35/tr35-dates.html#dfst-zone
+    fn format<W: fmt::Write + ?Sized>(
+        &self,
+        sink: &mut W,
+        time_zone: &impl TimeZoneInput,
+        _data_payloads: &TimeZoneDataPayloads,
+    ) -> Result<fmt::Result, DateTimeFormatterError> {
+        // The special short identifier "unk" is used for an unknown time zone.
+        let bcp47_id = time_zone
+            .time_zone_id()
+            .unwrap_or(TimeZoneBcp47Id(tinystr!(8, "unk")));
+        Ok(sink.write_str(&bcp47_id.0))
+    }
+}
+
+impl FormatTimeZone for IanaIdFormat {
+    /// Writes the long time zone identifier as defined by the UTS-35 spec.
+    /// e.g. America/Los_Angeles
+    /// https://unicode.org/reports/tr35/tr35-dates.html#dfst-zone
+    fn format<W: fmt::Write + ?Sized>(
+        &self,
+        sink: &mut W,
+        time_zone: &impl TimeZoneInput,
+        data_payloads: &TimeZoneDataPayloads,
+    ) -> Result<fmt::Result, DateTimeFormatterError> {
+        let iana_id = data_payloads
+            .iana_ids
+            .as_ref()
+            .map(|p| p.get())
+            .and_then(|ids| {
+                ids.0.get(
+                    &time_zone
+                        .time_zone_id()
+                        .unwrap_or(TimeZoneBcp47Id(tinystr!(8, "unk"))),
+                )
+            });
+        // The special identifier "Etc/Unknown" is used for an unknown time zone.
+        Ok(sink.write_str(iana_id.map_or("Etc/Unknown", |id| id)))
+    }
+}
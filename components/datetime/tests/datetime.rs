@@ -19,7 +19,7 @@ use icu_calendar::{
 use icu_datetime::provider::time_zones::{
     ExemplarCitiesV1Marker, MetaZoneGenericNamesLongV1Marker, MetaZoneGenericNamesShortV1Marker,
     MetaZoneId, MetaZoneSpecificNamesLongV1Marker, MetaZoneSpecificNamesShortV1Marker,
-    TimeZoneBcp47Id, TimeZoneFormatsV1Marker,
+    TimeZoneBcp47Id, TimeZoneFormatsV1Marker, TimeZoneIanaIdsV1Marker,
 };
 use icu_datetime::time_zone::TimeZoneFormatterConfig;
 use icu_datetime::{
@@ -395,6 +395,9 @@ fn test_dayperiod_patterns() {
         #[cfg(feature = "experimental")]
         let skeleton_data: DataPayload<DateSkeletonPatternsV1Marker> =
             provider.load(req).unwrap().take_payload().unwrap();
+        #[cfg(feature = "experimental")]
+        let append_items_data: DataPayload<DateAppendItemsV1Marker> =
+            provider.load(req).unwrap().take_payload().unwrap();
         let week_data: DataPayload<WeekDataV1Marker> =
             provider.load(req).unwrap().take_payload().unwrap();
         data_locale.retain_unicode_ext(|_| false);
@@ -431,6 +434,11 @@ fn test_dayperiod_patterns() {
                                 key: DateSkeletonPatternsV1Marker::KEY,
                                 data: skeleton_data.clone().wrap_into_any_payload(),
                             },
+                            #[cfg(feature = "experimental")]
+                            AnyPayloadProvider {
+                                key: DateAppendItemsV1Marker::KEY,
+                                data: append_items_data.clone().wrap_into_any_payload(),
+                            },
                             AnyPayloadProvider {
                                 key: GregorianDateLengthsV1Marker::KEY,
                                 data: date_patterns_data.clone().wrap_into_any_payload(),
@@ -599,6 +607,9 @@ fn test_time_zone_patterns() {
         #[cfg(feature = "experimental")]
         let skeleton_data: DataPayload<DateSkeletonPatternsV1Marker> =
             date_provider.load(req).unwrap().take_payload().unwrap();
+        #[cfg(feature = "experimental")]
+        let append_items_data: DataPayload<DateAppendItemsV1Marker> =
+            date_provider.load(req).unwrap().take_payload().unwrap();
         let symbols_data: DataPayload<GregorianDateSymbolsV1Marker> =
             date_provider.load(req).unwrap().take_payload().unwrap();
         let week_data: DataPayload<WeekDataV1Marker> =
@@ -617,6 +628,14 @@ fn test_time_zone_patterns() {
             zone_provider.load(req).unwrap().take_payload().unwrap();
         let exemplar_cities_data: DataPayload<ExemplarCitiesV1Marker> =
             zone_provider.load(req).unwrap().take_payload().unwrap();
+        let iana_ids_data: DataPayload<TimeZoneIanaIdsV1Marker> = zone_provider
+            .load(DataRequest {
+                locale: &Default::default(),
+                metadata: Default::default(),
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap();
 
         date_patterns_data.with_mut(|data| {
             data.length_combinations.long = "{0}".parse().unwrap();
@@ -646,6 +665,11 @@ fn test_time_zone_patterns() {
                         key: DateSkeletonPatternsV1Marker::KEY,
                         data: skeleton_data.clone().wrap_into_any_payload(),
                     },
+                    #[cfg(feature = "experimental")]
+                    AnyPayloadProvider {
+                        key: DateAppendItemsV1Marker::KEY,
+                        data: append_items_data.clone().wrap_into_any_payload(),
+                    },
                     AnyPayloadProvider {
                         key: GregorianDateLengthsV1Marker::KEY,
                         data: date_patterns_data.clone().wrap_into_any_payload(),
@@ -688,6 +712,10 @@ fn test_time_zone_patterns() {
                         key: ExemplarCitiesV1Marker::KEY,
                         data: exemplar_cities_data.clone().wrap_into_any_payload(),
                     },
+                    AnyPayloadProvider {
+                        key: TimeZoneIanaIdsV1Marker::KEY,
+                        data: iana_ids_data.clone().wrap_into_any_payload(),
+                    },
                 ]);
 
                 for (&fallback_format, expect) in fallback_formats.iter().zip(expected.iter()) {
@@ -793,6 +821,13 @@ fn test_components_combine_datetime() {
     test_fixture("components-combine-datetime");
 }
 
+/// Tests that component::Bags with week numbers use the week data of the locale.
+#[test]
+fn test_components_week() {
+    // components/datetime/tests/fixtures/tests/components-week.json
+    test_fixture("components-week");
+}
+
 #[test]
 fn constructing_datetime_format_with_time_zone_pattern_symbols_is_err() {
     use icu_datetime::{
@@ -844,3 +879,369 @@ fn test_vertical_fallback_disabled() {
         dtf.format_to_string(&DateTime::new_gregorian_datetime(2022, 4, 5, 12, 33, 44).unwrap())
     );
 }
+
+#[test]
+fn test_custom_patterns() {
+    use icu_datetime::DateTimeFormatterError;
+    use icu_locid::locale;
+
+    let provider = icu_testdata::get_provider();
+    let datetime = DateTime::new_gregorian_datetime(2022, 4, 5, 12, 33, 44).unwrap();
+
+    for (locale, pattern, expected) in [
+        (locale!("en"), "EEE, d MMM y HH:mm", "Tue, 5 Apr 2022 12:33"),
+        (locale!("fr"), "EEEE d MMMM y", "mardi 5 avril 2022"),
+        (locale!("ar-EG"), "d/M/y", "٥/٤/٢٠٢٢"),
+        (
+            locale!("en"),
+            "yyyy-MM-dd'T'HH:mm:ss",
+            "2022-04-05T12:33:44",
+        ),
+        (locale!("en"), "h 'o''clock' a", "12 o'clock PM"),
+    ] {
+        let dtf = TypedDateTimeFormatter::<Gregorian>::try_new_with_pattern_unstable(
+            &provider,
+            &locale.into(),
+            pattern,
+        )
+        .unwrap();
+        assert_eq!(dtf.format_to_string(&datetime), expected, "{}", pattern);
+    }
+
+    // Only two-digit years are truncated to their low-order digits
+    let dtf = TypedDateTimeFormatter::<Gregorian>::try_new_with_pattern_unstable(
+        &provider,
+        &locale!("en").into(),
+        "yy DD",
+    )
+    .unwrap();
+    let datetime = DateTime::new_gregorian_datetime(2022, 7, 3, 0, 0, 0).unwrap();
+    assert_eq!(dtf.format_to_string(&datetime), "22 184");
+
+    for pattern in ["HH:mm VV", "HH:mm:ss.A"] {
+        let result = TypedDateTimeFormatter::<Gregorian>::try_new_with_pattern_unstable(
+            &provider,
+            &locale!("en").into(),
+            pattern,
+        );
+        assert!(
+            matches!(result, Err(DateTimeFormatterError::UnsupportedField(_))),
+            "{}",
+            pattern
+        );
+    }
+
+    // Fields with a length that is not defined for them
+    for pattern in [
+        "ddd MMM y",
+        "HHH:mm",
+        "h:mmm",
+        "h:mm:sss",
+        "www",
+        "WW y",
+        "DDDD",
+        "EEEEEEE",
+    ] {
+        let result = TypedDateTimeFormatter::<Gregorian>::try_new_with_pattern_unstable(
+            &provider,
+            &locale!("en").into(),
+            pattern,
+        );
+        assert!(
+            matches!(result, Err(DateTimeFormatterError::Pattern(_))),
+            "{}",
+            pattern
+        );
+    }
+
+    assert!(matches!(
+        TypedDateTimeFormatter::<Gregorian>::try_new_with_pattern_unstable(
+            &provider,
+            &locale!("en").into(),
+            "HH:mm 'unterminated",
+        ),
+        Err(DateTimeFormatterError::Pattern(_))
+    ));
+}
+
+#[test]
+fn test_iso_week_date() {
+    use icu_locid::locale;
+
+    let provider = icu_testdata::get_provider();
+
+    for (locale, date, expected) in [
+        (locale!("en"), (2023, 2, 1), "2023-W05-3"),
+        // The week data of "en" would put this Sunday in the first week of 2020.
+        (locale!("en"), (2019, 12, 29), "2019-W52-7"),
+        (locale!("en"), (2019, 12, 30), "2020-W01-1"),
+        (locale!("en"), (2021, 1, 3), "2020-W53-7"),
+        (locale!("fr"), (2021, 1, 4), "2021-W01-1"),
+        (locale!("ar-EG"), (2023, 2, 1), "٢٠٢٣-W٠٥-٣"),
+    ] {
+        let dtf = TypedDateTimeFormatter::<Gregorian>::try_new_iso_week_date_unstable(
+            &provider,
+            &locale.into(),
+        )
+        .unwrap();
+        let datetime = DateTime::new_gregorian_datetime(date.0, date.1, date.2, 0, 0, 0).unwrap();
+        assert_eq!(dtf.format_to_string(&datetime), expected, "{:?}", date);
+    }
+}
+
+#[test]
+fn test_symbols() {
+    use icu_calendar::types::IsoWeekday;
+    use icu_datetime::symbols::{Context, DateTimeSymbols, Width};
+    use icu_locid::locale;
+
+    let provider = icu_testdata::get_provider();
+
+    let symbols = DateTimeSymbols::try_new_unstable(
+        &provider,
+        &locale!("en").into(),
+        AnyCalendarKind::Ethiopian,
+    )
+    .unwrap();
+    let months = symbols
+        .month_names(Context::Format, Width::Wide)
+        .collect::<Vec<_>>();
+    assert_eq!(months.len(), 13);
+    assert_eq!(months[0].1, "Meskerem");
+    assert_eq!(months[12].0 .0, "M13");
+    assert_eq!(months[12].1, "Pagumen");
+
+    let symbols = DateTimeSymbols::try_new_unstable(
+        &provider,
+        &locale!("ar-EG").into(),
+        AnyCalendarKind::Gregorian,
+    )
+    .unwrap();
+    assert_eq!(symbols.first_weekday(), IsoWeekday::Saturday);
+    let weekdays = symbols
+        .weekday_names(Context::Format, Width::Wide)
+        .map(|(day, _)| day)
+        .collect::<Vec<_>>();
+    assert_eq!(weekdays[0], IsoWeekday::Saturday);
+    assert_eq!(weekdays[6], IsoWeekday::Friday);
+
+    // Stand-alone names that are missing fall back to the format names.
+    let symbols = DateTimeSymbols::try_new_unstable(
+        &provider,
+        &locale!("en").into(),
+        AnyCalendarKind::Gregorian,
+    )
+    .unwrap();
+    assert_eq!(
+        symbols.day_period_names(Context::StandAlone, Width::Abbreviated),
+        symbols.day_period_names(Context::Format, Width::Abbreviated)
+    );
+    assert!(symbols
+        .month_names(Context::StandAlone, Width::Abbreviated)
+        .map(|(_, name)| name)
+        .eq(["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"]));
+}
+
+#[test]
+fn test_region_override_keywords() {
+    use icu_calendar::types::IsoWeekday;
+    use icu_datetime::{options::length, symbols::DateTimeSymbols};
+
+    let provider = icu_testdata::get_provider();
+
+    // en-ZA uses a 24-hour clock, while en uses a 12-hour clock.
+    let datetime = DateTime::new_gregorian_datetime(2023, 1, 1, 13, 5, 0).unwrap();
+    for (locale, expected) in [
+        ("en", "1:05 PM"),
+        ("en-u-rg-zazzzz", "13:05"),
+        ("en-u-rg-zazzzz-hc-h12", "1:05 PM"),
+        ("en-ZA-u-rg-uszzzz", "1:05 pm"),
+        ("en-u-sd-zawc", "13:05"),
+    ] {
+        let locale: DataLocale = locale.parse::<Locale>().unwrap().into();
+        let tf = TimeFormatter::try_new_unstable(&provider, &locale, length::Time::Short).unwrap();
+        assert_eq!(tf.format_to_string(&datetime), expected, "{}", locale);
+    }
+
+    // 2023-01-01 is a Sunday, which starts the first week of 2023 in the United States and
+    // ends the last week of 2022 in Great Britain.
+    for (locale, first_weekday, week) in [
+        ("en", IsoWeekday::Sunday, "1"),
+        ("en-u-rg-gbzzzz", IsoWeekday::Monday, "52"),
+        ("en-GB-u-rg-uszzzz", IsoWeekday::Sunday, "1"),
+        ("en-u-fw-mon", IsoWeekday::Monday, "1"),
+        ("en-u-rg-gbzzzz-fw-fri", IsoWeekday::Friday, "1"),
+    ] {
+        let locale: DataLocale = locale.parse::<Locale>().unwrap().into();
+        let symbols =
+            DateTimeSymbols::try_new_unstable(&provider, &locale, AnyCalendarKind::Gregorian)
+                .unwrap();
+        assert_eq!(symbols.first_weekday(), first_weekday, "{}", locale);
+        let dtf = TypedDateTimeFormatter::<Gregorian>::try_new_with_pattern_unstable(
+            &provider, &locale, "w",
+        )
+        .unwrap();
+        assert_eq!(dtf.format_to_string(&datetime), week, "{}", locale);
+    }
+}
+
+#[test]
+fn test_julian_gregorian() {
+    use icu_calendar::julian_gregorian::JulianGregorian;
+    use icu_datetime::options::length;
+    use icu_locid::locale;
+
+    let provider = icu_testdata::get_provider();
+    let formatter = TypedDateFormatter::<JulianGregorian>::try_new_unstable(
+        &provider,
+        &locale!("en").into(),
+        length::Date::Long,
+    )
+    .unwrap();
+
+    // The day before the cutover is formatted as a Julian date
+    let last_julian_day =
+        icu_calendar::Date::new_julian_gregorian_date(1582, 10, 4, JulianGregorian::new()).unwrap();
+    assert_eq!(
+        formatter.format_to_string(&last_julian_day),
+        "October 4, 1582"
+    );
+    let next_day = last_julian_day
+        .added(
+            icu_calendar::DateDuration::new(0, 0, 0, 1),
+            icu_calendar::types::Overflow::Reject,
+        )
+        .unwrap();
+    assert_eq!(formatter.format_to_string(&next_day), "October 15, 1582");
+}
+
+#[test]
+#[cfg(feature = "experimental")]
+fn test_year_month_and_month_day() {
+    use icu_calendar::types::{Era, MonthCode};
+    use icu_calendar::{MonthDay, YearMonth};
+    use icu_datetime::options::components;
+    use icu_locid::locale;
+    use tinystr::tinystr;
+
+    let provider = icu_testdata::get_provider();
+    let march = MonthCode(tinystr!(4, "M03"));
+
+    let mut bag = components::Bag::default();
+    bag.year = Some(components::Year::Numeric);
+    bag.month = Some(components::Month::Long);
+    let formatter = TypedDateTimeFormatter::<Gregorian>::try_new_unstable(
+        &provider,
+        &locale!("en").into(),
+        bag.into(),
+    )
+    .unwrap();
+    let year_month =
+        YearMonth::try_new_from_codes(Era(tinystr!(16, "ce")), 2023, march, Gregorian).unwrap();
+    assert_eq!(formatter.format_to_string(&year_month), "March 2023");
+
+    let mut bag = components::Bag::default();
+    bag.month = Some(components::Month::Long);
+    bag.day = Some(components::Day::NumericDayOfMonth);
+    let formatter = TypedDateTimeFormatter::<Gregorian>::try_new_unstable(
+        &provider,
+        &locale!("en").into(),
+        bag.into(),
+    )
+    .unwrap();
+    let month_day = MonthDay::try_new_from_codes(march, 5, Gregorian).unwrap();
+    assert_eq!(formatter.format_to_string(&month_day), "March 5");
+}
+
+#[test]
+fn test_hebrew_leap_year_months() {
+    use icu_calendar::Date;
+    use icu_datetime::{options::length, DateFormatter};
+    use icu_locid::locale;
+
+    let provider = icu_testdata::get_provider();
+    let formatter = DateFormatter::try_new_unstable(
+        &provider,
+        &locale!("en-u-ca-hebrew").into(),
+        length::Date::Long,
+    )
+    .unwrap();
+
+    // Adar keeps its code in leap years, where it is named Adar II
+    let adar_ii = Date::new_hebrew_date(5784, 7, 14).unwrap();
+    assert_eq!(adar_ii.month().code.0, "M06");
+    assert_eq!(
+        formatter.format_to_string(&adar_ii.to_any()).unwrap(),
+        "Adar II 14, 5784 AM"
+    );
+    let adar_i = Date::new_hebrew_date(5784, 6, 14).unwrap();
+    assert_eq!(
+        formatter.format_to_string(&adar_i.to_any()).unwrap(),
+        "Adar I 14, 5784 AM"
+    );
+    let adar = Date::new_hebrew_date(5785, 6, 14).unwrap();
+    assert_eq!(
+        formatter.format_to_string(&adar.to_any()).unwrap(),
+        "Adar 14, 5785 AM"
+    );
+}
+
+#[test]
+fn test_islamic() {
+    use icu_calendar::Date;
+    use icu_datetime::{options::length, DateFormatter};
+
+    let provider = icu_testdata::get_provider();
+    let date = Date::new_iso_date(2024, 3, 24).unwrap().to_any();
+
+    for (locale, expected) in [
+        ("en-u-ca-islamic-civil", "Ramadan 14, 1445 AH"),
+        ("en-u-ca-islamic-tbla", "Ramadan 15, 1445 AH"),
+        ("en-u-ca-islamic-umalqura", "Ramadan 14, 1445 AH"),
+    ] {
+        let locale = Locale::from_str(locale).unwrap();
+        let formatter =
+            DateFormatter::try_new_unstable(&provider, &locale.into(), length::Date::Long).unwrap();
+        assert_eq!(formatter.format_to_string(&date).unwrap(), expected);
+    }
+}
+
+#[test]
+fn test_chinese_cyclic_years() {
+    use icu_calendar::Date;
+    use icu_datetime::{options::length, DateFormatter};
+
+    let provider = icu_testdata::get_provider();
+    // The first day of the year of the water rabbit
+    let date = Date::new_iso_date(2023, 1, 22).unwrap().to_any();
+
+    for locale in ["en-u-ca-chinese", "en-u-ca-dangi"] {
+        let locale = Locale::from_str(locale).unwrap();
+        let formatter =
+            DateFormatter::try_new_unstable(&provider, &locale.into(), length::Date::Long).unwrap();
+        assert_eq!(
+            formatter.format_to_string(&date).unwrap(),
+            "First Month 1, 2023(gui-mao)"
+        );
+    }
+}
+
+#[test]
+fn test_persian_and_roc() {
+    use icu_calendar::Date;
+    use icu_datetime::{options::length, DateFormatter};
+
+    let provider = icu_testdata::get_provider();
+
+    for (locale, (year, month, day), expected) in [
+        ("en-u-ca-persian", (2024, 3, 20), "Farvardin 1, 1403 AP"),
+        ("en-u-ca-roc", (2024, 3, 20), "March 20, 113 Minguo"),
+        ("en-u-ca-roc", (1900, 1, 1), "January 1, 12 Before R.O.C."),
+    ] {
+        let date = Date::new_iso_date(year, month, day).unwrap().to_any();
+        let locale = Locale::from_str(locale).unwrap();
+        let formatter =
+            DateFormatter::try_new_unstable(&provider, &locale.into(), length::Date::Long).unwrap();
+        assert_eq!(formatter.format_to_string(&date).unwrap(), expected);
+    }
+}
@@ -0,0 +1,123 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_calendar::any_calendar::AnyCalendarKind;
+use icu_datetime::{options::preferences::HourCycle, DateTimePatternGenerator};
+use icu_locid::Locale;
+
+fn generator(locale: &str, calendar: AnyCalendarKind) -> DateTimePatternGenerator {
+    let provider = icu_testdata::get_provider();
+    DateTimePatternGenerator::try_new_unstable(
+        &provider,
+        &locale.parse::<Locale>().unwrap().into(),
+        calendar,
+    )
+    .expect("Failed to create a DateTimePatternGenerator.")
+}
+
+#[test]
+fn test_best_patterns() {
+    let cases = [
+        ("en", AnyCalendarKind::Gregorian, "yMd", "M/d/y"),
+        ("en", AnyCalendarKind::Gregorian, "yMMMMd", "MMMM d, y"),
+        (
+            "en",
+            AnyCalendarKind::Gregorian,
+            "yMMMdjm",
+            "MMM d, y, h:mm a",
+        ),
+        ("en", AnyCalendarKind::Gregorian, "Hms", "HH:mm:ss"),
+        ("en", AnyCalendarKind::Buddhist, "yMMMd", "MMM d, y G"),
+        ("en", AnyCalendarKind::Japanese, "yMMMd", "MMM d, y G"),
+        (
+            "fr",
+            AnyCalendarKind::Gregorian,
+            "yMMMMEEEEd",
+            "EEEE d MMMM y",
+        ),
+        ("ja", AnyCalendarKind::Gregorian, "yMMMd", "y年M月d日"),
+        ("ja", AnyCalendarKind::Gregorian, "jm", "H:mm"),
+        ("ja", AnyCalendarKind::Gregorian, "hm", "aK:mm"),
+        ("ru", AnyCalendarKind::Gregorian, "yMMMMd", "d MMMM y г."),
+    ];
+    for (locale, calendar, skeleton, expected) in cases {
+        assert_eq!(
+            generator(locale, calendar)
+                .get_best_pattern(skeleton)
+                .unwrap(),
+            expected,
+            "{} {:?} {}",
+            locale,
+            calendar,
+            skeleton
+        );
+    }
+}
+
+#[test]
+fn test_hour_cycles() {
+    let cases = [
+        ("en", HourCycle::H12, "h:mm a"),
+        ("en-u-hc-h23", HourCycle::H23, "HH:mm"),
+        ("en-u-hc-h11", HourCycle::H11, "K:mm a"),
+        ("en-u-hc-h24", HourCycle::H24, "kk:mm"),
+        ("fr", HourCycle::H23, "HH:mm"),
+        ("fr-u-hc-h12", HourCycle::H12, "h:mm a"),
+        ("ja", HourCycle::H23, "H:mm"),
+        ("en-u-rg-gbzzzz", HourCycle::H23, "HH:mm"),
+        ("en-ZA-u-rg-uszzzz", HourCycle::H12, "h:mm a"),
+    ];
+    for (locale, hour_cycle, expected) in cases {
+        let generator = generator(locale, AnyCalendarKind::Gregorian);
+        assert_eq!(generator.hour_cycle(), hour_cycle, "{}", locale);
+        assert_eq!(
+            generator.get_best_pattern("jm").unwrap(),
+            expected,
+            "{}",
+            locale
+        );
+    }
+}
+
+#[test]
+fn test_hour_symbols() {
+    let cases = [
+        ("en", "Jm", "hh:mm"),
+        ("en", "Cm", "h:mm a"),
+        ("en", "jmm", "h:mm a"),
+        ("en-u-hc-h11", "Jm", "KK:mm"),
+        ("en-u-hc-h24", "Cm", "kk:mm"),
+        ("fr", "Jm", "HH:mm"),
+        ("fr", "Cm", "HH:mm"),
+        // Iran prefers the 24-hour clock, but the 12-hour clock is the most common one.
+        ("en-u-rg-irzzzz", "jm", "HH:mm"),
+        ("en-u-rg-irzzzz", "Cm", "h:mm a"),
+        ("en-u-rg-irzzzz-hc-h11", "Cm", "K:mm a"),
+    ];
+    for (locale, skeleton, expected) in cases {
+        let generator = generator(locale, AnyCalendarKind::Gregorian);
+        assert_eq!(
+            generator.get_best_pattern(skeleton).unwrap(),
+            expected,
+            "{} {}",
+            locale,
+            skeleton
+        );
+    }
+}
+
+#[test]
+fn test_skeletons() {
+    let generator = generator("en", AnyCalendarKind::Gregorian);
+    let skeletons = generator.skeletons().collect::<Vec<_>>();
+    assert!(skeletons.iter().any(|skeleton| skeleton == "yMMMd"));
+    assert!(skeletons.iter().any(|skeleton| skeleton == "Hms"));
+    for skeleton in skeletons {
+        assert!(
+            generator.get_best_pattern(&skeleton).is_ok(),
+            "{}",
+            skeleton
+        );
+    }
+}
@@ -3,6 +3,7 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use displaydoc::Display;
+use icu_calendar::DateTimeError;
 use icu_provider::prelude::DataError;
 
 #[cfg(feature = "std")]
@@ -19,6 +20,12 @@ pub enum TimeZoneError {
     /// The time zone offset was invalid.
     #[displaydoc("Failed to parse time-zone offset")]
     InvalidOffset,
+    /// The IXDTF string was invalid.
+    #[displaydoc("Failed to parse IXDTF string")]
+    InvalidIxdtf,
+    /// An error originating inside of [`icu_calendar`].
+    #[displaydoc("{0}")]
+    DateTime(DateTimeError),
     /// An error originating inside of the [data provider](icu_provider).
     #[displaydoc("{0}")]
     DataProvider(DataError),
@@ -29,3 +36,9 @@ impl From<DataError> for TimeZoneError {
         TimeZoneError::DataProvider(e)
     }
 }
+
+impl From<DateTimeError> for TimeZoneError {
+    fn from(e: DateTimeError) -> Self {
+        TimeZoneError::DateTime(e)
+    }
+}
@@ -0,0 +1,537 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::{GmtOffset, TimeZoneError};
+use alloc::string::String;
+use core::str::FromStr;
+use icu_calendar::{
+    any_calendar::AnyCalendarKind, provider::JapaneseErasV1Marker,
+    provider::JapaneseExtendedErasV1Marker, types::Time, AnyCalendar, Date, DateTime, Iso,
+};
+use icu_locid::extensions::unicode::Value;
+use icu_provider::prelude::*;
+
+/// The time zone annotation of an IXDTF string, such as `[America/New_York]` or `[+05:30]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TimeZoneAnnotation {
+    /// An IANA time zone identifier, such as `America/New_York`.
+    Iana(String),
+    /// A fixed offset from UTC, such as `+05:30`.
+    Offset(GmtOffset),
+}
+
+/// A date, optionally with a time, a UTC offset, a time zone and a calendar, parsed from an
+/// [RFC 3339] or [RFC 9557] (Internet Extended Date/Time Format, IXDTF) string.
+///
+/// The basic and extended formats of [ISO 8601] dates and times are accepted as well, such as
+/// `20230105T100000`. Annotations other than the time zone and the calendar (`u-ca`) are ignored,
+/// unless they are marked as critical with a `!`, in which case parsing fails.
+///
+/// # Examples
+///
+/// ```
+/// use icu_calendar::any_calendar::AnyCalendarKind;
+/// use icu_timezone::{IxdtfDateTime, TimeZoneAnnotation};
+///
+/// let parsed: IxdtfDateTime = "2023-01-05T10:00:00.123-05:00[America/New_York][u-ca=coptic]"
+///     .parse()
+///     .expect("Failed to parse an IXDTF string.");
+///
+/// assert_eq!(parsed.date.year().number, 2023);
+/// assert_eq!(parsed.time.unwrap().hour.number(), 10);
+/// assert_eq!(parsed.time.unwrap().nanosecond.number(), 123_000_000);
+/// assert_eq!(parsed.offset.unwrap().raw_offset_seconds(), -5 * 60 * 60);
+/// assert_eq!(
+///     parsed.time_zone,
+///     Some(TimeZoneAnnotation::Iana("America/New_York".into()))
+/// );
+/// assert_eq!(parsed.calendar_kind(), Ok(AnyCalendarKind::Coptic));
+///
+/// # #[cfg(feature = "serde")] {
+/// let any_datetime = parsed
+///     .to_any_date_time_with_buffer_provider(&icu_testdata::get_provider())
+///     .expect("Failed to create the calendar.");
+/// assert_eq!(any_datetime.date.year().number, 1739);
+/// # }
+/// ```
+///
+/// [RFC 3339]: https://www.rfc-editor.org/rfc/rfc3339
+/// [RFC 9557]: https://www.rfc-editor.org/rfc/rfc9557
+/// [ISO 8601]: https://www.iso.org/iso-8601-date-and-time-format.html
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct IxdtfDateTime {
+    /// The date, in the ISO calendar.
+    pub date: Date<Iso>,
+    /// The time, if the string has one.
+    pub time: Option<Time>,
+    /// The UTC offset following the time, if any. `Z` is parsed as an offset of zero.
+    pub offset: Option<GmtOffset>,
+    /// The time zone annotation, if any.
+    pub time_zone: Option<TimeZoneAnnotation>,
+    /// The value of the `u-ca` annotation, if any.
+    pub calendar: Option<Value>,
+}
+
+impl IxdtfDateTime {
+    /// Returns the date and time in the ISO calendar, at midnight if the string has no time.
+    pub fn to_iso_date_time(&self) -> DateTime<Iso> {
+        let time = self.time.unwrap_or_else(|| {
+            Time::new(
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+            )
+        });
+        DateTime::new(self.date.clone(), time)
+    }
+
+    /// Returns the kind of calendar given by the `u-ca` annotation, or
+    /// [`AnyCalendarKind::Iso`] if there is none.
+    pub fn calendar_kind(&self) -> Result<AnyCalendarKind, TimeZoneError> {
+        match self.calendar {
+            Some(ref value) => Ok(AnyCalendarKind::from_bcp47(value)?),
+            None => Ok(AnyCalendarKind::Iso),
+        }
+    }
+
+    /// Converts the date and time to the calendar given by the `u-ca` annotation, or to the ISO
+    /// calendar if there is none.
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    /// <div class="stab unstable">
+    /// ⚠️ The bounds on this function may change over time, including in SemVer minor releases.
+    /// </div>
+    pub fn to_any_date_time_unstable<P>(
+        &self,
+        provider: &P,
+    ) -> Result<DateTime<AnyCalendar>, TimeZoneError>
+    where
+        P: DataProvider<JapaneseErasV1Marker> + DataProvider<JapaneseExtendedErasV1Marker> + ?Sized,
+    {
+        let calendar = AnyCalendar::try_new_unstable(provider, self.calendar_kind()?)?;
+        Ok(self.to_iso_date_time().to_calendar(calendar))
+    }
+
+    /// Converts the date and time using an [`AnyProvider`](icu_provider::AnyProvider).
+    ///
+    /// See also: [`Self::to_any_date_time_unstable`]
+    pub fn to_any_date_time_with_any_provider<P>(
+        &self,
+        provider: &P,
+    ) -> Result<DateTime<AnyCalendar>, TimeZoneError>
+    where
+        P: AnyProvider + ?Sized,
+    {
+        self.to_any_date_time_unstable(&provider.as_downcasting())
+    }
+
+    /// Converts the date and time using a [`BufferProvider`](icu_provider::BufferProvider). Enabled with the `"serde"` feature.
+    ///
+    /// See also: [`Self::to_any_date_time_unstable`]
+    #[cfg(feature = "serde")]
+    pub fn to_any_date_time_with_buffer_provider<P>(
+        &self,
+        provider: &P,
+    ) -> Result<DateTime<AnyCalendar>, TimeZoneError>
+    where
+        P: BufferProvider + ?Sized,
+    {
+        self.to_any_date_time_unstable(&provider.as_deserializing())
+    }
+}
+
+impl FromStr for IxdtfDateTime {
+    type Err = TimeZoneError;
+
+    /// Parse an [`IxdtfDateTime`] from a string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_timezone::IxdtfDateTime;
+    ///
+    /// let date: IxdtfDateTime = "2023-01-05".parse().expect("Failed to parse a date.");
+    /// let basic: IxdtfDateTime = "20230105T1000Z".parse().expect("Failed to parse a date.");
+    /// let extended: IxdtfDateTime = "+002023-01-05 10:00:00.5+01:00[+01:00]"
+    ///     .parse()
+    ///     .expect("Failed to parse a date.");
+    ///
+    /// assert!("2023-01-05T10:00[!x-private=1]".parse::<IxdtfDateTime>().is_err());
+    /// assert!("2023-02-30".parse::<IxdtfDateTime>().is_err());
+    /// ```
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut cursor = Cursor { input, position: 0 };
+
+        let date = cursor.parse_date()?;
+        let time = match cursor.peek() {
+            Some(b'T' | b't' | b' ') => {
+                cursor.position += 1;
+                Some(cursor.parse_time()?)
+            }
+            _ => None,
+        };
+        let offset = match (time, cursor.peek()) {
+            (Some(_), Some(b'Z' | b'z')) => {
+                cursor.position += 1;
+                Some(GmtOffset::default())
+            }
+            (Some(_), Some(b'+' | b'-')) => Some(cursor.parse_offset()?),
+            _ => None,
+        };
+
+        let mut result = Self {
+            date,
+            time,
+            offset,
+            time_zone: None,
+            calendar: None,
+        };
+        let mut first = true;
+        while cursor.eat(b'[') {
+            let critical = cursor.eat(b'!');
+            let annotation = cursor.take_until(b']')?;
+            match annotation.split_once('=') {
+                Some((key, value)) => {
+                    if key == "u-ca" {
+                        let value =
+                            Value::from_str(value).map_err(|_| TimeZoneError::InvalidIxdtf)?;
+                        match result.calendar {
+                            None => result.calendar = Some(value),
+                            Some(ref calendar) if critical && *calendar != value => {
+                                return Err(TimeZoneError::InvalidIxdtf)
+                            }
+                            Some(_) => (),
+                        }
+                    } else if critical || !is_annotation_key(key) {
+                        return Err(TimeZoneError::InvalidIxdtf);
+                    }
+                }
+                None if first => {
+                    result.time_zone = Some(if annotation.starts_with(['+', '-']) {
+                        TimeZoneAnnotation::Offset(annotation.parse()?)
+                    } else if is_iana_name(annotation) {
+                        TimeZoneAnnotation::Iana(annotation.into())
+                    } else {
+                        return Err(TimeZoneError::InvalidIxdtf);
+                    });
+                }
+                None => return Err(TimeZoneError::InvalidIxdtf),
+            }
+            first = false;
+        }
+
+        if cursor.position == input.len() {
+            Ok(result)
+        } else {
+            Err(TimeZoneError::InvalidIxdtf)
+        }
+    }
+}
+
+/// Whether a string is an annotation key: a lowercase letter or `_`, followed by lowercase
+/// letters, digits, `_` and `-`.
+fn is_annotation_key(key: &str) -> bool {
+    let mut bytes = key.bytes();
+    matches!(bytes.next(), Some(b'a'..=b'z' | b'_'))
+        && bytes.all(|b| matches!(b, b'a'..=b'z' | b'0'..=b'9' | b'_' | b'-'))
+}
+
+/// Whether a string is a syntactically valid IANA time zone name, such as `America/New_York`.
+fn is_iana_name(name: &str) -> bool {
+    name.split('/').all(|part| {
+        !part.is_empty()
+            && part != "."
+            && part != ".."
+            && part
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'.' | b'_' | b'-' | b'+'))
+    })
+}
+
+struct Cursor<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn peek(&self) -> Option<u8> {
+        self.input.as_bytes().get(self.position).copied()
+    }
+
+    fn eat(&mut self, byte: u8) -> bool {
+        let matches = self.peek() == Some(byte);
+        if matches {
+            self.position += 1;
+        }
+        matches
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), TimeZoneError> {
+        if self.eat(byte) {
+            Ok(())
+        } else {
+            Err(TimeZoneError::InvalidIxdtf)
+        }
+    }
+
+    fn peek_digit(&self) -> bool {
+        matches!(self.peek(), Some(b'0'..=b'9'))
+    }
+
+    /// Parses exactly `count` ASCII digits.
+    fn digits(&mut self, count: usize) -> Result<u32, TimeZoneError> {
+        let mut value = 0;
+        for _ in 0..count {
+            match self.peek() {
+                Some(b @ b'0'..=b'9') => value = value * 10 + (b - b'0') as u32,
+                _ => return Err(TimeZoneError::InvalidIxdtf),
+            }
+            self.position += 1;
+        }
+        Ok(value)
+    }
+
+    /// Returns the text up to the next `end` byte and moves past it.
+    fn take_until(&mut self, end: u8) -> Result<&'a str, TimeZoneError> {
+        let rest = self
+            .input
+            .get(self.position..)
+            .ok_or(TimeZoneError::InvalidIxdtf)?;
+        let length = rest
+            .bytes()
+            .position(|b| b == end)
+            .ok_or(TimeZoneError::InvalidIxdtf)?;
+        self.position += length + 1;
+        rest.get(..length).ok_or(TimeZoneError::InvalidIxdtf)
+    }
+
+    /// Parses `YYYY-MM-DD`, `YYYYMMDD`, or either with a signed six digit year.
+    fn parse_date(&mut self) -> Result<Date<Iso>, TimeZoneError> {
+        let year = match self.peek() {
+            Some(sign @ (b'+' | b'-')) => {
+                self.position += 1;
+                let year = self.digits(6)? as i32;
+                if sign == b'-' {
+                    // -000000 is not a valid year.
+                    if year == 0 {
+                        return Err(TimeZoneError::InvalidIxdtf);
+                    }
+                    -year
+                } else {
+                    year
+                }
+            }
+            _ => self.digits(4)? as i32,
+        };
+        let extended = self.eat(b'-');
+        let month = self.digits(2)? as u8;
+        if extended {
+            self.expect(b'-')?;
+        }
+        let day = self.digits(2)? as u8;
+        Ok(Date::new_iso_date(year, month, day)?)
+    }
+
+    /// Parses `hh[:mm[:ss[.fffffffff]]]` or `hh[mm[ss[.fffffffff]]]`.
+    ///
+    /// A leap second (`60`) is constrained to the previous second, and later seconds are
+    /// rejected.
+    fn parse_time(&mut self) -> Result<Time, TimeZoneError> {
+        let hour = self.digits(2)? as u8;
+        if hour > 23 {
+            return Err(TimeZoneError::InvalidIxdtf);
+        }
+        let extended = self.eat(b':');
+        let (mut minute, mut second, mut nanosecond) = (0, 0, 0);
+        if extended || self.peek_digit() {
+            minute = self.digits(2)? as u8;
+            if (extended && self.eat(b':')) || (!extended && self.peek_digit()) {
+                second = match self.digits(2)? as u8 {
+                    60 => 59,
+                    second if second > 60 => return Err(TimeZoneError::InvalidIxdtf),
+                    second => second,
+                };
+                if self.eat(b'.') || self.eat(b',') {
+                    let mut digits = 0;
+                    while self.peek_digit() && digits < 9 {
+                        nanosecond = nanosecond * 10 + self.digits(1)?;
+                        digits += 1;
+                    }
+                    if digits == 0 || self.peek_digit() {
+                        return Err(TimeZoneError::InvalidIxdtf);
+                    }
+                    nanosecond *= 10u32.pow(9 - digits);
+                }
+            }
+        }
+        Ok(Time::try_new(hour, minute, second, nanosecond)?)
+    }
+
+    /// Parses `±hh[:mm[:ss]]` or `±hh[mm[ss]]`.
+    fn parse_offset(&mut self) -> Result<GmtOffset, TimeZoneError> {
+        let sign = if self.eat(b'-') {
+            -1
+        } else {
+            self.expect(b'+')?;
+            1
+        };
+        let hours = self.digits(2)? as i32;
+        let extended = self.eat(b':');
+        let (mut minutes, mut seconds) = (0, 0);
+        if extended || self.peek_digit() {
+            minutes = self.digits(2)? as i32;
+            if (extended && self.eat(b':')) || (!extended && self.peek_digit()) {
+                seconds = self.digits(2)? as i32;
+            }
+        }
+        if hours > 23 || minutes > 59 || seconds > 59 {
+            return Err(TimeZoneError::InvalidOffset);
+        }
+        GmtOffset::try_new(sign * (hours * 60 * 60 + minutes * 60 + seconds))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid() {
+        let parsed: IxdtfDateTime = "2023-01-05T10:00:00.123-05:00[America/New_York][u-ca=hebrew]"
+            .parse()
+            .unwrap();
+        assert_eq!(parsed.date, Date::new_iso_date(2023, 1, 5).unwrap());
+        assert_eq!(
+            parsed.time,
+            Some(Time::try_new(10, 0, 0, 123_000_000).unwrap())
+        );
+        assert_eq!(
+            parsed.offset,
+            Some(GmtOffset::try_new(-5 * 60 * 60).unwrap())
+        );
+        assert_eq!(
+            parsed.time_zone,
+            Some(TimeZoneAnnotation::Iana("America/New_York".into()))
+        );
+        assert_eq!(parsed.calendar, Some("hebrew".parse().unwrap()));
+
+        for (input, date, time, offset) in [
+            ("2023-01-05", (2023, 1, 5), None, None),
+            ("20230105", (2023, 1, 5), None, None),
+            ("2023-01-05t10", (2023, 1, 5), Some((10, 0, 0, 0)), None),
+            (
+                "2023-01-05 10:30Z",
+                (2023, 1, 5),
+                Some((10, 30, 0, 0)),
+                Some(0),
+            ),
+            (
+                "20230105T103015,5+0530",
+                (2023, 1, 5),
+                Some((10, 30, 15, 500_000_000)),
+                Some(19800),
+            ),
+            (
+                "1990-12-31T23:59:60Z",
+                (1990, 12, 31),
+                Some((23, 59, 59, 0)),
+                Some(0),
+            ),
+            (
+                "-000001-01-01T00:00:00.000000001-12",
+                (-1, 1, 1),
+                Some((0, 0, 0, 1)),
+                Some(-43200),
+            ),
+            (
+                "+010000-06-15T12:00+01:00:30",
+                (10000, 6, 15),
+                Some((12, 0, 0, 0)),
+                Some(3630),
+            ),
+        ] {
+            let parsed: IxdtfDateTime = input.parse().unwrap();
+            assert_eq!(
+                parsed.date,
+                Date::new_iso_date(date.0, date.1, date.2).unwrap(),
+                "{}",
+                input
+            );
+            assert_eq!(
+                parsed.time,
+                time.map(|t| Time::try_new(t.0, t.1, t.2, t.3).unwrap()),
+                "{}",
+                input
+            );
+            assert_eq!(
+                parsed.offset,
+                offset.map(|o| GmtOffset::try_new(o).unwrap()),
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_annotations() {
+        let parsed: IxdtfDateTime =
+            "2023-01-05T10:00Z[-03:30][foo=bar][u-ca=japanese][u-ca=iso8601]"
+                .parse()
+                .unwrap();
+        assert_eq!(
+            parsed.time_zone,
+            Some(TimeZoneAnnotation::Offset(
+                GmtOffset::try_new(-(3 * 60 + 30) * 60).unwrap()
+            ))
+        );
+        assert_eq!(parsed.calendar, Some("japanese".parse().unwrap()));
+        assert_eq!(parsed.calendar_kind(), Ok(AnyCalendarKind::Japanese));
+
+        let parsed: IxdtfDateTime = "2023-01-05[!Etc/GMT+5]".parse().unwrap();
+        assert_eq!(
+            parsed.time_zone,
+            Some(TimeZoneAnnotation::Iana("Etc/GMT+5".into()))
+        );
+        assert_eq!(parsed.calendar_kind(), Ok(AnyCalendarKind::Iso));
+    }
+
+    #[test]
+    fn test_invalid() {
+        for input in [
+            "",
+            "2023",
+            "2023-01",
+            "2023-0105",
+            "202301-05",
+            "2023-13-01",
+            "2023-02-29",
+            "-000000-01-01",
+            "2023-01-05T",
+            "2023-01-05T1",
+            "2023-01-05T24:00",
+            "2023-01-05T10:00:61",
+            "2023-01-05T10:00:99Z",
+            "2023-01-05T10:00:00.",
+            "2023-01-05T10:00:00.1234567891",
+            "2023-01-05T10:00+24:00",
+            "2023-01-05Z",
+            "2023-01-05T10:00Z[",
+            "2023-01-05T10:00Z[]",
+            "2023-01-05T10:00Z[America//New_York]",
+            "2023-01-05T10:00Z[u-ca=japanese][UTC]",
+            "2023-01-05T10:00Z[Foo=bar]",
+            "2023-01-05T10:00Z[!foo=bar]",
+            "2023-01-05T10:00Z[u-ca=japanese][!u-ca=iso8601]",
+            "2023-01-05T10:00Z extra",
+        ] {
+            assert!(input.parse::<IxdtfDateTime>().is_err(), "{}", input);
+        }
+
+        let parsed: IxdtfDateTime = "2023-01-05[u-ca=unknown]".parse().unwrap();
+        assert!(parsed.calendar_kind().is_err());
+    }
+}
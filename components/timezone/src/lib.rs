@@ -21,12 +21,14 @@
 extern crate alloc;
 
 mod error;
+mod ixdtf;
 mod metazone;
 pub mod provider;
 mod time_zone;
 mod types;
 
 pub use error::TimeZoneError;
+pub use ixdtf::{IxdtfDateTime, TimeZoneAnnotation};
 pub use metazone::MetaZoneCalculator;
 pub use time_zone::CustomTimeZone;
 pub use types::{GmtOffset, ZoneVariant};
@@ -53,7 +53,7 @@ pub mod ffi {
             let provider = provider.0.as_deserializing();
             let locale = locale.to_datalocale();
 
-            AnyCalendar::try_new_for_locale_unstable(&provider, &locale)
+            AnyCalendar::try_new_for_locale_with_preferences_unstable(&provider, &locale)
                 .map(|df| Box::new(ICU4XCalendar(Arc::new(df))))
                 .map_err(Into::into)
                 .into()
@@ -7,6 +7,7 @@ use icu_provider::{DataKey, KeyedDataMarker};
 use icu_calendar::provider::*;
 use icu_collator::provider::*;
 use icu_datetime::provider::calendar::*;
+use icu_datetime::provider::time_data::*;
 use icu_datetime::provider::time_zones::*;
 use icu_datetime::provider::week_data::*;
 use icu_decimal::provider::*;
@@ -72,6 +73,7 @@ registry!(
     BlankV1Marker,
     BuddhistDateLengthsV1Marker,
     BuddhistDateSymbolsV1Marker,
+    CalendarPreferencesV1Marker,
     CanonicalCombiningClassV1Marker,
     CanonicalCompositionsV1Marker,
     CanonicalDecompositionDataV1Marker,
@@ -86,6 +88,8 @@ registry!(
     ChangesWhenNfkcCasefoldedV1Marker,
     ChangesWhenTitlecasedV1Marker,
     ChangesWhenUppercasedV1Marker,
+    ChineseDateLengthsV1Marker,
+    ChineseDateSymbolsV1Marker,
     CollationDataV1Marker,
     CollationDiacriticsV1Marker,
     CollationJamoV1Marker,
@@ -96,6 +100,8 @@ registry!(
     CompatibilityDecompositionTablesV1Marker,
     CopticDateLengthsV1Marker,
     CopticDateSymbolsV1Marker,
+    DangiDateLengthsV1Marker,
+    DangiDateSymbolsV1Marker,
     DashV1Marker,
     DecimalSymbolsV1Marker,
     DefaultIgnorableCodePointV1Marker,
@@ -121,6 +127,8 @@ registry!(
     GraphV1Marker,
     GregorianDateLengthsV1Marker,
     GregorianDateSymbolsV1Marker,
+    HebrewDateLengthsV1Marker,
+    HebrewDateSymbolsV1Marker,
     HexDigitV1Marker,
     HyphenV1Marker,
     IdContinueV1Marker,
@@ -130,6 +138,8 @@ registry!(
     IdsTrinaryOperatorV1Marker,
     IndianDateLengthsV1Marker,
     IndianDateSymbolsV1Marker,
+    IslamicDateLengthsV1Marker,
+    IslamicDateSymbolsV1Marker,
     JapaneseDateLengthsV1Marker,
     JapaneseDateSymbolsV1Marker,
     JapaneseErasV1Marker,
@@ -159,11 +169,15 @@ registry!(
     OrListV1Marker,
     PatternSyntaxV1Marker,
     PatternWhiteSpaceV1Marker,
+    PersianDateLengthsV1Marker,
+    PersianDateSymbolsV1Marker,
     PrependedConcatenationMarkV1Marker,
     PrintV1Marker,
     QuotationMarkV1Marker,
     RadicalV1Marker,
     RegionalIndicatorV1Marker,
+    RocDateLengthsV1Marker,
+    RocDateSymbolsV1Marker,
     ScriptV1Marker,
     ScriptWithExtensionsPropertyV1Marker,
     SegmentStarterV1Marker,
@@ -171,15 +185,18 @@ registry!(
     SentenceTerminalV1Marker,
     SoftDottedV1Marker,
     TerminalPunctuationV1Marker,
+    TimeDataV1Marker,
     TimeLengthsV1Marker,
     TimeSymbolsV1Marker,
     TimeZoneFormatsV1Marker,
+    TimeZoneIanaIdsV1Marker,
     UnifiedIdeographV1Marker,
     UnitListV1Marker,
     UppercaseV1Marker,
     Uts46DecompositionSupplementV1Marker,
     VariationSelectorV1Marker,
     WeekDataV1Marker,
+    WeekendDataV1Marker,
     WhiteSpaceV1Marker,
     WordBreakV1Marker,
     XdigitV1Marker,
@@ -188,6 +205,7 @@ registry!(
     #[cfg(feature = "experimental")]
     {
         CaseMappingV1Marker,
+        DateAppendItemsV1Marker,
         DateSkeletonPatternsV1Marker,
         GraphemeClusterBreakDataV1Marker,
         LineBreakDataV1Marker,
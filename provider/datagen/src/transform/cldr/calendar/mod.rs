@@ -4,3 +4,5 @@
 
 /// Data for calendar arithmetic
 pub mod japanese;
+/// Data for default calendar selection
+pub mod preferences;
@@ -0,0 +1,121 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::transform::cldr::cldr_serde::{
+    self,
+    week_data::{Territory, DEFAULT_TERRITORY},
+};
+use icu_calendar::provider::*;
+use icu_locid::LanguageIdentifier;
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+use tinystr::TinyStr16;
+
+impl IterableDataProvider<CalendarPreferencesV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        let preferences: &cldr_serde::calendar_preferences::Resource =
+            self.source
+                .cldr()?
+                .core()
+                .read_and_parse("supplemental/calendarPreferenceData.json")?;
+        Ok(preferences
+            .supplemental
+            .calendar_preference_data
+            .keys()
+            .filter_map(|t| match t {
+                &DEFAULT_TERRITORY => Some(None),
+                Territory::Region(r) => Some(Some(*r)),
+                _ => None,
+            })
+            .map(LanguageIdentifier::from)
+            .map(DataLocale::from)
+            .collect())
+    }
+}
+
+impl DataProvider<CalendarPreferencesV1Marker> for crate::DatagenProvider {
+    fn load(
+        &self,
+        req: DataRequest,
+    ) -> Result<DataResponse<CalendarPreferencesV1Marker>, DataError> {
+        let territory = req
+            .locale
+            .region()
+            .map(Territory::Region)
+            .unwrap_or_else(|| DEFAULT_TERRITORY.clone());
+
+        let preferences: &cldr_serde::calendar_preferences::Resource =
+            self.source
+                .cldr()?
+                .core()
+                .read_and_parse("supplemental/calendarPreferenceData.json")?;
+        let preferences = &preferences.supplemental.calendar_preference_data;
+
+        let calendars = preferences
+            .get(&territory)
+            .or_else(|| preferences.get(&DEFAULT_TERRITORY))
+            .ok_or(DataError::custom(
+                "Missing default entry in calendarPreferenceData.json",
+            ))?
+            .split_ascii_whitespace()
+            // CLDR uses its own names for some calendars, the data uses BCP-47 identifiers
+            .map(|calendar| match calendar {
+                "gregorian" => "gregory",
+                "ethiopic-amete-alem" => "ethioaa",
+                calendar => calendar,
+            })
+            .map(|calendar| {
+                TinyStr16::from_str(calendar).map_err(|_| {
+                    DataError::custom("Invalid calendar in calendarPreferenceData.json")
+                        .with_display_context(calendar)
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(CalendarPreferencesV1 { calendars })),
+        })
+    }
+}
+
+#[test]
+fn basic_cldr_calendar_preferences() {
+    use icu_calendar::{AnyCalendar, AnyCalendarKind};
+    use icu_locid::locale;
+    use icu_provider_adapters::fallback::LocaleFallbackProvider;
+
+    let provider = crate::DatagenProvider::for_test();
+
+    let preferences: DataPayload<CalendarPreferencesV1Marker> = provider
+        .load(Default::default())
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    assert_eq!(
+        preferences.get().calendars.iter().collect::<Vec<_>>(),
+        vec![tinystr::tinystr!(16, "gregory")]
+    );
+
+    let provider = LocaleFallbackProvider::try_new_unstable(provider).unwrap();
+    let kind = |locale: icu_locid::Locale| {
+        AnyCalendar::try_new_for_locale_with_preferences_unstable(&provider, &locale.into())
+            .unwrap()
+            .kind()
+    };
+
+    assert_eq!(kind(locale!("en")), AnyCalendarKind::Gregorian);
+    assert_eq!(kind(locale!("th")), AnyCalendarKind::Buddhist);
+    assert_eq!(kind(locale!("fa-IR")), AnyCalendarKind::Persian);
+    assert_eq!(kind(locale!("ar-SA")), AnyCalendarKind::IslamicUmmAlQura);
+    // Japan prefers the Gregorian calendar
+    assert_eq!(kind(locale!("ja")), AnyCalendarKind::Gregorian);
+    // The region of the `-u-rg` keyword replaces the region of the locale
+    assert_eq!(
+        kind(locale!("en-US-u-rg-thzzzz")),
+        AnyCalendarKind::Buddhist
+    );
+    // The `-u-ca` keyword takes precedence
+    assert_eq!(kind(locale!("th-u-ca-gregory")), AnyCalendarKind::Gregorian);
+}
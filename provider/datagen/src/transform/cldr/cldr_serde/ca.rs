@@ -66,6 +66,20 @@ symbols!(
     }
 );
 
+symbols!(
+    quarters,
+    pub struct Symbols {
+        #[serde(rename = "1")]
+        pub q1: String,
+        #[serde(rename = "2")]
+        pub q2: String,
+        #[serde(rename = "3")]
+        pub q3: String,
+        #[serde(rename = "4")]
+        pub q4: String,
+    }
+);
+
 // The day period symbols are Cow<'static, str> instead of String because the Option
 // needs to be retained when converting them into Cow for the data provider.
 symbols!(
@@ -100,7 +114,7 @@ impl LengthPattern {
     }
 }
 
-#[derive(PartialEq, Debug, Deserialize, Clone)]
+#[derive(PartialEq, Debug, Deserialize, Clone, Default)]
 pub struct Eras {
     #[serde(rename = "eraNames")]
     pub names: HashMap<String, String>,
@@ -110,6 +124,46 @@ pub struct Eras {
     pub narrow: HashMap<String, String>,
 }
 
+/// The patterns that derive the names of leap months from the names of the regular months in
+/// lunisolar calendars like the Chinese calendar.
+#[derive(PartialEq, Debug, Deserialize, Clone)]
+pub struct MonthPatterns {
+    pub format: MonthPatternWidths,
+    #[serde(rename = "stand-alone")]
+    pub stand_alone: Option<MonthPatternWidths>,
+}
+
+#[derive(PartialEq, Debug, Deserialize, Clone)]
+pub struct MonthPatternWidths {
+    pub abbreviated: Option<MonthPattern>,
+    pub narrow: Option<MonthPattern>,
+    pub wide: Option<MonthPattern>,
+}
+
+#[derive(PartialEq, Debug, Deserialize, Clone)]
+pub struct MonthPattern {
+    pub leap: Option<String>,
+}
+
+/// The names of the cycles of calendars like the Chinese calendar, of which only the names of the
+/// years of the sexagenary cycle are used.
+#[derive(PartialEq, Debug, Deserialize, Clone)]
+pub struct CyclicNameSets {
+    pub years: Option<CyclicNameSet>,
+}
+
+#[derive(PartialEq, Debug, Deserialize, Clone)]
+pub struct CyclicNameSet {
+    pub format: CyclicNameWidths,
+}
+
+#[derive(PartialEq, Debug, Deserialize, Clone)]
+pub struct CyclicNameWidths {
+    pub abbreviated: HashMap<String, String>,
+    pub narrow: HashMap<String, String>,
+    pub wide: HashMap<String, String>,
+}
+
 #[derive(PartialEq, Debug, Deserialize, Clone)]
 pub struct LengthPatterns {
     pub full: LengthPattern,
@@ -126,11 +180,39 @@ pub struct DateTimeFormats {
     pub short: LengthPattern,
     #[serde(rename = "availableFormats")]
     pub available_formats: AvailableFormats,
+    #[serde(rename = "appendItems")]
+    pub append_items: AppendItems,
 }
 
 #[derive(PartialEq, Clone, Debug, Deserialize)]
 pub struct AvailableFormats(pub HashMap<String, String>);
 
+#[derive(PartialEq, Clone, Debug, Deserialize)]
+pub struct AppendItems {
+    #[serde(rename = "Era")]
+    pub era: String,
+    #[serde(rename = "Year")]
+    pub year: String,
+    #[serde(rename = "Quarter")]
+    pub quarter: String,
+    #[serde(rename = "Month")]
+    pub month: String,
+    #[serde(rename = "Week")]
+    pub week: String,
+    #[serde(rename = "Day")]
+    pub day: String,
+    #[serde(rename = "Day-Of-Week")]
+    pub day_of_week: String,
+    #[serde(rename = "Hour")]
+    pub hour: String,
+    #[serde(rename = "Minute")]
+    pub minute: String,
+    #[serde(rename = "Second")]
+    pub second: String,
+    #[serde(rename = "Timezone")]
+    pub timezone: String,
+}
+
 /// This struct represents a 1:1 mapping of the CLDR ca-gregorian.json data at the key
 /// "main.LANGID.dates.calendars.gregorian" where "LANGID" is the identifier.
 ///
@@ -139,8 +221,15 @@ pub struct AvailableFormats(pub HashMap<String, String>);
 #[derive(PartialEq, Debug, Deserialize, Clone)]
 pub struct Dates {
     pub months: months::Contexts,
+    #[serde(rename = "monthPatterns")]
+    pub month_patterns: Option<MonthPatterns>,
+    pub quarters: quarters::Contexts,
     pub days: days::Contexts,
+    // The Chinese and Dangi calendars have no eras
+    #[serde(default)]
     pub eras: Eras,
+    #[serde(rename = "cyclicNameSets")]
+    pub cyclic_name_sets: Option<CyclicNameSets>,
     #[serde(rename = "dayPeriods")]
     pub day_periods: day_periods::Contexts,
     #[serde(rename = "dateFormats")]
@@ -0,0 +1,24 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Serde structs representing CLDR JSON calendarPreferenceData.json files.
+//!
+//! Sample file:
+//! `<https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-core/supplemental/calendarPreferenceData.json>`
+
+use super::week_data::Territory;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Resource {
+    pub supplemental: Supplemental,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Supplemental {
+    /// A space-separated list of CLDR calendar names for each territory, most preferred first
+    #[serde(rename = "calendarPreferenceData")]
+    pub calendar_preference_data: BTreeMap<Territory, String>,
+}
@@ -9,6 +9,7 @@
 
 pub mod aliases;
 pub mod ca;
+pub mod calendar_preferences;
 pub mod japanese;
 pub mod likely_subtags;
 pub mod list_patterns;
@@ -16,5 +17,6 @@ pub mod numbering_systems;
 pub mod numbers;
 pub mod parent_locales;
 pub mod plurals;
+pub mod time_data;
 pub mod time_zones;
 pub mod week_data;
@@ -0,0 +1,34 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Serde structs representing CLDR JSON timeData.json files.
+//!
+//! Sample file:
+//! `<https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-core/supplemental/timeData.json>`
+
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct HourFormats {
+    /// A space-separated list of the hour formats in use, most common first, such as `"h hb H hB"`
+    #[serde(rename = "_allowed")]
+    pub allowed: String,
+    /// The preferred hour format, such as `"h"`
+    #[serde(rename = "_preferred")]
+    pub preferred: String,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Supplemental {
+    /// The hour formats of each territory, such as `"US"`, or of a language in a territory, such
+    /// as `"ca_ES"`
+    #[serde(rename = "timeData")]
+    pub time_data: BTreeMap<String, HourFormats>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub struct Resource {
+    pub supplemental: Supplemental,
+}
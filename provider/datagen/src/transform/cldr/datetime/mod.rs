@@ -16,6 +16,7 @@ use std::str::FromStr;
 mod patterns;
 mod skeletons;
 mod symbols;
+mod time_data;
 pub mod week_data;
 
 lazy_static! {
@@ -25,9 +26,16 @@ lazy_static! {
         (value!("buddhist"), "buddhist"),
         (value!("japanese"), "japanese"),
         (value!("japanext"), "japanese"),
+        (value!("chinese"), "chinese"),
         (value!("coptic"), "coptic"),
+        (value!("dangi"), "dangi"),
+        (value!("hebrew"), "hebrew"),
+        // The variants of the Islamic calendar share their data
+        (value!("islamic"), "islamic"),
         (value!("indian"), "indian"),
         (value!("ethiopic"), "ethiopic"),
+        (value!("persian"), "persian"),
+        (value!("roc"), "roc"),
     ]
     .into_iter()
     .collect();
@@ -298,6 +306,36 @@ impl_data_provider!(
     symbols::convert_dates,
     calendared = "coptic"
 );
+impl_data_provider!(
+    ChineseDateSymbolsV1Marker,
+    symbols::convert_dates,
+    calendared = "chinese"
+);
+impl_data_provider!(
+    DangiDateSymbolsV1Marker,
+    symbols::convert_dates,
+    calendared = "dangi"
+);
+impl_data_provider!(
+    HebrewDateSymbolsV1Marker,
+    symbols::convert_dates,
+    calendared = "hebrew"
+);
+impl_data_provider!(
+    PersianDateSymbolsV1Marker,
+    symbols::convert_dates,
+    calendared = "persian"
+);
+impl_data_provider!(
+    RocDateSymbolsV1Marker,
+    symbols::convert_dates,
+    calendared = "roc"
+);
+impl_data_provider!(
+    IslamicDateSymbolsV1Marker,
+    symbols::convert_dates,
+    calendared = "islamic"
+);
 impl_data_provider!(
     IndianDateSymbolsV1Marker,
     symbols::convert_dates,
@@ -319,6 +357,12 @@ impl_data_provider!(
     |dates, _| { DateSkeletonPatternsV1::from(dates) },
     calendared = "locale"
 );
+#[cfg(feature = "experimental")]
+impl_data_provider!(
+    DateAppendItemsV1Marker,
+    |dates, _| { DateAppendItemsV1::from(dates) },
+    calendared = "locale"
+);
 impl_data_provider!(
     GregorianDateLengthsV1Marker,
     |dates, _| DateLengthsV1::from(dates),
@@ -344,6 +388,36 @@ impl_data_provider!(
     |dates, _| DateLengthsV1::from(dates),
     calendared = "coptic"
 );
+impl_data_provider!(
+    ChineseDateLengthsV1Marker,
+    |dates, _| DateLengthsV1::from(dates),
+    calendared = "chinese"
+);
+impl_data_provider!(
+    DangiDateLengthsV1Marker,
+    |dates, _| DateLengthsV1::from(dates),
+    calendared = "dangi"
+);
+impl_data_provider!(
+    HebrewDateLengthsV1Marker,
+    |dates, _| DateLengthsV1::from(dates),
+    calendared = "hebrew"
+);
+impl_data_provider!(
+    PersianDateLengthsV1Marker,
+    |dates, _| DateLengthsV1::from(dates),
+    calendared = "persian"
+);
+impl_data_provider!(
+    RocDateLengthsV1Marker,
+    |dates, _| DateLengthsV1::from(dates),
+    calendared = "roc"
+);
+impl_data_provider!(
+    IslamicDateLengthsV1Marker,
+    |dates, _| DateLengthsV1::from(dates),
+    calendared = "islamic"
+);
 impl_data_provider!(
     IndianDateLengthsV1Marker,
     |dates, _| DateLengthsV1::from(dates),
@@ -448,6 +522,28 @@ mod test {
         );
     }
 
+    #[test]
+    #[cfg(feature = "experimental")]
+    fn test_datetime_append_items() {
+        use icu_datetime::provider::calendar::DateAppendItemsV1Marker;
+
+        let provider = crate::DatagenProvider::for_test();
+
+        let locale: Locale = "en-u-ca-gregory".parse().unwrap();
+        let append_items: DataPayload<DateAppendItemsV1Marker> = provider
+            .load(DataRequest {
+                locale: &locale.into(),
+                metadata: Default::default(),
+            })
+            .expect("Failed to load payload")
+            .take_payload()
+            .expect("Failed to retrieve payload");
+
+        // The `{2}` field display name is dropped, along with its separator.
+        assert_eq!("{0} ({1})", append_items.get().day.to_string());
+        assert_eq!("{0} {1}", append_items.get().time_zone.to_string());
+    }
+
     #[test]
     fn test_basic_symbols() {
         use icu_calendar::types::MonthCode;
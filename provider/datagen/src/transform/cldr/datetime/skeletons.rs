@@ -74,3 +74,31 @@ impl From<&cldr_serde::ca::Dates> for DateSkeletonPatternsV1<'_> {
         Self(skeletons)
     }
 }
+
+impl From<&cldr_serde::ca::Dates> for DateAppendItemsV1<'_> {
+    fn from(other: &cldr_serde::ca::Dates) -> Self {
+        let append_items = &other.datetime_formats.append_items;
+        // The `{2}` placeholder is the display name of the missing field, which lives in
+        // dateFields.json. That data isn't ingested, so drop the name and keep the field.
+        let parse = |pattern: &str| {
+            pattern
+                .replace("{2}: ", "")
+                .replace("{2}", "")
+                .parse()
+                .expect("Unable to parse an append item pattern")
+        };
+        Self {
+            era: parse(&append_items.era),
+            year: parse(&append_items.year),
+            quarter: parse(&append_items.quarter),
+            month: parse(&append_items.month),
+            week: parse(&append_items.week),
+            day: parse(&append_items.day),
+            day_of_week: parse(&append_items.day_of_week),
+            hour: parse(&append_items.hour),
+            minute: parse(&append_items.minute),
+            second: parse(&append_items.second),
+            time_zone: parse(&append_items.timezone),
+        }
+    }
+}
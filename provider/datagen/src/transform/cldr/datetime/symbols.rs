@@ -6,15 +6,26 @@ use crate::transform::cldr::cldr_serde;
 use icu_calendar::types::MonthCode;
 use icu_datetime::provider::calendar::*;
 use std::borrow::Cow;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
 use tinystr::{tinystr, TinyStr16, TinyStr4};
-use zerovec::ZeroMap;
+use zerovec::{VarZeroVec, ZeroMap};
 
 pub fn convert_dates(other: &cldr_serde::ca::Dates, calendar: &str) -> DateSymbolsV1<'static> {
+    let months = match other.month_patterns {
+        Some(ref patterns) => Cow::Owned(with_leap_months(&other.months, patterns)),
+        None => Cow::Borrowed(&other.months),
+    };
     DateSymbolsV1 {
-        months: other.months.get(&get_month_code_map(calendar)),
+        months: months.get(&get_month_code_map(calendar)),
+        quarters: other.quarters.get(&()),
         weekdays: other.days.get(&()),
         eras: convert_eras(&other.eras, calendar),
+        cyclic_years: other
+            .cyclic_name_sets
+            .as_ref()
+            .and_then(|sets| sets.years.as_ref())
+            .map(convert_cyclic_years)
+            .unwrap_or_default(),
     }
 }
 
@@ -24,6 +35,64 @@ pub fn convert_times(other: &cldr_serde::ca::Dates) -> TimeSymbolsV1<'static> {
     }
 }
 
+/// Adds the names of the leap months of lunisolar calendars like the Chinese calendar, which
+/// CLDR derives from the names of the regular months with `leap` month patterns such as
+/// `"{0}bis"`. The leap months are added with a `-leap` suffix.
+fn with_leap_months(
+    months: &cldr_serde::ca::months::Contexts,
+    patterns: &cldr_serde::ca::MonthPatterns,
+) -> cldr_serde::ca::months::Contexts {
+    fn leap(pattern: &Option<cldr_serde::ca::MonthPattern>) -> Option<&str> {
+        pattern.as_ref().and_then(|pattern| pattern.leap.as_deref())
+    }
+
+    fn add_leap_months(symbols: &mut cldr_serde::ca::months::Symbols, pattern: Option<&str>) {
+        let pattern = pattern.expect("Calendars with month patterns must have leap patterns");
+        let leap_months: Vec<(String, String)> = symbols
+            .0
+            .iter()
+            .map(|(k, v)| (format!("{k}-leap"), pattern.replace("{0}", v)))
+            .collect();
+        symbols.0.extend(leap_months);
+    }
+
+    let mut months = months.clone();
+
+    // Widths without a pattern use the wide pattern of their context
+    let format = &patterns.format;
+    let format_wide = leap(&format.wide);
+    add_leap_months(
+        &mut months.format.abbreviated,
+        leap(&format.abbreviated).or(format_wide),
+    );
+    add_leap_months(
+        &mut months.format.narrow,
+        leap(&format.narrow).or(format_wide),
+    );
+    if let Some(ref mut short) = months.format.short {
+        add_leap_months(short, leap(&format.abbreviated).or(format_wide));
+    }
+    add_leap_months(&mut months.format.wide, format_wide);
+
+    if let Some(ref mut stand_alone) = months.stand_alone {
+        let patterns = patterns.stand_alone.as_ref().unwrap_or(format);
+        let wide = leap(&patterns.wide).or(format_wide);
+        let abbreviated = leap(&patterns.abbreviated).or(wide);
+        for (symbols, pattern) in [
+            (&mut stand_alone.abbreviated, abbreviated),
+            (&mut stand_alone.narrow, leap(&patterns.narrow).or(wide)),
+            (&mut stand_alone.short, abbreviated),
+            (&mut stand_alone.wide, wide),
+        ] {
+            if let Some(symbols) = symbols {
+                add_leap_months(symbols, pattern);
+            }
+        }
+    }
+
+    months
+}
+
 fn convert_eras(eras: &cldr_serde::ca::Eras, calendar: &str) -> Eras<'static> {
     let map = get_era_code_map(calendar);
     let mut out_eras = Eras::default();
@@ -42,9 +111,30 @@ fn convert_eras(eras: &cldr_serde::ca::Eras, calendar: &str) -> Eras<'static> {
     out_eras
 }
 
+fn convert_cyclic_years(years: &cldr_serde::ca::CyclicNameSet) -> CyclicYears<'static> {
+    // CLDR numbers the years of the cycle from 1 to 60
+    fn in_cycle_order(names: &HashMap<String, String>) -> VarZeroVec<'static, str> {
+        let names: Vec<&str> = (1..=60)
+            .map(|index| {
+                names
+                    .get(&index.to_string())
+                    .expect("Cyclic year names must cover the whole cycle")
+                    .as_str()
+            })
+            .collect();
+        VarZeroVec::from(&names)
+    }
+
+    CyclicYears {
+        names: in_cycle_order(&years.format.wide),
+        abbr: in_cycle_order(&years.format.abbreviated),
+        narrow: in_cycle_order(&years.format.narrow),
+    }
+}
+
 fn get_month_code_map(calendar: &str) -> &'static [TinyStr4] {
-    // This will need to be more complicated to handle lunar calendars
-    // https://github.com/unicode-org/icu4x/issues/2066
+    // The leap months of the Chinese and Dangi calendars are added by `with_leap_months` and
+    // mapped to the leap variants of these codes
     static SOLAR_MONTH_CODES: &[TinyStr4] = &[
         tinystr!(4, "M01"),
         tinystr!(4, "M02"),
@@ -61,9 +151,30 @@ fn get_month_code_map(calendar: &str) -> &'static [TinyStr4] {
         tinystr!(4, "M13"),
     ];
 
+    // CLDR numbers the months of the Hebrew calendar as if every year had the leap month
+    // Adar I, at index 6. Adar II keeps the code of Adar, at index 7, and its alternate
+    // `7-yeartype-leap` name is used in leap years.
+    static HEBREW_MONTH_CODES: &[TinyStr4] = &[
+        tinystr!(4, "M01"),
+        tinystr!(4, "M02"),
+        tinystr!(4, "M03"),
+        tinystr!(4, "M04"),
+        tinystr!(4, "M05"),
+        tinystr!(4, "M05L"),
+        tinystr!(4, "M06"),
+        tinystr!(4, "M07"),
+        tinystr!(4, "M08"),
+        tinystr!(4, "M09"),
+        tinystr!(4, "M10"),
+        tinystr!(4, "M11"),
+        tinystr!(4, "M12"),
+    ];
+
     match calendar {
-        "gregory" | "buddhist" | "japanese" | "japanext" | "indian" => &SOLAR_MONTH_CODES[0..12],
+        "gregory" | "buddhist" | "japanese" | "japanext" | "indian" | "islamic" | "persian"
+        | "roc" | "chinese" | "dangi" => &SOLAR_MONTH_CODES[0..12],
         "coptic" | "ethiopic" => SOLAR_MONTH_CODES,
+        "hebrew" => HEBREW_MONTH_CODES,
         _ => panic!("Month map unknown for {}", calendar),
     }
 }
@@ -88,9 +199,27 @@ fn get_era_code_map(calendar: &str) -> BTreeMap<String, TinyStr16> {
         ]
         .into_iter()
         .collect(),
+        "hebrew" => vec![("0".to_string(), tinystr!(16, "am"))]
+            .into_iter()
+            .collect(),
+        "chinese" | "dangi" => BTreeMap::new(),
         "indian" => vec![("0".to_string(), tinystr!(16, "saka"))]
             .into_iter()
             .collect(),
+        "islamic" => vec![("0".to_string(), tinystr!(16, "ah"))]
+            .into_iter()
+            .collect(),
+        "persian" => vec![("0".to_string(), tinystr!(16, "ap"))]
+            .into_iter()
+            .collect(),
+        "roc" => vec![
+            // Before Minguo
+            ("0".to_string(), tinystr!(16, "roc-inverse")),
+            // Minguo
+            ("1".to_string(), tinystr!(16, "roc")),
+        ]
+        .into_iter()
+        .collect(),
         "ethiopic" => vec![
             ("0".to_string(), tinystr!(16, "incar")),
             ("1".to_string(), tinystr!(16, "pre-incar")),
@@ -223,7 +352,20 @@ impl cldr_serde::ca::months::Symbols {
             months::SymbolsV1::SolarTwelve(arr)
         } else {
             let mut map: ZeroMap<MonthCode, str> = ZeroMap::default();
+            let mut leap_year_map: ZeroMap<MonthCode, str> = ZeroMap::default();
             for (k, v) in self.0.iter() {
+                // Names of months in leap years, such as Adar II in the Hebrew calendar,
+                // are marked with a `-yeartype-leap` suffix and keep the code of the month,
+                // while leap months, such as the ones of the Chinese calendar, are marked
+                // with a `-leap` suffix and get a leap month code
+                let (k, is_leap_year_name, is_leap_month) =
+                    if let Some(k) = k.strip_suffix("-yeartype-leap") {
+                        (k, true, false)
+                    } else if let Some(k) = k.strip_suffix("-leap") {
+                        (k, false, true)
+                    } else {
+                        (k.as_str(), false, false)
+                    };
                 let index: usize = k
                     .parse()
                     .expect("CLDR month indices must parse as numbers!");
@@ -233,14 +375,34 @@ impl cldr_serde::ca::months::Symbols {
                 let code = ctx
                     .get(index - 1)
                     .expect("Found out of bounds month index for calendar");
+                let code = if is_leap_month {
+                    format!("{code}L")
+                        .parse()
+                        .expect("Month codes of leap months must fit in four bytes")
+                } else {
+                    *code
+                };
 
-                map.insert(&MonthCode(*code), v);
+                if is_leap_year_name {
+                    leap_year_map.insert(&MonthCode(code), v);
+                } else {
+                    map.insert(&MonthCode(code), v);
+                }
+            }
+            if leap_year_map.is_empty() {
+                months::SymbolsV1::Other(map)
+            } else {
+                months::SymbolsV1::LeapYearNames {
+                    names: map,
+                    leap_year_names: leap_year_map,
+                }
             }
-            months::SymbolsV1::Other(map)
         }
     }
 }
 
+symbols_from!([quarters, quarters], (), [q1, q2, q3, q4]);
+
 symbols_from!([days, weekdays], (), [sun, mon, tue, wed, thu, fri, sat]);
 
 symbols_from!(
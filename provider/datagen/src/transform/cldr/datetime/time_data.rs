@@ -0,0 +1,124 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::transform::cldr::cldr_serde;
+use icu_datetime::options::preferences::HourCycle;
+use icu_datetime::provider::time_data::*;
+use icu_locid::{subtags::Region, LanguageIdentifier};
+use icu_provider::datagen::IterableDataProvider;
+use icu_provider::prelude::*;
+use std::str::FromStr;
+
+/// The key of the world in timeData.json, which is used for regions without an entry.
+const DEFAULT_TERRITORY: &str = "001";
+
+/// Parses a key of timeData.json, which is either a region, such as `"US"`, or a language in a
+/// region, such as `"ca_ES"`.
+fn parse_key(key: &str) -> Result<LanguageIdentifier, DataError> {
+    if key == DEFAULT_TERRITORY {
+        Ok(LanguageIdentifier::UND)
+    } else if key.contains('_') {
+        LanguageIdentifier::from_str(key)
+            .map_err(|e| DataError::custom("timeData").with_display_context(&e))
+    } else {
+        Region::from_str(key)
+            .map(|region| LanguageIdentifier::from(Some(region)))
+            .map_err(|e| DataError::custom("timeData").with_display_context(&e))
+    }
+}
+
+/// Converts an hour format of timeData.json, such as `"h"` or `"hB"`, to its hour cycle.
+fn parse_hour_cycle(format: &str) -> Result<HourCycle, DataError> {
+    match format.chars().next() {
+        Some('K') => Ok(HourCycle::H11),
+        Some('h') => Ok(HourCycle::H12),
+        Some('H') => Ok(HourCycle::H23),
+        Some('k') => Ok(HourCycle::H24),
+        _ => {
+            Err(DataError::custom("Unknown hour format in timeData.json")
+                .with_display_context(format))
+        }
+    }
+}
+
+impl IterableDataProvider<TimeDataV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        let time_data: &cldr_serde::time_data::Resource = self
+            .source
+            .cldr()?
+            .core()
+            .read_and_parse("supplemental/timeData.json")?;
+        time_data
+            .supplemental
+            .time_data
+            .keys()
+            .map(|key| parse_key(key).map(DataLocale::from))
+            .collect()
+    }
+}
+
+impl DataProvider<TimeDataV1Marker> for crate::DatagenProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<TimeDataV1Marker>, DataError> {
+        let time_data: &cldr_serde::time_data::Resource = self
+            .source
+            .cldr()?
+            .core()
+            .read_and_parse("supplemental/timeData.json")?;
+        let time_data = &time_data.supplemental.time_data;
+
+        let key = match (req.locale.language().is_empty(), req.locale.region()) {
+            (_, None) => DEFAULT_TERRITORY.to_string(),
+            (true, Some(region)) => region.to_string(),
+            (false, Some(region)) => format!("{}_{}", req.locale.language(), region),
+        };
+        let hour_formats = time_data
+            .get(&key)
+            .ok_or(DataErrorKind::MissingLocale.into_error())?;
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(TimeDataV1 {
+                preferred_hour_cycle: parse_hour_cycle(&hour_formats.preferred)?,
+                allowed_hour_cycle: parse_hour_cycle(
+                    hour_formats
+                        .allowed
+                        .split(' ')
+                        .next()
+                        .unwrap_or(&hour_formats.preferred),
+                )?,
+            })),
+        })
+    }
+}
+
+#[test]
+fn basic_cldr_time_data() {
+    use icu_locid::langid;
+
+    let provider = crate::DatagenProvider::for_test();
+
+    for (langid, preferred, allowed) in [
+        (langid!("und"), HourCycle::H23, HourCycle::H23),
+        (langid!("und-US"), HourCycle::H12, HourCycle::H12),
+        (langid!("und-JP"), HourCycle::H23, HourCycle::H23),
+        (langid!("und-GB"), HourCycle::H23, HourCycle::H23),
+        (langid!("und-IR"), HourCycle::H23, HourCycle::H12),
+    ] {
+        let time_data: DataPayload<TimeDataV1Marker> = provider
+            .load(DataRequest {
+                locale: &langid.clone().into(),
+                metadata: Default::default(),
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap();
+        assert_eq!(
+            time_data.get().preferred_hour_cycle,
+            preferred,
+            "{}",
+            langid
+        );
+        assert_eq!(time_data.get().allowed_hour_cycle, allowed, "{}", langid);
+    }
+}
@@ -6,7 +6,7 @@ use crate::transform::cldr::cldr_serde::{
     self,
     week_data::{Territory, DEFAULT_TERRITORY},
 };
-use icu_calendar::week_of::CalendarInfo;
+use icu_calendar::week_of::{CalendarInfo, WeekendInfo};
 use icu_datetime::provider::week_data::*;
 use icu_locid::LanguageIdentifier;
 use icu_provider::datagen::IterableDataProvider;
@@ -77,6 +77,69 @@ impl DataProvider<WeekDataV1Marker> for crate::DatagenProvider {
     }
 }
 
+impl IterableDataProvider<WeekendDataV1Marker> for crate::DatagenProvider {
+    fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
+        let week_data: &cldr_serde::week_data::Resource = self
+            .source
+            .cldr()?
+            .core()
+            .read_and_parse("supplemental/weekData.json")?;
+        let week_data = &week_data.supplemental.week_data;
+        let regions: HashSet<DataLocale> = week_data
+            .weekend_start
+            .keys()
+            .chain(week_data.weekend_end.keys())
+            .filter_map(|t| match t {
+                &DEFAULT_TERRITORY => Some(None),
+                Territory::Region(r) => Some(Some(*r)),
+                _ => None,
+            })
+            .map(LanguageIdentifier::from)
+            .map(DataLocale::from)
+            .collect();
+        Ok(regions.into_iter().collect())
+    }
+}
+
+impl DataProvider<WeekendDataV1Marker> for crate::DatagenProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<WeekendDataV1Marker>, DataError> {
+        let territory = req
+            .locale
+            .region()
+            .map(Territory::Region)
+            .unwrap_or_else(|| DEFAULT_TERRITORY.clone());
+
+        let week_data: &cldr_serde::week_data::Resource = self
+            .source
+            .cldr()?
+            .core()
+            .read_and_parse("supplemental/weekData.json")?;
+        let week_data = &week_data.supplemental.week_data;
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(WeekendDataV1(WeekendInfo {
+                weekend_start: week_data
+                    .weekend_start
+                    .get(&territory)
+                    .or_else(|| week_data.weekend_start.get(&DEFAULT_TERRITORY))
+                    .ok_or(DataError::custom(
+                        "Missing default entry for weekendStart in weekData.json",
+                    ))?
+                    .into(),
+                weekend_end: week_data
+                    .weekend_end
+                    .get(&territory)
+                    .or_else(|| week_data.weekend_end.get(&DEFAULT_TERRITORY))
+                    .ok_or(DataError::custom(
+                        "Missing default entry for weekendEnd in weekData.json",
+                    ))?
+                    .into(),
+            }))),
+        })
+    }
+}
+
 #[test]
 fn basic_cldr_week_data() {
     use icu_calendar::types::IsoWeekday;
@@ -133,3 +196,43 @@ fn basic_cldr_week_data() {
         gg_week_data.get().0.first_weekday
     );
 }
+
+#[test]
+fn basic_cldr_weekend_data() {
+    use icu_calendar::types::IsoWeekday;
+    use icu_locid::langid;
+
+    let provider = crate::DatagenProvider::for_test();
+
+    let load = |langid: LanguageIdentifier| -> WeekendInfo {
+        let payload: DataPayload<WeekendDataV1Marker> = provider
+            .load(DataRequest {
+                locale: &DataLocale::from(langid),
+                metadata: Default::default(),
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap();
+        payload.get().0
+    };
+
+    let default_weekend = load(langid!("und"));
+    assert_eq!(IsoWeekday::Saturday, default_weekend.weekend_start);
+    assert_eq!(IsoWeekday::Sunday, default_weekend.weekend_end);
+
+    // No entries for FR, the default is used.
+    assert_eq!(default_weekend, load(langid!("und-FR")));
+
+    let sa_weekend = load(langid!("und-SA"));
+    assert_eq!(IsoWeekday::Friday, sa_weekend.weekend_start);
+    assert_eq!(IsoWeekday::Saturday, sa_weekend.weekend_end);
+
+    let af_weekend = load(langid!("und-AF"));
+    assert_eq!(IsoWeekday::Thursday, af_weekend.weekend_start);
+    assert_eq!(IsoWeekday::Friday, af_weekend.weekend_end);
+
+    // Only weekendStart is defined for IN, weekendEnd uses the default.
+    let in_weekend = load(langid!("und-IN"));
+    assert_eq!(IsoWeekday::Sunday, in_weekend.weekend_start);
+    assert_eq!(IsoWeekday::Sunday, in_weekend.weekend_end);
+}
@@ -14,6 +14,7 @@ use icu_calendar::DateTime;
 use icu_datetime::provider::time_zones::{
     ExemplarCitiesV1, MetaZoneGenericNamesLongV1, MetaZoneGenericNamesShortV1, MetaZoneId,
     MetaZoneSpecificNamesLongV1, MetaZoneSpecificNamesShortV1, TimeZoneBcp47Id, TimeZoneFormatsV1,
+    TimeZoneIanaIdsV1,
 };
 use icu_timezone::provider::MetaZonePeriodV1;
 use icu_timezone::ZoneVariant;
@@ -167,6 +168,25 @@ impl From<CldrTimeZonesData<'_>> for ExemplarCitiesV1<'static> {
     }
 }
 
+impl From<CldrTimeZonesData<'_>> for TimeZoneIanaIdsV1<'static> {
+    fn from(other: CldrTimeZonesData<'_>) -> Self {
+        Self(
+            other
+                .bcp47_tzids_resource
+                .iter()
+                .filter_map(|(bcp47_tzid, bcp47_tzid_data)| {
+                    // The first alias is the canonical IANA time zone id.
+                    bcp47_tzid_data
+                        .alias
+                        .as_ref()
+                        .and_then(|alias| alias.split(' ').next())
+                        .map(|iana_id| (*bcp47_tzid, iana_id))
+                })
+                .collect(),
+        )
+    }
+}
+
 impl From<CldrTimeZonesData<'_>> for MetaZonePeriodV1<'static> {
     fn from(other: CldrTimeZonesData<'_>) -> Self {
         let data = other.meta_zone_periods_resource;
@@ -79,8 +79,10 @@ macro_rules! impl_data_provider {
 
             impl IterableDataProvider<$marker> for crate::DatagenProvider {
                 fn supported_locales(&self) -> Result<Vec<DataLocale>, DataError> {
-                    if <$marker>::KEY == MetaZonePeriodV1Marker::KEY {
-                        // MetaZonePeriodV1 does not require localized time zone data
+                    if <$marker>::KEY == MetaZonePeriodV1Marker::KEY
+                        || <$marker>::KEY == TimeZoneIanaIdsV1Marker::KEY
+                    {
+                        // MetaZonePeriodV1 and TimeZoneIanaIdsV1 do not require localized time zone data
                         Ok(vec![Default::default()])
                     } else {
 
@@ -106,7 +108,8 @@ impl_data_provider!(
     MetaZoneGenericNamesShortV1Marker,
     MetaZoneSpecificNamesLongV1Marker,
     MetaZoneSpecificNamesShortV1Marker,
-    MetaZonePeriodV1Marker
+    MetaZonePeriodV1Marker,
+    TimeZoneIanaIdsV1Marker
 );
 
 #[cfg(test)]
@@ -271,5 +274,22 @@ mod tests {
                 .get_copied_2d(&TimeZoneBcp47Id(tinystr!(8, "gblon")), &962040)
                 .unwrap()
         );
+
+        let iana_ids: DataPayload<TimeZoneIanaIdsV1Marker> = provider
+            .load(DataRequest {
+                locale: &Default::default(),
+                metadata: Default::default(),
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap();
+        assert_eq!(
+            "America/Los_Angeles",
+            iana_ids
+                .get()
+                .0
+                .get(&TimeZoneBcp47Id(tinystr!(8, "uslax")))
+                .unwrap()
+        );
     }
 }
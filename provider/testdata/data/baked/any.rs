@@ -1,6 +1,8 @@
 // @generated
 impl AnyProvider for BakedDataProvider {
     fn load_any(&self, key: DataKey, req: DataRequest) -> Result<AnyResponse, DataError> {
+        const CALENDARPREFERENCESV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_calendar::provider::CalendarPreferencesV1Marker::KEY.get_hash();
         const JAPANESEERASV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_calendar::provider::JapaneseErasV1Marker::KEY.get_hash();
         const JAPANESEEXTENDEDERASV1MARKER: ::icu_provider::DataKeyHash =
@@ -23,10 +25,20 @@ impl AnyProvider for BakedDataProvider {
             ::icu_datetime::provider::calendar::BuddhistDateLengthsV1Marker::KEY.get_hash();
         const BUDDHISTDATESYMBOLSV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_datetime::provider::calendar::BuddhistDateSymbolsV1Marker::KEY.get_hash();
+        const CHINESEDATELENGTHSV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_datetime::provider::calendar::ChineseDateLengthsV1Marker::KEY.get_hash();
+        const CHINESEDATESYMBOLSV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_datetime::provider::calendar::ChineseDateSymbolsV1Marker::KEY.get_hash();
         const COPTICDATELENGTHSV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_datetime::provider::calendar::CopticDateLengthsV1Marker::KEY.get_hash();
         const COPTICDATESYMBOLSV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_datetime::provider::calendar::CopticDateSymbolsV1Marker::KEY.get_hash();
+        const DANGIDATELENGTHSV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_datetime::provider::calendar::DangiDateLengthsV1Marker::KEY.get_hash();
+        const DANGIDATESYMBOLSV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_datetime::provider::calendar::DangiDateSymbolsV1Marker::KEY.get_hash();
+        const DATEAPPENDITEMSV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_datetime::provider::calendar::DateAppendItemsV1Marker::KEY.get_hash();
         const DATESKELETONPATTERNSV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_datetime::provider::calendar::DateSkeletonPatternsV1Marker::KEY.get_hash();
         const ETHIOPIANDATELENGTHSV1MARKER: ::icu_provider::DataKeyHash =
@@ -37,10 +49,18 @@ impl AnyProvider for BakedDataProvider {
             ::icu_datetime::provider::calendar::GregorianDateLengthsV1Marker::KEY.get_hash();
         const GREGORIANDATESYMBOLSV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_datetime::provider::calendar::GregorianDateSymbolsV1Marker::KEY.get_hash();
+        const HEBREWDATELENGTHSV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_datetime::provider::calendar::HebrewDateLengthsV1Marker::KEY.get_hash();
+        const HEBREWDATESYMBOLSV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_datetime::provider::calendar::HebrewDateSymbolsV1Marker::KEY.get_hash();
         const INDIANDATELENGTHSV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_datetime::provider::calendar::IndianDateLengthsV1Marker::KEY.get_hash();
         const INDIANDATESYMBOLSV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_datetime::provider::calendar::IndianDateSymbolsV1Marker::KEY.get_hash();
+        const ISLAMICDATELENGTHSV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_datetime::provider::calendar::IslamicDateLengthsV1Marker::KEY.get_hash();
+        const ISLAMICDATESYMBOLSV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_datetime::provider::calendar::IslamicDateSymbolsV1Marker::KEY.get_hash();
         const JAPANESEDATELENGTHSV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_datetime::provider::calendar::JapaneseDateLengthsV1Marker::KEY.get_hash();
         const JAPANESEDATESYMBOLSV1MARKER: ::icu_provider::DataKeyHash =
@@ -49,10 +69,20 @@ impl AnyProvider for BakedDataProvider {
             ::icu_datetime::provider::calendar::JapaneseExtendedDateLengthsV1Marker::KEY.get_hash();
         const JAPANESEEXTENDEDDATESYMBOLSV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_datetime::provider::calendar::JapaneseExtendedDateSymbolsV1Marker::KEY.get_hash();
+        const PERSIANDATELENGTHSV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_datetime::provider::calendar::PersianDateLengthsV1Marker::KEY.get_hash();
+        const PERSIANDATESYMBOLSV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_datetime::provider::calendar::PersianDateSymbolsV1Marker::KEY.get_hash();
+        const ROCDATELENGTHSV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_datetime::provider::calendar::RocDateLengthsV1Marker::KEY.get_hash();
+        const ROCDATESYMBOLSV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_datetime::provider::calendar::RocDateSymbolsV1Marker::KEY.get_hash();
         const TIMELENGTHSV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_datetime::provider::calendar::TimeLengthsV1Marker::KEY.get_hash();
         const TIMESYMBOLSV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_datetime::provider::calendar::TimeSymbolsV1Marker::KEY.get_hash();
+        const TIMEDATAV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_datetime::provider::time_data::TimeDataV1Marker::KEY.get_hash();
         const EXEMPLARCITIESV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_datetime::provider::time_zones::ExemplarCitiesV1Marker::KEY.get_hash();
         const METAZONEGENERICNAMESLONGV1MARKER: ::icu_provider::DataKeyHash =
@@ -66,8 +96,12 @@ impl AnyProvider for BakedDataProvider {
                 .get_hash();
         const TIMEZONEFORMATSV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_datetime::provider::time_zones::TimeZoneFormatsV1Marker::KEY.get_hash();
+        const TIMEZONEIANAIDSV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_datetime::provider::time_zones::TimeZoneIanaIdsV1Marker::KEY.get_hash();
         const WEEKDATAV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_datetime::provider::week_data::WeekDataV1Marker::KEY.get_hash();
+        const WEEKENDDATAV1MARKER: ::icu_provider::DataKeyHash =
+            ::icu_datetime::provider::week_data::WeekendDataV1Marker::KEY.get_hash();
         const DECIMALSYMBOLSV1MARKER: ::icu_provider::DataKeyHash =
             ::icu_decimal::provider::DecimalSymbolsV1Marker::KEY.get_hash();
         const ANDLISTV1MARKER: ::icu_provider::DataKeyHash =
@@ -241,6 +275,9 @@ impl AnyProvider for BakedDataProvider {
             ::icu_timezone::provider::MetaZonePeriodV1Marker::KEY.get_hash();
         Ok(AnyResponse {
             payload: Some(match key.get_hash() {
+                CALENDARPREFERENCESV1MARKER => calendar::preferences_v1_r::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .map(AnyPayload::from_static_ref),
                 JAPANESEERASV1MARKER => calendar::japanese_v1::DATA
                     .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                     .map(AnyPayload::from_static_ref),
@@ -274,12 +311,27 @@ impl AnyProvider for BakedDataProvider {
                 BUDDHISTDATESYMBOLSV1MARKER => datetime::buddhist::datesymbols_v1::DATA
                     .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                     .map(AnyPayload::from_static_ref),
+                CHINESEDATELENGTHSV1MARKER => datetime::chinese::datelengths_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .map(AnyPayload::from_static_ref),
+                CHINESEDATESYMBOLSV1MARKER => datetime::chinese::datesymbols_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .map(AnyPayload::from_static_ref),
                 COPTICDATELENGTHSV1MARKER => datetime::coptic::datelengths_v1::DATA
                     .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                     .map(AnyPayload::from_static_ref),
                 COPTICDATESYMBOLSV1MARKER => datetime::coptic::datesymbols_v1::DATA
                     .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                     .map(AnyPayload::from_static_ref),
+                DANGIDATELENGTHSV1MARKER => datetime::dangi::datelengths_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .map(AnyPayload::from_static_ref),
+                DANGIDATESYMBOLSV1MARKER => datetime::dangi::datesymbols_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .map(AnyPayload::from_static_ref),
+                DATEAPPENDITEMSV1MARKER => datetime::append_items_v1_u_ca::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .map(AnyPayload::from_static_ref),
                 DATESKELETONPATTERNSV1MARKER => datetime::skeletons_v1_u_ca::DATA
                     .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                     .map(|&data| {
@@ -301,12 +353,24 @@ impl AnyProvider for BakedDataProvider {
                 GREGORIANDATESYMBOLSV1MARKER => datetime::gregory::datesymbols_v1::DATA
                     .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                     .map(AnyPayload::from_static_ref),
+                HEBREWDATELENGTHSV1MARKER => datetime::hebrew::datelengths_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .map(AnyPayload::from_static_ref),
+                HEBREWDATESYMBOLSV1MARKER => datetime::hebrew::datesymbols_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .map(AnyPayload::from_static_ref),
                 INDIANDATELENGTHSV1MARKER => datetime::indian::datelengths_v1::DATA
                     .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                     .map(AnyPayload::from_static_ref),
                 INDIANDATESYMBOLSV1MARKER => datetime::indian::datesymbols_v1::DATA
                     .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                     .map(AnyPayload::from_static_ref),
+                ISLAMICDATELENGTHSV1MARKER => datetime::islamic::datelengths_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .map(AnyPayload::from_static_ref),
+                ISLAMICDATESYMBOLSV1MARKER => datetime::islamic::datesymbols_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .map(AnyPayload::from_static_ref),
                 JAPANESEDATELENGTHSV1MARKER => datetime::japanese::datelengths_v1::DATA
                     .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                     .map(AnyPayload::from_static_ref),
@@ -319,12 +383,27 @@ impl AnyProvider for BakedDataProvider {
                 JAPANESEEXTENDEDDATESYMBOLSV1MARKER => datetime::japanext::datesymbols_v1::DATA
                     .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                     .map(AnyPayload::from_static_ref),
+                PERSIANDATELENGTHSV1MARKER => datetime::persian::datelengths_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .map(AnyPayload::from_static_ref),
+                PERSIANDATESYMBOLSV1MARKER => datetime::persian::datesymbols_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .map(AnyPayload::from_static_ref),
+                ROCDATELENGTHSV1MARKER => datetime::roc::datelengths_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .map(AnyPayload::from_static_ref),
+                ROCDATESYMBOLSV1MARKER => datetime::roc::datesymbols_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .map(AnyPayload::from_static_ref),
                 TIMELENGTHSV1MARKER => datetime::timelengths_v1::DATA
                     .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                     .map(AnyPayload::from_static_ref),
                 TIMESYMBOLSV1MARKER => datetime::timesymbols_v1::DATA
                     .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                     .map(AnyPayload::from_static_ref),
+                TIMEDATAV1MARKER => datetime::time_data_v1_r::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .map(AnyPayload::from_static_ref),
                 EXEMPLARCITIESV1MARKER => time_zone::exemplar_cities_v1::DATA
                     .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                     .map(AnyPayload::from_static_ref),
@@ -343,9 +422,15 @@ impl AnyProvider for BakedDataProvider {
                 TIMEZONEFORMATSV1MARKER => time_zone::formats_v1::DATA
                     .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                     .map(AnyPayload::from_static_ref),
+                TIMEZONEIANAIDSV1MARKER => time_zone::iana_ids_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .map(AnyPayload::from_static_ref),
                 WEEKDATAV1MARKER => datetime::week_data_v1_r::DATA
                     .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                     .map(AnyPayload::from_static_ref),
+                WEEKENDDATAV1MARKER => datetime::weekend_v1_r::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .map(AnyPayload::from_static_ref),
                 DECIMALSYMBOLSV1MARKER => decimal::symbols_v1_u_nu::DATA
                     .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
                     .map(AnyPayload::from_static_ref),
@@ -1,3 +1,4 @@
 // @generated
 pub mod japanese_v1;
 pub mod japanext_v1;
+pub mod preferences_v1_r;
@@ -0,0 +1,193 @@
+// @generated
+type DataStruct =
+    <::icu_calendar::provider::CalendarPreferencesV1Marker as ::icu_provider::DataMarker>::Yokeable;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[
+        ("und", UND),
+        ("und-AE", UND_AE_UND_BH_UND_KW_UND_QA),
+        ("und-AF", UND_AF_UND_IR),
+        ("und-BD", UND_BD_UND_DJ_UND_DZ_UND_EH_UND_ER_UND_IQ),
+        ("und-BH", UND_AE_UND_BH_UND_KW_UND_QA),
+        ("und-CN", UND_CN_UND_CX_UND_HK_UND_MO_UND_SG),
+        ("und-CX", UND_CN_UND_CX_UND_HK_UND_MO_UND_SG),
+        ("und-DJ", UND_BD_UND_DJ_UND_DZ_UND_EH_UND_ER_UND_IQ),
+        ("und-DZ", UND_BD_UND_DJ_UND_DZ_UND_EH_UND_ER_UND_IQ),
+        ("und-EG", UND_EG),
+        ("und-EH", UND_BD_UND_DJ_UND_DZ_UND_EH_UND_ER_UND_IQ),
+        ("und-ER", UND_BD_UND_DJ_UND_DZ_UND_EH_UND_ER_UND_IQ),
+        ("und-ET", UND_ET),
+        ("und-HK", UND_CN_UND_CX_UND_HK_UND_MO_UND_SG),
+        ("und-IL", UND_IL),
+        ("und-IN", UND_IN),
+        ("und-IQ", UND_BD_UND_DJ_UND_DZ_UND_EH_UND_ER_UND_IQ),
+        ("und-IR", UND_AF_UND_IR),
+        ("und-JO", UND_BD_UND_DJ_UND_DZ_UND_EH_UND_ER_UND_IQ),
+        ("und-JP", UND_JP),
+        ("und-KM", UND_BD_UND_DJ_UND_DZ_UND_EH_UND_ER_UND_IQ),
+        ("und-KR", UND_KR),
+        ("und-KW", UND_AE_UND_BH_UND_KW_UND_QA),
+        ("und-LB", UND_BD_UND_DJ_UND_DZ_UND_EH_UND_ER_UND_IQ),
+        ("und-LY", UND_BD_UND_DJ_UND_DZ_UND_EH_UND_ER_UND_IQ),
+        ("und-MA", UND_BD_UND_DJ_UND_DZ_UND_EH_UND_ER_UND_IQ),
+        ("und-MO", UND_CN_UND_CX_UND_HK_UND_MO_UND_SG),
+        ("und-MR", UND_BD_UND_DJ_UND_DZ_UND_EH_UND_ER_UND_IQ),
+        ("und-OM", UND_BD_UND_DJ_UND_DZ_UND_EH_UND_ER_UND_IQ),
+        ("und-PK", UND_BD_UND_DJ_UND_DZ_UND_EH_UND_ER_UND_IQ),
+        ("und-PS", UND_BD_UND_DJ_UND_DZ_UND_EH_UND_ER_UND_IQ),
+        ("und-QA", UND_AE_UND_BH_UND_KW_UND_QA),
+        ("und-SA", UND_SA),
+        ("und-SD", UND_BD_UND_DJ_UND_DZ_UND_EH_UND_ER_UND_IQ),
+        ("und-SG", UND_CN_UND_CX_UND_HK_UND_MO_UND_SG),
+        ("und-SY", UND_BD_UND_DJ_UND_DZ_UND_EH_UND_ER_UND_IQ),
+        ("und-TD", UND_BD_UND_DJ_UND_DZ_UND_EH_UND_ER_UND_IQ),
+        ("und-TH", UND_TH),
+        ("und-TN", UND_BD_UND_DJ_UND_DZ_UND_EH_UND_ER_UND_IQ),
+        ("und-TW", UND_TW),
+        ("und-YE", UND_BD_UND_DJ_UND_DZ_UND_EH_UND_ER_UND_IQ),
+    ]);
+static UND: &DataStruct = &::icu_calendar::provider::CalendarPreferencesV1 {
+    calendars: unsafe {
+        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+            103u8, 114u8, 101u8, 103u8, 111u8, 114u8, 121u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8,
+        ])
+    },
+};
+static UND_AE_UND_BH_UND_KW_UND_QA: &DataStruct =
+    &::icu_calendar::provider::CalendarPreferencesV1 {
+        calendars: unsafe {
+            ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                103u8, 114u8, 101u8, 103u8, 111u8, 114u8, 121u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 117u8, 109u8, 97u8,
+                108u8, 113u8, 117u8, 114u8, 97u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8,
+                0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 105u8, 115u8, 108u8, 97u8, 109u8,
+                105u8, 99u8, 45u8, 99u8, 105u8, 118u8, 105u8, 108u8, 0u8, 0u8, 0u8, 105u8, 115u8,
+                108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 116u8, 98u8, 108u8, 97u8, 0u8, 0u8, 0u8,
+                0u8,
+            ])
+        },
+    };
+static UND_AF_UND_IR: &DataStruct = &::icu_calendar::provider::CalendarPreferencesV1 {
+    calendars: unsafe {
+        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+            112u8, 101u8, 114u8, 115u8, 105u8, 97u8, 110u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 103u8, 114u8, 101u8, 103u8, 111u8, 114u8, 121u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 99u8, 105u8,
+            118u8, 105u8, 108u8, 0u8, 0u8, 0u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8,
+            45u8, 116u8, 98u8, 108u8, 97u8, 0u8, 0u8, 0u8, 0u8,
+        ])
+    },
+};
+static UND_BD_UND_DJ_UND_DZ_UND_EH_UND_ER_UND_IQ: &DataStruct =
+    &::icu_calendar::provider::CalendarPreferencesV1 {
+        calendars: unsafe {
+            ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                103u8, 114u8, 101u8, 103u8, 111u8, 114u8, 121u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 99u8,
+                105u8, 118u8, 105u8, 108u8, 0u8, 0u8, 0u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8,
+                99u8, 45u8, 116u8, 98u8, 108u8, 97u8, 0u8, 0u8, 0u8, 0u8,
+            ])
+        },
+    };
+static UND_CN_UND_CX_UND_HK_UND_MO_UND_SG: &DataStruct =
+    &::icu_calendar::provider::CalendarPreferencesV1 {
+        calendars: unsafe {
+            ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                103u8, 114u8, 101u8, 103u8, 111u8, 114u8, 121u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 99u8, 104u8, 105u8, 110u8, 101u8, 115u8, 101u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8,
+            ])
+        },
+    };
+static UND_EG: &DataStruct = &::icu_calendar::provider::CalendarPreferencesV1 {
+    calendars: unsafe {
+        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+            103u8, 114u8, 101u8, 103u8, 111u8, 114u8, 121u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 99u8, 111u8, 112u8, 116u8, 105u8, 99u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 99u8, 105u8,
+            118u8, 105u8, 108u8, 0u8, 0u8, 0u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8,
+            45u8, 116u8, 98u8, 108u8, 97u8, 0u8, 0u8, 0u8, 0u8,
+        ])
+    },
+};
+static UND_ET: &DataStruct = &::icu_calendar::provider::CalendarPreferencesV1 {
+    calendars: unsafe {
+        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+            103u8, 114u8, 101u8, 103u8, 111u8, 114u8, 121u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 101u8, 116u8, 104u8, 105u8, 111u8, 112u8, 105u8, 99u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8,
+        ])
+    },
+};
+static UND_IL: &DataStruct = &::icu_calendar::provider::CalendarPreferencesV1 {
+    calendars: unsafe {
+        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+            103u8, 114u8, 101u8, 103u8, 111u8, 114u8, 121u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 104u8, 101u8, 98u8, 114u8, 101u8, 119u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 99u8, 105u8,
+            118u8, 105u8, 108u8, 0u8, 0u8, 0u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8,
+            45u8, 116u8, 98u8, 108u8, 97u8, 0u8, 0u8, 0u8, 0u8,
+        ])
+    },
+};
+static UND_IN: &DataStruct = &::icu_calendar::provider::CalendarPreferencesV1 {
+    calendars: unsafe {
+        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+            103u8, 114u8, 101u8, 103u8, 111u8, 114u8, 121u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 105u8, 110u8, 100u8, 105u8, 97u8, 110u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8,
+        ])
+    },
+};
+static UND_JP: &DataStruct = &::icu_calendar::provider::CalendarPreferencesV1 {
+    calendars: unsafe {
+        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+            103u8, 114u8, 101u8, 103u8, 111u8, 114u8, 121u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 106u8, 97u8, 112u8, 97u8, 110u8, 101u8, 115u8, 101u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8,
+        ])
+    },
+};
+static UND_KR: &DataStruct = &::icu_calendar::provider::CalendarPreferencesV1 {
+    calendars: unsafe {
+        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+            103u8, 114u8, 101u8, 103u8, 111u8, 114u8, 121u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 100u8, 97u8, 110u8, 103u8, 105u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8,
+        ])
+    },
+};
+static UND_SA: &DataStruct = &::icu_calendar::provider::CalendarPreferencesV1 {
+    calendars: unsafe {
+        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+            105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8, 117u8, 109u8, 97u8, 108u8, 113u8,
+            117u8, 114u8, 97u8, 103u8, 114u8, 101u8, 103u8, 111u8, 114u8, 121u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 105u8, 115u8, 108u8, 97u8, 109u8, 105u8, 99u8, 45u8,
+            114u8, 103u8, 115u8, 97u8, 0u8, 0u8, 0u8, 0u8,
+        ])
+    },
+};
+static UND_TH: &DataStruct = &::icu_calendar::provider::CalendarPreferencesV1 {
+    calendars: unsafe {
+        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+            98u8, 117u8, 100u8, 100u8, 104u8, 105u8, 115u8, 116u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 103u8, 114u8, 101u8, 103u8, 111u8, 114u8, 121u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8,
+        ])
+    },
+};
+static UND_TW: &DataStruct = &::icu_calendar::provider::CalendarPreferencesV1 {
+    calendars: unsafe {
+        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+            103u8, 114u8, 101u8, 103u8, 111u8, 114u8, 121u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 114u8, 111u8, 99u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 99u8, 104u8, 105u8, 110u8, 101u8, 115u8, 101u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8,
+        ])
+    },
+};
@@ -71,6 +71,30 @@ static AR_AR_EG: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV
         },
         stand_alone: None,
     },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("الربع الأول"),
+                alloc::borrow::Cow::Borrowed("الربع الثاني"),
+                alloc::borrow::Cow::Borrowed("الربع الثالث"),
+                alloc::borrow::Cow::Borrowed("الربع الرابع"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("١"),
+                alloc::borrow::Cow::Borrowed("٢"),
+                alloc::borrow::Cow::Borrowed("٣"),
+                alloc::borrow::Cow::Borrowed("٤"),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("الربع الأول"),
+                alloc::borrow::Cow::Borrowed("الربع الثاني"),
+                alloc::borrow::Cow::Borrowed("الربع الثالث"),
+                alloc::borrow::Cow::Borrowed("الربع الرابع"),
+            ]),
+        },
+        stand_alone: None,
+    },
     weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
         format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
@@ -161,6 +185,11 @@ static AR_AR_EG: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV
             )
         },
     },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
 };
 static BN: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
     months: ::icu_datetime::provider::calendar::months::ContextsV1 {
@@ -233,6 +262,42 @@ static BN: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
             },
         ),
     },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("ত\u{9cd}রৈম\u{9be}সিক"),
+                alloc::borrow::Cow::Borrowed("দ\u{9cd}বিতীয\u{9bc} ত\u{9cd}রৈম\u{9be}সিক"),
+                alloc::borrow::Cow::Borrowed("ত\u{9c3}তীয\u{9bc} ত\u{9cd}রৈম\u{9be}সিক"),
+                alloc::borrow::Cow::Borrowed("চত\u{9c1}র\u{9cd}থ ত\u{9cd}রৈম\u{9be}সিক"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("১"),
+                alloc::borrow::Cow::Borrowed("২"),
+                alloc::borrow::Cow::Borrowed("৩"),
+                alloc::borrow::Cow::Borrowed("৪"),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("ত\u{9cd}রৈম\u{9be}সিক"),
+                alloc::borrow::Cow::Borrowed("দ\u{9cd}বিতীয\u{9bc} ত\u{9cd}রৈম\u{9be}সিক"),
+                alloc::borrow::Cow::Borrowed("ত\u{9c3}তীয\u{9bc} ত\u{9cd}রৈম\u{9be}সিক"),
+                alloc::borrow::Cow::Borrowed("চত\u{9c1}র\u{9cd}থ ত\u{9cd}রৈম\u{9be}সিক"),
+            ]),
+        },
+        stand_alone: Some(
+            ::icu_datetime::provider::calendar::quarters::StandAloneWidthsV1 {
+                abbreviated: Some(::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                    alloc::borrow::Cow::Borrowed("Q1"),
+                    alloc::borrow::Cow::Borrowed("Q2"),
+                    alloc::borrow::Cow::Borrowed("Q3"),
+                    alloc::borrow::Cow::Borrowed("Q4"),
+                ])),
+                narrow: None,
+                short: None,
+                wide: None,
+            },
+        ),
+    },
     weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
         format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
@@ -321,190 +386,13 @@ static BN: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
             )
         },
     },
-};
-static CCP: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
-    months: ::icu_datetime::provider::calendar::months::ContextsV1 {
-        format: ::icu_datetime::provider::calendar::months::FormatWidthsV1 {
-            abbreviated: ::icu_datetime::provider::calendar::months::SymbolsV1::SolarTwelve([
-                alloc::borrow::Cow::Borrowed("𑄎𑄚\u{1112a}"),
-                alloc::borrow::Cow::Borrowed("𑄜𑄬𑄛\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄟𑄢\u{11134}𑄌\u{11127}"),
-                alloc::borrow::Cow::Borrowed("𑄃𑄬𑄛\u{11133}𑄢\u{11128}𑄣\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄟𑄬"),
-                alloc::borrow::Cow::Borrowed("𑄎\u{1112a}𑄚\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄎\u{1112a}𑄣\u{1112d}"),
-                alloc::borrow::Cow::Borrowed("𑄃𑄉\u{11127}𑄌\u{11134}𑄑\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄥𑄬𑄛\u{11134}𑄑𑄬𑄟\u{11134}𑄝\u{11127}𑄢\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄃\u{11127}𑄇\u{11134}𑄑\u{1112e}𑄝\u{11127}𑄢\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄚\u{11127}𑄞𑄬𑄟\u{11134}𑄝\u{11127}𑄢\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄓\u{11128}𑄥𑄬𑄟\u{11134}𑄝𑄢\u{11134}"),
-            ]),
-            narrow: ::icu_datetime::provider::calendar::months::SymbolsV1::SolarTwelve([
-                alloc::borrow::Cow::Borrowed("𑄎"),
-                alloc::borrow::Cow::Borrowed("𑄜𑄬"),
-                alloc::borrow::Cow::Borrowed("𑄟"),
-                alloc::borrow::Cow::Borrowed("𑄃𑄬"),
-                alloc::borrow::Cow::Borrowed("𑄟𑄬"),
-                alloc::borrow::Cow::Borrowed("𑄎\u{1112a}𑄚\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄎\u{1112a}"),
-                alloc::borrow::Cow::Borrowed("𑄃"),
-                alloc::borrow::Cow::Borrowed("𑄥𑄬"),
-                alloc::borrow::Cow::Borrowed("𑄃\u{11127}"),
-                alloc::borrow::Cow::Borrowed("𑄚\u{11127}"),
-                alloc::borrow::Cow::Borrowed("𑄓\u{11128}"),
-            ]),
-            short: None,
-            wide: ::icu_datetime::provider::calendar::months::SymbolsV1::SolarTwelve([
-                alloc::borrow::Cow::Borrowed("𑄎𑄚\u{1112a}𑄠𑄢\u{11128}"),
-                alloc::borrow::Cow::Borrowed("𑄜𑄬𑄛\u{11134}𑄝\u{11133}𑄢\u{1112a}𑄠𑄢\u{11128}"),
-                alloc::borrow::Cow::Borrowed("𑄟𑄢\u{11134}𑄌\u{11127}"),
-                alloc::borrow::Cow::Borrowed("𑄃𑄬𑄛\u{11133}𑄢\u{11128}𑄣\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄟𑄬"),
-                alloc::borrow::Cow::Borrowed("𑄎\u{1112a}𑄚\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄎\u{1112a}𑄣\u{1112d}"),
-                alloc::borrow::Cow::Borrowed("𑄃𑄉\u{11127}𑄌\u{11134}𑄑\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄥𑄬𑄛\u{11134}𑄑𑄬𑄟\u{11134}𑄝\u{11127}𑄢\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄃\u{11127}𑄇\u{11134}𑄑𑄬𑄝\u{11127}𑄢\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄚\u{11127}𑄞𑄬𑄟\u{11134}𑄝\u{11127}𑄢\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄓\u{11128}𑄥𑄬𑄟\u{11134}𑄝\u{11127}𑄢\u{11134}"),
-            ]),
-        },
-        stand_alone: Some(
-            ::icu_datetime::provider::calendar::months::StandAloneWidthsV1 {
-                abbreviated: Some(
-                    ::icu_datetime::provider::calendar::months::SymbolsV1::SolarTwelve([
-                        alloc::borrow::Cow::Borrowed("𑄎𑄚\u{1112a}𑄠𑄢\u{11128}"),
-                        alloc::borrow::Cow::Borrowed("𑄜𑄬𑄛\u{11134}𑄝\u{11133}𑄢\u{1112a}𑄠𑄢\u{11128}"),
-                        alloc::borrow::Cow::Borrowed("𑄟𑄢\u{11134}𑄌\u{11127}"),
-                        alloc::borrow::Cow::Borrowed("𑄃𑄬𑄛\u{11133}𑄢\u{11128}𑄣\u{11134}"),
-                        alloc::borrow::Cow::Borrowed("𑄟𑄬"),
-                        alloc::borrow::Cow::Borrowed("𑄎\u{1112a}𑄚\u{11134}"),
-                        alloc::borrow::Cow::Borrowed("𑄎\u{1112a}𑄣\u{1112d}"),
-                        alloc::borrow::Cow::Borrowed("𑄃𑄉\u{11127}𑄌\u{11134}𑄑\u{11134}"),
-                        alloc::borrow::Cow::Borrowed(
-                            "𑄥𑄬𑄛\u{11134}𑄑𑄬𑄟\u{11134}𑄝\u{11127}𑄢\u{11134}",
-                        ),
-                        alloc::borrow::Cow::Borrowed(
-                            "𑄃\u{11127}𑄇\u{11134}𑄑\u{1112e}𑄝\u{11127}𑄢\u{11134}",
-                        ),
-                        alloc::borrow::Cow::Borrowed("𑄚\u{11127}𑄞𑄬𑄟\u{11134}𑄝\u{11127}𑄢\u{11134}"),
-                        alloc::borrow::Cow::Borrowed("𑄓\u{11128}𑄥𑄬𑄟\u{11134}𑄝\u{11127}𑄢\u{11134}"),
-                    ]),
-                ),
-                narrow: None,
-                short: None,
-                wide: Some(
-                    ::icu_datetime::provider::calendar::months::SymbolsV1::SolarTwelve([
-                        alloc::borrow::Cow::Borrowed("𑄎𑄚\u{1112a}𑄠𑄢\u{11128}"),
-                        alloc::borrow::Cow::Borrowed("𑄜𑄬𑄛\u{11134}𑄝\u{11133}𑄢\u{1112a}𑄠𑄢\u{11128}"),
-                        alloc::borrow::Cow::Borrowed("𑄟𑄢\u{11134}𑄌\u{11127}"),
-                        alloc::borrow::Cow::Borrowed("𑄃𑄬𑄛\u{11133}𑄢\u{11128}𑄣\u{11134}"),
-                        alloc::borrow::Cow::Borrowed("𑄟𑄬"),
-                        alloc::borrow::Cow::Borrowed("𑄎\u{1112a}𑄚\u{11134}"),
-                        alloc::borrow::Cow::Borrowed("𑄎\u{1112a}𑄣\u{1112d}"),
-                        alloc::borrow::Cow::Borrowed("𑄃𑄉\u{11127}𑄌\u{11134}𑄑\u{11134}"),
-                        alloc::borrow::Cow::Borrowed(
-                            "𑄥𑄬𑄛\u{11134}𑄑𑄬𑄟\u{11134}𑄝\u{11127}𑄢\u{11134}",
-                        ),
-                        alloc::borrow::Cow::Borrowed(
-                            "𑄃\u{11127}𑄇\u{11134}𑄑\u{1112e}𑄝\u{11127}𑄢\u{11134}",
-                        ),
-                        alloc::borrow::Cow::Borrowed("𑄚\u{11127}𑄞𑄬𑄟\u{11134}𑄝\u{11127}𑄢\u{11134}"),
-                        alloc::borrow::Cow::Borrowed("𑄓\u{11128}𑄥𑄬𑄟\u{11134}𑄝\u{11127}𑄢\u{11134}"),
-                    ]),
-                ),
-            },
-        ),
-    },
-    weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
-        format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
-            abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
-                alloc::borrow::Cow::Borrowed("𑄢\u{11127}𑄝\u{11128}"),
-                alloc::borrow::Cow::Borrowed("𑄥\u{11127}𑄟\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄟\u{11127}\u{11101}𑄉\u{11127}𑄣\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄝\u{1112a}𑄖\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄝\u{11133}𑄢\u{11128}𑄥\u{1112a}𑄛\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄥\u{1112a}𑄇\u{11134}𑄇\u{1112e}𑄢\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄥\u{11127}𑄚\u{11128}"),
-            ]),
-            narrow: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
-                alloc::borrow::Cow::Borrowed("𑄢\u{11127}"),
-                alloc::borrow::Cow::Borrowed("𑄥\u{11127}"),
-                alloc::borrow::Cow::Borrowed("𑄟\u{11127}"),
-                alloc::borrow::Cow::Borrowed("𑄝\u{1112a}"),
-                alloc::borrow::Cow::Borrowed("𑄝\u{11133}𑄢\u{11128}"),
-                alloc::borrow::Cow::Borrowed("𑄥\u{1112a}"),
-                alloc::borrow::Cow::Borrowed("𑄥\u{11127}"),
-            ]),
-            short: Some(::icu_datetime::provider::calendar::weekdays::SymbolsV1([
-                alloc::borrow::Cow::Borrowed("𑄢\u{11127}𑄝\u{11128}"),
-                alloc::borrow::Cow::Borrowed("𑄥\u{11127}𑄟\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄟\u{11127}\u{11101}𑄉\u{11127}𑄣\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄝\u{1112a}𑄖\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄝\u{11133}𑄢\u{11128}𑄥\u{1112a}𑄛\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄥\u{1112a}𑄇\u{11134}𑄇\u{1112e}𑄢\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄥\u{11127}𑄚\u{11128}"),
-            ])),
-            wide: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
-                alloc::borrow::Cow::Borrowed("𑄢\u{11127}𑄝\u{11128}𑄝𑄢\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄥\u{11127}𑄟\u{11134}𑄝𑄢\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄟\u{11127}\u{11101}𑄉\u{11127}𑄣\u{11134}𑄝𑄢\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄝\u{1112a}𑄖\u{11134}𑄝𑄢\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄝\u{11133}𑄢\u{11128}𑄥\u{1112a}𑄛\u{11134}𑄝𑄢\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄥\u{1112a}𑄇\u{11134}𑄇\u{1112e}𑄢\u{11134}𑄝𑄢\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄥\u{11127}𑄚\u{11128}𑄝𑄢\u{11134}"),
-            ]),
-        },
-        stand_alone: None,
-    },
-    eras: ::icu_datetime::provider::calendar::Eras {
-        names: unsafe {
-            #[allow(unused_unsafe)]
-            ::zerovec::ZeroMap::from_parts_unchecked(
-                unsafe {
-                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
-                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 98u8, 101u8,
-                    ])
-                },
-                unsafe {
-                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
-                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 66u8, 69u8,
-                    ])
-                },
-            )
-        },
-        abbr: unsafe {
-            #[allow(unused_unsafe)]
-            ::zerovec::ZeroMap::from_parts_unchecked(
-                unsafe {
-                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
-                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 98u8, 101u8,
-                    ])
-                },
-                unsafe {
-                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
-                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 66u8, 69u8,
-                    ])
-                },
-            )
-        },
-        narrow: unsafe {
-            #[allow(unused_unsafe)]
-            ::zerovec::ZeroMap::from_parts_unchecked(
-                unsafe {
-                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
-                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 98u8, 101u8,
-                    ])
-                },
-                unsafe {
-                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
-                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 66u8, 69u8,
-                    ])
-                },
-            )
-        },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
     },
 };
+static CCP : & DataStruct = & :: icu_datetime :: provider :: calendar :: DateSymbolsV1 { months : :: icu_datetime :: provider :: calendar :: months :: ContextsV1 { format : :: icu_datetime :: provider :: calendar :: months :: FormatWidthsV1 { abbreviated : :: icu_datetime :: provider :: calendar :: months :: SymbolsV1 :: SolarTwelve ([alloc :: borrow :: Cow :: Borrowed ("𑄎𑄚\u{1112a}") , alloc :: borrow :: Cow :: Borrowed ("𑄜𑄬𑄛\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄟𑄢\u{11134}𑄌\u{11127}") , alloc :: borrow :: Cow :: Borrowed ("𑄃𑄬𑄛\u{11133}𑄢\u{11128}𑄣\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄟𑄬") , alloc :: borrow :: Cow :: Borrowed ("𑄎\u{1112a}𑄚\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄎\u{1112a}𑄣\u{1112d}") , alloc :: borrow :: Cow :: Borrowed ("𑄃𑄉\u{11127}𑄌\u{11134}𑄑\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄥𑄬𑄛\u{11134}𑄑𑄬𑄟\u{11134}𑄝\u{11127}𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄃\u{11127}𑄇\u{11134}𑄑\u{1112e}𑄝\u{11127}𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄚\u{11127}𑄞𑄬𑄟\u{11134}𑄝\u{11127}𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄓\u{11128}𑄥𑄬𑄟\u{11134}𑄝𑄢\u{11134}") ,] ,) , narrow : :: icu_datetime :: provider :: calendar :: months :: SymbolsV1 :: SolarTwelve ([alloc :: borrow :: Cow :: Borrowed ("𑄎") , alloc :: borrow :: Cow :: Borrowed ("𑄜𑄬") , alloc :: borrow :: Cow :: Borrowed ("𑄟") , alloc :: borrow :: Cow :: Borrowed ("𑄃𑄬") , alloc :: borrow :: Cow :: Borrowed ("𑄟𑄬") , alloc :: borrow :: Cow :: Borrowed ("𑄎\u{1112a}𑄚\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄎\u{1112a}") , alloc :: borrow :: Cow :: Borrowed ("𑄃") , alloc :: borrow :: Cow :: Borrowed ("𑄥𑄬") , alloc :: borrow :: Cow :: Borrowed ("𑄃\u{11127}") , alloc :: borrow :: Cow :: Borrowed ("𑄚\u{11127}") , alloc :: borrow :: Cow :: Borrowed ("𑄓\u{11128}") ,] ,) , short : None , wide : :: icu_datetime :: provider :: calendar :: months :: SymbolsV1 :: SolarTwelve ([alloc :: borrow :: Cow :: Borrowed ("𑄎𑄚\u{1112a}𑄠𑄢\u{11128}") , alloc :: borrow :: Cow :: Borrowed ("𑄜𑄬𑄛\u{11134}𑄝\u{11133}𑄢\u{1112a}𑄠𑄢\u{11128}") , alloc :: borrow :: Cow :: Borrowed ("𑄟𑄢\u{11134}𑄌\u{11127}") , alloc :: borrow :: Cow :: Borrowed ("𑄃𑄬𑄛\u{11133}𑄢\u{11128}𑄣\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄟𑄬") , alloc :: borrow :: Cow :: Borrowed ("𑄎\u{1112a}𑄚\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄎\u{1112a}𑄣\u{1112d}") , alloc :: borrow :: Cow :: Borrowed ("𑄃𑄉\u{11127}𑄌\u{11134}𑄑\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄥𑄬𑄛\u{11134}𑄑𑄬𑄟\u{11134}𑄝\u{11127}𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄃\u{11127}𑄇\u{11134}𑄑𑄬𑄝\u{11127}𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄚\u{11127}𑄞𑄬𑄟\u{11134}𑄝\u{11127}𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄓\u{11128}𑄥𑄬𑄟\u{11134}𑄝\u{11127}𑄢\u{11134}") ,] ,) , } , stand_alone : Some (:: icu_datetime :: provider :: calendar :: months :: StandAloneWidthsV1 { abbreviated : Some (:: icu_datetime :: provider :: calendar :: months :: SymbolsV1 :: SolarTwelve ([alloc :: borrow :: Cow :: Borrowed ("𑄎𑄚\u{1112a}𑄠𑄢\u{11128}") , alloc :: borrow :: Cow :: Borrowed ("𑄜𑄬𑄛\u{11134}𑄝\u{11133}𑄢\u{1112a}𑄠𑄢\u{11128}") , alloc :: borrow :: Cow :: Borrowed ("𑄟𑄢\u{11134}𑄌\u{11127}") , alloc :: borrow :: Cow :: Borrowed ("𑄃𑄬𑄛\u{11133}𑄢\u{11128}𑄣\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄟𑄬") , alloc :: borrow :: Cow :: Borrowed ("𑄎\u{1112a}𑄚\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄎\u{1112a}𑄣\u{1112d}") , alloc :: borrow :: Cow :: Borrowed ("𑄃𑄉\u{11127}𑄌\u{11134}𑄑\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄥𑄬𑄛\u{11134}𑄑𑄬𑄟\u{11134}𑄝\u{11127}𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄃\u{11127}𑄇\u{11134}𑄑\u{1112e}𑄝\u{11127}𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄚\u{11127}𑄞𑄬𑄟\u{11134}𑄝\u{11127}𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄓\u{11128}𑄥𑄬𑄟\u{11134}𑄝\u{11127}𑄢\u{11134}") ,] ,)) , narrow : None , short : None , wide : Some (:: icu_datetime :: provider :: calendar :: months :: SymbolsV1 :: SolarTwelve ([alloc :: borrow :: Cow :: Borrowed ("𑄎𑄚\u{1112a}𑄠𑄢\u{11128}") , alloc :: borrow :: Cow :: Borrowed ("𑄜𑄬𑄛\u{11134}𑄝\u{11133}𑄢\u{1112a}𑄠𑄢\u{11128}") , alloc :: borrow :: Cow :: Borrowed ("𑄟𑄢\u{11134}𑄌\u{11127}") , alloc :: borrow :: Cow :: Borrowed ("𑄃𑄬𑄛\u{11133}𑄢\u{11128}𑄣\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄟𑄬") , alloc :: borrow :: Cow :: Borrowed ("𑄎\u{1112a}𑄚\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄎\u{1112a}𑄣\u{1112d}") , alloc :: borrow :: Cow :: Borrowed ("𑄃𑄉\u{11127}𑄌\u{11134}𑄑\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄥𑄬𑄛\u{11134}𑄑𑄬𑄟\u{11134}𑄝\u{11127}𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄃\u{11127}𑄇\u{11134}𑄑\u{1112e}𑄝\u{11127}𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄚\u{11127}𑄞𑄬𑄟\u{11134}𑄝\u{11127}𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄓\u{11128}𑄥𑄬𑄟\u{11134}𑄝\u{11127}𑄢\u{11134}") ,] ,)) , }) , } , quarters : :: icu_datetime :: provider :: calendar :: quarters :: ContextsV1 { format : :: icu_datetime :: provider :: calendar :: quarters :: FormatWidthsV1 { abbreviated : :: icu_datetime :: provider :: calendar :: quarters :: SymbolsV1 ([alloc :: borrow :: Cow :: Borrowed ("Q1") , alloc :: borrow :: Cow :: Borrowed ("Q2") , alloc :: borrow :: Cow :: Borrowed ("Q3") , alloc :: borrow :: Cow :: Borrowed ("Q4") ,] ,) , narrow : :: icu_datetime :: provider :: calendar :: quarters :: SymbolsV1 ([alloc :: borrow :: Cow :: Borrowed ("𑄷") , alloc :: borrow :: Cow :: Borrowed ("𑄸") , alloc :: borrow :: Cow :: Borrowed ("𑄹") , alloc :: borrow :: Cow :: Borrowed ("𑄺") ,] ,) , short : None , wide : :: icu_datetime :: provider :: calendar :: quarters :: SymbolsV1 ([alloc :: borrow :: Cow :: Borrowed ("𑄖\u{11128}𑄚\u{11134}𑄟𑄎\u{11127}𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄘\u{11128} 𑄛\u{11133}𑄆𑄘\u{11133}𑄠𑄬 𑄖\u{11128}𑄚\u{11134}𑄟𑄎\u{11127}𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄖\u{11128}𑄚\u{11134} 𑄛\u{11133}𑄆𑄘\u{11133}𑄠𑄬 𑄖\u{11128}𑄚\u{11134}𑄟𑄎\u{11127}𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄌\u{11133}𑄆𑄬𑄢\u{11134} 𑄛\u{11133}𑄆𑄘\u{11133}𑄠𑄬 𑄖\u{11128}𑄚\u{11134}𑄟𑄎\u{11127}𑄢\u{11134}") ,] ,) , } , stand_alone : None , } , weekdays : :: icu_datetime :: provider :: calendar :: weekdays :: ContextsV1 { format : :: icu_datetime :: provider :: calendar :: weekdays :: FormatWidthsV1 { abbreviated : :: icu_datetime :: provider :: calendar :: weekdays :: SymbolsV1 ([alloc :: borrow :: Cow :: Borrowed ("𑄢\u{11127}𑄝\u{11128}") , alloc :: borrow :: Cow :: Borrowed ("𑄥\u{11127}𑄟\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄟\u{11127}\u{11101}𑄉\u{11127}𑄣\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄝\u{1112a}𑄖\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄝\u{11133}𑄢\u{11128}𑄥\u{1112a}𑄛\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄥\u{1112a}𑄇\u{11134}𑄇\u{1112e}𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄥\u{11127}𑄚\u{11128}") ,] ,) , narrow : :: icu_datetime :: provider :: calendar :: weekdays :: SymbolsV1 ([alloc :: borrow :: Cow :: Borrowed ("𑄢\u{11127}") , alloc :: borrow :: Cow :: Borrowed ("𑄥\u{11127}") , alloc :: borrow :: Cow :: Borrowed ("𑄟\u{11127}") , alloc :: borrow :: Cow :: Borrowed ("𑄝\u{1112a}") , alloc :: borrow :: Cow :: Borrowed ("𑄝\u{11133}𑄢\u{11128}") , alloc :: borrow :: Cow :: Borrowed ("𑄥\u{1112a}") , alloc :: borrow :: Cow :: Borrowed ("𑄥\u{11127}") ,] ,) , short : Some (:: icu_datetime :: provider :: calendar :: weekdays :: SymbolsV1 ([alloc :: borrow :: Cow :: Borrowed ("𑄢\u{11127}𑄝\u{11128}") , alloc :: borrow :: Cow :: Borrowed ("𑄥\u{11127}𑄟\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄟\u{11127}\u{11101}𑄉\u{11127}𑄣\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄝\u{1112a}𑄖\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄝\u{11133}𑄢\u{11128}𑄥\u{1112a}𑄛\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄥\u{1112a}𑄇\u{11134}𑄇\u{1112e}𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄥\u{11127}𑄚\u{11128}") ,] ,)) , wide : :: icu_datetime :: provider :: calendar :: weekdays :: SymbolsV1 ([alloc :: borrow :: Cow :: Borrowed ("𑄢\u{11127}𑄝\u{11128}𑄝𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄥\u{11127}𑄟\u{11134}𑄝𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄟\u{11127}\u{11101}𑄉\u{11127}𑄣\u{11134}𑄝𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄝\u{1112a}𑄖\u{11134}𑄝𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄝\u{11133}𑄢\u{11128}𑄥\u{1112a}𑄛\u{11134}𑄝𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄥\u{1112a}𑄇\u{11134}𑄇\u{1112e}𑄢\u{11134}𑄝𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄥\u{11127}𑄚\u{11128}𑄝𑄢\u{11134}") ,] ,) , } , stand_alone : None , } , eras : :: icu_datetime :: provider :: calendar :: Eras { names : unsafe { # [allow (unused_unsafe)] :: zerovec :: ZeroMap :: from_parts_unchecked (unsafe { :: zerovec :: VarZeroVec :: from_bytes_unchecked (& [1u8 , 0u8 , 0u8 , 0u8 , 0u8 , 0u8 , 98u8 , 101u8]) } , unsafe { :: zerovec :: VarZeroVec :: from_bytes_unchecked (& [1u8 , 0u8 , 0u8 , 0u8 , 0u8 , 0u8 , 66u8 , 69u8]) }) } , abbr : unsafe { # [allow (unused_unsafe)] :: zerovec :: ZeroMap :: from_parts_unchecked (unsafe { :: zerovec :: VarZeroVec :: from_bytes_unchecked (& [1u8 , 0u8 , 0u8 , 0u8 , 0u8 , 0u8 , 98u8 , 101u8]) } , unsafe { :: zerovec :: VarZeroVec :: from_bytes_unchecked (& [1u8 , 0u8 , 0u8 , 0u8 , 0u8 , 0u8 , 66u8 , 69u8]) }) } , narrow : unsafe { # [allow (unused_unsafe)] :: zerovec :: ZeroMap :: from_parts_unchecked (unsafe { :: zerovec :: VarZeroVec :: from_bytes_unchecked (& [1u8 , 0u8 , 0u8 , 0u8 , 0u8 , 0u8 , 98u8 , 101u8]) } , unsafe { :: zerovec :: VarZeroVec :: from_bytes_unchecked (& [1u8 , 0u8 , 0u8 , 0u8 , 0u8 , 0u8 , 66u8 , 69u8]) }) } , } , cyclic_years : :: icu_datetime :: provider :: calendar :: CyclicYears { names : unsafe { :: zerovec :: VarZeroVec :: from_bytes_unchecked (& []) } , abbr : unsafe { :: zerovec :: VarZeroVec :: from_bytes_unchecked (& []) } , narrow : unsafe { :: zerovec :: VarZeroVec :: from_bytes_unchecked (& []) } , } , } ;
 static EN: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
     months: ::icu_datetime::provider::calendar::months::ContextsV1 {
         format: ::icu_datetime::provider::calendar::months::FormatWidthsV1 {
@@ -554,6 +442,30 @@ static EN: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
         },
         stand_alone: None,
     },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Q1"),
+                alloc::borrow::Cow::Borrowed("Q2"),
+                alloc::borrow::Cow::Borrowed("Q3"),
+                alloc::borrow::Cow::Borrowed("Q4"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1"),
+                alloc::borrow::Cow::Borrowed("2"),
+                alloc::borrow::Cow::Borrowed("3"),
+                alloc::borrow::Cow::Borrowed("4"),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1st quarter"),
+                alloc::borrow::Cow::Borrowed("2nd quarter"),
+                alloc::borrow::Cow::Borrowed("3rd quarter"),
+                alloc::borrow::Cow::Borrowed("4th quarter"),
+            ]),
+        },
+        stand_alone: None,
+    },
     weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
         format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
@@ -642,6 +554,11 @@ static EN: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
             )
         },
     },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
 };
 static EN_001_EN_ZA: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
     months: ::icu_datetime::provider::calendar::months::ContextsV1 {
@@ -692,6 +609,30 @@ static EN_001_EN_ZA: &DataStruct = &::icu_datetime::provider::calendar::DateSymb
         },
         stand_alone: None,
     },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Q1"),
+                alloc::borrow::Cow::Borrowed("Q2"),
+                alloc::borrow::Cow::Borrowed("Q3"),
+                alloc::borrow::Cow::Borrowed("Q4"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1"),
+                alloc::borrow::Cow::Borrowed("2"),
+                alloc::borrow::Cow::Borrowed("3"),
+                alloc::borrow::Cow::Borrowed("4"),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1st quarter"),
+                alloc::borrow::Cow::Borrowed("2nd quarter"),
+                alloc::borrow::Cow::Borrowed("3rd quarter"),
+                alloc::borrow::Cow::Borrowed("4th quarter"),
+            ]),
+        },
+        stand_alone: None,
+    },
     weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
         format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
@@ -780,6 +721,11 @@ static EN_001_EN_ZA: &DataStruct = &::icu_datetime::provider::calendar::DateSymb
             )
         },
     },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
 };
 static ES: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
     months: ::icu_datetime::provider::calendar::months::ContextsV1 {
@@ -830,6 +776,30 @@ static ES: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
         },
         stand_alone: None,
     },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("T1"),
+                alloc::borrow::Cow::Borrowed("T2"),
+                alloc::borrow::Cow::Borrowed("T3"),
+                alloc::borrow::Cow::Borrowed("T4"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1"),
+                alloc::borrow::Cow::Borrowed("2"),
+                alloc::borrow::Cow::Borrowed("3"),
+                alloc::borrow::Cow::Borrowed("4"),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1.er trimestre"),
+                alloc::borrow::Cow::Borrowed("2.º trimestre"),
+                alloc::borrow::Cow::Borrowed("3.er trimestre"),
+                alloc::borrow::Cow::Borrowed("4.º trimestre"),
+            ]),
+        },
+        stand_alone: None,
+    },
     weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
         format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
@@ -918,6 +888,11 @@ static ES: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
             )
         },
     },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
 };
 static ES_AR: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
     months: ::icu_datetime::provider::calendar::months::ContextsV1 {
@@ -968,6 +943,30 @@ static ES_AR: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
         },
         stand_alone: None,
     },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("T1"),
+                alloc::borrow::Cow::Borrowed("T2"),
+                alloc::borrow::Cow::Borrowed("T3"),
+                alloc::borrow::Cow::Borrowed("T4"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1"),
+                alloc::borrow::Cow::Borrowed("2"),
+                alloc::borrow::Cow::Borrowed("3"),
+                alloc::borrow::Cow::Borrowed("4"),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1.er trimestre"),
+                alloc::borrow::Cow::Borrowed("2.º trimestre"),
+                alloc::borrow::Cow::Borrowed("3.er trimestre"),
+                alloc::borrow::Cow::Borrowed("4.º trimestre"),
+            ]),
+        },
+        stand_alone: None,
+    },
     weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
         format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
@@ -1056,6 +1055,11 @@ static ES_AR: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
             )
         },
     },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
 };
 static FIL: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
     months: ::icu_datetime::provider::calendar::months::ContextsV1 {
@@ -1128,6 +1132,30 @@ static FIL: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
             },
         ),
     },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Q1"),
+                alloc::borrow::Cow::Borrowed("Q2"),
+                alloc::borrow::Cow::Borrowed("Q3"),
+                alloc::borrow::Cow::Borrowed("Q4"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1"),
+                alloc::borrow::Cow::Borrowed("2"),
+                alloc::borrow::Cow::Borrowed("3"),
+                alloc::borrow::Cow::Borrowed("4"),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("ika-1 quarter"),
+                alloc::borrow::Cow::Borrowed("ika-2 quarter"),
+                alloc::borrow::Cow::Borrowed("ika-3 quarter"),
+                alloc::borrow::Cow::Borrowed("ika-4 na quarter"),
+            ]),
+        },
+        stand_alone: None,
+    },
     weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
         format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
@@ -1216,6 +1244,11 @@ static FIL: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
             )
         },
     },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
 };
 static FR: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
     months: ::icu_datetime::provider::calendar::months::ContextsV1 {
@@ -1266,6 +1299,30 @@ static FR: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
         },
         stand_alone: None,
     },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("T1"),
+                alloc::borrow::Cow::Borrowed("T2"),
+                alloc::borrow::Cow::Borrowed("T3"),
+                alloc::borrow::Cow::Borrowed("T4"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1"),
+                alloc::borrow::Cow::Borrowed("2"),
+                alloc::borrow::Cow::Borrowed("3"),
+                alloc::borrow::Cow::Borrowed("4"),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1er trimestre"),
+                alloc::borrow::Cow::Borrowed("2e trimestre"),
+                alloc::borrow::Cow::Borrowed("3e trimestre"),
+                alloc::borrow::Cow::Borrowed("4e trimestre"),
+            ]),
+        },
+        stand_alone: None,
+    },
     weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
         format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
@@ -1355,6 +1412,11 @@ static FR: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
             )
         },
     },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
 };
 static JA: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
     months: ::icu_datetime::provider::calendar::months::ContextsV1 {
@@ -1405,6 +1467,30 @@ static JA: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
         },
         stand_alone: None,
     },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Q1"),
+                alloc::borrow::Cow::Borrowed("Q2"),
+                alloc::borrow::Cow::Borrowed("Q3"),
+                alloc::borrow::Cow::Borrowed("Q4"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1"),
+                alloc::borrow::Cow::Borrowed("2"),
+                alloc::borrow::Cow::Borrowed("3"),
+                alloc::borrow::Cow::Borrowed("4"),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("第1四半期"),
+                alloc::borrow::Cow::Borrowed("第2四半期"),
+                alloc::borrow::Cow::Borrowed("第3四半期"),
+                alloc::borrow::Cow::Borrowed("第4四半期"),
+            ]),
+        },
+        stand_alone: None,
+    },
     weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
         format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
@@ -1493,6 +1579,11 @@ static JA: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
             )
         },
     },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
 };
 static RU: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
     months: ::icu_datetime::provider::calendar::months::ContextsV1 {
@@ -1580,6 +1671,30 @@ static RU: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
             },
         ),
     },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1-й кв."),
+                alloc::borrow::Cow::Borrowed("2-й кв."),
+                alloc::borrow::Cow::Borrowed("3-й кв."),
+                alloc::borrow::Cow::Borrowed("4-й кв."),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1"),
+                alloc::borrow::Cow::Borrowed("2"),
+                alloc::borrow::Cow::Borrowed("3"),
+                alloc::borrow::Cow::Borrowed("4"),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1-й квартал"),
+                alloc::borrow::Cow::Borrowed("2-й квартал"),
+                alloc::borrow::Cow::Borrowed("3-й квартал"),
+                alloc::borrow::Cow::Borrowed("4-й квартал"),
+            ]),
+        },
+        stand_alone: None,
+    },
     weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
         format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
@@ -1670,6 +1785,11 @@ static RU: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
             )
         },
     },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
 };
 static SR_LATN: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
     months: ::icu_datetime::provider::calendar::months::ContextsV1 {
@@ -1720,6 +1840,30 @@ static SR_LATN: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1
         },
         stand_alone: None,
     },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1. kv."),
+                alloc::borrow::Cow::Borrowed("2. kv."),
+                alloc::borrow::Cow::Borrowed("3. kv."),
+                alloc::borrow::Cow::Borrowed("4. kv."),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1."),
+                alloc::borrow::Cow::Borrowed("2."),
+                alloc::borrow::Cow::Borrowed("3."),
+                alloc::borrow::Cow::Borrowed("4."),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("prvi kvartal"),
+                alloc::borrow::Cow::Borrowed("drugi kvartal"),
+                alloc::borrow::Cow::Borrowed("treći kvartal"),
+                alloc::borrow::Cow::Borrowed("četvrti kvartal"),
+            ]),
+        },
+        stand_alone: None,
+    },
     weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
         format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
@@ -1808,6 +1952,11 @@ static SR_LATN: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1
             )
         },
     },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
 };
 static SR_SR_CYRL: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
     months: ::icu_datetime::provider::calendar::months::ContextsV1 {
@@ -1858,6 +2007,30 @@ static SR_SR_CYRL: &DataStruct = &::icu_datetime::provider::calendar::DateSymbol
         },
         stand_alone: None,
     },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1. кв."),
+                alloc::borrow::Cow::Borrowed("2. кв."),
+                alloc::borrow::Cow::Borrowed("3. кв."),
+                alloc::borrow::Cow::Borrowed("4. кв."),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1."),
+                alloc::borrow::Cow::Borrowed("2."),
+                alloc::borrow::Cow::Borrowed("3."),
+                alloc::borrow::Cow::Borrowed("4."),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("први квартал"),
+                alloc::borrow::Cow::Borrowed("други квартал"),
+                alloc::borrow::Cow::Borrowed("трећи квартал"),
+                alloc::borrow::Cow::Borrowed("четврти квартал"),
+            ]),
+        },
+        stand_alone: None,
+    },
     weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
         format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
@@ -1946,6 +2119,11 @@ static SR_SR_CYRL: &DataStruct = &::icu_datetime::provider::calendar::DateSymbol
             )
         },
     },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
 };
 static TH: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
     months: ::icu_datetime::provider::calendar::months::ContextsV1 {
@@ -1996,6 +2174,30 @@ static TH: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
         },
         stand_alone: None,
     },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("ไตรมาส 1"),
+                alloc::borrow::Cow::Borrowed("ไตรมาส 2"),
+                alloc::borrow::Cow::Borrowed("ไตรมาส 3"),
+                alloc::borrow::Cow::Borrowed("ไตรมาส 4"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1"),
+                alloc::borrow::Cow::Borrowed("2"),
+                alloc::borrow::Cow::Borrowed("3"),
+                alloc::borrow::Cow::Borrowed("4"),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("ไตรมาส 1"),
+                alloc::borrow::Cow::Borrowed("ไตรมาส 2"),
+                alloc::borrow::Cow::Borrowed("ไตรมาส 3"),
+                alloc::borrow::Cow::Borrowed("ไตรมาส 4"),
+            ]),
+        },
+        stand_alone: None,
+    },
     weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
         format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
@@ -2089,6 +2291,11 @@ static TH: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
             )
         },
     },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
 };
 static TR: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
     months: ::icu_datetime::provider::calendar::months::ContextsV1 {
@@ -2139,6 +2346,30 @@ static TR: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
         },
         stand_alone: None,
     },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Ç1"),
+                alloc::borrow::Cow::Borrowed("Ç2"),
+                alloc::borrow::Cow::Borrowed("Ç3"),
+                alloc::borrow::Cow::Borrowed("Ç4"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1."),
+                alloc::borrow::Cow::Borrowed("2."),
+                alloc::borrow::Cow::Borrowed("3."),
+                alloc::borrow::Cow::Borrowed("4."),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1. çeyrek"),
+                alloc::borrow::Cow::Borrowed("2. çeyrek"),
+                alloc::borrow::Cow::Borrowed("3. çeyrek"),
+                alloc::borrow::Cow::Borrowed("4. çeyrek"),
+            ]),
+        },
+        stand_alone: None,
+    },
     weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
         format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
@@ -2227,6 +2458,11 @@ static TR: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
             )
         },
     },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
 };
 static UND: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
     months: ::icu_datetime::provider::calendar::months::ContextsV1 {
@@ -2277,6 +2513,30 @@ static UND: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
         },
         stand_alone: None,
     },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Q1"),
+                alloc::borrow::Cow::Borrowed("Q2"),
+                alloc::borrow::Cow::Borrowed("Q3"),
+                alloc::borrow::Cow::Borrowed("Q4"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1"),
+                alloc::borrow::Cow::Borrowed("2"),
+                alloc::borrow::Cow::Borrowed("3"),
+                alloc::borrow::Cow::Borrowed("4"),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Q1"),
+                alloc::borrow::Cow::Borrowed("Q2"),
+                alloc::borrow::Cow::Borrowed("Q3"),
+                alloc::borrow::Cow::Borrowed("Q4"),
+            ]),
+        },
+        stand_alone: None,
+    },
     weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
         format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
@@ -2365,4 +2625,9 @@ static UND: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
             )
         },
     },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
 };
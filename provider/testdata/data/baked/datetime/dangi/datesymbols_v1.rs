@@ -0,0 +1,710 @@
+// @generated
+type DataStruct = < :: icu_datetime :: provider :: calendar :: DangiDateSymbolsV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[("en", EN), ("und", UND)]);
+static EN: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
+    months: ::icu_datetime::provider::calendar::months::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::months::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::months::SymbolsV1::Other(unsafe {
+                #[allow(unused_unsafe)]
+                ::zerovec::ZeroMap::from_parts_unchecked(
+                    unsafe {
+                        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                            77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 49u8, 76u8, 77u8, 48u8, 50u8, 0u8,
+                            77u8, 48u8, 50u8, 76u8, 77u8, 48u8, 51u8, 0u8, 77u8, 48u8, 51u8, 76u8,
+                            77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 52u8, 76u8, 77u8, 48u8, 53u8, 0u8,
+                            77u8, 48u8, 53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 54u8, 76u8,
+                            77u8, 48u8, 55u8, 0u8, 77u8, 48u8, 55u8, 76u8, 77u8, 48u8, 56u8, 0u8,
+                            77u8, 48u8, 56u8, 76u8, 77u8, 48u8, 57u8, 0u8, 77u8, 48u8, 57u8, 76u8,
+                            77u8, 49u8, 48u8, 0u8, 77u8, 49u8, 48u8, 76u8, 77u8, 49u8, 49u8, 0u8,
+                            77u8, 49u8, 49u8, 76u8, 77u8, 49u8, 50u8, 0u8, 77u8, 49u8, 50u8, 76u8,
+                        ])
+                    },
+                    unsafe {
+                        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                            24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 9u8, 0u8, 12u8, 0u8, 18u8,
+                            0u8, 21u8, 0u8, 27u8, 0u8, 30u8, 0u8, 36u8, 0u8, 39u8, 0u8, 45u8, 0u8,
+                            48u8, 0u8, 54u8, 0u8, 57u8, 0u8, 63u8, 0u8, 66u8, 0u8, 72u8, 0u8, 75u8,
+                            0u8, 81u8, 0u8, 85u8, 0u8, 92u8, 0u8, 96u8, 0u8, 103u8, 0u8, 107u8,
+                            0u8, 77u8, 111u8, 49u8, 77u8, 111u8, 49u8, 98u8, 105u8, 115u8, 77u8,
+                            111u8, 50u8, 77u8, 111u8, 50u8, 98u8, 105u8, 115u8, 77u8, 111u8, 51u8,
+                            77u8, 111u8, 51u8, 98u8, 105u8, 115u8, 77u8, 111u8, 52u8, 77u8, 111u8,
+                            52u8, 98u8, 105u8, 115u8, 77u8, 111u8, 53u8, 77u8, 111u8, 53u8, 98u8,
+                            105u8, 115u8, 77u8, 111u8, 54u8, 77u8, 111u8, 54u8, 98u8, 105u8, 115u8,
+                            77u8, 111u8, 55u8, 77u8, 111u8, 55u8, 98u8, 105u8, 115u8, 77u8, 111u8,
+                            56u8, 77u8, 111u8, 56u8, 98u8, 105u8, 115u8, 77u8, 111u8, 57u8, 77u8,
+                            111u8, 57u8, 98u8, 105u8, 115u8, 77u8, 111u8, 49u8, 48u8, 77u8, 111u8,
+                            49u8, 48u8, 98u8, 105u8, 115u8, 77u8, 111u8, 49u8, 49u8, 77u8, 111u8,
+                            49u8, 49u8, 98u8, 105u8, 115u8, 77u8, 111u8, 49u8, 50u8, 77u8, 111u8,
+                            49u8, 50u8, 98u8, 105u8, 115u8,
+                        ])
+                    },
+                )
+            }),
+            narrow: ::icu_datetime::provider::calendar::months::SymbolsV1::Other(unsafe {
+                #[allow(unused_unsafe)]
+                ::zerovec::ZeroMap::from_parts_unchecked(
+                    unsafe {
+                        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                            77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 49u8, 76u8, 77u8, 48u8, 50u8, 0u8,
+                            77u8, 48u8, 50u8, 76u8, 77u8, 48u8, 51u8, 0u8, 77u8, 48u8, 51u8, 76u8,
+                            77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 52u8, 76u8, 77u8, 48u8, 53u8, 0u8,
+                            77u8, 48u8, 53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 54u8, 76u8,
+                            77u8, 48u8, 55u8, 0u8, 77u8, 48u8, 55u8, 76u8, 77u8, 48u8, 56u8, 0u8,
+                            77u8, 48u8, 56u8, 76u8, 77u8, 48u8, 57u8, 0u8, 77u8, 48u8, 57u8, 76u8,
+                            77u8, 49u8, 48u8, 0u8, 77u8, 49u8, 48u8, 76u8, 77u8, 49u8, 49u8, 0u8,
+                            77u8, 49u8, 49u8, 76u8, 77u8, 49u8, 50u8, 0u8, 77u8, 49u8, 50u8, 76u8,
+                        ])
+                    },
+                    unsafe {
+                        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                            24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 3u8, 0u8, 4u8, 0u8, 6u8, 0u8,
+                            7u8, 0u8, 9u8, 0u8, 10u8, 0u8, 12u8, 0u8, 13u8, 0u8, 15u8, 0u8, 16u8,
+                            0u8, 18u8, 0u8, 19u8, 0u8, 21u8, 0u8, 22u8, 0u8, 24u8, 0u8, 25u8, 0u8,
+                            27u8, 0u8, 29u8, 0u8, 32u8, 0u8, 34u8, 0u8, 37u8, 0u8, 39u8, 0u8, 49u8,
+                            49u8, 98u8, 50u8, 50u8, 98u8, 51u8, 51u8, 98u8, 52u8, 52u8, 98u8, 53u8,
+                            53u8, 98u8, 54u8, 54u8, 98u8, 55u8, 55u8, 98u8, 56u8, 56u8, 98u8, 57u8,
+                            57u8, 98u8, 49u8, 48u8, 49u8, 48u8, 98u8, 49u8, 49u8, 49u8, 49u8, 98u8,
+                            49u8, 50u8, 49u8, 50u8, 98u8,
+                        ])
+                    },
+                )
+            }),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::months::SymbolsV1::Other(unsafe {
+                #[allow(unused_unsafe)]
+                ::zerovec::ZeroMap::from_parts_unchecked(
+                    unsafe {
+                        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                            77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 49u8, 76u8, 77u8, 48u8, 50u8, 0u8,
+                            77u8, 48u8, 50u8, 76u8, 77u8, 48u8, 51u8, 0u8, 77u8, 48u8, 51u8, 76u8,
+                            77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 52u8, 76u8, 77u8, 48u8, 53u8, 0u8,
+                            77u8, 48u8, 53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 54u8, 76u8,
+                            77u8, 48u8, 55u8, 0u8, 77u8, 48u8, 55u8, 76u8, 77u8, 48u8, 56u8, 0u8,
+                            77u8, 48u8, 56u8, 76u8, 77u8, 48u8, 57u8, 0u8, 77u8, 48u8, 57u8, 76u8,
+                            77u8, 49u8, 48u8, 0u8, 77u8, 49u8, 48u8, 76u8, 77u8, 49u8, 49u8, 0u8,
+                            77u8, 49u8, 49u8, 76u8, 77u8, 49u8, 50u8, 0u8, 77u8, 49u8, 50u8, 76u8,
+                        ])
+                    },
+                    unsafe {
+                        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                            24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 11u8, 0u8, 25u8, 0u8, 37u8, 0u8, 52u8,
+                            0u8, 63u8, 0u8, 77u8, 0u8, 89u8, 0u8, 104u8, 0u8, 115u8, 0u8, 129u8,
+                            0u8, 140u8, 0u8, 154u8, 0u8, 167u8, 0u8, 183u8, 0u8, 195u8, 0u8, 210u8,
+                            0u8, 221u8, 0u8, 235u8, 0u8, 246u8, 0u8, 4u8, 1u8, 18u8, 1u8, 35u8,
+                            1u8, 48u8, 1u8, 70u8, 105u8, 114u8, 115u8, 116u8, 32u8, 77u8, 111u8,
+                            110u8, 116u8, 104u8, 70u8, 105u8, 114u8, 115u8, 116u8, 32u8, 77u8,
+                            111u8, 110u8, 116u8, 104u8, 98u8, 105u8, 115u8, 83u8, 101u8, 99u8,
+                            111u8, 110u8, 100u8, 32u8, 77u8, 111u8, 110u8, 116u8, 104u8, 83u8,
+                            101u8, 99u8, 111u8, 110u8, 100u8, 32u8, 77u8, 111u8, 110u8, 116u8,
+                            104u8, 98u8, 105u8, 115u8, 84u8, 104u8, 105u8, 114u8, 100u8, 32u8,
+                            77u8, 111u8, 110u8, 116u8, 104u8, 84u8, 104u8, 105u8, 114u8, 100u8,
+                            32u8, 77u8, 111u8, 110u8, 116u8, 104u8, 98u8, 105u8, 115u8, 70u8,
+                            111u8, 117u8, 114u8, 116u8, 104u8, 32u8, 77u8, 111u8, 110u8, 116u8,
+                            104u8, 70u8, 111u8, 117u8, 114u8, 116u8, 104u8, 32u8, 77u8, 111u8,
+                            110u8, 116u8, 104u8, 98u8, 105u8, 115u8, 70u8, 105u8, 102u8, 116u8,
+                            104u8, 32u8, 77u8, 111u8, 110u8, 116u8, 104u8, 70u8, 105u8, 102u8,
+                            116u8, 104u8, 32u8, 77u8, 111u8, 110u8, 116u8, 104u8, 98u8, 105u8,
+                            115u8, 83u8, 105u8, 120u8, 116u8, 104u8, 32u8, 77u8, 111u8, 110u8,
+                            116u8, 104u8, 83u8, 105u8, 120u8, 116u8, 104u8, 32u8, 77u8, 111u8,
+                            110u8, 116u8, 104u8, 98u8, 105u8, 115u8, 83u8, 101u8, 118u8, 101u8,
+                            110u8, 116u8, 104u8, 32u8, 77u8, 111u8, 110u8, 116u8, 104u8, 83u8,
+                            101u8, 118u8, 101u8, 110u8, 116u8, 104u8, 32u8, 77u8, 111u8, 110u8,
+                            116u8, 104u8, 98u8, 105u8, 115u8, 69u8, 105u8, 103u8, 104u8, 116u8,
+                            104u8, 32u8, 77u8, 111u8, 110u8, 116u8, 104u8, 69u8, 105u8, 103u8,
+                            104u8, 116u8, 104u8, 32u8, 77u8, 111u8, 110u8, 116u8, 104u8, 98u8,
+                            105u8, 115u8, 78u8, 105u8, 110u8, 116u8, 104u8, 32u8, 77u8, 111u8,
+                            110u8, 116u8, 104u8, 78u8, 105u8, 110u8, 116u8, 104u8, 32u8, 77u8,
+                            111u8, 110u8, 116u8, 104u8, 98u8, 105u8, 115u8, 84u8, 101u8, 110u8,
+                            116u8, 104u8, 32u8, 77u8, 111u8, 110u8, 116u8, 104u8, 84u8, 101u8,
+                            110u8, 116u8, 104u8, 32u8, 77u8, 111u8, 110u8, 116u8, 104u8, 98u8,
+                            105u8, 115u8, 69u8, 108u8, 101u8, 118u8, 101u8, 110u8, 116u8, 104u8,
+                            32u8, 77u8, 111u8, 110u8, 116u8, 104u8, 69u8, 108u8, 101u8, 118u8,
+                            101u8, 110u8, 116u8, 104u8, 32u8, 77u8, 111u8, 110u8, 116u8, 104u8,
+                            98u8, 105u8, 115u8, 84u8, 119u8, 101u8, 108u8, 102u8, 116u8, 104u8,
+                            32u8, 77u8, 111u8, 110u8, 116u8, 104u8, 84u8, 119u8, 101u8, 108u8,
+                            102u8, 116u8, 104u8, 32u8, 77u8, 111u8, 110u8, 116u8, 104u8, 98u8,
+                            105u8, 115u8,
+                        ])
+                    },
+                )
+            }),
+        },
+        stand_alone: None,
+    },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Q1"),
+                alloc::borrow::Cow::Borrowed("Q2"),
+                alloc::borrow::Cow::Borrowed("Q3"),
+                alloc::borrow::Cow::Borrowed("Q4"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1"),
+                alloc::borrow::Cow::Borrowed("2"),
+                alloc::borrow::Cow::Borrowed("3"),
+                alloc::borrow::Cow::Borrowed("4"),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1st quarter"),
+                alloc::borrow::Cow::Borrowed("2nd quarter"),
+                alloc::borrow::Cow::Borrowed("3rd quarter"),
+                alloc::borrow::Cow::Borrowed("4th quarter"),
+            ]),
+        },
+        stand_alone: None,
+    },
+    weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Sun"),
+                alloc::borrow::Cow::Borrowed("Mon"),
+                alloc::borrow::Cow::Borrowed("Tue"),
+                alloc::borrow::Cow::Borrowed("Wed"),
+                alloc::borrow::Cow::Borrowed("Thu"),
+                alloc::borrow::Cow::Borrowed("Fri"),
+                alloc::borrow::Cow::Borrowed("Sat"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("S"),
+                alloc::borrow::Cow::Borrowed("M"),
+                alloc::borrow::Cow::Borrowed("T"),
+                alloc::borrow::Cow::Borrowed("W"),
+                alloc::borrow::Cow::Borrowed("T"),
+                alloc::borrow::Cow::Borrowed("F"),
+                alloc::borrow::Cow::Borrowed("S"),
+            ]),
+            short: Some(::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Su"),
+                alloc::borrow::Cow::Borrowed("Mo"),
+                alloc::borrow::Cow::Borrowed("Tu"),
+                alloc::borrow::Cow::Borrowed("We"),
+                alloc::borrow::Cow::Borrowed("Th"),
+                alloc::borrow::Cow::Borrowed("Fr"),
+                alloc::borrow::Cow::Borrowed("Sa"),
+            ])),
+            wide: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Sunday"),
+                alloc::borrow::Cow::Borrowed("Monday"),
+                alloc::borrow::Cow::Borrowed("Tuesday"),
+                alloc::borrow::Cow::Borrowed("Wednesday"),
+                alloc::borrow::Cow::Borrowed("Thursday"),
+                alloc::borrow::Cow::Borrowed("Friday"),
+                alloc::borrow::Cow::Borrowed("Saturday"),
+            ]),
+        },
+        stand_alone: None,
+    },
+    eras: ::icu_datetime::provider::calendar::Eras {
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+        abbr: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+        narrow: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+    },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe {
+            ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                60u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 13u8, 0u8, 21u8, 0u8, 29u8, 0u8, 36u8,
+                0u8, 41u8, 0u8, 48u8, 0u8, 55u8, 0u8, 63u8, 0u8, 70u8, 0u8, 76u8, 0u8, 82u8, 0u8,
+                89u8, 0u8, 98u8, 0u8, 104u8, 0u8, 110u8, 0u8, 119u8, 0u8, 125u8, 0u8, 131u8, 0u8,
+                138u8, 0u8, 146u8, 0u8, 152u8, 0u8, 159u8, 0u8, 167u8, 0u8, 172u8, 0u8, 179u8, 0u8,
+                187u8, 0u8, 194u8, 0u8, 202u8, 0u8, 208u8, 0u8, 214u8, 0u8, 220u8, 0u8, 229u8, 0u8,
+                237u8, 0u8, 242u8, 0u8, 248u8, 0u8, 255u8, 0u8, 7u8, 1u8, 14u8, 1u8, 21u8, 1u8,
+                29u8, 1u8, 34u8, 1u8, 41u8, 1u8, 49u8, 1u8, 56u8, 1u8, 62u8, 1u8, 69u8, 1u8, 76u8,
+                1u8, 82u8, 1u8, 90u8, 1u8, 97u8, 1u8, 103u8, 1u8, 112u8, 1u8, 119u8, 1u8, 124u8,
+                1u8, 130u8, 1u8, 139u8, 1u8, 146u8, 1u8, 152u8, 1u8, 106u8, 105u8, 97u8, 45u8,
+                122u8, 105u8, 121u8, 105u8, 45u8, 99u8, 104u8, 111u8, 117u8, 98u8, 105u8, 110u8,
+                103u8, 45u8, 121u8, 105u8, 110u8, 100u8, 105u8, 110u8, 103u8, 45u8, 109u8, 97u8,
+                111u8, 119u8, 117u8, 45u8, 99u8, 104u8, 101u8, 110u8, 106u8, 105u8, 45u8, 115u8,
+                105u8, 103u8, 101u8, 110u8, 103u8, 45u8, 119u8, 117u8, 120u8, 105u8, 110u8, 45u8,
+                119u8, 101u8, 105u8, 114u8, 101u8, 110u8, 45u8, 115u8, 104u8, 101u8, 110u8, 103u8,
+                117u8, 105u8, 45u8, 121u8, 111u8, 117u8, 106u8, 105u8, 97u8, 45u8, 120u8, 117u8,
+                121u8, 105u8, 45u8, 104u8, 97u8, 105u8, 98u8, 105u8, 110u8, 103u8, 45u8, 122u8,
+                105u8, 100u8, 105u8, 110u8, 103u8, 45u8, 99u8, 104u8, 111u8, 117u8, 119u8, 117u8,
+                45u8, 121u8, 105u8, 110u8, 106u8, 105u8, 45u8, 109u8, 97u8, 111u8, 103u8, 101u8,
+                110u8, 103u8, 45u8, 99u8, 104u8, 101u8, 110u8, 120u8, 105u8, 110u8, 45u8, 115u8,
+                105u8, 114u8, 101u8, 110u8, 45u8, 119u8, 117u8, 103u8, 117u8, 105u8, 45u8, 119u8,
+                101u8, 105u8, 106u8, 105u8, 97u8, 45u8, 115u8, 104u8, 101u8, 110u8, 121u8, 105u8,
+                45u8, 121u8, 111u8, 117u8, 98u8, 105u8, 110u8, 103u8, 45u8, 120u8, 117u8, 100u8,
+                105u8, 110u8, 103u8, 45u8, 104u8, 97u8, 105u8, 119u8, 117u8, 45u8, 122u8, 105u8,
+                106u8, 105u8, 45u8, 99u8, 104u8, 111u8, 117u8, 103u8, 101u8, 110u8, 103u8, 45u8,
+                121u8, 105u8, 110u8, 120u8, 105u8, 110u8, 45u8, 109u8, 97u8, 111u8, 114u8, 101u8,
+                110u8, 45u8, 99u8, 104u8, 101u8, 110u8, 103u8, 117u8, 105u8, 45u8, 115u8, 105u8,
+                106u8, 105u8, 97u8, 45u8, 119u8, 117u8, 121u8, 105u8, 45u8, 119u8, 101u8, 105u8,
+                98u8, 105u8, 110u8, 103u8, 45u8, 115u8, 104u8, 101u8, 110u8, 100u8, 105u8, 110u8,
+                103u8, 45u8, 121u8, 111u8, 117u8, 119u8, 117u8, 45u8, 120u8, 117u8, 106u8, 105u8,
+                45u8, 104u8, 97u8, 105u8, 103u8, 101u8, 110u8, 103u8, 45u8, 122u8, 105u8, 120u8,
+                105u8, 110u8, 45u8, 99u8, 104u8, 111u8, 117u8, 114u8, 101u8, 110u8, 45u8, 121u8,
+                105u8, 110u8, 103u8, 117u8, 105u8, 45u8, 109u8, 97u8, 111u8, 106u8, 105u8, 97u8,
+                45u8, 99u8, 104u8, 101u8, 110u8, 121u8, 105u8, 45u8, 115u8, 105u8, 98u8, 105u8,
+                110u8, 103u8, 45u8, 119u8, 117u8, 100u8, 105u8, 110u8, 103u8, 45u8, 119u8, 101u8,
+                105u8, 119u8, 117u8, 45u8, 115u8, 104u8, 101u8, 110u8, 106u8, 105u8, 45u8, 121u8,
+                111u8, 117u8, 103u8, 101u8, 110u8, 103u8, 45u8, 120u8, 117u8, 120u8, 105u8, 110u8,
+                45u8, 104u8, 97u8, 105u8, 114u8, 101u8, 110u8, 45u8, 122u8, 105u8, 103u8, 117u8,
+                105u8, 45u8, 99u8, 104u8, 111u8, 117u8, 106u8, 105u8, 97u8, 45u8, 121u8, 105u8,
+                110u8, 121u8, 105u8, 45u8, 109u8, 97u8, 111u8, 98u8, 105u8, 110u8, 103u8, 45u8,
+                99u8, 104u8, 101u8, 110u8, 100u8, 105u8, 110u8, 103u8, 45u8, 115u8, 105u8, 119u8,
+                117u8, 45u8, 119u8, 117u8, 106u8, 105u8, 45u8, 119u8, 101u8, 105u8, 103u8, 101u8,
+                110u8, 103u8, 45u8, 115u8, 104u8, 101u8, 110u8, 120u8, 105u8, 110u8, 45u8, 121u8,
+                111u8, 117u8, 114u8, 101u8, 110u8, 45u8, 120u8, 117u8, 103u8, 117u8, 105u8, 45u8,
+                104u8, 97u8, 105u8,
+            ])
+        },
+        abbr: unsafe {
+            ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                60u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 13u8, 0u8, 21u8, 0u8, 29u8, 0u8, 36u8,
+                0u8, 41u8, 0u8, 48u8, 0u8, 55u8, 0u8, 63u8, 0u8, 70u8, 0u8, 76u8, 0u8, 82u8, 0u8,
+                89u8, 0u8, 98u8, 0u8, 104u8, 0u8, 110u8, 0u8, 119u8, 0u8, 125u8, 0u8, 131u8, 0u8,
+                138u8, 0u8, 146u8, 0u8, 152u8, 0u8, 159u8, 0u8, 167u8, 0u8, 172u8, 0u8, 179u8, 0u8,
+                187u8, 0u8, 194u8, 0u8, 202u8, 0u8, 208u8, 0u8, 214u8, 0u8, 220u8, 0u8, 229u8, 0u8,
+                237u8, 0u8, 242u8, 0u8, 248u8, 0u8, 255u8, 0u8, 7u8, 1u8, 14u8, 1u8, 21u8, 1u8,
+                29u8, 1u8, 34u8, 1u8, 41u8, 1u8, 49u8, 1u8, 56u8, 1u8, 62u8, 1u8, 69u8, 1u8, 76u8,
+                1u8, 82u8, 1u8, 90u8, 1u8, 97u8, 1u8, 103u8, 1u8, 112u8, 1u8, 119u8, 1u8, 124u8,
+                1u8, 130u8, 1u8, 139u8, 1u8, 146u8, 1u8, 152u8, 1u8, 106u8, 105u8, 97u8, 45u8,
+                122u8, 105u8, 121u8, 105u8, 45u8, 99u8, 104u8, 111u8, 117u8, 98u8, 105u8, 110u8,
+                103u8, 45u8, 121u8, 105u8, 110u8, 100u8, 105u8, 110u8, 103u8, 45u8, 109u8, 97u8,
+                111u8, 119u8, 117u8, 45u8, 99u8, 104u8, 101u8, 110u8, 106u8, 105u8, 45u8, 115u8,
+                105u8, 103u8, 101u8, 110u8, 103u8, 45u8, 119u8, 117u8, 120u8, 105u8, 110u8, 45u8,
+                119u8, 101u8, 105u8, 114u8, 101u8, 110u8, 45u8, 115u8, 104u8, 101u8, 110u8, 103u8,
+                117u8, 105u8, 45u8, 121u8, 111u8, 117u8, 106u8, 105u8, 97u8, 45u8, 120u8, 117u8,
+                121u8, 105u8, 45u8, 104u8, 97u8, 105u8, 98u8, 105u8, 110u8, 103u8, 45u8, 122u8,
+                105u8, 100u8, 105u8, 110u8, 103u8, 45u8, 99u8, 104u8, 111u8, 117u8, 119u8, 117u8,
+                45u8, 121u8, 105u8, 110u8, 106u8, 105u8, 45u8, 109u8, 97u8, 111u8, 103u8, 101u8,
+                110u8, 103u8, 45u8, 99u8, 104u8, 101u8, 110u8, 120u8, 105u8, 110u8, 45u8, 115u8,
+                105u8, 114u8, 101u8, 110u8, 45u8, 119u8, 117u8, 103u8, 117u8, 105u8, 45u8, 119u8,
+                101u8, 105u8, 106u8, 105u8, 97u8, 45u8, 115u8, 104u8, 101u8, 110u8, 121u8, 105u8,
+                45u8, 121u8, 111u8, 117u8, 98u8, 105u8, 110u8, 103u8, 45u8, 120u8, 117u8, 100u8,
+                105u8, 110u8, 103u8, 45u8, 104u8, 97u8, 105u8, 119u8, 117u8, 45u8, 122u8, 105u8,
+                106u8, 105u8, 45u8, 99u8, 104u8, 111u8, 117u8, 103u8, 101u8, 110u8, 103u8, 45u8,
+                121u8, 105u8, 110u8, 120u8, 105u8, 110u8, 45u8, 109u8, 97u8, 111u8, 114u8, 101u8,
+                110u8, 45u8, 99u8, 104u8, 101u8, 110u8, 103u8, 117u8, 105u8, 45u8, 115u8, 105u8,
+                106u8, 105u8, 97u8, 45u8, 119u8, 117u8, 121u8, 105u8, 45u8, 119u8, 101u8, 105u8,
+                98u8, 105u8, 110u8, 103u8, 45u8, 115u8, 104u8, 101u8, 110u8, 100u8, 105u8, 110u8,
+                103u8, 45u8, 121u8, 111u8, 117u8, 119u8, 117u8, 45u8, 120u8, 117u8, 106u8, 105u8,
+                45u8, 104u8, 97u8, 105u8, 103u8, 101u8, 110u8, 103u8, 45u8, 122u8, 105u8, 120u8,
+                105u8, 110u8, 45u8, 99u8, 104u8, 111u8, 117u8, 114u8, 101u8, 110u8, 45u8, 121u8,
+                105u8, 110u8, 103u8, 117u8, 105u8, 45u8, 109u8, 97u8, 111u8, 106u8, 105u8, 97u8,
+                45u8, 99u8, 104u8, 101u8, 110u8, 121u8, 105u8, 45u8, 115u8, 105u8, 98u8, 105u8,
+                110u8, 103u8, 45u8, 119u8, 117u8, 100u8, 105u8, 110u8, 103u8, 45u8, 119u8, 101u8,
+                105u8, 119u8, 117u8, 45u8, 115u8, 104u8, 101u8, 110u8, 106u8, 105u8, 45u8, 121u8,
+                111u8, 117u8, 103u8, 101u8, 110u8, 103u8, 45u8, 120u8, 117u8, 120u8, 105u8, 110u8,
+                45u8, 104u8, 97u8, 105u8, 114u8, 101u8, 110u8, 45u8, 122u8, 105u8, 103u8, 117u8,
+                105u8, 45u8, 99u8, 104u8, 111u8, 117u8, 106u8, 105u8, 97u8, 45u8, 121u8, 105u8,
+                110u8, 121u8, 105u8, 45u8, 109u8, 97u8, 111u8, 98u8, 105u8, 110u8, 103u8, 45u8,
+                99u8, 104u8, 101u8, 110u8, 100u8, 105u8, 110u8, 103u8, 45u8, 115u8, 105u8, 119u8,
+                117u8, 45u8, 119u8, 117u8, 106u8, 105u8, 45u8, 119u8, 101u8, 105u8, 103u8, 101u8,
+                110u8, 103u8, 45u8, 115u8, 104u8, 101u8, 110u8, 120u8, 105u8, 110u8, 45u8, 121u8,
+                111u8, 117u8, 114u8, 101u8, 110u8, 45u8, 120u8, 117u8, 103u8, 117u8, 105u8, 45u8,
+                104u8, 97u8, 105u8,
+            ])
+        },
+        narrow: unsafe {
+            ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                60u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 13u8, 0u8, 21u8, 0u8, 29u8, 0u8, 36u8,
+                0u8, 41u8, 0u8, 48u8, 0u8, 55u8, 0u8, 63u8, 0u8, 70u8, 0u8, 76u8, 0u8, 82u8, 0u8,
+                89u8, 0u8, 98u8, 0u8, 104u8, 0u8, 110u8, 0u8, 119u8, 0u8, 125u8, 0u8, 131u8, 0u8,
+                138u8, 0u8, 146u8, 0u8, 152u8, 0u8, 159u8, 0u8, 167u8, 0u8, 172u8, 0u8, 179u8, 0u8,
+                187u8, 0u8, 194u8, 0u8, 202u8, 0u8, 208u8, 0u8, 214u8, 0u8, 220u8, 0u8, 229u8, 0u8,
+                237u8, 0u8, 242u8, 0u8, 248u8, 0u8, 255u8, 0u8, 7u8, 1u8, 14u8, 1u8, 21u8, 1u8,
+                29u8, 1u8, 34u8, 1u8, 41u8, 1u8, 49u8, 1u8, 56u8, 1u8, 62u8, 1u8, 69u8, 1u8, 76u8,
+                1u8, 82u8, 1u8, 90u8, 1u8, 97u8, 1u8, 103u8, 1u8, 112u8, 1u8, 119u8, 1u8, 124u8,
+                1u8, 130u8, 1u8, 139u8, 1u8, 146u8, 1u8, 152u8, 1u8, 106u8, 105u8, 97u8, 45u8,
+                122u8, 105u8, 121u8, 105u8, 45u8, 99u8, 104u8, 111u8, 117u8, 98u8, 105u8, 110u8,
+                103u8, 45u8, 121u8, 105u8, 110u8, 100u8, 105u8, 110u8, 103u8, 45u8, 109u8, 97u8,
+                111u8, 119u8, 117u8, 45u8, 99u8, 104u8, 101u8, 110u8, 106u8, 105u8, 45u8, 115u8,
+                105u8, 103u8, 101u8, 110u8, 103u8, 45u8, 119u8, 117u8, 120u8, 105u8, 110u8, 45u8,
+                119u8, 101u8, 105u8, 114u8, 101u8, 110u8, 45u8, 115u8, 104u8, 101u8, 110u8, 103u8,
+                117u8, 105u8, 45u8, 121u8, 111u8, 117u8, 106u8, 105u8, 97u8, 45u8, 120u8, 117u8,
+                121u8, 105u8, 45u8, 104u8, 97u8, 105u8, 98u8, 105u8, 110u8, 103u8, 45u8, 122u8,
+                105u8, 100u8, 105u8, 110u8, 103u8, 45u8, 99u8, 104u8, 111u8, 117u8, 119u8, 117u8,
+                45u8, 121u8, 105u8, 110u8, 106u8, 105u8, 45u8, 109u8, 97u8, 111u8, 103u8, 101u8,
+                110u8, 103u8, 45u8, 99u8, 104u8, 101u8, 110u8, 120u8, 105u8, 110u8, 45u8, 115u8,
+                105u8, 114u8, 101u8, 110u8, 45u8, 119u8, 117u8, 103u8, 117u8, 105u8, 45u8, 119u8,
+                101u8, 105u8, 106u8, 105u8, 97u8, 45u8, 115u8, 104u8, 101u8, 110u8, 121u8, 105u8,
+                45u8, 121u8, 111u8, 117u8, 98u8, 105u8, 110u8, 103u8, 45u8, 120u8, 117u8, 100u8,
+                105u8, 110u8, 103u8, 45u8, 104u8, 97u8, 105u8, 119u8, 117u8, 45u8, 122u8, 105u8,
+                106u8, 105u8, 45u8, 99u8, 104u8, 111u8, 117u8, 103u8, 101u8, 110u8, 103u8, 45u8,
+                121u8, 105u8, 110u8, 120u8, 105u8, 110u8, 45u8, 109u8, 97u8, 111u8, 114u8, 101u8,
+                110u8, 45u8, 99u8, 104u8, 101u8, 110u8, 103u8, 117u8, 105u8, 45u8, 115u8, 105u8,
+                106u8, 105u8, 97u8, 45u8, 119u8, 117u8, 121u8, 105u8, 45u8, 119u8, 101u8, 105u8,
+                98u8, 105u8, 110u8, 103u8, 45u8, 115u8, 104u8, 101u8, 110u8, 100u8, 105u8, 110u8,
+                103u8, 45u8, 121u8, 111u8, 117u8, 119u8, 117u8, 45u8, 120u8, 117u8, 106u8, 105u8,
+                45u8, 104u8, 97u8, 105u8, 103u8, 101u8, 110u8, 103u8, 45u8, 122u8, 105u8, 120u8,
+                105u8, 110u8, 45u8, 99u8, 104u8, 111u8, 117u8, 114u8, 101u8, 110u8, 45u8, 121u8,
+                105u8, 110u8, 103u8, 117u8, 105u8, 45u8, 109u8, 97u8, 111u8, 106u8, 105u8, 97u8,
+                45u8, 99u8, 104u8, 101u8, 110u8, 121u8, 105u8, 45u8, 115u8, 105u8, 98u8, 105u8,
+                110u8, 103u8, 45u8, 119u8, 117u8, 100u8, 105u8, 110u8, 103u8, 45u8, 119u8, 101u8,
+                105u8, 119u8, 117u8, 45u8, 115u8, 104u8, 101u8, 110u8, 106u8, 105u8, 45u8, 121u8,
+                111u8, 117u8, 103u8, 101u8, 110u8, 103u8, 45u8, 120u8, 117u8, 120u8, 105u8, 110u8,
+                45u8, 104u8, 97u8, 105u8, 114u8, 101u8, 110u8, 45u8, 122u8, 105u8, 103u8, 117u8,
+                105u8, 45u8, 99u8, 104u8, 111u8, 117u8, 106u8, 105u8, 97u8, 45u8, 121u8, 105u8,
+                110u8, 121u8, 105u8, 45u8, 109u8, 97u8, 111u8, 98u8, 105u8, 110u8, 103u8, 45u8,
+                99u8, 104u8, 101u8, 110u8, 100u8, 105u8, 110u8, 103u8, 45u8, 115u8, 105u8, 119u8,
+                117u8, 45u8, 119u8, 117u8, 106u8, 105u8, 45u8, 119u8, 101u8, 105u8, 103u8, 101u8,
+                110u8, 103u8, 45u8, 115u8, 104u8, 101u8, 110u8, 120u8, 105u8, 110u8, 45u8, 121u8,
+                111u8, 117u8, 114u8, 101u8, 110u8, 45u8, 120u8, 117u8, 103u8, 117u8, 105u8, 45u8,
+                104u8, 97u8, 105u8,
+            ])
+        },
+    },
+};
+static UND: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
+    months: ::icu_datetime::provider::calendar::months::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::months::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::months::SymbolsV1::Other(unsafe {
+                #[allow(unused_unsafe)]
+                ::zerovec::ZeroMap::from_parts_unchecked(
+                    unsafe {
+                        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                            77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 49u8, 76u8, 77u8, 48u8, 50u8, 0u8,
+                            77u8, 48u8, 50u8, 76u8, 77u8, 48u8, 51u8, 0u8, 77u8, 48u8, 51u8, 76u8,
+                            77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 52u8, 76u8, 77u8, 48u8, 53u8, 0u8,
+                            77u8, 48u8, 53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 54u8, 76u8,
+                            77u8, 48u8, 55u8, 0u8, 77u8, 48u8, 55u8, 76u8, 77u8, 48u8, 56u8, 0u8,
+                            77u8, 48u8, 56u8, 76u8, 77u8, 48u8, 57u8, 0u8, 77u8, 48u8, 57u8, 76u8,
+                            77u8, 49u8, 48u8, 0u8, 77u8, 49u8, 48u8, 76u8, 77u8, 49u8, 49u8, 0u8,
+                            77u8, 49u8, 49u8, 76u8, 77u8, 49u8, 50u8, 0u8, 77u8, 49u8, 50u8, 76u8,
+                        ])
+                    },
+                    unsafe {
+                        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                            24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 9u8, 0u8, 12u8, 0u8, 18u8,
+                            0u8, 21u8, 0u8, 27u8, 0u8, 30u8, 0u8, 36u8, 0u8, 39u8, 0u8, 45u8, 0u8,
+                            48u8, 0u8, 54u8, 0u8, 57u8, 0u8, 63u8, 0u8, 66u8, 0u8, 72u8, 0u8, 75u8,
+                            0u8, 81u8, 0u8, 84u8, 0u8, 90u8, 0u8, 93u8, 0u8, 99u8, 0u8, 102u8, 0u8,
+                            77u8, 48u8, 49u8, 77u8, 48u8, 49u8, 98u8, 105u8, 115u8, 77u8, 48u8,
+                            50u8, 77u8, 48u8, 50u8, 98u8, 105u8, 115u8, 77u8, 48u8, 51u8, 77u8,
+                            48u8, 51u8, 98u8, 105u8, 115u8, 77u8, 48u8, 52u8, 77u8, 48u8, 52u8,
+                            98u8, 105u8, 115u8, 77u8, 48u8, 53u8, 77u8, 48u8, 53u8, 98u8, 105u8,
+                            115u8, 77u8, 48u8, 54u8, 77u8, 48u8, 54u8, 98u8, 105u8, 115u8, 77u8,
+                            48u8, 55u8, 77u8, 48u8, 55u8, 98u8, 105u8, 115u8, 77u8, 48u8, 56u8,
+                            77u8, 48u8, 56u8, 98u8, 105u8, 115u8, 77u8, 48u8, 57u8, 77u8, 48u8,
+                            57u8, 98u8, 105u8, 115u8, 77u8, 49u8, 48u8, 77u8, 49u8, 48u8, 98u8,
+                            105u8, 115u8, 77u8, 49u8, 49u8, 77u8, 49u8, 49u8, 98u8, 105u8, 115u8,
+                            77u8, 49u8, 50u8, 77u8, 49u8, 50u8, 98u8, 105u8, 115u8,
+                        ])
+                    },
+                )
+            }),
+            narrow: ::icu_datetime::provider::calendar::months::SymbolsV1::Other(unsafe {
+                #[allow(unused_unsafe)]
+                ::zerovec::ZeroMap::from_parts_unchecked(
+                    unsafe {
+                        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                            77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 49u8, 76u8, 77u8, 48u8, 50u8, 0u8,
+                            77u8, 48u8, 50u8, 76u8, 77u8, 48u8, 51u8, 0u8, 77u8, 48u8, 51u8, 76u8,
+                            77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 52u8, 76u8, 77u8, 48u8, 53u8, 0u8,
+                            77u8, 48u8, 53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 54u8, 76u8,
+                            77u8, 48u8, 55u8, 0u8, 77u8, 48u8, 55u8, 76u8, 77u8, 48u8, 56u8, 0u8,
+                            77u8, 48u8, 56u8, 76u8, 77u8, 48u8, 57u8, 0u8, 77u8, 48u8, 57u8, 76u8,
+                            77u8, 49u8, 48u8, 0u8, 77u8, 49u8, 48u8, 76u8, 77u8, 49u8, 49u8, 0u8,
+                            77u8, 49u8, 49u8, 76u8, 77u8, 49u8, 50u8, 0u8, 77u8, 49u8, 50u8, 76u8,
+                        ])
+                    },
+                    unsafe {
+                        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                            24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 3u8, 0u8, 4u8, 0u8, 6u8, 0u8,
+                            7u8, 0u8, 9u8, 0u8, 10u8, 0u8, 12u8, 0u8, 13u8, 0u8, 15u8, 0u8, 16u8,
+                            0u8, 18u8, 0u8, 19u8, 0u8, 21u8, 0u8, 22u8, 0u8, 24u8, 0u8, 25u8, 0u8,
+                            27u8, 0u8, 29u8, 0u8, 32u8, 0u8, 34u8, 0u8, 37u8, 0u8, 39u8, 0u8, 49u8,
+                            49u8, 98u8, 50u8, 50u8, 98u8, 51u8, 51u8, 98u8, 52u8, 52u8, 98u8, 53u8,
+                            53u8, 98u8, 54u8, 54u8, 98u8, 55u8, 55u8, 98u8, 56u8, 56u8, 98u8, 57u8,
+                            57u8, 98u8, 49u8, 48u8, 49u8, 48u8, 98u8, 49u8, 49u8, 49u8, 49u8, 98u8,
+                            49u8, 50u8, 49u8, 50u8, 98u8,
+                        ])
+                    },
+                )
+            }),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::months::SymbolsV1::Other(unsafe {
+                #[allow(unused_unsafe)]
+                ::zerovec::ZeroMap::from_parts_unchecked(
+                    unsafe {
+                        ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                            77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 49u8, 76u8, 77u8, 48u8, 50u8, 0u8,
+                            77u8, 48u8, 50u8, 76u8, 77u8, 48u8, 51u8, 0u8, 77u8, 48u8, 51u8, 76u8,
+                            77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 52u8, 76u8, 77u8, 48u8, 53u8, 0u8,
+                            77u8, 48u8, 53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 54u8, 76u8,
+                            77u8, 48u8, 55u8, 0u8, 77u8, 48u8, 55u8, 76u8, 77u8, 48u8, 56u8, 0u8,
+                            77u8, 48u8, 56u8, 76u8, 77u8, 48u8, 57u8, 0u8, 77u8, 48u8, 57u8, 76u8,
+                            77u8, 49u8, 48u8, 0u8, 77u8, 49u8, 48u8, 76u8, 77u8, 49u8, 49u8, 0u8,
+                            77u8, 49u8, 49u8, 76u8, 77u8, 49u8, 50u8, 0u8, 77u8, 49u8, 50u8, 76u8,
+                        ])
+                    },
+                    unsafe {
+                        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                            24u8, 0u8, 0u8, 0u8, 0u8, 0u8, 3u8, 0u8, 9u8, 0u8, 12u8, 0u8, 18u8,
+                            0u8, 21u8, 0u8, 27u8, 0u8, 30u8, 0u8, 36u8, 0u8, 39u8, 0u8, 45u8, 0u8,
+                            48u8, 0u8, 54u8, 0u8, 57u8, 0u8, 63u8, 0u8, 66u8, 0u8, 72u8, 0u8, 75u8,
+                            0u8, 81u8, 0u8, 84u8, 0u8, 90u8, 0u8, 93u8, 0u8, 99u8, 0u8, 102u8, 0u8,
+                            77u8, 48u8, 49u8, 77u8, 48u8, 49u8, 98u8, 105u8, 115u8, 77u8, 48u8,
+                            50u8, 77u8, 48u8, 50u8, 98u8, 105u8, 115u8, 77u8, 48u8, 51u8, 77u8,
+                            48u8, 51u8, 98u8, 105u8, 115u8, 77u8, 48u8, 52u8, 77u8, 48u8, 52u8,
+                            98u8, 105u8, 115u8, 77u8, 48u8, 53u8, 77u8, 48u8, 53u8, 98u8, 105u8,
+                            115u8, 77u8, 48u8, 54u8, 77u8, 48u8, 54u8, 98u8, 105u8, 115u8, 77u8,
+                            48u8, 55u8, 77u8, 48u8, 55u8, 98u8, 105u8, 115u8, 77u8, 48u8, 56u8,
+                            77u8, 48u8, 56u8, 98u8, 105u8, 115u8, 77u8, 48u8, 57u8, 77u8, 48u8,
+                            57u8, 98u8, 105u8, 115u8, 77u8, 49u8, 48u8, 77u8, 49u8, 48u8, 98u8,
+                            105u8, 115u8, 77u8, 49u8, 49u8, 77u8, 49u8, 49u8, 98u8, 105u8, 115u8,
+                            77u8, 49u8, 50u8, 77u8, 49u8, 50u8, 98u8, 105u8, 115u8,
+                        ])
+                    },
+                )
+            }),
+        },
+        stand_alone: None,
+    },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Q1"),
+                alloc::borrow::Cow::Borrowed("Q2"),
+                alloc::borrow::Cow::Borrowed("Q3"),
+                alloc::borrow::Cow::Borrowed("Q4"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1"),
+                alloc::borrow::Cow::Borrowed("2"),
+                alloc::borrow::Cow::Borrowed("3"),
+                alloc::borrow::Cow::Borrowed("4"),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Q1"),
+                alloc::borrow::Cow::Borrowed("Q2"),
+                alloc::borrow::Cow::Borrowed("Q3"),
+                alloc::borrow::Cow::Borrowed("Q4"),
+            ]),
+        },
+        stand_alone: None,
+    },
+    weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Sun"),
+                alloc::borrow::Cow::Borrowed("Mon"),
+                alloc::borrow::Cow::Borrowed("Tue"),
+                alloc::borrow::Cow::Borrowed("Wed"),
+                alloc::borrow::Cow::Borrowed("Thu"),
+                alloc::borrow::Cow::Borrowed("Fri"),
+                alloc::borrow::Cow::Borrowed("Sat"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("S"),
+                alloc::borrow::Cow::Borrowed("M"),
+                alloc::borrow::Cow::Borrowed("T"),
+                alloc::borrow::Cow::Borrowed("W"),
+                alloc::borrow::Cow::Borrowed("T"),
+                alloc::borrow::Cow::Borrowed("F"),
+                alloc::borrow::Cow::Borrowed("S"),
+            ]),
+            short: Some(::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Sun"),
+                alloc::borrow::Cow::Borrowed("Mon"),
+                alloc::borrow::Cow::Borrowed("Tue"),
+                alloc::borrow::Cow::Borrowed("Wed"),
+                alloc::borrow::Cow::Borrowed("Thu"),
+                alloc::borrow::Cow::Borrowed("Fri"),
+                alloc::borrow::Cow::Borrowed("Sat"),
+            ])),
+            wide: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Sun"),
+                alloc::borrow::Cow::Borrowed("Mon"),
+                alloc::borrow::Cow::Borrowed("Tue"),
+                alloc::borrow::Cow::Borrowed("Wed"),
+                alloc::borrow::Cow::Borrowed("Thu"),
+                alloc::borrow::Cow::Borrowed("Fri"),
+                alloc::borrow::Cow::Borrowed("Sat"),
+            ]),
+        },
+        stand_alone: None,
+    },
+    eras: ::icu_datetime::provider::calendar::Eras {
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+        abbr: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+        narrow: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+                unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+            )
+        },
+    },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe {
+            ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                60u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 13u8, 0u8, 21u8, 0u8, 29u8, 0u8, 36u8,
+                0u8, 41u8, 0u8, 48u8, 0u8, 55u8, 0u8, 63u8, 0u8, 70u8, 0u8, 76u8, 0u8, 82u8, 0u8,
+                89u8, 0u8, 98u8, 0u8, 104u8, 0u8, 110u8, 0u8, 119u8, 0u8, 125u8, 0u8, 131u8, 0u8,
+                138u8, 0u8, 146u8, 0u8, 152u8, 0u8, 159u8, 0u8, 167u8, 0u8, 172u8, 0u8, 179u8, 0u8,
+                187u8, 0u8, 194u8, 0u8, 202u8, 0u8, 208u8, 0u8, 214u8, 0u8, 220u8, 0u8, 229u8, 0u8,
+                237u8, 0u8, 242u8, 0u8, 248u8, 0u8, 255u8, 0u8, 7u8, 1u8, 14u8, 1u8, 21u8, 1u8,
+                29u8, 1u8, 34u8, 1u8, 41u8, 1u8, 49u8, 1u8, 56u8, 1u8, 62u8, 1u8, 69u8, 1u8, 76u8,
+                1u8, 82u8, 1u8, 90u8, 1u8, 97u8, 1u8, 103u8, 1u8, 112u8, 1u8, 119u8, 1u8, 124u8,
+                1u8, 130u8, 1u8, 139u8, 1u8, 146u8, 1u8, 152u8, 1u8, 106u8, 105u8, 97u8, 45u8,
+                122u8, 105u8, 121u8, 105u8, 45u8, 99u8, 104u8, 111u8, 117u8, 98u8, 105u8, 110u8,
+                103u8, 45u8, 121u8, 105u8, 110u8, 100u8, 105u8, 110u8, 103u8, 45u8, 109u8, 97u8,
+                111u8, 119u8, 117u8, 45u8, 99u8, 104u8, 101u8, 110u8, 106u8, 105u8, 45u8, 115u8,
+                105u8, 103u8, 101u8, 110u8, 103u8, 45u8, 119u8, 117u8, 120u8, 105u8, 110u8, 45u8,
+                119u8, 101u8, 105u8, 114u8, 101u8, 110u8, 45u8, 115u8, 104u8, 101u8, 110u8, 103u8,
+                117u8, 105u8, 45u8, 121u8, 111u8, 117u8, 106u8, 105u8, 97u8, 45u8, 120u8, 117u8,
+                121u8, 105u8, 45u8, 104u8, 97u8, 105u8, 98u8, 105u8, 110u8, 103u8, 45u8, 122u8,
+                105u8, 100u8, 105u8, 110u8, 103u8, 45u8, 99u8, 104u8, 111u8, 117u8, 119u8, 117u8,
+                45u8, 121u8, 105u8, 110u8, 106u8, 105u8, 45u8, 109u8, 97u8, 111u8, 103u8, 101u8,
+                110u8, 103u8, 45u8, 99u8, 104u8, 101u8, 110u8, 120u8, 105u8, 110u8, 45u8, 115u8,
+                105u8, 114u8, 101u8, 110u8, 45u8, 119u8, 117u8, 103u8, 117u8, 105u8, 45u8, 119u8,
+                101u8, 105u8, 106u8, 105u8, 97u8, 45u8, 115u8, 104u8, 101u8, 110u8, 121u8, 105u8,
+                45u8, 121u8, 111u8, 117u8, 98u8, 105u8, 110u8, 103u8, 45u8, 120u8, 117u8, 100u8,
+                105u8, 110u8, 103u8, 45u8, 104u8, 97u8, 105u8, 119u8, 117u8, 45u8, 122u8, 105u8,
+                106u8, 105u8, 45u8, 99u8, 104u8, 111u8, 117u8, 103u8, 101u8, 110u8, 103u8, 45u8,
+                121u8, 105u8, 110u8, 120u8, 105u8, 110u8, 45u8, 109u8, 97u8, 111u8, 114u8, 101u8,
+                110u8, 45u8, 99u8, 104u8, 101u8, 110u8, 103u8, 117u8, 105u8, 45u8, 115u8, 105u8,
+                106u8, 105u8, 97u8, 45u8, 119u8, 117u8, 121u8, 105u8, 45u8, 119u8, 101u8, 105u8,
+                98u8, 105u8, 110u8, 103u8, 45u8, 115u8, 104u8, 101u8, 110u8, 100u8, 105u8, 110u8,
+                103u8, 45u8, 121u8, 111u8, 117u8, 119u8, 117u8, 45u8, 120u8, 117u8, 106u8, 105u8,
+                45u8, 104u8, 97u8, 105u8, 103u8, 101u8, 110u8, 103u8, 45u8, 122u8, 105u8, 120u8,
+                105u8, 110u8, 45u8, 99u8, 104u8, 111u8, 117u8, 114u8, 101u8, 110u8, 45u8, 121u8,
+                105u8, 110u8, 103u8, 117u8, 105u8, 45u8, 109u8, 97u8, 111u8, 106u8, 105u8, 97u8,
+                45u8, 99u8, 104u8, 101u8, 110u8, 121u8, 105u8, 45u8, 115u8, 105u8, 98u8, 105u8,
+                110u8, 103u8, 45u8, 119u8, 117u8, 100u8, 105u8, 110u8, 103u8, 45u8, 119u8, 101u8,
+                105u8, 119u8, 117u8, 45u8, 115u8, 104u8, 101u8, 110u8, 106u8, 105u8, 45u8, 121u8,
+                111u8, 117u8, 103u8, 101u8, 110u8, 103u8, 45u8, 120u8, 117u8, 120u8, 105u8, 110u8,
+                45u8, 104u8, 97u8, 105u8, 114u8, 101u8, 110u8, 45u8, 122u8, 105u8, 103u8, 117u8,
+                105u8, 45u8, 99u8, 104u8, 111u8, 117u8, 106u8, 105u8, 97u8, 45u8, 121u8, 105u8,
+                110u8, 121u8, 105u8, 45u8, 109u8, 97u8, 111u8, 98u8, 105u8, 110u8, 103u8, 45u8,
+                99u8, 104u8, 101u8, 110u8, 100u8, 105u8, 110u8, 103u8, 45u8, 115u8, 105u8, 119u8,
+                117u8, 45u8, 119u8, 117u8, 106u8, 105u8, 45u8, 119u8, 101u8, 105u8, 103u8, 101u8,
+                110u8, 103u8, 45u8, 115u8, 104u8, 101u8, 110u8, 120u8, 105u8, 110u8, 45u8, 121u8,
+                111u8, 117u8, 114u8, 101u8, 110u8, 45u8, 120u8, 117u8, 103u8, 117u8, 105u8, 45u8,
+                104u8, 97u8, 105u8,
+            ])
+        },
+        abbr: unsafe {
+            ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                60u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 13u8, 0u8, 21u8, 0u8, 29u8, 0u8, 36u8,
+                0u8, 41u8, 0u8, 48u8, 0u8, 55u8, 0u8, 63u8, 0u8, 70u8, 0u8, 76u8, 0u8, 82u8, 0u8,
+                89u8, 0u8, 98u8, 0u8, 104u8, 0u8, 110u8, 0u8, 119u8, 0u8, 125u8, 0u8, 131u8, 0u8,
+                138u8, 0u8, 146u8, 0u8, 152u8, 0u8, 159u8, 0u8, 167u8, 0u8, 172u8, 0u8, 179u8, 0u8,
+                187u8, 0u8, 194u8, 0u8, 202u8, 0u8, 208u8, 0u8, 214u8, 0u8, 220u8, 0u8, 229u8, 0u8,
+                237u8, 0u8, 242u8, 0u8, 248u8, 0u8, 255u8, 0u8, 7u8, 1u8, 14u8, 1u8, 21u8, 1u8,
+                29u8, 1u8, 34u8, 1u8, 41u8, 1u8, 49u8, 1u8, 56u8, 1u8, 62u8, 1u8, 69u8, 1u8, 76u8,
+                1u8, 82u8, 1u8, 90u8, 1u8, 97u8, 1u8, 103u8, 1u8, 112u8, 1u8, 119u8, 1u8, 124u8,
+                1u8, 130u8, 1u8, 139u8, 1u8, 146u8, 1u8, 152u8, 1u8, 106u8, 105u8, 97u8, 45u8,
+                122u8, 105u8, 121u8, 105u8, 45u8, 99u8, 104u8, 111u8, 117u8, 98u8, 105u8, 110u8,
+                103u8, 45u8, 121u8, 105u8, 110u8, 100u8, 105u8, 110u8, 103u8, 45u8, 109u8, 97u8,
+                111u8, 119u8, 117u8, 45u8, 99u8, 104u8, 101u8, 110u8, 106u8, 105u8, 45u8, 115u8,
+                105u8, 103u8, 101u8, 110u8, 103u8, 45u8, 119u8, 117u8, 120u8, 105u8, 110u8, 45u8,
+                119u8, 101u8, 105u8, 114u8, 101u8, 110u8, 45u8, 115u8, 104u8, 101u8, 110u8, 103u8,
+                117u8, 105u8, 45u8, 121u8, 111u8, 117u8, 106u8, 105u8, 97u8, 45u8, 120u8, 117u8,
+                121u8, 105u8, 45u8, 104u8, 97u8, 105u8, 98u8, 105u8, 110u8, 103u8, 45u8, 122u8,
+                105u8, 100u8, 105u8, 110u8, 103u8, 45u8, 99u8, 104u8, 111u8, 117u8, 119u8, 117u8,
+                45u8, 121u8, 105u8, 110u8, 106u8, 105u8, 45u8, 109u8, 97u8, 111u8, 103u8, 101u8,
+                110u8, 103u8, 45u8, 99u8, 104u8, 101u8, 110u8, 120u8, 105u8, 110u8, 45u8, 115u8,
+                105u8, 114u8, 101u8, 110u8, 45u8, 119u8, 117u8, 103u8, 117u8, 105u8, 45u8, 119u8,
+                101u8, 105u8, 106u8, 105u8, 97u8, 45u8, 115u8, 104u8, 101u8, 110u8, 121u8, 105u8,
+                45u8, 121u8, 111u8, 117u8, 98u8, 105u8, 110u8, 103u8, 45u8, 120u8, 117u8, 100u8,
+                105u8, 110u8, 103u8, 45u8, 104u8, 97u8, 105u8, 119u8, 117u8, 45u8, 122u8, 105u8,
+                106u8, 105u8, 45u8, 99u8, 104u8, 111u8, 117u8, 103u8, 101u8, 110u8, 103u8, 45u8,
+                121u8, 105u8, 110u8, 120u8, 105u8, 110u8, 45u8, 109u8, 97u8, 111u8, 114u8, 101u8,
+                110u8, 45u8, 99u8, 104u8, 101u8, 110u8, 103u8, 117u8, 105u8, 45u8, 115u8, 105u8,
+                106u8, 105u8, 97u8, 45u8, 119u8, 117u8, 121u8, 105u8, 45u8, 119u8, 101u8, 105u8,
+                98u8, 105u8, 110u8, 103u8, 45u8, 115u8, 104u8, 101u8, 110u8, 100u8, 105u8, 110u8,
+                103u8, 45u8, 121u8, 111u8, 117u8, 119u8, 117u8, 45u8, 120u8, 117u8, 106u8, 105u8,
+                45u8, 104u8, 97u8, 105u8, 103u8, 101u8, 110u8, 103u8, 45u8, 122u8, 105u8, 120u8,
+                105u8, 110u8, 45u8, 99u8, 104u8, 111u8, 117u8, 114u8, 101u8, 110u8, 45u8, 121u8,
+                105u8, 110u8, 103u8, 117u8, 105u8, 45u8, 109u8, 97u8, 111u8, 106u8, 105u8, 97u8,
+                45u8, 99u8, 104u8, 101u8, 110u8, 121u8, 105u8, 45u8, 115u8, 105u8, 98u8, 105u8,
+                110u8, 103u8, 45u8, 119u8, 117u8, 100u8, 105u8, 110u8, 103u8, 45u8, 119u8, 101u8,
+                105u8, 119u8, 117u8, 45u8, 115u8, 104u8, 101u8, 110u8, 106u8, 105u8, 45u8, 121u8,
+                111u8, 117u8, 103u8, 101u8, 110u8, 103u8, 45u8, 120u8, 117u8, 120u8, 105u8, 110u8,
+                45u8, 104u8, 97u8, 105u8, 114u8, 101u8, 110u8, 45u8, 122u8, 105u8, 103u8, 117u8,
+                105u8, 45u8, 99u8, 104u8, 111u8, 117u8, 106u8, 105u8, 97u8, 45u8, 121u8, 105u8,
+                110u8, 121u8, 105u8, 45u8, 109u8, 97u8, 111u8, 98u8, 105u8, 110u8, 103u8, 45u8,
+                99u8, 104u8, 101u8, 110u8, 100u8, 105u8, 110u8, 103u8, 45u8, 115u8, 105u8, 119u8,
+                117u8, 45u8, 119u8, 117u8, 106u8, 105u8, 45u8, 119u8, 101u8, 105u8, 103u8, 101u8,
+                110u8, 103u8, 45u8, 115u8, 104u8, 101u8, 110u8, 120u8, 105u8, 110u8, 45u8, 121u8,
+                111u8, 117u8, 114u8, 101u8, 110u8, 45u8, 120u8, 117u8, 103u8, 117u8, 105u8, 45u8,
+                104u8, 97u8, 105u8,
+            ])
+        },
+        narrow: unsafe {
+            ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                60u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 13u8, 0u8, 21u8, 0u8, 29u8, 0u8, 36u8,
+                0u8, 41u8, 0u8, 48u8, 0u8, 55u8, 0u8, 63u8, 0u8, 70u8, 0u8, 76u8, 0u8, 82u8, 0u8,
+                89u8, 0u8, 98u8, 0u8, 104u8, 0u8, 110u8, 0u8, 119u8, 0u8, 125u8, 0u8, 131u8, 0u8,
+                138u8, 0u8, 146u8, 0u8, 152u8, 0u8, 159u8, 0u8, 167u8, 0u8, 172u8, 0u8, 179u8, 0u8,
+                187u8, 0u8, 194u8, 0u8, 202u8, 0u8, 208u8, 0u8, 214u8, 0u8, 220u8, 0u8, 229u8, 0u8,
+                237u8, 0u8, 242u8, 0u8, 248u8, 0u8, 255u8, 0u8, 7u8, 1u8, 14u8, 1u8, 21u8, 1u8,
+                29u8, 1u8, 34u8, 1u8, 41u8, 1u8, 49u8, 1u8, 56u8, 1u8, 62u8, 1u8, 69u8, 1u8, 76u8,
+                1u8, 82u8, 1u8, 90u8, 1u8, 97u8, 1u8, 103u8, 1u8, 112u8, 1u8, 119u8, 1u8, 124u8,
+                1u8, 130u8, 1u8, 139u8, 1u8, 146u8, 1u8, 152u8, 1u8, 106u8, 105u8, 97u8, 45u8,
+                122u8, 105u8, 121u8, 105u8, 45u8, 99u8, 104u8, 111u8, 117u8, 98u8, 105u8, 110u8,
+                103u8, 45u8, 121u8, 105u8, 110u8, 100u8, 105u8, 110u8, 103u8, 45u8, 109u8, 97u8,
+                111u8, 119u8, 117u8, 45u8, 99u8, 104u8, 101u8, 110u8, 106u8, 105u8, 45u8, 115u8,
+                105u8, 103u8, 101u8, 110u8, 103u8, 45u8, 119u8, 117u8, 120u8, 105u8, 110u8, 45u8,
+                119u8, 101u8, 105u8, 114u8, 101u8, 110u8, 45u8, 115u8, 104u8, 101u8, 110u8, 103u8,
+                117u8, 105u8, 45u8, 121u8, 111u8, 117u8, 106u8, 105u8, 97u8, 45u8, 120u8, 117u8,
+                121u8, 105u8, 45u8, 104u8, 97u8, 105u8, 98u8, 105u8, 110u8, 103u8, 45u8, 122u8,
+                105u8, 100u8, 105u8, 110u8, 103u8, 45u8, 99u8, 104u8, 111u8, 117u8, 119u8, 117u8,
+                45u8, 121u8, 105u8, 110u8, 106u8, 105u8, 45u8, 109u8, 97u8, 111u8, 103u8, 101u8,
+                110u8, 103u8, 45u8, 99u8, 104u8, 101u8, 110u8, 120u8, 105u8, 110u8, 45u8, 115u8,
+                105u8, 114u8, 101u8, 110u8, 45u8, 119u8, 117u8, 103u8, 117u8, 105u8, 45u8, 119u8,
+                101u8, 105u8, 106u8, 105u8, 97u8, 45u8, 115u8, 104u8, 101u8, 110u8, 121u8, 105u8,
+                45u8, 121u8, 111u8, 117u8, 98u8, 105u8, 110u8, 103u8, 45u8, 120u8, 117u8, 100u8,
+                105u8, 110u8, 103u8, 45u8, 104u8, 97u8, 105u8, 119u8, 117u8, 45u8, 122u8, 105u8,
+                106u8, 105u8, 45u8, 99u8, 104u8, 111u8, 117u8, 103u8, 101u8, 110u8, 103u8, 45u8,
+                121u8, 105u8, 110u8, 120u8, 105u8, 110u8, 45u8, 109u8, 97u8, 111u8, 114u8, 101u8,
+                110u8, 45u8, 99u8, 104u8, 101u8, 110u8, 103u8, 117u8, 105u8, 45u8, 115u8, 105u8,
+                106u8, 105u8, 97u8, 45u8, 119u8, 117u8, 121u8, 105u8, 45u8, 119u8, 101u8, 105u8,
+                98u8, 105u8, 110u8, 103u8, 45u8, 115u8, 104u8, 101u8, 110u8, 100u8, 105u8, 110u8,
+                103u8, 45u8, 121u8, 111u8, 117u8, 119u8, 117u8, 45u8, 120u8, 117u8, 106u8, 105u8,
+                45u8, 104u8, 97u8, 105u8, 103u8, 101u8, 110u8, 103u8, 45u8, 122u8, 105u8, 120u8,
+                105u8, 110u8, 45u8, 99u8, 104u8, 111u8, 117u8, 114u8, 101u8, 110u8, 45u8, 121u8,
+                105u8, 110u8, 103u8, 117u8, 105u8, 45u8, 109u8, 97u8, 111u8, 106u8, 105u8, 97u8,
+                45u8, 99u8, 104u8, 101u8, 110u8, 121u8, 105u8, 45u8, 115u8, 105u8, 98u8, 105u8,
+                110u8, 103u8, 45u8, 119u8, 117u8, 100u8, 105u8, 110u8, 103u8, 45u8, 119u8, 101u8,
+                105u8, 119u8, 117u8, 45u8, 115u8, 104u8, 101u8, 110u8, 106u8, 105u8, 45u8, 121u8,
+                111u8, 117u8, 103u8, 101u8, 110u8, 103u8, 45u8, 120u8, 117u8, 120u8, 105u8, 110u8,
+                45u8, 104u8, 97u8, 105u8, 114u8, 101u8, 110u8, 45u8, 122u8, 105u8, 103u8, 117u8,
+                105u8, 45u8, 99u8, 104u8, 111u8, 117u8, 106u8, 105u8, 97u8, 45u8, 121u8, 105u8,
+                110u8, 121u8, 105u8, 45u8, 109u8, 97u8, 111u8, 98u8, 105u8, 110u8, 103u8, 45u8,
+                99u8, 104u8, 101u8, 110u8, 100u8, 105u8, 110u8, 103u8, 45u8, 115u8, 105u8, 119u8,
+                117u8, 45u8, 119u8, 117u8, 106u8, 105u8, 45u8, 119u8, 101u8, 105u8, 103u8, 101u8,
+                110u8, 103u8, 45u8, 115u8, 104u8, 101u8, 110u8, 120u8, 105u8, 110u8, 45u8, 121u8,
+                111u8, 117u8, 114u8, 101u8, 110u8, 45u8, 120u8, 117u8, 103u8, 117u8, 105u8, 45u8,
+                104u8, 97u8, 105u8,
+            ])
+        },
+    },
+};
@@ -115,6 +115,30 @@ static AR_AR_EG: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV
         },
         stand_alone: None,
     },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("الربع الأول"),
+                alloc::borrow::Cow::Borrowed("الربع الثاني"),
+                alloc::borrow::Cow::Borrowed("الربع الثالث"),
+                alloc::borrow::Cow::Borrowed("الربع الرابع"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("١"),
+                alloc::borrow::Cow::Borrowed("٢"),
+                alloc::borrow::Cow::Borrowed("٣"),
+                alloc::borrow::Cow::Borrowed("٤"),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("الربع الأول"),
+                alloc::borrow::Cow::Borrowed("الربع الثاني"),
+                alloc::borrow::Cow::Borrowed("الربع الثالث"),
+                alloc::borrow::Cow::Borrowed("الربع الرابع"),
+            ]),
+        },
+        stand_alone: None,
+    },
     weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
         format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
@@ -212,6 +236,11 @@ static AR_AR_EG: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV
             )
         },
     },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
 };
 static BN: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
     months: ::icu_datetime::provider::calendar::months::ContextsV1 {
@@ -338,6 +367,42 @@ static BN: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
         },
         stand_alone: None,
     },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("ত\u{9cd}রৈম\u{9be}সিক"),
+                alloc::borrow::Cow::Borrowed("দ\u{9cd}বিতীয\u{9bc} ত\u{9cd}রৈম\u{9be}সিক"),
+                alloc::borrow::Cow::Borrowed("ত\u{9c3}তীয\u{9bc} ত\u{9cd}রৈম\u{9be}সিক"),
+                alloc::borrow::Cow::Borrowed("চত\u{9c1}র\u{9cd}থ ত\u{9cd}রৈম\u{9be}সিক"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("১"),
+                alloc::borrow::Cow::Borrowed("২"),
+                alloc::borrow::Cow::Borrowed("৩"),
+                alloc::borrow::Cow::Borrowed("৪"),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("ত\u{9cd}রৈম\u{9be}সিক"),
+                alloc::borrow::Cow::Borrowed("দ\u{9cd}বিতীয\u{9bc} ত\u{9cd}রৈম\u{9be}সিক"),
+                alloc::borrow::Cow::Borrowed("ত\u{9c3}তীয\u{9bc} ত\u{9cd}রৈম\u{9be}সিক"),
+                alloc::borrow::Cow::Borrowed("চত\u{9c1}র\u{9cd}থ ত\u{9cd}রৈম\u{9be}সিক"),
+            ]),
+        },
+        stand_alone: Some(
+            ::icu_datetime::provider::calendar::quarters::StandAloneWidthsV1 {
+                abbreviated: Some(::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                    alloc::borrow::Cow::Borrowed("Q1"),
+                    alloc::borrow::Cow::Borrowed("Q2"),
+                    alloc::borrow::Cow::Borrowed("Q3"),
+                    alloc::borrow::Cow::Borrowed("Q4"),
+                ])),
+                narrow: None,
+                short: None,
+                wide: None,
+            },
+        ),
+    },
     weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
         format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
@@ -441,8 +506,14 @@ static BN: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
             )
         },
     },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
 };
-static CCP: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
+static CCP : & DataStruct = & :: icu_datetime :: provider :: calendar :: DateSymbolsV1 { months : :: icu_datetime :: provider :: calendar :: months :: ContextsV1 { format : :: icu_datetime :: provider :: calendar :: months :: FormatWidthsV1 { abbreviated : :: icu_datetime :: provider :: calendar :: months :: SymbolsV1 :: Other (unsafe { # [allow (unused_unsafe)] :: zerovec :: ZeroMap :: from_parts_unchecked (unsafe { :: zerovec :: ZeroVec :: from_bytes_unchecked (& [77u8 , 48u8 , 49u8 , 0u8 , 77u8 , 48u8 , 50u8 , 0u8 , 77u8 , 48u8 , 51u8 , 0u8 , 77u8 , 48u8 , 52u8 , 0u8 , 77u8 , 48u8 , 53u8 , 0u8 , 77u8 , 48u8 , 54u8 , 0u8 , 77u8 , 48u8 , 55u8 , 0u8 , 77u8 , 48u8 , 56u8 , 0u8 , 77u8 , 48u8 , 57u8 , 0u8 , 77u8 , 49u8 , 48u8 , 0u8 , 77u8 , 49u8 , 49u8 , 0u8 , 77u8 , 49u8 , 50u8 , 0u8 , 77u8 , 49u8 , 51u8 , 0u8]) } , unsafe { :: zerovec :: VarZeroVec :: from_bytes_unchecked (& [13u8 , 0u8 , 0u8 , 0u8 , 0u8 , 0u8 , 8u8 , 0u8 , 14u8 , 0u8 , 19u8 , 0u8 , 25u8 , 0u8 , 28u8 , 0u8 , 35u8 , 0u8 , 42u8 , 0u8 , 48u8 , 0u8 , 54u8 , 0u8 , 58u8 , 0u8 , 63u8 , 0u8 , 70u8 , 0u8 , 77u8 , 101u8 , 115u8 , 107u8 , 101u8 , 114u8 , 101u8 , 109u8 , 84u8 , 101u8 , 107u8 , 101u8 , 109u8 , 116u8 , 72u8 , 101u8 , 100u8 , 97u8 , 114u8 , 84u8 , 97u8 , 104u8 , 115u8 , 97u8 , 115u8 , 84u8 , 101u8 , 114u8 , 89u8 , 101u8 , 107u8 , 97u8 , 116u8 , 105u8 , 116u8 , 77u8 , 101u8 , 103u8 , 97u8 , 98u8 , 105u8 , 116u8 , 77u8 , 105u8 , 97u8 , 122u8 , 105u8 , 97u8 , 71u8 , 101u8 , 110u8 , 98u8 , 111u8 , 116u8 , 83u8 , 101u8 , 110u8 , 101u8 , 72u8 , 97u8 , 109u8 , 108u8 , 101u8 , 78u8 , 101u8 , 104u8 , 97u8 , 115u8 , 115u8 , 101u8 , 80u8 , 97u8 , 103u8 , 117u8 , 109u8 , 101u8 , 110u8]) }) } ,) , narrow : :: icu_datetime :: provider :: calendar :: months :: SymbolsV1 :: Other (unsafe { # [allow (unused_unsafe)] :: zerovec :: ZeroMap :: from_parts_unchecked (unsafe { :: zerovec :: ZeroVec :: from_bytes_unchecked (& [77u8 , 48u8 , 49u8 , 0u8 , 77u8 , 48u8 , 50u8 , 0u8 , 77u8 , 48u8 , 51u8 , 0u8 , 77u8 , 48u8 , 52u8 , 0u8 , 77u8 , 48u8 , 53u8 , 0u8 , 77u8 , 48u8 , 54u8 , 0u8 , 77u8 , 48u8 , 55u8 , 0u8 , 77u8 , 48u8 , 56u8 , 0u8 , 77u8 , 48u8 , 57u8 , 0u8 , 77u8 , 49u8 , 48u8 , 0u8 , 77u8 , 49u8 , 49u8 , 0u8 , 77u8 , 49u8 , 50u8 , 0u8 , 77u8 , 49u8 , 51u8 , 0u8]) } , unsafe { :: zerovec :: VarZeroVec :: from_bytes_unchecked (& [13u8 , 0u8 , 0u8 , 0u8 , 0u8 , 0u8 , 1u8 , 0u8 , 2u8 , 0u8 , 3u8 , 0u8 , 4u8 , 0u8 , 5u8 , 0u8 , 6u8 , 0u8 , 7u8 , 0u8 , 8u8 , 0u8 , 9u8 , 0u8 , 11u8 , 0u8 , 13u8 , 0u8 , 15u8 , 0u8 , 49u8 , 50u8 , 51u8 , 52u8 , 53u8 , 54u8 , 55u8 , 56u8 , 57u8 , 49u8 , 48u8 , 49u8 , 49u8 , 49u8 , 50u8 , 49u8 , 51u8]) }) } ,) , short : None , wide : :: icu_datetime :: provider :: calendar :: months :: SymbolsV1 :: Other (unsafe { # [allow (unused_unsafe)] :: zerovec :: ZeroMap :: from_parts_unchecked (unsafe { :: zerovec :: ZeroVec :: from_bytes_unchecked (& [77u8 , 48u8 , 49u8 , 0u8 , 77u8 , 48u8 , 50u8 , 0u8 , 77u8 , 48u8 , 51u8 , 0u8 , 77u8 , 48u8 , 52u8 , 0u8 , 77u8 , 48u8 , 53u8 , 0u8 , 77u8 , 48u8 , 54u8 , 0u8 , 77u8 , 48u8 , 55u8 , 0u8 , 77u8 , 48u8 , 56u8 , 0u8 , 77u8 , 48u8 , 57u8 , 0u8 , 77u8 , 49u8 , 48u8 , 0u8 , 77u8 , 49u8 , 49u8 , 0u8 , 77u8 , 49u8 , 50u8 , 0u8 , 77u8 , 49u8 , 51u8 , 0u8]) } , unsafe { :: zerovec :: VarZeroVec :: from_bytes_unchecked (& [13u8 , 0u8 , 0u8 , 0u8 , 0u8 , 0u8 , 8u8 , 0u8 , 14u8 , 0u8 , 19u8 , 0u8 , 25u8 , 0u8 , 28u8 , 0u8 , 35u8 , 0u8 , 42u8 , 0u8 , 48u8 , 0u8 , 54u8 , 0u8 , 58u8 , 0u8 , 63u8 , 0u8 , 70u8 , 0u8 , 77u8 , 101u8 , 115u8 , 107u8 , 101u8 , 114u8 , 101u8 , 109u8 , 84u8 , 101u8 , 107u8 , 101u8 , 109u8 , 116u8 , 72u8 , 101u8 , 100u8 , 97u8 , 114u8 , 84u8 , 97u8 , 104u8 , 115u8 , 97u8 , 115u8 , 84u8 , 101u8 , 114u8 , 89u8 , 101u8 , 107u8 , 97u8 , 116u8 , 105u8 , 116u8 , 77u8 , 101u8 , 103u8 , 97u8 , 98u8 , 105u8 , 116u8 , 77u8 , 105u8 , 97u8 , 122u8 , 105u8 , 97u8 , 71u8 , 101u8 , 110u8 , 98u8 , 111u8 , 116u8 , 83u8 , 101u8 , 110u8 , 101u8 , 72u8 , 97u8 , 109u8 , 108u8 , 101u8 , 78u8 , 101u8 , 104u8 , 97u8 , 115u8 , 115u8 , 101u8 , 80u8 , 97u8 , 103u8 , 117u8 , 109u8 , 101u8 , 110u8]) }) } ,) , } , stand_alone : None , } , quarters : :: icu_datetime :: provider :: calendar :: quarters :: ContextsV1 { format : :: icu_datetime :: provider :: calendar :: quarters :: FormatWidthsV1 { abbreviated : :: icu_datetime :: provider :: calendar :: quarters :: SymbolsV1 ([alloc :: borrow :: Cow :: Borrowed ("Q1") , alloc :: borrow :: Cow :: Borrowed ("Q2") , alloc :: borrow :: Cow :: Borrowed ("Q3") , alloc :: borrow :: Cow :: Borrowed ("Q4") ,] ,) , narrow : :: icu_datetime :: provider :: calendar :: quarters :: SymbolsV1 ([alloc :: borrow :: Cow :: Borrowed ("𑄷") , alloc :: borrow :: Cow :: Borrowed ("𑄸") , alloc :: borrow :: Cow :: Borrowed ("𑄹") , alloc :: borrow :: Cow :: Borrowed ("𑄺") ,] ,) , short : None , wide : :: icu_datetime :: provider :: calendar :: quarters :: SymbolsV1 ([alloc :: borrow :: Cow :: Borrowed ("𑄖\u{11128}𑄚\u{11134}𑄟𑄎\u{11127}𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄘\u{11128} 𑄛\u{11133}𑄆𑄘\u{11133}𑄠𑄬 𑄖\u{11128}𑄚\u{11134}𑄟𑄎\u{11127}𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄖\u{11128}𑄚\u{11134} 𑄛\u{11133}𑄆𑄘\u{11133}𑄠𑄬 𑄖\u{11128}𑄚\u{11134}𑄟𑄎\u{11127}𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄌\u{11133}𑄆𑄬𑄢\u{11134} 𑄛\u{11133}𑄆𑄘\u{11133}𑄠𑄬 𑄖\u{11128}𑄚\u{11134}𑄟𑄎\u{11127}𑄢\u{11134}") ,] ,) , } , stand_alone : None , } , weekdays : :: icu_datetime :: provider :: calendar :: weekdays :: ContextsV1 { format : :: icu_datetime :: provider :: calendar :: weekdays :: FormatWidthsV1 { abbreviated : :: icu_datetime :: provider :: calendar :: weekdays :: SymbolsV1 ([alloc :: borrow :: Cow :: Borrowed ("𑄢\u{11127}𑄝\u{11128}") , alloc :: borrow :: Cow :: Borrowed ("𑄥\u{11127}𑄟\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄟\u{11127}\u{11101}𑄉\u{11127}𑄣\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄝\u{1112a}𑄖\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄝\u{11133}𑄢\u{11128}𑄥\u{1112a}𑄛\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄥\u{1112a}𑄇\u{11134}𑄇\u{1112e}𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄥\u{11127}𑄚\u{11128}") ,] ,) , narrow : :: icu_datetime :: provider :: calendar :: weekdays :: SymbolsV1 ([alloc :: borrow :: Cow :: Borrowed ("𑄢\u{11127}") , alloc :: borrow :: Cow :: Borrowed ("𑄥\u{11127}") , alloc :: borrow :: Cow :: Borrowed ("𑄟\u{11127}") , alloc :: borrow :: Cow :: Borrowed ("𑄝\u{1112a}") , alloc :: borrow :: Cow :: Borrowed ("𑄝\u{11133}𑄢\u{11128}") , alloc :: borrow :: Cow :: Borrowed ("𑄥\u{1112a}") , alloc :: borrow :: Cow :: Borrowed ("𑄥\u{11127}") ,] ,) , short : Some (:: icu_datetime :: provider :: calendar :: weekdays :: SymbolsV1 ([alloc :: borrow :: Cow :: Borrowed ("𑄢\u{11127}𑄝\u{11128}") , alloc :: borrow :: Cow :: Borrowed ("𑄥\u{11127}𑄟\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄟\u{11127}\u{11101}𑄉\u{11127}𑄣\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄝\u{1112a}𑄖\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄝\u{11133}𑄢\u{11128}𑄥\u{1112a}𑄛\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄥\u{1112a}𑄇\u{11134}𑄇\u{1112e}𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄥\u{11127}𑄚\u{11128}") ,] ,)) , wide : :: icu_datetime :: provider :: calendar :: weekdays :: SymbolsV1 ([alloc :: borrow :: Cow :: Borrowed ("𑄢\u{11127}𑄝\u{11128}𑄝𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄥\u{11127}𑄟\u{11134}𑄝𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄟\u{11127}\u{11101}𑄉\u{11127}𑄣\u{11134}𑄝𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄝\u{1112a}𑄖\u{11134}𑄝𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄝\u{11133}𑄢\u{11128}𑄥\u{1112a}𑄛\u{11134}𑄝𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄥\u{1112a}𑄇\u{11134}𑄇\u{1112e}𑄢\u{11134}𑄝𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄥\u{11127}𑄚\u{11128}𑄝𑄢\u{11134}") ,] ,) , } , stand_alone : None , } , eras : :: icu_datetime :: provider :: calendar :: Eras { names : unsafe { # [allow (unused_unsafe)] :: zerovec :: ZeroMap :: from_parts_unchecked (unsafe { :: zerovec :: VarZeroVec :: from_bytes_unchecked (& [3u8 , 0u8 , 0u8 , 0u8 , 0u8 , 0u8 , 5u8 , 0u8 , 10u8 , 0u8 , 105u8 , 110u8 , 99u8 , 97u8 , 114u8 , 109u8 , 117u8 , 110u8 , 100u8 , 105u8 , 112u8 , 114u8 , 101u8 , 45u8 , 105u8 , 110u8 , 99u8 , 97u8 , 114u8]) } , unsafe { :: zerovec :: VarZeroVec :: from_bytes_unchecked (& [3u8 , 0u8 , 0u8 , 0u8 , 0u8 , 0u8 , 4u8 , 0u8 , 8u8 , 0u8 , 69u8 , 82u8 , 65u8 , 48u8 , 69u8 , 82u8 , 65u8 , 48u8 , 69u8 , 82u8 , 65u8 , 49u8]) }) } , abbr : unsafe { # [allow (unused_unsafe)] :: zerovec :: ZeroMap :: from_parts_unchecked (unsafe { :: zerovec :: VarZeroVec :: from_bytes_unchecked (& [3u8 , 0u8 , 0u8 , 0u8 , 0u8 , 0u8 , 5u8 , 0u8 , 10u8 , 0u8 , 105u8 , 110u8 , 99u8 , 97u8 , 114u8 , 109u8 , 117u8 , 110u8 , 100u8 , 105u8 , 112u8 , 114u8 , 101u8 , 45u8 , 105u8 , 110u8 , 99u8 , 97u8 , 114u8]) } , unsafe { :: zerovec :: VarZeroVec :: from_bytes_unchecked (& [3u8 , 0u8 , 0u8 , 0u8 , 0u8 , 0u8 , 4u8 , 0u8 , 8u8 , 0u8 , 69u8 , 82u8 , 65u8 , 48u8 , 69u8 , 82u8 , 65u8 , 48u8 , 69u8 , 82u8 , 65u8 , 49u8]) }) } , narrow : unsafe { # [allow (unused_unsafe)] :: zerovec :: ZeroMap :: from_parts_unchecked (unsafe { :: zerovec :: VarZeroVec :: from_bytes_unchecked (& [3u8 , 0u8 , 0u8 , 0u8 , 0u8 , 0u8 , 5u8 , 0u8 , 10u8 , 0u8 , 105u8 , 110u8 , 99u8 , 97u8 , 114u8 , 109u8 , 117u8 , 110u8 , 100u8 , 105u8 , 112u8 , 114u8 , 101u8 , 45u8 , 105u8 , 110u8 , 99u8 , 97u8 , 114u8]) } , unsafe { :: zerovec :: VarZeroVec :: from_bytes_unchecked (& [3u8 , 0u8 , 0u8 , 0u8 , 0u8 , 0u8 , 4u8 , 0u8 , 8u8 , 0u8 , 69u8 , 82u8 , 65u8 , 48u8 , 69u8 , 82u8 , 65u8 , 48u8 , 69u8 , 82u8 , 65u8 , 49u8]) }) } , } , cyclic_years : :: icu_datetime :: provider :: calendar :: CyclicYears { names : unsafe { :: zerovec :: VarZeroVec :: from_bytes_unchecked (& []) } , abbr : unsafe { :: zerovec :: VarZeroVec :: from_bytes_unchecked (& []) } , narrow : unsafe { :: zerovec :: VarZeroVec :: from_bytes_unchecked (& []) } , } , } ;
+static EN_EN_001_EN_ZA: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
     months: ::icu_datetime::provider::calendar::months::ContextsV1 {
         format: ::icu_datetime::provider::calendar::months::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::months::SymbolsV1::Other(unsafe {
@@ -527,186 +598,27 @@ static CCP: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
         },
         stand_alone: None,
     },
-    weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
-        format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
-            abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
-                alloc::borrow::Cow::Borrowed("𑄢\u{11127}𑄝\u{11128}"),
-                alloc::borrow::Cow::Borrowed("𑄥\u{11127}𑄟\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄟\u{11127}\u{11101}𑄉\u{11127}𑄣\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄝\u{1112a}𑄖\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄝\u{11133}𑄢\u{11128}𑄥\u{1112a}𑄛\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄥\u{1112a}𑄇\u{11134}𑄇\u{1112e}𑄢\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄥\u{11127}𑄚\u{11128}"),
-            ]),
-            narrow: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
-                alloc::borrow::Cow::Borrowed("𑄢\u{11127}"),
-                alloc::borrow::Cow::Borrowed("𑄥\u{11127}"),
-                alloc::borrow::Cow::Borrowed("𑄟\u{11127}"),
-                alloc::borrow::Cow::Borrowed("𑄝\u{1112a}"),
-                alloc::borrow::Cow::Borrowed("𑄝\u{11133}𑄢\u{11128}"),
-                alloc::borrow::Cow::Borrowed("𑄥\u{1112a}"),
-                alloc::borrow::Cow::Borrowed("𑄥\u{11127}"),
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Q1"),
+                alloc::borrow::Cow::Borrowed("Q2"),
+                alloc::borrow::Cow::Borrowed("Q3"),
+                alloc::borrow::Cow::Borrowed("Q4"),
             ]),
-            short: Some(::icu_datetime::provider::calendar::weekdays::SymbolsV1([
-                alloc::borrow::Cow::Borrowed("𑄢\u{11127}𑄝\u{11128}"),
-                alloc::borrow::Cow::Borrowed("𑄥\u{11127}𑄟\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄟\u{11127}\u{11101}𑄉\u{11127}𑄣\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄝\u{1112a}𑄖\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄝\u{11133}𑄢\u{11128}𑄥\u{1112a}𑄛\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄥\u{1112a}𑄇\u{11134}𑄇\u{1112e}𑄢\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄥\u{11127}𑄚\u{11128}"),
-            ])),
-            wide: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
-                alloc::borrow::Cow::Borrowed("𑄢\u{11127}𑄝\u{11128}𑄝𑄢\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄥\u{11127}𑄟\u{11134}𑄝𑄢\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄟\u{11127}\u{11101}𑄉\u{11127}𑄣\u{11134}𑄝𑄢\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄝\u{1112a}𑄖\u{11134}𑄝𑄢\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄝\u{11133}𑄢\u{11128}𑄥\u{1112a}𑄛\u{11134}𑄝𑄢\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄥\u{1112a}𑄇\u{11134}𑄇\u{1112e}𑄢\u{11134}𑄝𑄢\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄥\u{11127}𑄚\u{11128}𑄝𑄢\u{11134}"),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1"),
+                alloc::borrow::Cow::Borrowed("2"),
+                alloc::borrow::Cow::Borrowed("3"),
+                alloc::borrow::Cow::Borrowed("4"),
             ]),
-        },
-        stand_alone: None,
-    },
-    eras: ::icu_datetime::provider::calendar::Eras {
-        names: unsafe {
-            #[allow(unused_unsafe)]
-            ::zerovec::ZeroMap::from_parts_unchecked(
-                unsafe {
-                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
-                        3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 10u8, 0u8, 105u8, 110u8, 99u8,
-                        97u8, 114u8, 109u8, 117u8, 110u8, 100u8, 105u8, 112u8, 114u8, 101u8, 45u8,
-                        105u8, 110u8, 99u8, 97u8, 114u8,
-                    ])
-                },
-                unsafe {
-                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
-                        3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 8u8, 0u8, 69u8, 82u8, 65u8, 48u8,
-                        69u8, 82u8, 65u8, 48u8, 69u8, 82u8, 65u8, 49u8,
-                    ])
-                },
-            )
-        },
-        abbr: unsafe {
-            #[allow(unused_unsafe)]
-            ::zerovec::ZeroMap::from_parts_unchecked(
-                unsafe {
-                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
-                        3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 10u8, 0u8, 105u8, 110u8, 99u8,
-                        97u8, 114u8, 109u8, 117u8, 110u8, 100u8, 105u8, 112u8, 114u8, 101u8, 45u8,
-                        105u8, 110u8, 99u8, 97u8, 114u8,
-                    ])
-                },
-                unsafe {
-                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
-                        3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 8u8, 0u8, 69u8, 82u8, 65u8, 48u8,
-                        69u8, 82u8, 65u8, 48u8, 69u8, 82u8, 65u8, 49u8,
-                    ])
-                },
-            )
-        },
-        narrow: unsafe {
-            #[allow(unused_unsafe)]
-            ::zerovec::ZeroMap::from_parts_unchecked(
-                unsafe {
-                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
-                        3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 5u8, 0u8, 10u8, 0u8, 105u8, 110u8, 99u8,
-                        97u8, 114u8, 109u8, 117u8, 110u8, 100u8, 105u8, 112u8, 114u8, 101u8, 45u8,
-                        105u8, 110u8, 99u8, 97u8, 114u8,
-                    ])
-                },
-                unsafe {
-                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
-                        3u8, 0u8, 0u8, 0u8, 0u8, 0u8, 4u8, 0u8, 8u8, 0u8, 69u8, 82u8, 65u8, 48u8,
-                        69u8, 82u8, 65u8, 48u8, 69u8, 82u8, 65u8, 49u8,
-                    ])
-                },
-            )
-        },
-    },
-};
-static EN_EN_001_EN_ZA: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
-    months: ::icu_datetime::provider::calendar::months::ContextsV1 {
-        format: ::icu_datetime::provider::calendar::months::FormatWidthsV1 {
-            abbreviated: ::icu_datetime::provider::calendar::months::SymbolsV1::Other(unsafe {
-                #[allow(unused_unsafe)]
-                ::zerovec::ZeroMap::from_parts_unchecked(
-                    unsafe {
-                        ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                            77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 50u8, 0u8, 77u8, 48u8, 51u8, 0u8,
-                            77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 53u8, 0u8, 77u8, 48u8, 54u8, 0u8,
-                            77u8, 48u8, 55u8, 0u8, 77u8, 48u8, 56u8, 0u8, 77u8, 48u8, 57u8, 0u8,
-                            77u8, 49u8, 48u8, 0u8, 77u8, 49u8, 49u8, 0u8, 77u8, 49u8, 50u8, 0u8,
-                            77u8, 49u8, 51u8, 0u8,
-                        ])
-                    },
-                    unsafe {
-                        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
-                            13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 14u8, 0u8, 19u8, 0u8, 25u8,
-                            0u8, 28u8, 0u8, 35u8, 0u8, 42u8, 0u8, 48u8, 0u8, 54u8, 0u8, 58u8, 0u8,
-                            63u8, 0u8, 70u8, 0u8, 77u8, 101u8, 115u8, 107u8, 101u8, 114u8, 101u8,
-                            109u8, 84u8, 101u8, 107u8, 101u8, 109u8, 116u8, 72u8, 101u8, 100u8,
-                            97u8, 114u8, 84u8, 97u8, 104u8, 115u8, 97u8, 115u8, 84u8, 101u8, 114u8,
-                            89u8, 101u8, 107u8, 97u8, 116u8, 105u8, 116u8, 77u8, 101u8, 103u8,
-                            97u8, 98u8, 105u8, 116u8, 77u8, 105u8, 97u8, 122u8, 105u8, 97u8, 71u8,
-                            101u8, 110u8, 98u8, 111u8, 116u8, 83u8, 101u8, 110u8, 101u8, 72u8,
-                            97u8, 109u8, 108u8, 101u8, 78u8, 101u8, 104u8, 97u8, 115u8, 115u8,
-                            101u8, 80u8, 97u8, 103u8, 117u8, 109u8, 101u8, 110u8,
-                        ])
-                    },
-                )
-            }),
-            narrow: ::icu_datetime::provider::calendar::months::SymbolsV1::Other(unsafe {
-                #[allow(unused_unsafe)]
-                ::zerovec::ZeroMap::from_parts_unchecked(
-                    unsafe {
-                        ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                            77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 50u8, 0u8, 77u8, 48u8, 51u8, 0u8,
-                            77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 53u8, 0u8, 77u8, 48u8, 54u8, 0u8,
-                            77u8, 48u8, 55u8, 0u8, 77u8, 48u8, 56u8, 0u8, 77u8, 48u8, 57u8, 0u8,
-                            77u8, 49u8, 48u8, 0u8, 77u8, 49u8, 49u8, 0u8, 77u8, 49u8, 50u8, 0u8,
-                            77u8, 49u8, 51u8, 0u8,
-                        ])
-                    },
-                    unsafe {
-                        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
-                            13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 2u8, 0u8, 3u8, 0u8, 4u8, 0u8,
-                            5u8, 0u8, 6u8, 0u8, 7u8, 0u8, 8u8, 0u8, 9u8, 0u8, 11u8, 0u8, 13u8, 0u8,
-                            15u8, 0u8, 49u8, 50u8, 51u8, 52u8, 53u8, 54u8, 55u8, 56u8, 57u8, 49u8,
-                            48u8, 49u8, 49u8, 49u8, 50u8, 49u8, 51u8,
-                        ])
-                    },
-                )
-            }),
             short: None,
-            wide: ::icu_datetime::provider::calendar::months::SymbolsV1::Other(unsafe {
-                #[allow(unused_unsafe)]
-                ::zerovec::ZeroMap::from_parts_unchecked(
-                    unsafe {
-                        ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                            77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 50u8, 0u8, 77u8, 48u8, 51u8, 0u8,
-                            77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 53u8, 0u8, 77u8, 48u8, 54u8, 0u8,
-                            77u8, 48u8, 55u8, 0u8, 77u8, 48u8, 56u8, 0u8, 77u8, 48u8, 57u8, 0u8,
-                            77u8, 49u8, 48u8, 0u8, 77u8, 49u8, 49u8, 0u8, 77u8, 49u8, 50u8, 0u8,
-                            77u8, 49u8, 51u8, 0u8,
-                        ])
-                    },
-                    unsafe {
-                        ::zerovec::VarZeroVec::from_bytes_unchecked(&[
-                            13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 8u8, 0u8, 14u8, 0u8, 19u8, 0u8, 25u8,
-                            0u8, 28u8, 0u8, 35u8, 0u8, 42u8, 0u8, 48u8, 0u8, 54u8, 0u8, 58u8, 0u8,
-                            63u8, 0u8, 70u8, 0u8, 77u8, 101u8, 115u8, 107u8, 101u8, 114u8, 101u8,
-                            109u8, 84u8, 101u8, 107u8, 101u8, 109u8, 116u8, 72u8, 101u8, 100u8,
-                            97u8, 114u8, 84u8, 97u8, 104u8, 115u8, 97u8, 115u8, 84u8, 101u8, 114u8,
-                            89u8, 101u8, 107u8, 97u8, 116u8, 105u8, 116u8, 77u8, 101u8, 103u8,
-                            97u8, 98u8, 105u8, 116u8, 77u8, 105u8, 97u8, 122u8, 105u8, 97u8, 71u8,
-                            101u8, 110u8, 98u8, 111u8, 116u8, 83u8, 101u8, 110u8, 101u8, 72u8,
-                            97u8, 109u8, 108u8, 101u8, 78u8, 101u8, 104u8, 97u8, 115u8, 115u8,
-                            101u8, 80u8, 97u8, 103u8, 117u8, 109u8, 101u8, 110u8,
-                        ])
-                    },
-                )
-            }),
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1st quarter"),
+                alloc::borrow::Cow::Borrowed("2nd quarter"),
+                alloc::borrow::Cow::Borrowed("3rd quarter"),
+                alloc::borrow::Cow::Borrowed("4th quarter"),
+            ]),
         },
         stand_alone: None,
     },
@@ -807,6 +719,11 @@ static EN_EN_001_EN_ZA: &DataStruct = &::icu_datetime::provider::calendar::DateS
             )
         },
     },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
 };
 static ES: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
     months: ::icu_datetime::provider::calendar::months::ContextsV1 {
@@ -893,6 +810,30 @@ static ES: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
         },
         stand_alone: None,
     },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("T1"),
+                alloc::borrow::Cow::Borrowed("T2"),
+                alloc::borrow::Cow::Borrowed("T3"),
+                alloc::borrow::Cow::Borrowed("T4"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1"),
+                alloc::borrow::Cow::Borrowed("2"),
+                alloc::borrow::Cow::Borrowed("3"),
+                alloc::borrow::Cow::Borrowed("4"),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1.er trimestre"),
+                alloc::borrow::Cow::Borrowed("2.º trimestre"),
+                alloc::borrow::Cow::Borrowed("3.er trimestre"),
+                alloc::borrow::Cow::Borrowed("4.º trimestre"),
+            ]),
+        },
+        stand_alone: None,
+    },
     weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
         format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
@@ -990,6 +931,11 @@ static ES: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
             )
         },
     },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
 };
 static ES_AR: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
     months: ::icu_datetime::provider::calendar::months::ContextsV1 {
@@ -1076,6 +1022,30 @@ static ES_AR: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
         },
         stand_alone: None,
     },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("T1"),
+                alloc::borrow::Cow::Borrowed("T2"),
+                alloc::borrow::Cow::Borrowed("T3"),
+                alloc::borrow::Cow::Borrowed("T4"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1"),
+                alloc::borrow::Cow::Borrowed("2"),
+                alloc::borrow::Cow::Borrowed("3"),
+                alloc::borrow::Cow::Borrowed("4"),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1.er trimestre"),
+                alloc::borrow::Cow::Borrowed("2.º trimestre"),
+                alloc::borrow::Cow::Borrowed("3.er trimestre"),
+                alloc::borrow::Cow::Borrowed("4.º trimestre"),
+            ]),
+        },
+        stand_alone: None,
+    },
     weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
         format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
@@ -1173,6 +1143,11 @@ static ES_AR: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
             )
         },
     },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
 };
 static FIL: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
     months: ::icu_datetime::provider::calendar::months::ContextsV1 {
@@ -1259,6 +1234,30 @@ static FIL: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
         },
         stand_alone: None,
     },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Q1"),
+                alloc::borrow::Cow::Borrowed("Q2"),
+                alloc::borrow::Cow::Borrowed("Q3"),
+                alloc::borrow::Cow::Borrowed("Q4"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1"),
+                alloc::borrow::Cow::Borrowed("2"),
+                alloc::borrow::Cow::Borrowed("3"),
+                alloc::borrow::Cow::Borrowed("4"),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("ika-1 quarter"),
+                alloc::borrow::Cow::Borrowed("ika-2 quarter"),
+                alloc::borrow::Cow::Borrowed("ika-3 quarter"),
+                alloc::borrow::Cow::Borrowed("ika-4 na quarter"),
+            ]),
+        },
+        stand_alone: None,
+    },
     weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
         format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
@@ -1356,6 +1355,11 @@ static FIL: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
             )
         },
     },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
 };
 static FR: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
     months: ::icu_datetime::provider::calendar::months::ContextsV1 {
@@ -1442,6 +1446,30 @@ static FR: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
         },
         stand_alone: None,
     },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("T1"),
+                alloc::borrow::Cow::Borrowed("T2"),
+                alloc::borrow::Cow::Borrowed("T3"),
+                alloc::borrow::Cow::Borrowed("T4"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1"),
+                alloc::borrow::Cow::Borrowed("2"),
+                alloc::borrow::Cow::Borrowed("3"),
+                alloc::borrow::Cow::Borrowed("4"),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1er trimestre"),
+                alloc::borrow::Cow::Borrowed("2e trimestre"),
+                alloc::borrow::Cow::Borrowed("3e trimestre"),
+                alloc::borrow::Cow::Borrowed("4e trimestre"),
+            ]),
+        },
+        stand_alone: None,
+    },
     weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
         format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
@@ -1544,6 +1572,11 @@ static FR: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
             )
         },
     },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
 };
 static JA: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
     months: ::icu_datetime::provider::calendar::months::ContextsV1 {
@@ -1646,6 +1679,30 @@ static JA: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
         },
         stand_alone: None,
     },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Q1"),
+                alloc::borrow::Cow::Borrowed("Q2"),
+                alloc::borrow::Cow::Borrowed("Q3"),
+                alloc::borrow::Cow::Borrowed("Q4"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1"),
+                alloc::borrow::Cow::Borrowed("2"),
+                alloc::borrow::Cow::Borrowed("3"),
+                alloc::borrow::Cow::Borrowed("4"),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("第1四半期"),
+                alloc::borrow::Cow::Borrowed("第2四半期"),
+                alloc::borrow::Cow::Borrowed("第3四半期"),
+                alloc::borrow::Cow::Borrowed("第4四半期"),
+            ]),
+        },
+        stand_alone: None,
+    },
     weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
         format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
@@ -1743,6 +1800,11 @@ static JA: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
             )
         },
     },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
 };
 static RU: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
     months: ::icu_datetime::provider::calendar::months::ContextsV1 {
@@ -1845,6 +1907,30 @@ static RU: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
         },
         stand_alone: None,
     },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1-й кв."),
+                alloc::borrow::Cow::Borrowed("2-й кв."),
+                alloc::borrow::Cow::Borrowed("3-й кв."),
+                alloc::borrow::Cow::Borrowed("4-й кв."),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1"),
+                alloc::borrow::Cow::Borrowed("2"),
+                alloc::borrow::Cow::Borrowed("3"),
+                alloc::borrow::Cow::Borrowed("4"),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1-й квартал"),
+                alloc::borrow::Cow::Borrowed("2-й квартал"),
+                alloc::borrow::Cow::Borrowed("3-й квартал"),
+                alloc::borrow::Cow::Borrowed("4-й квартал"),
+            ]),
+        },
+        stand_alone: None,
+    },
     weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
         format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
@@ -1955,6 +2041,11 @@ static RU: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
             )
         },
     },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
 };
 static SR_LATN: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
     months: ::icu_datetime::provider::calendar::months::ContextsV1 {
@@ -2041,6 +2132,30 @@ static SR_LATN: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1
         },
         stand_alone: None,
     },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1. kv."),
+                alloc::borrow::Cow::Borrowed("2. kv."),
+                alloc::borrow::Cow::Borrowed("3. kv."),
+                alloc::borrow::Cow::Borrowed("4. kv."),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1."),
+                alloc::borrow::Cow::Borrowed("2."),
+                alloc::borrow::Cow::Borrowed("3."),
+                alloc::borrow::Cow::Borrowed("4."),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("prvi kvartal"),
+                alloc::borrow::Cow::Borrowed("drugi kvartal"),
+                alloc::borrow::Cow::Borrowed("treći kvartal"),
+                alloc::borrow::Cow::Borrowed("četvrti kvartal"),
+            ]),
+        },
+        stand_alone: None,
+    },
     weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
         format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
@@ -2138,6 +2253,11 @@ static SR_LATN: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1
             )
         },
     },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
 };
 static SR_SR_CYRL: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
     months: ::icu_datetime::provider::calendar::months::ContextsV1 {
@@ -2240,6 +2360,30 @@ static SR_SR_CYRL: &DataStruct = &::icu_datetime::provider::calendar::DateSymbol
         },
         stand_alone: None,
     },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1. кв."),
+                alloc::borrow::Cow::Borrowed("2. кв."),
+                alloc::borrow::Cow::Borrowed("3. кв."),
+                alloc::borrow::Cow::Borrowed("4. кв."),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1."),
+                alloc::borrow::Cow::Borrowed("2."),
+                alloc::borrow::Cow::Borrowed("3."),
+                alloc::borrow::Cow::Borrowed("4."),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("први квартал"),
+                alloc::borrow::Cow::Borrowed("други квартал"),
+                alloc::borrow::Cow::Borrowed("трећи квартал"),
+                alloc::borrow::Cow::Borrowed("четврти квартал"),
+            ]),
+        },
+        stand_alone: None,
+    },
     weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
         format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
@@ -2337,6 +2481,11 @@ static SR_SR_CYRL: &DataStruct = &::icu_datetime::provider::calendar::DateSymbol
             )
         },
     },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
 };
 static TH: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
     months: ::icu_datetime::provider::calendar::months::ContextsV1 {
@@ -2457,6 +2606,30 @@ static TH: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
         },
         stand_alone: None,
     },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("ไตรมาส 1"),
+                alloc::borrow::Cow::Borrowed("ไตรมาส 2"),
+                alloc::borrow::Cow::Borrowed("ไตรมาส 3"),
+                alloc::borrow::Cow::Borrowed("ไตรมาส 4"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1"),
+                alloc::borrow::Cow::Borrowed("2"),
+                alloc::borrow::Cow::Borrowed("3"),
+                alloc::borrow::Cow::Borrowed("4"),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("ไตรมาส 1"),
+                alloc::borrow::Cow::Borrowed("ไตรมาส 2"),
+                alloc::borrow::Cow::Borrowed("ไตรมาส 3"),
+                alloc::borrow::Cow::Borrowed("ไตรมาส 4"),
+            ]),
+        },
+        stand_alone: None,
+    },
     weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
         format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
@@ -2554,6 +2727,11 @@ static TH: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
             )
         },
     },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
 };
 static TR: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
     months: ::icu_datetime::provider::calendar::months::ContextsV1 {
@@ -2640,6 +2818,30 @@ static TR: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
         },
         stand_alone: None,
     },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Ç1"),
+                alloc::borrow::Cow::Borrowed("Ç2"),
+                alloc::borrow::Cow::Borrowed("Ç3"),
+                alloc::borrow::Cow::Borrowed("Ç4"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1."),
+                alloc::borrow::Cow::Borrowed("2."),
+                alloc::borrow::Cow::Borrowed("3."),
+                alloc::borrow::Cow::Borrowed("4."),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1. çeyrek"),
+                alloc::borrow::Cow::Borrowed("2. çeyrek"),
+                alloc::borrow::Cow::Borrowed("3. çeyrek"),
+                alloc::borrow::Cow::Borrowed("4. çeyrek"),
+            ]),
+        },
+        stand_alone: None,
+    },
     weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
         format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
@@ -2737,6 +2939,11 @@ static TR: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
             )
         },
     },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
 };
 static UND: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
     months: ::icu_datetime::provider::calendar::months::ContextsV1 {
@@ -2823,6 +3030,30 @@ static UND: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
         },
         stand_alone: None,
     },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Q1"),
+                alloc::borrow::Cow::Borrowed("Q2"),
+                alloc::borrow::Cow::Borrowed("Q3"),
+                alloc::borrow::Cow::Borrowed("Q4"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1"),
+                alloc::borrow::Cow::Borrowed("2"),
+                alloc::borrow::Cow::Borrowed("3"),
+                alloc::borrow::Cow::Borrowed("4"),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Q1"),
+                alloc::borrow::Cow::Borrowed("Q2"),
+                alloc::borrow::Cow::Borrowed("Q3"),
+                alloc::borrow::Cow::Borrowed("Q4"),
+            ]),
+        },
+        stand_alone: None,
+    },
     weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
         format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
@@ -2920,4 +3151,9 @@ static UND: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
             )
         },
     },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
 };
@@ -0,0 +1,148 @@
+// @generated
+type DataStruct = < :: icu_datetime :: provider :: calendar :: HebrewDateLengthsV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[("en", EN), ("und", UND)]);
+static EN: &DataStruct = &::icu_datetime::provider::calendar::DateLengthsV1 {
+    date: ::icu_datetime::provider::calendar::patterns::LengthPatternsV1 {
+        full: ::icu_datetime::pattern::runtime::Pattern {
+            items: unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    128u8, 80u8, 4u8, 0u8, 0u8, 44u8, 0u8, 0u8, 32u8, 128u8, 32u8, 4u8, 0u8, 0u8,
+                    32u8, 128u8, 64u8, 1u8, 0u8, 0u8, 44u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8, 0u8,
+                    0u8, 32u8, 128u8, 0u8, 1u8,
+                ])
+            },
+            time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+        },
+        long: ::icu_datetime::pattern::runtime::Pattern {
+            items: unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    128u8, 32u8, 4u8, 0u8, 0u8, 32u8, 128u8, 64u8, 1u8, 0u8, 0u8, 44u8, 0u8, 0u8,
+                    32u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8, 0u8, 1u8,
+                ])
+            },
+            time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+        },
+        medium: ::icu_datetime::pattern::runtime::Pattern {
+            items: unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    128u8, 32u8, 3u8, 0u8, 0u8, 32u8, 128u8, 64u8, 1u8, 0u8, 0u8, 44u8, 0u8, 0u8,
+                    32u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8, 0u8, 1u8,
+                ])
+            },
+            time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+        },
+        short: ::icu_datetime::pattern::runtime::Pattern {
+            items: unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    128u8, 32u8, 1u8, 0u8, 0u8, 47u8, 128u8, 64u8, 1u8, 0u8, 0u8, 47u8, 128u8,
+                    16u8, 1u8, 0u8, 0u8, 32u8, 128u8, 0u8, 5u8,
+                ])
+            },
+            time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+        },
+    },
+    length_combinations: ::icu_datetime::provider::calendar::patterns::GenericLengthPatternsV1 {
+        full: ::icu_datetime::pattern::runtime::GenericPattern {
+            items: unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    128u8, 0u8, 1u8, 0u8, 0u8, 32u8, 0u8, 0u8, 97u8, 0u8, 0u8, 116u8, 0u8, 0u8,
+                    32u8, 128u8, 0u8, 0u8,
+                ])
+            },
+        },
+        long: ::icu_datetime::pattern::runtime::GenericPattern {
+            items: unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    128u8, 0u8, 1u8, 0u8, 0u8, 32u8, 0u8, 0u8, 97u8, 0u8, 0u8, 116u8, 0u8, 0u8,
+                    32u8, 128u8, 0u8, 0u8,
+                ])
+            },
+        },
+        medium: ::icu_datetime::pattern::runtime::GenericPattern {
+            items: unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    128u8, 0u8, 1u8, 0u8, 0u8, 44u8, 0u8, 0u8, 32u8, 128u8, 0u8, 0u8,
+                ])
+            },
+        },
+        short: ::icu_datetime::pattern::runtime::GenericPattern {
+            items: unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    128u8, 0u8, 1u8, 0u8, 0u8, 44u8, 0u8, 0u8, 32u8, 128u8, 0u8, 0u8,
+                ])
+            },
+        },
+    },
+};
+static UND: &DataStruct = &::icu_datetime::provider::calendar::DateLengthsV1 {
+    date: ::icu_datetime::provider::calendar::patterns::LengthPatternsV1 {
+        full: ::icu_datetime::pattern::runtime::Pattern {
+            items: unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    128u8, 0u8, 1u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8, 32u8,
+                    4u8, 0u8, 0u8, 32u8, 128u8, 64u8, 1u8, 0u8, 0u8, 44u8, 0u8, 0u8, 32u8, 128u8,
+                    80u8, 4u8,
+                ])
+            },
+            time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+        },
+        long: ::icu_datetime::pattern::runtime::Pattern {
+            items: unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    128u8, 0u8, 1u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8, 32u8,
+                    4u8, 0u8, 0u8, 32u8, 128u8, 64u8, 1u8,
+                ])
+            },
+            time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+        },
+        medium: ::icu_datetime::pattern::runtime::Pattern {
+            items: unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    128u8, 0u8, 1u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8, 32u8,
+                    3u8, 0u8, 0u8, 32u8, 128u8, 64u8, 1u8,
+                ])
+            },
+            time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+        },
+        short: ::icu_datetime::pattern::runtime::Pattern {
+            items: unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    128u8, 0u8, 5u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8, 0u8, 0u8, 45u8, 128u8, 32u8,
+                    2u8, 0u8, 0u8, 45u8, 128u8, 64u8, 2u8,
+                ])
+            },
+            time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+        },
+    },
+    length_combinations: ::icu_datetime::provider::calendar::patterns::GenericLengthPatternsV1 {
+        full: ::icu_datetime::pattern::runtime::GenericPattern {
+            items: unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    128u8, 0u8, 1u8, 0u8, 0u8, 32u8, 128u8, 0u8, 0u8,
+                ])
+            },
+        },
+        long: ::icu_datetime::pattern::runtime::GenericPattern {
+            items: unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    128u8, 0u8, 1u8, 0u8, 0u8, 32u8, 128u8, 0u8, 0u8,
+                ])
+            },
+        },
+        medium: ::icu_datetime::pattern::runtime::GenericPattern {
+            items: unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    128u8, 0u8, 1u8, 0u8, 0u8, 32u8, 128u8, 0u8, 0u8,
+                ])
+            },
+        },
+        short: ::icu_datetime::pattern::runtime::GenericPattern {
+            items: unsafe {
+                ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                    128u8, 0u8, 1u8, 0u8, 0u8, 32u8, 128u8, 0u8, 0u8,
+                ])
+            },
+        },
+    },
+};
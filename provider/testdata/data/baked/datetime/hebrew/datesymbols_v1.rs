@@ -0,0 +1,500 @@
+// @generated
+type DataStruct = < :: icu_datetime :: provider :: calendar :: HebrewDateSymbolsV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[("en", EN), ("und", UND)]);
+static EN: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
+    months: ::icu_datetime::provider::calendar::months::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::months::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::months::SymbolsV1::LeapYearNames {
+                names: unsafe {
+                    #[allow(unused_unsafe)]
+                    ::zerovec::ZeroMap::from_parts_unchecked(
+                        unsafe {
+                            ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                                77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 50u8, 0u8, 77u8, 48u8, 51u8,
+                                0u8, 77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 53u8, 0u8, 77u8, 48u8,
+                                53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 55u8, 0u8, 77u8,
+                                48u8, 56u8, 0u8, 77u8, 48u8, 57u8, 0u8, 77u8, 49u8, 48u8, 0u8,
+                                77u8, 49u8, 49u8, 0u8, 77u8, 49u8, 50u8, 0u8,
+                            ])
+                        },
+                        unsafe {
+                            ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 13u8, 0u8, 19u8, 0u8,
+                                24u8, 0u8, 30u8, 0u8, 36u8, 0u8, 40u8, 0u8, 45u8, 0u8, 49u8, 0u8,
+                                54u8, 0u8, 59u8, 0u8, 61u8, 0u8, 84u8, 105u8, 115u8, 104u8, 114u8,
+                                105u8, 72u8, 101u8, 115u8, 104u8, 118u8, 97u8, 110u8, 75u8, 105u8,
+                                115u8, 108u8, 101u8, 118u8, 84u8, 101u8, 118u8, 101u8, 116u8, 83u8,
+                                104u8, 101u8, 118u8, 97u8, 116u8, 65u8, 100u8, 97u8, 114u8, 32u8,
+                                73u8, 65u8, 100u8, 97u8, 114u8, 78u8, 105u8, 115u8, 97u8, 110u8,
+                                73u8, 121u8, 97u8, 114u8, 83u8, 105u8, 118u8, 97u8, 110u8, 84u8,
+                                97u8, 109u8, 117u8, 122u8, 65u8, 118u8, 69u8, 108u8, 117u8, 108u8,
+                            ])
+                        },
+                    )
+                },
+                leap_year_names: unsafe {
+                    #[allow(unused_unsafe)]
+                    ::zerovec::ZeroMap::from_parts_unchecked(
+                        unsafe {
+                            ::zerovec::ZeroVec::from_bytes_unchecked(&[77u8, 48u8, 54u8, 0u8])
+                        },
+                        unsafe {
+                            ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 100u8, 97u8, 114u8, 32u8, 73u8,
+                                73u8,
+                            ])
+                        },
+                    )
+                },
+            },
+            narrow: ::icu_datetime::provider::calendar::months::SymbolsV1::LeapYearNames {
+                names: unsafe {
+                    #[allow(unused_unsafe)]
+                    ::zerovec::ZeroMap::from_parts_unchecked(
+                        unsafe {
+                            ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                                77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 50u8, 0u8, 77u8, 48u8, 51u8,
+                                0u8, 77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 53u8, 0u8, 77u8, 48u8,
+                                53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 55u8, 0u8, 77u8,
+                                48u8, 56u8, 0u8, 77u8, 48u8, 57u8, 0u8, 77u8, 49u8, 48u8, 0u8,
+                                77u8, 49u8, 49u8, 0u8, 77u8, 49u8, 50u8, 0u8,
+                            ])
+                        },
+                        unsafe {
+                            ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 2u8, 0u8, 3u8, 0u8, 4u8,
+                                0u8, 5u8, 0u8, 6u8, 0u8, 7u8, 0u8, 8u8, 0u8, 9u8, 0u8, 11u8, 0u8,
+                                13u8, 0u8, 15u8, 0u8, 49u8, 50u8, 51u8, 52u8, 53u8, 54u8, 55u8,
+                                56u8, 57u8, 49u8, 48u8, 49u8, 49u8, 49u8, 50u8, 49u8, 51u8,
+                            ])
+                        },
+                    )
+                },
+                leap_year_names: unsafe {
+                    #[allow(unused_unsafe)]
+                    ::zerovec::ZeroMap::from_parts_unchecked(
+                        unsafe {
+                            ::zerovec::ZeroVec::from_bytes_unchecked(&[77u8, 48u8, 54u8, 0u8])
+                        },
+                        unsafe {
+                            ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 55u8,
+                            ])
+                        },
+                    )
+                },
+            },
+            short: None,
+            wide: ::icu_datetime::provider::calendar::months::SymbolsV1::LeapYearNames {
+                names: unsafe {
+                    #[allow(unused_unsafe)]
+                    ::zerovec::ZeroMap::from_parts_unchecked(
+                        unsafe {
+                            ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                                77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 50u8, 0u8, 77u8, 48u8, 51u8,
+                                0u8, 77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 53u8, 0u8, 77u8, 48u8,
+                                53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 55u8, 0u8, 77u8,
+                                48u8, 56u8, 0u8, 77u8, 48u8, 57u8, 0u8, 77u8, 49u8, 48u8, 0u8,
+                                77u8, 49u8, 49u8, 0u8, 77u8, 49u8, 50u8, 0u8,
+                            ])
+                        },
+                        unsafe {
+                            ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 13u8, 0u8, 19u8, 0u8,
+                                24u8, 0u8, 30u8, 0u8, 36u8, 0u8, 40u8, 0u8, 45u8, 0u8, 49u8, 0u8,
+                                54u8, 0u8, 59u8, 0u8, 61u8, 0u8, 84u8, 105u8, 115u8, 104u8, 114u8,
+                                105u8, 72u8, 101u8, 115u8, 104u8, 118u8, 97u8, 110u8, 75u8, 105u8,
+                                115u8, 108u8, 101u8, 118u8, 84u8, 101u8, 118u8, 101u8, 116u8, 83u8,
+                                104u8, 101u8, 118u8, 97u8, 116u8, 65u8, 100u8, 97u8, 114u8, 32u8,
+                                73u8, 65u8, 100u8, 97u8, 114u8, 78u8, 105u8, 115u8, 97u8, 110u8,
+                                73u8, 121u8, 97u8, 114u8, 83u8, 105u8, 118u8, 97u8, 110u8, 84u8,
+                                97u8, 109u8, 117u8, 122u8, 65u8, 118u8, 69u8, 108u8, 117u8, 108u8,
+                            ])
+                        },
+                    )
+                },
+                leap_year_names: unsafe {
+                    #[allow(unused_unsafe)]
+                    ::zerovec::ZeroMap::from_parts_unchecked(
+                        unsafe {
+                            ::zerovec::ZeroVec::from_bytes_unchecked(&[77u8, 48u8, 54u8, 0u8])
+                        },
+                        unsafe {
+                            ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 100u8, 97u8, 114u8, 32u8, 73u8,
+                                73u8,
+                            ])
+                        },
+                    )
+                },
+            },
+        },
+        stand_alone: None,
+    },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Q1"),
+                alloc::borrow::Cow::Borrowed("Q2"),
+                alloc::borrow::Cow::Borrowed("Q3"),
+                alloc::borrow::Cow::Borrowed("Q4"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1"),
+                alloc::borrow::Cow::Borrowed("2"),
+                alloc::borrow::Cow::Borrowed("3"),
+                alloc::borrow::Cow::Borrowed("4"),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1st quarter"),
+                alloc::borrow::Cow::Borrowed("2nd quarter"),
+                alloc::borrow::Cow::Borrowed("3rd quarter"),
+                alloc::borrow::Cow::Borrowed("4th quarter"),
+            ]),
+        },
+        stand_alone: None,
+    },
+    weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Sun"),
+                alloc::borrow::Cow::Borrowed("Mon"),
+                alloc::borrow::Cow::Borrowed("Tue"),
+                alloc::borrow::Cow::Borrowed("Wed"),
+                alloc::borrow::Cow::Borrowed("Thu"),
+                alloc::borrow::Cow::Borrowed("Fri"),
+                alloc::borrow::Cow::Borrowed("Sat"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("S"),
+                alloc::borrow::Cow::Borrowed("M"),
+                alloc::borrow::Cow::Borrowed("T"),
+                alloc::borrow::Cow::Borrowed("W"),
+                alloc::borrow::Cow::Borrowed("T"),
+                alloc::borrow::Cow::Borrowed("F"),
+                alloc::borrow::Cow::Borrowed("S"),
+            ]),
+            short: Some(::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Su"),
+                alloc::borrow::Cow::Borrowed("Mo"),
+                alloc::borrow::Cow::Borrowed("Tu"),
+                alloc::borrow::Cow::Borrowed("We"),
+                alloc::borrow::Cow::Borrowed("Th"),
+                alloc::borrow::Cow::Borrowed("Fr"),
+                alloc::borrow::Cow::Borrowed("Sa"),
+            ])),
+            wide: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Sunday"),
+                alloc::borrow::Cow::Borrowed("Monday"),
+                alloc::borrow::Cow::Borrowed("Tuesday"),
+                alloc::borrow::Cow::Borrowed("Wednesday"),
+                alloc::borrow::Cow::Borrowed("Thursday"),
+                alloc::borrow::Cow::Borrowed("Friday"),
+                alloc::borrow::Cow::Borrowed("Saturday"),
+            ]),
+        },
+        stand_alone: None,
+    },
+    eras: ::icu_datetime::provider::calendar::Eras {
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 109u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 77u8,
+                    ])
+                },
+            )
+        },
+        abbr: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 109u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 77u8,
+                    ])
+                },
+            )
+        },
+        narrow: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 109u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 77u8,
+                    ])
+                },
+            )
+        },
+    },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
+};
+static UND: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
+    months: ::icu_datetime::provider::calendar::months::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::months::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::months::SymbolsV1::LeapYearNames {
+                names: unsafe {
+                    #[allow(unused_unsafe)]
+                    ::zerovec::ZeroMap::from_parts_unchecked(
+                        unsafe {
+                            ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                                77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 50u8, 0u8, 77u8, 48u8, 51u8,
+                                0u8, 77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 53u8, 0u8, 77u8, 48u8,
+                                53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 55u8, 0u8, 77u8,
+                                48u8, 56u8, 0u8, 77u8, 48u8, 57u8, 0u8, 77u8, 49u8, 48u8, 0u8,
+                                77u8, 49u8, 49u8, 0u8, 77u8, 49u8, 50u8, 0u8,
+                            ])
+                        },
+                        unsafe {
+                            ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 13u8, 0u8, 19u8, 0u8,
+                                24u8, 0u8, 30u8, 0u8, 36u8, 0u8, 40u8, 0u8, 45u8, 0u8, 49u8, 0u8,
+                                54u8, 0u8, 59u8, 0u8, 61u8, 0u8, 84u8, 105u8, 115u8, 104u8, 114u8,
+                                105u8, 72u8, 101u8, 115u8, 104u8, 118u8, 97u8, 110u8, 75u8, 105u8,
+                                115u8, 108u8, 101u8, 118u8, 84u8, 101u8, 118u8, 101u8, 116u8, 83u8,
+                                104u8, 101u8, 118u8, 97u8, 116u8, 65u8, 100u8, 97u8, 114u8, 32u8,
+                                73u8, 65u8, 100u8, 97u8, 114u8, 78u8, 105u8, 115u8, 97u8, 110u8,
+                                73u8, 121u8, 97u8, 114u8, 83u8, 105u8, 118u8, 97u8, 110u8, 84u8,
+                                97u8, 109u8, 117u8, 122u8, 65u8, 118u8, 69u8, 108u8, 117u8, 108u8,
+                            ])
+                        },
+                    )
+                },
+                leap_year_names: unsafe {
+                    #[allow(unused_unsafe)]
+                    ::zerovec::ZeroMap::from_parts_unchecked(
+                        unsafe {
+                            ::zerovec::ZeroVec::from_bytes_unchecked(&[77u8, 48u8, 54u8, 0u8])
+                        },
+                        unsafe {
+                            ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 100u8, 97u8, 114u8, 32u8, 73u8,
+                                73u8,
+                            ])
+                        },
+                    )
+                },
+            },
+            narrow: ::icu_datetime::provider::calendar::months::SymbolsV1::LeapYearNames {
+                names: unsafe {
+                    #[allow(unused_unsafe)]
+                    ::zerovec::ZeroMap::from_parts_unchecked(
+                        unsafe {
+                            ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                                77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 50u8, 0u8, 77u8, 48u8, 51u8,
+                                0u8, 77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 53u8, 0u8, 77u8, 48u8,
+                                53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 55u8, 0u8, 77u8,
+                                48u8, 56u8, 0u8, 77u8, 48u8, 57u8, 0u8, 77u8, 49u8, 48u8, 0u8,
+                                77u8, 49u8, 49u8, 0u8, 77u8, 49u8, 50u8, 0u8,
+                            ])
+                        },
+                        unsafe {
+                            ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 1u8, 0u8, 2u8, 0u8, 3u8, 0u8, 4u8,
+                                0u8, 5u8, 0u8, 6u8, 0u8, 7u8, 0u8, 8u8, 0u8, 9u8, 0u8, 11u8, 0u8,
+                                13u8, 0u8, 15u8, 0u8, 49u8, 50u8, 51u8, 52u8, 53u8, 54u8, 55u8,
+                                56u8, 57u8, 49u8, 48u8, 49u8, 49u8, 49u8, 50u8, 49u8, 51u8,
+                            ])
+                        },
+                    )
+                },
+                leap_year_names: unsafe {
+                    #[allow(unused_unsafe)]
+                    ::zerovec::ZeroMap::from_parts_unchecked(
+                        unsafe {
+                            ::zerovec::ZeroVec::from_bytes_unchecked(&[77u8, 48u8, 54u8, 0u8])
+                        },
+                        unsafe {
+                            ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 55u8,
+                            ])
+                        },
+                    )
+                },
+            },
+            short: None,
+            wide: ::icu_datetime::provider::calendar::months::SymbolsV1::LeapYearNames {
+                names: unsafe {
+                    #[allow(unused_unsafe)]
+                    ::zerovec::ZeroMap::from_parts_unchecked(
+                        unsafe {
+                            ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                                77u8, 48u8, 49u8, 0u8, 77u8, 48u8, 50u8, 0u8, 77u8, 48u8, 51u8,
+                                0u8, 77u8, 48u8, 52u8, 0u8, 77u8, 48u8, 53u8, 0u8, 77u8, 48u8,
+                                53u8, 76u8, 77u8, 48u8, 54u8, 0u8, 77u8, 48u8, 55u8, 0u8, 77u8,
+                                48u8, 56u8, 0u8, 77u8, 48u8, 57u8, 0u8, 77u8, 49u8, 48u8, 0u8,
+                                77u8, 49u8, 49u8, 0u8, 77u8, 49u8, 50u8, 0u8,
+                            ])
+                        },
+                        unsafe {
+                            ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                13u8, 0u8, 0u8, 0u8, 0u8, 0u8, 6u8, 0u8, 13u8, 0u8, 19u8, 0u8,
+                                24u8, 0u8, 30u8, 0u8, 36u8, 0u8, 40u8, 0u8, 45u8, 0u8, 49u8, 0u8,
+                                54u8, 0u8, 59u8, 0u8, 61u8, 0u8, 84u8, 105u8, 115u8, 104u8, 114u8,
+                                105u8, 72u8, 101u8, 115u8, 104u8, 118u8, 97u8, 110u8, 75u8, 105u8,
+                                115u8, 108u8, 101u8, 118u8, 84u8, 101u8, 118u8, 101u8, 116u8, 83u8,
+                                104u8, 101u8, 118u8, 97u8, 116u8, 65u8, 100u8, 97u8, 114u8, 32u8,
+                                73u8, 65u8, 100u8, 97u8, 114u8, 78u8, 105u8, 115u8, 97u8, 110u8,
+                                73u8, 121u8, 97u8, 114u8, 83u8, 105u8, 118u8, 97u8, 110u8, 84u8,
+                                97u8, 109u8, 117u8, 122u8, 65u8, 118u8, 69u8, 108u8, 117u8, 108u8,
+                            ])
+                        },
+                    )
+                },
+                leap_year_names: unsafe {
+                    #[allow(unused_unsafe)]
+                    ::zerovec::ZeroMap::from_parts_unchecked(
+                        unsafe {
+                            ::zerovec::ZeroVec::from_bytes_unchecked(&[77u8, 48u8, 54u8, 0u8])
+                        },
+                        unsafe {
+                            ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                                1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 100u8, 97u8, 114u8, 32u8, 73u8,
+                                73u8,
+                            ])
+                        },
+                    )
+                },
+            },
+        },
+        stand_alone: None,
+    },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Q1"),
+                alloc::borrow::Cow::Borrowed("Q2"),
+                alloc::borrow::Cow::Borrowed("Q3"),
+                alloc::borrow::Cow::Borrowed("Q4"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1"),
+                alloc::borrow::Cow::Borrowed("2"),
+                alloc::borrow::Cow::Borrowed("3"),
+                alloc::borrow::Cow::Borrowed("4"),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Q1"),
+                alloc::borrow::Cow::Borrowed("Q2"),
+                alloc::borrow::Cow::Borrowed("Q3"),
+                alloc::borrow::Cow::Borrowed("Q4"),
+            ]),
+        },
+        stand_alone: None,
+    },
+    weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Sun"),
+                alloc::borrow::Cow::Borrowed("Mon"),
+                alloc::borrow::Cow::Borrowed("Tue"),
+                alloc::borrow::Cow::Borrowed("Wed"),
+                alloc::borrow::Cow::Borrowed("Thu"),
+                alloc::borrow::Cow::Borrowed("Fri"),
+                alloc::borrow::Cow::Borrowed("Sat"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("S"),
+                alloc::borrow::Cow::Borrowed("M"),
+                alloc::borrow::Cow::Borrowed("T"),
+                alloc::borrow::Cow::Borrowed("W"),
+                alloc::borrow::Cow::Borrowed("T"),
+                alloc::borrow::Cow::Borrowed("F"),
+                alloc::borrow::Cow::Borrowed("S"),
+            ]),
+            short: Some(::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Sun"),
+                alloc::borrow::Cow::Borrowed("Mon"),
+                alloc::borrow::Cow::Borrowed("Tue"),
+                alloc::borrow::Cow::Borrowed("Wed"),
+                alloc::borrow::Cow::Borrowed("Thu"),
+                alloc::borrow::Cow::Borrowed("Fri"),
+                alloc::borrow::Cow::Borrowed("Sat"),
+            ])),
+            wide: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Sun"),
+                alloc::borrow::Cow::Borrowed("Mon"),
+                alloc::borrow::Cow::Borrowed("Tue"),
+                alloc::borrow::Cow::Borrowed("Wed"),
+                alloc::borrow::Cow::Borrowed("Thu"),
+                alloc::borrow::Cow::Borrowed("Fri"),
+                alloc::borrow::Cow::Borrowed("Sat"),
+            ]),
+        },
+        stand_alone: None,
+    },
+    eras: ::icu_datetime::provider::calendar::Eras {
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 109u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 77u8,
+                    ])
+                },
+            )
+        },
+        abbr: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 109u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 77u8,
+                    ])
+                },
+            )
+        },
+        narrow: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 109u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 77u8,
+                    ])
+                },
+            )
+        },
+    },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
+};
@@ -71,6 +71,30 @@ static AR_AR_EG: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV
         },
         stand_alone: None,
     },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("الربع الأول"),
+                alloc::borrow::Cow::Borrowed("الربع الثاني"),
+                alloc::borrow::Cow::Borrowed("الربع الثالث"),
+                alloc::borrow::Cow::Borrowed("الربع الرابع"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("١"),
+                alloc::borrow::Cow::Borrowed("٢"),
+                alloc::borrow::Cow::Borrowed("٣"),
+                alloc::borrow::Cow::Borrowed("٤"),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("الربع الأول"),
+                alloc::borrow::Cow::Borrowed("الربع الثاني"),
+                alloc::borrow::Cow::Borrowed("الربع الثالث"),
+                alloc::borrow::Cow::Borrowed("الربع الرابع"),
+            ]),
+        },
+        stand_alone: None,
+    },
     weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
         format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
@@ -159,6 +183,11 @@ static AR_AR_EG: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV
             )
         },
     },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
 };
 static BN: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
     months: ::icu_datetime::provider::calendar::months::ContextsV1 {
@@ -209,6 +238,42 @@ static BN: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
         },
         stand_alone: None,
     },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("ত\u{9cd}রৈম\u{9be}সিক"),
+                alloc::borrow::Cow::Borrowed("দ\u{9cd}বিতীয\u{9bc} ত\u{9cd}রৈম\u{9be}সিক"),
+                alloc::borrow::Cow::Borrowed("ত\u{9c3}তীয\u{9bc} ত\u{9cd}রৈম\u{9be}সিক"),
+                alloc::borrow::Cow::Borrowed("চত\u{9c1}র\u{9cd}থ ত\u{9cd}রৈম\u{9be}সিক"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("১"),
+                alloc::borrow::Cow::Borrowed("২"),
+                alloc::borrow::Cow::Borrowed("৩"),
+                alloc::borrow::Cow::Borrowed("৪"),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("ত\u{9cd}রৈম\u{9be}সিক"),
+                alloc::borrow::Cow::Borrowed("দ\u{9cd}বিতীয\u{9bc} ত\u{9cd}রৈম\u{9be}সিক"),
+                alloc::borrow::Cow::Borrowed("ত\u{9c3}তীয\u{9bc} ত\u{9cd}রৈম\u{9be}সিক"),
+                alloc::borrow::Cow::Borrowed("চত\u{9c1}র\u{9cd}থ ত\u{9cd}রৈম\u{9be}সিক"),
+            ]),
+        },
+        stand_alone: Some(
+            ::icu_datetime::provider::calendar::quarters::StandAloneWidthsV1 {
+                abbreviated: Some(::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                    alloc::borrow::Cow::Borrowed("Q1"),
+                    alloc::borrow::Cow::Borrowed("Q2"),
+                    alloc::borrow::Cow::Borrowed("Q3"),
+                    alloc::borrow::Cow::Borrowed("Q4"),
+                ])),
+                narrow: None,
+                short: None,
+                wide: None,
+            },
+        ),
+    },
     weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
         format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
@@ -300,148 +365,13 @@ static BN: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
             )
         },
     },
-};
-static CCP: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
-    months: ::icu_datetime::provider::calendar::months::ContextsV1 {
-        format: ::icu_datetime::provider::calendar::months::FormatWidthsV1 {
-            abbreviated: ::icu_datetime::provider::calendar::months::SymbolsV1::SolarTwelve([
-                alloc::borrow::Cow::Borrowed("𑄌\u{1112e}𑄖\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄝\u{1112e}𑄎𑄬𑄇\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄎\u{11133}𑄠𑄬𑄖\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄃𑄏𑄢\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄥𑄉\u{1112e}𑄚\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄞𑄘\u{11127}"),
-                alloc::borrow::Cow::Borrowed("𑄃𑄏\u{11128}𑄚\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄇𑄘\u{11128}"),
-                alloc::borrow::Cow::Borrowed("𑄃𑄊\u{1112e}𑄚\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄛\u{1112a}𑄌\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄟𑄇\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄜𑄉\u{1112a}𑄚\u{11134}"),
-            ]),
-            narrow: ::icu_datetime::provider::calendar::months::SymbolsV1::SolarTwelve([
-                alloc::borrow::Cow::Borrowed("𑄷"),
-                alloc::borrow::Cow::Borrowed("𑄸"),
-                alloc::borrow::Cow::Borrowed("𑄹"),
-                alloc::borrow::Cow::Borrowed("𑄺"),
-                alloc::borrow::Cow::Borrowed("𑄻"),
-                alloc::borrow::Cow::Borrowed("𑄼"),
-                alloc::borrow::Cow::Borrowed("𑄽"),
-                alloc::borrow::Cow::Borrowed("𑄾"),
-                alloc::borrow::Cow::Borrowed("𑄿"),
-                alloc::borrow::Cow::Borrowed("𑄷𑄶"),
-                alloc::borrow::Cow::Borrowed("𑄷𑄷"),
-                alloc::borrow::Cow::Borrowed("𑄷𑄸"),
-            ]),
-            short: None,
-            wide: ::icu_datetime::provider::calendar::months::SymbolsV1::SolarTwelve([
-                alloc::borrow::Cow::Borrowed("𑄌\u{1112e}𑄖\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄝\u{1112e}𑄎𑄬𑄇\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄎\u{11133}𑄠𑄬𑄖\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄃𑄏𑄢\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄥𑄉\u{1112e}𑄚\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄞𑄘\u{11127}"),
-                alloc::borrow::Cow::Borrowed("𑄃𑄏\u{11128}𑄚\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄇𑄘\u{11128}"),
-                alloc::borrow::Cow::Borrowed("𑄃𑄊\u{1112e}𑄚\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄛\u{1112a}𑄌\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄟𑄇\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄜𑄉\u{1112a}𑄚\u{11134}"),
-            ]),
-        },
-        stand_alone: None,
-    },
-    weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
-        format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
-            abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
-                alloc::borrow::Cow::Borrowed("𑄢\u{11127}𑄝\u{11128}"),
-                alloc::borrow::Cow::Borrowed("𑄥\u{11127}𑄟\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄟\u{11127}\u{11101}𑄉\u{11127}𑄣\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄝\u{1112a}𑄖\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄝\u{11133}𑄢\u{11128}𑄥\u{1112a}𑄛\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄥\u{1112a}𑄇\u{11134}𑄇\u{1112e}𑄢\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄥\u{11127}𑄚\u{11128}"),
-            ]),
-            narrow: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
-                alloc::borrow::Cow::Borrowed("𑄢\u{11127}"),
-                alloc::borrow::Cow::Borrowed("𑄥\u{11127}"),
-                alloc::borrow::Cow::Borrowed("𑄟\u{11127}"),
-                alloc::borrow::Cow::Borrowed("𑄝\u{1112a}"),
-                alloc::borrow::Cow::Borrowed("𑄝\u{11133}𑄢\u{11128}"),
-                alloc::borrow::Cow::Borrowed("𑄥\u{1112a}"),
-                alloc::borrow::Cow::Borrowed("𑄥\u{11127}"),
-            ]),
-            short: Some(::icu_datetime::provider::calendar::weekdays::SymbolsV1([
-                alloc::borrow::Cow::Borrowed("𑄢\u{11127}𑄝\u{11128}"),
-                alloc::borrow::Cow::Borrowed("𑄥\u{11127}𑄟\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄟\u{11127}\u{11101}𑄉\u{11127}𑄣\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄝\u{1112a}𑄖\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄝\u{11133}𑄢\u{11128}𑄥\u{1112a}𑄛\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄥\u{1112a}𑄇\u{11134}𑄇\u{1112e}𑄢\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄥\u{11127}𑄚\u{11128}"),
-            ])),
-            wide: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
-                alloc::borrow::Cow::Borrowed("𑄢\u{11127}𑄝\u{11128}𑄝𑄢\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄥\u{11127}𑄟\u{11134}𑄝𑄢\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄟\u{11127}\u{11101}𑄉\u{11127}𑄣\u{11134}𑄝𑄢\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄝\u{1112a}𑄖\u{11134}𑄝𑄢\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄝\u{11133}𑄢\u{11128}𑄥\u{1112a}𑄛\u{11134}𑄝𑄢\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄥\u{1112a}𑄇\u{11134}𑄇\u{1112e}𑄢\u{11134}𑄝𑄢\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄥\u{11127}𑄚\u{11128}𑄝𑄢\u{11134}"),
-            ]),
-        },
-        stand_alone: None,
-    },
-    eras: ::icu_datetime::provider::calendar::Eras {
-        names: unsafe {
-            #[allow(unused_unsafe)]
-            ::zerovec::ZeroMap::from_parts_unchecked(
-                unsafe {
-                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
-                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 115u8, 97u8, 107u8, 97u8,
-                    ])
-                },
-                unsafe {
-                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
-                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8,
-                        132u8, 163u8, 240u8, 145u8, 132u8, 180u8,
-                    ])
-                },
-            )
-        },
-        abbr: unsafe {
-            #[allow(unused_unsafe)]
-            ::zerovec::ZeroMap::from_parts_unchecked(
-                unsafe {
-                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
-                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 115u8, 97u8, 107u8, 97u8,
-                    ])
-                },
-                unsafe {
-                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
-                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8,
-                        132u8, 163u8, 240u8, 145u8, 132u8, 180u8,
-                    ])
-                },
-            )
-        },
-        narrow: unsafe {
-            #[allow(unused_unsafe)]
-            ::zerovec::ZeroMap::from_parts_unchecked(
-                unsafe {
-                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
-                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 115u8, 97u8, 107u8, 97u8,
-                    ])
-                },
-                unsafe {
-                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
-                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 240u8, 145u8, 132u8, 165u8, 240u8, 145u8,
-                        132u8, 163u8, 240u8, 145u8, 132u8, 180u8,
-                    ])
-                },
-            )
-        },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
     },
 };
+static CCP : & DataStruct = & :: icu_datetime :: provider :: calendar :: DateSymbolsV1 { months : :: icu_datetime :: provider :: calendar :: months :: ContextsV1 { format : :: icu_datetime :: provider :: calendar :: months :: FormatWidthsV1 { abbreviated : :: icu_datetime :: provider :: calendar :: months :: SymbolsV1 :: SolarTwelve ([alloc :: borrow :: Cow :: Borrowed ("𑄌\u{1112e}𑄖\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄝\u{1112e}𑄎𑄬𑄇\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄎\u{11133}𑄠𑄬𑄖\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄃𑄏𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄥𑄉\u{1112e}𑄚\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄞𑄘\u{11127}") , alloc :: borrow :: Cow :: Borrowed ("𑄃𑄏\u{11128}𑄚\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄇𑄘\u{11128}") , alloc :: borrow :: Cow :: Borrowed ("𑄃𑄊\u{1112e}𑄚\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄛\u{1112a}𑄌\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄟𑄇\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄜𑄉\u{1112a}𑄚\u{11134}") ,] ,) , narrow : :: icu_datetime :: provider :: calendar :: months :: SymbolsV1 :: SolarTwelve ([alloc :: borrow :: Cow :: Borrowed ("𑄷") , alloc :: borrow :: Cow :: Borrowed ("𑄸") , alloc :: borrow :: Cow :: Borrowed ("𑄹") , alloc :: borrow :: Cow :: Borrowed ("𑄺") , alloc :: borrow :: Cow :: Borrowed ("𑄻") , alloc :: borrow :: Cow :: Borrowed ("𑄼") , alloc :: borrow :: Cow :: Borrowed ("𑄽") , alloc :: borrow :: Cow :: Borrowed ("𑄾") , alloc :: borrow :: Cow :: Borrowed ("𑄿") , alloc :: borrow :: Cow :: Borrowed ("𑄷𑄶") , alloc :: borrow :: Cow :: Borrowed ("𑄷𑄷") , alloc :: borrow :: Cow :: Borrowed ("𑄷𑄸") ,] ,) , short : None , wide : :: icu_datetime :: provider :: calendar :: months :: SymbolsV1 :: SolarTwelve ([alloc :: borrow :: Cow :: Borrowed ("𑄌\u{1112e}𑄖\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄝\u{1112e}𑄎𑄬𑄇\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄎\u{11133}𑄠𑄬𑄖\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄃𑄏𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄥𑄉\u{1112e}𑄚\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄞𑄘\u{11127}") , alloc :: borrow :: Cow :: Borrowed ("𑄃𑄏\u{11128}𑄚\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄇𑄘\u{11128}") , alloc :: borrow :: Cow :: Borrowed ("𑄃𑄊\u{1112e}𑄚\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄛\u{1112a}𑄌\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄟𑄇\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄜𑄉\u{1112a}𑄚\u{11134}") ,] ,) , } , stand_alone : None , } , quarters : :: icu_datetime :: provider :: calendar :: quarters :: ContextsV1 { format : :: icu_datetime :: provider :: calendar :: quarters :: FormatWidthsV1 { abbreviated : :: icu_datetime :: provider :: calendar :: quarters :: SymbolsV1 ([alloc :: borrow :: Cow :: Borrowed ("Q1") , alloc :: borrow :: Cow :: Borrowed ("Q2") , alloc :: borrow :: Cow :: Borrowed ("Q3") , alloc :: borrow :: Cow :: Borrowed ("Q4") ,] ,) , narrow : :: icu_datetime :: provider :: calendar :: quarters :: SymbolsV1 ([alloc :: borrow :: Cow :: Borrowed ("𑄷") , alloc :: borrow :: Cow :: Borrowed ("𑄸") , alloc :: borrow :: Cow :: Borrowed ("𑄹") , alloc :: borrow :: Cow :: Borrowed ("𑄺") ,] ,) , short : None , wide : :: icu_datetime :: provider :: calendar :: quarters :: SymbolsV1 ([alloc :: borrow :: Cow :: Borrowed ("𑄖\u{11128}𑄚\u{11134}𑄟𑄎\u{11127}𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄘\u{11128} 𑄛\u{11133}𑄆𑄘\u{11133}𑄠𑄬 𑄖\u{11128}𑄚\u{11134}𑄟𑄎\u{11127}𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄖\u{11128}𑄚\u{11134} 𑄛\u{11133}𑄆𑄘\u{11133}𑄠𑄬 𑄖\u{11128}𑄚\u{11134}𑄟𑄎\u{11127}𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄌\u{11133}𑄆𑄬𑄢\u{11134} 𑄛\u{11133}𑄆𑄘\u{11133}𑄠𑄬 𑄖\u{11128}𑄚\u{11134}𑄟𑄎\u{11127}𑄢\u{11134}") ,] ,) , } , stand_alone : None , } , weekdays : :: icu_datetime :: provider :: calendar :: weekdays :: ContextsV1 { format : :: icu_datetime :: provider :: calendar :: weekdays :: FormatWidthsV1 { abbreviated : :: icu_datetime :: provider :: calendar :: weekdays :: SymbolsV1 ([alloc :: borrow :: Cow :: Borrowed ("𑄢\u{11127}𑄝\u{11128}") , alloc :: borrow :: Cow :: Borrowed ("𑄥\u{11127}𑄟\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄟\u{11127}\u{11101}𑄉\u{11127}𑄣\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄝\u{1112a}𑄖\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄝\u{11133}𑄢\u{11128}𑄥\u{1112a}𑄛\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄥\u{1112a}𑄇\u{11134}𑄇\u{1112e}𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄥\u{11127}𑄚\u{11128}") ,] ,) , narrow : :: icu_datetime :: provider :: calendar :: weekdays :: SymbolsV1 ([alloc :: borrow :: Cow :: Borrowed ("𑄢\u{11127}") , alloc :: borrow :: Cow :: Borrowed ("𑄥\u{11127}") , alloc :: borrow :: Cow :: Borrowed ("𑄟\u{11127}") , alloc :: borrow :: Cow :: Borrowed ("𑄝\u{1112a}") , alloc :: borrow :: Cow :: Borrowed ("𑄝\u{11133}𑄢\u{11128}") , alloc :: borrow :: Cow :: Borrowed ("𑄥\u{1112a}") , alloc :: borrow :: Cow :: Borrowed ("𑄥\u{11127}") ,] ,) , short : Some (:: icu_datetime :: provider :: calendar :: weekdays :: SymbolsV1 ([alloc :: borrow :: Cow :: Borrowed ("𑄢\u{11127}𑄝\u{11128}") , alloc :: borrow :: Cow :: Borrowed ("𑄥\u{11127}𑄟\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄟\u{11127}\u{11101}𑄉\u{11127}𑄣\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄝\u{1112a}𑄖\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄝\u{11133}𑄢\u{11128}𑄥\u{1112a}𑄛\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄥\u{1112a}𑄇\u{11134}𑄇\u{1112e}𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄥\u{11127}𑄚\u{11128}") ,] ,)) , wide : :: icu_datetime :: provider :: calendar :: weekdays :: SymbolsV1 ([alloc :: borrow :: Cow :: Borrowed ("𑄢\u{11127}𑄝\u{11128}𑄝𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄥\u{11127}𑄟\u{11134}𑄝𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄟\u{11127}\u{11101}𑄉\u{11127}𑄣\u{11134}𑄝𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄝\u{1112a}𑄖\u{11134}𑄝𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄝\u{11133}𑄢\u{11128}𑄥\u{1112a}𑄛\u{11134}𑄝𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄥\u{1112a}𑄇\u{11134}𑄇\u{1112e}𑄢\u{11134}𑄝𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄥\u{11127}𑄚\u{11128}𑄝𑄢\u{11134}") ,] ,) , } , stand_alone : None , } , eras : :: icu_datetime :: provider :: calendar :: Eras { names : unsafe { # [allow (unused_unsafe)] :: zerovec :: ZeroMap :: from_parts_unchecked (unsafe { :: zerovec :: VarZeroVec :: from_bytes_unchecked (& [1u8 , 0u8 , 0u8 , 0u8 , 0u8 , 0u8 , 115u8 , 97u8 , 107u8 , 97u8]) } , unsafe { :: zerovec :: VarZeroVec :: from_bytes_unchecked (& [1u8 , 0u8 , 0u8 , 0u8 , 0u8 , 0u8 , 240u8 , 145u8 , 132u8 , 165u8 , 240u8 , 145u8 , 132u8 , 163u8 , 240u8 , 145u8 , 132u8 , 180u8]) }) } , abbr : unsafe { # [allow (unused_unsafe)] :: zerovec :: ZeroMap :: from_parts_unchecked (unsafe { :: zerovec :: VarZeroVec :: from_bytes_unchecked (& [1u8 , 0u8 , 0u8 , 0u8 , 0u8 , 0u8 , 115u8 , 97u8 , 107u8 , 97u8]) } , unsafe { :: zerovec :: VarZeroVec :: from_bytes_unchecked (& [1u8 , 0u8 , 0u8 , 0u8 , 0u8 , 0u8 , 240u8 , 145u8 , 132u8 , 165u8 , 240u8 , 145u8 , 132u8 , 163u8 , 240u8 , 145u8 , 132u8 , 180u8]) }) } , narrow : unsafe { # [allow (unused_unsafe)] :: zerovec :: ZeroMap :: from_parts_unchecked (unsafe { :: zerovec :: VarZeroVec :: from_bytes_unchecked (& [1u8 , 0u8 , 0u8 , 0u8 , 0u8 , 0u8 , 115u8 , 97u8 , 107u8 , 97u8]) } , unsafe { :: zerovec :: VarZeroVec :: from_bytes_unchecked (& [1u8 , 0u8 , 0u8 , 0u8 , 0u8 , 0u8 , 240u8 , 145u8 , 132u8 , 165u8 , 240u8 , 145u8 , 132u8 , 163u8 , 240u8 , 145u8 , 132u8 , 180u8]) }) } , } , cyclic_years : :: icu_datetime :: provider :: calendar :: CyclicYears { names : unsafe { :: zerovec :: VarZeroVec :: from_bytes_unchecked (& []) } , abbr : unsafe { :: zerovec :: VarZeroVec :: from_bytes_unchecked (& []) } , narrow : unsafe { :: zerovec :: VarZeroVec :: from_bytes_unchecked (& []) } , } , } ;
 static EN_EN_001_EN_ZA: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
     months: ::icu_datetime::provider::calendar::months::ContextsV1 {
         format: ::icu_datetime::provider::calendar::months::FormatWidthsV1 {
@@ -491,6 +421,30 @@ static EN_EN_001_EN_ZA: &DataStruct = &::icu_datetime::provider::calendar::DateS
         },
         stand_alone: None,
     },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Q1"),
+                alloc::borrow::Cow::Borrowed("Q2"),
+                alloc::borrow::Cow::Borrowed("Q3"),
+                alloc::borrow::Cow::Borrowed("Q4"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1"),
+                alloc::borrow::Cow::Borrowed("2"),
+                alloc::borrow::Cow::Borrowed("3"),
+                alloc::borrow::Cow::Borrowed("4"),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1st quarter"),
+                alloc::borrow::Cow::Borrowed("2nd quarter"),
+                alloc::borrow::Cow::Borrowed("3rd quarter"),
+                alloc::borrow::Cow::Borrowed("4th quarter"),
+            ]),
+        },
+        stand_alone: None,
+    },
     weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
         format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
@@ -579,6 +533,11 @@ static EN_EN_001_EN_ZA: &DataStruct = &::icu_datetime::provider::calendar::DateS
             )
         },
     },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
 };
 static ES: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
     months: ::icu_datetime::provider::calendar::months::ContextsV1 {
@@ -629,6 +588,30 @@ static ES: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
         },
         stand_alone: None,
     },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("T1"),
+                alloc::borrow::Cow::Borrowed("T2"),
+                alloc::borrow::Cow::Borrowed("T3"),
+                alloc::borrow::Cow::Borrowed("T4"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1"),
+                alloc::borrow::Cow::Borrowed("2"),
+                alloc::borrow::Cow::Borrowed("3"),
+                alloc::borrow::Cow::Borrowed("4"),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1.er trimestre"),
+                alloc::borrow::Cow::Borrowed("2.º trimestre"),
+                alloc::borrow::Cow::Borrowed("3.er trimestre"),
+                alloc::borrow::Cow::Borrowed("4.º trimestre"),
+            ]),
+        },
+        stand_alone: None,
+    },
     weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
         format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
@@ -717,6 +700,11 @@ static ES: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
             )
         },
     },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
 };
 static ES_AR: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
     months: ::icu_datetime::provider::calendar::months::ContextsV1 {
@@ -767,6 +755,30 @@ static ES_AR: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
         },
         stand_alone: None,
     },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("T1"),
+                alloc::borrow::Cow::Borrowed("T2"),
+                alloc::borrow::Cow::Borrowed("T3"),
+                alloc::borrow::Cow::Borrowed("T4"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1"),
+                alloc::borrow::Cow::Borrowed("2"),
+                alloc::borrow::Cow::Borrowed("3"),
+                alloc::borrow::Cow::Borrowed("4"),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1.er trimestre"),
+                alloc::borrow::Cow::Borrowed("2.º trimestre"),
+                alloc::borrow::Cow::Borrowed("3.er trimestre"),
+                alloc::borrow::Cow::Borrowed("4.º trimestre"),
+            ]),
+        },
+        stand_alone: None,
+    },
     weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
         format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
@@ -855,6 +867,11 @@ static ES_AR: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
             )
         },
     },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
 };
 static FIL: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
     months: ::icu_datetime::provider::calendar::months::ContextsV1 {
@@ -905,6 +922,30 @@ static FIL: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
         },
         stand_alone: None,
     },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Q1"),
+                alloc::borrow::Cow::Borrowed("Q2"),
+                alloc::borrow::Cow::Borrowed("Q3"),
+                alloc::borrow::Cow::Borrowed("Q4"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1"),
+                alloc::borrow::Cow::Borrowed("2"),
+                alloc::borrow::Cow::Borrowed("3"),
+                alloc::borrow::Cow::Borrowed("4"),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("ika-1 quarter"),
+                alloc::borrow::Cow::Borrowed("ika-2 quarter"),
+                alloc::borrow::Cow::Borrowed("ika-3 quarter"),
+                alloc::borrow::Cow::Borrowed("ika-4 na quarter"),
+            ]),
+        },
+        stand_alone: None,
+    },
     weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
         format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
@@ -993,6 +1034,11 @@ static FIL: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
             )
         },
     },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
 };
 static FR: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
     months: ::icu_datetime::provider::calendar::months::ContextsV1 {
@@ -1043,6 +1089,30 @@ static FR: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
         },
         stand_alone: None,
     },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("T1"),
+                alloc::borrow::Cow::Borrowed("T2"),
+                alloc::borrow::Cow::Borrowed("T3"),
+                alloc::borrow::Cow::Borrowed("T4"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1"),
+                alloc::borrow::Cow::Borrowed("2"),
+                alloc::borrow::Cow::Borrowed("3"),
+                alloc::borrow::Cow::Borrowed("4"),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1er trimestre"),
+                alloc::borrow::Cow::Borrowed("2e trimestre"),
+                alloc::borrow::Cow::Borrowed("3e trimestre"),
+                alloc::borrow::Cow::Borrowed("4e trimestre"),
+            ]),
+        },
+        stand_alone: None,
+    },
     weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
         format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
@@ -1132,6 +1202,11 @@ static FR: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
             )
         },
     },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
 };
 static JA: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
     months: ::icu_datetime::provider::calendar::months::ContextsV1 {
@@ -1182,6 +1257,30 @@ static JA: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
         },
         stand_alone: None,
     },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Q1"),
+                alloc::borrow::Cow::Borrowed("Q2"),
+                alloc::borrow::Cow::Borrowed("Q3"),
+                alloc::borrow::Cow::Borrowed("Q4"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1"),
+                alloc::borrow::Cow::Borrowed("2"),
+                alloc::borrow::Cow::Borrowed("3"),
+                alloc::borrow::Cow::Borrowed("4"),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("第1四半期"),
+                alloc::borrow::Cow::Borrowed("第2四半期"),
+                alloc::borrow::Cow::Borrowed("第3四半期"),
+                alloc::borrow::Cow::Borrowed("第4四半期"),
+            ]),
+        },
+        stand_alone: None,
+    },
     weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
         format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
@@ -1270,6 +1369,11 @@ static JA: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
             )
         },
     },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
 };
 static RU: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
     months: ::icu_datetime::provider::calendar::months::ContextsV1 {
@@ -1320,6 +1424,30 @@ static RU: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
         },
         stand_alone: None,
     },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1-й кв."),
+                alloc::borrow::Cow::Borrowed("2-й кв."),
+                alloc::borrow::Cow::Borrowed("3-й кв."),
+                alloc::borrow::Cow::Borrowed("4-й кв."),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1"),
+                alloc::borrow::Cow::Borrowed("2"),
+                alloc::borrow::Cow::Borrowed("3"),
+                alloc::borrow::Cow::Borrowed("4"),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1-й квартал"),
+                alloc::borrow::Cow::Borrowed("2-й квартал"),
+                alloc::borrow::Cow::Borrowed("3-й квартал"),
+                alloc::borrow::Cow::Borrowed("4-й квартал"),
+            ]),
+        },
+        stand_alone: None,
+    },
     weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
         format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
@@ -1411,6 +1539,11 @@ static RU: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
             )
         },
     },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
 };
 static SR_LATN: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
     months: ::icu_datetime::provider::calendar::months::ContextsV1 {
@@ -1461,6 +1594,30 @@ static SR_LATN: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1
         },
         stand_alone: None,
     },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1. kv."),
+                alloc::borrow::Cow::Borrowed("2. kv."),
+                alloc::borrow::Cow::Borrowed("3. kv."),
+                alloc::borrow::Cow::Borrowed("4. kv."),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1."),
+                alloc::borrow::Cow::Borrowed("2."),
+                alloc::borrow::Cow::Borrowed("3."),
+                alloc::borrow::Cow::Borrowed("4."),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("prvi kvartal"),
+                alloc::borrow::Cow::Borrowed("drugi kvartal"),
+                alloc::borrow::Cow::Borrowed("treći kvartal"),
+                alloc::borrow::Cow::Borrowed("četvrti kvartal"),
+            ]),
+        },
+        stand_alone: None,
+    },
     weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
         format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
@@ -1549,6 +1706,11 @@ static SR_LATN: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1
             )
         },
     },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
 };
 static SR_SR_CYRL: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
     months: ::icu_datetime::provider::calendar::months::ContextsV1 {
@@ -1599,6 +1761,30 @@ static SR_SR_CYRL: &DataStruct = &::icu_datetime::provider::calendar::DateSymbol
         },
         stand_alone: None,
     },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1. кв."),
+                alloc::borrow::Cow::Borrowed("2. кв."),
+                alloc::borrow::Cow::Borrowed("3. кв."),
+                alloc::borrow::Cow::Borrowed("4. кв."),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1."),
+                alloc::borrow::Cow::Borrowed("2."),
+                alloc::borrow::Cow::Borrowed("3."),
+                alloc::borrow::Cow::Borrowed("4."),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("први квартал"),
+                alloc::borrow::Cow::Borrowed("други квартал"),
+                alloc::borrow::Cow::Borrowed("трећи квартал"),
+                alloc::borrow::Cow::Borrowed("четврти квартал"),
+            ]),
+        },
+        stand_alone: None,
+    },
     weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
         format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
@@ -1690,6 +1876,11 @@ static SR_SR_CYRL: &DataStruct = &::icu_datetime::provider::calendar::DateSymbol
             )
         },
     },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
 };
 static TH: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
     months: ::icu_datetime::provider::calendar::months::ContextsV1 {
@@ -1740,6 +1931,30 @@ static TH: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
         },
         stand_alone: None,
     },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("ไตรมาส 1"),
+                alloc::borrow::Cow::Borrowed("ไตรมาส 2"),
+                alloc::borrow::Cow::Borrowed("ไตรมาส 3"),
+                alloc::borrow::Cow::Borrowed("ไตรมาส 4"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1"),
+                alloc::borrow::Cow::Borrowed("2"),
+                alloc::borrow::Cow::Borrowed("3"),
+                alloc::borrow::Cow::Borrowed("4"),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("ไตรมาส 1"),
+                alloc::borrow::Cow::Borrowed("ไตรมาส 2"),
+                alloc::borrow::Cow::Borrowed("ไตรมาส 3"),
+                alloc::borrow::Cow::Borrowed("ไตรมาส 4"),
+            ]),
+        },
+        stand_alone: None,
+    },
     weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
         format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
@@ -1831,6 +2046,11 @@ static TH: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
             )
         },
     },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
 };
 static TR: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
     months: ::icu_datetime::provider::calendar::months::ContextsV1 {
@@ -1881,6 +2101,30 @@ static TR: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
         },
         stand_alone: None,
     },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Ç1"),
+                alloc::borrow::Cow::Borrowed("Ç2"),
+                alloc::borrow::Cow::Borrowed("Ç3"),
+                alloc::borrow::Cow::Borrowed("Ç4"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1."),
+                alloc::borrow::Cow::Borrowed("2."),
+                alloc::borrow::Cow::Borrowed("3."),
+                alloc::borrow::Cow::Borrowed("4."),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1. çeyrek"),
+                alloc::borrow::Cow::Borrowed("2. çeyrek"),
+                alloc::borrow::Cow::Borrowed("3. çeyrek"),
+                alloc::borrow::Cow::Borrowed("4. çeyrek"),
+            ]),
+        },
+        stand_alone: None,
+    },
     weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
         format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
@@ -1969,6 +2213,11 @@ static TR: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
             )
         },
     },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
 };
 static UND: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
     months: ::icu_datetime::provider::calendar::months::ContextsV1 {
@@ -2019,6 +2268,30 @@ static UND: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
         },
         stand_alone: None,
     },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Q1"),
+                alloc::borrow::Cow::Borrowed("Q2"),
+                alloc::borrow::Cow::Borrowed("Q3"),
+                alloc::borrow::Cow::Borrowed("Q4"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1"),
+                alloc::borrow::Cow::Borrowed("2"),
+                alloc::borrow::Cow::Borrowed("3"),
+                alloc::borrow::Cow::Borrowed("4"),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Q1"),
+                alloc::borrow::Cow::Borrowed("Q2"),
+                alloc::borrow::Cow::Borrowed("Q3"),
+                alloc::borrow::Cow::Borrowed("Q4"),
+            ]),
+        },
+        stand_alone: None,
+    },
     weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
         format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
@@ -2107,4 +2380,9 @@ static UND: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
             )
         },
     },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
 };
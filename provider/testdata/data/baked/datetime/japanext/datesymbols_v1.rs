@@ -71,6 +71,30 @@ static AR_AR_EG: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV
         },
         stand_alone: None,
     },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("الربع الأول"),
+                alloc::borrow::Cow::Borrowed("الربع الثاني"),
+                alloc::borrow::Cow::Borrowed("الربع الثالث"),
+                alloc::borrow::Cow::Borrowed("الربع الرابع"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("١"),
+                alloc::borrow::Cow::Borrowed("٢"),
+                alloc::borrow::Cow::Borrowed("٣"),
+                alloc::borrow::Cow::Borrowed("٤"),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("الربع الأول"),
+                alloc::borrow::Cow::Borrowed("الربع الثاني"),
+                alloc::borrow::Cow::Borrowed("الربع الثالث"),
+                alloc::borrow::Cow::Borrowed("الربع الرابع"),
+            ]),
+        },
+        stand_alone: None,
+    },
     weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
         format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
@@ -2034,6 +2058,11 @@ static AR_AR_EG: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV
             )
         },
     },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
 };
 static BN: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
     months: ::icu_datetime::provider::calendar::months::ContextsV1 {
@@ -2106,6 +2135,42 @@ static BN: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
             },
         ),
     },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("ত\u{9cd}রৈম\u{9be}সিক"),
+                alloc::borrow::Cow::Borrowed("দ\u{9cd}বিতীয\u{9bc} ত\u{9cd}রৈম\u{9be}সিক"),
+                alloc::borrow::Cow::Borrowed("ত\u{9c3}তীয\u{9bc} ত\u{9cd}রৈম\u{9be}সিক"),
+                alloc::borrow::Cow::Borrowed("চত\u{9c1}র\u{9cd}থ ত\u{9cd}রৈম\u{9be}সিক"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("১"),
+                alloc::borrow::Cow::Borrowed("২"),
+                alloc::borrow::Cow::Borrowed("৩"),
+                alloc::borrow::Cow::Borrowed("৪"),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("ত\u{9cd}রৈম\u{9be}সিক"),
+                alloc::borrow::Cow::Borrowed("দ\u{9cd}বিতীয\u{9bc} ত\u{9cd}রৈম\u{9be}সিক"),
+                alloc::borrow::Cow::Borrowed("ত\u{9c3}তীয\u{9bc} ত\u{9cd}রৈম\u{9be}সিক"),
+                alloc::borrow::Cow::Borrowed("চত\u{9c1}র\u{9cd}থ ত\u{9cd}রৈম\u{9be}সিক"),
+            ]),
+        },
+        stand_alone: Some(
+            ::icu_datetime::provider::calendar::quarters::StandAloneWidthsV1 {
+                abbreviated: Some(::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                    alloc::borrow::Cow::Borrowed("Q1"),
+                    alloc::borrow::Cow::Borrowed("Q2"),
+                    alloc::borrow::Cow::Borrowed("Q3"),
+                    alloc::borrow::Cow::Borrowed("Q4"),
+                ])),
+                narrow: None,
+                short: None,
+                wide: None,
+            },
+        ),
+    },
     weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
         format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
@@ -3843,2680 +3908,373 @@ static BN: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
                         45u8, 57u8, 52u8, 55u8, 116u8, 101u8, 110u8, 115u8, 104u8, 111u8, 45u8,
                         49u8, 49u8, 51u8, 49u8, 116u8, 101u8, 110u8, 115u8, 104u8, 111u8, 45u8,
                         49u8, 53u8, 55u8, 51u8, 116u8, 101u8, 110u8, 116u8, 111u8, 107u8, 117u8,
-                        45u8, 57u8, 53u8, 55u8, 116u8, 101u8, 110u8, 121u8, 111u8, 45u8, 49u8,
-                        49u8, 52u8, 52u8, 116u8, 111u8, 107u8, 117u8, 106u8, 105u8, 45u8, 49u8,
-                        51u8, 48u8, 54u8, 119u8, 97u8, 100u8, 111u8, 45u8, 55u8, 48u8, 56u8, 121u8,
-                        111u8, 114u8, 111u8, 45u8, 55u8, 49u8, 55u8, 121u8, 111u8, 119u8, 97u8,
-                        45u8, 49u8, 49u8, 56u8, 49u8,
-                    ])
-                },
-                unsafe {
-                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
-                        239u8, 0u8, 0u8, 0u8, 0u8, 0u8, 21u8, 0u8, 40u8, 0u8, 56u8, 0u8, 75u8, 0u8,
-                        94u8, 0u8, 130u8, 0u8, 152u8, 0u8, 173u8, 0u8, 195u8, 0u8, 214u8, 0u8,
-                        233u8, 0u8, 252u8, 0u8, 17u8, 1u8, 37u8, 1u8, 59u8, 1u8, 79u8, 1u8, 100u8,
-                        1u8, 122u8, 1u8, 142u8, 1u8, 163u8, 1u8, 190u8, 1u8, 211u8, 1u8, 231u8,
-                        1u8, 251u8, 1u8, 16u8, 2u8, 38u8, 2u8, 60u8, 2u8, 82u8, 2u8, 105u8, 2u8,
-                        127u8, 2u8, 147u8, 2u8, 167u8, 2u8, 185u8, 2u8, 204u8, 2u8, 224u8, 2u8,
-                        240u8, 2u8, 3u8, 3u8, 21u8, 3u8, 38u8, 3u8, 58u8, 3u8, 78u8, 3u8, 97u8,
-                        3u8, 116u8, 3u8, 136u8, 3u8, 157u8, 3u8, 177u8, 3u8, 197u8, 3u8, 213u8,
-                        3u8, 231u8, 3u8, 250u8, 3u8, 10u8, 4u8, 30u8, 4u8, 50u8, 4u8, 70u8, 4u8,
-                        91u8, 4u8, 110u8, 4u8, 129u8, 4u8, 149u8, 4u8, 168u8, 4u8, 188u8, 4u8,
-                        209u8, 4u8, 231u8, 4u8, 250u8, 4u8, 13u8, 5u8, 33u8, 5u8, 53u8, 5u8, 74u8,
-                        5u8, 93u8, 5u8, 113u8, 5u8, 132u8, 5u8, 153u8, 5u8, 175u8, 5u8, 196u8, 5u8,
-                        215u8, 5u8, 234u8, 5u8, 253u8, 5u8, 254u8, 5u8, 17u8, 6u8, 36u8, 6u8, 55u8,
-                        6u8, 75u8, 6u8, 94u8, 6u8, 111u8, 6u8, 132u8, 6u8, 153u8, 6u8, 171u8, 6u8,
-                        194u8, 6u8, 211u8, 6u8, 232u8, 6u8, 252u8, 6u8, 15u8, 7u8, 33u8, 7u8, 53u8,
-                        7u8, 71u8, 7u8, 89u8, 7u8, 110u8, 7u8, 131u8, 7u8, 150u8, 7u8, 169u8, 7u8,
-                        190u8, 7u8, 207u8, 7u8, 225u8, 7u8, 243u8, 7u8, 6u8, 8u8, 25u8, 8u8, 42u8,
-                        8u8, 61u8, 8u8, 82u8, 8u8, 102u8, 8u8, 124u8, 8u8, 146u8, 8u8, 166u8, 8u8,
-                        185u8, 8u8, 204u8, 8u8, 224u8, 8u8, 241u8, 8u8, 5u8, 9u8, 25u8, 9u8, 44u8,
-                        9u8, 64u8, 9u8, 85u8, 9u8, 106u8, 9u8, 124u8, 9u8, 144u8, 9u8, 165u8, 9u8,
-                        185u8, 9u8, 204u8, 9u8, 223u8, 9u8, 244u8, 9u8, 7u8, 10u8, 24u8, 10u8,
-                        45u8, 10u8, 67u8, 10u8, 87u8, 10u8, 106u8, 10u8, 127u8, 10u8, 146u8, 10u8,
-                        166u8, 10u8, 186u8, 10u8, 208u8, 10u8, 229u8, 10u8, 248u8, 10u8, 13u8,
-                        11u8, 33u8, 11u8, 53u8, 11u8, 71u8, 11u8, 90u8, 11u8, 109u8, 11u8, 128u8,
-                        11u8, 149u8, 11u8, 167u8, 11u8, 186u8, 11u8, 208u8, 11u8, 229u8, 11u8,
-                        248u8, 11u8, 11u8, 12u8, 32u8, 12u8, 54u8, 12u8, 76u8, 12u8, 96u8, 12u8,
-                        116u8, 12u8, 136u8, 12u8, 158u8, 12u8, 177u8, 12u8, 196u8, 12u8, 197u8,
-                        12u8, 216u8, 12u8, 237u8, 12u8, 2u8, 13u8, 23u8, 13u8, 42u8, 13u8, 64u8,
-                        13u8, 83u8, 13u8, 100u8, 13u8, 117u8, 13u8, 137u8, 13u8, 157u8, 13u8,
-                        175u8, 13u8, 193u8, 13u8, 212u8, 13u8, 232u8, 13u8, 248u8, 13u8, 9u8, 14u8,
-                        10u8, 14u8, 32u8, 14u8, 50u8, 14u8, 70u8, 14u8, 90u8, 14u8, 112u8, 14u8,
-                        134u8, 14u8, 155u8, 14u8, 176u8, 14u8, 196u8, 14u8, 216u8, 14u8, 236u8,
-                        14u8, 0u8, 15u8, 20u8, 15u8, 43u8, 15u8, 64u8, 15u8, 83u8, 15u8, 105u8,
-                        15u8, 106u8, 15u8, 126u8, 15u8, 145u8, 15u8, 164u8, 15u8, 182u8, 15u8,
-                        199u8, 15u8, 200u8, 15u8, 218u8, 15u8, 238u8, 15u8, 1u8, 16u8, 21u8, 16u8,
-                        41u8, 16u8, 59u8, 16u8, 78u8, 16u8, 97u8, 16u8, 116u8, 16u8, 135u8, 16u8,
-                        155u8, 16u8, 174u8, 16u8, 194u8, 16u8, 212u8, 16u8, 232u8, 16u8, 253u8,
-                        16u8, 16u8, 17u8, 41u8, 17u8, 66u8, 17u8, 92u8, 17u8, 119u8, 17u8, 138u8,
-                        17u8, 158u8, 17u8, 179u8, 17u8, 200u8, 17u8, 219u8, 17u8, 242u8, 17u8, 6u8,
-                        18u8, 23u8, 18u8, 41u8, 18u8, 65u8, 110u8, 226u8, 128u8, 153u8, 101u8,
-                        105u8, 32u8, 40u8, 49u8, 55u8, 55u8, 50u8, 226u8, 128u8, 147u8, 49u8, 55u8,
-                        56u8, 49u8, 41u8, 65u8, 110u8, 103u8, 101u8, 110u8, 32u8, 40u8, 49u8, 49u8,
-                        55u8, 53u8, 226u8, 128u8, 147u8, 49u8, 49u8, 55u8, 55u8, 41u8, 65u8, 110u8,
-                        110u8, 97u8, 32u8, 40u8, 57u8, 54u8, 56u8, 226u8, 128u8, 147u8, 57u8, 55u8,
-                        48u8, 41u8, 65u8, 110u8, 115u8, 101u8, 105u8, 32u8, 40u8, 49u8, 56u8, 53u8,
-                        52u8, 226u8, 128u8, 147u8, 49u8, 56u8, 54u8, 48u8, 41u8, 65u8, 110u8,
-                        116u8, 101u8, 105u8, 32u8, 40u8, 49u8, 50u8, 50u8, 55u8, 226u8, 128u8,
-                        147u8, 49u8, 50u8, 50u8, 57u8, 41u8, 224u8, 166u8, 150u8, 224u8, 167u8,
-                        141u8, 224u8, 166u8, 176u8, 224u8, 166u8, 191u8, 224u8, 166u8, 184u8,
-                        224u8, 167u8, 141u8, 224u8, 166u8, 159u8, 224u8, 166u8, 170u8, 224u8,
-                        167u8, 130u8, 224u8, 166u8, 176u8, 224u8, 167u8, 141u8, 224u8, 166u8,
-                        172u8, 66u8, 117u8, 110u8, 226u8, 128u8, 153u8, 97u8, 110u8, 32u8, 40u8,
-                        49u8, 52u8, 52u8, 52u8, 226u8, 128u8, 147u8, 49u8, 52u8, 52u8, 57u8, 41u8,
-                        66u8, 117u8, 110u8, 99u8, 104u8, 197u8, 171u8, 32u8, 40u8, 49u8, 51u8,
-                        55u8, 50u8, 226u8, 128u8, 147u8, 49u8, 51u8, 55u8, 53u8, 41u8, 66u8, 117u8,
-                        110u8, 226u8, 128u8, 153u8, 101u8, 105u8, 32u8, 40u8, 49u8, 50u8, 54u8,
-                        52u8, 226u8, 128u8, 147u8, 49u8, 50u8, 55u8, 53u8, 41u8, 66u8, 117u8,
-                        110u8, 106u8, 105u8, 32u8, 40u8, 49u8, 49u8, 56u8, 53u8, 226u8, 128u8,
-                        147u8, 49u8, 49u8, 57u8, 48u8, 41u8, 66u8, 117u8, 110u8, 107u8, 97u8, 32u8,
-                        40u8, 49u8, 56u8, 48u8, 52u8, 226u8, 128u8, 147u8, 49u8, 56u8, 49u8, 56u8,
-                        41u8, 66u8, 117u8, 110u8, 107u8, 105u8, 32u8, 40u8, 49u8, 53u8, 48u8, 49u8,
-                        226u8, 128u8, 147u8, 49u8, 53u8, 48u8, 52u8, 41u8, 66u8, 117u8, 110u8,
-                        107u8, 121u8, 197u8, 171u8, 32u8, 40u8, 49u8, 56u8, 54u8, 49u8, 226u8,
-                        128u8, 147u8, 49u8, 56u8, 54u8, 52u8, 41u8, 66u8, 117u8, 110u8, 109u8,
-                        101u8, 105u8, 32u8, 40u8, 49u8, 52u8, 54u8, 57u8, 226u8, 128u8, 147u8,
-                        49u8, 52u8, 56u8, 55u8, 41u8, 66u8, 117u8, 110u8, 226u8, 128u8, 153u8,
-                        197u8, 141u8, 32u8, 40u8, 49u8, 50u8, 54u8, 48u8, 226u8, 128u8, 147u8,
-                        49u8, 50u8, 54u8, 49u8, 41u8, 66u8, 117u8, 110u8, 112u8, 197u8, 141u8,
-                        32u8, 40u8, 49u8, 51u8, 49u8, 55u8, 226u8, 128u8, 147u8, 49u8, 51u8, 49u8,
-                        57u8, 41u8, 66u8, 117u8, 110u8, 114u8, 111u8, 107u8, 117u8, 32u8, 40u8,
-                        49u8, 53u8, 57u8, 50u8, 226u8, 128u8, 147u8, 49u8, 53u8, 57u8, 54u8, 41u8,
-                        66u8, 117u8, 110u8, 114u8, 121u8, 97u8, 107u8, 117u8, 32u8, 40u8, 49u8,
-                        50u8, 51u8, 52u8, 226u8, 128u8, 147u8, 49u8, 50u8, 51u8, 53u8, 41u8, 66u8,
-                        117u8, 110u8, 115u8, 101u8, 105u8, 32u8, 40u8, 49u8, 56u8, 49u8, 56u8,
-                        226u8, 128u8, 147u8, 49u8, 56u8, 51u8, 48u8, 41u8, 66u8, 117u8, 110u8,
-                        115u8, 104u8, 197u8, 141u8, 32u8, 40u8, 49u8, 52u8, 54u8, 54u8, 226u8,
-                        128u8, 147u8, 49u8, 52u8, 54u8, 55u8, 41u8, 224u8, 166u8, 150u8, 224u8,
-                        167u8, 131u8, 224u8, 166u8, 183u8, 224u8, 167u8, 141u8, 224u8, 166u8,
-                        159u8, 224u8, 166u8, 190u8, 224u8, 166u8, 172u8, 224u8, 167u8, 141u8,
-                        224u8, 166u8, 166u8, 67u8, 104u8, 197u8, 141u8, 103u8, 101u8, 110u8, 32u8,
-                        40u8, 49u8, 48u8, 50u8, 56u8, 226u8, 128u8, 147u8, 49u8, 48u8, 51u8, 55u8,
-                        41u8, 67u8, 104u8, 197u8, 141u8, 104u8, 197u8, 141u8, 32u8, 40u8, 57u8,
-                        57u8, 57u8, 226u8, 128u8, 147u8, 49u8, 48u8, 48u8, 52u8, 41u8, 67u8, 104u8,
-                        197u8, 141u8, 106u8, 105u8, 32u8, 40u8, 49u8, 49u8, 48u8, 52u8, 226u8,
-                        128u8, 147u8, 49u8, 49u8, 48u8, 54u8, 41u8, 67u8, 104u8, 197u8, 141u8,
-                        107u8, 97u8, 110u8, 32u8, 40u8, 49u8, 49u8, 54u8, 51u8, 226u8, 128u8,
-                        147u8, 49u8, 49u8, 54u8, 53u8, 41u8, 67u8, 104u8, 197u8, 141u8, 107u8,
-                        121u8, 197u8, 141u8, 32u8, 40u8, 49u8, 52u8, 56u8, 55u8, 226u8, 128u8,
-                        147u8, 49u8, 52u8, 56u8, 57u8, 41u8, 67u8, 104u8, 197u8, 141u8, 107u8,
-                        121u8, 197u8, 171u8, 32u8, 40u8, 49u8, 48u8, 52u8, 48u8, 226u8, 128u8,
-                        147u8, 49u8, 48u8, 52u8, 52u8, 41u8, 67u8, 104u8, 197u8, 141u8, 114u8,
-                        111u8, 107u8, 117u8, 32u8, 40u8, 49u8, 52u8, 53u8, 55u8, 226u8, 128u8,
-                        147u8, 49u8, 52u8, 54u8, 48u8, 41u8, 67u8, 104u8, 197u8, 141u8, 114u8,
-                        121u8, 97u8, 107u8, 117u8, 32u8, 40u8, 49u8, 48u8, 51u8, 55u8, 226u8,
-                        128u8, 147u8, 49u8, 48u8, 52u8, 48u8, 41u8, 67u8, 104u8, 197u8, 141u8,
-                        115u8, 104u8, 197u8, 141u8, 32u8, 40u8, 49u8, 49u8, 51u8, 50u8, 226u8,
-                        128u8, 147u8, 49u8, 49u8, 51u8, 53u8, 41u8, 67u8, 104u8, 197u8, 141u8,
-                        116u8, 111u8, 107u8, 117u8, 32u8, 40u8, 57u8, 57u8, 53u8, 226u8, 128u8,
-                        147u8, 57u8, 57u8, 57u8, 41u8, 67u8, 104u8, 197u8, 141u8, 119u8, 97u8,
-                        32u8, 40u8, 49u8, 48u8, 49u8, 50u8, 226u8, 128u8, 147u8, 49u8, 48u8, 49u8,
-                        55u8, 41u8, 68u8, 97u8, 105u8, 100u8, 197u8, 141u8, 32u8, 40u8, 56u8, 48u8,
-                        54u8, 226u8, 128u8, 147u8, 56u8, 49u8, 48u8, 41u8, 68u8, 97u8, 105u8,
-                        106u8, 105u8, 32u8, 40u8, 49u8, 49u8, 50u8, 54u8, 226u8, 128u8, 147u8,
-                        49u8, 49u8, 51u8, 49u8, 41u8, 69u8, 105u8, 99u8, 104u8, 197u8, 141u8, 32u8,
-                        40u8, 49u8, 48u8, 57u8, 54u8, 226u8, 128u8, 147u8, 49u8, 48u8, 57u8, 55u8,
-                        41u8, 69u8, 105u8, 101u8, 110u8, 32u8, 40u8, 57u8, 56u8, 55u8, 226u8,
-                        128u8, 147u8, 57u8, 56u8, 57u8, 41u8, 69u8, 105u8, 104u8, 197u8, 141u8,
-                        32u8, 40u8, 49u8, 48u8, 56u8, 49u8, 226u8, 128u8, 147u8, 49u8, 48u8, 56u8,
-                        52u8, 41u8, 69u8, 105u8, 106u8, 105u8, 32u8, 40u8, 49u8, 49u8, 52u8, 49u8,
-                        226u8, 128u8, 147u8, 49u8, 49u8, 52u8, 50u8, 41u8, 69u8, 105u8, 107u8,
-                        97u8, 110u8, 32u8, 40u8, 57u8, 56u8, 51u8, 226u8, 128u8, 147u8, 57u8, 56u8,
-                        53u8, 41u8, 69u8, 105u8, 107u8, 121u8, 197u8, 141u8, 32u8, 40u8, 49u8,
-                        52u8, 50u8, 57u8, 226u8, 128u8, 147u8, 49u8, 52u8, 52u8, 49u8, 41u8, 69u8,
-                        105u8, 107u8, 121u8, 197u8, 171u8, 32u8, 40u8, 49u8, 49u8, 49u8, 51u8,
-                        226u8, 128u8, 147u8, 49u8, 49u8, 49u8, 56u8, 41u8, 69u8, 105u8, 109u8,
-                        97u8, 110u8, 32u8, 40u8, 49u8, 49u8, 54u8, 53u8, 226u8, 128u8, 147u8, 49u8,
-                        49u8, 54u8, 54u8, 41u8, 69u8, 105u8, 110u8, 105u8, 110u8, 32u8, 40u8, 49u8,
-                        50u8, 57u8, 51u8, 226u8, 128u8, 147u8, 49u8, 50u8, 57u8, 57u8, 41u8, 69u8,
-                        105u8, 114u8, 111u8, 107u8, 117u8, 32u8, 40u8, 49u8, 53u8, 53u8, 56u8,
-                        226u8, 128u8, 147u8, 49u8, 53u8, 55u8, 48u8, 41u8, 69u8, 105u8, 114u8,
-                        121u8, 97u8, 107u8, 117u8, 32u8, 40u8, 49u8, 49u8, 54u8, 48u8, 226u8,
-                        128u8, 147u8, 49u8, 49u8, 54u8, 49u8, 41u8, 69u8, 105u8, 115u8, 104u8,
-                        197u8, 141u8, 32u8, 40u8, 49u8, 48u8, 52u8, 54u8, 226u8, 128u8, 147u8,
-                        49u8, 48u8, 53u8, 51u8, 41u8, 69u8, 105u8, 115u8, 104u8, 197u8, 141u8,
-                        32u8, 40u8, 49u8, 53u8, 48u8, 52u8, 226u8, 128u8, 147u8, 49u8, 53u8, 50u8,
-                        49u8, 41u8, 69u8, 105u8, 115u8, 111u8, 32u8, 40u8, 57u8, 56u8, 57u8, 226u8,
-                        128u8, 147u8, 57u8, 57u8, 48u8, 41u8, 69u8, 110u8, 99u8, 104u8, 197u8,
-                        141u8, 32u8, 40u8, 57u8, 50u8, 51u8, 226u8, 128u8, 147u8, 57u8, 51u8, 49u8,
-                        41u8, 69u8, 110u8, 103u8, 101u8, 110u8, 32u8, 40u8, 49u8, 51u8, 51u8, 54u8,
-                        226u8, 128u8, 147u8, 49u8, 51u8, 52u8, 48u8, 41u8, 69u8, 110u8, 103u8,
-                        105u8, 32u8, 40u8, 57u8, 48u8, 49u8, 226u8, 128u8, 147u8, 57u8, 50u8, 51u8,
-                        41u8, 69u8, 110u8, 107u8, 121u8, 197u8, 141u8, 32u8, 40u8, 49u8, 51u8,
-                        48u8, 56u8, 226u8, 128u8, 147u8, 49u8, 51u8, 49u8, 49u8, 41u8, 69u8, 110u8,
-                        107u8, 121u8, 197u8, 141u8, 32u8, 40u8, 49u8, 55u8, 52u8, 52u8, 226u8,
-                        128u8, 147u8, 49u8, 55u8, 52u8, 56u8, 41u8, 69u8, 110u8, 107u8, 121u8,
-                        197u8, 171u8, 32u8, 40u8, 49u8, 48u8, 54u8, 57u8, 226u8, 128u8, 147u8,
-                        49u8, 48u8, 55u8, 52u8, 41u8, 69u8, 110u8, 226u8, 128u8, 153u8, 197u8,
-                        141u8, 32u8, 40u8, 49u8, 50u8, 51u8, 57u8, 226u8, 128u8, 147u8, 49u8, 50u8,
-                        52u8, 48u8, 41u8, 69u8, 110u8, 112u8, 197u8, 141u8, 32u8, 40u8, 49u8, 54u8,
-                        55u8, 51u8, 226u8, 128u8, 147u8, 49u8, 54u8, 56u8, 49u8, 41u8, 69u8, 110u8,
-                        114u8, 121u8, 97u8, 107u8, 117u8, 32u8, 40u8, 55u8, 56u8, 50u8, 226u8,
-                        128u8, 147u8, 56u8, 48u8, 54u8, 41u8, 69u8, 110u8, 116u8, 111u8, 107u8,
-                        117u8, 32u8, 40u8, 49u8, 52u8, 56u8, 57u8, 226u8, 128u8, 147u8, 49u8, 52u8,
-                        57u8, 50u8, 41u8, 71u8, 97u8, 110u8, 103u8, 121u8, 197u8, 141u8, 32u8,
-                        40u8, 56u8, 55u8, 55u8, 226u8, 128u8, 147u8, 56u8, 56u8, 53u8, 41u8, 71u8,
-                        101u8, 110u8, 98u8, 117u8, 110u8, 32u8, 40u8, 49u8, 55u8, 51u8, 54u8,
-                        226u8, 128u8, 147u8, 49u8, 55u8, 52u8, 49u8, 41u8, 71u8, 101u8, 110u8,
-                        99u8, 104u8, 197u8, 171u8, 32u8, 40u8, 49u8, 51u8, 56u8, 52u8, 226u8,
-                        128u8, 147u8, 49u8, 51u8, 57u8, 50u8, 41u8, 71u8, 101u8, 110u8, 226u8,
-                        128u8, 153u8, 101u8, 105u8, 32u8, 40u8, 49u8, 49u8, 49u8, 56u8, 226u8,
-                        128u8, 147u8, 49u8, 49u8, 50u8, 48u8, 41u8, 71u8, 101u8, 110u8, 106u8,
-                        105u8, 32u8, 40u8, 49u8, 56u8, 54u8, 52u8, 226u8, 128u8, 147u8, 49u8, 56u8,
-                        54u8, 53u8, 41u8, 71u8, 101u8, 110u8, 107u8, 105u8, 32u8, 40u8, 49u8, 53u8,
-                        55u8, 48u8, 226u8, 128u8, 147u8, 49u8, 53u8, 55u8, 51u8, 41u8, 71u8, 101u8,
-                        110u8, 107u8, 197u8, 141u8, 32u8, 40u8, 49u8, 51u8, 50u8, 49u8, 226u8,
-                        128u8, 147u8, 49u8, 51u8, 50u8, 52u8, 41u8, 71u8, 101u8, 110u8, 107u8,
-                        197u8, 141u8, 32u8, 40u8, 49u8, 51u8, 51u8, 49u8, 226u8, 128u8, 147u8,
-                        49u8, 51u8, 51u8, 52u8, 41u8, 71u8, 101u8, 110u8, 107u8, 121u8, 197u8,
-                        171u8, 32u8, 40u8, 49u8, 50u8, 48u8, 52u8, 226u8, 128u8, 147u8, 49u8, 50u8,
-                        48u8, 54u8, 41u8, 71u8, 101u8, 110u8, 110u8, 97u8, 32u8, 40u8, 49u8, 54u8,
-                        49u8, 53u8, 226u8, 128u8, 147u8, 49u8, 54u8, 50u8, 52u8, 41u8, 71u8, 101u8,
-                        110u8, 110u8, 105u8, 110u8, 32u8, 40u8, 49u8, 50u8, 50u8, 52u8, 226u8,
-                        128u8, 147u8, 49u8, 50u8, 50u8, 53u8, 41u8, 71u8, 101u8, 110u8, 197u8,
-                        141u8, 32u8, 40u8, 49u8, 51u8, 49u8, 57u8, 226u8, 128u8, 147u8, 49u8, 51u8,
-                        50u8, 49u8, 41u8, 71u8, 101u8, 110u8, 114u8, 111u8, 107u8, 117u8, 32u8,
-                        40u8, 49u8, 54u8, 56u8, 56u8, 226u8, 128u8, 147u8, 49u8, 55u8, 48u8, 52u8,
-                        41u8, 71u8, 101u8, 110u8, 114u8, 121u8, 97u8, 107u8, 117u8, 32u8, 40u8,
-                        49u8, 49u8, 56u8, 52u8, 226u8, 128u8, 147u8, 49u8, 49u8, 56u8, 53u8, 41u8,
-                        71u8, 101u8, 110u8, 116u8, 111u8, 107u8, 117u8, 32u8, 40u8, 49u8, 51u8,
-                        50u8, 57u8, 226u8, 128u8, 147u8, 49u8, 51u8, 51u8, 49u8, 41u8, 72u8, 97u8,
-                        107u8, 117u8, 99u8, 104u8, 105u8, 32u8, 40u8, 54u8, 53u8, 48u8, 226u8,
-                        128u8, 147u8, 54u8, 55u8, 49u8, 41u8, 72u8, 97u8, 107u8, 117u8, 104u8,
-                        197u8, 141u8, 32u8, 40u8, 54u8, 55u8, 50u8, 226u8, 128u8, 147u8, 54u8,
-                        56u8, 54u8, 41u8, 72u8, 101u8, 105u8, 106u8, 105u8, 32u8, 40u8, 49u8, 49u8,
-                        53u8, 57u8, 226u8, 128u8, 147u8, 49u8, 49u8, 54u8, 48u8, 41u8, 72u8, 72u8,
-                        197u8, 141u8, 97u8, 110u8, 32u8, 40u8, 49u8, 49u8, 50u8, 48u8, 226u8,
-                        128u8, 147u8, 49u8, 49u8, 50u8, 52u8, 41u8, 72u8, 197u8, 141u8, 101u8,
-                        105u8, 32u8, 40u8, 49u8, 55u8, 48u8, 52u8, 226u8, 128u8, 147u8, 49u8, 55u8,
-                        49u8, 49u8, 41u8, 72u8, 197u8, 141u8, 101u8, 110u8, 32u8, 40u8, 49u8, 49u8,
-                        51u8, 53u8, 226u8, 128u8, 147u8, 49u8, 49u8, 52u8, 49u8, 41u8, 72u8, 197u8,
-                        141u8, 103u8, 101u8, 110u8, 32u8, 40u8, 49u8, 49u8, 53u8, 54u8, 226u8,
-                        128u8, 147u8, 49u8, 49u8, 53u8, 57u8, 41u8, 72u8, 197u8, 141u8, 106u8,
-                        105u8, 32u8, 40u8, 49u8, 50u8, 52u8, 55u8, 226u8, 128u8, 147u8, 49u8, 50u8,
-                        52u8, 57u8, 41u8, 72u8, 197u8, 141u8, 107u8, 105u8, 32u8, 40u8, 55u8, 55u8,
-                        48u8, 226u8, 128u8, 147u8, 55u8, 56u8, 48u8, 41u8, 72u8, 197u8, 141u8,
-                        114u8, 101u8, 107u8, 105u8, 32u8, 40u8, 49u8, 55u8, 53u8, 49u8, 226u8,
-                        128u8, 147u8, 49u8, 55u8, 54u8, 52u8, 41u8, 72u8, 197u8, 141u8, 116u8,
-                        111u8, 107u8, 117u8, 32u8, 40u8, 49u8, 52u8, 52u8, 57u8, 226u8, 128u8,
-                        147u8, 49u8, 52u8, 53u8, 50u8, 41u8, 74u8, 105u8, 97u8, 110u8, 32u8, 40u8,
-                        49u8, 48u8, 50u8, 49u8, 226u8, 128u8, 147u8, 49u8, 48u8, 50u8, 52u8, 41u8,
-                        74u8, 105u8, 110u8, 103u8, 111u8, 45u8, 107u8, 101u8, 105u8, 117u8, 110u8,
-                        32u8, 40u8, 55u8, 54u8, 55u8, 226u8, 128u8, 147u8, 55u8, 55u8, 48u8, 41u8,
-                        74u8, 105u8, 110u8, 107u8, 105u8, 32u8, 40u8, 55u8, 50u8, 52u8, 226u8,
-                        128u8, 147u8, 55u8, 50u8, 57u8, 41u8, 74u8, 105u8, 114u8, 121u8, 97u8,
-                        107u8, 117u8, 32u8, 40u8, 49u8, 48u8, 54u8, 53u8, 226u8, 128u8, 147u8,
-                        49u8, 48u8, 54u8, 57u8, 41u8, 74u8, 105u8, 115u8, 104u8, 197u8, 141u8,
-                        32u8, 40u8, 49u8, 49u8, 55u8, 55u8, 226u8, 128u8, 147u8, 49u8, 49u8, 56u8,
-                        49u8, 41u8, 74u8, 197u8, 141u8, 101u8, 105u8, 32u8, 40u8, 49u8, 50u8, 51u8,
-                        50u8, 226u8, 128u8, 147u8, 49u8, 50u8, 51u8, 51u8, 41u8, 74u8, 197u8,
-                        141u8, 103u8, 97u8, 110u8, 32u8, 40u8, 56u8, 53u8, 57u8, 226u8, 128u8,
-                        147u8, 56u8, 55u8, 55u8, 41u8, 74u8, 197u8, 141u8, 103u8, 101u8, 110u8,
-                        32u8, 40u8, 49u8, 50u8, 48u8, 55u8, 226u8, 128u8, 147u8, 49u8, 50u8, 49u8,
-                        49u8, 41u8, 74u8, 197u8, 141u8, 103u8, 101u8, 110u8, 32u8, 40u8, 57u8,
-                        55u8, 54u8, 226u8, 128u8, 147u8, 57u8, 55u8, 56u8, 41u8, 74u8, 197u8,
-                        141u8, 104u8, 101u8, 105u8, 32u8, 40u8, 57u8, 51u8, 49u8, 226u8, 128u8,
-                        147u8, 57u8, 51u8, 56u8, 41u8, 74u8, 197u8, 141u8, 107u8, 121u8, 197u8,
-                        141u8, 32u8, 40u8, 49u8, 54u8, 56u8, 52u8, 226u8, 128u8, 147u8, 49u8, 54u8,
-                        56u8, 56u8, 41u8, 74u8, 197u8, 141u8, 107u8, 121u8, 197u8, 171u8, 32u8,
-                        40u8, 49u8, 50u8, 49u8, 57u8, 226u8, 128u8, 147u8, 49u8, 50u8, 50u8, 50u8,
-                        41u8, 74u8, 197u8, 141u8, 197u8, 141u8, 32u8, 40u8, 49u8, 50u8, 50u8, 50u8,
-                        226u8, 128u8, 147u8, 49u8, 50u8, 50u8, 52u8, 41u8, 74u8, 197u8, 141u8,
-                        197u8, 141u8, 32u8, 40u8, 49u8, 54u8, 53u8, 50u8, 226u8, 128u8, 147u8,
-                        49u8, 54u8, 53u8, 53u8, 41u8, 74u8, 197u8, 141u8, 116u8, 111u8, 107u8,
-                        117u8, 32u8, 40u8, 49u8, 48u8, 57u8, 55u8, 226u8, 128u8, 147u8, 49u8, 48u8,
-                        57u8, 57u8, 41u8, 74u8, 197u8, 141u8, 119u8, 97u8, 32u8, 40u8, 56u8, 51u8,
-                        52u8, 226u8, 128u8, 147u8, 56u8, 52u8, 56u8, 41u8, 74u8, 117u8, 101u8,
-                        105u8, 32u8, 40u8, 49u8, 49u8, 56u8, 50u8, 226u8, 128u8, 147u8, 49u8, 49u8,
-                        56u8, 52u8, 41u8, 75u8, 97u8, 101u8, 105u8, 32u8, 40u8, 49u8, 56u8, 52u8,
-                        56u8, 226u8, 128u8, 147u8, 49u8, 56u8, 53u8, 52u8, 41u8, 75u8, 97u8, 103u8,
-                        101u8, 110u8, 32u8, 40u8, 49u8, 51u8, 48u8, 51u8, 226u8, 128u8, 147u8,
-                        49u8, 51u8, 48u8, 54u8, 41u8, 75u8, 97u8, 104u8, 197u8, 141u8, 32u8, 40u8,
-                        49u8, 48u8, 57u8, 52u8, 226u8, 128u8, 147u8, 49u8, 48u8, 57u8, 54u8, 41u8,
-                        75u8, 97u8, 106u8, 197u8, 141u8, 32u8, 40u8, 56u8, 52u8, 56u8, 226u8,
-                        128u8, 147u8, 56u8, 53u8, 49u8, 41u8, 75u8, 97u8, 107u8, 101u8, 105u8,
-                        32u8, 40u8, 49u8, 51u8, 56u8, 55u8, 226u8, 128u8, 147u8, 49u8, 51u8, 56u8,
-                        57u8, 41u8, 75u8, 97u8, 107u8, 105u8, 116u8, 115u8, 117u8, 32u8, 40u8,
-                        49u8, 52u8, 52u8, 49u8, 226u8, 128u8, 147u8, 49u8, 52u8, 52u8, 52u8, 41u8,
-                        75u8, 97u8, 110u8, 98u8, 117u8, 110u8, 32u8, 40u8, 49u8, 54u8, 54u8, 49u8,
-                        226u8, 128u8, 147u8, 49u8, 54u8, 55u8, 51u8, 41u8, 75u8, 97u8, 110u8,
-                        226u8, 128u8, 153u8, 101u8, 105u8, 32u8, 40u8, 49u8, 54u8, 50u8, 52u8,
-                        226u8, 128u8, 147u8, 49u8, 54u8, 52u8, 52u8, 41u8, 75u8, 97u8, 110u8,
-                        226u8, 128u8, 153u8, 101u8, 110u8, 32u8, 40u8, 49u8, 55u8, 52u8, 56u8,
-                        226u8, 128u8, 147u8, 49u8, 55u8, 53u8, 49u8, 41u8, 75u8, 97u8, 110u8,
-                        103u8, 101u8, 110u8, 32u8, 40u8, 49u8, 50u8, 52u8, 51u8, 226u8, 128u8,
-                        147u8, 49u8, 50u8, 52u8, 55u8, 41u8, 75u8, 97u8, 110u8, 106u8, 105u8, 32u8,
-                        40u8, 49u8, 48u8, 56u8, 55u8, 226u8, 128u8, 147u8, 49u8, 48u8, 57u8, 52u8,
-                        41u8, 75u8, 97u8, 110u8, 107u8, 105u8, 32u8, 40u8, 49u8, 50u8, 50u8, 57u8,
-                        226u8, 128u8, 147u8, 49u8, 50u8, 51u8, 50u8, 41u8, 75u8, 97u8, 110u8,
-                        107u8, 197u8, 141u8, 32u8, 40u8, 49u8, 48u8, 48u8, 52u8, 226u8, 128u8,
-                        147u8, 49u8, 48u8, 49u8, 50u8, 41u8, 75u8, 97u8, 110u8, 110u8, 97u8, 32u8,
-                        40u8, 57u8, 56u8, 53u8, 226u8, 128u8, 147u8, 57u8, 56u8, 55u8, 41u8, 75u8,
-                        97u8, 110u8, 110u8, 105u8, 110u8, 32u8, 40u8, 49u8, 48u8, 49u8, 55u8,
-                        226u8, 128u8, 147u8, 49u8, 48u8, 50u8, 49u8, 41u8, 75u8, 97u8, 110u8,
-                        112u8, 197u8, 141u8, 32u8, 40u8, 49u8, 55u8, 52u8, 49u8, 226u8, 128u8,
-                        147u8, 49u8, 55u8, 52u8, 52u8, 41u8, 75u8, 97u8, 110u8, 112u8, 121u8,
-                        197u8, 141u8, 32u8, 40u8, 56u8, 56u8, 57u8, 226u8, 128u8, 147u8, 56u8,
-                        57u8, 56u8, 41u8, 75u8, 97u8, 110u8, 115u8, 101u8, 105u8, 32u8, 40u8, 49u8,
-                        55u8, 56u8, 57u8, 226u8, 128u8, 147u8, 49u8, 56u8, 48u8, 49u8, 41u8, 75u8,
-                        97u8, 110u8, 115u8, 104u8, 197u8, 141u8, 32u8, 40u8, 49u8, 52u8, 54u8,
-                        48u8, 226u8, 128u8, 147u8, 49u8, 52u8, 54u8, 54u8, 41u8, 75u8, 97u8, 110u8,
-                        116u8, 111u8, 107u8, 117u8, 32u8, 40u8, 49u8, 48u8, 52u8, 52u8, 226u8,
-                        128u8, 147u8, 49u8, 48u8, 52u8, 54u8, 41u8, 75u8, 97u8, 197u8, 141u8, 32u8,
-                        40u8, 49u8, 49u8, 54u8, 57u8, 226u8, 128u8, 147u8, 49u8, 49u8, 55u8, 49u8,
-                        41u8, 75u8, 97u8, 114u8, 111u8, 107u8, 117u8, 32u8, 40u8, 49u8, 50u8, 50u8,
-                        53u8, 226u8, 128u8, 147u8, 49u8, 50u8, 50u8, 55u8, 41u8, 75u8, 97u8, 114u8,
-                        121u8, 97u8, 107u8, 117u8, 32u8, 40u8, 49u8, 51u8, 50u8, 54u8, 226u8,
-                        128u8, 147u8, 49u8, 51u8, 50u8, 57u8, 41u8, 75u8, 97u8, 115u8, 104u8,
-                        197u8, 141u8, 32u8, 40u8, 49u8, 49u8, 48u8, 54u8, 226u8, 128u8, 147u8,
-                        49u8, 49u8, 48u8, 56u8, 41u8, 75u8, 97u8, 116u8, 101u8, 105u8, 32u8, 40u8,
-                        49u8, 50u8, 51u8, 53u8, 226u8, 128u8, 147u8, 49u8, 50u8, 51u8, 56u8, 41u8,
-                        75u8, 101u8, 105u8, 97u8, 110u8, 32u8, 40u8, 49u8, 54u8, 52u8, 56u8, 226u8,
-                        128u8, 147u8, 49u8, 54u8, 53u8, 50u8, 41u8, 75u8, 101u8, 105u8, 99u8,
-                        104u8, 197u8, 141u8, 32u8, 40u8, 49u8, 53u8, 57u8, 54u8, 226u8, 128u8,
-                        147u8, 49u8, 54u8, 49u8, 53u8, 41u8, 75u8, 101u8, 105u8, 197u8, 141u8,
-                        32u8, 40u8, 49u8, 56u8, 54u8, 53u8, 226u8, 128u8, 147u8, 49u8, 56u8, 54u8,
-                        56u8, 41u8, 75u8, 101u8, 105u8, 117u8, 110u8, 32u8, 40u8, 55u8, 48u8, 52u8,
-                        226u8, 128u8, 147u8, 55u8, 48u8, 56u8, 41u8, 75u8, 101u8, 110u8, 99u8,
-                        104u8, 197u8, 141u8, 32u8, 40u8, 49u8, 50u8, 52u8, 57u8, 226u8, 128u8,
-                        147u8, 49u8, 50u8, 53u8, 54u8, 41u8, 75u8, 101u8, 110u8, 226u8, 128u8,
-                        153u8, 101u8, 105u8, 32u8, 40u8, 49u8, 50u8, 48u8, 54u8, 226u8, 128u8,
-                        147u8, 49u8, 50u8, 48u8, 55u8, 41u8, 75u8, 101u8, 110u8, 103u8, 101u8,
-                        110u8, 32u8, 40u8, 49u8, 51u8, 48u8, 50u8, 226u8, 128u8, 147u8, 49u8, 51u8,
-                        48u8, 51u8, 41u8, 75u8, 101u8, 110u8, 106u8, 105u8, 32u8, 40u8, 49u8, 50u8,
-                        55u8, 53u8, 226u8, 128u8, 147u8, 49u8, 50u8, 55u8, 56u8, 41u8, 75u8, 101u8,
-                        110u8, 107u8, 121u8, 197u8, 171u8, 32u8, 40u8, 49u8, 49u8, 57u8, 48u8,
-                        226u8, 128u8, 147u8, 49u8, 49u8, 57u8, 57u8, 41u8, 75u8, 101u8, 110u8,
-                        109u8, 117u8, 32u8, 40u8, 49u8, 51u8, 51u8, 52u8, 226u8, 128u8, 147u8,
-                        49u8, 51u8, 51u8, 54u8, 41u8, 75u8, 101u8, 110u8, 110u8, 105u8, 110u8,
-                        32u8, 40u8, 49u8, 50u8, 48u8, 49u8, 226u8, 128u8, 147u8, 49u8, 50u8, 48u8,
-                        52u8, 41u8, 75u8, 101u8, 110u8, 112u8, 197u8, 141u8, 32u8, 40u8, 49u8,
-                        50u8, 49u8, 51u8, 226u8, 128u8, 147u8, 49u8, 50u8, 49u8, 57u8, 41u8, 75u8,
-                        101u8, 110u8, 114u8, 121u8, 97u8, 107u8, 117u8, 32u8, 40u8, 49u8, 50u8,
-                        49u8, 49u8, 226u8, 128u8, 147u8, 49u8, 50u8, 49u8, 51u8, 41u8, 75u8, 101u8,
-                        110u8, 116u8, 111u8, 107u8, 117u8, 32u8, 40u8, 49u8, 51u8, 55u8, 48u8,
-                        226u8, 128u8, 147u8, 49u8, 51u8, 55u8, 50u8, 41u8, 75u8, 197u8, 141u8,
-                        97u8, 110u8, 32u8, 40u8, 49u8, 50u8, 55u8, 56u8, 226u8, 128u8, 147u8, 49u8,
-                        50u8, 56u8, 56u8, 41u8, 75u8, 197u8, 141u8, 99u8, 104u8, 197u8, 141u8,
-                        32u8, 40u8, 49u8, 50u8, 54u8, 49u8, 226u8, 128u8, 147u8, 49u8, 50u8, 54u8,
-                        52u8, 41u8, 75u8, 197u8, 141u8, 103u8, 101u8, 110u8, 32u8, 40u8, 49u8,
-                        50u8, 53u8, 54u8, 226u8, 128u8, 147u8, 49u8, 50u8, 53u8, 55u8, 41u8, 75u8,
-                        197u8, 141u8, 104u8, 101u8, 105u8, 32u8, 40u8, 49u8, 48u8, 53u8, 56u8,
-                        226u8, 128u8, 147u8, 49u8, 48u8, 54u8, 53u8, 41u8, 75u8, 197u8, 141u8,
-                        104u8, 197u8, 141u8, 32u8, 40u8, 57u8, 54u8, 52u8, 226u8, 128u8, 147u8,
-                        57u8, 54u8, 56u8, 41u8, 75u8, 197u8, 141u8, 106u8, 105u8, 32u8, 40u8, 49u8,
-                        49u8, 52u8, 50u8, 226u8, 128u8, 147u8, 49u8, 49u8, 52u8, 52u8, 41u8, 75u8,
-                        197u8, 141u8, 106u8, 105u8, 32u8, 40u8, 49u8, 53u8, 53u8, 53u8, 226u8,
-                        128u8, 147u8, 49u8, 53u8, 53u8, 56u8, 41u8, 75u8, 197u8, 141u8, 107u8,
-                        97u8, 32u8, 40u8, 49u8, 56u8, 52u8, 52u8, 226u8, 128u8, 147u8, 49u8, 56u8,
-                        52u8, 56u8, 41u8, 75u8, 197u8, 141u8, 107u8, 111u8, 107u8, 117u8, 32u8,
-                        40u8, 49u8, 51u8, 52u8, 48u8, 226u8, 128u8, 147u8, 49u8, 51u8, 52u8, 54u8,
-                        41u8, 75u8, 197u8, 141u8, 110u8, 105u8, 110u8, 32u8, 40u8, 56u8, 49u8,
-                        48u8, 226u8, 128u8, 147u8, 56u8, 50u8, 52u8, 41u8, 75u8, 197u8, 141u8,
-                        197u8, 141u8, 32u8, 40u8, 49u8, 51u8, 56u8, 57u8, 226u8, 128u8, 147u8,
-                        49u8, 51u8, 57u8, 48u8, 41u8, 75u8, 197u8, 141u8, 114u8, 121u8, 97u8,
-                        107u8, 117u8, 32u8, 40u8, 49u8, 51u8, 55u8, 57u8, 226u8, 128u8, 147u8,
-                        49u8, 51u8, 56u8, 49u8, 41u8, 75u8, 197u8, 141u8, 115u8, 104u8, 197u8,
-                        141u8, 32u8, 40u8, 49u8, 52u8, 53u8, 53u8, 226u8, 128u8, 147u8, 49u8, 52u8,
-                        53u8, 55u8, 41u8, 75u8, 197u8, 141u8, 119u8, 97u8, 32u8, 40u8, 49u8, 48u8,
-                        57u8, 57u8, 226u8, 128u8, 147u8, 49u8, 49u8, 48u8, 52u8, 41u8, 75u8, 197u8,
-                        141u8, 119u8, 97u8, 32u8, 40u8, 49u8, 51u8, 56u8, 49u8, 226u8, 128u8,
-                        147u8, 49u8, 51u8, 56u8, 52u8, 41u8, 75u8, 121u8, 197u8, 141u8, 104u8,
-                        197u8, 141u8, 32u8, 40u8, 49u8, 55u8, 49u8, 54u8, 226u8, 128u8, 147u8,
-                        49u8, 55u8, 51u8, 54u8, 41u8, 75u8, 121u8, 197u8, 141u8, 114u8, 111u8,
-                        107u8, 117u8, 32u8, 40u8, 49u8, 53u8, 50u8, 56u8, 226u8, 128u8, 147u8,
-                        49u8, 53u8, 51u8, 50u8, 41u8, 75u8, 121u8, 197u8, 141u8, 116u8, 111u8,
-                        107u8, 117u8, 32u8, 40u8, 49u8, 52u8, 53u8, 50u8, 226u8, 128u8, 147u8,
-                        49u8, 52u8, 53u8, 53u8, 41u8, 75u8, 121u8, 197u8, 141u8, 119u8, 97u8, 32u8,
-                        40u8, 49u8, 56u8, 48u8, 49u8, 226u8, 128u8, 147u8, 49u8, 56u8, 48u8, 52u8,
-                        41u8, 75u8, 121u8, 197u8, 171u8, 97u8, 110u8, 32u8, 40u8, 49u8, 49u8, 52u8,
-                        53u8, 226u8, 128u8, 147u8, 49u8, 49u8, 53u8, 49u8, 41u8, 75u8, 121u8,
-                        197u8, 171u8, 106u8, 117u8, 32u8, 40u8, 49u8, 49u8, 53u8, 52u8, 226u8,
-                        128u8, 147u8, 49u8, 49u8, 53u8, 54u8, 41u8, 77u8, 97u8, 110u8, 226u8,
-                        128u8, 153u8, 101u8, 110u8, 32u8, 40u8, 49u8, 56u8, 54u8, 48u8, 226u8,
-                        128u8, 147u8, 49u8, 56u8, 54u8, 49u8, 41u8, 77u8, 97u8, 110u8, 106u8,
-                        105u8, 32u8, 40u8, 49u8, 54u8, 53u8, 56u8, 226u8, 128u8, 147u8, 49u8, 54u8,
-                        54u8, 49u8, 41u8, 77u8, 97u8, 110u8, 106u8, 117u8, 32u8, 40u8, 49u8, 48u8,
-                        50u8, 52u8, 226u8, 128u8, 147u8, 49u8, 48u8, 50u8, 56u8, 41u8, 77u8, 77u8,
-                        101u8, 105u8, 197u8, 141u8, 32u8, 40u8, 49u8, 52u8, 57u8, 50u8, 226u8,
-                        128u8, 147u8, 49u8, 53u8, 48u8, 49u8, 41u8, 77u8, 101u8, 105u8, 114u8,
-                        101u8, 107u8, 105u8, 32u8, 40u8, 49u8, 54u8, 53u8, 53u8, 226u8, 128u8,
-                        147u8, 49u8, 54u8, 53u8, 56u8, 41u8, 77u8, 101u8, 105u8, 116u8, 111u8,
-                        107u8, 117u8, 32u8, 40u8, 49u8, 51u8, 56u8, 52u8, 226u8, 128u8, 147u8,
-                        49u8, 51u8, 56u8, 55u8, 41u8, 77u8, 101u8, 105u8, 116u8, 111u8, 107u8,
-                        117u8, 32u8, 40u8, 49u8, 51u8, 57u8, 48u8, 226u8, 128u8, 147u8, 49u8, 51u8,
-                        57u8, 52u8, 41u8, 77u8, 101u8, 105u8, 119u8, 97u8, 32u8, 40u8, 49u8, 55u8,
-                        54u8, 52u8, 226u8, 128u8, 147u8, 49u8, 55u8, 55u8, 50u8, 41u8, 78u8, 105u8,
-                        110u8, 226u8, 128u8, 153u8, 97u8, 110u8, 32u8, 40u8, 49u8, 49u8, 54u8,
-                        54u8, 226u8, 128u8, 147u8, 49u8, 49u8, 54u8, 57u8, 41u8, 78u8, 105u8,
-                        110u8, 106u8, 105u8, 32u8, 40u8, 49u8, 50u8, 52u8, 48u8, 226u8, 128u8,
-                        147u8, 49u8, 50u8, 52u8, 51u8, 41u8, 78u8, 105u8, 110u8, 106u8, 117u8,
-                        32u8, 40u8, 56u8, 53u8, 49u8, 226u8, 128u8, 147u8, 56u8, 53u8, 52u8, 41u8,
-                        78u8, 105u8, 110u8, 110u8, 97u8, 32u8, 40u8, 56u8, 56u8, 53u8, 226u8,
-                        128u8, 147u8, 56u8, 56u8, 57u8, 41u8, 78u8, 105u8, 110u8, 112u8, 101u8,
-                        105u8, 32u8, 40u8, 49u8, 49u8, 53u8, 49u8, 226u8, 128u8, 147u8, 49u8, 49u8,
-                        53u8, 52u8, 41u8, 197u8, 140u8, 99u8, 104u8, 197u8, 141u8, 32u8, 40u8,
-                        49u8, 51u8, 49u8, 49u8, 226u8, 128u8, 147u8, 49u8, 51u8, 49u8, 50u8, 41u8,
-                        197u8, 140u8, 101u8, 105u8, 32u8, 40u8, 49u8, 51u8, 57u8, 52u8, 226u8,
-                        128u8, 147u8, 49u8, 52u8, 50u8, 56u8, 41u8, 197u8, 140u8, 104u8, 111u8,
-                        32u8, 40u8, 49u8, 49u8, 54u8, 49u8, 226u8, 128u8, 147u8, 49u8, 49u8, 54u8,
-                        51u8, 41u8, 197u8, 140u8, 110u8, 105u8, 110u8, 32u8, 40u8, 49u8, 52u8,
-                        54u8, 55u8, 226u8, 128u8, 147u8, 49u8, 52u8, 54u8, 57u8, 41u8, 197u8,
-                        140u8, 116u8, 111u8, 107u8, 117u8, 32u8, 40u8, 49u8, 48u8, 56u8, 52u8,
-                        226u8, 128u8, 147u8, 49u8, 48u8, 56u8, 55u8, 41u8, 197u8, 140u8, 119u8,
-                        97u8, 32u8, 40u8, 57u8, 54u8, 49u8, 226u8, 128u8, 147u8, 57u8, 54u8, 52u8,
-                        41u8, 82u8, 101u8, 105u8, 107u8, 105u8, 32u8, 40u8, 55u8, 49u8, 53u8,
-                        226u8, 128u8, 147u8, 55u8, 49u8, 55u8, 41u8, 82u8, 82u8, 121u8, 97u8,
-                        107u8, 117u8, 110u8, 105u8, 110u8, 32u8, 40u8, 49u8, 50u8, 51u8, 56u8,
-                        226u8, 128u8, 147u8, 49u8, 50u8, 51u8, 57u8, 41u8, 83u8, 97u8, 105u8,
-                        107u8, 197u8, 141u8, 32u8, 40u8, 56u8, 53u8, 52u8, 226u8, 128u8, 147u8,
-                        56u8, 53u8, 55u8, 41u8, 83u8, 104u8, 197u8, 141u8, 97u8, 110u8, 32u8, 40u8,
-                        49u8, 49u8, 55u8, 49u8, 226u8, 128u8, 147u8, 49u8, 49u8, 55u8, 53u8, 41u8,
-                        83u8, 104u8, 197u8, 141u8, 97u8, 110u8, 32u8, 40u8, 49u8, 50u8, 57u8, 57u8,
-                        226u8, 128u8, 147u8, 49u8, 51u8, 48u8, 50u8, 41u8, 83u8, 104u8, 197u8,
-                        141u8, 99u8, 104u8, 197u8, 141u8, 32u8, 40u8, 49u8, 52u8, 50u8, 56u8,
-                        226u8, 128u8, 147u8, 49u8, 52u8, 50u8, 57u8, 41u8, 83u8, 104u8, 197u8,
-                        141u8, 99u8, 104u8, 197u8, 171u8, 32u8, 40u8, 49u8, 51u8, 50u8, 52u8,
-                        226u8, 128u8, 147u8, 49u8, 51u8, 50u8, 54u8, 41u8, 83u8, 104u8, 197u8,
-                        141u8, 103u8, 101u8, 110u8, 32u8, 40u8, 49u8, 50u8, 53u8, 57u8, 226u8,
-                        128u8, 147u8, 49u8, 50u8, 54u8, 48u8, 41u8, 83u8, 104u8, 197u8, 141u8,
-                        104u8, 101u8, 105u8, 32u8, 40u8, 49u8, 51u8, 52u8, 54u8, 226u8, 128u8,
-                        147u8, 49u8, 51u8, 55u8, 48u8, 41u8, 83u8, 104u8, 197u8, 141u8, 104u8,
-                        111u8, 32u8, 40u8, 49u8, 48u8, 55u8, 52u8, 226u8, 128u8, 147u8, 49u8, 48u8,
-                        55u8, 55u8, 41u8, 83u8, 104u8, 197u8, 141u8, 104u8, 111u8, 32u8, 40u8,
-                        49u8, 54u8, 52u8, 52u8, 226u8, 128u8, 147u8, 49u8, 54u8, 52u8, 56u8, 41u8,
-                        83u8, 104u8, 197u8, 141u8, 106u8, 105u8, 32u8, 40u8, 49u8, 49u8, 57u8,
-                        57u8, 226u8, 128u8, 147u8, 49u8, 50u8, 48u8, 49u8, 41u8, 83u8, 104u8,
-                        197u8, 141u8, 107u8, 97u8, 32u8, 40u8, 49u8, 50u8, 53u8, 55u8, 226u8,
-                        128u8, 147u8, 49u8, 50u8, 53u8, 57u8, 41u8, 83u8, 104u8, 197u8, 141u8,
-                        197u8, 141u8, 32u8, 40u8, 49u8, 50u8, 56u8, 56u8, 226u8, 128u8, 147u8,
-                        49u8, 50u8, 57u8, 51u8, 41u8, 83u8, 104u8, 197u8, 141u8, 114u8, 121u8,
-                        97u8, 107u8, 117u8, 32u8, 40u8, 49u8, 48u8, 55u8, 55u8, 226u8, 128u8,
-                        147u8, 49u8, 48u8, 56u8, 49u8, 41u8, 83u8, 104u8, 197u8, 141u8, 114u8,
-                        121u8, 97u8, 107u8, 117u8, 32u8, 40u8, 57u8, 57u8, 48u8, 226u8, 128u8,
-                        147u8, 57u8, 57u8, 53u8, 41u8, 83u8, 104u8, 197u8, 141u8, 116u8, 97u8,
-                        105u8, 32u8, 40u8, 56u8, 57u8, 56u8, 226u8, 128u8, 147u8, 57u8, 48u8, 49u8,
-                        41u8, 83u8, 104u8, 197u8, 141u8, 116u8, 111u8, 107u8, 117u8, 32u8, 40u8,
-                        49u8, 55u8, 49u8, 49u8, 226u8, 128u8, 147u8, 49u8, 55u8, 49u8, 54u8, 41u8,
-                        83u8, 83u8, 104u8, 197u8, 141u8, 119u8, 97u8, 32u8, 40u8, 49u8, 51u8, 49u8,
-                        50u8, 226u8, 128u8, 147u8, 49u8, 51u8, 49u8, 55u8, 41u8, 83u8, 104u8,
-                        117u8, 99u8, 104u8, 197u8, 141u8, 32u8, 40u8, 54u8, 56u8, 54u8, 226u8,
-                        128u8, 147u8, 55u8, 48u8, 49u8, 41u8, 84u8, 97u8, 105u8, 101u8, 105u8,
-                        32u8, 40u8, 49u8, 53u8, 50u8, 49u8, 226u8, 128u8, 147u8, 49u8, 53u8, 50u8,
-                        56u8, 41u8, 84u8, 97u8, 105u8, 104u8, 197u8, 141u8, 32u8, 40u8, 55u8, 48u8,
-                        49u8, 226u8, 128u8, 147u8, 55u8, 48u8, 52u8, 41u8, 84u8, 97u8, 105u8,
-                        107u8, 97u8, 32u8, 40u8, 54u8, 52u8, 53u8, 226u8, 128u8, 147u8, 54u8, 53u8,
-                        48u8, 41u8, 84u8, 84u8, 101u8, 110u8, 45u8, 97u8, 110u8, 32u8, 40u8, 56u8,
-                        53u8, 55u8, 226u8, 128u8, 147u8, 56u8, 53u8, 57u8, 41u8, 84u8, 101u8,
-                        110u8, 98u8, 117u8, 110u8, 32u8, 40u8, 49u8, 53u8, 51u8, 50u8, 226u8,
-                        128u8, 147u8, 49u8, 53u8, 53u8, 53u8, 41u8, 84u8, 101u8, 110u8, 99u8,
-                        104u8, 197u8, 141u8, 32u8, 40u8, 56u8, 50u8, 52u8, 226u8, 128u8, 147u8,
-                        56u8, 51u8, 52u8, 41u8, 84u8, 101u8, 110u8, 45u8, 101u8, 105u8, 32u8, 40u8,
-                        49u8, 49u8, 49u8, 48u8, 226u8, 128u8, 147u8, 49u8, 49u8, 49u8, 51u8, 41u8,
-                        84u8, 101u8, 110u8, 226u8, 128u8, 153u8, 101u8, 110u8, 32u8, 40u8, 57u8,
-                        55u8, 51u8, 226u8, 128u8, 147u8, 57u8, 55u8, 54u8, 41u8, 84u8, 101u8,
-                        110u8, 103u8, 101u8, 110u8, 32u8, 40u8, 57u8, 55u8, 56u8, 226u8, 128u8,
-                        147u8, 57u8, 56u8, 51u8, 41u8, 84u8, 101u8, 110u8, 103u8, 105u8, 32u8,
-                        40u8, 49u8, 48u8, 53u8, 51u8, 226u8, 128u8, 147u8, 49u8, 48u8, 53u8, 56u8,
-                        41u8, 84u8, 101u8, 110u8, 103u8, 121u8, 197u8, 141u8, 32u8, 40u8, 57u8,
-                        51u8, 56u8, 226u8, 128u8, 147u8, 57u8, 52u8, 55u8, 41u8, 84u8, 101u8,
-                        110u8, 106u8, 105u8, 32u8, 40u8, 49u8, 49u8, 50u8, 52u8, 226u8, 128u8,
-                        147u8, 49u8, 49u8, 50u8, 54u8, 41u8, 84u8, 101u8, 110u8, 106u8, 117u8,
-                        32u8, 40u8, 49u8, 51u8, 55u8, 53u8, 226u8, 128u8, 147u8, 49u8, 51u8, 55u8,
-                        57u8, 41u8, 84u8, 101u8, 110u8, 109u8, 101u8, 105u8, 32u8, 40u8, 49u8,
-                        55u8, 56u8, 49u8, 226u8, 128u8, 147u8, 49u8, 55u8, 56u8, 57u8, 41u8, 84u8,
-                        101u8, 110u8, 110u8, 97u8, 32u8, 40u8, 49u8, 54u8, 56u8, 49u8, 226u8,
-                        128u8, 147u8, 49u8, 54u8, 56u8, 52u8, 41u8, 84u8, 101u8, 110u8, 110u8,
-                        105u8, 110u8, 32u8, 40u8, 49u8, 49u8, 48u8, 56u8, 226u8, 128u8, 147u8,
-                        49u8, 49u8, 49u8, 48u8, 41u8, 84u8, 101u8, 110u8, 45u8, 197u8, 141u8, 32u8,
-                        40u8, 55u8, 56u8, 49u8, 226u8, 128u8, 147u8, 55u8, 56u8, 50u8, 41u8, 84u8,
-                        101u8, 110u8, 112u8, 197u8, 141u8, 32u8, 40u8, 49u8, 56u8, 51u8, 48u8,
-                        226u8, 128u8, 147u8, 49u8, 56u8, 52u8, 52u8, 41u8, 84u8, 101u8, 110u8,
-                        112u8, 117u8, 107u8, 117u8, 32u8, 40u8, 49u8, 50u8, 51u8, 51u8, 226u8,
-                        128u8, 147u8, 49u8, 50u8, 51u8, 52u8, 41u8, 84u8, 101u8, 110u8, 112u8,
-                        121u8, 197u8, 141u8, 32u8, 40u8, 55u8, 50u8, 57u8, 226u8, 128u8, 147u8,
-                        55u8, 52u8, 57u8, 41u8, 84u8, 101u8, 110u8, 112u8, 121u8, 197u8, 141u8,
-                        45u8, 104u8, 197u8, 141u8, 106u8, 105u8, 32u8, 40u8, 55u8, 53u8, 55u8,
-                        226u8, 128u8, 147u8, 55u8, 54u8, 53u8, 41u8, 84u8, 101u8, 110u8, 112u8,
-                        121u8, 197u8, 141u8, 45u8, 106u8, 105u8, 110u8, 103u8, 111u8, 32u8, 40u8,
-                        55u8, 54u8, 53u8, 226u8, 128u8, 147u8, 55u8, 54u8, 55u8, 41u8, 84u8, 101u8,
-                        110u8, 112u8, 121u8, 197u8, 141u8, 45u8, 107u8, 97u8, 109u8, 112u8, 197u8,
-                        141u8, 32u8, 40u8, 55u8, 52u8, 57u8, 226u8, 128u8, 147u8, 55u8, 52u8, 57u8,
-                        41u8, 84u8, 101u8, 110u8, 112u8, 121u8, 197u8, 141u8, 45u8, 115u8, 104u8,
-                        197u8, 141u8, 104u8, 197u8, 141u8, 32u8, 40u8, 55u8, 52u8, 57u8, 226u8,
-                        128u8, 147u8, 55u8, 53u8, 55u8, 41u8, 84u8, 101u8, 110u8, 114u8, 111u8,
-                        107u8, 117u8, 32u8, 40u8, 57u8, 55u8, 48u8, 226u8, 128u8, 147u8, 57u8,
-                        55u8, 51u8, 41u8, 84u8, 101u8, 110u8, 114u8, 121u8, 97u8, 107u8, 117u8,
-                        32u8, 40u8, 57u8, 52u8, 55u8, 226u8, 128u8, 147u8, 57u8, 53u8, 55u8, 41u8,
-                        84u8, 101u8, 110u8, 115u8, 104u8, 197u8, 141u8, 32u8, 40u8, 49u8, 49u8,
-                        51u8, 49u8, 226u8, 128u8, 147u8, 49u8, 49u8, 51u8, 50u8, 41u8, 84u8, 101u8,
-                        110u8, 115u8, 104u8, 197u8, 141u8, 32u8, 40u8, 49u8, 53u8, 55u8, 51u8,
-                        226u8, 128u8, 147u8, 49u8, 53u8, 57u8, 50u8, 41u8, 84u8, 101u8, 110u8,
-                        116u8, 111u8, 107u8, 117u8, 32u8, 40u8, 57u8, 53u8, 55u8, 226u8, 128u8,
-                        147u8, 57u8, 54u8, 49u8, 41u8, 84u8, 101u8, 110u8, 226u8, 128u8, 153u8,
-                        121u8, 197u8, 141u8, 32u8, 40u8, 49u8, 49u8, 52u8, 52u8, 226u8, 128u8,
-                        147u8, 49u8, 49u8, 52u8, 53u8, 41u8, 84u8, 111u8, 107u8, 117u8, 106u8,
-                        105u8, 32u8, 40u8, 49u8, 51u8, 48u8, 54u8, 226u8, 128u8, 147u8, 49u8, 51u8,
-                        48u8, 56u8, 41u8, 87u8, 97u8, 100u8, 197u8, 141u8, 32u8, 40u8, 55u8, 48u8,
-                        56u8, 226u8, 128u8, 147u8, 55u8, 49u8, 53u8, 41u8, 89u8, 197u8, 141u8,
-                        114u8, 197u8, 141u8, 32u8, 40u8, 55u8, 49u8, 55u8, 226u8, 128u8, 147u8,
-                        55u8, 50u8, 52u8, 41u8, 89u8, 197u8, 141u8, 119u8, 97u8, 32u8, 40u8, 49u8,
-                        49u8, 56u8, 49u8, 226u8, 128u8, 147u8, 49u8, 49u8, 56u8, 50u8, 41u8,
-                    ])
-                },
-            )
-        },
-    },
-};
-static CCP: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
-    months: ::icu_datetime::provider::calendar::months::ContextsV1 {
-        format: ::icu_datetime::provider::calendar::months::FormatWidthsV1 {
-            abbreviated: ::icu_datetime::provider::calendar::months::SymbolsV1::SolarTwelve([
-                alloc::borrow::Cow::Borrowed("𑄎𑄚\u{1112a}"),
-                alloc::borrow::Cow::Borrowed("𑄜𑄬𑄛\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄟𑄢\u{11134}𑄌\u{11127}"),
-                alloc::borrow::Cow::Borrowed("𑄃𑄬𑄛\u{11133}𑄢\u{11128}𑄣\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄟𑄬"),
-                alloc::borrow::Cow::Borrowed("𑄎\u{1112a}𑄚\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄎\u{1112a}𑄣\u{1112d}"),
-                alloc::borrow::Cow::Borrowed("𑄃𑄉\u{11127}𑄌\u{11134}𑄑\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄥𑄬𑄛\u{11134}𑄑𑄬𑄟\u{11134}𑄝\u{11127}𑄢\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄃\u{11127}𑄇\u{11134}𑄑\u{1112e}𑄝\u{11127}𑄢\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄚\u{11127}𑄞𑄬𑄟\u{11134}𑄝\u{11127}𑄢\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄓\u{11128}𑄥𑄬𑄟\u{11134}𑄝𑄢\u{11134}"),
-            ]),
-            narrow: ::icu_datetime::provider::calendar::months::SymbolsV1::SolarTwelve([
-                alloc::borrow::Cow::Borrowed("𑄎"),
-                alloc::borrow::Cow::Borrowed("𑄜𑄬"),
-                alloc::borrow::Cow::Borrowed("𑄟"),
-                alloc::borrow::Cow::Borrowed("𑄃𑄬"),
-                alloc::borrow::Cow::Borrowed("𑄟𑄬"),
-                alloc::borrow::Cow::Borrowed("𑄎\u{1112a}𑄚\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄎\u{1112a}"),
-                alloc::borrow::Cow::Borrowed("𑄃"),
-                alloc::borrow::Cow::Borrowed("𑄥𑄬"),
-                alloc::borrow::Cow::Borrowed("𑄃\u{11127}"),
-                alloc::borrow::Cow::Borrowed("𑄚\u{11127}"),
-                alloc::borrow::Cow::Borrowed("𑄓\u{11128}"),
-            ]),
-            short: None,
-            wide: ::icu_datetime::provider::calendar::months::SymbolsV1::SolarTwelve([
-                alloc::borrow::Cow::Borrowed("𑄎𑄚\u{1112a}𑄠𑄢\u{11128}"),
-                alloc::borrow::Cow::Borrowed("𑄜𑄬𑄛\u{11134}𑄝\u{11133}𑄢\u{1112a}𑄠𑄢\u{11128}"),
-                alloc::borrow::Cow::Borrowed("𑄟𑄢\u{11134}𑄌\u{11127}"),
-                alloc::borrow::Cow::Borrowed("𑄃𑄬𑄛\u{11133}𑄢\u{11128}𑄣\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄟𑄬"),
-                alloc::borrow::Cow::Borrowed("𑄎\u{1112a}𑄚\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄎\u{1112a}𑄣\u{1112d}"),
-                alloc::borrow::Cow::Borrowed("𑄃𑄉\u{11127}𑄌\u{11134}𑄑\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄥𑄬𑄛\u{11134}𑄑𑄬𑄟\u{11134}𑄝\u{11127}𑄢\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄃\u{11127}𑄇\u{11134}𑄑𑄬𑄝\u{11127}𑄢\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄚\u{11127}𑄞𑄬𑄟\u{11134}𑄝\u{11127}𑄢\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄓\u{11128}𑄥𑄬𑄟\u{11134}𑄝\u{11127}𑄢\u{11134}"),
-            ]),
-        },
-        stand_alone: Some(
-            ::icu_datetime::provider::calendar::months::StandAloneWidthsV1 {
-                abbreviated: Some(
-                    ::icu_datetime::provider::calendar::months::SymbolsV1::SolarTwelve([
-                        alloc::borrow::Cow::Borrowed("𑄎𑄚\u{1112a}𑄠𑄢\u{11128}"),
-                        alloc::borrow::Cow::Borrowed("𑄜𑄬𑄛\u{11134}𑄝\u{11133}𑄢\u{1112a}𑄠𑄢\u{11128}"),
-                        alloc::borrow::Cow::Borrowed("𑄟𑄢\u{11134}𑄌\u{11127}"),
-                        alloc::borrow::Cow::Borrowed("𑄃𑄬𑄛\u{11133}𑄢\u{11128}𑄣\u{11134}"),
-                        alloc::borrow::Cow::Borrowed("𑄟𑄬"),
-                        alloc::borrow::Cow::Borrowed("𑄎\u{1112a}𑄚\u{11134}"),
-                        alloc::borrow::Cow::Borrowed("𑄎\u{1112a}𑄣\u{1112d}"),
-                        alloc::borrow::Cow::Borrowed("𑄃𑄉\u{11127}𑄌\u{11134}𑄑\u{11134}"),
-                        alloc::borrow::Cow::Borrowed(
-                            "𑄥𑄬𑄛\u{11134}𑄑𑄬𑄟\u{11134}𑄝\u{11127}𑄢\u{11134}",
-                        ),
-                        alloc::borrow::Cow::Borrowed(
-                            "𑄃\u{11127}𑄇\u{11134}𑄑\u{1112e}𑄝\u{11127}𑄢\u{11134}",
-                        ),
-                        alloc::borrow::Cow::Borrowed("𑄚\u{11127}𑄞𑄬𑄟\u{11134}𑄝\u{11127}𑄢\u{11134}"),
-                        alloc::borrow::Cow::Borrowed("𑄓\u{11128}𑄥𑄬𑄟\u{11134}𑄝\u{11127}𑄢\u{11134}"),
-                    ]),
-                ),
-                narrow: None,
-                short: None,
-                wide: Some(
-                    ::icu_datetime::provider::calendar::months::SymbolsV1::SolarTwelve([
-                        alloc::borrow::Cow::Borrowed("𑄎𑄚\u{1112a}𑄠𑄢\u{11128}"),
-                        alloc::borrow::Cow::Borrowed("𑄜𑄬𑄛\u{11134}𑄝\u{11133}𑄢\u{1112a}𑄠𑄢\u{11128}"),
-                        alloc::borrow::Cow::Borrowed("𑄟𑄢\u{11134}𑄌\u{11127}"),
-                        alloc::borrow::Cow::Borrowed("𑄃𑄬𑄛\u{11133}𑄢\u{11128}𑄣\u{11134}"),
-                        alloc::borrow::Cow::Borrowed("𑄟𑄬"),
-                        alloc::borrow::Cow::Borrowed("𑄎\u{1112a}𑄚\u{11134}"),
-                        alloc::borrow::Cow::Borrowed("𑄎\u{1112a}𑄣\u{1112d}"),
-                        alloc::borrow::Cow::Borrowed("𑄃𑄉\u{11127}𑄌\u{11134}𑄑\u{11134}"),
-                        alloc::borrow::Cow::Borrowed(
-                            "𑄥𑄬𑄛\u{11134}𑄑𑄬𑄟\u{11134}𑄝\u{11127}𑄢\u{11134}",
-                        ),
-                        alloc::borrow::Cow::Borrowed(
-                            "𑄃\u{11127}𑄇\u{11134}𑄑\u{1112e}𑄝\u{11127}𑄢\u{11134}",
-                        ),
-                        alloc::borrow::Cow::Borrowed("𑄚\u{11127}𑄞𑄬𑄟\u{11134}𑄝\u{11127}𑄢\u{11134}"),
-                        alloc::borrow::Cow::Borrowed("𑄓\u{11128}𑄥𑄬𑄟\u{11134}𑄝\u{11127}𑄢\u{11134}"),
-                    ]),
-                ),
-            },
-        ),
-    },
-    weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
-        format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
-            abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
-                alloc::borrow::Cow::Borrowed("𑄢\u{11127}𑄝\u{11128}"),
-                alloc::borrow::Cow::Borrowed("𑄥\u{11127}𑄟\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄟\u{11127}\u{11101}𑄉\u{11127}𑄣\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄝\u{1112a}𑄖\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄝\u{11133}𑄢\u{11128}𑄥\u{1112a}𑄛\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄥\u{1112a}𑄇\u{11134}𑄇\u{1112e}𑄢\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄥\u{11127}𑄚\u{11128}"),
-            ]),
-            narrow: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
-                alloc::borrow::Cow::Borrowed("𑄢\u{11127}"),
-                alloc::borrow::Cow::Borrowed("𑄥\u{11127}"),
-                alloc::borrow::Cow::Borrowed("𑄟\u{11127}"),
-                alloc::borrow::Cow::Borrowed("𑄝\u{1112a}"),
-                alloc::borrow::Cow::Borrowed("𑄝\u{11133}𑄢\u{11128}"),
-                alloc::borrow::Cow::Borrowed("𑄥\u{1112a}"),
-                alloc::borrow::Cow::Borrowed("𑄥\u{11127}"),
-            ]),
-            short: Some(::icu_datetime::provider::calendar::weekdays::SymbolsV1([
-                alloc::borrow::Cow::Borrowed("𑄢\u{11127}𑄝\u{11128}"),
-                alloc::borrow::Cow::Borrowed("𑄥\u{11127}𑄟\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄟\u{11127}\u{11101}𑄉\u{11127}𑄣\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄝\u{1112a}𑄖\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄝\u{11133}𑄢\u{11128}𑄥\u{1112a}𑄛\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄥\u{1112a}𑄇\u{11134}𑄇\u{1112e}𑄢\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄥\u{11127}𑄚\u{11128}"),
-            ])),
-            wide: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
-                alloc::borrow::Cow::Borrowed("𑄢\u{11127}𑄝\u{11128}𑄝𑄢\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄥\u{11127}𑄟\u{11134}𑄝𑄢\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄟\u{11127}\u{11101}𑄉\u{11127}𑄣\u{11134}𑄝𑄢\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄝\u{1112a}𑄖\u{11134}𑄝𑄢\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄝\u{11133}𑄢\u{11128}𑄥\u{1112a}𑄛\u{11134}𑄝𑄢\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄥\u{1112a}𑄇\u{11134}𑄇\u{1112e}𑄢\u{11134}𑄝𑄢\u{11134}"),
-                alloc::borrow::Cow::Borrowed("𑄥\u{11127}𑄚\u{11128}𑄝𑄢\u{11134}"),
-            ]),
-        },
-        stand_alone: None,
-    },
-    eras: ::icu_datetime::provider::calendar::Eras {
-        names: unsafe {
-            #[allow(unused_unsafe)]
-            ::zerovec::ZeroMap::from_parts_unchecked(
-                unsafe {
-                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
-                        239u8, 0u8, 0u8, 0u8, 0u8, 0u8, 9u8, 0u8, 19u8, 0u8, 27u8, 0u8, 37u8, 0u8,
-                        47u8, 0u8, 50u8, 0u8, 60u8, 0u8, 71u8, 0u8, 81u8, 0u8, 91u8, 0u8, 101u8,
-                        0u8, 111u8, 0u8, 122u8, 0u8, 133u8, 0u8, 142u8, 0u8, 152u8, 0u8, 164u8,
-                        0u8, 177u8, 0u8, 188u8, 0u8, 199u8, 0u8, 201u8, 0u8, 212u8, 0u8, 221u8,
-                        0u8, 231u8, 0u8, 242u8, 0u8, 253u8, 0u8, 8u8, 1u8, 20u8, 1u8, 33u8, 1u8,
-                        44u8, 1u8, 55u8, 1u8, 65u8, 1u8, 74u8, 1u8, 84u8, 1u8, 94u8, 1u8, 102u8,
-                        1u8, 111u8, 1u8, 120u8, 1u8, 129u8, 1u8, 139u8, 1u8, 149u8, 1u8, 159u8,
-                        1u8, 169u8, 1u8, 180u8, 1u8, 192u8, 1u8, 202u8, 1u8, 212u8, 1u8, 220u8,
-                        1u8, 229u8, 1u8, 239u8, 1u8, 247u8, 1u8, 1u8, 2u8, 11u8, 2u8, 21u8, 2u8,
-                        29u8, 2u8, 38u8, 2u8, 49u8, 2u8, 60u8, 2u8, 70u8, 2u8, 81u8, 2u8, 92u8,
-                        2u8, 102u8, 2u8, 112u8, 2u8, 122u8, 2u8, 132u8, 2u8, 142u8, 2u8, 153u8,
-                        2u8, 163u8, 2u8, 174u8, 2u8, 183u8, 2u8, 195u8, 2u8, 208u8, 2u8, 220u8,
-                        2u8, 231u8, 2u8, 241u8, 2u8, 251u8, 2u8, 1u8, 3u8, 10u8, 3u8, 19u8, 3u8,
-                        28u8, 3u8, 38u8, 3u8, 47u8, 3u8, 55u8, 3u8, 66u8, 3u8, 77u8, 3u8, 86u8,
-                        3u8, 100u8, 3u8, 109u8, 3u8, 121u8, 3u8, 131u8, 3u8, 140u8, 3u8, 149u8,
-                        3u8, 159u8, 3u8, 168u8, 3u8, 177u8, 3u8, 187u8, 3u8, 197u8, 3u8, 205u8,
-                        3u8, 213u8, 3u8, 224u8, 3u8, 232u8, 3u8, 241u8, 3u8, 250u8, 3u8, 4u8, 4u8,
-                        13u8, 4u8, 21u8, 4u8, 31u8, 4u8, 43u8, 4u8, 54u8, 4u8, 64u8, 4u8, 74u8,
-                        4u8, 85u8, 4u8, 95u8, 4u8, 105u8, 4u8, 115u8, 4u8, 124u8, 4u8, 135u8, 4u8,
-                        145u8, 4u8, 155u8, 4u8, 166u8, 4u8, 177u8, 4u8, 189u8, 4u8, 197u8, 4u8,
-                        208u8, 4u8, 220u8, 4u8, 230u8, 4u8, 240u8, 4u8, 250u8, 4u8, 5u8, 5u8, 14u8,
-                        5u8, 23u8, 5u8, 34u8, 5u8, 44u8, 5u8, 55u8, 5u8, 65u8, 5u8, 76u8, 5u8,
-                        86u8, 5u8, 97u8, 5u8, 107u8, 5u8, 120u8, 5u8, 132u8, 5u8, 141u8, 5u8,
-                        151u8, 5u8, 161u8, 5u8, 171u8, 5u8, 179u8, 5u8, 188u8, 5u8, 197u8, 5u8,
-                        206u8, 5u8, 217u8, 5u8, 226u8, 5u8, 234u8, 5u8, 246u8, 5u8, 0u8, 6u8, 9u8,
-                        6u8, 18u8, 6u8, 28u8, 6u8, 40u8, 6u8, 52u8, 6u8, 62u8, 6u8, 72u8, 6u8,
-                        82u8, 6u8, 92u8, 6u8, 102u8, 6u8, 112u8, 6u8, 117u8, 6u8, 126u8, 6u8,
-                        138u8, 6u8, 150u8, 6u8, 162u8, 6u8, 172u8, 6u8, 182u8, 6u8, 192u8, 6u8,
-                        201u8, 6u8, 210u8, 6u8, 221u8, 6u8, 230u8, 6u8, 238u8, 6u8, 246u8, 6u8,
-                        255u8, 6u8, 9u8, 7u8, 16u8, 7u8, 25u8, 7u8, 30u8, 7u8, 43u8, 7u8, 52u8,
-                        7u8, 62u8, 7u8, 72u8, 7u8, 83u8, 7u8, 94u8, 7u8, 105u8, 7u8, 116u8, 7u8,
-                        126u8, 7u8, 136u8, 7u8, 146u8, 7u8, 156u8, 7u8, 165u8, 7u8, 178u8, 7u8,
-                        190u8, 7u8, 200u8, 7u8, 212u8, 7u8, 217u8, 7u8, 227u8, 7u8, 237u8, 7u8,
-                        247u8, 7u8, 0u8, 8u8, 9u8, 8u8, 15u8, 8u8, 24u8, 8u8, 35u8, 8u8, 45u8, 8u8,
-                        55u8, 8u8, 64u8, 8u8, 74u8, 8u8, 84u8, 8u8, 94u8, 8u8, 104u8, 8u8, 114u8,
-                        8u8, 125u8, 8u8, 135u8, 8u8, 146u8, 8u8, 154u8, 8u8, 164u8, 8u8, 176u8,
-                        8u8, 186u8, 8u8, 200u8, 8u8, 215u8, 8u8, 230u8, 8u8, 245u8, 8u8, 0u8, 9u8,
-                        12u8, 9u8, 23u8, 9u8, 34u8, 9u8, 45u8, 9u8, 55u8, 9u8, 66u8, 9u8, 74u8,
-                        9u8, 82u8, 9u8, 97u8, 110u8, 101u8, 105u8, 45u8, 49u8, 55u8, 55u8, 50u8,
-                        97u8, 110u8, 103u8, 101u8, 110u8, 45u8, 49u8, 49u8, 55u8, 53u8, 97u8,
-                        110u8, 110u8, 97u8, 45u8, 57u8, 54u8, 56u8, 97u8, 110u8, 115u8, 101u8,
-                        105u8, 45u8, 49u8, 56u8, 53u8, 52u8, 97u8, 110u8, 116u8, 101u8, 105u8,
-                        45u8, 49u8, 50u8, 50u8, 55u8, 98u8, 99u8, 101u8, 98u8, 117u8, 110u8, 97u8,
-                        110u8, 45u8, 49u8, 52u8, 52u8, 52u8, 98u8, 117u8, 110u8, 99u8, 104u8,
-                        117u8, 45u8, 49u8, 51u8, 55u8, 50u8, 98u8, 117u8, 110u8, 101u8, 105u8,
-                        45u8, 49u8, 50u8, 54u8, 52u8, 98u8, 117u8, 110u8, 106u8, 105u8, 45u8, 49u8,
-                        49u8, 56u8, 53u8, 98u8, 117u8, 110u8, 107u8, 97u8, 45u8, 49u8, 56u8, 48u8,
-                        52u8, 98u8, 117u8, 110u8, 107u8, 105u8, 45u8, 49u8, 53u8, 48u8, 49u8, 98u8,
-                        117u8, 110u8, 107u8, 121u8, 117u8, 45u8, 49u8, 56u8, 54u8, 49u8, 98u8,
-                        117u8, 110u8, 109u8, 101u8, 105u8, 45u8, 49u8, 52u8, 54u8, 57u8, 98u8,
-                        117u8, 110u8, 111u8, 45u8, 49u8, 50u8, 54u8, 48u8, 98u8, 117u8, 110u8,
-                        112u8, 111u8, 45u8, 49u8, 51u8, 49u8, 55u8, 98u8, 117u8, 110u8, 114u8,
-                        111u8, 107u8, 117u8, 45u8, 49u8, 53u8, 57u8, 50u8, 98u8, 117u8, 110u8,
-                        114u8, 121u8, 97u8, 107u8, 117u8, 45u8, 49u8, 50u8, 51u8, 52u8, 98u8,
-                        117u8, 110u8, 115u8, 101u8, 105u8, 45u8, 49u8, 56u8, 49u8, 56u8, 98u8,
-                        117u8, 110u8, 115u8, 104u8, 111u8, 45u8, 49u8, 52u8, 54u8, 54u8, 99u8,
-                        101u8, 99u8, 104u8, 111u8, 103u8, 101u8, 110u8, 45u8, 49u8, 48u8, 50u8,
-                        56u8, 99u8, 104u8, 111u8, 104u8, 111u8, 45u8, 57u8, 57u8, 57u8, 99u8,
-                        104u8, 111u8, 106u8, 105u8, 45u8, 49u8, 49u8, 48u8, 52u8, 99u8, 104u8,
-                        111u8, 107u8, 97u8, 110u8, 45u8, 49u8, 49u8, 54u8, 51u8, 99u8, 104u8,
-                        111u8, 107u8, 121u8, 111u8, 45u8, 49u8, 52u8, 56u8, 55u8, 99u8, 104u8,
-                        111u8, 107u8, 121u8, 117u8, 45u8, 49u8, 48u8, 52u8, 48u8, 99u8, 104u8,
-                        111u8, 114u8, 111u8, 107u8, 117u8, 45u8, 49u8, 52u8, 53u8, 55u8, 99u8,
-                        104u8, 111u8, 114u8, 121u8, 97u8, 107u8, 117u8, 45u8, 49u8, 48u8, 51u8,
-                        55u8, 99u8, 104u8, 111u8, 115u8, 104u8, 111u8, 45u8, 49u8, 49u8, 51u8,
-                        50u8, 99u8, 104u8, 111u8, 116u8, 111u8, 107u8, 117u8, 45u8, 57u8, 57u8,
-                        53u8, 99u8, 104u8, 111u8, 119u8, 97u8, 45u8, 49u8, 48u8, 49u8, 50u8, 100u8,
-                        97u8, 105u8, 100u8, 111u8, 45u8, 56u8, 48u8, 54u8, 100u8, 97u8, 105u8,
-                        106u8, 105u8, 45u8, 49u8, 49u8, 50u8, 54u8, 101u8, 105u8, 99u8, 104u8,
-                        111u8, 45u8, 49u8, 48u8, 57u8, 54u8, 101u8, 105u8, 101u8, 110u8, 45u8,
-                        57u8, 56u8, 55u8, 101u8, 105u8, 104u8, 111u8, 45u8, 49u8, 48u8, 56u8, 49u8,
-                        101u8, 105u8, 106u8, 105u8, 45u8, 49u8, 49u8, 52u8, 49u8, 101u8, 105u8,
-                        107u8, 97u8, 110u8, 45u8, 57u8, 56u8, 51u8, 101u8, 105u8, 107u8, 121u8,
-                        111u8, 45u8, 49u8, 52u8, 50u8, 57u8, 101u8, 105u8, 107u8, 121u8, 117u8,
-                        45u8, 49u8, 49u8, 49u8, 51u8, 101u8, 105u8, 109u8, 97u8, 110u8, 45u8, 49u8,
-                        49u8, 54u8, 53u8, 101u8, 105u8, 110u8, 105u8, 110u8, 45u8, 49u8, 50u8,
-                        57u8, 51u8, 101u8, 105u8, 114u8, 111u8, 107u8, 117u8, 45u8, 49u8, 53u8,
-                        53u8, 56u8, 101u8, 105u8, 114u8, 121u8, 97u8, 107u8, 117u8, 45u8, 49u8,
-                        49u8, 54u8, 48u8, 101u8, 105u8, 115u8, 104u8, 111u8, 45u8, 49u8, 48u8,
-                        52u8, 54u8, 101u8, 105u8, 115u8, 104u8, 111u8, 45u8, 49u8, 53u8, 48u8,
-                        52u8, 101u8, 105u8, 115u8, 111u8, 45u8, 57u8, 56u8, 57u8, 101u8, 110u8,
-                        99u8, 104u8, 111u8, 45u8, 57u8, 50u8, 51u8, 101u8, 110u8, 103u8, 101u8,
-                        110u8, 45u8, 49u8, 51u8, 51u8, 54u8, 101u8, 110u8, 103u8, 105u8, 45u8,
-                        57u8, 48u8, 49u8, 101u8, 110u8, 107u8, 121u8, 111u8, 45u8, 49u8, 51u8,
-                        48u8, 56u8, 101u8, 110u8, 107u8, 121u8, 111u8, 45u8, 49u8, 55u8, 52u8,
-                        52u8, 101u8, 110u8, 107u8, 121u8, 117u8, 45u8, 49u8, 48u8, 54u8, 57u8,
-                        101u8, 110u8, 111u8, 45u8, 49u8, 50u8, 51u8, 57u8, 101u8, 110u8, 112u8,
-                        111u8, 45u8, 49u8, 54u8, 55u8, 51u8, 101u8, 110u8, 114u8, 121u8, 97u8,
-                        107u8, 117u8, 45u8, 55u8, 56u8, 50u8, 101u8, 110u8, 116u8, 111u8, 107u8,
-                        117u8, 45u8, 49u8, 52u8, 56u8, 57u8, 103u8, 97u8, 110u8, 103u8, 121u8,
-                        111u8, 45u8, 56u8, 55u8, 55u8, 103u8, 101u8, 110u8, 98u8, 117u8, 110u8,
-                        45u8, 49u8, 55u8, 51u8, 54u8, 103u8, 101u8, 110u8, 99u8, 104u8, 117u8,
-                        45u8, 49u8, 51u8, 56u8, 52u8, 103u8, 101u8, 110u8, 101u8, 105u8, 45u8,
-                        49u8, 49u8, 49u8, 56u8, 103u8, 101u8, 110u8, 106u8, 105u8, 45u8, 49u8,
-                        56u8, 54u8, 52u8, 103u8, 101u8, 110u8, 107u8, 105u8, 45u8, 49u8, 53u8,
-                        55u8, 48u8, 103u8, 101u8, 110u8, 107u8, 111u8, 45u8, 49u8, 51u8, 50u8,
-                        49u8, 103u8, 101u8, 110u8, 107u8, 111u8, 45u8, 49u8, 51u8, 51u8, 49u8,
-                        103u8, 101u8, 110u8, 107u8, 121u8, 117u8, 45u8, 49u8, 50u8, 48u8, 52u8,
-                        103u8, 101u8, 110u8, 110u8, 97u8, 45u8, 49u8, 54u8, 49u8, 53u8, 103u8,
-                        101u8, 110u8, 110u8, 105u8, 110u8, 45u8, 49u8, 50u8, 50u8, 52u8, 103u8,
-                        101u8, 110u8, 111u8, 45u8, 49u8, 51u8, 49u8, 57u8, 103u8, 101u8, 110u8,
-                        114u8, 111u8, 107u8, 117u8, 45u8, 49u8, 54u8, 56u8, 56u8, 103u8, 101u8,
-                        110u8, 114u8, 121u8, 97u8, 107u8, 117u8, 45u8, 49u8, 49u8, 56u8, 52u8,
-                        103u8, 101u8, 110u8, 116u8, 111u8, 107u8, 117u8, 45u8, 49u8, 51u8, 50u8,
-                        57u8, 104u8, 97u8, 107u8, 117u8, 99u8, 104u8, 105u8, 45u8, 54u8, 53u8,
-                        48u8, 104u8, 97u8, 107u8, 117u8, 104u8, 111u8, 45u8, 54u8, 55u8, 50u8,
-                        104u8, 101u8, 105u8, 106u8, 105u8, 45u8, 49u8, 49u8, 53u8, 57u8, 104u8,
-                        101u8, 105u8, 115u8, 101u8, 105u8, 104u8, 111u8, 97u8, 110u8, 45u8, 49u8,
-                        49u8, 50u8, 48u8, 104u8, 111u8, 101u8, 105u8, 45u8, 49u8, 55u8, 48u8, 52u8,
-                        104u8, 111u8, 101u8, 110u8, 45u8, 49u8, 49u8, 51u8, 53u8, 104u8, 111u8,
-                        103u8, 101u8, 110u8, 45u8, 49u8, 49u8, 53u8, 54u8, 104u8, 111u8, 106u8,
-                        105u8, 45u8, 49u8, 50u8, 52u8, 55u8, 104u8, 111u8, 107u8, 105u8, 45u8,
-                        55u8, 55u8, 48u8, 104u8, 111u8, 114u8, 101u8, 107u8, 105u8, 45u8, 49u8,
-                        55u8, 53u8, 49u8, 104u8, 111u8, 116u8, 111u8, 107u8, 117u8, 45u8, 49u8,
-                        52u8, 52u8, 57u8, 106u8, 105u8, 97u8, 110u8, 45u8, 49u8, 48u8, 50u8, 49u8,
-                        106u8, 105u8, 110u8, 103u8, 111u8, 107u8, 101u8, 105u8, 117u8, 110u8, 45u8,
-                        55u8, 54u8, 55u8, 106u8, 105u8, 110u8, 107u8, 105u8, 45u8, 55u8, 50u8,
-                        52u8, 106u8, 105u8, 114u8, 121u8, 97u8, 107u8, 117u8, 45u8, 49u8, 48u8,
-                        54u8, 53u8, 106u8, 105u8, 115u8, 104u8, 111u8, 45u8, 49u8, 49u8, 55u8,
-                        55u8, 106u8, 111u8, 101u8, 105u8, 45u8, 49u8, 50u8, 51u8, 50u8, 106u8,
-                        111u8, 103u8, 97u8, 110u8, 45u8, 56u8, 53u8, 57u8, 106u8, 111u8, 103u8,
-                        101u8, 110u8, 45u8, 49u8, 50u8, 48u8, 55u8, 106u8, 111u8, 103u8, 101u8,
-                        110u8, 45u8, 57u8, 55u8, 54u8, 106u8, 111u8, 104u8, 101u8, 105u8, 45u8,
-                        57u8, 51u8, 49u8, 106u8, 111u8, 107u8, 121u8, 111u8, 45u8, 49u8, 54u8,
-                        56u8, 52u8, 106u8, 111u8, 107u8, 121u8, 117u8, 45u8, 49u8, 50u8, 49u8,
-                        57u8, 106u8, 111u8, 111u8, 45u8, 49u8, 50u8, 50u8, 50u8, 106u8, 111u8,
-                        111u8, 45u8, 49u8, 54u8, 53u8, 50u8, 106u8, 111u8, 116u8, 111u8, 107u8,
-                        117u8, 45u8, 49u8, 48u8, 57u8, 55u8, 106u8, 111u8, 119u8, 97u8, 45u8, 56u8,
-                        51u8, 52u8, 106u8, 117u8, 101u8, 105u8, 45u8, 49u8, 49u8, 56u8, 50u8,
-                        107u8, 97u8, 101u8, 105u8, 45u8, 49u8, 56u8, 52u8, 56u8, 107u8, 97u8,
-                        103u8, 101u8, 110u8, 45u8, 49u8, 51u8, 48u8, 51u8, 107u8, 97u8, 104u8,
-                        111u8, 45u8, 49u8, 48u8, 57u8, 52u8, 107u8, 97u8, 106u8, 111u8, 45u8, 56u8,
-                        52u8, 56u8, 107u8, 97u8, 107u8, 101u8, 105u8, 45u8, 49u8, 51u8, 56u8, 55u8,
-                        107u8, 97u8, 107u8, 105u8, 116u8, 115u8, 117u8, 45u8, 49u8, 52u8, 52u8,
-                        49u8, 107u8, 97u8, 110u8, 98u8, 117u8, 110u8, 45u8, 49u8, 54u8, 54u8, 49u8,
-                        107u8, 97u8, 110u8, 101u8, 105u8, 45u8, 49u8, 54u8, 50u8, 52u8, 107u8,
-                        97u8, 110u8, 101u8, 110u8, 45u8, 49u8, 55u8, 52u8, 56u8, 107u8, 97u8,
-                        110u8, 103u8, 101u8, 110u8, 45u8, 49u8, 50u8, 52u8, 51u8, 107u8, 97u8,
-                        110u8, 106u8, 105u8, 45u8, 49u8, 48u8, 56u8, 55u8, 107u8, 97u8, 110u8,
-                        107u8, 105u8, 45u8, 49u8, 50u8, 50u8, 57u8, 107u8, 97u8, 110u8, 107u8,
-                        111u8, 45u8, 49u8, 48u8, 48u8, 52u8, 107u8, 97u8, 110u8, 110u8, 97u8, 45u8,
-                        57u8, 56u8, 53u8, 107u8, 97u8, 110u8, 110u8, 105u8, 110u8, 45u8, 49u8,
-                        48u8, 49u8, 55u8, 107u8, 97u8, 110u8, 112u8, 111u8, 45u8, 49u8, 55u8, 52u8,
-                        49u8, 107u8, 97u8, 110u8, 112u8, 121u8, 111u8, 45u8, 56u8, 56u8, 57u8,
-                        107u8, 97u8, 110u8, 115u8, 101u8, 105u8, 45u8, 49u8, 55u8, 56u8, 57u8,
-                        107u8, 97u8, 110u8, 115u8, 104u8, 111u8, 45u8, 49u8, 52u8, 54u8, 48u8,
-                        107u8, 97u8, 110u8, 116u8, 111u8, 107u8, 117u8, 45u8, 49u8, 48u8, 52u8,
-                        52u8, 107u8, 97u8, 111u8, 45u8, 49u8, 49u8, 54u8, 57u8, 107u8, 97u8, 114u8,
-                        111u8, 107u8, 117u8, 45u8, 49u8, 50u8, 50u8, 53u8, 107u8, 97u8, 114u8,
-                        121u8, 97u8, 107u8, 117u8, 45u8, 49u8, 51u8, 50u8, 54u8, 107u8, 97u8,
-                        115u8, 104u8, 111u8, 45u8, 49u8, 49u8, 48u8, 54u8, 107u8, 97u8, 116u8,
-                        101u8, 105u8, 45u8, 49u8, 50u8, 51u8, 53u8, 107u8, 101u8, 105u8, 97u8,
-                        110u8, 45u8, 49u8, 54u8, 52u8, 56u8, 107u8, 101u8, 105u8, 99u8, 104u8,
-                        111u8, 45u8, 49u8, 53u8, 57u8, 54u8, 107u8, 101u8, 105u8, 111u8, 45u8,
-                        49u8, 56u8, 54u8, 53u8, 107u8, 101u8, 105u8, 117u8, 110u8, 45u8, 55u8,
-                        48u8, 52u8, 107u8, 101u8, 110u8, 99u8, 104u8, 111u8, 45u8, 49u8, 50u8,
-                        52u8, 57u8, 107u8, 101u8, 110u8, 101u8, 105u8, 45u8, 49u8, 50u8, 48u8,
-                        54u8, 107u8, 101u8, 110u8, 103u8, 101u8, 110u8, 45u8, 49u8, 51u8, 48u8,
-                        50u8, 107u8, 101u8, 110u8, 106u8, 105u8, 45u8, 49u8, 50u8, 55u8, 53u8,
-                        107u8, 101u8, 110u8, 107u8, 121u8, 117u8, 45u8, 49u8, 49u8, 57u8, 48u8,
-                        107u8, 101u8, 110u8, 109u8, 117u8, 45u8, 49u8, 51u8, 51u8, 52u8, 107u8,
-                        101u8, 110u8, 110u8, 105u8, 110u8, 45u8, 49u8, 50u8, 48u8, 49u8, 107u8,
-                        101u8, 110u8, 112u8, 111u8, 45u8, 49u8, 50u8, 49u8, 51u8, 107u8, 101u8,
-                        110u8, 114u8, 121u8, 97u8, 107u8, 117u8, 45u8, 49u8, 50u8, 49u8, 49u8,
-                        107u8, 101u8, 110u8, 116u8, 111u8, 107u8, 117u8, 45u8, 49u8, 51u8, 55u8,
-                        48u8, 107u8, 111u8, 97u8, 110u8, 45u8, 49u8, 50u8, 55u8, 56u8, 107u8,
-                        111u8, 99u8, 104u8, 111u8, 45u8, 49u8, 50u8, 54u8, 49u8, 107u8, 111u8,
-                        103u8, 101u8, 110u8, 45u8, 49u8, 50u8, 53u8, 54u8, 107u8, 111u8, 104u8,
-                        101u8, 105u8, 45u8, 49u8, 48u8, 53u8, 56u8, 107u8, 111u8, 104u8, 111u8,
-                        45u8, 57u8, 54u8, 52u8, 107u8, 111u8, 106u8, 105u8, 45u8, 49u8, 49u8, 52u8,
-                        50u8, 107u8, 111u8, 106u8, 105u8, 45u8, 49u8, 53u8, 53u8, 53u8, 107u8,
-                        111u8, 107u8, 97u8, 45u8, 49u8, 56u8, 52u8, 52u8, 107u8, 111u8, 107u8,
-                        111u8, 107u8, 117u8, 45u8, 49u8, 51u8, 52u8, 48u8, 107u8, 111u8, 110u8,
-                        105u8, 110u8, 45u8, 56u8, 49u8, 48u8, 107u8, 111u8, 111u8, 45u8, 49u8,
-                        51u8, 56u8, 57u8, 107u8, 111u8, 114u8, 121u8, 97u8, 107u8, 117u8, 45u8,
-                        49u8, 51u8, 55u8, 57u8, 107u8, 111u8, 115u8, 104u8, 111u8, 45u8, 49u8,
-                        52u8, 53u8, 53u8, 107u8, 111u8, 119u8, 97u8, 45u8, 49u8, 48u8, 57u8, 57u8,
-                        107u8, 111u8, 119u8, 97u8, 45u8, 49u8, 51u8, 56u8, 49u8, 107u8, 121u8,
-                        111u8, 104u8, 111u8, 45u8, 49u8, 55u8, 49u8, 54u8, 107u8, 121u8, 111u8,
-                        114u8, 111u8, 107u8, 117u8, 45u8, 49u8, 53u8, 50u8, 56u8, 107u8, 121u8,
-                        111u8, 116u8, 111u8, 107u8, 117u8, 45u8, 49u8, 52u8, 53u8, 50u8, 107u8,
-                        121u8, 111u8, 119u8, 97u8, 45u8, 49u8, 56u8, 48u8, 49u8, 107u8, 121u8,
-                        117u8, 97u8, 110u8, 45u8, 49u8, 49u8, 52u8, 53u8, 107u8, 121u8, 117u8,
-                        106u8, 117u8, 45u8, 49u8, 49u8, 53u8, 52u8, 109u8, 97u8, 110u8, 101u8,
-                        110u8, 45u8, 49u8, 56u8, 54u8, 48u8, 109u8, 97u8, 110u8, 106u8, 105u8,
-                        45u8, 49u8, 54u8, 53u8, 56u8, 109u8, 97u8, 110u8, 106u8, 117u8, 45u8, 49u8,
-                        48u8, 50u8, 52u8, 109u8, 101u8, 105u8, 106u8, 105u8, 109u8, 101u8, 105u8,
-                        111u8, 45u8, 49u8, 52u8, 57u8, 50u8, 109u8, 101u8, 105u8, 114u8, 101u8,
-                        107u8, 105u8, 45u8, 49u8, 54u8, 53u8, 53u8, 109u8, 101u8, 105u8, 116u8,
-                        111u8, 107u8, 117u8, 45u8, 49u8, 51u8, 56u8, 55u8, 109u8, 101u8, 105u8,
-                        116u8, 111u8, 107u8, 117u8, 45u8, 49u8, 51u8, 57u8, 48u8, 109u8, 101u8,
-                        105u8, 119u8, 97u8, 45u8, 49u8, 55u8, 54u8, 52u8, 110u8, 105u8, 110u8,
-                        97u8, 110u8, 45u8, 49u8, 49u8, 54u8, 54u8, 110u8, 105u8, 110u8, 106u8,
-                        105u8, 45u8, 49u8, 50u8, 52u8, 48u8, 110u8, 105u8, 110u8, 106u8, 117u8,
-                        45u8, 56u8, 53u8, 49u8, 110u8, 105u8, 110u8, 110u8, 97u8, 45u8, 56u8, 56u8,
-                        53u8, 110u8, 105u8, 110u8, 112u8, 101u8, 105u8, 45u8, 49u8, 49u8, 53u8,
-                        49u8, 111u8, 99u8, 104u8, 111u8, 45u8, 49u8, 51u8, 49u8, 49u8, 111u8,
-                        101u8, 105u8, 45u8, 49u8, 51u8, 57u8, 52u8, 111u8, 104u8, 111u8, 45u8,
-                        49u8, 49u8, 54u8, 49u8, 111u8, 110u8, 105u8, 110u8, 45u8, 49u8, 52u8, 54u8,
-                        55u8, 111u8, 116u8, 111u8, 107u8, 117u8, 45u8, 49u8, 48u8, 56u8, 52u8,
-                        111u8, 119u8, 97u8, 45u8, 57u8, 54u8, 49u8, 114u8, 101u8, 105u8, 107u8,
-                        105u8, 45u8, 55u8, 49u8, 53u8, 114u8, 101u8, 105u8, 119u8, 97u8, 114u8,
-                        121u8, 97u8, 107u8, 117u8, 110u8, 105u8, 110u8, 45u8, 49u8, 50u8, 51u8,
-                        56u8, 115u8, 97u8, 105u8, 107u8, 111u8, 45u8, 56u8, 53u8, 52u8, 115u8,
-                        104u8, 111u8, 97u8, 110u8, 45u8, 49u8, 49u8, 55u8, 49u8, 115u8, 104u8,
-                        111u8, 97u8, 110u8, 45u8, 49u8, 50u8, 57u8, 57u8, 115u8, 104u8, 111u8,
-                        99u8, 104u8, 111u8, 45u8, 49u8, 52u8, 50u8, 56u8, 115u8, 104u8, 111u8,
-                        99u8, 104u8, 117u8, 45u8, 49u8, 51u8, 50u8, 52u8, 115u8, 104u8, 111u8,
-                        103u8, 101u8, 110u8, 45u8, 49u8, 50u8, 53u8, 57u8, 115u8, 104u8, 111u8,
-                        104u8, 101u8, 105u8, 45u8, 49u8, 51u8, 52u8, 54u8, 115u8, 104u8, 111u8,
-                        104u8, 111u8, 45u8, 49u8, 48u8, 55u8, 52u8, 115u8, 104u8, 111u8, 104u8,
-                        111u8, 45u8, 49u8, 54u8, 52u8, 52u8, 115u8, 104u8, 111u8, 106u8, 105u8,
-                        45u8, 49u8, 49u8, 57u8, 57u8, 115u8, 104u8, 111u8, 107u8, 97u8, 45u8, 49u8,
-                        50u8, 53u8, 55u8, 115u8, 104u8, 111u8, 111u8, 45u8, 49u8, 50u8, 56u8, 56u8,
-                        115u8, 104u8, 111u8, 114u8, 121u8, 97u8, 107u8, 117u8, 45u8, 49u8, 48u8,
-                        55u8, 55u8, 115u8, 104u8, 111u8, 114u8, 121u8, 97u8, 107u8, 117u8, 45u8,
-                        57u8, 57u8, 48u8, 115u8, 104u8, 111u8, 116u8, 97u8, 105u8, 45u8, 56u8,
-                        57u8, 56u8, 115u8, 104u8, 111u8, 116u8, 111u8, 107u8, 117u8, 45u8, 49u8,
-                        55u8, 49u8, 49u8, 115u8, 104u8, 111u8, 119u8, 97u8, 115u8, 104u8, 111u8,
-                        119u8, 97u8, 45u8, 49u8, 51u8, 49u8, 50u8, 115u8, 104u8, 117u8, 99u8,
-                        104u8, 111u8, 45u8, 54u8, 56u8, 54u8, 116u8, 97u8, 105u8, 101u8, 105u8,
-                        45u8, 49u8, 53u8, 50u8, 49u8, 116u8, 97u8, 105u8, 104u8, 111u8, 45u8, 55u8,
-                        48u8, 49u8, 116u8, 97u8, 105u8, 107u8, 97u8, 45u8, 54u8, 52u8, 53u8, 116u8,
-                        97u8, 105u8, 115u8, 104u8, 111u8, 116u8, 101u8, 110u8, 97u8, 110u8, 45u8,
-                        56u8, 53u8, 55u8, 116u8, 101u8, 110u8, 98u8, 117u8, 110u8, 45u8, 49u8,
-                        53u8, 51u8, 50u8, 116u8, 101u8, 110u8, 99u8, 104u8, 111u8, 45u8, 56u8,
-                        50u8, 52u8, 116u8, 101u8, 110u8, 101u8, 105u8, 45u8, 49u8, 49u8, 49u8,
-                        48u8, 116u8, 101u8, 110u8, 101u8, 110u8, 45u8, 57u8, 55u8, 51u8, 116u8,
-                        101u8, 110u8, 103u8, 101u8, 110u8, 45u8, 57u8, 55u8, 56u8, 116u8, 101u8,
-                        110u8, 103u8, 105u8, 45u8, 49u8, 48u8, 53u8, 51u8, 116u8, 101u8, 110u8,
-                        103u8, 121u8, 111u8, 45u8, 57u8, 51u8, 56u8, 116u8, 101u8, 110u8, 106u8,
-                        105u8, 45u8, 49u8, 49u8, 50u8, 52u8, 116u8, 101u8, 110u8, 106u8, 117u8,
-                        45u8, 49u8, 51u8, 55u8, 53u8, 116u8, 101u8, 110u8, 109u8, 101u8, 105u8,
-                        45u8, 49u8, 55u8, 56u8, 49u8, 116u8, 101u8, 110u8, 110u8, 97u8, 45u8, 49u8,
-                        54u8, 56u8, 49u8, 116u8, 101u8, 110u8, 110u8, 105u8, 110u8, 45u8, 49u8,
-                        49u8, 48u8, 56u8, 116u8, 101u8, 110u8, 111u8, 45u8, 55u8, 56u8, 49u8,
-                        116u8, 101u8, 110u8, 112u8, 111u8, 45u8, 49u8, 56u8, 51u8, 48u8, 116u8,
-                        101u8, 110u8, 112u8, 117u8, 107u8, 117u8, 45u8, 49u8, 50u8, 51u8, 51u8,
-                        116u8, 101u8, 110u8, 112u8, 121u8, 111u8, 45u8, 55u8, 50u8, 57u8, 116u8,
-                        101u8, 110u8, 112u8, 121u8, 111u8, 104u8, 111u8, 106u8, 105u8, 45u8, 55u8,
-                        53u8, 55u8, 116u8, 101u8, 110u8, 112u8, 121u8, 111u8, 106u8, 105u8, 110u8,
-                        103u8, 111u8, 45u8, 55u8, 54u8, 53u8, 116u8, 101u8, 110u8, 112u8, 121u8,
-                        111u8, 107u8, 97u8, 109u8, 112u8, 111u8, 45u8, 55u8, 52u8, 57u8, 116u8,
-                        101u8, 110u8, 112u8, 121u8, 111u8, 115u8, 104u8, 111u8, 104u8, 111u8, 45u8,
-                        55u8, 52u8, 57u8, 116u8, 101u8, 110u8, 114u8, 111u8, 107u8, 117u8, 45u8,
-                        57u8, 55u8, 48u8, 116u8, 101u8, 110u8, 114u8, 121u8, 97u8, 107u8, 117u8,
-                        45u8, 57u8, 52u8, 55u8, 116u8, 101u8, 110u8, 115u8, 104u8, 111u8, 45u8,
-                        49u8, 49u8, 51u8, 49u8, 116u8, 101u8, 110u8, 115u8, 104u8, 111u8, 45u8,
-                        49u8, 53u8, 55u8, 51u8, 116u8, 101u8, 110u8, 116u8, 111u8, 107u8, 117u8,
-                        45u8, 57u8, 53u8, 55u8, 116u8, 101u8, 110u8, 121u8, 111u8, 45u8, 49u8,
-                        49u8, 52u8, 52u8, 116u8, 111u8, 107u8, 117u8, 106u8, 105u8, 45u8, 49u8,
-                        51u8, 48u8, 54u8, 119u8, 97u8, 100u8, 111u8, 45u8, 55u8, 48u8, 56u8, 121u8,
-                        111u8, 114u8, 111u8, 45u8, 55u8, 49u8, 55u8, 121u8, 111u8, 119u8, 97u8,
-                        45u8, 49u8, 49u8, 56u8, 49u8,
-                    ])
-                },
-                unsafe {
-                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
-                        239u8, 0u8, 0u8, 0u8, 0u8, 0u8, 21u8, 0u8, 40u8, 0u8, 56u8, 0u8, 75u8, 0u8,
-                        94u8, 0u8, 150u8, 0u8, 172u8, 0u8, 193u8, 0u8, 215u8, 0u8, 234u8, 0u8,
-                        253u8, 0u8, 16u8, 1u8, 37u8, 1u8, 57u8, 1u8, 79u8, 1u8, 99u8, 1u8, 120u8,
-                        1u8, 142u8, 1u8, 162u8, 1u8, 183u8, 1u8, 227u8, 1u8, 248u8, 1u8, 12u8, 2u8,
-                        32u8, 2u8, 53u8, 2u8, 75u8, 2u8, 97u8, 2u8, 119u8, 2u8, 142u8, 2u8, 164u8,
-                        2u8, 184u8, 2u8, 204u8, 2u8, 222u8, 2u8, 241u8, 2u8, 5u8, 3u8, 21u8, 3u8,
-                        40u8, 3u8, 58u8, 3u8, 75u8, 3u8, 95u8, 3u8, 115u8, 3u8, 134u8, 3u8, 153u8,
-                        3u8, 173u8, 3u8, 194u8, 3u8, 214u8, 3u8, 234u8, 3u8, 250u8, 3u8, 12u8, 4u8,
-                        31u8, 4u8, 47u8, 4u8, 67u8, 4u8, 87u8, 4u8, 107u8, 4u8, 128u8, 4u8, 147u8,
-                        4u8, 166u8, 4u8, 186u8, 4u8, 205u8, 4u8, 225u8, 4u8, 246u8, 4u8, 12u8, 5u8,
-                        31u8, 5u8, 50u8, 5u8, 70u8, 5u8, 90u8, 5u8, 111u8, 5u8, 130u8, 5u8, 150u8,
-                        5u8, 169u8, 5u8, 190u8, 5u8, 212u8, 5u8, 233u8, 5u8, 252u8, 5u8, 15u8, 6u8,
-                        34u8, 6u8, 40u8, 6u8, 59u8, 6u8, 78u8, 6u8, 97u8, 6u8, 117u8, 6u8, 136u8,
-                        6u8, 153u8, 6u8, 174u8, 6u8, 195u8, 6u8, 213u8, 6u8, 236u8, 6u8, 253u8,
-                        6u8, 18u8, 7u8, 38u8, 7u8, 57u8, 7u8, 75u8, 7u8, 95u8, 7u8, 113u8, 7u8,
-                        131u8, 7u8, 152u8, 7u8, 173u8, 7u8, 192u8, 7u8, 211u8, 7u8, 232u8, 7u8,
-                        249u8, 7u8, 11u8, 8u8, 29u8, 8u8, 48u8, 8u8, 67u8, 8u8, 84u8, 8u8, 103u8,
-                        8u8, 124u8, 8u8, 144u8, 8u8, 166u8, 8u8, 188u8, 8u8, 208u8, 8u8, 227u8,
-                        8u8, 246u8, 8u8, 10u8, 9u8, 27u8, 9u8, 47u8, 9u8, 67u8, 9u8, 86u8, 9u8,
-                        106u8, 9u8, 127u8, 9u8, 148u8, 9u8, 166u8, 9u8, 186u8, 9u8, 207u8, 9u8,
-                        227u8, 9u8, 246u8, 9u8, 9u8, 10u8, 30u8, 10u8, 49u8, 10u8, 66u8, 10u8,
-                        87u8, 10u8, 109u8, 10u8, 129u8, 10u8, 148u8, 10u8, 169u8, 10u8, 188u8,
-                        10u8, 208u8, 10u8, 228u8, 10u8, 250u8, 10u8, 15u8, 11u8, 34u8, 11u8, 55u8,
-                        11u8, 75u8, 11u8, 95u8, 11u8, 113u8, 11u8, 132u8, 11u8, 151u8, 11u8, 170u8,
-                        11u8, 191u8, 11u8, 209u8, 11u8, 228u8, 11u8, 250u8, 11u8, 15u8, 12u8, 34u8,
-                        12u8, 53u8, 12u8, 74u8, 12u8, 96u8, 12u8, 118u8, 12u8, 138u8, 12u8, 158u8,
-                        12u8, 178u8, 12u8, 200u8, 12u8, 219u8, 12u8, 238u8, 12u8, 243u8, 12u8, 6u8,
-                        13u8, 27u8, 13u8, 48u8, 13u8, 69u8, 13u8, 88u8, 13u8, 110u8, 13u8, 129u8,
-                        13u8, 146u8, 13u8, 163u8, 13u8, 183u8, 13u8, 203u8, 13u8, 221u8, 13u8,
-                        239u8, 13u8, 2u8, 14u8, 22u8, 14u8, 38u8, 14u8, 55u8, 14u8, 60u8, 14u8,
-                        82u8, 14u8, 100u8, 14u8, 120u8, 14u8, 140u8, 14u8, 162u8, 14u8, 184u8,
-                        14u8, 205u8, 14u8, 226u8, 14u8, 246u8, 14u8, 10u8, 15u8, 30u8, 15u8, 50u8,
-                        15u8, 70u8, 15u8, 93u8, 15u8, 114u8, 15u8, 133u8, 15u8, 155u8, 15u8, 161u8,
-                        15u8, 181u8, 15u8, 200u8, 15u8, 219u8, 15u8, 237u8, 15u8, 254u8, 15u8, 5u8,
-                        16u8, 23u8, 16u8, 43u8, 16u8, 62u8, 16u8, 82u8, 16u8, 102u8, 16u8, 120u8,
-                        16u8, 139u8, 16u8, 158u8, 16u8, 177u8, 16u8, 196u8, 16u8, 216u8, 16u8,
-                        235u8, 16u8, 255u8, 16u8, 17u8, 17u8, 37u8, 17u8, 58u8, 17u8, 77u8, 17u8,
-                        102u8, 17u8, 127u8, 17u8, 153u8, 17u8, 180u8, 17u8, 199u8, 17u8, 219u8,
-                        17u8, 240u8, 17u8, 5u8, 18u8, 24u8, 18u8, 47u8, 18u8, 67u8, 18u8, 84u8,
-                        18u8, 102u8, 18u8, 65u8, 110u8, 226u8, 128u8, 153u8, 101u8, 105u8, 32u8,
-                        40u8, 49u8, 55u8, 55u8, 50u8, 226u8, 128u8, 147u8, 49u8, 55u8, 56u8, 49u8,
-                        41u8, 65u8, 110u8, 103u8, 101u8, 110u8, 32u8, 40u8, 49u8, 49u8, 55u8, 53u8,
-                        226u8, 128u8, 147u8, 49u8, 49u8, 55u8, 55u8, 41u8, 65u8, 110u8, 110u8,
-                        97u8, 32u8, 40u8, 57u8, 54u8, 56u8, 226u8, 128u8, 147u8, 57u8, 55u8, 48u8,
-                        41u8, 65u8, 110u8, 115u8, 101u8, 105u8, 32u8, 40u8, 49u8, 56u8, 53u8, 52u8,
-                        226u8, 128u8, 147u8, 49u8, 56u8, 54u8, 48u8, 41u8, 65u8, 110u8, 116u8,
-                        101u8, 105u8, 32u8, 40u8, 49u8, 50u8, 50u8, 55u8, 226u8, 128u8, 147u8,
-                        49u8, 50u8, 50u8, 57u8, 41u8, 240u8, 145u8, 132u8, 136u8, 240u8, 145u8,
-                        132u8, 179u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 168u8,
-                        240u8, 145u8, 132u8, 140u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8,
-                        132u8, 145u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 155u8,
-                        240u8, 145u8, 132u8, 171u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8,
-                        132u8, 180u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 167u8, 66u8,
-                        117u8, 110u8, 226u8, 128u8, 153u8, 97u8, 110u8, 32u8, 40u8, 49u8, 52u8,
-                        52u8, 52u8, 226u8, 128u8, 147u8, 49u8, 52u8, 52u8, 57u8, 41u8, 66u8, 117u8,
-                        110u8, 99u8, 104u8, 197u8, 171u8, 32u8, 40u8, 49u8, 51u8, 55u8, 50u8,
-                        226u8, 128u8, 147u8, 49u8, 51u8, 55u8, 53u8, 41u8, 66u8, 117u8, 110u8,
-                        226u8, 128u8, 153u8, 101u8, 105u8, 32u8, 40u8, 49u8, 50u8, 54u8, 52u8,
-                        226u8, 128u8, 147u8, 49u8, 50u8, 55u8, 53u8, 41u8, 66u8, 117u8, 110u8,
-                        106u8, 105u8, 32u8, 40u8, 49u8, 49u8, 56u8, 53u8, 226u8, 128u8, 147u8,
-                        49u8, 49u8, 57u8, 48u8, 41u8, 66u8, 117u8, 110u8, 107u8, 97u8, 32u8, 40u8,
-                        49u8, 56u8, 48u8, 52u8, 226u8, 128u8, 147u8, 49u8, 56u8, 49u8, 56u8, 41u8,
-                        66u8, 117u8, 110u8, 107u8, 105u8, 32u8, 40u8, 49u8, 53u8, 48u8, 49u8,
-                        226u8, 128u8, 147u8, 49u8, 53u8, 48u8, 52u8, 41u8, 66u8, 117u8, 110u8,
-                        107u8, 121u8, 197u8, 171u8, 32u8, 40u8, 49u8, 56u8, 54u8, 49u8, 226u8,
-                        128u8, 147u8, 49u8, 56u8, 54u8, 52u8, 41u8, 66u8, 117u8, 110u8, 109u8,
-                        101u8, 105u8, 32u8, 40u8, 49u8, 52u8, 54u8, 57u8, 226u8, 128u8, 147u8,
-                        49u8, 52u8, 56u8, 55u8, 41u8, 66u8, 117u8, 110u8, 226u8, 128u8, 153u8,
-                        197u8, 141u8, 32u8, 40u8, 49u8, 50u8, 54u8, 48u8, 226u8, 128u8, 147u8,
-                        49u8, 50u8, 54u8, 49u8, 41u8, 66u8, 117u8, 110u8, 112u8, 197u8, 141u8,
-                        32u8, 40u8, 49u8, 51u8, 49u8, 55u8, 226u8, 128u8, 147u8, 49u8, 51u8, 49u8,
-                        57u8, 41u8, 66u8, 117u8, 110u8, 114u8, 111u8, 107u8, 117u8, 32u8, 40u8,
-                        49u8, 53u8, 57u8, 50u8, 226u8, 128u8, 147u8, 49u8, 53u8, 57u8, 54u8, 41u8,
-                        66u8, 117u8, 110u8, 114u8, 121u8, 97u8, 107u8, 117u8, 32u8, 40u8, 49u8,
-                        50u8, 51u8, 52u8, 226u8, 128u8, 147u8, 49u8, 50u8, 51u8, 53u8, 41u8, 66u8,
-                        117u8, 110u8, 115u8, 101u8, 105u8, 32u8, 40u8, 49u8, 56u8, 49u8, 56u8,
-                        226u8, 128u8, 147u8, 49u8, 56u8, 51u8, 48u8, 41u8, 66u8, 117u8, 110u8,
-                        115u8, 104u8, 197u8, 141u8, 32u8, 40u8, 49u8, 52u8, 54u8, 54u8, 226u8,
-                        128u8, 147u8, 49u8, 52u8, 54u8, 55u8, 41u8, 240u8, 145u8, 132u8, 136u8,
-                        240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8,
-                        132u8, 168u8, 240u8, 145u8, 132u8, 140u8, 240u8, 145u8, 132u8, 180u8,
-                        240u8, 145u8, 132u8, 145u8, 240u8, 145u8, 132u8, 155u8, 240u8, 145u8,
-                        132u8, 180u8, 240u8, 145u8, 132u8, 152u8, 240u8, 145u8, 132u8, 167u8, 67u8,
-                        104u8, 197u8, 141u8, 103u8, 101u8, 110u8, 32u8, 40u8, 49u8, 48u8, 50u8,
-                        56u8, 226u8, 128u8, 147u8, 49u8, 48u8, 51u8, 55u8, 41u8, 67u8, 104u8,
-                        197u8, 141u8, 104u8, 197u8, 141u8, 32u8, 40u8, 57u8, 57u8, 57u8, 226u8,
-                        128u8, 147u8, 49u8, 48u8, 48u8, 52u8, 41u8, 67u8, 104u8, 197u8, 141u8,
-                        106u8, 105u8, 32u8, 40u8, 49u8, 49u8, 48u8, 52u8, 226u8, 128u8, 147u8,
-                        49u8, 49u8, 48u8, 54u8, 41u8, 67u8, 104u8, 197u8, 141u8, 107u8, 97u8,
-                        110u8, 32u8, 40u8, 49u8, 49u8, 54u8, 51u8, 226u8, 128u8, 147u8, 49u8, 49u8,
-                        54u8, 53u8, 41u8, 67u8, 104u8, 197u8, 141u8, 107u8, 121u8, 197u8, 141u8,
-                        32u8, 40u8, 49u8, 52u8, 56u8, 55u8, 226u8, 128u8, 147u8, 49u8, 52u8, 56u8,
-                        57u8, 41u8, 67u8, 104u8, 197u8, 141u8, 107u8, 121u8, 197u8, 171u8, 32u8,
-                        40u8, 49u8, 48u8, 52u8, 48u8, 226u8, 128u8, 147u8, 49u8, 48u8, 52u8, 52u8,
-                        41u8, 67u8, 104u8, 197u8, 141u8, 114u8, 111u8, 107u8, 117u8, 32u8, 40u8,
-                        49u8, 52u8, 53u8, 55u8, 226u8, 128u8, 147u8, 49u8, 52u8, 54u8, 48u8, 41u8,
-                        67u8, 104u8, 197u8, 141u8, 114u8, 121u8, 97u8, 107u8, 117u8, 32u8, 40u8,
-                        49u8, 48u8, 51u8, 55u8, 226u8, 128u8, 147u8, 49u8, 48u8, 52u8, 48u8, 41u8,
-                        67u8, 104u8, 197u8, 141u8, 115u8, 104u8, 197u8, 141u8, 32u8, 40u8, 49u8,
-                        49u8, 51u8, 50u8, 226u8, 128u8, 147u8, 49u8, 49u8, 51u8, 53u8, 41u8, 67u8,
-                        104u8, 197u8, 141u8, 116u8, 111u8, 107u8, 117u8, 32u8, 40u8, 57u8, 57u8,
-                        53u8, 226u8, 128u8, 147u8, 57u8, 57u8, 57u8, 41u8, 67u8, 104u8, 197u8,
-                        141u8, 119u8, 97u8, 32u8, 40u8, 49u8, 48u8, 49u8, 50u8, 226u8, 128u8,
-                        147u8, 49u8, 48u8, 49u8, 55u8, 41u8, 68u8, 97u8, 105u8, 100u8, 197u8,
-                        141u8, 32u8, 40u8, 56u8, 48u8, 54u8, 226u8, 128u8, 147u8, 56u8, 49u8, 48u8,
-                        41u8, 68u8, 97u8, 105u8, 106u8, 105u8, 32u8, 40u8, 49u8, 49u8, 50u8, 54u8,
-                        226u8, 128u8, 147u8, 49u8, 49u8, 51u8, 49u8, 41u8, 69u8, 105u8, 99u8,
-                        104u8, 197u8, 141u8, 32u8, 40u8, 49u8, 48u8, 57u8, 54u8, 226u8, 128u8,
-                        147u8, 49u8, 48u8, 57u8, 55u8, 41u8, 69u8, 105u8, 101u8, 110u8, 32u8, 40u8,
-                        57u8, 56u8, 55u8, 226u8, 128u8, 147u8, 57u8, 56u8, 57u8, 41u8, 69u8, 105u8,
-                        104u8, 197u8, 141u8, 32u8, 40u8, 49u8, 48u8, 56u8, 49u8, 226u8, 128u8,
-                        147u8, 49u8, 48u8, 56u8, 52u8, 41u8, 69u8, 105u8, 106u8, 105u8, 32u8, 40u8,
-                        49u8, 49u8, 52u8, 49u8, 226u8, 128u8, 147u8, 49u8, 49u8, 52u8, 50u8, 41u8,
-                        69u8, 105u8, 107u8, 97u8, 110u8, 32u8, 40u8, 57u8, 56u8, 51u8, 226u8,
-                        128u8, 147u8, 57u8, 56u8, 53u8, 41u8, 69u8, 105u8, 107u8, 121u8, 197u8,
-                        141u8, 32u8, 40u8, 49u8, 52u8, 50u8, 57u8, 226u8, 128u8, 147u8, 49u8, 52u8,
-                        52u8, 49u8, 41u8, 69u8, 105u8, 107u8, 121u8, 197u8, 171u8, 32u8, 40u8,
-                        49u8, 49u8, 49u8, 51u8, 226u8, 128u8, 147u8, 49u8, 49u8, 49u8, 56u8, 41u8,
-                        69u8, 105u8, 109u8, 97u8, 110u8, 32u8, 40u8, 49u8, 49u8, 54u8, 53u8, 226u8,
-                        128u8, 147u8, 49u8, 49u8, 54u8, 54u8, 41u8, 69u8, 105u8, 110u8, 105u8,
-                        110u8, 32u8, 40u8, 49u8, 50u8, 57u8, 51u8, 226u8, 128u8, 147u8, 49u8, 50u8,
-                        57u8, 57u8, 41u8, 69u8, 105u8, 114u8, 111u8, 107u8, 117u8, 32u8, 40u8,
-                        49u8, 53u8, 53u8, 56u8, 226u8, 128u8, 147u8, 49u8, 53u8, 55u8, 48u8, 41u8,
-                        69u8, 105u8, 114u8, 121u8, 97u8, 107u8, 117u8, 32u8, 40u8, 49u8, 49u8,
-                        54u8, 48u8, 226u8, 128u8, 147u8, 49u8, 49u8, 54u8, 49u8, 41u8, 69u8, 105u8,
-                        115u8, 104u8, 197u8, 141u8, 32u8, 40u8, 49u8, 48u8, 52u8, 54u8, 226u8,
-                        128u8, 147u8, 49u8, 48u8, 53u8, 51u8, 41u8, 69u8, 105u8, 115u8, 104u8,
-                        197u8, 141u8, 32u8, 40u8, 49u8, 53u8, 48u8, 52u8, 226u8, 128u8, 147u8,
-                        49u8, 53u8, 50u8, 49u8, 41u8, 69u8, 105u8, 115u8, 111u8, 32u8, 40u8, 57u8,
-                        56u8, 57u8, 226u8, 128u8, 147u8, 57u8, 57u8, 48u8, 41u8, 69u8, 110u8, 99u8,
-                        104u8, 197u8, 141u8, 32u8, 40u8, 57u8, 50u8, 51u8, 226u8, 128u8, 147u8,
-                        57u8, 51u8, 49u8, 41u8, 69u8, 110u8, 103u8, 101u8, 110u8, 32u8, 40u8, 49u8,
-                        51u8, 51u8, 54u8, 226u8, 128u8, 147u8, 49u8, 51u8, 52u8, 48u8, 41u8, 69u8,
-                        110u8, 103u8, 105u8, 32u8, 40u8, 57u8, 48u8, 49u8, 226u8, 128u8, 147u8,
-                        57u8, 50u8, 51u8, 41u8, 69u8, 110u8, 107u8, 121u8, 197u8, 141u8, 32u8,
-                        40u8, 49u8, 51u8, 48u8, 56u8, 226u8, 128u8, 147u8, 49u8, 51u8, 49u8, 49u8,
-                        41u8, 69u8, 110u8, 107u8, 121u8, 197u8, 141u8, 32u8, 40u8, 49u8, 55u8,
-                        52u8, 52u8, 226u8, 128u8, 147u8, 49u8, 55u8, 52u8, 56u8, 41u8, 69u8, 110u8,
-                        107u8, 121u8, 197u8, 171u8, 32u8, 40u8, 49u8, 48u8, 54u8, 57u8, 226u8,
-                        128u8, 147u8, 49u8, 48u8, 55u8, 52u8, 41u8, 69u8, 110u8, 226u8, 128u8,
-                        153u8, 197u8, 141u8, 32u8, 40u8, 49u8, 50u8, 51u8, 57u8, 226u8, 128u8,
-                        147u8, 49u8, 50u8, 52u8, 48u8, 41u8, 69u8, 110u8, 112u8, 197u8, 141u8,
-                        32u8, 40u8, 49u8, 54u8, 55u8, 51u8, 226u8, 128u8, 147u8, 49u8, 54u8, 56u8,
-                        49u8, 41u8, 69u8, 110u8, 114u8, 121u8, 97u8, 107u8, 117u8, 32u8, 40u8,
-                        55u8, 56u8, 50u8, 226u8, 128u8, 147u8, 56u8, 48u8, 54u8, 41u8, 69u8, 110u8,
-                        116u8, 111u8, 107u8, 117u8, 32u8, 40u8, 49u8, 52u8, 56u8, 57u8, 226u8,
-                        128u8, 147u8, 49u8, 52u8, 57u8, 50u8, 41u8, 71u8, 97u8, 110u8, 103u8,
-                        121u8, 197u8, 141u8, 32u8, 40u8, 56u8, 55u8, 55u8, 226u8, 128u8, 147u8,
-                        56u8, 56u8, 53u8, 41u8, 71u8, 101u8, 110u8, 98u8, 117u8, 110u8, 32u8, 40u8,
-                        49u8, 55u8, 51u8, 54u8, 226u8, 128u8, 147u8, 49u8, 55u8, 52u8, 49u8, 41u8,
-                        71u8, 101u8, 110u8, 99u8, 104u8, 197u8, 171u8, 32u8, 40u8, 49u8, 51u8,
-                        56u8, 52u8, 226u8, 128u8, 147u8, 49u8, 51u8, 57u8, 50u8, 41u8, 71u8, 101u8,
-                        110u8, 226u8, 128u8, 153u8, 101u8, 105u8, 32u8, 40u8, 49u8, 49u8, 49u8,
-                        56u8, 226u8, 128u8, 147u8, 49u8, 49u8, 50u8, 48u8, 41u8, 71u8, 101u8,
-                        110u8, 106u8, 105u8, 32u8, 40u8, 49u8, 56u8, 54u8, 52u8, 226u8, 128u8,
-                        147u8, 49u8, 56u8, 54u8, 53u8, 41u8, 71u8, 101u8, 110u8, 107u8, 105u8,
-                        32u8, 40u8, 49u8, 53u8, 55u8, 48u8, 226u8, 128u8, 147u8, 49u8, 53u8, 55u8,
-                        51u8, 41u8, 71u8, 101u8, 110u8, 107u8, 197u8, 141u8, 32u8, 40u8, 49u8,
-                        51u8, 50u8, 49u8, 226u8, 128u8, 147u8, 49u8, 51u8, 50u8, 52u8, 41u8, 71u8,
-                        101u8, 110u8, 107u8, 197u8, 141u8, 32u8, 40u8, 49u8, 51u8, 51u8, 49u8,
-                        226u8, 128u8, 147u8, 49u8, 51u8, 51u8, 52u8, 41u8, 71u8, 101u8, 110u8,
-                        107u8, 121u8, 197u8, 171u8, 32u8, 40u8, 49u8, 50u8, 48u8, 52u8, 226u8,
-                        128u8, 147u8, 49u8, 50u8, 48u8, 54u8, 41u8, 71u8, 101u8, 110u8, 110u8,
-                        97u8, 32u8, 40u8, 49u8, 54u8, 49u8, 53u8, 226u8, 128u8, 147u8, 49u8, 54u8,
-                        50u8, 52u8, 41u8, 71u8, 101u8, 110u8, 110u8, 105u8, 110u8, 32u8, 40u8,
-                        49u8, 50u8, 50u8, 52u8, 226u8, 128u8, 147u8, 49u8, 50u8, 50u8, 53u8, 41u8,
-                        71u8, 101u8, 110u8, 197u8, 141u8, 32u8, 40u8, 49u8, 51u8, 49u8, 57u8,
-                        226u8, 128u8, 147u8, 49u8, 51u8, 50u8, 49u8, 41u8, 71u8, 101u8, 110u8,
-                        114u8, 111u8, 107u8, 117u8, 32u8, 40u8, 49u8, 54u8, 56u8, 56u8, 226u8,
-                        128u8, 147u8, 49u8, 55u8, 48u8, 52u8, 41u8, 71u8, 101u8, 110u8, 114u8,
-                        121u8, 97u8, 107u8, 117u8, 32u8, 40u8, 49u8, 49u8, 56u8, 52u8, 226u8,
-                        128u8, 147u8, 49u8, 49u8, 56u8, 53u8, 41u8, 71u8, 101u8, 110u8, 116u8,
-                        111u8, 107u8, 117u8, 32u8, 40u8, 49u8, 51u8, 50u8, 57u8, 226u8, 128u8,
-                        147u8, 49u8, 51u8, 51u8, 49u8, 41u8, 72u8, 97u8, 107u8, 117u8, 99u8, 104u8,
-                        105u8, 32u8, 40u8, 54u8, 53u8, 48u8, 226u8, 128u8, 147u8, 54u8, 55u8, 49u8,
-                        41u8, 72u8, 97u8, 107u8, 117u8, 104u8, 197u8, 141u8, 32u8, 40u8, 54u8,
-                        55u8, 50u8, 226u8, 128u8, 147u8, 54u8, 56u8, 54u8, 41u8, 72u8, 101u8,
-                        105u8, 106u8, 105u8, 32u8, 40u8, 49u8, 49u8, 53u8, 57u8, 226u8, 128u8,
-                        147u8, 49u8, 49u8, 54u8, 48u8, 41u8, 72u8, 101u8, 105u8, 115u8, 101u8,
-                        105u8, 72u8, 197u8, 141u8, 97u8, 110u8, 32u8, 40u8, 49u8, 49u8, 50u8, 48u8,
-                        226u8, 128u8, 147u8, 49u8, 49u8, 50u8, 52u8, 41u8, 72u8, 197u8, 141u8,
-                        101u8, 105u8, 32u8, 40u8, 49u8, 55u8, 48u8, 52u8, 226u8, 128u8, 147u8,
-                        49u8, 55u8, 49u8, 49u8, 41u8, 72u8, 197u8, 141u8, 101u8, 110u8, 32u8, 40u8,
-                        49u8, 49u8, 51u8, 53u8, 226u8, 128u8, 147u8, 49u8, 49u8, 52u8, 49u8, 41u8,
-                        72u8, 197u8, 141u8, 103u8, 101u8, 110u8, 32u8, 40u8, 49u8, 49u8, 53u8,
-                        54u8, 226u8, 128u8, 147u8, 49u8, 49u8, 53u8, 57u8, 41u8, 72u8, 197u8,
-                        141u8, 106u8, 105u8, 32u8, 40u8, 49u8, 50u8, 52u8, 55u8, 226u8, 128u8,
-                        147u8, 49u8, 50u8, 52u8, 57u8, 41u8, 72u8, 197u8, 141u8, 107u8, 105u8,
-                        32u8, 40u8, 55u8, 55u8, 48u8, 226u8, 128u8, 147u8, 55u8, 56u8, 48u8, 41u8,
-                        72u8, 197u8, 141u8, 114u8, 101u8, 107u8, 105u8, 32u8, 40u8, 49u8, 55u8,
-                        53u8, 49u8, 226u8, 128u8, 147u8, 49u8, 55u8, 54u8, 52u8, 41u8, 72u8, 197u8,
-                        141u8, 116u8, 111u8, 107u8, 117u8, 32u8, 40u8, 49u8, 52u8, 52u8, 57u8,
-                        226u8, 128u8, 147u8, 49u8, 52u8, 53u8, 50u8, 41u8, 74u8, 105u8, 97u8,
-                        110u8, 32u8, 40u8, 49u8, 48u8, 50u8, 49u8, 226u8, 128u8, 147u8, 49u8, 48u8,
-                        50u8, 52u8, 41u8, 74u8, 105u8, 110u8, 103u8, 111u8, 45u8, 107u8, 101u8,
-                        105u8, 117u8, 110u8, 32u8, 40u8, 55u8, 54u8, 55u8, 226u8, 128u8, 147u8,
-                        55u8, 55u8, 48u8, 41u8, 74u8, 105u8, 110u8, 107u8, 105u8, 32u8, 40u8, 55u8,
-                        50u8, 52u8, 226u8, 128u8, 147u8, 55u8, 50u8, 57u8, 41u8, 74u8, 105u8,
-                        114u8, 121u8, 97u8, 107u8, 117u8, 32u8, 40u8, 49u8, 48u8, 54u8, 53u8,
-                        226u8, 128u8, 147u8, 49u8, 48u8, 54u8, 57u8, 41u8, 74u8, 105u8, 115u8,
-                        104u8, 197u8, 141u8, 32u8, 40u8, 49u8, 49u8, 55u8, 55u8, 226u8, 128u8,
-                        147u8, 49u8, 49u8, 56u8, 49u8, 41u8, 74u8, 197u8, 141u8, 101u8, 105u8,
-                        32u8, 40u8, 49u8, 50u8, 51u8, 50u8, 226u8, 128u8, 147u8, 49u8, 50u8, 51u8,
-                        51u8, 41u8, 74u8, 197u8, 141u8, 103u8, 97u8, 110u8, 32u8, 40u8, 56u8, 53u8,
-                        57u8, 226u8, 128u8, 147u8, 56u8, 55u8, 55u8, 41u8, 74u8, 197u8, 141u8,
-                        103u8, 101u8, 110u8, 32u8, 40u8, 49u8, 50u8, 48u8, 55u8, 226u8, 128u8,
-                        147u8, 49u8, 50u8, 49u8, 49u8, 41u8, 74u8, 197u8, 141u8, 103u8, 101u8,
-                        110u8, 32u8, 40u8, 57u8, 55u8, 54u8, 226u8, 128u8, 147u8, 57u8, 55u8, 56u8,
-                        41u8, 74u8, 197u8, 141u8, 104u8, 101u8, 105u8, 32u8, 40u8, 57u8, 51u8,
-                        49u8, 226u8, 128u8, 147u8, 57u8, 51u8, 56u8, 41u8, 74u8, 197u8, 141u8,
-                        107u8, 121u8, 197u8, 141u8, 32u8, 40u8, 49u8, 54u8, 56u8, 52u8, 226u8,
-                        128u8, 147u8, 49u8, 54u8, 56u8, 56u8, 41u8, 74u8, 197u8, 141u8, 107u8,
-                        121u8, 197u8, 171u8, 32u8, 40u8, 49u8, 50u8, 49u8, 57u8, 226u8, 128u8,
-                        147u8, 49u8, 50u8, 50u8, 50u8, 41u8, 74u8, 197u8, 141u8, 197u8, 141u8,
-                        32u8, 40u8, 49u8, 50u8, 50u8, 50u8, 226u8, 128u8, 147u8, 49u8, 50u8, 50u8,
-                        52u8, 41u8, 74u8, 197u8, 141u8, 197u8, 141u8, 32u8, 40u8, 49u8, 54u8, 53u8,
-                        50u8, 226u8, 128u8, 147u8, 49u8, 54u8, 53u8, 53u8, 41u8, 74u8, 197u8,
-                        141u8, 116u8, 111u8, 107u8, 117u8, 32u8, 40u8, 49u8, 48u8, 57u8, 55u8,
-                        226u8, 128u8, 147u8, 49u8, 48u8, 57u8, 57u8, 41u8, 74u8, 197u8, 141u8,
-                        119u8, 97u8, 32u8, 40u8, 56u8, 51u8, 52u8, 226u8, 128u8, 147u8, 56u8, 52u8,
-                        56u8, 41u8, 74u8, 117u8, 101u8, 105u8, 32u8, 40u8, 49u8, 49u8, 56u8, 50u8,
-                        226u8, 128u8, 147u8, 49u8, 49u8, 56u8, 52u8, 41u8, 75u8, 97u8, 101u8,
-                        105u8, 32u8, 40u8, 49u8, 56u8, 52u8, 56u8, 226u8, 128u8, 147u8, 49u8, 56u8,
-                        53u8, 52u8, 41u8, 75u8, 97u8, 103u8, 101u8, 110u8, 32u8, 40u8, 49u8, 51u8,
-                        48u8, 51u8, 226u8, 128u8, 147u8, 49u8, 51u8, 48u8, 54u8, 41u8, 75u8, 97u8,
-                        104u8, 197u8, 141u8, 32u8, 40u8, 49u8, 48u8, 57u8, 52u8, 226u8, 128u8,
-                        147u8, 49u8, 48u8, 57u8, 54u8, 41u8, 75u8, 97u8, 106u8, 197u8, 141u8, 32u8,
-                        40u8, 56u8, 52u8, 56u8, 226u8, 128u8, 147u8, 56u8, 53u8, 49u8, 41u8, 75u8,
-                        97u8, 107u8, 101u8, 105u8, 32u8, 40u8, 49u8, 51u8, 56u8, 55u8, 226u8,
-                        128u8, 147u8, 49u8, 51u8, 56u8, 57u8, 41u8, 75u8, 97u8, 107u8, 105u8,
-                        116u8, 115u8, 117u8, 32u8, 40u8, 49u8, 52u8, 52u8, 49u8, 226u8, 128u8,
-                        147u8, 49u8, 52u8, 52u8, 52u8, 41u8, 75u8, 97u8, 110u8, 98u8, 117u8, 110u8,
-                        32u8, 40u8, 49u8, 54u8, 54u8, 49u8, 226u8, 128u8, 147u8, 49u8, 54u8, 55u8,
-                        51u8, 41u8, 75u8, 97u8, 110u8, 226u8, 128u8, 153u8, 101u8, 105u8, 32u8,
-                        40u8, 49u8, 54u8, 50u8, 52u8, 226u8, 128u8, 147u8, 49u8, 54u8, 52u8, 52u8,
-                        41u8, 75u8, 97u8, 110u8, 226u8, 128u8, 153u8, 101u8, 110u8, 32u8, 40u8,
-                        49u8, 55u8, 52u8, 56u8, 226u8, 128u8, 147u8, 49u8, 55u8, 53u8, 49u8, 41u8,
-                        75u8, 97u8, 110u8, 103u8, 101u8, 110u8, 32u8, 40u8, 49u8, 50u8, 52u8, 51u8,
-                        226u8, 128u8, 147u8, 49u8, 50u8, 52u8, 55u8, 41u8, 75u8, 97u8, 110u8,
-                        106u8, 105u8, 32u8, 40u8, 49u8, 48u8, 56u8, 55u8, 226u8, 128u8, 147u8,
-                        49u8, 48u8, 57u8, 52u8, 41u8, 75u8, 97u8, 110u8, 107u8, 105u8, 32u8, 40u8,
-                        49u8, 50u8, 50u8, 57u8, 226u8, 128u8, 147u8, 49u8, 50u8, 51u8, 50u8, 41u8,
-                        75u8, 97u8, 110u8, 107u8, 197u8, 141u8, 32u8, 40u8, 49u8, 48u8, 48u8, 52u8,
-                        226u8, 128u8, 147u8, 49u8, 48u8, 49u8, 50u8, 41u8, 75u8, 97u8, 110u8,
-                        110u8, 97u8, 32u8, 40u8, 57u8, 56u8, 53u8, 226u8, 128u8, 147u8, 57u8, 56u8,
-                        55u8, 41u8, 75u8, 97u8, 110u8, 110u8, 105u8, 110u8, 32u8, 40u8, 49u8, 48u8,
-                        49u8, 55u8, 226u8, 128u8, 147u8, 49u8, 48u8, 50u8, 49u8, 41u8, 75u8, 97u8,
-                        110u8, 112u8, 197u8, 141u8, 32u8, 40u8, 49u8, 55u8, 52u8, 49u8, 226u8,
-                        128u8, 147u8, 49u8, 55u8, 52u8, 52u8, 41u8, 75u8, 97u8, 110u8, 112u8,
-                        121u8, 197u8, 141u8, 32u8, 40u8, 56u8, 56u8, 57u8, 226u8, 128u8, 147u8,
-                        56u8, 57u8, 56u8, 41u8, 75u8, 97u8, 110u8, 115u8, 101u8, 105u8, 32u8, 40u8,
-                        49u8, 55u8, 56u8, 57u8, 226u8, 128u8, 147u8, 49u8, 56u8, 48u8, 49u8, 41u8,
-                        75u8, 97u8, 110u8, 115u8, 104u8, 197u8, 141u8, 32u8, 40u8, 49u8, 52u8,
-                        54u8, 48u8, 226u8, 128u8, 147u8, 49u8, 52u8, 54u8, 54u8, 41u8, 75u8, 97u8,
-                        110u8, 116u8, 111u8, 107u8, 117u8, 32u8, 40u8, 49u8, 48u8, 52u8, 52u8,
-                        226u8, 128u8, 147u8, 49u8, 48u8, 52u8, 54u8, 41u8, 75u8, 97u8, 197u8,
-                        141u8, 32u8, 40u8, 49u8, 49u8, 54u8, 57u8, 226u8, 128u8, 147u8, 49u8, 49u8,
-                        55u8, 49u8, 41u8, 75u8, 97u8, 114u8, 111u8, 107u8, 117u8, 32u8, 40u8, 49u8,
-                        50u8, 50u8, 53u8, 226u8, 128u8, 147u8, 49u8, 50u8, 50u8, 55u8, 41u8, 75u8,
-                        97u8, 114u8, 121u8, 97u8, 107u8, 117u8, 32u8, 40u8, 49u8, 51u8, 50u8, 54u8,
-                        226u8, 128u8, 147u8, 49u8, 51u8, 50u8, 57u8, 41u8, 75u8, 97u8, 115u8,
-                        104u8, 197u8, 141u8, 32u8, 40u8, 49u8, 49u8, 48u8, 54u8, 226u8, 128u8,
-                        147u8, 49u8, 49u8, 48u8, 56u8, 41u8, 75u8, 97u8, 116u8, 101u8, 105u8, 32u8,
-                        40u8, 49u8, 50u8, 51u8, 53u8, 226u8, 128u8, 147u8, 49u8, 50u8, 51u8, 56u8,
-                        41u8, 75u8, 101u8, 105u8, 97u8, 110u8, 32u8, 40u8, 49u8, 54u8, 52u8, 56u8,
-                        226u8, 128u8, 147u8, 49u8, 54u8, 53u8, 50u8, 41u8, 75u8, 101u8, 105u8,
-                        99u8, 104u8, 197u8, 141u8, 32u8, 40u8, 49u8, 53u8, 57u8, 54u8, 226u8,
-                        128u8, 147u8, 49u8, 54u8, 49u8, 53u8, 41u8, 75u8, 101u8, 105u8, 197u8,
-                        141u8, 32u8, 40u8, 49u8, 56u8, 54u8, 53u8, 226u8, 128u8, 147u8, 49u8, 56u8,
-                        54u8, 56u8, 41u8, 75u8, 101u8, 105u8, 117u8, 110u8, 32u8, 40u8, 55u8, 48u8,
-                        52u8, 226u8, 128u8, 147u8, 55u8, 48u8, 56u8, 41u8, 75u8, 101u8, 110u8,
-                        99u8, 104u8, 197u8, 141u8, 32u8, 40u8, 49u8, 50u8, 52u8, 57u8, 226u8,
-                        128u8, 147u8, 49u8, 50u8, 53u8, 54u8, 41u8, 75u8, 101u8, 110u8, 226u8,
-                        128u8, 153u8, 101u8, 105u8, 32u8, 40u8, 49u8, 50u8, 48u8, 54u8, 226u8,
-                        128u8, 147u8, 49u8, 50u8, 48u8, 55u8, 41u8, 75u8, 101u8, 110u8, 103u8,
-                        101u8, 110u8, 32u8, 40u8, 49u8, 51u8, 48u8, 50u8, 226u8, 128u8, 147u8,
-                        49u8, 51u8, 48u8, 51u8, 41u8, 75u8, 101u8, 110u8, 106u8, 105u8, 32u8, 40u8,
-                        49u8, 50u8, 55u8, 53u8, 226u8, 128u8, 147u8, 49u8, 50u8, 55u8, 56u8, 41u8,
-                        75u8, 101u8, 110u8, 107u8, 121u8, 197u8, 171u8, 32u8, 40u8, 49u8, 49u8,
-                        57u8, 48u8, 226u8, 128u8, 147u8, 49u8, 49u8, 57u8, 57u8, 41u8, 75u8, 101u8,
-                        110u8, 109u8, 117u8, 32u8, 40u8, 49u8, 51u8, 51u8, 52u8, 226u8, 128u8,
-                        147u8, 49u8, 51u8, 51u8, 54u8, 41u8, 75u8, 101u8, 110u8, 110u8, 105u8,
-                        110u8, 32u8, 40u8, 49u8, 50u8, 48u8, 49u8, 226u8, 128u8, 147u8, 49u8, 50u8,
-                        48u8, 52u8, 41u8, 75u8, 101u8, 110u8, 112u8, 197u8, 141u8, 32u8, 40u8,
-                        49u8, 50u8, 49u8, 51u8, 226u8, 128u8, 147u8, 49u8, 50u8, 49u8, 57u8, 41u8,
-                        75u8, 101u8, 110u8, 114u8, 121u8, 97u8, 107u8, 117u8, 32u8, 40u8, 49u8,
-                        50u8, 49u8, 49u8, 226u8, 128u8, 147u8, 49u8, 50u8, 49u8, 51u8, 41u8, 75u8,
-                        101u8, 110u8, 116u8, 111u8, 107u8, 117u8, 32u8, 40u8, 49u8, 51u8, 55u8,
-                        48u8, 226u8, 128u8, 147u8, 49u8, 51u8, 55u8, 50u8, 41u8, 75u8, 197u8,
-                        141u8, 97u8, 110u8, 32u8, 40u8, 49u8, 50u8, 55u8, 56u8, 226u8, 128u8,
-                        147u8, 49u8, 50u8, 56u8, 56u8, 41u8, 75u8, 197u8, 141u8, 99u8, 104u8,
-                        197u8, 141u8, 32u8, 40u8, 49u8, 50u8, 54u8, 49u8, 226u8, 128u8, 147u8,
-                        49u8, 50u8, 54u8, 52u8, 41u8, 75u8, 197u8, 141u8, 103u8, 101u8, 110u8,
-                        32u8, 40u8, 49u8, 50u8, 53u8, 54u8, 226u8, 128u8, 147u8, 49u8, 50u8, 53u8,
-                        55u8, 41u8, 75u8, 197u8, 141u8, 104u8, 101u8, 105u8, 32u8, 40u8, 49u8,
-                        48u8, 53u8, 56u8, 226u8, 128u8, 147u8, 49u8, 48u8, 54u8, 53u8, 41u8, 75u8,
-                        197u8, 141u8, 104u8, 197u8, 141u8, 32u8, 40u8, 57u8, 54u8, 52u8, 226u8,
-                        128u8, 147u8, 57u8, 54u8, 56u8, 41u8, 75u8, 197u8, 141u8, 106u8, 105u8,
-                        32u8, 40u8, 49u8, 49u8, 52u8, 50u8, 226u8, 128u8, 147u8, 49u8, 49u8, 52u8,
-                        52u8, 41u8, 75u8, 197u8, 141u8, 106u8, 105u8, 32u8, 40u8, 49u8, 53u8, 53u8,
-                        53u8, 226u8, 128u8, 147u8, 49u8, 53u8, 53u8, 56u8, 41u8, 75u8, 197u8,
-                        141u8, 107u8, 97u8, 32u8, 40u8, 49u8, 56u8, 52u8, 52u8, 226u8, 128u8,
-                        147u8, 49u8, 56u8, 52u8, 56u8, 41u8, 75u8, 197u8, 141u8, 107u8, 111u8,
-                        107u8, 117u8, 32u8, 40u8, 49u8, 51u8, 52u8, 48u8, 226u8, 128u8, 147u8,
-                        49u8, 51u8, 52u8, 54u8, 41u8, 75u8, 197u8, 141u8, 110u8, 105u8, 110u8,
-                        32u8, 40u8, 56u8, 49u8, 48u8, 226u8, 128u8, 147u8, 56u8, 50u8, 52u8, 41u8,
-                        75u8, 197u8, 141u8, 197u8, 141u8, 32u8, 40u8, 49u8, 51u8, 56u8, 57u8,
-                        226u8, 128u8, 147u8, 49u8, 51u8, 57u8, 48u8, 41u8, 75u8, 197u8, 141u8,
-                        114u8, 121u8, 97u8, 107u8, 117u8, 32u8, 40u8, 49u8, 51u8, 55u8, 57u8,
-                        226u8, 128u8, 147u8, 49u8, 51u8, 56u8, 49u8, 41u8, 75u8, 197u8, 141u8,
-                        115u8, 104u8, 197u8, 141u8, 32u8, 40u8, 49u8, 52u8, 53u8, 53u8, 226u8,
-                        128u8, 147u8, 49u8, 52u8, 53u8, 55u8, 41u8, 75u8, 197u8, 141u8, 119u8,
-                        97u8, 32u8, 40u8, 49u8, 48u8, 57u8, 57u8, 226u8, 128u8, 147u8, 49u8, 49u8,
-                        48u8, 52u8, 41u8, 75u8, 197u8, 141u8, 119u8, 97u8, 32u8, 40u8, 49u8, 51u8,
-                        56u8, 49u8, 226u8, 128u8, 147u8, 49u8, 51u8, 56u8, 52u8, 41u8, 75u8, 121u8,
-                        197u8, 141u8, 104u8, 197u8, 141u8, 32u8, 40u8, 49u8, 55u8, 49u8, 54u8,
-                        226u8, 128u8, 147u8, 49u8, 55u8, 51u8, 54u8, 41u8, 75u8, 121u8, 197u8,
-                        141u8, 114u8, 111u8, 107u8, 117u8, 32u8, 40u8, 49u8, 53u8, 50u8, 56u8,
-                        226u8, 128u8, 147u8, 49u8, 53u8, 51u8, 50u8, 41u8, 75u8, 121u8, 197u8,
-                        141u8, 116u8, 111u8, 107u8, 117u8, 32u8, 40u8, 49u8, 52u8, 53u8, 50u8,
-                        226u8, 128u8, 147u8, 49u8, 52u8, 53u8, 53u8, 41u8, 75u8, 121u8, 197u8,
-                        141u8, 119u8, 97u8, 32u8, 40u8, 49u8, 56u8, 48u8, 49u8, 226u8, 128u8,
-                        147u8, 49u8, 56u8, 48u8, 52u8, 41u8, 75u8, 121u8, 197u8, 171u8, 97u8,
-                        110u8, 32u8, 40u8, 49u8, 49u8, 52u8, 53u8, 226u8, 128u8, 147u8, 49u8, 49u8,
-                        53u8, 49u8, 41u8, 75u8, 121u8, 197u8, 171u8, 106u8, 117u8, 32u8, 40u8,
-                        49u8, 49u8, 53u8, 52u8, 226u8, 128u8, 147u8, 49u8, 49u8, 53u8, 54u8, 41u8,
-                        77u8, 97u8, 110u8, 226u8, 128u8, 153u8, 101u8, 110u8, 32u8, 40u8, 49u8,
-                        56u8, 54u8, 48u8, 226u8, 128u8, 147u8, 49u8, 56u8, 54u8, 49u8, 41u8, 77u8,
-                        97u8, 110u8, 106u8, 105u8, 32u8, 40u8, 49u8, 54u8, 53u8, 56u8, 226u8,
-                        128u8, 147u8, 49u8, 54u8, 54u8, 49u8, 41u8, 77u8, 97u8, 110u8, 106u8,
-                        117u8, 32u8, 40u8, 49u8, 48u8, 50u8, 52u8, 226u8, 128u8, 147u8, 49u8, 48u8,
-                        50u8, 56u8, 41u8, 77u8, 101u8, 105u8, 106u8, 105u8, 77u8, 101u8, 105u8,
-                        197u8, 141u8, 32u8, 40u8, 49u8, 52u8, 57u8, 50u8, 226u8, 128u8, 147u8,
-                        49u8, 53u8, 48u8, 49u8, 41u8, 77u8, 101u8, 105u8, 114u8, 101u8, 107u8,
-                        105u8, 32u8, 40u8, 49u8, 54u8, 53u8, 53u8, 226u8, 128u8, 147u8, 49u8, 54u8,
-                        53u8, 56u8, 41u8, 77u8, 101u8, 105u8, 116u8, 111u8, 107u8, 117u8, 32u8,
-                        40u8, 49u8, 51u8, 56u8, 52u8, 226u8, 128u8, 147u8, 49u8, 51u8, 56u8, 55u8,
-                        41u8, 77u8, 101u8, 105u8, 116u8, 111u8, 107u8, 117u8, 32u8, 40u8, 49u8,
-                        51u8, 57u8, 48u8, 226u8, 128u8, 147u8, 49u8, 51u8, 57u8, 52u8, 41u8, 77u8,
-                        101u8, 105u8, 119u8, 97u8, 32u8, 40u8, 49u8, 55u8, 54u8, 52u8, 226u8,
-                        128u8, 147u8, 49u8, 55u8, 55u8, 50u8, 41u8, 78u8, 105u8, 110u8, 226u8,
-                        128u8, 153u8, 97u8, 110u8, 32u8, 40u8, 49u8, 49u8, 54u8, 54u8, 226u8,
-                        128u8, 147u8, 49u8, 49u8, 54u8, 57u8, 41u8, 78u8, 105u8, 110u8, 106u8,
-                        105u8, 32u8, 40u8, 49u8, 50u8, 52u8, 48u8, 226u8, 128u8, 147u8, 49u8, 50u8,
-                        52u8, 51u8, 41u8, 78u8, 105u8, 110u8, 106u8, 117u8, 32u8, 40u8, 56u8, 53u8,
-                        49u8, 226u8, 128u8, 147u8, 56u8, 53u8, 52u8, 41u8, 78u8, 105u8, 110u8,
-                        110u8, 97u8, 32u8, 40u8, 56u8, 56u8, 53u8, 226u8, 128u8, 147u8, 56u8, 56u8,
-                        57u8, 41u8, 78u8, 105u8, 110u8, 112u8, 101u8, 105u8, 32u8, 40u8, 49u8,
-                        49u8, 53u8, 49u8, 226u8, 128u8, 147u8, 49u8, 49u8, 53u8, 52u8, 41u8, 197u8,
-                        140u8, 99u8, 104u8, 197u8, 141u8, 32u8, 40u8, 49u8, 51u8, 49u8, 49u8,
-                        226u8, 128u8, 147u8, 49u8, 51u8, 49u8, 50u8, 41u8, 197u8, 140u8, 101u8,
-                        105u8, 32u8, 40u8, 49u8, 51u8, 57u8, 52u8, 226u8, 128u8, 147u8, 49u8, 52u8,
-                        50u8, 56u8, 41u8, 197u8, 140u8, 104u8, 111u8, 32u8, 40u8, 49u8, 49u8, 54u8,
-                        49u8, 226u8, 128u8, 147u8, 49u8, 49u8, 54u8, 51u8, 41u8, 197u8, 140u8,
-                        110u8, 105u8, 110u8, 32u8, 40u8, 49u8, 52u8, 54u8, 55u8, 226u8, 128u8,
-                        147u8, 49u8, 52u8, 54u8, 57u8, 41u8, 197u8, 140u8, 116u8, 111u8, 107u8,
-                        117u8, 32u8, 40u8, 49u8, 48u8, 56u8, 52u8, 226u8, 128u8, 147u8, 49u8, 48u8,
-                        56u8, 55u8, 41u8, 197u8, 140u8, 119u8, 97u8, 32u8, 40u8, 57u8, 54u8, 49u8,
-                        226u8, 128u8, 147u8, 57u8, 54u8, 52u8, 41u8, 82u8, 101u8, 105u8, 107u8,
-                        105u8, 32u8, 40u8, 55u8, 49u8, 53u8, 226u8, 128u8, 147u8, 55u8, 49u8, 55u8,
-                        41u8, 82u8, 101u8, 105u8, 119u8, 97u8, 82u8, 121u8, 97u8, 107u8, 117u8,
-                        110u8, 105u8, 110u8, 32u8, 40u8, 49u8, 50u8, 51u8, 56u8, 226u8, 128u8,
-                        147u8, 49u8, 50u8, 51u8, 57u8, 41u8, 83u8, 97u8, 105u8, 107u8, 197u8,
-                        141u8, 32u8, 40u8, 56u8, 53u8, 52u8, 226u8, 128u8, 147u8, 56u8, 53u8, 55u8,
-                        41u8, 83u8, 104u8, 197u8, 141u8, 97u8, 110u8, 32u8, 40u8, 49u8, 49u8, 55u8,
-                        49u8, 226u8, 128u8, 147u8, 49u8, 49u8, 55u8, 53u8, 41u8, 83u8, 104u8,
-                        197u8, 141u8, 97u8, 110u8, 32u8, 40u8, 49u8, 50u8, 57u8, 57u8, 226u8,
-                        128u8, 147u8, 49u8, 51u8, 48u8, 50u8, 41u8, 83u8, 104u8, 197u8, 141u8,
-                        99u8, 104u8, 197u8, 141u8, 32u8, 40u8, 49u8, 52u8, 50u8, 56u8, 226u8,
-                        128u8, 147u8, 49u8, 52u8, 50u8, 57u8, 41u8, 83u8, 104u8, 197u8, 141u8,
-                        99u8, 104u8, 197u8, 171u8, 32u8, 40u8, 49u8, 51u8, 50u8, 52u8, 226u8,
-                        128u8, 147u8, 49u8, 51u8, 50u8, 54u8, 41u8, 83u8, 104u8, 197u8, 141u8,
-                        103u8, 101u8, 110u8, 32u8, 40u8, 49u8, 50u8, 53u8, 57u8, 226u8, 128u8,
-                        147u8, 49u8, 50u8, 54u8, 48u8, 41u8, 83u8, 104u8, 197u8, 141u8, 104u8,
-                        101u8, 105u8, 32u8, 40u8, 49u8, 51u8, 52u8, 54u8, 226u8, 128u8, 147u8,
-                        49u8, 51u8, 55u8, 48u8, 41u8, 83u8, 104u8, 197u8, 141u8, 104u8, 111u8,
-                        32u8, 40u8, 49u8, 48u8, 55u8, 52u8, 226u8, 128u8, 147u8, 49u8, 48u8, 55u8,
-                        55u8, 41u8, 83u8, 104u8, 197u8, 141u8, 104u8, 111u8, 32u8, 40u8, 49u8,
-                        54u8, 52u8, 52u8, 226u8, 128u8, 147u8, 49u8, 54u8, 52u8, 56u8, 41u8, 83u8,
-                        104u8, 197u8, 141u8, 106u8, 105u8, 32u8, 40u8, 49u8, 49u8, 57u8, 57u8,
-                        226u8, 128u8, 147u8, 49u8, 50u8, 48u8, 49u8, 41u8, 83u8, 104u8, 197u8,
-                        141u8, 107u8, 97u8, 32u8, 40u8, 49u8, 50u8, 53u8, 55u8, 226u8, 128u8,
-                        147u8, 49u8, 50u8, 53u8, 57u8, 41u8, 83u8, 104u8, 197u8, 141u8, 197u8,
-                        141u8, 32u8, 40u8, 49u8, 50u8, 56u8, 56u8, 226u8, 128u8, 147u8, 49u8, 50u8,
-                        57u8, 51u8, 41u8, 83u8, 104u8, 197u8, 141u8, 114u8, 121u8, 97u8, 107u8,
-                        117u8, 32u8, 40u8, 49u8, 48u8, 55u8, 55u8, 226u8, 128u8, 147u8, 49u8, 48u8,
-                        56u8, 49u8, 41u8, 83u8, 104u8, 197u8, 141u8, 114u8, 121u8, 97u8, 107u8,
-                        117u8, 32u8, 40u8, 57u8, 57u8, 48u8, 226u8, 128u8, 147u8, 57u8, 57u8, 53u8,
-                        41u8, 83u8, 104u8, 197u8, 141u8, 116u8, 97u8, 105u8, 32u8, 40u8, 56u8,
-                        57u8, 56u8, 226u8, 128u8, 147u8, 57u8, 48u8, 49u8, 41u8, 83u8, 104u8,
-                        197u8, 141u8, 116u8, 111u8, 107u8, 117u8, 32u8, 40u8, 49u8, 55u8, 49u8,
-                        49u8, 226u8, 128u8, 147u8, 49u8, 55u8, 49u8, 54u8, 41u8, 83u8, 104u8,
-                        197u8, 141u8, 119u8, 97u8, 83u8, 104u8, 197u8, 141u8, 119u8, 97u8, 32u8,
-                        40u8, 49u8, 51u8, 49u8, 50u8, 226u8, 128u8, 147u8, 49u8, 51u8, 49u8, 55u8,
-                        41u8, 83u8, 104u8, 117u8, 99u8, 104u8, 197u8, 141u8, 32u8, 40u8, 54u8,
-                        56u8, 54u8, 226u8, 128u8, 147u8, 55u8, 48u8, 49u8, 41u8, 84u8, 97u8, 105u8,
-                        101u8, 105u8, 32u8, 40u8, 49u8, 53u8, 50u8, 49u8, 226u8, 128u8, 147u8,
-                        49u8, 53u8, 50u8, 56u8, 41u8, 84u8, 97u8, 105u8, 104u8, 197u8, 141u8, 32u8,
-                        40u8, 55u8, 48u8, 49u8, 226u8, 128u8, 147u8, 55u8, 48u8, 52u8, 41u8, 84u8,
-                        97u8, 105u8, 107u8, 97u8, 32u8, 40u8, 54u8, 52u8, 53u8, 226u8, 128u8,
-                        147u8, 54u8, 53u8, 48u8, 41u8, 84u8, 97u8, 105u8, 115u8, 104u8, 197u8,
-                        141u8, 84u8, 101u8, 110u8, 45u8, 97u8, 110u8, 32u8, 40u8, 56u8, 53u8, 55u8,
-                        226u8, 128u8, 147u8, 56u8, 53u8, 57u8, 41u8, 84u8, 101u8, 110u8, 98u8,
-                        117u8, 110u8, 32u8, 40u8, 49u8, 53u8, 51u8, 50u8, 226u8, 128u8, 147u8,
-                        49u8, 53u8, 53u8, 53u8, 41u8, 84u8, 101u8, 110u8, 99u8, 104u8, 197u8,
-                        141u8, 32u8, 40u8, 56u8, 50u8, 52u8, 226u8, 128u8, 147u8, 56u8, 51u8, 52u8,
-                        41u8, 84u8, 101u8, 110u8, 45u8, 101u8, 105u8, 32u8, 40u8, 49u8, 49u8, 49u8,
-                        48u8, 226u8, 128u8, 147u8, 49u8, 49u8, 49u8, 51u8, 41u8, 84u8, 101u8,
-                        110u8, 226u8, 128u8, 153u8, 101u8, 110u8, 32u8, 40u8, 57u8, 55u8, 51u8,
-                        226u8, 128u8, 147u8, 57u8, 55u8, 54u8, 41u8, 84u8, 101u8, 110u8, 103u8,
-                        101u8, 110u8, 32u8, 40u8, 57u8, 55u8, 56u8, 226u8, 128u8, 147u8, 57u8,
-                        56u8, 51u8, 41u8, 84u8, 101u8, 110u8, 103u8, 105u8, 32u8, 40u8, 49u8, 48u8,
-                        53u8, 51u8, 226u8, 128u8, 147u8, 49u8, 48u8, 53u8, 56u8, 41u8, 84u8, 101u8,
-                        110u8, 103u8, 121u8, 197u8, 141u8, 32u8, 40u8, 57u8, 51u8, 56u8, 226u8,
-                        128u8, 147u8, 57u8, 52u8, 55u8, 41u8, 84u8, 101u8, 110u8, 106u8, 105u8,
-                        32u8, 40u8, 49u8, 49u8, 50u8, 52u8, 226u8, 128u8, 147u8, 49u8, 49u8, 50u8,
-                        54u8, 41u8, 84u8, 101u8, 110u8, 106u8, 117u8, 32u8, 40u8, 49u8, 51u8, 55u8,
-                        53u8, 226u8, 128u8, 147u8, 49u8, 51u8, 55u8, 57u8, 41u8, 84u8, 101u8,
-                        110u8, 109u8, 101u8, 105u8, 32u8, 40u8, 49u8, 55u8, 56u8, 49u8, 226u8,
-                        128u8, 147u8, 49u8, 55u8, 56u8, 57u8, 41u8, 84u8, 101u8, 110u8, 110u8,
-                        97u8, 32u8, 40u8, 49u8, 54u8, 56u8, 49u8, 226u8, 128u8, 147u8, 49u8, 54u8,
-                        56u8, 52u8, 41u8, 84u8, 101u8, 110u8, 110u8, 105u8, 110u8, 32u8, 40u8,
-                        49u8, 49u8, 48u8, 56u8, 226u8, 128u8, 147u8, 49u8, 49u8, 49u8, 48u8, 41u8,
-                        84u8, 101u8, 110u8, 45u8, 197u8, 141u8, 32u8, 40u8, 55u8, 56u8, 49u8,
-                        226u8, 128u8, 147u8, 55u8, 56u8, 50u8, 41u8, 84u8, 101u8, 110u8, 112u8,
-                        197u8, 141u8, 32u8, 40u8, 49u8, 56u8, 51u8, 48u8, 226u8, 128u8, 147u8,
-                        49u8, 56u8, 52u8, 52u8, 41u8, 84u8, 101u8, 110u8, 112u8, 117u8, 107u8,
-                        117u8, 32u8, 40u8, 49u8, 50u8, 51u8, 51u8, 226u8, 128u8, 147u8, 49u8, 50u8,
-                        51u8, 52u8, 41u8, 84u8, 101u8, 110u8, 112u8, 121u8, 197u8, 141u8, 32u8,
-                        40u8, 55u8, 50u8, 57u8, 226u8, 128u8, 147u8, 55u8, 52u8, 57u8, 41u8, 84u8,
-                        101u8, 110u8, 112u8, 121u8, 197u8, 141u8, 45u8, 104u8, 197u8, 141u8, 106u8,
-                        105u8, 32u8, 40u8, 55u8, 53u8, 55u8, 226u8, 128u8, 147u8, 55u8, 54u8, 53u8,
-                        41u8, 84u8, 101u8, 110u8, 112u8, 121u8, 197u8, 141u8, 45u8, 106u8, 105u8,
-                        110u8, 103u8, 111u8, 32u8, 40u8, 55u8, 54u8, 53u8, 226u8, 128u8, 147u8,
-                        55u8, 54u8, 55u8, 41u8, 84u8, 101u8, 110u8, 112u8, 121u8, 197u8, 141u8,
-                        45u8, 107u8, 97u8, 109u8, 112u8, 197u8, 141u8, 32u8, 40u8, 55u8, 52u8,
-                        57u8, 226u8, 128u8, 147u8, 55u8, 52u8, 57u8, 41u8, 84u8, 101u8, 110u8,
-                        112u8, 121u8, 197u8, 141u8, 45u8, 115u8, 104u8, 197u8, 141u8, 104u8, 197u8,
-                        141u8, 32u8, 40u8, 55u8, 52u8, 57u8, 226u8, 128u8, 147u8, 55u8, 53u8, 55u8,
-                        41u8, 84u8, 101u8, 110u8, 114u8, 111u8, 107u8, 117u8, 32u8, 40u8, 57u8,
-                        55u8, 48u8, 226u8, 128u8, 147u8, 57u8, 55u8, 51u8, 41u8, 84u8, 101u8,
-                        110u8, 114u8, 121u8, 97u8, 107u8, 117u8, 32u8, 40u8, 57u8, 52u8, 55u8,
-                        226u8, 128u8, 147u8, 57u8, 53u8, 55u8, 41u8, 84u8, 101u8, 110u8, 115u8,
-                        104u8, 197u8, 141u8, 32u8, 40u8, 49u8, 49u8, 51u8, 49u8, 226u8, 128u8,
-                        147u8, 49u8, 49u8, 51u8, 50u8, 41u8, 84u8, 101u8, 110u8, 115u8, 104u8,
-                        197u8, 141u8, 32u8, 40u8, 49u8, 53u8, 55u8, 51u8, 226u8, 128u8, 147u8,
-                        49u8, 53u8, 57u8, 50u8, 41u8, 84u8, 101u8, 110u8, 116u8, 111u8, 107u8,
-                        117u8, 32u8, 40u8, 57u8, 53u8, 55u8, 226u8, 128u8, 147u8, 57u8, 54u8, 49u8,
-                        41u8, 84u8, 101u8, 110u8, 226u8, 128u8, 153u8, 121u8, 197u8, 141u8, 32u8,
-                        40u8, 49u8, 49u8, 52u8, 52u8, 226u8, 128u8, 147u8, 49u8, 49u8, 52u8, 53u8,
-                        41u8, 84u8, 111u8, 107u8, 117u8, 106u8, 105u8, 32u8, 40u8, 49u8, 51u8,
-                        48u8, 54u8, 226u8, 128u8, 147u8, 49u8, 51u8, 48u8, 56u8, 41u8, 87u8, 97u8,
-                        100u8, 197u8, 141u8, 32u8, 40u8, 55u8, 48u8, 56u8, 226u8, 128u8, 147u8,
-                        55u8, 49u8, 53u8, 41u8, 89u8, 197u8, 141u8, 114u8, 197u8, 141u8, 32u8,
-                        40u8, 55u8, 49u8, 55u8, 226u8, 128u8, 147u8, 55u8, 50u8, 52u8, 41u8, 89u8,
-                        197u8, 141u8, 119u8, 97u8, 32u8, 40u8, 49u8, 49u8, 56u8, 49u8, 226u8,
-                        128u8, 147u8, 49u8, 49u8, 56u8, 50u8, 41u8,
-                    ])
-                },
-            )
-        },
-        abbr: unsafe {
-            #[allow(unused_unsafe)]
-            ::zerovec::ZeroMap::from_parts_unchecked(
-                unsafe {
-                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
-                        239u8, 0u8, 0u8, 0u8, 0u8, 0u8, 9u8, 0u8, 19u8, 0u8, 27u8, 0u8, 37u8, 0u8,
-                        47u8, 0u8, 50u8, 0u8, 60u8, 0u8, 71u8, 0u8, 81u8, 0u8, 91u8, 0u8, 101u8,
-                        0u8, 111u8, 0u8, 122u8, 0u8, 133u8, 0u8, 142u8, 0u8, 152u8, 0u8, 164u8,
-                        0u8, 177u8, 0u8, 188u8, 0u8, 199u8, 0u8, 201u8, 0u8, 212u8, 0u8, 221u8,
-                        0u8, 231u8, 0u8, 242u8, 0u8, 253u8, 0u8, 8u8, 1u8, 20u8, 1u8, 33u8, 1u8,
-                        44u8, 1u8, 55u8, 1u8, 65u8, 1u8, 74u8, 1u8, 84u8, 1u8, 94u8, 1u8, 102u8,
-                        1u8, 111u8, 1u8, 120u8, 1u8, 129u8, 1u8, 139u8, 1u8, 149u8, 1u8, 159u8,
-                        1u8, 169u8, 1u8, 180u8, 1u8, 192u8, 1u8, 202u8, 1u8, 212u8, 1u8, 220u8,
-                        1u8, 229u8, 1u8, 239u8, 1u8, 247u8, 1u8, 1u8, 2u8, 11u8, 2u8, 21u8, 2u8,
-                        29u8, 2u8, 38u8, 2u8, 49u8, 2u8, 60u8, 2u8, 70u8, 2u8, 81u8, 2u8, 92u8,
-                        2u8, 102u8, 2u8, 112u8, 2u8, 122u8, 2u8, 132u8, 2u8, 142u8, 2u8, 153u8,
-                        2u8, 163u8, 2u8, 174u8, 2u8, 183u8, 2u8, 195u8, 2u8, 208u8, 2u8, 220u8,
-                        2u8, 231u8, 2u8, 241u8, 2u8, 251u8, 2u8, 1u8, 3u8, 10u8, 3u8, 19u8, 3u8,
-                        28u8, 3u8, 38u8, 3u8, 47u8, 3u8, 55u8, 3u8, 66u8, 3u8, 77u8, 3u8, 86u8,
-                        3u8, 100u8, 3u8, 109u8, 3u8, 121u8, 3u8, 131u8, 3u8, 140u8, 3u8, 149u8,
-                        3u8, 159u8, 3u8, 168u8, 3u8, 177u8, 3u8, 187u8, 3u8, 197u8, 3u8, 205u8,
-                        3u8, 213u8, 3u8, 224u8, 3u8, 232u8, 3u8, 241u8, 3u8, 250u8, 3u8, 4u8, 4u8,
-                        13u8, 4u8, 21u8, 4u8, 31u8, 4u8, 43u8, 4u8, 54u8, 4u8, 64u8, 4u8, 74u8,
-                        4u8, 85u8, 4u8, 95u8, 4u8, 105u8, 4u8, 115u8, 4u8, 124u8, 4u8, 135u8, 4u8,
-                        145u8, 4u8, 155u8, 4u8, 166u8, 4u8, 177u8, 4u8, 189u8, 4u8, 197u8, 4u8,
-                        208u8, 4u8, 220u8, 4u8, 230u8, 4u8, 240u8, 4u8, 250u8, 4u8, 5u8, 5u8, 14u8,
-                        5u8, 23u8, 5u8, 34u8, 5u8, 44u8, 5u8, 55u8, 5u8, 65u8, 5u8, 76u8, 5u8,
-                        86u8, 5u8, 97u8, 5u8, 107u8, 5u8, 120u8, 5u8, 132u8, 5u8, 141u8, 5u8,
-                        151u8, 5u8, 161u8, 5u8, 171u8, 5u8, 179u8, 5u8, 188u8, 5u8, 197u8, 5u8,
-                        206u8, 5u8, 217u8, 5u8, 226u8, 5u8, 234u8, 5u8, 246u8, 5u8, 0u8, 6u8, 9u8,
-                        6u8, 18u8, 6u8, 28u8, 6u8, 40u8, 6u8, 52u8, 6u8, 62u8, 6u8, 72u8, 6u8,
-                        82u8, 6u8, 92u8, 6u8, 102u8, 6u8, 112u8, 6u8, 117u8, 6u8, 126u8, 6u8,
-                        138u8, 6u8, 150u8, 6u8, 162u8, 6u8, 172u8, 6u8, 182u8, 6u8, 192u8, 6u8,
-                        201u8, 6u8, 210u8, 6u8, 221u8, 6u8, 230u8, 6u8, 238u8, 6u8, 246u8, 6u8,
-                        255u8, 6u8, 9u8, 7u8, 16u8, 7u8, 25u8, 7u8, 30u8, 7u8, 43u8, 7u8, 52u8,
-                        7u8, 62u8, 7u8, 72u8, 7u8, 83u8, 7u8, 94u8, 7u8, 105u8, 7u8, 116u8, 7u8,
-                        126u8, 7u8, 136u8, 7u8, 146u8, 7u8, 156u8, 7u8, 165u8, 7u8, 178u8, 7u8,
-                        190u8, 7u8, 200u8, 7u8, 212u8, 7u8, 217u8, 7u8, 227u8, 7u8, 237u8, 7u8,
-                        247u8, 7u8, 0u8, 8u8, 9u8, 8u8, 15u8, 8u8, 24u8, 8u8, 35u8, 8u8, 45u8, 8u8,
-                        55u8, 8u8, 64u8, 8u8, 74u8, 8u8, 84u8, 8u8, 94u8, 8u8, 104u8, 8u8, 114u8,
-                        8u8, 125u8, 8u8, 135u8, 8u8, 146u8, 8u8, 154u8, 8u8, 164u8, 8u8, 176u8,
-                        8u8, 186u8, 8u8, 200u8, 8u8, 215u8, 8u8, 230u8, 8u8, 245u8, 8u8, 0u8, 9u8,
-                        12u8, 9u8, 23u8, 9u8, 34u8, 9u8, 45u8, 9u8, 55u8, 9u8, 66u8, 9u8, 74u8,
-                        9u8, 82u8, 9u8, 97u8, 110u8, 101u8, 105u8, 45u8, 49u8, 55u8, 55u8, 50u8,
-                        97u8, 110u8, 103u8, 101u8, 110u8, 45u8, 49u8, 49u8, 55u8, 53u8, 97u8,
-                        110u8, 110u8, 97u8, 45u8, 57u8, 54u8, 56u8, 97u8, 110u8, 115u8, 101u8,
-                        105u8, 45u8, 49u8, 56u8, 53u8, 52u8, 97u8, 110u8, 116u8, 101u8, 105u8,
-                        45u8, 49u8, 50u8, 50u8, 55u8, 98u8, 99u8, 101u8, 98u8, 117u8, 110u8, 97u8,
-                        110u8, 45u8, 49u8, 52u8, 52u8, 52u8, 98u8, 117u8, 110u8, 99u8, 104u8,
-                        117u8, 45u8, 49u8, 51u8, 55u8, 50u8, 98u8, 117u8, 110u8, 101u8, 105u8,
-                        45u8, 49u8, 50u8, 54u8, 52u8, 98u8, 117u8, 110u8, 106u8, 105u8, 45u8, 49u8,
-                        49u8, 56u8, 53u8, 98u8, 117u8, 110u8, 107u8, 97u8, 45u8, 49u8, 56u8, 48u8,
-                        52u8, 98u8, 117u8, 110u8, 107u8, 105u8, 45u8, 49u8, 53u8, 48u8, 49u8, 98u8,
-                        117u8, 110u8, 107u8, 121u8, 117u8, 45u8, 49u8, 56u8, 54u8, 49u8, 98u8,
-                        117u8, 110u8, 109u8, 101u8, 105u8, 45u8, 49u8, 52u8, 54u8, 57u8, 98u8,
-                        117u8, 110u8, 111u8, 45u8, 49u8, 50u8, 54u8, 48u8, 98u8, 117u8, 110u8,
-                        112u8, 111u8, 45u8, 49u8, 51u8, 49u8, 55u8, 98u8, 117u8, 110u8, 114u8,
-                        111u8, 107u8, 117u8, 45u8, 49u8, 53u8, 57u8, 50u8, 98u8, 117u8, 110u8,
-                        114u8, 121u8, 97u8, 107u8, 117u8, 45u8, 49u8, 50u8, 51u8, 52u8, 98u8,
-                        117u8, 110u8, 115u8, 101u8, 105u8, 45u8, 49u8, 56u8, 49u8, 56u8, 98u8,
-                        117u8, 110u8, 115u8, 104u8, 111u8, 45u8, 49u8, 52u8, 54u8, 54u8, 99u8,
-                        101u8, 99u8, 104u8, 111u8, 103u8, 101u8, 110u8, 45u8, 49u8, 48u8, 50u8,
-                        56u8, 99u8, 104u8, 111u8, 104u8, 111u8, 45u8, 57u8, 57u8, 57u8, 99u8,
-                        104u8, 111u8, 106u8, 105u8, 45u8, 49u8, 49u8, 48u8, 52u8, 99u8, 104u8,
-                        111u8, 107u8, 97u8, 110u8, 45u8, 49u8, 49u8, 54u8, 51u8, 99u8, 104u8,
-                        111u8, 107u8, 121u8, 111u8, 45u8, 49u8, 52u8, 56u8, 55u8, 99u8, 104u8,
-                        111u8, 107u8, 121u8, 117u8, 45u8, 49u8, 48u8, 52u8, 48u8, 99u8, 104u8,
-                        111u8, 114u8, 111u8, 107u8, 117u8, 45u8, 49u8, 52u8, 53u8, 55u8, 99u8,
-                        104u8, 111u8, 114u8, 121u8, 97u8, 107u8, 117u8, 45u8, 49u8, 48u8, 51u8,
-                        55u8, 99u8, 104u8, 111u8, 115u8, 104u8, 111u8, 45u8, 49u8, 49u8, 51u8,
-                        50u8, 99u8, 104u8, 111u8, 116u8, 111u8, 107u8, 117u8, 45u8, 57u8, 57u8,
-                        53u8, 99u8, 104u8, 111u8, 119u8, 97u8, 45u8, 49u8, 48u8, 49u8, 50u8, 100u8,
-                        97u8, 105u8, 100u8, 111u8, 45u8, 56u8, 48u8, 54u8, 100u8, 97u8, 105u8,
-                        106u8, 105u8, 45u8, 49u8, 49u8, 50u8, 54u8, 101u8, 105u8, 99u8, 104u8,
-                        111u8, 45u8, 49u8, 48u8, 57u8, 54u8, 101u8, 105u8, 101u8, 110u8, 45u8,
-                        57u8, 56u8, 55u8, 101u8, 105u8, 104u8, 111u8, 45u8, 49u8, 48u8, 56u8, 49u8,
-                        101u8, 105u8, 106u8, 105u8, 45u8, 49u8, 49u8, 52u8, 49u8, 101u8, 105u8,
-                        107u8, 97u8, 110u8, 45u8, 57u8, 56u8, 51u8, 101u8, 105u8, 107u8, 121u8,
-                        111u8, 45u8, 49u8, 52u8, 50u8, 57u8, 101u8, 105u8, 107u8, 121u8, 117u8,
-                        45u8, 49u8, 49u8, 49u8, 51u8, 101u8, 105u8, 109u8, 97u8, 110u8, 45u8, 49u8,
-                        49u8, 54u8, 53u8, 101u8, 105u8, 110u8, 105u8, 110u8, 45u8, 49u8, 50u8,
-                        57u8, 51u8, 101u8, 105u8, 114u8, 111u8, 107u8, 117u8, 45u8, 49u8, 53u8,
-                        53u8, 56u8, 101u8, 105u8, 114u8, 121u8, 97u8, 107u8, 117u8, 45u8, 49u8,
-                        49u8, 54u8, 48u8, 101u8, 105u8, 115u8, 104u8, 111u8, 45u8, 49u8, 48u8,
-                        52u8, 54u8, 101u8, 105u8, 115u8, 104u8, 111u8, 45u8, 49u8, 53u8, 48u8,
-                        52u8, 101u8, 105u8, 115u8, 111u8, 45u8, 57u8, 56u8, 57u8, 101u8, 110u8,
-                        99u8, 104u8, 111u8, 45u8, 57u8, 50u8, 51u8, 101u8, 110u8, 103u8, 101u8,
-                        110u8, 45u8, 49u8, 51u8, 51u8, 54u8, 101u8, 110u8, 103u8, 105u8, 45u8,
-                        57u8, 48u8, 49u8, 101u8, 110u8, 107u8, 121u8, 111u8, 45u8, 49u8, 51u8,
-                        48u8, 56u8, 101u8, 110u8, 107u8, 121u8, 111u8, 45u8, 49u8, 55u8, 52u8,
-                        52u8, 101u8, 110u8, 107u8, 121u8, 117u8, 45u8, 49u8, 48u8, 54u8, 57u8,
-                        101u8, 110u8, 111u8, 45u8, 49u8, 50u8, 51u8, 57u8, 101u8, 110u8, 112u8,
-                        111u8, 45u8, 49u8, 54u8, 55u8, 51u8, 101u8, 110u8, 114u8, 121u8, 97u8,
-                        107u8, 117u8, 45u8, 55u8, 56u8, 50u8, 101u8, 110u8, 116u8, 111u8, 107u8,
-                        117u8, 45u8, 49u8, 52u8, 56u8, 57u8, 103u8, 97u8, 110u8, 103u8, 121u8,
-                        111u8, 45u8, 56u8, 55u8, 55u8, 103u8, 101u8, 110u8, 98u8, 117u8, 110u8,
-                        45u8, 49u8, 55u8, 51u8, 54u8, 103u8, 101u8, 110u8, 99u8, 104u8, 117u8,
-                        45u8, 49u8, 51u8, 56u8, 52u8, 103u8, 101u8, 110u8, 101u8, 105u8, 45u8,
-                        49u8, 49u8, 49u8, 56u8, 103u8, 101u8, 110u8, 106u8, 105u8, 45u8, 49u8,
-                        56u8, 54u8, 52u8, 103u8, 101u8, 110u8, 107u8, 105u8, 45u8, 49u8, 53u8,
-                        55u8, 48u8, 103u8, 101u8, 110u8, 107u8, 111u8, 45u8, 49u8, 51u8, 50u8,
-                        49u8, 103u8, 101u8, 110u8, 107u8, 111u8, 45u8, 49u8, 51u8, 51u8, 49u8,
-                        103u8, 101u8, 110u8, 107u8, 121u8, 117u8, 45u8, 49u8, 50u8, 48u8, 52u8,
-                        103u8, 101u8, 110u8, 110u8, 97u8, 45u8, 49u8, 54u8, 49u8, 53u8, 103u8,
-                        101u8, 110u8, 110u8, 105u8, 110u8, 45u8, 49u8, 50u8, 50u8, 52u8, 103u8,
-                        101u8, 110u8, 111u8, 45u8, 49u8, 51u8, 49u8, 57u8, 103u8, 101u8, 110u8,
-                        114u8, 111u8, 107u8, 117u8, 45u8, 49u8, 54u8, 56u8, 56u8, 103u8, 101u8,
-                        110u8, 114u8, 121u8, 97u8, 107u8, 117u8, 45u8, 49u8, 49u8, 56u8, 52u8,
-                        103u8, 101u8, 110u8, 116u8, 111u8, 107u8, 117u8, 45u8, 49u8, 51u8, 50u8,
-                        57u8, 104u8, 97u8, 107u8, 117u8, 99u8, 104u8, 105u8, 45u8, 54u8, 53u8,
-                        48u8, 104u8, 97u8, 107u8, 117u8, 104u8, 111u8, 45u8, 54u8, 55u8, 50u8,
-                        104u8, 101u8, 105u8, 106u8, 105u8, 45u8, 49u8, 49u8, 53u8, 57u8, 104u8,
-                        101u8, 105u8, 115u8, 101u8, 105u8, 104u8, 111u8, 97u8, 110u8, 45u8, 49u8,
-                        49u8, 50u8, 48u8, 104u8, 111u8, 101u8, 105u8, 45u8, 49u8, 55u8, 48u8, 52u8,
-                        104u8, 111u8, 101u8, 110u8, 45u8, 49u8, 49u8, 51u8, 53u8, 104u8, 111u8,
-                        103u8, 101u8, 110u8, 45u8, 49u8, 49u8, 53u8, 54u8, 104u8, 111u8, 106u8,
-                        105u8, 45u8, 49u8, 50u8, 52u8, 55u8, 104u8, 111u8, 107u8, 105u8, 45u8,
-                        55u8, 55u8, 48u8, 104u8, 111u8, 114u8, 101u8, 107u8, 105u8, 45u8, 49u8,
-                        55u8, 53u8, 49u8, 104u8, 111u8, 116u8, 111u8, 107u8, 117u8, 45u8, 49u8,
-                        52u8, 52u8, 57u8, 106u8, 105u8, 97u8, 110u8, 45u8, 49u8, 48u8, 50u8, 49u8,
-                        106u8, 105u8, 110u8, 103u8, 111u8, 107u8, 101u8, 105u8, 117u8, 110u8, 45u8,
-                        55u8, 54u8, 55u8, 106u8, 105u8, 110u8, 107u8, 105u8, 45u8, 55u8, 50u8,
-                        52u8, 106u8, 105u8, 114u8, 121u8, 97u8, 107u8, 117u8, 45u8, 49u8, 48u8,
-                        54u8, 53u8, 106u8, 105u8, 115u8, 104u8, 111u8, 45u8, 49u8, 49u8, 55u8,
-                        55u8, 106u8, 111u8, 101u8, 105u8, 45u8, 49u8, 50u8, 51u8, 50u8, 106u8,
-                        111u8, 103u8, 97u8, 110u8, 45u8, 56u8, 53u8, 57u8, 106u8, 111u8, 103u8,
-                        101u8, 110u8, 45u8, 49u8, 50u8, 48u8, 55u8, 106u8, 111u8, 103u8, 101u8,
-                        110u8, 45u8, 57u8, 55u8, 54u8, 106u8, 111u8, 104u8, 101u8, 105u8, 45u8,
-                        57u8, 51u8, 49u8, 106u8, 111u8, 107u8, 121u8, 111u8, 45u8, 49u8, 54u8,
-                        56u8, 52u8, 106u8, 111u8, 107u8, 121u8, 117u8, 45u8, 49u8, 50u8, 49u8,
-                        57u8, 106u8, 111u8, 111u8, 45u8, 49u8, 50u8, 50u8, 50u8, 106u8, 111u8,
-                        111u8, 45u8, 49u8, 54u8, 53u8, 50u8, 106u8, 111u8, 116u8, 111u8, 107u8,
-                        117u8, 45u8, 49u8, 48u8, 57u8, 55u8, 106u8, 111u8, 119u8, 97u8, 45u8, 56u8,
-                        51u8, 52u8, 106u8, 117u8, 101u8, 105u8, 45u8, 49u8, 49u8, 56u8, 50u8,
-                        107u8, 97u8, 101u8, 105u8, 45u8, 49u8, 56u8, 52u8, 56u8, 107u8, 97u8,
-                        103u8, 101u8, 110u8, 45u8, 49u8, 51u8, 48u8, 51u8, 107u8, 97u8, 104u8,
-                        111u8, 45u8, 49u8, 48u8, 57u8, 52u8, 107u8, 97u8, 106u8, 111u8, 45u8, 56u8,
-                        52u8, 56u8, 107u8, 97u8, 107u8, 101u8, 105u8, 45u8, 49u8, 51u8, 56u8, 55u8,
-                        107u8, 97u8, 107u8, 105u8, 116u8, 115u8, 117u8, 45u8, 49u8, 52u8, 52u8,
-                        49u8, 107u8, 97u8, 110u8, 98u8, 117u8, 110u8, 45u8, 49u8, 54u8, 54u8, 49u8,
-                        107u8, 97u8, 110u8, 101u8, 105u8, 45u8, 49u8, 54u8, 50u8, 52u8, 107u8,
-                        97u8, 110u8, 101u8, 110u8, 45u8, 49u8, 55u8, 52u8, 56u8, 107u8, 97u8,
-                        110u8, 103u8, 101u8, 110u8, 45u8, 49u8, 50u8, 52u8, 51u8, 107u8, 97u8,
-                        110u8, 106u8, 105u8, 45u8, 49u8, 48u8, 56u8, 55u8, 107u8, 97u8, 110u8,
-                        107u8, 105u8, 45u8, 49u8, 50u8, 50u8, 57u8, 107u8, 97u8, 110u8, 107u8,
-                        111u8, 45u8, 49u8, 48u8, 48u8, 52u8, 107u8, 97u8, 110u8, 110u8, 97u8, 45u8,
-                        57u8, 56u8, 53u8, 107u8, 97u8, 110u8, 110u8, 105u8, 110u8, 45u8, 49u8,
-                        48u8, 49u8, 55u8, 107u8, 97u8, 110u8, 112u8, 111u8, 45u8, 49u8, 55u8, 52u8,
-                        49u8, 107u8, 97u8, 110u8, 112u8, 121u8, 111u8, 45u8, 56u8, 56u8, 57u8,
-                        107u8, 97u8, 110u8, 115u8, 101u8, 105u8, 45u8, 49u8, 55u8, 56u8, 57u8,
-                        107u8, 97u8, 110u8, 115u8, 104u8, 111u8, 45u8, 49u8, 52u8, 54u8, 48u8,
-                        107u8, 97u8, 110u8, 116u8, 111u8, 107u8, 117u8, 45u8, 49u8, 48u8, 52u8,
-                        52u8, 107u8, 97u8, 111u8, 45u8, 49u8, 49u8, 54u8, 57u8, 107u8, 97u8, 114u8,
-                        111u8, 107u8, 117u8, 45u8, 49u8, 50u8, 50u8, 53u8, 107u8, 97u8, 114u8,
-                        121u8, 97u8, 107u8, 117u8, 45u8, 49u8, 51u8, 50u8, 54u8, 107u8, 97u8,
-                        115u8, 104u8, 111u8, 45u8, 49u8, 49u8, 48u8, 54u8, 107u8, 97u8, 116u8,
-                        101u8, 105u8, 45u8, 49u8, 50u8, 51u8, 53u8, 107u8, 101u8, 105u8, 97u8,
-                        110u8, 45u8, 49u8, 54u8, 52u8, 56u8, 107u8, 101u8, 105u8, 99u8, 104u8,
-                        111u8, 45u8, 49u8, 53u8, 57u8, 54u8, 107u8, 101u8, 105u8, 111u8, 45u8,
-                        49u8, 56u8, 54u8, 53u8, 107u8, 101u8, 105u8, 117u8, 110u8, 45u8, 55u8,
-                        48u8, 52u8, 107u8, 101u8, 110u8, 99u8, 104u8, 111u8, 45u8, 49u8, 50u8,
-                        52u8, 57u8, 107u8, 101u8, 110u8, 101u8, 105u8, 45u8, 49u8, 50u8, 48u8,
-                        54u8, 107u8, 101u8, 110u8, 103u8, 101u8, 110u8, 45u8, 49u8, 51u8, 48u8,
-                        50u8, 107u8, 101u8, 110u8, 106u8, 105u8, 45u8, 49u8, 50u8, 55u8, 53u8,
-                        107u8, 101u8, 110u8, 107u8, 121u8, 117u8, 45u8, 49u8, 49u8, 57u8, 48u8,
-                        107u8, 101u8, 110u8, 109u8, 117u8, 45u8, 49u8, 51u8, 51u8, 52u8, 107u8,
-                        101u8, 110u8, 110u8, 105u8, 110u8, 45u8, 49u8, 50u8, 48u8, 49u8, 107u8,
-                        101u8, 110u8, 112u8, 111u8, 45u8, 49u8, 50u8, 49u8, 51u8, 107u8, 101u8,
-                        110u8, 114u8, 121u8, 97u8, 107u8, 117u8, 45u8, 49u8, 50u8, 49u8, 49u8,
-                        107u8, 101u8, 110u8, 116u8, 111u8, 107u8, 117u8, 45u8, 49u8, 51u8, 55u8,
-                        48u8, 107u8, 111u8, 97u8, 110u8, 45u8, 49u8, 50u8, 55u8, 56u8, 107u8,
-                        111u8, 99u8, 104u8, 111u8, 45u8, 49u8, 50u8, 54u8, 49u8, 107u8, 111u8,
-                        103u8, 101u8, 110u8, 45u8, 49u8, 50u8, 53u8, 54u8, 107u8, 111u8, 104u8,
-                        101u8, 105u8, 45u8, 49u8, 48u8, 53u8, 56u8, 107u8, 111u8, 104u8, 111u8,
-                        45u8, 57u8, 54u8, 52u8, 107u8, 111u8, 106u8, 105u8, 45u8, 49u8, 49u8, 52u8,
-                        50u8, 107u8, 111u8, 106u8, 105u8, 45u8, 49u8, 53u8, 53u8, 53u8, 107u8,
-                        111u8, 107u8, 97u8, 45u8, 49u8, 56u8, 52u8, 52u8, 107u8, 111u8, 107u8,
-                        111u8, 107u8, 117u8, 45u8, 49u8, 51u8, 52u8, 48u8, 107u8, 111u8, 110u8,
-                        105u8, 110u8, 45u8, 56u8, 49u8, 48u8, 107u8, 111u8, 111u8, 45u8, 49u8,
-                        51u8, 56u8, 57u8, 107u8, 111u8, 114u8, 121u8, 97u8, 107u8, 117u8, 45u8,
-                        49u8, 51u8, 55u8, 57u8, 107u8, 111u8, 115u8, 104u8, 111u8, 45u8, 49u8,
-                        52u8, 53u8, 53u8, 107u8, 111u8, 119u8, 97u8, 45u8, 49u8, 48u8, 57u8, 57u8,
-                        107u8, 111u8, 119u8, 97u8, 45u8, 49u8, 51u8, 56u8, 49u8, 107u8, 121u8,
-                        111u8, 104u8, 111u8, 45u8, 49u8, 55u8, 49u8, 54u8, 107u8, 121u8, 111u8,
-                        114u8, 111u8, 107u8, 117u8, 45u8, 49u8, 53u8, 50u8, 56u8, 107u8, 121u8,
-                        111u8, 116u8, 111u8, 107u8, 117u8, 45u8, 49u8, 52u8, 53u8, 50u8, 107u8,
-                        121u8, 111u8, 119u8, 97u8, 45u8, 49u8, 56u8, 48u8, 49u8, 107u8, 121u8,
-                        117u8, 97u8, 110u8, 45u8, 49u8, 49u8, 52u8, 53u8, 107u8, 121u8, 117u8,
-                        106u8, 117u8, 45u8, 49u8, 49u8, 53u8, 52u8, 109u8, 97u8, 110u8, 101u8,
-                        110u8, 45u8, 49u8, 56u8, 54u8, 48u8, 109u8, 97u8, 110u8, 106u8, 105u8,
-                        45u8, 49u8, 54u8, 53u8, 56u8, 109u8, 97u8, 110u8, 106u8, 117u8, 45u8, 49u8,
-                        48u8, 50u8, 52u8, 109u8, 101u8, 105u8, 106u8, 105u8, 109u8, 101u8, 105u8,
-                        111u8, 45u8, 49u8, 52u8, 57u8, 50u8, 109u8, 101u8, 105u8, 114u8, 101u8,
-                        107u8, 105u8, 45u8, 49u8, 54u8, 53u8, 53u8, 109u8, 101u8, 105u8, 116u8,
-                        111u8, 107u8, 117u8, 45u8, 49u8, 51u8, 56u8, 55u8, 109u8, 101u8, 105u8,
-                        116u8, 111u8, 107u8, 117u8, 45u8, 49u8, 51u8, 57u8, 48u8, 109u8, 101u8,
-                        105u8, 119u8, 97u8, 45u8, 49u8, 55u8, 54u8, 52u8, 110u8, 105u8, 110u8,
-                        97u8, 110u8, 45u8, 49u8, 49u8, 54u8, 54u8, 110u8, 105u8, 110u8, 106u8,
-                        105u8, 45u8, 49u8, 50u8, 52u8, 48u8, 110u8, 105u8, 110u8, 106u8, 117u8,
-                        45u8, 56u8, 53u8, 49u8, 110u8, 105u8, 110u8, 110u8, 97u8, 45u8, 56u8, 56u8,
-                        53u8, 110u8, 105u8, 110u8, 112u8, 101u8, 105u8, 45u8, 49u8, 49u8, 53u8,
-                        49u8, 111u8, 99u8, 104u8, 111u8, 45u8, 49u8, 51u8, 49u8, 49u8, 111u8,
-                        101u8, 105u8, 45u8, 49u8, 51u8, 57u8, 52u8, 111u8, 104u8, 111u8, 45u8,
-                        49u8, 49u8, 54u8, 49u8, 111u8, 110u8, 105u8, 110u8, 45u8, 49u8, 52u8, 54u8,
-                        55u8, 111u8, 116u8, 111u8, 107u8, 117u8, 45u8, 49u8, 48u8, 56u8, 52u8,
-                        111u8, 119u8, 97u8, 45u8, 57u8, 54u8, 49u8, 114u8, 101u8, 105u8, 107u8,
-                        105u8, 45u8, 55u8, 49u8, 53u8, 114u8, 101u8, 105u8, 119u8, 97u8, 114u8,
-                        121u8, 97u8, 107u8, 117u8, 110u8, 105u8, 110u8, 45u8, 49u8, 50u8, 51u8,
-                        56u8, 115u8, 97u8, 105u8, 107u8, 111u8, 45u8, 56u8, 53u8, 52u8, 115u8,
-                        104u8, 111u8, 97u8, 110u8, 45u8, 49u8, 49u8, 55u8, 49u8, 115u8, 104u8,
-                        111u8, 97u8, 110u8, 45u8, 49u8, 50u8, 57u8, 57u8, 115u8, 104u8, 111u8,
-                        99u8, 104u8, 111u8, 45u8, 49u8, 52u8, 50u8, 56u8, 115u8, 104u8, 111u8,
-                        99u8, 104u8, 117u8, 45u8, 49u8, 51u8, 50u8, 52u8, 115u8, 104u8, 111u8,
-                        103u8, 101u8, 110u8, 45u8, 49u8, 50u8, 53u8, 57u8, 115u8, 104u8, 111u8,
-                        104u8, 101u8, 105u8, 45u8, 49u8, 51u8, 52u8, 54u8, 115u8, 104u8, 111u8,
-                        104u8, 111u8, 45u8, 49u8, 48u8, 55u8, 52u8, 115u8, 104u8, 111u8, 104u8,
-                        111u8, 45u8, 49u8, 54u8, 52u8, 52u8, 115u8, 104u8, 111u8, 106u8, 105u8,
-                        45u8, 49u8, 49u8, 57u8, 57u8, 115u8, 104u8, 111u8, 107u8, 97u8, 45u8, 49u8,
-                        50u8, 53u8, 55u8, 115u8, 104u8, 111u8, 111u8, 45u8, 49u8, 50u8, 56u8, 56u8,
-                        115u8, 104u8, 111u8, 114u8, 121u8, 97u8, 107u8, 117u8, 45u8, 49u8, 48u8,
-                        55u8, 55u8, 115u8, 104u8, 111u8, 114u8, 121u8, 97u8, 107u8, 117u8, 45u8,
-                        57u8, 57u8, 48u8, 115u8, 104u8, 111u8, 116u8, 97u8, 105u8, 45u8, 56u8,
-                        57u8, 56u8, 115u8, 104u8, 111u8, 116u8, 111u8, 107u8, 117u8, 45u8, 49u8,
-                        55u8, 49u8, 49u8, 115u8, 104u8, 111u8, 119u8, 97u8, 115u8, 104u8, 111u8,
-                        119u8, 97u8, 45u8, 49u8, 51u8, 49u8, 50u8, 115u8, 104u8, 117u8, 99u8,
-                        104u8, 111u8, 45u8, 54u8, 56u8, 54u8, 116u8, 97u8, 105u8, 101u8, 105u8,
-                        45u8, 49u8, 53u8, 50u8, 49u8, 116u8, 97u8, 105u8, 104u8, 111u8, 45u8, 55u8,
-                        48u8, 49u8, 116u8, 97u8, 105u8, 107u8, 97u8, 45u8, 54u8, 52u8, 53u8, 116u8,
-                        97u8, 105u8, 115u8, 104u8, 111u8, 116u8, 101u8, 110u8, 97u8, 110u8, 45u8,
-                        56u8, 53u8, 55u8, 116u8, 101u8, 110u8, 98u8, 117u8, 110u8, 45u8, 49u8,
-                        53u8, 51u8, 50u8, 116u8, 101u8, 110u8, 99u8, 104u8, 111u8, 45u8, 56u8,
-                        50u8, 52u8, 116u8, 101u8, 110u8, 101u8, 105u8, 45u8, 49u8, 49u8, 49u8,
-                        48u8, 116u8, 101u8, 110u8, 101u8, 110u8, 45u8, 57u8, 55u8, 51u8, 116u8,
-                        101u8, 110u8, 103u8, 101u8, 110u8, 45u8, 57u8, 55u8, 56u8, 116u8, 101u8,
-                        110u8, 103u8, 105u8, 45u8, 49u8, 48u8, 53u8, 51u8, 116u8, 101u8, 110u8,
-                        103u8, 121u8, 111u8, 45u8, 57u8, 51u8, 56u8, 116u8, 101u8, 110u8, 106u8,
-                        105u8, 45u8, 49u8, 49u8, 50u8, 52u8, 116u8, 101u8, 110u8, 106u8, 117u8,
-                        45u8, 49u8, 51u8, 55u8, 53u8, 116u8, 101u8, 110u8, 109u8, 101u8, 105u8,
-                        45u8, 49u8, 55u8, 56u8, 49u8, 116u8, 101u8, 110u8, 110u8, 97u8, 45u8, 49u8,
-                        54u8, 56u8, 49u8, 116u8, 101u8, 110u8, 110u8, 105u8, 110u8, 45u8, 49u8,
-                        49u8, 48u8, 56u8, 116u8, 101u8, 110u8, 111u8, 45u8, 55u8, 56u8, 49u8,
-                        116u8, 101u8, 110u8, 112u8, 111u8, 45u8, 49u8, 56u8, 51u8, 48u8, 116u8,
-                        101u8, 110u8, 112u8, 117u8, 107u8, 117u8, 45u8, 49u8, 50u8, 51u8, 51u8,
-                        116u8, 101u8, 110u8, 112u8, 121u8, 111u8, 45u8, 55u8, 50u8, 57u8, 116u8,
-                        101u8, 110u8, 112u8, 121u8, 111u8, 104u8, 111u8, 106u8, 105u8, 45u8, 55u8,
-                        53u8, 55u8, 116u8, 101u8, 110u8, 112u8, 121u8, 111u8, 106u8, 105u8, 110u8,
-                        103u8, 111u8, 45u8, 55u8, 54u8, 53u8, 116u8, 101u8, 110u8, 112u8, 121u8,
-                        111u8, 107u8, 97u8, 109u8, 112u8, 111u8, 45u8, 55u8, 52u8, 57u8, 116u8,
-                        101u8, 110u8, 112u8, 121u8, 111u8, 115u8, 104u8, 111u8, 104u8, 111u8, 45u8,
-                        55u8, 52u8, 57u8, 116u8, 101u8, 110u8, 114u8, 111u8, 107u8, 117u8, 45u8,
-                        57u8, 55u8, 48u8, 116u8, 101u8, 110u8, 114u8, 121u8, 97u8, 107u8, 117u8,
-                        45u8, 57u8, 52u8, 55u8, 116u8, 101u8, 110u8, 115u8, 104u8, 111u8, 45u8,
-                        49u8, 49u8, 51u8, 49u8, 116u8, 101u8, 110u8, 115u8, 104u8, 111u8, 45u8,
-                        49u8, 53u8, 55u8, 51u8, 116u8, 101u8, 110u8, 116u8, 111u8, 107u8, 117u8,
-                        45u8, 57u8, 53u8, 55u8, 116u8, 101u8, 110u8, 121u8, 111u8, 45u8, 49u8,
-                        49u8, 52u8, 52u8, 116u8, 111u8, 107u8, 117u8, 106u8, 105u8, 45u8, 49u8,
-                        51u8, 48u8, 54u8, 119u8, 97u8, 100u8, 111u8, 45u8, 55u8, 48u8, 56u8, 121u8,
-                        111u8, 114u8, 111u8, 45u8, 55u8, 49u8, 55u8, 121u8, 111u8, 119u8, 97u8,
-                        45u8, 49u8, 49u8, 56u8, 49u8,
-                    ])
-                },
-                unsafe {
-                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
-                        239u8, 0u8, 0u8, 0u8, 0u8, 0u8, 21u8, 0u8, 40u8, 0u8, 56u8, 0u8, 75u8, 0u8,
-                        94u8, 0u8, 150u8, 0u8, 172u8, 0u8, 193u8, 0u8, 215u8, 0u8, 234u8, 0u8,
-                        253u8, 0u8, 16u8, 1u8, 37u8, 1u8, 57u8, 1u8, 79u8, 1u8, 99u8, 1u8, 120u8,
-                        1u8, 142u8, 1u8, 162u8, 1u8, 183u8, 1u8, 227u8, 1u8, 248u8, 1u8, 12u8, 2u8,
-                        32u8, 2u8, 53u8, 2u8, 75u8, 2u8, 97u8, 2u8, 119u8, 2u8, 142u8, 2u8, 164u8,
-                        2u8, 184u8, 2u8, 204u8, 2u8, 222u8, 2u8, 241u8, 2u8, 5u8, 3u8, 21u8, 3u8,
-                        40u8, 3u8, 58u8, 3u8, 75u8, 3u8, 95u8, 3u8, 115u8, 3u8, 134u8, 3u8, 153u8,
-                        3u8, 173u8, 3u8, 194u8, 3u8, 214u8, 3u8, 234u8, 3u8, 250u8, 3u8, 12u8, 4u8,
-                        31u8, 4u8, 47u8, 4u8, 67u8, 4u8, 87u8, 4u8, 107u8, 4u8, 128u8, 4u8, 147u8,
-                        4u8, 166u8, 4u8, 186u8, 4u8, 205u8, 4u8, 225u8, 4u8, 246u8, 4u8, 12u8, 5u8,
-                        31u8, 5u8, 50u8, 5u8, 70u8, 5u8, 90u8, 5u8, 111u8, 5u8, 130u8, 5u8, 150u8,
-                        5u8, 169u8, 5u8, 190u8, 5u8, 212u8, 5u8, 233u8, 5u8, 252u8, 5u8, 15u8, 6u8,
-                        34u8, 6u8, 40u8, 6u8, 59u8, 6u8, 78u8, 6u8, 97u8, 6u8, 117u8, 6u8, 136u8,
-                        6u8, 153u8, 6u8, 174u8, 6u8, 195u8, 6u8, 213u8, 6u8, 236u8, 6u8, 253u8,
-                        6u8, 18u8, 7u8, 38u8, 7u8, 57u8, 7u8, 75u8, 7u8, 95u8, 7u8, 113u8, 7u8,
-                        131u8, 7u8, 152u8, 7u8, 173u8, 7u8, 192u8, 7u8, 211u8, 7u8, 232u8, 7u8,
-                        249u8, 7u8, 11u8, 8u8, 29u8, 8u8, 48u8, 8u8, 67u8, 8u8, 84u8, 8u8, 103u8,
-                        8u8, 124u8, 8u8, 144u8, 8u8, 166u8, 8u8, 188u8, 8u8, 208u8, 8u8, 227u8,
-                        8u8, 246u8, 8u8, 10u8, 9u8, 27u8, 9u8, 47u8, 9u8, 67u8, 9u8, 86u8, 9u8,
-                        106u8, 9u8, 127u8, 9u8, 148u8, 9u8, 166u8, 9u8, 186u8, 9u8, 207u8, 9u8,
-                        227u8, 9u8, 246u8, 9u8, 9u8, 10u8, 30u8, 10u8, 49u8, 10u8, 66u8, 10u8,
-                        87u8, 10u8, 109u8, 10u8, 129u8, 10u8, 148u8, 10u8, 169u8, 10u8, 188u8,
-                        10u8, 208u8, 10u8, 228u8, 10u8, 250u8, 10u8, 15u8, 11u8, 34u8, 11u8, 55u8,
-                        11u8, 75u8, 11u8, 95u8, 11u8, 113u8, 11u8, 132u8, 11u8, 151u8, 11u8, 170u8,
-                        11u8, 191u8, 11u8, 209u8, 11u8, 228u8, 11u8, 250u8, 11u8, 15u8, 12u8, 34u8,
-                        12u8, 53u8, 12u8, 74u8, 12u8, 96u8, 12u8, 118u8, 12u8, 138u8, 12u8, 158u8,
-                        12u8, 178u8, 12u8, 200u8, 12u8, 219u8, 12u8, 238u8, 12u8, 243u8, 12u8, 6u8,
-                        13u8, 27u8, 13u8, 48u8, 13u8, 69u8, 13u8, 88u8, 13u8, 110u8, 13u8, 129u8,
-                        13u8, 146u8, 13u8, 163u8, 13u8, 183u8, 13u8, 203u8, 13u8, 221u8, 13u8,
-                        239u8, 13u8, 2u8, 14u8, 22u8, 14u8, 38u8, 14u8, 55u8, 14u8, 60u8, 14u8,
-                        82u8, 14u8, 100u8, 14u8, 120u8, 14u8, 140u8, 14u8, 162u8, 14u8, 184u8,
-                        14u8, 205u8, 14u8, 226u8, 14u8, 246u8, 14u8, 10u8, 15u8, 30u8, 15u8, 50u8,
-                        15u8, 70u8, 15u8, 93u8, 15u8, 114u8, 15u8, 133u8, 15u8, 155u8, 15u8, 161u8,
-                        15u8, 181u8, 15u8, 200u8, 15u8, 219u8, 15u8, 237u8, 15u8, 254u8, 15u8, 5u8,
-                        16u8, 23u8, 16u8, 43u8, 16u8, 62u8, 16u8, 82u8, 16u8, 102u8, 16u8, 120u8,
-                        16u8, 139u8, 16u8, 158u8, 16u8, 177u8, 16u8, 196u8, 16u8, 216u8, 16u8,
-                        235u8, 16u8, 255u8, 16u8, 17u8, 17u8, 37u8, 17u8, 58u8, 17u8, 77u8, 17u8,
-                        102u8, 17u8, 127u8, 17u8, 153u8, 17u8, 180u8, 17u8, 199u8, 17u8, 219u8,
-                        17u8, 240u8, 17u8, 5u8, 18u8, 24u8, 18u8, 47u8, 18u8, 67u8, 18u8, 84u8,
-                        18u8, 102u8, 18u8, 65u8, 110u8, 226u8, 128u8, 153u8, 101u8, 105u8, 32u8,
-                        40u8, 49u8, 55u8, 55u8, 50u8, 226u8, 128u8, 147u8, 49u8, 55u8, 56u8, 49u8,
-                        41u8, 65u8, 110u8, 103u8, 101u8, 110u8, 32u8, 40u8, 49u8, 49u8, 55u8, 53u8,
-                        226u8, 128u8, 147u8, 49u8, 49u8, 55u8, 55u8, 41u8, 65u8, 110u8, 110u8,
-                        97u8, 32u8, 40u8, 57u8, 54u8, 56u8, 226u8, 128u8, 147u8, 57u8, 55u8, 48u8,
-                        41u8, 65u8, 110u8, 115u8, 101u8, 105u8, 32u8, 40u8, 49u8, 56u8, 53u8, 52u8,
-                        226u8, 128u8, 147u8, 49u8, 56u8, 54u8, 48u8, 41u8, 65u8, 110u8, 116u8,
-                        101u8, 105u8, 32u8, 40u8, 49u8, 50u8, 50u8, 55u8, 226u8, 128u8, 147u8,
-                        49u8, 50u8, 50u8, 57u8, 41u8, 240u8, 145u8, 132u8, 136u8, 240u8, 145u8,
-                        132u8, 179u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8, 168u8,
-                        240u8, 145u8, 132u8, 140u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8,
-                        132u8, 145u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 155u8,
-                        240u8, 145u8, 132u8, 171u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8,
-                        132u8, 180u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8, 167u8, 66u8,
-                        117u8, 110u8, 226u8, 128u8, 153u8, 97u8, 110u8, 32u8, 40u8, 49u8, 52u8,
-                        52u8, 52u8, 226u8, 128u8, 147u8, 49u8, 52u8, 52u8, 57u8, 41u8, 66u8, 117u8,
-                        110u8, 99u8, 104u8, 197u8, 171u8, 32u8, 40u8, 49u8, 51u8, 55u8, 50u8,
-                        226u8, 128u8, 147u8, 49u8, 51u8, 55u8, 53u8, 41u8, 66u8, 117u8, 110u8,
-                        226u8, 128u8, 153u8, 101u8, 105u8, 32u8, 40u8, 49u8, 50u8, 54u8, 52u8,
-                        226u8, 128u8, 147u8, 49u8, 50u8, 55u8, 53u8, 41u8, 66u8, 117u8, 110u8,
-                        106u8, 105u8, 32u8, 40u8, 49u8, 49u8, 56u8, 53u8, 226u8, 128u8, 147u8,
-                        49u8, 49u8, 57u8, 48u8, 41u8, 66u8, 117u8, 110u8, 107u8, 97u8, 32u8, 40u8,
-                        49u8, 56u8, 48u8, 52u8, 226u8, 128u8, 147u8, 49u8, 56u8, 49u8, 56u8, 41u8,
-                        66u8, 117u8, 110u8, 107u8, 105u8, 32u8, 40u8, 49u8, 53u8, 48u8, 49u8,
-                        226u8, 128u8, 147u8, 49u8, 53u8, 48u8, 52u8, 41u8, 66u8, 117u8, 110u8,
-                        107u8, 121u8, 197u8, 171u8, 32u8, 40u8, 49u8, 56u8, 54u8, 49u8, 226u8,
-                        128u8, 147u8, 49u8, 56u8, 54u8, 52u8, 41u8, 66u8, 117u8, 110u8, 109u8,
-                        101u8, 105u8, 32u8, 40u8, 49u8, 52u8, 54u8, 57u8, 226u8, 128u8, 147u8,
-                        49u8, 52u8, 56u8, 55u8, 41u8, 66u8, 117u8, 110u8, 226u8, 128u8, 153u8,
-                        197u8, 141u8, 32u8, 40u8, 49u8, 50u8, 54u8, 48u8, 226u8, 128u8, 147u8,
-                        49u8, 50u8, 54u8, 49u8, 41u8, 66u8, 117u8, 110u8, 112u8, 197u8, 141u8,
-                        32u8, 40u8, 49u8, 51u8, 49u8, 55u8, 226u8, 128u8, 147u8, 49u8, 51u8, 49u8,
-                        57u8, 41u8, 66u8, 117u8, 110u8, 114u8, 111u8, 107u8, 117u8, 32u8, 40u8,
-                        49u8, 53u8, 57u8, 50u8, 226u8, 128u8, 147u8, 49u8, 53u8, 57u8, 54u8, 41u8,
-                        66u8, 117u8, 110u8, 114u8, 121u8, 97u8, 107u8, 117u8, 32u8, 40u8, 49u8,
-                        50u8, 51u8, 52u8, 226u8, 128u8, 147u8, 49u8, 50u8, 51u8, 53u8, 41u8, 66u8,
-                        117u8, 110u8, 115u8, 101u8, 105u8, 32u8, 40u8, 49u8, 56u8, 49u8, 56u8,
-                        226u8, 128u8, 147u8, 49u8, 56u8, 51u8, 48u8, 41u8, 66u8, 117u8, 110u8,
-                        115u8, 104u8, 197u8, 141u8, 32u8, 40u8, 49u8, 52u8, 54u8, 54u8, 226u8,
-                        128u8, 147u8, 49u8, 52u8, 54u8, 55u8, 41u8, 240u8, 145u8, 132u8, 136u8,
-                        240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8,
-                        132u8, 168u8, 240u8, 145u8, 132u8, 140u8, 240u8, 145u8, 132u8, 180u8,
-                        240u8, 145u8, 132u8, 145u8, 240u8, 145u8, 132u8, 155u8, 240u8, 145u8,
-                        132u8, 180u8, 240u8, 145u8, 132u8, 152u8, 240u8, 145u8, 132u8, 167u8, 67u8,
-                        104u8, 197u8, 141u8, 103u8, 101u8, 110u8, 32u8, 40u8, 49u8, 48u8, 50u8,
-                        56u8, 226u8, 128u8, 147u8, 49u8, 48u8, 51u8, 55u8, 41u8, 67u8, 104u8,
-                        197u8, 141u8, 104u8, 197u8, 141u8, 32u8, 40u8, 57u8, 57u8, 57u8, 226u8,
-                        128u8, 147u8, 49u8, 48u8, 48u8, 52u8, 41u8, 67u8, 104u8, 197u8, 141u8,
-                        106u8, 105u8, 32u8, 40u8, 49u8, 49u8, 48u8, 52u8, 226u8, 128u8, 147u8,
-                        49u8, 49u8, 48u8, 54u8, 41u8, 67u8, 104u8, 197u8, 141u8, 107u8, 97u8,
-                        110u8, 32u8, 40u8, 49u8, 49u8, 54u8, 51u8, 226u8, 128u8, 147u8, 49u8, 49u8,
-                        54u8, 53u8, 41u8, 67u8, 104u8, 197u8, 141u8, 107u8, 121u8, 197u8, 141u8,
-                        32u8, 40u8, 49u8, 52u8, 56u8, 55u8, 226u8, 128u8, 147u8, 49u8, 52u8, 56u8,
-                        57u8, 41u8, 67u8, 104u8, 197u8, 141u8, 107u8, 121u8, 197u8, 171u8, 32u8,
-                        40u8, 49u8, 48u8, 52u8, 48u8, 226u8, 128u8, 147u8, 49u8, 48u8, 52u8, 52u8,
-                        41u8, 67u8, 104u8, 197u8, 141u8, 114u8, 111u8, 107u8, 117u8, 32u8, 40u8,
-                        49u8, 52u8, 53u8, 55u8, 226u8, 128u8, 147u8, 49u8, 52u8, 54u8, 48u8, 41u8,
-                        67u8, 104u8, 197u8, 141u8, 114u8, 121u8, 97u8, 107u8, 117u8, 32u8, 40u8,
-                        49u8, 48u8, 51u8, 55u8, 226u8, 128u8, 147u8, 49u8, 48u8, 52u8, 48u8, 41u8,
-                        67u8, 104u8, 197u8, 141u8, 115u8, 104u8, 197u8, 141u8, 32u8, 40u8, 49u8,
-                        49u8, 51u8, 50u8, 226u8, 128u8, 147u8, 49u8, 49u8, 51u8, 53u8, 41u8, 67u8,
-                        104u8, 197u8, 141u8, 116u8, 111u8, 107u8, 117u8, 32u8, 40u8, 57u8, 57u8,
-                        53u8, 226u8, 128u8, 147u8, 57u8, 57u8, 57u8, 41u8, 67u8, 104u8, 197u8,
-                        141u8, 119u8, 97u8, 32u8, 40u8, 49u8, 48u8, 49u8, 50u8, 226u8, 128u8,
-                        147u8, 49u8, 48u8, 49u8, 55u8, 41u8, 68u8, 97u8, 105u8, 100u8, 197u8,
-                        141u8, 32u8, 40u8, 56u8, 48u8, 54u8, 226u8, 128u8, 147u8, 56u8, 49u8, 48u8,
-                        41u8, 68u8, 97u8, 105u8, 106u8, 105u8, 32u8, 40u8, 49u8, 49u8, 50u8, 54u8,
-                        226u8, 128u8, 147u8, 49u8, 49u8, 51u8, 49u8, 41u8, 69u8, 105u8, 99u8,
-                        104u8, 197u8, 141u8, 32u8, 40u8, 49u8, 48u8, 57u8, 54u8, 226u8, 128u8,
-                        147u8, 49u8, 48u8, 57u8, 55u8, 41u8, 69u8, 105u8, 101u8, 110u8, 32u8, 40u8,
-                        57u8, 56u8, 55u8, 226u8, 128u8, 147u8, 57u8, 56u8, 57u8, 41u8, 69u8, 105u8,
-                        104u8, 197u8, 141u8, 32u8, 40u8, 49u8, 48u8, 56u8, 49u8, 226u8, 128u8,
-                        147u8, 49u8, 48u8, 56u8, 52u8, 41u8, 69u8, 105u8, 106u8, 105u8, 32u8, 40u8,
-                        49u8, 49u8, 52u8, 49u8, 226u8, 128u8, 147u8, 49u8, 49u8, 52u8, 50u8, 41u8,
-                        69u8, 105u8, 107u8, 97u8, 110u8, 32u8, 40u8, 57u8, 56u8, 51u8, 226u8,
-                        128u8, 147u8, 57u8, 56u8, 53u8, 41u8, 69u8, 105u8, 107u8, 121u8, 197u8,
-                        141u8, 32u8, 40u8, 49u8, 52u8, 50u8, 57u8, 226u8, 128u8, 147u8, 49u8, 52u8,
-                        52u8, 49u8, 41u8, 69u8, 105u8, 107u8, 121u8, 197u8, 171u8, 32u8, 40u8,
-                        49u8, 49u8, 49u8, 51u8, 226u8, 128u8, 147u8, 49u8, 49u8, 49u8, 56u8, 41u8,
-                        69u8, 105u8, 109u8, 97u8, 110u8, 32u8, 40u8, 49u8, 49u8, 54u8, 53u8, 226u8,
-                        128u8, 147u8, 49u8, 49u8, 54u8, 54u8, 41u8, 69u8, 105u8, 110u8, 105u8,
-                        110u8, 32u8, 40u8, 49u8, 50u8, 57u8, 51u8, 226u8, 128u8, 147u8, 49u8, 50u8,
-                        57u8, 57u8, 41u8, 69u8, 105u8, 114u8, 111u8, 107u8, 117u8, 32u8, 40u8,
-                        49u8, 53u8, 53u8, 56u8, 226u8, 128u8, 147u8, 49u8, 53u8, 55u8, 48u8, 41u8,
-                        69u8, 105u8, 114u8, 121u8, 97u8, 107u8, 117u8, 32u8, 40u8, 49u8, 49u8,
-                        54u8, 48u8, 226u8, 128u8, 147u8, 49u8, 49u8, 54u8, 49u8, 41u8, 69u8, 105u8,
-                        115u8, 104u8, 197u8, 141u8, 32u8, 40u8, 49u8, 48u8, 52u8, 54u8, 226u8,
-                        128u8, 147u8, 49u8, 48u8, 53u8, 51u8, 41u8, 69u8, 105u8, 115u8, 104u8,
-                        197u8, 141u8, 32u8, 40u8, 49u8, 53u8, 48u8, 52u8, 226u8, 128u8, 147u8,
-                        49u8, 53u8, 50u8, 49u8, 41u8, 69u8, 105u8, 115u8, 111u8, 32u8, 40u8, 57u8,
-                        56u8, 57u8, 226u8, 128u8, 147u8, 57u8, 57u8, 48u8, 41u8, 69u8, 110u8, 99u8,
-                        104u8, 197u8, 141u8, 32u8, 40u8, 57u8, 50u8, 51u8, 226u8, 128u8, 147u8,
-                        57u8, 51u8, 49u8, 41u8, 69u8, 110u8, 103u8, 101u8, 110u8, 32u8, 40u8, 49u8,
-                        51u8, 51u8, 54u8, 226u8, 128u8, 147u8, 49u8, 51u8, 52u8, 48u8, 41u8, 69u8,
-                        110u8, 103u8, 105u8, 32u8, 40u8, 57u8, 48u8, 49u8, 226u8, 128u8, 147u8,
-                        57u8, 50u8, 51u8, 41u8, 69u8, 110u8, 107u8, 121u8, 197u8, 141u8, 32u8,
-                        40u8, 49u8, 51u8, 48u8, 56u8, 226u8, 128u8, 147u8, 49u8, 51u8, 49u8, 49u8,
-                        41u8, 69u8, 110u8, 107u8, 121u8, 197u8, 141u8, 32u8, 40u8, 49u8, 55u8,
-                        52u8, 52u8, 226u8, 128u8, 147u8, 49u8, 55u8, 52u8, 56u8, 41u8, 69u8, 110u8,
-                        107u8, 121u8, 197u8, 171u8, 32u8, 40u8, 49u8, 48u8, 54u8, 57u8, 226u8,
-                        128u8, 147u8, 49u8, 48u8, 55u8, 52u8, 41u8, 69u8, 110u8, 226u8, 128u8,
-                        153u8, 197u8, 141u8, 32u8, 40u8, 49u8, 50u8, 51u8, 57u8, 226u8, 128u8,
-                        147u8, 49u8, 50u8, 52u8, 48u8, 41u8, 69u8, 110u8, 112u8, 197u8, 141u8,
-                        32u8, 40u8, 49u8, 54u8, 55u8, 51u8, 226u8, 128u8, 147u8, 49u8, 54u8, 56u8,
-                        49u8, 41u8, 69u8, 110u8, 114u8, 121u8, 97u8, 107u8, 117u8, 32u8, 40u8,
-                        55u8, 56u8, 50u8, 226u8, 128u8, 147u8, 56u8, 48u8, 54u8, 41u8, 69u8, 110u8,
-                        116u8, 111u8, 107u8, 117u8, 32u8, 40u8, 49u8, 52u8, 56u8, 57u8, 226u8,
-                        128u8, 147u8, 49u8, 52u8, 57u8, 50u8, 41u8, 71u8, 97u8, 110u8, 103u8,
-                        121u8, 197u8, 141u8, 32u8, 40u8, 56u8, 55u8, 55u8, 226u8, 128u8, 147u8,
-                        56u8, 56u8, 53u8, 41u8, 71u8, 101u8, 110u8, 98u8, 117u8, 110u8, 32u8, 40u8,
-                        49u8, 55u8, 51u8, 54u8, 226u8, 128u8, 147u8, 49u8, 55u8, 52u8, 49u8, 41u8,
-                        71u8, 101u8, 110u8, 99u8, 104u8, 197u8, 171u8, 32u8, 40u8, 49u8, 51u8,
-                        56u8, 52u8, 226u8, 128u8, 147u8, 49u8, 51u8, 57u8, 50u8, 41u8, 71u8, 101u8,
-                        110u8, 226u8, 128u8, 153u8, 101u8, 105u8, 32u8, 40u8, 49u8, 49u8, 49u8,
-                        56u8, 226u8, 128u8, 147u8, 49u8, 49u8, 50u8, 48u8, 41u8, 71u8, 101u8,
-                        110u8, 106u8, 105u8, 32u8, 40u8, 49u8, 56u8, 54u8, 52u8, 226u8, 128u8,
-                        147u8, 49u8, 56u8, 54u8, 53u8, 41u8, 71u8, 101u8, 110u8, 107u8, 105u8,
-                        32u8, 40u8, 49u8, 53u8, 55u8, 48u8, 226u8, 128u8, 147u8, 49u8, 53u8, 55u8,
-                        51u8, 41u8, 71u8, 101u8, 110u8, 107u8, 197u8, 141u8, 32u8, 40u8, 49u8,
-                        51u8, 50u8, 49u8, 226u8, 128u8, 147u8, 49u8, 51u8, 50u8, 52u8, 41u8, 71u8,
-                        101u8, 110u8, 107u8, 197u8, 141u8, 32u8, 40u8, 49u8, 51u8, 51u8, 49u8,
-                        226u8, 128u8, 147u8, 49u8, 51u8, 51u8, 52u8, 41u8, 71u8, 101u8, 110u8,
-                        107u8, 121u8, 197u8, 171u8, 32u8, 40u8, 49u8, 50u8, 48u8, 52u8, 226u8,
-                        128u8, 147u8, 49u8, 50u8, 48u8, 54u8, 41u8, 71u8, 101u8, 110u8, 110u8,
-                        97u8, 32u8, 40u8, 49u8, 54u8, 49u8, 53u8, 226u8, 128u8, 147u8, 49u8, 54u8,
-                        50u8, 52u8, 41u8, 71u8, 101u8, 110u8, 110u8, 105u8, 110u8, 32u8, 40u8,
-                        49u8, 50u8, 50u8, 52u8, 226u8, 128u8, 147u8, 49u8, 50u8, 50u8, 53u8, 41u8,
-                        71u8, 101u8, 110u8, 197u8, 141u8, 32u8, 40u8, 49u8, 51u8, 49u8, 57u8,
-                        226u8, 128u8, 147u8, 49u8, 51u8, 50u8, 49u8, 41u8, 71u8, 101u8, 110u8,
-                        114u8, 111u8, 107u8, 117u8, 32u8, 40u8, 49u8, 54u8, 56u8, 56u8, 226u8,
-                        128u8, 147u8, 49u8, 55u8, 48u8, 52u8, 41u8, 71u8, 101u8, 110u8, 114u8,
-                        121u8, 97u8, 107u8, 117u8, 32u8, 40u8, 49u8, 49u8, 56u8, 52u8, 226u8,
-                        128u8, 147u8, 49u8, 49u8, 56u8, 53u8, 41u8, 71u8, 101u8, 110u8, 116u8,
-                        111u8, 107u8, 117u8, 32u8, 40u8, 49u8, 51u8, 50u8, 57u8, 226u8, 128u8,
-                        147u8, 49u8, 51u8, 51u8, 49u8, 41u8, 72u8, 97u8, 107u8, 117u8, 99u8, 104u8,
-                        105u8, 32u8, 40u8, 54u8, 53u8, 48u8, 226u8, 128u8, 147u8, 54u8, 55u8, 49u8,
-                        41u8, 72u8, 97u8, 107u8, 117u8, 104u8, 197u8, 141u8, 32u8, 40u8, 54u8,
-                        55u8, 50u8, 226u8, 128u8, 147u8, 54u8, 56u8, 54u8, 41u8, 72u8, 101u8,
-                        105u8, 106u8, 105u8, 32u8, 40u8, 49u8, 49u8, 53u8, 57u8, 226u8, 128u8,
-                        147u8, 49u8, 49u8, 54u8, 48u8, 41u8, 72u8, 101u8, 105u8, 115u8, 101u8,
-                        105u8, 72u8, 197u8, 141u8, 97u8, 110u8, 32u8, 40u8, 49u8, 49u8, 50u8, 48u8,
-                        226u8, 128u8, 147u8, 49u8, 49u8, 50u8, 52u8, 41u8, 72u8, 197u8, 141u8,
-                        101u8, 105u8, 32u8, 40u8, 49u8, 55u8, 48u8, 52u8, 226u8, 128u8, 147u8,
-                        49u8, 55u8, 49u8, 49u8, 41u8, 72u8, 197u8, 141u8, 101u8, 110u8, 32u8, 40u8,
-                        49u8, 49u8, 51u8, 53u8, 226u8, 128u8, 147u8, 49u8, 49u8, 52u8, 49u8, 41u8,
-                        72u8, 197u8, 141u8, 103u8, 101u8, 110u8, 32u8, 40u8, 49u8, 49u8, 53u8,
-                        54u8, 226u8, 128u8, 147u8, 49u8, 49u8, 53u8, 57u8, 41u8, 72u8, 197u8,
-                        141u8, 106u8, 105u8, 32u8, 40u8, 49u8, 50u8, 52u8, 55u8, 226u8, 128u8,
-                        147u8, 49u8, 50u8, 52u8, 57u8, 41u8, 72u8, 197u8, 141u8, 107u8, 105u8,
-                        32u8, 40u8, 55u8, 55u8, 48u8, 226u8, 128u8, 147u8, 55u8, 56u8, 48u8, 41u8,
-                        72u8, 197u8, 141u8, 114u8, 101u8, 107u8, 105u8, 32u8, 40u8, 49u8, 55u8,
-                        53u8, 49u8, 226u8, 128u8, 147u8, 49u8, 55u8, 54u8, 52u8, 41u8, 72u8, 197u8,
-                        141u8, 116u8, 111u8, 107u8, 117u8, 32u8, 40u8, 49u8, 52u8, 52u8, 57u8,
-                        226u8, 128u8, 147u8, 49u8, 52u8, 53u8, 50u8, 41u8, 74u8, 105u8, 97u8,
-                        110u8, 32u8, 40u8, 49u8, 48u8, 50u8, 49u8, 226u8, 128u8, 147u8, 49u8, 48u8,
-                        50u8, 52u8, 41u8, 74u8, 105u8, 110u8, 103u8, 111u8, 45u8, 107u8, 101u8,
-                        105u8, 117u8, 110u8, 32u8, 40u8, 55u8, 54u8, 55u8, 226u8, 128u8, 147u8,
-                        55u8, 55u8, 48u8, 41u8, 74u8, 105u8, 110u8, 107u8, 105u8, 32u8, 40u8, 55u8,
-                        50u8, 52u8, 226u8, 128u8, 147u8, 55u8, 50u8, 57u8, 41u8, 74u8, 105u8,
-                        114u8, 121u8, 97u8, 107u8, 117u8, 32u8, 40u8, 49u8, 48u8, 54u8, 53u8,
-                        226u8, 128u8, 147u8, 49u8, 48u8, 54u8, 57u8, 41u8, 74u8, 105u8, 115u8,
-                        104u8, 197u8, 141u8, 32u8, 40u8, 49u8, 49u8, 55u8, 55u8, 226u8, 128u8,
-                        147u8, 49u8, 49u8, 56u8, 49u8, 41u8, 74u8, 197u8, 141u8, 101u8, 105u8,
-                        32u8, 40u8, 49u8, 50u8, 51u8, 50u8, 226u8, 128u8, 147u8, 49u8, 50u8, 51u8,
-                        51u8, 41u8, 74u8, 197u8, 141u8, 103u8, 97u8, 110u8, 32u8, 40u8, 56u8, 53u8,
-                        57u8, 226u8, 128u8, 147u8, 56u8, 55u8, 55u8, 41u8, 74u8, 197u8, 141u8,
-                        103u8, 101u8, 110u8, 32u8, 40u8, 49u8, 50u8, 48u8, 55u8, 226u8, 128u8,
-                        147u8, 49u8, 50u8, 49u8, 49u8, 41u8, 74u8, 197u8, 141u8, 103u8, 101u8,
-                        110u8, 32u8, 40u8, 57u8, 55u8, 54u8, 226u8, 128u8, 147u8, 57u8, 55u8, 56u8,
-                        41u8, 74u8, 197u8, 141u8, 104u8, 101u8, 105u8, 32u8, 40u8, 57u8, 51u8,
-                        49u8, 226u8, 128u8, 147u8, 57u8, 51u8, 56u8, 41u8, 74u8, 197u8, 141u8,
-                        107u8, 121u8, 197u8, 141u8, 32u8, 40u8, 49u8, 54u8, 56u8, 52u8, 226u8,
-                        128u8, 147u8, 49u8, 54u8, 56u8, 56u8, 41u8, 74u8, 197u8, 141u8, 107u8,
-                        121u8, 197u8, 171u8, 32u8, 40u8, 49u8, 50u8, 49u8, 57u8, 226u8, 128u8,
-                        147u8, 49u8, 50u8, 50u8, 50u8, 41u8, 74u8, 197u8, 141u8, 197u8, 141u8,
-                        32u8, 40u8, 49u8, 50u8, 50u8, 50u8, 226u8, 128u8, 147u8, 49u8, 50u8, 50u8,
-                        52u8, 41u8, 74u8, 197u8, 141u8, 197u8, 141u8, 32u8, 40u8, 49u8, 54u8, 53u8,
-                        50u8, 226u8, 128u8, 147u8, 49u8, 54u8, 53u8, 53u8, 41u8, 74u8, 197u8,
-                        141u8, 116u8, 111u8, 107u8, 117u8, 32u8, 40u8, 49u8, 48u8, 57u8, 55u8,
-                        226u8, 128u8, 147u8, 49u8, 48u8, 57u8, 57u8, 41u8, 74u8, 197u8, 141u8,
-                        119u8, 97u8, 32u8, 40u8, 56u8, 51u8, 52u8, 226u8, 128u8, 147u8, 56u8, 52u8,
-                        56u8, 41u8, 74u8, 117u8, 101u8, 105u8, 32u8, 40u8, 49u8, 49u8, 56u8, 50u8,
-                        226u8, 128u8, 147u8, 49u8, 49u8, 56u8, 52u8, 41u8, 75u8, 97u8, 101u8,
-                        105u8, 32u8, 40u8, 49u8, 56u8, 52u8, 56u8, 226u8, 128u8, 147u8, 49u8, 56u8,
-                        53u8, 52u8, 41u8, 75u8, 97u8, 103u8, 101u8, 110u8, 32u8, 40u8, 49u8, 51u8,
-                        48u8, 51u8, 226u8, 128u8, 147u8, 49u8, 51u8, 48u8, 54u8, 41u8, 75u8, 97u8,
-                        104u8, 197u8, 141u8, 32u8, 40u8, 49u8, 48u8, 57u8, 52u8, 226u8, 128u8,
-                        147u8, 49u8, 48u8, 57u8, 54u8, 41u8, 75u8, 97u8, 106u8, 197u8, 141u8, 32u8,
-                        40u8, 56u8, 52u8, 56u8, 226u8, 128u8, 147u8, 56u8, 53u8, 49u8, 41u8, 75u8,
-                        97u8, 107u8, 101u8, 105u8, 32u8, 40u8, 49u8, 51u8, 56u8, 55u8, 226u8,
-                        128u8, 147u8, 49u8, 51u8, 56u8, 57u8, 41u8, 75u8, 97u8, 107u8, 105u8,
-                        116u8, 115u8, 117u8, 32u8, 40u8, 49u8, 52u8, 52u8, 49u8, 226u8, 128u8,
-                        147u8, 49u8, 52u8, 52u8, 52u8, 41u8, 75u8, 97u8, 110u8, 98u8, 117u8, 110u8,
-                        32u8, 40u8, 49u8, 54u8, 54u8, 49u8, 226u8, 128u8, 147u8, 49u8, 54u8, 55u8,
-                        51u8, 41u8, 75u8, 97u8, 110u8, 226u8, 128u8, 153u8, 101u8, 105u8, 32u8,
-                        40u8, 49u8, 54u8, 50u8, 52u8, 226u8, 128u8, 147u8, 49u8, 54u8, 52u8, 52u8,
-                        41u8, 75u8, 97u8, 110u8, 226u8, 128u8, 153u8, 101u8, 110u8, 32u8, 40u8,
-                        49u8, 55u8, 52u8, 56u8, 226u8, 128u8, 147u8, 49u8, 55u8, 53u8, 49u8, 41u8,
-                        75u8, 97u8, 110u8, 103u8, 101u8, 110u8, 32u8, 40u8, 49u8, 50u8, 52u8, 51u8,
-                        226u8, 128u8, 147u8, 49u8, 50u8, 52u8, 55u8, 41u8, 75u8, 97u8, 110u8,
-                        106u8, 105u8, 32u8, 40u8, 49u8, 48u8, 56u8, 55u8, 226u8, 128u8, 147u8,
-                        49u8, 48u8, 57u8, 52u8, 41u8, 75u8, 97u8, 110u8, 107u8, 105u8, 32u8, 40u8,
-                        49u8, 50u8, 50u8, 57u8, 226u8, 128u8, 147u8, 49u8, 50u8, 51u8, 50u8, 41u8,
-                        75u8, 97u8, 110u8, 107u8, 197u8, 141u8, 32u8, 40u8, 49u8, 48u8, 48u8, 52u8,
-                        226u8, 128u8, 147u8, 49u8, 48u8, 49u8, 50u8, 41u8, 75u8, 97u8, 110u8,
-                        110u8, 97u8, 32u8, 40u8, 57u8, 56u8, 53u8, 226u8, 128u8, 147u8, 57u8, 56u8,
-                        55u8, 41u8, 75u8, 97u8, 110u8, 110u8, 105u8, 110u8, 32u8, 40u8, 49u8, 48u8,
-                        49u8, 55u8, 226u8, 128u8, 147u8, 49u8, 48u8, 50u8, 49u8, 41u8, 75u8, 97u8,
-                        110u8, 112u8, 197u8, 141u8, 32u8, 40u8, 49u8, 55u8, 52u8, 49u8, 226u8,
-                        128u8, 147u8, 49u8, 55u8, 52u8, 52u8, 41u8, 75u8, 97u8, 110u8, 112u8,
-                        121u8, 197u8, 141u8, 32u8, 40u8, 56u8, 56u8, 57u8, 226u8, 128u8, 147u8,
-                        56u8, 57u8, 56u8, 41u8, 75u8, 97u8, 110u8, 115u8, 101u8, 105u8, 32u8, 40u8,
-                        49u8, 55u8, 56u8, 57u8, 226u8, 128u8, 147u8, 49u8, 56u8, 48u8, 49u8, 41u8,
-                        75u8, 97u8, 110u8, 115u8, 104u8, 197u8, 141u8, 32u8, 40u8, 49u8, 52u8,
-                        54u8, 48u8, 226u8, 128u8, 147u8, 49u8, 52u8, 54u8, 54u8, 41u8, 75u8, 97u8,
-                        110u8, 116u8, 111u8, 107u8, 117u8, 32u8, 40u8, 49u8, 48u8, 52u8, 52u8,
-                        226u8, 128u8, 147u8, 49u8, 48u8, 52u8, 54u8, 41u8, 75u8, 97u8, 197u8,
-                        141u8, 32u8, 40u8, 49u8, 49u8, 54u8, 57u8, 226u8, 128u8, 147u8, 49u8, 49u8,
-                        55u8, 49u8, 41u8, 75u8, 97u8, 114u8, 111u8, 107u8, 117u8, 32u8, 40u8, 49u8,
-                        50u8, 50u8, 53u8, 226u8, 128u8, 147u8, 49u8, 50u8, 50u8, 55u8, 41u8, 75u8,
-                        97u8, 114u8, 121u8, 97u8, 107u8, 117u8, 32u8, 40u8, 49u8, 51u8, 50u8, 54u8,
-                        226u8, 128u8, 147u8, 49u8, 51u8, 50u8, 57u8, 41u8, 75u8, 97u8, 115u8,
-                        104u8, 197u8, 141u8, 32u8, 40u8, 49u8, 49u8, 48u8, 54u8, 226u8, 128u8,
-                        147u8, 49u8, 49u8, 48u8, 56u8, 41u8, 75u8, 97u8, 116u8, 101u8, 105u8, 32u8,
-                        40u8, 49u8, 50u8, 51u8, 53u8, 226u8, 128u8, 147u8, 49u8, 50u8, 51u8, 56u8,
-                        41u8, 75u8, 101u8, 105u8, 97u8, 110u8, 32u8, 40u8, 49u8, 54u8, 52u8, 56u8,
-                        226u8, 128u8, 147u8, 49u8, 54u8, 53u8, 50u8, 41u8, 75u8, 101u8, 105u8,
-                        99u8, 104u8, 197u8, 141u8, 32u8, 40u8, 49u8, 53u8, 57u8, 54u8, 226u8,
-                        128u8, 147u8, 49u8, 54u8, 49u8, 53u8, 41u8, 75u8, 101u8, 105u8, 197u8,
-                        141u8, 32u8, 40u8, 49u8, 56u8, 54u8, 53u8, 226u8, 128u8, 147u8, 49u8, 56u8,
-                        54u8, 56u8, 41u8, 75u8, 101u8, 105u8, 117u8, 110u8, 32u8, 40u8, 55u8, 48u8,
-                        52u8, 226u8, 128u8, 147u8, 55u8, 48u8, 56u8, 41u8, 75u8, 101u8, 110u8,
-                        99u8, 104u8, 197u8, 141u8, 32u8, 40u8, 49u8, 50u8, 52u8, 57u8, 226u8,
-                        128u8, 147u8, 49u8, 50u8, 53u8, 54u8, 41u8, 75u8, 101u8, 110u8, 226u8,
-                        128u8, 153u8, 101u8, 105u8, 32u8, 40u8, 49u8, 50u8, 48u8, 54u8, 226u8,
-                        128u8, 147u8, 49u8, 50u8, 48u8, 55u8, 41u8, 75u8, 101u8, 110u8, 103u8,
-                        101u8, 110u8, 32u8, 40u8, 49u8, 51u8, 48u8, 50u8, 226u8, 128u8, 147u8,
-                        49u8, 51u8, 48u8, 51u8, 41u8, 75u8, 101u8, 110u8, 106u8, 105u8, 32u8, 40u8,
-                        49u8, 50u8, 55u8, 53u8, 226u8, 128u8, 147u8, 49u8, 50u8, 55u8, 56u8, 41u8,
-                        75u8, 101u8, 110u8, 107u8, 121u8, 197u8, 171u8, 32u8, 40u8, 49u8, 49u8,
-                        57u8, 48u8, 226u8, 128u8, 147u8, 49u8, 49u8, 57u8, 57u8, 41u8, 75u8, 101u8,
-                        110u8, 109u8, 117u8, 32u8, 40u8, 49u8, 51u8, 51u8, 52u8, 226u8, 128u8,
-                        147u8, 49u8, 51u8, 51u8, 54u8, 41u8, 75u8, 101u8, 110u8, 110u8, 105u8,
-                        110u8, 32u8, 40u8, 49u8, 50u8, 48u8, 49u8, 226u8, 128u8, 147u8, 49u8, 50u8,
-                        48u8, 52u8, 41u8, 75u8, 101u8, 110u8, 112u8, 197u8, 141u8, 32u8, 40u8,
-                        49u8, 50u8, 49u8, 51u8, 226u8, 128u8, 147u8, 49u8, 50u8, 49u8, 57u8, 41u8,
-                        75u8, 101u8, 110u8, 114u8, 121u8, 97u8, 107u8, 117u8, 32u8, 40u8, 49u8,
-                        50u8, 49u8, 49u8, 226u8, 128u8, 147u8, 49u8, 50u8, 49u8, 51u8, 41u8, 75u8,
-                        101u8, 110u8, 116u8, 111u8, 107u8, 117u8, 32u8, 40u8, 49u8, 51u8, 55u8,
-                        48u8, 226u8, 128u8, 147u8, 49u8, 51u8, 55u8, 50u8, 41u8, 75u8, 197u8,
-                        141u8, 97u8, 110u8, 32u8, 40u8, 49u8, 50u8, 55u8, 56u8, 226u8, 128u8,
-                        147u8, 49u8, 50u8, 56u8, 56u8, 41u8, 75u8, 197u8, 141u8, 99u8, 104u8,
-                        197u8, 141u8, 32u8, 40u8, 49u8, 50u8, 54u8, 49u8, 226u8, 128u8, 147u8,
-                        49u8, 50u8, 54u8, 52u8, 41u8, 75u8, 197u8, 141u8, 103u8, 101u8, 110u8,
-                        32u8, 40u8, 49u8, 50u8, 53u8, 54u8, 226u8, 128u8, 147u8, 49u8, 50u8, 53u8,
-                        55u8, 41u8, 75u8, 197u8, 141u8, 104u8, 101u8, 105u8, 32u8, 40u8, 49u8,
-                        48u8, 53u8, 56u8, 226u8, 128u8, 147u8, 49u8, 48u8, 54u8, 53u8, 41u8, 75u8,
-                        197u8, 141u8, 104u8, 197u8, 141u8, 32u8, 40u8, 57u8, 54u8, 52u8, 226u8,
-                        128u8, 147u8, 57u8, 54u8, 56u8, 41u8, 75u8, 197u8, 141u8, 106u8, 105u8,
-                        32u8, 40u8, 49u8, 49u8, 52u8, 50u8, 226u8, 128u8, 147u8, 49u8, 49u8, 52u8,
-                        52u8, 41u8, 75u8, 197u8, 141u8, 106u8, 105u8, 32u8, 40u8, 49u8, 53u8, 53u8,
-                        53u8, 226u8, 128u8, 147u8, 49u8, 53u8, 53u8, 56u8, 41u8, 75u8, 197u8,
-                        141u8, 107u8, 97u8, 32u8, 40u8, 49u8, 56u8, 52u8, 52u8, 226u8, 128u8,
-                        147u8, 49u8, 56u8, 52u8, 56u8, 41u8, 75u8, 197u8, 141u8, 107u8, 111u8,
-                        107u8, 117u8, 32u8, 40u8, 49u8, 51u8, 52u8, 48u8, 226u8, 128u8, 147u8,
-                        49u8, 51u8, 52u8, 54u8, 41u8, 75u8, 197u8, 141u8, 110u8, 105u8, 110u8,
-                        32u8, 40u8, 56u8, 49u8, 48u8, 226u8, 128u8, 147u8, 56u8, 50u8, 52u8, 41u8,
-                        75u8, 197u8, 141u8, 197u8, 141u8, 32u8, 40u8, 49u8, 51u8, 56u8, 57u8,
-                        226u8, 128u8, 147u8, 49u8, 51u8, 57u8, 48u8, 41u8, 75u8, 197u8, 141u8,
-                        114u8, 121u8, 97u8, 107u8, 117u8, 32u8, 40u8, 49u8, 51u8, 55u8, 57u8,
-                        226u8, 128u8, 147u8, 49u8, 51u8, 56u8, 49u8, 41u8, 75u8, 197u8, 141u8,
-                        115u8, 104u8, 197u8, 141u8, 32u8, 40u8, 49u8, 52u8, 53u8, 53u8, 226u8,
-                        128u8, 147u8, 49u8, 52u8, 53u8, 55u8, 41u8, 75u8, 197u8, 141u8, 119u8,
-                        97u8, 32u8, 40u8, 49u8, 48u8, 57u8, 57u8, 226u8, 128u8, 147u8, 49u8, 49u8,
-                        48u8, 52u8, 41u8, 75u8, 197u8, 141u8, 119u8, 97u8, 32u8, 40u8, 49u8, 51u8,
-                        56u8, 49u8, 226u8, 128u8, 147u8, 49u8, 51u8, 56u8, 52u8, 41u8, 75u8, 121u8,
-                        197u8, 141u8, 104u8, 197u8, 141u8, 32u8, 40u8, 49u8, 55u8, 49u8, 54u8,
-                        226u8, 128u8, 147u8, 49u8, 55u8, 51u8, 54u8, 41u8, 75u8, 121u8, 197u8,
-                        141u8, 114u8, 111u8, 107u8, 117u8, 32u8, 40u8, 49u8, 53u8, 50u8, 56u8,
-                        226u8, 128u8, 147u8, 49u8, 53u8, 51u8, 50u8, 41u8, 75u8, 121u8, 197u8,
-                        141u8, 116u8, 111u8, 107u8, 117u8, 32u8, 40u8, 49u8, 52u8, 53u8, 50u8,
-                        226u8, 128u8, 147u8, 49u8, 52u8, 53u8, 53u8, 41u8, 75u8, 121u8, 197u8,
-                        141u8, 119u8, 97u8, 32u8, 40u8, 49u8, 56u8, 48u8, 49u8, 226u8, 128u8,
-                        147u8, 49u8, 56u8, 48u8, 52u8, 41u8, 75u8, 121u8, 197u8, 171u8, 97u8,
-                        110u8, 32u8, 40u8, 49u8, 49u8, 52u8, 53u8, 226u8, 128u8, 147u8, 49u8, 49u8,
-                        53u8, 49u8, 41u8, 75u8, 121u8, 197u8, 171u8, 106u8, 117u8, 32u8, 40u8,
-                        49u8, 49u8, 53u8, 52u8, 226u8, 128u8, 147u8, 49u8, 49u8, 53u8, 54u8, 41u8,
-                        77u8, 97u8, 110u8, 226u8, 128u8, 153u8, 101u8, 110u8, 32u8, 40u8, 49u8,
-                        56u8, 54u8, 48u8, 226u8, 128u8, 147u8, 49u8, 56u8, 54u8, 49u8, 41u8, 77u8,
-                        97u8, 110u8, 106u8, 105u8, 32u8, 40u8, 49u8, 54u8, 53u8, 56u8, 226u8,
-                        128u8, 147u8, 49u8, 54u8, 54u8, 49u8, 41u8, 77u8, 97u8, 110u8, 106u8,
-                        117u8, 32u8, 40u8, 49u8, 48u8, 50u8, 52u8, 226u8, 128u8, 147u8, 49u8, 48u8,
-                        50u8, 56u8, 41u8, 77u8, 101u8, 105u8, 106u8, 105u8, 77u8, 101u8, 105u8,
-                        197u8, 141u8, 32u8, 40u8, 49u8, 52u8, 57u8, 50u8, 226u8, 128u8, 147u8,
-                        49u8, 53u8, 48u8, 49u8, 41u8, 77u8, 101u8, 105u8, 114u8, 101u8, 107u8,
-                        105u8, 32u8, 40u8, 49u8, 54u8, 53u8, 53u8, 226u8, 128u8, 147u8, 49u8, 54u8,
-                        53u8, 56u8, 41u8, 77u8, 101u8, 105u8, 116u8, 111u8, 107u8, 117u8, 32u8,
-                        40u8, 49u8, 51u8, 56u8, 52u8, 226u8, 128u8, 147u8, 49u8, 51u8, 56u8, 55u8,
-                        41u8, 77u8, 101u8, 105u8, 116u8, 111u8, 107u8, 117u8, 32u8, 40u8, 49u8,
-                        51u8, 57u8, 48u8, 226u8, 128u8, 147u8, 49u8, 51u8, 57u8, 52u8, 41u8, 77u8,
-                        101u8, 105u8, 119u8, 97u8, 32u8, 40u8, 49u8, 55u8, 54u8, 52u8, 226u8,
-                        128u8, 147u8, 49u8, 55u8, 55u8, 50u8, 41u8, 78u8, 105u8, 110u8, 226u8,
-                        128u8, 153u8, 97u8, 110u8, 32u8, 40u8, 49u8, 49u8, 54u8, 54u8, 226u8,
-                        128u8, 147u8, 49u8, 49u8, 54u8, 57u8, 41u8, 78u8, 105u8, 110u8, 106u8,
-                        105u8, 32u8, 40u8, 49u8, 50u8, 52u8, 48u8, 226u8, 128u8, 147u8, 49u8, 50u8,
-                        52u8, 51u8, 41u8, 78u8, 105u8, 110u8, 106u8, 117u8, 32u8, 40u8, 56u8, 53u8,
-                        49u8, 226u8, 128u8, 147u8, 56u8, 53u8, 52u8, 41u8, 78u8, 105u8, 110u8,
-                        110u8, 97u8, 32u8, 40u8, 56u8, 56u8, 53u8, 226u8, 128u8, 147u8, 56u8, 56u8,
-                        57u8, 41u8, 78u8, 105u8, 110u8, 112u8, 101u8, 105u8, 32u8, 40u8, 49u8,
-                        49u8, 53u8, 49u8, 226u8, 128u8, 147u8, 49u8, 49u8, 53u8, 52u8, 41u8, 197u8,
-                        140u8, 99u8, 104u8, 197u8, 141u8, 32u8, 40u8, 49u8, 51u8, 49u8, 49u8,
-                        226u8, 128u8, 147u8, 49u8, 51u8, 49u8, 50u8, 41u8, 197u8, 140u8, 101u8,
-                        105u8, 32u8, 40u8, 49u8, 51u8, 57u8, 52u8, 226u8, 128u8, 147u8, 49u8, 52u8,
-                        50u8, 56u8, 41u8, 197u8, 140u8, 104u8, 111u8, 32u8, 40u8, 49u8, 49u8, 54u8,
-                        49u8, 226u8, 128u8, 147u8, 49u8, 49u8, 54u8, 51u8, 41u8, 197u8, 140u8,
-                        110u8, 105u8, 110u8, 32u8, 40u8, 49u8, 52u8, 54u8, 55u8, 226u8, 128u8,
-                        147u8, 49u8, 52u8, 54u8, 57u8, 41u8, 197u8, 140u8, 116u8, 111u8, 107u8,
-                        117u8, 32u8, 40u8, 49u8, 48u8, 56u8, 52u8, 226u8, 128u8, 147u8, 49u8, 48u8,
-                        56u8, 55u8, 41u8, 197u8, 140u8, 119u8, 97u8, 32u8, 40u8, 57u8, 54u8, 49u8,
-                        226u8, 128u8, 147u8, 57u8, 54u8, 52u8, 41u8, 82u8, 101u8, 105u8, 107u8,
-                        105u8, 32u8, 40u8, 55u8, 49u8, 53u8, 226u8, 128u8, 147u8, 55u8, 49u8, 55u8,
-                        41u8, 82u8, 101u8, 105u8, 119u8, 97u8, 82u8, 121u8, 97u8, 107u8, 117u8,
-                        110u8, 105u8, 110u8, 32u8, 40u8, 49u8, 50u8, 51u8, 56u8, 226u8, 128u8,
-                        147u8, 49u8, 50u8, 51u8, 57u8, 41u8, 83u8, 97u8, 105u8, 107u8, 197u8,
-                        141u8, 32u8, 40u8, 56u8, 53u8, 52u8, 226u8, 128u8, 147u8, 56u8, 53u8, 55u8,
-                        41u8, 83u8, 104u8, 197u8, 141u8, 97u8, 110u8, 32u8, 40u8, 49u8, 49u8, 55u8,
-                        49u8, 226u8, 128u8, 147u8, 49u8, 49u8, 55u8, 53u8, 41u8, 83u8, 104u8,
-                        197u8, 141u8, 97u8, 110u8, 32u8, 40u8, 49u8, 50u8, 57u8, 57u8, 226u8,
-                        128u8, 147u8, 49u8, 51u8, 48u8, 50u8, 41u8, 83u8, 104u8, 197u8, 141u8,
-                        99u8, 104u8, 197u8, 141u8, 32u8, 40u8, 49u8, 52u8, 50u8, 56u8, 226u8,
-                        128u8, 147u8, 49u8, 52u8, 50u8, 57u8, 41u8, 83u8, 104u8, 197u8, 141u8,
-                        99u8, 104u8, 197u8, 171u8, 32u8, 40u8, 49u8, 51u8, 50u8, 52u8, 226u8,
-                        128u8, 147u8, 49u8, 51u8, 50u8, 54u8, 41u8, 83u8, 104u8, 197u8, 141u8,
-                        103u8, 101u8, 110u8, 32u8, 40u8, 49u8, 50u8, 53u8, 57u8, 226u8, 128u8,
-                        147u8, 49u8, 50u8, 54u8, 48u8, 41u8, 83u8, 104u8, 197u8, 141u8, 104u8,
-                        101u8, 105u8, 32u8, 40u8, 49u8, 51u8, 52u8, 54u8, 226u8, 128u8, 147u8,
-                        49u8, 51u8, 55u8, 48u8, 41u8, 83u8, 104u8, 197u8, 141u8, 104u8, 111u8,
-                        32u8, 40u8, 49u8, 48u8, 55u8, 52u8, 226u8, 128u8, 147u8, 49u8, 48u8, 55u8,
-                        55u8, 41u8, 83u8, 104u8, 197u8, 141u8, 104u8, 111u8, 32u8, 40u8, 49u8,
-                        54u8, 52u8, 52u8, 226u8, 128u8, 147u8, 49u8, 54u8, 52u8, 56u8, 41u8, 83u8,
-                        104u8, 197u8, 141u8, 106u8, 105u8, 32u8, 40u8, 49u8, 49u8, 57u8, 57u8,
-                        226u8, 128u8, 147u8, 49u8, 50u8, 48u8, 49u8, 41u8, 83u8, 104u8, 197u8,
-                        141u8, 107u8, 97u8, 32u8, 40u8, 49u8, 50u8, 53u8, 55u8, 226u8, 128u8,
-                        147u8, 49u8, 50u8, 53u8, 57u8, 41u8, 83u8, 104u8, 197u8, 141u8, 197u8,
-                        141u8, 32u8, 40u8, 49u8, 50u8, 56u8, 56u8, 226u8, 128u8, 147u8, 49u8, 50u8,
-                        57u8, 51u8, 41u8, 83u8, 104u8, 197u8, 141u8, 114u8, 121u8, 97u8, 107u8,
-                        117u8, 32u8, 40u8, 49u8, 48u8, 55u8, 55u8, 226u8, 128u8, 147u8, 49u8, 48u8,
-                        56u8, 49u8, 41u8, 83u8, 104u8, 197u8, 141u8, 114u8, 121u8, 97u8, 107u8,
-                        117u8, 32u8, 40u8, 57u8, 57u8, 48u8, 226u8, 128u8, 147u8, 57u8, 57u8, 53u8,
-                        41u8, 83u8, 104u8, 197u8, 141u8, 116u8, 97u8, 105u8, 32u8, 40u8, 56u8,
-                        57u8, 56u8, 226u8, 128u8, 147u8, 57u8, 48u8, 49u8, 41u8, 83u8, 104u8,
-                        197u8, 141u8, 116u8, 111u8, 107u8, 117u8, 32u8, 40u8, 49u8, 55u8, 49u8,
-                        49u8, 226u8, 128u8, 147u8, 49u8, 55u8, 49u8, 54u8, 41u8, 83u8, 104u8,
-                        197u8, 141u8, 119u8, 97u8, 83u8, 104u8, 197u8, 141u8, 119u8, 97u8, 32u8,
-                        40u8, 49u8, 51u8, 49u8, 50u8, 226u8, 128u8, 147u8, 49u8, 51u8, 49u8, 55u8,
-                        41u8, 83u8, 104u8, 117u8, 99u8, 104u8, 197u8, 141u8, 32u8, 40u8, 54u8,
-                        56u8, 54u8, 226u8, 128u8, 147u8, 55u8, 48u8, 49u8, 41u8, 84u8, 97u8, 105u8,
-                        101u8, 105u8, 32u8, 40u8, 49u8, 53u8, 50u8, 49u8, 226u8, 128u8, 147u8,
-                        49u8, 53u8, 50u8, 56u8, 41u8, 84u8, 97u8, 105u8, 104u8, 197u8, 141u8, 32u8,
-                        40u8, 55u8, 48u8, 49u8, 226u8, 128u8, 147u8, 55u8, 48u8, 52u8, 41u8, 84u8,
-                        97u8, 105u8, 107u8, 97u8, 32u8, 40u8, 54u8, 52u8, 53u8, 226u8, 128u8,
-                        147u8, 54u8, 53u8, 48u8, 41u8, 84u8, 97u8, 105u8, 115u8, 104u8, 197u8,
-                        141u8, 84u8, 101u8, 110u8, 45u8, 97u8, 110u8, 32u8, 40u8, 56u8, 53u8, 55u8,
-                        226u8, 128u8, 147u8, 56u8, 53u8, 57u8, 41u8, 84u8, 101u8, 110u8, 98u8,
-                        117u8, 110u8, 32u8, 40u8, 49u8, 53u8, 51u8, 50u8, 226u8, 128u8, 147u8,
-                        49u8, 53u8, 53u8, 53u8, 41u8, 84u8, 101u8, 110u8, 99u8, 104u8, 197u8,
-                        141u8, 32u8, 40u8, 56u8, 50u8, 52u8, 226u8, 128u8, 147u8, 56u8, 51u8, 52u8,
-                        41u8, 84u8, 101u8, 110u8, 45u8, 101u8, 105u8, 32u8, 40u8, 49u8, 49u8, 49u8,
-                        48u8, 226u8, 128u8, 147u8, 49u8, 49u8, 49u8, 51u8, 41u8, 84u8, 101u8,
-                        110u8, 226u8, 128u8, 153u8, 101u8, 110u8, 32u8, 40u8, 57u8, 55u8, 51u8,
-                        226u8, 128u8, 147u8, 57u8, 55u8, 54u8, 41u8, 84u8, 101u8, 110u8, 103u8,
-                        101u8, 110u8, 32u8, 40u8, 57u8, 55u8, 56u8, 226u8, 128u8, 147u8, 57u8,
-                        56u8, 51u8, 41u8, 84u8, 101u8, 110u8, 103u8, 105u8, 32u8, 40u8, 49u8, 48u8,
-                        53u8, 51u8, 226u8, 128u8, 147u8, 49u8, 48u8, 53u8, 56u8, 41u8, 84u8, 101u8,
-                        110u8, 103u8, 121u8, 197u8, 141u8, 32u8, 40u8, 57u8, 51u8, 56u8, 226u8,
-                        128u8, 147u8, 57u8, 52u8, 55u8, 41u8, 84u8, 101u8, 110u8, 106u8, 105u8,
-                        32u8, 40u8, 49u8, 49u8, 50u8, 52u8, 226u8, 128u8, 147u8, 49u8, 49u8, 50u8,
-                        54u8, 41u8, 84u8, 101u8, 110u8, 106u8, 117u8, 32u8, 40u8, 49u8, 51u8, 55u8,
-                        53u8, 226u8, 128u8, 147u8, 49u8, 51u8, 55u8, 57u8, 41u8, 84u8, 101u8,
-                        110u8, 109u8, 101u8, 105u8, 32u8, 40u8, 49u8, 55u8, 56u8, 49u8, 226u8,
-                        128u8, 147u8, 49u8, 55u8, 56u8, 57u8, 41u8, 84u8, 101u8, 110u8, 110u8,
-                        97u8, 32u8, 40u8, 49u8, 54u8, 56u8, 49u8, 226u8, 128u8, 147u8, 49u8, 54u8,
-                        56u8, 52u8, 41u8, 84u8, 101u8, 110u8, 110u8, 105u8, 110u8, 32u8, 40u8,
-                        49u8, 49u8, 48u8, 56u8, 226u8, 128u8, 147u8, 49u8, 49u8, 49u8, 48u8, 41u8,
-                        84u8, 101u8, 110u8, 45u8, 197u8, 141u8, 32u8, 40u8, 55u8, 56u8, 49u8,
-                        226u8, 128u8, 147u8, 55u8, 56u8, 50u8, 41u8, 84u8, 101u8, 110u8, 112u8,
-                        197u8, 141u8, 32u8, 40u8, 49u8, 56u8, 51u8, 48u8, 226u8, 128u8, 147u8,
-                        49u8, 56u8, 52u8, 52u8, 41u8, 84u8, 101u8, 110u8, 112u8, 117u8, 107u8,
-                        117u8, 32u8, 40u8, 49u8, 50u8, 51u8, 51u8, 226u8, 128u8, 147u8, 49u8, 50u8,
-                        51u8, 52u8, 41u8, 84u8, 101u8, 110u8, 112u8, 121u8, 197u8, 141u8, 32u8,
-                        40u8, 55u8, 50u8, 57u8, 226u8, 128u8, 147u8, 55u8, 52u8, 57u8, 41u8, 84u8,
-                        101u8, 110u8, 112u8, 121u8, 197u8, 141u8, 45u8, 104u8, 197u8, 141u8, 106u8,
-                        105u8, 32u8, 40u8, 55u8, 53u8, 55u8, 226u8, 128u8, 147u8, 55u8, 54u8, 53u8,
-                        41u8, 84u8, 101u8, 110u8, 112u8, 121u8, 197u8, 141u8, 45u8, 106u8, 105u8,
-                        110u8, 103u8, 111u8, 32u8, 40u8, 55u8, 54u8, 53u8, 226u8, 128u8, 147u8,
-                        55u8, 54u8, 55u8, 41u8, 84u8, 101u8, 110u8, 112u8, 121u8, 197u8, 141u8,
-                        45u8, 107u8, 97u8, 109u8, 112u8, 197u8, 141u8, 32u8, 40u8, 55u8, 52u8,
-                        57u8, 226u8, 128u8, 147u8, 55u8, 52u8, 57u8, 41u8, 84u8, 101u8, 110u8,
-                        112u8, 121u8, 197u8, 141u8, 45u8, 115u8, 104u8, 197u8, 141u8, 104u8, 197u8,
-                        141u8, 32u8, 40u8, 55u8, 52u8, 57u8, 226u8, 128u8, 147u8, 55u8, 53u8, 55u8,
-                        41u8, 84u8, 101u8, 110u8, 114u8, 111u8, 107u8, 117u8, 32u8, 40u8, 57u8,
-                        55u8, 48u8, 226u8, 128u8, 147u8, 57u8, 55u8, 51u8, 41u8, 84u8, 101u8,
-                        110u8, 114u8, 121u8, 97u8, 107u8, 117u8, 32u8, 40u8, 57u8, 52u8, 55u8,
-                        226u8, 128u8, 147u8, 57u8, 53u8, 55u8, 41u8, 84u8, 101u8, 110u8, 115u8,
-                        104u8, 197u8, 141u8, 32u8, 40u8, 49u8, 49u8, 51u8, 49u8, 226u8, 128u8,
-                        147u8, 49u8, 49u8, 51u8, 50u8, 41u8, 84u8, 101u8, 110u8, 115u8, 104u8,
-                        197u8, 141u8, 32u8, 40u8, 49u8, 53u8, 55u8, 51u8, 226u8, 128u8, 147u8,
-                        49u8, 53u8, 57u8, 50u8, 41u8, 84u8, 101u8, 110u8, 116u8, 111u8, 107u8,
-                        117u8, 32u8, 40u8, 57u8, 53u8, 55u8, 226u8, 128u8, 147u8, 57u8, 54u8, 49u8,
-                        41u8, 84u8, 101u8, 110u8, 226u8, 128u8, 153u8, 121u8, 197u8, 141u8, 32u8,
-                        40u8, 49u8, 49u8, 52u8, 52u8, 226u8, 128u8, 147u8, 49u8, 49u8, 52u8, 53u8,
-                        41u8, 84u8, 111u8, 107u8, 117u8, 106u8, 105u8, 32u8, 40u8, 49u8, 51u8,
-                        48u8, 54u8, 226u8, 128u8, 147u8, 49u8, 51u8, 48u8, 56u8, 41u8, 87u8, 97u8,
-                        100u8, 197u8, 141u8, 32u8, 40u8, 55u8, 48u8, 56u8, 226u8, 128u8, 147u8,
-                        55u8, 49u8, 53u8, 41u8, 89u8, 197u8, 141u8, 114u8, 197u8, 141u8, 32u8,
-                        40u8, 55u8, 49u8, 55u8, 226u8, 128u8, 147u8, 55u8, 50u8, 52u8, 41u8, 89u8,
-                        197u8, 141u8, 119u8, 97u8, 32u8, 40u8, 49u8, 49u8, 56u8, 49u8, 226u8,
-                        128u8, 147u8, 49u8, 49u8, 56u8, 50u8, 41u8,
-                    ])
-                },
-            )
-        },
-        narrow: unsafe {
-            #[allow(unused_unsafe)]
-            ::zerovec::ZeroMap::from_parts_unchecked(
-                unsafe {
-                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
-                        239u8, 0u8, 0u8, 0u8, 0u8, 0u8, 9u8, 0u8, 19u8, 0u8, 27u8, 0u8, 37u8, 0u8,
-                        47u8, 0u8, 50u8, 0u8, 60u8, 0u8, 71u8, 0u8, 81u8, 0u8, 91u8, 0u8, 101u8,
-                        0u8, 111u8, 0u8, 122u8, 0u8, 133u8, 0u8, 142u8, 0u8, 152u8, 0u8, 164u8,
-                        0u8, 177u8, 0u8, 188u8, 0u8, 199u8, 0u8, 201u8, 0u8, 212u8, 0u8, 221u8,
-                        0u8, 231u8, 0u8, 242u8, 0u8, 253u8, 0u8, 8u8, 1u8, 20u8, 1u8, 33u8, 1u8,
-                        44u8, 1u8, 55u8, 1u8, 65u8, 1u8, 74u8, 1u8, 84u8, 1u8, 94u8, 1u8, 102u8,
-                        1u8, 111u8, 1u8, 120u8, 1u8, 129u8, 1u8, 139u8, 1u8, 149u8, 1u8, 159u8,
-                        1u8, 169u8, 1u8, 180u8, 1u8, 192u8, 1u8, 202u8, 1u8, 212u8, 1u8, 220u8,
-                        1u8, 229u8, 1u8, 239u8, 1u8, 247u8, 1u8, 1u8, 2u8, 11u8, 2u8, 21u8, 2u8,
-                        29u8, 2u8, 38u8, 2u8, 49u8, 2u8, 60u8, 2u8, 70u8, 2u8, 81u8, 2u8, 92u8,
-                        2u8, 102u8, 2u8, 112u8, 2u8, 122u8, 2u8, 132u8, 2u8, 142u8, 2u8, 153u8,
-                        2u8, 163u8, 2u8, 174u8, 2u8, 183u8, 2u8, 195u8, 2u8, 208u8, 2u8, 220u8,
-                        2u8, 231u8, 2u8, 241u8, 2u8, 251u8, 2u8, 1u8, 3u8, 10u8, 3u8, 19u8, 3u8,
-                        28u8, 3u8, 38u8, 3u8, 47u8, 3u8, 55u8, 3u8, 66u8, 3u8, 77u8, 3u8, 86u8,
-                        3u8, 100u8, 3u8, 109u8, 3u8, 121u8, 3u8, 131u8, 3u8, 140u8, 3u8, 149u8,
-                        3u8, 159u8, 3u8, 168u8, 3u8, 177u8, 3u8, 187u8, 3u8, 197u8, 3u8, 205u8,
-                        3u8, 213u8, 3u8, 224u8, 3u8, 232u8, 3u8, 241u8, 3u8, 250u8, 3u8, 4u8, 4u8,
-                        13u8, 4u8, 21u8, 4u8, 31u8, 4u8, 43u8, 4u8, 54u8, 4u8, 64u8, 4u8, 74u8,
-                        4u8, 85u8, 4u8, 95u8, 4u8, 105u8, 4u8, 115u8, 4u8, 124u8, 4u8, 135u8, 4u8,
-                        145u8, 4u8, 155u8, 4u8, 166u8, 4u8, 177u8, 4u8, 189u8, 4u8, 197u8, 4u8,
-                        208u8, 4u8, 220u8, 4u8, 230u8, 4u8, 240u8, 4u8, 250u8, 4u8, 5u8, 5u8, 14u8,
-                        5u8, 23u8, 5u8, 34u8, 5u8, 44u8, 5u8, 55u8, 5u8, 65u8, 5u8, 76u8, 5u8,
-                        86u8, 5u8, 97u8, 5u8, 107u8, 5u8, 120u8, 5u8, 132u8, 5u8, 141u8, 5u8,
-                        151u8, 5u8, 161u8, 5u8, 171u8, 5u8, 179u8, 5u8, 188u8, 5u8, 197u8, 5u8,
-                        206u8, 5u8, 217u8, 5u8, 226u8, 5u8, 234u8, 5u8, 246u8, 5u8, 0u8, 6u8, 9u8,
-                        6u8, 18u8, 6u8, 28u8, 6u8, 40u8, 6u8, 52u8, 6u8, 62u8, 6u8, 72u8, 6u8,
-                        82u8, 6u8, 92u8, 6u8, 102u8, 6u8, 112u8, 6u8, 117u8, 6u8, 126u8, 6u8,
-                        138u8, 6u8, 150u8, 6u8, 162u8, 6u8, 172u8, 6u8, 182u8, 6u8, 192u8, 6u8,
-                        201u8, 6u8, 210u8, 6u8, 221u8, 6u8, 230u8, 6u8, 238u8, 6u8, 246u8, 6u8,
-                        255u8, 6u8, 9u8, 7u8, 16u8, 7u8, 25u8, 7u8, 30u8, 7u8, 43u8, 7u8, 52u8,
-                        7u8, 62u8, 7u8, 72u8, 7u8, 83u8, 7u8, 94u8, 7u8, 105u8, 7u8, 116u8, 7u8,
-                        126u8, 7u8, 136u8, 7u8, 146u8, 7u8, 156u8, 7u8, 165u8, 7u8, 178u8, 7u8,
-                        190u8, 7u8, 200u8, 7u8, 212u8, 7u8, 217u8, 7u8, 227u8, 7u8, 237u8, 7u8,
-                        247u8, 7u8, 0u8, 8u8, 9u8, 8u8, 15u8, 8u8, 24u8, 8u8, 35u8, 8u8, 45u8, 8u8,
-                        55u8, 8u8, 64u8, 8u8, 74u8, 8u8, 84u8, 8u8, 94u8, 8u8, 104u8, 8u8, 114u8,
-                        8u8, 125u8, 8u8, 135u8, 8u8, 146u8, 8u8, 154u8, 8u8, 164u8, 8u8, 176u8,
-                        8u8, 186u8, 8u8, 200u8, 8u8, 215u8, 8u8, 230u8, 8u8, 245u8, 8u8, 0u8, 9u8,
-                        12u8, 9u8, 23u8, 9u8, 34u8, 9u8, 45u8, 9u8, 55u8, 9u8, 66u8, 9u8, 74u8,
-                        9u8, 82u8, 9u8, 97u8, 110u8, 101u8, 105u8, 45u8, 49u8, 55u8, 55u8, 50u8,
-                        97u8, 110u8, 103u8, 101u8, 110u8, 45u8, 49u8, 49u8, 55u8, 53u8, 97u8,
-                        110u8, 110u8, 97u8, 45u8, 57u8, 54u8, 56u8, 97u8, 110u8, 115u8, 101u8,
-                        105u8, 45u8, 49u8, 56u8, 53u8, 52u8, 97u8, 110u8, 116u8, 101u8, 105u8,
-                        45u8, 49u8, 50u8, 50u8, 55u8, 98u8, 99u8, 101u8, 98u8, 117u8, 110u8, 97u8,
-                        110u8, 45u8, 49u8, 52u8, 52u8, 52u8, 98u8, 117u8, 110u8, 99u8, 104u8,
-                        117u8, 45u8, 49u8, 51u8, 55u8, 50u8, 98u8, 117u8, 110u8, 101u8, 105u8,
-                        45u8, 49u8, 50u8, 54u8, 52u8, 98u8, 117u8, 110u8, 106u8, 105u8, 45u8, 49u8,
-                        49u8, 56u8, 53u8, 98u8, 117u8, 110u8, 107u8, 97u8, 45u8, 49u8, 56u8, 48u8,
-                        52u8, 98u8, 117u8, 110u8, 107u8, 105u8, 45u8, 49u8, 53u8, 48u8, 49u8, 98u8,
-                        117u8, 110u8, 107u8, 121u8, 117u8, 45u8, 49u8, 56u8, 54u8, 49u8, 98u8,
-                        117u8, 110u8, 109u8, 101u8, 105u8, 45u8, 49u8, 52u8, 54u8, 57u8, 98u8,
-                        117u8, 110u8, 111u8, 45u8, 49u8, 50u8, 54u8, 48u8, 98u8, 117u8, 110u8,
-                        112u8, 111u8, 45u8, 49u8, 51u8, 49u8, 55u8, 98u8, 117u8, 110u8, 114u8,
-                        111u8, 107u8, 117u8, 45u8, 49u8, 53u8, 57u8, 50u8, 98u8, 117u8, 110u8,
-                        114u8, 121u8, 97u8, 107u8, 117u8, 45u8, 49u8, 50u8, 51u8, 52u8, 98u8,
-                        117u8, 110u8, 115u8, 101u8, 105u8, 45u8, 49u8, 56u8, 49u8, 56u8, 98u8,
-                        117u8, 110u8, 115u8, 104u8, 111u8, 45u8, 49u8, 52u8, 54u8, 54u8, 99u8,
-                        101u8, 99u8, 104u8, 111u8, 103u8, 101u8, 110u8, 45u8, 49u8, 48u8, 50u8,
-                        56u8, 99u8, 104u8, 111u8, 104u8, 111u8, 45u8, 57u8, 57u8, 57u8, 99u8,
-                        104u8, 111u8, 106u8, 105u8, 45u8, 49u8, 49u8, 48u8, 52u8, 99u8, 104u8,
-                        111u8, 107u8, 97u8, 110u8, 45u8, 49u8, 49u8, 54u8, 51u8, 99u8, 104u8,
-                        111u8, 107u8, 121u8, 111u8, 45u8, 49u8, 52u8, 56u8, 55u8, 99u8, 104u8,
-                        111u8, 107u8, 121u8, 117u8, 45u8, 49u8, 48u8, 52u8, 48u8, 99u8, 104u8,
-                        111u8, 114u8, 111u8, 107u8, 117u8, 45u8, 49u8, 52u8, 53u8, 55u8, 99u8,
-                        104u8, 111u8, 114u8, 121u8, 97u8, 107u8, 117u8, 45u8, 49u8, 48u8, 51u8,
-                        55u8, 99u8, 104u8, 111u8, 115u8, 104u8, 111u8, 45u8, 49u8, 49u8, 51u8,
-                        50u8, 99u8, 104u8, 111u8, 116u8, 111u8, 107u8, 117u8, 45u8, 57u8, 57u8,
-                        53u8, 99u8, 104u8, 111u8, 119u8, 97u8, 45u8, 49u8, 48u8, 49u8, 50u8, 100u8,
-                        97u8, 105u8, 100u8, 111u8, 45u8, 56u8, 48u8, 54u8, 100u8, 97u8, 105u8,
-                        106u8, 105u8, 45u8, 49u8, 49u8, 50u8, 54u8, 101u8, 105u8, 99u8, 104u8,
-                        111u8, 45u8, 49u8, 48u8, 57u8, 54u8, 101u8, 105u8, 101u8, 110u8, 45u8,
-                        57u8, 56u8, 55u8, 101u8, 105u8, 104u8, 111u8, 45u8, 49u8, 48u8, 56u8, 49u8,
-                        101u8, 105u8, 106u8, 105u8, 45u8, 49u8, 49u8, 52u8, 49u8, 101u8, 105u8,
-                        107u8, 97u8, 110u8, 45u8, 57u8, 56u8, 51u8, 101u8, 105u8, 107u8, 121u8,
-                        111u8, 45u8, 49u8, 52u8, 50u8, 57u8, 101u8, 105u8, 107u8, 121u8, 117u8,
-                        45u8, 49u8, 49u8, 49u8, 51u8, 101u8, 105u8, 109u8, 97u8, 110u8, 45u8, 49u8,
-                        49u8, 54u8, 53u8, 101u8, 105u8, 110u8, 105u8, 110u8, 45u8, 49u8, 50u8,
-                        57u8, 51u8, 101u8, 105u8, 114u8, 111u8, 107u8, 117u8, 45u8, 49u8, 53u8,
-                        53u8, 56u8, 101u8, 105u8, 114u8, 121u8, 97u8, 107u8, 117u8, 45u8, 49u8,
-                        49u8, 54u8, 48u8, 101u8, 105u8, 115u8, 104u8, 111u8, 45u8, 49u8, 48u8,
-                        52u8, 54u8, 101u8, 105u8, 115u8, 104u8, 111u8, 45u8, 49u8, 53u8, 48u8,
-                        52u8, 101u8, 105u8, 115u8, 111u8, 45u8, 57u8, 56u8, 57u8, 101u8, 110u8,
-                        99u8, 104u8, 111u8, 45u8, 57u8, 50u8, 51u8, 101u8, 110u8, 103u8, 101u8,
-                        110u8, 45u8, 49u8, 51u8, 51u8, 54u8, 101u8, 110u8, 103u8, 105u8, 45u8,
-                        57u8, 48u8, 49u8, 101u8, 110u8, 107u8, 121u8, 111u8, 45u8, 49u8, 51u8,
-                        48u8, 56u8, 101u8, 110u8, 107u8, 121u8, 111u8, 45u8, 49u8, 55u8, 52u8,
-                        52u8, 101u8, 110u8, 107u8, 121u8, 117u8, 45u8, 49u8, 48u8, 54u8, 57u8,
-                        101u8, 110u8, 111u8, 45u8, 49u8, 50u8, 51u8, 57u8, 101u8, 110u8, 112u8,
-                        111u8, 45u8, 49u8, 54u8, 55u8, 51u8, 101u8, 110u8, 114u8, 121u8, 97u8,
-                        107u8, 117u8, 45u8, 55u8, 56u8, 50u8, 101u8, 110u8, 116u8, 111u8, 107u8,
-                        117u8, 45u8, 49u8, 52u8, 56u8, 57u8, 103u8, 97u8, 110u8, 103u8, 121u8,
-                        111u8, 45u8, 56u8, 55u8, 55u8, 103u8, 101u8, 110u8, 98u8, 117u8, 110u8,
-                        45u8, 49u8, 55u8, 51u8, 54u8, 103u8, 101u8, 110u8, 99u8, 104u8, 117u8,
-                        45u8, 49u8, 51u8, 56u8, 52u8, 103u8, 101u8, 110u8, 101u8, 105u8, 45u8,
-                        49u8, 49u8, 49u8, 56u8, 103u8, 101u8, 110u8, 106u8, 105u8, 45u8, 49u8,
-                        56u8, 54u8, 52u8, 103u8, 101u8, 110u8, 107u8, 105u8, 45u8, 49u8, 53u8,
-                        55u8, 48u8, 103u8, 101u8, 110u8, 107u8, 111u8, 45u8, 49u8, 51u8, 50u8,
-                        49u8, 103u8, 101u8, 110u8, 107u8, 111u8, 45u8, 49u8, 51u8, 51u8, 49u8,
-                        103u8, 101u8, 110u8, 107u8, 121u8, 117u8, 45u8, 49u8, 50u8, 48u8, 52u8,
-                        103u8, 101u8, 110u8, 110u8, 97u8, 45u8, 49u8, 54u8, 49u8, 53u8, 103u8,
-                        101u8, 110u8, 110u8, 105u8, 110u8, 45u8, 49u8, 50u8, 50u8, 52u8, 103u8,
-                        101u8, 110u8, 111u8, 45u8, 49u8, 51u8, 49u8, 57u8, 103u8, 101u8, 110u8,
-                        114u8, 111u8, 107u8, 117u8, 45u8, 49u8, 54u8, 56u8, 56u8, 103u8, 101u8,
-                        110u8, 114u8, 121u8, 97u8, 107u8, 117u8, 45u8, 49u8, 49u8, 56u8, 52u8,
-                        103u8, 101u8, 110u8, 116u8, 111u8, 107u8, 117u8, 45u8, 49u8, 51u8, 50u8,
-                        57u8, 104u8, 97u8, 107u8, 117u8, 99u8, 104u8, 105u8, 45u8, 54u8, 53u8,
-                        48u8, 104u8, 97u8, 107u8, 117u8, 104u8, 111u8, 45u8, 54u8, 55u8, 50u8,
-                        104u8, 101u8, 105u8, 106u8, 105u8, 45u8, 49u8, 49u8, 53u8, 57u8, 104u8,
-                        101u8, 105u8, 115u8, 101u8, 105u8, 104u8, 111u8, 97u8, 110u8, 45u8, 49u8,
-                        49u8, 50u8, 48u8, 104u8, 111u8, 101u8, 105u8, 45u8, 49u8, 55u8, 48u8, 52u8,
-                        104u8, 111u8, 101u8, 110u8, 45u8, 49u8, 49u8, 51u8, 53u8, 104u8, 111u8,
-                        103u8, 101u8, 110u8, 45u8, 49u8, 49u8, 53u8, 54u8, 104u8, 111u8, 106u8,
-                        105u8, 45u8, 49u8, 50u8, 52u8, 55u8, 104u8, 111u8, 107u8, 105u8, 45u8,
-                        55u8, 55u8, 48u8, 104u8, 111u8, 114u8, 101u8, 107u8, 105u8, 45u8, 49u8,
-                        55u8, 53u8, 49u8, 104u8, 111u8, 116u8, 111u8, 107u8, 117u8, 45u8, 49u8,
-                        52u8, 52u8, 57u8, 106u8, 105u8, 97u8, 110u8, 45u8, 49u8, 48u8, 50u8, 49u8,
-                        106u8, 105u8, 110u8, 103u8, 111u8, 107u8, 101u8, 105u8, 117u8, 110u8, 45u8,
-                        55u8, 54u8, 55u8, 106u8, 105u8, 110u8, 107u8, 105u8, 45u8, 55u8, 50u8,
-                        52u8, 106u8, 105u8, 114u8, 121u8, 97u8, 107u8, 117u8, 45u8, 49u8, 48u8,
-                        54u8, 53u8, 106u8, 105u8, 115u8, 104u8, 111u8, 45u8, 49u8, 49u8, 55u8,
-                        55u8, 106u8, 111u8, 101u8, 105u8, 45u8, 49u8, 50u8, 51u8, 50u8, 106u8,
-                        111u8, 103u8, 97u8, 110u8, 45u8, 56u8, 53u8, 57u8, 106u8, 111u8, 103u8,
-                        101u8, 110u8, 45u8, 49u8, 50u8, 48u8, 55u8, 106u8, 111u8, 103u8, 101u8,
-                        110u8, 45u8, 57u8, 55u8, 54u8, 106u8, 111u8, 104u8, 101u8, 105u8, 45u8,
-                        57u8, 51u8, 49u8, 106u8, 111u8, 107u8, 121u8, 111u8, 45u8, 49u8, 54u8,
-                        56u8, 52u8, 106u8, 111u8, 107u8, 121u8, 117u8, 45u8, 49u8, 50u8, 49u8,
-                        57u8, 106u8, 111u8, 111u8, 45u8, 49u8, 50u8, 50u8, 50u8, 106u8, 111u8,
-                        111u8, 45u8, 49u8, 54u8, 53u8, 50u8, 106u8, 111u8, 116u8, 111u8, 107u8,
-                        117u8, 45u8, 49u8, 48u8, 57u8, 55u8, 106u8, 111u8, 119u8, 97u8, 45u8, 56u8,
-                        51u8, 52u8, 106u8, 117u8, 101u8, 105u8, 45u8, 49u8, 49u8, 56u8, 50u8,
-                        107u8, 97u8, 101u8, 105u8, 45u8, 49u8, 56u8, 52u8, 56u8, 107u8, 97u8,
-                        103u8, 101u8, 110u8, 45u8, 49u8, 51u8, 48u8, 51u8, 107u8, 97u8, 104u8,
-                        111u8, 45u8, 49u8, 48u8, 57u8, 52u8, 107u8, 97u8, 106u8, 111u8, 45u8, 56u8,
-                        52u8, 56u8, 107u8, 97u8, 107u8, 101u8, 105u8, 45u8, 49u8, 51u8, 56u8, 55u8,
-                        107u8, 97u8, 107u8, 105u8, 116u8, 115u8, 117u8, 45u8, 49u8, 52u8, 52u8,
-                        49u8, 107u8, 97u8, 110u8, 98u8, 117u8, 110u8, 45u8, 49u8, 54u8, 54u8, 49u8,
-                        107u8, 97u8, 110u8, 101u8, 105u8, 45u8, 49u8, 54u8, 50u8, 52u8, 107u8,
-                        97u8, 110u8, 101u8, 110u8, 45u8, 49u8, 55u8, 52u8, 56u8, 107u8, 97u8,
-                        110u8, 103u8, 101u8, 110u8, 45u8, 49u8, 50u8, 52u8, 51u8, 107u8, 97u8,
-                        110u8, 106u8, 105u8, 45u8, 49u8, 48u8, 56u8, 55u8, 107u8, 97u8, 110u8,
-                        107u8, 105u8, 45u8, 49u8, 50u8, 50u8, 57u8, 107u8, 97u8, 110u8, 107u8,
-                        111u8, 45u8, 49u8, 48u8, 48u8, 52u8, 107u8, 97u8, 110u8, 110u8, 97u8, 45u8,
-                        57u8, 56u8, 53u8, 107u8, 97u8, 110u8, 110u8, 105u8, 110u8, 45u8, 49u8,
-                        48u8, 49u8, 55u8, 107u8, 97u8, 110u8, 112u8, 111u8, 45u8, 49u8, 55u8, 52u8,
-                        49u8, 107u8, 97u8, 110u8, 112u8, 121u8, 111u8, 45u8, 56u8, 56u8, 57u8,
-                        107u8, 97u8, 110u8, 115u8, 101u8, 105u8, 45u8, 49u8, 55u8, 56u8, 57u8,
-                        107u8, 97u8, 110u8, 115u8, 104u8, 111u8, 45u8, 49u8, 52u8, 54u8, 48u8,
-                        107u8, 97u8, 110u8, 116u8, 111u8, 107u8, 117u8, 45u8, 49u8, 48u8, 52u8,
-                        52u8, 107u8, 97u8, 111u8, 45u8, 49u8, 49u8, 54u8, 57u8, 107u8, 97u8, 114u8,
-                        111u8, 107u8, 117u8, 45u8, 49u8, 50u8, 50u8, 53u8, 107u8, 97u8, 114u8,
-                        121u8, 97u8, 107u8, 117u8, 45u8, 49u8, 51u8, 50u8, 54u8, 107u8, 97u8,
-                        115u8, 104u8, 111u8, 45u8, 49u8, 49u8, 48u8, 54u8, 107u8, 97u8, 116u8,
-                        101u8, 105u8, 45u8, 49u8, 50u8, 51u8, 53u8, 107u8, 101u8, 105u8, 97u8,
-                        110u8, 45u8, 49u8, 54u8, 52u8, 56u8, 107u8, 101u8, 105u8, 99u8, 104u8,
-                        111u8, 45u8, 49u8, 53u8, 57u8, 54u8, 107u8, 101u8, 105u8, 111u8, 45u8,
-                        49u8, 56u8, 54u8, 53u8, 107u8, 101u8, 105u8, 117u8, 110u8, 45u8, 55u8,
-                        48u8, 52u8, 107u8, 101u8, 110u8, 99u8, 104u8, 111u8, 45u8, 49u8, 50u8,
-                        52u8, 57u8, 107u8, 101u8, 110u8, 101u8, 105u8, 45u8, 49u8, 50u8, 48u8,
-                        54u8, 107u8, 101u8, 110u8, 103u8, 101u8, 110u8, 45u8, 49u8, 51u8, 48u8,
-                        50u8, 107u8, 101u8, 110u8, 106u8, 105u8, 45u8, 49u8, 50u8, 55u8, 53u8,
-                        107u8, 101u8, 110u8, 107u8, 121u8, 117u8, 45u8, 49u8, 49u8, 57u8, 48u8,
-                        107u8, 101u8, 110u8, 109u8, 117u8, 45u8, 49u8, 51u8, 51u8, 52u8, 107u8,
-                        101u8, 110u8, 110u8, 105u8, 110u8, 45u8, 49u8, 50u8, 48u8, 49u8, 107u8,
-                        101u8, 110u8, 112u8, 111u8, 45u8, 49u8, 50u8, 49u8, 51u8, 107u8, 101u8,
-                        110u8, 114u8, 121u8, 97u8, 107u8, 117u8, 45u8, 49u8, 50u8, 49u8, 49u8,
-                        107u8, 101u8, 110u8, 116u8, 111u8, 107u8, 117u8, 45u8, 49u8, 51u8, 55u8,
-                        48u8, 107u8, 111u8, 97u8, 110u8, 45u8, 49u8, 50u8, 55u8, 56u8, 107u8,
-                        111u8, 99u8, 104u8, 111u8, 45u8, 49u8, 50u8, 54u8, 49u8, 107u8, 111u8,
-                        103u8, 101u8, 110u8, 45u8, 49u8, 50u8, 53u8, 54u8, 107u8, 111u8, 104u8,
-                        101u8, 105u8, 45u8, 49u8, 48u8, 53u8, 56u8, 107u8, 111u8, 104u8, 111u8,
-                        45u8, 57u8, 54u8, 52u8, 107u8, 111u8, 106u8, 105u8, 45u8, 49u8, 49u8, 52u8,
-                        50u8, 107u8, 111u8, 106u8, 105u8, 45u8, 49u8, 53u8, 53u8, 53u8, 107u8,
-                        111u8, 107u8, 97u8, 45u8, 49u8, 56u8, 52u8, 52u8, 107u8, 111u8, 107u8,
-                        111u8, 107u8, 117u8, 45u8, 49u8, 51u8, 52u8, 48u8, 107u8, 111u8, 110u8,
-                        105u8, 110u8, 45u8, 56u8, 49u8, 48u8, 107u8, 111u8, 111u8, 45u8, 49u8,
-                        51u8, 56u8, 57u8, 107u8, 111u8, 114u8, 121u8, 97u8, 107u8, 117u8, 45u8,
-                        49u8, 51u8, 55u8, 57u8, 107u8, 111u8, 115u8, 104u8, 111u8, 45u8, 49u8,
-                        52u8, 53u8, 53u8, 107u8, 111u8, 119u8, 97u8, 45u8, 49u8, 48u8, 57u8, 57u8,
-                        107u8, 111u8, 119u8, 97u8, 45u8, 49u8, 51u8, 56u8, 49u8, 107u8, 121u8,
-                        111u8, 104u8, 111u8, 45u8, 49u8, 55u8, 49u8, 54u8, 107u8, 121u8, 111u8,
-                        114u8, 111u8, 107u8, 117u8, 45u8, 49u8, 53u8, 50u8, 56u8, 107u8, 121u8,
-                        111u8, 116u8, 111u8, 107u8, 117u8, 45u8, 49u8, 52u8, 53u8, 50u8, 107u8,
-                        121u8, 111u8, 119u8, 97u8, 45u8, 49u8, 56u8, 48u8, 49u8, 107u8, 121u8,
-                        117u8, 97u8, 110u8, 45u8, 49u8, 49u8, 52u8, 53u8, 107u8, 121u8, 117u8,
-                        106u8, 117u8, 45u8, 49u8, 49u8, 53u8, 52u8, 109u8, 97u8, 110u8, 101u8,
-                        110u8, 45u8, 49u8, 56u8, 54u8, 48u8, 109u8, 97u8, 110u8, 106u8, 105u8,
-                        45u8, 49u8, 54u8, 53u8, 56u8, 109u8, 97u8, 110u8, 106u8, 117u8, 45u8, 49u8,
-                        48u8, 50u8, 52u8, 109u8, 101u8, 105u8, 106u8, 105u8, 109u8, 101u8, 105u8,
-                        111u8, 45u8, 49u8, 52u8, 57u8, 50u8, 109u8, 101u8, 105u8, 114u8, 101u8,
-                        107u8, 105u8, 45u8, 49u8, 54u8, 53u8, 53u8, 109u8, 101u8, 105u8, 116u8,
-                        111u8, 107u8, 117u8, 45u8, 49u8, 51u8, 56u8, 55u8, 109u8, 101u8, 105u8,
-                        116u8, 111u8, 107u8, 117u8, 45u8, 49u8, 51u8, 57u8, 48u8, 109u8, 101u8,
-                        105u8, 119u8, 97u8, 45u8, 49u8, 55u8, 54u8, 52u8, 110u8, 105u8, 110u8,
-                        97u8, 110u8, 45u8, 49u8, 49u8, 54u8, 54u8, 110u8, 105u8, 110u8, 106u8,
-                        105u8, 45u8, 49u8, 50u8, 52u8, 48u8, 110u8, 105u8, 110u8, 106u8, 117u8,
-                        45u8, 56u8, 53u8, 49u8, 110u8, 105u8, 110u8, 110u8, 97u8, 45u8, 56u8, 56u8,
-                        53u8, 110u8, 105u8, 110u8, 112u8, 101u8, 105u8, 45u8, 49u8, 49u8, 53u8,
-                        49u8, 111u8, 99u8, 104u8, 111u8, 45u8, 49u8, 51u8, 49u8, 49u8, 111u8,
-                        101u8, 105u8, 45u8, 49u8, 51u8, 57u8, 52u8, 111u8, 104u8, 111u8, 45u8,
-                        49u8, 49u8, 54u8, 49u8, 111u8, 110u8, 105u8, 110u8, 45u8, 49u8, 52u8, 54u8,
-                        55u8, 111u8, 116u8, 111u8, 107u8, 117u8, 45u8, 49u8, 48u8, 56u8, 52u8,
-                        111u8, 119u8, 97u8, 45u8, 57u8, 54u8, 49u8, 114u8, 101u8, 105u8, 107u8,
-                        105u8, 45u8, 55u8, 49u8, 53u8, 114u8, 101u8, 105u8, 119u8, 97u8, 114u8,
-                        121u8, 97u8, 107u8, 117u8, 110u8, 105u8, 110u8, 45u8, 49u8, 50u8, 51u8,
-                        56u8, 115u8, 97u8, 105u8, 107u8, 111u8, 45u8, 56u8, 53u8, 52u8, 115u8,
-                        104u8, 111u8, 97u8, 110u8, 45u8, 49u8, 49u8, 55u8, 49u8, 115u8, 104u8,
-                        111u8, 97u8, 110u8, 45u8, 49u8, 50u8, 57u8, 57u8, 115u8, 104u8, 111u8,
-                        99u8, 104u8, 111u8, 45u8, 49u8, 52u8, 50u8, 56u8, 115u8, 104u8, 111u8,
-                        99u8, 104u8, 117u8, 45u8, 49u8, 51u8, 50u8, 52u8, 115u8, 104u8, 111u8,
-                        103u8, 101u8, 110u8, 45u8, 49u8, 50u8, 53u8, 57u8, 115u8, 104u8, 111u8,
-                        104u8, 101u8, 105u8, 45u8, 49u8, 51u8, 52u8, 54u8, 115u8, 104u8, 111u8,
-                        104u8, 111u8, 45u8, 49u8, 48u8, 55u8, 52u8, 115u8, 104u8, 111u8, 104u8,
-                        111u8, 45u8, 49u8, 54u8, 52u8, 52u8, 115u8, 104u8, 111u8, 106u8, 105u8,
-                        45u8, 49u8, 49u8, 57u8, 57u8, 115u8, 104u8, 111u8, 107u8, 97u8, 45u8, 49u8,
-                        50u8, 53u8, 55u8, 115u8, 104u8, 111u8, 111u8, 45u8, 49u8, 50u8, 56u8, 56u8,
-                        115u8, 104u8, 111u8, 114u8, 121u8, 97u8, 107u8, 117u8, 45u8, 49u8, 48u8,
-                        55u8, 55u8, 115u8, 104u8, 111u8, 114u8, 121u8, 97u8, 107u8, 117u8, 45u8,
-                        57u8, 57u8, 48u8, 115u8, 104u8, 111u8, 116u8, 97u8, 105u8, 45u8, 56u8,
-                        57u8, 56u8, 115u8, 104u8, 111u8, 116u8, 111u8, 107u8, 117u8, 45u8, 49u8,
-                        55u8, 49u8, 49u8, 115u8, 104u8, 111u8, 119u8, 97u8, 115u8, 104u8, 111u8,
-                        119u8, 97u8, 45u8, 49u8, 51u8, 49u8, 50u8, 115u8, 104u8, 117u8, 99u8,
-                        104u8, 111u8, 45u8, 54u8, 56u8, 54u8, 116u8, 97u8, 105u8, 101u8, 105u8,
-                        45u8, 49u8, 53u8, 50u8, 49u8, 116u8, 97u8, 105u8, 104u8, 111u8, 45u8, 55u8,
-                        48u8, 49u8, 116u8, 97u8, 105u8, 107u8, 97u8, 45u8, 54u8, 52u8, 53u8, 116u8,
-                        97u8, 105u8, 115u8, 104u8, 111u8, 116u8, 101u8, 110u8, 97u8, 110u8, 45u8,
-                        56u8, 53u8, 55u8, 116u8, 101u8, 110u8, 98u8, 117u8, 110u8, 45u8, 49u8,
-                        53u8, 51u8, 50u8, 116u8, 101u8, 110u8, 99u8, 104u8, 111u8, 45u8, 56u8,
-                        50u8, 52u8, 116u8, 101u8, 110u8, 101u8, 105u8, 45u8, 49u8, 49u8, 49u8,
-                        48u8, 116u8, 101u8, 110u8, 101u8, 110u8, 45u8, 57u8, 55u8, 51u8, 116u8,
-                        101u8, 110u8, 103u8, 101u8, 110u8, 45u8, 57u8, 55u8, 56u8, 116u8, 101u8,
-                        110u8, 103u8, 105u8, 45u8, 49u8, 48u8, 53u8, 51u8, 116u8, 101u8, 110u8,
-                        103u8, 121u8, 111u8, 45u8, 57u8, 51u8, 56u8, 116u8, 101u8, 110u8, 106u8,
-                        105u8, 45u8, 49u8, 49u8, 50u8, 52u8, 116u8, 101u8, 110u8, 106u8, 117u8,
-                        45u8, 49u8, 51u8, 55u8, 53u8, 116u8, 101u8, 110u8, 109u8, 101u8, 105u8,
-                        45u8, 49u8, 55u8, 56u8, 49u8, 116u8, 101u8, 110u8, 110u8, 97u8, 45u8, 49u8,
-                        54u8, 56u8, 49u8, 116u8, 101u8, 110u8, 110u8, 105u8, 110u8, 45u8, 49u8,
-                        49u8, 48u8, 56u8, 116u8, 101u8, 110u8, 111u8, 45u8, 55u8, 56u8, 49u8,
-                        116u8, 101u8, 110u8, 112u8, 111u8, 45u8, 49u8, 56u8, 51u8, 48u8, 116u8,
-                        101u8, 110u8, 112u8, 117u8, 107u8, 117u8, 45u8, 49u8, 50u8, 51u8, 51u8,
-                        116u8, 101u8, 110u8, 112u8, 121u8, 111u8, 45u8, 55u8, 50u8, 57u8, 116u8,
-                        101u8, 110u8, 112u8, 121u8, 111u8, 104u8, 111u8, 106u8, 105u8, 45u8, 55u8,
-                        53u8, 55u8, 116u8, 101u8, 110u8, 112u8, 121u8, 111u8, 106u8, 105u8, 110u8,
-                        103u8, 111u8, 45u8, 55u8, 54u8, 53u8, 116u8, 101u8, 110u8, 112u8, 121u8,
-                        111u8, 107u8, 97u8, 109u8, 112u8, 111u8, 45u8, 55u8, 52u8, 57u8, 116u8,
-                        101u8, 110u8, 112u8, 121u8, 111u8, 115u8, 104u8, 111u8, 104u8, 111u8, 45u8,
-                        55u8, 52u8, 57u8, 116u8, 101u8, 110u8, 114u8, 111u8, 107u8, 117u8, 45u8,
-                        57u8, 55u8, 48u8, 116u8, 101u8, 110u8, 114u8, 121u8, 97u8, 107u8, 117u8,
-                        45u8, 57u8, 52u8, 55u8, 116u8, 101u8, 110u8, 115u8, 104u8, 111u8, 45u8,
-                        49u8, 49u8, 51u8, 49u8, 116u8, 101u8, 110u8, 115u8, 104u8, 111u8, 45u8,
-                        49u8, 53u8, 55u8, 51u8, 116u8, 101u8, 110u8, 116u8, 111u8, 107u8, 117u8,
-                        45u8, 57u8, 53u8, 55u8, 116u8, 101u8, 110u8, 121u8, 111u8, 45u8, 49u8,
-                        49u8, 52u8, 52u8, 116u8, 111u8, 107u8, 117u8, 106u8, 105u8, 45u8, 49u8,
-                        51u8, 48u8, 54u8, 119u8, 97u8, 100u8, 111u8, 45u8, 55u8, 48u8, 56u8, 121u8,
-                        111u8, 114u8, 111u8, 45u8, 55u8, 49u8, 55u8, 121u8, 111u8, 119u8, 97u8,
-                        45u8, 49u8, 49u8, 56u8, 49u8,
-                    ])
-                },
-                unsafe {
-                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
-                        239u8, 0u8, 0u8, 0u8, 0u8, 0u8, 21u8, 0u8, 40u8, 0u8, 56u8, 0u8, 75u8, 0u8,
-                        94u8, 0u8, 150u8, 0u8, 172u8, 0u8, 193u8, 0u8, 215u8, 0u8, 234u8, 0u8,
-                        253u8, 0u8, 16u8, 1u8, 37u8, 1u8, 57u8, 1u8, 79u8, 1u8, 99u8, 1u8, 120u8,
-                        1u8, 142u8, 1u8, 162u8, 1u8, 183u8, 1u8, 227u8, 1u8, 248u8, 1u8, 12u8, 2u8,
-                        32u8, 2u8, 53u8, 2u8, 75u8, 2u8, 97u8, 2u8, 119u8, 2u8, 142u8, 2u8, 164u8,
-                        2u8, 184u8, 2u8, 204u8, 2u8, 222u8, 2u8, 241u8, 2u8, 5u8, 3u8, 21u8, 3u8,
-                        40u8, 3u8, 58u8, 3u8, 75u8, 3u8, 95u8, 3u8, 115u8, 3u8, 134u8, 3u8, 153u8,
-                        3u8, 173u8, 3u8, 194u8, 3u8, 214u8, 3u8, 234u8, 3u8, 250u8, 3u8, 12u8, 4u8,
-                        31u8, 4u8, 47u8, 4u8, 67u8, 4u8, 87u8, 4u8, 107u8, 4u8, 128u8, 4u8, 147u8,
-                        4u8, 166u8, 4u8, 186u8, 4u8, 205u8, 4u8, 225u8, 4u8, 246u8, 4u8, 12u8, 5u8,
-                        31u8, 5u8, 50u8, 5u8, 70u8, 5u8, 90u8, 5u8, 111u8, 5u8, 130u8, 5u8, 150u8,
-                        5u8, 169u8, 5u8, 190u8, 5u8, 212u8, 5u8, 233u8, 5u8, 252u8, 5u8, 15u8, 6u8,
-                        34u8, 6u8, 35u8, 6u8, 54u8, 6u8, 73u8, 6u8, 92u8, 6u8, 112u8, 6u8, 131u8,
-                        6u8, 148u8, 6u8, 169u8, 6u8, 190u8, 6u8, 208u8, 6u8, 231u8, 6u8, 248u8,
-                        6u8, 13u8, 7u8, 33u8, 7u8, 52u8, 7u8, 70u8, 7u8, 90u8, 7u8, 108u8, 7u8,
-                        126u8, 7u8, 147u8, 7u8, 168u8, 7u8, 187u8, 7u8, 206u8, 7u8, 227u8, 7u8,
-                        244u8, 7u8, 6u8, 8u8, 24u8, 8u8, 43u8, 8u8, 62u8, 8u8, 79u8, 8u8, 98u8,
-                        8u8, 119u8, 8u8, 139u8, 8u8, 161u8, 8u8, 183u8, 8u8, 203u8, 8u8, 222u8,
-                        8u8, 241u8, 8u8, 5u8, 9u8, 22u8, 9u8, 42u8, 9u8, 62u8, 9u8, 81u8, 9u8,
-                        101u8, 9u8, 122u8, 9u8, 143u8, 9u8, 161u8, 9u8, 181u8, 9u8, 202u8, 9u8,
-                        222u8, 9u8, 241u8, 9u8, 4u8, 10u8, 25u8, 10u8, 44u8, 10u8, 61u8, 10u8,
-                        82u8, 10u8, 104u8, 10u8, 124u8, 10u8, 143u8, 10u8, 164u8, 10u8, 183u8,
-                        10u8, 203u8, 10u8, 223u8, 10u8, 245u8, 10u8, 10u8, 11u8, 29u8, 11u8, 50u8,
-                        11u8, 70u8, 11u8, 90u8, 11u8, 108u8, 11u8, 127u8, 11u8, 146u8, 11u8, 165u8,
-                        11u8, 186u8, 11u8, 204u8, 11u8, 223u8, 11u8, 245u8, 11u8, 10u8, 12u8, 29u8,
-                        12u8, 48u8, 12u8, 69u8, 12u8, 91u8, 12u8, 113u8, 12u8, 133u8, 12u8, 153u8,
-                        12u8, 173u8, 12u8, 195u8, 12u8, 214u8, 12u8, 233u8, 12u8, 234u8, 12u8,
-                        253u8, 12u8, 18u8, 13u8, 39u8, 13u8, 60u8, 13u8, 79u8, 13u8, 101u8, 13u8,
-                        120u8, 13u8, 137u8, 13u8, 154u8, 13u8, 174u8, 13u8, 194u8, 13u8, 212u8,
-                        13u8, 230u8, 13u8, 249u8, 13u8, 13u8, 14u8, 29u8, 14u8, 46u8, 14u8, 47u8,
-                        14u8, 69u8, 14u8, 87u8, 14u8, 107u8, 14u8, 127u8, 14u8, 149u8, 14u8, 171u8,
-                        14u8, 192u8, 14u8, 213u8, 14u8, 233u8, 14u8, 253u8, 14u8, 17u8, 15u8, 37u8,
-                        15u8, 57u8, 15u8, 80u8, 15u8, 101u8, 15u8, 120u8, 15u8, 142u8, 15u8, 143u8,
-                        15u8, 163u8, 15u8, 182u8, 15u8, 201u8, 15u8, 219u8, 15u8, 236u8, 15u8,
-                        237u8, 15u8, 255u8, 15u8, 19u8, 16u8, 38u8, 16u8, 58u8, 16u8, 78u8, 16u8,
-                        96u8, 16u8, 115u8, 16u8, 134u8, 16u8, 153u8, 16u8, 172u8, 16u8, 192u8,
-                        16u8, 211u8, 16u8, 231u8, 16u8, 249u8, 16u8, 13u8, 17u8, 34u8, 17u8, 53u8,
-                        17u8, 78u8, 17u8, 103u8, 17u8, 129u8, 17u8, 156u8, 17u8, 175u8, 17u8,
-                        195u8, 17u8, 216u8, 17u8, 237u8, 17u8, 0u8, 18u8, 23u8, 18u8, 43u8, 18u8,
-                        60u8, 18u8, 78u8, 18u8, 65u8, 110u8, 226u8, 128u8, 153u8, 101u8, 105u8,
-                        32u8, 40u8, 49u8, 55u8, 55u8, 50u8, 226u8, 128u8, 147u8, 49u8, 55u8, 56u8,
-                        49u8, 41u8, 65u8, 110u8, 103u8, 101u8, 110u8, 32u8, 40u8, 49u8, 49u8, 55u8,
-                        53u8, 226u8, 128u8, 147u8, 49u8, 49u8, 55u8, 55u8, 41u8, 65u8, 110u8,
-                        110u8, 97u8, 32u8, 40u8, 57u8, 54u8, 56u8, 226u8, 128u8, 147u8, 57u8, 55u8,
-                        48u8, 41u8, 65u8, 110u8, 115u8, 101u8, 105u8, 32u8, 40u8, 49u8, 56u8, 53u8,
-                        52u8, 226u8, 128u8, 147u8, 49u8, 56u8, 54u8, 48u8, 41u8, 65u8, 110u8,
-                        116u8, 101u8, 105u8, 32u8, 40u8, 49u8, 50u8, 50u8, 55u8, 226u8, 128u8,
-                        147u8, 49u8, 50u8, 50u8, 57u8, 41u8, 240u8, 145u8, 132u8, 136u8, 240u8,
-                        145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8, 132u8,
-                        168u8, 240u8, 145u8, 132u8, 140u8, 240u8, 145u8, 132u8, 180u8, 240u8,
-                        145u8, 132u8, 145u8, 240u8, 145u8, 132u8, 180u8, 240u8, 145u8, 132u8,
-                        155u8, 240u8, 145u8, 132u8, 171u8, 240u8, 145u8, 132u8, 162u8, 240u8,
-                        145u8, 132u8, 180u8, 240u8, 145u8, 132u8, 157u8, 240u8, 145u8, 132u8,
-                        167u8, 66u8, 117u8, 110u8, 226u8, 128u8, 153u8, 97u8, 110u8, 32u8, 40u8,
-                        49u8, 52u8, 52u8, 52u8, 226u8, 128u8, 147u8, 49u8, 52u8, 52u8, 57u8, 41u8,
-                        66u8, 117u8, 110u8, 99u8, 104u8, 197u8, 171u8, 32u8, 40u8, 49u8, 51u8,
-                        55u8, 50u8, 226u8, 128u8, 147u8, 49u8, 51u8, 55u8, 53u8, 41u8, 66u8, 117u8,
-                        110u8, 226u8, 128u8, 153u8, 101u8, 105u8, 32u8, 40u8, 49u8, 50u8, 54u8,
-                        52u8, 226u8, 128u8, 147u8, 49u8, 50u8, 55u8, 53u8, 41u8, 66u8, 117u8,
-                        110u8, 106u8, 105u8, 32u8, 40u8, 49u8, 49u8, 56u8, 53u8, 226u8, 128u8,
-                        147u8, 49u8, 49u8, 57u8, 48u8, 41u8, 66u8, 117u8, 110u8, 107u8, 97u8, 32u8,
-                        40u8, 49u8, 56u8, 48u8, 52u8, 226u8, 128u8, 147u8, 49u8, 56u8, 49u8, 56u8,
-                        41u8, 66u8, 117u8, 110u8, 107u8, 105u8, 32u8, 40u8, 49u8, 53u8, 48u8, 49u8,
-                        226u8, 128u8, 147u8, 49u8, 53u8, 48u8, 52u8, 41u8, 66u8, 117u8, 110u8,
-                        107u8, 121u8, 197u8, 171u8, 32u8, 40u8, 49u8, 56u8, 54u8, 49u8, 226u8,
-                        128u8, 147u8, 49u8, 56u8, 54u8, 52u8, 41u8, 66u8, 117u8, 110u8, 109u8,
-                        101u8, 105u8, 32u8, 40u8, 49u8, 52u8, 54u8, 57u8, 226u8, 128u8, 147u8,
-                        49u8, 52u8, 56u8, 55u8, 41u8, 66u8, 117u8, 110u8, 226u8, 128u8, 153u8,
-                        197u8, 141u8, 32u8, 40u8, 49u8, 50u8, 54u8, 48u8, 226u8, 128u8, 147u8,
-                        49u8, 50u8, 54u8, 49u8, 41u8, 66u8, 117u8, 110u8, 112u8, 197u8, 141u8,
-                        32u8, 40u8, 49u8, 51u8, 49u8, 55u8, 226u8, 128u8, 147u8, 49u8, 51u8, 49u8,
-                        57u8, 41u8, 66u8, 117u8, 110u8, 114u8, 111u8, 107u8, 117u8, 32u8, 40u8,
-                        49u8, 53u8, 57u8, 50u8, 226u8, 128u8, 147u8, 49u8, 53u8, 57u8, 54u8, 41u8,
-                        66u8, 117u8, 110u8, 114u8, 121u8, 97u8, 107u8, 117u8, 32u8, 40u8, 49u8,
-                        50u8, 51u8, 52u8, 226u8, 128u8, 147u8, 49u8, 50u8, 51u8, 53u8, 41u8, 66u8,
-                        117u8, 110u8, 115u8, 101u8, 105u8, 32u8, 40u8, 49u8, 56u8, 49u8, 56u8,
-                        226u8, 128u8, 147u8, 49u8, 56u8, 51u8, 48u8, 41u8, 66u8, 117u8, 110u8,
-                        115u8, 104u8, 197u8, 141u8, 32u8, 40u8, 49u8, 52u8, 54u8, 54u8, 226u8,
-                        128u8, 147u8, 49u8, 52u8, 54u8, 55u8, 41u8, 240u8, 145u8, 132u8, 136u8,
-                        240u8, 145u8, 132u8, 179u8, 240u8, 145u8, 132u8, 162u8, 240u8, 145u8,
-                        132u8, 168u8, 240u8, 145u8, 132u8, 140u8, 240u8, 145u8, 132u8, 180u8,
-                        240u8, 145u8, 132u8, 145u8, 240u8, 145u8, 132u8, 155u8, 240u8, 145u8,
-                        132u8, 180u8, 240u8, 145u8, 132u8, 152u8, 240u8, 145u8, 132u8, 167u8, 67u8,
-                        104u8, 197u8, 141u8, 103u8, 101u8, 110u8, 32u8, 40u8, 49u8, 48u8, 50u8,
-                        56u8, 226u8, 128u8, 147u8, 49u8, 48u8, 51u8, 55u8, 41u8, 67u8, 104u8,
-                        197u8, 141u8, 104u8, 197u8, 141u8, 32u8, 40u8, 57u8, 57u8, 57u8, 226u8,
-                        128u8, 147u8, 49u8, 48u8, 48u8, 52u8, 41u8, 67u8, 104u8, 197u8, 141u8,
-                        106u8, 105u8, 32u8, 40u8, 49u8, 49u8, 48u8, 52u8, 226u8, 128u8, 147u8,
-                        49u8, 49u8, 48u8, 54u8, 41u8, 67u8, 104u8, 197u8, 141u8, 107u8, 97u8,
-                        110u8, 32u8, 40u8, 49u8, 49u8, 54u8, 51u8, 226u8, 128u8, 147u8, 49u8, 49u8,
-                        54u8, 53u8, 41u8, 67u8, 104u8, 197u8, 141u8, 107u8, 121u8, 197u8, 141u8,
-                        32u8, 40u8, 49u8, 52u8, 56u8, 55u8, 226u8, 128u8, 147u8, 49u8, 52u8, 56u8,
-                        57u8, 41u8, 67u8, 104u8, 197u8, 141u8, 107u8, 121u8, 197u8, 171u8, 32u8,
-                        40u8, 49u8, 48u8, 52u8, 48u8, 226u8, 128u8, 147u8, 49u8, 48u8, 52u8, 52u8,
-                        41u8, 67u8, 104u8, 197u8, 141u8, 114u8, 111u8, 107u8, 117u8, 32u8, 40u8,
-                        49u8, 52u8, 53u8, 55u8, 226u8, 128u8, 147u8, 49u8, 52u8, 54u8, 48u8, 41u8,
-                        67u8, 104u8, 197u8, 141u8, 114u8, 121u8, 97u8, 107u8, 117u8, 32u8, 40u8,
-                        49u8, 48u8, 51u8, 55u8, 226u8, 128u8, 147u8, 49u8, 48u8, 52u8, 48u8, 41u8,
-                        67u8, 104u8, 197u8, 141u8, 115u8, 104u8, 197u8, 141u8, 32u8, 40u8, 49u8,
-                        49u8, 51u8, 50u8, 226u8, 128u8, 147u8, 49u8, 49u8, 51u8, 53u8, 41u8, 67u8,
-                        104u8, 197u8, 141u8, 116u8, 111u8, 107u8, 117u8, 32u8, 40u8, 57u8, 57u8,
-                        53u8, 226u8, 128u8, 147u8, 57u8, 57u8, 57u8, 41u8, 67u8, 104u8, 197u8,
-                        141u8, 119u8, 97u8, 32u8, 40u8, 49u8, 48u8, 49u8, 50u8, 226u8, 128u8,
-                        147u8, 49u8, 48u8, 49u8, 55u8, 41u8, 68u8, 97u8, 105u8, 100u8, 197u8,
-                        141u8, 32u8, 40u8, 56u8, 48u8, 54u8, 226u8, 128u8, 147u8, 56u8, 49u8, 48u8,
-                        41u8, 68u8, 97u8, 105u8, 106u8, 105u8, 32u8, 40u8, 49u8, 49u8, 50u8, 54u8,
-                        226u8, 128u8, 147u8, 49u8, 49u8, 51u8, 49u8, 41u8, 69u8, 105u8, 99u8,
-                        104u8, 197u8, 141u8, 32u8, 40u8, 49u8, 48u8, 57u8, 54u8, 226u8, 128u8,
-                        147u8, 49u8, 48u8, 57u8, 55u8, 41u8, 69u8, 105u8, 101u8, 110u8, 32u8, 40u8,
-                        57u8, 56u8, 55u8, 226u8, 128u8, 147u8, 57u8, 56u8, 57u8, 41u8, 69u8, 105u8,
-                        104u8, 197u8, 141u8, 32u8, 40u8, 49u8, 48u8, 56u8, 49u8, 226u8, 128u8,
-                        147u8, 49u8, 48u8, 56u8, 52u8, 41u8, 69u8, 105u8, 106u8, 105u8, 32u8, 40u8,
-                        49u8, 49u8, 52u8, 49u8, 226u8, 128u8, 147u8, 49u8, 49u8, 52u8, 50u8, 41u8,
-                        69u8, 105u8, 107u8, 97u8, 110u8, 32u8, 40u8, 57u8, 56u8, 51u8, 226u8,
-                        128u8, 147u8, 57u8, 56u8, 53u8, 41u8, 69u8, 105u8, 107u8, 121u8, 197u8,
-                        141u8, 32u8, 40u8, 49u8, 52u8, 50u8, 57u8, 226u8, 128u8, 147u8, 49u8, 52u8,
-                        52u8, 49u8, 41u8, 69u8, 105u8, 107u8, 121u8, 197u8, 171u8, 32u8, 40u8,
-                        49u8, 49u8, 49u8, 51u8, 226u8, 128u8, 147u8, 49u8, 49u8, 49u8, 56u8, 41u8,
-                        69u8, 105u8, 109u8, 97u8, 110u8, 32u8, 40u8, 49u8, 49u8, 54u8, 53u8, 226u8,
-                        128u8, 147u8, 49u8, 49u8, 54u8, 54u8, 41u8, 69u8, 105u8, 110u8, 105u8,
-                        110u8, 32u8, 40u8, 49u8, 50u8, 57u8, 51u8, 226u8, 128u8, 147u8, 49u8, 50u8,
-                        57u8, 57u8, 41u8, 69u8, 105u8, 114u8, 111u8, 107u8, 117u8, 32u8, 40u8,
-                        49u8, 53u8, 53u8, 56u8, 226u8, 128u8, 147u8, 49u8, 53u8, 55u8, 48u8, 41u8,
-                        69u8, 105u8, 114u8, 121u8, 97u8, 107u8, 117u8, 32u8, 40u8, 49u8, 49u8,
-                        54u8, 48u8, 226u8, 128u8, 147u8, 49u8, 49u8, 54u8, 49u8, 41u8, 69u8, 105u8,
-                        115u8, 104u8, 197u8, 141u8, 32u8, 40u8, 49u8, 48u8, 52u8, 54u8, 226u8,
-                        128u8, 147u8, 49u8, 48u8, 53u8, 51u8, 41u8, 69u8, 105u8, 115u8, 104u8,
-                        197u8, 141u8, 32u8, 40u8, 49u8, 53u8, 48u8, 52u8, 226u8, 128u8, 147u8,
-                        49u8, 53u8, 50u8, 49u8, 41u8, 69u8, 105u8, 115u8, 111u8, 32u8, 40u8, 57u8,
-                        56u8, 57u8, 226u8, 128u8, 147u8, 57u8, 57u8, 48u8, 41u8, 69u8, 110u8, 99u8,
-                        104u8, 197u8, 141u8, 32u8, 40u8, 57u8, 50u8, 51u8, 226u8, 128u8, 147u8,
-                        57u8, 51u8, 49u8, 41u8, 69u8, 110u8, 103u8, 101u8, 110u8, 32u8, 40u8, 49u8,
-                        51u8, 51u8, 54u8, 226u8, 128u8, 147u8, 49u8, 51u8, 52u8, 48u8, 41u8, 69u8,
-                        110u8, 103u8, 105u8, 32u8, 40u8, 57u8, 48u8, 49u8, 226u8, 128u8, 147u8,
-                        57u8, 50u8, 51u8, 41u8, 69u8, 110u8, 107u8, 121u8, 197u8, 141u8, 32u8,
-                        40u8, 49u8, 51u8, 48u8, 56u8, 226u8, 128u8, 147u8, 49u8, 51u8, 49u8, 49u8,
-                        41u8, 69u8, 110u8, 107u8, 121u8, 197u8, 141u8, 32u8, 40u8, 49u8, 55u8,
-                        52u8, 52u8, 226u8, 128u8, 147u8, 49u8, 55u8, 52u8, 56u8, 41u8, 69u8, 110u8,
-                        107u8, 121u8, 197u8, 171u8, 32u8, 40u8, 49u8, 48u8, 54u8, 57u8, 226u8,
-                        128u8, 147u8, 49u8, 48u8, 55u8, 52u8, 41u8, 69u8, 110u8, 226u8, 128u8,
-                        153u8, 197u8, 141u8, 32u8, 40u8, 49u8, 50u8, 51u8, 57u8, 226u8, 128u8,
-                        147u8, 49u8, 50u8, 52u8, 48u8, 41u8, 69u8, 110u8, 112u8, 197u8, 141u8,
-                        32u8, 40u8, 49u8, 54u8, 55u8, 51u8, 226u8, 128u8, 147u8, 49u8, 54u8, 56u8,
-                        49u8, 41u8, 69u8, 110u8, 114u8, 121u8, 97u8, 107u8, 117u8, 32u8, 40u8,
-                        55u8, 56u8, 50u8, 226u8, 128u8, 147u8, 56u8, 48u8, 54u8, 41u8, 69u8, 110u8,
-                        116u8, 111u8, 107u8, 117u8, 32u8, 40u8, 49u8, 52u8, 56u8, 57u8, 226u8,
-                        128u8, 147u8, 49u8, 52u8, 57u8, 50u8, 41u8, 71u8, 97u8, 110u8, 103u8,
-                        121u8, 197u8, 141u8, 32u8, 40u8, 56u8, 55u8, 55u8, 226u8, 128u8, 147u8,
-                        56u8, 56u8, 53u8, 41u8, 71u8, 101u8, 110u8, 98u8, 117u8, 110u8, 32u8, 40u8,
-                        49u8, 55u8, 51u8, 54u8, 226u8, 128u8, 147u8, 49u8, 55u8, 52u8, 49u8, 41u8,
-                        71u8, 101u8, 110u8, 99u8, 104u8, 197u8, 171u8, 32u8, 40u8, 49u8, 51u8,
-                        56u8, 52u8, 226u8, 128u8, 147u8, 49u8, 51u8, 57u8, 50u8, 41u8, 71u8, 101u8,
-                        110u8, 226u8, 128u8, 153u8, 101u8, 105u8, 32u8, 40u8, 49u8, 49u8, 49u8,
-                        56u8, 226u8, 128u8, 147u8, 49u8, 49u8, 50u8, 48u8, 41u8, 71u8, 101u8,
-                        110u8, 106u8, 105u8, 32u8, 40u8, 49u8, 56u8, 54u8, 52u8, 226u8, 128u8,
-                        147u8, 49u8, 56u8, 54u8, 53u8, 41u8, 71u8, 101u8, 110u8, 107u8, 105u8,
-                        32u8, 40u8, 49u8, 53u8, 55u8, 48u8, 226u8, 128u8, 147u8, 49u8, 53u8, 55u8,
-                        51u8, 41u8, 71u8, 101u8, 110u8, 107u8, 197u8, 141u8, 32u8, 40u8, 49u8,
-                        51u8, 50u8, 49u8, 226u8, 128u8, 147u8, 49u8, 51u8, 50u8, 52u8, 41u8, 71u8,
-                        101u8, 110u8, 107u8, 197u8, 141u8, 32u8, 40u8, 49u8, 51u8, 51u8, 49u8,
-                        226u8, 128u8, 147u8, 49u8, 51u8, 51u8, 52u8, 41u8, 71u8, 101u8, 110u8,
-                        107u8, 121u8, 197u8, 171u8, 32u8, 40u8, 49u8, 50u8, 48u8, 52u8, 226u8,
-                        128u8, 147u8, 49u8, 50u8, 48u8, 54u8, 41u8, 71u8, 101u8, 110u8, 110u8,
-                        97u8, 32u8, 40u8, 49u8, 54u8, 49u8, 53u8, 226u8, 128u8, 147u8, 49u8, 54u8,
-                        50u8, 52u8, 41u8, 71u8, 101u8, 110u8, 110u8, 105u8, 110u8, 32u8, 40u8,
-                        49u8, 50u8, 50u8, 52u8, 226u8, 128u8, 147u8, 49u8, 50u8, 50u8, 53u8, 41u8,
-                        71u8, 101u8, 110u8, 197u8, 141u8, 32u8, 40u8, 49u8, 51u8, 49u8, 57u8,
-                        226u8, 128u8, 147u8, 49u8, 51u8, 50u8, 49u8, 41u8, 71u8, 101u8, 110u8,
-                        114u8, 111u8, 107u8, 117u8, 32u8, 40u8, 49u8, 54u8, 56u8, 56u8, 226u8,
-                        128u8, 147u8, 49u8, 55u8, 48u8, 52u8, 41u8, 71u8, 101u8, 110u8, 114u8,
-                        121u8, 97u8, 107u8, 117u8, 32u8, 40u8, 49u8, 49u8, 56u8, 52u8, 226u8,
-                        128u8, 147u8, 49u8, 49u8, 56u8, 53u8, 41u8, 71u8, 101u8, 110u8, 116u8,
-                        111u8, 107u8, 117u8, 32u8, 40u8, 49u8, 51u8, 50u8, 57u8, 226u8, 128u8,
-                        147u8, 49u8, 51u8, 51u8, 49u8, 41u8, 72u8, 97u8, 107u8, 117u8, 99u8, 104u8,
-                        105u8, 32u8, 40u8, 54u8, 53u8, 48u8, 226u8, 128u8, 147u8, 54u8, 55u8, 49u8,
-                        41u8, 72u8, 97u8, 107u8, 117u8, 104u8, 197u8, 141u8, 32u8, 40u8, 54u8,
-                        55u8, 50u8, 226u8, 128u8, 147u8, 54u8, 56u8, 54u8, 41u8, 72u8, 101u8,
-                        105u8, 106u8, 105u8, 32u8, 40u8, 49u8, 49u8, 53u8, 57u8, 226u8, 128u8,
-                        147u8, 49u8, 49u8, 54u8, 48u8, 41u8, 72u8, 72u8, 197u8, 141u8, 97u8, 110u8,
-                        32u8, 40u8, 49u8, 49u8, 50u8, 48u8, 226u8, 128u8, 147u8, 49u8, 49u8, 50u8,
-                        52u8, 41u8, 72u8, 197u8, 141u8, 101u8, 105u8, 32u8, 40u8, 49u8, 55u8, 48u8,
-                        52u8, 226u8, 128u8, 147u8, 49u8, 55u8, 49u8, 49u8, 41u8, 72u8, 197u8,
-                        141u8, 101u8, 110u8, 32u8, 40u8, 49u8, 49u8, 51u8, 53u8, 226u8, 128u8,
-                        147u8, 49u8, 49u8, 52u8, 49u8, 41u8, 72u8, 197u8, 141u8, 103u8, 101u8,
-                        110u8, 32u8, 40u8, 49u8, 49u8, 53u8, 54u8, 226u8, 128u8, 147u8, 49u8, 49u8,
-                        53u8, 57u8, 41u8, 72u8, 197u8, 141u8, 106u8, 105u8, 32u8, 40u8, 49u8, 50u8,
-                        52u8, 55u8, 226u8, 128u8, 147u8, 49u8, 50u8, 52u8, 57u8, 41u8, 72u8, 197u8,
-                        141u8, 107u8, 105u8, 32u8, 40u8, 55u8, 55u8, 48u8, 226u8, 128u8, 147u8,
-                        55u8, 56u8, 48u8, 41u8, 72u8, 197u8, 141u8, 114u8, 101u8, 107u8, 105u8,
-                        32u8, 40u8, 49u8, 55u8, 53u8, 49u8, 226u8, 128u8, 147u8, 49u8, 55u8, 54u8,
-                        52u8, 41u8, 72u8, 197u8, 141u8, 116u8, 111u8, 107u8, 117u8, 32u8, 40u8,
-                        49u8, 52u8, 52u8, 57u8, 226u8, 128u8, 147u8, 49u8, 52u8, 53u8, 50u8, 41u8,
-                        74u8, 105u8, 97u8, 110u8, 32u8, 40u8, 49u8, 48u8, 50u8, 49u8, 226u8, 128u8,
-                        147u8, 49u8, 48u8, 50u8, 52u8, 41u8, 74u8, 105u8, 110u8, 103u8, 111u8,
-                        45u8, 107u8, 101u8, 105u8, 117u8, 110u8, 32u8, 40u8, 55u8, 54u8, 55u8,
-                        226u8, 128u8, 147u8, 55u8, 55u8, 48u8, 41u8, 74u8, 105u8, 110u8, 107u8,
-                        105u8, 32u8, 40u8, 55u8, 50u8, 52u8, 226u8, 128u8, 147u8, 55u8, 50u8, 57u8,
-                        41u8, 74u8, 105u8, 114u8, 121u8, 97u8, 107u8, 117u8, 32u8, 40u8, 49u8,
-                        48u8, 54u8, 53u8, 226u8, 128u8, 147u8, 49u8, 48u8, 54u8, 57u8, 41u8, 74u8,
-                        105u8, 115u8, 104u8, 197u8, 141u8, 32u8, 40u8, 49u8, 49u8, 55u8, 55u8,
-                        226u8, 128u8, 147u8, 49u8, 49u8, 56u8, 49u8, 41u8, 74u8, 197u8, 141u8,
-                        101u8, 105u8, 32u8, 40u8, 49u8, 50u8, 51u8, 50u8, 226u8, 128u8, 147u8,
-                        49u8, 50u8, 51u8, 51u8, 41u8, 74u8, 197u8, 141u8, 103u8, 97u8, 110u8, 32u8,
-                        40u8, 56u8, 53u8, 57u8, 226u8, 128u8, 147u8, 56u8, 55u8, 55u8, 41u8, 74u8,
-                        197u8, 141u8, 103u8, 101u8, 110u8, 32u8, 40u8, 49u8, 50u8, 48u8, 55u8,
-                        226u8, 128u8, 147u8, 49u8, 50u8, 49u8, 49u8, 41u8, 74u8, 197u8, 141u8,
-                        103u8, 101u8, 110u8, 32u8, 40u8, 57u8, 55u8, 54u8, 226u8, 128u8, 147u8,
-                        57u8, 55u8, 56u8, 41u8, 74u8, 197u8, 141u8, 104u8, 101u8, 105u8, 32u8,
-                        40u8, 57u8, 51u8, 49u8, 226u8, 128u8, 147u8, 57u8, 51u8, 56u8, 41u8, 74u8,
-                        197u8, 141u8, 107u8, 121u8, 197u8, 141u8, 32u8, 40u8, 49u8, 54u8, 56u8,
-                        52u8, 226u8, 128u8, 147u8, 49u8, 54u8, 56u8, 56u8, 41u8, 74u8, 197u8,
-                        141u8, 107u8, 121u8, 197u8, 171u8, 32u8, 40u8, 49u8, 50u8, 49u8, 57u8,
-                        226u8, 128u8, 147u8, 49u8, 50u8, 50u8, 50u8, 41u8, 74u8, 197u8, 141u8,
-                        197u8, 141u8, 32u8, 40u8, 49u8, 50u8, 50u8, 50u8, 226u8, 128u8, 147u8,
-                        49u8, 50u8, 50u8, 52u8, 41u8, 74u8, 197u8, 141u8, 197u8, 141u8, 32u8, 40u8,
-                        49u8, 54u8, 53u8, 50u8, 226u8, 128u8, 147u8, 49u8, 54u8, 53u8, 53u8, 41u8,
-                        74u8, 197u8, 141u8, 116u8, 111u8, 107u8, 117u8, 32u8, 40u8, 49u8, 48u8,
-                        57u8, 55u8, 226u8, 128u8, 147u8, 49u8, 48u8, 57u8, 57u8, 41u8, 74u8, 197u8,
-                        141u8, 119u8, 97u8, 32u8, 40u8, 56u8, 51u8, 52u8, 226u8, 128u8, 147u8,
-                        56u8, 52u8, 56u8, 41u8, 74u8, 117u8, 101u8, 105u8, 32u8, 40u8, 49u8, 49u8,
-                        56u8, 50u8, 226u8, 128u8, 147u8, 49u8, 49u8, 56u8, 52u8, 41u8, 75u8, 97u8,
-                        101u8, 105u8, 32u8, 40u8, 49u8, 56u8, 52u8, 56u8, 226u8, 128u8, 147u8,
-                        49u8, 56u8, 53u8, 52u8, 41u8, 75u8, 97u8, 103u8, 101u8, 110u8, 32u8, 40u8,
-                        49u8, 51u8, 48u8, 51u8, 226u8, 128u8, 147u8, 49u8, 51u8, 48u8, 54u8, 41u8,
-                        75u8, 97u8, 104u8, 197u8, 141u8, 32u8, 40u8, 49u8, 48u8, 57u8, 52u8, 226u8,
-                        128u8, 147u8, 49u8, 48u8, 57u8, 54u8, 41u8, 75u8, 97u8, 106u8, 197u8,
-                        141u8, 32u8, 40u8, 56u8, 52u8, 56u8, 226u8, 128u8, 147u8, 56u8, 53u8, 49u8,
-                        41u8, 75u8, 97u8, 107u8, 101u8, 105u8, 32u8, 40u8, 49u8, 51u8, 56u8, 55u8,
-                        226u8, 128u8, 147u8, 49u8, 51u8, 56u8, 57u8, 41u8, 75u8, 97u8, 107u8,
-                        105u8, 116u8, 115u8, 117u8, 32u8, 40u8, 49u8, 52u8, 52u8, 49u8, 226u8,
-                        128u8, 147u8, 49u8, 52u8, 52u8, 52u8, 41u8, 75u8, 97u8, 110u8, 98u8, 117u8,
-                        110u8, 32u8, 40u8, 49u8, 54u8, 54u8, 49u8, 226u8, 128u8, 147u8, 49u8, 54u8,
-                        55u8, 51u8, 41u8, 75u8, 97u8, 110u8, 226u8, 128u8, 153u8, 101u8, 105u8,
-                        32u8, 40u8, 49u8, 54u8, 50u8, 52u8, 226u8, 128u8, 147u8, 49u8, 54u8, 52u8,
-                        52u8, 41u8, 75u8, 97u8, 110u8, 226u8, 128u8, 153u8, 101u8, 110u8, 32u8,
-                        40u8, 49u8, 55u8, 52u8, 56u8, 226u8, 128u8, 147u8, 49u8, 55u8, 53u8, 49u8,
-                        41u8, 75u8, 97u8, 110u8, 103u8, 101u8, 110u8, 32u8, 40u8, 49u8, 50u8, 52u8,
-                        51u8, 226u8, 128u8, 147u8, 49u8, 50u8, 52u8, 55u8, 41u8, 75u8, 97u8, 110u8,
-                        106u8, 105u8, 32u8, 40u8, 49u8, 48u8, 56u8, 55u8, 226u8, 128u8, 147u8,
-                        49u8, 48u8, 57u8, 52u8, 41u8, 75u8, 97u8, 110u8, 107u8, 105u8, 32u8, 40u8,
-                        49u8, 50u8, 50u8, 57u8, 226u8, 128u8, 147u8, 49u8, 50u8, 51u8, 50u8, 41u8,
-                        75u8, 97u8, 110u8, 107u8, 197u8, 141u8, 32u8, 40u8, 49u8, 48u8, 48u8, 52u8,
-                        226u8, 128u8, 147u8, 49u8, 48u8, 49u8, 50u8, 41u8, 75u8, 97u8, 110u8,
-                        110u8, 97u8, 32u8, 40u8, 57u8, 56u8, 53u8, 226u8, 128u8, 147u8, 57u8, 56u8,
-                        55u8, 41u8, 75u8, 97u8, 110u8, 110u8, 105u8, 110u8, 32u8, 40u8, 49u8, 48u8,
-                        49u8, 55u8, 226u8, 128u8, 147u8, 49u8, 48u8, 50u8, 49u8, 41u8, 75u8, 97u8,
-                        110u8, 112u8, 197u8, 141u8, 32u8, 40u8, 49u8, 55u8, 52u8, 49u8, 226u8,
-                        128u8, 147u8, 49u8, 55u8, 52u8, 52u8, 41u8, 75u8, 97u8, 110u8, 112u8,
-                        121u8, 197u8, 141u8, 32u8, 40u8, 56u8, 56u8, 57u8, 226u8, 128u8, 147u8,
-                        56u8, 57u8, 56u8, 41u8, 75u8, 97u8, 110u8, 115u8, 101u8, 105u8, 32u8, 40u8,
-                        49u8, 55u8, 56u8, 57u8, 226u8, 128u8, 147u8, 49u8, 56u8, 48u8, 49u8, 41u8,
-                        75u8, 97u8, 110u8, 115u8, 104u8, 197u8, 141u8, 32u8, 40u8, 49u8, 52u8,
-                        54u8, 48u8, 226u8, 128u8, 147u8, 49u8, 52u8, 54u8, 54u8, 41u8, 75u8, 97u8,
-                        110u8, 116u8, 111u8, 107u8, 117u8, 32u8, 40u8, 49u8, 48u8, 52u8, 52u8,
-                        226u8, 128u8, 147u8, 49u8, 48u8, 52u8, 54u8, 41u8, 75u8, 97u8, 197u8,
-                        141u8, 32u8, 40u8, 49u8, 49u8, 54u8, 57u8, 226u8, 128u8, 147u8, 49u8, 49u8,
-                        55u8, 49u8, 41u8, 75u8, 97u8, 114u8, 111u8, 107u8, 117u8, 32u8, 40u8, 49u8,
-                        50u8, 50u8, 53u8, 226u8, 128u8, 147u8, 49u8, 50u8, 50u8, 55u8, 41u8, 75u8,
-                        97u8, 114u8, 121u8, 97u8, 107u8, 117u8, 32u8, 40u8, 49u8, 51u8, 50u8, 54u8,
-                        226u8, 128u8, 147u8, 49u8, 51u8, 50u8, 57u8, 41u8, 75u8, 97u8, 115u8,
-                        104u8, 197u8, 141u8, 32u8, 40u8, 49u8, 49u8, 48u8, 54u8, 226u8, 128u8,
-                        147u8, 49u8, 49u8, 48u8, 56u8, 41u8, 75u8, 97u8, 116u8, 101u8, 105u8, 32u8,
-                        40u8, 49u8, 50u8, 51u8, 53u8, 226u8, 128u8, 147u8, 49u8, 50u8, 51u8, 56u8,
-                        41u8, 75u8, 101u8, 105u8, 97u8, 110u8, 32u8, 40u8, 49u8, 54u8, 52u8, 56u8,
-                        226u8, 128u8, 147u8, 49u8, 54u8, 53u8, 50u8, 41u8, 75u8, 101u8, 105u8,
-                        99u8, 104u8, 197u8, 141u8, 32u8, 40u8, 49u8, 53u8, 57u8, 54u8, 226u8,
-                        128u8, 147u8, 49u8, 54u8, 49u8, 53u8, 41u8, 75u8, 101u8, 105u8, 197u8,
-                        141u8, 32u8, 40u8, 49u8, 56u8, 54u8, 53u8, 226u8, 128u8, 147u8, 49u8, 56u8,
-                        54u8, 56u8, 41u8, 75u8, 101u8, 105u8, 117u8, 110u8, 32u8, 40u8, 55u8, 48u8,
-                        52u8, 226u8, 128u8, 147u8, 55u8, 48u8, 56u8, 41u8, 75u8, 101u8, 110u8,
-                        99u8, 104u8, 197u8, 141u8, 32u8, 40u8, 49u8, 50u8, 52u8, 57u8, 226u8,
-                        128u8, 147u8, 49u8, 50u8, 53u8, 54u8, 41u8, 75u8, 101u8, 110u8, 226u8,
-                        128u8, 153u8, 101u8, 105u8, 32u8, 40u8, 49u8, 50u8, 48u8, 54u8, 226u8,
-                        128u8, 147u8, 49u8, 50u8, 48u8, 55u8, 41u8, 75u8, 101u8, 110u8, 103u8,
-                        101u8, 110u8, 32u8, 40u8, 49u8, 51u8, 48u8, 50u8, 226u8, 128u8, 147u8,
-                        49u8, 51u8, 48u8, 51u8, 41u8, 75u8, 101u8, 110u8, 106u8, 105u8, 32u8, 40u8,
-                        49u8, 50u8, 55u8, 53u8, 226u8, 128u8, 147u8, 49u8, 50u8, 55u8, 56u8, 41u8,
-                        75u8, 101u8, 110u8, 107u8, 121u8, 197u8, 171u8, 32u8, 40u8, 49u8, 49u8,
-                        57u8, 48u8, 226u8, 128u8, 147u8, 49u8, 49u8, 57u8, 57u8, 41u8, 75u8, 101u8,
-                        110u8, 109u8, 117u8, 32u8, 40u8, 49u8, 51u8, 51u8, 52u8, 226u8, 128u8,
-                        147u8, 49u8, 51u8, 51u8, 54u8, 41u8, 75u8, 101u8, 110u8, 110u8, 105u8,
-                        110u8, 32u8, 40u8, 49u8, 50u8, 48u8, 49u8, 226u8, 128u8, 147u8, 49u8, 50u8,
-                        48u8, 52u8, 41u8, 75u8, 101u8, 110u8, 112u8, 197u8, 141u8, 32u8, 40u8,
-                        49u8, 50u8, 49u8, 51u8, 226u8, 128u8, 147u8, 49u8, 50u8, 49u8, 57u8, 41u8,
-                        75u8, 101u8, 110u8, 114u8, 121u8, 97u8, 107u8, 117u8, 32u8, 40u8, 49u8,
-                        50u8, 49u8, 49u8, 226u8, 128u8, 147u8, 49u8, 50u8, 49u8, 51u8, 41u8, 75u8,
-                        101u8, 110u8, 116u8, 111u8, 107u8, 117u8, 32u8, 40u8, 49u8, 51u8, 55u8,
-                        48u8, 226u8, 128u8, 147u8, 49u8, 51u8, 55u8, 50u8, 41u8, 75u8, 197u8,
-                        141u8, 97u8, 110u8, 32u8, 40u8, 49u8, 50u8, 55u8, 56u8, 226u8, 128u8,
-                        147u8, 49u8, 50u8, 56u8, 56u8, 41u8, 75u8, 197u8, 141u8, 99u8, 104u8,
-                        197u8, 141u8, 32u8, 40u8, 49u8, 50u8, 54u8, 49u8, 226u8, 128u8, 147u8,
-                        49u8, 50u8, 54u8, 52u8, 41u8, 75u8, 197u8, 141u8, 103u8, 101u8, 110u8,
-                        32u8, 40u8, 49u8, 50u8, 53u8, 54u8, 226u8, 128u8, 147u8, 49u8, 50u8, 53u8,
-                        55u8, 41u8, 75u8, 197u8, 141u8, 104u8, 101u8, 105u8, 32u8, 40u8, 49u8,
-                        48u8, 53u8, 56u8, 226u8, 128u8, 147u8, 49u8, 48u8, 54u8, 53u8, 41u8, 75u8,
-                        197u8, 141u8, 104u8, 197u8, 141u8, 32u8, 40u8, 57u8, 54u8, 52u8, 226u8,
-                        128u8, 147u8, 57u8, 54u8, 56u8, 41u8, 75u8, 197u8, 141u8, 106u8, 105u8,
-                        32u8, 40u8, 49u8, 49u8, 52u8, 50u8, 226u8, 128u8, 147u8, 49u8, 49u8, 52u8,
-                        52u8, 41u8, 75u8, 197u8, 141u8, 106u8, 105u8, 32u8, 40u8, 49u8, 53u8, 53u8,
-                        53u8, 226u8, 128u8, 147u8, 49u8, 53u8, 53u8, 56u8, 41u8, 75u8, 197u8,
-                        141u8, 107u8, 97u8, 32u8, 40u8, 49u8, 56u8, 52u8, 52u8, 226u8, 128u8,
-                        147u8, 49u8, 56u8, 52u8, 56u8, 41u8, 75u8, 197u8, 141u8, 107u8, 111u8,
-                        107u8, 117u8, 32u8, 40u8, 49u8, 51u8, 52u8, 48u8, 226u8, 128u8, 147u8,
-                        49u8, 51u8, 52u8, 54u8, 41u8, 75u8, 197u8, 141u8, 110u8, 105u8, 110u8,
-                        32u8, 40u8, 56u8, 49u8, 48u8, 226u8, 128u8, 147u8, 56u8, 50u8, 52u8, 41u8,
-                        75u8, 197u8, 141u8, 197u8, 141u8, 32u8, 40u8, 49u8, 51u8, 56u8, 57u8,
-                        226u8, 128u8, 147u8, 49u8, 51u8, 57u8, 48u8, 41u8, 75u8, 197u8, 141u8,
-                        114u8, 121u8, 97u8, 107u8, 117u8, 32u8, 40u8, 49u8, 51u8, 55u8, 57u8,
-                        226u8, 128u8, 147u8, 49u8, 51u8, 56u8, 49u8, 41u8, 75u8, 197u8, 141u8,
-                        115u8, 104u8, 197u8, 141u8, 32u8, 40u8, 49u8, 52u8, 53u8, 53u8, 226u8,
-                        128u8, 147u8, 49u8, 52u8, 53u8, 55u8, 41u8, 75u8, 197u8, 141u8, 119u8,
-                        97u8, 32u8, 40u8, 49u8, 48u8, 57u8, 57u8, 226u8, 128u8, 147u8, 49u8, 49u8,
-                        48u8, 52u8, 41u8, 75u8, 197u8, 141u8, 119u8, 97u8, 32u8, 40u8, 49u8, 51u8,
-                        56u8, 49u8, 226u8, 128u8, 147u8, 49u8, 51u8, 56u8, 52u8, 41u8, 75u8, 121u8,
-                        197u8, 141u8, 104u8, 197u8, 141u8, 32u8, 40u8, 49u8, 55u8, 49u8, 54u8,
-                        226u8, 128u8, 147u8, 49u8, 55u8, 51u8, 54u8, 41u8, 75u8, 121u8, 197u8,
-                        141u8, 114u8, 111u8, 107u8, 117u8, 32u8, 40u8, 49u8, 53u8, 50u8, 56u8,
-                        226u8, 128u8, 147u8, 49u8, 53u8, 51u8, 50u8, 41u8, 75u8, 121u8, 197u8,
-                        141u8, 116u8, 111u8, 107u8, 117u8, 32u8, 40u8, 49u8, 52u8, 53u8, 50u8,
-                        226u8, 128u8, 147u8, 49u8, 52u8, 53u8, 53u8, 41u8, 75u8, 121u8, 197u8,
-                        141u8, 119u8, 97u8, 32u8, 40u8, 49u8, 56u8, 48u8, 49u8, 226u8, 128u8,
-                        147u8, 49u8, 56u8, 48u8, 52u8, 41u8, 75u8, 121u8, 197u8, 171u8, 97u8,
-                        110u8, 32u8, 40u8, 49u8, 49u8, 52u8, 53u8, 226u8, 128u8, 147u8, 49u8, 49u8,
-                        53u8, 49u8, 41u8, 75u8, 121u8, 197u8, 171u8, 106u8, 117u8, 32u8, 40u8,
-                        49u8, 49u8, 53u8, 52u8, 226u8, 128u8, 147u8, 49u8, 49u8, 53u8, 54u8, 41u8,
-                        77u8, 97u8, 110u8, 226u8, 128u8, 153u8, 101u8, 110u8, 32u8, 40u8, 49u8,
-                        56u8, 54u8, 48u8, 226u8, 128u8, 147u8, 49u8, 56u8, 54u8, 49u8, 41u8, 77u8,
-                        97u8, 110u8, 106u8, 105u8, 32u8, 40u8, 49u8, 54u8, 53u8, 56u8, 226u8,
-                        128u8, 147u8, 49u8, 54u8, 54u8, 49u8, 41u8, 77u8, 97u8, 110u8, 106u8,
-                        117u8, 32u8, 40u8, 49u8, 48u8, 50u8, 52u8, 226u8, 128u8, 147u8, 49u8, 48u8,
-                        50u8, 56u8, 41u8, 77u8, 77u8, 101u8, 105u8, 197u8, 141u8, 32u8, 40u8, 49u8,
-                        52u8, 57u8, 50u8, 226u8, 128u8, 147u8, 49u8, 53u8, 48u8, 49u8, 41u8, 77u8,
-                        101u8, 105u8, 114u8, 101u8, 107u8, 105u8, 32u8, 40u8, 49u8, 54u8, 53u8,
-                        53u8, 226u8, 128u8, 147u8, 49u8, 54u8, 53u8, 56u8, 41u8, 77u8, 101u8,
-                        105u8, 116u8, 111u8, 107u8, 117u8, 32u8, 40u8, 49u8, 51u8, 56u8, 52u8,
-                        226u8, 128u8, 147u8, 49u8, 51u8, 56u8, 55u8, 41u8, 77u8, 101u8, 105u8,
-                        116u8, 111u8, 107u8, 117u8, 32u8, 40u8, 49u8, 51u8, 57u8, 48u8, 226u8,
-                        128u8, 147u8, 49u8, 51u8, 57u8, 52u8, 41u8, 77u8, 101u8, 105u8, 119u8,
-                        97u8, 32u8, 40u8, 49u8, 55u8, 54u8, 52u8, 226u8, 128u8, 147u8, 49u8, 55u8,
-                        55u8, 50u8, 41u8, 78u8, 105u8, 110u8, 226u8, 128u8, 153u8, 97u8, 110u8,
-                        32u8, 40u8, 49u8, 49u8, 54u8, 54u8, 226u8, 128u8, 147u8, 49u8, 49u8, 54u8,
-                        57u8, 41u8, 78u8, 105u8, 110u8, 106u8, 105u8, 32u8, 40u8, 49u8, 50u8, 52u8,
-                        48u8, 226u8, 128u8, 147u8, 49u8, 50u8, 52u8, 51u8, 41u8, 78u8, 105u8,
-                        110u8, 106u8, 117u8, 32u8, 40u8, 56u8, 53u8, 49u8, 226u8, 128u8, 147u8,
-                        56u8, 53u8, 52u8, 41u8, 78u8, 105u8, 110u8, 110u8, 97u8, 32u8, 40u8, 56u8,
-                        56u8, 53u8, 226u8, 128u8, 147u8, 56u8, 56u8, 57u8, 41u8, 78u8, 105u8,
-                        110u8, 112u8, 101u8, 105u8, 32u8, 40u8, 49u8, 49u8, 53u8, 49u8, 226u8,
-                        128u8, 147u8, 49u8, 49u8, 53u8, 52u8, 41u8, 197u8, 140u8, 99u8, 104u8,
-                        197u8, 141u8, 32u8, 40u8, 49u8, 51u8, 49u8, 49u8, 226u8, 128u8, 147u8,
-                        49u8, 51u8, 49u8, 50u8, 41u8, 197u8, 140u8, 101u8, 105u8, 32u8, 40u8, 49u8,
-                        51u8, 57u8, 52u8, 226u8, 128u8, 147u8, 49u8, 52u8, 50u8, 56u8, 41u8, 197u8,
-                        140u8, 104u8, 111u8, 32u8, 40u8, 49u8, 49u8, 54u8, 49u8, 226u8, 128u8,
-                        147u8, 49u8, 49u8, 54u8, 51u8, 41u8, 197u8, 140u8, 110u8, 105u8, 110u8,
-                        32u8, 40u8, 49u8, 52u8, 54u8, 55u8, 226u8, 128u8, 147u8, 49u8, 52u8, 54u8,
-                        57u8, 41u8, 197u8, 140u8, 116u8, 111u8, 107u8, 117u8, 32u8, 40u8, 49u8,
-                        48u8, 56u8, 52u8, 226u8, 128u8, 147u8, 49u8, 48u8, 56u8, 55u8, 41u8, 197u8,
-                        140u8, 119u8, 97u8, 32u8, 40u8, 57u8, 54u8, 49u8, 226u8, 128u8, 147u8,
-                        57u8, 54u8, 52u8, 41u8, 82u8, 101u8, 105u8, 107u8, 105u8, 32u8, 40u8, 55u8,
-                        49u8, 53u8, 226u8, 128u8, 147u8, 55u8, 49u8, 55u8, 41u8, 82u8, 82u8, 121u8,
-                        97u8, 107u8, 117u8, 110u8, 105u8, 110u8, 32u8, 40u8, 49u8, 50u8, 51u8,
-                        56u8, 226u8, 128u8, 147u8, 49u8, 50u8, 51u8, 57u8, 41u8, 83u8, 97u8, 105u8,
+                        45u8, 57u8, 53u8, 55u8, 116u8, 101u8, 110u8, 121u8, 111u8, 45u8, 49u8,
+                        49u8, 52u8, 52u8, 116u8, 111u8, 107u8, 117u8, 106u8, 105u8, 45u8, 49u8,
+                        51u8, 48u8, 54u8, 119u8, 97u8, 100u8, 111u8, 45u8, 55u8, 48u8, 56u8, 121u8,
+                        111u8, 114u8, 111u8, 45u8, 55u8, 49u8, 55u8, 121u8, 111u8, 119u8, 97u8,
+                        45u8, 49u8, 49u8, 56u8, 49u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        239u8, 0u8, 0u8, 0u8, 0u8, 0u8, 21u8, 0u8, 40u8, 0u8, 56u8, 0u8, 75u8, 0u8,
+                        94u8, 0u8, 130u8, 0u8, 152u8, 0u8, 173u8, 0u8, 195u8, 0u8, 214u8, 0u8,
+                        233u8, 0u8, 252u8, 0u8, 17u8, 1u8, 37u8, 1u8, 59u8, 1u8, 79u8, 1u8, 100u8,
+                        1u8, 122u8, 1u8, 142u8, 1u8, 163u8, 1u8, 190u8, 1u8, 211u8, 1u8, 231u8,
+                        1u8, 251u8, 1u8, 16u8, 2u8, 38u8, 2u8, 60u8, 2u8, 82u8, 2u8, 105u8, 2u8,
+                        127u8, 2u8, 147u8, 2u8, 167u8, 2u8, 185u8, 2u8, 204u8, 2u8, 224u8, 2u8,
+                        240u8, 2u8, 3u8, 3u8, 21u8, 3u8, 38u8, 3u8, 58u8, 3u8, 78u8, 3u8, 97u8,
+                        3u8, 116u8, 3u8, 136u8, 3u8, 157u8, 3u8, 177u8, 3u8, 197u8, 3u8, 213u8,
+                        3u8, 231u8, 3u8, 250u8, 3u8, 10u8, 4u8, 30u8, 4u8, 50u8, 4u8, 70u8, 4u8,
+                        91u8, 4u8, 110u8, 4u8, 129u8, 4u8, 149u8, 4u8, 168u8, 4u8, 188u8, 4u8,
+                        209u8, 4u8, 231u8, 4u8, 250u8, 4u8, 13u8, 5u8, 33u8, 5u8, 53u8, 5u8, 74u8,
+                        5u8, 93u8, 5u8, 113u8, 5u8, 132u8, 5u8, 153u8, 5u8, 175u8, 5u8, 196u8, 5u8,
+                        215u8, 5u8, 234u8, 5u8, 253u8, 5u8, 254u8, 5u8, 17u8, 6u8, 36u8, 6u8, 55u8,
+                        6u8, 75u8, 6u8, 94u8, 6u8, 111u8, 6u8, 132u8, 6u8, 153u8, 6u8, 171u8, 6u8,
+                        194u8, 6u8, 211u8, 6u8, 232u8, 6u8, 252u8, 6u8, 15u8, 7u8, 33u8, 7u8, 53u8,
+                        7u8, 71u8, 7u8, 89u8, 7u8, 110u8, 7u8, 131u8, 7u8, 150u8, 7u8, 169u8, 7u8,
+                        190u8, 7u8, 207u8, 7u8, 225u8, 7u8, 243u8, 7u8, 6u8, 8u8, 25u8, 8u8, 42u8,
+                        8u8, 61u8, 8u8, 82u8, 8u8, 102u8, 8u8, 124u8, 8u8, 146u8, 8u8, 166u8, 8u8,
+                        185u8, 8u8, 204u8, 8u8, 224u8, 8u8, 241u8, 8u8, 5u8, 9u8, 25u8, 9u8, 44u8,
+                        9u8, 64u8, 9u8, 85u8, 9u8, 106u8, 9u8, 124u8, 9u8, 144u8, 9u8, 165u8, 9u8,
+                        185u8, 9u8, 204u8, 9u8, 223u8, 9u8, 244u8, 9u8, 7u8, 10u8, 24u8, 10u8,
+                        45u8, 10u8, 67u8, 10u8, 87u8, 10u8, 106u8, 10u8, 127u8, 10u8, 146u8, 10u8,
+                        166u8, 10u8, 186u8, 10u8, 208u8, 10u8, 229u8, 10u8, 248u8, 10u8, 13u8,
+                        11u8, 33u8, 11u8, 53u8, 11u8, 71u8, 11u8, 90u8, 11u8, 109u8, 11u8, 128u8,
+                        11u8, 149u8, 11u8, 167u8, 11u8, 186u8, 11u8, 208u8, 11u8, 229u8, 11u8,
+                        248u8, 11u8, 11u8, 12u8, 32u8, 12u8, 54u8, 12u8, 76u8, 12u8, 96u8, 12u8,
+                        116u8, 12u8, 136u8, 12u8, 158u8, 12u8, 177u8, 12u8, 196u8, 12u8, 197u8,
+                        12u8, 216u8, 12u8, 237u8, 12u8, 2u8, 13u8, 23u8, 13u8, 42u8, 13u8, 64u8,
+                        13u8, 83u8, 13u8, 100u8, 13u8, 117u8, 13u8, 137u8, 13u8, 157u8, 13u8,
+                        175u8, 13u8, 193u8, 13u8, 212u8, 13u8, 232u8, 13u8, 248u8, 13u8, 9u8, 14u8,
+                        10u8, 14u8, 32u8, 14u8, 50u8, 14u8, 70u8, 14u8, 90u8, 14u8, 112u8, 14u8,
+                        134u8, 14u8, 155u8, 14u8, 176u8, 14u8, 196u8, 14u8, 216u8, 14u8, 236u8,
+                        14u8, 0u8, 15u8, 20u8, 15u8, 43u8, 15u8, 64u8, 15u8, 83u8, 15u8, 105u8,
+                        15u8, 106u8, 15u8, 126u8, 15u8, 145u8, 15u8, 164u8, 15u8, 182u8, 15u8,
+                        199u8, 15u8, 200u8, 15u8, 218u8, 15u8, 238u8, 15u8, 1u8, 16u8, 21u8, 16u8,
+                        41u8, 16u8, 59u8, 16u8, 78u8, 16u8, 97u8, 16u8, 116u8, 16u8, 135u8, 16u8,
+                        155u8, 16u8, 174u8, 16u8, 194u8, 16u8, 212u8, 16u8, 232u8, 16u8, 253u8,
+                        16u8, 16u8, 17u8, 41u8, 17u8, 66u8, 17u8, 92u8, 17u8, 119u8, 17u8, 138u8,
+                        17u8, 158u8, 17u8, 179u8, 17u8, 200u8, 17u8, 219u8, 17u8, 242u8, 17u8, 6u8,
+                        18u8, 23u8, 18u8, 41u8, 18u8, 65u8, 110u8, 226u8, 128u8, 153u8, 101u8,
+                        105u8, 32u8, 40u8, 49u8, 55u8, 55u8, 50u8, 226u8, 128u8, 147u8, 49u8, 55u8,
+                        56u8, 49u8, 41u8, 65u8, 110u8, 103u8, 101u8, 110u8, 32u8, 40u8, 49u8, 49u8,
+                        55u8, 53u8, 226u8, 128u8, 147u8, 49u8, 49u8, 55u8, 55u8, 41u8, 65u8, 110u8,
+                        110u8, 97u8, 32u8, 40u8, 57u8, 54u8, 56u8, 226u8, 128u8, 147u8, 57u8, 55u8,
+                        48u8, 41u8, 65u8, 110u8, 115u8, 101u8, 105u8, 32u8, 40u8, 49u8, 56u8, 53u8,
+                        52u8, 226u8, 128u8, 147u8, 49u8, 56u8, 54u8, 48u8, 41u8, 65u8, 110u8,
+                        116u8, 101u8, 105u8, 32u8, 40u8, 49u8, 50u8, 50u8, 55u8, 226u8, 128u8,
+                        147u8, 49u8, 50u8, 50u8, 57u8, 41u8, 224u8, 166u8, 150u8, 224u8, 167u8,
+                        141u8, 224u8, 166u8, 176u8, 224u8, 166u8, 191u8, 224u8, 166u8, 184u8,
+                        224u8, 167u8, 141u8, 224u8, 166u8, 159u8, 224u8, 166u8, 170u8, 224u8,
+                        167u8, 130u8, 224u8, 166u8, 176u8, 224u8, 167u8, 141u8, 224u8, 166u8,
+                        172u8, 66u8, 117u8, 110u8, 226u8, 128u8, 153u8, 97u8, 110u8, 32u8, 40u8,
+                        49u8, 52u8, 52u8, 52u8, 226u8, 128u8, 147u8, 49u8, 52u8, 52u8, 57u8, 41u8,
+                        66u8, 117u8, 110u8, 99u8, 104u8, 197u8, 171u8, 32u8, 40u8, 49u8, 51u8,
+                        55u8, 50u8, 226u8, 128u8, 147u8, 49u8, 51u8, 55u8, 53u8, 41u8, 66u8, 117u8,
+                        110u8, 226u8, 128u8, 153u8, 101u8, 105u8, 32u8, 40u8, 49u8, 50u8, 54u8,
+                        52u8, 226u8, 128u8, 147u8, 49u8, 50u8, 55u8, 53u8, 41u8, 66u8, 117u8,
+                        110u8, 106u8, 105u8, 32u8, 40u8, 49u8, 49u8, 56u8, 53u8, 226u8, 128u8,
+                        147u8, 49u8, 49u8, 57u8, 48u8, 41u8, 66u8, 117u8, 110u8, 107u8, 97u8, 32u8,
+                        40u8, 49u8, 56u8, 48u8, 52u8, 226u8, 128u8, 147u8, 49u8, 56u8, 49u8, 56u8,
+                        41u8, 66u8, 117u8, 110u8, 107u8, 105u8, 32u8, 40u8, 49u8, 53u8, 48u8, 49u8,
+                        226u8, 128u8, 147u8, 49u8, 53u8, 48u8, 52u8, 41u8, 66u8, 117u8, 110u8,
+                        107u8, 121u8, 197u8, 171u8, 32u8, 40u8, 49u8, 56u8, 54u8, 49u8, 226u8,
+                        128u8, 147u8, 49u8, 56u8, 54u8, 52u8, 41u8, 66u8, 117u8, 110u8, 109u8,
+                        101u8, 105u8, 32u8, 40u8, 49u8, 52u8, 54u8, 57u8, 226u8, 128u8, 147u8,
+                        49u8, 52u8, 56u8, 55u8, 41u8, 66u8, 117u8, 110u8, 226u8, 128u8, 153u8,
+                        197u8, 141u8, 32u8, 40u8, 49u8, 50u8, 54u8, 48u8, 226u8, 128u8, 147u8,
+                        49u8, 50u8, 54u8, 49u8, 41u8, 66u8, 117u8, 110u8, 112u8, 197u8, 141u8,
+                        32u8, 40u8, 49u8, 51u8, 49u8, 55u8, 226u8, 128u8, 147u8, 49u8, 51u8, 49u8,
+                        57u8, 41u8, 66u8, 117u8, 110u8, 114u8, 111u8, 107u8, 117u8, 32u8, 40u8,
+                        49u8, 53u8, 57u8, 50u8, 226u8, 128u8, 147u8, 49u8, 53u8, 57u8, 54u8, 41u8,
+                        66u8, 117u8, 110u8, 114u8, 121u8, 97u8, 107u8, 117u8, 32u8, 40u8, 49u8,
+                        50u8, 51u8, 52u8, 226u8, 128u8, 147u8, 49u8, 50u8, 51u8, 53u8, 41u8, 66u8,
+                        117u8, 110u8, 115u8, 101u8, 105u8, 32u8, 40u8, 49u8, 56u8, 49u8, 56u8,
+                        226u8, 128u8, 147u8, 49u8, 56u8, 51u8, 48u8, 41u8, 66u8, 117u8, 110u8,
+                        115u8, 104u8, 197u8, 141u8, 32u8, 40u8, 49u8, 52u8, 54u8, 54u8, 226u8,
+                        128u8, 147u8, 49u8, 52u8, 54u8, 55u8, 41u8, 224u8, 166u8, 150u8, 224u8,
+                        167u8, 131u8, 224u8, 166u8, 183u8, 224u8, 167u8, 141u8, 224u8, 166u8,
+                        159u8, 224u8, 166u8, 190u8, 224u8, 166u8, 172u8, 224u8, 167u8, 141u8,
+                        224u8, 166u8, 166u8, 67u8, 104u8, 197u8, 141u8, 103u8, 101u8, 110u8, 32u8,
+                        40u8, 49u8, 48u8, 50u8, 56u8, 226u8, 128u8, 147u8, 49u8, 48u8, 51u8, 55u8,
+                        41u8, 67u8, 104u8, 197u8, 141u8, 104u8, 197u8, 141u8, 32u8, 40u8, 57u8,
+                        57u8, 57u8, 226u8, 128u8, 147u8, 49u8, 48u8, 48u8, 52u8, 41u8, 67u8, 104u8,
+                        197u8, 141u8, 106u8, 105u8, 32u8, 40u8, 49u8, 49u8, 48u8, 52u8, 226u8,
+                        128u8, 147u8, 49u8, 49u8, 48u8, 54u8, 41u8, 67u8, 104u8, 197u8, 141u8,
+                        107u8, 97u8, 110u8, 32u8, 40u8, 49u8, 49u8, 54u8, 51u8, 226u8, 128u8,
+                        147u8, 49u8, 49u8, 54u8, 53u8, 41u8, 67u8, 104u8, 197u8, 141u8, 107u8,
+                        121u8, 197u8, 141u8, 32u8, 40u8, 49u8, 52u8, 56u8, 55u8, 226u8, 128u8,
+                        147u8, 49u8, 52u8, 56u8, 57u8, 41u8, 67u8, 104u8, 197u8, 141u8, 107u8,
+                        121u8, 197u8, 171u8, 32u8, 40u8, 49u8, 48u8, 52u8, 48u8, 226u8, 128u8,
+                        147u8, 49u8, 48u8, 52u8, 52u8, 41u8, 67u8, 104u8, 197u8, 141u8, 114u8,
+                        111u8, 107u8, 117u8, 32u8, 40u8, 49u8, 52u8, 53u8, 55u8, 226u8, 128u8,
+                        147u8, 49u8, 52u8, 54u8, 48u8, 41u8, 67u8, 104u8, 197u8, 141u8, 114u8,
+                        121u8, 97u8, 107u8, 117u8, 32u8, 40u8, 49u8, 48u8, 51u8, 55u8, 226u8,
+                        128u8, 147u8, 49u8, 48u8, 52u8, 48u8, 41u8, 67u8, 104u8, 197u8, 141u8,
+                        115u8, 104u8, 197u8, 141u8, 32u8, 40u8, 49u8, 49u8, 51u8, 50u8, 226u8,
+                        128u8, 147u8, 49u8, 49u8, 51u8, 53u8, 41u8, 67u8, 104u8, 197u8, 141u8,
+                        116u8, 111u8, 107u8, 117u8, 32u8, 40u8, 57u8, 57u8, 53u8, 226u8, 128u8,
+                        147u8, 57u8, 57u8, 57u8, 41u8, 67u8, 104u8, 197u8, 141u8, 119u8, 97u8,
+                        32u8, 40u8, 49u8, 48u8, 49u8, 50u8, 226u8, 128u8, 147u8, 49u8, 48u8, 49u8,
+                        55u8, 41u8, 68u8, 97u8, 105u8, 100u8, 197u8, 141u8, 32u8, 40u8, 56u8, 48u8,
+                        54u8, 226u8, 128u8, 147u8, 56u8, 49u8, 48u8, 41u8, 68u8, 97u8, 105u8,
+                        106u8, 105u8, 32u8, 40u8, 49u8, 49u8, 50u8, 54u8, 226u8, 128u8, 147u8,
+                        49u8, 49u8, 51u8, 49u8, 41u8, 69u8, 105u8, 99u8, 104u8, 197u8, 141u8, 32u8,
+                        40u8, 49u8, 48u8, 57u8, 54u8, 226u8, 128u8, 147u8, 49u8, 48u8, 57u8, 55u8,
+                        41u8, 69u8, 105u8, 101u8, 110u8, 32u8, 40u8, 57u8, 56u8, 55u8, 226u8,
+                        128u8, 147u8, 57u8, 56u8, 57u8, 41u8, 69u8, 105u8, 104u8, 197u8, 141u8,
+                        32u8, 40u8, 49u8, 48u8, 56u8, 49u8, 226u8, 128u8, 147u8, 49u8, 48u8, 56u8,
+                        52u8, 41u8, 69u8, 105u8, 106u8, 105u8, 32u8, 40u8, 49u8, 49u8, 52u8, 49u8,
+                        226u8, 128u8, 147u8, 49u8, 49u8, 52u8, 50u8, 41u8, 69u8, 105u8, 107u8,
+                        97u8, 110u8, 32u8, 40u8, 57u8, 56u8, 51u8, 226u8, 128u8, 147u8, 57u8, 56u8,
+                        53u8, 41u8, 69u8, 105u8, 107u8, 121u8, 197u8, 141u8, 32u8, 40u8, 49u8,
+                        52u8, 50u8, 57u8, 226u8, 128u8, 147u8, 49u8, 52u8, 52u8, 49u8, 41u8, 69u8,
+                        105u8, 107u8, 121u8, 197u8, 171u8, 32u8, 40u8, 49u8, 49u8, 49u8, 51u8,
+                        226u8, 128u8, 147u8, 49u8, 49u8, 49u8, 56u8, 41u8, 69u8, 105u8, 109u8,
+                        97u8, 110u8, 32u8, 40u8, 49u8, 49u8, 54u8, 53u8, 226u8, 128u8, 147u8, 49u8,
+                        49u8, 54u8, 54u8, 41u8, 69u8, 105u8, 110u8, 105u8, 110u8, 32u8, 40u8, 49u8,
+                        50u8, 57u8, 51u8, 226u8, 128u8, 147u8, 49u8, 50u8, 57u8, 57u8, 41u8, 69u8,
+                        105u8, 114u8, 111u8, 107u8, 117u8, 32u8, 40u8, 49u8, 53u8, 53u8, 56u8,
+                        226u8, 128u8, 147u8, 49u8, 53u8, 55u8, 48u8, 41u8, 69u8, 105u8, 114u8,
+                        121u8, 97u8, 107u8, 117u8, 32u8, 40u8, 49u8, 49u8, 54u8, 48u8, 226u8,
+                        128u8, 147u8, 49u8, 49u8, 54u8, 49u8, 41u8, 69u8, 105u8, 115u8, 104u8,
+                        197u8, 141u8, 32u8, 40u8, 49u8, 48u8, 52u8, 54u8, 226u8, 128u8, 147u8,
+                        49u8, 48u8, 53u8, 51u8, 41u8, 69u8, 105u8, 115u8, 104u8, 197u8, 141u8,
+                        32u8, 40u8, 49u8, 53u8, 48u8, 52u8, 226u8, 128u8, 147u8, 49u8, 53u8, 50u8,
+                        49u8, 41u8, 69u8, 105u8, 115u8, 111u8, 32u8, 40u8, 57u8, 56u8, 57u8, 226u8,
+                        128u8, 147u8, 57u8, 57u8, 48u8, 41u8, 69u8, 110u8, 99u8, 104u8, 197u8,
+                        141u8, 32u8, 40u8, 57u8, 50u8, 51u8, 226u8, 128u8, 147u8, 57u8, 51u8, 49u8,
+                        41u8, 69u8, 110u8, 103u8, 101u8, 110u8, 32u8, 40u8, 49u8, 51u8, 51u8, 54u8,
+                        226u8, 128u8, 147u8, 49u8, 51u8, 52u8, 48u8, 41u8, 69u8, 110u8, 103u8,
+                        105u8, 32u8, 40u8, 57u8, 48u8, 49u8, 226u8, 128u8, 147u8, 57u8, 50u8, 51u8,
+                        41u8, 69u8, 110u8, 107u8, 121u8, 197u8, 141u8, 32u8, 40u8, 49u8, 51u8,
+                        48u8, 56u8, 226u8, 128u8, 147u8, 49u8, 51u8, 49u8, 49u8, 41u8, 69u8, 110u8,
+                        107u8, 121u8, 197u8, 141u8, 32u8, 40u8, 49u8, 55u8, 52u8, 52u8, 226u8,
+                        128u8, 147u8, 49u8, 55u8, 52u8, 56u8, 41u8, 69u8, 110u8, 107u8, 121u8,
+                        197u8, 171u8, 32u8, 40u8, 49u8, 48u8, 54u8, 57u8, 226u8, 128u8, 147u8,
+                        49u8, 48u8, 55u8, 52u8, 41u8, 69u8, 110u8, 226u8, 128u8, 153u8, 197u8,
+                        141u8, 32u8, 40u8, 49u8, 50u8, 51u8, 57u8, 226u8, 128u8, 147u8, 49u8, 50u8,
+                        52u8, 48u8, 41u8, 69u8, 110u8, 112u8, 197u8, 141u8, 32u8, 40u8, 49u8, 54u8,
+                        55u8, 51u8, 226u8, 128u8, 147u8, 49u8, 54u8, 56u8, 49u8, 41u8, 69u8, 110u8,
+                        114u8, 121u8, 97u8, 107u8, 117u8, 32u8, 40u8, 55u8, 56u8, 50u8, 226u8,
+                        128u8, 147u8, 56u8, 48u8, 54u8, 41u8, 69u8, 110u8, 116u8, 111u8, 107u8,
+                        117u8, 32u8, 40u8, 49u8, 52u8, 56u8, 57u8, 226u8, 128u8, 147u8, 49u8, 52u8,
+                        57u8, 50u8, 41u8, 71u8, 97u8, 110u8, 103u8, 121u8, 197u8, 141u8, 32u8,
+                        40u8, 56u8, 55u8, 55u8, 226u8, 128u8, 147u8, 56u8, 56u8, 53u8, 41u8, 71u8,
+                        101u8, 110u8, 98u8, 117u8, 110u8, 32u8, 40u8, 49u8, 55u8, 51u8, 54u8,
+                        226u8, 128u8, 147u8, 49u8, 55u8, 52u8, 49u8, 41u8, 71u8, 101u8, 110u8,
+                        99u8, 104u8, 197u8, 171u8, 32u8, 40u8, 49u8, 51u8, 56u8, 52u8, 226u8,
+                        128u8, 147u8, 49u8, 51u8, 57u8, 50u8, 41u8, 71u8, 101u8, 110u8, 226u8,
+                        128u8, 153u8, 101u8, 105u8, 32u8, 40u8, 49u8, 49u8, 49u8, 56u8, 226u8,
+                        128u8, 147u8, 49u8, 49u8, 50u8, 48u8, 41u8, 71u8, 101u8, 110u8, 106u8,
+                        105u8, 32u8, 40u8, 49u8, 56u8, 54u8, 52u8, 226u8, 128u8, 147u8, 49u8, 56u8,
+                        54u8, 53u8, 41u8, 71u8, 101u8, 110u8, 107u8, 105u8, 32u8, 40u8, 49u8, 53u8,
+                        55u8, 48u8, 226u8, 128u8, 147u8, 49u8, 53u8, 55u8, 51u8, 41u8, 71u8, 101u8,
+                        110u8, 107u8, 197u8, 141u8, 32u8, 40u8, 49u8, 51u8, 50u8, 49u8, 226u8,
+                        128u8, 147u8, 49u8, 51u8, 50u8, 52u8, 41u8, 71u8, 101u8, 110u8, 107u8,
+                        197u8, 141u8, 32u8, 40u8, 49u8, 51u8, 51u8, 49u8, 226u8, 128u8, 147u8,
+                        49u8, 51u8, 51u8, 52u8, 41u8, 71u8, 101u8, 110u8, 107u8, 121u8, 197u8,
+                        171u8, 32u8, 40u8, 49u8, 50u8, 48u8, 52u8, 226u8, 128u8, 147u8, 49u8, 50u8,
+                        48u8, 54u8, 41u8, 71u8, 101u8, 110u8, 110u8, 97u8, 32u8, 40u8, 49u8, 54u8,
+                        49u8, 53u8, 226u8, 128u8, 147u8, 49u8, 54u8, 50u8, 52u8, 41u8, 71u8, 101u8,
+                        110u8, 110u8, 105u8, 110u8, 32u8, 40u8, 49u8, 50u8, 50u8, 52u8, 226u8,
+                        128u8, 147u8, 49u8, 50u8, 50u8, 53u8, 41u8, 71u8, 101u8, 110u8, 197u8,
+                        141u8, 32u8, 40u8, 49u8, 51u8, 49u8, 57u8, 226u8, 128u8, 147u8, 49u8, 51u8,
+                        50u8, 49u8, 41u8, 71u8, 101u8, 110u8, 114u8, 111u8, 107u8, 117u8, 32u8,
+                        40u8, 49u8, 54u8, 56u8, 56u8, 226u8, 128u8, 147u8, 49u8, 55u8, 48u8, 52u8,
+                        41u8, 71u8, 101u8, 110u8, 114u8, 121u8, 97u8, 107u8, 117u8, 32u8, 40u8,
+                        49u8, 49u8, 56u8, 52u8, 226u8, 128u8, 147u8, 49u8, 49u8, 56u8, 53u8, 41u8,
+                        71u8, 101u8, 110u8, 116u8, 111u8, 107u8, 117u8, 32u8, 40u8, 49u8, 51u8,
+                        50u8, 57u8, 226u8, 128u8, 147u8, 49u8, 51u8, 51u8, 49u8, 41u8, 72u8, 97u8,
+                        107u8, 117u8, 99u8, 104u8, 105u8, 32u8, 40u8, 54u8, 53u8, 48u8, 226u8,
+                        128u8, 147u8, 54u8, 55u8, 49u8, 41u8, 72u8, 97u8, 107u8, 117u8, 104u8,
+                        197u8, 141u8, 32u8, 40u8, 54u8, 55u8, 50u8, 226u8, 128u8, 147u8, 54u8,
+                        56u8, 54u8, 41u8, 72u8, 101u8, 105u8, 106u8, 105u8, 32u8, 40u8, 49u8, 49u8,
+                        53u8, 57u8, 226u8, 128u8, 147u8, 49u8, 49u8, 54u8, 48u8, 41u8, 72u8, 72u8,
+                        197u8, 141u8, 97u8, 110u8, 32u8, 40u8, 49u8, 49u8, 50u8, 48u8, 226u8,
+                        128u8, 147u8, 49u8, 49u8, 50u8, 52u8, 41u8, 72u8, 197u8, 141u8, 101u8,
+                        105u8, 32u8, 40u8, 49u8, 55u8, 48u8, 52u8, 226u8, 128u8, 147u8, 49u8, 55u8,
+                        49u8, 49u8, 41u8, 72u8, 197u8, 141u8, 101u8, 110u8, 32u8, 40u8, 49u8, 49u8,
+                        51u8, 53u8, 226u8, 128u8, 147u8, 49u8, 49u8, 52u8, 49u8, 41u8, 72u8, 197u8,
+                        141u8, 103u8, 101u8, 110u8, 32u8, 40u8, 49u8, 49u8, 53u8, 54u8, 226u8,
+                        128u8, 147u8, 49u8, 49u8, 53u8, 57u8, 41u8, 72u8, 197u8, 141u8, 106u8,
+                        105u8, 32u8, 40u8, 49u8, 50u8, 52u8, 55u8, 226u8, 128u8, 147u8, 49u8, 50u8,
+                        52u8, 57u8, 41u8, 72u8, 197u8, 141u8, 107u8, 105u8, 32u8, 40u8, 55u8, 55u8,
+                        48u8, 226u8, 128u8, 147u8, 55u8, 56u8, 48u8, 41u8, 72u8, 197u8, 141u8,
+                        114u8, 101u8, 107u8, 105u8, 32u8, 40u8, 49u8, 55u8, 53u8, 49u8, 226u8,
+                        128u8, 147u8, 49u8, 55u8, 54u8, 52u8, 41u8, 72u8, 197u8, 141u8, 116u8,
+                        111u8, 107u8, 117u8, 32u8, 40u8, 49u8, 52u8, 52u8, 57u8, 226u8, 128u8,
+                        147u8, 49u8, 52u8, 53u8, 50u8, 41u8, 74u8, 105u8, 97u8, 110u8, 32u8, 40u8,
+                        49u8, 48u8, 50u8, 49u8, 226u8, 128u8, 147u8, 49u8, 48u8, 50u8, 52u8, 41u8,
+                        74u8, 105u8, 110u8, 103u8, 111u8, 45u8, 107u8, 101u8, 105u8, 117u8, 110u8,
+                        32u8, 40u8, 55u8, 54u8, 55u8, 226u8, 128u8, 147u8, 55u8, 55u8, 48u8, 41u8,
+                        74u8, 105u8, 110u8, 107u8, 105u8, 32u8, 40u8, 55u8, 50u8, 52u8, 226u8,
+                        128u8, 147u8, 55u8, 50u8, 57u8, 41u8, 74u8, 105u8, 114u8, 121u8, 97u8,
+                        107u8, 117u8, 32u8, 40u8, 49u8, 48u8, 54u8, 53u8, 226u8, 128u8, 147u8,
+                        49u8, 48u8, 54u8, 57u8, 41u8, 74u8, 105u8, 115u8, 104u8, 197u8, 141u8,
+                        32u8, 40u8, 49u8, 49u8, 55u8, 55u8, 226u8, 128u8, 147u8, 49u8, 49u8, 56u8,
+                        49u8, 41u8, 74u8, 197u8, 141u8, 101u8, 105u8, 32u8, 40u8, 49u8, 50u8, 51u8,
+                        50u8, 226u8, 128u8, 147u8, 49u8, 50u8, 51u8, 51u8, 41u8, 74u8, 197u8,
+                        141u8, 103u8, 97u8, 110u8, 32u8, 40u8, 56u8, 53u8, 57u8, 226u8, 128u8,
+                        147u8, 56u8, 55u8, 55u8, 41u8, 74u8, 197u8, 141u8, 103u8, 101u8, 110u8,
+                        32u8, 40u8, 49u8, 50u8, 48u8, 55u8, 226u8, 128u8, 147u8, 49u8, 50u8, 49u8,
+                        49u8, 41u8, 74u8, 197u8, 141u8, 103u8, 101u8, 110u8, 32u8, 40u8, 57u8,
+                        55u8, 54u8, 226u8, 128u8, 147u8, 57u8, 55u8, 56u8, 41u8, 74u8, 197u8,
+                        141u8, 104u8, 101u8, 105u8, 32u8, 40u8, 57u8, 51u8, 49u8, 226u8, 128u8,
+                        147u8, 57u8, 51u8, 56u8, 41u8, 74u8, 197u8, 141u8, 107u8, 121u8, 197u8,
+                        141u8, 32u8, 40u8, 49u8, 54u8, 56u8, 52u8, 226u8, 128u8, 147u8, 49u8, 54u8,
+                        56u8, 56u8, 41u8, 74u8, 197u8, 141u8, 107u8, 121u8, 197u8, 171u8, 32u8,
+                        40u8, 49u8, 50u8, 49u8, 57u8, 226u8, 128u8, 147u8, 49u8, 50u8, 50u8, 50u8,
+                        41u8, 74u8, 197u8, 141u8, 197u8, 141u8, 32u8, 40u8, 49u8, 50u8, 50u8, 50u8,
+                        226u8, 128u8, 147u8, 49u8, 50u8, 50u8, 52u8, 41u8, 74u8, 197u8, 141u8,
+                        197u8, 141u8, 32u8, 40u8, 49u8, 54u8, 53u8, 50u8, 226u8, 128u8, 147u8,
+                        49u8, 54u8, 53u8, 53u8, 41u8, 74u8, 197u8, 141u8, 116u8, 111u8, 107u8,
+                        117u8, 32u8, 40u8, 49u8, 48u8, 57u8, 55u8, 226u8, 128u8, 147u8, 49u8, 48u8,
+                        57u8, 57u8, 41u8, 74u8, 197u8, 141u8, 119u8, 97u8, 32u8, 40u8, 56u8, 51u8,
+                        52u8, 226u8, 128u8, 147u8, 56u8, 52u8, 56u8, 41u8, 74u8, 117u8, 101u8,
+                        105u8, 32u8, 40u8, 49u8, 49u8, 56u8, 50u8, 226u8, 128u8, 147u8, 49u8, 49u8,
+                        56u8, 52u8, 41u8, 75u8, 97u8, 101u8, 105u8, 32u8, 40u8, 49u8, 56u8, 52u8,
+                        56u8, 226u8, 128u8, 147u8, 49u8, 56u8, 53u8, 52u8, 41u8, 75u8, 97u8, 103u8,
+                        101u8, 110u8, 32u8, 40u8, 49u8, 51u8, 48u8, 51u8, 226u8, 128u8, 147u8,
+                        49u8, 51u8, 48u8, 54u8, 41u8, 75u8, 97u8, 104u8, 197u8, 141u8, 32u8, 40u8,
+                        49u8, 48u8, 57u8, 52u8, 226u8, 128u8, 147u8, 49u8, 48u8, 57u8, 54u8, 41u8,
+                        75u8, 97u8, 106u8, 197u8, 141u8, 32u8, 40u8, 56u8, 52u8, 56u8, 226u8,
+                        128u8, 147u8, 56u8, 53u8, 49u8, 41u8, 75u8, 97u8, 107u8, 101u8, 105u8,
+                        32u8, 40u8, 49u8, 51u8, 56u8, 55u8, 226u8, 128u8, 147u8, 49u8, 51u8, 56u8,
+                        57u8, 41u8, 75u8, 97u8, 107u8, 105u8, 116u8, 115u8, 117u8, 32u8, 40u8,
+                        49u8, 52u8, 52u8, 49u8, 226u8, 128u8, 147u8, 49u8, 52u8, 52u8, 52u8, 41u8,
+                        75u8, 97u8, 110u8, 98u8, 117u8, 110u8, 32u8, 40u8, 49u8, 54u8, 54u8, 49u8,
+                        226u8, 128u8, 147u8, 49u8, 54u8, 55u8, 51u8, 41u8, 75u8, 97u8, 110u8,
+                        226u8, 128u8, 153u8, 101u8, 105u8, 32u8, 40u8, 49u8, 54u8, 50u8, 52u8,
+                        226u8, 128u8, 147u8, 49u8, 54u8, 52u8, 52u8, 41u8, 75u8, 97u8, 110u8,
+                        226u8, 128u8, 153u8, 101u8, 110u8, 32u8, 40u8, 49u8, 55u8, 52u8, 56u8,
+                        226u8, 128u8, 147u8, 49u8, 55u8, 53u8, 49u8, 41u8, 75u8, 97u8, 110u8,
+                        103u8, 101u8, 110u8, 32u8, 40u8, 49u8, 50u8, 52u8, 51u8, 226u8, 128u8,
+                        147u8, 49u8, 50u8, 52u8, 55u8, 41u8, 75u8, 97u8, 110u8, 106u8, 105u8, 32u8,
+                        40u8, 49u8, 48u8, 56u8, 55u8, 226u8, 128u8, 147u8, 49u8, 48u8, 57u8, 52u8,
+                        41u8, 75u8, 97u8, 110u8, 107u8, 105u8, 32u8, 40u8, 49u8, 50u8, 50u8, 57u8,
+                        226u8, 128u8, 147u8, 49u8, 50u8, 51u8, 50u8, 41u8, 75u8, 97u8, 110u8,
+                        107u8, 197u8, 141u8, 32u8, 40u8, 49u8, 48u8, 48u8, 52u8, 226u8, 128u8,
+                        147u8, 49u8, 48u8, 49u8, 50u8, 41u8, 75u8, 97u8, 110u8, 110u8, 97u8, 32u8,
+                        40u8, 57u8, 56u8, 53u8, 226u8, 128u8, 147u8, 57u8, 56u8, 55u8, 41u8, 75u8,
+                        97u8, 110u8, 110u8, 105u8, 110u8, 32u8, 40u8, 49u8, 48u8, 49u8, 55u8,
+                        226u8, 128u8, 147u8, 49u8, 48u8, 50u8, 49u8, 41u8, 75u8, 97u8, 110u8,
+                        112u8, 197u8, 141u8, 32u8, 40u8, 49u8, 55u8, 52u8, 49u8, 226u8, 128u8,
+                        147u8, 49u8, 55u8, 52u8, 52u8, 41u8, 75u8, 97u8, 110u8, 112u8, 121u8,
+                        197u8, 141u8, 32u8, 40u8, 56u8, 56u8, 57u8, 226u8, 128u8, 147u8, 56u8,
+                        57u8, 56u8, 41u8, 75u8, 97u8, 110u8, 115u8, 101u8, 105u8, 32u8, 40u8, 49u8,
+                        55u8, 56u8, 57u8, 226u8, 128u8, 147u8, 49u8, 56u8, 48u8, 49u8, 41u8, 75u8,
+                        97u8, 110u8, 115u8, 104u8, 197u8, 141u8, 32u8, 40u8, 49u8, 52u8, 54u8,
+                        48u8, 226u8, 128u8, 147u8, 49u8, 52u8, 54u8, 54u8, 41u8, 75u8, 97u8, 110u8,
+                        116u8, 111u8, 107u8, 117u8, 32u8, 40u8, 49u8, 48u8, 52u8, 52u8, 226u8,
+                        128u8, 147u8, 49u8, 48u8, 52u8, 54u8, 41u8, 75u8, 97u8, 197u8, 141u8, 32u8,
+                        40u8, 49u8, 49u8, 54u8, 57u8, 226u8, 128u8, 147u8, 49u8, 49u8, 55u8, 49u8,
+                        41u8, 75u8, 97u8, 114u8, 111u8, 107u8, 117u8, 32u8, 40u8, 49u8, 50u8, 50u8,
+                        53u8, 226u8, 128u8, 147u8, 49u8, 50u8, 50u8, 55u8, 41u8, 75u8, 97u8, 114u8,
+                        121u8, 97u8, 107u8, 117u8, 32u8, 40u8, 49u8, 51u8, 50u8, 54u8, 226u8,
+                        128u8, 147u8, 49u8, 51u8, 50u8, 57u8, 41u8, 75u8, 97u8, 115u8, 104u8,
+                        197u8, 141u8, 32u8, 40u8, 49u8, 49u8, 48u8, 54u8, 226u8, 128u8, 147u8,
+                        49u8, 49u8, 48u8, 56u8, 41u8, 75u8, 97u8, 116u8, 101u8, 105u8, 32u8, 40u8,
+                        49u8, 50u8, 51u8, 53u8, 226u8, 128u8, 147u8, 49u8, 50u8, 51u8, 56u8, 41u8,
+                        75u8, 101u8, 105u8, 97u8, 110u8, 32u8, 40u8, 49u8, 54u8, 52u8, 56u8, 226u8,
+                        128u8, 147u8, 49u8, 54u8, 53u8, 50u8, 41u8, 75u8, 101u8, 105u8, 99u8,
+                        104u8, 197u8, 141u8, 32u8, 40u8, 49u8, 53u8, 57u8, 54u8, 226u8, 128u8,
+                        147u8, 49u8, 54u8, 49u8, 53u8, 41u8, 75u8, 101u8, 105u8, 197u8, 141u8,
+                        32u8, 40u8, 49u8, 56u8, 54u8, 53u8, 226u8, 128u8, 147u8, 49u8, 56u8, 54u8,
+                        56u8, 41u8, 75u8, 101u8, 105u8, 117u8, 110u8, 32u8, 40u8, 55u8, 48u8, 52u8,
+                        226u8, 128u8, 147u8, 55u8, 48u8, 56u8, 41u8, 75u8, 101u8, 110u8, 99u8,
+                        104u8, 197u8, 141u8, 32u8, 40u8, 49u8, 50u8, 52u8, 57u8, 226u8, 128u8,
+                        147u8, 49u8, 50u8, 53u8, 54u8, 41u8, 75u8, 101u8, 110u8, 226u8, 128u8,
+                        153u8, 101u8, 105u8, 32u8, 40u8, 49u8, 50u8, 48u8, 54u8, 226u8, 128u8,
+                        147u8, 49u8, 50u8, 48u8, 55u8, 41u8, 75u8, 101u8, 110u8, 103u8, 101u8,
+                        110u8, 32u8, 40u8, 49u8, 51u8, 48u8, 50u8, 226u8, 128u8, 147u8, 49u8, 51u8,
+                        48u8, 51u8, 41u8, 75u8, 101u8, 110u8, 106u8, 105u8, 32u8, 40u8, 49u8, 50u8,
+                        55u8, 53u8, 226u8, 128u8, 147u8, 49u8, 50u8, 55u8, 56u8, 41u8, 75u8, 101u8,
+                        110u8, 107u8, 121u8, 197u8, 171u8, 32u8, 40u8, 49u8, 49u8, 57u8, 48u8,
+                        226u8, 128u8, 147u8, 49u8, 49u8, 57u8, 57u8, 41u8, 75u8, 101u8, 110u8,
+                        109u8, 117u8, 32u8, 40u8, 49u8, 51u8, 51u8, 52u8, 226u8, 128u8, 147u8,
+                        49u8, 51u8, 51u8, 54u8, 41u8, 75u8, 101u8, 110u8, 110u8, 105u8, 110u8,
+                        32u8, 40u8, 49u8, 50u8, 48u8, 49u8, 226u8, 128u8, 147u8, 49u8, 50u8, 48u8,
+                        52u8, 41u8, 75u8, 101u8, 110u8, 112u8, 197u8, 141u8, 32u8, 40u8, 49u8,
+                        50u8, 49u8, 51u8, 226u8, 128u8, 147u8, 49u8, 50u8, 49u8, 57u8, 41u8, 75u8,
+                        101u8, 110u8, 114u8, 121u8, 97u8, 107u8, 117u8, 32u8, 40u8, 49u8, 50u8,
+                        49u8, 49u8, 226u8, 128u8, 147u8, 49u8, 50u8, 49u8, 51u8, 41u8, 75u8, 101u8,
+                        110u8, 116u8, 111u8, 107u8, 117u8, 32u8, 40u8, 49u8, 51u8, 55u8, 48u8,
+                        226u8, 128u8, 147u8, 49u8, 51u8, 55u8, 50u8, 41u8, 75u8, 197u8, 141u8,
+                        97u8, 110u8, 32u8, 40u8, 49u8, 50u8, 55u8, 56u8, 226u8, 128u8, 147u8, 49u8,
+                        50u8, 56u8, 56u8, 41u8, 75u8, 197u8, 141u8, 99u8, 104u8, 197u8, 141u8,
+                        32u8, 40u8, 49u8, 50u8, 54u8, 49u8, 226u8, 128u8, 147u8, 49u8, 50u8, 54u8,
+                        52u8, 41u8, 75u8, 197u8, 141u8, 103u8, 101u8, 110u8, 32u8, 40u8, 49u8,
+                        50u8, 53u8, 54u8, 226u8, 128u8, 147u8, 49u8, 50u8, 53u8, 55u8, 41u8, 75u8,
+                        197u8, 141u8, 104u8, 101u8, 105u8, 32u8, 40u8, 49u8, 48u8, 53u8, 56u8,
+                        226u8, 128u8, 147u8, 49u8, 48u8, 54u8, 53u8, 41u8, 75u8, 197u8, 141u8,
+                        104u8, 197u8, 141u8, 32u8, 40u8, 57u8, 54u8, 52u8, 226u8, 128u8, 147u8,
+                        57u8, 54u8, 56u8, 41u8, 75u8, 197u8, 141u8, 106u8, 105u8, 32u8, 40u8, 49u8,
+                        49u8, 52u8, 50u8, 226u8, 128u8, 147u8, 49u8, 49u8, 52u8, 52u8, 41u8, 75u8,
+                        197u8, 141u8, 106u8, 105u8, 32u8, 40u8, 49u8, 53u8, 53u8, 53u8, 226u8,
+                        128u8, 147u8, 49u8, 53u8, 53u8, 56u8, 41u8, 75u8, 197u8, 141u8, 107u8,
+                        97u8, 32u8, 40u8, 49u8, 56u8, 52u8, 52u8, 226u8, 128u8, 147u8, 49u8, 56u8,
+                        52u8, 56u8, 41u8, 75u8, 197u8, 141u8, 107u8, 111u8, 107u8, 117u8, 32u8,
+                        40u8, 49u8, 51u8, 52u8, 48u8, 226u8, 128u8, 147u8, 49u8, 51u8, 52u8, 54u8,
+                        41u8, 75u8, 197u8, 141u8, 110u8, 105u8, 110u8, 32u8, 40u8, 56u8, 49u8,
+                        48u8, 226u8, 128u8, 147u8, 56u8, 50u8, 52u8, 41u8, 75u8, 197u8, 141u8,
+                        197u8, 141u8, 32u8, 40u8, 49u8, 51u8, 56u8, 57u8, 226u8, 128u8, 147u8,
+                        49u8, 51u8, 57u8, 48u8, 41u8, 75u8, 197u8, 141u8, 114u8, 121u8, 97u8,
+                        107u8, 117u8, 32u8, 40u8, 49u8, 51u8, 55u8, 57u8, 226u8, 128u8, 147u8,
+                        49u8, 51u8, 56u8, 49u8, 41u8, 75u8, 197u8, 141u8, 115u8, 104u8, 197u8,
+                        141u8, 32u8, 40u8, 49u8, 52u8, 53u8, 53u8, 226u8, 128u8, 147u8, 49u8, 52u8,
+                        53u8, 55u8, 41u8, 75u8, 197u8, 141u8, 119u8, 97u8, 32u8, 40u8, 49u8, 48u8,
+                        57u8, 57u8, 226u8, 128u8, 147u8, 49u8, 49u8, 48u8, 52u8, 41u8, 75u8, 197u8,
+                        141u8, 119u8, 97u8, 32u8, 40u8, 49u8, 51u8, 56u8, 49u8, 226u8, 128u8,
+                        147u8, 49u8, 51u8, 56u8, 52u8, 41u8, 75u8, 121u8, 197u8, 141u8, 104u8,
+                        197u8, 141u8, 32u8, 40u8, 49u8, 55u8, 49u8, 54u8, 226u8, 128u8, 147u8,
+                        49u8, 55u8, 51u8, 54u8, 41u8, 75u8, 121u8, 197u8, 141u8, 114u8, 111u8,
+                        107u8, 117u8, 32u8, 40u8, 49u8, 53u8, 50u8, 56u8, 226u8, 128u8, 147u8,
+                        49u8, 53u8, 51u8, 50u8, 41u8, 75u8, 121u8, 197u8, 141u8, 116u8, 111u8,
+                        107u8, 117u8, 32u8, 40u8, 49u8, 52u8, 53u8, 50u8, 226u8, 128u8, 147u8,
+                        49u8, 52u8, 53u8, 53u8, 41u8, 75u8, 121u8, 197u8, 141u8, 119u8, 97u8, 32u8,
+                        40u8, 49u8, 56u8, 48u8, 49u8, 226u8, 128u8, 147u8, 49u8, 56u8, 48u8, 52u8,
+                        41u8, 75u8, 121u8, 197u8, 171u8, 97u8, 110u8, 32u8, 40u8, 49u8, 49u8, 52u8,
+                        53u8, 226u8, 128u8, 147u8, 49u8, 49u8, 53u8, 49u8, 41u8, 75u8, 121u8,
+                        197u8, 171u8, 106u8, 117u8, 32u8, 40u8, 49u8, 49u8, 53u8, 52u8, 226u8,
+                        128u8, 147u8, 49u8, 49u8, 53u8, 54u8, 41u8, 77u8, 97u8, 110u8, 226u8,
+                        128u8, 153u8, 101u8, 110u8, 32u8, 40u8, 49u8, 56u8, 54u8, 48u8, 226u8,
+                        128u8, 147u8, 49u8, 56u8, 54u8, 49u8, 41u8, 77u8, 97u8, 110u8, 106u8,
+                        105u8, 32u8, 40u8, 49u8, 54u8, 53u8, 56u8, 226u8, 128u8, 147u8, 49u8, 54u8,
+                        54u8, 49u8, 41u8, 77u8, 97u8, 110u8, 106u8, 117u8, 32u8, 40u8, 49u8, 48u8,
+                        50u8, 52u8, 226u8, 128u8, 147u8, 49u8, 48u8, 50u8, 56u8, 41u8, 77u8, 77u8,
+                        101u8, 105u8, 197u8, 141u8, 32u8, 40u8, 49u8, 52u8, 57u8, 50u8, 226u8,
+                        128u8, 147u8, 49u8, 53u8, 48u8, 49u8, 41u8, 77u8, 101u8, 105u8, 114u8,
+                        101u8, 107u8, 105u8, 32u8, 40u8, 49u8, 54u8, 53u8, 53u8, 226u8, 128u8,
+                        147u8, 49u8, 54u8, 53u8, 56u8, 41u8, 77u8, 101u8, 105u8, 116u8, 111u8,
+                        107u8, 117u8, 32u8, 40u8, 49u8, 51u8, 56u8, 52u8, 226u8, 128u8, 147u8,
+                        49u8, 51u8, 56u8, 55u8, 41u8, 77u8, 101u8, 105u8, 116u8, 111u8, 107u8,
+                        117u8, 32u8, 40u8, 49u8, 51u8, 57u8, 48u8, 226u8, 128u8, 147u8, 49u8, 51u8,
+                        57u8, 52u8, 41u8, 77u8, 101u8, 105u8, 119u8, 97u8, 32u8, 40u8, 49u8, 55u8,
+                        54u8, 52u8, 226u8, 128u8, 147u8, 49u8, 55u8, 55u8, 50u8, 41u8, 78u8, 105u8,
+                        110u8, 226u8, 128u8, 153u8, 97u8, 110u8, 32u8, 40u8, 49u8, 49u8, 54u8,
+                        54u8, 226u8, 128u8, 147u8, 49u8, 49u8, 54u8, 57u8, 41u8, 78u8, 105u8,
+                        110u8, 106u8, 105u8, 32u8, 40u8, 49u8, 50u8, 52u8, 48u8, 226u8, 128u8,
+                        147u8, 49u8, 50u8, 52u8, 51u8, 41u8, 78u8, 105u8, 110u8, 106u8, 117u8,
+                        32u8, 40u8, 56u8, 53u8, 49u8, 226u8, 128u8, 147u8, 56u8, 53u8, 52u8, 41u8,
+                        78u8, 105u8, 110u8, 110u8, 97u8, 32u8, 40u8, 56u8, 56u8, 53u8, 226u8,
+                        128u8, 147u8, 56u8, 56u8, 57u8, 41u8, 78u8, 105u8, 110u8, 112u8, 101u8,
+                        105u8, 32u8, 40u8, 49u8, 49u8, 53u8, 49u8, 226u8, 128u8, 147u8, 49u8, 49u8,
+                        53u8, 52u8, 41u8, 197u8, 140u8, 99u8, 104u8, 197u8, 141u8, 32u8, 40u8,
+                        49u8, 51u8, 49u8, 49u8, 226u8, 128u8, 147u8, 49u8, 51u8, 49u8, 50u8, 41u8,
+                        197u8, 140u8, 101u8, 105u8, 32u8, 40u8, 49u8, 51u8, 57u8, 52u8, 226u8,
+                        128u8, 147u8, 49u8, 52u8, 50u8, 56u8, 41u8, 197u8, 140u8, 104u8, 111u8,
+                        32u8, 40u8, 49u8, 49u8, 54u8, 49u8, 226u8, 128u8, 147u8, 49u8, 49u8, 54u8,
+                        51u8, 41u8, 197u8, 140u8, 110u8, 105u8, 110u8, 32u8, 40u8, 49u8, 52u8,
+                        54u8, 55u8, 226u8, 128u8, 147u8, 49u8, 52u8, 54u8, 57u8, 41u8, 197u8,
+                        140u8, 116u8, 111u8, 107u8, 117u8, 32u8, 40u8, 49u8, 48u8, 56u8, 52u8,
+                        226u8, 128u8, 147u8, 49u8, 48u8, 56u8, 55u8, 41u8, 197u8, 140u8, 119u8,
+                        97u8, 32u8, 40u8, 57u8, 54u8, 49u8, 226u8, 128u8, 147u8, 57u8, 54u8, 52u8,
+                        41u8, 82u8, 101u8, 105u8, 107u8, 105u8, 32u8, 40u8, 55u8, 49u8, 53u8,
+                        226u8, 128u8, 147u8, 55u8, 49u8, 55u8, 41u8, 82u8, 82u8, 121u8, 97u8,
+                        107u8, 117u8, 110u8, 105u8, 110u8, 32u8, 40u8, 49u8, 50u8, 51u8, 56u8,
+                        226u8, 128u8, 147u8, 49u8, 50u8, 51u8, 57u8, 41u8, 83u8, 97u8, 105u8,
                         107u8, 197u8, 141u8, 32u8, 40u8, 56u8, 53u8, 52u8, 226u8, 128u8, 147u8,
                         56u8, 53u8, 55u8, 41u8, 83u8, 104u8, 197u8, 141u8, 97u8, 110u8, 32u8, 40u8,
                         49u8, 49u8, 55u8, 49u8, 226u8, 128u8, 147u8, 49u8, 49u8, 55u8, 53u8, 41u8,
@@ -6615,7 +4373,13 @@ static CCP: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
             )
         },
     },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
 };
+static CCP : & DataStruct = & :: icu_datetime :: provider :: calendar :: DateSymbolsV1 { months : :: icu_datetime :: provider :: calendar :: months :: ContextsV1 { format : :: icu_datetime :: provider :: calendar :: months :: FormatWidthsV1 { abbreviated : :: icu_datetime :: provider :: calendar :: months :: SymbolsV1 :: SolarTwelve ([alloc :: borrow :: Cow :: Borrowed ("𑄎𑄚\u{1112a}") , alloc :: borrow :: Cow :: Borrowed ("𑄜𑄬𑄛\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄟𑄢\u{11134}𑄌\u{11127}") , alloc :: borrow :: Cow :: Borrowed ("𑄃𑄬𑄛\u{11133}𑄢\u{11128}𑄣\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄟𑄬") , alloc :: borrow :: Cow :: Borrowed ("𑄎\u{1112a}𑄚\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄎\u{1112a}𑄣\u{1112d}") , alloc :: borrow :: Cow :: Borrowed ("𑄃𑄉\u{11127}𑄌\u{11134}𑄑\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄥𑄬𑄛\u{11134}𑄑𑄬𑄟\u{11134}𑄝\u{11127}𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄃\u{11127}𑄇\u{11134}𑄑\u{1112e}𑄝\u{11127}𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄚\u{11127}𑄞𑄬𑄟\u{11134}𑄝\u{11127}𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄓\u{11128}𑄥𑄬𑄟\u{11134}𑄝𑄢\u{11134}") ,] ,) , narrow : :: icu_datetime :: provider :: calendar :: months :: SymbolsV1 :: SolarTwelve ([alloc :: borrow :: Cow :: Borrowed ("𑄎") , alloc :: borrow :: Cow :: Borrowed ("𑄜𑄬") , alloc :: borrow :: Cow :: Borrowed ("𑄟") , alloc :: borrow :: Cow :: Borrowed ("𑄃𑄬") , alloc :: borrow :: Cow :: Borrowed ("𑄟𑄬") , alloc :: borrow :: Cow :: Borrowed ("𑄎\u{1112a}𑄚\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄎\u{1112a}") , alloc :: borrow :: Cow :: Borrowed ("𑄃") , alloc :: borrow :: Cow :: Borrowed ("𑄥𑄬") , alloc :: borrow :: Cow :: Borrowed ("𑄃\u{11127}") , alloc :: borrow :: Cow :: Borrowed ("𑄚\u{11127}") , alloc :: borrow :: Cow :: Borrowed ("𑄓\u{11128}") ,] ,) , short : None , wide : :: icu_datetime :: provider :: calendar :: months :: SymbolsV1 :: SolarTwelve ([alloc :: borrow :: Cow :: Borrowed ("𑄎𑄚\u{1112a}𑄠𑄢\u{11128}") , alloc :: borrow :: Cow :: Borrowed ("𑄜𑄬𑄛\u{11134}𑄝\u{11133}𑄢\u{1112a}𑄠𑄢\u{11128}") , alloc :: borrow :: Cow :: Borrowed ("𑄟𑄢\u{11134}𑄌\u{11127}") , alloc :: borrow :: Cow :: Borrowed ("𑄃𑄬𑄛\u{11133}𑄢\u{11128}𑄣\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄟𑄬") , alloc :: borrow :: Cow :: Borrowed ("𑄎\u{1112a}𑄚\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄎\u{1112a}𑄣\u{1112d}") , alloc :: borrow :: Cow :: Borrowed ("𑄃𑄉\u{11127}𑄌\u{11134}𑄑\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄥𑄬𑄛\u{11134}𑄑𑄬𑄟\u{11134}𑄝\u{11127}𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄃\u{11127}𑄇\u{11134}𑄑𑄬𑄝\u{11127}𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄚\u{11127}𑄞𑄬𑄟\u{11134}𑄝\u{11127}𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄓\u{11128}𑄥𑄬𑄟\u{11134}𑄝\u{11127}𑄢\u{11134}") ,] ,) , } , stand_alone : Some (:: icu_datetime :: provider :: calendar :: months :: StandAloneWidthsV1 { abbreviated : Some (:: icu_datetime :: provider :: calendar :: months :: SymbolsV1 :: SolarTwelve ([alloc :: borrow :: Cow :: Borrowed ("𑄎𑄚\u{1112a}𑄠𑄢\u{11128}") , alloc :: borrow :: Cow :: Borrowed ("𑄜𑄬𑄛\u{11134}𑄝\u{11133}𑄢\u{1112a}𑄠𑄢\u{11128}") , alloc :: borrow :: Cow :: Borrowed ("𑄟𑄢\u{11134}𑄌\u{11127}") , alloc :: borrow :: Cow :: Borrowed ("𑄃𑄬𑄛\u{11133}𑄢\u{11128}𑄣\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄟𑄬") , alloc :: borrow :: Cow :: Borrowed ("𑄎\u{1112a}𑄚\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄎\u{1112a}𑄣\u{1112d}") , alloc :: borrow :: Cow :: Borrowed ("𑄃𑄉\u{11127}𑄌\u{11134}𑄑\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄥𑄬𑄛\u{11134}𑄑𑄬𑄟\u{11134}𑄝\u{11127}𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄃\u{11127}𑄇\u{11134}𑄑\u{1112e}𑄝\u{11127}𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄚\u{11127}𑄞𑄬𑄟\u{11134}𑄝\u{11127}𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄓\u{11128}𑄥𑄬𑄟\u{11134}𑄝\u{11127}𑄢\u{11134}") ,] ,)) , narrow : None , short : None , wide : Some (:: icu_datetime :: provider :: calendar :: months :: SymbolsV1 :: SolarTwelve ([alloc :: borrow :: Cow :: Borrowed ("𑄎𑄚\u{1112a}𑄠𑄢\u{11128}") , alloc :: borrow :: Cow :: Borrowed ("𑄜𑄬𑄛\u{11134}𑄝\u{11133}𑄢\u{1112a}𑄠𑄢\u{11128}") , alloc :: borrow :: Cow :: Borrowed ("𑄟𑄢\u{11134}𑄌\u{11127}") , alloc :: borrow :: Cow :: Borrowed ("𑄃𑄬𑄛\u{11133}𑄢\u{11128}𑄣\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄟𑄬") , alloc :: borrow :: Cow :: Borrowed ("𑄎\u{1112a}𑄚\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄎\u{1112a}𑄣\u{1112d}") , alloc :: borrow :: Cow :: Borrowed ("𑄃𑄉\u{11127}𑄌\u{11134}𑄑\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄥𑄬𑄛\u{11134}𑄑𑄬𑄟\u{11134}𑄝\u{11127}𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄃\u{11127}𑄇\u{11134}𑄑\u{1112e}𑄝\u{11127}𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄚\u{11127}𑄞𑄬𑄟\u{11134}𑄝\u{11127}𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄓\u{11128}𑄥𑄬𑄟\u{11134}𑄝\u{11127}𑄢\u{11134}") ,] ,)) , }) , } , quarters : :: icu_datetime :: provider :: calendar :: quarters :: ContextsV1 { format : :: icu_datetime :: provider :: calendar :: quarters :: FormatWidthsV1 { abbreviated : :: icu_datetime :: provider :: calendar :: quarters :: SymbolsV1 ([alloc :: borrow :: Cow :: Borrowed ("Q1") , alloc :: borrow :: Cow :: Borrowed ("Q2") , alloc :: borrow :: Cow :: Borrowed ("Q3") , alloc :: borrow :: Cow :: Borrowed ("Q4") ,] ,) , narrow : :: icu_datetime :: provider :: calendar :: quarters :: SymbolsV1 ([alloc :: borrow :: Cow :: Borrowed ("𑄷") , alloc :: borrow :: Cow :: Borrowed ("𑄸") , alloc :: borrow :: Cow :: Borrowed ("𑄹") , alloc :: borrow :: Cow :: Borrowed ("𑄺") ,] ,) , short : None , wide : :: icu_datetime :: provider :: calendar :: quarters :: SymbolsV1 ([alloc :: borrow :: Cow :: Borrowed ("𑄖\u{11128}𑄚\u{11134}𑄟𑄎\u{11127}𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄘\u{11128} 𑄛\u{11133}𑄆𑄘\u{11133}𑄠𑄬 𑄖\u{11128}𑄚\u{11134}𑄟𑄎\u{11127}𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄖\u{11128}𑄚\u{11134} 𑄛\u{11133}𑄆𑄘\u{11133}𑄠𑄬 𑄖\u{11128}𑄚\u{11134}𑄟𑄎\u{11127}𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄌\u{11133}𑄆𑄬𑄢\u{11134} 𑄛\u{11133}𑄆𑄘\u{11133}𑄠𑄬 𑄖\u{11128}𑄚\u{11134}𑄟𑄎\u{11127}𑄢\u{11134}") ,] ,) , } , stand_alone : None , } , weekdays : :: icu_datetime :: provider :: calendar :: weekdays :: ContextsV1 { format : :: icu_datetime :: provider :: calendar :: weekdays :: FormatWidthsV1 { abbreviated : :: icu_datetime :: provider :: calendar :: weekdays :: SymbolsV1 ([alloc :: borrow :: Cow :: Borrowed ("𑄢\u{11127}𑄝\u{11128}") , alloc :: borrow :: Cow :: Borrowed ("𑄥\u{11127}𑄟\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄟\u{11127}\u{11101}𑄉\u{11127}𑄣\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄝\u{1112a}𑄖\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄝\u{11133}𑄢\u{11128}𑄥\u{1112a}𑄛\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄥\u{1112a}𑄇\u{11134}𑄇\u{1112e}𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄥\u{11127}𑄚\u{11128}") ,] ,) , narrow : :: icu_datetime :: provider :: calendar :: weekdays :: SymbolsV1 ([alloc :: borrow :: Cow :: Borrowed ("𑄢\u{11127}") , alloc :: borrow :: Cow :: Borrowed ("𑄥\u{11127}") , alloc :: borrow :: Cow :: Borrowed ("𑄟\u{11127}") , alloc :: borrow :: Cow :: Borrowed ("𑄝\u{1112a}") , alloc :: borrow :: Cow :: Borrowed ("𑄝\u{11133}𑄢\u{11128}") , alloc :: borrow :: Cow :: Borrowed ("𑄥\u{1112a}") , alloc :: borrow :: Cow :: Borrowed ("𑄥\u{11127}") ,] ,) , short : Some (:: icu_datetime :: provider :: calendar :: weekdays :: SymbolsV1 ([alloc :: borrow :: Cow :: Borrowed ("𑄢\u{11127}𑄝\u{11128}") , alloc :: borrow :: Cow :: Borrowed ("𑄥\u{11127}𑄟\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄟\u{11127}\u{11101}𑄉\u{11127}𑄣\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄝\u{1112a}𑄖\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄝\u{11133}𑄢\u{11128}𑄥\u{1112a}𑄛\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄥\u{1112a}𑄇\u{11134}𑄇\u{1112e}𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄥\u{11127}𑄚\u{11128}") ,] ,)) , wide : :: icu_datetime :: provider :: calendar :: weekdays :: SymbolsV1 ([alloc :: borrow :: Cow :: Borrowed ("𑄢\u{11127}𑄝\u{11128}𑄝𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄥\u{11127}𑄟\u{11134}𑄝𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄟\u{11127}\u{11101}𑄉\u{11127}𑄣\u{11134}𑄝𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄝\u{1112a}𑄖\u{11134}𑄝𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄝\u{11133}𑄢\u{11128}𑄥\u{1112a}𑄛\u{11134}𑄝𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄥\u{1112a}𑄇\u{11134}𑄇\u{1112e}𑄢\u{11134}𑄝𑄢\u{11134}") , alloc :: borrow :: Cow :: Borrowed ("𑄥\u{11127}𑄚\u{11128}𑄝𑄢\u{11134}") ,] ,) , } , stand_alone : None , } , eras : :: icu_datetime :: provider :: calendar :: Eras { names : unsafe { # [allow (unused_unsafe)] :: zerovec :: ZeroMap :: from_parts_unchecked (unsafe { :: zerovec :: VarZeroVec :: from_bytes_unchecked (& [239u8 , 0u8 , 0u8 , 0u8 , 0u8 , 0u8 , 9u8 , 0u8 , 19u8 , 0u8 , 27u8 , 0u8 , 37u8 , 0u8 , 47u8 , 0u8 , 50u8 , 0u8 , 60u8 , 0u8 , 71u8 , 0u8 , 81u8 , 0u8 , 91u8 , 0u8 , 101u8 , 0u8 , 111u8 , 0u8 , 122u8 , 0u8 , 133u8 , 0u8 , 142u8 , 0u8 , 152u8 , 0u8 , 164u8 , 0u8 , 177u8 , 0u8 , 188u8 , 0u8 , 199u8 , 0u8 , 201u8 , 0u8 , 212u8 , 0u8 , 221u8 , 0u8 , 231u8 , 0u8 , 242u8 , 0u8 , 253u8 , 0u8 , 8u8 , 1u8 , 20u8 , 1u8 , 33u8 , 1u8 , 44u8 , 1u8 , 55u8 , 1u8 , 65u8 , 1u8 , 74u8 , 1u8 , 84u8 , 1u8 , 94u8 , 1u8 , 102u8 , 1u8 , 111u8 , 1u8 , 120u8 , 1u8 , 129u8 , 1u8 , 139u8 , 1u8 , 149u8 , 1u8 , 159u8 , 1u8 , 169u8 , 1u8 , 180u8 , 1u8 , 192u8 , 1u8 , 202u8 , 1u8 , 212u8 , 1u8 , 220u8 , 1u8 , 229u8 , 1u8 , 239u8 , 1u8 , 247u8 , 1u8 , 1u8 , 2u8 , 11u8 , 2u8 , 21u8 , 2u8 , 29u8 , 2u8 , 38u8 , 2u8 , 49u8 , 2u8 , 60u8 , 2u8 , 70u8 , 2u8 , 81u8 , 2u8 , 92u8 , 2u8 , 102u8 , 2u8 , 112u8 , 2u8 , 122u8 , 2u8 , 132u8 , 2u8 , 142u8 , 2u8 , 153u8 , 2u8 , 163u8 , 2u8 , 174u8 , 2u8 , 183u8 , 2u8 , 195u8 , 2u8 , 208u8 , 2u8 , 220u8 , 2u8 , 231u8 , 2u8 , 241u8 , 2u8 , 251u8 , 2u8 , 1u8 , 3u8 , 10u8 , 3u8 , 19u8 , 3u8 , 28u8 , 3u8 , 38u8 , 3u8 , 47u8 , 3u8 , 55u8 , 3u8 , 66u8 , 3u8 , 77u8 , 3u8 , 86u8 , 3u8 , 100u8 , 3u8 , 109u8 , 3u8 , 121u8 , 3u8 , 131u8 , 3u8 , 140u8 , 3u8 , 149u8 , 3u8 , 159u8 , 3u8 , 168u8 , 3u8 , 177u8 , 3u8 , 187u8 , 3u8 , 197u8 , 3u8 , 205u8 , 3u8 , 213u8 , 3u8 , 224u8 , 3u8 , 232u8 , 3u8 , 241u8 , 3u8 , 250u8 , 3u8 , 4u8 , 4u8 , 13u8 , 4u8 , 21u8 , 4u8 , 31u8 , 4u8 , 43u8 , 4u8 , 54u8 , 4u8 , 64u8 , 4u8 , 74u8 , 4u8 , 85u8 , 4u8 , 95u8 , 4u8 , 105u8 , 4u8 , 115u8 , 4u8 , 124u8 , 4u8 , 135u8 , 4u8 , 145u8 , 4u8 , 155u8 , 4u8 , 166u8 , 4u8 , 177u8 , 4u8 , 189u8 , 4u8 , 197u8 , 4u8 , 208u8 , 4u8 , 220u8 , 4u8 , 230u8 , 4u8 , 240u8 , 4u8 , 250u8 , 4u8 , 5u8 , 5u8 , 14u8 , 5u8 , 23u8 , 5u8 , 34u8 , 5u8 , 44u8 , 5u8 , 55u8 , 5u8 , 65u8 , 5u8 , 76u8 , 5u8 , 86u8 , 5u8 , 97u8 , 5u8 , 107u8 , 5u8 , 120u8 , 5u8 , 132u8 , 5u8 , 141u8 , 5u8 , 151u8 , 5u8 , 161u8 , 5u8 , 171u8 , 5u8 , 179u8 , 5u8 , 188u8 , 5u8 , 197u8 , 5u8 , 206u8 , 5u8 , 217u8 , 5u8 , 226u8 , 5u8 , 234u8 , 5u8 , 246u8 , 5u8 , 0u8 , 6u8 , 9u8 , 6u8 , 18u8 , 6u8 , 28u8 , 6u8 , 40u8 , 6u8 , 52u8 , 6u8 , 62u8 , 6u8 , 72u8 , 6u8 , 82u8 , 6u8 , 92u8 , 6u8 , 102u8 , 6u8 , 112u8 , 6u8 , 117u8 , 6u8 , 126u8 , 6u8 , 138u8 , 6u8 , 150u8 , 6u8 , 162u8 , 6u8 , 172u8 , 6u8 , 182u8 , 6u8 , 192u8 , 6u8 , 201u8 , 6u8 , 210u8 , 6u8 , 221u8 , 6u8 , 230u8 , 6u8 , 238u8 , 6u8 , 246u8 , 6u8 , 255u8 , 6u8 , 9u8 , 7u8 , 16u8 , 7u8 , 25u8 , 7u8 , 30u8 , 7u8 , 43u8 , 7u8 , 52u8 , 7u8 , 62u8 , 7u8 , 72u8 , 7u8 , 83u8 , 7u8 , 94u8 , 7u8 , 105u8 , 7u8 , 116u8 , 7u8 , 126u8 , 7u8 , 136u8 , 7u8 , 146u8 , 7u8 , 156u8 , 7u8 , 165u8 , 7u8 , 178u8 , 7u8 , 190u8 , 7u8 , 200u8 , 7u8 , 212u8 , 7u8 , 217u8 , 7u8 , 227u8 , 7u8 , 237u8 , 7u8 , 247u8 , 7u8 , 0u8 , 8u8 , 9u8 , 8u8 , 15u8 , 8u8 , 24u8 , 8u8 , 35u8 , 8u8 , 45u8 , 8u8 , 55u8 , 8u8 , 64u8 , 8u8 , 74u8 , 8u8 , 84u8 , 8u8 , 94u8 , 8u8 , 104u8 , 8u8 , 114u8 , 8u8 , 125u8 , 8u8 , 135u8 , 8u8 , 146u8 , 8u8 , 154u8 , 8u8 , 164u8 , 8u8 , 176u8 , 8u8 , 186u8 , 8u8 , 200u8 , 8u8 , 215u8 , 8u8 , 230u8 , 8u8 , 245u8 , 8u8 , 0u8 , 9u8 , 12u8 , 9u8 , 23u8 , 9u8 , 34u8 , 9u8 , 45u8 , 9u8 , 55u8 , 9u8 , 66u8 , 9u8 , 74u8 , 9u8 , 82u8 , 9u8 , 97u8 , 110u8 , 101u8 , 105u8 , 45u8 , 49u8 , 55u8 , 55u8 , 50u8 , 97u8 , 110u8 , 103u8 , 101u8 , 110u8 , 45u8 , 49u8 , 49u8 , 55u8 , 53u8 , 97u8 , 110u8 , 110u8 , 97u8 , 45u8 , 57u8 , 54u8 , 56u8 , 97u8 , 110u8 , 115u8 , 101u8 , 105u8 , 45u8 , 49u8 , 56u8 , 53u8 , 52u8 , 97u8 , 110u8 , 116u8 , 101u8 , 105u8 , 45u8 , 49u8 , 50u8 , 50u8 , 55u8 , 98u8 , 99u8 , 101u8 , 98u8 , 117u8 , 110u8 , 97u8 , 110u8 , 45u8 , 49u8 , 52u8 , 52u8 , 52u8 , 98u8 , 117u8 , 110u8 , 99u8 , 104u8 , 117u8 , 45u8 , 49u8 , 51u8 , 55u8 , 50u8 , 98u8 , 117u8 , 110u8 , 101u8 , 105u8 , 45u8 , 49u8 , 50u8 , 54u8 , 52u8 , 98u8 , 117u8 , 110u8 , 106u8 , 105u8 , 45u8 , 49u8 , 49u8 , 56u8 , 53u8 , 98u8 , 117u8 , 110u8 , 107u8 , 97u8 , 45u8 , 49u8 , 56u8 , 48u8 , 52u8 , 98u8 , 117u8 , 110u8 , 107u8 , 105u8 , 45u8 , 49u8 , 53u8 , 48u8 , 49u8 , 98u8 , 117u8 , 110u8 , 107u8 , 121u8 , 117u8 , 45u8 , 49u8 , 56u8 , 54u8 , 49u8 , 98u8 , 117u8 , 110u8 , 109u8 , 101u8 , 105u8 , 45u8 , 49u8 , 52u8 , 54u8 , 57u8 , 98u8 , 117u8 , 110u8 , 111u8 , 45u8 , 49u8 , 50u8 , 54u8 , 48u8 , 98u8 , 117u8 , 110u8 , 112u8 , 111u8 , 45u8 , 49u8 , 51u8 , 49u8 , 55u8 , 98u8 , 117u8 , 110u8 , 114u8 , 111u8 , 107u8 , 117u8 , 45u8 , 49u8 , 53u8 , 57u8 , 50u8 , 98u8 , 117u8 , 110u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 45u8 , 49u8 , 50u8 , 51u8 , 52u8 , 98u8 , 117u8 , 110u8 , 115u8 , 101u8 , 105u8 , 45u8 , 49u8 , 56u8 , 49u8 , 56u8 , 98u8 , 117u8 , 110u8 , 115u8 , 104u8 , 111u8 , 45u8 , 49u8 , 52u8 , 54u8 , 54u8 , 99u8 , 101u8 , 99u8 , 104u8 , 111u8 , 103u8 , 101u8 , 110u8 , 45u8 , 49u8 , 48u8 , 50u8 , 56u8 , 99u8 , 104u8 , 111u8 , 104u8 , 111u8 , 45u8 , 57u8 , 57u8 , 57u8 , 99u8 , 104u8 , 111u8 , 106u8 , 105u8 , 45u8 , 49u8 , 49u8 , 48u8 , 52u8 , 99u8 , 104u8 , 111u8 , 107u8 , 97u8 , 110u8 , 45u8 , 49u8 , 49u8 , 54u8 , 51u8 , 99u8 , 104u8 , 111u8 , 107u8 , 121u8 , 111u8 , 45u8 , 49u8 , 52u8 , 56u8 , 55u8 , 99u8 , 104u8 , 111u8 , 107u8 , 121u8 , 117u8 , 45u8 , 49u8 , 48u8 , 52u8 , 48u8 , 99u8 , 104u8 , 111u8 , 114u8 , 111u8 , 107u8 , 117u8 , 45u8 , 49u8 , 52u8 , 53u8 , 55u8 , 99u8 , 104u8 , 111u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 45u8 , 49u8 , 48u8 , 51u8 , 55u8 , 99u8 , 104u8 , 111u8 , 115u8 , 104u8 , 111u8 , 45u8 , 49u8 , 49u8 , 51u8 , 50u8 , 99u8 , 104u8 , 111u8 , 116u8 , 111u8 , 107u8 , 117u8 , 45u8 , 57u8 , 57u8 , 53u8 , 99u8 , 104u8 , 111u8 , 119u8 , 97u8 , 45u8 , 49u8 , 48u8 , 49u8 , 50u8 , 100u8 , 97u8 , 105u8 , 100u8 , 111u8 , 45u8 , 56u8 , 48u8 , 54u8 , 100u8 , 97u8 , 105u8 , 106u8 , 105u8 , 45u8 , 49u8 , 49u8 , 50u8 , 54u8 , 101u8 , 105u8 , 99u8 , 104u8 , 111u8 , 45u8 , 49u8 , 48u8 , 57u8 , 54u8 , 101u8 , 105u8 , 101u8 , 110u8 , 45u8 , 57u8 , 56u8 , 55u8 , 101u8 , 105u8 , 104u8 , 111u8 , 45u8 , 49u8 , 48u8 , 56u8 , 49u8 , 101u8 , 105u8 , 106u8 , 105u8 , 45u8 , 49u8 , 49u8 , 52u8 , 49u8 , 101u8 , 105u8 , 107u8 , 97u8 , 110u8 , 45u8 , 57u8 , 56u8 , 51u8 , 101u8 , 105u8 , 107u8 , 121u8 , 111u8 , 45u8 , 49u8 , 52u8 , 50u8 , 57u8 , 101u8 , 105u8 , 107u8 , 121u8 , 117u8 , 45u8 , 49u8 , 49u8 , 49u8 , 51u8 , 101u8 , 105u8 , 109u8 , 97u8 , 110u8 , 45u8 , 49u8 , 49u8 , 54u8 , 53u8 , 101u8 , 105u8 , 110u8 , 105u8 , 110u8 , 45u8 , 49u8 , 50u8 , 57u8 , 51u8 , 101u8 , 105u8 , 114u8 , 111u8 , 107u8 , 117u8 , 45u8 , 49u8 , 53u8 , 53u8 , 56u8 , 101u8 , 105u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 45u8 , 49u8 , 49u8 , 54u8 , 48u8 , 101u8 , 105u8 , 115u8 , 104u8 , 111u8 , 45u8 , 49u8 , 48u8 , 52u8 , 54u8 , 101u8 , 105u8 , 115u8 , 104u8 , 111u8 , 45u8 , 49u8 , 53u8 , 48u8 , 52u8 , 101u8 , 105u8 , 115u8 , 111u8 , 45u8 , 57u8 , 56u8 , 57u8 , 101u8 , 110u8 , 99u8 , 104u8 , 111u8 , 45u8 , 57u8 , 50u8 , 51u8 , 101u8 , 110u8 , 103u8 , 101u8 , 110u8 , 45u8 , 49u8 , 51u8 , 51u8 , 54u8 , 101u8 , 110u8 , 103u8 , 105u8 , 45u8 , 57u8 , 48u8 , 49u8 , 101u8 , 110u8 , 107u8 , 121u8 , 111u8 , 45u8 , 49u8 , 51u8 , 48u8 , 56u8 , 101u8 , 110u8 , 107u8 , 121u8 , 111u8 , 45u8 , 49u8 , 55u8 , 52u8 , 52u8 , 101u8 , 110u8 , 107u8 , 121u8 , 117u8 , 45u8 , 49u8 , 48u8 , 54u8 , 57u8 , 101u8 , 110u8 , 111u8 , 45u8 , 49u8 , 50u8 , 51u8 , 57u8 , 101u8 , 110u8 , 112u8 , 111u8 , 45u8 , 49u8 , 54u8 , 55u8 , 51u8 , 101u8 , 110u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 45u8 , 55u8 , 56u8 , 50u8 , 101u8 , 110u8 , 116u8 , 111u8 , 107u8 , 117u8 , 45u8 , 49u8 , 52u8 , 56u8 , 57u8 , 103u8 , 97u8 , 110u8 , 103u8 , 121u8 , 111u8 , 45u8 , 56u8 , 55u8 , 55u8 , 103u8 , 101u8 , 110u8 , 98u8 , 117u8 , 110u8 , 45u8 , 49u8 , 55u8 , 51u8 , 54u8 , 103u8 , 101u8 , 110u8 , 99u8 , 104u8 , 117u8 , 45u8 , 49u8 , 51u8 , 56u8 , 52u8 , 103u8 , 101u8 , 110u8 , 101u8 , 105u8 , 45u8 , 49u8 , 49u8 , 49u8 , 56u8 , 103u8 , 101u8 , 110u8 , 106u8 , 105u8 , 45u8 , 49u8 , 56u8 , 54u8 , 52u8 , 103u8 , 101u8 , 110u8 , 107u8 , 105u8 , 45u8 , 49u8 , 53u8 , 55u8 , 48u8 , 103u8 , 101u8 , 110u8 , 107u8 , 111u8 , 45u8 , 49u8 , 51u8 , 50u8 , 49u8 , 103u8 , 101u8 , 110u8 , 107u8 , 111u8 , 45u8 , 49u8 , 51u8 , 51u8 , 49u8 , 103u8 , 101u8 , 110u8 , 107u8 , 121u8 , 117u8 , 45u8 , 49u8 , 50u8 , 48u8 , 52u8 , 103u8 , 101u8 , 110u8 , 110u8 , 97u8 , 45u8 , 49u8 , 54u8 , 49u8 , 53u8 , 103u8 , 101u8 , 110u8 , 110u8 , 105u8 , 110u8 , 45u8 , 49u8 , 50u8 , 50u8 , 52u8 , 103u8 , 101u8 , 110u8 , 111u8 , 45u8 , 49u8 , 51u8 , 49u8 , 57u8 , 103u8 , 101u8 , 110u8 , 114u8 , 111u8 , 107u8 , 117u8 , 45u8 , 49u8 , 54u8 , 56u8 , 56u8 , 103u8 , 101u8 , 110u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 45u8 , 49u8 , 49u8 , 56u8 , 52u8 , 103u8 , 101u8 , 110u8 , 116u8 , 111u8 , 107u8 , 117u8 , 45u8 , 49u8 , 51u8 , 50u8 , 57u8 , 104u8 , 97u8 , 107u8 , 117u8 , 99u8 , 104u8 , 105u8 , 45u8 , 54u8 , 53u8 , 48u8 , 104u8 , 97u8 , 107u8 , 117u8 , 104u8 , 111u8 , 45u8 , 54u8 , 55u8 , 50u8 , 104u8 , 101u8 , 105u8 , 106u8 , 105u8 , 45u8 , 49u8 , 49u8 , 53u8 , 57u8 , 104u8 , 101u8 , 105u8 , 115u8 , 101u8 , 105u8 , 104u8 , 111u8 , 97u8 , 110u8 , 45u8 , 49u8 , 49u8 , 50u8 , 48u8 , 104u8 , 111u8 , 101u8 , 105u8 , 45u8 , 49u8 , 55u8 , 48u8 , 52u8 , 104u8 , 111u8 , 101u8 , 110u8 , 45u8 , 49u8 , 49u8 , 51u8 , 53u8 , 104u8 , 111u8 , 103u8 , 101u8 , 110u8 , 45u8 , 49u8 , 49u8 , 53u8 , 54u8 , 104u8 , 111u8 , 106u8 , 105u8 , 45u8 , 49u8 , 50u8 , 52u8 , 55u8 , 104u8 , 111u8 , 107u8 , 105u8 , 45u8 , 55u8 , 55u8 , 48u8 , 104u8 , 111u8 , 114u8 , 101u8 , 107u8 , 105u8 , 45u8 , 49u8 , 55u8 , 53u8 , 49u8 , 104u8 , 111u8 , 116u8 , 111u8 , 107u8 , 117u8 , 45u8 , 49u8 , 52u8 , 52u8 , 57u8 , 106u8 , 105u8 , 97u8 , 110u8 , 45u8 , 49u8 , 48u8 , 50u8 , 49u8 , 106u8 , 105u8 , 110u8 , 103u8 , 111u8 , 107u8 , 101u8 , 105u8 , 117u8 , 110u8 , 45u8 , 55u8 , 54u8 , 55u8 , 106u8 , 105u8 , 110u8 , 107u8 , 105u8 , 45u8 , 55u8 , 50u8 , 52u8 , 106u8 , 105u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 45u8 , 49u8 , 48u8 , 54u8 , 53u8 , 106u8 , 105u8 , 115u8 , 104u8 , 111u8 , 45u8 , 49u8 , 49u8 , 55u8 , 55u8 , 106u8 , 111u8 , 101u8 , 105u8 , 45u8 , 49u8 , 50u8 , 51u8 , 50u8 , 106u8 , 111u8 , 103u8 , 97u8 , 110u8 , 45u8 , 56u8 , 53u8 , 57u8 , 106u8 , 111u8 , 103u8 , 101u8 , 110u8 , 45u8 , 49u8 , 50u8 , 48u8 , 55u8 , 106u8 , 111u8 , 103u8 , 101u8 , 110u8 , 45u8 , 57u8 , 55u8 , 54u8 , 106u8 , 111u8 , 104u8 , 101u8 , 105u8 , 45u8 , 57u8 , 51u8 , 49u8 , 106u8 , 111u8 , 107u8 , 121u8 , 111u8 , 45u8 , 49u8 , 54u8 , 56u8 , 52u8 , 106u8 , 111u8 , 107u8 , 121u8 , 117u8 , 45u8 , 49u8 , 50u8 , 49u8 , 57u8 , 106u8 , 111u8 , 111u8 , 45u8 , 49u8 , 50u8 , 50u8 , 50u8 , 106u8 , 111u8 , 111u8 , 45u8 , 49u8 , 54u8 , 53u8 , 50u8 , 106u8 , 111u8 , 116u8 , 111u8 , 107u8 , 117u8 , 45u8 , 49u8 , 48u8 , 57u8 , 55u8 , 106u8 , 111u8 , 119u8 , 97u8 , 45u8 , 56u8 , 51u8 , 52u8 , 106u8 , 117u8 , 101u8 , 105u8 , 45u8 , 49u8 , 49u8 , 56u8 , 50u8 , 107u8 , 97u8 , 101u8 , 105u8 , 45u8 , 49u8 , 56u8 , 52u8 , 56u8 , 107u8 , 97u8 , 103u8 , 101u8 , 110u8 , 45u8 , 49u8 , 51u8 , 48u8 , 51u8 , 107u8 , 97u8 , 104u8 , 111u8 , 45u8 , 49u8 , 48u8 , 57u8 , 52u8 , 107u8 , 97u8 , 106u8 , 111u8 , 45u8 , 56u8 , 52u8 , 56u8 , 107u8 , 97u8 , 107u8 , 101u8 , 105u8 , 45u8 , 49u8 , 51u8 , 56u8 , 55u8 , 107u8 , 97u8 , 107u8 , 105u8 , 116u8 , 115u8 , 117u8 , 45u8 , 49u8 , 52u8 , 52u8 , 49u8 , 107u8 , 97u8 , 110u8 , 98u8 , 117u8 , 110u8 , 45u8 , 49u8 , 54u8 , 54u8 , 49u8 , 107u8 , 97u8 , 110u8 , 101u8 , 105u8 , 45u8 , 49u8 , 54u8 , 50u8 , 52u8 , 107u8 , 97u8 , 110u8 , 101u8 , 110u8 , 45u8 , 49u8 , 55u8 , 52u8 , 56u8 , 107u8 , 97u8 , 110u8 , 103u8 , 101u8 , 110u8 , 45u8 , 49u8 , 50u8 , 52u8 , 51u8 , 107u8 , 97u8 , 110u8 , 106u8 , 105u8 , 45u8 , 49u8 , 48u8 , 56u8 , 55u8 , 107u8 , 97u8 , 110u8 , 107u8 , 105u8 , 45u8 , 49u8 , 50u8 , 50u8 , 57u8 , 107u8 , 97u8 , 110u8 , 107u8 , 111u8 , 45u8 , 49u8 , 48u8 , 48u8 , 52u8 , 107u8 , 97u8 , 110u8 , 110u8 , 97u8 , 45u8 , 57u8 , 56u8 , 53u8 , 107u8 , 97u8 , 110u8 , 110u8 , 105u8 , 110u8 , 45u8 , 49u8 , 48u8 , 49u8 , 55u8 , 107u8 , 97u8 , 110u8 , 112u8 , 111u8 , 45u8 , 49u8 , 55u8 , 52u8 , 49u8 , 107u8 , 97u8 , 110u8 , 112u8 , 121u8 , 111u8 , 45u8 , 56u8 , 56u8 , 57u8 , 107u8 , 97u8 , 110u8 , 115u8 , 101u8 , 105u8 , 45u8 , 49u8 , 55u8 , 56u8 , 57u8 , 107u8 , 97u8 , 110u8 , 115u8 , 104u8 , 111u8 , 45u8 , 49u8 , 52u8 , 54u8 , 48u8 , 107u8 , 97u8 , 110u8 , 116u8 , 111u8 , 107u8 , 117u8 , 45u8 , 49u8 , 48u8 , 52u8 , 52u8 , 107u8 , 97u8 , 111u8 , 45u8 , 49u8 , 49u8 , 54u8 , 57u8 , 107u8 , 97u8 , 114u8 , 111u8 , 107u8 , 117u8 , 45u8 , 49u8 , 50u8 , 50u8 , 53u8 , 107u8 , 97u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 45u8 , 49u8 , 51u8 , 50u8 , 54u8 , 107u8 , 97u8 , 115u8 , 104u8 , 111u8 , 45u8 , 49u8 , 49u8 , 48u8 , 54u8 , 107u8 , 97u8 , 116u8 , 101u8 , 105u8 , 45u8 , 49u8 , 50u8 , 51u8 , 53u8 , 107u8 , 101u8 , 105u8 , 97u8 , 110u8 , 45u8 , 49u8 , 54u8 , 52u8 , 56u8 , 107u8 , 101u8 , 105u8 , 99u8 , 104u8 , 111u8 , 45u8 , 49u8 , 53u8 , 57u8 , 54u8 , 107u8 , 101u8 , 105u8 , 111u8 , 45u8 , 49u8 , 56u8 , 54u8 , 53u8 , 107u8 , 101u8 , 105u8 , 117u8 , 110u8 , 45u8 , 55u8 , 48u8 , 52u8 , 107u8 , 101u8 , 110u8 , 99u8 , 104u8 , 111u8 , 45u8 , 49u8 , 50u8 , 52u8 , 57u8 , 107u8 , 101u8 , 110u8 , 101u8 , 105u8 , 45u8 , 49u8 , 50u8 , 48u8 , 54u8 , 107u8 , 101u8 , 110u8 , 103u8 , 101u8 , 110u8 , 45u8 , 49u8 , 51u8 , 48u8 , 50u8 , 107u8 , 101u8 , 110u8 , 106u8 , 105u8 , 45u8 , 49u8 , 50u8 , 55u8 , 53u8 , 107u8 , 101u8 , 110u8 , 107u8 , 121u8 , 117u8 , 45u8 , 49u8 , 49u8 , 57u8 , 48u8 , 107u8 , 101u8 , 110u8 , 109u8 , 117u8 , 45u8 , 49u8 , 51u8 , 51u8 , 52u8 , 107u8 , 101u8 , 110u8 , 110u8 , 105u8 , 110u8 , 45u8 , 49u8 , 50u8 , 48u8 , 49u8 , 107u8 , 101u8 , 110u8 , 112u8 , 111u8 , 45u8 , 49u8 , 50u8 , 49u8 , 51u8 , 107u8 , 101u8 , 110u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 45u8 , 49u8 , 50u8 , 49u8 , 49u8 , 107u8 , 101u8 , 110u8 , 116u8 , 111u8 , 107u8 , 117u8 , 45u8 , 49u8 , 51u8 , 55u8 , 48u8 , 107u8 , 111u8 , 97u8 , 110u8 , 45u8 , 49u8 , 50u8 , 55u8 , 56u8 , 107u8 , 111u8 , 99u8 , 104u8 , 111u8 , 45u8 , 49u8 , 50u8 , 54u8 , 49u8 , 107u8 , 111u8 , 103u8 , 101u8 , 110u8 , 45u8 , 49u8 , 50u8 , 53u8 , 54u8 , 107u8 , 111u8 , 104u8 , 101u8 , 105u8 , 45u8 , 49u8 , 48u8 , 53u8 , 56u8 , 107u8 , 111u8 , 104u8 , 111u8 , 45u8 , 57u8 , 54u8 , 52u8 , 107u8 , 111u8 , 106u8 , 105u8 , 45u8 , 49u8 , 49u8 , 52u8 , 50u8 , 107u8 , 111u8 , 106u8 , 105u8 , 45u8 , 49u8 , 53u8 , 53u8 , 53u8 , 107u8 , 111u8 , 107u8 , 97u8 , 45u8 , 49u8 , 56u8 , 52u8 , 52u8 , 107u8 , 111u8 , 107u8 , 111u8 , 107u8 , 117u8 , 45u8 , 49u8 , 51u8 , 52u8 , 48u8 , 107u8 , 111u8 , 110u8 , 105u8 , 110u8 , 45u8 , 56u8 , 49u8 , 48u8 , 107u8 , 111u8 , 111u8 , 45u8 , 49u8 , 51u8 , 56u8 , 57u8 , 107u8 , 111u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 45u8 , 49u8 , 51u8 , 55u8 , 57u8 , 107u8 , 111u8 , 115u8 , 104u8 , 111u8 , 45u8 , 49u8 , 52u8 , 53u8 , 53u8 , 107u8 , 111u8 , 119u8 , 97u8 , 45u8 , 49u8 , 48u8 , 57u8 , 57u8 , 107u8 , 111u8 , 119u8 , 97u8 , 45u8 , 49u8 , 51u8 , 56u8 , 49u8 , 107u8 , 121u8 , 111u8 , 104u8 , 111u8 , 45u8 , 49u8 , 55u8 , 49u8 , 54u8 , 107u8 , 121u8 , 111u8 , 114u8 , 111u8 , 107u8 , 117u8 , 45u8 , 49u8 , 53u8 , 50u8 , 56u8 , 107u8 , 121u8 , 111u8 , 116u8 , 111u8 , 107u8 , 117u8 , 45u8 , 49u8 , 52u8 , 53u8 , 50u8 , 107u8 , 121u8 , 111u8 , 119u8 , 97u8 , 45u8 , 49u8 , 56u8 , 48u8 , 49u8 , 107u8 , 121u8 , 117u8 , 97u8 , 110u8 , 45u8 , 49u8 , 49u8 , 52u8 , 53u8 , 107u8 , 121u8 , 117u8 , 106u8 , 117u8 , 45u8 , 49u8 , 49u8 , 53u8 , 52u8 , 109u8 , 97u8 , 110u8 , 101u8 , 110u8 , 45u8 , 49u8 , 56u8 , 54u8 , 48u8 , 109u8 , 97u8 , 110u8 , 106u8 , 105u8 , 45u8 , 49u8 , 54u8 , 53u8 , 56u8 , 109u8 , 97u8 , 110u8 , 106u8 , 117u8 , 45u8 , 49u8 , 48u8 , 50u8 , 52u8 , 109u8 , 101u8 , 105u8 , 106u8 , 105u8 , 109u8 , 101u8 , 105u8 , 111u8 , 45u8 , 49u8 , 52u8 , 57u8 , 50u8 , 109u8 , 101u8 , 105u8 , 114u8 , 101u8 , 107u8 , 105u8 , 45u8 , 49u8 , 54u8 , 53u8 , 53u8 , 109u8 , 101u8 , 105u8 , 116u8 , 111u8 , 107u8 , 117u8 , 45u8 , 49u8 , 51u8 , 56u8 , 55u8 , 109u8 , 101u8 , 105u8 , 116u8 , 111u8 , 107u8 , 117u8 , 45u8 , 49u8 , 51u8 , 57u8 , 48u8 , 109u8 , 101u8 , 105u8 , 119u8 , 97u8 , 45u8 , 49u8 , 55u8 , 54u8 , 52u8 , 110u8 , 105u8 , 110u8 , 97u8 , 110u8 , 45u8 , 49u8 , 49u8 , 54u8 , 54u8 , 110u8 , 105u8 , 110u8 , 106u8 , 105u8 , 45u8 , 49u8 , 50u8 , 52u8 , 48u8 , 110u8 , 105u8 , 110u8 , 106u8 , 117u8 , 45u8 , 56u8 , 53u8 , 49u8 , 110u8 , 105u8 , 110u8 , 110u8 , 97u8 , 45u8 , 56u8 , 56u8 , 53u8 , 110u8 , 105u8 , 110u8 , 112u8 , 101u8 , 105u8 , 45u8 , 49u8 , 49u8 , 53u8 , 49u8 , 111u8 , 99u8 , 104u8 , 111u8 , 45u8 , 49u8 , 51u8 , 49u8 , 49u8 , 111u8 , 101u8 , 105u8 , 45u8 , 49u8 , 51u8 , 57u8 , 52u8 , 111u8 , 104u8 , 111u8 , 45u8 , 49u8 , 49u8 , 54u8 , 49u8 , 111u8 , 110u8 , 105u8 , 110u8 , 45u8 , 49u8 , 52u8 , 54u8 , 55u8 , 111u8 , 116u8 , 111u8 , 107u8 , 117u8 , 45u8 , 49u8 , 48u8 , 56u8 , 52u8 , 111u8 , 119u8 , 97u8 , 45u8 , 57u8 , 54u8 , 49u8 , 114u8 , 101u8 , 105u8 , 107u8 , 105u8 , 45u8 , 55u8 , 49u8 , 53u8 , 114u8 , 101u8 , 105u8 , 119u8 , 97u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 110u8 , 105u8 , 110u8 , 45u8 , 49u8 , 50u8 , 51u8 , 56u8 , 115u8 , 97u8 , 105u8 , 107u8 , 111u8 , 45u8 , 56u8 , 53u8 , 52u8 , 115u8 , 104u8 , 111u8 , 97u8 , 110u8 , 45u8 , 49u8 , 49u8 , 55u8 , 49u8 , 115u8 , 104u8 , 111u8 , 97u8 , 110u8 , 45u8 , 49u8 , 50u8 , 57u8 , 57u8 , 115u8 , 104u8 , 111u8 , 99u8 , 104u8 , 111u8 , 45u8 , 49u8 , 52u8 , 50u8 , 56u8 , 115u8 , 104u8 , 111u8 , 99u8 , 104u8 , 117u8 , 45u8 , 49u8 , 51u8 , 50u8 , 52u8 , 115u8 , 104u8 , 111u8 , 103u8 , 101u8 , 110u8 , 45u8 , 49u8 , 50u8 , 53u8 , 57u8 , 115u8 , 104u8 , 111u8 , 104u8 , 101u8 , 105u8 , 45u8 , 49u8 , 51u8 , 52u8 , 54u8 , 115u8 , 104u8 , 111u8 , 104u8 , 111u8 , 45u8 , 49u8 , 48u8 , 55u8 , 52u8 , 115u8 , 104u8 , 111u8 , 104u8 , 111u8 , 45u8 , 49u8 , 54u8 , 52u8 , 52u8 , 115u8 , 104u8 , 111u8 , 106u8 , 105u8 , 45u8 , 49u8 , 49u8 , 57u8 , 57u8 , 115u8 , 104u8 , 111u8 , 107u8 , 97u8 , 45u8 , 49u8 , 50u8 , 53u8 , 55u8 , 115u8 , 104u8 , 111u8 , 111u8 , 45u8 , 49u8 , 50u8 , 56u8 , 56u8 , 115u8 , 104u8 , 111u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 45u8 , 49u8 , 48u8 , 55u8 , 55u8 , 115u8 , 104u8 , 111u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 45u8 , 57u8 , 57u8 , 48u8 , 115u8 , 104u8 , 111u8 , 116u8 , 97u8 , 105u8 , 45u8 , 56u8 , 57u8 , 56u8 , 115u8 , 104u8 , 111u8 , 116u8 , 111u8 , 107u8 , 117u8 , 45u8 , 49u8 , 55u8 , 49u8 , 49u8 , 115u8 , 104u8 , 111u8 , 119u8 , 97u8 , 115u8 , 104u8 , 111u8 , 119u8 , 97u8 , 45u8 , 49u8 , 51u8 , 49u8 , 50u8 , 115u8 , 104u8 , 117u8 , 99u8 , 104u8 , 111u8 , 45u8 , 54u8 , 56u8 , 54u8 , 116u8 , 97u8 , 105u8 , 101u8 , 105u8 , 45u8 , 49u8 , 53u8 , 50u8 , 49u8 , 116u8 , 97u8 , 105u8 , 104u8 , 111u8 , 45u8 , 55u8 , 48u8 , 49u8 , 116u8 , 97u8 , 105u8 , 107u8 , 97u8 , 45u8 , 54u8 , 52u8 , 53u8 , 116u8 , 97u8 , 105u8 , 115u8 , 104u8 , 111u8 , 116u8 , 101u8 , 110u8 , 97u8 , 110u8 , 45u8 , 56u8 , 53u8 , 55u8 , 116u8 , 101u8 , 110u8 , 98u8 , 117u8 , 110u8 , 45u8 , 49u8 , 53u8 , 51u8 , 50u8 , 116u8 , 101u8 , 110u8 , 99u8 , 104u8 , 111u8 , 45u8 , 56u8 , 50u8 , 52u8 , 116u8 , 101u8 , 110u8 , 101u8 , 105u8 , 45u8 , 49u8 , 49u8 , 49u8 , 48u8 , 116u8 , 101u8 , 110u8 , 101u8 , 110u8 , 45u8 , 57u8 , 55u8 , 51u8 , 116u8 , 101u8 , 110u8 , 103u8 , 101u8 , 110u8 , 45u8 , 57u8 , 55u8 , 56u8 , 116u8 , 101u8 , 110u8 , 103u8 , 105u8 , 45u8 , 49u8 , 48u8 , 53u8 , 51u8 , 116u8 , 101u8 , 110u8 , 103u8 , 121u8 , 111u8 , 45u8 , 57u8 , 51u8 , 56u8 , 116u8 , 101u8 , 110u8 , 106u8 , 105u8 , 45u8 , 49u8 , 49u8 , 50u8 , 52u8 , 116u8 , 101u8 , 110u8 , 106u8 , 117u8 , 45u8 , 49u8 , 51u8 , 55u8 , 53u8 , 116u8 , 101u8 , 110u8 , 109u8 , 101u8 , 105u8 , 45u8 , 49u8 , 55u8 , 56u8 , 49u8 , 116u8 , 101u8 , 110u8 , 110u8 , 97u8 , 45u8 , 49u8 , 54u8 , 56u8 , 49u8 , 116u8 , 101u8 , 110u8 , 110u8 , 105u8 , 110u8 , 45u8 , 49u8 , 49u8 , 48u8 , 56u8 , 116u8 , 101u8 , 110u8 , 111u8 , 45u8 , 55u8 , 56u8 , 49u8 , 116u8 , 101u8 , 110u8 , 112u8 , 111u8 , 45u8 , 49u8 , 56u8 , 51u8 , 48u8 , 116u8 , 101u8 , 110u8 , 112u8 , 117u8 , 107u8 , 117u8 , 45u8 , 49u8 , 50u8 , 51u8 , 51u8 , 116u8 , 101u8 , 110u8 , 112u8 , 121u8 , 111u8 , 45u8 , 55u8 , 50u8 , 57u8 , 116u8 , 101u8 , 110u8 , 112u8 , 121u8 , 111u8 , 104u8 , 111u8 , 106u8 , 105u8 , 45u8 , 55u8 , 53u8 , 55u8 , 116u8 , 101u8 , 110u8 , 112u8 , 121u8 , 111u8 , 106u8 , 105u8 , 110u8 , 103u8 , 111u8 , 45u8 , 55u8 , 54u8 , 53u8 , 116u8 , 101u8 , 110u8 , 112u8 , 121u8 , 111u8 , 107u8 , 97u8 , 109u8 , 112u8 , 111u8 , 45u8 , 55u8 , 52u8 , 57u8 , 116u8 , 101u8 , 110u8 , 112u8 , 121u8 , 111u8 , 115u8 , 104u8 , 111u8 , 104u8 , 111u8 , 45u8 , 55u8 , 52u8 , 57u8 , 116u8 , 101u8 , 110u8 , 114u8 , 111u8 , 107u8 , 117u8 , 45u8 , 57u8 , 55u8 , 48u8 , 116u8 , 101u8 , 110u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 45u8 , 57u8 , 52u8 , 55u8 , 116u8 , 101u8 , 110u8 , 115u8 , 104u8 , 111u8 , 45u8 , 49u8 , 49u8 , 51u8 , 49u8 , 116u8 , 101u8 , 110u8 , 115u8 , 104u8 , 111u8 , 45u8 , 49u8 , 53u8 , 55u8 , 51u8 , 116u8 , 101u8 , 110u8 , 116u8 , 111u8 , 107u8 , 117u8 , 45u8 , 57u8 , 53u8 , 55u8 , 116u8 , 101u8 , 110u8 , 121u8 , 111u8 , 45u8 , 49u8 , 49u8 , 52u8 , 52u8 , 116u8 , 111u8 , 107u8 , 117u8 , 106u8 , 105u8 , 45u8 , 49u8 , 51u8 , 48u8 , 54u8 , 119u8 , 97u8 , 100u8 , 111u8 , 45u8 , 55u8 , 48u8 , 56u8 , 121u8 , 111u8 , 114u8 , 111u8 , 45u8 , 55u8 , 49u8 , 55u8 , 121u8 , 111u8 , 119u8 , 97u8 , 45u8 , 49u8 , 49u8 , 56u8 , 49u8]) } , unsafe { :: zerovec :: VarZeroVec :: from_bytes_unchecked (& [239u8 , 0u8 , 0u8 , 0u8 , 0u8 , 0u8 , 21u8 , 0u8 , 40u8 , 0u8 , 56u8 , 0u8 , 75u8 , 0u8 , 94u8 , 0u8 , 150u8 , 0u8 , 172u8 , 0u8 , 193u8 , 0u8 , 215u8 , 0u8 , 234u8 , 0u8 , 253u8 , 0u8 , 16u8 , 1u8 , 37u8 , 1u8 , 57u8 , 1u8 , 79u8 , 1u8 , 99u8 , 1u8 , 120u8 , 1u8 , 142u8 , 1u8 , 162u8 , 1u8 , 183u8 , 1u8 , 227u8 , 1u8 , 248u8 , 1u8 , 12u8 , 2u8 , 32u8 , 2u8 , 53u8 , 2u8 , 75u8 , 2u8 , 97u8 , 2u8 , 119u8 , 2u8 , 142u8 , 2u8 , 164u8 , 2u8 , 184u8 , 2u8 , 204u8 , 2u8 , 222u8 , 2u8 , 241u8 , 2u8 , 5u8 , 3u8 , 21u8 , 3u8 , 40u8 , 3u8 , 58u8 , 3u8 , 75u8 , 3u8 , 95u8 , 3u8 , 115u8 , 3u8 , 134u8 , 3u8 , 153u8 , 3u8 , 173u8 , 3u8 , 194u8 , 3u8 , 214u8 , 3u8 , 234u8 , 3u8 , 250u8 , 3u8 , 12u8 , 4u8 , 31u8 , 4u8 , 47u8 , 4u8 , 67u8 , 4u8 , 87u8 , 4u8 , 107u8 , 4u8 , 128u8 , 4u8 , 147u8 , 4u8 , 166u8 , 4u8 , 186u8 , 4u8 , 205u8 , 4u8 , 225u8 , 4u8 , 246u8 , 4u8 , 12u8 , 5u8 , 31u8 , 5u8 , 50u8 , 5u8 , 70u8 , 5u8 , 90u8 , 5u8 , 111u8 , 5u8 , 130u8 , 5u8 , 150u8 , 5u8 , 169u8 , 5u8 , 190u8 , 5u8 , 212u8 , 5u8 , 233u8 , 5u8 , 252u8 , 5u8 , 15u8 , 6u8 , 34u8 , 6u8 , 40u8 , 6u8 , 59u8 , 6u8 , 78u8 , 6u8 , 97u8 , 6u8 , 117u8 , 6u8 , 136u8 , 6u8 , 153u8 , 6u8 , 174u8 , 6u8 , 195u8 , 6u8 , 213u8 , 6u8 , 236u8 , 6u8 , 253u8 , 6u8 , 18u8 , 7u8 , 38u8 , 7u8 , 57u8 , 7u8 , 75u8 , 7u8 , 95u8 , 7u8 , 113u8 , 7u8 , 131u8 , 7u8 , 152u8 , 7u8 , 173u8 , 7u8 , 192u8 , 7u8 , 211u8 , 7u8 , 232u8 , 7u8 , 249u8 , 7u8 , 11u8 , 8u8 , 29u8 , 8u8 , 48u8 , 8u8 , 67u8 , 8u8 , 84u8 , 8u8 , 103u8 , 8u8 , 124u8 , 8u8 , 144u8 , 8u8 , 166u8 , 8u8 , 188u8 , 8u8 , 208u8 , 8u8 , 227u8 , 8u8 , 246u8 , 8u8 , 10u8 , 9u8 , 27u8 , 9u8 , 47u8 , 9u8 , 67u8 , 9u8 , 86u8 , 9u8 , 106u8 , 9u8 , 127u8 , 9u8 , 148u8 , 9u8 , 166u8 , 9u8 , 186u8 , 9u8 , 207u8 , 9u8 , 227u8 , 9u8 , 246u8 , 9u8 , 9u8 , 10u8 , 30u8 , 10u8 , 49u8 , 10u8 , 66u8 , 10u8 , 87u8 , 10u8 , 109u8 , 10u8 , 129u8 , 10u8 , 148u8 , 10u8 , 169u8 , 10u8 , 188u8 , 10u8 , 208u8 , 10u8 , 228u8 , 10u8 , 250u8 , 10u8 , 15u8 , 11u8 , 34u8 , 11u8 , 55u8 , 11u8 , 75u8 , 11u8 , 95u8 , 11u8 , 113u8 , 11u8 , 132u8 , 11u8 , 151u8 , 11u8 , 170u8 , 11u8 , 191u8 , 11u8 , 209u8 , 11u8 , 228u8 , 11u8 , 250u8 , 11u8 , 15u8 , 12u8 , 34u8 , 12u8 , 53u8 , 12u8 , 74u8 , 12u8 , 96u8 , 12u8 , 118u8 , 12u8 , 138u8 , 12u8 , 158u8 , 12u8 , 178u8 , 12u8 , 200u8 , 12u8 , 219u8 , 12u8 , 238u8 , 12u8 , 243u8 , 12u8 , 6u8 , 13u8 , 27u8 , 13u8 , 48u8 , 13u8 , 69u8 , 13u8 , 88u8 , 13u8 , 110u8 , 13u8 , 129u8 , 13u8 , 146u8 , 13u8 , 163u8 , 13u8 , 183u8 , 13u8 , 203u8 , 13u8 , 221u8 , 13u8 , 239u8 , 13u8 , 2u8 , 14u8 , 22u8 , 14u8 , 38u8 , 14u8 , 55u8 , 14u8 , 60u8 , 14u8 , 82u8 , 14u8 , 100u8 , 14u8 , 120u8 , 14u8 , 140u8 , 14u8 , 162u8 , 14u8 , 184u8 , 14u8 , 205u8 , 14u8 , 226u8 , 14u8 , 246u8 , 14u8 , 10u8 , 15u8 , 30u8 , 15u8 , 50u8 , 15u8 , 70u8 , 15u8 , 93u8 , 15u8 , 114u8 , 15u8 , 133u8 , 15u8 , 155u8 , 15u8 , 161u8 , 15u8 , 181u8 , 15u8 , 200u8 , 15u8 , 219u8 , 15u8 , 237u8 , 15u8 , 254u8 , 15u8 , 5u8 , 16u8 , 23u8 , 16u8 , 43u8 , 16u8 , 62u8 , 16u8 , 82u8 , 16u8 , 102u8 , 16u8 , 120u8 , 16u8 , 139u8 , 16u8 , 158u8 , 16u8 , 177u8 , 16u8 , 196u8 , 16u8 , 216u8 , 16u8 , 235u8 , 16u8 , 255u8 , 16u8 , 17u8 , 17u8 , 37u8 , 17u8 , 58u8 , 17u8 , 77u8 , 17u8 , 102u8 , 17u8 , 127u8 , 17u8 , 153u8 , 17u8 , 180u8 , 17u8 , 199u8 , 17u8 , 219u8 , 17u8 , 240u8 , 17u8 , 5u8 , 18u8 , 24u8 , 18u8 , 47u8 , 18u8 , 67u8 , 18u8 , 84u8 , 18u8 , 102u8 , 18u8 , 65u8 , 110u8 , 226u8 , 128u8 , 153u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 55u8 , 55u8 , 50u8 , 226u8 , 128u8 , 147u8 , 49u8 , 55u8 , 56u8 , 49u8 , 41u8 , 65u8 , 110u8 , 103u8 , 101u8 , 110u8 , 32u8 , 40u8 , 49u8 , 49u8 , 55u8 , 53u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 55u8 , 55u8 , 41u8 , 65u8 , 110u8 , 110u8 , 97u8 , 32u8 , 40u8 , 57u8 , 54u8 , 56u8 , 226u8 , 128u8 , 147u8 , 57u8 , 55u8 , 48u8 , 41u8 , 65u8 , 110u8 , 115u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 56u8 , 53u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 56u8 , 54u8 , 48u8 , 41u8 , 65u8 , 110u8 , 116u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 50u8 , 50u8 , 55u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 50u8 , 57u8 , 41u8 , 240u8 , 145u8 , 132u8 , 136u8 , 240u8 , 145u8 , 132u8 , 179u8 , 240u8 , 145u8 , 132u8 , 162u8 , 240u8 , 145u8 , 132u8 , 168u8 , 240u8 , 145u8 , 132u8 , 140u8 , 240u8 , 145u8 , 132u8 , 180u8 , 240u8 , 145u8 , 132u8 , 145u8 , 240u8 , 145u8 , 132u8 , 180u8 , 240u8 , 145u8 , 132u8 , 155u8 , 240u8 , 145u8 , 132u8 , 171u8 , 240u8 , 145u8 , 132u8 , 162u8 , 240u8 , 145u8 , 132u8 , 180u8 , 240u8 , 145u8 , 132u8 , 157u8 , 240u8 , 145u8 , 132u8 , 167u8 , 66u8 , 117u8 , 110u8 , 226u8 , 128u8 , 153u8 , 97u8 , 110u8 , 32u8 , 40u8 , 49u8 , 52u8 , 52u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 52u8 , 52u8 , 57u8 , 41u8 , 66u8 , 117u8 , 110u8 , 99u8 , 104u8 , 197u8 , 171u8 , 32u8 , 40u8 , 49u8 , 51u8 , 55u8 , 50u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 55u8 , 53u8 , 41u8 , 66u8 , 117u8 , 110u8 , 226u8 , 128u8 , 153u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 50u8 , 54u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 55u8 , 53u8 , 41u8 , 66u8 , 117u8 , 110u8 , 106u8 , 105u8 , 32u8 , 40u8 , 49u8 , 49u8 , 56u8 , 53u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 57u8 , 48u8 , 41u8 , 66u8 , 117u8 , 110u8 , 107u8 , 97u8 , 32u8 , 40u8 , 49u8 , 56u8 , 48u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 56u8 , 49u8 , 56u8 , 41u8 , 66u8 , 117u8 , 110u8 , 107u8 , 105u8 , 32u8 , 40u8 , 49u8 , 53u8 , 48u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 53u8 , 48u8 , 52u8 , 41u8 , 66u8 , 117u8 , 110u8 , 107u8 , 121u8 , 197u8 , 171u8 , 32u8 , 40u8 , 49u8 , 56u8 , 54u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 56u8 , 54u8 , 52u8 , 41u8 , 66u8 , 117u8 , 110u8 , 109u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 52u8 , 54u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 52u8 , 56u8 , 55u8 , 41u8 , 66u8 , 117u8 , 110u8 , 226u8 , 128u8 , 153u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 50u8 , 54u8 , 48u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 54u8 , 49u8 , 41u8 , 66u8 , 117u8 , 110u8 , 112u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 51u8 , 49u8 , 55u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 49u8 , 57u8 , 41u8 , 66u8 , 117u8 , 110u8 , 114u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 53u8 , 57u8 , 50u8 , 226u8 , 128u8 , 147u8 , 49u8 , 53u8 , 57u8 , 54u8 , 41u8 , 66u8 , 117u8 , 110u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 50u8 , 51u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 51u8 , 53u8 , 41u8 , 66u8 , 117u8 , 110u8 , 115u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 56u8 , 49u8 , 56u8 , 226u8 , 128u8 , 147u8 , 49u8 , 56u8 , 51u8 , 48u8 , 41u8 , 66u8 , 117u8 , 110u8 , 115u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 52u8 , 54u8 , 54u8 , 226u8 , 128u8 , 147u8 , 49u8 , 52u8 , 54u8 , 55u8 , 41u8 , 240u8 , 145u8 , 132u8 , 136u8 , 240u8 , 145u8 , 132u8 , 179u8 , 240u8 , 145u8 , 132u8 , 162u8 , 240u8 , 145u8 , 132u8 , 168u8 , 240u8 , 145u8 , 132u8 , 140u8 , 240u8 , 145u8 , 132u8 , 180u8 , 240u8 , 145u8 , 132u8 , 145u8 , 240u8 , 145u8 , 132u8 , 155u8 , 240u8 , 145u8 , 132u8 , 180u8 , 240u8 , 145u8 , 132u8 , 152u8 , 240u8 , 145u8 , 132u8 , 167u8 , 67u8 , 104u8 , 197u8 , 141u8 , 103u8 , 101u8 , 110u8 , 32u8 , 40u8 , 49u8 , 48u8 , 50u8 , 56u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 51u8 , 55u8 , 41u8 , 67u8 , 104u8 , 197u8 , 141u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 57u8 , 57u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 48u8 , 52u8 , 41u8 , 67u8 , 104u8 , 197u8 , 141u8 , 106u8 , 105u8 , 32u8 , 40u8 , 49u8 , 49u8 , 48u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 48u8 , 54u8 , 41u8 , 67u8 , 104u8 , 197u8 , 141u8 , 107u8 , 97u8 , 110u8 , 32u8 , 40u8 , 49u8 , 49u8 , 54u8 , 51u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 54u8 , 53u8 , 41u8 , 67u8 , 104u8 , 197u8 , 141u8 , 107u8 , 121u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 52u8 , 56u8 , 55u8 , 226u8 , 128u8 , 147u8 , 49u8 , 52u8 , 56u8 , 57u8 , 41u8 , 67u8 , 104u8 , 197u8 , 141u8 , 107u8 , 121u8 , 197u8 , 171u8 , 32u8 , 40u8 , 49u8 , 48u8 , 52u8 , 48u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 52u8 , 52u8 , 41u8 , 67u8 , 104u8 , 197u8 , 141u8 , 114u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 52u8 , 53u8 , 55u8 , 226u8 , 128u8 , 147u8 , 49u8 , 52u8 , 54u8 , 48u8 , 41u8 , 67u8 , 104u8 , 197u8 , 141u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 48u8 , 51u8 , 55u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 52u8 , 48u8 , 41u8 , 67u8 , 104u8 , 197u8 , 141u8 , 115u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 49u8 , 51u8 , 50u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 51u8 , 53u8 , 41u8 , 67u8 , 104u8 , 197u8 , 141u8 , 116u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 57u8 , 57u8 , 53u8 , 226u8 , 128u8 , 147u8 , 57u8 , 57u8 , 57u8 , 41u8 , 67u8 , 104u8 , 197u8 , 141u8 , 119u8 , 97u8 , 32u8 , 40u8 , 49u8 , 48u8 , 49u8 , 50u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 49u8 , 55u8 , 41u8 , 68u8 , 97u8 , 105u8 , 100u8 , 197u8 , 141u8 , 32u8 , 40u8 , 56u8 , 48u8 , 54u8 , 226u8 , 128u8 , 147u8 , 56u8 , 49u8 , 48u8 , 41u8 , 68u8 , 97u8 , 105u8 , 106u8 , 105u8 , 32u8 , 40u8 , 49u8 , 49u8 , 50u8 , 54u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 51u8 , 49u8 , 41u8 , 69u8 , 105u8 , 99u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 48u8 , 57u8 , 54u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 57u8 , 55u8 , 41u8 , 69u8 , 105u8 , 101u8 , 110u8 , 32u8 , 40u8 , 57u8 , 56u8 , 55u8 , 226u8 , 128u8 , 147u8 , 57u8 , 56u8 , 57u8 , 41u8 , 69u8 , 105u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 48u8 , 56u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 56u8 , 52u8 , 41u8 , 69u8 , 105u8 , 106u8 , 105u8 , 32u8 , 40u8 , 49u8 , 49u8 , 52u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 52u8 , 50u8 , 41u8 , 69u8 , 105u8 , 107u8 , 97u8 , 110u8 , 32u8 , 40u8 , 57u8 , 56u8 , 51u8 , 226u8 , 128u8 , 147u8 , 57u8 , 56u8 , 53u8 , 41u8 , 69u8 , 105u8 , 107u8 , 121u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 52u8 , 50u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 52u8 , 52u8 , 49u8 , 41u8 , 69u8 , 105u8 , 107u8 , 121u8 , 197u8 , 171u8 , 32u8 , 40u8 , 49u8 , 49u8 , 49u8 , 51u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 49u8 , 56u8 , 41u8 , 69u8 , 105u8 , 109u8 , 97u8 , 110u8 , 32u8 , 40u8 , 49u8 , 49u8 , 54u8 , 53u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 54u8 , 54u8 , 41u8 , 69u8 , 105u8 , 110u8 , 105u8 , 110u8 , 32u8 , 40u8 , 49u8 , 50u8 , 57u8 , 51u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 57u8 , 57u8 , 41u8 , 69u8 , 105u8 , 114u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 53u8 , 53u8 , 56u8 , 226u8 , 128u8 , 147u8 , 49u8 , 53u8 , 55u8 , 48u8 , 41u8 , 69u8 , 105u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 49u8 , 54u8 , 48u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 54u8 , 49u8 , 41u8 , 69u8 , 105u8 , 115u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 48u8 , 52u8 , 54u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 53u8 , 51u8 , 41u8 , 69u8 , 105u8 , 115u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 53u8 , 48u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 53u8 , 50u8 , 49u8 , 41u8 , 69u8 , 105u8 , 115u8 , 111u8 , 32u8 , 40u8 , 57u8 , 56u8 , 57u8 , 226u8 , 128u8 , 147u8 , 57u8 , 57u8 , 48u8 , 41u8 , 69u8 , 110u8 , 99u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 57u8 , 50u8 , 51u8 , 226u8 , 128u8 , 147u8 , 57u8 , 51u8 , 49u8 , 41u8 , 69u8 , 110u8 , 103u8 , 101u8 , 110u8 , 32u8 , 40u8 , 49u8 , 51u8 , 51u8 , 54u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 52u8 , 48u8 , 41u8 , 69u8 , 110u8 , 103u8 , 105u8 , 32u8 , 40u8 , 57u8 , 48u8 , 49u8 , 226u8 , 128u8 , 147u8 , 57u8 , 50u8 , 51u8 , 41u8 , 69u8 , 110u8 , 107u8 , 121u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 51u8 , 48u8 , 56u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 49u8 , 49u8 , 41u8 , 69u8 , 110u8 , 107u8 , 121u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 55u8 , 52u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 55u8 , 52u8 , 56u8 , 41u8 , 69u8 , 110u8 , 107u8 , 121u8 , 197u8 , 171u8 , 32u8 , 40u8 , 49u8 , 48u8 , 54u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 55u8 , 52u8 , 41u8 , 69u8 , 110u8 , 226u8 , 128u8 , 153u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 50u8 , 51u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 52u8 , 48u8 , 41u8 , 69u8 , 110u8 , 112u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 54u8 , 55u8 , 51u8 , 226u8 , 128u8 , 147u8 , 49u8 , 54u8 , 56u8 , 49u8 , 41u8 , 69u8 , 110u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 32u8 , 40u8 , 55u8 , 56u8 , 50u8 , 226u8 , 128u8 , 147u8 , 56u8 , 48u8 , 54u8 , 41u8 , 69u8 , 110u8 , 116u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 52u8 , 56u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 52u8 , 57u8 , 50u8 , 41u8 , 71u8 , 97u8 , 110u8 , 103u8 , 121u8 , 197u8 , 141u8 , 32u8 , 40u8 , 56u8 , 55u8 , 55u8 , 226u8 , 128u8 , 147u8 , 56u8 , 56u8 , 53u8 , 41u8 , 71u8 , 101u8 , 110u8 , 98u8 , 117u8 , 110u8 , 32u8 , 40u8 , 49u8 , 55u8 , 51u8 , 54u8 , 226u8 , 128u8 , 147u8 , 49u8 , 55u8 , 52u8 , 49u8 , 41u8 , 71u8 , 101u8 , 110u8 , 99u8 , 104u8 , 197u8 , 171u8 , 32u8 , 40u8 , 49u8 , 51u8 , 56u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 57u8 , 50u8 , 41u8 , 71u8 , 101u8 , 110u8 , 226u8 , 128u8 , 153u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 49u8 , 49u8 , 56u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 50u8 , 48u8 , 41u8 , 71u8 , 101u8 , 110u8 , 106u8 , 105u8 , 32u8 , 40u8 , 49u8 , 56u8 , 54u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 56u8 , 54u8 , 53u8 , 41u8 , 71u8 , 101u8 , 110u8 , 107u8 , 105u8 , 32u8 , 40u8 , 49u8 , 53u8 , 55u8 , 48u8 , 226u8 , 128u8 , 147u8 , 49u8 , 53u8 , 55u8 , 51u8 , 41u8 , 71u8 , 101u8 , 110u8 , 107u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 51u8 , 50u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 50u8 , 52u8 , 41u8 , 71u8 , 101u8 , 110u8 , 107u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 51u8 , 51u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 51u8 , 52u8 , 41u8 , 71u8 , 101u8 , 110u8 , 107u8 , 121u8 , 197u8 , 171u8 , 32u8 , 40u8 , 49u8 , 50u8 , 48u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 48u8 , 54u8 , 41u8 , 71u8 , 101u8 , 110u8 , 110u8 , 97u8 , 32u8 , 40u8 , 49u8 , 54u8 , 49u8 , 53u8 , 226u8 , 128u8 , 147u8 , 49u8 , 54u8 , 50u8 , 52u8 , 41u8 , 71u8 , 101u8 , 110u8 , 110u8 , 105u8 , 110u8 , 32u8 , 40u8 , 49u8 , 50u8 , 50u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 50u8 , 53u8 , 41u8 , 71u8 , 101u8 , 110u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 51u8 , 49u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 50u8 , 49u8 , 41u8 , 71u8 , 101u8 , 110u8 , 114u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 54u8 , 56u8 , 56u8 , 226u8 , 128u8 , 147u8 , 49u8 , 55u8 , 48u8 , 52u8 , 41u8 , 71u8 , 101u8 , 110u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 49u8 , 56u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 56u8 , 53u8 , 41u8 , 71u8 , 101u8 , 110u8 , 116u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 51u8 , 50u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 51u8 , 49u8 , 41u8 , 72u8 , 97u8 , 107u8 , 117u8 , 99u8 , 104u8 , 105u8 , 32u8 , 40u8 , 54u8 , 53u8 , 48u8 , 226u8 , 128u8 , 147u8 , 54u8 , 55u8 , 49u8 , 41u8 , 72u8 , 97u8 , 107u8 , 117u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 54u8 , 55u8 , 50u8 , 226u8 , 128u8 , 147u8 , 54u8 , 56u8 , 54u8 , 41u8 , 72u8 , 101u8 , 105u8 , 106u8 , 105u8 , 32u8 , 40u8 , 49u8 , 49u8 , 53u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 54u8 , 48u8 , 41u8 , 72u8 , 101u8 , 105u8 , 115u8 , 101u8 , 105u8 , 72u8 , 197u8 , 141u8 , 97u8 , 110u8 , 32u8 , 40u8 , 49u8 , 49u8 , 50u8 , 48u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 50u8 , 52u8 , 41u8 , 72u8 , 197u8 , 141u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 55u8 , 48u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 55u8 , 49u8 , 49u8 , 41u8 , 72u8 , 197u8 , 141u8 , 101u8 , 110u8 , 32u8 , 40u8 , 49u8 , 49u8 , 51u8 , 53u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 52u8 , 49u8 , 41u8 , 72u8 , 197u8 , 141u8 , 103u8 , 101u8 , 110u8 , 32u8 , 40u8 , 49u8 , 49u8 , 53u8 , 54u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 53u8 , 57u8 , 41u8 , 72u8 , 197u8 , 141u8 , 106u8 , 105u8 , 32u8 , 40u8 , 49u8 , 50u8 , 52u8 , 55u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 52u8 , 57u8 , 41u8 , 72u8 , 197u8 , 141u8 , 107u8 , 105u8 , 32u8 , 40u8 , 55u8 , 55u8 , 48u8 , 226u8 , 128u8 , 147u8 , 55u8 , 56u8 , 48u8 , 41u8 , 72u8 , 197u8 , 141u8 , 114u8 , 101u8 , 107u8 , 105u8 , 32u8 , 40u8 , 49u8 , 55u8 , 53u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 55u8 , 54u8 , 52u8 , 41u8 , 72u8 , 197u8 , 141u8 , 116u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 52u8 , 52u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 52u8 , 53u8 , 50u8 , 41u8 , 74u8 , 105u8 , 97u8 , 110u8 , 32u8 , 40u8 , 49u8 , 48u8 , 50u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 50u8 , 52u8 , 41u8 , 74u8 , 105u8 , 110u8 , 103u8 , 111u8 , 45u8 , 107u8 , 101u8 , 105u8 , 117u8 , 110u8 , 32u8 , 40u8 , 55u8 , 54u8 , 55u8 , 226u8 , 128u8 , 147u8 , 55u8 , 55u8 , 48u8 , 41u8 , 74u8 , 105u8 , 110u8 , 107u8 , 105u8 , 32u8 , 40u8 , 55u8 , 50u8 , 52u8 , 226u8 , 128u8 , 147u8 , 55u8 , 50u8 , 57u8 , 41u8 , 74u8 , 105u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 48u8 , 54u8 , 53u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 54u8 , 57u8 , 41u8 , 74u8 , 105u8 , 115u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 49u8 , 55u8 , 55u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 56u8 , 49u8 , 41u8 , 74u8 , 197u8 , 141u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 50u8 , 51u8 , 50u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 51u8 , 51u8 , 41u8 , 74u8 , 197u8 , 141u8 , 103u8 , 97u8 , 110u8 , 32u8 , 40u8 , 56u8 , 53u8 , 57u8 , 226u8 , 128u8 , 147u8 , 56u8 , 55u8 , 55u8 , 41u8 , 74u8 , 197u8 , 141u8 , 103u8 , 101u8 , 110u8 , 32u8 , 40u8 , 49u8 , 50u8 , 48u8 , 55u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 49u8 , 49u8 , 41u8 , 74u8 , 197u8 , 141u8 , 103u8 , 101u8 , 110u8 , 32u8 , 40u8 , 57u8 , 55u8 , 54u8 , 226u8 , 128u8 , 147u8 , 57u8 , 55u8 , 56u8 , 41u8 , 74u8 , 197u8 , 141u8 , 104u8 , 101u8 , 105u8 , 32u8 , 40u8 , 57u8 , 51u8 , 49u8 , 226u8 , 128u8 , 147u8 , 57u8 , 51u8 , 56u8 , 41u8 , 74u8 , 197u8 , 141u8 , 107u8 , 121u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 54u8 , 56u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 54u8 , 56u8 , 56u8 , 41u8 , 74u8 , 197u8 , 141u8 , 107u8 , 121u8 , 197u8 , 171u8 , 32u8 , 40u8 , 49u8 , 50u8 , 49u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 50u8 , 50u8 , 41u8 , 74u8 , 197u8 , 141u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 50u8 , 50u8 , 50u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 50u8 , 52u8 , 41u8 , 74u8 , 197u8 , 141u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 54u8 , 53u8 , 50u8 , 226u8 , 128u8 , 147u8 , 49u8 , 54u8 , 53u8 , 53u8 , 41u8 , 74u8 , 197u8 , 141u8 , 116u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 48u8 , 57u8 , 55u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 57u8 , 57u8 , 41u8 , 74u8 , 197u8 , 141u8 , 119u8 , 97u8 , 32u8 , 40u8 , 56u8 , 51u8 , 52u8 , 226u8 , 128u8 , 147u8 , 56u8 , 52u8 , 56u8 , 41u8 , 74u8 , 117u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 49u8 , 56u8 , 50u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 56u8 , 52u8 , 41u8 , 75u8 , 97u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 56u8 , 52u8 , 56u8 , 226u8 , 128u8 , 147u8 , 49u8 , 56u8 , 53u8 , 52u8 , 41u8 , 75u8 , 97u8 , 103u8 , 101u8 , 110u8 , 32u8 , 40u8 , 49u8 , 51u8 , 48u8 , 51u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 48u8 , 54u8 , 41u8 , 75u8 , 97u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 48u8 , 57u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 57u8 , 54u8 , 41u8 , 75u8 , 97u8 , 106u8 , 197u8 , 141u8 , 32u8 , 40u8 , 56u8 , 52u8 , 56u8 , 226u8 , 128u8 , 147u8 , 56u8 , 53u8 , 49u8 , 41u8 , 75u8 , 97u8 , 107u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 51u8 , 56u8 , 55u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 56u8 , 57u8 , 41u8 , 75u8 , 97u8 , 107u8 , 105u8 , 116u8 , 115u8 , 117u8 , 32u8 , 40u8 , 49u8 , 52u8 , 52u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 52u8 , 52u8 , 52u8 , 41u8 , 75u8 , 97u8 , 110u8 , 98u8 , 117u8 , 110u8 , 32u8 , 40u8 , 49u8 , 54u8 , 54u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 54u8 , 55u8 , 51u8 , 41u8 , 75u8 , 97u8 , 110u8 , 226u8 , 128u8 , 153u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 54u8 , 50u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 54u8 , 52u8 , 52u8 , 41u8 , 75u8 , 97u8 , 110u8 , 226u8 , 128u8 , 153u8 , 101u8 , 110u8 , 32u8 , 40u8 , 49u8 , 55u8 , 52u8 , 56u8 , 226u8 , 128u8 , 147u8 , 49u8 , 55u8 , 53u8 , 49u8 , 41u8 , 75u8 , 97u8 , 110u8 , 103u8 , 101u8 , 110u8 , 32u8 , 40u8 , 49u8 , 50u8 , 52u8 , 51u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 52u8 , 55u8 , 41u8 , 75u8 , 97u8 , 110u8 , 106u8 , 105u8 , 32u8 , 40u8 , 49u8 , 48u8 , 56u8 , 55u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 57u8 , 52u8 , 41u8 , 75u8 , 97u8 , 110u8 , 107u8 , 105u8 , 32u8 , 40u8 , 49u8 , 50u8 , 50u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 51u8 , 50u8 , 41u8 , 75u8 , 97u8 , 110u8 , 107u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 48u8 , 48u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 49u8 , 50u8 , 41u8 , 75u8 , 97u8 , 110u8 , 110u8 , 97u8 , 32u8 , 40u8 , 57u8 , 56u8 , 53u8 , 226u8 , 128u8 , 147u8 , 57u8 , 56u8 , 55u8 , 41u8 , 75u8 , 97u8 , 110u8 , 110u8 , 105u8 , 110u8 , 32u8 , 40u8 , 49u8 , 48u8 , 49u8 , 55u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 50u8 , 49u8 , 41u8 , 75u8 , 97u8 , 110u8 , 112u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 55u8 , 52u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 55u8 , 52u8 , 52u8 , 41u8 , 75u8 , 97u8 , 110u8 , 112u8 , 121u8 , 197u8 , 141u8 , 32u8 , 40u8 , 56u8 , 56u8 , 57u8 , 226u8 , 128u8 , 147u8 , 56u8 , 57u8 , 56u8 , 41u8 , 75u8 , 97u8 , 110u8 , 115u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 55u8 , 56u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 56u8 , 48u8 , 49u8 , 41u8 , 75u8 , 97u8 , 110u8 , 115u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 52u8 , 54u8 , 48u8 , 226u8 , 128u8 , 147u8 , 49u8 , 52u8 , 54u8 , 54u8 , 41u8 , 75u8 , 97u8 , 110u8 , 116u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 48u8 , 52u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 52u8 , 54u8 , 41u8 , 75u8 , 97u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 49u8 , 54u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 55u8 , 49u8 , 41u8 , 75u8 , 97u8 , 114u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 50u8 , 50u8 , 53u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 50u8 , 55u8 , 41u8 , 75u8 , 97u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 51u8 , 50u8 , 54u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 50u8 , 57u8 , 41u8 , 75u8 , 97u8 , 115u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 49u8 , 48u8 , 54u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 48u8 , 56u8 , 41u8 , 75u8 , 97u8 , 116u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 50u8 , 51u8 , 53u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 51u8 , 56u8 , 41u8 , 75u8 , 101u8 , 105u8 , 97u8 , 110u8 , 32u8 , 40u8 , 49u8 , 54u8 , 52u8 , 56u8 , 226u8 , 128u8 , 147u8 , 49u8 , 54u8 , 53u8 , 50u8 , 41u8 , 75u8 , 101u8 , 105u8 , 99u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 53u8 , 57u8 , 54u8 , 226u8 , 128u8 , 147u8 , 49u8 , 54u8 , 49u8 , 53u8 , 41u8 , 75u8 , 101u8 , 105u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 56u8 , 54u8 , 53u8 , 226u8 , 128u8 , 147u8 , 49u8 , 56u8 , 54u8 , 56u8 , 41u8 , 75u8 , 101u8 , 105u8 , 117u8 , 110u8 , 32u8 , 40u8 , 55u8 , 48u8 , 52u8 , 226u8 , 128u8 , 147u8 , 55u8 , 48u8 , 56u8 , 41u8 , 75u8 , 101u8 , 110u8 , 99u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 50u8 , 52u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 53u8 , 54u8 , 41u8 , 75u8 , 101u8 , 110u8 , 226u8 , 128u8 , 153u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 50u8 , 48u8 , 54u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 48u8 , 55u8 , 41u8 , 75u8 , 101u8 , 110u8 , 103u8 , 101u8 , 110u8 , 32u8 , 40u8 , 49u8 , 51u8 , 48u8 , 50u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 48u8 , 51u8 , 41u8 , 75u8 , 101u8 , 110u8 , 106u8 , 105u8 , 32u8 , 40u8 , 49u8 , 50u8 , 55u8 , 53u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 55u8 , 56u8 , 41u8 , 75u8 , 101u8 , 110u8 , 107u8 , 121u8 , 197u8 , 171u8 , 32u8 , 40u8 , 49u8 , 49u8 , 57u8 , 48u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 57u8 , 57u8 , 41u8 , 75u8 , 101u8 , 110u8 , 109u8 , 117u8 , 32u8 , 40u8 , 49u8 , 51u8 , 51u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 51u8 , 54u8 , 41u8 , 75u8 , 101u8 , 110u8 , 110u8 , 105u8 , 110u8 , 32u8 , 40u8 , 49u8 , 50u8 , 48u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 48u8 , 52u8 , 41u8 , 75u8 , 101u8 , 110u8 , 112u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 50u8 , 49u8 , 51u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 49u8 , 57u8 , 41u8 , 75u8 , 101u8 , 110u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 50u8 , 49u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 49u8 , 51u8 , 41u8 , 75u8 , 101u8 , 110u8 , 116u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 51u8 , 55u8 , 48u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 55u8 , 50u8 , 41u8 , 75u8 , 197u8 , 141u8 , 97u8 , 110u8 , 32u8 , 40u8 , 49u8 , 50u8 , 55u8 , 56u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 56u8 , 56u8 , 41u8 , 75u8 , 197u8 , 141u8 , 99u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 50u8 , 54u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 54u8 , 52u8 , 41u8 , 75u8 , 197u8 , 141u8 , 103u8 , 101u8 , 110u8 , 32u8 , 40u8 , 49u8 , 50u8 , 53u8 , 54u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 53u8 , 55u8 , 41u8 , 75u8 , 197u8 , 141u8 , 104u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 48u8 , 53u8 , 56u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 54u8 , 53u8 , 41u8 , 75u8 , 197u8 , 141u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 57u8 , 54u8 , 52u8 , 226u8 , 128u8 , 147u8 , 57u8 , 54u8 , 56u8 , 41u8 , 75u8 , 197u8 , 141u8 , 106u8 , 105u8 , 32u8 , 40u8 , 49u8 , 49u8 , 52u8 , 50u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 52u8 , 52u8 , 41u8 , 75u8 , 197u8 , 141u8 , 106u8 , 105u8 , 32u8 , 40u8 , 49u8 , 53u8 , 53u8 , 53u8 , 226u8 , 128u8 , 147u8 , 49u8 , 53u8 , 53u8 , 56u8 , 41u8 , 75u8 , 197u8 , 141u8 , 107u8 , 97u8 , 32u8 , 40u8 , 49u8 , 56u8 , 52u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 56u8 , 52u8 , 56u8 , 41u8 , 75u8 , 197u8 , 141u8 , 107u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 51u8 , 52u8 , 48u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 52u8 , 54u8 , 41u8 , 75u8 , 197u8 , 141u8 , 110u8 , 105u8 , 110u8 , 32u8 , 40u8 , 56u8 , 49u8 , 48u8 , 226u8 , 128u8 , 147u8 , 56u8 , 50u8 , 52u8 , 41u8 , 75u8 , 197u8 , 141u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 51u8 , 56u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 57u8 , 48u8 , 41u8 , 75u8 , 197u8 , 141u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 51u8 , 55u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 56u8 , 49u8 , 41u8 , 75u8 , 197u8 , 141u8 , 115u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 52u8 , 53u8 , 53u8 , 226u8 , 128u8 , 147u8 , 49u8 , 52u8 , 53u8 , 55u8 , 41u8 , 75u8 , 197u8 , 141u8 , 119u8 , 97u8 , 32u8 , 40u8 , 49u8 , 48u8 , 57u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 48u8 , 52u8 , 41u8 , 75u8 , 197u8 , 141u8 , 119u8 , 97u8 , 32u8 , 40u8 , 49u8 , 51u8 , 56u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 56u8 , 52u8 , 41u8 , 75u8 , 121u8 , 197u8 , 141u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 55u8 , 49u8 , 54u8 , 226u8 , 128u8 , 147u8 , 49u8 , 55u8 , 51u8 , 54u8 , 41u8 , 75u8 , 121u8 , 197u8 , 141u8 , 114u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 53u8 , 50u8 , 56u8 , 226u8 , 128u8 , 147u8 , 49u8 , 53u8 , 51u8 , 50u8 , 41u8 , 75u8 , 121u8 , 197u8 , 141u8 , 116u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 52u8 , 53u8 , 50u8 , 226u8 , 128u8 , 147u8 , 49u8 , 52u8 , 53u8 , 53u8 , 41u8 , 75u8 , 121u8 , 197u8 , 141u8 , 119u8 , 97u8 , 32u8 , 40u8 , 49u8 , 56u8 , 48u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 56u8 , 48u8 , 52u8 , 41u8 , 75u8 , 121u8 , 197u8 , 171u8 , 97u8 , 110u8 , 32u8 , 40u8 , 49u8 , 49u8 , 52u8 , 53u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 53u8 , 49u8 , 41u8 , 75u8 , 121u8 , 197u8 , 171u8 , 106u8 , 117u8 , 32u8 , 40u8 , 49u8 , 49u8 , 53u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 53u8 , 54u8 , 41u8 , 77u8 , 97u8 , 110u8 , 226u8 , 128u8 , 153u8 , 101u8 , 110u8 , 32u8 , 40u8 , 49u8 , 56u8 , 54u8 , 48u8 , 226u8 , 128u8 , 147u8 , 49u8 , 56u8 , 54u8 , 49u8 , 41u8 , 77u8 , 97u8 , 110u8 , 106u8 , 105u8 , 32u8 , 40u8 , 49u8 , 54u8 , 53u8 , 56u8 , 226u8 , 128u8 , 147u8 , 49u8 , 54u8 , 54u8 , 49u8 , 41u8 , 77u8 , 97u8 , 110u8 , 106u8 , 117u8 , 32u8 , 40u8 , 49u8 , 48u8 , 50u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 50u8 , 56u8 , 41u8 , 77u8 , 101u8 , 105u8 , 106u8 , 105u8 , 77u8 , 101u8 , 105u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 52u8 , 57u8 , 50u8 , 226u8 , 128u8 , 147u8 , 49u8 , 53u8 , 48u8 , 49u8 , 41u8 , 77u8 , 101u8 , 105u8 , 114u8 , 101u8 , 107u8 , 105u8 , 32u8 , 40u8 , 49u8 , 54u8 , 53u8 , 53u8 , 226u8 , 128u8 , 147u8 , 49u8 , 54u8 , 53u8 , 56u8 , 41u8 , 77u8 , 101u8 , 105u8 , 116u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 51u8 , 56u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 56u8 , 55u8 , 41u8 , 77u8 , 101u8 , 105u8 , 116u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 51u8 , 57u8 , 48u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 57u8 , 52u8 , 41u8 , 77u8 , 101u8 , 105u8 , 119u8 , 97u8 , 32u8 , 40u8 , 49u8 , 55u8 , 54u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 55u8 , 55u8 , 50u8 , 41u8 , 78u8 , 105u8 , 110u8 , 226u8 , 128u8 , 153u8 , 97u8 , 110u8 , 32u8 , 40u8 , 49u8 , 49u8 , 54u8 , 54u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 54u8 , 57u8 , 41u8 , 78u8 , 105u8 , 110u8 , 106u8 , 105u8 , 32u8 , 40u8 , 49u8 , 50u8 , 52u8 , 48u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 52u8 , 51u8 , 41u8 , 78u8 , 105u8 , 110u8 , 106u8 , 117u8 , 32u8 , 40u8 , 56u8 , 53u8 , 49u8 , 226u8 , 128u8 , 147u8 , 56u8 , 53u8 , 52u8 , 41u8 , 78u8 , 105u8 , 110u8 , 110u8 , 97u8 , 32u8 , 40u8 , 56u8 , 56u8 , 53u8 , 226u8 , 128u8 , 147u8 , 56u8 , 56u8 , 57u8 , 41u8 , 78u8 , 105u8 , 110u8 , 112u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 49u8 , 53u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 53u8 , 52u8 , 41u8 , 197u8 , 140u8 , 99u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 51u8 , 49u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 49u8 , 50u8 , 41u8 , 197u8 , 140u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 51u8 , 57u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 52u8 , 50u8 , 56u8 , 41u8 , 197u8 , 140u8 , 104u8 , 111u8 , 32u8 , 40u8 , 49u8 , 49u8 , 54u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 54u8 , 51u8 , 41u8 , 197u8 , 140u8 , 110u8 , 105u8 , 110u8 , 32u8 , 40u8 , 49u8 , 52u8 , 54u8 , 55u8 , 226u8 , 128u8 , 147u8 , 49u8 , 52u8 , 54u8 , 57u8 , 41u8 , 197u8 , 140u8 , 116u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 48u8 , 56u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 56u8 , 55u8 , 41u8 , 197u8 , 140u8 , 119u8 , 97u8 , 32u8 , 40u8 , 57u8 , 54u8 , 49u8 , 226u8 , 128u8 , 147u8 , 57u8 , 54u8 , 52u8 , 41u8 , 82u8 , 101u8 , 105u8 , 107u8 , 105u8 , 32u8 , 40u8 , 55u8 , 49u8 , 53u8 , 226u8 , 128u8 , 147u8 , 55u8 , 49u8 , 55u8 , 41u8 , 82u8 , 101u8 , 105u8 , 119u8 , 97u8 , 82u8 , 121u8 , 97u8 , 107u8 , 117u8 , 110u8 , 105u8 , 110u8 , 32u8 , 40u8 , 49u8 , 50u8 , 51u8 , 56u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 51u8 , 57u8 , 41u8 , 83u8 , 97u8 , 105u8 , 107u8 , 197u8 , 141u8 , 32u8 , 40u8 , 56u8 , 53u8 , 52u8 , 226u8 , 128u8 , 147u8 , 56u8 , 53u8 , 55u8 , 41u8 , 83u8 , 104u8 , 197u8 , 141u8 , 97u8 , 110u8 , 32u8 , 40u8 , 49u8 , 49u8 , 55u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 55u8 , 53u8 , 41u8 , 83u8 , 104u8 , 197u8 , 141u8 , 97u8 , 110u8 , 32u8 , 40u8 , 49u8 , 50u8 , 57u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 48u8 , 50u8 , 41u8 , 83u8 , 104u8 , 197u8 , 141u8 , 99u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 52u8 , 50u8 , 56u8 , 226u8 , 128u8 , 147u8 , 49u8 , 52u8 , 50u8 , 57u8 , 41u8 , 83u8 , 104u8 , 197u8 , 141u8 , 99u8 , 104u8 , 197u8 , 171u8 , 32u8 , 40u8 , 49u8 , 51u8 , 50u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 50u8 , 54u8 , 41u8 , 83u8 , 104u8 , 197u8 , 141u8 , 103u8 , 101u8 , 110u8 , 32u8 , 40u8 , 49u8 , 50u8 , 53u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 54u8 , 48u8 , 41u8 , 83u8 , 104u8 , 197u8 , 141u8 , 104u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 51u8 , 52u8 , 54u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 55u8 , 48u8 , 41u8 , 83u8 , 104u8 , 197u8 , 141u8 , 104u8 , 111u8 , 32u8 , 40u8 , 49u8 , 48u8 , 55u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 55u8 , 55u8 , 41u8 , 83u8 , 104u8 , 197u8 , 141u8 , 104u8 , 111u8 , 32u8 , 40u8 , 49u8 , 54u8 , 52u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 54u8 , 52u8 , 56u8 , 41u8 , 83u8 , 104u8 , 197u8 , 141u8 , 106u8 , 105u8 , 32u8 , 40u8 , 49u8 , 49u8 , 57u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 48u8 , 49u8 , 41u8 , 83u8 , 104u8 , 197u8 , 141u8 , 107u8 , 97u8 , 32u8 , 40u8 , 49u8 , 50u8 , 53u8 , 55u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 53u8 , 57u8 , 41u8 , 83u8 , 104u8 , 197u8 , 141u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 50u8 , 56u8 , 56u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 57u8 , 51u8 , 41u8 , 83u8 , 104u8 , 197u8 , 141u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 48u8 , 55u8 , 55u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 56u8 , 49u8 , 41u8 , 83u8 , 104u8 , 197u8 , 141u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 32u8 , 40u8 , 57u8 , 57u8 , 48u8 , 226u8 , 128u8 , 147u8 , 57u8 , 57u8 , 53u8 , 41u8 , 83u8 , 104u8 , 197u8 , 141u8 , 116u8 , 97u8 , 105u8 , 32u8 , 40u8 , 56u8 , 57u8 , 56u8 , 226u8 , 128u8 , 147u8 , 57u8 , 48u8 , 49u8 , 41u8 , 83u8 , 104u8 , 197u8 , 141u8 , 116u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 55u8 , 49u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 55u8 , 49u8 , 54u8 , 41u8 , 83u8 , 104u8 , 197u8 , 141u8 , 119u8 , 97u8 , 83u8 , 104u8 , 197u8 , 141u8 , 119u8 , 97u8 , 32u8 , 40u8 , 49u8 , 51u8 , 49u8 , 50u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 49u8 , 55u8 , 41u8 , 83u8 , 104u8 , 117u8 , 99u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 54u8 , 56u8 , 54u8 , 226u8 , 128u8 , 147u8 , 55u8 , 48u8 , 49u8 , 41u8 , 84u8 , 97u8 , 105u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 53u8 , 50u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 53u8 , 50u8 , 56u8 , 41u8 , 84u8 , 97u8 , 105u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 55u8 , 48u8 , 49u8 , 226u8 , 128u8 , 147u8 , 55u8 , 48u8 , 52u8 , 41u8 , 84u8 , 97u8 , 105u8 , 107u8 , 97u8 , 32u8 , 40u8 , 54u8 , 52u8 , 53u8 , 226u8 , 128u8 , 147u8 , 54u8 , 53u8 , 48u8 , 41u8 , 84u8 , 97u8 , 105u8 , 115u8 , 104u8 , 197u8 , 141u8 , 84u8 , 101u8 , 110u8 , 45u8 , 97u8 , 110u8 , 32u8 , 40u8 , 56u8 , 53u8 , 55u8 , 226u8 , 128u8 , 147u8 , 56u8 , 53u8 , 57u8 , 41u8 , 84u8 , 101u8 , 110u8 , 98u8 , 117u8 , 110u8 , 32u8 , 40u8 , 49u8 , 53u8 , 51u8 , 50u8 , 226u8 , 128u8 , 147u8 , 49u8 , 53u8 , 53u8 , 53u8 , 41u8 , 84u8 , 101u8 , 110u8 , 99u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 56u8 , 50u8 , 52u8 , 226u8 , 128u8 , 147u8 , 56u8 , 51u8 , 52u8 , 41u8 , 84u8 , 101u8 , 110u8 , 45u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 49u8 , 49u8 , 48u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 49u8 , 51u8 , 41u8 , 84u8 , 101u8 , 110u8 , 226u8 , 128u8 , 153u8 , 101u8 , 110u8 , 32u8 , 40u8 , 57u8 , 55u8 , 51u8 , 226u8 , 128u8 , 147u8 , 57u8 , 55u8 , 54u8 , 41u8 , 84u8 , 101u8 , 110u8 , 103u8 , 101u8 , 110u8 , 32u8 , 40u8 , 57u8 , 55u8 , 56u8 , 226u8 , 128u8 , 147u8 , 57u8 , 56u8 , 51u8 , 41u8 , 84u8 , 101u8 , 110u8 , 103u8 , 105u8 , 32u8 , 40u8 , 49u8 , 48u8 , 53u8 , 51u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 53u8 , 56u8 , 41u8 , 84u8 , 101u8 , 110u8 , 103u8 , 121u8 , 197u8 , 141u8 , 32u8 , 40u8 , 57u8 , 51u8 , 56u8 , 226u8 , 128u8 , 147u8 , 57u8 , 52u8 , 55u8 , 41u8 , 84u8 , 101u8 , 110u8 , 106u8 , 105u8 , 32u8 , 40u8 , 49u8 , 49u8 , 50u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 50u8 , 54u8 , 41u8 , 84u8 , 101u8 , 110u8 , 106u8 , 117u8 , 32u8 , 40u8 , 49u8 , 51u8 , 55u8 , 53u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 55u8 , 57u8 , 41u8 , 84u8 , 101u8 , 110u8 , 109u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 55u8 , 56u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 55u8 , 56u8 , 57u8 , 41u8 , 84u8 , 101u8 , 110u8 , 110u8 , 97u8 , 32u8 , 40u8 , 49u8 , 54u8 , 56u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 54u8 , 56u8 , 52u8 , 41u8 , 84u8 , 101u8 , 110u8 , 110u8 , 105u8 , 110u8 , 32u8 , 40u8 , 49u8 , 49u8 , 48u8 , 56u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 49u8 , 48u8 , 41u8 , 84u8 , 101u8 , 110u8 , 45u8 , 197u8 , 141u8 , 32u8 , 40u8 , 55u8 , 56u8 , 49u8 , 226u8 , 128u8 , 147u8 , 55u8 , 56u8 , 50u8 , 41u8 , 84u8 , 101u8 , 110u8 , 112u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 56u8 , 51u8 , 48u8 , 226u8 , 128u8 , 147u8 , 49u8 , 56u8 , 52u8 , 52u8 , 41u8 , 84u8 , 101u8 , 110u8 , 112u8 , 117u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 50u8 , 51u8 , 51u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 51u8 , 52u8 , 41u8 , 84u8 , 101u8 , 110u8 , 112u8 , 121u8 , 197u8 , 141u8 , 32u8 , 40u8 , 55u8 , 50u8 , 57u8 , 226u8 , 128u8 , 147u8 , 55u8 , 52u8 , 57u8 , 41u8 , 84u8 , 101u8 , 110u8 , 112u8 , 121u8 , 197u8 , 141u8 , 45u8 , 104u8 , 197u8 , 141u8 , 106u8 , 105u8 , 32u8 , 40u8 , 55u8 , 53u8 , 55u8 , 226u8 , 128u8 , 147u8 , 55u8 , 54u8 , 53u8 , 41u8 , 84u8 , 101u8 , 110u8 , 112u8 , 121u8 , 197u8 , 141u8 , 45u8 , 106u8 , 105u8 , 110u8 , 103u8 , 111u8 , 32u8 , 40u8 , 55u8 , 54u8 , 53u8 , 226u8 , 128u8 , 147u8 , 55u8 , 54u8 , 55u8 , 41u8 , 84u8 , 101u8 , 110u8 , 112u8 , 121u8 , 197u8 , 141u8 , 45u8 , 107u8 , 97u8 , 109u8 , 112u8 , 197u8 , 141u8 , 32u8 , 40u8 , 55u8 , 52u8 , 57u8 , 226u8 , 128u8 , 147u8 , 55u8 , 52u8 , 57u8 , 41u8 , 84u8 , 101u8 , 110u8 , 112u8 , 121u8 , 197u8 , 141u8 , 45u8 , 115u8 , 104u8 , 197u8 , 141u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 55u8 , 52u8 , 57u8 , 226u8 , 128u8 , 147u8 , 55u8 , 53u8 , 55u8 , 41u8 , 84u8 , 101u8 , 110u8 , 114u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 57u8 , 55u8 , 48u8 , 226u8 , 128u8 , 147u8 , 57u8 , 55u8 , 51u8 , 41u8 , 84u8 , 101u8 , 110u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 32u8 , 40u8 , 57u8 , 52u8 , 55u8 , 226u8 , 128u8 , 147u8 , 57u8 , 53u8 , 55u8 , 41u8 , 84u8 , 101u8 , 110u8 , 115u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 49u8 , 51u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 51u8 , 50u8 , 41u8 , 84u8 , 101u8 , 110u8 , 115u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 53u8 , 55u8 , 51u8 , 226u8 , 128u8 , 147u8 , 49u8 , 53u8 , 57u8 , 50u8 , 41u8 , 84u8 , 101u8 , 110u8 , 116u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 57u8 , 53u8 , 55u8 , 226u8 , 128u8 , 147u8 , 57u8 , 54u8 , 49u8 , 41u8 , 84u8 , 101u8 , 110u8 , 226u8 , 128u8 , 153u8 , 121u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 49u8 , 52u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 52u8 , 53u8 , 41u8 , 84u8 , 111u8 , 107u8 , 117u8 , 106u8 , 105u8 , 32u8 , 40u8 , 49u8 , 51u8 , 48u8 , 54u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 48u8 , 56u8 , 41u8 , 87u8 , 97u8 , 100u8 , 197u8 , 141u8 , 32u8 , 40u8 , 55u8 , 48u8 , 56u8 , 226u8 , 128u8 , 147u8 , 55u8 , 49u8 , 53u8 , 41u8 , 89u8 , 197u8 , 141u8 , 114u8 , 197u8 , 141u8 , 32u8 , 40u8 , 55u8 , 49u8 , 55u8 , 226u8 , 128u8 , 147u8 , 55u8 , 50u8 , 52u8 , 41u8 , 89u8 , 197u8 , 141u8 , 119u8 , 97u8 , 32u8 , 40u8 , 49u8 , 49u8 , 56u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 56u8 , 50u8 , 41u8]) }) } , abbr : unsafe { # [allow (unused_unsafe)] :: zerovec :: ZeroMap :: from_parts_unchecked (unsafe { :: zerovec :: VarZeroVec :: from_bytes_unchecked (& [239u8 , 0u8 , 0u8 , 0u8 , 0u8 , 0u8 , 9u8 , 0u8 , 19u8 , 0u8 , 27u8 , 0u8 , 37u8 , 0u8 , 47u8 , 0u8 , 50u8 , 0u8 , 60u8 , 0u8 , 71u8 , 0u8 , 81u8 , 0u8 , 91u8 , 0u8 , 101u8 , 0u8 , 111u8 , 0u8 , 122u8 , 0u8 , 133u8 , 0u8 , 142u8 , 0u8 , 152u8 , 0u8 , 164u8 , 0u8 , 177u8 , 0u8 , 188u8 , 0u8 , 199u8 , 0u8 , 201u8 , 0u8 , 212u8 , 0u8 , 221u8 , 0u8 , 231u8 , 0u8 , 242u8 , 0u8 , 253u8 , 0u8 , 8u8 , 1u8 , 20u8 , 1u8 , 33u8 , 1u8 , 44u8 , 1u8 , 55u8 , 1u8 , 65u8 , 1u8 , 74u8 , 1u8 , 84u8 , 1u8 , 94u8 , 1u8 , 102u8 , 1u8 , 111u8 , 1u8 , 120u8 , 1u8 , 129u8 , 1u8 , 139u8 , 1u8 , 149u8 , 1u8 , 159u8 , 1u8 , 169u8 , 1u8 , 180u8 , 1u8 , 192u8 , 1u8 , 202u8 , 1u8 , 212u8 , 1u8 , 220u8 , 1u8 , 229u8 , 1u8 , 239u8 , 1u8 , 247u8 , 1u8 , 1u8 , 2u8 , 11u8 , 2u8 , 21u8 , 2u8 , 29u8 , 2u8 , 38u8 , 2u8 , 49u8 , 2u8 , 60u8 , 2u8 , 70u8 , 2u8 , 81u8 , 2u8 , 92u8 , 2u8 , 102u8 , 2u8 , 112u8 , 2u8 , 122u8 , 2u8 , 132u8 , 2u8 , 142u8 , 2u8 , 153u8 , 2u8 , 163u8 , 2u8 , 174u8 , 2u8 , 183u8 , 2u8 , 195u8 , 2u8 , 208u8 , 2u8 , 220u8 , 2u8 , 231u8 , 2u8 , 241u8 , 2u8 , 251u8 , 2u8 , 1u8 , 3u8 , 10u8 , 3u8 , 19u8 , 3u8 , 28u8 , 3u8 , 38u8 , 3u8 , 47u8 , 3u8 , 55u8 , 3u8 , 66u8 , 3u8 , 77u8 , 3u8 , 86u8 , 3u8 , 100u8 , 3u8 , 109u8 , 3u8 , 121u8 , 3u8 , 131u8 , 3u8 , 140u8 , 3u8 , 149u8 , 3u8 , 159u8 , 3u8 , 168u8 , 3u8 , 177u8 , 3u8 , 187u8 , 3u8 , 197u8 , 3u8 , 205u8 , 3u8 , 213u8 , 3u8 , 224u8 , 3u8 , 232u8 , 3u8 , 241u8 , 3u8 , 250u8 , 3u8 , 4u8 , 4u8 , 13u8 , 4u8 , 21u8 , 4u8 , 31u8 , 4u8 , 43u8 , 4u8 , 54u8 , 4u8 , 64u8 , 4u8 , 74u8 , 4u8 , 85u8 , 4u8 , 95u8 , 4u8 , 105u8 , 4u8 , 115u8 , 4u8 , 124u8 , 4u8 , 135u8 , 4u8 , 145u8 , 4u8 , 155u8 , 4u8 , 166u8 , 4u8 , 177u8 , 4u8 , 189u8 , 4u8 , 197u8 , 4u8 , 208u8 , 4u8 , 220u8 , 4u8 , 230u8 , 4u8 , 240u8 , 4u8 , 250u8 , 4u8 , 5u8 , 5u8 , 14u8 , 5u8 , 23u8 , 5u8 , 34u8 , 5u8 , 44u8 , 5u8 , 55u8 , 5u8 , 65u8 , 5u8 , 76u8 , 5u8 , 86u8 , 5u8 , 97u8 , 5u8 , 107u8 , 5u8 , 120u8 , 5u8 , 132u8 , 5u8 , 141u8 , 5u8 , 151u8 , 5u8 , 161u8 , 5u8 , 171u8 , 5u8 , 179u8 , 5u8 , 188u8 , 5u8 , 197u8 , 5u8 , 206u8 , 5u8 , 217u8 , 5u8 , 226u8 , 5u8 , 234u8 , 5u8 , 246u8 , 5u8 , 0u8 , 6u8 , 9u8 , 6u8 , 18u8 , 6u8 , 28u8 , 6u8 , 40u8 , 6u8 , 52u8 , 6u8 , 62u8 , 6u8 , 72u8 , 6u8 , 82u8 , 6u8 , 92u8 , 6u8 , 102u8 , 6u8 , 112u8 , 6u8 , 117u8 , 6u8 , 126u8 , 6u8 , 138u8 , 6u8 , 150u8 , 6u8 , 162u8 , 6u8 , 172u8 , 6u8 , 182u8 , 6u8 , 192u8 , 6u8 , 201u8 , 6u8 , 210u8 , 6u8 , 221u8 , 6u8 , 230u8 , 6u8 , 238u8 , 6u8 , 246u8 , 6u8 , 255u8 , 6u8 , 9u8 , 7u8 , 16u8 , 7u8 , 25u8 , 7u8 , 30u8 , 7u8 , 43u8 , 7u8 , 52u8 , 7u8 , 62u8 , 7u8 , 72u8 , 7u8 , 83u8 , 7u8 , 94u8 , 7u8 , 105u8 , 7u8 , 116u8 , 7u8 , 126u8 , 7u8 , 136u8 , 7u8 , 146u8 , 7u8 , 156u8 , 7u8 , 165u8 , 7u8 , 178u8 , 7u8 , 190u8 , 7u8 , 200u8 , 7u8 , 212u8 , 7u8 , 217u8 , 7u8 , 227u8 , 7u8 , 237u8 , 7u8 , 247u8 , 7u8 , 0u8 , 8u8 , 9u8 , 8u8 , 15u8 , 8u8 , 24u8 , 8u8 , 35u8 , 8u8 , 45u8 , 8u8 , 55u8 , 8u8 , 64u8 , 8u8 , 74u8 , 8u8 , 84u8 , 8u8 , 94u8 , 8u8 , 104u8 , 8u8 , 114u8 , 8u8 , 125u8 , 8u8 , 135u8 , 8u8 , 146u8 , 8u8 , 154u8 , 8u8 , 164u8 , 8u8 , 176u8 , 8u8 , 186u8 , 8u8 , 200u8 , 8u8 , 215u8 , 8u8 , 230u8 , 8u8 , 245u8 , 8u8 , 0u8 , 9u8 , 12u8 , 9u8 , 23u8 , 9u8 , 34u8 , 9u8 , 45u8 , 9u8 , 55u8 , 9u8 , 66u8 , 9u8 , 74u8 , 9u8 , 82u8 , 9u8 , 97u8 , 110u8 , 101u8 , 105u8 , 45u8 , 49u8 , 55u8 , 55u8 , 50u8 , 97u8 , 110u8 , 103u8 , 101u8 , 110u8 , 45u8 , 49u8 , 49u8 , 55u8 , 53u8 , 97u8 , 110u8 , 110u8 , 97u8 , 45u8 , 57u8 , 54u8 , 56u8 , 97u8 , 110u8 , 115u8 , 101u8 , 105u8 , 45u8 , 49u8 , 56u8 , 53u8 , 52u8 , 97u8 , 110u8 , 116u8 , 101u8 , 105u8 , 45u8 , 49u8 , 50u8 , 50u8 , 55u8 , 98u8 , 99u8 , 101u8 , 98u8 , 117u8 , 110u8 , 97u8 , 110u8 , 45u8 , 49u8 , 52u8 , 52u8 , 52u8 , 98u8 , 117u8 , 110u8 , 99u8 , 104u8 , 117u8 , 45u8 , 49u8 , 51u8 , 55u8 , 50u8 , 98u8 , 117u8 , 110u8 , 101u8 , 105u8 , 45u8 , 49u8 , 50u8 , 54u8 , 52u8 , 98u8 , 117u8 , 110u8 , 106u8 , 105u8 , 45u8 , 49u8 , 49u8 , 56u8 , 53u8 , 98u8 , 117u8 , 110u8 , 107u8 , 97u8 , 45u8 , 49u8 , 56u8 , 48u8 , 52u8 , 98u8 , 117u8 , 110u8 , 107u8 , 105u8 , 45u8 , 49u8 , 53u8 , 48u8 , 49u8 , 98u8 , 117u8 , 110u8 , 107u8 , 121u8 , 117u8 , 45u8 , 49u8 , 56u8 , 54u8 , 49u8 , 98u8 , 117u8 , 110u8 , 109u8 , 101u8 , 105u8 , 45u8 , 49u8 , 52u8 , 54u8 , 57u8 , 98u8 , 117u8 , 110u8 , 111u8 , 45u8 , 49u8 , 50u8 , 54u8 , 48u8 , 98u8 , 117u8 , 110u8 , 112u8 , 111u8 , 45u8 , 49u8 , 51u8 , 49u8 , 55u8 , 98u8 , 117u8 , 110u8 , 114u8 , 111u8 , 107u8 , 117u8 , 45u8 , 49u8 , 53u8 , 57u8 , 50u8 , 98u8 , 117u8 , 110u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 45u8 , 49u8 , 50u8 , 51u8 , 52u8 , 98u8 , 117u8 , 110u8 , 115u8 , 101u8 , 105u8 , 45u8 , 49u8 , 56u8 , 49u8 , 56u8 , 98u8 , 117u8 , 110u8 , 115u8 , 104u8 , 111u8 , 45u8 , 49u8 , 52u8 , 54u8 , 54u8 , 99u8 , 101u8 , 99u8 , 104u8 , 111u8 , 103u8 , 101u8 , 110u8 , 45u8 , 49u8 , 48u8 , 50u8 , 56u8 , 99u8 , 104u8 , 111u8 , 104u8 , 111u8 , 45u8 , 57u8 , 57u8 , 57u8 , 99u8 , 104u8 , 111u8 , 106u8 , 105u8 , 45u8 , 49u8 , 49u8 , 48u8 , 52u8 , 99u8 , 104u8 , 111u8 , 107u8 , 97u8 , 110u8 , 45u8 , 49u8 , 49u8 , 54u8 , 51u8 , 99u8 , 104u8 , 111u8 , 107u8 , 121u8 , 111u8 , 45u8 , 49u8 , 52u8 , 56u8 , 55u8 , 99u8 , 104u8 , 111u8 , 107u8 , 121u8 , 117u8 , 45u8 , 49u8 , 48u8 , 52u8 , 48u8 , 99u8 , 104u8 , 111u8 , 114u8 , 111u8 , 107u8 , 117u8 , 45u8 , 49u8 , 52u8 , 53u8 , 55u8 , 99u8 , 104u8 , 111u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 45u8 , 49u8 , 48u8 , 51u8 , 55u8 , 99u8 , 104u8 , 111u8 , 115u8 , 104u8 , 111u8 , 45u8 , 49u8 , 49u8 , 51u8 , 50u8 , 99u8 , 104u8 , 111u8 , 116u8 , 111u8 , 107u8 , 117u8 , 45u8 , 57u8 , 57u8 , 53u8 , 99u8 , 104u8 , 111u8 , 119u8 , 97u8 , 45u8 , 49u8 , 48u8 , 49u8 , 50u8 , 100u8 , 97u8 , 105u8 , 100u8 , 111u8 , 45u8 , 56u8 , 48u8 , 54u8 , 100u8 , 97u8 , 105u8 , 106u8 , 105u8 , 45u8 , 49u8 , 49u8 , 50u8 , 54u8 , 101u8 , 105u8 , 99u8 , 104u8 , 111u8 , 45u8 , 49u8 , 48u8 , 57u8 , 54u8 , 101u8 , 105u8 , 101u8 , 110u8 , 45u8 , 57u8 , 56u8 , 55u8 , 101u8 , 105u8 , 104u8 , 111u8 , 45u8 , 49u8 , 48u8 , 56u8 , 49u8 , 101u8 , 105u8 , 106u8 , 105u8 , 45u8 , 49u8 , 49u8 , 52u8 , 49u8 , 101u8 , 105u8 , 107u8 , 97u8 , 110u8 , 45u8 , 57u8 , 56u8 , 51u8 , 101u8 , 105u8 , 107u8 , 121u8 , 111u8 , 45u8 , 49u8 , 52u8 , 50u8 , 57u8 , 101u8 , 105u8 , 107u8 , 121u8 , 117u8 , 45u8 , 49u8 , 49u8 , 49u8 , 51u8 , 101u8 , 105u8 , 109u8 , 97u8 , 110u8 , 45u8 , 49u8 , 49u8 , 54u8 , 53u8 , 101u8 , 105u8 , 110u8 , 105u8 , 110u8 , 45u8 , 49u8 , 50u8 , 57u8 , 51u8 , 101u8 , 105u8 , 114u8 , 111u8 , 107u8 , 117u8 , 45u8 , 49u8 , 53u8 , 53u8 , 56u8 , 101u8 , 105u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 45u8 , 49u8 , 49u8 , 54u8 , 48u8 , 101u8 , 105u8 , 115u8 , 104u8 , 111u8 , 45u8 , 49u8 , 48u8 , 52u8 , 54u8 , 101u8 , 105u8 , 115u8 , 104u8 , 111u8 , 45u8 , 49u8 , 53u8 , 48u8 , 52u8 , 101u8 , 105u8 , 115u8 , 111u8 , 45u8 , 57u8 , 56u8 , 57u8 , 101u8 , 110u8 , 99u8 , 104u8 , 111u8 , 45u8 , 57u8 , 50u8 , 51u8 , 101u8 , 110u8 , 103u8 , 101u8 , 110u8 , 45u8 , 49u8 , 51u8 , 51u8 , 54u8 , 101u8 , 110u8 , 103u8 , 105u8 , 45u8 , 57u8 , 48u8 , 49u8 , 101u8 , 110u8 , 107u8 , 121u8 , 111u8 , 45u8 , 49u8 , 51u8 , 48u8 , 56u8 , 101u8 , 110u8 , 107u8 , 121u8 , 111u8 , 45u8 , 49u8 , 55u8 , 52u8 , 52u8 , 101u8 , 110u8 , 107u8 , 121u8 , 117u8 , 45u8 , 49u8 , 48u8 , 54u8 , 57u8 , 101u8 , 110u8 , 111u8 , 45u8 , 49u8 , 50u8 , 51u8 , 57u8 , 101u8 , 110u8 , 112u8 , 111u8 , 45u8 , 49u8 , 54u8 , 55u8 , 51u8 , 101u8 , 110u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 45u8 , 55u8 , 56u8 , 50u8 , 101u8 , 110u8 , 116u8 , 111u8 , 107u8 , 117u8 , 45u8 , 49u8 , 52u8 , 56u8 , 57u8 , 103u8 , 97u8 , 110u8 , 103u8 , 121u8 , 111u8 , 45u8 , 56u8 , 55u8 , 55u8 , 103u8 , 101u8 , 110u8 , 98u8 , 117u8 , 110u8 , 45u8 , 49u8 , 55u8 , 51u8 , 54u8 , 103u8 , 101u8 , 110u8 , 99u8 , 104u8 , 117u8 , 45u8 , 49u8 , 51u8 , 56u8 , 52u8 , 103u8 , 101u8 , 110u8 , 101u8 , 105u8 , 45u8 , 49u8 , 49u8 , 49u8 , 56u8 , 103u8 , 101u8 , 110u8 , 106u8 , 105u8 , 45u8 , 49u8 , 56u8 , 54u8 , 52u8 , 103u8 , 101u8 , 110u8 , 107u8 , 105u8 , 45u8 , 49u8 , 53u8 , 55u8 , 48u8 , 103u8 , 101u8 , 110u8 , 107u8 , 111u8 , 45u8 , 49u8 , 51u8 , 50u8 , 49u8 , 103u8 , 101u8 , 110u8 , 107u8 , 111u8 , 45u8 , 49u8 , 51u8 , 51u8 , 49u8 , 103u8 , 101u8 , 110u8 , 107u8 , 121u8 , 117u8 , 45u8 , 49u8 , 50u8 , 48u8 , 52u8 , 103u8 , 101u8 , 110u8 , 110u8 , 97u8 , 45u8 , 49u8 , 54u8 , 49u8 , 53u8 , 103u8 , 101u8 , 110u8 , 110u8 , 105u8 , 110u8 , 45u8 , 49u8 , 50u8 , 50u8 , 52u8 , 103u8 , 101u8 , 110u8 , 111u8 , 45u8 , 49u8 , 51u8 , 49u8 , 57u8 , 103u8 , 101u8 , 110u8 , 114u8 , 111u8 , 107u8 , 117u8 , 45u8 , 49u8 , 54u8 , 56u8 , 56u8 , 103u8 , 101u8 , 110u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 45u8 , 49u8 , 49u8 , 56u8 , 52u8 , 103u8 , 101u8 , 110u8 , 116u8 , 111u8 , 107u8 , 117u8 , 45u8 , 49u8 , 51u8 , 50u8 , 57u8 , 104u8 , 97u8 , 107u8 , 117u8 , 99u8 , 104u8 , 105u8 , 45u8 , 54u8 , 53u8 , 48u8 , 104u8 , 97u8 , 107u8 , 117u8 , 104u8 , 111u8 , 45u8 , 54u8 , 55u8 , 50u8 , 104u8 , 101u8 , 105u8 , 106u8 , 105u8 , 45u8 , 49u8 , 49u8 , 53u8 , 57u8 , 104u8 , 101u8 , 105u8 , 115u8 , 101u8 , 105u8 , 104u8 , 111u8 , 97u8 , 110u8 , 45u8 , 49u8 , 49u8 , 50u8 , 48u8 , 104u8 , 111u8 , 101u8 , 105u8 , 45u8 , 49u8 , 55u8 , 48u8 , 52u8 , 104u8 , 111u8 , 101u8 , 110u8 , 45u8 , 49u8 , 49u8 , 51u8 , 53u8 , 104u8 , 111u8 , 103u8 , 101u8 , 110u8 , 45u8 , 49u8 , 49u8 , 53u8 , 54u8 , 104u8 , 111u8 , 106u8 , 105u8 , 45u8 , 49u8 , 50u8 , 52u8 , 55u8 , 104u8 , 111u8 , 107u8 , 105u8 , 45u8 , 55u8 , 55u8 , 48u8 , 104u8 , 111u8 , 114u8 , 101u8 , 107u8 , 105u8 , 45u8 , 49u8 , 55u8 , 53u8 , 49u8 , 104u8 , 111u8 , 116u8 , 111u8 , 107u8 , 117u8 , 45u8 , 49u8 , 52u8 , 52u8 , 57u8 , 106u8 , 105u8 , 97u8 , 110u8 , 45u8 , 49u8 , 48u8 , 50u8 , 49u8 , 106u8 , 105u8 , 110u8 , 103u8 , 111u8 , 107u8 , 101u8 , 105u8 , 117u8 , 110u8 , 45u8 , 55u8 , 54u8 , 55u8 , 106u8 , 105u8 , 110u8 , 107u8 , 105u8 , 45u8 , 55u8 , 50u8 , 52u8 , 106u8 , 105u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 45u8 , 49u8 , 48u8 , 54u8 , 53u8 , 106u8 , 105u8 , 115u8 , 104u8 , 111u8 , 45u8 , 49u8 , 49u8 , 55u8 , 55u8 , 106u8 , 111u8 , 101u8 , 105u8 , 45u8 , 49u8 , 50u8 , 51u8 , 50u8 , 106u8 , 111u8 , 103u8 , 97u8 , 110u8 , 45u8 , 56u8 , 53u8 , 57u8 , 106u8 , 111u8 , 103u8 , 101u8 , 110u8 , 45u8 , 49u8 , 50u8 , 48u8 , 55u8 , 106u8 , 111u8 , 103u8 , 101u8 , 110u8 , 45u8 , 57u8 , 55u8 , 54u8 , 106u8 , 111u8 , 104u8 , 101u8 , 105u8 , 45u8 , 57u8 , 51u8 , 49u8 , 106u8 , 111u8 , 107u8 , 121u8 , 111u8 , 45u8 , 49u8 , 54u8 , 56u8 , 52u8 , 106u8 , 111u8 , 107u8 , 121u8 , 117u8 , 45u8 , 49u8 , 50u8 , 49u8 , 57u8 , 106u8 , 111u8 , 111u8 , 45u8 , 49u8 , 50u8 , 50u8 , 50u8 , 106u8 , 111u8 , 111u8 , 45u8 , 49u8 , 54u8 , 53u8 , 50u8 , 106u8 , 111u8 , 116u8 , 111u8 , 107u8 , 117u8 , 45u8 , 49u8 , 48u8 , 57u8 , 55u8 , 106u8 , 111u8 , 119u8 , 97u8 , 45u8 , 56u8 , 51u8 , 52u8 , 106u8 , 117u8 , 101u8 , 105u8 , 45u8 , 49u8 , 49u8 , 56u8 , 50u8 , 107u8 , 97u8 , 101u8 , 105u8 , 45u8 , 49u8 , 56u8 , 52u8 , 56u8 , 107u8 , 97u8 , 103u8 , 101u8 , 110u8 , 45u8 , 49u8 , 51u8 , 48u8 , 51u8 , 107u8 , 97u8 , 104u8 , 111u8 , 45u8 , 49u8 , 48u8 , 57u8 , 52u8 , 107u8 , 97u8 , 106u8 , 111u8 , 45u8 , 56u8 , 52u8 , 56u8 , 107u8 , 97u8 , 107u8 , 101u8 , 105u8 , 45u8 , 49u8 , 51u8 , 56u8 , 55u8 , 107u8 , 97u8 , 107u8 , 105u8 , 116u8 , 115u8 , 117u8 , 45u8 , 49u8 , 52u8 , 52u8 , 49u8 , 107u8 , 97u8 , 110u8 , 98u8 , 117u8 , 110u8 , 45u8 , 49u8 , 54u8 , 54u8 , 49u8 , 107u8 , 97u8 , 110u8 , 101u8 , 105u8 , 45u8 , 49u8 , 54u8 , 50u8 , 52u8 , 107u8 , 97u8 , 110u8 , 101u8 , 110u8 , 45u8 , 49u8 , 55u8 , 52u8 , 56u8 , 107u8 , 97u8 , 110u8 , 103u8 , 101u8 , 110u8 , 45u8 , 49u8 , 50u8 , 52u8 , 51u8 , 107u8 , 97u8 , 110u8 , 106u8 , 105u8 , 45u8 , 49u8 , 48u8 , 56u8 , 55u8 , 107u8 , 97u8 , 110u8 , 107u8 , 105u8 , 45u8 , 49u8 , 50u8 , 50u8 , 57u8 , 107u8 , 97u8 , 110u8 , 107u8 , 111u8 , 45u8 , 49u8 , 48u8 , 48u8 , 52u8 , 107u8 , 97u8 , 110u8 , 110u8 , 97u8 , 45u8 , 57u8 , 56u8 , 53u8 , 107u8 , 97u8 , 110u8 , 110u8 , 105u8 , 110u8 , 45u8 , 49u8 , 48u8 , 49u8 , 55u8 , 107u8 , 97u8 , 110u8 , 112u8 , 111u8 , 45u8 , 49u8 , 55u8 , 52u8 , 49u8 , 107u8 , 97u8 , 110u8 , 112u8 , 121u8 , 111u8 , 45u8 , 56u8 , 56u8 , 57u8 , 107u8 , 97u8 , 110u8 , 115u8 , 101u8 , 105u8 , 45u8 , 49u8 , 55u8 , 56u8 , 57u8 , 107u8 , 97u8 , 110u8 , 115u8 , 104u8 , 111u8 , 45u8 , 49u8 , 52u8 , 54u8 , 48u8 , 107u8 , 97u8 , 110u8 , 116u8 , 111u8 , 107u8 , 117u8 , 45u8 , 49u8 , 48u8 , 52u8 , 52u8 , 107u8 , 97u8 , 111u8 , 45u8 , 49u8 , 49u8 , 54u8 , 57u8 , 107u8 , 97u8 , 114u8 , 111u8 , 107u8 , 117u8 , 45u8 , 49u8 , 50u8 , 50u8 , 53u8 , 107u8 , 97u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 45u8 , 49u8 , 51u8 , 50u8 , 54u8 , 107u8 , 97u8 , 115u8 , 104u8 , 111u8 , 45u8 , 49u8 , 49u8 , 48u8 , 54u8 , 107u8 , 97u8 , 116u8 , 101u8 , 105u8 , 45u8 , 49u8 , 50u8 , 51u8 , 53u8 , 107u8 , 101u8 , 105u8 , 97u8 , 110u8 , 45u8 , 49u8 , 54u8 , 52u8 , 56u8 , 107u8 , 101u8 , 105u8 , 99u8 , 104u8 , 111u8 , 45u8 , 49u8 , 53u8 , 57u8 , 54u8 , 107u8 , 101u8 , 105u8 , 111u8 , 45u8 , 49u8 , 56u8 , 54u8 , 53u8 , 107u8 , 101u8 , 105u8 , 117u8 , 110u8 , 45u8 , 55u8 , 48u8 , 52u8 , 107u8 , 101u8 , 110u8 , 99u8 , 104u8 , 111u8 , 45u8 , 49u8 , 50u8 , 52u8 , 57u8 , 107u8 , 101u8 , 110u8 , 101u8 , 105u8 , 45u8 , 49u8 , 50u8 , 48u8 , 54u8 , 107u8 , 101u8 , 110u8 , 103u8 , 101u8 , 110u8 , 45u8 , 49u8 , 51u8 , 48u8 , 50u8 , 107u8 , 101u8 , 110u8 , 106u8 , 105u8 , 45u8 , 49u8 , 50u8 , 55u8 , 53u8 , 107u8 , 101u8 , 110u8 , 107u8 , 121u8 , 117u8 , 45u8 , 49u8 , 49u8 , 57u8 , 48u8 , 107u8 , 101u8 , 110u8 , 109u8 , 117u8 , 45u8 , 49u8 , 51u8 , 51u8 , 52u8 , 107u8 , 101u8 , 110u8 , 110u8 , 105u8 , 110u8 , 45u8 , 49u8 , 50u8 , 48u8 , 49u8 , 107u8 , 101u8 , 110u8 , 112u8 , 111u8 , 45u8 , 49u8 , 50u8 , 49u8 , 51u8 , 107u8 , 101u8 , 110u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 45u8 , 49u8 , 50u8 , 49u8 , 49u8 , 107u8 , 101u8 , 110u8 , 116u8 , 111u8 , 107u8 , 117u8 , 45u8 , 49u8 , 51u8 , 55u8 , 48u8 , 107u8 , 111u8 , 97u8 , 110u8 , 45u8 , 49u8 , 50u8 , 55u8 , 56u8 , 107u8 , 111u8 , 99u8 , 104u8 , 111u8 , 45u8 , 49u8 , 50u8 , 54u8 , 49u8 , 107u8 , 111u8 , 103u8 , 101u8 , 110u8 , 45u8 , 49u8 , 50u8 , 53u8 , 54u8 , 107u8 , 111u8 , 104u8 , 101u8 , 105u8 , 45u8 , 49u8 , 48u8 , 53u8 , 56u8 , 107u8 , 111u8 , 104u8 , 111u8 , 45u8 , 57u8 , 54u8 , 52u8 , 107u8 , 111u8 , 106u8 , 105u8 , 45u8 , 49u8 , 49u8 , 52u8 , 50u8 , 107u8 , 111u8 , 106u8 , 105u8 , 45u8 , 49u8 , 53u8 , 53u8 , 53u8 , 107u8 , 111u8 , 107u8 , 97u8 , 45u8 , 49u8 , 56u8 , 52u8 , 52u8 , 107u8 , 111u8 , 107u8 , 111u8 , 107u8 , 117u8 , 45u8 , 49u8 , 51u8 , 52u8 , 48u8 , 107u8 , 111u8 , 110u8 , 105u8 , 110u8 , 45u8 , 56u8 , 49u8 , 48u8 , 107u8 , 111u8 , 111u8 , 45u8 , 49u8 , 51u8 , 56u8 , 57u8 , 107u8 , 111u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 45u8 , 49u8 , 51u8 , 55u8 , 57u8 , 107u8 , 111u8 , 115u8 , 104u8 , 111u8 , 45u8 , 49u8 , 52u8 , 53u8 , 53u8 , 107u8 , 111u8 , 119u8 , 97u8 , 45u8 , 49u8 , 48u8 , 57u8 , 57u8 , 107u8 , 111u8 , 119u8 , 97u8 , 45u8 , 49u8 , 51u8 , 56u8 , 49u8 , 107u8 , 121u8 , 111u8 , 104u8 , 111u8 , 45u8 , 49u8 , 55u8 , 49u8 , 54u8 , 107u8 , 121u8 , 111u8 , 114u8 , 111u8 , 107u8 , 117u8 , 45u8 , 49u8 , 53u8 , 50u8 , 56u8 , 107u8 , 121u8 , 111u8 , 116u8 , 111u8 , 107u8 , 117u8 , 45u8 , 49u8 , 52u8 , 53u8 , 50u8 , 107u8 , 121u8 , 111u8 , 119u8 , 97u8 , 45u8 , 49u8 , 56u8 , 48u8 , 49u8 , 107u8 , 121u8 , 117u8 , 97u8 , 110u8 , 45u8 , 49u8 , 49u8 , 52u8 , 53u8 , 107u8 , 121u8 , 117u8 , 106u8 , 117u8 , 45u8 , 49u8 , 49u8 , 53u8 , 52u8 , 109u8 , 97u8 , 110u8 , 101u8 , 110u8 , 45u8 , 49u8 , 56u8 , 54u8 , 48u8 , 109u8 , 97u8 , 110u8 , 106u8 , 105u8 , 45u8 , 49u8 , 54u8 , 53u8 , 56u8 , 109u8 , 97u8 , 110u8 , 106u8 , 117u8 , 45u8 , 49u8 , 48u8 , 50u8 , 52u8 , 109u8 , 101u8 , 105u8 , 106u8 , 105u8 , 109u8 , 101u8 , 105u8 , 111u8 , 45u8 , 49u8 , 52u8 , 57u8 , 50u8 , 109u8 , 101u8 , 105u8 , 114u8 , 101u8 , 107u8 , 105u8 , 45u8 , 49u8 , 54u8 , 53u8 , 53u8 , 109u8 , 101u8 , 105u8 , 116u8 , 111u8 , 107u8 , 117u8 , 45u8 , 49u8 , 51u8 , 56u8 , 55u8 , 109u8 , 101u8 , 105u8 , 116u8 , 111u8 , 107u8 , 117u8 , 45u8 , 49u8 , 51u8 , 57u8 , 48u8 , 109u8 , 101u8 , 105u8 , 119u8 , 97u8 , 45u8 , 49u8 , 55u8 , 54u8 , 52u8 , 110u8 , 105u8 , 110u8 , 97u8 , 110u8 , 45u8 , 49u8 , 49u8 , 54u8 , 54u8 , 110u8 , 105u8 , 110u8 , 106u8 , 105u8 , 45u8 , 49u8 , 50u8 , 52u8 , 48u8 , 110u8 , 105u8 , 110u8 , 106u8 , 117u8 , 45u8 , 56u8 , 53u8 , 49u8 , 110u8 , 105u8 , 110u8 , 110u8 , 97u8 , 45u8 , 56u8 , 56u8 , 53u8 , 110u8 , 105u8 , 110u8 , 112u8 , 101u8 , 105u8 , 45u8 , 49u8 , 49u8 , 53u8 , 49u8 , 111u8 , 99u8 , 104u8 , 111u8 , 45u8 , 49u8 , 51u8 , 49u8 , 49u8 , 111u8 , 101u8 , 105u8 , 45u8 , 49u8 , 51u8 , 57u8 , 52u8 , 111u8 , 104u8 , 111u8 , 45u8 , 49u8 , 49u8 , 54u8 , 49u8 , 111u8 , 110u8 , 105u8 , 110u8 , 45u8 , 49u8 , 52u8 , 54u8 , 55u8 , 111u8 , 116u8 , 111u8 , 107u8 , 117u8 , 45u8 , 49u8 , 48u8 , 56u8 , 52u8 , 111u8 , 119u8 , 97u8 , 45u8 , 57u8 , 54u8 , 49u8 , 114u8 , 101u8 , 105u8 , 107u8 , 105u8 , 45u8 , 55u8 , 49u8 , 53u8 , 114u8 , 101u8 , 105u8 , 119u8 , 97u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 110u8 , 105u8 , 110u8 , 45u8 , 49u8 , 50u8 , 51u8 , 56u8 , 115u8 , 97u8 , 105u8 , 107u8 , 111u8 , 45u8 , 56u8 , 53u8 , 52u8 , 115u8 , 104u8 , 111u8 , 97u8 , 110u8 , 45u8 , 49u8 , 49u8 , 55u8 , 49u8 , 115u8 , 104u8 , 111u8 , 97u8 , 110u8 , 45u8 , 49u8 , 50u8 , 57u8 , 57u8 , 115u8 , 104u8 , 111u8 , 99u8 , 104u8 , 111u8 , 45u8 , 49u8 , 52u8 , 50u8 , 56u8 , 115u8 , 104u8 , 111u8 , 99u8 , 104u8 , 117u8 , 45u8 , 49u8 , 51u8 , 50u8 , 52u8 , 115u8 , 104u8 , 111u8 , 103u8 , 101u8 , 110u8 , 45u8 , 49u8 , 50u8 , 53u8 , 57u8 , 115u8 , 104u8 , 111u8 , 104u8 , 101u8 , 105u8 , 45u8 , 49u8 , 51u8 , 52u8 , 54u8 , 115u8 , 104u8 , 111u8 , 104u8 , 111u8 , 45u8 , 49u8 , 48u8 , 55u8 , 52u8 , 115u8 , 104u8 , 111u8 , 104u8 , 111u8 , 45u8 , 49u8 , 54u8 , 52u8 , 52u8 , 115u8 , 104u8 , 111u8 , 106u8 , 105u8 , 45u8 , 49u8 , 49u8 , 57u8 , 57u8 , 115u8 , 104u8 , 111u8 , 107u8 , 97u8 , 45u8 , 49u8 , 50u8 , 53u8 , 55u8 , 115u8 , 104u8 , 111u8 , 111u8 , 45u8 , 49u8 , 50u8 , 56u8 , 56u8 , 115u8 , 104u8 , 111u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 45u8 , 49u8 , 48u8 , 55u8 , 55u8 , 115u8 , 104u8 , 111u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 45u8 , 57u8 , 57u8 , 48u8 , 115u8 , 104u8 , 111u8 , 116u8 , 97u8 , 105u8 , 45u8 , 56u8 , 57u8 , 56u8 , 115u8 , 104u8 , 111u8 , 116u8 , 111u8 , 107u8 , 117u8 , 45u8 , 49u8 , 55u8 , 49u8 , 49u8 , 115u8 , 104u8 , 111u8 , 119u8 , 97u8 , 115u8 , 104u8 , 111u8 , 119u8 , 97u8 , 45u8 , 49u8 , 51u8 , 49u8 , 50u8 , 115u8 , 104u8 , 117u8 , 99u8 , 104u8 , 111u8 , 45u8 , 54u8 , 56u8 , 54u8 , 116u8 , 97u8 , 105u8 , 101u8 , 105u8 , 45u8 , 49u8 , 53u8 , 50u8 , 49u8 , 116u8 , 97u8 , 105u8 , 104u8 , 111u8 , 45u8 , 55u8 , 48u8 , 49u8 , 116u8 , 97u8 , 105u8 , 107u8 , 97u8 , 45u8 , 54u8 , 52u8 , 53u8 , 116u8 , 97u8 , 105u8 , 115u8 , 104u8 , 111u8 , 116u8 , 101u8 , 110u8 , 97u8 , 110u8 , 45u8 , 56u8 , 53u8 , 55u8 , 116u8 , 101u8 , 110u8 , 98u8 , 117u8 , 110u8 , 45u8 , 49u8 , 53u8 , 51u8 , 50u8 , 116u8 , 101u8 , 110u8 , 99u8 , 104u8 , 111u8 , 45u8 , 56u8 , 50u8 , 52u8 , 116u8 , 101u8 , 110u8 , 101u8 , 105u8 , 45u8 , 49u8 , 49u8 , 49u8 , 48u8 , 116u8 , 101u8 , 110u8 , 101u8 , 110u8 , 45u8 , 57u8 , 55u8 , 51u8 , 116u8 , 101u8 , 110u8 , 103u8 , 101u8 , 110u8 , 45u8 , 57u8 , 55u8 , 56u8 , 116u8 , 101u8 , 110u8 , 103u8 , 105u8 , 45u8 , 49u8 , 48u8 , 53u8 , 51u8 , 116u8 , 101u8 , 110u8 , 103u8 , 121u8 , 111u8 , 45u8 , 57u8 , 51u8 , 56u8 , 116u8 , 101u8 , 110u8 , 106u8 , 105u8 , 45u8 , 49u8 , 49u8 , 50u8 , 52u8 , 116u8 , 101u8 , 110u8 , 106u8 , 117u8 , 45u8 , 49u8 , 51u8 , 55u8 , 53u8 , 116u8 , 101u8 , 110u8 , 109u8 , 101u8 , 105u8 , 45u8 , 49u8 , 55u8 , 56u8 , 49u8 , 116u8 , 101u8 , 110u8 , 110u8 , 97u8 , 45u8 , 49u8 , 54u8 , 56u8 , 49u8 , 116u8 , 101u8 , 110u8 , 110u8 , 105u8 , 110u8 , 45u8 , 49u8 , 49u8 , 48u8 , 56u8 , 116u8 , 101u8 , 110u8 , 111u8 , 45u8 , 55u8 , 56u8 , 49u8 , 116u8 , 101u8 , 110u8 , 112u8 , 111u8 , 45u8 , 49u8 , 56u8 , 51u8 , 48u8 , 116u8 , 101u8 , 110u8 , 112u8 , 117u8 , 107u8 , 117u8 , 45u8 , 49u8 , 50u8 , 51u8 , 51u8 , 116u8 , 101u8 , 110u8 , 112u8 , 121u8 , 111u8 , 45u8 , 55u8 , 50u8 , 57u8 , 116u8 , 101u8 , 110u8 , 112u8 , 121u8 , 111u8 , 104u8 , 111u8 , 106u8 , 105u8 , 45u8 , 55u8 , 53u8 , 55u8 , 116u8 , 101u8 , 110u8 , 112u8 , 121u8 , 111u8 , 106u8 , 105u8 , 110u8 , 103u8 , 111u8 , 45u8 , 55u8 , 54u8 , 53u8 , 116u8 , 101u8 , 110u8 , 112u8 , 121u8 , 111u8 , 107u8 , 97u8 , 109u8 , 112u8 , 111u8 , 45u8 , 55u8 , 52u8 , 57u8 , 116u8 , 101u8 , 110u8 , 112u8 , 121u8 , 111u8 , 115u8 , 104u8 , 111u8 , 104u8 , 111u8 , 45u8 , 55u8 , 52u8 , 57u8 , 116u8 , 101u8 , 110u8 , 114u8 , 111u8 , 107u8 , 117u8 , 45u8 , 57u8 , 55u8 , 48u8 , 116u8 , 101u8 , 110u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 45u8 , 57u8 , 52u8 , 55u8 , 116u8 , 101u8 , 110u8 , 115u8 , 104u8 , 111u8 , 45u8 , 49u8 , 49u8 , 51u8 , 49u8 , 116u8 , 101u8 , 110u8 , 115u8 , 104u8 , 111u8 , 45u8 , 49u8 , 53u8 , 55u8 , 51u8 , 116u8 , 101u8 , 110u8 , 116u8 , 111u8 , 107u8 , 117u8 , 45u8 , 57u8 , 53u8 , 55u8 , 116u8 , 101u8 , 110u8 , 121u8 , 111u8 , 45u8 , 49u8 , 49u8 , 52u8 , 52u8 , 116u8 , 111u8 , 107u8 , 117u8 , 106u8 , 105u8 , 45u8 , 49u8 , 51u8 , 48u8 , 54u8 , 119u8 , 97u8 , 100u8 , 111u8 , 45u8 , 55u8 , 48u8 , 56u8 , 121u8 , 111u8 , 114u8 , 111u8 , 45u8 , 55u8 , 49u8 , 55u8 , 121u8 , 111u8 , 119u8 , 97u8 , 45u8 , 49u8 , 49u8 , 56u8 , 49u8]) } , unsafe { :: zerovec :: VarZeroVec :: from_bytes_unchecked (& [239u8 , 0u8 , 0u8 , 0u8 , 0u8 , 0u8 , 21u8 , 0u8 , 40u8 , 0u8 , 56u8 , 0u8 , 75u8 , 0u8 , 94u8 , 0u8 , 150u8 , 0u8 , 172u8 , 0u8 , 193u8 , 0u8 , 215u8 , 0u8 , 234u8 , 0u8 , 253u8 , 0u8 , 16u8 , 1u8 , 37u8 , 1u8 , 57u8 , 1u8 , 79u8 , 1u8 , 99u8 , 1u8 , 120u8 , 1u8 , 142u8 , 1u8 , 162u8 , 1u8 , 183u8 , 1u8 , 227u8 , 1u8 , 248u8 , 1u8 , 12u8 , 2u8 , 32u8 , 2u8 , 53u8 , 2u8 , 75u8 , 2u8 , 97u8 , 2u8 , 119u8 , 2u8 , 142u8 , 2u8 , 164u8 , 2u8 , 184u8 , 2u8 , 204u8 , 2u8 , 222u8 , 2u8 , 241u8 , 2u8 , 5u8 , 3u8 , 21u8 , 3u8 , 40u8 , 3u8 , 58u8 , 3u8 , 75u8 , 3u8 , 95u8 , 3u8 , 115u8 , 3u8 , 134u8 , 3u8 , 153u8 , 3u8 , 173u8 , 3u8 , 194u8 , 3u8 , 214u8 , 3u8 , 234u8 , 3u8 , 250u8 , 3u8 , 12u8 , 4u8 , 31u8 , 4u8 , 47u8 , 4u8 , 67u8 , 4u8 , 87u8 , 4u8 , 107u8 , 4u8 , 128u8 , 4u8 , 147u8 , 4u8 , 166u8 , 4u8 , 186u8 , 4u8 , 205u8 , 4u8 , 225u8 , 4u8 , 246u8 , 4u8 , 12u8 , 5u8 , 31u8 , 5u8 , 50u8 , 5u8 , 70u8 , 5u8 , 90u8 , 5u8 , 111u8 , 5u8 , 130u8 , 5u8 , 150u8 , 5u8 , 169u8 , 5u8 , 190u8 , 5u8 , 212u8 , 5u8 , 233u8 , 5u8 , 252u8 , 5u8 , 15u8 , 6u8 , 34u8 , 6u8 , 40u8 , 6u8 , 59u8 , 6u8 , 78u8 , 6u8 , 97u8 , 6u8 , 117u8 , 6u8 , 136u8 , 6u8 , 153u8 , 6u8 , 174u8 , 6u8 , 195u8 , 6u8 , 213u8 , 6u8 , 236u8 , 6u8 , 253u8 , 6u8 , 18u8 , 7u8 , 38u8 , 7u8 , 57u8 , 7u8 , 75u8 , 7u8 , 95u8 , 7u8 , 113u8 , 7u8 , 131u8 , 7u8 , 152u8 , 7u8 , 173u8 , 7u8 , 192u8 , 7u8 , 211u8 , 7u8 , 232u8 , 7u8 , 249u8 , 7u8 , 11u8 , 8u8 , 29u8 , 8u8 , 48u8 , 8u8 , 67u8 , 8u8 , 84u8 , 8u8 , 103u8 , 8u8 , 124u8 , 8u8 , 144u8 , 8u8 , 166u8 , 8u8 , 188u8 , 8u8 , 208u8 , 8u8 , 227u8 , 8u8 , 246u8 , 8u8 , 10u8 , 9u8 , 27u8 , 9u8 , 47u8 , 9u8 , 67u8 , 9u8 , 86u8 , 9u8 , 106u8 , 9u8 , 127u8 , 9u8 , 148u8 , 9u8 , 166u8 , 9u8 , 186u8 , 9u8 , 207u8 , 9u8 , 227u8 , 9u8 , 246u8 , 9u8 , 9u8 , 10u8 , 30u8 , 10u8 , 49u8 , 10u8 , 66u8 , 10u8 , 87u8 , 10u8 , 109u8 , 10u8 , 129u8 , 10u8 , 148u8 , 10u8 , 169u8 , 10u8 , 188u8 , 10u8 , 208u8 , 10u8 , 228u8 , 10u8 , 250u8 , 10u8 , 15u8 , 11u8 , 34u8 , 11u8 , 55u8 , 11u8 , 75u8 , 11u8 , 95u8 , 11u8 , 113u8 , 11u8 , 132u8 , 11u8 , 151u8 , 11u8 , 170u8 , 11u8 , 191u8 , 11u8 , 209u8 , 11u8 , 228u8 , 11u8 , 250u8 , 11u8 , 15u8 , 12u8 , 34u8 , 12u8 , 53u8 , 12u8 , 74u8 , 12u8 , 96u8 , 12u8 , 118u8 , 12u8 , 138u8 , 12u8 , 158u8 , 12u8 , 178u8 , 12u8 , 200u8 , 12u8 , 219u8 , 12u8 , 238u8 , 12u8 , 243u8 , 12u8 , 6u8 , 13u8 , 27u8 , 13u8 , 48u8 , 13u8 , 69u8 , 13u8 , 88u8 , 13u8 , 110u8 , 13u8 , 129u8 , 13u8 , 146u8 , 13u8 , 163u8 , 13u8 , 183u8 , 13u8 , 203u8 , 13u8 , 221u8 , 13u8 , 239u8 , 13u8 , 2u8 , 14u8 , 22u8 , 14u8 , 38u8 , 14u8 , 55u8 , 14u8 , 60u8 , 14u8 , 82u8 , 14u8 , 100u8 , 14u8 , 120u8 , 14u8 , 140u8 , 14u8 , 162u8 , 14u8 , 184u8 , 14u8 , 205u8 , 14u8 , 226u8 , 14u8 , 246u8 , 14u8 , 10u8 , 15u8 , 30u8 , 15u8 , 50u8 , 15u8 , 70u8 , 15u8 , 93u8 , 15u8 , 114u8 , 15u8 , 133u8 , 15u8 , 155u8 , 15u8 , 161u8 , 15u8 , 181u8 , 15u8 , 200u8 , 15u8 , 219u8 , 15u8 , 237u8 , 15u8 , 254u8 , 15u8 , 5u8 , 16u8 , 23u8 , 16u8 , 43u8 , 16u8 , 62u8 , 16u8 , 82u8 , 16u8 , 102u8 , 16u8 , 120u8 , 16u8 , 139u8 , 16u8 , 158u8 , 16u8 , 177u8 , 16u8 , 196u8 , 16u8 , 216u8 , 16u8 , 235u8 , 16u8 , 255u8 , 16u8 , 17u8 , 17u8 , 37u8 , 17u8 , 58u8 , 17u8 , 77u8 , 17u8 , 102u8 , 17u8 , 127u8 , 17u8 , 153u8 , 17u8 , 180u8 , 17u8 , 199u8 , 17u8 , 219u8 , 17u8 , 240u8 , 17u8 , 5u8 , 18u8 , 24u8 , 18u8 , 47u8 , 18u8 , 67u8 , 18u8 , 84u8 , 18u8 , 102u8 , 18u8 , 65u8 , 110u8 , 226u8 , 128u8 , 153u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 55u8 , 55u8 , 50u8 , 226u8 , 128u8 , 147u8 , 49u8 , 55u8 , 56u8 , 49u8 , 41u8 , 65u8 , 110u8 , 103u8 , 101u8 , 110u8 , 32u8 , 40u8 , 49u8 , 49u8 , 55u8 , 53u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 55u8 , 55u8 , 41u8 , 65u8 , 110u8 , 110u8 , 97u8 , 32u8 , 40u8 , 57u8 , 54u8 , 56u8 , 226u8 , 128u8 , 147u8 , 57u8 , 55u8 , 48u8 , 41u8 , 65u8 , 110u8 , 115u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 56u8 , 53u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 56u8 , 54u8 , 48u8 , 41u8 , 65u8 , 110u8 , 116u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 50u8 , 50u8 , 55u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 50u8 , 57u8 , 41u8 , 240u8 , 145u8 , 132u8 , 136u8 , 240u8 , 145u8 , 132u8 , 179u8 , 240u8 , 145u8 , 132u8 , 162u8 , 240u8 , 145u8 , 132u8 , 168u8 , 240u8 , 145u8 , 132u8 , 140u8 , 240u8 , 145u8 , 132u8 , 180u8 , 240u8 , 145u8 , 132u8 , 145u8 , 240u8 , 145u8 , 132u8 , 180u8 , 240u8 , 145u8 , 132u8 , 155u8 , 240u8 , 145u8 , 132u8 , 171u8 , 240u8 , 145u8 , 132u8 , 162u8 , 240u8 , 145u8 , 132u8 , 180u8 , 240u8 , 145u8 , 132u8 , 157u8 , 240u8 , 145u8 , 132u8 , 167u8 , 66u8 , 117u8 , 110u8 , 226u8 , 128u8 , 153u8 , 97u8 , 110u8 , 32u8 , 40u8 , 49u8 , 52u8 , 52u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 52u8 , 52u8 , 57u8 , 41u8 , 66u8 , 117u8 , 110u8 , 99u8 , 104u8 , 197u8 , 171u8 , 32u8 , 40u8 , 49u8 , 51u8 , 55u8 , 50u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 55u8 , 53u8 , 41u8 , 66u8 , 117u8 , 110u8 , 226u8 , 128u8 , 153u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 50u8 , 54u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 55u8 , 53u8 , 41u8 , 66u8 , 117u8 , 110u8 , 106u8 , 105u8 , 32u8 , 40u8 , 49u8 , 49u8 , 56u8 , 53u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 57u8 , 48u8 , 41u8 , 66u8 , 117u8 , 110u8 , 107u8 , 97u8 , 32u8 , 40u8 , 49u8 , 56u8 , 48u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 56u8 , 49u8 , 56u8 , 41u8 , 66u8 , 117u8 , 110u8 , 107u8 , 105u8 , 32u8 , 40u8 , 49u8 , 53u8 , 48u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 53u8 , 48u8 , 52u8 , 41u8 , 66u8 , 117u8 , 110u8 , 107u8 , 121u8 , 197u8 , 171u8 , 32u8 , 40u8 , 49u8 , 56u8 , 54u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 56u8 , 54u8 , 52u8 , 41u8 , 66u8 , 117u8 , 110u8 , 109u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 52u8 , 54u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 52u8 , 56u8 , 55u8 , 41u8 , 66u8 , 117u8 , 110u8 , 226u8 , 128u8 , 153u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 50u8 , 54u8 , 48u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 54u8 , 49u8 , 41u8 , 66u8 , 117u8 , 110u8 , 112u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 51u8 , 49u8 , 55u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 49u8 , 57u8 , 41u8 , 66u8 , 117u8 , 110u8 , 114u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 53u8 , 57u8 , 50u8 , 226u8 , 128u8 , 147u8 , 49u8 , 53u8 , 57u8 , 54u8 , 41u8 , 66u8 , 117u8 , 110u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 50u8 , 51u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 51u8 , 53u8 , 41u8 , 66u8 , 117u8 , 110u8 , 115u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 56u8 , 49u8 , 56u8 , 226u8 , 128u8 , 147u8 , 49u8 , 56u8 , 51u8 , 48u8 , 41u8 , 66u8 , 117u8 , 110u8 , 115u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 52u8 , 54u8 , 54u8 , 226u8 , 128u8 , 147u8 , 49u8 , 52u8 , 54u8 , 55u8 , 41u8 , 240u8 , 145u8 , 132u8 , 136u8 , 240u8 , 145u8 , 132u8 , 179u8 , 240u8 , 145u8 , 132u8 , 162u8 , 240u8 , 145u8 , 132u8 , 168u8 , 240u8 , 145u8 , 132u8 , 140u8 , 240u8 , 145u8 , 132u8 , 180u8 , 240u8 , 145u8 , 132u8 , 145u8 , 240u8 , 145u8 , 132u8 , 155u8 , 240u8 , 145u8 , 132u8 , 180u8 , 240u8 , 145u8 , 132u8 , 152u8 , 240u8 , 145u8 , 132u8 , 167u8 , 67u8 , 104u8 , 197u8 , 141u8 , 103u8 , 101u8 , 110u8 , 32u8 , 40u8 , 49u8 , 48u8 , 50u8 , 56u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 51u8 , 55u8 , 41u8 , 67u8 , 104u8 , 197u8 , 141u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 57u8 , 57u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 48u8 , 52u8 , 41u8 , 67u8 , 104u8 , 197u8 , 141u8 , 106u8 , 105u8 , 32u8 , 40u8 , 49u8 , 49u8 , 48u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 48u8 , 54u8 , 41u8 , 67u8 , 104u8 , 197u8 , 141u8 , 107u8 , 97u8 , 110u8 , 32u8 , 40u8 , 49u8 , 49u8 , 54u8 , 51u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 54u8 , 53u8 , 41u8 , 67u8 , 104u8 , 197u8 , 141u8 , 107u8 , 121u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 52u8 , 56u8 , 55u8 , 226u8 , 128u8 , 147u8 , 49u8 , 52u8 , 56u8 , 57u8 , 41u8 , 67u8 , 104u8 , 197u8 , 141u8 , 107u8 , 121u8 , 197u8 , 171u8 , 32u8 , 40u8 , 49u8 , 48u8 , 52u8 , 48u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 52u8 , 52u8 , 41u8 , 67u8 , 104u8 , 197u8 , 141u8 , 114u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 52u8 , 53u8 , 55u8 , 226u8 , 128u8 , 147u8 , 49u8 , 52u8 , 54u8 , 48u8 , 41u8 , 67u8 , 104u8 , 197u8 , 141u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 48u8 , 51u8 , 55u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 52u8 , 48u8 , 41u8 , 67u8 , 104u8 , 197u8 , 141u8 , 115u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 49u8 , 51u8 , 50u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 51u8 , 53u8 , 41u8 , 67u8 , 104u8 , 197u8 , 141u8 , 116u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 57u8 , 57u8 , 53u8 , 226u8 , 128u8 , 147u8 , 57u8 , 57u8 , 57u8 , 41u8 , 67u8 , 104u8 , 197u8 , 141u8 , 119u8 , 97u8 , 32u8 , 40u8 , 49u8 , 48u8 , 49u8 , 50u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 49u8 , 55u8 , 41u8 , 68u8 , 97u8 , 105u8 , 100u8 , 197u8 , 141u8 , 32u8 , 40u8 , 56u8 , 48u8 , 54u8 , 226u8 , 128u8 , 147u8 , 56u8 , 49u8 , 48u8 , 41u8 , 68u8 , 97u8 , 105u8 , 106u8 , 105u8 , 32u8 , 40u8 , 49u8 , 49u8 , 50u8 , 54u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 51u8 , 49u8 , 41u8 , 69u8 , 105u8 , 99u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 48u8 , 57u8 , 54u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 57u8 , 55u8 , 41u8 , 69u8 , 105u8 , 101u8 , 110u8 , 32u8 , 40u8 , 57u8 , 56u8 , 55u8 , 226u8 , 128u8 , 147u8 , 57u8 , 56u8 , 57u8 , 41u8 , 69u8 , 105u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 48u8 , 56u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 56u8 , 52u8 , 41u8 , 69u8 , 105u8 , 106u8 , 105u8 , 32u8 , 40u8 , 49u8 , 49u8 , 52u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 52u8 , 50u8 , 41u8 , 69u8 , 105u8 , 107u8 , 97u8 , 110u8 , 32u8 , 40u8 , 57u8 , 56u8 , 51u8 , 226u8 , 128u8 , 147u8 , 57u8 , 56u8 , 53u8 , 41u8 , 69u8 , 105u8 , 107u8 , 121u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 52u8 , 50u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 52u8 , 52u8 , 49u8 , 41u8 , 69u8 , 105u8 , 107u8 , 121u8 , 197u8 , 171u8 , 32u8 , 40u8 , 49u8 , 49u8 , 49u8 , 51u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 49u8 , 56u8 , 41u8 , 69u8 , 105u8 , 109u8 , 97u8 , 110u8 , 32u8 , 40u8 , 49u8 , 49u8 , 54u8 , 53u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 54u8 , 54u8 , 41u8 , 69u8 , 105u8 , 110u8 , 105u8 , 110u8 , 32u8 , 40u8 , 49u8 , 50u8 , 57u8 , 51u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 57u8 , 57u8 , 41u8 , 69u8 , 105u8 , 114u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 53u8 , 53u8 , 56u8 , 226u8 , 128u8 , 147u8 , 49u8 , 53u8 , 55u8 , 48u8 , 41u8 , 69u8 , 105u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 49u8 , 54u8 , 48u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 54u8 , 49u8 , 41u8 , 69u8 , 105u8 , 115u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 48u8 , 52u8 , 54u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 53u8 , 51u8 , 41u8 , 69u8 , 105u8 , 115u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 53u8 , 48u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 53u8 , 50u8 , 49u8 , 41u8 , 69u8 , 105u8 , 115u8 , 111u8 , 32u8 , 40u8 , 57u8 , 56u8 , 57u8 , 226u8 , 128u8 , 147u8 , 57u8 , 57u8 , 48u8 , 41u8 , 69u8 , 110u8 , 99u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 57u8 , 50u8 , 51u8 , 226u8 , 128u8 , 147u8 , 57u8 , 51u8 , 49u8 , 41u8 , 69u8 , 110u8 , 103u8 , 101u8 , 110u8 , 32u8 , 40u8 , 49u8 , 51u8 , 51u8 , 54u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 52u8 , 48u8 , 41u8 , 69u8 , 110u8 , 103u8 , 105u8 , 32u8 , 40u8 , 57u8 , 48u8 , 49u8 , 226u8 , 128u8 , 147u8 , 57u8 , 50u8 , 51u8 , 41u8 , 69u8 , 110u8 , 107u8 , 121u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 51u8 , 48u8 , 56u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 49u8 , 49u8 , 41u8 , 69u8 , 110u8 , 107u8 , 121u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 55u8 , 52u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 55u8 , 52u8 , 56u8 , 41u8 , 69u8 , 110u8 , 107u8 , 121u8 , 197u8 , 171u8 , 32u8 , 40u8 , 49u8 , 48u8 , 54u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 55u8 , 52u8 , 41u8 , 69u8 , 110u8 , 226u8 , 128u8 , 153u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 50u8 , 51u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 52u8 , 48u8 , 41u8 , 69u8 , 110u8 , 112u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 54u8 , 55u8 , 51u8 , 226u8 , 128u8 , 147u8 , 49u8 , 54u8 , 56u8 , 49u8 , 41u8 , 69u8 , 110u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 32u8 , 40u8 , 55u8 , 56u8 , 50u8 , 226u8 , 128u8 , 147u8 , 56u8 , 48u8 , 54u8 , 41u8 , 69u8 , 110u8 , 116u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 52u8 , 56u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 52u8 , 57u8 , 50u8 , 41u8 , 71u8 , 97u8 , 110u8 , 103u8 , 121u8 , 197u8 , 141u8 , 32u8 , 40u8 , 56u8 , 55u8 , 55u8 , 226u8 , 128u8 , 147u8 , 56u8 , 56u8 , 53u8 , 41u8 , 71u8 , 101u8 , 110u8 , 98u8 , 117u8 , 110u8 , 32u8 , 40u8 , 49u8 , 55u8 , 51u8 , 54u8 , 226u8 , 128u8 , 147u8 , 49u8 , 55u8 , 52u8 , 49u8 , 41u8 , 71u8 , 101u8 , 110u8 , 99u8 , 104u8 , 197u8 , 171u8 , 32u8 , 40u8 , 49u8 , 51u8 , 56u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 57u8 , 50u8 , 41u8 , 71u8 , 101u8 , 110u8 , 226u8 , 128u8 , 153u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 49u8 , 49u8 , 56u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 50u8 , 48u8 , 41u8 , 71u8 , 101u8 , 110u8 , 106u8 , 105u8 , 32u8 , 40u8 , 49u8 , 56u8 , 54u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 56u8 , 54u8 , 53u8 , 41u8 , 71u8 , 101u8 , 110u8 , 107u8 , 105u8 , 32u8 , 40u8 , 49u8 , 53u8 , 55u8 , 48u8 , 226u8 , 128u8 , 147u8 , 49u8 , 53u8 , 55u8 , 51u8 , 41u8 , 71u8 , 101u8 , 110u8 , 107u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 51u8 , 50u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 50u8 , 52u8 , 41u8 , 71u8 , 101u8 , 110u8 , 107u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 51u8 , 51u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 51u8 , 52u8 , 41u8 , 71u8 , 101u8 , 110u8 , 107u8 , 121u8 , 197u8 , 171u8 , 32u8 , 40u8 , 49u8 , 50u8 , 48u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 48u8 , 54u8 , 41u8 , 71u8 , 101u8 , 110u8 , 110u8 , 97u8 , 32u8 , 40u8 , 49u8 , 54u8 , 49u8 , 53u8 , 226u8 , 128u8 , 147u8 , 49u8 , 54u8 , 50u8 , 52u8 , 41u8 , 71u8 , 101u8 , 110u8 , 110u8 , 105u8 , 110u8 , 32u8 , 40u8 , 49u8 , 50u8 , 50u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 50u8 , 53u8 , 41u8 , 71u8 , 101u8 , 110u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 51u8 , 49u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 50u8 , 49u8 , 41u8 , 71u8 , 101u8 , 110u8 , 114u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 54u8 , 56u8 , 56u8 , 226u8 , 128u8 , 147u8 , 49u8 , 55u8 , 48u8 , 52u8 , 41u8 , 71u8 , 101u8 , 110u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 49u8 , 56u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 56u8 , 53u8 , 41u8 , 71u8 , 101u8 , 110u8 , 116u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 51u8 , 50u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 51u8 , 49u8 , 41u8 , 72u8 , 97u8 , 107u8 , 117u8 , 99u8 , 104u8 , 105u8 , 32u8 , 40u8 , 54u8 , 53u8 , 48u8 , 226u8 , 128u8 , 147u8 , 54u8 , 55u8 , 49u8 , 41u8 , 72u8 , 97u8 , 107u8 , 117u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 54u8 , 55u8 , 50u8 , 226u8 , 128u8 , 147u8 , 54u8 , 56u8 , 54u8 , 41u8 , 72u8 , 101u8 , 105u8 , 106u8 , 105u8 , 32u8 , 40u8 , 49u8 , 49u8 , 53u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 54u8 , 48u8 , 41u8 , 72u8 , 101u8 , 105u8 , 115u8 , 101u8 , 105u8 , 72u8 , 197u8 , 141u8 , 97u8 , 110u8 , 32u8 , 40u8 , 49u8 , 49u8 , 50u8 , 48u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 50u8 , 52u8 , 41u8 , 72u8 , 197u8 , 141u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 55u8 , 48u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 55u8 , 49u8 , 49u8 , 41u8 , 72u8 , 197u8 , 141u8 , 101u8 , 110u8 , 32u8 , 40u8 , 49u8 , 49u8 , 51u8 , 53u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 52u8 , 49u8 , 41u8 , 72u8 , 197u8 , 141u8 , 103u8 , 101u8 , 110u8 , 32u8 , 40u8 , 49u8 , 49u8 , 53u8 , 54u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 53u8 , 57u8 , 41u8 , 72u8 , 197u8 , 141u8 , 106u8 , 105u8 , 32u8 , 40u8 , 49u8 , 50u8 , 52u8 , 55u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 52u8 , 57u8 , 41u8 , 72u8 , 197u8 , 141u8 , 107u8 , 105u8 , 32u8 , 40u8 , 55u8 , 55u8 , 48u8 , 226u8 , 128u8 , 147u8 , 55u8 , 56u8 , 48u8 , 41u8 , 72u8 , 197u8 , 141u8 , 114u8 , 101u8 , 107u8 , 105u8 , 32u8 , 40u8 , 49u8 , 55u8 , 53u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 55u8 , 54u8 , 52u8 , 41u8 , 72u8 , 197u8 , 141u8 , 116u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 52u8 , 52u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 52u8 , 53u8 , 50u8 , 41u8 , 74u8 , 105u8 , 97u8 , 110u8 , 32u8 , 40u8 , 49u8 , 48u8 , 50u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 50u8 , 52u8 , 41u8 , 74u8 , 105u8 , 110u8 , 103u8 , 111u8 , 45u8 , 107u8 , 101u8 , 105u8 , 117u8 , 110u8 , 32u8 , 40u8 , 55u8 , 54u8 , 55u8 , 226u8 , 128u8 , 147u8 , 55u8 , 55u8 , 48u8 , 41u8 , 74u8 , 105u8 , 110u8 , 107u8 , 105u8 , 32u8 , 40u8 , 55u8 , 50u8 , 52u8 , 226u8 , 128u8 , 147u8 , 55u8 , 50u8 , 57u8 , 41u8 , 74u8 , 105u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 48u8 , 54u8 , 53u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 54u8 , 57u8 , 41u8 , 74u8 , 105u8 , 115u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 49u8 , 55u8 , 55u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 56u8 , 49u8 , 41u8 , 74u8 , 197u8 , 141u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 50u8 , 51u8 , 50u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 51u8 , 51u8 , 41u8 , 74u8 , 197u8 , 141u8 , 103u8 , 97u8 , 110u8 , 32u8 , 40u8 , 56u8 , 53u8 , 57u8 , 226u8 , 128u8 , 147u8 , 56u8 , 55u8 , 55u8 , 41u8 , 74u8 , 197u8 , 141u8 , 103u8 , 101u8 , 110u8 , 32u8 , 40u8 , 49u8 , 50u8 , 48u8 , 55u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 49u8 , 49u8 , 41u8 , 74u8 , 197u8 , 141u8 , 103u8 , 101u8 , 110u8 , 32u8 , 40u8 , 57u8 , 55u8 , 54u8 , 226u8 , 128u8 , 147u8 , 57u8 , 55u8 , 56u8 , 41u8 , 74u8 , 197u8 , 141u8 , 104u8 , 101u8 , 105u8 , 32u8 , 40u8 , 57u8 , 51u8 , 49u8 , 226u8 , 128u8 , 147u8 , 57u8 , 51u8 , 56u8 , 41u8 , 74u8 , 197u8 , 141u8 , 107u8 , 121u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 54u8 , 56u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 54u8 , 56u8 , 56u8 , 41u8 , 74u8 , 197u8 , 141u8 , 107u8 , 121u8 , 197u8 , 171u8 , 32u8 , 40u8 , 49u8 , 50u8 , 49u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 50u8 , 50u8 , 41u8 , 74u8 , 197u8 , 141u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 50u8 , 50u8 , 50u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 50u8 , 52u8 , 41u8 , 74u8 , 197u8 , 141u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 54u8 , 53u8 , 50u8 , 226u8 , 128u8 , 147u8 , 49u8 , 54u8 , 53u8 , 53u8 , 41u8 , 74u8 , 197u8 , 141u8 , 116u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 48u8 , 57u8 , 55u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 57u8 , 57u8 , 41u8 , 74u8 , 197u8 , 141u8 , 119u8 , 97u8 , 32u8 , 40u8 , 56u8 , 51u8 , 52u8 , 226u8 , 128u8 , 147u8 , 56u8 , 52u8 , 56u8 , 41u8 , 74u8 , 117u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 49u8 , 56u8 , 50u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 56u8 , 52u8 , 41u8 , 75u8 , 97u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 56u8 , 52u8 , 56u8 , 226u8 , 128u8 , 147u8 , 49u8 , 56u8 , 53u8 , 52u8 , 41u8 , 75u8 , 97u8 , 103u8 , 101u8 , 110u8 , 32u8 , 40u8 , 49u8 , 51u8 , 48u8 , 51u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 48u8 , 54u8 , 41u8 , 75u8 , 97u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 48u8 , 57u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 57u8 , 54u8 , 41u8 , 75u8 , 97u8 , 106u8 , 197u8 , 141u8 , 32u8 , 40u8 , 56u8 , 52u8 , 56u8 , 226u8 , 128u8 , 147u8 , 56u8 , 53u8 , 49u8 , 41u8 , 75u8 , 97u8 , 107u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 51u8 , 56u8 , 55u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 56u8 , 57u8 , 41u8 , 75u8 , 97u8 , 107u8 , 105u8 , 116u8 , 115u8 , 117u8 , 32u8 , 40u8 , 49u8 , 52u8 , 52u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 52u8 , 52u8 , 52u8 , 41u8 , 75u8 , 97u8 , 110u8 , 98u8 , 117u8 , 110u8 , 32u8 , 40u8 , 49u8 , 54u8 , 54u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 54u8 , 55u8 , 51u8 , 41u8 , 75u8 , 97u8 , 110u8 , 226u8 , 128u8 , 153u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 54u8 , 50u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 54u8 , 52u8 , 52u8 , 41u8 , 75u8 , 97u8 , 110u8 , 226u8 , 128u8 , 153u8 , 101u8 , 110u8 , 32u8 , 40u8 , 49u8 , 55u8 , 52u8 , 56u8 , 226u8 , 128u8 , 147u8 , 49u8 , 55u8 , 53u8 , 49u8 , 41u8 , 75u8 , 97u8 , 110u8 , 103u8 , 101u8 , 110u8 , 32u8 , 40u8 , 49u8 , 50u8 , 52u8 , 51u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 52u8 , 55u8 , 41u8 , 75u8 , 97u8 , 110u8 , 106u8 , 105u8 , 32u8 , 40u8 , 49u8 , 48u8 , 56u8 , 55u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 57u8 , 52u8 , 41u8 , 75u8 , 97u8 , 110u8 , 107u8 , 105u8 , 32u8 , 40u8 , 49u8 , 50u8 , 50u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 51u8 , 50u8 , 41u8 , 75u8 , 97u8 , 110u8 , 107u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 48u8 , 48u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 49u8 , 50u8 , 41u8 , 75u8 , 97u8 , 110u8 , 110u8 , 97u8 , 32u8 , 40u8 , 57u8 , 56u8 , 53u8 , 226u8 , 128u8 , 147u8 , 57u8 , 56u8 , 55u8 , 41u8 , 75u8 , 97u8 , 110u8 , 110u8 , 105u8 , 110u8 , 32u8 , 40u8 , 49u8 , 48u8 , 49u8 , 55u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 50u8 , 49u8 , 41u8 , 75u8 , 97u8 , 110u8 , 112u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 55u8 , 52u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 55u8 , 52u8 , 52u8 , 41u8 , 75u8 , 97u8 , 110u8 , 112u8 , 121u8 , 197u8 , 141u8 , 32u8 , 40u8 , 56u8 , 56u8 , 57u8 , 226u8 , 128u8 , 147u8 , 56u8 , 57u8 , 56u8 , 41u8 , 75u8 , 97u8 , 110u8 , 115u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 55u8 , 56u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 56u8 , 48u8 , 49u8 , 41u8 , 75u8 , 97u8 , 110u8 , 115u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 52u8 , 54u8 , 48u8 , 226u8 , 128u8 , 147u8 , 49u8 , 52u8 , 54u8 , 54u8 , 41u8 , 75u8 , 97u8 , 110u8 , 116u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 48u8 , 52u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 52u8 , 54u8 , 41u8 , 75u8 , 97u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 49u8 , 54u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 55u8 , 49u8 , 41u8 , 75u8 , 97u8 , 114u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 50u8 , 50u8 , 53u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 50u8 , 55u8 , 41u8 , 75u8 , 97u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 51u8 , 50u8 , 54u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 50u8 , 57u8 , 41u8 , 75u8 , 97u8 , 115u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 49u8 , 48u8 , 54u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 48u8 , 56u8 , 41u8 , 75u8 , 97u8 , 116u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 50u8 , 51u8 , 53u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 51u8 , 56u8 , 41u8 , 75u8 , 101u8 , 105u8 , 97u8 , 110u8 , 32u8 , 40u8 , 49u8 , 54u8 , 52u8 , 56u8 , 226u8 , 128u8 , 147u8 , 49u8 , 54u8 , 53u8 , 50u8 , 41u8 , 75u8 , 101u8 , 105u8 , 99u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 53u8 , 57u8 , 54u8 , 226u8 , 128u8 , 147u8 , 49u8 , 54u8 , 49u8 , 53u8 , 41u8 , 75u8 , 101u8 , 105u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 56u8 , 54u8 , 53u8 , 226u8 , 128u8 , 147u8 , 49u8 , 56u8 , 54u8 , 56u8 , 41u8 , 75u8 , 101u8 , 105u8 , 117u8 , 110u8 , 32u8 , 40u8 , 55u8 , 48u8 , 52u8 , 226u8 , 128u8 , 147u8 , 55u8 , 48u8 , 56u8 , 41u8 , 75u8 , 101u8 , 110u8 , 99u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 50u8 , 52u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 53u8 , 54u8 , 41u8 , 75u8 , 101u8 , 110u8 , 226u8 , 128u8 , 153u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 50u8 , 48u8 , 54u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 48u8 , 55u8 , 41u8 , 75u8 , 101u8 , 110u8 , 103u8 , 101u8 , 110u8 , 32u8 , 40u8 , 49u8 , 51u8 , 48u8 , 50u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 48u8 , 51u8 , 41u8 , 75u8 , 101u8 , 110u8 , 106u8 , 105u8 , 32u8 , 40u8 , 49u8 , 50u8 , 55u8 , 53u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 55u8 , 56u8 , 41u8 , 75u8 , 101u8 , 110u8 , 107u8 , 121u8 , 197u8 , 171u8 , 32u8 , 40u8 , 49u8 , 49u8 , 57u8 , 48u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 57u8 , 57u8 , 41u8 , 75u8 , 101u8 , 110u8 , 109u8 , 117u8 , 32u8 , 40u8 , 49u8 , 51u8 , 51u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 51u8 , 54u8 , 41u8 , 75u8 , 101u8 , 110u8 , 110u8 , 105u8 , 110u8 , 32u8 , 40u8 , 49u8 , 50u8 , 48u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 48u8 , 52u8 , 41u8 , 75u8 , 101u8 , 110u8 , 112u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 50u8 , 49u8 , 51u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 49u8 , 57u8 , 41u8 , 75u8 , 101u8 , 110u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 50u8 , 49u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 49u8 , 51u8 , 41u8 , 75u8 , 101u8 , 110u8 , 116u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 51u8 , 55u8 , 48u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 55u8 , 50u8 , 41u8 , 75u8 , 197u8 , 141u8 , 97u8 , 110u8 , 32u8 , 40u8 , 49u8 , 50u8 , 55u8 , 56u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 56u8 , 56u8 , 41u8 , 75u8 , 197u8 , 141u8 , 99u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 50u8 , 54u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 54u8 , 52u8 , 41u8 , 75u8 , 197u8 , 141u8 , 103u8 , 101u8 , 110u8 , 32u8 , 40u8 , 49u8 , 50u8 , 53u8 , 54u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 53u8 , 55u8 , 41u8 , 75u8 , 197u8 , 141u8 , 104u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 48u8 , 53u8 , 56u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 54u8 , 53u8 , 41u8 , 75u8 , 197u8 , 141u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 57u8 , 54u8 , 52u8 , 226u8 , 128u8 , 147u8 , 57u8 , 54u8 , 56u8 , 41u8 , 75u8 , 197u8 , 141u8 , 106u8 , 105u8 , 32u8 , 40u8 , 49u8 , 49u8 , 52u8 , 50u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 52u8 , 52u8 , 41u8 , 75u8 , 197u8 , 141u8 , 106u8 , 105u8 , 32u8 , 40u8 , 49u8 , 53u8 , 53u8 , 53u8 , 226u8 , 128u8 , 147u8 , 49u8 , 53u8 , 53u8 , 56u8 , 41u8 , 75u8 , 197u8 , 141u8 , 107u8 , 97u8 , 32u8 , 40u8 , 49u8 , 56u8 , 52u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 56u8 , 52u8 , 56u8 , 41u8 , 75u8 , 197u8 , 141u8 , 107u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 51u8 , 52u8 , 48u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 52u8 , 54u8 , 41u8 , 75u8 , 197u8 , 141u8 , 110u8 , 105u8 , 110u8 , 32u8 , 40u8 , 56u8 , 49u8 , 48u8 , 226u8 , 128u8 , 147u8 , 56u8 , 50u8 , 52u8 , 41u8 , 75u8 , 197u8 , 141u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 51u8 , 56u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 57u8 , 48u8 , 41u8 , 75u8 , 197u8 , 141u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 51u8 , 55u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 56u8 , 49u8 , 41u8 , 75u8 , 197u8 , 141u8 , 115u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 52u8 , 53u8 , 53u8 , 226u8 , 128u8 , 147u8 , 49u8 , 52u8 , 53u8 , 55u8 , 41u8 , 75u8 , 197u8 , 141u8 , 119u8 , 97u8 , 32u8 , 40u8 , 49u8 , 48u8 , 57u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 48u8 , 52u8 , 41u8 , 75u8 , 197u8 , 141u8 , 119u8 , 97u8 , 32u8 , 40u8 , 49u8 , 51u8 , 56u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 56u8 , 52u8 , 41u8 , 75u8 , 121u8 , 197u8 , 141u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 55u8 , 49u8 , 54u8 , 226u8 , 128u8 , 147u8 , 49u8 , 55u8 , 51u8 , 54u8 , 41u8 , 75u8 , 121u8 , 197u8 , 141u8 , 114u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 53u8 , 50u8 , 56u8 , 226u8 , 128u8 , 147u8 , 49u8 , 53u8 , 51u8 , 50u8 , 41u8 , 75u8 , 121u8 , 197u8 , 141u8 , 116u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 52u8 , 53u8 , 50u8 , 226u8 , 128u8 , 147u8 , 49u8 , 52u8 , 53u8 , 53u8 , 41u8 , 75u8 , 121u8 , 197u8 , 141u8 , 119u8 , 97u8 , 32u8 , 40u8 , 49u8 , 56u8 , 48u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 56u8 , 48u8 , 52u8 , 41u8 , 75u8 , 121u8 , 197u8 , 171u8 , 97u8 , 110u8 , 32u8 , 40u8 , 49u8 , 49u8 , 52u8 , 53u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 53u8 , 49u8 , 41u8 , 75u8 , 121u8 , 197u8 , 171u8 , 106u8 , 117u8 , 32u8 , 40u8 , 49u8 , 49u8 , 53u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 53u8 , 54u8 , 41u8 , 77u8 , 97u8 , 110u8 , 226u8 , 128u8 , 153u8 , 101u8 , 110u8 , 32u8 , 40u8 , 49u8 , 56u8 , 54u8 , 48u8 , 226u8 , 128u8 , 147u8 , 49u8 , 56u8 , 54u8 , 49u8 , 41u8 , 77u8 , 97u8 , 110u8 , 106u8 , 105u8 , 32u8 , 40u8 , 49u8 , 54u8 , 53u8 , 56u8 , 226u8 , 128u8 , 147u8 , 49u8 , 54u8 , 54u8 , 49u8 , 41u8 , 77u8 , 97u8 , 110u8 , 106u8 , 117u8 , 32u8 , 40u8 , 49u8 , 48u8 , 50u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 50u8 , 56u8 , 41u8 , 77u8 , 101u8 , 105u8 , 106u8 , 105u8 , 77u8 , 101u8 , 105u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 52u8 , 57u8 , 50u8 , 226u8 , 128u8 , 147u8 , 49u8 , 53u8 , 48u8 , 49u8 , 41u8 , 77u8 , 101u8 , 105u8 , 114u8 , 101u8 , 107u8 , 105u8 , 32u8 , 40u8 , 49u8 , 54u8 , 53u8 , 53u8 , 226u8 , 128u8 , 147u8 , 49u8 , 54u8 , 53u8 , 56u8 , 41u8 , 77u8 , 101u8 , 105u8 , 116u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 51u8 , 56u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 56u8 , 55u8 , 41u8 , 77u8 , 101u8 , 105u8 , 116u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 51u8 , 57u8 , 48u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 57u8 , 52u8 , 41u8 , 77u8 , 101u8 , 105u8 , 119u8 , 97u8 , 32u8 , 40u8 , 49u8 , 55u8 , 54u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 55u8 , 55u8 , 50u8 , 41u8 , 78u8 , 105u8 , 110u8 , 226u8 , 128u8 , 153u8 , 97u8 , 110u8 , 32u8 , 40u8 , 49u8 , 49u8 , 54u8 , 54u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 54u8 , 57u8 , 41u8 , 78u8 , 105u8 , 110u8 , 106u8 , 105u8 , 32u8 , 40u8 , 49u8 , 50u8 , 52u8 , 48u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 52u8 , 51u8 , 41u8 , 78u8 , 105u8 , 110u8 , 106u8 , 117u8 , 32u8 , 40u8 , 56u8 , 53u8 , 49u8 , 226u8 , 128u8 , 147u8 , 56u8 , 53u8 , 52u8 , 41u8 , 78u8 , 105u8 , 110u8 , 110u8 , 97u8 , 32u8 , 40u8 , 56u8 , 56u8 , 53u8 , 226u8 , 128u8 , 147u8 , 56u8 , 56u8 , 57u8 , 41u8 , 78u8 , 105u8 , 110u8 , 112u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 49u8 , 53u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 53u8 , 52u8 , 41u8 , 197u8 , 140u8 , 99u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 51u8 , 49u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 49u8 , 50u8 , 41u8 , 197u8 , 140u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 51u8 , 57u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 52u8 , 50u8 , 56u8 , 41u8 , 197u8 , 140u8 , 104u8 , 111u8 , 32u8 , 40u8 , 49u8 , 49u8 , 54u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 54u8 , 51u8 , 41u8 , 197u8 , 140u8 , 110u8 , 105u8 , 110u8 , 32u8 , 40u8 , 49u8 , 52u8 , 54u8 , 55u8 , 226u8 , 128u8 , 147u8 , 49u8 , 52u8 , 54u8 , 57u8 , 41u8 , 197u8 , 140u8 , 116u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 48u8 , 56u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 56u8 , 55u8 , 41u8 , 197u8 , 140u8 , 119u8 , 97u8 , 32u8 , 40u8 , 57u8 , 54u8 , 49u8 , 226u8 , 128u8 , 147u8 , 57u8 , 54u8 , 52u8 , 41u8 , 82u8 , 101u8 , 105u8 , 107u8 , 105u8 , 32u8 , 40u8 , 55u8 , 49u8 , 53u8 , 226u8 , 128u8 , 147u8 , 55u8 , 49u8 , 55u8 , 41u8 , 82u8 , 101u8 , 105u8 , 119u8 , 97u8 , 82u8 , 121u8 , 97u8 , 107u8 , 117u8 , 110u8 , 105u8 , 110u8 , 32u8 , 40u8 , 49u8 , 50u8 , 51u8 , 56u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 51u8 , 57u8 , 41u8 , 83u8 , 97u8 , 105u8 , 107u8 , 197u8 , 141u8 , 32u8 , 40u8 , 56u8 , 53u8 , 52u8 , 226u8 , 128u8 , 147u8 , 56u8 , 53u8 , 55u8 , 41u8 , 83u8 , 104u8 , 197u8 , 141u8 , 97u8 , 110u8 , 32u8 , 40u8 , 49u8 , 49u8 , 55u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 55u8 , 53u8 , 41u8 , 83u8 , 104u8 , 197u8 , 141u8 , 97u8 , 110u8 , 32u8 , 40u8 , 49u8 , 50u8 , 57u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 48u8 , 50u8 , 41u8 , 83u8 , 104u8 , 197u8 , 141u8 , 99u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 52u8 , 50u8 , 56u8 , 226u8 , 128u8 , 147u8 , 49u8 , 52u8 , 50u8 , 57u8 , 41u8 , 83u8 , 104u8 , 197u8 , 141u8 , 99u8 , 104u8 , 197u8 , 171u8 , 32u8 , 40u8 , 49u8 , 51u8 , 50u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 50u8 , 54u8 , 41u8 , 83u8 , 104u8 , 197u8 , 141u8 , 103u8 , 101u8 , 110u8 , 32u8 , 40u8 , 49u8 , 50u8 , 53u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 54u8 , 48u8 , 41u8 , 83u8 , 104u8 , 197u8 , 141u8 , 104u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 51u8 , 52u8 , 54u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 55u8 , 48u8 , 41u8 , 83u8 , 104u8 , 197u8 , 141u8 , 104u8 , 111u8 , 32u8 , 40u8 , 49u8 , 48u8 , 55u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 55u8 , 55u8 , 41u8 , 83u8 , 104u8 , 197u8 , 141u8 , 104u8 , 111u8 , 32u8 , 40u8 , 49u8 , 54u8 , 52u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 54u8 , 52u8 , 56u8 , 41u8 , 83u8 , 104u8 , 197u8 , 141u8 , 106u8 , 105u8 , 32u8 , 40u8 , 49u8 , 49u8 , 57u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 48u8 , 49u8 , 41u8 , 83u8 , 104u8 , 197u8 , 141u8 , 107u8 , 97u8 , 32u8 , 40u8 , 49u8 , 50u8 , 53u8 , 55u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 53u8 , 57u8 , 41u8 , 83u8 , 104u8 , 197u8 , 141u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 50u8 , 56u8 , 56u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 57u8 , 51u8 , 41u8 , 83u8 , 104u8 , 197u8 , 141u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 48u8 , 55u8 , 55u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 56u8 , 49u8 , 41u8 , 83u8 , 104u8 , 197u8 , 141u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 32u8 , 40u8 , 57u8 , 57u8 , 48u8 , 226u8 , 128u8 , 147u8 , 57u8 , 57u8 , 53u8 , 41u8 , 83u8 , 104u8 , 197u8 , 141u8 , 116u8 , 97u8 , 105u8 , 32u8 , 40u8 , 56u8 , 57u8 , 56u8 , 226u8 , 128u8 , 147u8 , 57u8 , 48u8 , 49u8 , 41u8 , 83u8 , 104u8 , 197u8 , 141u8 , 116u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 55u8 , 49u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 55u8 , 49u8 , 54u8 , 41u8 , 83u8 , 104u8 , 197u8 , 141u8 , 119u8 , 97u8 , 83u8 , 104u8 , 197u8 , 141u8 , 119u8 , 97u8 , 32u8 , 40u8 , 49u8 , 51u8 , 49u8 , 50u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 49u8 , 55u8 , 41u8 , 83u8 , 104u8 , 117u8 , 99u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 54u8 , 56u8 , 54u8 , 226u8 , 128u8 , 147u8 , 55u8 , 48u8 , 49u8 , 41u8 , 84u8 , 97u8 , 105u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 53u8 , 50u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 53u8 , 50u8 , 56u8 , 41u8 , 84u8 , 97u8 , 105u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 55u8 , 48u8 , 49u8 , 226u8 , 128u8 , 147u8 , 55u8 , 48u8 , 52u8 , 41u8 , 84u8 , 97u8 , 105u8 , 107u8 , 97u8 , 32u8 , 40u8 , 54u8 , 52u8 , 53u8 , 226u8 , 128u8 , 147u8 , 54u8 , 53u8 , 48u8 , 41u8 , 84u8 , 97u8 , 105u8 , 115u8 , 104u8 , 197u8 , 141u8 , 84u8 , 101u8 , 110u8 , 45u8 , 97u8 , 110u8 , 32u8 , 40u8 , 56u8 , 53u8 , 55u8 , 226u8 , 128u8 , 147u8 , 56u8 , 53u8 , 57u8 , 41u8 , 84u8 , 101u8 , 110u8 , 98u8 , 117u8 , 110u8 , 32u8 , 40u8 , 49u8 , 53u8 , 51u8 , 50u8 , 226u8 , 128u8 , 147u8 , 49u8 , 53u8 , 53u8 , 53u8 , 41u8 , 84u8 , 101u8 , 110u8 , 99u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 56u8 , 50u8 , 52u8 , 226u8 , 128u8 , 147u8 , 56u8 , 51u8 , 52u8 , 41u8 , 84u8 , 101u8 , 110u8 , 45u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 49u8 , 49u8 , 48u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 49u8 , 51u8 , 41u8 , 84u8 , 101u8 , 110u8 , 226u8 , 128u8 , 153u8 , 101u8 , 110u8 , 32u8 , 40u8 , 57u8 , 55u8 , 51u8 , 226u8 , 128u8 , 147u8 , 57u8 , 55u8 , 54u8 , 41u8 , 84u8 , 101u8 , 110u8 , 103u8 , 101u8 , 110u8 , 32u8 , 40u8 , 57u8 , 55u8 , 56u8 , 226u8 , 128u8 , 147u8 , 57u8 , 56u8 , 51u8 , 41u8 , 84u8 , 101u8 , 110u8 , 103u8 , 105u8 , 32u8 , 40u8 , 49u8 , 48u8 , 53u8 , 51u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 53u8 , 56u8 , 41u8 , 84u8 , 101u8 , 110u8 , 103u8 , 121u8 , 197u8 , 141u8 , 32u8 , 40u8 , 57u8 , 51u8 , 56u8 , 226u8 , 128u8 , 147u8 , 57u8 , 52u8 , 55u8 , 41u8 , 84u8 , 101u8 , 110u8 , 106u8 , 105u8 , 32u8 , 40u8 , 49u8 , 49u8 , 50u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 50u8 , 54u8 , 41u8 , 84u8 , 101u8 , 110u8 , 106u8 , 117u8 , 32u8 , 40u8 , 49u8 , 51u8 , 55u8 , 53u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 55u8 , 57u8 , 41u8 , 84u8 , 101u8 , 110u8 , 109u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 55u8 , 56u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 55u8 , 56u8 , 57u8 , 41u8 , 84u8 , 101u8 , 110u8 , 110u8 , 97u8 , 32u8 , 40u8 , 49u8 , 54u8 , 56u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 54u8 , 56u8 , 52u8 , 41u8 , 84u8 , 101u8 , 110u8 , 110u8 , 105u8 , 110u8 , 32u8 , 40u8 , 49u8 , 49u8 , 48u8 , 56u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 49u8 , 48u8 , 41u8 , 84u8 , 101u8 , 110u8 , 45u8 , 197u8 , 141u8 , 32u8 , 40u8 , 55u8 , 56u8 , 49u8 , 226u8 , 128u8 , 147u8 , 55u8 , 56u8 , 50u8 , 41u8 , 84u8 , 101u8 , 110u8 , 112u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 56u8 , 51u8 , 48u8 , 226u8 , 128u8 , 147u8 , 49u8 , 56u8 , 52u8 , 52u8 , 41u8 , 84u8 , 101u8 , 110u8 , 112u8 , 117u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 50u8 , 51u8 , 51u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 51u8 , 52u8 , 41u8 , 84u8 , 101u8 , 110u8 , 112u8 , 121u8 , 197u8 , 141u8 , 32u8 , 40u8 , 55u8 , 50u8 , 57u8 , 226u8 , 128u8 , 147u8 , 55u8 , 52u8 , 57u8 , 41u8 , 84u8 , 101u8 , 110u8 , 112u8 , 121u8 , 197u8 , 141u8 , 45u8 , 104u8 , 197u8 , 141u8 , 106u8 , 105u8 , 32u8 , 40u8 , 55u8 , 53u8 , 55u8 , 226u8 , 128u8 , 147u8 , 55u8 , 54u8 , 53u8 , 41u8 , 84u8 , 101u8 , 110u8 , 112u8 , 121u8 , 197u8 , 141u8 , 45u8 , 106u8 , 105u8 , 110u8 , 103u8 , 111u8 , 32u8 , 40u8 , 55u8 , 54u8 , 53u8 , 226u8 , 128u8 , 147u8 , 55u8 , 54u8 , 55u8 , 41u8 , 84u8 , 101u8 , 110u8 , 112u8 , 121u8 , 197u8 , 141u8 , 45u8 , 107u8 , 97u8 , 109u8 , 112u8 , 197u8 , 141u8 , 32u8 , 40u8 , 55u8 , 52u8 , 57u8 , 226u8 , 128u8 , 147u8 , 55u8 , 52u8 , 57u8 , 41u8 , 84u8 , 101u8 , 110u8 , 112u8 , 121u8 , 197u8 , 141u8 , 45u8 , 115u8 , 104u8 , 197u8 , 141u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 55u8 , 52u8 , 57u8 , 226u8 , 128u8 , 147u8 , 55u8 , 53u8 , 55u8 , 41u8 , 84u8 , 101u8 , 110u8 , 114u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 57u8 , 55u8 , 48u8 , 226u8 , 128u8 , 147u8 , 57u8 , 55u8 , 51u8 , 41u8 , 84u8 , 101u8 , 110u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 32u8 , 40u8 , 57u8 , 52u8 , 55u8 , 226u8 , 128u8 , 147u8 , 57u8 , 53u8 , 55u8 , 41u8 , 84u8 , 101u8 , 110u8 , 115u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 49u8 , 51u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 51u8 , 50u8 , 41u8 , 84u8 , 101u8 , 110u8 , 115u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 53u8 , 55u8 , 51u8 , 226u8 , 128u8 , 147u8 , 49u8 , 53u8 , 57u8 , 50u8 , 41u8 , 84u8 , 101u8 , 110u8 , 116u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 57u8 , 53u8 , 55u8 , 226u8 , 128u8 , 147u8 , 57u8 , 54u8 , 49u8 , 41u8 , 84u8 , 101u8 , 110u8 , 226u8 , 128u8 , 153u8 , 121u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 49u8 , 52u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 52u8 , 53u8 , 41u8 , 84u8 , 111u8 , 107u8 , 117u8 , 106u8 , 105u8 , 32u8 , 40u8 , 49u8 , 51u8 , 48u8 , 54u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 48u8 , 56u8 , 41u8 , 87u8 , 97u8 , 100u8 , 197u8 , 141u8 , 32u8 , 40u8 , 55u8 , 48u8 , 56u8 , 226u8 , 128u8 , 147u8 , 55u8 , 49u8 , 53u8 , 41u8 , 89u8 , 197u8 , 141u8 , 114u8 , 197u8 , 141u8 , 32u8 , 40u8 , 55u8 , 49u8 , 55u8 , 226u8 , 128u8 , 147u8 , 55u8 , 50u8 , 52u8 , 41u8 , 89u8 , 197u8 , 141u8 , 119u8 , 97u8 , 32u8 , 40u8 , 49u8 , 49u8 , 56u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 56u8 , 50u8 , 41u8]) }) } , narrow : unsafe { # [allow (unused_unsafe)] :: zerovec :: ZeroMap :: from_parts_unchecked (unsafe { :: zerovec :: VarZeroVec :: from_bytes_unchecked (& [239u8 , 0u8 , 0u8 , 0u8 , 0u8 , 0u8 , 9u8 , 0u8 , 19u8 , 0u8 , 27u8 , 0u8 , 37u8 , 0u8 , 47u8 , 0u8 , 50u8 , 0u8 , 60u8 , 0u8 , 71u8 , 0u8 , 81u8 , 0u8 , 91u8 , 0u8 , 101u8 , 0u8 , 111u8 , 0u8 , 122u8 , 0u8 , 133u8 , 0u8 , 142u8 , 0u8 , 152u8 , 0u8 , 164u8 , 0u8 , 177u8 , 0u8 , 188u8 , 0u8 , 199u8 , 0u8 , 201u8 , 0u8 , 212u8 , 0u8 , 221u8 , 0u8 , 231u8 , 0u8 , 242u8 , 0u8 , 253u8 , 0u8 , 8u8 , 1u8 , 20u8 , 1u8 , 33u8 , 1u8 , 44u8 , 1u8 , 55u8 , 1u8 , 65u8 , 1u8 , 74u8 , 1u8 , 84u8 , 1u8 , 94u8 , 1u8 , 102u8 , 1u8 , 111u8 , 1u8 , 120u8 , 1u8 , 129u8 , 1u8 , 139u8 , 1u8 , 149u8 , 1u8 , 159u8 , 1u8 , 169u8 , 1u8 , 180u8 , 1u8 , 192u8 , 1u8 , 202u8 , 1u8 , 212u8 , 1u8 , 220u8 , 1u8 , 229u8 , 1u8 , 239u8 , 1u8 , 247u8 , 1u8 , 1u8 , 2u8 , 11u8 , 2u8 , 21u8 , 2u8 , 29u8 , 2u8 , 38u8 , 2u8 , 49u8 , 2u8 , 60u8 , 2u8 , 70u8 , 2u8 , 81u8 , 2u8 , 92u8 , 2u8 , 102u8 , 2u8 , 112u8 , 2u8 , 122u8 , 2u8 , 132u8 , 2u8 , 142u8 , 2u8 , 153u8 , 2u8 , 163u8 , 2u8 , 174u8 , 2u8 , 183u8 , 2u8 , 195u8 , 2u8 , 208u8 , 2u8 , 220u8 , 2u8 , 231u8 , 2u8 , 241u8 , 2u8 , 251u8 , 2u8 , 1u8 , 3u8 , 10u8 , 3u8 , 19u8 , 3u8 , 28u8 , 3u8 , 38u8 , 3u8 , 47u8 , 3u8 , 55u8 , 3u8 , 66u8 , 3u8 , 77u8 , 3u8 , 86u8 , 3u8 , 100u8 , 3u8 , 109u8 , 3u8 , 121u8 , 3u8 , 131u8 , 3u8 , 140u8 , 3u8 , 149u8 , 3u8 , 159u8 , 3u8 , 168u8 , 3u8 , 177u8 , 3u8 , 187u8 , 3u8 , 197u8 , 3u8 , 205u8 , 3u8 , 213u8 , 3u8 , 224u8 , 3u8 , 232u8 , 3u8 , 241u8 , 3u8 , 250u8 , 3u8 , 4u8 , 4u8 , 13u8 , 4u8 , 21u8 , 4u8 , 31u8 , 4u8 , 43u8 , 4u8 , 54u8 , 4u8 , 64u8 , 4u8 , 74u8 , 4u8 , 85u8 , 4u8 , 95u8 , 4u8 , 105u8 , 4u8 , 115u8 , 4u8 , 124u8 , 4u8 , 135u8 , 4u8 , 145u8 , 4u8 , 155u8 , 4u8 , 166u8 , 4u8 , 177u8 , 4u8 , 189u8 , 4u8 , 197u8 , 4u8 , 208u8 , 4u8 , 220u8 , 4u8 , 230u8 , 4u8 , 240u8 , 4u8 , 250u8 , 4u8 , 5u8 , 5u8 , 14u8 , 5u8 , 23u8 , 5u8 , 34u8 , 5u8 , 44u8 , 5u8 , 55u8 , 5u8 , 65u8 , 5u8 , 76u8 , 5u8 , 86u8 , 5u8 , 97u8 , 5u8 , 107u8 , 5u8 , 120u8 , 5u8 , 132u8 , 5u8 , 141u8 , 5u8 , 151u8 , 5u8 , 161u8 , 5u8 , 171u8 , 5u8 , 179u8 , 5u8 , 188u8 , 5u8 , 197u8 , 5u8 , 206u8 , 5u8 , 217u8 , 5u8 , 226u8 , 5u8 , 234u8 , 5u8 , 246u8 , 5u8 , 0u8 , 6u8 , 9u8 , 6u8 , 18u8 , 6u8 , 28u8 , 6u8 , 40u8 , 6u8 , 52u8 , 6u8 , 62u8 , 6u8 , 72u8 , 6u8 , 82u8 , 6u8 , 92u8 , 6u8 , 102u8 , 6u8 , 112u8 , 6u8 , 117u8 , 6u8 , 126u8 , 6u8 , 138u8 , 6u8 , 150u8 , 6u8 , 162u8 , 6u8 , 172u8 , 6u8 , 182u8 , 6u8 , 192u8 , 6u8 , 201u8 , 6u8 , 210u8 , 6u8 , 221u8 , 6u8 , 230u8 , 6u8 , 238u8 , 6u8 , 246u8 , 6u8 , 255u8 , 6u8 , 9u8 , 7u8 , 16u8 , 7u8 , 25u8 , 7u8 , 30u8 , 7u8 , 43u8 , 7u8 , 52u8 , 7u8 , 62u8 , 7u8 , 72u8 , 7u8 , 83u8 , 7u8 , 94u8 , 7u8 , 105u8 , 7u8 , 116u8 , 7u8 , 126u8 , 7u8 , 136u8 , 7u8 , 146u8 , 7u8 , 156u8 , 7u8 , 165u8 , 7u8 , 178u8 , 7u8 , 190u8 , 7u8 , 200u8 , 7u8 , 212u8 , 7u8 , 217u8 , 7u8 , 227u8 , 7u8 , 237u8 , 7u8 , 247u8 , 7u8 , 0u8 , 8u8 , 9u8 , 8u8 , 15u8 , 8u8 , 24u8 , 8u8 , 35u8 , 8u8 , 45u8 , 8u8 , 55u8 , 8u8 , 64u8 , 8u8 , 74u8 , 8u8 , 84u8 , 8u8 , 94u8 , 8u8 , 104u8 , 8u8 , 114u8 , 8u8 , 125u8 , 8u8 , 135u8 , 8u8 , 146u8 , 8u8 , 154u8 , 8u8 , 164u8 , 8u8 , 176u8 , 8u8 , 186u8 , 8u8 , 200u8 , 8u8 , 215u8 , 8u8 , 230u8 , 8u8 , 245u8 , 8u8 , 0u8 , 9u8 , 12u8 , 9u8 , 23u8 , 9u8 , 34u8 , 9u8 , 45u8 , 9u8 , 55u8 , 9u8 , 66u8 , 9u8 , 74u8 , 9u8 , 82u8 , 9u8 , 97u8 , 110u8 , 101u8 , 105u8 , 45u8 , 49u8 , 55u8 , 55u8 , 50u8 , 97u8 , 110u8 , 103u8 , 101u8 , 110u8 , 45u8 , 49u8 , 49u8 , 55u8 , 53u8 , 97u8 , 110u8 , 110u8 , 97u8 , 45u8 , 57u8 , 54u8 , 56u8 , 97u8 , 110u8 , 115u8 , 101u8 , 105u8 , 45u8 , 49u8 , 56u8 , 53u8 , 52u8 , 97u8 , 110u8 , 116u8 , 101u8 , 105u8 , 45u8 , 49u8 , 50u8 , 50u8 , 55u8 , 98u8 , 99u8 , 101u8 , 98u8 , 117u8 , 110u8 , 97u8 , 110u8 , 45u8 , 49u8 , 52u8 , 52u8 , 52u8 , 98u8 , 117u8 , 110u8 , 99u8 , 104u8 , 117u8 , 45u8 , 49u8 , 51u8 , 55u8 , 50u8 , 98u8 , 117u8 , 110u8 , 101u8 , 105u8 , 45u8 , 49u8 , 50u8 , 54u8 , 52u8 , 98u8 , 117u8 , 110u8 , 106u8 , 105u8 , 45u8 , 49u8 , 49u8 , 56u8 , 53u8 , 98u8 , 117u8 , 110u8 , 107u8 , 97u8 , 45u8 , 49u8 , 56u8 , 48u8 , 52u8 , 98u8 , 117u8 , 110u8 , 107u8 , 105u8 , 45u8 , 49u8 , 53u8 , 48u8 , 49u8 , 98u8 , 117u8 , 110u8 , 107u8 , 121u8 , 117u8 , 45u8 , 49u8 , 56u8 , 54u8 , 49u8 , 98u8 , 117u8 , 110u8 , 109u8 , 101u8 , 105u8 , 45u8 , 49u8 , 52u8 , 54u8 , 57u8 , 98u8 , 117u8 , 110u8 , 111u8 , 45u8 , 49u8 , 50u8 , 54u8 , 48u8 , 98u8 , 117u8 , 110u8 , 112u8 , 111u8 , 45u8 , 49u8 , 51u8 , 49u8 , 55u8 , 98u8 , 117u8 , 110u8 , 114u8 , 111u8 , 107u8 , 117u8 , 45u8 , 49u8 , 53u8 , 57u8 , 50u8 , 98u8 , 117u8 , 110u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 45u8 , 49u8 , 50u8 , 51u8 , 52u8 , 98u8 , 117u8 , 110u8 , 115u8 , 101u8 , 105u8 , 45u8 , 49u8 , 56u8 , 49u8 , 56u8 , 98u8 , 117u8 , 110u8 , 115u8 , 104u8 , 111u8 , 45u8 , 49u8 , 52u8 , 54u8 , 54u8 , 99u8 , 101u8 , 99u8 , 104u8 , 111u8 , 103u8 , 101u8 , 110u8 , 45u8 , 49u8 , 48u8 , 50u8 , 56u8 , 99u8 , 104u8 , 111u8 , 104u8 , 111u8 , 45u8 , 57u8 , 57u8 , 57u8 , 99u8 , 104u8 , 111u8 , 106u8 , 105u8 , 45u8 , 49u8 , 49u8 , 48u8 , 52u8 , 99u8 , 104u8 , 111u8 , 107u8 , 97u8 , 110u8 , 45u8 , 49u8 , 49u8 , 54u8 , 51u8 , 99u8 , 104u8 , 111u8 , 107u8 , 121u8 , 111u8 , 45u8 , 49u8 , 52u8 , 56u8 , 55u8 , 99u8 , 104u8 , 111u8 , 107u8 , 121u8 , 117u8 , 45u8 , 49u8 , 48u8 , 52u8 , 48u8 , 99u8 , 104u8 , 111u8 , 114u8 , 111u8 , 107u8 , 117u8 , 45u8 , 49u8 , 52u8 , 53u8 , 55u8 , 99u8 , 104u8 , 111u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 45u8 , 49u8 , 48u8 , 51u8 , 55u8 , 99u8 , 104u8 , 111u8 , 115u8 , 104u8 , 111u8 , 45u8 , 49u8 , 49u8 , 51u8 , 50u8 , 99u8 , 104u8 , 111u8 , 116u8 , 111u8 , 107u8 , 117u8 , 45u8 , 57u8 , 57u8 , 53u8 , 99u8 , 104u8 , 111u8 , 119u8 , 97u8 , 45u8 , 49u8 , 48u8 , 49u8 , 50u8 , 100u8 , 97u8 , 105u8 , 100u8 , 111u8 , 45u8 , 56u8 , 48u8 , 54u8 , 100u8 , 97u8 , 105u8 , 106u8 , 105u8 , 45u8 , 49u8 , 49u8 , 50u8 , 54u8 , 101u8 , 105u8 , 99u8 , 104u8 , 111u8 , 45u8 , 49u8 , 48u8 , 57u8 , 54u8 , 101u8 , 105u8 , 101u8 , 110u8 , 45u8 , 57u8 , 56u8 , 55u8 , 101u8 , 105u8 , 104u8 , 111u8 , 45u8 , 49u8 , 48u8 , 56u8 , 49u8 , 101u8 , 105u8 , 106u8 , 105u8 , 45u8 , 49u8 , 49u8 , 52u8 , 49u8 , 101u8 , 105u8 , 107u8 , 97u8 , 110u8 , 45u8 , 57u8 , 56u8 , 51u8 , 101u8 , 105u8 , 107u8 , 121u8 , 111u8 , 45u8 , 49u8 , 52u8 , 50u8 , 57u8 , 101u8 , 105u8 , 107u8 , 121u8 , 117u8 , 45u8 , 49u8 , 49u8 , 49u8 , 51u8 , 101u8 , 105u8 , 109u8 , 97u8 , 110u8 , 45u8 , 49u8 , 49u8 , 54u8 , 53u8 , 101u8 , 105u8 , 110u8 , 105u8 , 110u8 , 45u8 , 49u8 , 50u8 , 57u8 , 51u8 , 101u8 , 105u8 , 114u8 , 111u8 , 107u8 , 117u8 , 45u8 , 49u8 , 53u8 , 53u8 , 56u8 , 101u8 , 105u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 45u8 , 49u8 , 49u8 , 54u8 , 48u8 , 101u8 , 105u8 , 115u8 , 104u8 , 111u8 , 45u8 , 49u8 , 48u8 , 52u8 , 54u8 , 101u8 , 105u8 , 115u8 , 104u8 , 111u8 , 45u8 , 49u8 , 53u8 , 48u8 , 52u8 , 101u8 , 105u8 , 115u8 , 111u8 , 45u8 , 57u8 , 56u8 , 57u8 , 101u8 , 110u8 , 99u8 , 104u8 , 111u8 , 45u8 , 57u8 , 50u8 , 51u8 , 101u8 , 110u8 , 103u8 , 101u8 , 110u8 , 45u8 , 49u8 , 51u8 , 51u8 , 54u8 , 101u8 , 110u8 , 103u8 , 105u8 , 45u8 , 57u8 , 48u8 , 49u8 , 101u8 , 110u8 , 107u8 , 121u8 , 111u8 , 45u8 , 49u8 , 51u8 , 48u8 , 56u8 , 101u8 , 110u8 , 107u8 , 121u8 , 111u8 , 45u8 , 49u8 , 55u8 , 52u8 , 52u8 , 101u8 , 110u8 , 107u8 , 121u8 , 117u8 , 45u8 , 49u8 , 48u8 , 54u8 , 57u8 , 101u8 , 110u8 , 111u8 , 45u8 , 49u8 , 50u8 , 51u8 , 57u8 , 101u8 , 110u8 , 112u8 , 111u8 , 45u8 , 49u8 , 54u8 , 55u8 , 51u8 , 101u8 , 110u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 45u8 , 55u8 , 56u8 , 50u8 , 101u8 , 110u8 , 116u8 , 111u8 , 107u8 , 117u8 , 45u8 , 49u8 , 52u8 , 56u8 , 57u8 , 103u8 , 97u8 , 110u8 , 103u8 , 121u8 , 111u8 , 45u8 , 56u8 , 55u8 , 55u8 , 103u8 , 101u8 , 110u8 , 98u8 , 117u8 , 110u8 , 45u8 , 49u8 , 55u8 , 51u8 , 54u8 , 103u8 , 101u8 , 110u8 , 99u8 , 104u8 , 117u8 , 45u8 , 49u8 , 51u8 , 56u8 , 52u8 , 103u8 , 101u8 , 110u8 , 101u8 , 105u8 , 45u8 , 49u8 , 49u8 , 49u8 , 56u8 , 103u8 , 101u8 , 110u8 , 106u8 , 105u8 , 45u8 , 49u8 , 56u8 , 54u8 , 52u8 , 103u8 , 101u8 , 110u8 , 107u8 , 105u8 , 45u8 , 49u8 , 53u8 , 55u8 , 48u8 , 103u8 , 101u8 , 110u8 , 107u8 , 111u8 , 45u8 , 49u8 , 51u8 , 50u8 , 49u8 , 103u8 , 101u8 , 110u8 , 107u8 , 111u8 , 45u8 , 49u8 , 51u8 , 51u8 , 49u8 , 103u8 , 101u8 , 110u8 , 107u8 , 121u8 , 117u8 , 45u8 , 49u8 , 50u8 , 48u8 , 52u8 , 103u8 , 101u8 , 110u8 , 110u8 , 97u8 , 45u8 , 49u8 , 54u8 , 49u8 , 53u8 , 103u8 , 101u8 , 110u8 , 110u8 , 105u8 , 110u8 , 45u8 , 49u8 , 50u8 , 50u8 , 52u8 , 103u8 , 101u8 , 110u8 , 111u8 , 45u8 , 49u8 , 51u8 , 49u8 , 57u8 , 103u8 , 101u8 , 110u8 , 114u8 , 111u8 , 107u8 , 117u8 , 45u8 , 49u8 , 54u8 , 56u8 , 56u8 , 103u8 , 101u8 , 110u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 45u8 , 49u8 , 49u8 , 56u8 , 52u8 , 103u8 , 101u8 , 110u8 , 116u8 , 111u8 , 107u8 , 117u8 , 45u8 , 49u8 , 51u8 , 50u8 , 57u8 , 104u8 , 97u8 , 107u8 , 117u8 , 99u8 , 104u8 , 105u8 , 45u8 , 54u8 , 53u8 , 48u8 , 104u8 , 97u8 , 107u8 , 117u8 , 104u8 , 111u8 , 45u8 , 54u8 , 55u8 , 50u8 , 104u8 , 101u8 , 105u8 , 106u8 , 105u8 , 45u8 , 49u8 , 49u8 , 53u8 , 57u8 , 104u8 , 101u8 , 105u8 , 115u8 , 101u8 , 105u8 , 104u8 , 111u8 , 97u8 , 110u8 , 45u8 , 49u8 , 49u8 , 50u8 , 48u8 , 104u8 , 111u8 , 101u8 , 105u8 , 45u8 , 49u8 , 55u8 , 48u8 , 52u8 , 104u8 , 111u8 , 101u8 , 110u8 , 45u8 , 49u8 , 49u8 , 51u8 , 53u8 , 104u8 , 111u8 , 103u8 , 101u8 , 110u8 , 45u8 , 49u8 , 49u8 , 53u8 , 54u8 , 104u8 , 111u8 , 106u8 , 105u8 , 45u8 , 49u8 , 50u8 , 52u8 , 55u8 , 104u8 , 111u8 , 107u8 , 105u8 , 45u8 , 55u8 , 55u8 , 48u8 , 104u8 , 111u8 , 114u8 , 101u8 , 107u8 , 105u8 , 45u8 , 49u8 , 55u8 , 53u8 , 49u8 , 104u8 , 111u8 , 116u8 , 111u8 , 107u8 , 117u8 , 45u8 , 49u8 , 52u8 , 52u8 , 57u8 , 106u8 , 105u8 , 97u8 , 110u8 , 45u8 , 49u8 , 48u8 , 50u8 , 49u8 , 106u8 , 105u8 , 110u8 , 103u8 , 111u8 , 107u8 , 101u8 , 105u8 , 117u8 , 110u8 , 45u8 , 55u8 , 54u8 , 55u8 , 106u8 , 105u8 , 110u8 , 107u8 , 105u8 , 45u8 , 55u8 , 50u8 , 52u8 , 106u8 , 105u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 45u8 , 49u8 , 48u8 , 54u8 , 53u8 , 106u8 , 105u8 , 115u8 , 104u8 , 111u8 , 45u8 , 49u8 , 49u8 , 55u8 , 55u8 , 106u8 , 111u8 , 101u8 , 105u8 , 45u8 , 49u8 , 50u8 , 51u8 , 50u8 , 106u8 , 111u8 , 103u8 , 97u8 , 110u8 , 45u8 , 56u8 , 53u8 , 57u8 , 106u8 , 111u8 , 103u8 , 101u8 , 110u8 , 45u8 , 49u8 , 50u8 , 48u8 , 55u8 , 106u8 , 111u8 , 103u8 , 101u8 , 110u8 , 45u8 , 57u8 , 55u8 , 54u8 , 106u8 , 111u8 , 104u8 , 101u8 , 105u8 , 45u8 , 57u8 , 51u8 , 49u8 , 106u8 , 111u8 , 107u8 , 121u8 , 111u8 , 45u8 , 49u8 , 54u8 , 56u8 , 52u8 , 106u8 , 111u8 , 107u8 , 121u8 , 117u8 , 45u8 , 49u8 , 50u8 , 49u8 , 57u8 , 106u8 , 111u8 , 111u8 , 45u8 , 49u8 , 50u8 , 50u8 , 50u8 , 106u8 , 111u8 , 111u8 , 45u8 , 49u8 , 54u8 , 53u8 , 50u8 , 106u8 , 111u8 , 116u8 , 111u8 , 107u8 , 117u8 , 45u8 , 49u8 , 48u8 , 57u8 , 55u8 , 106u8 , 111u8 , 119u8 , 97u8 , 45u8 , 56u8 , 51u8 , 52u8 , 106u8 , 117u8 , 101u8 , 105u8 , 45u8 , 49u8 , 49u8 , 56u8 , 50u8 , 107u8 , 97u8 , 101u8 , 105u8 , 45u8 , 49u8 , 56u8 , 52u8 , 56u8 , 107u8 , 97u8 , 103u8 , 101u8 , 110u8 , 45u8 , 49u8 , 51u8 , 48u8 , 51u8 , 107u8 , 97u8 , 104u8 , 111u8 , 45u8 , 49u8 , 48u8 , 57u8 , 52u8 , 107u8 , 97u8 , 106u8 , 111u8 , 45u8 , 56u8 , 52u8 , 56u8 , 107u8 , 97u8 , 107u8 , 101u8 , 105u8 , 45u8 , 49u8 , 51u8 , 56u8 , 55u8 , 107u8 , 97u8 , 107u8 , 105u8 , 116u8 , 115u8 , 117u8 , 45u8 , 49u8 , 52u8 , 52u8 , 49u8 , 107u8 , 97u8 , 110u8 , 98u8 , 117u8 , 110u8 , 45u8 , 49u8 , 54u8 , 54u8 , 49u8 , 107u8 , 97u8 , 110u8 , 101u8 , 105u8 , 45u8 , 49u8 , 54u8 , 50u8 , 52u8 , 107u8 , 97u8 , 110u8 , 101u8 , 110u8 , 45u8 , 49u8 , 55u8 , 52u8 , 56u8 , 107u8 , 97u8 , 110u8 , 103u8 , 101u8 , 110u8 , 45u8 , 49u8 , 50u8 , 52u8 , 51u8 , 107u8 , 97u8 , 110u8 , 106u8 , 105u8 , 45u8 , 49u8 , 48u8 , 56u8 , 55u8 , 107u8 , 97u8 , 110u8 , 107u8 , 105u8 , 45u8 , 49u8 , 50u8 , 50u8 , 57u8 , 107u8 , 97u8 , 110u8 , 107u8 , 111u8 , 45u8 , 49u8 , 48u8 , 48u8 , 52u8 , 107u8 , 97u8 , 110u8 , 110u8 , 97u8 , 45u8 , 57u8 , 56u8 , 53u8 , 107u8 , 97u8 , 110u8 , 110u8 , 105u8 , 110u8 , 45u8 , 49u8 , 48u8 , 49u8 , 55u8 , 107u8 , 97u8 , 110u8 , 112u8 , 111u8 , 45u8 , 49u8 , 55u8 , 52u8 , 49u8 , 107u8 , 97u8 , 110u8 , 112u8 , 121u8 , 111u8 , 45u8 , 56u8 , 56u8 , 57u8 , 107u8 , 97u8 , 110u8 , 115u8 , 101u8 , 105u8 , 45u8 , 49u8 , 55u8 , 56u8 , 57u8 , 107u8 , 97u8 , 110u8 , 115u8 , 104u8 , 111u8 , 45u8 , 49u8 , 52u8 , 54u8 , 48u8 , 107u8 , 97u8 , 110u8 , 116u8 , 111u8 , 107u8 , 117u8 , 45u8 , 49u8 , 48u8 , 52u8 , 52u8 , 107u8 , 97u8 , 111u8 , 45u8 , 49u8 , 49u8 , 54u8 , 57u8 , 107u8 , 97u8 , 114u8 , 111u8 , 107u8 , 117u8 , 45u8 , 49u8 , 50u8 , 50u8 , 53u8 , 107u8 , 97u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 45u8 , 49u8 , 51u8 , 50u8 , 54u8 , 107u8 , 97u8 , 115u8 , 104u8 , 111u8 , 45u8 , 49u8 , 49u8 , 48u8 , 54u8 , 107u8 , 97u8 , 116u8 , 101u8 , 105u8 , 45u8 , 49u8 , 50u8 , 51u8 , 53u8 , 107u8 , 101u8 , 105u8 , 97u8 , 110u8 , 45u8 , 49u8 , 54u8 , 52u8 , 56u8 , 107u8 , 101u8 , 105u8 , 99u8 , 104u8 , 111u8 , 45u8 , 49u8 , 53u8 , 57u8 , 54u8 , 107u8 , 101u8 , 105u8 , 111u8 , 45u8 , 49u8 , 56u8 , 54u8 , 53u8 , 107u8 , 101u8 , 105u8 , 117u8 , 110u8 , 45u8 , 55u8 , 48u8 , 52u8 , 107u8 , 101u8 , 110u8 , 99u8 , 104u8 , 111u8 , 45u8 , 49u8 , 50u8 , 52u8 , 57u8 , 107u8 , 101u8 , 110u8 , 101u8 , 105u8 , 45u8 , 49u8 , 50u8 , 48u8 , 54u8 , 107u8 , 101u8 , 110u8 , 103u8 , 101u8 , 110u8 , 45u8 , 49u8 , 51u8 , 48u8 , 50u8 , 107u8 , 101u8 , 110u8 , 106u8 , 105u8 , 45u8 , 49u8 , 50u8 , 55u8 , 53u8 , 107u8 , 101u8 , 110u8 , 107u8 , 121u8 , 117u8 , 45u8 , 49u8 , 49u8 , 57u8 , 48u8 , 107u8 , 101u8 , 110u8 , 109u8 , 117u8 , 45u8 , 49u8 , 51u8 , 51u8 , 52u8 , 107u8 , 101u8 , 110u8 , 110u8 , 105u8 , 110u8 , 45u8 , 49u8 , 50u8 , 48u8 , 49u8 , 107u8 , 101u8 , 110u8 , 112u8 , 111u8 , 45u8 , 49u8 , 50u8 , 49u8 , 51u8 , 107u8 , 101u8 , 110u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 45u8 , 49u8 , 50u8 , 49u8 , 49u8 , 107u8 , 101u8 , 110u8 , 116u8 , 111u8 , 107u8 , 117u8 , 45u8 , 49u8 , 51u8 , 55u8 , 48u8 , 107u8 , 111u8 , 97u8 , 110u8 , 45u8 , 49u8 , 50u8 , 55u8 , 56u8 , 107u8 , 111u8 , 99u8 , 104u8 , 111u8 , 45u8 , 49u8 , 50u8 , 54u8 , 49u8 , 107u8 , 111u8 , 103u8 , 101u8 , 110u8 , 45u8 , 49u8 , 50u8 , 53u8 , 54u8 , 107u8 , 111u8 , 104u8 , 101u8 , 105u8 , 45u8 , 49u8 , 48u8 , 53u8 , 56u8 , 107u8 , 111u8 , 104u8 , 111u8 , 45u8 , 57u8 , 54u8 , 52u8 , 107u8 , 111u8 , 106u8 , 105u8 , 45u8 , 49u8 , 49u8 , 52u8 , 50u8 , 107u8 , 111u8 , 106u8 , 105u8 , 45u8 , 49u8 , 53u8 , 53u8 , 53u8 , 107u8 , 111u8 , 107u8 , 97u8 , 45u8 , 49u8 , 56u8 , 52u8 , 52u8 , 107u8 , 111u8 , 107u8 , 111u8 , 107u8 , 117u8 , 45u8 , 49u8 , 51u8 , 52u8 , 48u8 , 107u8 , 111u8 , 110u8 , 105u8 , 110u8 , 45u8 , 56u8 , 49u8 , 48u8 , 107u8 , 111u8 , 111u8 , 45u8 , 49u8 , 51u8 , 56u8 , 57u8 , 107u8 , 111u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 45u8 , 49u8 , 51u8 , 55u8 , 57u8 , 107u8 , 111u8 , 115u8 , 104u8 , 111u8 , 45u8 , 49u8 , 52u8 , 53u8 , 53u8 , 107u8 , 111u8 , 119u8 , 97u8 , 45u8 , 49u8 , 48u8 , 57u8 , 57u8 , 107u8 , 111u8 , 119u8 , 97u8 , 45u8 , 49u8 , 51u8 , 56u8 , 49u8 , 107u8 , 121u8 , 111u8 , 104u8 , 111u8 , 45u8 , 49u8 , 55u8 , 49u8 , 54u8 , 107u8 , 121u8 , 111u8 , 114u8 , 111u8 , 107u8 , 117u8 , 45u8 , 49u8 , 53u8 , 50u8 , 56u8 , 107u8 , 121u8 , 111u8 , 116u8 , 111u8 , 107u8 , 117u8 , 45u8 , 49u8 , 52u8 , 53u8 , 50u8 , 107u8 , 121u8 , 111u8 , 119u8 , 97u8 , 45u8 , 49u8 , 56u8 , 48u8 , 49u8 , 107u8 , 121u8 , 117u8 , 97u8 , 110u8 , 45u8 , 49u8 , 49u8 , 52u8 , 53u8 , 107u8 , 121u8 , 117u8 , 106u8 , 117u8 , 45u8 , 49u8 , 49u8 , 53u8 , 52u8 , 109u8 , 97u8 , 110u8 , 101u8 , 110u8 , 45u8 , 49u8 , 56u8 , 54u8 , 48u8 , 109u8 , 97u8 , 110u8 , 106u8 , 105u8 , 45u8 , 49u8 , 54u8 , 53u8 , 56u8 , 109u8 , 97u8 , 110u8 , 106u8 , 117u8 , 45u8 , 49u8 , 48u8 , 50u8 , 52u8 , 109u8 , 101u8 , 105u8 , 106u8 , 105u8 , 109u8 , 101u8 , 105u8 , 111u8 , 45u8 , 49u8 , 52u8 , 57u8 , 50u8 , 109u8 , 101u8 , 105u8 , 114u8 , 101u8 , 107u8 , 105u8 , 45u8 , 49u8 , 54u8 , 53u8 , 53u8 , 109u8 , 101u8 , 105u8 , 116u8 , 111u8 , 107u8 , 117u8 , 45u8 , 49u8 , 51u8 , 56u8 , 55u8 , 109u8 , 101u8 , 105u8 , 116u8 , 111u8 , 107u8 , 117u8 , 45u8 , 49u8 , 51u8 , 57u8 , 48u8 , 109u8 , 101u8 , 105u8 , 119u8 , 97u8 , 45u8 , 49u8 , 55u8 , 54u8 , 52u8 , 110u8 , 105u8 , 110u8 , 97u8 , 110u8 , 45u8 , 49u8 , 49u8 , 54u8 , 54u8 , 110u8 , 105u8 , 110u8 , 106u8 , 105u8 , 45u8 , 49u8 , 50u8 , 52u8 , 48u8 , 110u8 , 105u8 , 110u8 , 106u8 , 117u8 , 45u8 , 56u8 , 53u8 , 49u8 , 110u8 , 105u8 , 110u8 , 110u8 , 97u8 , 45u8 , 56u8 , 56u8 , 53u8 , 110u8 , 105u8 , 110u8 , 112u8 , 101u8 , 105u8 , 45u8 , 49u8 , 49u8 , 53u8 , 49u8 , 111u8 , 99u8 , 104u8 , 111u8 , 45u8 , 49u8 , 51u8 , 49u8 , 49u8 , 111u8 , 101u8 , 105u8 , 45u8 , 49u8 , 51u8 , 57u8 , 52u8 , 111u8 , 104u8 , 111u8 , 45u8 , 49u8 , 49u8 , 54u8 , 49u8 , 111u8 , 110u8 , 105u8 , 110u8 , 45u8 , 49u8 , 52u8 , 54u8 , 55u8 , 111u8 , 116u8 , 111u8 , 107u8 , 117u8 , 45u8 , 49u8 , 48u8 , 56u8 , 52u8 , 111u8 , 119u8 , 97u8 , 45u8 , 57u8 , 54u8 , 49u8 , 114u8 , 101u8 , 105u8 , 107u8 , 105u8 , 45u8 , 55u8 , 49u8 , 53u8 , 114u8 , 101u8 , 105u8 , 119u8 , 97u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 110u8 , 105u8 , 110u8 , 45u8 , 49u8 , 50u8 , 51u8 , 56u8 , 115u8 , 97u8 , 105u8 , 107u8 , 111u8 , 45u8 , 56u8 , 53u8 , 52u8 , 115u8 , 104u8 , 111u8 , 97u8 , 110u8 , 45u8 , 49u8 , 49u8 , 55u8 , 49u8 , 115u8 , 104u8 , 111u8 , 97u8 , 110u8 , 45u8 , 49u8 , 50u8 , 57u8 , 57u8 , 115u8 , 104u8 , 111u8 , 99u8 , 104u8 , 111u8 , 45u8 , 49u8 , 52u8 , 50u8 , 56u8 , 115u8 , 104u8 , 111u8 , 99u8 , 104u8 , 117u8 , 45u8 , 49u8 , 51u8 , 50u8 , 52u8 , 115u8 , 104u8 , 111u8 , 103u8 , 101u8 , 110u8 , 45u8 , 49u8 , 50u8 , 53u8 , 57u8 , 115u8 , 104u8 , 111u8 , 104u8 , 101u8 , 105u8 , 45u8 , 49u8 , 51u8 , 52u8 , 54u8 , 115u8 , 104u8 , 111u8 , 104u8 , 111u8 , 45u8 , 49u8 , 48u8 , 55u8 , 52u8 , 115u8 , 104u8 , 111u8 , 104u8 , 111u8 , 45u8 , 49u8 , 54u8 , 52u8 , 52u8 , 115u8 , 104u8 , 111u8 , 106u8 , 105u8 , 45u8 , 49u8 , 49u8 , 57u8 , 57u8 , 115u8 , 104u8 , 111u8 , 107u8 , 97u8 , 45u8 , 49u8 , 50u8 , 53u8 , 55u8 , 115u8 , 104u8 , 111u8 , 111u8 , 45u8 , 49u8 , 50u8 , 56u8 , 56u8 , 115u8 , 104u8 , 111u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 45u8 , 49u8 , 48u8 , 55u8 , 55u8 , 115u8 , 104u8 , 111u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 45u8 , 57u8 , 57u8 , 48u8 , 115u8 , 104u8 , 111u8 , 116u8 , 97u8 , 105u8 , 45u8 , 56u8 , 57u8 , 56u8 , 115u8 , 104u8 , 111u8 , 116u8 , 111u8 , 107u8 , 117u8 , 45u8 , 49u8 , 55u8 , 49u8 , 49u8 , 115u8 , 104u8 , 111u8 , 119u8 , 97u8 , 115u8 , 104u8 , 111u8 , 119u8 , 97u8 , 45u8 , 49u8 , 51u8 , 49u8 , 50u8 , 115u8 , 104u8 , 117u8 , 99u8 , 104u8 , 111u8 , 45u8 , 54u8 , 56u8 , 54u8 , 116u8 , 97u8 , 105u8 , 101u8 , 105u8 , 45u8 , 49u8 , 53u8 , 50u8 , 49u8 , 116u8 , 97u8 , 105u8 , 104u8 , 111u8 , 45u8 , 55u8 , 48u8 , 49u8 , 116u8 , 97u8 , 105u8 , 107u8 , 97u8 , 45u8 , 54u8 , 52u8 , 53u8 , 116u8 , 97u8 , 105u8 , 115u8 , 104u8 , 111u8 , 116u8 , 101u8 , 110u8 , 97u8 , 110u8 , 45u8 , 56u8 , 53u8 , 55u8 , 116u8 , 101u8 , 110u8 , 98u8 , 117u8 , 110u8 , 45u8 , 49u8 , 53u8 , 51u8 , 50u8 , 116u8 , 101u8 , 110u8 , 99u8 , 104u8 , 111u8 , 45u8 , 56u8 , 50u8 , 52u8 , 116u8 , 101u8 , 110u8 , 101u8 , 105u8 , 45u8 , 49u8 , 49u8 , 49u8 , 48u8 , 116u8 , 101u8 , 110u8 , 101u8 , 110u8 , 45u8 , 57u8 , 55u8 , 51u8 , 116u8 , 101u8 , 110u8 , 103u8 , 101u8 , 110u8 , 45u8 , 57u8 , 55u8 , 56u8 , 116u8 , 101u8 , 110u8 , 103u8 , 105u8 , 45u8 , 49u8 , 48u8 , 53u8 , 51u8 , 116u8 , 101u8 , 110u8 , 103u8 , 121u8 , 111u8 , 45u8 , 57u8 , 51u8 , 56u8 , 116u8 , 101u8 , 110u8 , 106u8 , 105u8 , 45u8 , 49u8 , 49u8 , 50u8 , 52u8 , 116u8 , 101u8 , 110u8 , 106u8 , 117u8 , 45u8 , 49u8 , 51u8 , 55u8 , 53u8 , 116u8 , 101u8 , 110u8 , 109u8 , 101u8 , 105u8 , 45u8 , 49u8 , 55u8 , 56u8 , 49u8 , 116u8 , 101u8 , 110u8 , 110u8 , 97u8 , 45u8 , 49u8 , 54u8 , 56u8 , 49u8 , 116u8 , 101u8 , 110u8 , 110u8 , 105u8 , 110u8 , 45u8 , 49u8 , 49u8 , 48u8 , 56u8 , 116u8 , 101u8 , 110u8 , 111u8 , 45u8 , 55u8 , 56u8 , 49u8 , 116u8 , 101u8 , 110u8 , 112u8 , 111u8 , 45u8 , 49u8 , 56u8 , 51u8 , 48u8 , 116u8 , 101u8 , 110u8 , 112u8 , 117u8 , 107u8 , 117u8 , 45u8 , 49u8 , 50u8 , 51u8 , 51u8 , 116u8 , 101u8 , 110u8 , 112u8 , 121u8 , 111u8 , 45u8 , 55u8 , 50u8 , 57u8 , 116u8 , 101u8 , 110u8 , 112u8 , 121u8 , 111u8 , 104u8 , 111u8 , 106u8 , 105u8 , 45u8 , 55u8 , 53u8 , 55u8 , 116u8 , 101u8 , 110u8 , 112u8 , 121u8 , 111u8 , 106u8 , 105u8 , 110u8 , 103u8 , 111u8 , 45u8 , 55u8 , 54u8 , 53u8 , 116u8 , 101u8 , 110u8 , 112u8 , 121u8 , 111u8 , 107u8 , 97u8 , 109u8 , 112u8 , 111u8 , 45u8 , 55u8 , 52u8 , 57u8 , 116u8 , 101u8 , 110u8 , 112u8 , 121u8 , 111u8 , 115u8 , 104u8 , 111u8 , 104u8 , 111u8 , 45u8 , 55u8 , 52u8 , 57u8 , 116u8 , 101u8 , 110u8 , 114u8 , 111u8 , 107u8 , 117u8 , 45u8 , 57u8 , 55u8 , 48u8 , 116u8 , 101u8 , 110u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 45u8 , 57u8 , 52u8 , 55u8 , 116u8 , 101u8 , 110u8 , 115u8 , 104u8 , 111u8 , 45u8 , 49u8 , 49u8 , 51u8 , 49u8 , 116u8 , 101u8 , 110u8 , 115u8 , 104u8 , 111u8 , 45u8 , 49u8 , 53u8 , 55u8 , 51u8 , 116u8 , 101u8 , 110u8 , 116u8 , 111u8 , 107u8 , 117u8 , 45u8 , 57u8 , 53u8 , 55u8 , 116u8 , 101u8 , 110u8 , 121u8 , 111u8 , 45u8 , 49u8 , 49u8 , 52u8 , 52u8 , 116u8 , 111u8 , 107u8 , 117u8 , 106u8 , 105u8 , 45u8 , 49u8 , 51u8 , 48u8 , 54u8 , 119u8 , 97u8 , 100u8 , 111u8 , 45u8 , 55u8 , 48u8 , 56u8 , 121u8 , 111u8 , 114u8 , 111u8 , 45u8 , 55u8 , 49u8 , 55u8 , 121u8 , 111u8 , 119u8 , 97u8 , 45u8 , 49u8 , 49u8 , 56u8 , 49u8]) } , unsafe { :: zerovec :: VarZeroVec :: from_bytes_unchecked (& [239u8 , 0u8 , 0u8 , 0u8 , 0u8 , 0u8 , 21u8 , 0u8 , 40u8 , 0u8 , 56u8 , 0u8 , 75u8 , 0u8 , 94u8 , 0u8 , 150u8 , 0u8 , 172u8 , 0u8 , 193u8 , 0u8 , 215u8 , 0u8 , 234u8 , 0u8 , 253u8 , 0u8 , 16u8 , 1u8 , 37u8 , 1u8 , 57u8 , 1u8 , 79u8 , 1u8 , 99u8 , 1u8 , 120u8 , 1u8 , 142u8 , 1u8 , 162u8 , 1u8 , 183u8 , 1u8 , 227u8 , 1u8 , 248u8 , 1u8 , 12u8 , 2u8 , 32u8 , 2u8 , 53u8 , 2u8 , 75u8 , 2u8 , 97u8 , 2u8 , 119u8 , 2u8 , 142u8 , 2u8 , 164u8 , 2u8 , 184u8 , 2u8 , 204u8 , 2u8 , 222u8 , 2u8 , 241u8 , 2u8 , 5u8 , 3u8 , 21u8 , 3u8 , 40u8 , 3u8 , 58u8 , 3u8 , 75u8 , 3u8 , 95u8 , 3u8 , 115u8 , 3u8 , 134u8 , 3u8 , 153u8 , 3u8 , 173u8 , 3u8 , 194u8 , 3u8 , 214u8 , 3u8 , 234u8 , 3u8 , 250u8 , 3u8 , 12u8 , 4u8 , 31u8 , 4u8 , 47u8 , 4u8 , 67u8 , 4u8 , 87u8 , 4u8 , 107u8 , 4u8 , 128u8 , 4u8 , 147u8 , 4u8 , 166u8 , 4u8 , 186u8 , 4u8 , 205u8 , 4u8 , 225u8 , 4u8 , 246u8 , 4u8 , 12u8 , 5u8 , 31u8 , 5u8 , 50u8 , 5u8 , 70u8 , 5u8 , 90u8 , 5u8 , 111u8 , 5u8 , 130u8 , 5u8 , 150u8 , 5u8 , 169u8 , 5u8 , 190u8 , 5u8 , 212u8 , 5u8 , 233u8 , 5u8 , 252u8 , 5u8 , 15u8 , 6u8 , 34u8 , 6u8 , 35u8 , 6u8 , 54u8 , 6u8 , 73u8 , 6u8 , 92u8 , 6u8 , 112u8 , 6u8 , 131u8 , 6u8 , 148u8 , 6u8 , 169u8 , 6u8 , 190u8 , 6u8 , 208u8 , 6u8 , 231u8 , 6u8 , 248u8 , 6u8 , 13u8 , 7u8 , 33u8 , 7u8 , 52u8 , 7u8 , 70u8 , 7u8 , 90u8 , 7u8 , 108u8 , 7u8 , 126u8 , 7u8 , 147u8 , 7u8 , 168u8 , 7u8 , 187u8 , 7u8 , 206u8 , 7u8 , 227u8 , 7u8 , 244u8 , 7u8 , 6u8 , 8u8 , 24u8 , 8u8 , 43u8 , 8u8 , 62u8 , 8u8 , 79u8 , 8u8 , 98u8 , 8u8 , 119u8 , 8u8 , 139u8 , 8u8 , 161u8 , 8u8 , 183u8 , 8u8 , 203u8 , 8u8 , 222u8 , 8u8 , 241u8 , 8u8 , 5u8 , 9u8 , 22u8 , 9u8 , 42u8 , 9u8 , 62u8 , 9u8 , 81u8 , 9u8 , 101u8 , 9u8 , 122u8 , 9u8 , 143u8 , 9u8 , 161u8 , 9u8 , 181u8 , 9u8 , 202u8 , 9u8 , 222u8 , 9u8 , 241u8 , 9u8 , 4u8 , 10u8 , 25u8 , 10u8 , 44u8 , 10u8 , 61u8 , 10u8 , 82u8 , 10u8 , 104u8 , 10u8 , 124u8 , 10u8 , 143u8 , 10u8 , 164u8 , 10u8 , 183u8 , 10u8 , 203u8 , 10u8 , 223u8 , 10u8 , 245u8 , 10u8 , 10u8 , 11u8 , 29u8 , 11u8 , 50u8 , 11u8 , 70u8 , 11u8 , 90u8 , 11u8 , 108u8 , 11u8 , 127u8 , 11u8 , 146u8 , 11u8 , 165u8 , 11u8 , 186u8 , 11u8 , 204u8 , 11u8 , 223u8 , 11u8 , 245u8 , 11u8 , 10u8 , 12u8 , 29u8 , 12u8 , 48u8 , 12u8 , 69u8 , 12u8 , 91u8 , 12u8 , 113u8 , 12u8 , 133u8 , 12u8 , 153u8 , 12u8 , 173u8 , 12u8 , 195u8 , 12u8 , 214u8 , 12u8 , 233u8 , 12u8 , 234u8 , 12u8 , 253u8 , 12u8 , 18u8 , 13u8 , 39u8 , 13u8 , 60u8 , 13u8 , 79u8 , 13u8 , 101u8 , 13u8 , 120u8 , 13u8 , 137u8 , 13u8 , 154u8 , 13u8 , 174u8 , 13u8 , 194u8 , 13u8 , 212u8 , 13u8 , 230u8 , 13u8 , 249u8 , 13u8 , 13u8 , 14u8 , 29u8 , 14u8 , 46u8 , 14u8 , 47u8 , 14u8 , 69u8 , 14u8 , 87u8 , 14u8 , 107u8 , 14u8 , 127u8 , 14u8 , 149u8 , 14u8 , 171u8 , 14u8 , 192u8 , 14u8 , 213u8 , 14u8 , 233u8 , 14u8 , 253u8 , 14u8 , 17u8 , 15u8 , 37u8 , 15u8 , 57u8 , 15u8 , 80u8 , 15u8 , 101u8 , 15u8 , 120u8 , 15u8 , 142u8 , 15u8 , 143u8 , 15u8 , 163u8 , 15u8 , 182u8 , 15u8 , 201u8 , 15u8 , 219u8 , 15u8 , 236u8 , 15u8 , 237u8 , 15u8 , 255u8 , 15u8 , 19u8 , 16u8 , 38u8 , 16u8 , 58u8 , 16u8 , 78u8 , 16u8 , 96u8 , 16u8 , 115u8 , 16u8 , 134u8 , 16u8 , 153u8 , 16u8 , 172u8 , 16u8 , 192u8 , 16u8 , 211u8 , 16u8 , 231u8 , 16u8 , 249u8 , 16u8 , 13u8 , 17u8 , 34u8 , 17u8 , 53u8 , 17u8 , 78u8 , 17u8 , 103u8 , 17u8 , 129u8 , 17u8 , 156u8 , 17u8 , 175u8 , 17u8 , 195u8 , 17u8 , 216u8 , 17u8 , 237u8 , 17u8 , 0u8 , 18u8 , 23u8 , 18u8 , 43u8 , 18u8 , 60u8 , 18u8 , 78u8 , 18u8 , 65u8 , 110u8 , 226u8 , 128u8 , 153u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 55u8 , 55u8 , 50u8 , 226u8 , 128u8 , 147u8 , 49u8 , 55u8 , 56u8 , 49u8 , 41u8 , 65u8 , 110u8 , 103u8 , 101u8 , 110u8 , 32u8 , 40u8 , 49u8 , 49u8 , 55u8 , 53u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 55u8 , 55u8 , 41u8 , 65u8 , 110u8 , 110u8 , 97u8 , 32u8 , 40u8 , 57u8 , 54u8 , 56u8 , 226u8 , 128u8 , 147u8 , 57u8 , 55u8 , 48u8 , 41u8 , 65u8 , 110u8 , 115u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 56u8 , 53u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 56u8 , 54u8 , 48u8 , 41u8 , 65u8 , 110u8 , 116u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 50u8 , 50u8 , 55u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 50u8 , 57u8 , 41u8 , 240u8 , 145u8 , 132u8 , 136u8 , 240u8 , 145u8 , 132u8 , 179u8 , 240u8 , 145u8 , 132u8 , 162u8 , 240u8 , 145u8 , 132u8 , 168u8 , 240u8 , 145u8 , 132u8 , 140u8 , 240u8 , 145u8 , 132u8 , 180u8 , 240u8 , 145u8 , 132u8 , 145u8 , 240u8 , 145u8 , 132u8 , 180u8 , 240u8 , 145u8 , 132u8 , 155u8 , 240u8 , 145u8 , 132u8 , 171u8 , 240u8 , 145u8 , 132u8 , 162u8 , 240u8 , 145u8 , 132u8 , 180u8 , 240u8 , 145u8 , 132u8 , 157u8 , 240u8 , 145u8 , 132u8 , 167u8 , 66u8 , 117u8 , 110u8 , 226u8 , 128u8 , 153u8 , 97u8 , 110u8 , 32u8 , 40u8 , 49u8 , 52u8 , 52u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 52u8 , 52u8 , 57u8 , 41u8 , 66u8 , 117u8 , 110u8 , 99u8 , 104u8 , 197u8 , 171u8 , 32u8 , 40u8 , 49u8 , 51u8 , 55u8 , 50u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 55u8 , 53u8 , 41u8 , 66u8 , 117u8 , 110u8 , 226u8 , 128u8 , 153u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 50u8 , 54u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 55u8 , 53u8 , 41u8 , 66u8 , 117u8 , 110u8 , 106u8 , 105u8 , 32u8 , 40u8 , 49u8 , 49u8 , 56u8 , 53u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 57u8 , 48u8 , 41u8 , 66u8 , 117u8 , 110u8 , 107u8 , 97u8 , 32u8 , 40u8 , 49u8 , 56u8 , 48u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 56u8 , 49u8 , 56u8 , 41u8 , 66u8 , 117u8 , 110u8 , 107u8 , 105u8 , 32u8 , 40u8 , 49u8 , 53u8 , 48u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 53u8 , 48u8 , 52u8 , 41u8 , 66u8 , 117u8 , 110u8 , 107u8 , 121u8 , 197u8 , 171u8 , 32u8 , 40u8 , 49u8 , 56u8 , 54u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 56u8 , 54u8 , 52u8 , 41u8 , 66u8 , 117u8 , 110u8 , 109u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 52u8 , 54u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 52u8 , 56u8 , 55u8 , 41u8 , 66u8 , 117u8 , 110u8 , 226u8 , 128u8 , 153u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 50u8 , 54u8 , 48u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 54u8 , 49u8 , 41u8 , 66u8 , 117u8 , 110u8 , 112u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 51u8 , 49u8 , 55u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 49u8 , 57u8 , 41u8 , 66u8 , 117u8 , 110u8 , 114u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 53u8 , 57u8 , 50u8 , 226u8 , 128u8 , 147u8 , 49u8 , 53u8 , 57u8 , 54u8 , 41u8 , 66u8 , 117u8 , 110u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 50u8 , 51u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 51u8 , 53u8 , 41u8 , 66u8 , 117u8 , 110u8 , 115u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 56u8 , 49u8 , 56u8 , 226u8 , 128u8 , 147u8 , 49u8 , 56u8 , 51u8 , 48u8 , 41u8 , 66u8 , 117u8 , 110u8 , 115u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 52u8 , 54u8 , 54u8 , 226u8 , 128u8 , 147u8 , 49u8 , 52u8 , 54u8 , 55u8 , 41u8 , 240u8 , 145u8 , 132u8 , 136u8 , 240u8 , 145u8 , 132u8 , 179u8 , 240u8 , 145u8 , 132u8 , 162u8 , 240u8 , 145u8 , 132u8 , 168u8 , 240u8 , 145u8 , 132u8 , 140u8 , 240u8 , 145u8 , 132u8 , 180u8 , 240u8 , 145u8 , 132u8 , 145u8 , 240u8 , 145u8 , 132u8 , 155u8 , 240u8 , 145u8 , 132u8 , 180u8 , 240u8 , 145u8 , 132u8 , 152u8 , 240u8 , 145u8 , 132u8 , 167u8 , 67u8 , 104u8 , 197u8 , 141u8 , 103u8 , 101u8 , 110u8 , 32u8 , 40u8 , 49u8 , 48u8 , 50u8 , 56u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 51u8 , 55u8 , 41u8 , 67u8 , 104u8 , 197u8 , 141u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 57u8 , 57u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 48u8 , 52u8 , 41u8 , 67u8 , 104u8 , 197u8 , 141u8 , 106u8 , 105u8 , 32u8 , 40u8 , 49u8 , 49u8 , 48u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 48u8 , 54u8 , 41u8 , 67u8 , 104u8 , 197u8 , 141u8 , 107u8 , 97u8 , 110u8 , 32u8 , 40u8 , 49u8 , 49u8 , 54u8 , 51u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 54u8 , 53u8 , 41u8 , 67u8 , 104u8 , 197u8 , 141u8 , 107u8 , 121u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 52u8 , 56u8 , 55u8 , 226u8 , 128u8 , 147u8 , 49u8 , 52u8 , 56u8 , 57u8 , 41u8 , 67u8 , 104u8 , 197u8 , 141u8 , 107u8 , 121u8 , 197u8 , 171u8 , 32u8 , 40u8 , 49u8 , 48u8 , 52u8 , 48u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 52u8 , 52u8 , 41u8 , 67u8 , 104u8 , 197u8 , 141u8 , 114u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 52u8 , 53u8 , 55u8 , 226u8 , 128u8 , 147u8 , 49u8 , 52u8 , 54u8 , 48u8 , 41u8 , 67u8 , 104u8 , 197u8 , 141u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 48u8 , 51u8 , 55u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 52u8 , 48u8 , 41u8 , 67u8 , 104u8 , 197u8 , 141u8 , 115u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 49u8 , 51u8 , 50u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 51u8 , 53u8 , 41u8 , 67u8 , 104u8 , 197u8 , 141u8 , 116u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 57u8 , 57u8 , 53u8 , 226u8 , 128u8 , 147u8 , 57u8 , 57u8 , 57u8 , 41u8 , 67u8 , 104u8 , 197u8 , 141u8 , 119u8 , 97u8 , 32u8 , 40u8 , 49u8 , 48u8 , 49u8 , 50u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 49u8 , 55u8 , 41u8 , 68u8 , 97u8 , 105u8 , 100u8 , 197u8 , 141u8 , 32u8 , 40u8 , 56u8 , 48u8 , 54u8 , 226u8 , 128u8 , 147u8 , 56u8 , 49u8 , 48u8 , 41u8 , 68u8 , 97u8 , 105u8 , 106u8 , 105u8 , 32u8 , 40u8 , 49u8 , 49u8 , 50u8 , 54u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 51u8 , 49u8 , 41u8 , 69u8 , 105u8 , 99u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 48u8 , 57u8 , 54u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 57u8 , 55u8 , 41u8 , 69u8 , 105u8 , 101u8 , 110u8 , 32u8 , 40u8 , 57u8 , 56u8 , 55u8 , 226u8 , 128u8 , 147u8 , 57u8 , 56u8 , 57u8 , 41u8 , 69u8 , 105u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 48u8 , 56u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 56u8 , 52u8 , 41u8 , 69u8 , 105u8 , 106u8 , 105u8 , 32u8 , 40u8 , 49u8 , 49u8 , 52u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 52u8 , 50u8 , 41u8 , 69u8 , 105u8 , 107u8 , 97u8 , 110u8 , 32u8 , 40u8 , 57u8 , 56u8 , 51u8 , 226u8 , 128u8 , 147u8 , 57u8 , 56u8 , 53u8 , 41u8 , 69u8 , 105u8 , 107u8 , 121u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 52u8 , 50u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 52u8 , 52u8 , 49u8 , 41u8 , 69u8 , 105u8 , 107u8 , 121u8 , 197u8 , 171u8 , 32u8 , 40u8 , 49u8 , 49u8 , 49u8 , 51u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 49u8 , 56u8 , 41u8 , 69u8 , 105u8 , 109u8 , 97u8 , 110u8 , 32u8 , 40u8 , 49u8 , 49u8 , 54u8 , 53u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 54u8 , 54u8 , 41u8 , 69u8 , 105u8 , 110u8 , 105u8 , 110u8 , 32u8 , 40u8 , 49u8 , 50u8 , 57u8 , 51u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 57u8 , 57u8 , 41u8 , 69u8 , 105u8 , 114u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 53u8 , 53u8 , 56u8 , 226u8 , 128u8 , 147u8 , 49u8 , 53u8 , 55u8 , 48u8 , 41u8 , 69u8 , 105u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 49u8 , 54u8 , 48u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 54u8 , 49u8 , 41u8 , 69u8 , 105u8 , 115u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 48u8 , 52u8 , 54u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 53u8 , 51u8 , 41u8 , 69u8 , 105u8 , 115u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 53u8 , 48u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 53u8 , 50u8 , 49u8 , 41u8 , 69u8 , 105u8 , 115u8 , 111u8 , 32u8 , 40u8 , 57u8 , 56u8 , 57u8 , 226u8 , 128u8 , 147u8 , 57u8 , 57u8 , 48u8 , 41u8 , 69u8 , 110u8 , 99u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 57u8 , 50u8 , 51u8 , 226u8 , 128u8 , 147u8 , 57u8 , 51u8 , 49u8 , 41u8 , 69u8 , 110u8 , 103u8 , 101u8 , 110u8 , 32u8 , 40u8 , 49u8 , 51u8 , 51u8 , 54u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 52u8 , 48u8 , 41u8 , 69u8 , 110u8 , 103u8 , 105u8 , 32u8 , 40u8 , 57u8 , 48u8 , 49u8 , 226u8 , 128u8 , 147u8 , 57u8 , 50u8 , 51u8 , 41u8 , 69u8 , 110u8 , 107u8 , 121u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 51u8 , 48u8 , 56u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 49u8 , 49u8 , 41u8 , 69u8 , 110u8 , 107u8 , 121u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 55u8 , 52u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 55u8 , 52u8 , 56u8 , 41u8 , 69u8 , 110u8 , 107u8 , 121u8 , 197u8 , 171u8 , 32u8 , 40u8 , 49u8 , 48u8 , 54u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 55u8 , 52u8 , 41u8 , 69u8 , 110u8 , 226u8 , 128u8 , 153u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 50u8 , 51u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 52u8 , 48u8 , 41u8 , 69u8 , 110u8 , 112u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 54u8 , 55u8 , 51u8 , 226u8 , 128u8 , 147u8 , 49u8 , 54u8 , 56u8 , 49u8 , 41u8 , 69u8 , 110u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 32u8 , 40u8 , 55u8 , 56u8 , 50u8 , 226u8 , 128u8 , 147u8 , 56u8 , 48u8 , 54u8 , 41u8 , 69u8 , 110u8 , 116u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 52u8 , 56u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 52u8 , 57u8 , 50u8 , 41u8 , 71u8 , 97u8 , 110u8 , 103u8 , 121u8 , 197u8 , 141u8 , 32u8 , 40u8 , 56u8 , 55u8 , 55u8 , 226u8 , 128u8 , 147u8 , 56u8 , 56u8 , 53u8 , 41u8 , 71u8 , 101u8 , 110u8 , 98u8 , 117u8 , 110u8 , 32u8 , 40u8 , 49u8 , 55u8 , 51u8 , 54u8 , 226u8 , 128u8 , 147u8 , 49u8 , 55u8 , 52u8 , 49u8 , 41u8 , 71u8 , 101u8 , 110u8 , 99u8 , 104u8 , 197u8 , 171u8 , 32u8 , 40u8 , 49u8 , 51u8 , 56u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 57u8 , 50u8 , 41u8 , 71u8 , 101u8 , 110u8 , 226u8 , 128u8 , 153u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 49u8 , 49u8 , 56u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 50u8 , 48u8 , 41u8 , 71u8 , 101u8 , 110u8 , 106u8 , 105u8 , 32u8 , 40u8 , 49u8 , 56u8 , 54u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 56u8 , 54u8 , 53u8 , 41u8 , 71u8 , 101u8 , 110u8 , 107u8 , 105u8 , 32u8 , 40u8 , 49u8 , 53u8 , 55u8 , 48u8 , 226u8 , 128u8 , 147u8 , 49u8 , 53u8 , 55u8 , 51u8 , 41u8 , 71u8 , 101u8 , 110u8 , 107u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 51u8 , 50u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 50u8 , 52u8 , 41u8 , 71u8 , 101u8 , 110u8 , 107u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 51u8 , 51u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 51u8 , 52u8 , 41u8 , 71u8 , 101u8 , 110u8 , 107u8 , 121u8 , 197u8 , 171u8 , 32u8 , 40u8 , 49u8 , 50u8 , 48u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 48u8 , 54u8 , 41u8 , 71u8 , 101u8 , 110u8 , 110u8 , 97u8 , 32u8 , 40u8 , 49u8 , 54u8 , 49u8 , 53u8 , 226u8 , 128u8 , 147u8 , 49u8 , 54u8 , 50u8 , 52u8 , 41u8 , 71u8 , 101u8 , 110u8 , 110u8 , 105u8 , 110u8 , 32u8 , 40u8 , 49u8 , 50u8 , 50u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 50u8 , 53u8 , 41u8 , 71u8 , 101u8 , 110u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 51u8 , 49u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 50u8 , 49u8 , 41u8 , 71u8 , 101u8 , 110u8 , 114u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 54u8 , 56u8 , 56u8 , 226u8 , 128u8 , 147u8 , 49u8 , 55u8 , 48u8 , 52u8 , 41u8 , 71u8 , 101u8 , 110u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 49u8 , 56u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 56u8 , 53u8 , 41u8 , 71u8 , 101u8 , 110u8 , 116u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 51u8 , 50u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 51u8 , 49u8 , 41u8 , 72u8 , 97u8 , 107u8 , 117u8 , 99u8 , 104u8 , 105u8 , 32u8 , 40u8 , 54u8 , 53u8 , 48u8 , 226u8 , 128u8 , 147u8 , 54u8 , 55u8 , 49u8 , 41u8 , 72u8 , 97u8 , 107u8 , 117u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 54u8 , 55u8 , 50u8 , 226u8 , 128u8 , 147u8 , 54u8 , 56u8 , 54u8 , 41u8 , 72u8 , 101u8 , 105u8 , 106u8 , 105u8 , 32u8 , 40u8 , 49u8 , 49u8 , 53u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 54u8 , 48u8 , 41u8 , 72u8 , 72u8 , 197u8 , 141u8 , 97u8 , 110u8 , 32u8 , 40u8 , 49u8 , 49u8 , 50u8 , 48u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 50u8 , 52u8 , 41u8 , 72u8 , 197u8 , 141u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 55u8 , 48u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 55u8 , 49u8 , 49u8 , 41u8 , 72u8 , 197u8 , 141u8 , 101u8 , 110u8 , 32u8 , 40u8 , 49u8 , 49u8 , 51u8 , 53u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 52u8 , 49u8 , 41u8 , 72u8 , 197u8 , 141u8 , 103u8 , 101u8 , 110u8 , 32u8 , 40u8 , 49u8 , 49u8 , 53u8 , 54u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 53u8 , 57u8 , 41u8 , 72u8 , 197u8 , 141u8 , 106u8 , 105u8 , 32u8 , 40u8 , 49u8 , 50u8 , 52u8 , 55u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 52u8 , 57u8 , 41u8 , 72u8 , 197u8 , 141u8 , 107u8 , 105u8 , 32u8 , 40u8 , 55u8 , 55u8 , 48u8 , 226u8 , 128u8 , 147u8 , 55u8 , 56u8 , 48u8 , 41u8 , 72u8 , 197u8 , 141u8 , 114u8 , 101u8 , 107u8 , 105u8 , 32u8 , 40u8 , 49u8 , 55u8 , 53u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 55u8 , 54u8 , 52u8 , 41u8 , 72u8 , 197u8 , 141u8 , 116u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 52u8 , 52u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 52u8 , 53u8 , 50u8 , 41u8 , 74u8 , 105u8 , 97u8 , 110u8 , 32u8 , 40u8 , 49u8 , 48u8 , 50u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 50u8 , 52u8 , 41u8 , 74u8 , 105u8 , 110u8 , 103u8 , 111u8 , 45u8 , 107u8 , 101u8 , 105u8 , 117u8 , 110u8 , 32u8 , 40u8 , 55u8 , 54u8 , 55u8 , 226u8 , 128u8 , 147u8 , 55u8 , 55u8 , 48u8 , 41u8 , 74u8 , 105u8 , 110u8 , 107u8 , 105u8 , 32u8 , 40u8 , 55u8 , 50u8 , 52u8 , 226u8 , 128u8 , 147u8 , 55u8 , 50u8 , 57u8 , 41u8 , 74u8 , 105u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 48u8 , 54u8 , 53u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 54u8 , 57u8 , 41u8 , 74u8 , 105u8 , 115u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 49u8 , 55u8 , 55u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 56u8 , 49u8 , 41u8 , 74u8 , 197u8 , 141u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 50u8 , 51u8 , 50u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 51u8 , 51u8 , 41u8 , 74u8 , 197u8 , 141u8 , 103u8 , 97u8 , 110u8 , 32u8 , 40u8 , 56u8 , 53u8 , 57u8 , 226u8 , 128u8 , 147u8 , 56u8 , 55u8 , 55u8 , 41u8 , 74u8 , 197u8 , 141u8 , 103u8 , 101u8 , 110u8 , 32u8 , 40u8 , 49u8 , 50u8 , 48u8 , 55u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 49u8 , 49u8 , 41u8 , 74u8 , 197u8 , 141u8 , 103u8 , 101u8 , 110u8 , 32u8 , 40u8 , 57u8 , 55u8 , 54u8 , 226u8 , 128u8 , 147u8 , 57u8 , 55u8 , 56u8 , 41u8 , 74u8 , 197u8 , 141u8 , 104u8 , 101u8 , 105u8 , 32u8 , 40u8 , 57u8 , 51u8 , 49u8 , 226u8 , 128u8 , 147u8 , 57u8 , 51u8 , 56u8 , 41u8 , 74u8 , 197u8 , 141u8 , 107u8 , 121u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 54u8 , 56u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 54u8 , 56u8 , 56u8 , 41u8 , 74u8 , 197u8 , 141u8 , 107u8 , 121u8 , 197u8 , 171u8 , 32u8 , 40u8 , 49u8 , 50u8 , 49u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 50u8 , 50u8 , 41u8 , 74u8 , 197u8 , 141u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 50u8 , 50u8 , 50u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 50u8 , 52u8 , 41u8 , 74u8 , 197u8 , 141u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 54u8 , 53u8 , 50u8 , 226u8 , 128u8 , 147u8 , 49u8 , 54u8 , 53u8 , 53u8 , 41u8 , 74u8 , 197u8 , 141u8 , 116u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 48u8 , 57u8 , 55u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 57u8 , 57u8 , 41u8 , 74u8 , 197u8 , 141u8 , 119u8 , 97u8 , 32u8 , 40u8 , 56u8 , 51u8 , 52u8 , 226u8 , 128u8 , 147u8 , 56u8 , 52u8 , 56u8 , 41u8 , 74u8 , 117u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 49u8 , 56u8 , 50u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 56u8 , 52u8 , 41u8 , 75u8 , 97u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 56u8 , 52u8 , 56u8 , 226u8 , 128u8 , 147u8 , 49u8 , 56u8 , 53u8 , 52u8 , 41u8 , 75u8 , 97u8 , 103u8 , 101u8 , 110u8 , 32u8 , 40u8 , 49u8 , 51u8 , 48u8 , 51u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 48u8 , 54u8 , 41u8 , 75u8 , 97u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 48u8 , 57u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 57u8 , 54u8 , 41u8 , 75u8 , 97u8 , 106u8 , 197u8 , 141u8 , 32u8 , 40u8 , 56u8 , 52u8 , 56u8 , 226u8 , 128u8 , 147u8 , 56u8 , 53u8 , 49u8 , 41u8 , 75u8 , 97u8 , 107u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 51u8 , 56u8 , 55u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 56u8 , 57u8 , 41u8 , 75u8 , 97u8 , 107u8 , 105u8 , 116u8 , 115u8 , 117u8 , 32u8 , 40u8 , 49u8 , 52u8 , 52u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 52u8 , 52u8 , 52u8 , 41u8 , 75u8 , 97u8 , 110u8 , 98u8 , 117u8 , 110u8 , 32u8 , 40u8 , 49u8 , 54u8 , 54u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 54u8 , 55u8 , 51u8 , 41u8 , 75u8 , 97u8 , 110u8 , 226u8 , 128u8 , 153u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 54u8 , 50u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 54u8 , 52u8 , 52u8 , 41u8 , 75u8 , 97u8 , 110u8 , 226u8 , 128u8 , 153u8 , 101u8 , 110u8 , 32u8 , 40u8 , 49u8 , 55u8 , 52u8 , 56u8 , 226u8 , 128u8 , 147u8 , 49u8 , 55u8 , 53u8 , 49u8 , 41u8 , 75u8 , 97u8 , 110u8 , 103u8 , 101u8 , 110u8 , 32u8 , 40u8 , 49u8 , 50u8 , 52u8 , 51u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 52u8 , 55u8 , 41u8 , 75u8 , 97u8 , 110u8 , 106u8 , 105u8 , 32u8 , 40u8 , 49u8 , 48u8 , 56u8 , 55u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 57u8 , 52u8 , 41u8 , 75u8 , 97u8 , 110u8 , 107u8 , 105u8 , 32u8 , 40u8 , 49u8 , 50u8 , 50u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 51u8 , 50u8 , 41u8 , 75u8 , 97u8 , 110u8 , 107u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 48u8 , 48u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 49u8 , 50u8 , 41u8 , 75u8 , 97u8 , 110u8 , 110u8 , 97u8 , 32u8 , 40u8 , 57u8 , 56u8 , 53u8 , 226u8 , 128u8 , 147u8 , 57u8 , 56u8 , 55u8 , 41u8 , 75u8 , 97u8 , 110u8 , 110u8 , 105u8 , 110u8 , 32u8 , 40u8 , 49u8 , 48u8 , 49u8 , 55u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 50u8 , 49u8 , 41u8 , 75u8 , 97u8 , 110u8 , 112u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 55u8 , 52u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 55u8 , 52u8 , 52u8 , 41u8 , 75u8 , 97u8 , 110u8 , 112u8 , 121u8 , 197u8 , 141u8 , 32u8 , 40u8 , 56u8 , 56u8 , 57u8 , 226u8 , 128u8 , 147u8 , 56u8 , 57u8 , 56u8 , 41u8 , 75u8 , 97u8 , 110u8 , 115u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 55u8 , 56u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 56u8 , 48u8 , 49u8 , 41u8 , 75u8 , 97u8 , 110u8 , 115u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 52u8 , 54u8 , 48u8 , 226u8 , 128u8 , 147u8 , 49u8 , 52u8 , 54u8 , 54u8 , 41u8 , 75u8 , 97u8 , 110u8 , 116u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 48u8 , 52u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 52u8 , 54u8 , 41u8 , 75u8 , 97u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 49u8 , 54u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 55u8 , 49u8 , 41u8 , 75u8 , 97u8 , 114u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 50u8 , 50u8 , 53u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 50u8 , 55u8 , 41u8 , 75u8 , 97u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 51u8 , 50u8 , 54u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 50u8 , 57u8 , 41u8 , 75u8 , 97u8 , 115u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 49u8 , 48u8 , 54u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 48u8 , 56u8 , 41u8 , 75u8 , 97u8 , 116u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 50u8 , 51u8 , 53u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 51u8 , 56u8 , 41u8 , 75u8 , 101u8 , 105u8 , 97u8 , 110u8 , 32u8 , 40u8 , 49u8 , 54u8 , 52u8 , 56u8 , 226u8 , 128u8 , 147u8 , 49u8 , 54u8 , 53u8 , 50u8 , 41u8 , 75u8 , 101u8 , 105u8 , 99u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 53u8 , 57u8 , 54u8 , 226u8 , 128u8 , 147u8 , 49u8 , 54u8 , 49u8 , 53u8 , 41u8 , 75u8 , 101u8 , 105u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 56u8 , 54u8 , 53u8 , 226u8 , 128u8 , 147u8 , 49u8 , 56u8 , 54u8 , 56u8 , 41u8 , 75u8 , 101u8 , 105u8 , 117u8 , 110u8 , 32u8 , 40u8 , 55u8 , 48u8 , 52u8 , 226u8 , 128u8 , 147u8 , 55u8 , 48u8 , 56u8 , 41u8 , 75u8 , 101u8 , 110u8 , 99u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 50u8 , 52u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 53u8 , 54u8 , 41u8 , 75u8 , 101u8 , 110u8 , 226u8 , 128u8 , 153u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 50u8 , 48u8 , 54u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 48u8 , 55u8 , 41u8 , 75u8 , 101u8 , 110u8 , 103u8 , 101u8 , 110u8 , 32u8 , 40u8 , 49u8 , 51u8 , 48u8 , 50u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 48u8 , 51u8 , 41u8 , 75u8 , 101u8 , 110u8 , 106u8 , 105u8 , 32u8 , 40u8 , 49u8 , 50u8 , 55u8 , 53u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 55u8 , 56u8 , 41u8 , 75u8 , 101u8 , 110u8 , 107u8 , 121u8 , 197u8 , 171u8 , 32u8 , 40u8 , 49u8 , 49u8 , 57u8 , 48u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 57u8 , 57u8 , 41u8 , 75u8 , 101u8 , 110u8 , 109u8 , 117u8 , 32u8 , 40u8 , 49u8 , 51u8 , 51u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 51u8 , 54u8 , 41u8 , 75u8 , 101u8 , 110u8 , 110u8 , 105u8 , 110u8 , 32u8 , 40u8 , 49u8 , 50u8 , 48u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 48u8 , 52u8 , 41u8 , 75u8 , 101u8 , 110u8 , 112u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 50u8 , 49u8 , 51u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 49u8 , 57u8 , 41u8 , 75u8 , 101u8 , 110u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 50u8 , 49u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 49u8 , 51u8 , 41u8 , 75u8 , 101u8 , 110u8 , 116u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 51u8 , 55u8 , 48u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 55u8 , 50u8 , 41u8 , 75u8 , 197u8 , 141u8 , 97u8 , 110u8 , 32u8 , 40u8 , 49u8 , 50u8 , 55u8 , 56u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 56u8 , 56u8 , 41u8 , 75u8 , 197u8 , 141u8 , 99u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 50u8 , 54u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 54u8 , 52u8 , 41u8 , 75u8 , 197u8 , 141u8 , 103u8 , 101u8 , 110u8 , 32u8 , 40u8 , 49u8 , 50u8 , 53u8 , 54u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 53u8 , 55u8 , 41u8 , 75u8 , 197u8 , 141u8 , 104u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 48u8 , 53u8 , 56u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 54u8 , 53u8 , 41u8 , 75u8 , 197u8 , 141u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 57u8 , 54u8 , 52u8 , 226u8 , 128u8 , 147u8 , 57u8 , 54u8 , 56u8 , 41u8 , 75u8 , 197u8 , 141u8 , 106u8 , 105u8 , 32u8 , 40u8 , 49u8 , 49u8 , 52u8 , 50u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 52u8 , 52u8 , 41u8 , 75u8 , 197u8 , 141u8 , 106u8 , 105u8 , 32u8 , 40u8 , 49u8 , 53u8 , 53u8 , 53u8 , 226u8 , 128u8 , 147u8 , 49u8 , 53u8 , 53u8 , 56u8 , 41u8 , 75u8 , 197u8 , 141u8 , 107u8 , 97u8 , 32u8 , 40u8 , 49u8 , 56u8 , 52u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 56u8 , 52u8 , 56u8 , 41u8 , 75u8 , 197u8 , 141u8 , 107u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 51u8 , 52u8 , 48u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 52u8 , 54u8 , 41u8 , 75u8 , 197u8 , 141u8 , 110u8 , 105u8 , 110u8 , 32u8 , 40u8 , 56u8 , 49u8 , 48u8 , 226u8 , 128u8 , 147u8 , 56u8 , 50u8 , 52u8 , 41u8 , 75u8 , 197u8 , 141u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 51u8 , 56u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 57u8 , 48u8 , 41u8 , 75u8 , 197u8 , 141u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 51u8 , 55u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 56u8 , 49u8 , 41u8 , 75u8 , 197u8 , 141u8 , 115u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 52u8 , 53u8 , 53u8 , 226u8 , 128u8 , 147u8 , 49u8 , 52u8 , 53u8 , 55u8 , 41u8 , 75u8 , 197u8 , 141u8 , 119u8 , 97u8 , 32u8 , 40u8 , 49u8 , 48u8 , 57u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 48u8 , 52u8 , 41u8 , 75u8 , 197u8 , 141u8 , 119u8 , 97u8 , 32u8 , 40u8 , 49u8 , 51u8 , 56u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 56u8 , 52u8 , 41u8 , 75u8 , 121u8 , 197u8 , 141u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 55u8 , 49u8 , 54u8 , 226u8 , 128u8 , 147u8 , 49u8 , 55u8 , 51u8 , 54u8 , 41u8 , 75u8 , 121u8 , 197u8 , 141u8 , 114u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 53u8 , 50u8 , 56u8 , 226u8 , 128u8 , 147u8 , 49u8 , 53u8 , 51u8 , 50u8 , 41u8 , 75u8 , 121u8 , 197u8 , 141u8 , 116u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 52u8 , 53u8 , 50u8 , 226u8 , 128u8 , 147u8 , 49u8 , 52u8 , 53u8 , 53u8 , 41u8 , 75u8 , 121u8 , 197u8 , 141u8 , 119u8 , 97u8 , 32u8 , 40u8 , 49u8 , 56u8 , 48u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 56u8 , 48u8 , 52u8 , 41u8 , 75u8 , 121u8 , 197u8 , 171u8 , 97u8 , 110u8 , 32u8 , 40u8 , 49u8 , 49u8 , 52u8 , 53u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 53u8 , 49u8 , 41u8 , 75u8 , 121u8 , 197u8 , 171u8 , 106u8 , 117u8 , 32u8 , 40u8 , 49u8 , 49u8 , 53u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 53u8 , 54u8 , 41u8 , 77u8 , 97u8 , 110u8 , 226u8 , 128u8 , 153u8 , 101u8 , 110u8 , 32u8 , 40u8 , 49u8 , 56u8 , 54u8 , 48u8 , 226u8 , 128u8 , 147u8 , 49u8 , 56u8 , 54u8 , 49u8 , 41u8 , 77u8 , 97u8 , 110u8 , 106u8 , 105u8 , 32u8 , 40u8 , 49u8 , 54u8 , 53u8 , 56u8 , 226u8 , 128u8 , 147u8 , 49u8 , 54u8 , 54u8 , 49u8 , 41u8 , 77u8 , 97u8 , 110u8 , 106u8 , 117u8 , 32u8 , 40u8 , 49u8 , 48u8 , 50u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 50u8 , 56u8 , 41u8 , 77u8 , 77u8 , 101u8 , 105u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 52u8 , 57u8 , 50u8 , 226u8 , 128u8 , 147u8 , 49u8 , 53u8 , 48u8 , 49u8 , 41u8 , 77u8 , 101u8 , 105u8 , 114u8 , 101u8 , 107u8 , 105u8 , 32u8 , 40u8 , 49u8 , 54u8 , 53u8 , 53u8 , 226u8 , 128u8 , 147u8 , 49u8 , 54u8 , 53u8 , 56u8 , 41u8 , 77u8 , 101u8 , 105u8 , 116u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 51u8 , 56u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 56u8 , 55u8 , 41u8 , 77u8 , 101u8 , 105u8 , 116u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 51u8 , 57u8 , 48u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 57u8 , 52u8 , 41u8 , 77u8 , 101u8 , 105u8 , 119u8 , 97u8 , 32u8 , 40u8 , 49u8 , 55u8 , 54u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 55u8 , 55u8 , 50u8 , 41u8 , 78u8 , 105u8 , 110u8 , 226u8 , 128u8 , 153u8 , 97u8 , 110u8 , 32u8 , 40u8 , 49u8 , 49u8 , 54u8 , 54u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 54u8 , 57u8 , 41u8 , 78u8 , 105u8 , 110u8 , 106u8 , 105u8 , 32u8 , 40u8 , 49u8 , 50u8 , 52u8 , 48u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 52u8 , 51u8 , 41u8 , 78u8 , 105u8 , 110u8 , 106u8 , 117u8 , 32u8 , 40u8 , 56u8 , 53u8 , 49u8 , 226u8 , 128u8 , 147u8 , 56u8 , 53u8 , 52u8 , 41u8 , 78u8 , 105u8 , 110u8 , 110u8 , 97u8 , 32u8 , 40u8 , 56u8 , 56u8 , 53u8 , 226u8 , 128u8 , 147u8 , 56u8 , 56u8 , 57u8 , 41u8 , 78u8 , 105u8 , 110u8 , 112u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 49u8 , 53u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 53u8 , 52u8 , 41u8 , 197u8 , 140u8 , 99u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 51u8 , 49u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 49u8 , 50u8 , 41u8 , 197u8 , 140u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 51u8 , 57u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 52u8 , 50u8 , 56u8 , 41u8 , 197u8 , 140u8 , 104u8 , 111u8 , 32u8 , 40u8 , 49u8 , 49u8 , 54u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 54u8 , 51u8 , 41u8 , 197u8 , 140u8 , 110u8 , 105u8 , 110u8 , 32u8 , 40u8 , 49u8 , 52u8 , 54u8 , 55u8 , 226u8 , 128u8 , 147u8 , 49u8 , 52u8 , 54u8 , 57u8 , 41u8 , 197u8 , 140u8 , 116u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 48u8 , 56u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 56u8 , 55u8 , 41u8 , 197u8 , 140u8 , 119u8 , 97u8 , 32u8 , 40u8 , 57u8 , 54u8 , 49u8 , 226u8 , 128u8 , 147u8 , 57u8 , 54u8 , 52u8 , 41u8 , 82u8 , 101u8 , 105u8 , 107u8 , 105u8 , 32u8 , 40u8 , 55u8 , 49u8 , 53u8 , 226u8 , 128u8 , 147u8 , 55u8 , 49u8 , 55u8 , 41u8 , 82u8 , 82u8 , 121u8 , 97u8 , 107u8 , 117u8 , 110u8 , 105u8 , 110u8 , 32u8 , 40u8 , 49u8 , 50u8 , 51u8 , 56u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 51u8 , 57u8 , 41u8 , 83u8 , 97u8 , 105u8 , 107u8 , 197u8 , 141u8 , 32u8 , 40u8 , 56u8 , 53u8 , 52u8 , 226u8 , 128u8 , 147u8 , 56u8 , 53u8 , 55u8 , 41u8 , 83u8 , 104u8 , 197u8 , 141u8 , 97u8 , 110u8 , 32u8 , 40u8 , 49u8 , 49u8 , 55u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 55u8 , 53u8 , 41u8 , 83u8 , 104u8 , 197u8 , 141u8 , 97u8 , 110u8 , 32u8 , 40u8 , 49u8 , 50u8 , 57u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 48u8 , 50u8 , 41u8 , 83u8 , 104u8 , 197u8 , 141u8 , 99u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 52u8 , 50u8 , 56u8 , 226u8 , 128u8 , 147u8 , 49u8 , 52u8 , 50u8 , 57u8 , 41u8 , 83u8 , 104u8 , 197u8 , 141u8 , 99u8 , 104u8 , 197u8 , 171u8 , 32u8 , 40u8 , 49u8 , 51u8 , 50u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 50u8 , 54u8 , 41u8 , 83u8 , 104u8 , 197u8 , 141u8 , 103u8 , 101u8 , 110u8 , 32u8 , 40u8 , 49u8 , 50u8 , 53u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 54u8 , 48u8 , 41u8 , 83u8 , 104u8 , 197u8 , 141u8 , 104u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 51u8 , 52u8 , 54u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 55u8 , 48u8 , 41u8 , 83u8 , 104u8 , 197u8 , 141u8 , 104u8 , 111u8 , 32u8 , 40u8 , 49u8 , 48u8 , 55u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 55u8 , 55u8 , 41u8 , 83u8 , 104u8 , 197u8 , 141u8 , 104u8 , 111u8 , 32u8 , 40u8 , 49u8 , 54u8 , 52u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 54u8 , 52u8 , 56u8 , 41u8 , 83u8 , 104u8 , 197u8 , 141u8 , 106u8 , 105u8 , 32u8 , 40u8 , 49u8 , 49u8 , 57u8 , 57u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 48u8 , 49u8 , 41u8 , 83u8 , 104u8 , 197u8 , 141u8 , 107u8 , 97u8 , 32u8 , 40u8 , 49u8 , 50u8 , 53u8 , 55u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 53u8 , 57u8 , 41u8 , 83u8 , 104u8 , 197u8 , 141u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 50u8 , 56u8 , 56u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 57u8 , 51u8 , 41u8 , 83u8 , 104u8 , 197u8 , 141u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 48u8 , 55u8 , 55u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 56u8 , 49u8 , 41u8 , 83u8 , 104u8 , 197u8 , 141u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 32u8 , 40u8 , 57u8 , 57u8 , 48u8 , 226u8 , 128u8 , 147u8 , 57u8 , 57u8 , 53u8 , 41u8 , 83u8 , 104u8 , 197u8 , 141u8 , 116u8 , 97u8 , 105u8 , 32u8 , 40u8 , 56u8 , 57u8 , 56u8 , 226u8 , 128u8 , 147u8 , 57u8 , 48u8 , 49u8 , 41u8 , 83u8 , 104u8 , 197u8 , 141u8 , 116u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 55u8 , 49u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 55u8 , 49u8 , 54u8 , 41u8 , 83u8 , 83u8 , 104u8 , 197u8 , 141u8 , 119u8 , 97u8 , 32u8 , 40u8 , 49u8 , 51u8 , 49u8 , 50u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 49u8 , 55u8 , 41u8 , 83u8 , 104u8 , 117u8 , 99u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 54u8 , 56u8 , 54u8 , 226u8 , 128u8 , 147u8 , 55u8 , 48u8 , 49u8 , 41u8 , 84u8 , 97u8 , 105u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 53u8 , 50u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 53u8 , 50u8 , 56u8 , 41u8 , 84u8 , 97u8 , 105u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 55u8 , 48u8 , 49u8 , 226u8 , 128u8 , 147u8 , 55u8 , 48u8 , 52u8 , 41u8 , 84u8 , 97u8 , 105u8 , 107u8 , 97u8 , 32u8 , 40u8 , 54u8 , 52u8 , 53u8 , 226u8 , 128u8 , 147u8 , 54u8 , 53u8 , 48u8 , 41u8 , 84u8 , 84u8 , 101u8 , 110u8 , 45u8 , 97u8 , 110u8 , 32u8 , 40u8 , 56u8 , 53u8 , 55u8 , 226u8 , 128u8 , 147u8 , 56u8 , 53u8 , 57u8 , 41u8 , 84u8 , 101u8 , 110u8 , 98u8 , 117u8 , 110u8 , 32u8 , 40u8 , 49u8 , 53u8 , 51u8 , 50u8 , 226u8 , 128u8 , 147u8 , 49u8 , 53u8 , 53u8 , 53u8 , 41u8 , 84u8 , 101u8 , 110u8 , 99u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 56u8 , 50u8 , 52u8 , 226u8 , 128u8 , 147u8 , 56u8 , 51u8 , 52u8 , 41u8 , 84u8 , 101u8 , 110u8 , 45u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 49u8 , 49u8 , 48u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 49u8 , 51u8 , 41u8 , 84u8 , 101u8 , 110u8 , 226u8 , 128u8 , 153u8 , 101u8 , 110u8 , 32u8 , 40u8 , 57u8 , 55u8 , 51u8 , 226u8 , 128u8 , 147u8 , 57u8 , 55u8 , 54u8 , 41u8 , 84u8 , 101u8 , 110u8 , 103u8 , 101u8 , 110u8 , 32u8 , 40u8 , 57u8 , 55u8 , 56u8 , 226u8 , 128u8 , 147u8 , 57u8 , 56u8 , 51u8 , 41u8 , 84u8 , 101u8 , 110u8 , 103u8 , 105u8 , 32u8 , 40u8 , 49u8 , 48u8 , 53u8 , 51u8 , 226u8 , 128u8 , 147u8 , 49u8 , 48u8 , 53u8 , 56u8 , 41u8 , 84u8 , 101u8 , 110u8 , 103u8 , 121u8 , 197u8 , 141u8 , 32u8 , 40u8 , 57u8 , 51u8 , 56u8 , 226u8 , 128u8 , 147u8 , 57u8 , 52u8 , 55u8 , 41u8 , 84u8 , 101u8 , 110u8 , 106u8 , 105u8 , 32u8 , 40u8 , 49u8 , 49u8 , 50u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 50u8 , 54u8 , 41u8 , 84u8 , 101u8 , 110u8 , 106u8 , 117u8 , 32u8 , 40u8 , 49u8 , 51u8 , 55u8 , 53u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 55u8 , 57u8 , 41u8 , 84u8 , 101u8 , 110u8 , 109u8 , 101u8 , 105u8 , 32u8 , 40u8 , 49u8 , 55u8 , 56u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 55u8 , 56u8 , 57u8 , 41u8 , 84u8 , 101u8 , 110u8 , 110u8 , 97u8 , 32u8 , 40u8 , 49u8 , 54u8 , 56u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 54u8 , 56u8 , 52u8 , 41u8 , 84u8 , 101u8 , 110u8 , 110u8 , 105u8 , 110u8 , 32u8 , 40u8 , 49u8 , 49u8 , 48u8 , 56u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 49u8 , 48u8 , 41u8 , 84u8 , 101u8 , 110u8 , 45u8 , 197u8 , 141u8 , 32u8 , 40u8 , 55u8 , 56u8 , 49u8 , 226u8 , 128u8 , 147u8 , 55u8 , 56u8 , 50u8 , 41u8 , 84u8 , 101u8 , 110u8 , 112u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 56u8 , 51u8 , 48u8 , 226u8 , 128u8 , 147u8 , 49u8 , 56u8 , 52u8 , 52u8 , 41u8 , 84u8 , 101u8 , 110u8 , 112u8 , 117u8 , 107u8 , 117u8 , 32u8 , 40u8 , 49u8 , 50u8 , 51u8 , 51u8 , 226u8 , 128u8 , 147u8 , 49u8 , 50u8 , 51u8 , 52u8 , 41u8 , 84u8 , 101u8 , 110u8 , 112u8 , 121u8 , 197u8 , 141u8 , 32u8 , 40u8 , 55u8 , 50u8 , 57u8 , 226u8 , 128u8 , 147u8 , 55u8 , 52u8 , 57u8 , 41u8 , 84u8 , 101u8 , 110u8 , 112u8 , 121u8 , 197u8 , 141u8 , 45u8 , 104u8 , 197u8 , 141u8 , 106u8 , 105u8 , 32u8 , 40u8 , 55u8 , 53u8 , 55u8 , 226u8 , 128u8 , 147u8 , 55u8 , 54u8 , 53u8 , 41u8 , 84u8 , 101u8 , 110u8 , 112u8 , 121u8 , 197u8 , 141u8 , 45u8 , 106u8 , 105u8 , 110u8 , 103u8 , 111u8 , 32u8 , 40u8 , 55u8 , 54u8 , 53u8 , 226u8 , 128u8 , 147u8 , 55u8 , 54u8 , 55u8 , 41u8 , 84u8 , 101u8 , 110u8 , 112u8 , 121u8 , 197u8 , 141u8 , 45u8 , 107u8 , 97u8 , 109u8 , 112u8 , 197u8 , 141u8 , 32u8 , 40u8 , 55u8 , 52u8 , 57u8 , 226u8 , 128u8 , 147u8 , 55u8 , 52u8 , 57u8 , 41u8 , 84u8 , 101u8 , 110u8 , 112u8 , 121u8 , 197u8 , 141u8 , 45u8 , 115u8 , 104u8 , 197u8 , 141u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 55u8 , 52u8 , 57u8 , 226u8 , 128u8 , 147u8 , 55u8 , 53u8 , 55u8 , 41u8 , 84u8 , 101u8 , 110u8 , 114u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 57u8 , 55u8 , 48u8 , 226u8 , 128u8 , 147u8 , 57u8 , 55u8 , 51u8 , 41u8 , 84u8 , 101u8 , 110u8 , 114u8 , 121u8 , 97u8 , 107u8 , 117u8 , 32u8 , 40u8 , 57u8 , 52u8 , 55u8 , 226u8 , 128u8 , 147u8 , 57u8 , 53u8 , 55u8 , 41u8 , 84u8 , 101u8 , 110u8 , 115u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 49u8 , 51u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 51u8 , 50u8 , 41u8 , 84u8 , 101u8 , 110u8 , 115u8 , 104u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 53u8 , 55u8 , 51u8 , 226u8 , 128u8 , 147u8 , 49u8 , 53u8 , 57u8 , 50u8 , 41u8 , 84u8 , 101u8 , 110u8 , 116u8 , 111u8 , 107u8 , 117u8 , 32u8 , 40u8 , 57u8 , 53u8 , 55u8 , 226u8 , 128u8 , 147u8 , 57u8 , 54u8 , 49u8 , 41u8 , 84u8 , 101u8 , 110u8 , 226u8 , 128u8 , 153u8 , 121u8 , 197u8 , 141u8 , 32u8 , 40u8 , 49u8 , 49u8 , 52u8 , 52u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 52u8 , 53u8 , 41u8 , 84u8 , 111u8 , 107u8 , 117u8 , 106u8 , 105u8 , 32u8 , 40u8 , 49u8 , 51u8 , 48u8 , 54u8 , 226u8 , 128u8 , 147u8 , 49u8 , 51u8 , 48u8 , 56u8 , 41u8 , 87u8 , 97u8 , 100u8 , 197u8 , 141u8 , 32u8 , 40u8 , 55u8 , 48u8 , 56u8 , 226u8 , 128u8 , 147u8 , 55u8 , 49u8 , 53u8 , 41u8 , 89u8 , 197u8 , 141u8 , 114u8 , 197u8 , 141u8 , 32u8 , 40u8 , 55u8 , 49u8 , 55u8 , 226u8 , 128u8 , 147u8 , 55u8 , 50u8 , 52u8 , 41u8 , 89u8 , 197u8 , 141u8 , 119u8 , 97u8 , 32u8 , 40u8 , 49u8 , 49u8 , 56u8 , 49u8 , 226u8 , 128u8 , 147u8 , 49u8 , 49u8 , 56u8 , 50u8 , 41u8]) }) } , } , cyclic_years : :: icu_datetime :: provider :: calendar :: CyclicYears { names : unsafe { :: zerovec :: VarZeroVec :: from_bytes_unchecked (& []) } , abbr : unsafe { :: zerovec :: VarZeroVec :: from_bytes_unchecked (& []) } , narrow : unsafe { :: zerovec :: VarZeroVec :: from_bytes_unchecked (& []) } , } , } ;
 static EN: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
     months: ::icu_datetime::provider::calendar::months::ContextsV1 {
         format: ::icu_datetime::provider::calendar::months::FormatWidthsV1 {
@@ -6665,6 +4429,30 @@ static EN: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
         },
         stand_alone: None,
     },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Q1"),
+                alloc::borrow::Cow::Borrowed("Q2"),
+                alloc::borrow::Cow::Borrowed("Q3"),
+                alloc::borrow::Cow::Borrowed("Q4"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1"),
+                alloc::borrow::Cow::Borrowed("2"),
+                alloc::borrow::Cow::Borrowed("3"),
+                alloc::borrow::Cow::Borrowed("4"),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1st quarter"),
+                alloc::borrow::Cow::Borrowed("2nd quarter"),
+                alloc::borrow::Cow::Borrowed("3rd quarter"),
+                alloc::borrow::Cow::Borrowed("4th quarter"),
+            ]),
+        },
+        stand_alone: None,
+    },
     weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
         format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
@@ -8850,6 +6638,11 @@ static EN: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
             )
         },
     },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
 };
 static EN_001_EN_ZA: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
     months: ::icu_datetime::provider::calendar::months::ContextsV1 {
@@ -8900,6 +6693,30 @@ static EN_001_EN_ZA: &DataStruct = &::icu_datetime::provider::calendar::DateSymb
         },
         stand_alone: None,
     },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Q1"),
+                alloc::borrow::Cow::Borrowed("Q2"),
+                alloc::borrow::Cow::Borrowed("Q3"),
+                alloc::borrow::Cow::Borrowed("Q4"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1"),
+                alloc::borrow::Cow::Borrowed("2"),
+                alloc::borrow::Cow::Borrowed("3"),
+                alloc::borrow::Cow::Borrowed("4"),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1st quarter"),
+                alloc::borrow::Cow::Borrowed("2nd quarter"),
+                alloc::borrow::Cow::Borrowed("3rd quarter"),
+                alloc::borrow::Cow::Borrowed("4th quarter"),
+            ]),
+        },
+        stand_alone: None,
+    },
     weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
         format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
@@ -11085,6 +8902,11 @@ static EN_001_EN_ZA: &DataStruct = &::icu_datetime::provider::calendar::DateSymb
             )
         },
     },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
 };
 static ES: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
     months: ::icu_datetime::provider::calendar::months::ContextsV1 {
@@ -11135,6 +8957,30 @@ static ES: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
         },
         stand_alone: None,
     },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("T1"),
+                alloc::borrow::Cow::Borrowed("T2"),
+                alloc::borrow::Cow::Borrowed("T3"),
+                alloc::borrow::Cow::Borrowed("T4"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1"),
+                alloc::borrow::Cow::Borrowed("2"),
+                alloc::borrow::Cow::Borrowed("3"),
+                alloc::borrow::Cow::Borrowed("4"),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1.er trimestre"),
+                alloc::borrow::Cow::Borrowed("2.º trimestre"),
+                alloc::borrow::Cow::Borrowed("3.er trimestre"),
+                alloc::borrow::Cow::Borrowed("4.º trimestre"),
+            ]),
+        },
+        stand_alone: None,
+    },
     weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
         format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
@@ -13322,6 +11168,11 @@ static ES: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
             )
         },
     },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
 };
 static ES_AR: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
     months: ::icu_datetime::provider::calendar::months::ContextsV1 {
@@ -13372,6 +11223,30 @@ static ES_AR: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
         },
         stand_alone: None,
     },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("T1"),
+                alloc::borrow::Cow::Borrowed("T2"),
+                alloc::borrow::Cow::Borrowed("T3"),
+                alloc::borrow::Cow::Borrowed("T4"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1"),
+                alloc::borrow::Cow::Borrowed("2"),
+                alloc::borrow::Cow::Borrowed("3"),
+                alloc::borrow::Cow::Borrowed("4"),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1.er trimestre"),
+                alloc::borrow::Cow::Borrowed("2.º trimestre"),
+                alloc::borrow::Cow::Borrowed("3.er trimestre"),
+                alloc::borrow::Cow::Borrowed("4.º trimestre"),
+            ]),
+        },
+        stand_alone: None,
+    },
     weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
         format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
@@ -15559,6 +13434,11 @@ static ES_AR: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
             )
         },
     },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
 };
 static FIL: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
     months: ::icu_datetime::provider::calendar::months::ContextsV1 {
@@ -15631,6 +13511,30 @@ static FIL: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
             },
         ),
     },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Q1"),
+                alloc::borrow::Cow::Borrowed("Q2"),
+                alloc::borrow::Cow::Borrowed("Q3"),
+                alloc::borrow::Cow::Borrowed("Q4"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1"),
+                alloc::borrow::Cow::Borrowed("2"),
+                alloc::borrow::Cow::Borrowed("3"),
+                alloc::borrow::Cow::Borrowed("4"),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("ika-1 quarter"),
+                alloc::borrow::Cow::Borrowed("ika-2 quarter"),
+                alloc::borrow::Cow::Borrowed("ika-3 quarter"),
+                alloc::borrow::Cow::Borrowed("ika-4 na quarter"),
+            ]),
+        },
+        stand_alone: None,
+    },
     weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
         format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
@@ -17816,6 +15720,11 @@ static FIL: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
             )
         },
     },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
 };
 static FR: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
     months: ::icu_datetime::provider::calendar::months::ContextsV1 {
@@ -17866,6 +15775,30 @@ static FR: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
         },
         stand_alone: None,
     },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("T1"),
+                alloc::borrow::Cow::Borrowed("T2"),
+                alloc::borrow::Cow::Borrowed("T3"),
+                alloc::borrow::Cow::Borrowed("T4"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1"),
+                alloc::borrow::Cow::Borrowed("2"),
+                alloc::borrow::Cow::Borrowed("3"),
+                alloc::borrow::Cow::Borrowed("4"),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1er trimestre"),
+                alloc::borrow::Cow::Borrowed("2e trimestre"),
+                alloc::borrow::Cow::Borrowed("3e trimestre"),
+                alloc::borrow::Cow::Borrowed("4e trimestre"),
+            ]),
+        },
+        stand_alone: None,
+    },
     weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
         format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
@@ -20055,6 +17988,11 @@ static FR: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
             )
         },
     },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
 };
 static JA: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
     months: ::icu_datetime::provider::calendar::months::ContextsV1 {
@@ -20105,6 +18043,30 @@ static JA: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
         },
         stand_alone: None,
     },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Q1"),
+                alloc::borrow::Cow::Borrowed("Q2"),
+                alloc::borrow::Cow::Borrowed("Q3"),
+                alloc::borrow::Cow::Borrowed("Q4"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1"),
+                alloc::borrow::Cow::Borrowed("2"),
+                alloc::borrow::Cow::Borrowed("3"),
+                alloc::borrow::Cow::Borrowed("4"),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("第1四半期"),
+                alloc::borrow::Cow::Borrowed("第2四半期"),
+                alloc::borrow::Cow::Borrowed("第3四半期"),
+                alloc::borrow::Cow::Borrowed("第4四半期"),
+            ]),
+        },
+        stand_alone: None,
+    },
     weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
         format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
@@ -21500,6 +19462,11 @@ static JA: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
             )
         },
     },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
 };
 static RU: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
     months: ::icu_datetime::provider::calendar::months::ContextsV1 {
@@ -21587,6 +19554,30 @@ static RU: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
             },
         ),
     },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1-й кв."),
+                alloc::borrow::Cow::Borrowed("2-й кв."),
+                alloc::borrow::Cow::Borrowed("3-й кв."),
+                alloc::borrow::Cow::Borrowed("4-й кв."),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1"),
+                alloc::borrow::Cow::Borrowed("2"),
+                alloc::borrow::Cow::Borrowed("3"),
+                alloc::borrow::Cow::Borrowed("4"),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1-й квартал"),
+                alloc::borrow::Cow::Borrowed("2-й квартал"),
+                alloc::borrow::Cow::Borrowed("3-й квартал"),
+                alloc::borrow::Cow::Borrowed("4-й квартал"),
+            ]),
+        },
+        stand_alone: None,
+    },
     weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
         format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
@@ -24449,6 +22440,11 @@ static RU: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
             )
         },
     },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
 };
 static SR_LATN: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
     months: ::icu_datetime::provider::calendar::months::ContextsV1 {
@@ -24499,6 +22495,30 @@ static SR_LATN: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1
         },
         stand_alone: None,
     },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1. kv."),
+                alloc::borrow::Cow::Borrowed("2. kv."),
+                alloc::borrow::Cow::Borrowed("3. kv."),
+                alloc::borrow::Cow::Borrowed("4. kv."),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1."),
+                alloc::borrow::Cow::Borrowed("2."),
+                alloc::borrow::Cow::Borrowed("3."),
+                alloc::borrow::Cow::Borrowed("4."),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("prvi kvartal"),
+                alloc::borrow::Cow::Borrowed("drugi kvartal"),
+                alloc::borrow::Cow::Borrowed("treći kvartal"),
+                alloc::borrow::Cow::Borrowed("četvrti kvartal"),
+            ]),
+        },
+        stand_alone: None,
+    },
     weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
         format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
@@ -26644,6 +24664,11 @@ static SR_LATN: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1
             )
         },
     },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
 };
 static SR_SR_CYRL: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
     months: ::icu_datetime::provider::calendar::months::ContextsV1 {
@@ -26694,6 +24719,30 @@ static SR_SR_CYRL: &DataStruct = &::icu_datetime::provider::calendar::DateSymbol
         },
         stand_alone: None,
     },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1. кв."),
+                alloc::borrow::Cow::Borrowed("2. кв."),
+                alloc::borrow::Cow::Borrowed("3. кв."),
+                alloc::borrow::Cow::Borrowed("4. кв."),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1."),
+                alloc::borrow::Cow::Borrowed("2."),
+                alloc::borrow::Cow::Borrowed("3."),
+                alloc::borrow::Cow::Borrowed("4."),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("први квартал"),
+                alloc::borrow::Cow::Borrowed("други квартал"),
+                alloc::borrow::Cow::Borrowed("трећи квартал"),
+                alloc::borrow::Cow::Borrowed("четврти квартал"),
+            ]),
+        },
+        stand_alone: None,
+    },
     weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
         format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
@@ -29064,6 +27113,11 @@ static SR_SR_CYRL: &DataStruct = &::icu_datetime::provider::calendar::DateSymbol
             )
         },
     },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
 };
 static TH: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
     months: ::icu_datetime::provider::calendar::months::ContextsV1 {
@@ -29114,6 +27168,30 @@ static TH: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
         },
         stand_alone: None,
     },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("ไตรมาส 1"),
+                alloc::borrow::Cow::Borrowed("ไตรมาส 2"),
+                alloc::borrow::Cow::Borrowed("ไตรมาส 3"),
+                alloc::borrow::Cow::Borrowed("ไตรมาส 4"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1"),
+                alloc::borrow::Cow::Borrowed("2"),
+                alloc::borrow::Cow::Borrowed("3"),
+                alloc::borrow::Cow::Borrowed("4"),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("ไตรมาส 1"),
+                alloc::borrow::Cow::Borrowed("ไตรมาส 2"),
+                alloc::borrow::Cow::Borrowed("ไตรมาส 3"),
+                alloc::borrow::Cow::Borrowed("ไตรมาส 4"),
+            ]),
+        },
+        stand_alone: None,
+    },
     weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
         format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
@@ -31864,6 +29942,11 @@ static TH: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
             )
         },
     },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
 };
 static TR: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
     months: ::icu_datetime::provider::calendar::months::ContextsV1 {
@@ -31914,6 +29997,30 @@ static TR: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
         },
         stand_alone: None,
     },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Ç1"),
+                alloc::borrow::Cow::Borrowed("Ç2"),
+                alloc::borrow::Cow::Borrowed("Ç3"),
+                alloc::borrow::Cow::Borrowed("Ç4"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1."),
+                alloc::borrow::Cow::Borrowed("2."),
+                alloc::borrow::Cow::Borrowed("3."),
+                alloc::borrow::Cow::Borrowed("4."),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1. çeyrek"),
+                alloc::borrow::Cow::Borrowed("2. çeyrek"),
+                alloc::borrow::Cow::Borrowed("3. çeyrek"),
+                alloc::borrow::Cow::Borrowed("4. çeyrek"),
+            ]),
+        },
+        stand_alone: None,
+    },
     weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
         format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
@@ -34100,6 +32207,11 @@ static TR: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
             )
         },
     },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
 };
 static UND: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
     months: ::icu_datetime::provider::calendar::months::ContextsV1 {
@@ -34150,6 +32262,30 @@ static UND: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
         },
         stand_alone: None,
     },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Q1"),
+                alloc::borrow::Cow::Borrowed("Q2"),
+                alloc::borrow::Cow::Borrowed("Q3"),
+                alloc::borrow::Cow::Borrowed("Q4"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1"),
+                alloc::borrow::Cow::Borrowed("2"),
+                alloc::borrow::Cow::Borrowed("3"),
+                alloc::borrow::Cow::Borrowed("4"),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Q1"),
+                alloc::borrow::Cow::Borrowed("Q2"),
+                alloc::borrow::Cow::Borrowed("Q3"),
+                alloc::borrow::Cow::Borrowed("Q4"),
+            ]),
+        },
+        stand_alone: None,
+    },
     weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
         format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
             abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
@@ -36333,4 +34469,9 @@ static UND: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
             )
         },
     },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
 };
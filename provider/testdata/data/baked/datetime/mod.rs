@@ -1,12 +1,21 @@
 // @generated
+pub mod append_items_v1_u_ca;
 pub mod buddhist;
+pub mod chinese;
 pub mod coptic;
+pub mod dangi;
 pub mod ethiopic;
 pub mod gregory;
+pub mod hebrew;
 pub mod indian;
+pub mod islamic;
 pub mod japanese;
 pub mod japanext;
+pub mod persian;
+pub mod roc;
 pub mod skeletons_v1_u_ca;
+pub mod time_data_v1_r;
 pub mod timelengths_v1;
 pub mod timesymbols_v1;
 pub mod week_data_v1_r;
+pub mod weekend_v1_r;
@@ -0,0 +1,338 @@
+// @generated
+type DataStruct = < :: icu_datetime :: provider :: calendar :: PersianDateSymbolsV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[("en", EN), ("und", UND)]);
+static EN: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
+    months: ::icu_datetime::provider::calendar::months::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::months::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::months::SymbolsV1::SolarTwelve([
+                alloc::borrow::Cow::Borrowed("Farvardin"),
+                alloc::borrow::Cow::Borrowed("Ordibehesht"),
+                alloc::borrow::Cow::Borrowed("Khordad"),
+                alloc::borrow::Cow::Borrowed("Tir"),
+                alloc::borrow::Cow::Borrowed("Mordad"),
+                alloc::borrow::Cow::Borrowed("Shahrivar"),
+                alloc::borrow::Cow::Borrowed("Mehr"),
+                alloc::borrow::Cow::Borrowed("Aban"),
+                alloc::borrow::Cow::Borrowed("Azar"),
+                alloc::borrow::Cow::Borrowed("Dey"),
+                alloc::borrow::Cow::Borrowed("Bahman"),
+                alloc::borrow::Cow::Borrowed("Esfand"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::months::SymbolsV1::SolarTwelve([
+                alloc::borrow::Cow::Borrowed("1"),
+                alloc::borrow::Cow::Borrowed("2"),
+                alloc::borrow::Cow::Borrowed("3"),
+                alloc::borrow::Cow::Borrowed("4"),
+                alloc::borrow::Cow::Borrowed("5"),
+                alloc::borrow::Cow::Borrowed("6"),
+                alloc::borrow::Cow::Borrowed("7"),
+                alloc::borrow::Cow::Borrowed("8"),
+                alloc::borrow::Cow::Borrowed("9"),
+                alloc::borrow::Cow::Borrowed("10"),
+                alloc::borrow::Cow::Borrowed("11"),
+                alloc::borrow::Cow::Borrowed("12"),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::months::SymbolsV1::SolarTwelve([
+                alloc::borrow::Cow::Borrowed("Farvardin"),
+                alloc::borrow::Cow::Borrowed("Ordibehesht"),
+                alloc::borrow::Cow::Borrowed("Khordad"),
+                alloc::borrow::Cow::Borrowed("Tir"),
+                alloc::borrow::Cow::Borrowed("Mordad"),
+                alloc::borrow::Cow::Borrowed("Shahrivar"),
+                alloc::borrow::Cow::Borrowed("Mehr"),
+                alloc::borrow::Cow::Borrowed("Aban"),
+                alloc::borrow::Cow::Borrowed("Azar"),
+                alloc::borrow::Cow::Borrowed("Dey"),
+                alloc::borrow::Cow::Borrowed("Bahman"),
+                alloc::borrow::Cow::Borrowed("Esfand"),
+            ]),
+        },
+        stand_alone: None,
+    },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Q1"),
+                alloc::borrow::Cow::Borrowed("Q2"),
+                alloc::borrow::Cow::Borrowed("Q3"),
+                alloc::borrow::Cow::Borrowed("Q4"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1"),
+                alloc::borrow::Cow::Borrowed("2"),
+                alloc::borrow::Cow::Borrowed("3"),
+                alloc::borrow::Cow::Borrowed("4"),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1st quarter"),
+                alloc::borrow::Cow::Borrowed("2nd quarter"),
+                alloc::borrow::Cow::Borrowed("3rd quarter"),
+                alloc::borrow::Cow::Borrowed("4th quarter"),
+            ]),
+        },
+        stand_alone: None,
+    },
+    weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Sun"),
+                alloc::borrow::Cow::Borrowed("Mon"),
+                alloc::borrow::Cow::Borrowed("Tue"),
+                alloc::borrow::Cow::Borrowed("Wed"),
+                alloc::borrow::Cow::Borrowed("Thu"),
+                alloc::borrow::Cow::Borrowed("Fri"),
+                alloc::borrow::Cow::Borrowed("Sat"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("S"),
+                alloc::borrow::Cow::Borrowed("M"),
+                alloc::borrow::Cow::Borrowed("T"),
+                alloc::borrow::Cow::Borrowed("W"),
+                alloc::borrow::Cow::Borrowed("T"),
+                alloc::borrow::Cow::Borrowed("F"),
+                alloc::borrow::Cow::Borrowed("S"),
+            ]),
+            short: Some(::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Su"),
+                alloc::borrow::Cow::Borrowed("Mo"),
+                alloc::borrow::Cow::Borrowed("Tu"),
+                alloc::borrow::Cow::Borrowed("We"),
+                alloc::borrow::Cow::Borrowed("Th"),
+                alloc::borrow::Cow::Borrowed("Fr"),
+                alloc::borrow::Cow::Borrowed("Sa"),
+            ])),
+            wide: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Sunday"),
+                alloc::borrow::Cow::Borrowed("Monday"),
+                alloc::borrow::Cow::Borrowed("Tuesday"),
+                alloc::borrow::Cow::Borrowed("Wednesday"),
+                alloc::borrow::Cow::Borrowed("Thursday"),
+                alloc::borrow::Cow::Borrowed("Friday"),
+                alloc::borrow::Cow::Borrowed("Saturday"),
+            ]),
+        },
+        stand_alone: None,
+    },
+    eras: ::icu_datetime::provider::calendar::Eras {
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 112u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 80u8,
+                    ])
+                },
+            )
+        },
+        abbr: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 112u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 80u8,
+                    ])
+                },
+            )
+        },
+        narrow: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 112u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 80u8,
+                    ])
+                },
+            )
+        },
+    },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
+};
+static UND: &DataStruct = &::icu_datetime::provider::calendar::DateSymbolsV1 {
+    months: ::icu_datetime::provider::calendar::months::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::months::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::months::SymbolsV1::SolarTwelve([
+                alloc::borrow::Cow::Borrowed("Farvardin"),
+                alloc::borrow::Cow::Borrowed("Ordibehesht"),
+                alloc::borrow::Cow::Borrowed("Khordad"),
+                alloc::borrow::Cow::Borrowed("Tir"),
+                alloc::borrow::Cow::Borrowed("Mordad"),
+                alloc::borrow::Cow::Borrowed("Shahrivar"),
+                alloc::borrow::Cow::Borrowed("Mehr"),
+                alloc::borrow::Cow::Borrowed("Aban"),
+                alloc::borrow::Cow::Borrowed("Azar"),
+                alloc::borrow::Cow::Borrowed("Dey"),
+                alloc::borrow::Cow::Borrowed("Bahman"),
+                alloc::borrow::Cow::Borrowed("Esfand"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::months::SymbolsV1::SolarTwelve([
+                alloc::borrow::Cow::Borrowed("1"),
+                alloc::borrow::Cow::Borrowed("2"),
+                alloc::borrow::Cow::Borrowed("3"),
+                alloc::borrow::Cow::Borrowed("4"),
+                alloc::borrow::Cow::Borrowed("5"),
+                alloc::borrow::Cow::Borrowed("6"),
+                alloc::borrow::Cow::Borrowed("7"),
+                alloc::borrow::Cow::Borrowed("8"),
+                alloc::borrow::Cow::Borrowed("9"),
+                alloc::borrow::Cow::Borrowed("10"),
+                alloc::borrow::Cow::Borrowed("11"),
+                alloc::borrow::Cow::Borrowed("12"),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::months::SymbolsV1::SolarTwelve([
+                alloc::borrow::Cow::Borrowed("Farvardin"),
+                alloc::borrow::Cow::Borrowed("Ordibehesht"),
+                alloc::borrow::Cow::Borrowed("Khordad"),
+                alloc::borrow::Cow::Borrowed("Tir"),
+                alloc::borrow::Cow::Borrowed("Mordad"),
+                alloc::borrow::Cow::Borrowed("Shahrivar"),
+                alloc::borrow::Cow::Borrowed("Mehr"),
+                alloc::borrow::Cow::Borrowed("Aban"),
+                alloc::borrow::Cow::Borrowed("Azar"),
+                alloc::borrow::Cow::Borrowed("Dey"),
+                alloc::borrow::Cow::Borrowed("Bahman"),
+                alloc::borrow::Cow::Borrowed("Esfand"),
+            ]),
+        },
+        stand_alone: None,
+    },
+    quarters: ::icu_datetime::provider::calendar::quarters::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::quarters::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Q1"),
+                alloc::borrow::Cow::Borrowed("Q2"),
+                alloc::borrow::Cow::Borrowed("Q3"),
+                alloc::borrow::Cow::Borrowed("Q4"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("1"),
+                alloc::borrow::Cow::Borrowed("2"),
+                alloc::borrow::Cow::Borrowed("3"),
+                alloc::borrow::Cow::Borrowed("4"),
+            ]),
+            short: None,
+            wide: ::icu_datetime::provider::calendar::quarters::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Q1"),
+                alloc::borrow::Cow::Borrowed("Q2"),
+                alloc::borrow::Cow::Borrowed("Q3"),
+                alloc::borrow::Cow::Borrowed("Q4"),
+            ]),
+        },
+        stand_alone: None,
+    },
+    weekdays: ::icu_datetime::provider::calendar::weekdays::ContextsV1 {
+        format: ::icu_datetime::provider::calendar::weekdays::FormatWidthsV1 {
+            abbreviated: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Sun"),
+                alloc::borrow::Cow::Borrowed("Mon"),
+                alloc::borrow::Cow::Borrowed("Tue"),
+                alloc::borrow::Cow::Borrowed("Wed"),
+                alloc::borrow::Cow::Borrowed("Thu"),
+                alloc::borrow::Cow::Borrowed("Fri"),
+                alloc::borrow::Cow::Borrowed("Sat"),
+            ]),
+            narrow: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("S"),
+                alloc::borrow::Cow::Borrowed("M"),
+                alloc::borrow::Cow::Borrowed("T"),
+                alloc::borrow::Cow::Borrowed("W"),
+                alloc::borrow::Cow::Borrowed("T"),
+                alloc::borrow::Cow::Borrowed("F"),
+                alloc::borrow::Cow::Borrowed("S"),
+            ]),
+            short: Some(::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Sun"),
+                alloc::borrow::Cow::Borrowed("Mon"),
+                alloc::borrow::Cow::Borrowed("Tue"),
+                alloc::borrow::Cow::Borrowed("Wed"),
+                alloc::borrow::Cow::Borrowed("Thu"),
+                alloc::borrow::Cow::Borrowed("Fri"),
+                alloc::borrow::Cow::Borrowed("Sat"),
+            ])),
+            wide: ::icu_datetime::provider::calendar::weekdays::SymbolsV1([
+                alloc::borrow::Cow::Borrowed("Sun"),
+                alloc::borrow::Cow::Borrowed("Mon"),
+                alloc::borrow::Cow::Borrowed("Tue"),
+                alloc::borrow::Cow::Borrowed("Wed"),
+                alloc::borrow::Cow::Borrowed("Thu"),
+                alloc::borrow::Cow::Borrowed("Fri"),
+                alloc::borrow::Cow::Borrowed("Sat"),
+            ]),
+        },
+        stand_alone: None,
+    },
+    eras: ::icu_datetime::provider::calendar::Eras {
+        names: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 112u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 80u8,
+                    ])
+                },
+            )
+        },
+        abbr: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 112u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 80u8,
+                    ])
+                },
+            )
+        },
+        narrow: unsafe {
+            #[allow(unused_unsafe)]
+            ::zerovec::ZeroMap::from_parts_unchecked(
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 97u8, 112u8,
+                    ])
+                },
+                unsafe {
+                    ::zerovec::VarZeroVec::from_bytes_unchecked(&[
+                        1u8, 0u8, 0u8, 0u8, 0u8, 0u8, 65u8, 80u8,
+                    ])
+                },
+            )
+        },
+    },
+    cyclic_years: ::icu_datetime::provider::calendar::CyclicYears {
+        names: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        abbr: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+        narrow: unsafe { ::zerovec::VarZeroVec::from_bytes_unchecked(&[]) },
+    },
+};
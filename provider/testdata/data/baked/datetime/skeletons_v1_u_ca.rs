@@ -103,28 +103,52 @@ pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
         ("en-ZA-u-ca-japanext", EN_ZA_U_CA_BUDDHIST_EN_ZA_U_CA_COPTIC),
         (
             "en-u-ca-buddhist",
-            EN_U_CA_BUDDHIST_EN_U_CA_COPTIC_EN_U_CA_ETHIOPIC,
+            EN_U_CA_BUDDHIST_EN_U_CA_CHINESE_EN_U_CA_COPTIC,
+        ),
+        (
+            "en-u-ca-chinese",
+            EN_U_CA_BUDDHIST_EN_U_CA_CHINESE_EN_U_CA_COPTIC,
         ),
         (
             "en-u-ca-coptic",
-            EN_U_CA_BUDDHIST_EN_U_CA_COPTIC_EN_U_CA_ETHIOPIC,
+            EN_U_CA_BUDDHIST_EN_U_CA_CHINESE_EN_U_CA_COPTIC,
+        ),
+        (
+            "en-u-ca-dangi",
+            EN_U_CA_BUDDHIST_EN_U_CA_CHINESE_EN_U_CA_COPTIC,
         ),
         (
             "en-u-ca-ethiopic",
-            EN_U_CA_BUDDHIST_EN_U_CA_COPTIC_EN_U_CA_ETHIOPIC,
+            EN_U_CA_BUDDHIST_EN_U_CA_CHINESE_EN_U_CA_COPTIC,
         ),
         ("en-u-ca-gregory", EN_U_CA_GREGORY),
+        (
+            "en-u-ca-hebrew",
+            EN_U_CA_BUDDHIST_EN_U_CA_CHINESE_EN_U_CA_COPTIC,
+        ),
         (
             "en-u-ca-indian",
-            EN_U_CA_BUDDHIST_EN_U_CA_COPTIC_EN_U_CA_ETHIOPIC,
+            EN_U_CA_BUDDHIST_EN_U_CA_CHINESE_EN_U_CA_COPTIC,
+        ),
+        (
+            "en-u-ca-islamic",
+            EN_U_CA_BUDDHIST_EN_U_CA_CHINESE_EN_U_CA_COPTIC,
         ),
         (
             "en-u-ca-japanese",
-            EN_U_CA_BUDDHIST_EN_U_CA_COPTIC_EN_U_CA_ETHIOPIC,
+            EN_U_CA_BUDDHIST_EN_U_CA_CHINESE_EN_U_CA_COPTIC,
         ),
         (
             "en-u-ca-japanext",
-            EN_U_CA_BUDDHIST_EN_U_CA_COPTIC_EN_U_CA_ETHIOPIC,
+            EN_U_CA_BUDDHIST_EN_U_CA_CHINESE_EN_U_CA_COPTIC,
+        ),
+        (
+            "en-u-ca-persian",
+            EN_U_CA_BUDDHIST_EN_U_CA_CHINESE_EN_U_CA_COPTIC,
+        ),
+        (
+            "en-u-ca-roc",
+            EN_U_CA_BUDDHIST_EN_U_CA_CHINESE_EN_U_CA_COPTIC,
         ),
         ("es-AR-u-ca-buddhist", ES_AR_U_CA_BUDDHIST_ES_AR_U_CA_COPTIC),
         ("es-AR-u-ca-coptic", ES_AR_U_CA_BUDDHIST_ES_AR_U_CA_COPTIC),
@@ -352,28 +376,52 @@ pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
         ),
         (
             "und-u-ca-buddhist",
-            UND_U_CA_BUDDHIST_UND_U_CA_COPTIC_UND_U_CA_ETHIOPIC,
+            UND_U_CA_BUDDHIST_UND_U_CA_CHINESE_UND_U_CA_COPTIC,
+        ),
+        (
+            "und-u-ca-chinese",
+            UND_U_CA_BUDDHIST_UND_U_CA_CHINESE_UND_U_CA_COPTIC,
         ),
         (
             "und-u-ca-coptic",
-            UND_U_CA_BUDDHIST_UND_U_CA_COPTIC_UND_U_CA_ETHIOPIC,
+            UND_U_CA_BUDDHIST_UND_U_CA_CHINESE_UND_U_CA_COPTIC,
+        ),
+        (
+            "und-u-ca-dangi",
+            UND_U_CA_BUDDHIST_UND_U_CA_CHINESE_UND_U_CA_COPTIC,
         ),
         (
             "und-u-ca-ethiopic",
-            UND_U_CA_BUDDHIST_UND_U_CA_COPTIC_UND_U_CA_ETHIOPIC,
+            UND_U_CA_BUDDHIST_UND_U_CA_CHINESE_UND_U_CA_COPTIC,
         ),
         ("und-u-ca-gregory", UND_U_CA_GREGORY),
+        (
+            "und-u-ca-hebrew",
+            UND_U_CA_BUDDHIST_UND_U_CA_CHINESE_UND_U_CA_COPTIC,
+        ),
         (
             "und-u-ca-indian",
-            UND_U_CA_BUDDHIST_UND_U_CA_COPTIC_UND_U_CA_ETHIOPIC,
+            UND_U_CA_BUDDHIST_UND_U_CA_CHINESE_UND_U_CA_COPTIC,
+        ),
+        (
+            "und-u-ca-islamic",
+            UND_U_CA_BUDDHIST_UND_U_CA_CHINESE_UND_U_CA_COPTIC,
         ),
         (
             "und-u-ca-japanese",
-            UND_U_CA_BUDDHIST_UND_U_CA_COPTIC_UND_U_CA_ETHIOPIC,
+            UND_U_CA_BUDDHIST_UND_U_CA_CHINESE_UND_U_CA_COPTIC,
         ),
         (
             "und-u-ca-japanext",
-            UND_U_CA_BUDDHIST_UND_U_CA_COPTIC_UND_U_CA_ETHIOPIC,
+            UND_U_CA_BUDDHIST_UND_U_CA_CHINESE_UND_U_CA_COPTIC,
+        ),
+        (
+            "und-u-ca-persian",
+            UND_U_CA_BUDDHIST_UND_U_CA_CHINESE_UND_U_CA_COPTIC,
+        ),
+        (
+            "und-u-ca-roc",
+            UND_U_CA_BUDDHIST_UND_U_CA_CHINESE_UND_U_CA_COPTIC,
         ),
     ]);
 static AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC: &DataStruct = &[
@@ -587,6 +635,60 @@ static AR_EG_U_CA_BUDDHIST_AR_EG_U_CA_COPTIC: &DataStruct = &[
             },
         ),
     ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Abbreviated,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 176u8, 3u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8,
+                        0u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 176u8, 4u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8,
+                        0u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
     (
         &[
             ::icu_datetime::fields::Field {
@@ -1530,6 +1632,58 @@ static AR_EG_U_CA_GREGORY_AR_U_CA_GREGORY: &DataStruct = &[
             },
         ),
     ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Abbreviated,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 176u8, 3u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 176u8, 4u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
     (
         &[
             ::icu_datetime::fields::Field {
@@ -2770,6 +2924,60 @@ static BN_U_CA_BUDDHIST_BN_U_CA_COPTIC_BN_U_CA_ETHIOPIC: &DataStruct = &[
             },
         ),
     ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Abbreviated,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 176u8, 3u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8,
+                        0u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 176u8, 4u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8,
+                        0u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
     (
         &[
             ::icu_datetime::fields::Field {
@@ -3798,6 +4006,58 @@ static BN_U_CA_GREGORY: &DataStruct = &[
             },
         ),
     ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Abbreviated,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 176u8, 3u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 176u8, 4u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
     (
         &[
             ::icu_datetime::fields::Field {
@@ -5038,6 +5298,60 @@ static CCP_U_CA_BUDDHIST_CCP_U_CA_COPTIC_CCP_U_CA_ETHIOPIC: &DataStruct = &[
             },
         ),
     ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Abbreviated,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 176u8, 3u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8,
+                        0u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 176u8, 4u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8,
+                        0u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
     (
         &[
             ::icu_datetime::fields::Field {
@@ -6066,6 +6380,58 @@ static CCP_U_CA_GREGORY: &DataStruct = &[
             },
         ),
     ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Abbreviated,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 176u8, 3u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 176u8, 4u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
     (
         &[
             ::icu_datetime::fields::Field {
@@ -7307,6 +7673,60 @@ static EN_001_U_CA_BUDDHIST_EN_001_U_CA_COPTIC: &DataStruct = &[
             },
         ),
     ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Abbreviated,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 176u8, 3u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8,
+                        0u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 176u8, 4u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8,
+                        0u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
     (
         &[
             ::icu_datetime::fields::Field {
@@ -8249,6 +8669,58 @@ static EN_001_U_CA_GREGORY: &DataStruct = &[
             },
         ),
     ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Abbreviated,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 176u8, 3u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 176u8, 4u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
     (
         &[
             ::icu_datetime::fields::Field {
@@ -9217,7 +9689,7 @@ static EN_001_U_CA_GREGORY: &DataStruct = &[
         ),
     ),
 ];
-static EN_U_CA_BUDDHIST_EN_U_CA_COPTIC_EN_U_CA_ETHIOPIC: &DataStruct = &[
+static EN_U_CA_BUDDHIST_EN_U_CA_CHINESE_EN_U_CA_COPTIC: &DataStruct = &[
     (
         &[
             ::icu_datetime::fields::Field {
@@ -9427,6 +9899,60 @@ static EN_U_CA_BUDDHIST_EN_U_CA_COPTIC_EN_U_CA_ETHIOPIC: &DataStruct = &[
             },
         ),
     ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Abbreviated,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 176u8, 3u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8,
+                        0u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 176u8, 4u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8,
+                        0u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
     (
         &[
             ::icu_datetime::fields::Field {
@@ -10378,17 +10904,17 @@ static EN_U_CA_GREGORY: &DataStruct = &[
                 length: ::icu_datetime::fields::FieldLength::One,
             },
             ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Month(
-                    ::icu_datetime::fields::Month::Format,
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
                 ),
-                length: ::icu_datetime::fields::FieldLength::One,
+                length: ::icu_datetime::fields::FieldLength::Abbreviated,
             },
         ],
         ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 32u8, 1u8, 0u8, 0u8, 47u8, 128u8, 16u8, 1u8,
+                        128u8, 176u8, 3u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -10404,14 +10930,34 @@ static EN_U_CA_GREGORY: &DataStruct = &[
                 length: ::icu_datetime::fields::FieldLength::One,
             },
             ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Month(
-                    ::icu_datetime::fields::Month::Format,
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 176u8, 4u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
                 ),
                 length: ::icu_datetime::fields::FieldLength::One,
             },
             ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Day(
-                    ::icu_datetime::fields::Day::DayOfMonth,
+                symbol: ::icu_datetime::fields::FieldSymbol::Month(
+                    ::icu_datetime::fields::Month::Format,
                 ),
                 length: ::icu_datetime::fields::FieldLength::One,
             },
@@ -10420,8 +10966,7 @@ static EN_U_CA_GREGORY: &DataStruct = &[
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 32u8, 1u8, 0u8, 0u8, 47u8, 128u8, 64u8, 1u8, 0u8, 0u8, 47u8, 128u8,
-                        16u8, 1u8,
+                        128u8, 32u8, 1u8, 0u8, 0u8, 47u8, 128u8, 16u8, 1u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -10448,19 +10993,13 @@ static EN_U_CA_GREGORY: &DataStruct = &[
                 ),
                 length: ::icu_datetime::fields::FieldLength::One,
             },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Weekday(
-                    ::icu_datetime::fields::Weekday::Format,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
         ],
         ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 80u8, 1u8, 0u8, 0u8, 44u8, 0u8, 0u8, 32u8, 128u8, 32u8, 1u8, 0u8,
-                        0u8, 47u8, 128u8, 64u8, 1u8, 0u8, 0u8, 47u8, 128u8, 16u8, 1u8,
+                        128u8, 32u8, 1u8, 0u8, 0u8, 47u8, 128u8, 64u8, 1u8, 0u8, 0u8, 47u8, 128u8,
+                        16u8, 1u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -10479,14 +11018,27 @@ static EN_U_CA_GREGORY: &DataStruct = &[
                 symbol: ::icu_datetime::fields::FieldSymbol::Month(
                     ::icu_datetime::fields::Month::Format,
                 ),
-                length: ::icu_datetime::fields::FieldLength::Abbreviated,
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Day(
+                    ::icu_datetime::fields::Day::DayOfMonth,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Weekday(
+                    ::icu_datetime::fields::Weekday::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
             },
         ],
         ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 32u8, 3u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8,
+                        128u8, 80u8, 1u8, 0u8, 0u8, 44u8, 0u8, 0u8, 32u8, 128u8, 32u8, 1u8, 0u8,
+                        0u8, 47u8, 128u8, 64u8, 1u8, 0u8, 0u8, 47u8, 128u8, 16u8, 1u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -10507,19 +11059,45 @@ static EN_U_CA_GREGORY: &DataStruct = &[
                 ),
                 length: ::icu_datetime::fields::FieldLength::Abbreviated,
             },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Day(
-                    ::icu_datetime::fields::Day::DayOfMonth,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
         ],
         ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 32u8, 3u8, 0u8, 0u8, 32u8, 128u8, 64u8, 1u8, 0u8, 0u8, 44u8, 0u8,
-                        0u8, 32u8, 128u8, 16u8, 1u8,
+                        128u8, 32u8, 3u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Month(
+                    ::icu_datetime::fields::Month::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Abbreviated,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Day(
+                    ::icu_datetime::fields::Day::DayOfMonth,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 32u8, 3u8, 0u8, 0u8, 32u8, 128u8, 64u8, 1u8, 0u8, 0u8, 44u8, 0u8,
+                        0u8, 32u8, 128u8, 16u8, 1u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -11522,6 +12100,60 @@ static EN_ZA_U_CA_BUDDHIST_EN_ZA_U_CA_COPTIC: &DataStruct = &[
             },
         ),
     ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Abbreviated,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 176u8, 3u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8,
+                        0u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 176u8, 4u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8,
+                        0u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
     (
         &[
             ::icu_datetime::fields::Field {
@@ -12464,6 +13096,58 @@ static EN_ZA_U_CA_GREGORY: &DataStruct = &[
             },
         ),
     ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Abbreviated,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 176u8, 3u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 176u8, 4u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
     (
         &[
             ::icu_datetime::fields::Field {
@@ -13798,6 +14482,60 @@ static ES_AR_U_CA_BUDDHIST_ES_AR_U_CA_COPTIC: &DataStruct = &[
             },
         ),
     ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Abbreviated,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 176u8, 3u8, 0u8, 0u8, 32u8, 0u8, 0u8, 100u8, 0u8, 0u8, 101u8, 0u8,
+                        0u8, 32u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8, 0u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 176u8, 4u8, 0u8, 0u8, 32u8, 0u8, 0u8, 100u8, 0u8, 0u8, 101u8, 0u8,
+                        0u8, 32u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8, 0u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
     (
         &[
             ::icu_datetime::fields::Field {
@@ -14969,6 +15707,60 @@ static ES_AR_U_CA_GREGORY: &DataStruct = &[
             },
         ),
     ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Abbreviated,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 176u8, 3u8, 0u8, 0u8, 32u8, 0u8, 0u8, 100u8, 0u8, 0u8, 101u8, 0u8,
+                        0u8, 32u8, 128u8, 16u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 176u8, 4u8, 0u8, 0u8, 32u8, 0u8, 0u8, 100u8, 0u8, 0u8, 101u8, 0u8,
+                        0u8, 32u8, 128u8, 16u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
     (
         &[
             ::icu_datetime::fields::Field {
@@ -16536,18 +17328,18 @@ static ES_U_CA_BUDDHIST_ES_U_CA_COPTIC_ES_U_CA_ETHIOPIC: &DataStruct = &[
                 length: ::icu_datetime::fields::FieldLength::Wide,
             },
             ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Month(
-                    ::icu_datetime::fields::Month::Format,
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
                 ),
-                length: ::icu_datetime::fields::FieldLength::One,
+                length: ::icu_datetime::fields::FieldLength::Abbreviated,
             },
         ],
         ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 32u8, 1u8, 0u8, 0u8, 47u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8,
-                        0u8, 5u8,
+                        128u8, 176u8, 3u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8,
+                        0u8, 1u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -16563,14 +17355,35 @@ static ES_U_CA_BUDDHIST_ES_U_CA_COPTIC_ES_U_CA_ETHIOPIC: &DataStruct = &[
                 length: ::icu_datetime::fields::FieldLength::Wide,
             },
             ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Month(
-                    ::icu_datetime::fields::Month::Format,
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
                 ),
-                length: ::icu_datetime::fields::FieldLength::One,
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 176u8, 4u8, 0u8, 0u8, 32u8, 0u8, 0u8, 100u8, 0u8, 0u8, 101u8, 0u8,
+                        0u8, 32u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8, 0u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
             },
             ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Day(
-                    ::icu_datetime::fields::Day::DayOfMonth,
+                symbol: ::icu_datetime::fields::FieldSymbol::Month(
+                    ::icu_datetime::fields::Month::Format,
                 ),
                 length: ::icu_datetime::fields::FieldLength::One,
             },
@@ -16579,8 +17392,41 @@ static ES_U_CA_BUDDHIST_ES_U_CA_COPTIC_ES_U_CA_ETHIOPIC: &DataStruct = &[
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 64u8, 1u8, 0u8, 0u8, 47u8, 128u8, 32u8, 1u8, 0u8, 0u8, 47u8, 128u8,
-                        16u8, 1u8, 0u8, 0u8, 32u8, 128u8, 0u8, 5u8,
+                        128u8, 32u8, 1u8, 0u8, 0u8, 47u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8,
+                        0u8, 5u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Month(
+                    ::icu_datetime::fields::Month::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Day(
+                    ::icu_datetime::fields::Day::DayOfMonth,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 64u8, 1u8, 0u8, 0u8, 47u8, 128u8, 32u8, 1u8, 0u8, 0u8, 47u8, 128u8,
+                        16u8, 1u8, 0u8, 0u8, 32u8, 128u8, 0u8, 5u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -17693,6 +18539,59 @@ static ES_U_CA_GREGORY: &DataStruct = &[
             },
         ),
     ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Abbreviated,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 176u8, 3u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 176u8, 4u8, 0u8, 0u8, 32u8, 0u8, 0u8, 100u8, 0u8, 0u8, 101u8, 0u8,
+                        0u8, 32u8, 128u8, 16u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
     (
         &[
             ::icu_datetime::fields::Field {
@@ -19110,6 +20009,60 @@ static FIL_U_CA_BUDDHIST_FIL_U_CA_COPTIC_FIL_U_CA_ETHIOPIC: &DataStruct = &[
             },
         ),
     ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Abbreviated,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 176u8, 3u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8,
+                        0u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 176u8, 4u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8,
+                        0u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
     (
         &[
             ::icu_datetime::fields::Field {
@@ -20112,6 +21065,58 @@ static FIL_U_CA_GREGORY: &DataStruct = &[
             },
         ),
     ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Abbreviated,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 176u8, 3u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 176u8, 4u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
     (
         &[
             ::icu_datetime::fields::Field {
@@ -21364,6 +22369,60 @@ static FR_U_CA_BUDDHIST: &DataStruct = &[
             },
         ),
     ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Abbreviated,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 176u8, 3u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8,
+                        0u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 176u8, 4u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8,
+                        0u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
     (
         &[
             ::icu_datetime::fields::Field {
@@ -22337,18 +23396,18 @@ static FR_U_CA_COPTIC_FR_U_CA_ETHIOPIC_FR_U_CA_INDIAN: &DataStruct = &[
                 length: ::icu_datetime::fields::FieldLength::Wide,
             },
             ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Month(
-                    ::icu_datetime::fields::Month::Format,
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
                 ),
-                length: ::icu_datetime::fields::FieldLength::One,
+                length: ::icu_datetime::fields::FieldLength::Abbreviated,
             },
         ],
         ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 32u8, 2u8, 0u8, 0u8, 47u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8,
-                        0u8, 5u8,
+                        128u8, 176u8, 3u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8,
+                        0u8, 1u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -22364,14 +23423,35 @@ static FR_U_CA_COPTIC_FR_U_CA_ETHIOPIC_FR_U_CA_INDIAN: &DataStruct = &[
                 length: ::icu_datetime::fields::FieldLength::Wide,
             },
             ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Month(
-                    ::icu_datetime::fields::Month::Format,
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
                 ),
-                length: ::icu_datetime::fields::FieldLength::One,
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 176u8, 4u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8,
+                        0u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
             },
             ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Day(
-                    ::icu_datetime::fields::Day::DayOfMonth,
+                symbol: ::icu_datetime::fields::FieldSymbol::Month(
+                    ::icu_datetime::fields::Month::Format,
                 ),
                 length: ::icu_datetime::fields::FieldLength::One,
             },
@@ -22380,8 +23460,41 @@ static FR_U_CA_COPTIC_FR_U_CA_ETHIOPIC_FR_U_CA_INDIAN: &DataStruct = &[
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 64u8, 2u8, 0u8, 0u8, 47u8, 128u8, 32u8, 2u8, 0u8, 0u8, 47u8, 128u8,
-                        16u8, 1u8, 0u8, 0u8, 32u8, 128u8, 0u8, 5u8,
+                        128u8, 32u8, 2u8, 0u8, 0u8, 47u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8,
+                        0u8, 5u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Month(
+                    ::icu_datetime::fields::Month::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Day(
+                    ::icu_datetime::fields::Day::DayOfMonth,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 64u8, 2u8, 0u8, 0u8, 47u8, 128u8, 32u8, 2u8, 0u8, 0u8, 47u8, 128u8,
+                        16u8, 1u8, 0u8, 0u8, 32u8, 128u8, 0u8, 5u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -23270,6 +24383,58 @@ static FR_U_CA_GREGORY: &DataStruct = &[
             },
         ),
     ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Abbreviated,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 176u8, 3u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 176u8, 4u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
     (
         &[
             ::icu_datetime::fields::Field {
@@ -24427,6 +25592,60 @@ static FR_U_CA_JAPANESE_FR_U_CA_JAPANEXT: &DataStruct = &[
             },
         ),
     ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Abbreviated,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 176u8, 3u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8,
+                        0u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 176u8, 4u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8,
+                        0u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
     (
         &[
             ::icu_datetime::fields::Field {
@@ -25434,6 +26653,58 @@ static JA_U_CA_BUDDHIST: &DataStruct = &[
             },
         ),
     ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Abbreviated,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 0u8, 1u8, 128u8, 16u8, 1u8, 0u8, 0u8, 47u8, 128u8, 176u8, 3u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 0u8, 1u8, 128u8, 16u8, 1u8, 0u8, 94u8, 116u8, 128u8, 176u8, 4u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
     (
         &[
             ::icu_datetime::fields::Field {
@@ -26655,17 +27926,17 @@ static JA_U_CA_COPTIC_JA_U_CA_ETHIOPIC_JA_U_CA_INDIAN: &DataStruct = &[
                 length: ::icu_datetime::fields::FieldLength::Wide,
             },
             ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Month(
-                    ::icu_datetime::fields::Month::Format,
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
                 ),
-                length: ::icu_datetime::fields::FieldLength::One,
+                length: ::icu_datetime::fields::FieldLength::Abbreviated,
             },
         ],
         ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 0u8, 5u8, 128u8, 16u8, 1u8, 0u8, 0u8, 47u8, 128u8, 32u8, 1u8,
+                        128u8, 0u8, 1u8, 128u8, 16u8, 1u8, 0u8, 0u8, 47u8, 128u8, 176u8, 3u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -26681,14 +27952,34 @@ static JA_U_CA_COPTIC_JA_U_CA_ETHIOPIC_JA_U_CA_INDIAN: &DataStruct = &[
                 length: ::icu_datetime::fields::FieldLength::Wide,
             },
             ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Month(
-                    ::icu_datetime::fields::Month::Format,
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
                 ),
-                length: ::icu_datetime::fields::FieldLength::One,
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 0u8, 1u8, 128u8, 16u8, 1u8, 0u8, 94u8, 116u8, 128u8, 176u8, 4u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
             },
             ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Day(
-                    ::icu_datetime::fields::Day::DayOfMonth,
+                symbol: ::icu_datetime::fields::FieldSymbol::Month(
+                    ::icu_datetime::fields::Month::Format,
                 ),
                 length: ::icu_datetime::fields::FieldLength::One,
             },
@@ -26697,8 +27988,7 @@ static JA_U_CA_COPTIC_JA_U_CA_ETHIOPIC_JA_U_CA_INDIAN: &DataStruct = &[
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 0u8, 5u8, 128u8, 16u8, 1u8, 0u8, 0u8, 47u8, 128u8, 32u8, 1u8, 0u8,
-                        0u8, 47u8, 128u8, 64u8, 1u8,
+                        128u8, 0u8, 5u8, 128u8, 16u8, 1u8, 0u8, 0u8, 47u8, 128u8, 32u8, 1u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -26725,20 +28015,13 @@ static JA_U_CA_COPTIC_JA_U_CA_ETHIOPIC_JA_U_CA_INDIAN: &DataStruct = &[
                 ),
                 length: ::icu_datetime::fields::FieldLength::One,
             },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Weekday(
-                    ::icu_datetime::fields::Weekday::Format,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
         ],
         ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
                         128u8, 0u8, 5u8, 128u8, 16u8, 1u8, 0u8, 0u8, 47u8, 128u8, 32u8, 1u8, 0u8,
-                        0u8, 47u8, 128u8, 64u8, 1u8, 0u8, 0u8, 40u8, 128u8, 80u8, 1u8, 0u8, 0u8,
-                        41u8,
+                        0u8, 47u8, 128u8, 64u8, 1u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -26769,7 +28052,7 @@ static JA_U_CA_COPTIC_JA_U_CA_ETHIOPIC_JA_U_CA_INDIAN: &DataStruct = &[
                 symbol: ::icu_datetime::fields::FieldSymbol::Weekday(
                     ::icu_datetime::fields::Weekday::Format,
                 ),
-                length: ::icu_datetime::fields::FieldLength::Wide,
+                length: ::icu_datetime::fields::FieldLength::One,
             },
         ],
         ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
@@ -26777,7 +28060,7 @@ static JA_U_CA_COPTIC_JA_U_CA_ETHIOPIC_JA_U_CA_INDIAN: &DataStruct = &[
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
                         128u8, 0u8, 5u8, 128u8, 16u8, 1u8, 0u8, 0u8, 47u8, 128u8, 32u8, 1u8, 0u8,
-                        0u8, 47u8, 128u8, 64u8, 1u8, 0u8, 0u8, 40u8, 128u8, 80u8, 4u8, 0u8, 0u8,
+                        0u8, 47u8, 128u8, 64u8, 1u8, 0u8, 0u8, 40u8, 128u8, 80u8, 1u8, 0u8, 0u8,
                         41u8,
                     ])
                 },
@@ -26797,15 +28080,28 @@ static JA_U_CA_COPTIC_JA_U_CA_ETHIOPIC_JA_U_CA_INDIAN: &DataStruct = &[
                 symbol: ::icu_datetime::fields::FieldSymbol::Month(
                     ::icu_datetime::fields::Month::Format,
                 ),
-                length: ::icu_datetime::fields::FieldLength::Abbreviated,
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Day(
+                    ::icu_datetime::fields::Day::DayOfMonth,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Weekday(
+                    ::icu_datetime::fields::Weekday::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
             },
         ],
         ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 0u8, 1u8, 128u8, 16u8, 1u8, 0u8, 94u8, 116u8, 128u8, 32u8, 1u8, 0u8,
-                        103u8, 8u8,
+                        128u8, 0u8, 5u8, 128u8, 16u8, 1u8, 0u8, 0u8, 47u8, 128u8, 32u8, 1u8, 0u8,
+                        0u8, 47u8, 128u8, 64u8, 1u8, 0u8, 0u8, 40u8, 128u8, 80u8, 4u8, 0u8, 0u8,
+                        41u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -26826,19 +28122,13 @@ static JA_U_CA_COPTIC_JA_U_CA_ETHIOPIC_JA_U_CA_INDIAN: &DataStruct = &[
                 ),
                 length: ::icu_datetime::fields::FieldLength::Abbreviated,
             },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Day(
-                    ::icu_datetime::fields::Day::DayOfMonth,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
         ],
         ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
                         128u8, 0u8, 1u8, 128u8, 16u8, 1u8, 0u8, 94u8, 116u8, 128u8, 32u8, 1u8, 0u8,
-                        103u8, 8u8, 128u8, 64u8, 1u8, 0u8, 101u8, 229u8,
+                        103u8, 8u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -26865,20 +28155,53 @@ static JA_U_CA_COPTIC_JA_U_CA_ETHIOPIC_JA_U_CA_INDIAN: &DataStruct = &[
                 ),
                 length: ::icu_datetime::fields::FieldLength::One,
             },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Weekday(
-                    ::icu_datetime::fields::Weekday::Format,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
         ],
         ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
                         128u8, 0u8, 1u8, 128u8, 16u8, 1u8, 0u8, 94u8, 116u8, 128u8, 32u8, 1u8, 0u8,
-                        103u8, 8u8, 128u8, 64u8, 1u8, 0u8, 101u8, 229u8, 0u8, 0u8, 40u8, 128u8,
-                        80u8, 1u8, 0u8, 0u8, 41u8,
+                        103u8, 8u8, 128u8, 64u8, 1u8, 0u8, 101u8, 229u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Month(
+                    ::icu_datetime::fields::Month::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Abbreviated,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Day(
+                    ::icu_datetime::fields::Day::DayOfMonth,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Weekday(
+                    ::icu_datetime::fields::Weekday::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 0u8, 1u8, 128u8, 16u8, 1u8, 0u8, 94u8, 116u8, 128u8, 32u8, 1u8, 0u8,
+                        103u8, 8u8, 128u8, 64u8, 1u8, 0u8, 101u8, 229u8, 0u8, 0u8, 40u8, 128u8,
+                        80u8, 1u8, 0u8, 0u8, 41u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -27814,6 +29137,58 @@ static JA_U_CA_GREGORY: &DataStruct = &[
             },
         ),
     ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Abbreviated,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 16u8, 1u8, 0u8, 0u8, 47u8, 128u8, 176u8, 3u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 16u8, 1u8, 0u8, 94u8, 116u8, 128u8, 176u8, 4u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
     (
         &[
             ::icu_datetime::fields::Field {
@@ -29211,6 +30586,58 @@ static JA_U_CA_JAPANESE_JA_U_CA_JAPANEXT: &DataStruct = &[
             },
         ),
     ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Abbreviated,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 0u8, 1u8, 128u8, 16u8, 1u8, 0u8, 0u8, 47u8, 128u8, 176u8, 3u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 0u8, 1u8, 128u8, 16u8, 1u8, 0u8, 94u8, 116u8, 128u8, 176u8, 4u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
     (
         &[
             ::icu_datetime::fields::Field {
@@ -30382,6 +31809,60 @@ static RU_U_CA_BUDDHIST_RU_U_CA_COPTIC_RU_U_CA_ETHIOPIC: &DataStruct = &[
             },
         ),
     ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Abbreviated,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 176u8, 3u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 0u8,
+                        4u8, 51u8, 0u8, 0u8, 46u8, 0u8, 0u8, 32u8, 128u8, 0u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 176u8, 4u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 0u8,
+                        4u8, 51u8, 0u8, 0u8, 46u8, 0u8, 0u8, 32u8, 128u8, 0u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
     (
         &[
             ::icu_datetime::fields::Field {
@@ -31336,17 +32817,18 @@ static RU_U_CA_GREGORY: &DataStruct = &[
                 length: ::icu_datetime::fields::FieldLength::One,
             },
             ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Month(
-                    ::icu_datetime::fields::Month::Format,
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
                 ),
-                length: ::icu_datetime::fields::FieldLength::One,
+                length: ::icu_datetime::fields::FieldLength::Abbreviated,
             },
         ],
         ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 32u8, 2u8, 0u8, 0u8, 46u8, 128u8, 16u8, 1u8,
+                        128u8, 176u8, 3u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 0u8,
+                        4u8, 51u8, 0u8, 0u8, 46u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -31362,14 +32844,35 @@ static RU_U_CA_GREGORY: &DataStruct = &[
                 length: ::icu_datetime::fields::FieldLength::One,
             },
             ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Month(
-                    ::icu_datetime::fields::Month::Format,
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 176u8, 4u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 0u8,
+                        4u8, 51u8, 0u8, 0u8, 46u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
                 ),
                 length: ::icu_datetime::fields::FieldLength::One,
             },
             ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Day(
-                    ::icu_datetime::fields::Day::DayOfMonth,
+                symbol: ::icu_datetime::fields::FieldSymbol::Month(
+                    ::icu_datetime::fields::Month::Format,
                 ),
                 length: ::icu_datetime::fields::FieldLength::One,
             },
@@ -31378,8 +32881,7 @@ static RU_U_CA_GREGORY: &DataStruct = &[
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 64u8, 2u8, 0u8, 0u8, 46u8, 128u8, 32u8, 2u8, 0u8, 0u8, 46u8, 128u8,
-                        16u8, 1u8,
+                        128u8, 32u8, 2u8, 0u8, 0u8, 46u8, 128u8, 16u8, 1u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -31406,20 +32908,13 @@ static RU_U_CA_GREGORY: &DataStruct = &[
                 ),
                 length: ::icu_datetime::fields::FieldLength::One,
             },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Weekday(
-                    ::icu_datetime::fields::Weekday::Format,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
         ],
         ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 82u8, 3u8, 0u8, 0u8, 44u8, 0u8, 0u8, 32u8, 128u8, 64u8, 2u8, 0u8,
-                        0u8, 46u8, 128u8, 32u8, 2u8, 0u8, 0u8, 46u8, 128u8, 16u8, 1u8, 0u8, 0u8,
-                        32u8, 0u8, 4u8, 51u8, 0u8, 0u8, 46u8,
+                        128u8, 64u8, 2u8, 0u8, 0u8, 46u8, 128u8, 32u8, 2u8, 0u8, 0u8, 46u8, 128u8,
+                        16u8, 1u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -31438,14 +32933,28 @@ static RU_U_CA_GREGORY: &DataStruct = &[
                 symbol: ::icu_datetime::fields::FieldSymbol::Month(
                     ::icu_datetime::fields::Month::Format,
                 ),
-                length: ::icu_datetime::fields::FieldLength::TwoDigit,
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Day(
+                    ::icu_datetime::fields::Day::DayOfMonth,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Weekday(
+                    ::icu_datetime::fields::Weekday::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
             },
         ],
         ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 32u8, 2u8, 0u8, 0u8, 46u8, 128u8, 16u8, 1u8,
+                        128u8, 82u8, 3u8, 0u8, 0u8, 44u8, 0u8, 0u8, 32u8, 128u8, 64u8, 2u8, 0u8,
+                        0u8, 46u8, 128u8, 32u8, 2u8, 0u8, 0u8, 46u8, 128u8, 16u8, 1u8, 0u8, 0u8,
+                        32u8, 0u8, 4u8, 51u8, 0u8, 0u8, 46u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -31464,15 +32973,14 @@ static RU_U_CA_GREGORY: &DataStruct = &[
                 symbol: ::icu_datetime::fields::FieldSymbol::Month(
                     ::icu_datetime::fields::Month::Format,
                 ),
-                length: ::icu_datetime::fields::FieldLength::Abbreviated,
+                length: ::icu_datetime::fields::FieldLength::TwoDigit,
             },
         ],
         ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 33u8, 3u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 0u8,
-                        4u8, 51u8, 0u8, 0u8, 46u8,
+                        128u8, 32u8, 2u8, 0u8, 0u8, 46u8, 128u8, 16u8, 1u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -31493,19 +33001,46 @@ static RU_U_CA_GREGORY: &DataStruct = &[
                 ),
                 length: ::icu_datetime::fields::FieldLength::Abbreviated,
             },
-            ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Day(
-                    ::icu_datetime::fields::Day::DayOfMonth,
-                ),
-                length: ::icu_datetime::fields::FieldLength::One,
-            },
         ],
         ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 64u8, 1u8, 0u8, 0u8, 32u8, 128u8, 32u8, 3u8, 0u8, 0u8, 32u8, 128u8,
-                        16u8, 1u8, 0u8, 0u8, 32u8, 0u8, 4u8, 51u8, 0u8, 0u8, 46u8,
+                        128u8, 33u8, 3u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 0u8,
+                        4u8, 51u8, 0u8, 0u8, 46u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Month(
+                    ::icu_datetime::fields::Month::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Abbreviated,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Day(
+                    ::icu_datetime::fields::Day::DayOfMonth,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 64u8, 1u8, 0u8, 0u8, 32u8, 128u8, 32u8, 3u8, 0u8, 0u8, 32u8, 128u8,
+                        16u8, 1u8, 0u8, 0u8, 32u8, 0u8, 4u8, 51u8, 0u8, 0u8, 46u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -32537,6 +34072,60 @@ static SR_CYRL_U_CA_BUDDHIST_SR_CYRL_U_CA_COPTIC: &DataStruct = &[
             },
         ),
     ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Abbreviated,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 176u8, 3u8, 0u8, 0u8, 44u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8, 0u8,
+                        0u8, 46u8, 0u8, 0u8, 32u8, 128u8, 0u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 176u8, 4u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8, 0u8, 0u8, 46u8, 0u8,
+                        0u8, 32u8, 128u8, 0u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
     (
         &[
             ::icu_datetime::fields::Field {
@@ -33628,6 +35217,58 @@ static SR_CYRL_U_CA_GREGORY_SR_U_CA_GREGORY: &DataStruct = &[
             },
         ),
     ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Abbreviated,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 176u8, 3u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8, 0u8, 0u8, 46u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 176u8, 4u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8, 0u8, 0u8, 46u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
     (
         &[
             ::icu_datetime::fields::Field {
@@ -34909,6 +36550,58 @@ static SR_LATN_U_CA_GREGORY: &DataStruct = &[
             },
         ),
     ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Abbreviated,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 176u8, 3u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8, 0u8, 0u8, 46u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 176u8, 4u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8, 0u8, 0u8, 46u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
     (
         &[
             ::icu_datetime::fields::Field {
@@ -36253,6 +37946,58 @@ static TH_U_CA_BUDDHIST: &DataStruct = &[
             },
         ),
     ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Abbreviated,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 176u8, 3u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 176u8, 4u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
     (
         &[
             ::icu_datetime::fields::Field {
@@ -37404,18 +39149,18 @@ static TH_U_CA_COPTIC_TH_U_CA_ETHIOPIC_TH_U_CA_INDIAN: &DataStruct = &[
                 length: ::icu_datetime::fields::FieldLength::Wide,
             },
             ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Month(
-                    ::icu_datetime::fields::Month::Format,
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
                 ),
-                length: ::icu_datetime::fields::FieldLength::One,
+                length: ::icu_datetime::fields::FieldLength::Abbreviated,
             },
         ],
         ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 32u8, 1u8, 0u8, 0u8, 47u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8,
-                        0u8, 1u8,
+                        128u8, 176u8, 3u8, 0u8, 0u8, 32u8, 128u8, 0u8, 1u8, 0u8, 0u8, 32u8, 128u8,
+                        16u8, 1u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -37431,14 +39176,35 @@ static TH_U_CA_COPTIC_TH_U_CA_ETHIOPIC_TH_U_CA_INDIAN: &DataStruct = &[
                 length: ::icu_datetime::fields::FieldLength::Wide,
             },
             ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Month(
-                    ::icu_datetime::fields::Month::Format,
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
                 ),
-                length: ::icu_datetime::fields::FieldLength::One,
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 176u8, 4u8, 0u8, 0u8, 32u8, 128u8, 0u8, 1u8, 0u8, 0u8, 32u8, 128u8,
+                        16u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
             },
             ::icu_datetime::fields::Field {
-                symbol: ::icu_datetime::fields::FieldSymbol::Day(
-                    ::icu_datetime::fields::Day::DayOfMonth,
+                symbol: ::icu_datetime::fields::FieldSymbol::Month(
+                    ::icu_datetime::fields::Month::Format,
                 ),
                 length: ::icu_datetime::fields::FieldLength::One,
             },
@@ -37447,8 +39213,41 @@ static TH_U_CA_COPTIC_TH_U_CA_ETHIOPIC_TH_U_CA_INDIAN: &DataStruct = &[
             ::icu_datetime::pattern::runtime::Pattern {
                 items: unsafe {
                     ::zerovec::ZeroVec::from_bytes_unchecked(&[
-                        128u8, 64u8, 1u8, 0u8, 0u8, 47u8, 128u8, 32u8, 1u8, 0u8, 0u8, 47u8, 128u8,
-                        16u8, 1u8, 0u8, 0u8, 32u8, 128u8, 0u8, 5u8,
+                        128u8, 32u8, 1u8, 0u8, 0u8, 47u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8,
+                        0u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Month(
+                    ::icu_datetime::fields::Month::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Day(
+                    ::icu_datetime::fields::Day::DayOfMonth,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 64u8, 1u8, 0u8, 0u8, 47u8, 128u8, 32u8, 1u8, 0u8, 0u8, 47u8, 128u8,
+                        16u8, 1u8, 0u8, 0u8, 32u8, 128u8, 0u8, 5u8,
                     ])
                 },
                 time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
@@ -38454,6 +40253,59 @@ static TH_U_CA_GREGORY: &DataStruct = &[
             },
         ),
     ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Abbreviated,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 176u8, 3u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 176u8, 4u8, 0u8, 0u8, 32u8, 128u8, 0u8, 1u8, 0u8, 0u8, 32u8, 128u8,
+                        16u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
     (
         &[
             ::icu_datetime::fields::Field {
@@ -39934,6 +41786,60 @@ static TH_U_CA_JAPANESE_TH_U_CA_JAPANEXT: &DataStruct = &[
             },
         ),
     ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Abbreviated,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 176u8, 3u8, 0u8, 0u8, 32u8, 128u8, 0u8, 1u8, 0u8, 0u8, 32u8, 128u8,
+                        16u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 176u8, 4u8, 0u8, 0u8, 32u8, 128u8, 0u8, 1u8, 0u8, 0u8, 32u8, 128u8,
+                        16u8, 1u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
     (
         &[
             ::icu_datetime::fields::Field {
@@ -40971,6 +42877,60 @@ static TR_U_CA_BUDDHIST_TR_U_CA_COPTIC_TR_U_CA_ETHIOPIC: &DataStruct = &[
             },
         ),
     ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Abbreviated,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 0u8, 1u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8, 0u8, 0u8, 47u8, 128u8,
+                        176u8, 3u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 0u8, 1u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8, 0u8, 0u8, 47u8, 128u8,
+                        176u8, 4u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
     (
         &[
             ::icu_datetime::fields::Field {
@@ -41997,6 +43957,58 @@ static TR_U_CA_GREGORY: &DataStruct = &[
             },
         ),
     ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Abbreviated,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8, 176u8, 3u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8, 176u8, 4u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
     (
         &[
             ::icu_datetime::fields::Field {
@@ -43026,7 +45038,7 @@ static TR_U_CA_GREGORY: &DataStruct = &[
         ),
     ),
 ];
-static UND_U_CA_BUDDHIST_UND_U_CA_COPTIC_UND_U_CA_ETHIOPIC: &DataStruct = &[
+static UND_U_CA_BUDDHIST_UND_U_CA_CHINESE_UND_U_CA_COPTIC: &DataStruct = &[
     (
         &[
             ::icu_datetime::fields::Field {
@@ -43236,6 +45248,60 @@ static UND_U_CA_BUDDHIST_UND_U_CA_COPTIC_UND_U_CA_ETHIOPIC: &DataStruct = &[
             },
         ),
     ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Abbreviated,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 0u8, 1u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8,
+                        176u8, 3u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 0u8, 1u8, 0u8, 0u8, 32u8, 128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8,
+                        176u8, 4u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
     (
         &[
             ::icu_datetime::fields::Field {
@@ -44178,6 +46244,58 @@ static UND_U_CA_GREGORY: &DataStruct = &[
             },
         ),
     ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Abbreviated,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8, 176u8, 3u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
+    (
+        &[
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Year(
+                    ::icu_datetime::fields::Year::Calendar,
+                ),
+                length: ::icu_datetime::fields::FieldLength::One,
+            },
+            ::icu_datetime::fields::Field {
+                symbol: ::icu_datetime::fields::FieldSymbol::Quarter(
+                    ::icu_datetime::fields::Quarter::Format,
+                ),
+                length: ::icu_datetime::fields::FieldLength::Wide,
+            },
+        ],
+        ::icu_datetime::pattern::runtime::PatternPlurals::SinglePattern(
+            ::icu_datetime::pattern::runtime::Pattern {
+                items: unsafe {
+                    ::zerovec::ZeroVec::from_bytes_unchecked(&[
+                        128u8, 16u8, 1u8, 0u8, 0u8, 32u8, 128u8, 176u8, 4u8,
+                    ])
+                },
+                time_granularity: ::icu_datetime::pattern::TimeGranularity::None,
+            },
+        ),
+    ),
     (
         &[
             ::icu_datetime::fields::Field {
@@ -0,0 +1,40 @@
+// @generated
+type DataStruct =
+    <::icu_datetime::provider::time_data::TimeDataV1Marker as ::icu_provider::DataMarker>::Yokeable;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[
+        ("und", UND_UND_AR_UND_DE_UND_ES_UND_FR_UND_GB),
+        ("und-AR", UND_UND_AR_UND_DE_UND_ES_UND_FR_UND_GB),
+        ("und-AU", UND_AU_UND_BD_UND_CA_UND_EG_UND_IN_UND_PH),
+        ("und-BD", UND_AU_UND_BD_UND_CA_UND_EG_UND_IN_UND_PH),
+        ("und-CA", UND_AU_UND_BD_UND_CA_UND_EG_UND_IN_UND_PH),
+        ("und-DE", UND_UND_AR_UND_DE_UND_ES_UND_FR_UND_GB),
+        ("und-EG", UND_AU_UND_BD_UND_CA_UND_EG_UND_IN_UND_PH),
+        ("und-ES", UND_UND_AR_UND_DE_UND_ES_UND_FR_UND_GB),
+        ("und-FR", UND_UND_AR_UND_DE_UND_ES_UND_FR_UND_GB),
+        ("und-GB", UND_UND_AR_UND_DE_UND_ES_UND_FR_UND_GB),
+        ("und-IN", UND_AU_UND_BD_UND_CA_UND_EG_UND_IN_UND_PH),
+        ("und-IR", UND_IR),
+        ("und-JP", UND_UND_AR_UND_DE_UND_ES_UND_FR_UND_GB),
+        ("und-PH", UND_AU_UND_BD_UND_CA_UND_EG_UND_IN_UND_PH),
+        ("und-RS", UND_UND_AR_UND_DE_UND_ES_UND_FR_UND_GB),
+        ("und-RU", UND_UND_AR_UND_DE_UND_ES_UND_FR_UND_GB),
+        ("und-TH", UND_UND_AR_UND_DE_UND_ES_UND_FR_UND_GB),
+        ("und-TR", UND_UND_AR_UND_DE_UND_ES_UND_FR_UND_GB),
+        ("und-US", UND_AU_UND_BD_UND_CA_UND_EG_UND_IN_UND_PH),
+        ("und-ZA", UND_UND_AR_UND_DE_UND_ES_UND_FR_UND_GB),
+    ]);
+static UND_AU_UND_BD_UND_CA_UND_EG_UND_IN_UND_PH: &DataStruct =
+    &::icu_datetime::provider::time_data::TimeDataV1 {
+        preferred_hour_cycle: ::icu_datetime::options::preferences::HourCycle::H12,
+        allowed_hour_cycle: ::icu_datetime::options::preferences::HourCycle::H12,
+    };
+static UND_IR: &DataStruct = &::icu_datetime::provider::time_data::TimeDataV1 {
+    preferred_hour_cycle: ::icu_datetime::options::preferences::HourCycle::H23,
+    allowed_hour_cycle: ::icu_datetime::options::preferences::HourCycle::H12,
+};
+static UND_UND_AR_UND_DE_UND_ES_UND_FR_UND_GB: &DataStruct =
+    &::icu_datetime::provider::time_data::TimeDataV1 {
+        preferred_hour_cycle: ::icu_datetime::options::preferences::HourCycle::H23,
+        allowed_hour_cycle: ::icu_datetime::options::preferences::HourCycle::H23,
+    };
@@ -0,0 +1,49 @@
+// @generated
+type DataStruct = < :: icu_datetime :: provider :: week_data :: WeekendDataV1Marker as :: icu_provider :: DataMarker > :: Yokeable ;
+pub static DATA: litemap::LiteMap<&str, &DataStruct, &[(&str, &DataStruct)]> =
+    litemap::LiteMap::from_sorted_store_unchecked(&[
+        ("und", UND),
+        ("und-AF", UND_AF),
+        ("und-BH", UND_BH_UND_DZ_UND_EG_UND_IL_UND_IQ_UND_JO),
+        ("und-DZ", UND_BH_UND_DZ_UND_EG_UND_IL_UND_IQ_UND_JO),
+        ("und-EG", UND_BH_UND_DZ_UND_EG_UND_IL_UND_IQ_UND_JO),
+        ("und-IL", UND_BH_UND_DZ_UND_EG_UND_IL_UND_IQ_UND_JO),
+        ("und-IN", UND_IN_UND_UG),
+        ("und-IQ", UND_BH_UND_DZ_UND_EG_UND_IL_UND_IQ_UND_JO),
+        ("und-IR", UND_IR),
+        ("und-JO", UND_BH_UND_DZ_UND_EG_UND_IL_UND_IQ_UND_JO),
+        ("und-KW", UND_BH_UND_DZ_UND_EG_UND_IL_UND_IQ_UND_JO),
+        ("und-LY", UND_BH_UND_DZ_UND_EG_UND_IL_UND_IQ_UND_JO),
+        ("und-OM", UND_BH_UND_DZ_UND_EG_UND_IL_UND_IQ_UND_JO),
+        ("und-QA", UND_BH_UND_DZ_UND_EG_UND_IL_UND_IQ_UND_JO),
+        ("und-SA", UND_BH_UND_DZ_UND_EG_UND_IL_UND_IQ_UND_JO),
+        ("und-SD", UND_BH_UND_DZ_UND_EG_UND_IL_UND_IQ_UND_JO),
+        ("und-SY", UND_BH_UND_DZ_UND_EG_UND_IL_UND_IQ_UND_JO),
+        ("und-UG", UND_IN_UND_UG),
+        ("und-YE", UND_BH_UND_DZ_UND_EG_UND_IL_UND_IQ_UND_JO),
+    ]);
+static UND: &DataStruct =
+    &::icu_datetime::provider::week_data::WeekendDataV1(::icu_calendar::week_of::WeekendInfo {
+        weekend_start: ::icu_calendar::types::IsoWeekday::Saturday,
+        weekend_end: ::icu_calendar::types::IsoWeekday::Sunday,
+    });
+static UND_AF: &DataStruct =
+    &::icu_datetime::provider::week_data::WeekendDataV1(::icu_calendar::week_of::WeekendInfo {
+        weekend_start: ::icu_calendar::types::IsoWeekday::Thursday,
+        weekend_end: ::icu_calendar::types::IsoWeekday::Friday,
+    });
+static UND_BH_UND_DZ_UND_EG_UND_IL_UND_IQ_UND_JO: &DataStruct =
+    &::icu_datetime::provider::week_data::WeekendDataV1(::icu_calendar::week_of::WeekendInfo {
+        weekend_start: ::icu_calendar::types::IsoWeekday::Friday,
+        weekend_end: ::icu_calendar::types::IsoWeekday::Saturday,
+    });
+static UND_IN_UND_UG: &DataStruct =
+    &::icu_datetime::provider::week_data::WeekendDataV1(::icu_calendar::week_of::WeekendInfo {
+        weekend_start: ::icu_calendar::types::IsoWeekday::Sunday,
+        weekend_end: ::icu_calendar::types::IsoWeekday::Sunday,
+    });
+static UND_IR: &DataStruct =
+    &::icu_datetime::provider::week_data::WeekendDataV1(::icu_calendar::week_of::WeekendInfo {
+        weekend_start: ::icu_calendar::types::IsoWeekday::Friday,
+        weekend_end: ::icu_calendar::types::IsoWeekday::Friday,
+    });
@@ -17,6 +17,18 @@ mod time_zone;
 #[non_exhaustive]
 pub struct BakedDataProvider;
 use ::icu_provider::prelude::*;
+impl DataProvider<::icu_calendar::provider::CalendarPreferencesV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_calendar::provider::CalendarPreferencesV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *calendar::preferences_v1_r::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_calendar::provider::CalendarPreferencesV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
 impl DataProvider<::icu_calendar::provider::JapaneseErasV1Marker> for BakedDataProvider {
     fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_calendar::provider::JapaneseErasV1Marker>, DataError> {
         Ok(DataResponse {
@@ -153,6 +165,30 @@ impl DataProvider<::icu_datetime::provider::calendar::BuddhistDateSymbolsV1Marke
         })
     }
 }
+impl DataProvider<::icu_datetime::provider::calendar::ChineseDateLengthsV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_datetime::provider::calendar::ChineseDateLengthsV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *datetime::chinese::datelengths_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_datetime::provider::calendar::ChineseDateLengthsV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+impl DataProvider<::icu_datetime::provider::calendar::ChineseDateSymbolsV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_datetime::provider::calendar::ChineseDateSymbolsV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *datetime::chinese::datesymbols_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_datetime::provider::calendar::ChineseDateSymbolsV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
 impl DataProvider<::icu_datetime::provider::calendar::CopticDateLengthsV1Marker> for BakedDataProvider {
     fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_datetime::provider::calendar::CopticDateLengthsV1Marker>, DataError> {
         Ok(DataResponse {
@@ -177,6 +213,42 @@ impl DataProvider<::icu_datetime::provider::calendar::CopticDateSymbolsV1Marker>
         })
     }
 }
+impl DataProvider<::icu_datetime::provider::calendar::DangiDateLengthsV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_datetime::provider::calendar::DangiDateLengthsV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *datetime::dangi::datelengths_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_datetime::provider::calendar::DangiDateLengthsV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+impl DataProvider<::icu_datetime::provider::calendar::DangiDateSymbolsV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_datetime::provider::calendar::DangiDateSymbolsV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *datetime::dangi::datesymbols_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_datetime::provider::calendar::DangiDateSymbolsV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+impl DataProvider<::icu_datetime::provider::calendar::DateAppendItemsV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_datetime::provider::calendar::DateAppendItemsV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *datetime::append_items_v1_u_ca::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_datetime::provider::calendar::DateAppendItemsV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
 impl DataProvider<::icu_datetime::provider::calendar::DateSkeletonPatternsV1Marker> for BakedDataProvider {
     fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_datetime::provider::calendar::DateSkeletonPatternsV1Marker>, DataError> {
         Ok(DataResponse {
@@ -247,6 +319,30 @@ impl DataProvider<::icu_datetime::provider::calendar::GregorianDateSymbolsV1Mark
         })
     }
 }
+impl DataProvider<::icu_datetime::provider::calendar::HebrewDateLengthsV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_datetime::provider::calendar::HebrewDateLengthsV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *datetime::hebrew::datelengths_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_datetime::provider::calendar::HebrewDateLengthsV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+impl DataProvider<::icu_datetime::provider::calendar::HebrewDateSymbolsV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_datetime::provider::calendar::HebrewDateSymbolsV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *datetime::hebrew::datesymbols_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_datetime::provider::calendar::HebrewDateSymbolsV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
 impl DataProvider<::icu_datetime::provider::calendar::IndianDateLengthsV1Marker> for BakedDataProvider {
     fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_datetime::provider::calendar::IndianDateLengthsV1Marker>, DataError> {
         Ok(DataResponse {
@@ -271,6 +367,30 @@ impl DataProvider<::icu_datetime::provider::calendar::IndianDateSymbolsV1Marker>
         })
     }
 }
+impl DataProvider<::icu_datetime::provider::calendar::IslamicDateLengthsV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_datetime::provider::calendar::IslamicDateLengthsV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *datetime::islamic::datelengths_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_datetime::provider::calendar::IslamicDateLengthsV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+impl DataProvider<::icu_datetime::provider::calendar::IslamicDateSymbolsV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_datetime::provider::calendar::IslamicDateSymbolsV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *datetime::islamic::datesymbols_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_datetime::provider::calendar::IslamicDateSymbolsV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
 impl DataProvider<::icu_datetime::provider::calendar::JapaneseDateLengthsV1Marker> for BakedDataProvider {
     fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_datetime::provider::calendar::JapaneseDateLengthsV1Marker>, DataError> {
         Ok(DataResponse {
@@ -327,6 +447,54 @@ impl DataProvider<::icu_datetime::provider::calendar::JapaneseExtendedDateSymbol
         })
     }
 }
+impl DataProvider<::icu_datetime::provider::calendar::PersianDateLengthsV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_datetime::provider::calendar::PersianDateLengthsV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *datetime::persian::datelengths_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_datetime::provider::calendar::PersianDateLengthsV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+impl DataProvider<::icu_datetime::provider::calendar::PersianDateSymbolsV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_datetime::provider::calendar::PersianDateSymbolsV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *datetime::persian::datesymbols_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_datetime::provider::calendar::PersianDateSymbolsV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+impl DataProvider<::icu_datetime::provider::calendar::RocDateLengthsV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_datetime::provider::calendar::RocDateLengthsV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *datetime::roc::datelengths_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_datetime::provider::calendar::RocDateLengthsV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
+impl DataProvider<::icu_datetime::provider::calendar::RocDateSymbolsV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_datetime::provider::calendar::RocDateSymbolsV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *datetime::roc::datesymbols_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_datetime::provider::calendar::RocDateSymbolsV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
 impl DataProvider<::icu_datetime::provider::calendar::TimeLengthsV1Marker> for BakedDataProvider {
     fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_datetime::provider::calendar::TimeLengthsV1Marker>, DataError> {
         Ok(DataResponse {
@@ -351,6 +519,18 @@ impl DataProvider<::icu_datetime::provider::calendar::TimeSymbolsV1Marker> for B
         })
     }
 }
+impl DataProvider<::icu_datetime::provider::time_data::TimeDataV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_datetime::provider::time_data::TimeDataV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *datetime::time_data_v1_r::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_datetime::provider::time_data::TimeDataV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
 impl DataProvider<::icu_datetime::provider::time_zones::ExemplarCitiesV1Marker> for BakedDataProvider {
     fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_datetime::provider::time_zones::ExemplarCitiesV1Marker>, DataError> {
         Ok(DataResponse {
@@ -431,6 +611,18 @@ impl DataProvider<::icu_datetime::provider::time_zones::TimeZoneFormatsV1Marker>
         })
     }
 }
+impl DataProvider<::icu_datetime::provider::time_zones::TimeZoneIanaIdsV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_datetime::provider::time_zones::TimeZoneIanaIdsV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *time_zone::iana_ids_v1::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_datetime::provider::time_zones::TimeZoneIanaIdsV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
 impl DataProvider<::icu_datetime::provider::week_data::WeekDataV1Marker> for BakedDataProvider {
     fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_datetime::provider::week_data::WeekDataV1Marker>, DataError> {
         Ok(DataResponse {
@@ -443,6 +635,18 @@ impl DataProvider<::icu_datetime::provider::week_data::WeekDataV1Marker> for Bak
         })
     }
 }
+impl DataProvider<::icu_datetime::provider::week_data::WeekendDataV1Marker> for BakedDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_datetime::provider::week_data::WeekendDataV1Marker>, DataError> {
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: Some(DataPayload::from_owned(zerofrom::ZeroFrom::zero_from(
+                *datetime::weekend_v1_r::DATA
+                    .get_by(|k| req.locale.strict_cmp(k.as_bytes()).reverse())
+                    .ok_or_else(|| DataErrorKind::MissingLocale.with_req(::icu_datetime::provider::week_data::WeekendDataV1Marker::KEY, req))?,
+            ))),
+        })
+    }
+}
 impl DataProvider<::icu_decimal::provider::DecimalSymbolsV1Marker> for BakedDataProvider {
     fn load(&self, req: DataRequest) -> Result<DataResponse<::icu_decimal::provider::DecimalSymbolsV1Marker>, DataError> {
         Ok(DataResponse {
@@ -3,6 +3,7 @@ pub mod exemplar_cities_v1;
 pub mod formats_v1;
 pub mod generic_long_v1;
 pub mod generic_short_v1;
+pub mod iana_ids_v1;
 pub mod metazone_period_v1;
 pub mod specific_long_v1;
 pub mod specific_short_v1;